@@ -0,0 +1,42 @@
+//! Contour lines, filled bands and density estimation
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use d3rs::contour::{ContourGenerator, DensityEstimator};
+use d3rs_bench::ids::{self, contour::*};
+use d3rs_bench::{linspace, random_points, thresholds, wave_grid};
+
+/// Number of bands between the thresholds
+const LEVELS: usize = 10;
+
+fn contour(c: &mut Criterion) {
+    let mut group = c.benchmark_group(GROUP);
+    for size in SIZES {
+        let values = wave_grid(size, size);
+        let levels = thresholds(&values, LEVELS);
+        let generator = ContourGenerator::new(size, size)
+            .x_values(linspace(0.0, 1.0, size))
+            .y_values(linspace(0.0, 1.0, size));
+        let id = ids::grid(size);
+
+        group.bench_with_input(BenchmarkId::new(LINES, &id), &values, |b, values| {
+            b.iter(|| generator.contours(values, &levels));
+        });
+        group.bench_with_input(BenchmarkId::new(BANDS, &id), &values, |b, values| {
+            b.iter(|| generator.contour_bands(values, &levels));
+        });
+    }
+
+    let points = random_points(DENSITY_POINTS, 100.0, 42);
+    let estimator = DensityEstimator::new()
+        .size(100, 100)
+        .x(0.0, 100.0)
+        .y(0.0, 100.0)
+        .bandwidth(5.0);
+    group.bench_function(BenchmarkId::new(DENSITY, DENSITY_PARAMETER), |b| {
+        b.iter(|| estimator.estimate(&points));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, contour);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+//! Force simulation ticks
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use d3rs::force::{ForceCenter, ForceManyBody, Simulation, SimulationNode};
+use d3rs_bench::ids::force::*;
+use d3rs_bench::random_points;
+
+fn simulation(count: usize) -> Simulation {
+    let nodes = random_points(count, 800.0, 42)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (x, y))| SimulationNode::new(i, x, y))
+        .collect();
+    Simulation::new(nodes)
+        .force(Box::new(ForceManyBody::new()))
+        .force(Box::new(ForceCenter::new(400.0, 400.0)))
+}
+
+fn force(c: &mut Criterion) {
+    let mut group = c.benchmark_group(GROUP);
+    for size in SIZES {
+        group.bench_function(BenchmarkId::new(TICK, size), |b| {
+            b.iter_batched_ref(|| simulation(size), Simulation::tick, BatchSize::SmallInput);
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, force);
+criterion_main!(benches);
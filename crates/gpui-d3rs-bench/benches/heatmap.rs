@@ -0,0 +1,62 @@
+//! Heatmap cell layout, the per-frame work of painting a heatmap
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use d3rs::color::D3Color;
+use d3rs::scale::LinearScale;
+use d3rs::shape::{ContourConfig, HeatmapData, HeatmapElement, NoDataStyle, viridis_color_scale};
+use d3rs_bench::ids::{self, heatmap::*};
+use d3rs_bench::{linspace, wave_grid};
+
+/// Element size in pixels
+const WIDTH: f32 = 800.0;
+const HEIGHT: f32 = 600.0;
+
+fn heatmap_element(size: usize, no_data: NoDataStyle) -> HeatmapElement<LinearScale, LinearScale> {
+    let mut values = wave_grid(size, size);
+    // Sprinkle missing values so the no-data path is measured too
+    for value in values.iter_mut().step_by(97) {
+        *value = f64::NAN;
+    }
+    let data = HeatmapData::new(linspace(0.0, 1.0, size), linspace(0.0, 1.0, size), values);
+    let x_scale = LinearScale::new()
+        .domain(0.0, 1.0)
+        .range(0.0, f64::from(WIDTH));
+    let y_scale = LinearScale::new()
+        .domain(0.0, 1.0)
+        .range(f64::from(HEIGHT), 0.0);
+    HeatmapElement::new(data, x_scale, y_scale).config(
+        ContourConfig::new()
+            .color_scale(viridis_color_scale())
+            .no_data(no_data),
+    )
+}
+
+fn heatmap(c: &mut Criterion) {
+    let mut group = c.benchmark_group(GROUP);
+    for size in SIZES {
+        let id = ids::grid(size);
+        let element = heatmap_element(size, NoDataStyle::Transparent);
+        group.bench_with_input(BenchmarkId::new(CELLS, &id), &element, |b, element| {
+            b.iter(|| element.cells(WIDTH, HEIGHT).count());
+        });
+
+        let hatched = heatmap_element(
+            size,
+            NoDataStyle::Hatch {
+                color: D3Color::from_hex(0x888888),
+                background: D3Color::from_hex(0x222222),
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new(CELLS_HATCHED, &id),
+            &hatched,
+            |b, element| {
+                b.iter(|| element.cells(WIDTH, HEIGHT).count());
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, heatmap);
+criterion_main!(benches);
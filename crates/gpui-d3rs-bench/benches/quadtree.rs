@@ -0,0 +1,48 @@
+//! Quadtree construction and nearest-neighbour queries
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use d3rs::quadtree::QuadTree;
+use d3rs_bench::ids::quadtree::*;
+use d3rs_bench::random_points;
+
+/// Number of queries per iteration
+const QUERIES: usize = 1_000;
+
+fn quadtree(c: &mut Criterion) {
+    let queries = random_points(QUERIES, 1000.0, 7);
+
+    let mut group = c.benchmark_group(GROUP);
+    for size in SIZES {
+        let points = random_points(size, 1000.0, 42);
+        group.bench_with_input(BenchmarkId::new(BUILD, size), &points, |b, points| {
+            b.iter(|| {
+                let mut tree = QuadTree::new();
+                tree.add_all(points, |p| p.0, |p| p.1);
+                tree
+            });
+        });
+
+        let mut tree = QuadTree::new();
+        tree.add_all(&points, |p| p.0, |p| p.1);
+        group.bench_with_input(BenchmarkId::new(FIND_1K, size), &tree, |b, tree| {
+            b.iter(|| {
+                queries
+                    .iter()
+                    .filter(|&&(x, y)| tree.find(x, y, None).is_some())
+                    .count()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new(FIND_ALL_1K, size), &tree, |b, tree| {
+            b.iter(|| {
+                queries
+                    .iter()
+                    .map(|&(x, y)| tree.find_all(x, y, 20.0).len())
+                    .sum::<usize>()
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, quadtree);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+//! Scale mapping, inversion and tick generation
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use d3rs::scale::{LinearScale, LogScale, Scale};
+use d3rs_bench::ids::scales::*;
+use d3rs_bench::linspace;
+use std::hint::black_box;
+
+fn scales(c: &mut Criterion) {
+    let linear = LinearScale::new().domain(0.0, 100.0).range(0.0, 800.0);
+    let log = LogScale::new().domain(20.0, 20000.0).range(0.0, 800.0);
+    let values = linspace(0.0, 100.0, 10_000);
+    let frequencies = linspace(20.0, 20000.0, 10_000);
+    let pixels = linspace(0.0, 800.0, 10_000);
+
+    let mut group = c.benchmark_group(GROUP);
+    group.bench_function(LINEAR_SCALE_10K, |b| {
+        b.iter(|| values.iter().map(|&v| linear.scale(v)).sum::<f64>());
+    });
+    group.bench_function(LINEAR_INVERT_10K, |b| {
+        b.iter(|| pixels.iter().filter_map(|&p| linear.invert(p)).sum::<f64>());
+    });
+    group.bench_function(LOG_SCALE_10K, |b| {
+        b.iter(|| frequencies.iter().map(|&f| log.scale(f)).sum::<f64>());
+    });
+    group.bench_function(LINEAR_TICKS, |b| {
+        b.iter(|| linear.ticks(black_box(10)));
+    });
+    group.bench_function(LOG_TICKS, |b| {
+        b.iter(|| log.ticks(black_box(10)));
+    });
+    group.bench_function(LINEAR_NICE, |b| {
+        b.iter(|| {
+            LinearScale::new()
+                .domain(black_box(0.13), black_box(97.2))
+                .nice(Some(10))
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, scales);
+criterion_main!(benches);
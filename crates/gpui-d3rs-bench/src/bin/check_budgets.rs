@@ -0,0 +1,75 @@
+//! Check the latest benchmark results against the performance budgets
+//!
+//! ```text
+//! cargo run -p gpui-d3rs-bench --bin check-budgets -- [--baseline NAME] [--budgets FILE]
+//! ```
+//!
+//! Reads criterion results from `$CARGO_TARGET_DIR/criterion` (default:
+//! the workspace `target/criterion`) and exits with status 1 when a
+//! benchmark is over budget, regressed against the baseline, or missing.
+
+use d3rs_bench::budget::{Budgets, check_all};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut budgets_path = manifest_dir.join("budgets.toml");
+    let mut baseline = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--baseline", Some(name)) => baseline = Some(name),
+            ("--budgets", Some(path)) => budgets_path = PathBuf::from(path),
+            _ => {
+                eprintln!("usage: check-budgets [--baseline NAME] [--budgets FILE]");
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let criterion_dir = std::env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| manifest_dir.join("../../target"), PathBuf::from)
+        .join("criterion");
+
+    let checks = match Budgets::load(&budgets_path)
+        .and_then(|budgets| check_all(&budgets, &criterion_dir, baseline.as_deref()))
+    {
+        Ok(checks) => checks,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let format_us = |us: Option<f64>| us.map_or_else(|| "-".to_string(), |us| format!("{us:.1}"));
+    println!(
+        "{:<40} {:>12} {:>12} {:>12}  verdict",
+        "benchmark", "mean (us)", "budget (us)", "base (us)"
+    );
+    for check in &checks {
+        println!(
+            "{:<40} {:>12} {:>12.1} {:>12}  {}",
+            check.id,
+            format_us(check.mean_us),
+            check.budget_us,
+            format_us(check.baseline_us),
+            check.verdict
+        );
+    }
+
+    let failures = checks
+        .iter()
+        .filter(|check| check.verdict.is_failure())
+        .count();
+    if failures > 0 {
+        eprintln!(
+            "{failures} of {} benchmarks failed their budget",
+            checks.len()
+        );
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
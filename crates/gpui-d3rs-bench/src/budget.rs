@@ -0,0 +1,245 @@
+//! Performance budgets checked against criterion results
+//!
+//! Criterion writes the estimates of each benchmark to
+//! `target/criterion/<benchmark id>/<sample>/estimates.json`, where the
+//! sample is `new` for the latest run or the name of a saved baseline.
+//! A benchmark fails its check when its mean exceeds the budget, or when it
+//! is slower than the baseline by more than the regression tolerance.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Default allowed slowdown against the baseline (10%)
+pub const DEFAULT_TOLERANCE: f64 = 0.10;
+
+/// Errors reading budgets or criterion estimates
+#[derive(Debug, Error)]
+pub enum BudgetError {
+    /// A file could not be read.
+    #[error("cannot read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    /// The budgets file is not valid.
+    #[error("invalid budgets file: {0}")]
+    Budgets(#[from] toml::de::Error),
+
+    /// A criterion estimates file is not valid.
+    #[error("invalid estimates in {path}: {source}")]
+    Estimates {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// Contents of `budgets.toml`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Budgets {
+    /// Allowed slowdown against the baseline, as a fraction
+    #[serde(default = "default_tolerance")]
+    pub regression_tolerance: f64,
+    /// Upper bound on the mean time of each benchmark id, in microseconds
+    pub max_us: BTreeMap<String, f64>,
+}
+
+fn default_tolerance() -> f64 {
+    DEFAULT_TOLERANCE
+}
+
+impl Budgets {
+    /// Parse budgets from TOML
+    pub fn parse(text: &str) -> Result<Self, BudgetError> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Read budgets from a TOML file
+    pub fn load(path: &Path) -> Result<Self, BudgetError> {
+        let text = std::fs::read_to_string(path).map_err(|source| BudgetError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&text)
+    }
+}
+
+/// Subset of criterion's `estimates.json` used for the checks
+#[derive(Debug, Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+#[derive(Debug, Deserialize)]
+struct Estimate {
+    /// In nanoseconds
+    point_estimate: f64,
+}
+
+/// Mean time of benchmark `id` in `sample`, in microseconds
+///
+/// Returns `Ok(None)` when the benchmark has not been run.
+pub fn read_mean_us(
+    criterion_dir: &Path,
+    id: &str,
+    sample: &str,
+) -> Result<Option<f64>, BudgetError> {
+    let path = criterion_dir.join(id).join(sample).join("estimates.json");
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => return Err(BudgetError::Io { path, source }),
+    };
+    let estimates: Estimates =
+        serde_json::from_str(&text).map_err(|source| BudgetError::Estimates { path, source })?;
+    Ok(Some(estimates.mean.point_estimate / 1000.0))
+}
+
+/// Outcome of the check of one benchmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// Within budget and tolerance
+    Pass,
+    /// Mean above the budget
+    OverBudget,
+    /// Slower than the baseline by more than the tolerance
+    Regressed,
+    /// No result, the benchmark was not run
+    Missing,
+}
+
+impl Verdict {
+    /// Whether the check failed
+    pub fn is_failure(self) -> bool {
+        self != Verdict::Pass
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Pass => "ok",
+            Verdict::OverBudget => "OVER BUDGET",
+            Verdict::Regressed => "REGRESSED",
+            Verdict::Missing => "MISSING",
+        })
+    }
+}
+
+/// Judge a mean against its budget and, when there is one, its baseline
+pub fn evaluate(
+    budget_us: f64,
+    mean_us: Option<f64>,
+    baseline_us: Option<f64>,
+    tolerance: f64,
+) -> Verdict {
+    let Some(mean_us) = mean_us else {
+        return Verdict::Missing;
+    };
+    if mean_us > budget_us {
+        Verdict::OverBudget
+    } else if baseline_us.is_some_and(|baseline| mean_us > baseline * (1.0 + tolerance)) {
+        Verdict::Regressed
+    } else {
+        Verdict::Pass
+    }
+}
+
+/// Check result of one benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    /// Benchmark id, e.g. `contour/bands/100x100`
+    pub id: String,
+    /// Budget in microseconds
+    pub budget_us: f64,
+    /// Mean of the latest run in microseconds
+    pub mean_us: Option<f64>,
+    /// Mean of the baseline in microseconds
+    pub baseline_us: Option<f64>,
+    /// Outcome of the check
+    pub verdict: Verdict,
+}
+
+/// Check the latest run of every budgeted benchmark
+///
+/// With `baseline`, benchmarks are also compared to that saved baseline.
+pub fn check_all(
+    budgets: &Budgets,
+    criterion_dir: &Path,
+    baseline: Option<&str>,
+) -> Result<Vec<Check>, BudgetError> {
+    budgets
+        .max_us
+        .iter()
+        .map(|(id, &budget_us)| {
+            let mean_us = read_mean_us(criterion_dir, id, "new")?;
+            let baseline_us = match baseline {
+                Some(baseline) => read_mean_us(criterion_dir, id, baseline)?,
+                None => None,
+            };
+            Ok(Check {
+                id: id.clone(),
+                budget_us,
+                mean_us,
+                baseline_us,
+                verdict: evaluate(
+                    budget_us,
+                    mean_us,
+                    baseline_us,
+                    budgets.regression_tolerance,
+                ),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_budgets() {
+        let budgets = Budgets::parse(
+            r#"
+            [max_us]
+            "scales/linear/scale_10k" = 40.0
+            "force/tick/500" = 2500
+            "#,
+        )
+        .unwrap();
+        assert_eq!(budgets.regression_tolerance, DEFAULT_TOLERANCE);
+        assert_eq!(budgets.max_us["scales/linear/scale_10k"], 40.0);
+        assert_eq!(budgets.max_us["force/tick/500"], 2500.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(evaluate(10.0, Some(8.0), None, 0.1), Verdict::Pass);
+        assert_eq!(evaluate(10.0, Some(12.0), None, 0.1), Verdict::OverBudget);
+        assert_eq!(
+            evaluate(10.0, Some(8.0), Some(7.0), 0.1),
+            Verdict::Regressed
+        );
+        assert_eq!(evaluate(10.0, Some(8.0), Some(7.5), 0.1), Verdict::Pass);
+        assert_eq!(evaluate(10.0, None, Some(7.0), 0.1), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_read_mean_us() {
+        let dir = std::env::temp_dir().join(format!("d3rs-bench-{}", std::process::id()));
+        let sample = dir.join("group/case/new");
+        std::fs::create_dir_all(&sample).unwrap();
+        std::fs::write(
+            sample.join("estimates.json"),
+            r#"{"mean":{"confidence_interval":{"confidence_level":0.95,"lower_bound":1400.0,"upper_bound":1600.0},"point_estimate":1500.0,"standard_error":20.0}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(read_mean_us(&dir, "group/case", "new").unwrap(), Some(1.5));
+        assert_eq!(read_mean_us(&dir, "group/case", "base").unwrap(), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
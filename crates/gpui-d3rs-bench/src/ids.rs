@@ -0,0 +1,137 @@
+//! Names and parameters of the benchmarks
+//!
+//! The benches build their criterion ids from these constants, and
+//! [`all`] lists the resulting ids, so `budgets.toml` can be checked
+//! against what the benches actually register. Criterion stores results
+//! under `<group>/<function>/<parameter>`; a `/` inside a function name is
+//! replaced, so ids with a parameter must use `BenchmarkId::new`.
+
+/// `scales` bench
+pub mod scales {
+    pub const GROUP: &str = "scales";
+    pub const LINEAR_SCALE_10K: &str = "linear_scale_10k";
+    pub const LINEAR_INVERT_10K: &str = "linear_invert_10k";
+    pub const LOG_SCALE_10K: &str = "log_scale_10k";
+    pub const LINEAR_TICKS: &str = "linear_ticks";
+    pub const LOG_TICKS: &str = "log_ticks";
+    pub const LINEAR_NICE: &str = "linear_nice";
+}
+
+/// `contour` bench
+pub mod contour {
+    pub const GROUP: &str = "contour";
+    pub const LINES: &str = "lines";
+    pub const BANDS: &str = "bands";
+    pub const DENSITY: &str = "density";
+    /// Grid sizes: a typical plot, and a large measurement grid
+    pub const SIZES: [usize; 2] = [100, 400];
+    /// Number of points of the density estimate
+    pub const DENSITY_POINTS: usize = 1_000;
+    pub const DENSITY_PARAMETER: &str = "1k_points";
+}
+
+/// `quadtree` bench
+pub mod quadtree {
+    pub const GROUP: &str = "quadtree";
+    pub const BUILD: &str = "build";
+    pub const FIND_1K: &str = "find_1k";
+    pub const FIND_ALL_1K: &str = "find_all_1k";
+    /// Number of points in the tree
+    pub const SIZES: [usize; 2] = [1_000, 10_000];
+}
+
+/// `force` bench
+pub mod force {
+    pub const GROUP: &str = "force";
+    pub const TICK: &str = "tick";
+    /// Number of nodes in the simulation
+    pub const SIZES: [usize; 3] = [100, 500, 1_000];
+}
+
+/// `heatmap` bench
+pub mod heatmap {
+    pub const GROUP: &str = "heatmap";
+    pub const CELLS: &str = "cells";
+    pub const CELLS_HATCHED: &str = "cells_hatched";
+    /// Grid sizes
+    pub const SIZES: [usize; 2] = [100, 400];
+}
+
+/// Parameter of the grid benchmarks, e.g. `100x100`
+pub fn grid(size: usize) -> String {
+    format!("{size}x{size}")
+}
+
+/// Every benchmark id registered by the benches, as criterion names them
+pub fn all() -> Vec<String> {
+    let mut ids = Vec::new();
+    for function in [
+        scales::LINEAR_SCALE_10K,
+        scales::LINEAR_INVERT_10K,
+        scales::LOG_SCALE_10K,
+        scales::LINEAR_TICKS,
+        scales::LOG_TICKS,
+        scales::LINEAR_NICE,
+    ] {
+        ids.push(format!("{}/{function}", scales::GROUP));
+    }
+    for size in contour::SIZES {
+        for function in [contour::LINES, contour::BANDS] {
+            ids.push(format!("{}/{function}/{}", contour::GROUP, grid(size)));
+        }
+    }
+    ids.push(format!(
+        "{}/{}/{}",
+        contour::GROUP,
+        contour::DENSITY,
+        contour::DENSITY_PARAMETER
+    ));
+    for size in quadtree::SIZES {
+        for function in [quadtree::BUILD, quadtree::FIND_1K, quadtree::FIND_ALL_1K] {
+            ids.push(format!("{}/{function}/{size}", quadtree::GROUP));
+        }
+    }
+    for size in force::SIZES {
+        ids.push(format!("{}/{}/{size}", force::GROUP, force::TICK));
+    }
+    for size in heatmap::SIZES {
+        for function in [heatmap::CELLS, heatmap::CELLS_HATCHED] {
+            ids.push(format!("{}/{function}/{}", heatmap::GROUP, grid(size)));
+        }
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budget::Budgets;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_budgets_match_registered_ids() {
+        let budgets =
+            Budgets::load(&std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("budgets.toml"))
+                .unwrap();
+        let budgeted: BTreeSet<_> = budgets.max_us.keys().cloned().collect();
+        let registered: BTreeSet<_> = all().into_iter().collect();
+
+        let unknown: Vec<_> = budgeted.difference(&registered).collect();
+        assert!(
+            unknown.is_empty(),
+            "budgets for unknown benchmarks: {unknown:?}"
+        );
+        let unbudgeted: Vec<_> = registered.difference(&budgeted).collect();
+        assert!(
+            unbudgeted.is_empty(),
+            "benchmarks without a budget: {unbudgeted:?}"
+        );
+    }
+
+    #[test]
+    fn test_ids_are_unique() {
+        let ids = all();
+        let unique: BTreeSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+    }
+}
@@ -0,0 +1,101 @@
+//! Benchmarks and performance budgets for gpui-d3rs
+//!
+//! The criterion benchmarks in `benches/` cover the hot paths of the
+//! library: scales, contour generation, quadtree queries, force simulation
+//! ticks and heatmap rendering preparation. This crate provides the
+//! deterministic inputs they share and the budget checks run on their
+//! results.
+//!
+//! ```text
+//! just bench           # run all benchmarks, saving the `base` baseline
+//! just bench-check     # run again and check budgets and regressions
+//! ```
+//!
+//! Budgets live in `budgets.toml`; see the crate README for how they were
+//! chosen and when to update them. Benchmark ids are built from [`ids`], which
+//! a test checks against the budget entries.
+
+pub mod budget;
+pub mod ids;
+
+use d3rs::random::LcgRng;
+use std::f64::consts::PI;
+
+/// Smooth grid of `width` x `height` values, in row-major order
+///
+/// Sum of a few sine waves, giving nested closed contours similar to a
+/// directivity map or a density estimate.
+pub fn wave_grid(width: usize, height: usize) -> Vec<f64> {
+    let mut values = Vec::with_capacity(width * height);
+    for yi in 0..height {
+        let y = yi as f64 / height.max(1) as f64;
+        for xi in 0..width {
+            let x = xi as f64 / width.max(1) as f64;
+            values.push(
+                (2.0 * PI * x).sin() * (3.0 * PI * y).cos() + 0.5 * (5.0 * PI * (x + y)).sin(),
+            );
+        }
+    }
+    values
+}
+
+/// Evenly spaced coordinates from `start` to `end`
+pub fn linspace(start: f64, end: f64, count: usize) -> Vec<f64> {
+    if count < 2 {
+        return vec![start; count];
+    }
+    let step = (end - start) / (count - 1) as f64;
+    (0..count).map(|i| start + step * i as f64).collect()
+}
+
+/// `count` pseudo-random points in `0..extent` squared, the same for a given seed
+pub fn random_points(count: usize, extent: f64, seed: u64) -> Vec<(f64, f64)> {
+    let rng = LcgRng::new(seed);
+    (0..count)
+        .map(|_| (rng.next_f64() * extent, rng.next_f64() * extent))
+        .collect()
+}
+
+/// Contour thresholds splitting `values` into `count` equal bands
+pub fn thresholds(values: &[f64], count: usize) -> Vec<f64> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let step = (max - min) / count.max(1) as f64;
+    (0..=count).map(|i| min + step * i as f64).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wave_grid_size() {
+        assert_eq!(wave_grid(30, 20).len(), 600);
+        assert!(wave_grid(30, 20).iter().all(|v| v.abs() <= 1.5));
+    }
+
+    #[test]
+    fn test_linspace() {
+        assert_eq!(linspace(0.0, 1.0, 5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(linspace(3.0, 4.0, 1), vec![3.0]);
+        assert!(linspace(0.0, 1.0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_random_points_are_deterministic() {
+        let points = random_points(100, 10.0, 7);
+        assert_eq!(points, random_points(100, 10.0, 7));
+        assert_ne!(points, random_points(100, 10.0, 8));
+        assert!(
+            points
+                .iter()
+                .all(|&(x, y)| (0.0..10.0).contains(&x) && (0.0..10.0).contains(&y))
+        );
+    }
+
+    #[test]
+    fn test_thresholds_cover_range() {
+        let levels = thresholds(&[-1.0, 0.0, 3.0], 4);
+        assert_eq!(levels, vec![-1.0, 0.0, 1.0, 2.0, 3.0]);
+    }
+}
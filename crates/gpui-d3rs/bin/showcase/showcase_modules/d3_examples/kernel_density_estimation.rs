@@ -5,11 +5,13 @@
 //!
 //! The example shows both:
 //! 1. **High-level API**: Using d3rs shape primitives
-//! 2. **Low-level API**: Direct computation of KDE with custom kernels
+//! 2. **Low-level API**: KDE computed with [`kernel_density_1d`] and the
+//!    kernels of `d3rs::contour`
 
 use super::faithful_data::{FAITHFUL_WAITING, faithful_stats};
 use crate::ShowcaseApp;
 use d3rs::color::D3Color;
+use d3rs::contour::kernel_density_1d;
 use d3rs::prelude::*;
 use gpui::prelude::FluentBuilder;
 use gpui::*;
@@ -41,54 +43,17 @@ impl KernelType {
             Self::Uniform => Self::Epanechnikov,
         }
     }
-}
 
-/// Epanechnikov (parabolic) kernel
-/// K(x) = 0.75 * (1 - x^2) for |x| <= 1, 0 otherwise
-fn epanechnikov_kernel(bandwidth: f64) -> impl Fn(f64) -> f64 {
-    move |x: f64| {
-        let u = x / bandwidth;
-        if u.abs() <= 1.0 {
-            0.75 * (1.0 - u * u) / bandwidth
-        } else {
-            0.0
+    /// The d3rs kernel
+    fn kernel(&self) -> d3rs::contour::KernelType {
+        match self {
+            Self::Epanechnikov => d3rs::contour::KernelType::Epanechnikov,
+            Self::Gaussian => d3rs::contour::KernelType::Gaussian,
+            Self::Uniform => d3rs::contour::KernelType::Uniform,
         }
     }
 }
 
-/// Gaussian kernel
-/// K(x) = (1/sqrt(2*pi)) * exp(-0.5 * x^2)
-fn gaussian_kernel(bandwidth: f64) -> impl Fn(f64) -> f64 {
-    let sqrt_2pi = (2.0 * std::f64::consts::PI).sqrt();
-    move |x: f64| {
-        let u = x / bandwidth;
-        ((-0.5 * u * u).exp()) / (sqrt_2pi * bandwidth)
-    }
-}
-
-/// Uniform (box) kernel
-/// K(x) = 0.5 for |x| <= 1, 0 otherwise
-fn uniform_kernel(bandwidth: f64) -> impl Fn(f64) -> f64 {
-    move |x: f64| {
-        let u = x / bandwidth;
-        if u.abs() <= 1.0 { 0.5 / bandwidth } else { 0.0 }
-    }
-}
-
-/// Compute kernel density estimation
-fn kde<F>(kernel: F, thresholds: &[f64], data: &[f64]) -> Vec<(f64, f64)>
-where
-    F: Fn(f64) -> f64,
-{
-    thresholds
-        .iter()
-        .map(|&t| {
-            let density = data.iter().map(|&d| kernel(t - d)).sum::<f64>() / data.len() as f64;
-            (t, density)
-        })
-        .collect()
-}
-
 /// Compute histogram bins
 fn histogram(data: &[f64], bin_count: usize, min: f64, max: f64) -> Vec<(f64, f64, usize)> {
     let bin_width = (max - min) / bin_count as f64;
@@ -134,11 +99,7 @@ pub fn render(app: &mut ShowcaseApp, cx: &mut Context<ShowcaseApp>) -> Div {
         .collect();
 
     // Compute KDE based on kernel type
-    let density_points: Vec<(f64, f64)> = match kernel_type {
-        KernelType::Epanechnikov => kde(epanechnikov_kernel(bandwidth), &thresholds, data),
-        KernelType::Gaussian => kde(gaussian_kernel(bandwidth), &thresholds, data),
-        KernelType::Uniform => kde(uniform_kernel(bandwidth), &thresholds, data),
-    };
+    let density_points = kernel_density_1d(data, &thresholds, kernel_type.kernel(), bandwidth);
 
     // Find max density for Y scale
     let max_density = density_points
@@ -1,13 +1,18 @@
 use d3rs::geo::{
-    ConicEqualArea, Equirectangular, GeoPath, Graticule, Mercator, Orthographic, Projection,
-    Stereographic,
+    ConicEqualArea, Equirectangular, GeoPath, GeoPathCache, Graticule, Mercator, Orthographic,
+    Projection, Stereographic,
 };
 use gpui::*;
+use std::sync::{LazyLock, Mutex};
 
 use super::ShowcaseApp;
 use super::world_data::get_world_data;
 use crate::GeoProjectionType;
 
+/// Projected continents, shared across frames
+static CONTINENTS_CACHE: LazyLock<Mutex<GeoPathCache>> =
+    LazyLock::new(|| Mutex::new(GeoPathCache::new()));
+
 /// Famous cities with their coordinates
 const CITIES: &[(&str, f64, f64)] = &[
     ("New York", -74.0, 40.7),
@@ -161,63 +166,36 @@ pub fn render(app: &ShowcaseApp, cx: &mut Context<ShowcaseApp>) -> Div {
                                         GeoProjectionType::ConicEqualArea => map_height / 4.5,
                                     };
 
-                                     // 1. Draw Continents (Fill)
+                                     // 1. Draw Continents (Fill), reusing cached projections while
+                                     // only the translation changes or the rotation barely moves
                                      {
                                          let world_data = get_world_data(use_large_data);
-                                         let continents_svg = match current_projection {
-                                              GeoProjectionType::Mercator => { let p = Mercator::new().scale(scale).translate(center_x, center_y).rotate(rotation_lon, rotation_lat, 0.0); GeoPath::new(p).render(world_data) },
-                                              GeoProjectionType::Equirectangular => { let p = Equirectangular::new().scale(scale).translate(center_x, center_y).rotate(rotation_lon, rotation_lat, 0.0); GeoPath::new(p).render(world_data) },
-                                              GeoProjectionType::Orthographic => { let p = Orthographic::new().scale(scale).translate(center_x, center_y).rotate(rotation_lon, rotation_lat, 0.0); GeoPath::new(p).render(world_data) },
-                                              GeoProjectionType::Stereographic => { let p = Stereographic::new().scale(scale).translate(center_x, center_y).rotate(rotation_lon, rotation_lat, 0.0); GeoPath::new(p).render(world_data) },
-                                              GeoProjectionType::ConicEqualArea => { let p = ConicEqualArea::new().scale(scale).translate(center_x, center_y + 50.0).center(0.0, 30.0).rotate(rotation_lon, rotation_lat, 0.0); GeoPath::new(p).render(world_data) },
+                                         let id = use_large_data as u64;
+                                         let mut cache = CONTINENTS_CACHE
+                                             .lock()
+                                             .unwrap_or_else(|poisoned| poisoned.into_inner());
+                                         let continents = match current_projection {
+                                              GeoProjectionType::Mercator => { let p = Mercator::new().scale(scale).translate(center_x, center_y).rotate(rotation_lon, rotation_lat, 0.0); cache.get(id, &GeoPath::new(p).resample(0.5), world_data) },
+                                              GeoProjectionType::Equirectangular => { let p = Equirectangular::new().scale(scale).translate(center_x, center_y).rotate(rotation_lon, rotation_lat, 0.0); cache.get(id, &GeoPath::new(p).resample(0.5), world_data) },
+                                              GeoProjectionType::Orthographic => { let p = Orthographic::new().scale(scale).translate(center_x, center_y).rotate(rotation_lon, rotation_lat, 0.0); cache.get(id, &GeoPath::new(p).resample(0.5), world_data) },
+                                              GeoProjectionType::Stereographic => { let p = Stereographic::new().scale(scale).translate(center_x, center_y).rotate(rotation_lon, rotation_lat, 0.0); cache.get(id, &GeoPath::new(p).resample(0.5), world_data) },
+                                              GeoProjectionType::ConicEqualArea => { let p = ConicEqualArea::new().scale(scale).translate(center_x, center_y + 50.0).center(0.0, 30.0).rotate(rotation_lon, rotation_lat, 0.0); cache.get(id, &GeoPath::new(p).resample(0.5), world_data) },
                                          };
+                                         drop(cache);
 
-                                         // VERY simple M/L parser for demo purposes
-                                         // In production, use a real SVG path parser or d3rs should output Path events directly
                                          // Use PathBuilder::fill() for filled shapes
                                          let mut builder = PathBuilder::fill();
-                                         let tokens = continents_svg.replace("M", " M ").replace("L", " L ").replace("Z", " Z ").replace("z", " Z ");
-                                         let parts: Vec<&str> = tokens.split_whitespace().collect();
-                                         let mut i = 0;
-                                         while i < parts.len() {
-                                             match parts[i] {
-                                                 "M" => {
-                                                     if i+1 < parts.len() {
-                                                         let coords: Vec<&str> = parts[i+1].split(',').collect();
-                                                         if coords.len() == 2
-                                                             && let (Ok(x), Ok(y)) = (coords[0].parse::<f32>(), coords[1].parse::<f32>())
-                                                             && x.is_finite() && y.is_finite()
-                                                         {
-                                                             builder.move_to(bounds.origin + point(px(x), px(y)));
-                                                         }
-                                                         i += 2;
-                                                     } else { i += 1; }
-                                                 },
-                                                 "L" => {
-                                                     if i+1 < parts.len() {
-                                                         let coords: Vec<&str> = parts[i+1].split(',').collect();
-                                                         if coords.len() == 2
-                                                             && let (Ok(x), Ok(y)) = (coords[0].parse::<f32>(), coords[1].parse::<f32>())
-                                                             && x.is_finite() && y.is_finite()
-                                                         {
-                                                             builder.line_to(bounds.origin + point(px(x), px(y)));
-                                                         }
-                                                         i += 2;
-                                                     } else { i += 1; }
-                                                 },
-                                                 "Z" => {
-                                                     builder.close();
-                                                     i += 1;
-                                                 },
-                                                 _ => i += 1,
+                                         for run in &continents.runs {
+                                             for (i, &(x, y)) in run.points.iter().enumerate() {
+                                                 let p = bounds.origin + point(px(x as f32), px(y as f32));
+                                                 if i == 0 { builder.move_to(p) } else { builder.line_to(p) }
+                                             }
+                                             if run.closed {
+                                                 builder.close();
                                              }
                                          }
 
                                          if let Ok(path) = builder.build() {
-                                             // paint_path takes (path, color) in recent GPUI versions?
-                                             // Looking at the error: unexpected argument #3 of type `gpui::Rgba`
-                                             // It seems paint_path expects 2 arguments: self and path? No, method signature is `paint_path(&mut self, path, color)`.
-                                             // Let's try passing just the path and color (fill color).
                                              window.paint_path(path, rgb(0xd6e4ff));
                                          }
                                      }
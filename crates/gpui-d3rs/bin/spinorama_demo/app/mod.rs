@@ -12,11 +12,13 @@ use d3rs::axis::{AxisConfig, DefaultAxisTheme, render_axis};
 use d3rs::brush::{BrushSelection, BrushState};
 use d3rs::color::D3Color;
 use d3rs::contour::ContourGenerator;
+use d3rs::fetch::CancellationToken;
 use d3rs::gpu2d::{
     ContourConfig, HeatmapData, render_contour, render_contour_bands, render_heatmap,
 };
 use d3rs::grid::{GridConfig, render_grid};
 use d3rs::prelude::{LinearScale, LogScale};
+use d3rs::spinorama::SpinoramaClient;
 // Radial shape functions could be used in future - currently using canvas-based custom rendering
 // use d3rs::shape::radial::{polar_grid_circles, polar_grid_rays, radial_line, RadialLineConfig, RadialPoint};
 use d3rs::gpu3d::{
@@ -44,6 +46,7 @@ use super::utils::{
 /// Main application state
 pub struct SpinoramaApp {
     pub runtime: Arc<Runtime>,
+    pub client: SpinoramaClient,
     // Speaker list
     pub speakers: Vec<String>,
     pub speakers_load_state: LoadState,
@@ -115,6 +118,7 @@ impl SpinoramaApp {
 
         let mut app = Self {
             runtime,
+            client: SpinoramaClient::new(),
             speakers: Vec::new(),
             speakers_load_state: LoadState::Idle,
             versions: Vec::new(),
@@ -219,23 +223,18 @@ impl SpinoramaApp {
         self.versions.clear();
         self.selected_version = None;
         let runtime = self.runtime.clone();
+        let client = self.client.clone();
 
         cx.spawn(async move |this: WeakEntity<Self>, cx| {
             let result: Result<Vec<String>, String> = runtime
                 .spawn({
                     let speaker = speaker.clone();
+                    let client = client.clone();
                     async move {
-                        let url = format!(
-                            "https://api.spinorama.org/v1/speaker/{}/versions",
-                            urlencoding::encode(&speaker)
-                        );
-                        let response = reqwest::get(&url).await.map_err(|e| e.to_string())?;
-                        if !response.status().is_success() {
-                            return Err(format!("Failed to fetch versions: {}", response.status()));
-                        }
-                        let versions: Vec<String> =
-                            response.json().await.map_err(|e| e.to_string())?;
-                        Ok(versions)
+                        client
+                            .versions(&speaker, &CancellationToken::new())
+                            .await
+                            .map_err(|e| e.to_string())
                     }
                 })
                 .await
@@ -1059,10 +1058,7 @@ impl SpinoramaApp {
             let log_max = x_max.ln();
             let log_range = log_max - log_min;
             let log_delta = -(dx as f64) * log_range / (chart_width as f64);
-            (
-                (log_min + log_delta).exp(),
-                (log_max + log_delta).exp(),
-            )
+            ((log_min + log_delta).exp(), (log_max + log_delta).exp())
         } else {
             let x_range = x_max - x_min;
             let domain_dx = -(dx as f64) * x_range / (chart_width as f64);
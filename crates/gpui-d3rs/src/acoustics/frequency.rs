@@ -0,0 +1,158 @@
+//! Frequency-domain helpers for magnitude responses
+//!
+//! Conversions between decibels and linear values, fractional-octave
+//! smoothing of `(freq, magnitude)` curves and the standard A and C
+//! frequency weightings (IEC 61672-1).
+
+/// Convert an amplitude ratio in dB to a linear factor (`10^(dB/20)`)
+pub fn db_to_linear(db: f64) -> f64 {
+    10f64.powf(db / 20.0)
+}
+
+/// Convert a linear amplitude factor to dB (`20·log10`)
+///
+/// Zero gives negative infinity.
+pub fn linear_to_db(amplitude: f64) -> f64 {
+    20.0 * amplitude.abs().log10()
+}
+
+/// Convert a power ratio in dB to a linear factor (`10^(dB/10)`)
+pub fn db_to_power(db: f64) -> f64 {
+    10f64.powf(db / 10.0)
+}
+
+/// Convert a linear power factor to dB (`10·log10`)
+pub fn power_to_db(power: f64) -> f64 {
+    10.0 * power.log10()
+}
+
+/// 1/N-octave smoothing of a magnitude curve
+///
+/// Each value becomes the mean of the values within ±1/(2N) octave of its
+/// frequency. `freq` must be sorted in ascending order; values are usually
+/// in dB.
+///
+/// # Example
+///
+/// ```
+/// use d3rs::acoustics::smooth_octave;
+///
+/// let freq = [100.0, 200.0, 400.0];
+/// let smoothed = smooth_octave(&freq, &[0.0, 6.0, 0.0], 1);
+/// assert_eq!(smoothed[1], 2.0);
+/// ```
+pub fn smooth_octave(freq: &[f64], values: &[f64], fraction: u32) -> Vec<f64> {
+    let n = freq.len().min(values.len());
+    let half_band = 2f64.powf(1.0 / (2.0 * fraction.max(1) as f64));
+    (0..n)
+        .map(|i| {
+            let (lo, hi) = (freq[i] / half_band, freq[i] * half_band);
+            let start = freq[..n].partition_point(|&f| f < lo);
+            let end = freq[..n].partition_point(|&f| f <= hi);
+            let window = &values[start..end.max(start + 1)];
+            window.iter().sum::<f64>() / window.len() as f64
+        })
+        .collect()
+}
+
+/// Standard frequency weighting curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Weighting {
+    /// A-weighting: follows the ear's sensitivity at low levels
+    A,
+    /// C-weighting: nearly flat, rolls off only at the extremes
+    C,
+    /// Z-weighting: no weighting at all
+    #[default]
+    Z,
+}
+
+impl Weighting {
+    /// Gain of the weighting curve at `freq` Hz, in dB (0 dB at 1 kHz)
+    pub fn gain_db(self, freq: f64) -> f64 {
+        const F1: f64 = 20.598_997;
+        const F2: f64 = 107.652_65;
+        const F3: f64 = 737.862_23;
+        const F4: f64 = 12_194.217;
+
+        let f2 = freq * freq;
+        match self {
+            Weighting::A => {
+                let r = F4 * F4 * f2 * f2
+                    / ((f2 + F1 * F1) * ((f2 + F2 * F2) * (f2 + F3 * F3)).sqrt() * (f2 + F4 * F4));
+                linear_to_db(r) + 2.0
+            }
+            Weighting::C => {
+                let r = F4 * F4 * f2 / ((f2 + F1 * F1) * (f2 + F4 * F4));
+                linear_to_db(r) + 0.062
+            }
+            Weighting::Z => 0.0,
+        }
+    }
+
+    /// Apply the weighting to a magnitude curve in dB
+    pub fn apply(self, freq: &[f64], magnitude_db: &[f64]) -> Vec<f64> {
+        freq.iter()
+            .zip(magnitude_db)
+            .map(|(&f, &m)| m + self.gain_db(f))
+            .collect()
+    }
+
+    /// Display label
+    pub fn label(self) -> &'static str {
+        match self {
+            Weighting::A => "A",
+            Weighting::C => "C",
+            Weighting::Z => "Z",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_db_conversions() {
+        assert!((db_to_linear(6.0) - 1.995).abs() < 1e-3);
+        assert!((linear_to_db(db_to_linear(-12.5)) + 12.5).abs() < 1e-12);
+        assert!((db_to_power(3.0) - 1.995).abs() < 1e-3);
+        assert!((power_to_db(100.0) - 20.0).abs() < 1e-12);
+        assert_eq!(linear_to_db(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_smoothing() {
+        let freq: Vec<f64> = (0..31).map(|i| 20.0 * 2f64.powf(i as f64 / 3.0)).collect();
+        let flat = vec![3.0; freq.len()];
+        assert_eq!(smooth_octave(&freq, &flat, 3), flat);
+
+        // A single spike is spread over its neighbours at 1/1 octave
+        let mut spike = vec![0.0; freq.len()];
+        spike[15] = 9.0;
+        let smoothed = smooth_octave(&freq, &spike, 1);
+        assert!(smoothed[15] < 9.0 && smoothed[15] > 0.0);
+        assert!(smoothed[14] > 0.0 && smoothed[16] > 0.0);
+        assert_eq!(smoothed[0], 0.0);
+    }
+
+    #[test]
+    fn test_weighting_reference_values() {
+        // Nominal values from IEC 61672-1, rounded to 0.1 dB at nominal frequencies
+        for (freq, a, c) in [
+            (31.5, -39.4, -3.0),
+            (100.0, -19.1, -0.3),
+            (1000.0, 0.0, 0.0),
+            (4000.0, 1.0, -0.8),
+            (10000.0, -2.5, -4.4),
+        ] {
+            assert!((Weighting::A.gain_db(freq) - a).abs() < 0.2, "A at {freq}");
+            assert!((Weighting::C.gain_db(freq) - c).abs() < 0.2, "C at {freq}");
+        }
+        assert_eq!(Weighting::Z.gain_db(50.0), 0.0);
+
+        let weighted = Weighting::A.apply(&[1000.0, 100.0], &[80.0, 80.0]);
+        assert!((weighted[0] - 80.0).abs() < 0.01);
+        assert!((weighted[1] - 60.9).abs() < 0.1);
+    }
+}
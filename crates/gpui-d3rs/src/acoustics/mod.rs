@@ -0,0 +1,328 @@
+//! Room acoustics and frequency-domain utilities
+//!
+//! Decibel conversions, fractional-octave smoothing and A/C weighting of
+//! magnitude curves live in [`smooth_octave`], [`Weighting`] and friends.
+//!
+//! The room model computes the standing-wave modes of a rectangular room
+//! and flags the frequency regions where they cause trouble:
+//!
+//! - **Axial** modes bounce between two parallel surfaces (strongest)
+//! - **Tangential** modes involve four surfaces
+//! - **Oblique** modes involve all six surfaces (weakest)
+//!
+//! Modes are only meaningful below the Schroeder frequency, where the room
+//! behaves as a set of discrete resonances rather than a diffuse field.
+//!
+//! # Example
+//!
+//! ```
+//! use d3rs::acoustics::{ModeKind, Room};
+//!
+//! let room = Room::new(5.0, 4.0, 2.5);
+//! let modes = room.modes(120.0);
+//!
+//! // Lowest mode is the first axial mode along the length: c / 2L
+//! assert_eq!(modes[0].kind, ModeKind::Axial);
+//! assert!((modes[0].frequency - 34.3).abs() < 0.01);
+//!
+//! let problems = room.problem_regions(&modes, 5.0, 20.0);
+//! assert!(!problems.is_empty());
+//! ```
+
+mod frequency;
+#[cfg(feature = "gpui")]
+mod render;
+
+pub use frequency::{
+    Weighting, db_to_linear, db_to_power, linear_to_db, power_to_db, smooth_octave,
+};
+
+#[cfg(feature = "gpui")]
+pub use render::{RoomModeConfig, render_room_modes};
+
+/// Speed of sound in air at 20 °C, in m/s
+pub const SPEED_OF_SOUND: f64 = 343.0;
+
+/// Mode family, by how many room dimensions take part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModeKind {
+    /// One dimension (two parallel surfaces)
+    Axial,
+    /// Two dimensions (four surfaces)
+    Tangential,
+    /// Three dimensions (all six surfaces)
+    Oblique,
+}
+
+impl ModeKind {
+    /// Mode family for the index triple `(nx, ny, nz)`
+    fn from_indices(indices: (u32, u32, u32)) -> Self {
+        let (x, y, z) = indices;
+        match [x, y, z].iter().filter(|&&n| n > 0).count() {
+            1 => ModeKind::Axial,
+            2 => ModeKind::Tangential,
+            _ => ModeKind::Oblique,
+        }
+    }
+
+    /// Relative energy of the family (axial = 1.0)
+    ///
+    /// Tangential modes carry about half the energy of axial modes and
+    /// oblique modes about a quarter.
+    pub fn weight(self) -> f64 {
+        match self {
+            ModeKind::Axial => 1.0,
+            ModeKind::Tangential => 0.5,
+            ModeKind::Oblique => 0.25,
+        }
+    }
+
+    /// Display label
+    pub fn label(self) -> &'static str {
+        match self {
+            ModeKind::Axial => "Axial",
+            ModeKind::Tangential => "Tangential",
+            ModeKind::Oblique => "Oblique",
+        }
+    }
+}
+
+/// A single room mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoomMode {
+    /// Mode indices along length, width and height
+    pub indices: (u32, u32, u32),
+    /// Resonance frequency in Hz
+    pub frequency: f64,
+    /// Mode family
+    pub kind: ModeKind,
+}
+
+/// Kind of problem flagged by [`Room::problem_regions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemKind {
+    /// Several modes stack up close together (peak)
+    Cluster,
+    /// No axial mode over a wide span (dip / uneven bass)
+    Gap,
+}
+
+/// A frequency span with a modal problem
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProblemRegion {
+    /// Start frequency in Hz
+    pub start: f64,
+    /// End frequency in Hz
+    pub end: f64,
+    /// Problem kind
+    pub kind: ProblemKind,
+    /// Number of modes inside the span
+    pub mode_count: usize,
+}
+
+/// A rectangular room
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Room {
+    /// Length in metres
+    pub length: f64,
+    /// Width in metres
+    pub width: f64,
+    /// Height in metres
+    pub height: f64,
+    /// Speed of sound in m/s
+    pub speed_of_sound: f64,
+}
+
+impl Room {
+    /// Create a room from its dimensions in metres
+    pub fn new(length: f64, width: f64, height: f64) -> Self {
+        Self {
+            length,
+            width,
+            height,
+            speed_of_sound: SPEED_OF_SOUND,
+        }
+    }
+
+    /// Set the speed of sound in m/s
+    pub fn speed_of_sound(mut self, c: f64) -> Self {
+        self.speed_of_sound = c;
+        self
+    }
+
+    /// Room volume in m³
+    pub fn volume(&self) -> f64 {
+        self.length * self.width * self.height
+    }
+
+    /// Frequency of mode `(nx, ny, nz)` in Hz
+    pub fn mode_frequency(&self, nx: u32, ny: u32, nz: u32) -> f64 {
+        let term = |n: u32, d: f64| (n as f64 / d).powi(2);
+        self.speed_of_sound / 2.0
+            * (term(nx, self.length) + term(ny, self.width) + term(nz, self.height)).sqrt()
+    }
+
+    /// All modes up to `max_freq` Hz, sorted by frequency
+    pub fn modes(&self, max_freq: f64) -> Vec<RoomMode> {
+        let dims = [self.length, self.width, self.height];
+        if dims.iter().any(|d| !(d.is_finite() && *d > 0.0)) || max_freq <= 0.0 {
+            return Vec::new();
+        }
+        // Highest index along each dimension whose axial mode fits
+        let limit = |d: f64| (2.0 * d * max_freq / self.speed_of_sound).floor() as u32;
+        let (lx, ly, lz) = (limit(self.length), limit(self.width), limit(self.height));
+
+        let mut modes = Vec::new();
+        for nx in 0..=lx {
+            for ny in 0..=ly {
+                for nz in 0..=lz {
+                    if nx + ny + nz == 0 {
+                        continue;
+                    }
+                    let frequency = self.mode_frequency(nx, ny, nz);
+                    if frequency <= max_freq {
+                        let indices = (nx, ny, nz);
+                        modes.push(RoomMode {
+                            indices,
+                            frequency,
+                            kind: ModeKind::from_indices(indices),
+                        });
+                    }
+                }
+            }
+        }
+        modes.sort_by(|a, b| a.frequency.total_cmp(&b.frequency));
+        modes
+    }
+
+    /// Schroeder frequency in Hz for a reverberation time `rt60` in seconds
+    ///
+    /// Above this frequency modes overlap and the room no longer behaves
+    /// modally: `2000 * sqrt(RT60 / V)`.
+    pub fn schroeder_frequency(&self, rt60: f64) -> f64 {
+        2000.0 * (rt60 / self.volume()).sqrt()
+    }
+
+    /// Flag modal problems in `modes`:
+    ///
+    /// - [`ProblemKind::Cluster`]: axial or tangential modes closer than
+    ///   `cluster_hz` to their neighbour
+    /// - [`ProblemKind::Gap`]: consecutive axial modes more than `gap_hz`
+    ///   apart
+    ///
+    /// Oblique modes are too weak to matter and are ignored. Regions are
+    /// sorted by start frequency.
+    pub fn problem_regions(
+        &self,
+        modes: &[RoomMode],
+        cluster_hz: f64,
+        gap_hz: f64,
+    ) -> Vec<ProblemRegion> {
+        let mut regions = Vec::new();
+
+        // Clusters: runs of strong modes with small spacing
+        let strong: Vec<f64> = modes
+            .iter()
+            .filter(|m| m.kind != ModeKind::Oblique)
+            .map(|m| m.frequency)
+            .collect();
+        let mut i = 0;
+        while i < strong.len() {
+            let mut j = i;
+            while j + 1 < strong.len() && strong[j + 1] - strong[j] < cluster_hz {
+                j += 1;
+            }
+            if j > i {
+                regions.push(ProblemRegion {
+                    start: strong[i],
+                    end: strong[j],
+                    kind: ProblemKind::Cluster,
+                    mode_count: j - i + 1,
+                });
+            }
+            i = j + 1;
+        }
+
+        // Gaps between consecutive axial modes
+        let axial: Vec<f64> = modes
+            .iter()
+            .filter(|m| m.kind == ModeKind::Axial)
+            .map(|m| m.frequency)
+            .collect();
+        for pair in axial.windows(2) {
+            if pair[1] - pair[0] > gap_hz {
+                regions.push(ProblemRegion {
+                    start: pair[0],
+                    end: pair[1],
+                    kind: ProblemKind::Gap,
+                    mode_count: 0,
+                });
+            }
+        }
+
+        regions.sort_by(|a, b| a.start.total_cmp(&b.start));
+        regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axial_modes() {
+        let room = Room::new(5.0, 4.0, 2.5);
+        assert!((room.mode_frequency(1, 0, 0) - 34.3).abs() < 1e-9);
+        assert!((room.mode_frequency(0, 1, 0) - 42.875).abs() < 1e-9);
+        assert!((room.mode_frequency(0, 0, 1) - 68.6).abs() < 1e-9);
+
+        let modes = room.modes(70.0);
+        let mut axial: Vec<_> = modes
+            .iter()
+            .filter(|m| m.kind == ModeKind::Axial)
+            .map(|m| m.indices)
+            .collect();
+        // (2, 0, 0) and (0, 0, 1) coincide at 68.6 Hz
+        axial.sort();
+        assert_eq!(axial, vec![(0, 0, 1), (0, 1, 0), (1, 0, 0), (2, 0, 0)]);
+        assert!(modes.windows(2).all(|w| w[0].frequency <= w[1].frequency));
+    }
+
+    #[test]
+    fn test_mode_kinds() {
+        let modes = Room::new(5.0, 4.0, 2.5).modes(100.0);
+        let find = |i| modes.iter().find(|m| m.indices == i).unwrap().kind;
+        assert_eq!(find((1, 1, 0)), ModeKind::Tangential);
+        assert_eq!(find((1, 1, 1)), ModeKind::Oblique);
+        assert!(modes.iter().all(|m| m.frequency <= 100.0));
+        assert!(Room::new(0.0, 4.0, 2.5).modes(100.0).is_empty());
+    }
+
+    #[test]
+    fn test_problem_regions() {
+        // A cube stacks all three axial families on the same frequencies
+        let cube = Room::new(3.0, 3.0, 3.0);
+        let modes = cube.modes(60.0);
+        let regions = cube.problem_regions(&modes, 1.0, 100.0);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].kind, ProblemKind::Cluster);
+        assert_eq!(regions[0].mode_count, 3);
+
+        // A long narrow room leaves a wide gap above its first axial mode
+        let hall = Room::new(3.0, 2.0, 1.0);
+        let modes = hall.modes(200.0);
+        let gaps: Vec<_> = hall
+            .problem_regions(&modes, 0.5, 50.0)
+            .into_iter()
+            .filter(|r| r.kind == ProblemKind::Gap)
+            .collect();
+        assert!(!gaps.is_empty());
+        assert!(gaps.iter().all(|g| g.end - g.start > 50.0));
+    }
+
+    #[test]
+    fn test_schroeder_frequency() {
+        let room = Room::new(5.0, 4.0, 2.5);
+        assert!((room.schroeder_frequency(0.5) - 2000.0 * (0.01f64).sqrt()).abs() < 1e-9);
+    }
+}
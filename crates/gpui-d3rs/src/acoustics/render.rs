@@ -0,0 +1,186 @@
+//! Room mode stem plot rendering
+
+use super::{ModeKind, ProblemKind, ProblemRegion, RoomMode};
+use crate::color::D3Color;
+use crate::scale::Scale;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Configuration for room mode rendering
+#[derive(Clone)]
+pub struct RoomModeConfig {
+    /// Stem color for axial modes
+    pub axial_color: D3Color,
+    /// Stem color for tangential modes
+    pub tangential_color: D3Color,
+    /// Stem color for oblique modes
+    pub oblique_color: D3Color,
+    /// Fill color for mode clusters
+    pub cluster_color: D3Color,
+    /// Fill color for mode gaps
+    pub gap_color: D3Color,
+    /// Opacity of problem region fills (0.0 - 1.0)
+    pub region_opacity: f32,
+    /// Height of an axial stem as a fraction of the plot height; other
+    /// families are scaled by [`ModeKind::weight`]
+    pub stem_height: f32,
+    /// Stem width in pixels
+    pub stem_width: f32,
+    /// Whether to draw oblique modes
+    pub show_oblique: bool,
+}
+
+impl Default for RoomModeConfig {
+    fn default() -> Self {
+        Self {
+            axial_color: D3Color::from_hex(0xd62728),
+            tangential_color: D3Color::from_hex(0xff7f0e),
+            oblique_color: D3Color::from_hex(0x7f7f7f),
+            cluster_color: D3Color::from_hex(0xd62728),
+            gap_color: D3Color::from_hex(0x1f77b4),
+            region_opacity: 0.15,
+            stem_height: 0.4,
+            stem_width: 2.0,
+            show_oblique: true,
+        }
+    }
+}
+
+impl RoomModeConfig {
+    /// Create a new configuration with defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the stem color of a mode family
+    pub fn mode_color(mut self, kind: ModeKind, color: D3Color) -> Self {
+        match kind {
+            ModeKind::Axial => self.axial_color = color,
+            ModeKind::Tangential => self.tangential_color = color,
+            ModeKind::Oblique => self.oblique_color = color,
+        }
+        self
+    }
+
+    /// Set the fill color of a problem kind
+    pub fn region_color(mut self, kind: ProblemKind, color: D3Color) -> Self {
+        match kind {
+            ProblemKind::Cluster => self.cluster_color = color,
+            ProblemKind::Gap => self.gap_color = color,
+        }
+        self
+    }
+
+    /// Set the problem region opacity
+    pub fn region_opacity(mut self, opacity: f32) -> Self {
+        self.region_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the axial stem height as a fraction of the plot height
+    pub fn stem_height(mut self, height: f32) -> Self {
+        self.stem_height = height.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the stem width in pixels
+    pub fn stem_width(mut self, width: f32) -> Self {
+        self.stem_width = width;
+        self
+    }
+
+    /// Show or hide oblique modes
+    pub fn show_oblique(mut self, show: bool) -> Self {
+        self.show_oblique = show;
+        self
+    }
+
+    fn color_for(&self, kind: ModeKind) -> &D3Color {
+        match kind {
+            ModeKind::Axial => &self.axial_color,
+            ModeKind::Tangential => &self.tangential_color,
+            ModeKind::Oblique => &self.oblique_color,
+        }
+    }
+}
+
+/// Render room modes as stems rising from the bottom of the plot, with
+/// problem regions shaded behind them
+///
+/// Draw it in the same plot area as a measured response (e.g. with
+/// [`render_line`](crate::shape::render_line)) sharing the same `x_scale`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use d3rs::acoustics::{Room, RoomModeConfig, render_room_modes};
+/// use d3rs::scale::LogScale;
+///
+/// let room = Room::new(5.0, 4.0, 2.5);
+/// let modes = room.modes(300.0);
+/// let regions = room.problem_regions(&modes, 3.0, 20.0);
+/// let x_scale = LogScale::new().domain(20.0, 300.0).range(0.0, 600.0);
+/// // render_room_modes(&x_scale, &modes, &regions, &RoomModeConfig::new())
+/// ```
+pub fn render_room_modes<XS>(
+    x_scale: &XS,
+    modes: &[RoomMode],
+    regions: &[ProblemRegion],
+    config: &RoomModeConfig,
+) -> impl IntoElement
+where
+    XS: Scale<f64, f64>,
+{
+    let (x_min, x_max) = x_scale.range();
+    let x_range_span = x_max - x_min;
+    let (domain_min, domain_max) = x_scale.domain();
+    let (lo, hi) = (domain_min.min(domain_max), domain_min.max(domain_max));
+    let to_rel = |freq: f64| ((x_scale.scale(freq) - x_min) / x_range_span) as f32;
+
+    let region_elements = regions
+        .iter()
+        .filter(|r| r.end >= lo && r.start <= hi)
+        .map(|region| {
+            let start = to_rel(region.start.max(lo));
+            let end = to_rel(region.end.min(hi));
+            let color = match region.kind {
+                ProblemKind::Cluster => &config.cluster_color,
+                ProblemKind::Gap => &config.gap_color,
+            };
+            // Clusters can collapse to a single frequency; keep them visible
+            div()
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .left(relative(start.min(end)))
+                .w(relative((end - start).abs()))
+                .min_w(px(4.0))
+                .bg(color.to_rgba())
+                .opacity(config.region_opacity)
+        })
+        .collect::<Vec<_>>();
+
+    let stem_width = config.stem_width;
+    let stems = modes
+        .iter()
+        .filter(|m| m.frequency >= lo && m.frequency <= hi)
+        .filter(|m| config.show_oblique || m.kind != ModeKind::Oblique)
+        .map(|mode| {
+            let height = config.stem_height * mode.kind.weight() as f32;
+            div()
+                .absolute()
+                .bottom_0()
+                .left(relative(to_rel(mode.frequency)))
+                .ml(px(-stem_width / 2.0))
+                .w(px(stem_width))
+                .h(relative(height))
+                .bg(config.color_for(mode.kind).to_rgba())
+        })
+        .collect::<Vec<_>>();
+
+    div()
+        .absolute()
+        .inset_0()
+        .children(region_elements)
+        .children(stems)
+}
@@ -13,6 +13,11 @@ pub trait AxisTheme {
     /// Color for axis labels
     fn axis_label_color(&self) -> Rgba;
 
+    /// Color for grid lines (defaults to the axis line color)
+    fn grid_color(&self) -> Rgba {
+        self.axis_line_color()
+    }
+
     /// Background color (optional, for context)
     fn background_color(&self) -> Option<Rgba> {
         None
@@ -1,8 +1,10 @@
 pub mod chromatic;
 mod interpolate;
+pub mod oklab;
 pub mod rgb;
 pub mod scheme;
 
 pub use interpolate::{interpolate_colors, sequential_color};
+pub use oklab::{Oklab, Oklch};
 pub use rgb::D3Color;
 pub use scheme::ColorScheme;
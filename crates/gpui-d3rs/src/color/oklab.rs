@@ -0,0 +1,306 @@
+//! OKLab and OKLCH perceptual color spaces
+//!
+//! OKLab (Björn Ottosson, 2020) is a perceptually uniform color space:
+//! equal steps in lightness look like equal steps to the eye, unlike HSL.
+//! OKLCH is its polar form (lightness, chroma, hue), which is convenient
+//! for deriving tints and shades of a color without shifting its hue.
+
+use super::D3Color;
+
+/// Color in the OKLab space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    /// Perceptual lightness (0.0 - 1.0)
+    pub l: f32,
+    /// Green-red axis
+    pub a: f32,
+    /// Blue-yellow axis
+    pub b: f32,
+    /// Alpha component (0.0 - 1.0)
+    pub alpha: f32,
+}
+
+/// Color in the OKLCH space (polar OKLab)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    /// Perceptual lightness (0.0 - 1.0)
+    pub l: f32,
+    /// Chroma (0.0 for grays, about 0.37 at most for sRGB colors)
+    pub c: f32,
+    /// Hue in degrees (0-360)
+    pub h: f32,
+    /// Alpha component (0.0 - 1.0)
+    pub alpha: f32,
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl Oklab {
+    /// Convert to linear sRGB components, which may fall outside 0.0 - 1.0
+    fn to_linear_srgb(self) -> (f32, f32, f32) {
+        let l_ = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m_ = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s_ = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+
+        let l = l_ * l_ * l_;
+        let m = m_ * m_ * m_;
+        let s = s_ * s_ * s_;
+
+        (
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        )
+    }
+
+    /// Whether the color can be shown in sRGB without clipping
+    pub fn in_gamut(&self) -> bool {
+        const EPS: f32 = 1e-4;
+        let (r, g, b) = self.to_linear_srgb();
+        [r, g, b].iter().all(|c| (-EPS..=1.0 + EPS).contains(c))
+    }
+
+    /// Convert to polar OKLCH
+    pub fn to_oklch(&self) -> Oklch {
+        let c = (self.a * self.a + self.b * self.b).sqrt();
+        let h = if c < 1e-6 {
+            0.0
+        } else {
+            self.b.atan2(self.a).to_degrees().rem_euclid(360.0)
+        };
+        Oklch {
+            l: self.l,
+            c,
+            h,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Convert to an sRGB color, clipping out-of-gamut components
+    pub fn to_color(&self) -> D3Color {
+        let (r, g, b) = self.to_linear_srgb();
+        D3Color::from_rgba_f32(
+            linear_to_srgb(r.clamp(0.0, 1.0)),
+            linear_to_srgb(g.clamp(0.0, 1.0)),
+            linear_to_srgb(b.clamp(0.0, 1.0)),
+            self.alpha,
+        )
+    }
+}
+
+impl Oklch {
+    /// Create an opaque OKLCH color
+    pub fn new(l: f32, c: f32, h: f32) -> Self {
+        Self {
+            l,
+            c,
+            h,
+            alpha: 1.0,
+        }
+    }
+
+    /// Convert to cartesian OKLab
+    pub fn to_oklab(&self) -> Oklab {
+        let h = self.h.to_radians();
+        Oklab {
+            l: self.l,
+            a: self.c * h.cos(),
+            b: self.c * h.sin(),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Convert to an sRGB color
+    ///
+    /// Out-of-gamut colors keep their lightness and hue and lose chroma
+    /// until they fit, so the result never shifts hue the way clipping does.
+    pub fn to_color(&self) -> D3Color {
+        let l = self.l.clamp(0.0, 1.0);
+        let candidate = Oklch { l, ..*self };
+        if candidate.to_oklab().in_gamut() {
+            return candidate.to_oklab().to_color();
+        }
+
+        let (mut lo, mut hi) = (0.0_f32, self.c.max(0.0));
+        for _ in 0..20 {
+            let mid = (lo + hi) / 2.0;
+            if candidate.with_chroma(mid).to_oklab().in_gamut() {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        candidate.with_chroma(lo).to_oklab().to_color()
+    }
+
+    /// Same color with another lightness
+    pub fn with_lightness(self, l: f32) -> Self {
+        Self {
+            l: l.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Same color with another chroma
+    pub fn with_chroma(self, c: f32) -> Self {
+        Self {
+            c: c.max(0.0),
+            ..self
+        }
+    }
+}
+
+impl From<D3Color> for Oklab {
+    fn from(color: D3Color) -> Self {
+        let r = srgb_to_linear(color.r);
+        let g = srgb_to_linear(color.g);
+        let b = srgb_to_linear(color.b);
+
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        Oklab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha: color.a,
+        }
+    }
+}
+
+impl From<D3Color> for Oklch {
+    fn from(color: D3Color) -> Self {
+        Oklab::from(color).to_oklch()
+    }
+}
+
+impl From<Oklab> for D3Color {
+    fn from(lab: Oklab) -> Self {
+        lab.to_color()
+    }
+}
+
+impl From<Oklch> for D3Color {
+    fn from(lch: Oklch) -> Self {
+        lch.to_color()
+    }
+}
+
+impl D3Color {
+    /// Convert to OKLab
+    pub fn to_oklab(&self) -> Oklab {
+        Oklab::from(*self)
+    }
+
+    /// Convert to OKLCH
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use d3rs::color::D3Color;
+    ///
+    /// let white = D3Color::rgb(255, 255, 255).to_oklch();
+    /// assert!((white.l - 1.0).abs() < 1e-3);
+    /// assert!(white.c < 1e-3);
+    /// ```
+    pub fn to_oklch(&self) -> Oklch {
+        Oklch::from(*self)
+    }
+
+    /// Create an opaque color from OKLCH values, reducing chroma to fit sRGB
+    ///
+    /// - l: Lightness (0-1)
+    /// - c: Chroma (0-0.37)
+    /// - h: Hue in degrees (0-360)
+    pub fn from_oklch(l: f32, c: f32, h: f32) -> D3Color {
+        Oklch::new(l, c, h).to_color()
+    }
+
+    /// Change perceptual lightness by `amount` (-1.0 to 1.0), keeping hue
+    ///
+    /// Unlike [`lighten`](Self::lighten), equal amounts look like equal
+    /// steps regardless of the hue of the color.
+    pub fn adjust_lightness(&self, amount: f32) -> D3Color {
+        let lch = self.to_oklch();
+        lch.with_lightness(lch.l + amount).to_color()
+    }
+
+    /// Interpolate through OKLab, which avoids the gray midpoints of RGB
+    pub fn interpolate_oklab(&self, other: &D3Color, t: f32) -> D3Color {
+        let t = t.clamp(0.0, 1.0);
+        let a = self.to_oklab();
+        let b = other.to_oklab();
+        Oklab {
+            l: a.l + (b.l - a.l) * t,
+            a: a.a + (b.a - a.a) * t,
+            b: a.b + (b.b - a.b) * t,
+            alpha: a.alpha + (b.alpha - a.alpha) * t,
+        }
+        .to_color()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_reference_values() {
+        // Reference values from the OKLab specification
+        let red = D3Color::rgb(255, 0, 0).to_oklch();
+        assert_relative_eq!(red.l, 0.628, epsilon = 1e-3);
+        assert_relative_eq!(red.c, 0.2577, epsilon = 1e-3);
+        assert_relative_eq!(red.h, 29.23, epsilon = 0.1);
+
+        let black = D3Color::rgb(0, 0, 0).to_oklab();
+        assert_relative_eq!(black.l, 0.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for hex in [0x007acc, 0xff8040, 0x22c55e, 0x808080, 0xffffff] {
+            let color = D3Color::from_hex(hex);
+            let back = color.to_oklch().to_color();
+            assert_relative_eq!(back.r, color.r, epsilon = 1e-3);
+            assert_relative_eq!(back.g, color.g, epsilon = 1e-3);
+            assert_relative_eq!(back.b, color.b, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_gamut_mapping_keeps_hue() {
+        // Far more chroma than sRGB can show
+        let lch = Oklch::new(0.7, 0.5, 145.0);
+        assert!(!lch.to_oklab().in_gamut());
+        let mapped = lch.to_color().to_oklch();
+        assert_relative_eq!(mapped.l, 0.7, epsilon = 1e-2);
+        assert_relative_eq!(mapped.h, 145.0, epsilon = 1.0);
+        assert!(mapped.c < 0.5);
+    }
+
+    #[test]
+    fn test_adjust_lightness() {
+        let color = D3Color::from_hex(0x007acc);
+        let base = color.to_oklch();
+        let lighter = color.adjust_lightness(0.1).to_oklch();
+        assert_relative_eq!(lighter.l, base.l + 0.1, epsilon = 1e-2);
+        assert_relative_eq!(lighter.h, base.h, epsilon = 2.0);
+        assert!(color.adjust_lightness(-0.1).to_oklch().l < base.l);
+    }
+}
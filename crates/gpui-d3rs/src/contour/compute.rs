@@ -0,0 +1,319 @@
+//! Parallel and background computation for contours and density estimation
+//!
+//! With the `parallel` feature, contour tracing, band generation and kernel
+//! density estimation spread their work over the rayon thread pool. The
+//! `spawn_*` functions additionally move the whole computation off the
+//! calling thread and return a [`ComputeHandle`], a future that resolves to
+//! the result. It works with any executor, so a GPUI view can await it in
+//! `cx.spawn` and update its chart when the result is ready.
+//!
+//! # Example
+//!
+//! ```
+//! use d3rs::contour::{ContourGenerator, spawn_contour_bands};
+//!
+//! let values = vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+//! let handle = spawn_contour_bands(
+//!     ContourGenerator::new(3, 3),
+//!     values,
+//!     vec![0.0, 0.5, 1.0],
+//! );
+//! let bands = handle.wait();
+//! assert_eq!(bands.len(), 2);
+//! ```
+
+use super::density::DensityEstimator;
+use super::marching_squares::{ContourBand, ContourGenerator};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Map `f` over `items`, in parallel with the `parallel` feature.
+///
+/// The output keeps the order of `items`.
+pub(crate) fn par_map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items.par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(f).collect()
+    }
+}
+
+/// Number of worker threads used by [`par_map`].
+pub(crate) fn parallelism() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::current_num_threads()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+struct ComputeState<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+}
+
+struct ComputeShared<T> {
+    state: Mutex<ComputeState<T>>,
+    ready: Condvar,
+    /// Progress fraction stored as `f32` bits
+    progress: AtomicU32,
+}
+
+impl<T> ComputeShared<T> {
+    /// Raise the progress to `progress`, ignoring values below the current one.
+    ///
+    /// Parallel workers report out of order, so a plain store could move the
+    /// progress backwards. Non-negative floats order like their bits, which
+    /// lets `fetch_max` keep the largest value.
+    fn set_progress(&self, progress: f32) {
+        // Also skips NaN and -0.0, whose bits compare above 1.0
+        if progress > 0.0 {
+            self.progress
+                .fetch_max(progress.min(1.0).to_bits(), Ordering::Relaxed);
+        }
+    }
+
+    fn complete(&self, result: T) {
+        self.set_progress(1.0);
+        let waker = {
+            let mut state = self.lock();
+            state.result = Some(result);
+            state.waker.take()
+        };
+        self.ready.notify_all();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ComputeState<T>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Handle to a computation running in the background.
+///
+/// Await it to get the result, poll it with [`ComputeHandle::try_take`], or
+/// block with [`ComputeHandle::wait`]. [`ComputeHandle::progress`] can be
+/// read at any time, e.g. to draw a progress bar while a large grid is being
+/// processed. Dropping the handle lets the computation finish and discards
+/// its result.
+pub struct ComputeHandle<T> {
+    shared: Arc<ComputeShared<T>>,
+}
+
+impl<T> std::fmt::Debug for ComputeHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComputeHandle")
+            .field("progress", &self.progress())
+            .field("ready", &self.is_ready())
+            .finish()
+    }
+}
+
+impl<T> ComputeHandle<T> {
+    /// Fraction of the work done, in 0..=1.
+    pub fn progress(&self) -> f32 {
+        f32::from_bits(self.shared.progress.load(Ordering::Relaxed))
+    }
+
+    /// Whether the result is available.
+    pub fn is_ready(&self) -> bool {
+        self.shared.lock().result.is_some()
+    }
+
+    /// Take the result if it is available.
+    pub fn try_take(&mut self) -> Option<T> {
+        self.shared.lock().result.take()
+    }
+
+    /// Block the current thread until the result is available.
+    pub fn wait(self) -> T {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(result) = state.result.take() {
+                return result;
+            }
+            state = self
+                .shared
+                .ready
+                .wait(state)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+}
+
+impl<T> Future for ComputeHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.shared.lock();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Run `work` off the calling thread.
+///
+/// `work` receives a progress callback taking the fraction done (0..=1).
+/// Runs on the rayon pool with the `parallel` feature, on a new thread
+/// otherwise.
+pub fn spawn_compute<T, F>(work: F) -> ComputeHandle<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&(dyn Fn(f32) + Sync)) -> T + Send + 'static,
+{
+    let shared = Arc::new(ComputeShared {
+        state: Mutex::new(ComputeState {
+            result: None,
+            waker: None,
+        }),
+        ready: Condvar::new(),
+        progress: AtomicU32::new(0.0f32.to_bits()),
+    });
+
+    let worker = Arc::clone(&shared);
+    let job = move || {
+        let result = work(&|progress| worker.set_progress(progress));
+        worker.complete(result);
+    };
+
+    #[cfg(feature = "parallel")]
+    rayon::spawn(job);
+    #[cfg(not(feature = "parallel"))]
+    std::thread::spawn(job);
+
+    ComputeHandle { shared }
+}
+
+/// Generate filled contour bands in the background.
+///
+/// See [`ContourGenerator::contour_bands`].
+pub fn spawn_contour_bands(
+    generator: ContourGenerator,
+    values: Vec<f64>,
+    thresholds: Vec<f64>,
+) -> ComputeHandle<Vec<ContourBand>> {
+    spawn_compute(move |progress| {
+        generator.contour_bands_with_progress(&values, &thresholds, progress)
+    })
+}
+
+/// Estimate a density grid in the background.
+///
+/// See [`DensityEstimator::estimate`].
+pub fn spawn_density(
+    estimator: DensityEstimator,
+    points: Vec<(f64, f64)>,
+) -> ComputeHandle<Vec<f64>> {
+    spawn_compute(move |progress| estimator.estimate_with_progress(&points, progress))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::Wake;
+
+    struct CountingWaker(std::sync::atomic::AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn peak_grid(size: usize) -> Vec<f64> {
+        let center = (size - 1) as f64 / 2.0;
+        (0..size * size)
+            .map(|idx| {
+                let (x, y) = ((idx % size) as f64, (idx / size) as f64);
+                let d2 = (x - center).powi(2) + (y - center).powi(2);
+                (-d2 / (size as f64)).exp()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_par_map_keeps_order() {
+        let items: Vec<usize> = (0..1000).collect();
+        let doubled = par_map(&items, |&i| i * 2);
+        assert!(doubled.iter().enumerate().all(|(i, &v)| v == i * 2));
+    }
+
+    #[test]
+    fn test_spawn_contour_bands_matches_sync() {
+        let values = peak_grid(60);
+        let thresholds = vec![0.0, 0.25, 0.5, 0.75, 1.0];
+        let generator = ContourGenerator::new(60, 60);
+
+        let expected = generator.contour_bands(&values, &thresholds);
+        let bands = spawn_contour_bands(generator, values, thresholds.clone()).wait();
+
+        assert_eq!(bands.len(), expected.len());
+        for (band, expected) in bands.iter().zip(&expected) {
+            assert_eq!(band.lower, expected.lower);
+            assert_eq!(band.polygons.len(), expected.polygons.len());
+        }
+    }
+
+    #[test]
+    fn test_progress_never_goes_backwards() {
+        let (reported, check) = std::sync::mpsc::channel();
+        let (resume, paused) = std::sync::mpsc::channel::<()>();
+        let handle = spawn_compute(move |progress| {
+            progress(0.5);
+            progress(0.25);
+            progress(f32::NAN);
+            progress(-0.0);
+            reported.send(()).unwrap();
+            paused.recv().unwrap();
+        });
+
+        check.recv().unwrap();
+        assert_eq!(handle.progress(), 0.5);
+        resume.send(()).unwrap();
+        handle.wait();
+    }
+
+    #[test]
+    fn test_handle_future_wakes_and_resolves() {
+        let points: Vec<(f64, f64)> = (0..2000).map(|i| (i as f64 / 2000.0, 0.5)).collect();
+        let mut handle = spawn_density(DensityEstimator::new().size(20, 20), points);
+
+        let counter = Arc::new(CountingWaker(std::sync::atomic::AtomicUsize::new(0)));
+        let waker = Waker::from(Arc::clone(&counter));
+        let mut cx = Context::from_waker(&waker);
+
+        let grid = loop {
+            match Pin::new(&mut handle).poll(&mut cx) {
+                Poll::Ready(grid) => break grid,
+                Poll::Pending => std::thread::yield_now(),
+            }
+        };
+        assert_eq!(grid.len(), 400);
+        assert_eq!(handle.progress(), 1.0);
+        assert!(handle.try_take().is_none());
+    }
+}
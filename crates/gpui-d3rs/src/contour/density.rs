@@ -1,8 +1,13 @@
-//! Kernel density estimation for 2D point clouds
+//! Kernel density estimation for 1D samples and 2D point clouds
 //!
 //! Provides density estimation using kernel functions.
 
+use super::compute::{par_map, parallelism};
 use std::f64::consts::PI;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Smallest chunk of points accumulated into its own partial grid
+const MIN_CHUNK_POINTS: usize = 1024;
 
 /// Gaussian kernel function.
 ///
@@ -29,6 +34,84 @@ pub fn epanechnikov_kernel(x: f64, bandwidth: f64) -> f64 {
     }
 }
 
+/// Uniform (box) kernel function.
+pub fn uniform_kernel(x: f64, bandwidth: f64) -> f64 {
+    if (x / bandwidth).abs() <= 1.0 {
+        0.5 / bandwidth
+    } else {
+        0.0
+    }
+}
+
+/// Kernel density of 1D samples evaluated at each threshold.
+///
+/// Returns `(threshold, density)` pairs; the density integrates to 1.
+///
+/// # Example
+///
+/// ```
+/// use d3rs::contour::{KernelType, kernel_density_1d};
+///
+/// let data = [1.0, 2.0, 2.5, 3.0];
+/// let density = kernel_density_1d(&data, &[0.0, 2.0, 5.0], KernelType::Gaussian, 0.5);
+/// assert!(density[1].1 > density[0].1);
+/// assert!(density[1].1 > density[2].1);
+/// ```
+pub fn kernel_density_1d(
+    data: &[f64],
+    thresholds: &[f64],
+    kernel: KernelType,
+    bandwidth: f64,
+) -> Vec<(f64, f64)> {
+    thresholds
+        .iter()
+        .map(|&t| {
+            let density = if data.is_empty() {
+                0.0
+            } else {
+                data.iter()
+                    .map(|&d| kernel.evaluate(t - d, bandwidth))
+                    .sum::<f64>()
+                    / data.len() as f64
+            };
+            (t, density)
+        })
+        .collect()
+}
+
+/// Bandwidth for 1D samples by Silverman's rule of thumb.
+///
+/// Uses the smaller of the standard deviation and IQR / 1.34, so that
+/// multimodal data is not oversmoothed. Returns 1.0 when the samples have
+/// no spread.
+pub fn silverman_bandwidth(data: &[f64]) -> f64 {
+    let n = data.len();
+    if n < 2 {
+        return 1.0;
+    }
+    let mean = data.iter().sum::<f64>() / n as f64;
+    let std_dev = (data.iter().map(|&d| (d - mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt();
+
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let quantile = |p: f64| {
+        let index = p * (n - 1) as f64;
+        let (lower, upper) = (index.floor() as usize, index.ceil() as usize);
+        sorted[lower] + (sorted[upper] - sorted[lower]) * (index - lower as f64)
+    };
+    let iqr = quantile(0.75) - quantile(0.25);
+
+    let spread = match iqr / 1.34 {
+        scaled_iqr if scaled_iqr > 0.0 => std_dev.min(scaled_iqr),
+        _ => std_dev,
+    };
+    if spread > 0.0 {
+        0.9 * spread * (n as f64).powf(-0.2)
+    } else {
+        1.0
+    }
+}
+
 /// 2D density estimator using kernel density estimation.
 ///
 /// # Example
@@ -68,12 +151,26 @@ pub struct DensityEstimator {
 }
 
 /// Kernel type for density estimation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum KernelType {
     /// Gaussian kernel
+    #[default]
     Gaussian,
     /// Epanechnikov kernel
     Epanechnikov,
+    /// Uniform (box) kernel
+    Uniform,
+}
+
+impl KernelType {
+    /// Evaluate the kernel at a distance.
+    pub fn evaluate(self, x: f64, bandwidth: f64) -> f64 {
+        match self {
+            KernelType::Gaussian => gaussian_kernel(x, bandwidth),
+            KernelType::Epanechnikov => epanechnikov_kernel(x, bandwidth),
+            KernelType::Uniform => uniform_kernel(x, bandwidth),
+        }
+    }
 }
 
 impl Default for DensityEstimator {
@@ -132,118 +229,140 @@ impl DensityEstimator {
 
     /// Estimate density from a list of (x, y) points.
     pub fn estimate(&self, points: &[(f64, f64)]) -> Vec<f64> {
-        let mut grid = vec![0.0; self.width * self.height];
-
-        if points.is_empty() {
-            return grid;
-        }
-
-        let dx = (self.x1 - self.x0) / (self.width - 1) as f64;
-        let dy = (self.y1 - self.y0) / (self.height - 1) as f64;
-
-        // For each point, add its contribution to nearby grid cells
-        for &(px, py) in points {
-            // Convert to grid coordinates
-            let gx = ((px - self.x0) / dx) as isize;
-            let gy = ((py - self.y0) / dy) as isize;
+        self.estimate_with_progress(points, |_| {})
+    }
 
-            // Determine the radius of influence (3 sigma for Gaussian)
-            let radius = (3.0 * self.bandwidth / dx.min(dy)).ceil() as isize;
+    /// Estimate density from (x, y) points, reporting progress.
+    ///
+    /// `progress` receives the fraction of points processed (0..=1); with the
+    /// `parallel` feature it may be called from worker threads. Points are
+    /// split into chunks accumulated in parallel with the `parallel` feature.
+    pub fn estimate_with_progress(
+        &self,
+        points: &[(f64, f64)],
+        progress: impl Fn(f32) + Sync,
+    ) -> Vec<f64> {
+        let mut grid = self.accumulate(points, |&(x, y)| (x, y, 1.0), &progress);
 
-            for j in (-radius)..=radius {
-                let grid_y = gy + j;
-                if grid_y < 0 || grid_y >= self.height as isize {
-                    continue;
-                }
+        // Normalize by the number of points
+        if !points.is_empty() {
+            let n = points.len() as f64;
+            for val in &mut grid {
+                *val /= n;
+            }
+        }
 
-                let cell_y = self.y0 + (grid_y as f64) * dy;
-                let ky = self.kernel_value((py - cell_y) / dy);
+        grid
+    }
 
-                for i in (-radius)..=radius {
-                    let grid_x = gx + i;
-                    if grid_x < 0 || grid_x >= self.width as isize {
-                        continue;
-                    }
+    /// Estimate density with weighted points.
+    pub fn estimate_weighted(&self, points: &[(f64, f64, f64)]) -> Vec<f64> {
+        self.estimate_weighted_with_progress(points, |_| {})
+    }
 
-                    let cell_x = self.x0 + (grid_x as f64) * dx;
-                    let kx = self.kernel_value((px - cell_x) / dx);
+    /// Estimate density with weighted points, reporting progress.
+    ///
+    /// See [`DensityEstimator::estimate_with_progress`].
+    pub fn estimate_weighted_with_progress(
+        &self,
+        points: &[(f64, f64, f64)],
+        progress: impl Fn(f32) + Sync,
+    ) -> Vec<f64> {
+        let mut grid = self.accumulate(points, |&point| point, &progress);
 
-                    let idx = (grid_y as usize) * self.width + (grid_x as usize);
-                    grid[idx] += kx * ky;
-                }
+        // Normalize by total weight
+        let total_weight: f64 = points.iter().map(|&(_, _, weight)| weight).sum();
+        if total_weight > 0.0 {
+            for val in &mut grid {
+                *val /= total_weight;
             }
         }
 
-        // Normalize by the number of points
-        let n = points.len() as f64;
-        for val in &mut grid {
-            *val /= n;
-        }
-
         grid
     }
 
-    /// Estimate density with weighted points.
-    pub fn estimate_weighted(&self, points: &[(f64, f64, f64)]) -> Vec<f64> {
+    /// Sum the kernel contributions of all points into a new grid.
+    ///
+    /// Each chunk of points is splatted into its own partial grid, then the
+    /// partial grids are added together.
+    fn accumulate<P: Sync>(
+        &self,
+        points: &[P],
+        point: impl Fn(&P) -> (f64, f64, f64) + Sync,
+        progress: &(impl Fn(f32) + Sync),
+    ) -> Vec<f64> {
         let mut grid = vec![0.0; self.width * self.height];
 
         if points.is_empty() {
             return grid;
         }
 
+        // A few chunks per worker so progress advances smoothly
+        let chunk_len = points
+            .len()
+            .div_ceil(parallelism() * 4)
+            .max(MIN_CHUNK_POINTS);
+        let chunks: Vec<&[P]> = points.chunks(chunk_len).collect();
+        let done = AtomicUsize::new(0);
+        let partials = par_map(&chunks, |chunk| {
+            let mut partial = vec![0.0; self.width * self.height];
+            for p in *chunk {
+                let (px, py, weight) = point(p);
+                self.splat(&mut partial, px, py, weight);
+            }
+            let finished = done.fetch_add(chunk.len(), Ordering::Relaxed) + chunk.len();
+            progress(finished as f32 / points.len() as f32);
+            partial
+        });
+
+        for partial in partials {
+            for (cell, value) in grid.iter_mut().zip(partial) {
+                *cell += value;
+            }
+        }
+
+        grid
+    }
+
+    /// Add the weighted kernel of one point to nearby grid cells.
+    fn splat(&self, grid: &mut [f64], px: f64, py: f64, weight: f64) {
         let dx = (self.x1 - self.x0) / (self.width - 1) as f64;
         let dy = (self.y1 - self.y0) / (self.height - 1) as f64;
 
-        let mut total_weight = 0.0;
+        // Convert to grid coordinates
+        let gx = ((px - self.x0) / dx) as isize;
+        let gy = ((py - self.y0) / dy) as isize;
 
-        for &(px, py, weight) in points {
-            total_weight += weight;
+        // Determine the radius of influence (3 sigma for Gaussian)
+        let radius = (3.0 * self.bandwidth / dx.min(dy)).ceil() as isize;
 
-            let gx = ((px - self.x0) / dx) as isize;
-            let gy = ((py - self.y0) / dy) as isize;
+        for j in (-radius)..=radius {
+            let grid_y = gy + j;
+            if grid_y < 0 || grid_y >= self.height as isize {
+                continue;
+            }
 
-            let radius = (3.0 * self.bandwidth / dx.min(dy)).ceil() as isize;
+            let cell_y = self.y0 + (grid_y as f64) * dy;
+            let ky = self.kernel_value((py - cell_y) / dy);
 
-            for j in (-radius)..=radius {
-                let grid_y = gy + j;
-                if grid_y < 0 || grid_y >= self.height as isize {
+            for i in (-radius)..=radius {
+                let grid_x = gx + i;
+                if grid_x < 0 || grid_x >= self.width as isize {
                     continue;
                 }
 
-                let cell_y = self.y0 + (grid_y as f64) * dy;
-                let ky = self.kernel_value((py - cell_y) / dy);
-
-                for i in (-radius)..=radius {
-                    let grid_x = gx + i;
-                    if grid_x < 0 || grid_x >= self.width as isize {
-                        continue;
-                    }
-
-                    let cell_x = self.x0 + (grid_x as f64) * dx;
-                    let kx = self.kernel_value((px - cell_x) / dx);
+                let cell_x = self.x0 + (grid_x as f64) * dx;
+                let kx = self.kernel_value((px - cell_x) / dx);
 
-                    let idx = (grid_y as usize) * self.width + (grid_x as usize);
-                    grid[idx] += weight * kx * ky;
-                }
-            }
-        }
-
-        // Normalize by total weight
-        if total_weight > 0.0 {
-            for val in &mut grid {
-                *val /= total_weight;
+                let idx = (grid_y as usize) * self.width + (grid_x as usize);
+                grid[idx] += weight * kx * ky;
             }
         }
-
-        grid
     }
 
     /// Evaluate the kernel at a distance.
     fn kernel_value(&self, x: f64) -> f64 {
-        match self.kernel {
-            KernelType::Gaussian => gaussian_kernel(x, self.bandwidth),
-            KernelType::Epanechnikov => epanechnikov_kernel(x, self.bandwidth),
-        }
+        self.kernel.evaluate(x, self.bandwidth)
     }
 }
 
@@ -317,6 +436,33 @@ mod tests {
         assert_eq!(epanechnikov_kernel(1.5, 1.0), 0.0);
     }
 
+    #[test]
+    fn test_kernel_density_1d_integrates_to_one() {
+        let data = [-1.0, 0.0, 0.5, 2.0];
+        let step = 0.01;
+        let thresholds: Vec<f64> = (0..=1000).map(|i| -5.0 + i as f64 * step).collect();
+        for kernel in [
+            KernelType::Gaussian,
+            KernelType::Epanechnikov,
+            KernelType::Uniform,
+        ] {
+            let density = kernel_density_1d(&data, &thresholds, kernel, 0.5);
+            let area: f64 = density.iter().map(|&(_, d)| d * step).sum();
+            assert!((area - 1.0).abs() < 0.01, "{kernel:?}: {area}");
+        }
+        assert_eq!(uniform_kernel(1.5, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_silverman_bandwidth() {
+        let data: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+        let bandwidth = silverman_bandwidth(&data);
+        assert!(bandwidth > 0.5 && bandwidth < 2.0, "{bandwidth}");
+        // No spread
+        assert_eq!(silverman_bandwidth(&[3.0, 3.0, 3.0]), 1.0);
+        assert_eq!(silverman_bandwidth(&[3.0]), 1.0);
+    }
+
     #[test]
     fn test_density_estimator() {
         let points = vec![(0.5, 0.5), (0.6, 0.4), (0.4, 0.6)];
@@ -347,6 +493,34 @@ mod tests {
         assert_eq!(grid.len(), width * height);
     }
 
+    #[test]
+    fn test_density_progress_and_chunking() {
+        // Enough points to be split into several chunks
+        let points: Vec<(f64, f64)> = (0..5000)
+            .map(|i| {
+                let t = i as f64 / 5000.0;
+                (t, (t * 7.0).sin() * 0.5 + 0.5)
+            })
+            .collect();
+        let estimator = DensityEstimator::new().bandwidth(0.05).size(40, 30);
+
+        let last = std::sync::Mutex::new(0.0f32);
+        let grid = estimator.estimate_with_progress(&points, |p| {
+            let mut last = last.lock().unwrap();
+            *last = last.max(p);
+        });
+        assert_eq!(*last.lock().unwrap(), 1.0);
+
+        // Same result as splatting every point sequentially
+        let mut expected = vec![0.0; 40 * 30];
+        for &(x, y) in &points {
+            estimator.splat(&mut expected, x, y, 1.0);
+        }
+        for (a, b) in grid.iter().zip(&expected) {
+            assert!((a - b / 5000.0).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn test_weighted_density() {
         let points = vec![(0.5, 0.5, 10.0), (0.0, 0.0, 1.0)];
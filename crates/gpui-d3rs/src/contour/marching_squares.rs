@@ -3,7 +3,9 @@
 //! Implements the marching squares algorithm for generating contour lines
 //! from a 2D scalar field.
 
+use super::compute::par_map;
 use crate::shape::path::Point;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// A contour ring (polygon) representing a closed contour line.
 #[derive(Debug, Clone, Default)]
@@ -220,11 +222,10 @@ impl ContourGenerator {
     }
 
     /// Generate contours at multiple threshold values.
+    ///
+    /// Thresholds are traced in parallel with the `parallel` feature.
     pub fn contours(&self, values: &[f64], thresholds: &[f64]) -> Vec<Contour> {
-        thresholds
-            .iter()
-            .map(|&t| self.contour(values, t))
-            .collect()
+        par_map(thresholds, |&t| self.contour(values, t))
     }
 
     /// Compute the marching squares case for a cell.
@@ -622,22 +623,34 @@ impl ContourGenerator {
     /// # Returns
     /// A vector of ContourBand, one for each pair of consecutive thresholds.
     pub fn contour_bands(&self, values: &[f64], thresholds: &[f64]) -> Vec<ContourBand> {
+        self.contour_bands_with_progress(values, thresholds, |_| {})
+    }
+
+    /// Generate filled contour bands, reporting progress.
+    ///
+    /// `progress` receives the fraction of bands done (0..=1) after each
+    /// band; with the `parallel` feature it may be called from worker threads
+    /// and out of order. Bands, and the rows within each band, are computed
+    /// in parallel with the `parallel` feature.
+    pub fn contour_bands_with_progress(
+        &self,
+        values: &[f64],
+        thresholds: &[f64],
+        progress: impl Fn(f32) + Sync,
+    ) -> Vec<ContourBand> {
         if thresholds.len() < 2 || values.len() < (self.width * self.height) {
             return Vec::new();
         }
 
-        let mut bands = Vec::with_capacity(thresholds.len() - 1);
-
-        // For each pair of consecutive thresholds, create a band
-        for i in 0..thresholds.len() - 1 {
-            let lower = thresholds[i];
-            let upper = thresholds[i + 1];
-
+        // One band per pair of consecutive thresholds
+        let pairs: Vec<(f64, f64)> = thresholds.windows(2).map(|w| (w[0], w[1])).collect();
+        let done = AtomicUsize::new(0);
+        par_map(&pairs, |&(lower, upper)| {
             let band = self.generate_band(values, lower, upper);
-            bands.push(band);
-        }
-
-        bands
+            let finished = done.fetch_add(1, Ordering::Relaxed) + 1;
+            progress(finished as f32 / pairs.len() as f32);
+            band
+        })
     }
 
     /// Generate a single contour band between two threshold values.
@@ -645,23 +658,19 @@ impl ContourGenerator {
         let mut band = ContourBand::new(lower, upper);
 
         // For each cell, determine which band case it belongs to
-        // and generate the appropriate polygon fragments
-        let mut cell_polygons: Vec<Vec<Point>> = Vec::new();
-
-        for j in 0..self.height - 1 {
-            for i in 0..self.width - 1 {
-                if let Some(poly) = self.cell_band_polygon(values, i, j, lower, upper) {
-                    cell_polygons.push(poly);
-                }
-            }
-        }
+        // and generate the appropriate polygon fragments, one row per task
+        let rows: Vec<usize> = (0..self.height - 1).collect();
+        let cell_polygons = par_map(&rows, |&j| {
+            (0..self.width - 1)
+                .filter_map(|i| self.cell_band_polygon(values, i, j, lower, upper))
+                .collect::<Vec<_>>()
+        });
 
         // Merge adjacent cell polygons into contiguous bands
         // For simplicity, we'll just add each cell polygon as a separate ring
         // A more sophisticated implementation would merge connected polygons
-        for poly in cell_polygons {
-            if poly.len() >= 3 {
-                let mut ring = poly.clone();
+        for mut ring in cell_polygons.into_iter().flatten() {
+            if ring.len() >= 3 {
                 // Close the ring if not already closed
                 if !points_equal(&ring[0], &ring[ring.len() - 1]) {
                     ring.push(ring[0]);
@@ -688,6 +697,11 @@ impl ContourGenerator {
         let v01 = values[(j + 1) * self.width + i];
         let v11 = values[(j + 1) * self.width + i + 1];
 
+        // Cells touching missing data have no band
+        if [v00, v10, v01, v11].iter().any(|v| v.is_nan()) {
+            return None;
+        }
+
         // Classify each corner: 0 = below lower, 1 = in band, 2 = above upper
         let c00 = Self::classify_value(v00, lower, upper);
         let c10 = Self::classify_value(v10, lower, upper);
@@ -943,4 +957,16 @@ mod tests {
 
         assert!(ring.is_closed());
     }
+
+    #[test]
+    fn test_contour_bands_skip_missing_cells() {
+        // 3x3 grid of cells with a missing corner in the middle of the top row
+        let mut values = vec![0.5; 16];
+        values[1] = f64::NAN;
+
+        let generator = ContourGenerator::new(4, 4);
+        let bands = generator.contour_bands(&values, &[0.0, 1.0]);
+        // The two cells touching the missing corner are left out
+        assert_eq!(bands[0].polygons.len(), 7);
+    }
 }
@@ -6,8 +6,11 @@
 //! # Features
 //!
 //! - **Marching Squares**: Generate contour polygons from a 2D grid
-//! - **Density Estimation**: Kernel density estimation for 2D point clouds
+//! - **Density Estimation**: Kernel density estimation for 1D samples and 2D
+//!   point clouds
 //! - **Threshold Generation**: Automatic threshold calculation
+//! - **Parallel & Background Computation**: rayon-parallel bands and density
+//!   (`parallel` feature) and awaitable background jobs with progress
 //!
 //! # Example
 //!
@@ -25,11 +28,16 @@
 //! let result = contour_gen.contour(&values, 0.5);
 //! ```
 
+mod compute;
 mod density;
 mod marching_squares;
 mod thresholds;
 
-pub use density::{DensityEstimator, density_2d, gaussian_kernel};
+pub use compute::{ComputeHandle, spawn_compute, spawn_contour_bands, spawn_density};
+pub use density::{
+    DensityEstimator, KernelType, density_2d, epanechnikov_kernel, gaussian_kernel,
+    kernel_density_1d, silverman_bandwidth, uniform_kernel,
+};
 pub use marching_squares::{
     Contour, ContourBand, ContourGenerator, ContourRing, contour, contours,
 };
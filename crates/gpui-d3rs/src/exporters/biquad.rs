@@ -0,0 +1,126 @@
+//! Biquad coefficients for PEQ filters
+//!
+//! Coefficients follow the RBJ Audio EQ Cookbook and are normalized so that
+//! `a0 == 1`.
+
+use super::{PeqFilter, PeqFilterType};
+use crate::acoustics::power_to_db;
+use std::f64::consts::PI;
+
+/// Normalized biquad coefficients (`a0 == 1`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Biquad {
+    /// Feed-forward coefficient b0
+    pub b0: f64,
+    /// Feed-forward coefficient b1
+    pub b1: f64,
+    /// Feed-forward coefficient b2
+    pub b2: f64,
+    /// Feedback coefficient a1
+    pub a1: f64,
+    /// Feedback coefficient a2
+    pub a2: f64,
+}
+
+impl Biquad {
+    /// Coefficients of `filter` at `sample_rate` Hz.
+    pub fn from_filter(filter: &PeqFilter, sample_rate: f64) -> Self {
+        let w0 = 2.0 * PI * filter.freq / sample_rate;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2.0 * filter.q);
+        let a = 10f64.powf(filter.gain_db / 40.0);
+        let sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+
+        let (b0, b1, b2, a0, a1, a2) = match filter.kind {
+            PeqFilterType::Peak => (
+                1.0 + alpha * a,
+                -2.0 * cos,
+                1.0 - alpha * a,
+                1.0 + alpha / a,
+                -2.0 * cos,
+                1.0 - alpha / a,
+            ),
+            PeqFilterType::LowShelf => (
+                a * ((a + 1.0) - (a - 1.0) * cos + sqrt_a_alpha),
+                2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+                a * ((a + 1.0) - (a - 1.0) * cos - sqrt_a_alpha),
+                (a + 1.0) + (a - 1.0) * cos + sqrt_a_alpha,
+                -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+                (a + 1.0) + (a - 1.0) * cos - sqrt_a_alpha,
+            ),
+            PeqFilterType::HighShelf => (
+                a * ((a + 1.0) + (a - 1.0) * cos + sqrt_a_alpha),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                a * ((a + 1.0) + (a - 1.0) * cos - sqrt_a_alpha),
+                (a + 1.0) - (a - 1.0) * cos + sqrt_a_alpha,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                (a + 1.0) - (a - 1.0) * cos - sqrt_a_alpha,
+            ),
+            PeqFilterType::LowPass => (
+                (1.0 - cos) / 2.0,
+                1.0 - cos,
+                (1.0 - cos) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            PeqFilterType::HighPass => (
+                (1.0 + cos) / 2.0,
+                -(1.0 + cos),
+                (1.0 + cos) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+        };
+
+        Self {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+
+    /// Magnitude response in dB at `freq` Hz.
+    pub fn magnitude_db(&self, freq: f64, sample_rate: f64) -> f64 {
+        let w = 2.0 * PI * freq / sample_rate;
+        let (s1, c1) = w.sin_cos();
+        let (s2, c2) = (2.0 * w).sin_cos();
+        let num_re = self.b0 + self.b1 * c1 + self.b2 * c2;
+        let num_im = -(self.b1 * s1 + self.b2 * s2);
+        let den_re = 1.0 + self.a1 * c1 + self.a2 * c2;
+        let den_im = -(self.a1 * s1 + self.a2 * s2);
+        let power = (num_re * num_re + num_im * num_im) / (den_re * den_re + den_im * den_im);
+        power_to_db(power)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peak_gain_at_center() {
+        let filter = PeqFilter::peak(1000.0, 2.0, -6.0);
+        let biquad = Biquad::from_filter(&filter, 48000.0);
+        assert!((biquad.magnitude_db(1000.0, 48000.0) + 6.0).abs() < 1e-9);
+        assert!(biquad.magnitude_db(20.0, 48000.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_shelves_and_passes() {
+        let fs = 48000.0;
+        let low = Biquad::from_filter(&PeqFilter::low_shelf(100.0, 0.707, 6.0), fs);
+        assert!((low.magnitude_db(10.0, fs) - 6.0).abs() < 0.1);
+        assert!(low.magnitude_db(10000.0, fs).abs() < 0.1);
+
+        let high = Biquad::from_filter(&PeqFilter::high_shelf(5000.0, 0.707, -4.0), fs);
+        assert!((high.magnitude_db(20000.0, fs) + 4.0).abs() < 0.2);
+
+        let hp = Biquad::from_filter(&PeqFilter::high_pass(100.0, 0.707), fs);
+        assert!((hp.magnitude_db(100.0, fs) + 3.01).abs() < 0.05);
+        assert!(hp.magnitude_db(10.0, fs) < -35.0);
+    }
+}
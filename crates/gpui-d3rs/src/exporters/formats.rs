@@ -0,0 +1,175 @@
+//! Text writers for each export target
+//!
+//! Writers assume the filter set already passed the target's
+//! [`DeviceLimits`](super::DeviceLimits) check.
+
+use super::{Biquad, ExportFormat, PeqFilterType, PeqSet};
+use crate::acoustics::db_to_linear;
+use std::fmt::Write as _;
+
+/// Equalizer APO filter type keyword
+fn apo_type(kind: PeqFilterType) -> &'static str {
+    match kind {
+        PeqFilterType::Peak => "PK",
+        PeqFilterType::LowShelf => "LSC",
+        PeqFilterType::HighShelf => "HSC",
+        PeqFilterType::LowPass => "LPQ",
+        PeqFilterType::HighPass => "HPQ",
+    }
+}
+
+/// Equalizer APO `config.txt`.
+pub(super) fn equalizer_apo(set: &PeqSet) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Preamp: {:.1} dB", set.preamp_db);
+    for (i, filter) in set.filters.iter().enumerate() {
+        let _ = write!(
+            out,
+            "Filter {}: ON {} Fc {:.0} Hz",
+            i + 1,
+            apo_type(filter.kind),
+            filter.freq
+        );
+        if filter.kind.has_gain() {
+            let _ = write!(out, " Gain {:.1} dB", filter.gain_db);
+        }
+        let _ = writeln!(out, " Q {:.2}", filter.q);
+    }
+    out
+}
+
+/// miniDSP biquad file, padded with pass-through biquads to the device's
+/// filter count; the preamp is folded into the first biquad.
+pub(super) fn minidsp(set: &PeqSet) -> String {
+    let slots = ExportFormat::MiniDsp
+        .limits()
+        .max_filters
+        .unwrap_or(set.filters.len());
+    let unity = Biquad {
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+        a1: 0.0,
+        a2: 0.0,
+    };
+    let mut biquads: Vec<Biquad> = set
+        .filters
+        .iter()
+        .map(|f| Biquad::from_filter(f, set.sample_rate))
+        .collect();
+    biquads.resize(slots.max(1), unity);
+
+    let preamp = db_to_linear(set.preamp_db);
+    biquads[0].b0 *= preamp;
+    biquads[0].b1 *= preamp;
+    biquads[0].b2 *= preamp;
+
+    let mut lines = Vec::with_capacity(biquads.len() * 6);
+    for (i, bq) in biquads.iter().enumerate() {
+        lines.push(format!("biquad{},", i + 1));
+        lines.push(format!("b0={:.15},", bq.b0));
+        lines.push(format!("b1={:.15},", bq.b1));
+        lines.push(format!("b2={:.15},", bq.b2));
+        // miniDSP expects the feedback coefficients with inverted sign
+        lines.push(format!("a1={:.15},", 0.0 - bq.a1));
+        lines.push(format!("a2={:.15},", 0.0 - bq.a2));
+    }
+    if let Some(last) = lines.last_mut() {
+        last.pop();
+    }
+    lines.join("\n") + "\n"
+}
+
+/// RME TotalMix FX room EQ preset.
+///
+/// All bands are written; unused bands are flat peaks. Band types are
+/// 0 = peak, 1 = low shelf, 2 = high shelf.
+pub(super) fn rme_totalmix(set: &PeqSet) -> String {
+    let bands = ExportFormat::RmeTotalMix.limits().max_filters.unwrap_or(9);
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Preset>\n");
+    out.push_str("\t<RoomEQ>\n\t\t<Params>\n");
+    let _ = writeln!(out, "\t\t\t<val e=\"Volume\" v=\"{:.1}\"/>", set.preamp_db);
+    for band in 0..bands {
+        let (kind, freq, gain, q) = match set.filters.get(band) {
+            Some(f) => {
+                let kind = match f.kind {
+                    PeqFilterType::LowShelf => 1,
+                    PeqFilterType::HighShelf => 2,
+                    _ => 0,
+                };
+                (kind, f.freq, f.gain_db, f.q)
+            }
+            None => (0, 1000.0, 0.0, 1.0),
+        };
+        let n = band + 1;
+        let _ = writeln!(out, "\t\t\t<val e=\"Band{n}Type\" v=\"{kind}\"/>");
+        let _ = writeln!(out, "\t\t\t<val e=\"Band{n}Freq\" v=\"{freq:.0}\"/>");
+        let _ = writeln!(out, "\t\t\t<val e=\"Band{n}Gain\" v=\"{gain:.1}\"/>");
+        let _ = writeln!(out, "\t\t\t<val e=\"Band{n}Q\" v=\"{q:.2}\"/>");
+    }
+    out.push_str("\t\t</Params>\n\t</RoomEQ>\n</Preset>\n");
+    out
+}
+
+/// PEACE preset (INI).
+pub(super) fn peace(set: &PeqSet) -> String {
+    let mut out = String::from("[General]\n");
+    let _ = writeln!(out, "PreAmp={:.1}", set.preamp_db);
+    let _ = writeln!(out, "FilterCount={}", set.filters.len());
+    for (i, filter) in set.filters.iter().enumerate() {
+        let _ = writeln!(out, "\n[Filter{}]", i + 1);
+        let _ = writeln!(out, "Enabled=1");
+        let _ = writeln!(out, "Type={}", apo_type(filter.kind));
+        let _ = writeln!(out, "Frequency={:.0}", filter.freq);
+        let _ = writeln!(out, "Gain={:.1}", filter.gain_db);
+        let _ = writeln!(out, "Q={:.2}", filter.q);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::PeqFilter;
+    use super::*;
+
+    fn sample() -> PeqSet {
+        PeqSet::new(vec![
+            PeqFilter::high_pass(25.0, 0.707),
+            PeqFilter::peak(1000.0, 1.414, -3.3),
+        ])
+        .preamp(-2.0)
+    }
+
+    #[test]
+    fn test_equalizer_apo() {
+        assert_eq!(
+            equalizer_apo(&sample()),
+            "Preamp: -2.0 dB\n\
+             Filter 1: ON HPQ Fc 25 Hz Q 0.71\n\
+             Filter 2: ON PK Fc 1000 Hz Gain -3.3 dB Q 1.41\n"
+        );
+    }
+
+    #[test]
+    fn test_minidsp_padding_and_signs() {
+        let text = minidsp(&PeqSet::new(vec![PeqFilter::peak(1000.0, 1.0, 6.0)]));
+        assert_eq!(text.matches("biquad").count(), 10);
+        assert!(text.trim_end().ends_with("a2=0.000000000000000"));
+        assert!(text.contains("biquad10,\nb0=1.000000000000000,"));
+
+        let bq = Biquad::from_filter(&PeqFilter::peak(1000.0, 1.0, 6.0), 48000.0);
+        assert!(text.contains(&format!("a1={:.15},", -bq.a1)));
+    }
+
+    #[test]
+    fn test_totalmix_and_peace() {
+        let set = PeqSet::new(vec![PeqFilter::low_shelf(80.0, 0.7, 4.0)]);
+        let xml = rme_totalmix(&set);
+        assert!(xml.contains("<val e=\"Band1Type\" v=\"1\"/>"));
+        assert!(xml.contains("<val e=\"Band9Gain\" v=\"0.0\"/>"));
+
+        let ini = peace(&sample());
+        assert!(ini.starts_with("[General]\nPreAmp=-2.0\nFilterCount=2\n"));
+        assert!(ini.contains("[Filter2]\nEnabled=1\nType=PK\nFrequency=1000\nGain=-3.3\nQ=1.41\n"));
+    }
+}
@@ -0,0 +1,524 @@
+//! EQ export writers
+//!
+//! Converts a set of parametric EQ filters, as produced by AutoEQ workflows,
+//! into configuration files for common playback targets:
+//!
+//! - **Equalizer APO**: `config.txt` filter lines
+//! - **miniDSP**: biquad coefficient files for the advanced PEQ import
+//! - **RME TotalMix FX**: room EQ preset (XML)
+//! - **PEACE**: preset file for the Equalizer APO GUI
+//!
+//! Every target has [`DeviceLimits`] (filter count, Q, gain and frequency
+//! ranges, supported filter types); [`export`] checks them first and reports
+//! every violation instead of writing a file the device would reject.
+//!
+//! # Example
+//!
+//! ```
+//! use d3rs::exporters::{ExportFormat, PeqFilter, PeqSet, export};
+//!
+//! let eq = PeqSet::new(vec![
+//!     PeqFilter::low_shelf(105.0, 0.71, 3.0),
+//!     PeqFilter::peak(1250.0, 2.5, -4.5),
+//! ])
+//! .preamp(-3.5);
+//!
+//! let apo = export(&eq, ExportFormat::EqualizerApo).unwrap();
+//! assert!(apo.starts_with("Preamp: -3.5 dB"));
+//! assert!(apo.contains("Filter 2: ON PK Fc 1250 Hz Gain -4.5 dB Q 2.50"));
+//! ```
+
+mod biquad;
+mod formats;
+
+pub use biquad::Biquad;
+
+/// Kind of a parametric EQ filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeqFilterType {
+    /// Peaking (bell) filter
+    Peak,
+    /// Low shelf
+    LowShelf,
+    /// High shelf
+    HighShelf,
+    /// Second-order low pass
+    LowPass,
+    /// Second-order high pass
+    HighPass,
+}
+
+impl PeqFilterType {
+    /// All filter types.
+    pub const ALL: [PeqFilterType; 5] = [
+        PeqFilterType::Peak,
+        PeqFilterType::LowShelf,
+        PeqFilterType::HighShelf,
+        PeqFilterType::LowPass,
+        PeqFilterType::HighPass,
+    ];
+
+    /// Whether the gain parameter affects the filter.
+    pub fn has_gain(self) -> bool {
+        !matches!(self, PeqFilterType::LowPass | PeqFilterType::HighPass)
+    }
+
+    /// Short label, e.g. "PK" or "LS".
+    pub fn label(self) -> &'static str {
+        match self {
+            PeqFilterType::Peak => "PK",
+            PeqFilterType::LowShelf => "LS",
+            PeqFilterType::HighShelf => "HS",
+            PeqFilterType::LowPass => "LP",
+            PeqFilterType::HighPass => "HP",
+        }
+    }
+}
+
+/// One parametric EQ filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeqFilter {
+    /// Filter type
+    pub kind: PeqFilterType,
+    /// Center or corner frequency in Hz
+    pub freq: f64,
+    /// Quality factor
+    pub q: f64,
+    /// Gain in dB (ignored by low and high pass filters)
+    pub gain_db: f64,
+}
+
+impl PeqFilter {
+    /// Create a filter.
+    pub fn new(kind: PeqFilterType, freq: f64, q: f64, gain_db: f64) -> Self {
+        Self {
+            kind,
+            freq,
+            q,
+            gain_db,
+        }
+    }
+
+    /// Peaking filter.
+    pub fn peak(freq: f64, q: f64, gain_db: f64) -> Self {
+        Self::new(PeqFilterType::Peak, freq, q, gain_db)
+    }
+
+    /// Low shelf.
+    pub fn low_shelf(freq: f64, q: f64, gain_db: f64) -> Self {
+        Self::new(PeqFilterType::LowShelf, freq, q, gain_db)
+    }
+
+    /// High shelf.
+    pub fn high_shelf(freq: f64, q: f64, gain_db: f64) -> Self {
+        Self::new(PeqFilterType::HighShelf, freq, q, gain_db)
+    }
+
+    /// Low pass.
+    pub fn low_pass(freq: f64, q: f64) -> Self {
+        Self::new(PeqFilterType::LowPass, freq, q, 0.0)
+    }
+
+    /// High pass.
+    pub fn high_pass(freq: f64, q: f64) -> Self {
+        Self::new(PeqFilterType::HighPass, freq, q, 0.0)
+    }
+}
+
+/// A PEQ filter chain with its preamp gain.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PeqSet {
+    /// Filters in processing order
+    pub filters: Vec<PeqFilter>,
+    /// Preamp gain in dB, usually negative to leave headroom
+    pub preamp_db: f64,
+    /// Sample rate for coefficient-based formats (default: 48000 Hz)
+    pub sample_rate: f64,
+}
+
+impl PeqSet {
+    /// Create a set with no preamp at 48 kHz.
+    pub fn new(filters: Vec<PeqFilter>) -> Self {
+        Self {
+            filters,
+            preamp_db: 0.0,
+            sample_rate: 48000.0,
+        }
+    }
+
+    /// Set the preamp gain in dB.
+    pub fn preamp(mut self, preamp_db: f64) -> Self {
+        self.preamp_db = preamp_db;
+        self
+    }
+
+    /// Set the sample rate used for biquad coefficients.
+    pub fn sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    /// Preamp that keeps the combined response at or below 0 dB.
+    ///
+    /// The chain is evaluated at `points` log-spaced frequencies between
+    /// 20 Hz and 20 kHz.
+    pub fn auto_preamp(&self, points: usize) -> f64 {
+        let points = points.max(2);
+        let peak = (0..points)
+            .map(|i| {
+                let t = i as f64 / (points - 1) as f64;
+                self.response_db(20.0 * 1000f64.powf(t))
+            })
+            .fold(f64::NEG_INFINITY, f64::max);
+        -peak.max(0.0)
+    }
+
+    /// Combined filter response in dB at `freq`, without the preamp.
+    pub fn response_db(&self, freq: f64) -> f64 {
+        self.filters
+            .iter()
+            .map(|f| Biquad::from_filter(f, self.sample_rate).magnitude_db(freq, self.sample_rate))
+            .sum()
+    }
+}
+
+/// Export target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    /// Equalizer APO `config.txt`
+    EqualizerApo,
+    /// miniDSP biquad coefficients
+    MiniDsp,
+    /// RME TotalMix FX room EQ preset
+    RmeTotalMix,
+    /// PEACE preset
+    Peace,
+}
+
+impl ExportFormat {
+    /// All export formats.
+    pub const ALL: [ExportFormat; 4] = [
+        ExportFormat::EqualizerApo,
+        ExportFormat::MiniDsp,
+        ExportFormat::RmeTotalMix,
+        ExportFormat::Peace,
+    ];
+
+    /// Display name.
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::EqualizerApo => "Equalizer APO",
+            ExportFormat::MiniDsp => "miniDSP",
+            ExportFormat::RmeTotalMix => "RME TotalMix FX",
+            ExportFormat::Peace => "PEACE",
+        }
+    }
+
+    /// Conventional file extension, without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::EqualizerApo | ExportFormat::MiniDsp => "txt",
+            ExportFormat::RmeTotalMix => "tmreq",
+            ExportFormat::Peace => "peace",
+        }
+    }
+
+    /// Constraints of the target.
+    pub fn limits(self) -> DeviceLimits {
+        match self {
+            ExportFormat::EqualizerApo => DeviceLimits {
+                max_filters: None,
+                q_range: (0.01, 100.0),
+                gain_range: (-30.0, 30.0),
+                freq_range: (1.0, 24000.0),
+                filter_types: &PeqFilterType::ALL,
+            },
+            ExportFormat::MiniDsp => DeviceLimits {
+                max_filters: Some(10),
+                q_range: (0.1, 20.0),
+                gain_range: (-16.0, 16.0),
+                freq_range: (10.0, 20000.0),
+                filter_types: &PeqFilterType::ALL,
+            },
+            ExportFormat::RmeTotalMix => DeviceLimits {
+                max_filters: Some(9),
+                q_range: (0.4, 9.9),
+                gain_range: (-20.0, 20.0),
+                freq_range: (20.0, 20000.0),
+                filter_types: &[
+                    PeqFilterType::Peak,
+                    PeqFilterType::LowShelf,
+                    PeqFilterType::HighShelf,
+                ],
+            },
+            ExportFormat::Peace => DeviceLimits {
+                max_filters: Some(31),
+                q_range: (0.01, 100.0),
+                gain_range: (-30.0, 30.0),
+                freq_range: (1.0, 24000.0),
+                filter_types: &PeqFilterType::ALL,
+            },
+        }
+    }
+}
+
+/// Constraints a target places on a filter set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceLimits {
+    /// Maximum number of filters, if limited
+    pub max_filters: Option<usize>,
+    /// Accepted Q range (inclusive)
+    pub q_range: (f64, f64),
+    /// Accepted gain range in dB (inclusive)
+    pub gain_range: (f64, f64),
+    /// Accepted frequency range in Hz (inclusive)
+    pub freq_range: (f64, f64),
+    /// Supported filter types
+    pub filter_types: &'static [PeqFilterType],
+}
+
+impl DeviceLimits {
+    /// Check a filter set, returning every violation found.
+    pub fn check(&self, set: &PeqSet) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if let Some(max) = self.max_filters
+            && set.filters.len() > max
+        {
+            violations.push(Violation::TooManyFilters {
+                count: set.filters.len(),
+                max,
+            });
+        }
+        let outside = |v: f64, (lo, hi): (f64, f64)| !(lo..=hi).contains(&v);
+        for (index, filter) in set.filters.iter().enumerate() {
+            if !self.filter_types.contains(&filter.kind) {
+                violations.push(Violation::UnsupportedType {
+                    index,
+                    kind: filter.kind,
+                });
+            }
+            if outside(filter.freq, self.freq_range) {
+                violations.push(Violation::Frequency {
+                    index,
+                    value: filter.freq,
+                    range: self.freq_range,
+                });
+            }
+            if outside(filter.q, self.q_range) {
+                violations.push(Violation::Q {
+                    index,
+                    value: filter.q,
+                    range: self.q_range,
+                });
+            }
+            if filter.kind.has_gain() && outside(filter.gain_db, self.gain_range) {
+                violations.push(Violation::Gain {
+                    index,
+                    value: filter.gain_db,
+                    range: self.gain_range,
+                });
+            }
+        }
+        violations
+    }
+}
+
+/// A filter set property the target cannot represent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// More filters than the device provides
+    TooManyFilters {
+        /// Filters in the set
+        count: usize,
+        /// Filters supported
+        max: usize,
+    },
+    /// Filter type not available on the device
+    UnsupportedType {
+        /// Filter index
+        index: usize,
+        /// Filter type
+        kind: PeqFilterType,
+    },
+    /// Frequency out of range
+    Frequency {
+        /// Filter index
+        index: usize,
+        /// Requested value
+        value: f64,
+        /// Accepted range
+        range: (f64, f64),
+    },
+    /// Q out of range
+    Q {
+        /// Filter index
+        index: usize,
+        /// Requested value
+        value: f64,
+        /// Accepted range
+        range: (f64, f64),
+    },
+    /// Gain out of range
+    Gain {
+        /// Filter index
+        index: usize,
+        /// Requested value
+        value: f64,
+        /// Accepted range
+        range: (f64, f64),
+    },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::TooManyFilters { count, max } => {
+                write!(f, "{count} filters, device supports {max}")
+            }
+            Violation::UnsupportedType { index, kind } => {
+                write!(
+                    f,
+                    "filter {}: type {} not supported",
+                    index + 1,
+                    kind.label()
+                )
+            }
+            Violation::Frequency {
+                index,
+                value,
+                range,
+            } => write!(
+                f,
+                "filter {}: frequency {value} Hz outside {}-{} Hz",
+                index + 1,
+                range.0,
+                range.1
+            ),
+            Violation::Q {
+                index,
+                value,
+                range,
+            } => write!(
+                f,
+                "filter {}: Q {value} outside {}-{}",
+                index + 1,
+                range.0,
+                range.1
+            ),
+            Violation::Gain {
+                index,
+                value,
+                range,
+            } => write!(
+                f,
+                "filter {}: gain {value} dB outside {} to {} dB",
+                index + 1,
+                range.0,
+                range.1
+            ),
+        }
+    }
+}
+
+/// Error returned by [`export`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportError {
+    /// Target format
+    pub format: ExportFormat,
+    /// Every constraint the filter set violates
+    pub violations: Vec<Violation>,
+}
+
+impl std::fmt::Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cannot export to {}: ", self.format.label())?;
+        let messages: Vec<String> = self.violations.iter().map(|v| v.to_string()).collect();
+        write!(f, "{}", messages.join("; "))
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+/// Write `set` in the given format after checking the target's limits.
+pub fn export(set: &PeqSet, format: ExportFormat) -> Result<String, ExportError> {
+    let violations = format.limits().check(set);
+    if !violations.is_empty() {
+        return Err(ExportError { format, violations });
+    }
+    Ok(match format {
+        ExportFormat::EqualizerApo => formats::equalizer_apo(set),
+        ExportFormat::MiniDsp => formats::minidsp(set),
+        ExportFormat::RmeTotalMix => formats::rme_totalmix(set),
+        ExportFormat::Peace => formats::peace(set),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits_report_all_violations() {
+        let mut filters = vec![PeqFilter::peak(1000.0, 1.0, 0.0); 9];
+        filters.push(PeqFilter::high_pass(30.0, 0.7));
+        filters.push(PeqFilter::peak(15.0, 12.0, -25.0));
+        let set = PeqSet::new(filters);
+
+        let err = export(&set, ExportFormat::RmeTotalMix).unwrap_err();
+        assert_eq!(
+            err.violations,
+            vec![
+                Violation::TooManyFilters { count: 11, max: 9 },
+                Violation::UnsupportedType {
+                    index: 9,
+                    kind: PeqFilterType::HighPass,
+                },
+                Violation::Frequency {
+                    index: 10,
+                    value: 15.0,
+                    range: (20.0, 20000.0),
+                },
+                Violation::Q {
+                    index: 10,
+                    value: 12.0,
+                    range: (0.4, 9.9),
+                },
+                Violation::Gain {
+                    index: 10,
+                    value: -25.0,
+                    range: (-20.0, 20.0),
+                },
+            ]
+        );
+        assert!(
+            err.to_string()
+                .starts_with("cannot export to RME TotalMix FX: 11 filters")
+        );
+        assert!(export(&set, ExportFormat::EqualizerApo).is_ok());
+    }
+
+    #[test]
+    fn test_pass_filters_ignore_gain_limits() {
+        let set = PeqSet::new(vec![PeqFilter::new(
+            PeqFilterType::LowPass,
+            8000.0,
+            0.7,
+            99.0,
+        )]);
+        assert!(ExportFormat::MiniDsp.limits().check(&set).is_empty());
+    }
+
+    #[test]
+    fn test_auto_preamp() {
+        let set = PeqSet::new(vec![
+            PeqFilter::peak(100.0, 1.0, 4.0),
+            PeqFilter::peak(3000.0, 2.0, -6.0),
+        ]);
+        let preamp = set.auto_preamp(400);
+        assert!((preamp + 4.0).abs() < 0.1, "{preamp}");
+        assert_eq!(
+            PeqSet::new(vec![PeqFilter::peak(100.0, 1.0, -3.0)]).auto_preamp(50),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Column-ordered tabular data
+//!
+//! A [`Dataset`] keeps the header order of the source and auto-types every
+//! value, so charts can pull numeric columns without re-parsing strings.
+
+use super::auto_type::{AutoTyped, auto_type};
+use super::dsv::DsvParser;
+
+/// A table of auto-typed values with named, ordered columns.
+///
+/// # Example
+///
+/// ```
+/// use d3rs::fetch::Dataset;
+///
+/// let data = Dataset::from_csv("freq,spl\n20,80.5\n1000,85");
+/// assert_eq!(data.columns(), &["freq", "spl"]);
+/// assert_eq!(data.numbers("spl"), vec![80.5, 85.0]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dataset {
+    columns: Vec<String>,
+    rows: Vec<Vec<AutoTyped>>,
+}
+
+impl Dataset {
+    /// Create a dataset from column names and rows.
+    ///
+    /// Rows shorter than the header are padded with [`AutoTyped::Null`];
+    /// longer rows are truncated.
+    pub fn new(columns: Vec<String>, rows: Vec<Vec<AutoTyped>>) -> Self {
+        let width = columns.len();
+        let rows = rows
+            .into_iter()
+            .map(|mut row| {
+                row.resize(width, AutoTyped::Null);
+                row
+            })
+            .collect();
+        Self { columns, rows }
+    }
+
+    /// Parse delimiter-separated text whose first line is the header.
+    pub fn from_dsv(text: &str, delimiter: char) -> Self {
+        let mut lines = DsvParser::new(delimiter).parse_rows(text).into_iter();
+        let Some(columns) = lines.next() else {
+            return Self::default();
+        };
+        let rows = lines
+            .map(|line| line.iter().map(|value| auto_type(value)).collect())
+            .collect();
+        Self::new(columns, rows)
+    }
+
+    /// Parse CSV text whose first line is the header.
+    pub fn from_csv(text: &str) -> Self {
+        Self::from_dsv(text, ',')
+    }
+
+    /// Parse TSV text whose first line is the header.
+    pub fn from_tsv(text: &str) -> Self {
+        Self::from_dsv(text, '\t')
+    }
+
+    /// Build a dataset from a JSON array of objects.
+    ///
+    /// Columns are taken in order of first appearance (keys within one object
+    /// follow `serde_json`'s map order); nested arrays and objects are kept as
+    /// their JSON text.
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        let records = value.as_array()?;
+        let mut columns: Vec<String> = Vec::new();
+        for record in records {
+            for key in record.as_object()?.keys() {
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+        let rows = records
+            .iter()
+            .map(|record| {
+                columns
+                    .iter()
+                    .map(|column| record.get(column).map_or(AutoTyped::Null, json_value))
+                    .collect()
+            })
+            .collect();
+        Some(Self::new(columns, rows))
+    }
+
+    /// Column names in source order.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// All rows, each with one value per column.
+    pub fn rows(&self) -> &[Vec<AutoTyped>] {
+        &self.rows
+    }
+
+    /// Number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether the dataset has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Index of a column by name.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c == name)
+    }
+
+    /// Values of a column, or `None` if it does not exist.
+    pub fn column(&self, name: &str) -> Option<Vec<&AutoTyped>> {
+        let index = self.column_index(name)?;
+        Some(self.rows.iter().map(|row| &row[index]).collect())
+    }
+
+    /// Numeric values of a column; non-numeric cells become `NaN`.
+    ///
+    /// Returns an empty vector if the column does not exist.
+    pub fn numbers(&self, name: &str) -> Vec<f64> {
+        self.column(name)
+            .map(|values| {
+                values
+                    .into_iter()
+                    .map(|v| v.as_f64().unwrap_or(f64::NAN))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn json_value(value: &serde_json::Value) -> AutoTyped {
+    match value {
+        serde_json::Value::Null => AutoTyped::Null,
+        serde_json::Value::Bool(b) => AutoTyped::Bool(*b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => AutoTyped::Integer(i),
+            None => AutoTyped::Float(n.as_f64().unwrap_or(f64::NAN)),
+        },
+        serde_json::Value::String(s) => auto_type(s),
+        other => AutoTyped::String(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv_keeps_column_order() {
+        let data = Dataset::from_csv("z,a,m\n1,x,true\n2.5,,false\n3");
+        assert_eq!(data.columns(), &["z", "a", "m"]);
+        assert_eq!(data.len(), 3);
+        assert_eq!(data.rows()[0][2], AutoTyped::Bool(true));
+        assert_eq!(data.rows()[1][1], AutoTyped::Null);
+        assert_eq!(data.rows()[2][1], AutoTyped::Null);
+        assert_eq!(data.numbers("z"), vec![1.0, 2.5, 3.0]);
+        assert!(data.numbers("missing").is_empty());
+    }
+
+    #[test]
+    fn test_from_json_records() {
+        let value = serde_json::json!([
+            {"freq": 20, "spl": 80.5},
+            {"freq": 1000, "spl": "85", "note": [1, 2]}
+        ]);
+        let data = Dataset::from_json(&value).unwrap();
+        assert_eq!(data.columns(), &["freq", "spl", "note"]);
+        assert_eq!(data.numbers("spl"), vec![80.5, 85.0]);
+        assert_eq!(data.rows()[0][2], AutoTyped::Null);
+        assert_eq!(data.rows()[1][2], AutoTyped::String("[1,2]".to_string()));
+        assert!(Dataset::from_json(&serde_json::json!({"a": 1})).is_none());
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(Dataset::from_csv("").is_empty());
+        assert!(Dataset::from_csv("").columns().is_empty());
+    }
+}
@@ -0,0 +1,493 @@
+//! HTTP data sources with caching and retry
+//!
+//! [`HttpClient`] wraps reqwest with the plumbing every data-driven app ends
+//! up writing: an ETag-aware disk cache, retry with exponential backoff,
+//! cooperative cancellation and decoding into [`Dataset`] or serde types.
+//!
+//! The client is async and must run on a tokio runtime; GPUI apps typically
+//! keep a runtime around and `spawn` fetches onto it.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use d3rs::fetch::{CancellationToken, HttpClient, RetryPolicy};
+//!
+//! # async fn demo() -> Result<(), d3rs::fetch::FetchError> {
+//! let client = HttpClient::new()
+//!     .cache_dir(std::env::temp_dir().join("spinorama-cache"))
+//!     .retry(RetryPolicy::default().max_retries(5));
+//! let token = CancellationToken::new();
+//!
+//! let data = client
+//!     .fetch_csv("https://example.com/measurements.csv", &token)
+//!     .await?;
+//! println!("{} rows, columns {:?}", data.len(), data.columns());
+//! # Ok(())
+//! # }
+//! ```
+
+use super::dataset::Dataset;
+use reqwest::StatusCode;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// Error returned by [`HttpClient`] fetches.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The request could not be sent or the body could not be read
+    Network(String),
+    /// The server answered with a non-success status
+    Status(u16),
+    /// The body could not be decoded
+    Decode(String),
+    /// The fetch was cancelled through its [`CancellationToken`]
+    Cancelled,
+}
+
+impl FetchError {
+    /// Whether retrying the request may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            FetchError::Network(_) => true,
+            FetchError::Status(code) => *code == 429 || *code >= 500,
+            FetchError::Decode(_) | FetchError::Cancelled => false,
+        }
+    }
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Network(err) => write!(f, "network error: {err}"),
+            FetchError::Status(code) => write!(f, "HTTP status {code}"),
+            FetchError::Decode(err) => write!(f, "decode error: {err}"),
+            FetchError::Cancelled => write!(f, "fetch cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Retry schedule with exponential backoff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self::default().max_retries(0)
+    }
+
+    /// Set the number of retries after the first attempt (default: 3).
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Set the delay before the first retry (default: 250ms).
+    pub fn initial_backoff(mut self, delay: Duration) -> Self {
+        self.initial_backoff = delay;
+        self
+    }
+
+    /// Set the upper bound on any single delay (default: 5s).
+    pub fn max_backoff(mut self, delay: Duration) -> Self {
+        self.max_backoff = delay;
+        self
+    }
+
+    /// Delay before retry number `retry` (0-based), doubling each time.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u32.checked_shl(retry).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Cooperative cancellation for in-flight fetches.
+///
+/// Clones share state: cancelling one cancels them all.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancelState>,
+}
+
+#[derive(Debug, Default)]
+struct CancelState {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// Create a token that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel every fetch using this token.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolve once the token is cancelled.
+    pub async fn cancelled(&self) {
+        let notified = self.inner.notify.notified();
+        tokio::pin!(notified);
+        // Register before checking the flag so a concurrent cancel is not missed
+        notified.as_mut().enable();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
+    }
+}
+
+/// A cached response body with its validator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedResponse {
+    /// ETag sent by the server, if any
+    pub etag: Option<String>,
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+/// On-disk response cache keyed by URL.
+///
+/// Each entry is a `<key>.body` file plus an optional `<key>.etag` file.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Use `dir` as the cache directory (created on first store).
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Stable file stem for a URL (FNV-1a, hex).
+    pub fn key(url: &str) -> String {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in url.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        format!("{hash:016x}")
+    }
+
+    fn path(&self, url: &str, extension: &str) -> PathBuf {
+        self.dir.join(format!("{}.{extension}", Self::key(url)))
+    }
+
+    /// Read the cached response for `url`.
+    pub fn load(&self, url: &str) -> Option<CachedResponse> {
+        let body = std::fs::read(self.path(url, "body")).ok()?;
+        let etag = std::fs::read_to_string(self.path(url, "etag")).ok();
+        Some(CachedResponse { etag, body })
+    }
+
+    /// Store a response for `url`, replacing any previous entry.
+    pub fn store(&self, url: &str, etag: Option<&str>, body: &[u8]) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(url, "body"), body)?;
+        let etag_path = self.path(url, "etag");
+        match etag {
+            Some(etag) => std::fs::write(etag_path, etag),
+            None => match std::fs::remove_file(etag_path) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+                _ => Ok(()),
+            },
+        }
+    }
+
+    /// Remove the entry for `url`.
+    pub fn remove(&self, url: &str) {
+        let _ = std::fs::remove_file(self.path(url, "body"));
+        let _ = std::fs::remove_file(self.path(url, "etag"));
+    }
+}
+
+/// Outcome of a single request attempt.
+enum Attempt {
+    NotModified,
+    Fresh { etag: Option<String>, body: Vec<u8> },
+}
+
+/// Async HTTP client for data sources.
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    client: reqwest::Client,
+    cache: Option<DiskCache>,
+    retry: RetryPolicy,
+    stale_on_error: bool,
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpClient {
+    /// Create a client without a cache, using the default retry policy.
+    pub fn new() -> Self {
+        Self::with_client(reqwest::Client::new())
+    }
+
+    /// Create a client around a configured reqwest client
+    /// (timeouts, user agent, proxies).
+    pub fn with_client(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            cache: None,
+            retry: RetryPolicy::default(),
+            stale_on_error: true,
+        }
+    }
+
+    /// Cache responses in `dir` and revalidate them with `If-None-Match`.
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache = Some(DiskCache::new(dir));
+        self
+    }
+
+    /// Set the retry policy.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Serve the cached copy when the server cannot be reached (default: true).
+    pub fn stale_on_error(mut self, enabled: bool) -> Self {
+        self.stale_on_error = enabled;
+        self
+    }
+
+    /// The disk cache, if configured.
+    pub fn cache(&self) -> Option<&DiskCache> {
+        self.cache.as_ref()
+    }
+
+    /// Fetch the raw response body.
+    pub async fn fetch_bytes(
+        &self,
+        url: &str,
+        token: &CancellationToken,
+    ) -> Result<Vec<u8>, FetchError> {
+        let cached = self.cache.as_ref().and_then(|cache| cache.load(url));
+        let etag = cached.as_ref().and_then(|c| c.etag.as_deref());
+
+        let mut retry = 0;
+        loop {
+            if token.is_cancelled() {
+                return Err(FetchError::Cancelled);
+            }
+            let result = tokio::select! {
+                _ = token.cancelled() => return Err(FetchError::Cancelled),
+                result = self.attempt(url, etag) => result,
+            };
+
+            match result {
+                Ok(Attempt::NotModified) => {
+                    // Only sent when a validator was cached, so the body exists
+                    return cached
+                        .map(|cached| cached.body)
+                        .ok_or(FetchError::Status(304));
+                }
+                Ok(Attempt::Fresh { etag, body }) => {
+                    if let Some(cache) = &self.cache {
+                        let _ = cache.store(url, etag.as_deref(), &body);
+                    }
+                    return Ok(body);
+                }
+                Err(err) if err.is_retryable() && retry < self.retry.max_retries => {
+                    let delay = self.retry.delay(retry);
+                    retry += 1;
+                    tokio::select! {
+                        _ = token.cancelled() => return Err(FetchError::Cancelled),
+                        _ = tokio::time::sleep(delay) => {}
+                    }
+                }
+                Err(err) => {
+                    return match cached {
+                        Some(cached) if self.stale_on_error && err.is_retryable() => {
+                            Ok(cached.body)
+                        }
+                        _ => Err(err),
+                    };
+                }
+            }
+        }
+    }
+
+    async fn attempt(&self, url: &str, etag: Option<&str>) -> Result<Attempt, FetchError> {
+        let mut request = self.client.get(url);
+        if let Some(etag) = etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| FetchError::Network(e.to_string()))?;
+
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED {
+            return Ok(Attempt::NotModified);
+        }
+        if !status.is_success() {
+            return Err(FetchError::Status(status.as_u16()));
+        }
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| FetchError::Network(e.to_string()))?;
+        Ok(Attempt::Fresh {
+            etag,
+            body: body.to_vec(),
+        })
+    }
+
+    /// Fetch the body as UTF-8 text.
+    pub async fn fetch_text(
+        &self,
+        url: &str,
+        token: &CancellationToken,
+    ) -> Result<String, FetchError> {
+        let bytes = self.fetch_bytes(url, token).await?;
+        String::from_utf8(bytes).map_err(|e| FetchError::Decode(e.to_string()))
+    }
+
+    /// Fetch and deserialize a JSON body.
+    pub async fn fetch_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        token: &CancellationToken,
+    ) -> Result<T, FetchError> {
+        let bytes = self.fetch_bytes(url, token).await?;
+        serde_json::from_slice(&bytes).map_err(|e| FetchError::Decode(e.to_string()))
+    }
+
+    /// Fetch a JSON array of objects as a [`Dataset`].
+    pub async fn fetch_json_dataset(
+        &self,
+        url: &str,
+        token: &CancellationToken,
+    ) -> Result<Dataset, FetchError> {
+        let value: serde_json::Value = self.fetch_json(url, token).await?;
+        Dataset::from_json(&value)
+            .ok_or_else(|| FetchError::Decode("expected a JSON array of objects".to_string()))
+    }
+
+    /// Fetch a CSV body as a [`Dataset`].
+    pub async fn fetch_csv(
+        &self,
+        url: &str,
+        token: &CancellationToken,
+    ) -> Result<Dataset, FetchError> {
+        Ok(Dataset::from_csv(&self.fetch_text(url, token).await?))
+    }
+
+    /// Fetch a TSV body as a [`Dataset`].
+    pub async fn fetch_tsv(
+        &self,
+        url: &str,
+        token: &CancellationToken,
+    ) -> Result<Dataset, FetchError> {
+        Ok(Dataset::from_tsv(&self.fetch_text(url, token).await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delays() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(500));
+        let delays: Vec<_> = (0..5).map(|i| policy.delay(i).as_millis()).collect();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+        assert_eq!(policy.delay(64), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_retryable_errors() {
+        assert!(FetchError::Network("reset".into()).is_retryable());
+        assert!(FetchError::Status(503).is_retryable());
+        assert!(FetchError::Status(429).is_retryable());
+        assert!(!FetchError::Status(404).is_retryable());
+        assert!(!FetchError::Cancelled.is_retryable());
+    }
+
+    #[test]
+    fn test_disk_cache_round_trip() {
+        let dir = std::env::temp_dir().join(format!("d3rs-cache-{}", std::process::id()));
+        let cache = DiskCache::new(&dir);
+        let url = "https://example.com/data.csv";
+        assert!(cache.load(url).is_none());
+
+        cache.store(url, Some("\"v1\""), b"a,b\n1,2").unwrap();
+        let entry = cache.load(url).unwrap();
+        assert_eq!(entry.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(entry.body, b"a,b\n1,2");
+
+        cache.store(url, None, b"a\n1").unwrap();
+        assert_eq!(cache.load(url).unwrap().etag, None);
+
+        cache.remove(url);
+        assert!(cache.load(url).is_none());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_cache_key_is_stable() {
+        assert_eq!(DiskCache::key(""), "cbf29ce484222325");
+        assert_ne!(DiskCache::key("a"), DiskCache::key("b"));
+    }
+
+    #[test]
+    fn test_cancellation_token_shared() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+}
@@ -1,10 +1,10 @@
 //! Data fetching and parsing utilities
 //!
 //! This module provides utilities for parsing common data formats like CSV, TSV,
-//! and JSON. Inspired by d3-fetch but adapted for Rust's synchronous model.
+//! and JSON into rows or a column-ordered [`Dataset`]. Inspired by d3-fetch.
 //!
-//! Note: For actual HTTP fetching, use reqwest or similar crates. This module
-//! focuses on parsing the data once you have it as a string.
+//! With the `http` feature, [`HttpClient`] adds async fetching with an ETag
+//! disk cache, retry with backoff and cancellation (requires a tokio runtime).
 //!
 //! # Example
 //!
@@ -19,11 +19,17 @@
 
 mod auto_type;
 mod csv;
+mod dataset;
 mod dsv;
+#[cfg(feature = "http")]
+mod http;
 
 pub use auto_type::{AutoTyped, auto_type, auto_type_row, auto_type_rows};
 pub use csv::{
     CsvOptions, format_csv, format_tsv, parse_csv, parse_csv_with_options, parse_tsv,
     parse_tsv_with_options,
 };
+pub use dataset::Dataset;
 pub use dsv::{DsvParser, DsvRow, parse_dsv};
+#[cfg(feature = "http")]
+pub use http::{CachedResponse, CancellationToken, DiskCache, FetchError, HttpClient, RetryPolicy};
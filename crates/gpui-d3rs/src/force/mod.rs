@@ -1,10 +1,15 @@
 //! Force-directed graph layout (d3-force)
 //!
 //! This module implements a force-directed graph simulation using velocity Verlet integration.
+//! [`SimulationWorker`] steps a simulation on a background thread for large graphs.
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
+mod worker;
+
+pub use worker::{SimulationWorker, SimulationWorkerConfig};
+
 /// A node in the simulation
 #[derive(Debug, Clone)]
 pub struct SimulationNode {
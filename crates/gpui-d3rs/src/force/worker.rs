@@ -0,0 +1,427 @@
+//! Background stepping of force simulations
+//!
+//! [`SimulationWorker`] runs a [`Simulation`] on its own thread so large
+//! graphs don't stall the UI. The worker ticks at a fixed rate, spends at
+//! most a time budget per step (adapting the number of ticks to how long a
+//! tick takes), and publishes node positions through a double buffer: the
+//! render loop always reads a complete frame of positions, never a
+//! half-updated one.
+//!
+//! Nodes and forces use `Rc`, so the simulation is built on the worker
+//! thread by the closure passed to [`SimulationWorker::spawn`].
+//!
+//! # Example
+//!
+//! ```
+//! use d3rs::force::{
+//!     ForceCenter, Simulation, SimulationNode, SimulationWorker, SimulationWorkerConfig,
+//! };
+//!
+//! let worker = SimulationWorker::spawn(SimulationWorkerConfig::default(), || {
+//!     let nodes = (0..10)
+//!         .map(|i| SimulationNode::new(i, i as f64, 0.0))
+//!         .collect();
+//!     Simulation::new(nodes).force(Box::new(ForceCenter::new(0.0, 0.0)))
+//! });
+//!
+//! // In the render loop: redraw when a new frame was published
+//! let generation = worker.generation();
+//! worker.with_positions(|positions| assert_eq!(positions.len(), 10));
+//! # let _ = generation;
+//! ```
+
+use super::Simulation;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Timing settings of a [`SimulationWorker`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimulationWorkerConfig {
+    /// Simulation ticks per second (fixed timestep)
+    pub tick_rate: f64,
+    /// Maximum time spent ticking before publishing positions
+    pub step_budget: Duration,
+    /// Upper bound on ticks run between two publications
+    pub max_ticks_per_step: usize,
+}
+
+impl Default for SimulationWorkerConfig {
+    fn default() -> Self {
+        Self {
+            tick_rate: 60.0,
+            step_budget: Duration::from_millis(8),
+            max_ticks_per_step: 8,
+        }
+    }
+}
+
+impl SimulationWorkerConfig {
+    /// Set the number of ticks per second
+    pub fn tick_rate(mut self, rate: f64) -> Self {
+        self.tick_rate = rate.max(1.0);
+        self
+    }
+
+    /// Set the time budget per step
+    pub fn step_budget(mut self, budget: Duration) -> Self {
+        self.step_budget = budget;
+        self
+    }
+
+    /// Set the maximum number of ticks per step
+    pub fn max_ticks_per_step(mut self, max: usize) -> Self {
+        self.max_ticks_per_step = max.max(1);
+        self
+    }
+}
+
+enum Command {
+    Reheat(f64),
+    AlphaTarget(f64),
+    Fix(usize, Option<(f64, f64)>),
+    Pause,
+    Resume,
+    Stop,
+}
+
+/// Published state, swapped wholesale with the worker's back buffer
+#[derive(Debug, Default)]
+struct Frame {
+    positions: Vec<(f64, f64)>,
+    alpha: f64,
+    ticks: u64,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    front: Mutex<Frame>,
+    generation: AtomicU64,
+    running: AtomicBool,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, Frame> {
+        self.front
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A force simulation stepped on a background thread
+///
+/// Dropping the worker stops the thread.
+pub struct SimulationWorker {
+    shared: Arc<Shared>,
+    commands: Sender<Command>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for SimulationWorker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimulationWorker")
+            .field("generation", &self.generation())
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+impl SimulationWorker {
+    /// Build a simulation on a new thread and start stepping it
+    pub fn spawn<F>(config: SimulationWorkerConfig, build: F) -> Self
+    where
+        F: FnOnce() -> Simulation + Send + 'static,
+    {
+        let shared = Arc::new(Shared::default());
+        shared.running.store(true, Ordering::Release);
+        let (commands, receiver) = mpsc::channel();
+
+        // Publish the initial positions before returning, so readers never
+        // see an empty frame for a non-empty graph
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let worker_shared = Arc::clone(&shared);
+        let thread = std::thread::Builder::new()
+            .name("d3rs-force".into())
+            .spawn(move || {
+                let mut simulation = build();
+                let mut back = Frame::default();
+                publish(&worker_shared, &simulation, &mut back, 0);
+                let _ = ready_tx.send(());
+                run(config, &mut simulation, &worker_shared, back, receiver);
+            })
+            .expect("failed to spawn force simulation thread");
+        let _ = ready_rx.recv();
+
+        Self {
+            shared,
+            commands,
+            thread: Some(thread),
+        }
+    }
+
+    /// Read the latest published positions, indexed like the nodes
+    pub fn with_positions<R>(&self, f: impl FnOnce(&[(f64, f64)]) -> R) -> R {
+        f(&self.shared.lock().positions)
+    }
+
+    /// Copy of the latest published positions
+    pub fn positions(&self) -> Vec<(f64, f64)> {
+        self.with_positions(<[_]>::to_vec)
+    }
+
+    /// Counter incremented on every publication; redraw when it changes
+    pub fn generation(&self) -> u64 {
+        self.shared.generation.load(Ordering::Acquire)
+    }
+
+    /// Alpha of the latest published frame
+    pub fn alpha(&self) -> f64 {
+        self.shared.lock().alpha
+    }
+
+    /// Total number of ticks run so far
+    pub fn ticks(&self) -> u64 {
+        self.shared.lock().ticks
+    }
+
+    /// Whether the simulation is still moving (not converged or paused)
+    pub fn is_running(&self) -> bool {
+        self.shared.running.load(Ordering::Acquire)
+    }
+
+    /// Restart the simulation at the given alpha
+    pub fn reheat(&self, alpha: f64) {
+        self.send(Command::Reheat(alpha));
+    }
+
+    /// Set the alpha target (non-zero keeps the simulation warm, e.g. while dragging)
+    pub fn set_alpha_target(&self, target: f64) {
+        self.send(Command::AlphaTarget(target));
+    }
+
+    /// Pin a node at a position, or release it with `None`
+    pub fn fix_node(&self, index: usize, position: Option<(f64, f64)>) {
+        self.send(Command::Fix(index, position));
+    }
+
+    /// Stop ticking until [`SimulationWorker::resume`]
+    pub fn pause(&self) {
+        self.send(Command::Pause);
+    }
+
+    /// Resume ticking after [`SimulationWorker::pause`] or convergence
+    pub fn resume(&self) {
+        self.send(Command::Resume);
+    }
+
+    fn send(&self, command: Command) {
+        let resumes = !matches!(command, Command::Pause | Command::Stop);
+        if self.commands.send(command).is_ok() && resumes {
+            self.shared.running.store(true, Ordering::Release);
+        }
+    }
+}
+
+impl Drop for SimulationWorker {
+    fn drop(&mut self) {
+        let _ = self.commands.send(Command::Stop);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Copy positions into the back buffer and swap it to the front
+fn publish(shared: &Shared, simulation: &Simulation, back: &mut Frame, ticks: u64) {
+    back.positions.clear();
+    back.positions.extend(simulation.nodes.iter().map(|node| {
+        let node = node.borrow();
+        (node.x, node.y)
+    }));
+    back.alpha = simulation.alpha;
+    back.ticks = ticks;
+
+    std::mem::swap(&mut *shared.lock(), back);
+    shared.generation.fetch_add(1, Ordering::AcqRel);
+}
+
+/// Apply a command; returns false to stop the worker
+fn apply(command: Command, simulation: &mut Simulation, running: &mut bool) -> bool {
+    match command {
+        Command::Reheat(alpha) => {
+            simulation.alpha = alpha;
+            *running = true;
+        }
+        Command::AlphaTarget(target) => {
+            simulation.alpha_target = target;
+            *running = true;
+        }
+        Command::Fix(index, position) => {
+            if let Some(node) = simulation.nodes.get(index) {
+                let mut node = node.borrow_mut();
+                node.fx = position.map(|p| p.0);
+                node.fy = position.map(|p| p.1);
+                if let Some((x, y)) = position {
+                    node.x = x;
+                    node.y = y;
+                }
+            }
+            *running = true;
+        }
+        Command::Pause => *running = false,
+        Command::Resume => *running = true,
+        Command::Stop => return false,
+    }
+    true
+}
+
+fn run(
+    config: SimulationWorkerConfig,
+    simulation: &mut Simulation,
+    shared: &Shared,
+    mut back: Frame,
+    commands: Receiver<Command>,
+) {
+    let dt = Duration::from_secs_f64(1.0 / config.tick_rate.max(1.0));
+    let mut running = true;
+    let mut ticks: u64 = 0;
+    let mut backlog = Duration::ZERO;
+    let mut last = Instant::now();
+    // Moving average of the cost of one tick, for the adaptive budget
+    let mut tick_cost = Duration::ZERO;
+
+    loop {
+        // Idle: block until a command arrives instead of spinning
+        if !running {
+            shared.running.store(false, Ordering::Release);
+            match commands.recv() {
+                Ok(command) => {
+                    if !apply(command, simulation, &mut running) {
+                        return;
+                    }
+                }
+                Err(_) => return,
+            }
+            last = Instant::now();
+            backlog = Duration::ZERO;
+            continue;
+        }
+
+        loop {
+            match commands.try_recv() {
+                Ok(command) => {
+                    if !apply(command, simulation, &mut running) {
+                        return;
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+        if !running {
+            continue;
+        }
+        shared.running.store(true, Ordering::Release);
+
+        // Fixed timestep: run the ticks that are due, within the budget
+        let now = Instant::now();
+        backlog += now - last;
+        last = now;
+        let due = (backlog.as_secs_f64() / dt.as_secs_f64()) as usize;
+        let budget = if tick_cost.is_zero() {
+            1
+        } else {
+            (config.step_budget.as_secs_f64() / tick_cost.as_secs_f64()) as usize
+        }
+        .clamp(1, config.max_ticks_per_step.max(1));
+        let count = due.min(budget);
+
+        if count > 0 {
+            let start = Instant::now();
+            for _ in 0..count {
+                simulation.tick();
+            }
+            let cost = start.elapsed() / count as u32;
+            tick_cost = if tick_cost.is_zero() {
+                cost
+            } else {
+                (tick_cost * 3 + cost) / 4
+            };
+            ticks += count as u64;
+            publish(shared, simulation, &mut back, ticks);
+
+            // Drop what can't be caught up instead of falling further behind
+            backlog = backlog.saturating_sub(dt * count as u32).min(dt);
+        }
+
+        if simulation.alpha < simulation.alpha_min && simulation.alpha_target < simulation.alpha_min
+        {
+            running = false;
+            continue;
+        }
+
+        if let Some(wait) = dt.checked_sub(backlog) {
+            std::thread::sleep(wait);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::force::{ForceCenter, ForceManyBody, SimulationNode};
+
+    fn wait_until(mut done: impl FnMut() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !done() {
+            assert!(Instant::now() < deadline, "timed out");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    fn spawn_graph(n: usize) -> SimulationWorker {
+        let config = SimulationWorkerConfig::default().tick_rate(10_000.0);
+        SimulationWorker::spawn(config, move || {
+            let nodes = (0..n)
+                .map(|i| SimulationNode::new(i, (i % 10) as f64, (i / 10) as f64))
+                .collect();
+            Simulation::new(nodes)
+                .force(Box::new(ForceManyBody::new()))
+                .force(Box::new(ForceCenter::new(0.0, 0.0)))
+        })
+    }
+
+    #[test]
+    fn test_worker_runs_to_convergence() {
+        let worker = spawn_graph(50);
+        assert_eq!(worker.positions().len(), 50);
+
+        wait_until(|| !worker.is_running());
+        assert!(worker.alpha() < 0.001);
+        assert!(worker.ticks() > 100);
+        let generation = worker.generation();
+
+        // Reheating publishes new frames again
+        worker.reheat(0.5);
+        wait_until(|| worker.generation() > generation);
+        wait_until(|| !worker.is_running());
+    }
+
+    #[test]
+    fn test_worker_fix_node() {
+        let worker = spawn_graph(20);
+        worker.fix_node(3, Some((100.0, -50.0)));
+        worker.set_alpha_target(0.3);
+
+        let generation = worker.generation();
+        wait_until(|| worker.generation() > generation + 2);
+        worker.with_positions(|positions| {
+            assert_eq!(positions[3], (100.0, -50.0));
+        });
+
+        worker.pause();
+        wait_until(|| !worker.is_running());
+    }
+}
@@ -0,0 +1,421 @@
+//! Projected path cache
+//!
+//! Projecting, resampling and simplifying world-scale geometry is far more
+//! expensive than drawing it. [`GeoPathCache`] keeps the projected
+//! polylines of each geometry and reuses them across frames:
+//!
+//! - Entries are keyed by a caller-chosen geometry id, the projection type,
+//!   its center and the path's simplification and resampling settings.
+//! - Translation and scale changes are applied to cached polylines exactly,
+//!   since every projection is `translate + scale * raw(λ, φ)`.
+//! - An entry is re-projected when the rotation moves by more than
+//!   [`rotation_threshold`](GeoPathCache::rotation_threshold) degrees, or the
+//!   scale by more than a [`zoom_threshold`](GeoPathCache::zoom_threshold)
+//!   ratio so simplification and resampling match the new level of detail.
+//!
+//! # Example
+//!
+//! ```rust
+//! use d3rs::geo::{GeoJsonGeometry, GeoPath, GeoPathCache, Orthographic};
+//! use d3rs::shape::simplify::Simplify;
+//!
+//! let world = GeoJsonGeometry::Polygon(vec![vec![
+//!     (-10.0, 40.0), (30.0, 40.0), (30.0, 60.0), (-10.0, 60.0), (-10.0, 40.0),
+//! ]]);
+//! let mut cache = GeoPathCache::new().rotation_threshold(0.5);
+//!
+//! for frame in 0..10 {
+//!     let projection = Orthographic::new()
+//!         .scale(200.0)
+//!         .translate(300.0, 200.0)
+//!         .rotate(frame as f64 * 0.01, 0.0, 0.0);
+//!     let path = GeoPath::new(projection)
+//!         .resample(0.5)
+//!         .simplify(Simplify::visvalingam(0.5));
+//!     let projected = cache.get(0, &path, &world);
+//!     assert_eq!(projected.runs.len(), 1);
+//! }
+//! assert_eq!(cache.stats().misses, 1);
+//! ```
+
+use super::path::{GeoJsonGeometry, GeoPath};
+use super::projection::Projection;
+use crate::shape::dash::dash_polyline;
+use crate::shape::simplify::SimplifyMethod;
+use std::collections::HashMap;
+
+/// One projected polyline
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProjectedRun {
+    /// Projected (x, y) vertices
+    pub points: Vec<(f64, f64)>,
+    /// Whether the run is a polygon ring to be closed
+    pub closed: bool,
+}
+
+/// A geometry projected to polylines
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProjectedPath {
+    /// Lines and ring pieces, split at antimeridian crossings and
+    /// unprojectable points
+    pub runs: Vec<ProjectedRun>,
+    /// Projected point features
+    pub points: Vec<(f64, f64)>,
+}
+
+impl ProjectedPath {
+    /// Total number of vertices
+    pub fn vertex_count(&self) -> usize {
+        self.runs.iter().map(|run| run.points.len()).sum::<usize>() + self.points.len()
+    }
+
+    /// Apply `x * scale + offset` to every vertex
+    fn transformed(&self, scale: f64, offset: (f64, f64)) -> Self {
+        let map = |&(x, y): &(f64, f64)| (x * scale + offset.0, y * scale + offset.1);
+        Self {
+            runs: self
+                .runs
+                .iter()
+                .map(|run| ProjectedRun {
+                    points: run.points.iter().map(map).collect(),
+                    closed: run.closed,
+                })
+                .collect(),
+            points: self.points.iter().map(map).collect(),
+        }
+    }
+
+    /// Cut the runs into dashes of `pattern`, in projected pixels
+    ///
+    /// Dash after simplification and after getting the path for the current
+    /// zoom from [`GeoPathCache`], so dashes keep their length on screen.
+    /// Rings are dashed through their closing edge; the dashes are open runs.
+    pub fn dashed(&self, pattern: &[f32]) -> Self {
+        let runs = self
+            .runs
+            .iter()
+            .flat_map(|run| {
+                let mut points: Vec<(f32, f32)> = run
+                    .points
+                    .iter()
+                    .map(|&(x, y)| (x as f32, y as f32))
+                    .collect();
+                if run.closed && points.len() > 2 {
+                    points.push(points[0]);
+                }
+                dash_polyline(&points, pattern)
+            })
+            .map(|dash| ProjectedRun {
+                points: dash
+                    .into_iter()
+                    .map(|(x, y)| (x as f64, y as f64))
+                    .collect(),
+                closed: false,
+            })
+            .collect();
+        Self {
+            runs,
+            points: self.points.clone(),
+        }
+    }
+
+    /// Render the runs as SVG path data (point features are omitted)
+    pub fn to_svg(&self, digits: usize) -> String {
+        let d = digits;
+        let mut path = String::new();
+        for run in &self.runs {
+            for (i, (x, y)) in run.points.iter().enumerate() {
+                let command = if i == 0 { 'M' } else { 'L' };
+                path.push_str(&format!("{command}{x:.d$},{y:.d$}"));
+            }
+            if run.closed {
+                path.push('Z');
+            }
+        }
+        path
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    id: u64,
+    projection: &'static str,
+    center: (u64, u64),
+    simplify: Option<(SimplifyMethod, u64)>,
+    resample: Option<u64>,
+}
+
+impl CacheKey {
+    fn new<P: Projection>(id: u64, path: &GeoPath<P>) -> Self {
+        let config = path.config();
+        let center = path.projection().center();
+        Self {
+            id,
+            projection: std::any::type_name::<P>(),
+            center: (center.0.to_bits(), center.1.to_bits()),
+            simplify: config
+                .simplify
+                .map(|simplify| (simplify.method, simplify.tolerance.to_bits())),
+            resample: config.resample.map(f64::to_bits),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    scale: f64,
+    translate: (f64, f64),
+    rotate: (f64, f64, f64),
+    path: ProjectedPath,
+}
+
+/// Hit statistics of a [`GeoPathCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GeoPathCacheStats {
+    /// Lookups answered from cached polylines
+    pub hits: u64,
+    /// Lookups that projected the geometry
+    pub misses: u64,
+    /// Geometries currently cached
+    pub entries: usize,
+}
+
+/// Cache of projected geometries
+#[derive(Debug)]
+pub struct GeoPathCache {
+    entries: HashMap<CacheKey, CacheEntry>,
+    rotation_threshold: f64,
+    zoom_threshold: f64,
+    hits: u64,
+    misses: u64,
+}
+
+impl Default for GeoPathCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GeoPathCache {
+    /// Create an empty cache.
+    ///
+    /// By default an entry is re-projected when the rotation changes by more
+    /// than 0.25° or the scale by more than 5%.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            rotation_threshold: 0.25,
+            zoom_threshold: 0.05,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Rotation change, in degrees on any axis, that invalidates an entry.
+    ///
+    /// Within the threshold the cached geometry is shown at its old rotation;
+    /// use 0 to re-project on every rotation change.
+    pub fn rotation_threshold(mut self, degrees: f64) -> Self {
+        self.rotation_threshold = degrees.max(0.0);
+        self
+    }
+
+    /// Relative scale change that invalidates an entry.
+    ///
+    /// Within the threshold cached polylines are rescaled, keeping the
+    /// simplification and resampling of the scale they were projected at.
+    pub fn zoom_threshold(mut self, ratio: f64) -> Self {
+        self.zoom_threshold = ratio.max(0.0);
+        self
+    }
+
+    /// Get the projected polylines of `geometry` drawn with `path`.
+    ///
+    /// `id` identifies the geometry: use a different id for every geometry
+    /// drawn through the same cache, and [`remove`](Self::remove) it when
+    /// the geometry changes.
+    pub fn get<P: Projection>(
+        &mut self,
+        id: u64,
+        path: &GeoPath<P>,
+        geometry: &GeoJsonGeometry,
+    ) -> ProjectedPath {
+        let projection = path.projection();
+        let scale = projection.scale();
+        let translate = projection.translate();
+        let rotate = projection.rotate();
+        let key = CacheKey::new(id, path);
+
+        let reusable = self
+            .entries
+            .get(&key)
+            .is_some_and(|entry| self.is_reusable(entry, scale, rotate));
+        if !reusable {
+            self.misses += 1;
+            let projected = path.project_path(geometry);
+            self.entries.insert(
+                key,
+                CacheEntry {
+                    scale,
+                    translate,
+                    rotate,
+                    path: projected.clone(),
+                },
+            );
+            return projected;
+        }
+
+        self.hits += 1;
+        let entry = &self.entries[&key];
+        if entry.scale == scale && entry.translate == translate {
+            return entry.path.clone();
+        }
+        let k = scale / entry.scale;
+        entry.path.transformed(
+            k,
+            (
+                translate.0 - entry.translate.0 * k,
+                translate.1 - entry.translate.1 * k,
+            ),
+        )
+    }
+
+    fn is_reusable(&self, entry: &CacheEntry, scale: f64, rotate: (f64, f64, f64)) -> bool {
+        let angle = |a: f64, b: f64| (a - b + 180.0).rem_euclid(360.0) - 180.0;
+        let rotation = angle(rotate.0, entry.rotate.0)
+            .abs()
+            .max(angle(rotate.1, entry.rotate.1).abs())
+            .max(angle(rotate.2, entry.rotate.2).abs());
+        entry.scale > 0.0
+            && rotation <= self.rotation_threshold
+            && (scale / entry.scale - 1.0).abs() <= self.zoom_threshold
+    }
+
+    /// Drop the cached projections of one geometry
+    pub fn remove(&mut self, id: u64) {
+        self.entries.retain(|key, _| key.id != id);
+    }
+
+    /// Drop all cached projections (statistics are kept)
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of cached projections
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Current hit statistics
+    pub fn stats(&self) -> GeoPathCacheStats {
+        GeoPathCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::{Mercator, Orthographic};
+    use crate::shape::simplify::Simplify;
+
+    fn square() -> GeoJsonGeometry {
+        GeoJsonGeometry::Polygon(vec![vec![
+            (-20.0, -10.0),
+            (20.0, -10.0),
+            (20.0, 10.0),
+            (-20.0, 10.0),
+            (-20.0, -10.0),
+        ]])
+    }
+
+    #[test]
+    fn test_pan_and_zoom_reuse_exactly() {
+        let geometry = square();
+        let mut cache = GeoPathCache::new();
+        let path = |scale: f64, tx: f64| {
+            GeoPath::new(Mercator::new().scale(scale).translate(tx, 200.0)).resample(0.5)
+        };
+
+        cache.get(1, &path(100.0, 300.0), &geometry);
+        let moved = cache.get(1, &path(104.0, 350.0), &geometry);
+        assert_eq!(cache.stats().misses, 1);
+        assert_eq!(cache.stats().hits, 1);
+
+        let fresh = path(104.0, 350.0).project_path(&geometry);
+        assert_eq!(moved.runs.len(), fresh.runs.len());
+        for (a, b) in moved.runs[0].points.iter().zip(&fresh.runs[0].points) {
+            assert!((a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9);
+        }
+
+        // Beyond the zoom threshold the geometry is projected again
+        cache.get(1, &path(120.0, 350.0), &geometry);
+        assert_eq!(cache.stats().misses, 2);
+    }
+
+    #[test]
+    fn test_rotation_threshold_and_keys() {
+        let geometry = square();
+        let mut cache = GeoPathCache::new().rotation_threshold(1.0);
+        let path = |lambda: f64| GeoPath::new(Orthographic::new().rotate(lambda, 0.0, 0.0));
+
+        cache.get(1, &path(0.0), &geometry);
+        cache.get(1, &path(0.5), &geometry);
+        cache.get(1, &path(-0.5), &geometry);
+        assert_eq!(cache.stats().misses, 1);
+        cache.get(1, &path(2.0), &geometry);
+        assert_eq!(cache.stats().misses, 2);
+        // Wrapping around ±180° is a small rotation
+        cache.get(1, &path(179.8), &geometry);
+        cache.get(1, &path(-179.8), &geometry);
+        assert_eq!(cache.stats().misses, 3);
+
+        // Other geometry ids and simplification levels are separate entries
+        cache.get(2, &path(-179.8), &geometry);
+        let simplified = path(-179.8).simplify(Simplify::douglas_peucker(1.0));
+        cache.get(1, &simplified, &geometry);
+        assert_eq!(cache.len(), 3);
+
+        cache.remove(1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_project_path_matches_render() {
+        let geometry = square();
+        let path = GeoPath::new(Mercator::new());
+        let projected = path.project_path(&geometry);
+        assert_eq!(projected.runs.len(), 1);
+        assert!(projected.runs[0].closed);
+        assert_eq!(projected.to_svg(3), path.render(&geometry));
+    }
+
+    #[test]
+    fn test_dashed_rings_include_closing_edge() {
+        let path = ProjectedPath {
+            runs: vec![ProjectedRun {
+                points: vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+                closed: true,
+            }],
+            points: vec![(5.0, 5.0)],
+        };
+        let dashed = path.dashed(&[10.0, 0.0]);
+        // A gap of zero joins the dashes back into one run around the ring
+        assert_eq!(dashed.runs.len(), 1);
+        assert_eq!(dashed.runs[0].points.last(), Some(&(0.0, 0.0)));
+        assert!(!dashed.runs[0].closed);
+        assert_eq!(dashed.points, path.points);
+
+        let dashed = path.dashed(&[5.0, 5.0]);
+        assert_eq!(dashed.runs.len(), 4);
+        assert_eq!(dashed.runs[3].points, vec![(0.0, 10.0), (0.0, 5.0)]);
+    }
+}
@@ -21,16 +21,20 @@
 //! }
 //! ```
 
+mod cache;
 mod graticule;
 mod path;
 pub mod projection;
+mod resample;
 
+pub use cache::{GeoPathCache, GeoPathCacheStats, ProjectedPath, ProjectedRun};
 pub use graticule::{Graticule, GraticuleConfig};
 pub use path::{GeoJsonGeometry, GeoPath, GeoPathConfig};
 pub use projection::{
     Albers, ConicEqualArea, Equirectangular, Mercator, Orthographic, Projection, Stereographic,
     TransverseMercator,
 };
+pub use resample::resample_coords;
 
 use std::f64::consts::PI;
 
@@ -3,7 +3,12 @@
 //! This module provides functionality for rendering GeoJSON features
 //! to SVG path strings or other path representations.
 
+use super::cache::{ProjectedPath, ProjectedRun};
 use super::projection::Projection;
+use super::resample::resample_coords;
+use crate::shape::path::Point;
+use crate::shape::simplify::Simplify;
+use std::borrow::Cow;
 
 /// Configuration for GeoPath rendering.
 #[derive(Clone, Debug)]
@@ -12,6 +17,10 @@ pub struct GeoPathConfig {
     pub digits: usize,
     /// Radius for point features
     pub point_radius: f64,
+    /// Simplification of lines and rings in projected coordinates
+    pub simplify: Option<Simplify>,
+    /// Adaptive resampling precision of projected edges, in pixels
+    pub resample: Option<f64>,
 }
 
 impl Default for GeoPathConfig {
@@ -19,6 +28,8 @@ impl Default for GeoPathConfig {
         Self {
             digits: 3,
             point_radius: 4.5,
+            simplify: None,
+            resample: None,
         }
     }
 }
@@ -71,6 +82,30 @@ impl<P: Projection> GeoPath<P> {
         self
     }
 
+    /// Simplify lines and polygon rings after projection.
+    ///
+    /// The tolerance is in projected units (pixels), so detailed boundaries
+    /// lose vertices only where they would not be visible at the current scale.
+    pub fn simplify(mut self, simplify: Simplify) -> Self {
+        self.config.simplify = Some(simplify);
+        self
+    }
+
+    /// Resample edges along great circles before projecting.
+    ///
+    /// Points are inserted only where a projected edge deviates from a
+    /// straight segment by more than `precision` pixels. See
+    /// [`resample_coords`](super::resample_coords).
+    pub fn resample(mut self, precision: f64) -> Self {
+        self.config.resample = Some(precision);
+        self
+    }
+
+    /// Get the rendering configuration.
+    pub fn config(&self) -> &GeoPathConfig {
+        &self.config
+    }
+
     /// Get a reference to the projection.
     pub fn projection(&self) -> &P {
         &self.projection
@@ -133,6 +168,8 @@ impl<P: Projection> GeoPath<P> {
         let d = self.config.digits;
         let mut path = String::new();
         let mut prev_lon: Option<f64> = None;
+        let coords = self.resampled(coords);
+        let keep = self.simplify_mask(&coords);
 
         for (i, &(lon, lat)) in coords.iter().enumerate() {
             let (x, y) = self.projection.project(lon, lat);
@@ -150,13 +187,16 @@ impl<P: Projection> GeoPath<P> {
                 false
             };
 
+            prev_lon = Some(lon);
+            if keep.as_ref().is_some_and(|keep| !keep[i]) {
+                continue;
+            }
+
             if i == 0 || crosses_antimeridian {
                 path.push_str(&format!("M{:.d$},{:.d$}", x, y, d = d));
             } else {
                 path.push_str(&format!("L{:.d$},{:.d$}", x, y, d = d));
             }
-
-            prev_lon = Some(lon);
         }
 
         path
@@ -187,6 +227,8 @@ impl<P: Projection> GeoPath<P> {
 
             let mut prev_lon: Option<f64> = None;
             let mut ring_started = false;
+            let ring = self.resampled(ring);
+            let keep = self.simplify_mask(&ring);
 
             for (i, &(lon, lat)) in ring.iter().enumerate() {
                 let (x, y) = self.projection.project(lon, lat);
@@ -208,6 +250,11 @@ impl<P: Projection> GeoPath<P> {
                     false
                 };
 
+                prev_lon = Some(lon);
+                if keep.as_ref().is_some_and(|keep| !keep[i]) {
+                    continue;
+                }
+
                 if i == 0 || crosses_antimeridian {
                     // Close previous segment if we're breaking due to antimeridian
                     if ring_started && crosses_antimeridian {
@@ -218,8 +265,6 @@ impl<P: Projection> GeoPath<P> {
                 } else {
                     path.push_str(&format!("L{:.d$},{:.d$}", x, y, d = d));
                 }
-
-                prev_lon = Some(lon);
             }
 
             // Close the ring
@@ -231,6 +276,52 @@ impl<P: Projection> GeoPath<P> {
         path
     }
 
+    /// Coordinates with resampled edges, borrowed when resampling is disabled.
+    fn resampled<'a>(&self, coords: &'a [(f64, f64)]) -> Cow<'a, [(f64, f64)]> {
+        match self.config.resample {
+            Some(precision) => Cow::Owned(resample_coords(&self.projection, coords, precision)),
+            None => Cow::Borrowed(coords),
+        }
+    }
+
+    /// Which coordinates survive simplification, or `None` when disabled.
+    ///
+    /// Coordinates are simplified in projected space, run by run: invalid
+    /// projections and antimeridian crossings split runs, and run endpoints
+    /// are always kept so the rendered breaks don't move.
+    fn simplify_mask(&self, coords: &[(f64, f64)]) -> Option<Vec<bool>> {
+        let simplify = self.config.simplify?;
+        let mut keep = vec![false; coords.len()];
+        let mut run: Vec<Point> = Vec::new();
+        let mut run_start = 0;
+        let mut prev_lon: Option<f64> = None;
+
+        let flush = |run: &mut Vec<Point>, start: usize, keep: &mut [bool]| {
+            for i in simplify.indices(run) {
+                keep[start + i] = true;
+            }
+            run.clear();
+        };
+
+        for (i, &(lon, lat)) in coords.iter().enumerate() {
+            let (x, y) = self.projection.project(lon, lat);
+            if !x.is_finite() || !y.is_finite() {
+                flush(&mut run, run_start, &mut keep);
+                prev_lon = None;
+                continue;
+            }
+            if prev_lon.is_some_and(|prev| (lon - prev).abs() > 180.0) || run.is_empty() {
+                flush(&mut run, run_start, &mut keep);
+                run_start = i;
+            }
+            run.push(Point::new(x, y));
+            prev_lon = Some(lon);
+        }
+        flush(&mut run, run_start, &mut keep);
+
+        Some(keep)
+    }
+
     /// Render multiple polygons.
     fn render_multi_polygon(&self, polygons: &[Vec<Vec<(f64, f64)>>]) -> String {
         polygons
@@ -240,6 +331,86 @@ impl<P: Projection> GeoPath<P> {
             .join("")
     }
 
+    /// Project a geometry to polylines instead of an SVG string.
+    ///
+    /// Lines and rings are resampled, split and simplified exactly as in
+    /// [`render`](Self::render); polygon rings become closed runs. Point
+    /// features are projected to [`ProjectedPath::points`].
+    pub fn project_path(&self, geometry: &GeoJsonGeometry) -> ProjectedPath {
+        let mut projected = ProjectedPath::default();
+        match geometry {
+            GeoJsonGeometry::Point(lon, lat) => {
+                self.push_point(*lon, *lat, &mut projected);
+            }
+            GeoJsonGeometry::MultiPoint(points) => {
+                for &(lon, lat) in points {
+                    self.push_point(lon, lat, &mut projected);
+                }
+            }
+            GeoJsonGeometry::LineString(coords) => {
+                self.push_runs(coords, false, &mut projected);
+            }
+            GeoJsonGeometry::MultiLineString(lines) => {
+                for line in lines {
+                    self.push_runs(line, false, &mut projected);
+                }
+            }
+            GeoJsonGeometry::Polygon(rings) => {
+                for ring in rings {
+                    self.push_runs(ring, true, &mut projected);
+                }
+            }
+            GeoJsonGeometry::MultiPolygon(polygons) => {
+                for ring in polygons.iter().flatten() {
+                    self.push_runs(ring, true, &mut projected);
+                }
+            }
+        }
+        projected
+    }
+
+    fn push_point(&self, lon: f64, lat: f64, projected: &mut ProjectedPath) {
+        let (x, y) = self.projection.project(lon, lat);
+        if x.is_finite() && y.is_finite() {
+            projected.points.push((x, y));
+        }
+    }
+
+    /// Append the runs of one line or ring.
+    fn push_runs(&self, coords: &[(f64, f64)], closed: bool, projected: &mut ProjectedPath) {
+        let coords = self.resampled(coords);
+        let keep = self.simplify_mask(&coords);
+        let mut points = Vec::new();
+        let mut prev_lon: Option<f64> = None;
+
+        let mut flush = |points: &mut Vec<(f64, f64)>| {
+            if !points.is_empty() {
+                projected.runs.push(ProjectedRun {
+                    points: std::mem::take(points),
+                    closed,
+                });
+            }
+        };
+
+        for (i, &(lon, lat)) in coords.iter().enumerate() {
+            let (x, y) = self.projection.project(lon, lat);
+            if !x.is_finite() || !y.is_finite() {
+                flush(&mut points);
+                prev_lon = None;
+                continue;
+            }
+            if prev_lon.is_some_and(|prev| (lon - prev).abs() > 180.0) {
+                flush(&mut points);
+            }
+            prev_lon = Some(lon);
+            if keep.as_ref().is_some_and(|keep| !keep[i]) {
+                continue;
+            }
+            points.push((x, y));
+        }
+        flush(&mut points);
+    }
+
     /// Render coordinates to a vector of projected (x, y) points.
     pub fn project_coords(&self, coords: &[(f64, f64)]) -> Vec<(f64, f64)> {
         coords
@@ -343,6 +514,24 @@ mod tests {
         assert!(svg.contains('L'));
     }
 
+    #[test]
+    fn test_geo_path_simplify() {
+        let proj = Equirectangular::new().scale(100.0).translate(0.0, 0.0);
+        // Dense, nearly straight line with one bend
+        let mut coords: Vec<(f64, f64)> = (0..=100).map(|i| (i as f64 * 0.1, 0.0)).collect();
+        coords.extend((1..=100).map(|i| (10.0, i as f64 * 0.1)));
+        let geometry = GeoJsonGeometry::LineString(coords);
+
+        let full = GeoPath::new(proj.clone()).render(&geometry);
+        let simplified = GeoPath::new(proj)
+            .simplify(Simplify::douglas_peucker(0.5))
+            .render(&geometry);
+
+        assert_eq!(full.matches('L').count(), 200);
+        assert_eq!(simplified.matches('L').count(), 2);
+        assert!(simplified.starts_with('M'));
+    }
+
     #[test]
     fn test_geo_path_polygon() {
         let proj = Equirectangular::new().scale(100.0).translate(0.0, 0.0);
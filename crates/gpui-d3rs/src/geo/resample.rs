@@ -0,0 +1,161 @@
+//! Adaptive resampling of projected edges
+//!
+//! Geographic edges are great-circle arcs, but paths draw them as straight
+//! segments between projected vertices. On curved projections (orthographic,
+//! conic) long edges then visibly cut corners. Resampling inserts points
+//! along the arc, subdividing recursively only where the projected arc
+//! midpoint is farther than a precision (in pixels) from the straight
+//! segment, so nearly straight edges gain no vertices.
+
+use super::geo_interpolate;
+use super::projection::Projection;
+
+/// Maximum number of recursive subdivisions of one edge
+const MAX_DEPTH: u32 = 16;
+
+/// Resample a line of (longitude, latitude) coordinates for a projection.
+///
+/// Returns the coordinates with great-circle points inserted wherever the
+/// projected edge deviates from a straight segment by more than `precision`
+/// pixels. Edges crossing the antimeridian or with an endpoint that does not
+/// project are left alone. A non-positive precision returns the input.
+pub fn resample_coords<P: Projection>(
+    projection: &P,
+    coords: &[(f64, f64)],
+    precision: f64,
+) -> Vec<(f64, f64)> {
+    if coords.len() < 2 || precision.is_nan() || precision <= 0.0 {
+        return coords.to_vec();
+    }
+
+    let delta_sq = precision * precision;
+    let mut resampled = Vec::with_capacity(coords.len());
+    resampled.push(coords[0]);
+    for edge in coords.windows(2) {
+        let (a, b) = (edge[0], edge[1]);
+        if (b.0 - a.0).abs() <= 180.0 {
+            let pa = projection.project(a.0, a.1);
+            let pb = projection.project(b.0, b.1);
+            if is_finite(pa) && is_finite(pb) {
+                subdivide(
+                    projection,
+                    (a, pa),
+                    (b, pb),
+                    delta_sq,
+                    MAX_DEPTH,
+                    &mut resampled,
+                );
+            }
+        }
+        resampled.push(b);
+    }
+    resampled
+}
+
+type Vertex = ((f64, f64), (f64, f64));
+
+/// Push the points needed between `a` and `b` (both excluded).
+fn subdivide<P: Projection>(
+    projection: &P,
+    (a, pa): Vertex,
+    (b, pb): Vertex,
+    delta_sq: f64,
+    depth: u32,
+    out: &mut Vec<(f64, f64)>,
+) {
+    let (dx, dy) = (pb.0 - pa.0, pb.1 - pa.1);
+    let length_sq = dx * dx + dy * dy;
+    if depth == 0 || length_sq <= 4.0 * delta_sq {
+        return;
+    }
+
+    let mut m = geo_interpolate(a.0, a.1, b.0, b.1, 0.5);
+    // Keep the midpoint on the same side of the antimeridian as `a`
+    if m.0 - a.0 > 180.0 {
+        m.0 -= 360.0;
+    } else if a.0 - m.0 > 180.0 {
+        m.0 += 360.0;
+    }
+    let pm = projection.project(m.0, m.1);
+    if !is_finite(pm) {
+        return;
+    }
+
+    // Distance of the projected midpoint from the segment, and its position
+    // along it: a midpoint far from the middle also means distortion
+    let (mx, my) = (pm.0 - pa.0, pm.1 - pa.1);
+    let cross = dy * mx - dx * my;
+    let along = (mx * dx + my * dy) / length_sq;
+    if cross * cross / length_sq > delta_sq || (along - 0.5).abs() > 0.3 {
+        subdivide(projection, (a, pa), (m, pm), delta_sq, depth - 1, out);
+        out.push(m);
+        subdivide(projection, (m, pm), (b, pb), delta_sq, depth - 1, out);
+    }
+}
+
+fn is_finite((x, y): (f64, f64)) -> bool {
+    x.is_finite() && y.is_finite()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::{Equirectangular, Orthographic};
+
+    #[test]
+    fn test_resample_follows_great_circle() {
+        let projection = Equirectangular::new().scale(100.0);
+        let line = [(-60.0, 50.0), (60.0, 50.0)];
+
+        let resampled = resample_coords(&projection, &line, 0.5);
+        assert!(resampled.len() > 10);
+        assert_eq!(resampled.first(), line.first());
+        assert_eq!(resampled.last(), line.last());
+
+        // The great circle between two points on a parallel bends poleward
+        let top = resampled
+            .iter()
+            .map(|&(_, lat)| lat)
+            .fold(f64::MIN, f64::max);
+        assert!(top > 60.0);
+
+        // Longitudes stay ordered
+        assert!(resampled.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_resample_adapts_to_precision() {
+        let projection = Orthographic::new().scale(200.0);
+        let line = [(-40.0, 10.0), (40.0, 30.0)];
+
+        let coarse = resample_coords(&projection, &line, 5.0);
+        let fine = resample_coords(&projection, &line, 0.1);
+        assert!(fine.len() > coarse.len());
+
+        // Every resampled edge is short or nearly straight
+        for edge in fine.windows(2) {
+            let pa = projection.project(edge[0].0, edge[0].1);
+            let pb = projection.project(edge[1].0, edge[1].1);
+            let m = geo_interpolate(edge[0].0, edge[0].1, edge[1].0, edge[1].1, 0.5);
+            let pm = projection.project(m.0, m.1);
+            let (cx, cy) = ((pa.0 + pb.0) / 2.0, (pa.1 + pb.1) / 2.0);
+            assert!(((pm.0 - cx).powi(2) + (pm.1 - cy).powi(2)).sqrt() < 0.5);
+        }
+    }
+
+    #[test]
+    fn test_resample_skips_short_and_crossing_edges() {
+        let projection = Equirectangular::new().scale(100.0);
+
+        // Edges shorter than two pixels are never split
+        let short = [(0.0, 0.0), (0.5, 0.5)];
+        assert_eq!(resample_coords(&projection, &short, 0.5), short);
+
+        // Antimeridian crossings are left to the path renderer
+        let crossing = [(170.0, 60.0), (-170.0, 60.0)];
+        assert_eq!(resample_coords(&projection, &crossing, 0.5), crossing);
+
+        let line = [(-60.0, 50.0), (60.0, 50.0)];
+        assert_eq!(resample_coords(&projection, &line, 0.0), line);
+    }
+}
@@ -1,7 +1,7 @@
 //! GPU-accelerated 2D chart rendering module
 //!
 //! This module provides hardware-accelerated rendering for 2D charts including:
-//! - Lines with configurable width and anti-aliasing
+//! - Lines and polylines with configurable width, dash patterns and anti-aliasing
 //! - Rectangles with optional rounded corners
 //! - Circles/points with smooth edges
 //! - Text rendering via font atlas
@@ -10,6 +10,14 @@
 //!
 //! The module follows the same pattern as `surface3d`: render to a wgpu texture,
 //! copy pixels back, and paint via GPUI's `window.paint_image()`.
+//! [`ChartTexture`] renders the same way offscreen and caches the image for
+//! embedding with `gpui::img`.
+//!
+//! All primitives of a kind go into a single vertex batch drawn with one
+//! draw call per frame. The chart functions prepare as much as possible
+//! outside of paint: contour bands are triangulated once, and heatmap cells
+//! of identical color are merged into larger quads
+//! ([`primitives::merge_grid_cells`]).
 //!
 //! # Example
 //!
@@ -29,6 +37,7 @@ mod element;
 mod renderer;
 mod shaders;
 mod shapes;
+mod texture;
 
 pub mod primitives;
 pub mod text;
@@ -36,6 +45,7 @@ pub mod text;
 pub use device::Gpu2DContext;
 pub use element::Chart2DElement;
 pub use renderer::Chart2DRenderer;
+pub use texture::{ChartTexture, encode_png};
 
 // GPU-accelerated shape rendering functions
 pub use shapes::{
@@ -66,6 +76,7 @@ pub use shapes::{
     render_contour_bands,
     render_grid,
     render_heatmap,
+    render_heatmap_in_range,
     render_line,
     render_scatter,
     turbo_color_scale,
@@ -86,6 +86,12 @@ impl CircleBatch {
         ]);
     }
 
+    /// Reserve room for `additional` more circles
+    pub fn reserve(&mut self, additional: usize) {
+        self.vertices.reserve(additional * 4);
+        self.indices.reserve(additional * 6);
+    }
+
     pub fn is_empty(&self) -> bool {
         self.vertices.is_empty()
     }
@@ -0,0 +1,70 @@
+//! Quad merging for grid-based charts
+
+use super::Color4;
+use std::collections::HashMap;
+
+/// A rectangle of grid cells sharing one color
+///
+/// Cell ranges are half-open: the rectangle covers columns `x0..x1` and
+/// rows `y0..y1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MergedCell {
+    pub x0: usize,
+    pub x1: usize,
+    pub y0: usize,
+    pub y1: usize,
+    pub color: Color4,
+}
+
+/// Merge adjacent grid cells of identical color into larger rectangles
+///
+/// `colors` is a row-major `width * height` grid; `None` cells are not
+/// drawn. Equal-colored runs are first merged along each row, then runs
+/// with the same columns and color are extended down across rows. Heatmaps
+/// with plateaus or quantized color scales shrink to a fraction of their
+/// cell count, and the output never has more rectangles than input cells.
+pub fn merge_grid_cells(width: usize, height: usize, colors: &[Option<Color4>]) -> Vec<MergedCell> {
+    debug_assert_eq!(colors.len(), width * height);
+
+    let mut merged: Vec<MergedCell> = Vec::new();
+    // Rectangles that reached the previous row, keyed by their column span
+    let mut open: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut next_open: HashMap<(usize, usize), usize> = HashMap::new();
+
+    for y in 0..height {
+        let row = &colors[y * width..(y + 1) * width];
+        let mut x = 0;
+        while x < width {
+            let Some(color) = row[x] else {
+                x += 1;
+                continue;
+            };
+            let start = x;
+            while x < width && row[x] == Some(color) {
+                x += 1;
+            }
+
+            let span = (start, x);
+            match open.get(&span) {
+                Some(&idx) if merged[idx].color == color => {
+                    merged[idx].y1 = y + 1;
+                    next_open.insert(span, idx);
+                }
+                _ => {
+                    next_open.insert(span, merged.len());
+                    merged.push(MergedCell {
+                        x0: start,
+                        x1: x,
+                        y0: y,
+                        y1: y + 1,
+                        color,
+                    });
+                }
+            }
+        }
+        std::mem::swap(&mut open, &mut next_open);
+        next_open.clear();
+    }
+
+    merged
+}
@@ -2,13 +2,15 @@
 
 mod circle;
 mod line;
+mod merge;
 mod rect;
 mod triangle;
 
 pub use circle::{CircleBatch, CircleVertex};
 pub use line::{LineBatch, LineVertex};
+pub use merge::{MergedCell, merge_grid_cells};
 pub use rect::{RectBatch, RectVertex};
-pub use triangle::{TriangleBatch, TriangleVertex};
+pub use triangle::{TriangleBatch, TriangleVertex, triangulate_indices};
 
 /// A color represented as RGBA floats [0.0, 1.0]
 pub type Color4 = [f32; 4];
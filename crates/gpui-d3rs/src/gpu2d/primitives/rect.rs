@@ -119,6 +119,12 @@ impl RectBatch {
         ]);
     }
 
+    /// Reserve room for `additional` more rectangles
+    pub fn reserve(&mut self, additional: usize) {
+        self.vertices.reserve(additional * 4);
+        self.indices.reserve(additional * 6);
+    }
+
     pub fn is_empty(&self) -> bool {
         self.vertices.is_empty()
     }
@@ -61,11 +61,45 @@ impl TriangleBatch {
         }
 
         // Simple ear clipping triangulation
-        let triangles = triangulate_polygon(points);
+        let indices = triangulate_indices(points);
+        self.add_mesh(points.iter().copied(), &indices, color);
+    }
 
-        for tri in triangles {
-            self.add_triangle(tri[0], tri[1], tri[2], color);
-        }
+    /// Add an already triangulated mesh
+    ///
+    /// `indices` refer to `vertices` (three per triangle). Use with
+    /// [`triangulate_indices`] to triangulate a polygon once and redraw it
+    /// every frame without clipping ears again.
+    pub fn add_mesh(
+        &mut self,
+        vertices: impl IntoIterator<Item = [f32; 2]>,
+        indices: &[u32],
+        color: Color4,
+    ) {
+        let base = self.vertices.len() as u32;
+        self.vertices.extend(
+            vertices
+                .into_iter()
+                .map(|position| TriangleVertex::new(position, color)),
+        );
+        self.indices.extend(indices.iter().map(|i| base + i));
+    }
+
+    /// Add a triangulated mesh with a color per vertex
+    ///
+    /// Colors are blended linearly across each triangle.
+    pub fn add_shaded_mesh(
+        &mut self,
+        vertices: impl IntoIterator<Item = ([f32; 2], Color4)>,
+        indices: &[u32],
+    ) {
+        let base = self.vertices.len() as u32;
+        self.vertices.extend(
+            vertices
+                .into_iter()
+                .map(|(position, color)| TriangleVertex::new(position, color)),
+        );
+        self.indices.extend(indices.iter().map(|i| base + i));
     }
 
     pub fn vertex_bytes(&self) -> &[u8] {
@@ -83,21 +117,26 @@ impl Default for TriangleBatch {
     }
 }
 
-/// Simple ear clipping triangulation for convex/simple polygons
-fn triangulate_polygon(points: &[[f32; 2]]) -> Vec<[[f32; 2]; 3]> {
+/// Triangulate a simple polygon with ear clipping
+///
+/// Returns indices into `points`, three per triangle. The triangulation
+/// only depends on the polygon's shape up to positive axis scaling, so it
+/// can be computed once in relative coordinates and reused at any size.
+pub fn triangulate_indices(points: &[[f32; 2]]) -> Vec<u32> {
     if points.len() < 3 {
         return Vec::new();
     }
 
     if points.len() == 3 {
-        return vec![[points[0], points[1], points[2]]];
+        return vec![0, 1, 2];
     }
 
     // For simple/convex polygons, fan triangulation works
     // For complex polygons, we use ear clipping
 
-    let mut result = Vec::with_capacity(points.len() - 2);
-    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut result = Vec::with_capacity((points.len() - 2) * 3);
+    let mut remaining: Vec<u32> = (0..points.len() as u32).collect();
+    let at = |i: u32| points[i as usize];
 
     // Check if polygon is clockwise and reverse if needed
     let area = signed_polygon_area(points);
@@ -114,9 +153,9 @@ fn triangulate_polygon(points: &[[f32; 2]]) -> Vec<[[f32; 2]; 3]> {
             let prev_idx = if i == 0 { remaining.len() - 1 } else { i - 1 };
             let next_idx = (i + 1) % remaining.len();
 
-            let prev = points[remaining[prev_idx]];
-            let curr = points[remaining[i]];
-            let next = points[remaining[next_idx]];
+            let prev = at(remaining[prev_idx]);
+            let curr = at(remaining[i]);
+            let next = at(remaining[next_idx]);
 
             // Check if this is a convex vertex (ear candidate)
             if !is_convex(prev, curr, next) {
@@ -129,7 +168,7 @@ fn triangulate_polygon(points: &[[f32; 2]]) -> Vec<[[f32; 2]; 3]> {
                 if j == prev_idx || j == i || j == next_idx {
                     continue;
                 }
-                let p = points[remaining[j]];
+                let p = at(remaining[j]);
                 if point_in_triangle(p, prev, curr, next) {
                     is_ear = false;
                     break;
@@ -137,7 +176,7 @@ fn triangulate_polygon(points: &[[f32; 2]]) -> Vec<[[f32; 2]; 3]> {
             }
 
             if is_ear {
-                result.push([prev, curr, next]);
+                result.extend_from_slice(&[remaining[prev_idx], remaining[i], remaining[next_idx]]);
                 remaining.remove(i);
                 found_ear = true;
                 break;
@@ -152,16 +191,12 @@ fn triangulate_polygon(points: &[[f32; 2]]) -> Vec<[[f32; 2]; 3]> {
 
     // Handle remaining triangle
     if remaining.len() == 3 {
-        result.push([
-            points[remaining[0]],
-            points[remaining[1]],
-            points[remaining[2]],
-        ]);
+        result.extend_from_slice(&remaining);
     } else if remaining.len() > 3 {
         // Fallback: fan triangulation from first vertex
-        let first = points[remaining[0]];
+        let first = remaining[0];
         for i in 1..remaining.len() - 1 {
-            result.push([first, points[remaining[i]], points[remaining[i + 1]]]);
+            result.extend_from_slice(&[first, remaining[i], remaining[i + 1]]);
         }
     }
 
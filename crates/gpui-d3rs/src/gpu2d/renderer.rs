@@ -7,6 +7,7 @@ use super::primitives::{
 };
 use super::shaders;
 use super::text::{TextAtlas, TextBatch, TextVertex};
+use crate::shape::dash::dash_polyline;
 use bytemuck::{Pod, Zeroable};
 use std::sync::Arc;
 use wgpu::util::DeviceExt;
@@ -522,6 +523,34 @@ impl Chart2DRenderer {
         self.line_batch.add_line(x0, y0, x1, y1, width, color);
     }
 
+    /// Draw a polyline through `points`
+    pub fn draw_polyline(&mut self, points: &[[f32; 2]], width: f32, color: Color4) {
+        for pair in points.windows(2) {
+            let ([x0, y0], [x1, y1]) = (pair[0], pair[1]);
+            self.line_batch.add_line(x0, y0, x1, y1, width, color);
+        }
+    }
+
+    /// Draw a polyline through `points`, dashed with alternating dash and gap
+    /// lengths in pixels
+    ///
+    /// See [`crate::shape::dash`] for how patterns are interpreted.
+    pub fn draw_dashed_polyline(
+        &mut self,
+        points: &[[f32; 2]],
+        width: f32,
+        pattern: &[f32],
+        color: Color4,
+    ) {
+        let points: Vec<(f32, f32)> = points.iter().map(|&[x, y]| (x, y)).collect();
+        for dash in dash_polyline(&points, pattern) {
+            for pair in dash.windows(2) {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                self.line_batch.add_line(x0, y0, x1, y1, width, color);
+            }
+        }
+    }
+
     /// Draw a rectangle
     pub fn draw_rect(&mut self, rect: Rect, color: Color4, corner_radius: f32) {
         self.rect_batch.add_rect(rect, color, corner_radius);
@@ -532,6 +561,45 @@ impl Chart2DRenderer {
         self.circle_batch.add_circle(cx, cy, radius, color);
     }
 
+    /// Draw many rectangles sharing a corner radius in one batch
+    pub fn draw_rects(&mut self, rects: &[(Rect, Color4)], corner_radius: f32) {
+        self.rect_batch.reserve(rects.len());
+        for &(rect, color) in rects {
+            self.rect_batch.add_rect(rect, color, corner_radius);
+        }
+    }
+
+    /// Draw many circles of the same radius and color in one batch
+    pub fn draw_circles(&mut self, centers: &[[f32; 2]], radius: f32, color: Color4) {
+        self.circle_batch.reserve(centers.len());
+        for &[cx, cy] in centers {
+            self.circle_batch.add_circle(cx, cy, radius, color);
+        }
+    }
+
+    /// Draw scatter markers, each with an optional outline behind its fill
+    ///
+    /// `stroke` is `(stroke_width, stroke_color)`. Outline and fill are
+    /// interleaved per marker so overlapping markers stack like individual
+    /// draws, but everything goes into a single batch.
+    pub fn draw_markers(
+        &mut self,
+        centers: &[[f32; 2]],
+        radius: f32,
+        fill: Color4,
+        stroke: Option<(f32, Color4)>,
+    ) {
+        let per_marker = if stroke.is_some() { 2 } else { 1 };
+        self.circle_batch.reserve(centers.len() * per_marker);
+        for &[cx, cy] in centers {
+            if let Some((stroke_width, stroke_color)) = stroke {
+                self.circle_batch
+                    .add_circle(cx, cy, radius + stroke_width, stroke_color);
+            }
+            self.circle_batch.add_circle(cx, cy, radius, fill);
+        }
+    }
+
     /// Draw a single triangle
     pub fn draw_triangle(&mut self, p0: [f32; 2], p1: [f32; 2], p2: [f32; 2], color: Color4) {
         self.triangle_batch.add_triangle(p0, p1, p2, color);
@@ -542,6 +610,30 @@ impl Chart2DRenderer {
         self.triangle_batch.add_polygon(points, color);
     }
 
+    /// Draw a pre-triangulated mesh (see [`triangulate_indices`])
+    ///
+    /// [`triangulate_indices`]: super::primitives::triangulate_indices
+    pub fn draw_mesh(
+        &mut self,
+        vertices: impl IntoIterator<Item = [f32; 2]>,
+        indices: &[u32],
+        color: Color4,
+    ) {
+        self.triangle_batch.add_mesh(vertices, indices, color);
+    }
+
+    /// Draw a pre-triangulated mesh with a color per vertex
+    ///
+    /// Colors are blended linearly across each triangle, e.g. to draw a
+    /// smoothly upsampled heatmap.
+    pub fn draw_shaded_mesh(
+        &mut self,
+        vertices: impl IntoIterator<Item = ([f32; 2], Color4)>,
+        indices: &[u32],
+    ) {
+        self.triangle_batch.add_shaded_mesh(vertices, indices);
+    }
+
     /// Draw text at the given position
     pub fn draw_text(&mut self, text: &str, x: f32, y: f32, size: f32, color: Color4) {
         let atlas = match &mut self.text_atlas {
@@ -3,9 +3,11 @@
 //! These functions mirror the API of src/shape/*.rs but use GPU rendering.
 
 use super::element::Chart2DElement;
-use super::primitives::{Color4, Rect};
+use super::primitives::{Color4, Rect, merge_grid_cells, triangulate_indices};
 use crate::color::D3Color;
+use crate::interpolate::{GridInterpolation, grid_coordinate, sample_grid};
 use crate::scale::Scale;
+use crate::shape::dash::dash_segments;
 use gpui::*;
 
 use std::sync::Arc;
@@ -70,18 +72,14 @@ where
         let width: f32 = bounds.size.width.into();
         let height: f32 = bounds.size.height.into();
 
-        for &(x_rel, y_rel) in &points {
-            let cx = x_rel * width;
-            let cy = y_rel * height;
-
-            // Draw stroke circle first (larger, behind)
-            if let Some(stroke) = stroke_color {
-                renderer.draw_circle(cx, cy, radius + stroke_width, stroke);
-            }
+        let centers: Vec<[f32; 2]> = points
+            .iter()
+            .map(|&(x_rel, y_rel)| [x_rel * width, y_rel * height])
+            .collect();
 
-            // Draw fill circle
-            renderer.draw_circle(cx, cy, radius, fill_color);
-        }
+        // Stroke circle (larger, behind) then fill, per marker
+        let stroke = stroke_color.map(|color| (stroke_width, color));
+        renderer.draw_markers(&centers, radius, fill_color, stroke);
     })
     .transparent()
     .absolute()
@@ -308,6 +306,21 @@ where
             }
             segs
         }
+        CurveType::MonotoneX => {
+            use crate::shape::{Curve, Point};
+            let points: Vec<Point> = relative_points
+                .iter()
+                .map(|&(x, y)| Point::new(x as f64, y as f64))
+                .collect();
+            Curve::MonotoneX
+                .interpolate(&points)
+                .windows(2)
+                .filter_map(|pair| {
+                    let (p0, p1) = (pair[0], pair[1]);
+                    clip_line_segment(p0.x as f32, p0.y as f32, p1.x as f32, p1.y as f32)
+                })
+                .collect()
+        }
         CurveType::StepBefore => {
             let mut segs = Vec::new();
             for i in 1..relative_points.len() {
@@ -327,6 +340,7 @@ where
 
     let stroke_color = to_color4(&config.stroke_color, config.opacity);
     let stroke_width = config.stroke_width;
+    let dash = config.stroke_dash.clone();
     let show_points = config.show_points;
     let point_radius = config.point_radius;
     let point_color = config
@@ -339,16 +353,18 @@ where
         let width: f32 = bounds.size.width.into();
         let height: f32 = bounds.size.height.into();
 
-        // Draw line segments
-        for &(x0, y0, x1, y1) in &segments {
-            renderer.draw_line(
-                x0 * width,
-                y0 * height,
-                x1 * width,
-                y1 * height,
-                stroke_width,
-                stroke_color,
-            );
+        // Draw line segments, dashed in pixels so dashes keep their length
+        // when the chart is resized
+        let pixel_segments: Vec<(f32, f32, f32, f32)> = segments
+            .iter()
+            .map(|&(x0, y0, x1, y1)| (x0 * width, y0 * height, x1 * width, y1 * height))
+            .collect();
+        let pixel_segments = match &dash {
+            Some(pattern) => dash_segments(&pixel_segments, pattern),
+            None => pixel_segments,
+        };
+        for (x0, y0, x1, y1) in pixel_segments {
+            renderer.draw_line(x0, y0, x1, y1, stroke_width, stroke_color);
         }
 
         // Draw points if enabled
@@ -920,6 +936,7 @@ where
         .collect();
 
     let stroke_width = config.stroke_width;
+    let stroke_dash = config.stroke_dash.clone();
     let do_fill = config.fill;
 
     Chart2DElement::new(move |renderer, bounds| {
@@ -955,30 +972,24 @@ where
 
                 // Draw stroke
                 if stroke_width > 0.0 {
-                    let mut i = 0;
-                    while i < ring.len() - 1 {
-                        let (x0, y0) = ring[i];
-                        let (x1, y1) = ring[i + 1];
-
-                        // Skip if jump
-                        if (x1 - x0).abs() > x_jump_threshold || (y1 - y0).abs() > y_jump_threshold
-                        {
-                            i += 1;
-                            continue;
-                        }
-
-                        // Clip and draw
-                        if let Some((cx0, cy0, cx1, cy1)) = clip_line_segment(x0, y0, x1, y1) {
-                            renderer.draw_line(
-                                cx0 * width,
-                                cy0 * height,
-                                cx1 * width,
-                                cy1 * height,
-                                stroke_width,
-                                data.stroke_color,
-                            );
-                        }
-                        i += 1;
+                    // Clip and skip jumps
+                    let segments: Vec<(f32, f32, f32, f32)> = ring
+                        .windows(2)
+                        .filter(|pair| {
+                            (pair[1].0 - pair[0].0).abs() <= x_jump_threshold
+                                && (pair[1].1 - pair[0].1).abs() <= y_jump_threshold
+                        })
+                        .filter_map(|pair| {
+                            clip_line_segment(pair[0].0, pair[0].1, pair[1].0, pair[1].1)
+                        })
+                        .map(|(x0, y0, x1, y1)| (x0 * width, y0 * height, x1 * width, y1 * height))
+                        .collect();
+                    let segments = match &stroke_dash {
+                        Some(pattern) => dash_segments(&segments, pattern),
+                        None => segments,
+                    };
+                    for (x0, y0, x1, y1) in segments {
+                        renderer.draw_line(x0, y0, x1, y1, stroke_width, data.stroke_color);
                     }
                 }
             }
@@ -1032,21 +1043,25 @@ where
                 config.fill_opacity,
             );
 
-            let polygons: Vec<Vec<(f32, f32)>> = band
+            // Triangulate once here rather than on every paint
+            let polygons: Vec<PolygonMesh> = band
                 .polygons
                 .iter()
                 .filter(|ring| ring.points.len() >= 3)
                 .map(|ring| {
-                    ring.points
+                    let points: Vec<[f32; 2]> = ring
+                        .points
                         .iter()
                         .map(|p| {
                             let x_scaled = x_scale.scale(p.x);
                             let y_scaled = y_scale.scale(p.y);
                             let x_rel = ((x_scaled - x_range_min) / x_range_span) as f32;
                             let y_rel = 1.0 - ((y_scaled - y_range_min) / y_range_span) as f32;
-                            (x_rel, y_rel)
+                            [x_rel, y_rel]
                         })
-                        .collect()
+                        .collect();
+                    let indices = triangulate_indices(&points);
+                    PolygonMesh { points, indices }
                 })
                 .collect();
 
@@ -1065,17 +1080,14 @@ where
 
         for data in &band_data {
             for polygon in &data.polygons {
-                if polygon.len() < 3 {
-                    continue;
-                }
-
                 let pts: Vec<[f32; 2]> = polygon
+                    .points
                     .iter()
-                    .map(|(x, y)| [x * width, y * height])
+                    .map(|[x, y]| [x * width, y * height])
                     .collect();
 
                 // Draw fill
-                renderer.draw_polygon(&pts, data.fill_color);
+                renderer.draw_mesh(pts.iter().copied(), &polygon.indices, data.fill_color);
 
                 // Draw stroke to eliminate anti-aliasing gaps
                 for i in 0..pts.len() {
@@ -1099,6 +1111,8 @@ where
 /// Render a heatmap (2D grid of colored cells) using GPU acceleration
 ///
 /// This is a drop-in replacement for `crate::shape::contour::render_heatmap`.
+/// With a smooth [`ContourConfig::interpolation`], the grid is upsampled to
+/// screen resolution instead of drawn as cells.
 pub fn render_heatmap<XS, YS>(
     data: HeatmapData,
     x_scale: &XS,
@@ -1110,94 +1124,250 @@ where
     YS: Scale<f64, f64> + Clone + 'static,
 {
     // Calculate value range
-    let value_range = if data.values.is_empty() {
-        (0.0, 1.0)
-    } else {
-        let min = data.values.iter().cloned().fold(f64::INFINITY, f64::min);
-        let max = data
-            .values
-            .iter()
-            .cloned()
-            .fold(f64::NEG_INFINITY, f64::max);
-        (min, max)
-    };
+    let value_range = data
+        .values
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .fold(None, |range: Option<(f64, f64)>, value| {
+            Some(range.map_or((value, value), |(min, max)| {
+                (min.min(value), max.max(value))
+            }))
+        })
+        .unwrap_or((0.0, 1.0));
 
+    render_heatmap_in_range(data, x_scale, y_scale, config, value_range)
+}
+
+/// Render a heatmap with colors normalized over `value_range`
+///
+/// Use to match a colorbar or other charts, or to center a diverging scale.
+pub fn render_heatmap_in_range<XS, YS>(
+    data: HeatmapData,
+    x_scale: &XS,
+    y_scale: &YS,
+    config: &ContourConfig,
+    value_range: (f64, f64),
+) -> impl IntoElement
+where
+    XS: Scale<f64, f64> + Clone + 'static,
+    YS: Scale<f64, f64> + Clone + 'static,
+{
     let (x_range_min, x_range_max) = x_scale.range();
     let (y_range_min, y_range_max) = y_scale.range();
     let x_range_span = x_range_max - x_range_min;
     let y_range_span = y_range_max - y_range_min;
 
-    // Pre-process cells
-    let mut cells: Vec<CellDrawData> = Vec::with_capacity(data.width * data.height);
+    let to_x_rel = |x: f64| ((x_scale.scale(x) - x_range_min) / x_range_span) as f32;
+    let to_y_rel = |y: f64| 1.0 - ((y_scale.scale(y) - y_range_min) / y_range_span) as f32;
+
+    // Cell edges in relative coordinates depend only on the column (row)
+    let x_edges: Vec<(f32, f32)> = (0..data.width)
+        .map(|xi| {
+            let (x0_data, x1_data) = cell_extent(&data.x_values, xi);
+            (to_x_rel(x0_data), to_x_rel(x1_data))
+        })
+        .collect();
+    let y_edges: Vec<(f32, f32)> = (0..data.height)
+        .map(|yi| {
+            let (y0_data, y1_data) = cell_extent(&data.y_values, yi);
+            (to_y_rel(y0_data), to_y_rel(y1_data))
+        })
+        .collect();
 
+    if config.interpolation != GridInterpolation::Nearest {
+        return smooth_heatmap(data, &x_edges, &y_edges, config, value_range);
+    }
+
+    let sorted = |edges: &[(f32, f32)]| -> Vec<(f32, f32)> {
+        edges.iter().map(|&(a, b)| (a.min(b), a.max(b))).collect()
+    };
+    let (x_bounds, y_bounds) = (sorted(&x_edges), sorted(&y_edges));
+
+    // Cell colors, row-major; masked cells are not drawn and missing values
+    // take the solid color of the no-data style
+    let no_data = config
+        .no_data
+        .fill_color()
+        .map(|color| to_color4(&color, 1.0));
+    let mut colors: Vec<Option<Color4>> = Vec::with_capacity(data.width * data.height);
     for yi in 0..data.height {
         for xi in 0..data.width {
-            let value = match data.get(xi, yi) {
-                Some(v) if v.is_finite() => v,
-                _ => continue,
-            };
+            if data.is_masked(xi, yi) {
+                colors.push(None);
+                continue;
+            }
+            colors.push(match data.get(xi, yi) {
+                Some(value) if value.is_finite() => {
+                    let t = normalize_value(value, value_range.0, value_range.1);
+                    Some(get_contour_color(
+                        t,
+                        &config.color_scale,
+                        &config.fill_color,
+                        config.fill_opacity,
+                    ))
+                }
+                _ => no_data,
+            });
+        }
+    }
 
-            let t = normalize_value(value, value_range.0, value_range.1);
-            let fill_color = get_contour_color(
-                t,
-                &config.color_scale,
-                &config.fill_color,
-                config.fill_opacity,
-            );
+    // Merge same-colored neighbours so plateaus are drawn as one quad
+    let span = |bounds: &[(f32, f32)]| {
+        bounds
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |acc, b| {
+                (acc.0.min(b.0), acc.1.max(b.1))
+            })
+    };
+    let cells: Vec<CellDrawData> = merge_grid_cells(data.width, data.height, &colors)
+        .into_iter()
+        .map(|cell| {
+            let (x_min, x_max) = span(&x_bounds[cell.x0..cell.x1]);
+            let (y_min, y_max) = span(&y_bounds[cell.y0..cell.y1]);
+            CellDrawData {
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+                fill_color: cell.color,
+            }
+        })
+        .collect();
 
-            // Cell boundaries in data coordinates
-            let x0_data = data.x_values[xi];
-            let x1_data = if xi + 1 < data.width {
-                data.x_values[xi + 1]
-            } else if xi > 0 {
-                x0_data + (x0_data - data.x_values[xi - 1])
-            } else {
-                x0_data * 1.1
-            };
+    Chart2DElement::new(move |renderer, bounds| {
+        let width: f32 = bounds.size.width.into();
+        let height: f32 = bounds.size.height.into();
 
-            let y0_data = data.y_values[yi];
-            let y1_data = if yi + 1 < data.height {
-                data.y_values[yi + 1]
-            } else if yi > 0 {
-                y0_data + (y0_data - data.y_values[yi - 1])
-            } else {
-                y0_data * 1.1
-            };
+        let rects: Vec<(Rect, Color4)> = cells
+            .iter()
+            .map(|cell| {
+                let x = cell.x_min * width;
+                let y = cell.y_min * height;
+                let w = (cell.x_max - cell.x_min) * width + 0.5; // Slight overlap
+                let h = (cell.y_max - cell.y_min) * height + 0.5;
+
+                (Rect::new(x, y, w.max(1.0), h.max(1.0)), cell.fill_color)
+            })
+            .collect();
+        renderer.draw_rects(&rects, 0.0);
+    })
+    .transparent()
+    .absolute()
+}
 
-            // Transform to relative coordinates
-            let x0_scaled = x_scale.scale(x0_data);
-            let x1_scaled = x_scale.scale(x1_data);
-            let y0_scaled = y_scale.scale(y0_data);
-            let y1_scaled = y_scale.scale(y1_data);
-
-            let x0_rel = ((x0_scaled - x_range_min) / x_range_span) as f32;
-            let x1_rel = ((x1_scaled - x_range_min) / x_range_span) as f32;
-            let y0_rel = 1.0 - ((y0_scaled - y_range_min) / y_range_span) as f32;
-            let y1_rel = 1.0 - ((y1_scaled - y_range_min) / y_range_span) as f32;
-
-            cells.push(CellDrawData {
-                x_min: x0_rel.min(x1_rel),
-                x_max: x0_rel.max(x1_rel),
-                y_min: y0_rel.min(y1_rel),
-                y_max: y0_rel.max(y1_rel),
-                fill_color,
-            });
-        }
-    }
+/// Data coordinates of the start and end of cell `i`
+///
+/// Cells start at their value and end at the next one; the last cell is as
+/// wide as the one before it.
+fn cell_extent(values: &[f64], i: usize) -> (f64, f64) {
+    let start = values[i];
+    let end = if i + 1 < values.len() {
+        values[i + 1]
+    } else if i > 0 {
+        start + (start - values[i - 1])
+    } else {
+        start * 1.1
+    };
+    (start, end)
+}
+
+/// Pixels between the vertices of a smoothly upsampled heatmap; the GPU
+/// blends colors in between
+const SMOOTH_STEP: f32 = 2.0;
+
+/// Heatmap sampled with [`ContourConfig::interpolation`] on a mesh of
+/// [`SMOOTH_STEP`] pixels
+///
+/// Masked cells are not drawn and missing values take the solid color of
+/// the no-data style.
+fn smooth_heatmap(
+    data: HeatmapData,
+    x_edges: &[(f32, f32)],
+    y_edges: &[(f32, f32)],
+    config: &ContourConfig,
+    value_range: (f64, f64),
+) -> Chart2DElement {
+    let widen = |edges: &[(f32, f32)]| -> Vec<(f64, f64)> {
+        edges.iter().map(|&(a, b)| (a as f64, b as f64)).collect()
+    };
+    let (x_edges, y_edges) = (widen(x_edges), widen(y_edges));
+    let mode = config.interpolation;
+    let color_scale = config.color_scale.clone();
+    let fill_color = config.fill_color;
+    let fill_opacity = config.fill_opacity;
+    let no_data = config
+        .no_data
+        .fill_color()
+        .map(|color| to_color4(&color, 1.0));
 
     Chart2DElement::new(move |renderer, bounds| {
         let width: f32 = bounds.size.width.into();
         let height: f32 = bounds.size.height.into();
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
 
-        for cell in &cells {
-            let x = cell.x_min * width;
-            let y = cell.y_min * height;
-            let w = (cell.x_max - cell.x_min) * width + 0.5; // Slight overlap
-            let h = (cell.y_max - cell.y_min) * height + 0.5;
+        // Cell coordinates of the mesh columns and rows
+        let axis = |extent: f32, edges: &[(f64, f64)]| -> Vec<(f32, Option<f64>)> {
+            let count = (extent / SMOOTH_STEP).ceil() as usize + 1;
+            (0..count)
+                .map(|i| {
+                    let position = (i as f32 * SMOOTH_STEP).min(extent);
+                    let coordinate = grid_coordinate(edges, (position / extent) as f64);
+                    (position, coordinate)
+                })
+                .collect()
+        };
+        let columns = axis(width, &x_edges);
+        let rows = axis(height, &y_edges);
 
-            let rect = Rect::new(x, y, w.max(1.0), h.max(1.0));
-            renderer.draw_rect(rect, cell.fill_color, 0.0);
+        let vertices: Vec<([f32; 2], Option<Color4>)> = rows
+            .iter()
+            .flat_map(|&(y, v)| columns.iter().map(move |&(x, u)| (x, u, y, v)))
+            .map(|(x, u, y, v)| {
+                let color = u.zip(v).and_then(|(u, v)| {
+                    let xi = (u.round().max(0.0) as usize).min(data.width - 1);
+                    let yi = (v.round().max(0.0) as usize).min(data.height - 1);
+                    if data.is_masked(xi, yi) {
+                        return None;
+                    }
+                    let value = sample_grid(&data.values, data.width, data.height, u, v, mode);
+                    if !value.is_finite() {
+                        return no_data;
+                    }
+                    let t = normalize_value(value, value_range.0, value_range.1);
+                    Some(get_contour_color(
+                        t,
+                        &color_scale,
+                        &fill_color,
+                        fill_opacity,
+                    ))
+                });
+                ([x, y], color)
+            })
+            .collect();
+
+        // Two triangles per mesh square whose corners all have a color
+        let stride = columns.len();
+        let mut indices = Vec::new();
+        for j in 0..rows.len() - 1 {
+            for i in 0..stride - 1 {
+                let (top, bottom) = (j * stride + i, (j + 1) * stride + i);
+                let corners = [top, top + 1, bottom, bottom + 1];
+                if corners.iter().all(|&k| vertices[k].1.is_some()) {
+                    let [a, b, c, d] = corners.map(|k| k as u32);
+                    indices.extend_from_slice(&[a, b, c, b, d, c]);
+                }
+            }
         }
+
+        renderer.draw_shaded_mesh(
+            vertices
+                .into_iter()
+                .map(|(position, color)| (position, color.unwrap_or_default())),
+            &indices,
+        );
     })
     .transparent()
     .absolute()
@@ -1211,10 +1381,16 @@ struct ContourDrawData {
 }
 
 struct BandDrawData {
-    polygons: Vec<Vec<(f32, f32)>>,
+    polygons: Vec<PolygonMesh>,
     fill_color: Color4,
 }
 
+/// Polygon in relative coordinates with its triangulation
+struct PolygonMesh {
+    points: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
 struct CellDrawData {
     x_min: f32,
     x_max: f32,
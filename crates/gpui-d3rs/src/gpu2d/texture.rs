@@ -0,0 +1,152 @@
+//! Offscreen rendering into cached textures
+//!
+//! `ChartTexture` renders a draw function into an image once and reuses it
+//! until the content key or size changes. The resulting `RenderImage` can be
+//! embedded anywhere with `gpui::img`, e.g. a sparkline inside a table cell.
+
+use super::primitives::Color4;
+use super::renderer::Chart2DRenderer;
+use gpui::RenderImage;
+use image::{Frame, ImageFormat, RgbaImage};
+use std::io::Cursor;
+use std::sync::Arc;
+
+/// A rendered texture together with the inputs it was rendered from
+struct CachedImage {
+    key: u64,
+    width: u32,
+    height: u32,
+    image: Arc<RenderImage>,
+}
+
+/// Offscreen render target with an image cache
+///
+/// Keep one `ChartTexture` per embedded chart (e.g. in the owning view) so
+/// repeated renders with unchanged content reuse the same image.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let image = self.texture.get_or_render(data_version, 120, 24, |renderer| {
+///     renderer.draw_line(0.0, 20.0, 120.0, 4.0, 1.5, [0.1, 0.4, 0.8, 1.0]);
+/// });
+/// if let Some(image) = image {
+///     cell = cell.child(img(image).w(px(120.0)).h(px(24.0)));
+/// }
+/// ```
+pub struct ChartTexture {
+    renderer: Option<Chart2DRenderer>,
+    cached: Option<CachedImage>,
+    background_color: Color4,
+}
+
+impl Default for ChartTexture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChartTexture {
+    /// Create an empty texture cache with a transparent background
+    ///
+    /// The GPU renderer is created lazily on first render.
+    pub fn new() -> Self {
+        Self {
+            renderer: None,
+            cached: None,
+            background_color: [0.0, 0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Set the background color (invalidates the cache)
+    pub fn background_color(mut self, color: Color4) -> Self {
+        self.background_color = color;
+        self.cached = None;
+        self
+    }
+
+    /// Whether an image for `key` at the given size is cached
+    pub fn is_cached(&self, key: u64, width: u32, height: u32) -> bool {
+        self.cached
+            .as_ref()
+            .is_some_and(|c| c.key == key && c.width == width && c.height == height)
+    }
+
+    /// Drop the cached image so the next render redraws
+    pub fn invalidate(&mut self) {
+        self.cached = None;
+    }
+
+    /// Return the cached image, rendering it with `draw` if the key or size changed
+    ///
+    /// Returns `None` for zero-sized targets or when rendering fails.
+    pub fn get_or_render<F>(
+        &mut self,
+        key: u64,
+        width: u32,
+        height: u32,
+        draw: F,
+    ) -> Option<Arc<RenderImage>>
+    where
+        F: FnOnce(&mut Chart2DRenderer),
+    {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        if self.is_cached(key, width, height) {
+            return self.cached.as_ref().map(|c| c.image.clone());
+        }
+
+        let renderer = self.renderer.get_or_insert_with(Chart2DRenderer::new);
+        renderer.begin_frame(width, height, self.background_color);
+        draw(renderer);
+        let pixels = renderer.end_frame()?;
+
+        let rgba_image = RgbaImage::from_raw(width, height, pixels)?;
+        let image = Arc::new(RenderImage::new(vec![Frame::new(rgba_image)]));
+        self.cached = Some(CachedImage {
+            key,
+            width,
+            height,
+            image: image.clone(),
+        });
+        Some(image)
+    }
+
+    /// Render `draw` once and return the RGBA pixels, bypassing the cache
+    ///
+    /// Used for exports such as reports, where the pixels are composited or
+    /// embedded elsewhere.
+    pub fn render_rgba<F>(&mut self, width: u32, height: u32, draw: F) -> Option<Vec<u8>>
+    where
+        F: FnOnce(&mut Chart2DRenderer),
+    {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let renderer = self.renderer.get_or_insert_with(Chart2DRenderer::new);
+        renderer.begin_frame(width, height, self.background_color);
+        draw(renderer);
+        renderer.end_frame()
+    }
+
+    /// Render `draw` once and encode the pixels as PNG, bypassing the cache
+    ///
+    /// Used for exports such as copying a chart to the clipboard.
+    pub fn render_png<F>(&mut self, width: u32, height: u32, draw: F) -> Option<Vec<u8>>
+    where
+        F: FnOnce(&mut Chart2DRenderer),
+    {
+        let pixels = self.render_rgba(width, height, draw)?;
+        encode_png(width, height, pixels)
+    }
+}
+
+/// Encode RGBA pixels as PNG
+pub fn encode_png(width: u32, height: u32, pixels: Vec<u8>) -> Option<Vec<u8>> {
+    let rgba_image = RgbaImage::from_raw(width, height, pixels)?;
+    let mut png = Cursor::new(Vec::new());
+    rgba_image.write_to(&mut png, ImageFormat::Png).ok()?;
+    Some(png.into_inner())
+}
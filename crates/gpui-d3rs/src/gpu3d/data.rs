@@ -1,5 +1,7 @@
 //! Surface data structures for 3D visualization
 
+use super::camera::Camera3D;
+use super::config::SurfacePlotType;
 use glam::Vec3;
 
 /// A single vertex in the surface mesh
@@ -72,6 +74,27 @@ pub struct SurfaceData {
     pub y_ticks: Option<Vec<f64>>,
     /// Custom ticks for Z-axis
     pub z_ticks: Option<Vec<f64>>,
+    /// Unit appended to X-axis tick labels
+    pub x_unit: Option<String>,
+    /// Unit appended to Y-axis tick labels (defaults to "°")
+    pub y_unit: Option<String>,
+    /// Unit appended to Z-axis tick labels (defaults to "dB")
+    pub z_unit: Option<String>,
+}
+
+/// Grid point of a surface found under the cursor by [`SurfaceData::pick`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurfacePick {
+    /// Column index in the grid
+    pub xi: usize,
+    /// Row index in the grid
+    pub yi: usize,
+    /// X value of the point
+    pub x: f64,
+    /// Y value of the point
+    pub y: f64,
+    /// Z value of the point
+    pub z: f64,
 }
 
 impl SurfaceData {
@@ -107,6 +130,9 @@ impl SurfaceData {
             x_ticks: None,
             y_ticks: None,
             z_ticks: None,
+            x_unit: None,
+            y_unit: None,
+            z_unit: None,
         }
     }
 
@@ -212,6 +238,24 @@ impl SurfaceData {
         self
     }
 
+    /// Set X-axis unit
+    pub fn with_x_unit(mut self, unit: impl Into<String>) -> Self {
+        self.x_unit = Some(unit.into());
+        self
+    }
+
+    /// Set Y-axis unit
+    pub fn with_y_unit(mut self, unit: impl Into<String>) -> Self {
+        self.y_unit = Some(unit.into());
+        self
+    }
+
+    /// Set Z-axis unit
+    pub fn with_z_unit(mut self, unit: impl Into<String>) -> Self {
+        self.z_unit = Some(unit.into());
+        self
+    }
+
     /// Get number of X points
     pub fn x_count(&self) -> usize {
         self.x_values.len()
@@ -287,6 +331,73 @@ impl SurfaceData {
         }
     }
 
+    /// World position of a grid point, as placed in the mesh
+    pub fn world_position(&self, xi: usize, yi: usize, plot_type: SurfacePlotType) -> Option<Vec3> {
+        let z = self.z_at(xi, yi)?;
+        let nx = self.normalize_x(*self.x_values.get(xi)?);
+        let ny = self.normalize_y(*self.y_values.get(yi)?);
+        let nz = self.normalize_z(z);
+
+        Some(match plot_type {
+            // Map normalized z [0,1] to height [-0.5, 0.5]
+            SurfacePlotType::Cartesian => Vec3::new(nx, nz - 0.5, ny),
+            SurfacePlotType::Spherical => {
+                // X maps to latitude [-PI/2, PI/2] and Y to longitude
+                // [-PI, PI] on the unit sphere
+                let phi = nx * std::f32::consts::FRAC_PI_2;
+                let theta = ny * std::f32::consts::PI;
+                let r_xz = phi.cos();
+                Vec3::new(r_xz * theta.sin(), phi.sin(), r_xz * theta.cos())
+            }
+        })
+    }
+
+    /// Find the grid point under a screen position
+    ///
+    /// `screen` is relative to the top-left corner of a `width` x `height`
+    /// viewport. Among the grid points projected within `radius` pixels, the
+    /// one closest to the camera wins, so points hidden behind a fold of the
+    /// surface are not picked.
+    pub fn pick(
+        &self,
+        camera: &Camera3D,
+        plot_type: SurfacePlotType,
+        screen: (f32, f32),
+        width: f32,
+        height: f32,
+        radius: f32,
+    ) -> Option<SurfacePick> {
+        let mut best: Option<(f32, SurfacePick)> = None;
+        for yi in 0..self.y_count() {
+            for xi in 0..self.x_count() {
+                let Some(pos) = self.world_position(xi, yi, plot_type) else {
+                    continue;
+                };
+                let Some(p) = camera.project_to_screen(pos, width, height) else {
+                    continue;
+                };
+                if !(0.0..=1.0).contains(&p.z) {
+                    continue;
+                }
+                let (dx, dy) = (p.x - screen.0, p.y - screen.1);
+                if dx * dx + dy * dy > radius * radius {
+                    continue;
+                }
+                if best.is_none_or(|(depth, _)| p.z < depth) {
+                    let pick = SurfacePick {
+                        xi,
+                        yi,
+                        x: self.x_values[xi],
+                        y: self.y_values[yi],
+                        z: self.z_values[yi][xi],
+                    };
+                    best = Some((p.z, pick));
+                }
+            }
+        }
+        best.map(|(_, pick)| pick)
+    }
+
     fn compute_z_range(z_values: &[Vec<f64>]) -> (f64, f64) {
         let mut z_min = f64::INFINITY;
         let mut z_max = f64::NEG_INFINITY;
@@ -356,6 +467,55 @@ mod tests {
         assert!((data.normalize_x(100.0) - 0.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_world_position_cartesian() {
+        let data = SurfaceData::from_function((0.0, 1.0), (0.0, 1.0), 2, 2, |x, y| x + y);
+
+        let low = data
+            .world_position(0, 0, SurfacePlotType::Cartesian)
+            .unwrap();
+        let high = data
+            .world_position(1, 1, SurfacePlotType::Cartesian)
+            .unwrap();
+        assert!((low - Vec3::new(-1.0, -0.5, -1.0)).length() < 1e-5);
+        assert!((high - Vec3::new(1.0, 0.5, 1.0)).length() < 1e-5);
+        assert!(
+            data.world_position(2, 0, SurfacePlotType::Cartesian)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_pick_finds_point_under_cursor() {
+        let data = SurfaceData::from_function((0.0, 1.0), (0.0, 1.0), 3, 3, |x, y| x * y);
+        let mut camera = Camera3D::new();
+        camera.position = Vec3::new(0.0, 10.0, 0.0);
+        camera.target = Vec3::ZERO;
+        camera.up = Vec3::new(0.0, 0.0, -1.0);
+        camera.aspect = 1.0;
+
+        let center = data
+            .world_position(1, 1, SurfacePlotType::Cartesian)
+            .unwrap();
+        let screen = camera.project_to_screen(center, 400.0, 400.0).unwrap();
+        let cartesian = SurfacePlotType::Cartesian;
+        let pick = data
+            .pick(
+                &camera,
+                cartesian,
+                (screen.x + 2.0, screen.y),
+                400.0,
+                400.0,
+                8.0,
+            )
+            .unwrap();
+        assert_eq!((pick.xi, pick.yi), (1, 1));
+        assert_eq!(pick.z, 0.25);
+
+        let miss = data.pick(&camera, cartesian, (-50.0, -50.0), 400.0, 400.0, 8.0);
+        assert!(miss.is_none());
+    }
+
     #[test]
     fn test_normalize_z_log() {
         let data = SurfaceData::from_function((-1.0, 1.0), (-1.0, 1.0), 2, 2, |_, _| 0.0)
@@ -3,7 +3,7 @@
 use super::camera::{Camera3D, OrbitControls};
 use super::config::Surface3DConfig;
 use super::config::SurfacePlotType;
-use super::data::SurfaceData;
+use super::data::{SurfaceData, SurfacePick};
 use super::mesh::SurfaceMesh;
 use super::renderer::Surface3DRenderer;
 use crate::text::{measure_text_width, paint_vector_text_at};
@@ -70,6 +70,7 @@ pub struct Surface3DElement {
     state: Rc<RefCell<Surface3DState>>,
     renderer: Rc<RefCell<Option<Surface3DRenderer>>>,
     mesh: Rc<RefCell<Option<SurfaceMesh>>>,
+    picked: Option<SurfacePick>,
 }
 
 impl Surface3DElement {
@@ -87,6 +88,7 @@ impl Surface3DElement {
             state: Rc::new(RefCell::new(state)),
             renderer: Rc::new(RefCell::new(None)),
             mesh: Rc::new(RefCell::new(None)),
+            picked: None,
         }
     }
 
@@ -125,6 +127,12 @@ impl Surface3DElement {
         self
     }
 
+    /// Mark a picked grid point with a dot and its values
+    pub fn with_picked(mut self, picked: Option<SurfacePick>) -> Self {
+        self.picked = picked;
+        self
+    }
+
     fn ensure_renderer(&self) -> bool {
         let mut renderer_ref = self.renderer.borrow_mut();
         if renderer_ref.is_none() {
@@ -423,10 +431,11 @@ impl Element for Surface3DElement {
                 let tick_dir_z = if best_x_z_val > 0.0 { 1.0 } else { -1.0 };
                 let tick_vec = glam::Vec3::new(0.0, 0.0, 0.1 * tick_dir_z);
 
+                let unit = self.data.x_unit.as_deref().unwrap_or("");
                 let label = if freq >= 1000.0 {
-                    format!("{}k", freq / 1000.0)
+                    format!("{}k{}", freq / 1000.0, unit)
                 } else {
-                    format!("{}", freq)
+                    format!("{}{}", freq, unit)
                 };
 
                 draw_tick_and_label(window, pos, tick_vec, label);
@@ -435,10 +444,7 @@ impl Element for Surface3DElement {
             // X Axis Title
             draw_label(
                 window,
-                self.data
-                    .x_label
-                    .clone()
-                    .unwrap_or("Freq. (Hz)".to_string()),
+                axis_title(&self.data.x_label, &self.data.x_unit, "Freq. (Hz)"),
                 glam::Vec3::new(
                     0.0,
                     -0.5,
@@ -487,14 +493,14 @@ impl Element for Surface3DElement {
                 let tick_dir_x = if best_z_x_val > 0.0 { 1.0 } else { -1.0 };
                 let tick_vec = glam::Vec3::new(0.1 * tick_dir_x, 0.0, 0.0);
 
-                let label = format!("{}°", angle);
+                let label = format!("{}{}", angle, self.data.y_unit.as_deref().unwrap_or("°"));
 
                 draw_tick_and_label(window, pos, tick_vec, label);
             }
             // Angle Axis Title
             draw_label(
                 window,
-                self.data.y_label.clone().unwrap_or("Angle".to_string()),
+                axis_title(&self.data.y_label, &self.data.y_unit, "Angle"),
                 glam::Vec3::new(
                     best_z_x_val + 0.3 * (if best_z_x_val > 0.0 { 1.0 } else { -1.0 }),
                     -0.5,
@@ -557,13 +563,13 @@ impl Element for Surface3DElement {
                 let pos = glam::Vec3::new(best_y_x, y, best_y_z);
                 let tick_vec = glam::Vec3::new(best_y_x * 0.1, 0.0, best_y_z * 0.1);
 
-                let label = format!("{}dB", spl);
+                let label = format!("{}{}", spl, self.data.z_unit.as_deref().unwrap_or("dB"));
                 draw_tick_and_label(window, pos, tick_vec, label);
             }
             // SPL Axis Title
             draw_label(
                 window,
-                self.data.z_label.clone().unwrap_or("SPL".to_string()),
+                axis_title(&self.data.z_label, &self.data.z_unit, "SPL"),
                 glam::Vec3::new(best_y_x * 1.4, 0.0, best_y_z * 1.4),
                 false,
                 false,
@@ -707,6 +713,41 @@ impl Element for Surface3DElement {
             }
         }
 
+        // Draw picked point marker
+        if let Some(pick) = self.picked
+            && let Some(pos) = self
+                .data
+                .world_position(pick.xi, pick.yi, self.config.plot_type)
+            && let Some(screen) = camera.project_to_screen(pos, width, height)
+        {
+            let radius = 4.0;
+            let x = screen.x + f32::from(bounds.origin.x);
+            let y = screen.y + f32::from(bounds.origin.y);
+            window.paint_quad(gpui::PaintQuad {
+                bounds: gpui::Bounds::new(
+                    gpui::point(px(x - radius), px(y - radius)),
+                    gpui::size(px(radius * 2.0), px(radius * 2.0)),
+                ),
+                corner_radii: gpui::Corners::all(px(radius)),
+                background: gpui::rgba(0xff3030ff).into(),
+                border_widths: gpui::Edges::all(px(1.0)),
+                border_color: gpui::rgba(0xffffffff).into(),
+                border_style: Default::default(),
+            });
+
+            let label = format!("x {:.2}, y {:.2}, z {:.2}", pick.x, pick.y, pick.z);
+            paint_vector_text_at(
+                window,
+                &label,
+                x + radius + 4.0,
+                y - radius - 10.0,
+                9.0,
+                1.0,
+                gpui::rgba(0xffffffff),
+                0.0,
+            );
+        }
+
         // Draw colorbar legend if enabled
         if self.config.show_colorbar {
             let colorbar_width: f32 = 20.0;
@@ -781,7 +822,7 @@ impl Element for Surface3DElement {
                 }
 
                 // Draw label
-                let label = format!("{:.0}", value);
+                let label = format!("{:.0}{}", value, self.data.z_unit.as_deref().unwrap_or(""));
                 paint_vector_text_at(
                     window,
                     &label,
@@ -795,13 +836,14 @@ impl Element for Surface3DElement {
             }
 
             // Draw colorbar title (Z label)
-            if let Some(ref z_label) = self.data.z_label {
+            if self.data.z_label.is_some() {
+                let z_label = axis_title(&self.data.z_label, &self.data.z_unit, "");
                 let label_x = colorbar_x + colorbar_width / 2.0;
                 let label_y = colorbar_y - 15.0;
-                let text_width = measure_text_width(z_label, 10.0);
+                let text_width = measure_text_width(&z_label, 10.0);
                 paint_vector_text_at(
                     window,
-                    z_label,
+                    &z_label,
                     label_x - text_width / 2.0,
                     label_y,
                     10.0,
@@ -813,3 +855,12 @@ impl Element for Surface3DElement {
         }
     }
 }
+
+/// Axis title with its unit, e.g. "Frequency (Hz)"
+fn axis_title(label: &Option<String>, unit: &Option<String>, default: &str) -> String {
+    match (label, unit) {
+        (Some(label), Some(unit)) => format!("{} ({})", label, unit),
+        (Some(label), None) => label.clone(),
+        (None, _) => default.to_string(),
+    }
+}
@@ -58,44 +58,8 @@ impl SurfaceMesh {
 
         for yi in 0..y_count {
             for xi in 0..x_count {
-                let x = data.x_values[xi];
-                let y = data.y_values[yi];
-                let z = data.z_values[yi][xi];
-
-                // Normalize to [-1, 1] range for x and y, and scale z appropriately
-                let nx = data.normalize_x(x);
-                let ny = data.normalize_y(y);
-                let nz = data.normalize_z(z);
-
-                let position = match plot_type {
-                    SurfacePlotType::Cartesian => {
-                        // Map normalized z [0,1] to height [-0.5, 0.5]
-                        let height = nz - 0.5;
-                        Vec3::new(nx, height, ny)
-                    }
-                    SurfacePlotType::Spherical => {
-                        // Map X (Freq) to Latitude (Phi): [-1, 1] -> [-PI/2, PI/2]
-                        // Map Y (Angle) to Longitude (Theta): [-1, 1] -> [-PI, PI]
-                        // Map Z (SPL) to Radius? Or just color.
-                        // Let's use Radius = 1.0 + nz * 0.2 (slight extrusion)
-
-                        let phi = nx * std::f32::consts::FRAC_PI_2; // -90 to 90 deg
-                        let theta = ny * std::f32::consts::PI; // -180 to 180 deg
-                        let radius = 1.0; // Unit sphere
-
-                        // Spherical to Cartesian
-                        // y is up (sin phi)
-                        // x, z are horizontal plane
-                        let y_pos = radius * phi.sin();
-                        let r_xz = radius * phi.cos();
-                        let x_pos = r_xz * theta.sin();
-                        let z_pos = r_xz * theta.cos();
-
-                        Vec3::new(x_pos, y_pos, z_pos)
-                    }
-                };
-
-                let value = nz;
+                let position = data.world_position(xi, yi, plot_type).unwrap_or(Vec3::ZERO);
+                let value = data.normalize_z(data.z_values[yi][xi]);
 
                 // Placeholder normal - will be computed after
                 vertices.push(GpuVertex::new(position, Vec3::Y, value));
@@ -38,7 +38,7 @@ mod shaders;
 
 pub use camera::{Camera3D, OrbitControls};
 pub use config::{Colormap, Surface3DConfig, SurfacePlotType};
-pub use data::{SurfaceData, SurfaceVertex};
+pub use data::{SurfaceData, SurfacePick, SurfaceVertex};
 pub use element::{Surface3DElement, Surface3DState};
 pub use mesh::SurfaceMesh;
 pub use renderer::Surface3DRenderer;
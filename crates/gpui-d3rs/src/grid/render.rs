@@ -68,7 +68,7 @@ where
                     .top_0()
                     .bottom_0()
                     .w(px(config.line_width))
-                    .bg(theme.axis_line_color())
+                    .bg(theme.grid_color())
                     .opacity(config.line_opacity)
             }))
         })
@@ -86,7 +86,7 @@ where
                     .left_0()
                     .right_0()
                     .h(px(config.line_width))
-                    .bg(theme.axis_line_color())
+                    .bg(theme.grid_color())
                     .opacity(config.line_opacity)
             }))
         })
@@ -110,7 +110,7 @@ where
                         .ml(px(-config.dot_radius))
                         .mt(px(-config.dot_radius))
                         .rounded_full()
-                        .bg(theme.axis_line_color())
+                        .bg(theme.grid_color())
                         .opacity(config.dot_opacity)
                 })
             }))
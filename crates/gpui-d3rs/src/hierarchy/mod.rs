@@ -6,7 +6,9 @@
 use std::cell::RefCell;
 use std::rc::{Rc, Weak};
 
+pub mod pack;
 pub mod tree;
+pub use pack::{PackCircle, PackLayout, pack_enclose, pack_siblings};
 pub use tree::TreeLayout;
 
 /// A node in a hierarchy
@@ -30,6 +32,8 @@ pub struct HierarchyNode<T> {
     pub x: f64,
     /// Y coordinate (computed by layouts)
     pub y: f64,
+    /// Radius (computed by the pack layout)
+    pub r: f64,
 }
 
 impl<T> HierarchyNode<T> {
@@ -44,6 +48,7 @@ impl<T> HierarchyNode<T> {
             height: 0,
             x: 0.0,
             y: 0.0,
+            r: 0.0,
         }))
     }
 
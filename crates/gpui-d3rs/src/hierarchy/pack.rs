@@ -0,0 +1,505 @@
+//! Circle packing layout (D3 pack)
+//!
+//! Enclosure diagrams use containment to represent the hierarchy: each leaf is
+//! a circle whose area is proportional to its value, and each parent is the
+//! smallest circle enclosing its packed children.
+
+use super::HierarchyNode;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A circle used by the packing algorithms
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackCircle {
+    /// Center x
+    pub x: f64,
+    /// Center y
+    pub y: f64,
+    /// Radius
+    pub r: f64,
+}
+
+impl PackCircle {
+    /// Create a new circle
+    pub fn new(x: f64, y: f64, r: f64) -> Self {
+        Self { x, y, r }
+    }
+}
+
+/// Pack layout configuration
+#[derive(Clone, Debug)]
+pub struct PackLayout {
+    /// Layout size (width, height)
+    pub size: (f64, f64),
+    /// Padding between sibling circles and their parent
+    pub padding: f64,
+}
+
+impl Default for PackLayout {
+    fn default() -> Self {
+        Self {
+            size: (1.0, 1.0),
+            padding: 0.0,
+        }
+    }
+}
+
+impl PackLayout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn size(mut self, size: (f64, f64)) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    /// Lay out the hierarchy, setting `x`, `y` and `r` on every node.
+    ///
+    /// Node values must be computed beforehand with [`HierarchyNode::sum`];
+    /// each leaf gets a radius proportional to the square root of its value.
+    pub fn layout<T>(&self, root: Rc<RefCell<HierarchyNode<T>>>) {
+        let (dx, dy) = self.size;
+
+        HierarchyNode::each(root.clone(), |node| {
+            let mut n = node.borrow_mut();
+            if n.children.as_ref().is_none_or(|c| c.is_empty()) {
+                n.r = n.value.unwrap_or(0.0).max(0.0).sqrt();
+            }
+        });
+
+        // First pass without padding to find the natural root radius, then
+        // repack with padding expressed in the final pixel scale
+        Self::pack_children(&root, 0.0);
+        let root_r = root.borrow().r;
+        if root_r > 0.0 && self.padding > 0.0 {
+            Self::pack_children(&root, self.padding * root_r / dx.min(dy));
+        }
+
+        let root_r = root.borrow().r;
+        let k = if root_r > 0.0 {
+            dx.min(dy) / (2.0 * root_r)
+        } else {
+            0.0
+        };
+        {
+            let mut r = root.borrow_mut();
+            r.x = dx / 2.0;
+            r.y = dy / 2.0;
+            r.r *= k;
+        }
+        Self::translate_children(&root, k);
+    }
+
+    fn pack_children<T>(node: &Rc<RefCell<HierarchyNode<T>>>, padding: f64) {
+        let Some(children) = node.borrow().children.clone().filter(|c| !c.is_empty()) else {
+            return;
+        };
+
+        for child in &children {
+            Self::pack_children(child, padding);
+        }
+
+        let mut circles: Vec<PackCircle> = children
+            .iter()
+            .map(|c| PackCircle::new(0.0, 0.0, c.borrow().r + padding))
+            .collect();
+        let enclosing = pack_siblings(&mut circles);
+
+        for (child, circle) in children.iter().zip(&circles) {
+            let mut c = child.borrow_mut();
+            c.x = circle.x;
+            c.y = circle.y;
+            c.r = circle.r - padding;
+        }
+        node.borrow_mut().r = enclosing + padding;
+    }
+
+    /// Convert child positions from parent-relative to absolute, scaled by `k`
+    fn translate_children<T>(node: &Rc<RefCell<HierarchyNode<T>>>, k: f64) {
+        let (px, py, children) = {
+            let n = node.borrow();
+            (n.x, n.y, n.children.clone())
+        };
+        for child in children.iter().flatten() {
+            {
+                let mut c = child.borrow_mut();
+                c.x = px + k * c.x;
+                c.y = py + k * c.y;
+                c.r *= k;
+            }
+            Self::translate_children(child, k);
+        }
+    }
+}
+
+/// Pack circles side by side around the origin without overlap.
+///
+/// Positions of `circles` are updated in place so that their smallest
+/// enclosing circle is centered on the origin. Returns the enclosing radius.
+pub fn pack_siblings(circles: &mut [PackCircle]) -> f64 {
+    let n = circles.len();
+    if n == 0 {
+        return 0.0;
+    }
+
+    circles[0].x = 0.0;
+    circles[0].y = 0.0;
+    if n == 1 {
+        return circles[0].r;
+    }
+
+    circles[0].x = -circles[1].r;
+    circles[1].x = circles[0].r;
+    circles[1].y = 0.0;
+    if n == 2 {
+        return circles[0].r + circles[1].r;
+    }
+
+    place(circles[1], circles[0], &mut circles[2]);
+
+    // Front chain as a circular doubly-linked list over circle indices
+    let mut next = vec![0usize; n];
+    let mut prev = vec![0usize; n];
+    let (mut a, mut b) = (0, 1);
+    next[0] = 1;
+    next[1] = 2;
+    next[2] = 0;
+    prev[0] = 2;
+    prev[1] = 0;
+    prev[2] = 1;
+
+    let score = |circles: &[PackCircle], next: &[usize], node: usize| {
+        let a = circles[node];
+        let b = circles[next[node]];
+        let ab = a.r + b.r;
+        let dx = (a.x * b.r + b.x * a.r) / ab;
+        let dy = (a.y * b.r + b.y * a.r) / ab;
+        dx * dx + dy * dy
+    };
+
+    let mut i = 3;
+    'pack: while i < n {
+        place(circles[a], circles[b], &mut circles[i]);
+        let c = i;
+
+        // Find the closest intersecting circle on the front chain, if any
+        let (mut j, mut k) = (next[b], prev[a]);
+        let (mut sj, mut sk) = (circles[b].r, circles[a].r);
+        loop {
+            if sj <= sk {
+                if intersects(circles[j], circles[c]) {
+                    b = j;
+                    next[a] = b;
+                    prev[b] = a;
+                    continue 'pack;
+                }
+                sj += circles[j].r;
+                j = next[j];
+            } else {
+                if intersects(circles[k], circles[c]) {
+                    a = k;
+                    next[a] = b;
+                    prev[b] = a;
+                    continue 'pack;
+                }
+                sk += circles[k].r;
+                k = prev[k];
+            }
+            if j == next[k] {
+                break;
+            }
+        }
+
+        // Insert the new circle between a and b
+        prev[c] = a;
+        next[c] = b;
+        next[a] = c;
+        prev[b] = c;
+        b = c;
+
+        // Pick the new closest circle pair to the centroid
+        let mut best = score(circles, &next, a);
+        let mut node = next[c];
+        while node != b {
+            let s = score(circles, &next, node);
+            if s < best {
+                a = node;
+                best = s;
+            }
+            node = next[node];
+        }
+        b = next[a];
+        i += 1;
+    }
+
+    // Compute the enclosing circle of the front chain and recenter
+    let mut chain = vec![circles[b]];
+    let mut node = next[b];
+    while node != b {
+        chain.push(circles[node]);
+        node = next[node];
+    }
+    let enclosing = pack_enclose(&chain).unwrap_or(PackCircle::new(0.0, 0.0, 0.0));
+    for circle in circles.iter_mut() {
+        circle.x -= enclosing.x;
+        circle.y -= enclosing.y;
+    }
+    enclosing.r
+}
+
+/// Compute the smallest circle enclosing all `circles` (Welzl's algorithm).
+///
+/// Returns `None` for an empty slice.
+pub fn pack_enclose(circles: &[PackCircle]) -> Option<PackCircle> {
+    let mut basis: Vec<PackCircle> = Vec::new();
+    let mut enclosing: Option<PackCircle> = None;
+    let mut i = 0;
+    while i < circles.len() {
+        let p = circles[i];
+        if enclosing.is_some_and(|e| encloses_weak(e, p)) {
+            i += 1;
+        } else {
+            basis = extend_basis(&basis, p);
+            enclosing = Some(enclose_basis(&basis));
+            i = 0;
+        }
+    }
+    enclosing
+}
+
+/// Place circle `c` tangent to both `a` and `b`
+fn place(b: PackCircle, a: PackCircle, c: &mut PackCircle) {
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    let d2 = dx * dx + dy * dy;
+    if d2 > 0.0 {
+        let a2 = (a.r + c.r).powi(2);
+        let b2 = (b.r + c.r).powi(2);
+        if a2 > b2 {
+            let x = (d2 + b2 - a2) / (2.0 * d2);
+            let y = (b2 / d2 - x * x).max(0.0).sqrt();
+            c.x = b.x - x * dx - y * dy;
+            c.y = b.y - x * dy + y * dx;
+        } else {
+            let x = (d2 + a2 - b2) / (2.0 * d2);
+            let y = (a2 / d2 - x * x).max(0.0).sqrt();
+            c.x = a.x + x * dx - y * dy;
+            c.y = a.y + x * dy + y * dx;
+        }
+    } else {
+        c.x = a.x + c.r;
+        c.y = a.y;
+    }
+}
+
+fn intersects(a: PackCircle, b: PackCircle) -> bool {
+    let dr = a.r + b.r - 1e-6;
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    dr > 0.0 && dr * dr > dx * dx + dy * dy
+}
+
+fn extend_basis(basis: &[PackCircle], p: PackCircle) -> Vec<PackCircle> {
+    if encloses_weak_all(p, basis) {
+        return vec![p];
+    }
+
+    for &b in basis {
+        if encloses_not(p, b) && encloses_weak_all(enclose_basis2(b, p), basis) {
+            return vec![b, p];
+        }
+    }
+
+    for i in 0..basis.len().saturating_sub(1) {
+        for j in i + 1..basis.len() {
+            let (bi, bj) = (basis[i], basis[j]);
+            if encloses_not(enclose_basis2(bi, bj), p)
+                && encloses_not(enclose_basis2(bi, p), bj)
+                && encloses_not(enclose_basis2(bj, p), bi)
+                && encloses_weak_all(enclose_basis3(bi, bj, p), basis)
+            {
+                return vec![bi, bj, p];
+            }
+        }
+    }
+
+    // Only reachable through floating-point degeneracy
+    vec![p]
+}
+
+fn encloses_not(a: PackCircle, b: PackCircle) -> bool {
+    let dr = a.r - b.r;
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    dr < 0.0 || dr * dr < dx * dx + dy * dy
+}
+
+fn encloses_weak(a: PackCircle, b: PackCircle) -> bool {
+    let dr = a.r - b.r + a.r.max(b.r).max(1.0) * 1e-9;
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    dr > 0.0 && dr * dr > dx * dx + dy * dy
+}
+
+fn encloses_weak_all(a: PackCircle, basis: &[PackCircle]) -> bool {
+    basis.iter().all(|&b| encloses_weak(a, b))
+}
+
+fn enclose_basis(basis: &[PackCircle]) -> PackCircle {
+    match basis {
+        [a] => *a,
+        [a, b] => enclose_basis2(*a, *b),
+        [a, b, c] => enclose_basis3(*a, *b, *c),
+        _ => PackCircle::new(0.0, 0.0, 0.0),
+    }
+}
+
+fn enclose_basis2(a: PackCircle, b: PackCircle) -> PackCircle {
+    let x21 = b.x - a.x;
+    let y21 = b.y - a.y;
+    let r21 = b.r - a.r;
+    let l = (x21 * x21 + y21 * y21).sqrt();
+    PackCircle::new(
+        (a.x + b.x + x21 / l * r21) / 2.0,
+        (a.y + b.y + y21 / l * r21) / 2.0,
+        (l + a.r + b.r) / 2.0,
+    )
+}
+
+fn enclose_basis3(a: PackCircle, b: PackCircle, c: PackCircle) -> PackCircle {
+    let (x1, y1, r1) = (a.x, a.y, a.r);
+    let (x2, y2, r2) = (b.x, b.y, b.r);
+    let (x3, y3, r3) = (c.x, c.y, c.r);
+    let a2 = x1 - x2;
+    let a3 = x1 - x3;
+    let b2 = y1 - y2;
+    let b3 = y1 - y3;
+    let c2 = r2 - r1;
+    let c3 = r3 - r1;
+    let d1 = x1 * x1 + y1 * y1 - r1 * r1;
+    let d2 = d1 - x2 * x2 - y2 * y2 + r2 * r2;
+    let d3 = d1 - x3 * x3 - y3 * y3 + r3 * r3;
+    let ab = a3 * b2 - a2 * b3;
+    let xa = (b2 * d3 - b3 * d2) / (ab * 2.0) - x1;
+    let xb = (b3 * c2 - b2 * c3) / ab;
+    let ya = (a3 * d2 - a2 * d3) / (ab * 2.0) - y1;
+    let yb = (a2 * c3 - a3 * c2) / ab;
+    let qa = xb * xb + yb * yb - 1.0;
+    let qb = 2.0 * (r1 + xa * xb + ya * yb);
+    let qc = xa * xa + ya * ya - r1 * r1;
+    let r = -(if qa.abs() > 1e-6 {
+        (qb + (qb * qb - 4.0 * qa * qc).sqrt()) / (2.0 * qa)
+    } else {
+        qc / qb
+    });
+    PackCircle::new(x1 + xa + xb * r, y1 + ya + yb * r, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlaps(a: &PackCircle, b: &PackCircle) -> bool {
+        let d = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+        d < a.r + b.r - 1e-6
+    }
+
+    #[test]
+    fn test_pack_siblings_two() {
+        let mut circles = vec![
+            PackCircle::new(0.0, 0.0, 1.0),
+            PackCircle::new(0.0, 0.0, 2.0),
+        ];
+        let r = pack_siblings(&mut circles);
+        assert!((r - 3.0).abs() < 1e-9);
+        assert!((circles[1].x - circles[0].x - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pack_siblings_no_overlap() {
+        let mut circles: Vec<PackCircle> = (1..=20)
+            .map(|i| PackCircle::new(0.0, 0.0, (i as f64).sqrt()))
+            .collect();
+        let r = pack_siblings(&mut circles);
+
+        for i in 0..circles.len() {
+            for j in i + 1..circles.len() {
+                assert!(!overlaps(&circles[i], &circles[j]), "{i} overlaps {j}");
+            }
+            let c = circles[i];
+            assert!((c.x * c.x + c.y * c.y).sqrt() + c.r <= r + 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_pack_enclose() {
+        assert_eq!(pack_enclose(&[]), None);
+
+        let e = pack_enclose(&[
+            PackCircle::new(-1.0, 0.0, 1.0),
+            PackCircle::new(1.0, 0.0, 1.0),
+        ])
+        .unwrap();
+        assert!(e.x.abs() < 1e-9 && e.y.abs() < 1e-9);
+        assert!((e.r - 2.0).abs() < 1e-9);
+
+        let e = pack_enclose(&[
+            PackCircle::new(0.0, 0.0, 1.0),
+            PackCircle::new(4.0, 0.0, 1.0),
+            PackCircle::new(2.0, 3.0, 1.0),
+        ])
+        .unwrap();
+        for (x, y) in [(0.0, 0.0), (4.0, 0.0), (2.0, 3.0)] {
+            let d = ((x - e.x).powi(2) + (y - e.y).powi(2)).sqrt();
+            assert!(d + 1.0 <= e.r + 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_pack_layout() {
+        let root = HierarchyNode::new(0.0);
+        let children: Vec<_> = [1.0, 2.0, 3.0, 4.0]
+            .into_iter()
+            .map(HierarchyNode::new)
+            .collect();
+        {
+            let mut r = root.borrow_mut();
+            r.set_children(&root, children.clone());
+        }
+        HierarchyNode::sum(root.clone(), |v| *v);
+
+        PackLayout::new()
+            .size((200.0, 100.0))
+            .padding(2.0)
+            .layout(root.clone());
+
+        let r = root.borrow();
+        assert!((r.x - 100.0).abs() < 1e-9);
+        assert!((r.y - 50.0).abs() < 1e-9);
+        assert!((r.r - 50.0).abs() < 1e-9);
+
+        let circles: Vec<PackCircle> = children
+            .iter()
+            .map(|c| {
+                let c = c.borrow();
+                PackCircle::new(c.x, c.y, c.r)
+            })
+            .collect();
+        for (i, c) in circles.iter().enumerate() {
+            let d = ((c.x - r.x).powi(2) + (c.y - r.y).powi(2)).sqrt();
+            assert!(d + c.r <= r.r + 1e-6, "child {i} escapes parent");
+            for other in &circles[i + 1..] {
+                assert!(!overlaps(c, other));
+            }
+        }
+        // Area is proportional to value
+        assert!(circles[3].r > circles[0].r);
+        assert!(((circles[3].r / circles[0].r).powi(2) - 4.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,411 @@
+//! Measurement file importers
+//!
+//! Parses frequency response exports from common acoustic measurement tools
+//! into a single [`FrequencyResponse`] type:
+//!
+//! - **REW**: text export (`File > Export > Measurement as text`)
+//! - **Klippel**: dB-Lab / QC text and CSV exports
+//! - **ARTA**: ASCII export of the frequency response window
+//! - **Generic**: any two- or three-column `freq spl [phase]` text file
+//!
+//! Binary project files (REW `.mdat`, ARTA `.pir`) are not supported: their
+//! layout is undocumented, so [`import`] rejects them, and any other binary
+//! file, with [`ImportError::Unsupported`]. Export the measurement as text
+//! from the tool instead.
+//!
+//! # Example
+//!
+//! ```
+//! use d3rs::importers::{MeasurementFormat, parse};
+//!
+//! let text = "* Measurement: Woofer\n* Freq(Hz) SPL(dB) Phase(degrees)\n\
+//!             20.0 80.5 -10.0\n1000.0 85.0 5.0\n";
+//! let response = parse(text, MeasurementFormat::Auto).unwrap();
+//! assert_eq!(response.name, "Woofer");
+//! assert_eq!(response.format, MeasurementFormat::Rew);
+//!
+//! // Feed straight into a line chart
+//! let (freq, spl) = response.xy();
+//! assert_eq!(freq, &[20.0, 1000.0]);
+//! assert_eq!(spl, &[80.5, 85.0]);
+//! ```
+
+mod text;
+
+use std::path::Path;
+use text::{NumericTable, parse_numeric_table};
+
+/// Source format of a measurement export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MeasurementFormat {
+    /// Detect the format from the file contents
+    #[default]
+    Auto,
+    /// Room EQ Wizard text export
+    Rew,
+    /// Klippel text or CSV export
+    Klippel,
+    /// ARTA ASCII export
+    Arta,
+    /// Plain `freq spl [phase]` columns
+    Generic,
+}
+
+impl MeasurementFormat {
+    /// Guess the format from the contents of a text export.
+    pub fn detect(text: &str) -> Self {
+        let head: String = text.lines().take(40).collect::<Vec<_>>().join("\n");
+        let lower = head.to_ascii_lowercase();
+        if lower.contains("rew v") || head.lines().any(|l| l.trim_start().starts_with("* ")) {
+            MeasurementFormat::Rew
+        } else if lower.contains("klippel") || head.lines().any(|l| l.contains(';')) {
+            MeasurementFormat::Klippel
+        } else if lower.contains("arta") {
+            MeasurementFormat::Arta
+        } else {
+            MeasurementFormat::Generic
+        }
+    }
+
+    /// Display name of the format.
+    pub fn label(self) -> &'static str {
+        match self {
+            MeasurementFormat::Auto => "Auto",
+            MeasurementFormat::Rew => "REW",
+            MeasurementFormat::Klippel => "Klippel",
+            MeasurementFormat::Arta => "ARTA",
+            MeasurementFormat::Generic => "Text",
+        }
+    }
+
+    fn comment_prefixes(self) -> &'static [&'static str] {
+        match self {
+            MeasurementFormat::Rew => &["*"],
+            MeasurementFormat::Klippel => &["#", "//"],
+            MeasurementFormat::Arta => &["//", "#"],
+            MeasurementFormat::Auto | MeasurementFormat::Generic => &["#", "*", "//", "%"],
+        }
+    }
+}
+
+/// Error returned when a measurement cannot be imported.
+#[derive(Debug)]
+pub enum ImportError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is a binary format this module does not read
+    Unsupported(String),
+    /// No frequency response data was found
+    NoData,
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportError::Io(err) => write!(f, "failed to read measurement: {err}"),
+            ImportError::Unsupported(what) => write!(f, "unsupported measurement file: {what}"),
+            ImportError::NoData => write!(f, "no frequency response data found"),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ImportError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ImportError {
+    fn from(err: std::io::Error) -> Self {
+        ImportError::Io(err)
+    }
+}
+
+/// A measured frequency response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrequencyResponse {
+    /// Measurement name, from the file header or file name
+    pub name: String,
+    /// Format the data was read from
+    pub format: MeasurementFormat,
+    /// Frequencies in Hz, strictly increasing
+    pub freq: Vec<f64>,
+    /// Magnitude in dB, one per frequency
+    pub spl: Vec<f64>,
+    /// Phase in degrees, if the export contained it
+    pub phase: Option<Vec<f64>>,
+}
+
+impl FrequencyResponse {
+    /// Create a response, sorting by frequency and dropping invalid points.
+    ///
+    /// Points with a non-positive or non-finite frequency, a non-finite
+    /// magnitude or a repeated frequency are removed. Phase is dropped if its
+    /// length does not match.
+    pub fn new(
+        name: impl Into<String>,
+        freq: Vec<f64>,
+        spl: Vec<f64>,
+        phase: Option<Vec<f64>>,
+    ) -> Self {
+        let phase = phase.filter(|p| p.len() == freq.len());
+        let mut points: Vec<(f64, f64, f64)> = freq
+            .iter()
+            .zip(&spl)
+            .enumerate()
+            .map(|(i, (&f, &s))| (f, s, phase.as_ref().map_or(0.0, |p| p[i])))
+            .filter(|(f, s, _)| f.is_finite() && *f > 0.0 && s.is_finite())
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        points.dedup_by(|b, a| a.0 == b.0);
+
+        Self {
+            name: name.into(),
+            format: MeasurementFormat::Generic,
+            freq: points.iter().map(|p| p.0).collect(),
+            spl: points.iter().map(|p| p.1).collect(),
+            phase: phase.map(|_| points.iter().map(|p| p.2).collect()),
+        }
+    }
+
+    /// `(x, y)` inputs for a line or spectrum chart.
+    pub fn xy(&self) -> (&[f64], &[f64]) {
+        (&self.freq, &self.spl)
+    }
+
+    /// Number of points.
+    pub fn len(&self) -> usize {
+        self.freq.len()
+    }
+
+    /// Whether the response has no points.
+    pub fn is_empty(&self) -> bool {
+        self.freq.is_empty()
+    }
+
+    /// Frequency range covered, in Hz.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        Some((*self.freq.first()?, *self.freq.last()?))
+    }
+
+    /// Shift the magnitude so that it reads 0 dB at `freq` (nearest point).
+    pub fn normalized_at(&self, freq: f64) -> Self {
+        let reference = self
+            .freq
+            .iter()
+            .zip(&self.spl)
+            .min_by(|a, b| (a.0 - freq).abs().total_cmp(&(b.0 - freq).abs()))
+            .map_or(0.0, |(_, s)| *s);
+        Self {
+            spl: self.spl.iter().map(|s| s - reference).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Write as `frequency,spl[,phase]` CSV, the layout AutoEQ reads for
+    /// custom measurement and target files.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(match self.phase {
+            Some(_) => "frequency,spl,phase\n",
+            None => "frequency,spl\n",
+        });
+        for i in 0..self.freq.len() {
+            out.push_str(&format!("{},{}", self.freq[i], self.spl[i]));
+            if let Some(phase) = &self.phase {
+                out.push_str(&format!(",{}", phase[i]));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Parse a text export.
+pub fn parse(text: &str, format: MeasurementFormat) -> Result<FrequencyResponse, ImportError> {
+    let format = match format {
+        MeasurementFormat::Auto => MeasurementFormat::detect(text),
+        format => format,
+    };
+    let table = parse_numeric_table(text, format.comment_prefixes());
+    let name = match format {
+        MeasurementFormat::Rew => header_value(&table, "Measurement"),
+        MeasurementFormat::Klippel => header_value(&table, "Name").or_else(|| {
+            table
+                .header
+                .first()
+                .map(|l| l.trim_matches(';').to_string())
+        }),
+        MeasurementFormat::Arta => header_value(&table, "Title"),
+        MeasurementFormat::Auto | MeasurementFormat::Generic => None,
+    };
+    let mut response = from_table(&table, name.unwrap_or_default())?;
+    response.format = format;
+    Ok(response)
+}
+
+/// Read and parse a measurement file.
+///
+/// The name defaults to the file stem when the header does not carry one.
+pub fn import(
+    path: impl AsRef<Path>,
+    format: MeasurementFormat,
+) -> Result<FrequencyResponse, ImportError> {
+    let path = path.as_ref();
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("mdat") => {
+            return Err(ImportError::Unsupported(
+                "REW .mdat is binary; use File > Export > Measurement as text".to_string(),
+            ));
+        }
+        Some("pir") => {
+            return Err(ImportError::Unsupported(
+                "ARTA .pir is an impulse response; export the frequency response as ASCII"
+                    .to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    let bytes = std::fs::read(path)?;
+    // Text exports never contain NUL; binary files would parse as garbage
+    if bytes.contains(&0) {
+        return Err(ImportError::Unsupported(
+            "binary file; export the measurement as text".to_string(),
+        ));
+    }
+    // Older tools write Latin-1; map bytes one to one when not valid UTF-8
+    let text = String::from_utf8(bytes)
+        .unwrap_or_else(|e| e.into_bytes().iter().map(|&b| b as char).collect());
+    let mut response = parse(&text, format)?;
+    if response.name.is_empty() {
+        response.name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_string();
+    }
+    Ok(response)
+}
+
+/// Value of a `Key: value` or `Key = value` header line.
+fn header_value(table: &NumericTable, key: &str) -> Option<String> {
+    table.header.iter().find_map(|line| {
+        let line = line.trim_start_matches(['*', '#', '/', ' ']);
+        let rest = line.strip_prefix(key)?.trim_start();
+        let value = rest
+            .strip_prefix(':')
+            .or_else(|| rest.strip_prefix('='))?
+            .trim()
+            .trim_matches(';');
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+fn from_table(table: &NumericTable, name: String) -> Result<FrequencyResponse, ImportError> {
+    let freq: Vec<f64> = table.rows.iter().map(|r| r[0]).collect();
+    let spl: Vec<f64> = table.rows.iter().map(|r| r[1]).collect();
+    let phase = table
+        .rows
+        .iter()
+        .map(|r| r.get(2).copied())
+        .collect::<Option<Vec<f64>>>();
+    let response = FrequencyResponse::new(name, freq, spl, phase);
+    if response.len() < 2 {
+        return Err(ImportError::NoData);
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REW: &str = "\
+* Measurement data measured by REW V5.20.13
+* Source: UMIK-1
+* Measurement: Left speaker
+* Smoothing: 1/48 octave
+*
+* Freq(Hz) SPL(dB) Phase(degrees)
+20.000 74.5 -120.0
+40.000 78.25 -60.0
+80.000 80.0 0.0
+";
+
+    #[test]
+    fn test_parse_rew() {
+        let response = parse(REW, MeasurementFormat::Auto).unwrap();
+        assert_eq!(response.format, MeasurementFormat::Rew);
+        assert_eq!(response.name, "Left speaker");
+        assert_eq!(response.freq, vec![20.0, 40.0, 80.0]);
+        assert_eq!(response.phase, Some(vec![-120.0, -60.0, 0.0]));
+    }
+
+    #[test]
+    fn test_parse_klippel_semicolon_decimal_comma() {
+        let text = "Sound Pressure Level;;\nFrequency [Hz];SPL [dB];\n20;85,5;\n40;86,25;\n";
+        let response = parse(text, MeasurementFormat::Auto).unwrap();
+        assert_eq!(response.format, MeasurementFormat::Klippel);
+        assert_eq!(response.name, "Sound Pressure Level");
+        assert_eq!(response.spl, vec![85.5, 86.25]);
+        assert_eq!(response.phase, None);
+    }
+
+    #[test]
+    fn test_parse_arta_and_generic() {
+        let text = "ARTA frequency response\nTitle: Tweeter\nFreq(Hz)\tMag(dB)\tPhase\n\
+                    1000\t90\t10\n2000\t91\t20\n";
+        let response = parse(text, MeasurementFormat::Auto).unwrap();
+        assert_eq!(response.format, MeasurementFormat::Arta);
+        assert_eq!(response.name, "Tweeter");
+
+        let generic = parse("40 2\n20 1\n20 9\n0 5\n", MeasurementFormat::Auto).unwrap();
+        assert_eq!(generic.format, MeasurementFormat::Generic);
+        assert_eq!(generic.freq, vec![20.0, 40.0]);
+        assert_eq!(generic.spl, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_no_data() {
+        assert!(matches!(
+            parse("* only a header\n", MeasurementFormat::Rew),
+            Err(ImportError::NoData)
+        ));
+        assert!(matches!(
+            import("speaker.mdat", MeasurementFormat::Auto),
+            Err(ImportError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_import_rejects_binary_content() {
+        let path = std::env::temp_dir().join(format!("d3rs-import-{}.txt", std::process::id()));
+        std::fs::write(&path, b"20.0\x0080.5\x00\x01\x02").unwrap();
+        let result = import(&path, MeasurementFormat::Auto);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(ImportError::Unsupported(_))));
+    }
+
+    #[test]
+    fn test_normalize_and_csv() {
+        let response = FrequencyResponse::new(
+            "x",
+            vec![100.0, 1000.0],
+            vec![80.0, 90.0],
+            Some(vec![1.0, 2.0]),
+        );
+        assert_eq!(response.normalized_at(900.0).spl, vec![-10.0, 0.0]);
+        assert_eq!(
+            response.to_csv(),
+            "frequency,spl,phase\n100,80,1\n1000,90,2\n"
+        );
+        assert_eq!(response.range(), Some((100.0, 1000.0)));
+    }
+}
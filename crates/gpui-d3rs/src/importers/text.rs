@@ -0,0 +1,107 @@
+//! Numeric column extraction from measurement text exports
+//!
+//! Measurement tools write a few header or comment lines followed by rows of
+//! numbers. Separators vary (spaces, tabs, semicolons, commas) and some
+//! locales use a decimal comma; this parser accepts all of them.
+
+/// Rows of numbers and the non-numeric lines that preceded them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct NumericTable {
+    /// Header and comment lines, trimmed, in file order
+    pub header: Vec<String>,
+    /// Data rows with at least two numeric fields
+    pub rows: Vec<Vec<f64>>,
+}
+
+/// Split `text` into header lines and numeric rows.
+///
+/// Lines starting with one of `comment_prefixes` always go to the header.
+/// Other lines are data if they contain at least two numbers; non-numeric
+/// lines found after data has started are ignored.
+pub(super) fn parse_numeric_table(text: &str, comment_prefixes: &[&str]) -> NumericTable {
+    let mut table = NumericTable::default();
+    for line in text.lines() {
+        let line = line.trim().trim_start_matches('\u{feff}');
+        if line.is_empty() {
+            continue;
+        }
+        if comment_prefixes.iter().any(|p| line.starts_with(p)) {
+            table.header.push(line.to_string());
+            continue;
+        }
+        match parse_numeric_row(line) {
+            Some(row) if row.len() >= 2 => table.rows.push(row),
+            _ if table.rows.is_empty() => table.header.push(line.to_string()),
+            _ => {}
+        }
+    }
+    table
+}
+
+/// Parse one line of numbers, guessing the separator.
+pub(super) fn parse_numeric_row(line: &str) -> Option<Vec<f64>> {
+    if line.contains(';') {
+        return parse_fields(line.split(';'), true);
+    }
+    if line.contains('\t') {
+        return parse_fields(line.split('\t'), true);
+    }
+    if line.contains(',') {
+        // Comma-separated values, or space-separated values with decimal commas
+        if let Some(row) = parse_fields(line.split(','), false) {
+            return Some(row);
+        }
+    }
+    parse_fields(line.split_whitespace(), true)
+}
+
+fn parse_fields<'a>(
+    fields: impl Iterator<Item = &'a str>,
+    decimal_comma: bool,
+) -> Option<Vec<f64>> {
+    let mut values = Vec::new();
+    for field in fields {
+        let field = field.trim();
+        if field.is_empty() {
+            // Trailing separators are common in spreadsheet exports
+            continue;
+        }
+        let value = if decimal_comma && !field.contains('.') {
+            field.replace(',', ".").parse::<f64>().ok()?
+        } else {
+            field.parse::<f64>().ok()?
+        };
+        values.push(value);
+    }
+    (!values.is_empty()).then_some(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_separators_and_decimal_comma() {
+        assert_eq!(
+            parse_numeric_row("20 80.5 -12"),
+            Some(vec![20.0, 80.5, -12.0])
+        );
+        assert_eq!(parse_numeric_row("20\t80.5"), Some(vec![20.0, 80.5]));
+        assert_eq!(parse_numeric_row("20;80,5;"), Some(vec![20.0, 80.5]));
+        assert_eq!(parse_numeric_row("20,80.5"), Some(vec![20.0, 80.5]));
+        assert_eq!(parse_numeric_row("20 80,5"), Some(vec![20.0, 80.5]));
+        assert_eq!(parse_numeric_row("1e3 7.5E-1"), Some(vec![1000.0, 0.75]));
+        assert_eq!(parse_numeric_row("Freq SPL"), None);
+    }
+
+    #[test]
+    fn test_table_header_and_rows() {
+        let text = "* Measurement: A\nFreq(Hz) SPL(dB)\n20 80\n\n40 81\n* trailer\nend\n";
+        let table = parse_numeric_table(text, &["*"]);
+        assert_eq!(
+            table.header,
+            vec!["* Measurement: A", "Freq(Hz) SPL(dB)", "* trailer"]
+        );
+        assert_eq!(table.rows, vec![vec![20.0, 80.0], vec![40.0, 81.0]]);
+    }
+}
@@ -0,0 +1,244 @@
+//! Grid interpolation
+//!
+//! Samples a row-major grid of cell values between cell centers, to
+//! upsample heatmaps and images to screen resolution.
+
+/// How a grid is sampled between cell centers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GridInterpolation {
+    /// Value of the nearest cell, keeping cells crisp (e.g. confusion
+    /// matrices)
+    #[default]
+    Nearest,
+    /// Linear blend of the 4 surrounding cells
+    Bilinear,
+    /// Catmull-Rom blend of the 16 surrounding cells, smoother than
+    /// bilinear (e.g. spectrograms)
+    Bicubic,
+}
+
+/// Sample a `width * height` row-major grid at (`u`, `v`)
+///
+/// Coordinates are in cells with cell centers on integers: (0, 0) is the
+/// center of the first cell. Positions beyond the outer centers take the
+/// edge values. Where a cell needed by the interpolation is not finite, the
+/// nearest cell is used instead, so gaps keep their shape. Bicubic samples
+/// are clamped to their 16 cells to avoid overshoot at sharp edges.
+///
+/// # Example
+///
+/// ```
+/// use d3rs::interpolate::{GridInterpolation, sample_grid};
+///
+/// let values = [0.0, 10.0, 20.0, 30.0];
+/// assert_eq!(sample_grid(&values, 2, 2, 0.5, 0.5, GridInterpolation::Bilinear), 15.0);
+/// assert_eq!(sample_grid(&values, 2, 2, 0.4, 0.6, GridInterpolation::Nearest), 20.0);
+/// ```
+pub fn sample_grid(
+    values: &[f64],
+    width: usize,
+    height: usize,
+    u: f64,
+    v: f64,
+    mode: GridInterpolation,
+) -> f64 {
+    if width == 0 || height == 0 || values.len() < width * height {
+        return f64::NAN;
+    }
+    let cell = |x: isize, y: isize| {
+        let x = x.clamp(0, width as isize - 1) as usize;
+        let y = y.clamp(0, height as isize - 1) as usize;
+        values[y * width + x]
+    };
+
+    let nearest = cell(u.round() as isize, v.round() as isize);
+    let (x0, y0) = (u.floor() as isize, v.floor() as isize);
+    let (fx, fy) = (u - x0 as f64, v - y0 as f64);
+
+    match mode {
+        GridInterpolation::Nearest => nearest,
+        GridInterpolation::Bilinear => {
+            let row = |y| cell(x0, y) * (1.0 - fx) + cell(x0 + 1, y) * fx;
+            let value = row(y0) * (1.0 - fy) + row(y0 + 1) * fy;
+            if value.is_finite() { value } else { nearest }
+        }
+        GridInterpolation::Bicubic => {
+            let (mut lo, mut hi) = (f64::INFINITY, f64::NEG_INFINITY);
+            let rows = [-1, 0, 1, 2].map(|j| {
+                let p = [-1, 0, 1, 2].map(|i| cell(x0 + i, y0 + j));
+                for &p in &p {
+                    lo = lo.min(p);
+                    hi = hi.max(p);
+                }
+                catmull_rom(p, fx)
+            });
+            // A non-finite cell makes the blend non-finite, so a finite
+            // value means `lo <= hi`
+            let value = catmull_rom(rows, fy);
+            if value.is_finite() {
+                value.clamp(lo, hi)
+            } else {
+                nearest
+            }
+        }
+    }
+}
+
+/// Catmull-Rom spline through `p[1]` (at 0) and `p[2]` (at 1)
+fn catmull_rom(p: [f64; 4], t: f64) -> f64 {
+    p[1] + 0.5
+        * t
+        * (p[2] - p[0]
+            + t * (2.0 * p[0] - 5.0 * p[1] + 4.0 * p[2] - p[3]
+                + t * (3.0 * (p[1] - p[2]) + p[3] - p[0])))
+}
+
+/// Cell coordinate of `position` along one axis, for [`sample_grid`]
+///
+/// `edges` holds the start and end of each cell, in any direction and unit,
+/// e.g. pixels after a log scale, so cells of uneven size are sampled
+/// evenly within each cell. Returns `None` outside all cells.
+pub fn grid_coordinate(edges: &[(f64, f64)], position: f64) -> Option<f64> {
+    edges.iter().enumerate().find_map(|(i, &(start, end))| {
+        let (lo, hi) = (start.min(end), start.max(end));
+        (hi > lo && (lo..=hi).contains(&position))
+            .then(|| i as f64 + (position - start) / (end - start) - 0.5)
+    })
+}
+
+/// Cell coordinates of `new_len` samples spread evenly over `len` cells
+///
+/// The first and last samples sit on the outer cell centers, so a grid
+/// resampled at these coordinates covers the same extent.
+pub fn resampled_coordinates(len: usize, new_len: usize) -> Vec<f64> {
+    if new_len <= 1 || len <= 1 {
+        return vec![0.0; new_len];
+    }
+    let step = (len - 1) as f64 / (new_len - 1) as f64;
+    (0..new_len).map(|i| i as f64 * step).collect()
+}
+
+/// Resample a `width * height` row-major grid to `new_width * new_height`
+///
+/// Samples are taken with [`sample_grid`] at [`resampled_coordinates`], e.g.
+/// to upsample a small grid to screen resolution ahead of rendering, or
+/// reduce a large one.
+///
+/// # Example
+///
+/// ```
+/// use d3rs::interpolate::{GridInterpolation, resample_grid};
+///
+/// let values = [0.0, 10.0];
+/// let resampled = resample_grid(&values, 2, 1, 3, 1, GridInterpolation::Bilinear);
+/// assert_eq!(resampled, vec![0.0, 5.0, 10.0]);
+/// ```
+pub fn resample_grid(
+    values: &[f64],
+    width: usize,
+    height: usize,
+    new_width: usize,
+    new_height: usize,
+    mode: GridInterpolation,
+) -> Vec<f64> {
+    let us = resampled_coordinates(width, new_width);
+    let vs = resampled_coordinates(height, new_height);
+    vs.iter()
+        .flat_map(|&v| {
+            us.iter()
+                .map(move |&u| sample_grid(values, width, height, u, v, mode))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 4x4 grid of `x + y`
+    fn ramp() -> Vec<f64> {
+        (0..16).map(|i| (i % 4 + i / 4) as f64).collect()
+    }
+
+    #[test]
+    fn test_modes_agree_on_cell_centers() {
+        for mode in [
+            GridInterpolation::Nearest,
+            GridInterpolation::Bilinear,
+            GridInterpolation::Bicubic,
+        ] {
+            assert_eq!(sample_grid(&ramp(), 4, 4, 1.0, 2.0, mode), 3.0);
+            // Beyond the edges, the edge value
+            assert_eq!(sample_grid(&ramp(), 4, 4, -0.4, 0.0, mode), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_smooth_modes_follow_a_ramp() {
+        // Both reproduce a linear gradient exactly
+        for mode in [GridInterpolation::Bilinear, GridInterpolation::Bicubic] {
+            let value = sample_grid(&ramp(), 4, 4, 1.75, 1.25, mode);
+            assert!((value - 3.0).abs() < 1e-12, "{mode:?}: {value}");
+        }
+        assert_eq!(
+            sample_grid(&ramp(), 4, 4, 1.75, 1.25, GridInterpolation::Nearest),
+            3.0
+        );
+        assert_eq!(
+            sample_grid(&ramp(), 4, 4, 0.4, 1.4, GridInterpolation::Nearest),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_bicubic_does_not_overshoot() {
+        let step = [0.0, 0.0, 1.0, 1.0];
+        for i in 0..=30 {
+            let u = i as f64 / 10.0;
+            let value = sample_grid(&step, 4, 1, u, 0.0, GridInterpolation::Bicubic);
+            assert!((0.0..=1.0).contains(&value), "{u}: {value}");
+        }
+    }
+
+    #[test]
+    fn test_gaps_fall_back_to_nearest() {
+        let values = [1.0, f64::NAN, 3.0, 4.0];
+        let bilinear = |u, v| sample_grid(&values, 2, 2, u, v, GridInterpolation::Bilinear);
+        assert!(bilinear(0.6, 0.0).is_nan());
+        assert_eq!(bilinear(0.4, 0.2), 1.0);
+        let bicubic = sample_grid(&values, 2, 2, 0.2, 0.8, GridInterpolation::Bicubic);
+        assert_eq!(bicubic, 3.0);
+        assert!(sample_grid(&values, 3, 2, 0.0, 0.0, GridInterpolation::Nearest).is_nan());
+    }
+
+    #[test]
+    fn test_grid_coordinate() {
+        // Cells of 10 px, and a y axis pointing down
+        let x_edges = [(0.0, 10.0), (10.0, 20.0)];
+        assert_eq!(grid_coordinate(&x_edges, 5.0), Some(0.0));
+        assert_eq!(grid_coordinate(&x_edges, 15.0), Some(1.0));
+        assert_eq!(grid_coordinate(&x_edges, 12.5), Some(0.75));
+        assert_eq!(grid_coordinate(&x_edges, 25.0), None);
+
+        let y_edges = [(20.0, 10.0), (10.0, 0.0)];
+        assert_eq!(grid_coordinate(&y_edges, 17.5), Some(-0.25));
+        assert_eq!(grid_coordinate(&y_edges, 5.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_resample_grid() {
+        assert_eq!(
+            resampled_coordinates(4, 7),
+            vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0]
+        );
+        assert_eq!(resampled_coordinates(4, 1), vec![0.0]);
+
+        // Upsampling a ramp keeps it a ramp, downsampling keeps the corners
+        let up = resample_grid(&ramp(), 4, 4, 7, 7, GridInterpolation::Bilinear);
+        assert_eq!(up.len(), 49);
+        assert_eq!(up[8], 1.0);
+        assert_eq!(up[48], 6.0);
+        let down = resample_grid(&ramp(), 4, 4, 2, 2, GridInterpolation::Nearest);
+        assert_eq!(down, vec![0.0, 3.0, 3.0, 6.0]);
+    }
+}
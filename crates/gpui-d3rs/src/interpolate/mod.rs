@@ -22,6 +22,7 @@
 
 mod array;
 mod color;
+mod grid;
 mod number;
 mod piecewise;
 mod string;
@@ -30,6 +31,7 @@ pub mod zoom;
 
 pub use array::*;
 pub use color::*;
+pub use grid::*;
 pub use number::*;
 pub use piecewise::*;
 pub use string::*;
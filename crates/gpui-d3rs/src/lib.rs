@@ -6,7 +6,7 @@
 //!
 //! - **Scales**: Linear, log, power, symlog, quantize, quantile, threshold scales
 //! - **Axes**: Four orientations (Top, Right, Bottom, Left) with customizable formatting
-//! - **Colors**: RGB/HSL with interpolation and categorical schemes
+//! - **Colors**: RGB/HSL/OKLCH with interpolation and categorical schemes
 //! - **Shapes**: Bars, lines, areas, scatter plots, arcs, pies, symbols, stacks
 //! - **Curves**: Linear, step, basis, cardinal, catmull-rom, monotone, natural
 //! - **Grids**: Dots and lines at tick intersections
@@ -16,6 +16,10 @@
 //! - **Contours**: Marching squares, density estimation (d3-contour)
 //! - **Fetch**: CSV/TSV/JSON parsing utilities (d3-fetch)
 //! - **Format**: Number formatting with SI prefixes, locales (d3-format)
+//! - **Acoustics**: Room mode calculator with axial/tangential/oblique modes and problem regions
+//! - **Exporters**: PEQ filters to Equalizer APO, miniDSP, RME TotalMix and PEACE
+//! - **Importers**: REW, Klippel and ARTA frequency response exports
+//! - **Spinorama**: Typed spinorama.org API client (`spinorama` feature)
 //!
 //! ## Example
 //!
@@ -31,14 +35,17 @@
 
 #![cfg_attr(feature = "gpui", recursion_limit = "512")]
 
+pub mod acoustics;
 pub mod array;
 pub mod brush;
 pub mod chord;
 pub mod color;
 pub mod ease;
+pub mod exporters;
 pub mod force;
 pub mod format;
 pub mod hierarchy;
+pub mod importers;
 pub mod interpolate;
 pub mod scale;
 pub mod time;
@@ -66,6 +73,8 @@ pub mod polygon;
 pub mod quadtree;
 pub mod random;
 pub mod shape;
+#[cfg(feature = "spinorama")]
+pub mod spinorama;
 #[cfg(all(feature = "gpui", not(test)))]
 pub mod surface;
 #[cfg(feature = "gpui")]
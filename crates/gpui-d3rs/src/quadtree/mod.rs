@@ -10,6 +10,9 @@
 //! - **Nearest Neighbor Search**: Find closest point to any location
 //! - **Range Queries**: Find all points within a radius
 //! - **Tree Traversal**: Visit nodes in pre-order or post-order
+//! - **Incremental Updates**: Move points in place with
+//!   [`QuadTree::update_positions`], recycling internal nodes through a
+//!   free list instead of rebuilding the tree every frame
 //!
 //! # Example
 //!
@@ -71,6 +74,26 @@ impl<T> QuadNode<T> {
     }
 }
 
+/// A point move for [`QuadTree::update_positions`]: `(old, new)` positions
+pub type PositionUpdate = ((f64, f64), (f64, f64));
+
+/// Children of an internal node
+type Children<T> = Box<[Option<QuadNode<T>>; 4]>;
+
+/// Free list of empty child arrays left by collapsed internal nodes
+///
+/// Moving points splits and collapses nodes constantly; reusing their
+/// allocations keeps per-frame updates free of allocator traffic.
+type NodePool<T> = Vec<Children<T>>;
+
+/// Get an internal node with no children, reusing a pooled allocation
+fn alloc_internal<T>(pool: &mut NodePool<T>) -> QuadNode<T> {
+    match pool.pop() {
+        Some(children) => QuadNode::Internal(children),
+        None => QuadNode::new_internal(),
+    }
+}
+
 /// Extent (bounding box) of the quadtree
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Extent {
@@ -128,6 +151,8 @@ pub struct QuadTree<T> {
     extent: Option<Extent>,
     /// Number of points in the tree
     size: usize,
+    /// Recycled internal nodes
+    free_nodes: NodePool<T>,
 }
 
 impl<T: Clone> Default for QuadTree<T> {
@@ -143,6 +168,7 @@ impl<T: Clone> QuadTree<T> {
             root: None,
             extent: None,
             size: 0,
+            free_nodes: Vec::new(),
         }
     }
 
@@ -252,9 +278,11 @@ impl<T: Clone> QuadTree<T> {
                     // D3.js places old node at parent[i] where i is the cover direction
                     if self.root.is_some() {
                         let old_root = self.root.take();
-                        let mut new_children: [Option<QuadNode<T>>; 4] = [None, None, None, None];
-                        new_children[i] = old_root;
-                        self.root = Some(QuadNode::Internal(Box::new(new_children)));
+                        let mut new_root = alloc_internal(&mut self.free_nodes);
+                        if let QuadNode::Internal(children) = &mut new_root {
+                            children[i] = old_root;
+                        }
+                        self.root = Some(new_root);
                     }
                 }
 
@@ -283,6 +311,7 @@ impl<T: Clone> QuadTree<T> {
 
         // Navigate to the correct position and insert
         self.root = Some(Self::add_to_node(
+            &mut self.free_nodes,
             self.root.take().unwrap(),
             point,
             ext.x0,
@@ -295,6 +324,7 @@ impl<T: Clone> QuadTree<T> {
     }
 
     fn add_to_node(
+        pool: &mut NodePool<T>,
         node: QuadNode<T>,
         point: QuadPoint<T>,
         x0: f64,
@@ -313,12 +343,12 @@ impl<T: Clone> QuadTree<T> {
                     QuadNode::Leaf(existing)
                 } else {
                     // Split into internal node
-                    let mut internal = QuadNode::new_internal();
+                    let mut internal = alloc_internal(pool);
 
                     // Insert existing point
-                    internal = Self::insert_into_internal(internal, existing, x0, y0, x1, y1);
+                    internal = Self::insert_into_internal(pool, internal, existing, x0, y0, x1, y1);
                     // Insert new point
-                    internal = Self::insert_into_internal(internal, point, x0, y0, x1, y1);
+                    internal = Self::insert_into_internal(pool, internal, point, x0, y0, x1, y1);
 
                     internal
                 }
@@ -332,7 +362,7 @@ impl<T: Clone> QuadTree<T> {
 
                 children[i] = Some(match children[i].take() {
                     None => QuadNode::Leaf(point),
-                    Some(child) => Self::add_to_node(child, point, nx0, ny0, nx1, ny1),
+                    Some(child) => Self::add_to_node(pool, child, point, nx0, ny0, nx1, ny1),
                 });
 
                 QuadNode::Internal(children)
@@ -341,6 +371,7 @@ impl<T: Clone> QuadTree<T> {
     }
 
     fn insert_into_internal(
+        pool: &mut NodePool<T>,
         node: QuadNode<T>,
         point: QuadPoint<T>,
         x0: f64,
@@ -358,7 +389,7 @@ impl<T: Clone> QuadTree<T> {
 
                 children[i] = Some(match children[i].take() {
                     None => QuadNode::Leaf(point),
-                    Some(child) => Self::add_to_node(child, point, nx0, ny0, nx1, ny1),
+                    Some(child) => Self::add_to_node(pool, child, point, nx0, ny0, nx1, ny1),
                 });
 
                 QuadNode::Internal(children)
@@ -426,20 +457,24 @@ impl<T: Clone> QuadTree<T> {
     ///
     /// Returns true if a point was removed
     pub fn remove(&mut self, x: f64, y: f64) -> bool {
+        self.take(x, y).is_some()
+    }
+
+    /// Remove a point from the quadtree and return its data
+    ///
+    /// With coincident points, the first one found is removed.
+    pub fn take(&mut self, x: f64, y: f64) -> Option<T> {
         if !x.is_finite() || !y.is_finite() || self.root.is_none() {
-            return false;
+            return None;
         }
 
-        let ext = match &self.extent {
-            Some(e) => *e,
-            None => return false,
-        };
-
+        let ext = self.extent?;
         if !ext.contains(x, y) {
-            return false;
+            return None;
         }
 
         let (new_root, removed) = Self::remove_from_node(
+            &mut self.free_nodes,
             self.root.take().unwrap(),
             x,
             y,
@@ -451,14 +486,55 @@ impl<T: Clone> QuadTree<T> {
 
         self.root = new_root;
 
-        if removed {
+        if removed.is_some() {
             self.size -= 1;
         }
 
         removed
     }
 
+    /// Move the point at `old` to `new`, keeping its data
+    ///
+    /// Only the affected branches are touched; the tree is not rebuilt.
+    /// Returns false (and leaves the tree unchanged) if there is no point at
+    /// `old` or `new` is not finite.
+    pub fn update_position(&mut self, old: (f64, f64), new: (f64, f64)) -> bool {
+        self.update_positions(&[(old, new)]) == 1
+    }
+
+    /// Move many points at once, as `(old, new)` position pairs
+    ///
+    /// All points are taken out before any is reinserted, so points may move
+    /// onto each other's previous positions (e.g. a permutation) without
+    /// being confused. Pairs whose `new` position is not finite or whose
+    /// `old` position holds no point are skipped. Returns the number of
+    /// points moved.
+    pub fn update_positions(&mut self, moves: &[PositionUpdate]) -> usize {
+        let mut moved = Vec::with_capacity(moves.len());
+        for &((old_x, old_y), (new_x, new_y)) in moves {
+            if !new_x.is_finite() || !new_y.is_finite() {
+                continue;
+            }
+            if let Some(data) = self.take(old_x, old_y) {
+                moved.push((new_x, new_y, data));
+            }
+        }
+
+        // Grow the extent once for all destinations, as in `add_all`
+        for &(x, y, _) in &moved {
+            self.cover(x, y);
+        }
+
+        let count = moved.len();
+        for (x, y, data) in moved {
+            self.add(x, y, data);
+        }
+        count
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn remove_from_node(
+        pool: &mut NodePool<T>,
         node: QuadNode<T>,
         x: f64,
         y: f64,
@@ -466,30 +542,27 @@ impl<T: Clone> QuadTree<T> {
         y0: f64,
         x1: f64,
         y1: f64,
-    ) -> (Option<QuadNode<T>>, bool) {
+    ) -> (Option<QuadNode<T>>, Option<T>) {
         match node {
             QuadNode::Leaf(mut point) => {
                 if (point.x - x).abs() < 1e-12 && (point.y - y).abs() < 1e-12 {
                     // Found the point - check for linked list
-                    if let Some(next) = point.next.take() {
-                        (Some(QuadNode::Leaf(*next)), true)
-                    } else {
-                        (None, true)
-                    }
+                    let next = point.next.take().map(|next| QuadNode::Leaf(*next));
+                    (next, Some(point.data))
                 } else {
                     // Check linked list for coincident points
-                    let mut removed = false;
                     let mut current = &mut point.next;
-
-                    while let Some(next) = current {
-                        if (next.x - x).abs() < 1e-12 && (next.y - y).abs() < 1e-12 {
-                            *current = next.next.take();
-                            removed = true;
-                            break;
-                        }
+                    while current.as_ref().is_some_and(|next| {
+                        (next.x - x).abs() >= 1e-12 || (next.y - y).abs() >= 1e-12
+                    }) {
                         current = &mut current.as_mut().unwrap().next;
                     }
 
+                    let removed = current.take().map(|mut found| {
+                        *current = found.next.take();
+                        found.data
+                    });
+
                     (Some(QuadNode::Leaf(point)), removed)
                 }
             }
@@ -501,7 +574,7 @@ impl<T: Clone> QuadTree<T> {
                 if let Some(child) = children[i].take() {
                     let (nx0, ny0, nx1, ny1) = Self::child_extent(i, x0, y0, x1, y1, xm, ym);
                     let (new_child, removed) =
-                        Self::remove_from_node(child, x, y, nx0, ny0, nx1, ny1);
+                        Self::remove_from_node(pool, child, x, y, nx0, ny0, nx1, ny1);
                     children[i] = new_child;
 
                     // Check if we can collapse the node
@@ -518,16 +591,18 @@ impl<T: Clone> QuadTree<T> {
                     }
 
                     if non_empty_count == 0 {
+                        pool.push(children);
                         (None, removed)
                     } else if non_empty_count == 1 && single_leaf.is_some() {
                         // Collapse to single leaf
                         let leaf = children[single_leaf.unwrap()].take();
+                        pool.push(children);
                         (leaf, removed)
                     } else {
                         (Some(QuadNode::Internal(children)), removed)
                     }
                 } else {
-                    (Some(QuadNode::Internal(children)), false)
+                    (Some(QuadNode::Internal(children)), None)
                 }
             }
         }
@@ -950,6 +1025,65 @@ mod tests {
         assert!(!tree.remove(100.0, 100.0));
     }
 
+    #[test]
+    fn test_update_position() {
+        let mut tree = QuadTree::new();
+        tree.add(0.0, 0.0, "a");
+        tree.add(1.0, 1.0, "b");
+
+        assert!(tree.update_position((1.0, 1.0), (5.0, 3.0)));
+        assert_eq!(tree.size(), 2);
+        assert_eq!(tree.find(5.0, 3.0, Some(0.1)), Some(&"b"));
+        assert_eq!(tree.find(1.0, 1.0, Some(0.1)), None);
+
+        // Missing source or invalid destination leave the tree unchanged
+        assert!(!tree.update_position((9.0, 9.0), (0.5, 0.5)));
+        assert!(!tree.update_position((0.0, 0.0), (f64::NAN, 0.0)));
+        assert_eq!(tree.find(0.0, 0.0, Some(0.1)), Some(&"a"));
+        assert_eq!(tree.take(0.0, 0.0), Some("a"));
+    }
+
+    #[test]
+    fn test_update_positions_matches_rebuild() {
+        let points: Vec<(f64, f64, usize)> = (0..500)
+            .map(|i| ((i * 37 % 101) as f64, (i * 53 % 97) as f64, i))
+            .collect();
+        let mut tree = QuadTree::new();
+        for &(x, y, i) in &points {
+            tree.add(x, y, i);
+        }
+
+        // Swap pairs of points and shift the rest, twice
+        let mut current = points.clone();
+        for step in 0..2 {
+            let next: Vec<(f64, f64, usize)> = current
+                .iter()
+                .enumerate()
+                .map(|(k, &(x, y, i))| {
+                    if k % 2 == 0 && k + 1 < current.len() {
+                        (current[k + 1].0, current[k + 1].1, i)
+                    } else if k % 2 == 1 {
+                        (current[k - 1].0, current[k - 1].1, i)
+                    } else {
+                        (x + 0.5 + step as f64, y - 0.25, i)
+                    }
+                })
+                .collect();
+            let moves: Vec<((f64, f64), (f64, f64))> = current
+                .iter()
+                .zip(&next)
+                .map(|(a, b)| ((a.0, a.1), (b.0, b.1)))
+                .collect();
+            assert_eq!(tree.update_positions(&moves), points.len());
+            current = next;
+        }
+
+        assert_eq!(tree.size(), points.len());
+        let mut data: Vec<(f64, f64, usize)> = tree.data();
+        data.sort_by_key(|d| d.2);
+        assert_eq!(data, current);
+    }
+
     #[test]
     fn test_coincident_points() {
         let mut tree = QuadTree::new();
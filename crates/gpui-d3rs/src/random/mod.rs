@@ -1,6 +1,9 @@
 //! Random number generators (d3-random)
 //!
 //! This module provides various random number distributions useful for visualization.
+//! The distributions use the same algorithms as d3-random, and [`SeededRng`]
+//! reproduces `d3.randomLcg`, so a seeded generator yields the same values as
+//! the JavaScript version on every run and platform.
 //!
 //! Note: These are simple implementations for visualization purposes, not cryptographically secure.
 //!
@@ -16,9 +19,27 @@
 //! let normal = RandomNormal::new(0.0, 1.0);
 //! let value = normal.sample(); // Standard normal distribution
 //! ```
+//!
+//! Reproducible samples, like `d3.randomNormal.source(d3.randomLcg(42))(0, 1)`:
+//!
+//! ```
+//! use d3rs::random::{RandomNormal, SeededRng};
+//!
+//! let a = RandomNormal::with_source(0.0, 1.0, SeededRng::new(42));
+//! let b = RandomNormal::with_source(0.0, 1.0, SeededRng::new(42));
+//! assert_eq!(a.sample(), b.sample());
+//! ```
 
 use std::cell::Cell;
 
+/// A source of uniform random numbers in [0, 1)
+///
+/// Every distribution draws from a source, like `random.source(...)` in d3.
+pub trait RandomSource {
+    /// Generate the next random value in [0, 1)
+    fn next_f64(&self) -> f64;
+}
+
 /// A simple linear congruential generator for reproducible random numbers
 #[derive(Debug, Clone)]
 pub struct LcgRng {
@@ -66,12 +87,182 @@ impl Default for LcgRng {
     }
 }
 
+impl RandomSource for LcgRng {
+    fn next_f64(&self) -> f64 {
+        LcgRng::next_f64(self)
+    }
+}
+
+/// Seedable generator producing the same sequence as `d3.randomLcg(seed)`
+///
+/// A 32-bit linear congruential generator: fast and portable, with values
+/// that match d3 bit for bit, at the cost of a shorter period than [`LcgRng`].
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: Cell<u32>,
+}
+
+impl SeededRng {
+    const MUL: u32 = 0x19660D;
+    const INC: u32 = 0x3C6EF35F;
+    const EPS: f64 = 1.0 / 4_294_967_296.0;
+
+    /// Create a generator from an integer seed
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: Cell::new(seed as u32),
+        }
+    }
+
+    /// Create a generator from any seed accepted by `d3.randomLcg`
+    ///
+    /// Seeds in [0, 1) are scaled to 32 bits, so values from another random
+    /// source make good seeds; other seeds use their integer part.
+    pub fn from_f64(seed: f64) -> Self {
+        let seed = if (0.0..1.0).contains(&seed) {
+            seed / Self::EPS
+        } else {
+            seed.abs()
+        };
+        let state = if seed.is_finite() {
+            seed.trunc().rem_euclid(4_294_967_296.0) as u32
+        } else {
+            0
+        };
+        Self {
+            state: Cell::new(state),
+        }
+    }
+}
+
+impl RandomSource for SeededRng {
+    fn next_f64(&self) -> f64 {
+        let state = self
+            .state
+            .get()
+            .wrapping_mul(Self::MUL)
+            .wrapping_add(Self::INC);
+        self.state.set(state);
+        state as f64 * Self::EPS
+    }
+}
+
+/// Standard normal values by the Marsaglia polar method
+///
+/// Each round yields two values; the second is kept for the next call, as
+/// d3 does.
+#[derive(Debug, Clone, Default)]
+struct PolarNormal {
+    spare: Cell<Option<(f64, f64)>>,
+}
+
+impl PolarNormal {
+    fn sample(&self, source: &impl RandomSource) -> f64 {
+        let (y, r) = match self.spare.take() {
+            Some(spare) => spare,
+            None => loop {
+                let x = source.next_f64() * 2.0 - 1.0;
+                let y = source.next_f64() * 2.0 - 1.0;
+                let r = x * x + y * y;
+                if r != 0.0 && r <= 1.0 {
+                    self.spare.set(Some((x, r)));
+                    break (y, r);
+                }
+            },
+        };
+        y * (-2.0 * r.ln() / r).sqrt()
+    }
+}
+
+/// Gamma sample by the Marsaglia-Tsang method
+fn sample_gamma(source: &impl RandomSource, normal: &PolarNormal, k: f64, theta: f64) -> f64 {
+    if k <= 0.0 {
+        return 0.0;
+    }
+    if k == 1.0 {
+        return -(-source.next_f64()).ln_1p() * theta;
+    }
+    let d = if k < 1.0 { k + 1.0 } else { k } - 1.0 / 3.0;
+    let c = 1.0 / (3.0 * d.sqrt());
+    loop {
+        let (x, v) = loop {
+            let x = normal.sample(source);
+            let v = 1.0 + c * x;
+            if v > 0.0 {
+                break (x, v * v * v);
+            }
+        };
+        let u = 1.0 - source.next_f64();
+        if u < 1.0 - 0.0331 * x * x * x * x || u.ln() < 0.5 * x * x + d * (1.0 - v + v.ln()) {
+            let multiplier = if k < 1.0 {
+                source.next_f64().powf(1.0 / k)
+            } else {
+                1.0
+            };
+            return d * v * multiplier * theta;
+        }
+    }
+}
+
+/// Beta sample as the ratio of two gamma samples
+fn sample_beta(source: &impl RandomSource, normal: &PolarNormal, alpha: f64, beta: f64) -> f64 {
+    let x = sample_gamma(source, normal, alpha, 1.0);
+    if x == 0.0 {
+        0.0
+    } else {
+        x / (x + sample_gamma(source, normal, beta, 1.0))
+    }
+}
+
+/// Number of trials up to the first success, `f64::INFINITY` when p is 0
+fn sample_geometric(source: &impl RandomSource, p: f64) -> f64 {
+    if p <= 0.0 {
+        return f64::INFINITY;
+    }
+    if p >= 1.0 {
+        return 1.0;
+    }
+    1.0 + ((-source.next_f64()).ln_1p() / (-p).ln_1p()).floor()
+}
+
+/// Binomial sample, splitting large counts with beta samples
+fn sample_binomial(source: &impl RandomSource, normal: &PolarNormal, n: f64, p: f64) -> f64 {
+    if p >= 1.0 {
+        return n;
+    }
+    if p <= 0.0 {
+        return 0.0;
+    }
+    let (mut acc, mut n, mut p) = (0.0, n, p);
+    while n * p > 16.0 && n * (1.0 - p) > 16.0 {
+        let i = ((n + 1.0) * p).floor();
+        let y = sample_beta(source, normal, i, n - i + 1.0);
+        if y <= p {
+            acc += i;
+            n -= i;
+            p = (p - y) / (1.0 - y);
+        } else {
+            n = i - 1.0;
+            p /= y;
+        }
+    }
+    let below_half = p < 0.5;
+    let p_final = if below_half { p } else { 1.0 - p };
+    let mut s = sample_geometric(source, p_final);
+    let mut k = 0.0;
+    while s <= n {
+        s += sample_geometric(source, p_final);
+        k += 1.0;
+    }
+    acc + if below_half { k } else { n - k }
+}
+
 /// Uniform distribution random generator
 ///
 /// Generates random numbers uniformly distributed in [min, max).
 #[derive(Debug, Clone)]
-pub struct RandomUniform {
-    rng: LcgRng,
+pub struct RandomUniform<R = LcgRng> {
+    rng: R,
     min: f64,
     max: f64,
 }
@@ -79,26 +270,25 @@ pub struct RandomUniform {
 impl RandomUniform {
     /// Create a uniform generator in [min, max)
     pub fn new(min: f64, max: f64) -> Self {
-        Self {
-            rng: LcgRng::default_seed(),
-            min,
-            max,
-        }
+        Self::with_source(min, max, LcgRng::default_seed())
     }
 
     /// Create a uniform generator with a specific seed
     pub fn with_seed(min: f64, max: f64, seed: u64) -> Self {
-        Self {
-            rng: LcgRng::new(seed),
-            min,
-            max,
-        }
+        Self::with_source(min, max, LcgRng::new(seed))
     }
 
     /// Create a uniform generator in [0, 1)
     pub fn unit() -> Self {
         Self::new(0.0, 1.0)
     }
+}
+
+impl<R: RandomSource> RandomUniform<R> {
+    /// Create a uniform generator drawing from `rng`
+    pub fn with_source(min: f64, max: f64, rng: R) -> Self {
+        Self { rng, min, max }
+    }
 
     /// Sample a random value
     pub fn sample(&self) -> f64 {
@@ -108,10 +298,11 @@ impl RandomUniform {
 
 /// Normal (Gaussian) distribution random generator
 ///
-/// Uses the Box-Muller transform.
+/// Uses the Marsaglia polar method.
 #[derive(Debug, Clone)]
-pub struct RandomNormal {
-    rng: LcgRng,
+pub struct RandomNormal<R = LcgRng> {
+    rng: R,
+    polar: PolarNormal,
     mean: f64,
     std_dev: f64,
 }
@@ -119,40 +310,41 @@ pub struct RandomNormal {
 impl RandomNormal {
     /// Create a normal generator with given mean and standard deviation
     pub fn new(mean: f64, std_dev: f64) -> Self {
-        Self {
-            rng: LcgRng::default_seed(),
-            mean,
-            std_dev,
-        }
+        Self::with_source(mean, std_dev, LcgRng::default_seed())
     }
 
     /// Create a normal generator with a specific seed
     pub fn with_seed(mean: f64, std_dev: f64, seed: u64) -> Self {
-        Self {
-            rng: LcgRng::new(seed),
-            mean,
-            std_dev,
-        }
+        Self::with_source(mean, std_dev, LcgRng::new(seed))
     }
 
     /// Create a standard normal generator (mean=0, std_dev=1)
     pub fn standard() -> Self {
         Self::new(0.0, 1.0)
     }
+}
+
+impl<R: RandomSource> RandomNormal<R> {
+    /// Create a normal generator drawing from `rng`
+    pub fn with_source(mean: f64, std_dev: f64, rng: R) -> Self {
+        Self {
+            rng,
+            polar: PolarNormal::default(),
+            mean,
+            std_dev,
+        }
+    }
 
-    /// Sample a random value using Box-Muller transform
+    /// Sample a random value
     pub fn sample(&self) -> f64 {
-        let u1 = self.rng.next_f64();
-        let u2 = self.rng.next_f64();
-        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
-        self.mean + z * self.std_dev
+        self.mean + self.std_dev * self.polar.sample(&self.rng)
     }
 }
 
 /// Log-normal distribution random generator
 #[derive(Debug, Clone)]
-pub struct RandomLogNormal {
-    normal: RandomNormal,
+pub struct RandomLogNormal<R = LcgRng> {
+    normal: RandomNormal<R>,
 }
 
 impl RandomLogNormal {
@@ -161,15 +353,20 @@ impl RandomLogNormal {
     /// The parameters mu and sigma are the mean and standard deviation
     /// of the underlying normal distribution.
     pub fn new(mu: f64, sigma: f64) -> Self {
-        Self {
-            normal: RandomNormal::new(mu, sigma),
-        }
+        Self::with_source(mu, sigma, LcgRng::default_seed())
     }
 
     /// Create with a specific seed
     pub fn with_seed(mu: f64, sigma: f64, seed: u64) -> Self {
+        Self::with_source(mu, sigma, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomLogNormal<R> {
+    /// Create a log-normal generator drawing from `rng`
+    pub fn with_source(mu: f64, sigma: f64, rng: R) -> Self {
         Self {
-            normal: RandomNormal::with_seed(mu, sigma, seed),
+            normal: RandomNormal::with_source(mu, sigma, rng),
         }
     }
 
@@ -181,54 +378,59 @@ impl RandomLogNormal {
 
 /// Exponential distribution random generator
 #[derive(Debug, Clone)]
-pub struct RandomExponential {
-    rng: LcgRng,
+pub struct RandomExponential<R = LcgRng> {
+    rng: R,
     lambda: f64,
 }
 
 impl RandomExponential {
     /// Create an exponential generator with rate parameter lambda
     pub fn new(lambda: f64) -> Self {
-        Self {
-            rng: LcgRng::default_seed(),
-            lambda,
-        }
+        Self::with_source(lambda, LcgRng::default_seed())
     }
 
     /// Create with a specific seed
     pub fn with_seed(lambda: f64, seed: u64) -> Self {
-        Self {
-            rng: LcgRng::new(seed),
-            lambda,
-        }
+        Self::with_source(lambda, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomExponential<R> {
+    /// Create an exponential generator drawing from `rng`
+    pub fn with_source(lambda: f64, rng: R) -> Self {
+        Self { rng, lambda }
     }
 
     /// Sample a random value
     pub fn sample(&self) -> f64 {
-        -self.rng.next_f64().ln() / self.lambda
+        -(-self.rng.next_f64()).ln_1p() / self.lambda
     }
 }
 
 /// Bernoulli distribution random generator
 #[derive(Debug, Clone)]
-pub struct RandomBernoulli {
-    rng: LcgRng,
+pub struct RandomBernoulli<R = LcgRng> {
+    rng: R,
     p: f64,
 }
 
 impl RandomBernoulli {
     /// Create a Bernoulli generator with probability p
     pub fn new(p: f64) -> Self {
-        Self {
-            rng: LcgRng::default_seed(),
-            p: p.clamp(0.0, 1.0),
-        }
+        Self::with_source(p, LcgRng::default_seed())
     }
 
     /// Create with a specific seed
     pub fn with_seed(p: f64, seed: u64) -> Self {
+        Self::with_source(p, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomBernoulli<R> {
+    /// Create a Bernoulli generator drawing from `rng`
+    pub fn with_source(p: f64, rng: R) -> Self {
         Self {
-            rng: LcgRng::new(seed),
+            rng,
             p: p.clamp(0.0, 1.0),
         }
     }
@@ -244,70 +446,244 @@ impl RandomBernoulli {
     }
 }
 
+/// Geometric distribution random generator
+///
+/// Counts the Bernoulli trials up to and including the first success.
+#[derive(Debug, Clone)]
+pub struct RandomGeometric<R = LcgRng> {
+    rng: R,
+    p: f64,
+}
+
+impl RandomGeometric {
+    /// Create a geometric generator with success probability p
+    pub fn new(p: f64) -> Self {
+        Self::with_source(p, LcgRng::default_seed())
+    }
+
+    /// Create with a specific seed
+    pub fn with_seed(p: f64, seed: u64) -> Self {
+        Self::with_source(p, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomGeometric<R> {
+    /// Create a geometric generator drawing from `rng`
+    pub fn with_source(p: f64, rng: R) -> Self {
+        Self {
+            rng,
+            p: p.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Sample a random value (`u64::MAX` when p is 0)
+    pub fn sample(&self) -> u64 {
+        let trials = sample_geometric(&self.rng, self.p);
+        if trials.is_finite() {
+            trials as u64
+        } else {
+            u64::MAX
+        }
+    }
+}
+
+/// Binomial distribution random generator
+///
+/// Counts the successes in n Bernoulli trials.
+#[derive(Debug, Clone)]
+pub struct RandomBinomial<R = LcgRng> {
+    rng: R,
+    beta_normal: PolarNormal,
+    n: u64,
+    p: f64,
+}
+
+impl RandomBinomial {
+    /// Create a binomial generator for n trials with success probability p
+    pub fn new(n: u64, p: f64) -> Self {
+        Self::with_source(n, p, LcgRng::default_seed())
+    }
+
+    /// Create with a specific seed
+    pub fn with_seed(n: u64, p: f64, seed: u64) -> Self {
+        Self::with_source(n, p, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomBinomial<R> {
+    /// Create a binomial generator drawing from `rng`
+    pub fn with_source(n: u64, p: f64, rng: R) -> Self {
+        Self {
+            rng,
+            beta_normal: PolarNormal::default(),
+            n,
+            p,
+        }
+    }
+
+    /// Sample a random value
+    pub fn sample(&self) -> u64 {
+        sample_binomial(&self.rng, &self.beta_normal, self.n as f64, self.p) as u64
+    }
+}
+
+/// Gamma distribution random generator
+#[derive(Debug, Clone)]
+pub struct RandomGamma<R = LcgRng> {
+    rng: R,
+    normal: PolarNormal,
+    k: f64,
+    theta: f64,
+}
+
+impl RandomGamma {
+    /// Create a gamma generator with shape k and scale theta
+    pub fn new(k: f64, theta: f64) -> Self {
+        Self::with_source(k, theta, LcgRng::default_seed())
+    }
+
+    /// Create with a specific seed
+    pub fn with_seed(k: f64, theta: f64, seed: u64) -> Self {
+        Self::with_source(k, theta, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomGamma<R> {
+    /// Create a gamma generator drawing from `rng`
+    pub fn with_source(k: f64, theta: f64, rng: R) -> Self {
+        Self {
+            rng,
+            normal: PolarNormal::default(),
+            k,
+            theta,
+        }
+    }
+
+    /// Sample a random value
+    pub fn sample(&self) -> f64 {
+        sample_gamma(&self.rng, &self.normal, self.k, self.theta)
+    }
+}
+
+/// Beta distribution random generator
+#[derive(Debug, Clone)]
+pub struct RandomBeta<R = LcgRng> {
+    rng: R,
+    normal: PolarNormal,
+    alpha: f64,
+    beta: f64,
+}
+
+impl RandomBeta {
+    /// Create a beta generator with shape parameters alpha and beta
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        Self::with_source(alpha, beta, LcgRng::default_seed())
+    }
+
+    /// Create with a specific seed
+    pub fn with_seed(alpha: f64, beta: f64, seed: u64) -> Self {
+        Self::with_source(alpha, beta, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomBeta<R> {
+    /// Create a beta generator drawing from `rng`
+    pub fn with_source(alpha: f64, beta: f64, rng: R) -> Self {
+        Self {
+            rng,
+            normal: PolarNormal::default(),
+            alpha,
+            beta,
+        }
+    }
+
+    /// Sample a random value in [0, 1]
+    pub fn sample(&self) -> f64 {
+        sample_beta(&self.rng, &self.normal, self.alpha, self.beta)
+    }
+}
+
 /// Poisson distribution random generator
 #[derive(Debug, Clone)]
-pub struct RandomPoisson {
-    rng: LcgRng,
+pub struct RandomPoisson<R = LcgRng> {
+    rng: R,
+    gamma_normal: PolarNormal,
+    beta_normal: PolarNormal,
     lambda: f64,
 }
 
 impl RandomPoisson {
     /// Create a Poisson generator with rate parameter lambda
     pub fn new(lambda: f64) -> Self {
-        Self {
-            rng: LcgRng::default_seed(),
-            lambda,
-        }
+        Self::with_source(lambda, LcgRng::default_seed())
     }
 
     /// Create with a specific seed
     pub fn with_seed(lambda: f64, seed: u64) -> Self {
+        Self::with_source(lambda, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomPoisson<R> {
+    /// Create a Poisson generator drawing from `rng`
+    pub fn with_source(lambda: f64, rng: R) -> Self {
         Self {
-            rng: LcgRng::new(seed),
+            rng,
+            gamma_normal: PolarNormal::default(),
+            beta_normal: PolarNormal::default(),
             lambda,
         }
     }
 
-    /// Sample a random value using the Knuth algorithm
+    /// Sample a random value
+    ///
+    /// Large rates are reduced with gamma and binomial samples, so the cost
+    /// stays bounded instead of growing with lambda.
     pub fn sample(&self) -> u64 {
-        let l = (-self.lambda).exp();
-        let mut k = 0u64;
-        let mut p = 1.0;
-
-        loop {
-            k += 1;
-            p *= self.rng.next_f64();
-            if p <= l {
-                break;
+        let (mut acc, mut l) = (0.0, self.lambda);
+        while l > 16.0 {
+            let n = (0.875 * l).floor();
+            let t = sample_gamma(&self.rng, &self.gamma_normal, n, 1.0);
+            if t > l {
+                return (acc + sample_binomial(&self.rng, &self.beta_normal, n - 1.0, l / t))
+                    as u64;
             }
+            acc += n;
+            l -= t;
         }
-
-        k - 1
+        let mut s = -(-self.rng.next_f64()).ln_1p();
+        let mut k = 0.0;
+        while s <= l {
+            s -= (-self.rng.next_f64()).ln_1p();
+            k += 1.0;
+        }
+        (acc + k) as u64
     }
 }
 
 /// Irwin-Hall distribution (sum of n uniform random variables)
 #[derive(Debug, Clone)]
-pub struct RandomIrwinHall {
-    rng: LcgRng,
+pub struct RandomIrwinHall<R = LcgRng> {
+    rng: R,
     n: usize,
 }
 
 impl RandomIrwinHall {
     /// Create an Irwin-Hall generator with n uniform summands
     pub fn new(n: usize) -> Self {
-        Self {
-            rng: LcgRng::default_seed(),
-            n,
-        }
+        Self::with_source(n, LcgRng::default_seed())
     }
 
     /// Create with a specific seed
     pub fn with_seed(n: usize, seed: u64) -> Self {
-        Self {
-            rng: LcgRng::new(seed),
-            n,
-        }
+        Self::with_source(n, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomIrwinHall<R> {
+    /// Create an Irwin-Hall generator drawing from `rng`
+    pub fn with_source(n: usize, rng: R) -> Self {
+        Self { rng, n }
     }
 
     /// Sample a random value
@@ -318,43 +694,51 @@ impl RandomIrwinHall {
 
 /// Bates distribution (mean of n uniform random variables)
 #[derive(Debug, Clone)]
-pub struct RandomBates {
-    irwin_hall: RandomIrwinHall,
+pub struct RandomBates<R = LcgRng> {
+    irwin_hall: RandomIrwinHall<R>,
 }
 
 impl RandomBates {
     /// Create a Bates generator with n uniform summands
     pub fn new(n: usize) -> Self {
-        Self {
-            irwin_hall: RandomIrwinHall::new(n),
-        }
+        Self::with_source(n, LcgRng::default_seed())
     }
 
     /// Create with a specific seed
     pub fn with_seed(n: usize, seed: u64) -> Self {
+        Self::with_source(n, LcgRng::new(seed))
+    }
+}
+
+impl<R: RandomSource> RandomBates<R> {
+    /// Create a Bates generator drawing from `rng`
+    pub fn with_source(n: usize, rng: R) -> Self {
         Self {
-            irwin_hall: RandomIrwinHall::with_seed(n, seed),
+            irwin_hall: RandomIrwinHall::with_source(n, rng),
         }
     }
 
-    /// Sample a random value
+    /// Sample a random value (uniform when n is 0, as in d3)
     pub fn sample(&self) -> f64 {
-        self.irwin_hall.sample() / self.irwin_hall.n as f64
+        match self.irwin_hall.n {
+            0 => self.irwin_hall.rng.next_f64(),
+            n => self.irwin_hall.sample() / n as f64,
+        }
     }
 }
 
 /// Generate a shuffled copy of a slice
-pub fn shuffle<T: Clone>(rng: &LcgRng, data: &[T]) -> Vec<T> {
+pub fn shuffle<T: Clone>(rng: &impl RandomSource, data: &[T]) -> Vec<T> {
     let mut result = data.to_vec();
     shuffle_in_place(rng, &mut result);
     result
 }
 
 /// Shuffle a slice in place using Fisher-Yates algorithm
-pub fn shuffle_in_place<T>(rng: &LcgRng, data: &mut [T]) {
+pub fn shuffle_in_place<T>(rng: &impl RandomSource, data: &mut [T]) {
     let n = data.len();
     for i in (1..n).rev() {
-        let j = rng.next_u64(i as u64 + 1) as usize;
+        let j = (rng.next_f64() * (i + 1) as f64) as usize;
         data.swap(i, j);
     }
 }
@@ -467,4 +851,78 @@ mod tests {
         let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
         assert!((mean - 0.5).abs() < 0.1);
     }
+
+    #[test]
+    fn test_seeded_rng_matches_d3() {
+        // d3.randomLcg(42)
+        let rng = SeededRng::new(42);
+        assert_eq!(rng.next_f64(), 0.2523451747838408);
+        assert_eq!(rng.next_f64(), 0.08812504541128874);
+        assert_eq!(rng.next_f64(), 0.5772811982315034);
+
+        // Fractional and negative seeds
+        assert_eq!(SeededRng::from_f64(0.5).next_f64(), 0.7360679728444666);
+        assert_eq!(SeededRng::from_f64(-7.0).next_f64(), 0.23878083983436227);
+        assert_eq!(
+            SeededRng::from_f64(42.0).next_f64(),
+            SeededRng::new(42).next_f64()
+        );
+    }
+
+    #[test]
+    fn test_seeded_distributions_reproducible() {
+        let a = RandomPoisson::with_source(40.0, SeededRng::new(7));
+        let b = RandomPoisson::with_source(40.0, SeededRng::new(7));
+        for _ in 0..100 {
+            assert_eq!(a.sample(), b.sample());
+        }
+    }
+
+    #[test]
+    fn test_poisson() {
+        for lambda in [3.0, 50.0] {
+            let poisson = RandomPoisson::with_seed(lambda, 12345);
+            let samples: Vec<f64> = (0..10000).map(|_| poisson.sample() as f64).collect();
+            let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+            let variance: f64 = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>()
+                / (samples.len() - 1) as f64;
+
+            // Mean and variance are both lambda
+            assert!((mean - lambda).abs() < 0.05 * lambda);
+            assert!((variance - lambda).abs() < 0.1 * lambda);
+        }
+    }
+
+    #[test]
+    fn test_gamma_and_beta() {
+        let gamma = RandomGamma::with_seed(3.0, 2.0, 12345);
+        let mean: f64 = (0..10000).map(|_| gamma.sample()).sum::<f64>() / 10000.0;
+        // Mean is k * theta = 6
+        assert!((mean - 6.0).abs() < 0.2);
+
+        let beta = RandomBeta::with_seed(2.0, 6.0, 12345);
+        let samples: Vec<f64> = (0..10000).map(|_| beta.sample()).collect();
+        assert!(samples.iter().all(|&x| (0.0..=1.0).contains(&x)));
+        // Mean is alpha / (alpha + beta) = 0.25
+        let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!((mean - 0.25).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_binomial_and_geometric() {
+        for (n, p) in [(10, 0.3), (1000, 0.4)] {
+            let binomial = RandomBinomial::with_seed(n, p, 12345);
+            let samples: Vec<u64> = (0..5000).map(|_| binomial.sample()).collect();
+            assert!(samples.iter().all(|&k| k <= n));
+            let mean = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+            assert!((mean - n as f64 * p).abs() < 0.05 * n as f64 * p);
+        }
+
+        let geometric = RandomGeometric::with_seed(0.25, 12345);
+        let mean = (0..10000).map(|_| geometric.sample()).sum::<u64>() as f64 / 10000.0;
+        // Mean is 1 / p = 4
+        assert!((mean - 4.0).abs() < 0.2);
+        assert_eq!(RandomGeometric::with_seed(1.0, 1).sample(), 1);
+        assert_eq!(RandomGeometric::with_seed(0.0, 1).sample(), u64::MAX);
+    }
 }
@@ -4,6 +4,7 @@
 
 use super::curve::Curve;
 use super::path::{Path, PathBuilder, Point};
+use super::simplify::Simplify;
 
 /// An area generator for creating filled area shapes.
 ///
@@ -30,6 +31,7 @@ pub struct Area<T> {
     y1: Option<Box<dyn Fn(&T) -> f64>>,
     defined: Box<dyn Fn(&T) -> bool>,
     curve: Curve,
+    simplify: Option<Simplify>,
 }
 
 impl<T> Default for Area<T> {
@@ -43,6 +45,7 @@ impl<T> Default for Area<T> {
             y1: None,
             defined: Box::new(|_| true),
             curve: Curve::Linear,
+            simplify: None,
         }
     }
 }
@@ -124,6 +127,15 @@ impl<T> Area<T> {
         self
     }
 
+    /// Simplify the top and bottom lines before building the path.
+    ///
+    /// The tolerance is in output units, i.e. pixels when the accessors
+    /// return screen coordinates.
+    pub fn simplify(mut self, simplify: Simplify) -> Self {
+        self.simplify = Some(simplify);
+        self
+    }
+
     /// Generate the area path from data.
     pub fn generate(&self, data: &[T]) -> Path {
         if data.is_empty() {
@@ -173,6 +185,11 @@ impl<T> Area<T> {
                 })
                 .collect();
 
+            let (top_points, bottom_points) = match self.simplify {
+                Some(simplify) => (simplify.apply(&top_points), simplify.apply(&bottom_points)),
+                None => (top_points, bottom_points),
+            };
+
             // Generate curved path for top line
             if !top_points.is_empty() {
                 let first = top_points[0];
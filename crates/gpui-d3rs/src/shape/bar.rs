@@ -1,5 +1,6 @@
 //! Bar chart rendering
 
+use super::pattern::{FillPattern, paint_pattern_rect};
 use crate::color::{ColorScheme, D3Color};
 use crate::scale::Scale;
 use gpui::prelude::*;
@@ -21,6 +22,8 @@ pub struct BarConfig {
     pub stroke_color: Option<D3Color>,
     /// Stroke width in pixels
     pub stroke_width: f32,
+    /// Optional pattern drawn over the fill
+    pub pattern: Option<FillPattern>,
 }
 
 impl Default for BarConfig {
@@ -32,6 +35,7 @@ impl Default for BarConfig {
             border_radius: 2.0,
             stroke_color: None,
             stroke_width: 1.0,
+            pattern: None,
         }
     }
 }
@@ -77,6 +81,12 @@ impl BarConfig {
         self.stroke_width = width;
         self
     }
+
+    /// Draw a pattern over the fill
+    pub fn pattern(mut self, pattern: FillPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
 }
 
 /// Data point for a bar chart
@@ -98,6 +108,69 @@ impl BarDatum {
     }
 }
 
+/// Position of a bar in the plot area, in pixels from its top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BarRect {
+    /// Index of the datum (single series) or category (grouped bars)
+    pub index: usize,
+    /// Index of the series (always 0 for single-series bars)
+    pub series: usize,
+    /// Left edge
+    pub x: f32,
+    /// Top edge
+    pub y: f32,
+    /// Bar width
+    pub width: f32,
+    /// Bar height
+    pub height: f32,
+}
+
+/// Compute the bars of [`render_bars`] without rendering them.
+pub fn bar_rects<XS, YS>(
+    x_scale: &XS,
+    y_scale: &YS,
+    data: &[BarDatum],
+    width: f32,
+    height: f32,
+    config: &BarConfig,
+) -> Vec<BarRect>
+where
+    XS: Scale<f64, f64>,
+    YS: Scale<f64, f64>,
+{
+    let (x_min, x_max) = x_scale.range();
+    let x_range_span = x_max - x_min;
+
+    // Calculate bar width based on number of bars
+    let bar_count = data.len() as f32;
+    let available_width = width - (config.bar_gap * (bar_count - 1.0));
+    let bar_width = if bar_count > 0.0 {
+        available_width / bar_count
+    } else {
+        0.0
+    };
+
+    let baseline_pos = baseline_position(y_scale);
+
+    data.iter()
+        .enumerate()
+        .map(|(i, datum)| {
+            let x_value = i as f64 + 0.5; // Center bars at integer positions
+            let x_range = x_scale.scale(x_value);
+            let x_pos = ((x_range - x_min) / x_range_span) as f32;
+            let (y, bar_height) = bar_extent(y_scale, datum.value, baseline_pos, height);
+            BarRect {
+                index: i,
+                series: 0,
+                x: x_pos * width - bar_width / 2.0,
+                y,
+                width: bar_width,
+                height: bar_height,
+            }
+        })
+        .collect()
+}
+
 /// Render a bar chart
 ///
 /// # Example
@@ -130,75 +203,99 @@ where
     XS: Scale<f64, f64>,
     YS: Scale<f64, f64>,
 {
-    let (x_min, x_max) = x_scale.range();
-    let (y_min, y_max) = y_scale.range();
-    let x_range_span = x_max - x_min;
-    let y_range_span = y_max - y_min;
-
-    // Calculate bar width based on number of bars
-    let bar_count = data.len() as f32;
-    let available_width = width - (config.bar_gap * (bar_count - 1.0));
-    let bar_width = if bar_count > 0.0 {
-        available_width / bar_count
-    } else {
-        0.0
-    };
+    let fill = config.fill_color.to_rgba();
+    div().absolute().inset_0().children(
+        bar_rects(x_scale, y_scale, data, width, height, config)
+            .into_iter()
+            .map(|rect| {
+                bar_element(
+                    rect,
+                    fill,
+                    config.opacity,
+                    config.border_radius,
+                    config.stroke_color.as_ref(),
+                    config.stroke_width,
+                    config.pattern,
+                )
+            }),
+    )
+}
 
-    // Get baseline (zero point in y scale)
+/// Relative position (0 at the top) of the bar baseline: zero when the
+/// domain contains it, the domain minimum otherwise.
+fn baseline_position<YS: Scale<f64, f64>>(y_scale: &YS) -> f32 {
+    let (y_min, y_max) = y_scale.range();
     let (y_domain_min, y_domain_max) = y_scale.domain();
     let baseline = if y_domain_min <= 0.0 && y_domain_max >= 0.0 {
         y_scale.scale(0.0)
     } else {
         y_scale.scale(y_domain_min)
     };
-    let baseline_pos = 1.0 - ((baseline - y_min) / y_range_span) as f32;
+    1.0 - ((baseline - y_min) / (y_max - y_min)) as f32
+}
+
+/// Top and height in pixels of a bar from the baseline to `value`.
+fn bar_extent<YS: Scale<f64, f64>>(
+    y_scale: &YS,
+    value: f64,
+    baseline_pos: f32,
+    height: f32,
+) -> (f32, f32) {
+    let (y_min, y_max) = y_scale.range();
+    let y_range = y_scale.scale(value);
+    // Invert Y for screen coordinates (bottom-to-top becomes top-to-bottom)
+    let y_pos = 1.0 - ((y_range - y_min) / (y_max - y_min)) as f32;
+    let bar_top = if value >= 0.0 { y_pos } else { baseline_pos };
+    (bar_top * height, (baseline_pos - y_pos).abs() * height)
+}
 
-    div()
+/// Bar element at a computed position.
+fn bar_element(
+    rect: BarRect,
+    fill: Rgba,
+    opacity: f32,
+    border_radius: f32,
+    stroke: Option<&D3Color>,
+    stroke_width: f32,
+    pattern: Option<FillPattern>,
+) -> Div {
+    let mut bar = div()
         .absolute()
-        .inset_0()
-        .children(data.iter().enumerate().map(|(i, datum)| {
-            let x_value = i as f64 + 0.5; // Center bars at integer positions
-            let x_range = x_scale.scale(x_value);
-            let x_pos = ((x_range - x_min) / x_range_span) as f32;
+        .left(px(rect.x))
+        .top(px(rect.y))
+        .w(px(rect.width))
+        .h(px(rect.height))
+        .bg(fill)
+        .opacity(opacity);
+
+    if border_radius > 0.0 {
+        bar = bar.rounded(px(border_radius));
+    }
 
-            let y_range = y_scale.scale(datum.value);
-            // Invert Y for screen coordinates (bottom-to-top becomes top-to-bottom)
-            let y_pos = 1.0 - ((y_range - y_min) / y_range_span) as f32;
-
-            // Calculate bar height (from baseline to value) - convert from relative to pixels
-            let bar_height_rel = (baseline_pos - y_pos).abs();
-            let bar_height_px = bar_height_rel * height;
-            let bar_top = if datum.value >= 0.0 {
-                y_pos
-            } else {
-                baseline_pos
-            };
-            let bar_top_px = bar_top * height;
-
-            let fill = config.fill_color.to_rgba();
-
-            let mut bar = div()
-                .absolute()
-                .left(relative(x_pos))
-                .top(px(bar_top_px))
-                .w(px(bar_width))
-                .h(px(bar_height_px))
-                .ml(px(-bar_width / 2.0)) // Center the bar
-                .bg(fill)
-                .opacity(config.opacity);
-
-            if config.border_radius > 0.0 {
-                bar = bar.rounded(px(config.border_radius));
-            }
+    if let Some(stroke) = stroke {
+        bar = bar.border_color(stroke.to_rgba()).border(px(stroke_width));
+    }
 
-            if let Some(stroke) = &config.stroke_color {
-                bar = bar
-                    .border_color(stroke.to_rgba())
-                    .border(px(config.stroke_width));
-            }
+    if let Some(pattern) = pattern {
+        bar = bar.overflow_hidden().child(
+            canvas(
+                |_, _, _| {},
+                move |bounds, _, window, _| {
+                    paint_pattern_rect(
+                        window,
+                        bounds.origin.x.into(),
+                        bounds.origin.y.into(),
+                        bounds.size.width.into(),
+                        bounds.size.height.into(),
+                        &pattern,
+                    );
+                },
+            )
+            .size_full(),
+        );
+    }
 
-            bar
-        }))
+    bar
 }
 
 // =============================================================================
@@ -246,6 +343,8 @@ pub struct GroupedBarConfig {
     pub stroke_color: Option<D3Color>,
     /// Stroke width in pixels
     pub stroke_width: f32,
+    /// Patterns drawn over the fills, cycled per series
+    pub series_patterns: Vec<FillPattern>,
 }
 
 impl Default for GroupedBarConfig {
@@ -259,6 +358,7 @@ impl Default for GroupedBarConfig {
             border_radius: 2.0,
             stroke_color: None,
             stroke_width: 1.0,
+            series_patterns: Vec::new(),
         }
     }
 }
@@ -317,6 +417,21 @@ impl GroupedBarConfig {
         self
     }
 
+    /// Draw patterns over the fills, cycling through `patterns` per series
+    pub fn series_patterns(mut self, patterns: Vec<FillPattern>) -> Self {
+        self.series_patterns = patterns;
+        self
+    }
+
+    /// Get pattern for a series by index
+    fn get_series_pattern(&self, index: usize) -> Option<FillPattern> {
+        if self.series_patterns.is_empty() {
+            None
+        } else {
+            Some(self.series_patterns[index % self.series_patterns.len()])
+        }
+    }
+
     /// Get color for a series by index
     fn get_series_color(&self, index: usize) -> D3Color {
         if let Some(ref colors) = self.series_colors
@@ -379,35 +494,17 @@ pub fn analyze_grouped_data(data: &[GroupedBarDatum]) -> GroupedBarMeta {
     }
 }
 
-/// Render a grouped bar chart
-///
-/// # Example
-///
-/// ```rust,no_run
-/// use d3rs::prelude::*;
-/// use d3rs::shape::{render_grouped_bars, GroupedBarConfig, GroupedBarDatum, analyze_grouped_data};
-///
-/// let data = vec![
-///     GroupedBarDatum::new("Q1", "Product A", 50.0),
-///     GroupedBarDatum::new("Q1", "Product B", 80.0),
-///     GroupedBarDatum::new("Q2", "Product A", 70.0),
-///     GroupedBarDatum::new("Q2", "Product B", 60.0),
-/// ];
-///
-/// let meta = analyze_grouped_data(&data);
-/// let y_scale = LinearScale::new().domain(0.0, meta.max_value).range(300.0, 0.0);
+/// Compute the bars of [`render_grouped_bars`] without rendering them.
 ///
-/// let config = GroupedBarConfig::new();
-/// // render_grouped_bars(&y_scale, &data, &meta, 400.0, 300.0, &config)
-/// ```
-pub fn render_grouped_bars<YS>(
+/// Bars whose category or series is missing from `meta` are skipped.
+pub fn grouped_bar_rects<YS>(
     y_scale: &YS,
     data: &[GroupedBarDatum],
     meta: &GroupedBarMeta,
     width: f32,
     height: f32,
     config: &GroupedBarConfig,
-) -> impl IntoElement
+) -> Vec<BarRect>
 where
     YS: Scale<f64, f64>,
 {
@@ -415,7 +512,7 @@ where
     let num_series = meta.series.len() as f32;
 
     if num_categories == 0.0 || num_series == 0.0 {
-        return div().absolute().inset_0();
+        return Vec::new();
     }
 
     // Calculate group and bar widths
@@ -442,66 +539,76 @@ where
         .map(|(i, s)| (s.as_str(), i))
         .collect();
 
-    // Get baseline (zero point in y scale)
-    let (y_min, y_max) = y_scale.range();
-    let y_range_span = y_max - y_min;
-    let (y_domain_min, y_domain_max) = y_scale.domain();
-    let baseline = if y_domain_min <= 0.0 && y_domain_max >= 0.0 {
-        y_scale.scale(0.0)
-    } else {
-        y_scale.scale(y_domain_min)
-    };
-    let baseline_pos = 1.0 - ((baseline - y_min) / y_range_span) as f32;
+    let baseline_pos = baseline_position(y_scale);
 
-    div()
-        .absolute()
-        .inset_0()
-        .children(data.iter().filter_map(|datum| {
+    data.iter()
+        .filter_map(|datum| {
             let cat_idx = *category_index.get(datum.category.as_str())?;
             let ser_idx = *series_index.get(datum.series.as_str())?;
 
-            // Calculate x position for this bar
-            // Group start position
+            // Group start position plus bar position within group
             let group_start = cat_idx as f32 * (group_width + config.group_gap);
-            // Bar position within group
             let bar_offset = ser_idx as f32 * (bar_width + config.bar_gap);
-            let x_pos = group_start + bar_offset;
-
-            // Calculate y position
-            let y_range = y_scale.scale(datum.value);
-            let y_pos = 1.0 - ((y_range - y_min) / y_range_span) as f32;
-
-            // Calculate bar height (from baseline to value)
-            let bar_height_rel = (baseline_pos - y_pos).abs();
-            let bar_height_px = bar_height_rel * height;
-            let bar_top = if datum.value >= 0.0 {
-                y_pos
-            } else {
-                baseline_pos
-            };
-            let bar_top_px = bar_top * height;
-
-            let fill = config.get_series_color(ser_idx).to_rgba();
-
-            let mut bar = div()
-                .absolute()
-                .left(px(x_pos))
-                .top(px(bar_top_px))
-                .w(px(bar_width))
-                .h(px(bar_height_px))
-                .bg(fill)
-                .opacity(config.opacity);
-
-            if config.border_radius > 0.0 {
-                bar = bar.rounded(px(config.border_radius));
-            }
-
-            if let Some(stroke) = &config.stroke_color {
-                bar = bar
-                    .border_color(stroke.to_rgba())
-                    .border(px(config.stroke_width));
-            }
+            let (y, bar_height) = bar_extent(y_scale, datum.value, baseline_pos, height);
+
+            Some(BarRect {
+                index: cat_idx,
+                series: ser_idx,
+                x: group_start + bar_offset,
+                y,
+                width: bar_width,
+                height: bar_height,
+            })
+        })
+        .collect()
+}
 
-            Some(bar)
-        }))
+/// Render a grouped bar chart
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use d3rs::prelude::*;
+/// use d3rs::shape::{render_grouped_bars, GroupedBarConfig, GroupedBarDatum, analyze_grouped_data};
+///
+/// let data = vec![
+///     GroupedBarDatum::new("Q1", "Product A", 50.0),
+///     GroupedBarDatum::new("Q1", "Product B", 80.0),
+///     GroupedBarDatum::new("Q2", "Product A", 70.0),
+///     GroupedBarDatum::new("Q2", "Product B", 60.0),
+/// ];
+///
+/// let meta = analyze_grouped_data(&data);
+/// let y_scale = LinearScale::new().domain(0.0, meta.max_value).range(300.0, 0.0);
+///
+/// let config = GroupedBarConfig::new();
+/// // render_grouped_bars(&y_scale, &data, &meta, 400.0, 300.0, &config)
+/// ```
+pub fn render_grouped_bars<YS>(
+    y_scale: &YS,
+    data: &[GroupedBarDatum],
+    meta: &GroupedBarMeta,
+    width: f32,
+    height: f32,
+    config: &GroupedBarConfig,
+) -> impl IntoElement
+where
+    YS: Scale<f64, f64>,
+{
+    div().absolute().inset_0().children(
+        grouped_bar_rects(y_scale, data, meta, width, height, config)
+            .into_iter()
+            .map(|rect| {
+                let fill = config.get_series_color(rect.series).to_rgba();
+                bar_element(
+                    rect,
+                    fill,
+                    config.opacity,
+                    config.border_radius,
+                    config.stroke_color.as_ref(),
+                    config.stroke_width,
+                    config.get_series_pattern(rect.series),
+                )
+            }),
+    )
 }
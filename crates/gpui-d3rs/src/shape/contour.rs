@@ -2,14 +2,48 @@
 //!
 //! Provides visualization of contour data generated by the d3-contour module.
 
+use super::dash::dash_polyline;
+use super::pattern::{FillPattern, paint_pattern, paint_pattern_rect};
 use crate::color::D3Color;
 use crate::contour::Contour;
+use crate::interpolate::GridInterpolation;
 use crate::scale::Scale;
 use gpui::prelude::*;
 use gpui::*;
 use std::panic;
 use std::sync::Arc;
 
+/// How grid cells without data (NaN values) are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NoDataStyle {
+    /// Leave the cell empty so the background shows through
+    #[default]
+    Transparent,
+    /// Fill the cell with a solid color
+    Color(D3Color),
+    /// Diagonal hatching over a background color
+    Hatch {
+        /// Color of the hatch lines
+        color: D3Color,
+        /// Color behind the lines
+        background: D3Color,
+    },
+}
+
+impl NoDataStyle {
+    /// Solid color standing for the style, for renderers without patterns
+    pub fn fill_color(&self) -> Option<D3Color> {
+        match self {
+            NoDataStyle::Transparent => None,
+            NoDataStyle::Color(color) => Some(*color),
+            NoDataStyle::Hatch { background, .. } => Some(*background),
+        }
+    }
+}
+
+/// Spacing between hatch lines of [`NoDataStyle::Hatch`] in pixels
+const HATCH_SPACING: f32 = 6.0;
+
 /// Configuration for contour rendering
 #[derive(Clone)]
 pub struct ContourConfig {
@@ -27,6 +61,15 @@ pub struct ContourConfig {
     pub stroke_color: D3Color,
     /// Fixed fill color (used if color_scale is None)
     pub fill_color: D3Color,
+    /// How heatmap cells without data are drawn
+    pub no_data: NoDataStyle,
+    /// How heatmaps are upsampled to screen resolution by the GPU renderer
+    pub interpolation: GridInterpolation,
+    /// Patterns drawn over filled bands, cycled from the lowest band
+    pub band_patterns: Vec<FillPattern>,
+    /// Dash pattern of contour lines as alternating dash and gap lengths in
+    /// pixels
+    pub stroke_dash: Option<Vec<f32>>,
 }
 
 impl Default for ContourConfig {
@@ -39,6 +82,10 @@ impl Default for ContourConfig {
             color_scale: None,
             stroke_color: D3Color::from_hex(0x4682b4),
             fill_color: D3Color::from_hex(0x4682b4),
+            no_data: NoDataStyle::default(),
+            interpolation: GridInterpolation::default(),
+            band_patterns: Vec::new(),
+            stroke_dash: None,
         }
     }
 }
@@ -82,6 +129,14 @@ impl ContourConfig {
         self
     }
 
+    /// Dash contour lines with alternating dash and gap lengths in pixels
+    ///
+    /// See [`crate::shape::dash`] for how patterns are interpreted.
+    pub fn stroke_dash(mut self, pattern: &[f32]) -> Self {
+        self.stroke_dash = Some(pattern.to_vec());
+        self
+    }
+
     /// Set fixed stroke color
     pub fn stroke_color(mut self, color: D3Color) -> Self {
         self.stroke_color = color;
@@ -93,6 +148,28 @@ impl ContourConfig {
         self.fill_color = color;
         self
     }
+
+    /// Set how heatmap cells without data (NaN) are drawn
+    pub fn no_data(mut self, style: NoDataStyle) -> Self {
+        self.no_data = style;
+        self
+    }
+
+    /// Set how heatmaps are upsampled to screen resolution
+    ///
+    /// Smooth modes are rendered by `gpu2d::render_heatmap`; the
+    /// cell-based [`render_heatmap`] always draws nearest cells.
+    pub fn interpolation(mut self, interpolation: GridInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Draw patterns over filled bands, cycling through `patterns` from the
+    /// lowest band, so bands stay distinguishable without color
+    pub fn band_patterns(mut self, patterns: Vec<FillPattern>) -> Self {
+        self.band_patterns = patterns;
+        self
+    }
 }
 
 /// A custom element for rendering contours
@@ -365,8 +442,6 @@ where
                     let x_jump_threshold = width * 0.15;
                     let y_jump_threshold = height * 0.15;
 
-                    let mut builder = PathBuilder::stroke(px(self.config.stroke_width));
-
                     // Skip the last point if it's a duplicate of the first (closing point added by marching squares)
                     let points_to_draw = if screen_points.len() >= 2 {
                         let first = &screen_points[0];
@@ -383,22 +458,34 @@ where
                         &screen_points[..]
                     };
 
-                    for i in 0..points_to_draw.len() {
-                        if i == 0 {
-                            builder.move_to(points_to_draw[0]);
-                        } else {
-                            let prev = &points_to_draw[i - 1];
-                            let curr = &points_to_draw[i];
-                            let dx: f32 = (curr.x - prev.x).abs().into();
-                            let dy: f32 = (curr.y - prev.y).abs().into();
-
+                    // Split into runs at large jumps (lift the pen)
+                    let mut runs: Vec<Vec<(f32, f32)>> = Vec::new();
+                    for (i, curr) in points_to_draw.iter().enumerate() {
+                        let curr = (f32::from(curr.x), f32::from(curr.y));
+                        let jump = i == 0 || {
+                            let prev = points_to_draw[i - 1];
+                            let dx = (curr.0 - f32::from(prev.x)).abs();
+                            let dy = (curr.1 - f32::from(prev.y)).abs();
                             // Jump if either axis has a large discontinuity
-                            if dx > x_jump_threshold || dy > y_jump_threshold {
-                                // Large jump detected - start a new sub-path (lift the pen)
-                                builder.move_to(*curr);
-                            } else {
-                                builder.line_to(*curr);
-                            }
+                            dx > x_jump_threshold || dy > y_jump_threshold
+                        };
+                        match runs.last_mut() {
+                            Some(run) if !jump => run.push(curr),
+                            _ => runs.push(vec![curr]),
+                        }
+                    }
+                    if let Some(pattern) = &self.config.stroke_dash {
+                        runs = runs
+                            .iter()
+                            .flat_map(|run| dash_polyline(run, pattern))
+                            .collect();
+                    }
+
+                    let mut builder = PathBuilder::stroke(px(self.config.stroke_width));
+                    for run in &runs {
+                        builder.move_to(point(px(run[0].0), px(run[0].1)));
+                        for &(x, y) in &run[1..] {
+                            builder.line_to(point(px(x), px(y)));
                         }
                     }
 
@@ -779,8 +866,12 @@ where
         let y_range_lo = y_range_min.min(y_range_max);
 
         // Paint each band (from lowest to highest value for proper layering)
-        for band in self.bands.iter() {
+        for (band_index, band) in self.bands.iter().enumerate() {
             let fill_color = self.get_fill_color(band.mid_value());
+            let pattern = (!self.config.band_patterns.is_empty())
+                .then(|| &self.config.band_patterns[band_index % self.config.band_patterns.len()]);
+            // Outer rings and holes of the band, for the pattern
+            let mut pattern_rings: Vec<Vec<(f32, f32)>> = Vec::new();
 
             for ring in &band.polygons {
                 if ring.points.len() < 3 {
@@ -837,8 +928,22 @@ where
                     if let Ok(stroke_path) = stroke_builder.build() {
                         window.paint_path(stroke_path, stroke_color);
                     }
+
+                    if pattern.is_some() {
+                        pattern_rings.push(
+                            screen_points
+                                .iter()
+                                .map(|p| (p.x.into(), p.y.into()))
+                                .collect(),
+                        );
+                    }
                 }
             }
+
+            if let Some(pattern) = pattern {
+                let rings: Vec<&[(f32, f32)]> = pattern_rings.iter().map(Vec::as_slice).collect();
+                paint_pattern(window, &rings, pattern);
+            }
         }
     }
 }
@@ -874,6 +979,8 @@ pub struct HeatmapData {
     pub width: usize,
     /// Number of rows
     pub height: usize,
+    /// Cells left blank, in the same order as `values`
+    pub mask: Option<Vec<bool>>,
 }
 
 impl HeatmapData {
@@ -887,9 +994,27 @@ impl HeatmapData {
             values,
             width,
             height,
+            mask: None,
         }
     }
 
+    /// Blank the cells whose mask entry is `true`
+    ///
+    /// Masked cells are never drawn, not even with the no-data style.
+    pub fn with_mask(mut self, mask: Vec<bool>) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Whether the cell at grid position is masked
+    pub fn is_masked(&self, x: usize, y: usize) -> bool {
+        self.mask
+            .as_ref()
+            .and_then(|mask| mask.get(y * self.width + x))
+            .copied()
+            .unwrap_or(false)
+    }
+
     /// Get value at grid position
     pub fn get(&self, x: usize, y: usize) -> Option<f64> {
         if x < self.width && y < self.height {
@@ -898,6 +1023,57 @@ impl HeatmapData {
             None
         }
     }
+
+    /// Data extent of the cell at grid position, as `((x0, x1), (y0, y1))`
+    ///
+    /// A cell spans from its grid value to the next one; the last column
+    /// and row repeat the previous spacing.
+    pub fn cell_extent(&self, x: usize, y: usize) -> Option<((f64, f64), (f64, f64))> {
+        if x < self.width && y < self.height {
+            Some((cell_span(&self.x_values, x), cell_span(&self.y_values, y)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Span of grid cell `i` along one axis
+fn cell_span(values: &[f64], i: usize) -> (f64, f64) {
+    let start = values[i];
+    let end = match values.get(i + 1) {
+        Some(&next) => next,
+        // Extrapolate for the last cell
+        None if i > 0 => start + (start - values[i - 1]),
+        None => start * 1.1,
+    };
+    (start, end)
+}
+
+/// A heatmap cell laid out in element coordinates, see [`HeatmapElement::cells`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatmapCell {
+    /// Left edge, from the element origin
+    pub x: f32,
+    /// Top edge, from the element origin
+    pub y: f32,
+    /// Width, including a slight overlap with the next cell
+    pub width: f32,
+    /// Height, including a slight overlap with the next cell
+    pub height: f32,
+    /// Fill color
+    pub fill: Rgba,
+    /// Hatch color of a missing value drawn with [`NoDataStyle::Hatch`]
+    pub hatch: Option<D3Color>,
+}
+
+/// Screen range of the scales and element size shared by all cells
+struct CellFrame {
+    x_lo: f64,
+    y_lo: f64,
+    x_span: f64,
+    y_span: f64,
+    width: f32,
+    height: f32,
 }
 
 /// A custom element for rendering heatmaps as colored quads
@@ -984,6 +1160,79 @@ where
             self.config.fill_color
         }
     }
+
+    /// Lay out the cells in an element of `width` x `height` pixels
+    ///
+    /// Masked cells, and missing values with the transparent no-data style,
+    /// are skipped. This is all the work done per cell when painting.
+    pub fn cells(&self, width: f32, height: f32) -> impl Iterator<Item = HeatmapCell> + '_ {
+        // Get the range (screen coordinates) for proper normalization
+        // Use actual min/max for proper handling of inverted scales
+        let (x_range_min, x_range_max) = self.x_scale.range();
+        let (y_range_min, y_range_max) = self.y_scale.range();
+        let frame = CellFrame {
+            x_lo: x_range_min.min(x_range_max),
+            y_lo: y_range_min.min(y_range_max),
+            x_span: (x_range_max - x_range_min).abs(),
+            y_span: (y_range_max - y_range_min).abs(),
+            width,
+            height,
+        };
+        (0..self.data.height)
+            .flat_map(move |yi| (0..self.data.width).map(move |xi| (xi, yi)))
+            .filter_map(move |(xi, yi)| self.cell(xi, yi, &frame))
+    }
+
+    /// Lay out the cell at grid position
+    fn cell(&self, xi: usize, yi: usize, frame: &CellFrame) -> Option<HeatmapCell> {
+        if self.data.is_masked(xi, yi) {
+            return None;
+        }
+        let value = self.data.get(xi, yi).filter(|v| v.is_finite());
+        if value.is_none() && self.config.no_data == NoDataStyle::Transparent {
+            return None;
+        }
+
+        // Get cell boundaries in data coordinates
+        let ((x0_data, x1_data), (y0_data, y1_data)) = self.data.cell_extent(xi, yi)?;
+
+        // Transform to screen coordinates using the scale, normalized to 0-1
+        // Y scale is already inverted (high values at top=0, low at bottom=height)
+        // so no additional inversion needed here
+        let x0_norm = ((self.x_scale.scale(x0_data) - frame.x_lo) / frame.x_span) as f32;
+        let x1_norm = ((self.x_scale.scale(x1_data) - frame.x_lo) / frame.x_span) as f32;
+        let y0_norm = ((self.y_scale.scale(y0_data) - frame.y_lo) / frame.y_span) as f32;
+        let y1_norm = ((self.y_scale.scale(y1_data) - frame.y_lo) / frame.y_span) as f32;
+
+        let x0 = x0_norm.min(x1_norm) * frame.width;
+        let x1 = x0_norm.max(x1_norm) * frame.width;
+        let y0 = y0_norm.min(y1_norm) * frame.height;
+        let y1 = y0_norm.max(y1_norm) * frame.height;
+
+        // Get color for this cell
+        let fill = match value {
+            Some(value) => {
+                let mut rgba = self.get_fill_color(value).to_rgba();
+                rgba.a *= self.config.fill_opacity;
+                rgba
+            }
+            None => self.config.no_data.fill_color()?.to_rgba(),
+        };
+        let hatch = match (value, self.config.no_data) {
+            (None, NoDataStyle::Hatch { color, .. }) => Some(color),
+            _ => None,
+        };
+
+        // Ensure minimum cell size of 1 pixel and slight overlap to prevent gaps
+        Some(HeatmapCell {
+            x: x0,
+            y: y0,
+            width: (x1 - x0).max(1.0) + 0.5,
+            height: (y1 - y0).max(1.0) + 0.5,
+            fill,
+            hatch,
+        })
+    }
 }
 
 impl<XS, YS> IntoElement for HeatmapElement<XS, YS>
@@ -1063,97 +1312,36 @@ where
         let width: f32 = bounds.size.width.into();
         let height: f32 = bounds.size.height.into();
 
-        // Get the range (screen coordinates) for proper normalization
-        let (x_range_min, x_range_max) = self.x_scale.range();
-        let (y_range_min, y_range_max) = self.y_scale.range();
-        let x_range_span = (x_range_max - x_range_min).abs();
-        let y_range_span = (y_range_max - y_range_min).abs();
-
-        // Paint each cell as a quad (rectangle)
-        for yi in 0..self.data.height {
-            for xi in 0..self.data.width {
-                let value = match self.data.get(xi, yi) {
-                    Some(v) if v.is_finite() => v,
-                    _ => continue,
-                };
-
-                // Get cell boundaries in data coordinates
-                let x0_data = self.data.x_values[xi];
-                let x1_data = if xi + 1 < self.data.width {
-                    self.data.x_values[xi + 1]
-                } else {
-                    // Extrapolate for last column
-                    if xi > 0 {
-                        x0_data + (x0_data - self.data.x_values[xi - 1])
-                    } else {
-                        x0_data * 1.1
-                    }
-                };
-
-                let y0_data = self.data.y_values[yi];
-                let y1_data = if yi + 1 < self.data.height {
-                    self.data.y_values[yi + 1]
-                } else {
-                    // Extrapolate for last row
-                    if yi > 0 {
-                        y0_data + (y0_data - self.data.y_values[yi - 1])
-                    } else {
-                        y0_data * 1.1
-                    }
-                };
-
-                // Transform to screen coordinates using the scale
-                let x0_scaled = self.x_scale.scale(x0_data);
-                let x1_scaled = self.x_scale.scale(x1_data);
-                let y0_scaled = self.y_scale.scale(y0_data);
-                let y1_scaled = self.y_scale.scale(y1_data);
-
-                // Normalize to 0-1 based on range
-                // Use actual min/max for proper handling of inverted scales
-                let x_range_lo = x_range_min.min(x_range_max);
-                let y_range_lo = y_range_min.min(y_range_max);
-
-                let x0_norm = ((x0_scaled - x_range_lo) / x_range_span) as f32;
-                let x1_norm = ((x1_scaled - x_range_lo) / x_range_span) as f32;
-                // Y scale is already inverted (high values at top=0, low at bottom=height)
-                // so no additional inversion needed here
-                let y0_norm = ((y0_scaled - y_range_lo) / y_range_span) as f32;
-                let y1_norm = ((y1_scaled - y_range_lo) / y_range_span) as f32;
-
-                // Convert to screen pixels
-                let screen_x0 = origin_x + x0_norm.min(x1_norm) * width;
-                let screen_x1 = origin_x + x0_norm.max(x1_norm) * width;
-                let screen_y0 = origin_y + y0_norm.min(y1_norm) * height;
-                let screen_y1 = origin_y + y0_norm.max(y1_norm) * height;
-
-                // Ensure minimum cell size of 1 pixel and slight overlap to prevent gaps
-                let cell_width = (screen_x1 - screen_x0).max(1.0) + 0.5;
-                let cell_height = (screen_y1 - screen_y0).max(1.0) + 0.5;
-
-                // Get color for this cell
-                let fill_color = self.get_fill_color(value);
-                let mut fill_rgba = fill_color.to_rgba();
-                fill_rgba.a *= self.config.fill_opacity;
-
-                // Paint as a quad (rectangle) - no anti-aliasing gaps!
-                let cell_bounds = Bounds::new(
-                    point(px(screen_x0), px(screen_y0)),
-                    size(px(cell_width), px(cell_height)),
-                );
-
-                window.paint_quad(PaintQuad {
-                    bounds: cell_bounds,
-                    corner_radii: Corners::default(),
-                    background: fill_rgba.into(),
-                    border_widths: Edges::default(),
-                    border_color: gpui::transparent_black(),
-                    border_style: Default::default(),
-                });
+        // Paint each cell as a quad (rectangle) - no anti-aliasing gaps!
+        for cell in self.cells(width, height) {
+            let (x, y) = (origin_x + cell.x, origin_y + cell.y);
+            window.paint_quad(PaintQuad {
+                bounds: Bounds::new(point(px(x), px(y)), size(px(cell.width), px(cell.height))),
+                corner_radii: Corners::default(),
+                background: cell.fill.into(),
+                border_widths: Edges::default(),
+                border_color: gpui::transparent_black(),
+                border_style: Default::default(),
+            });
+
+            if let Some(color) = cell.hatch {
+                paint_hatch(window, x, y, cell.width, cell.height, color);
             }
         }
     }
 }
 
+/// Paint diagonal hatch lines clipped to a cell
+///
+/// Lines follow `x + y = k * HATCH_SPACING` in window coordinates, so the
+/// pattern continues seamlessly across neighbouring cells.
+fn paint_hatch(window: &mut Window, x0: f32, y0: f32, width: f32, height: f32, color: D3Color) {
+    // Patterns are spaced perpendicular to their lines
+    let pattern =
+        FillPattern::hatch(color).spacing(HATCH_SPACING * std::f32::consts::FRAC_1_SQRT_2);
+    paint_pattern_rect(window, x0, y0, width, height, &pattern);
+}
+
 /// Render a heatmap (2D grid of colored cells) using scales
 pub fn render_heatmap<XS, YS>(
     data: HeatmapData,
@@ -0,0 +1,182 @@
+//! Dash patterns for strokes
+//!
+//! A pattern alternates dash and gap lengths in pixels. Like SVG's
+//! `stroke-dasharray`, an odd-length pattern is repeated to make it even, and
+//! a pattern with a negative or non-finite length, or summing to zero, draws a
+//! solid stroke.
+//!
+//! Dashing works on screen coordinates, after simplification, projection and
+//! zoom, so dashes keep their length in pixels at every scale and are not
+//! distorted by dropped vertices. The pattern is carried across vertices so
+//! corners don't restart it.
+//!
+//! # Example
+//!
+//! ```
+//! use d3rs::shape::dash::dash_polyline;
+//!
+//! let dashes = dash_polyline(&[(0.0, 0.0), (10.0, 0.0)], &[4.0, 2.0]);
+//! assert_eq!(dashes, vec![
+//!     vec![(0.0, 0.0), (4.0, 0.0)],
+//!     vec![(6.0, 0.0), (10.0, 0.0)],
+//! ]);
+//! ```
+
+/// Position along a dash pattern
+struct Dasher {
+    pattern: Vec<f32>,
+    index: usize,
+    remaining: f32,
+}
+
+impl Dasher {
+    /// Start of `pattern`, or `None` when the stroke is solid
+    fn new(pattern: &[f32]) -> Option<Self> {
+        if pattern
+            .iter()
+            .any(|length| !length.is_finite() || *length < 0.0)
+            || pattern.iter().sum::<f32>() <= 0.0
+        {
+            return None;
+        }
+        let pattern: Vec<f32> = if pattern.len() % 2 == 1 {
+            pattern.iter().chain(pattern).copied().collect()
+        } else {
+            pattern.to_vec()
+        };
+        let remaining = pattern[0];
+        Some(Self {
+            pattern,
+            index: 0,
+            remaining,
+        })
+    }
+
+    /// Walk from `p0` to `p1`, calling `emit` with the drawn pieces
+    ///
+    /// Pieces that reach a segment end use its exact coordinates, so dashes
+    /// running through a vertex can be joined by comparing points.
+    fn segment(
+        &mut self,
+        p0: (f32, f32),
+        p1: (f32, f32),
+        mut emit: impl FnMut((f32, f32), (f32, f32)),
+    ) {
+        let length = (p1.0 - p0.0).hypot(p1.1 - p0.1);
+        let at = |t: f32| {
+            if t <= 0.0 {
+                p0
+            } else if t >= length {
+                p1
+            } else {
+                let f = t / length;
+                (p0.0 + (p1.0 - p0.0) * f, p0.1 + (p1.1 - p0.1) * f)
+            }
+        };
+
+        let mut t = 0.0;
+        while t < length {
+            let step = self.remaining.min(length - t);
+            if self.index % 2 == 0 && step > 0.0 {
+                emit(at(t), at(t + step));
+            }
+            t += step;
+            self.remaining -= step;
+            if self.remaining <= 0.0 {
+                self.index = (self.index + 1) % self.pattern.len();
+                self.remaining = self.pattern[self.index];
+            }
+        }
+    }
+}
+
+/// Cut a polyline into dashes of `pattern`
+///
+/// Each dash is a polyline of its own, keeping the vertices it runs
+/// through. Close a ring by repeating its first point at the end.
+pub fn dash_polyline(points: &[(f32, f32)], pattern: &[f32]) -> Vec<Vec<(f32, f32)>> {
+    let Some(mut dasher) = Dasher::new(pattern) else {
+        return if points.len() > 1 {
+            vec![points.to_vec()]
+        } else {
+            Vec::new()
+        };
+    };
+
+    let mut dashes: Vec<Vec<(f32, f32)>> = Vec::new();
+    for pair in points.windows(2) {
+        dasher.segment(pair[0], pair[1], |start, end| match dashes.last_mut() {
+            Some(dash) if dash.last() == Some(&start) => dash.push(end),
+            _ => dashes.push(vec![start, end]),
+        });
+    }
+    dashes
+}
+
+/// Cut segments into dashes of `pattern`, carrying the pattern from each
+/// segment to the next
+pub fn dash_segments(
+    segments: &[(f32, f32, f32, f32)],
+    pattern: &[f32],
+) -> Vec<(f32, f32, f32, f32)> {
+    let Some(mut dasher) = Dasher::new(pattern) else {
+        return segments.to_vec();
+    };
+
+    let mut dashes = Vec::new();
+    for &(x0, y0, x1, y1) in segments {
+        dasher.segment((x0, y0), (x1, y1), |start, end| {
+            dashes.push((start.0, start.1, end.0, end.1));
+        });
+    }
+    dashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashes_run_through_corners() {
+        let points = [(0.0, 0.0), (3.0, 0.0), (3.0, 4.0)];
+        let dashes = dash_polyline(&points, &[5.0, 1.0]);
+        assert_eq!(
+            dashes,
+            vec![
+                vec![(0.0, 0.0), (3.0, 0.0), (3.0, 2.0)],
+                vec![(3.0, 3.0), (3.0, 4.0)]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_odd_pattern_is_repeated() {
+        let dashes = dash_polyline(&[(0.0, 0.0), (9.0, 0.0)], &[3.0]);
+        assert_eq!(
+            dashes,
+            vec![vec![(0.0, 0.0), (3.0, 0.0)], vec![(6.0, 0.0), (9.0, 0.0)]]
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_solid() {
+        let points = [(0.0, 0.0), (5.0, 0.0), (5.0, 5.0)];
+        for pattern in [&[][..], &[0.0, 0.0], &[4.0, -1.0], &[f32::NAN, 2.0]] {
+            assert_eq!(dash_polyline(&points, pattern), vec![points.to_vec()]);
+        }
+        assert!(dash_polyline(&points[..1], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_dash_length_is_independent_of_vertices() {
+        // The same line, with and without a vertex halfway, dashes the same
+        let straight = dash_segments(&[(0.0, 0.0, 10.0, 0.0)], &[4.0, 2.0]);
+        let split = dash_polyline(&[(0.0, 0.0), (5.0, 0.0), (10.0, 0.0)], &[4.0, 2.0]);
+        let lengths: Vec<f32> = split
+            .iter()
+            .map(|dash| dash.windows(2).map(|p| p[1].0 - p[0].0).sum())
+            .collect();
+        assert_eq!(lengths, vec![4.0, 4.0]);
+        assert_eq!(straight.len(), 2);
+    }
+}
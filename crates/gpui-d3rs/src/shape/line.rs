@@ -2,6 +2,11 @@
 
 use crate::color::D3Color;
 use crate::scale::Scale;
+use crate::shape::curve::Curve;
+use crate::shape::dash::dash_segments;
+use crate::shape::path::Point;
+use crate::shape::simplify::Simplify;
+use crate::shape::symbol::{Symbol, SymbolType};
 use gpui::prelude::*;
 use gpui::*;
 
@@ -16,6 +21,8 @@ pub enum CurveType {
     StepBefore,
     /// Step after (horizontal then vertical)
     StepAfter,
+    /// Smooth monotone cubic through the points, without overshoot
+    MonotoneX,
 }
 
 /// Configuration for line chart rendering
@@ -35,6 +42,17 @@ pub struct LineConfig {
     pub point_radius: f32,
     /// Fill color for points
     pub point_fill_color: Option<D3Color>,
+    /// Marker shape of the points
+    pub point_symbol: SymbolType,
+    /// Draw a point every `point_spacing` data points
+    pub point_spacing: usize,
+    /// Dash pattern as alternating dash and gap lengths in pixels
+    pub stroke_dash: Option<Vec<f32>>,
+    /// Simplification applied in pixel space before drawing (linear curves only)
+    pub simplify: Option<Simplify>,
+    /// Radius of the dot drawn for a point with undefined neighbours, which
+    /// has no segment to show it (defaults to the stroke width, 0 hides it)
+    pub isolated_point_radius: Option<f32>,
 }
 
 impl Default for LineConfig {
@@ -47,6 +65,11 @@ impl Default for LineConfig {
             show_points: false,
             point_radius: 3.0,
             point_fill_color: None,
+            point_symbol: SymbolType::Circle,
+            point_spacing: 1,
+            stroke_dash: None,
+            simplify: None,
+            isolated_point_radius: None,
         }
     }
 }
@@ -98,6 +121,50 @@ impl LineConfig {
         self.point_fill_color = Some(color);
         self
     }
+
+    /// Set the marker shape of the points
+    pub fn point_symbol(mut self, symbol: SymbolType) -> Self {
+        self.point_symbol = symbol;
+        self
+    }
+
+    /// Draw a point every `spacing` data points, starting with the first
+    pub fn point_spacing(mut self, spacing: usize) -> Self {
+        self.point_spacing = spacing.max(1);
+        self
+    }
+
+    /// Dash the line with alternating dash and gap lengths in pixels.
+    ///
+    /// Like SVG's `stroke-dasharray`, an odd-length pattern is repeated to
+    /// make it even; an empty pattern draws a solid line.
+    pub fn stroke_dash(mut self, pattern: &[f32]) -> Self {
+        self.stroke_dash = Some(pattern.to_vec());
+        self
+    }
+
+    /// Dash the line, see [`stroke_dash`](Self::stroke_dash).
+    #[deprecated(note = "use `stroke_dash`")]
+    pub fn dash(self, pattern: &[f32]) -> Self {
+        self.stroke_dash(pattern)
+    }
+
+    /// Simplify the line before drawing, with a tolerance in pixels.
+    ///
+    /// Reduces the vertex count of dense traces; a tolerance around 0.5px is
+    /// visually lossless. Only applies to [`CurveType::Linear`]; points drawn
+    /// with `show_points` are not simplified.
+    pub fn simplify(mut self, simplify: Simplify) -> Self {
+        self.simplify = Some(simplify);
+        self
+    }
+
+    /// Set the radius of dots marking points isolated between two gaps
+    /// (non-finite neighbours); 0 hides them
+    pub fn isolated_point_radius(mut self, radius: f32) -> Self {
+        self.isolated_point_radius = Some(radius);
+        self
+    }
 }
 
 /// Data point for a line chart
@@ -189,6 +256,11 @@ fn clip_line_segment(x0: f32, y0: f32, x1: f32, y1: f32) -> Option<(f32, f32, f3
 
 /// Render a line chart using GPUI's PathBuilder for proper vector line rendering
 ///
+/// Points with a non-finite coordinate (e.g. a NaN y value for a missing
+/// measurement) are undefined: they break the line, and a defined point
+/// between two gaps is marked with a dot (see
+/// [`LineConfig::isolated_point_radius`]).
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -253,6 +325,11 @@ where
     let curve_type = config.curve;
     let show_points = config.show_points;
     let point_radius = config.point_radius;
+    let point_symbol = config.point_symbol;
+    let point_spacing = config.point_spacing.max(1);
+    let dash = config.stroke_dash.clone();
+    let simplify = config.simplify;
+    let isolated_point_radius = config.isolated_point_radius.unwrap_or(stroke_width);
     let point_fill = config
         .point_fill_color
         .as_ref()
@@ -280,63 +357,33 @@ where
         ),
               window,
               _cx| {
-            if rel_points.len() < 2 {
-                return;
-            }
+            let runs = defined_runs(&rel_points);
 
-            // Build segments to draw based on curve type, applying clipping
-            let segments_to_draw: Vec<(f32, f32, f32, f32)> = match curve_type {
-                CurveType::Linear => {
-                    let mut segments = Vec::new();
-                    for i in 1..rel_points.len() {
-                        let (x0, y0) = rel_points[i - 1];
-                        let (x1, y1) = rel_points[i];
-                        if let Some(clipped) = clip_line_segment(x0, y0, x1, y1) {
-                            segments.push(clipped);
-                        }
-                    }
-                    segments
-                }
-                CurveType::Step | CurveType::StepAfter => {
-                    let mut segments = Vec::new();
-                    for i in 1..rel_points.len() {
-                        let (x0, y0) = rel_points[i - 1];
-                        let (x1, y1) = rel_points[i];
-                        // Horizontal then vertical: (x0,y0) -> (x1,y0) -> (x1,y1)
-                        if let Some(clipped) = clip_line_segment(x0, y0, x1, y0) {
-                            segments.push(clipped);
-                        }
-                        if let Some(clipped) = clip_line_segment(x1, y0, x1, y1) {
-                            segments.push(clipped);
-                        }
-                    }
-                    segments
-                }
-                CurveType::StepBefore => {
-                    let mut segments = Vec::new();
-                    for i in 1..rel_points.len() {
-                        let (x0, y0) = rel_points[i - 1];
-                        let (x1, y1) = rel_points[i];
-                        // Vertical then horizontal: (x0,y0) -> (x0,y1) -> (x1,y1)
-                        if let Some(clipped) = clip_line_segment(x0, y0, x0, y1) {
-                            segments.push(clipped);
-                        }
-                        if let Some(clipped) = clip_line_segment(x0, y1, x1, y1) {
-                            segments.push(clipped);
-                        }
-                    }
-                    segments
-                }
-            };
-
-            // Build continuous paths from clipped segments
-            if !segments_to_draw.is_empty() {
-                let mut path_builder = PathBuilder::stroke(px(stroke_width));
+            // Build one path from the clipped segments of every run
+            let mut path_builder = PathBuilder::stroke(px(stroke_width));
+            let mut has_segments = false;
+            for run in runs.iter().filter(|run| run.len() >= 2) {
                 let mut last_end: Option<(f32, f32)> = None;
 
-                for (x0, y0, x1, y1) in &segments_to_draw {
-                    let start = (origin_x + x0 * width, origin_y + y0 * height);
-                    let end = (origin_x + x1 * width, origin_y + y1 * height);
+                let segments: Vec<(f32, f32, f32, f32)> =
+                    run_segments(run, curve_type, simplify, width, height)
+                        .into_iter()
+                        .map(|(x0, y0, x1, y1)| {
+                            (
+                                origin_x + x0 * width,
+                                origin_y + y0 * height,
+                                origin_x + x1 * width,
+                                origin_y + y1 * height,
+                            )
+                        })
+                        .collect();
+                let segments = match &dash {
+                    Some(pattern) => dash_segments(&segments, pattern),
+                    None => segments,
+                };
+
+                for (x0, y0, x1, y1) in segments {
+                    let (start, end) = ((x0, y0), (x1, y1));
 
                     // Check if we need to start a new path segment
                     let need_move = match last_end {
@@ -349,44 +396,40 @@ where
                     }
                     path_builder.line_to(gpui::point(px(end.0), px(end.1)));
                     last_end = Some(end);
+                    has_segments = true;
                 }
+            }
 
-                if let Ok(path) = path_builder.build() {
-                    let color_with_opacity = Rgba {
-                        r: stroke_color.r,
-                        g: stroke_color.g,
-                        b: stroke_color.b,
-                        a: stroke_color.a * opacity,
-                    };
-                    window.paint_path(path, color_with_opacity);
+            let line_color = Rgba {
+                a: stroke_color.a * opacity,
+                ..stroke_color
+            };
+            if has_segments && let Ok(path) = path_builder.build() {
+                window.paint_path(path, line_color);
+            }
+
+            // Points between two gaps have no segment: mark them with a dot
+            if isolated_point_radius > 0.0 {
+                for run in runs.iter().filter(|run| run.len() == 1) {
+                    let (x_rel, y_rel) = run[0];
+                    if (0.0..=1.0).contains(&x_rel) && (0.0..=1.0).contains(&y_rel) {
+                        let center = (origin_x + x_rel * width, origin_y + y_rel * height);
+                        paint_dot(window, center, isolated_point_radius, line_color);
+                    }
                 }
             }
 
             // Paint points if enabled (only for points inside the clip region)
             if show_points {
-                for &(x_rel, y_rel) in &rel_points {
+                let point_color = Rgba {
+                    a: point_fill.a * opacity,
+                    ..point_fill
+                };
+                for &(x_rel, y_rel) in rel_points.iter().step_by(point_spacing) {
                     // Only draw points inside the chart area
                     if (0.0..=1.0).contains(&x_rel) && (0.0..=1.0).contains(&y_rel) {
-                        let px_x = origin_x + x_rel * width;
-                        let px_y = origin_y + y_rel * height;
-                        let point_bounds = Bounds {
-                            origin: gpui::point(px(px_x - point_radius), px(px_y - point_radius)),
-                            size: gpui::size(px(point_radius * 2.0), px(point_radius * 2.0)),
-                        };
-                        let color_with_opacity = Rgba {
-                            r: point_fill.r,
-                            g: point_fill.g,
-                            b: point_fill.b,
-                            a: point_fill.a * opacity,
-                        };
-                        window.paint_quad(PaintQuad {
-                            bounds: point_bounds,
-                            corner_radii: Corners::all(px(point_radius)),
-                            background: color_with_opacity.into(),
-                            border_widths: Edges::default(),
-                            border_color: transparent_black(),
-                            border_style: BorderStyle::default(),
-                        });
+                        let center = (origin_x + x_rel * width, origin_y + y_rel * height);
+                        paint_marker(window, point_symbol, center, point_radius, point_color);
                     }
                 }
             }
@@ -396,3 +439,190 @@ where
     .absolute()
     .inset_0()
 }
+
+/// Split points into runs of consecutive defined (finite) points.
+///
+/// Like d3's `line.defined()`, an undefined point breaks the line: segments
+/// are only drawn within a run.
+fn defined_runs(points: &[(f32, f32)]) -> Vec<&[(f32, f32)]> {
+    points
+        .split(|(x, y)| !x.is_finite() || !y.is_finite())
+        .filter(|run| !run.is_empty())
+        .collect()
+}
+
+/// Clipped segments of one run of defined points, in relative coordinates
+fn run_segments(
+    run: &[(f32, f32)],
+    curve_type: CurveType,
+    simplify: Option<Simplify>,
+    width: f32,
+    height: f32,
+) -> Vec<(f32, f32, f32, f32)> {
+    let mut segments = Vec::new();
+    match curve_type {
+        CurveType::Linear => {
+            // Simplify in pixel space so the tolerance is in pixels
+            let line_points: Vec<(f32, f32)> = match simplify {
+                Some(simplify) => {
+                    let pixels: Vec<Point> = run
+                        .iter()
+                        .map(|&(x, y)| Point::new((x * width) as f64, (y * height) as f64))
+                        .collect();
+                    simplify
+                        .indices(&pixels)
+                        .into_iter()
+                        .map(|i| run[i])
+                        .collect()
+                }
+                None => run.to_vec(),
+            };
+            for pair in line_points.windows(2) {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                segments.extend(clip_line_segment(x0, y0, x1, y1));
+            }
+        }
+        CurveType::Step | CurveType::StepAfter => {
+            for pair in run.windows(2) {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                // Horizontal then vertical: (x0,y0) -> (x1,y0) -> (x1,y1)
+                segments.extend(clip_line_segment(x0, y0, x1, y0));
+                segments.extend(clip_line_segment(x1, y0, x1, y1));
+            }
+        }
+        CurveType::StepBefore => {
+            for pair in run.windows(2) {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                // Vertical then horizontal: (x0,y0) -> (x0,y1) -> (x1,y1)
+                segments.extend(clip_line_segment(x0, y0, x0, y1));
+                segments.extend(clip_line_segment(x0, y1, x1, y1));
+            }
+        }
+        CurveType::MonotoneX => {
+            // Monotone interpolation is unchanged by axis scaling, so it can
+            // run on relative coordinates
+            let points: Vec<Point> = run
+                .iter()
+                .map(|&(x, y)| Point::new(x as f64, y as f64))
+                .collect();
+            for pair in Curve::MonotoneX.interpolate(&points).windows(2) {
+                let (p0, p1) = (pair[0], pair[1]);
+                segments.extend(clip_line_segment(
+                    p0.x as f32,
+                    p0.y as f32,
+                    p1.x as f32,
+                    p1.y as f32,
+                ));
+            }
+        }
+    }
+    segments
+}
+
+/// Paint a marker of `symbol` centered at `center`, as large as a dot of
+/// `radius`
+fn paint_marker(
+    window: &mut Window,
+    symbol: SymbolType,
+    center: (f32, f32),
+    radius: f32,
+    color: Rgba,
+) {
+    if symbol == SymbolType::Circle {
+        paint_dot(window, center, radius, color);
+        return;
+    }
+    let area = std::f64::consts::PI * (radius as f64).powi(2);
+    let outline = Symbol::new(symbol, area).points();
+    let Some(first) = outline.first() else {
+        return;
+    };
+    let at = |p: &Point| gpui::point(px(center.0 + p.x as f32), px(center.1 + p.y as f32));
+    let mut builder = PathBuilder::fill();
+    builder.move_to(at(first));
+    for p in &outline[1..] {
+        builder.line_to(at(p));
+    }
+    builder.close();
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// Paint a filled circle centered at `center` (window coordinates)
+fn paint_dot(window: &mut Window, center: (f32, f32), radius: f32, color: Rgba) {
+    window.paint_quad(PaintQuad {
+        bounds: Bounds {
+            origin: gpui::point(px(center.0 - radius), px(center.1 - radius)),
+            size: gpui::size(px(radius * 2.0), px(radius * 2.0)),
+        },
+        corner_radii: Corners::all(px(radius)),
+        background: color.into(),
+        border_widths: Edges::default(),
+        border_color: transparent_black(),
+        border_style: BorderStyle::default(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defined_runs_split_on_nan() {
+        let points = [
+            (0.0, 0.0),
+            (0.1, 0.2),
+            (0.2, f32::NAN),
+            (0.3, 0.4),
+            (f32::NAN, 0.5),
+            (f32::NAN, 0.6),
+            (0.6, 0.7),
+            (0.7, 0.8),
+        ];
+        let runs = defined_runs(&points);
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0], &[(0.0, 0.0), (0.1, 0.2)]);
+        assert_eq!(runs[1], &[(0.3, 0.4)]);
+        assert_eq!(runs[2], &[(0.6, 0.7), (0.7, 0.8)]);
+    }
+
+    #[test]
+    fn test_step_segments_stay_within_run() {
+        let run = [(0.0, 0.5), (0.5, 0.25)];
+        let segments = run_segments(&run, CurveType::StepAfter, None, 100.0, 100.0);
+        assert_eq!(segments, vec![(0.0, 0.5, 0.5, 0.5), (0.5, 0.5, 0.5, 0.25)]);
+    }
+
+    #[test]
+    fn test_monotone_segments_pass_through_points() {
+        let run = [(0.0, 0.8), (0.5, 0.2), (1.0, 0.4)];
+        let segments = run_segments(&run, CurveType::MonotoneX, None, 100.0, 100.0);
+        assert!(segments.len() > 2);
+        assert_eq!(segments.first().map(|s| (s.0, s.1)), Some((0.0, 0.8)));
+        assert!(
+            segments
+                .iter()
+                .any(|s| (s.2 - 0.5).abs() < 1e-6 && (s.3 - 0.2).abs() < 1e-6)
+        );
+    }
+
+    #[test]
+    fn test_dash_segments_carry_pattern_across_joins() {
+        // An L of two 10 px segments with 4 on, 2 off
+        let segments = [(0.0, 0.0, 10.0, 0.0), (10.0, 0.0, 10.0, 10.0)];
+        let dashes = dash_segments(&segments, &[4.0, 2.0]);
+        assert_eq!(
+            dashes,
+            vec![
+                (0.0, 0.0, 4.0, 0.0),
+                (6.0, 0.0, 10.0, 0.0),
+                (10.0, 2.0, 10.0, 6.0),
+                (10.0, 8.0, 10.0, 10.0),
+            ]
+        );
+        // Odd patterns repeat: [3] is 3 on, 3 off
+        assert_eq!(dash_segments(&segments[..1], &[3.0]).len(), 2);
+        assert_eq!(dash_segments(&segments, &[]), segments.to_vec());
+    }
+}
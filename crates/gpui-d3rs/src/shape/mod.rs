@@ -14,6 +14,9 @@
 //! - `stack`: Stack layout for stacked charts
 //! - `link`: Link generators for tree/network diagrams
 //! - `radial`: Radial line/area generators for polar visualizations
+//! - `simplify`: Douglas-Peucker/Visvalingam polyline simplification
+//! - `dash`: Dash patterns for strokes
+//! - `pattern`: Hatch, crosshatch and dot pattern fills
 //! - `bar`: Bar chart rendering
 //! - `line`: Line chart rendering
 //! - `scatter`: Scatter plot rendering
@@ -45,10 +48,13 @@
 pub mod arc;
 pub mod area;
 pub mod curve;
+pub mod dash;
 pub mod link;
 pub mod path;
+pub mod pattern;
 pub mod pie;
 pub mod radial;
+pub mod simplify;
 pub mod stack;
 pub mod symbol;
 
@@ -64,31 +70,37 @@ mod scatter;
 // Re-export existing chart rendering functions (GPUI only)
 #[cfg(feature = "gpui")]
 pub use bar::{
-    BarConfig, BarDatum, GroupedBarConfig, GroupedBarDatum, GroupedBarMeta, analyze_grouped_data,
-    render_bars, render_grouped_bars,
+    BarConfig, BarDatum, BarRect, GroupedBarConfig, GroupedBarDatum, GroupedBarMeta,
+    analyze_grouped_data, bar_rects, grouped_bar_rects, render_bars, render_grouped_bars,
 };
 #[cfg(feature = "gpui")]
 pub use contour::{
-    ContourBandElement, ContourConfig, ContourElement, HeatmapData, HeatmapElement,
-    heat_color_scale, render_contour, render_contour_bands, render_heatmap, viridis_color_scale,
+    ContourBandElement, ContourConfig, ContourElement, HeatmapCell, HeatmapData, HeatmapElement,
+    NoDataStyle, heat_color_scale, render_contour, render_contour_bands, render_heatmap,
+    viridis_color_scale,
 };
 #[cfg(feature = "gpui")]
 pub use line::{CurveType, LineConfig, LinePoint, render_line};
 #[cfg(feature = "gpui")]
+pub use pattern::{paint_pattern, paint_pattern_rect};
+#[cfg(feature = "gpui")]
 pub use scatter::{ScatterConfig, ScatterPoint, render_scatter};
 
 // Re-export new shape utilities (no GPUI dependency)
 pub use arc::{Arc, ArcDatum, arc_points};
 pub use area::{Area, SimpleArea, area_points};
 pub use curve::Curve;
+pub use dash::{dash_polyline, dash_segments};
 pub use link::{
     Link, LinkDirection, RadialLink, link_horizontal, link_radial, link_step, link_vertical,
 };
 pub use path::{Path, PathBuilder, PathCommand, Point};
+pub use pattern::{FillPattern, PatternKind, hatch_segments};
 pub use pie::{Pie, PieSlice, donut, half_pie, pie};
 pub use radial::{
     RadialAreaConfig, RadialLineConfig, RadialPoint, polar_grid_circles, polar_grid_rays,
     radial_area, radial_line,
 };
+pub use simplify::{Simplify, SimplifyMethod, simplify_douglas_peucker, simplify_visvalingam};
 pub use stack::{Stack, StackOffset, StackOrder, StackSeries, stack, stack_expand, streamgraph};
 pub use symbol::{Symbol, SymbolType, symbol_radius};
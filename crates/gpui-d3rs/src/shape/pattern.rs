@@ -0,0 +1,402 @@
+//! Pattern fills
+//!
+//! Hatching, crosshatching and dots drawn over (or instead of) a solid fill,
+//! so bars, areas and contour bands stay distinguishable when printed in
+//! grayscale or seen without full color vision.
+//!
+//! Shapes are given as rings of points and filled with the even-odd rule,
+//! so a ring inside another is a hole. Patterns are anchored to screen
+//! coordinates rather than to each shape, so the lines of adjacent shapes
+//! with the same pattern line up.
+//!
+//! # Example
+//!
+//! ```
+//! use d3rs::color::D3Color;
+//! use d3rs::shape::pattern::FillPattern;
+//!
+//! let pattern = FillPattern::crosshatch(D3Color::from_hex(0x333333)).spacing(8.0);
+//! let square = [(0.0, 0.0), (40.0, 0.0), (40.0, 40.0), (0.0, 40.0)];
+//! assert!(!pattern.segments(&[&square]).is_empty());
+//! ```
+
+use crate::color::D3Color;
+use std::f32::consts::FRAC_PI_4;
+
+/// Shape of a pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternKind {
+    /// Diagonal lines rising to the right (`/`)
+    Hatch,
+    /// Diagonal lines in both directions (`X`)
+    Crosshatch,
+    /// Dots on a square grid
+    Dots,
+}
+
+/// A pattern drawn inside a shape
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillPattern {
+    /// Shape of the pattern
+    pub kind: PatternKind,
+    /// Color of the lines or dots
+    pub color: D3Color,
+    /// Distance between lines or dots in pixels; smaller is denser
+    pub spacing: f32,
+    /// Line width, or dot diameter, in pixels
+    pub line_width: f32,
+}
+
+impl FillPattern {
+    /// Create a pattern with default density
+    pub fn new(kind: PatternKind, color: D3Color) -> Self {
+        Self {
+            kind,
+            color,
+            spacing: 6.0,
+            line_width: match kind {
+                PatternKind::Dots => 2.0,
+                PatternKind::Hatch | PatternKind::Crosshatch => 1.0,
+            },
+        }
+    }
+
+    /// Diagonal hatch
+    pub fn hatch(color: D3Color) -> Self {
+        Self::new(PatternKind::Hatch, color)
+    }
+
+    /// Diagonal crosshatch
+    pub fn crosshatch(color: D3Color) -> Self {
+        Self::new(PatternKind::Crosshatch, color)
+    }
+
+    /// Grid of dots
+    pub fn dots(color: D3Color) -> Self {
+        Self::new(PatternKind::Dots, color)
+    }
+
+    /// Set the distance between lines or dots (at least 1 pixel)
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing.max(1.0);
+        self
+    }
+
+    /// Set the line width, or the dot diameter
+    pub fn line_width(mut self, width: f32) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    /// Set the color
+    pub fn color(mut self, color: D3Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Line segments of the pattern inside `rings` (empty for dots)
+    pub fn segments(&self, rings: &[&[(f32, f32)]]) -> Vec<[(f32, f32); 2]> {
+        match self.kind {
+            PatternKind::Hatch => hatch_segments(rings, self.spacing, -FRAC_PI_4),
+            PatternKind::Crosshatch => {
+                let mut segments = hatch_segments(rings, self.spacing, -FRAC_PI_4);
+                segments.extend(hatch_segments(rings, self.spacing, FRAC_PI_4));
+                segments
+            }
+            PatternKind::Dots => Vec::new(),
+        }
+    }
+
+    /// Dot centers of the pattern inside `rings` (empty for lines)
+    pub fn dots_in(&self, rings: &[&[(f32, f32)]]) -> Vec<(f32, f32)> {
+        if self.kind != PatternKind::Dots {
+            return Vec::new();
+        }
+        let (x_min, y_min, x_max, y_max) = bounding_box(rings);
+        let spacing = self.spacing;
+        // Dots sit in the middle of the grid cells
+        let first_col = (x_min / spacing - 0.5).ceil() as i64;
+        let last_col = (x_max / spacing - 0.5).floor() as i64;
+        let first_row = (y_min / spacing - 0.5).ceil() as i64;
+        let last_row = (y_max / spacing - 0.5).floor() as i64;
+
+        let mut dots = Vec::new();
+        for row in first_row..=last_row {
+            let y = (row as f32 + 0.5) * spacing;
+            for col in first_col..=last_col {
+                let x = (col as f32 + 0.5) * spacing;
+                if contains(rings, x, y) {
+                    dots.push((x, y));
+                }
+            }
+        }
+        dots
+    }
+}
+
+/// Parallel lines clipped to `rings`, with the even-odd rule
+///
+/// Lines run at `angle` (radians, clockwise from the x axis in screen
+/// coordinates) and are `spacing` pixels apart, anchored at the origin.
+pub fn hatch_segments(rings: &[&[(f32, f32)]], spacing: f32, angle: f32) -> Vec<[(f32, f32); 2]> {
+    if spacing <= 0.0 {
+        return Vec::new();
+    }
+    // Direction along the lines, and normal to them
+    let (dx, dy) = (angle.cos(), angle.sin());
+    let (nx, ny) = (-dy, dx);
+    let offset = |(x, y): (f32, f32)| x * nx + y * ny;
+    let along = |(x, y): (f32, f32)| x * dx + y * dy;
+
+    let (lo, hi) = rings
+        .iter()
+        .flat_map(|ring| ring.iter())
+        .map(|&p| offset(p))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), o| {
+            (lo.min(o), hi.max(o))
+        });
+
+    let mut segments = Vec::new();
+    let mut crossings = Vec::new();
+    for k in (lo / spacing).ceil() as i64..=(hi / spacing).floor() as i64 {
+        let c = k as f32 * spacing;
+        crossings.clear();
+        for (a, b) in edges(rings) {
+            let (oa, ob) = (offset(a), offset(b));
+            // Half-open test so a vertex on the line is counted once
+            if (oa <= c) != (ob <= c) {
+                let t = (c - oa) / (ob - oa);
+                let point = (a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1));
+                crossings.push(along(point));
+            }
+        }
+        crossings.sort_by(f32::total_cmp);
+        for pair in crossings.chunks_exact(2) {
+            let point = |u: f32| (c * nx + u * dx, c * ny + u * dy);
+            segments.push([point(pair[0]), point(pair[1])]);
+        }
+    }
+    segments
+}
+
+/// Edges of the rings with at least 3 points, each ring closed
+fn edges<'a>(rings: &'a [&'a [(f32, f32)]]) -> impl Iterator<Item = ((f32, f32), (f32, f32))> + 'a {
+    rings
+        .iter()
+        .filter(|ring| ring.len() >= 3)
+        .flat_map(|ring| (0..ring.len()).map(move |i| (ring[i], ring[(i + 1) % ring.len()])))
+}
+
+fn bounding_box(rings: &[&[(f32, f32)]]) -> (f32, f32, f32, f32) {
+    rings.iter().flat_map(|ring| ring.iter()).fold(
+        (
+            f32::INFINITY,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NEG_INFINITY,
+        ),
+        |(x_min, y_min, x_max, y_max), &(x, y)| {
+            (x_min.min(x), y_min.min(y), x_max.max(x), y_max.max(y))
+        },
+    )
+}
+
+/// Even-odd point in polygon test
+fn contains(rings: &[&[(f32, f32)]], x: f32, y: f32) -> bool {
+    let mut inside = false;
+    for ((xa, ya), (xb, yb)) in edges(rings) {
+        if (ya <= y) != (yb <= y) && x < xa + (y - ya) / (yb - ya) * (xb - xa) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+#[cfg(feature = "gpui")]
+mod paint {
+    use super::{FillPattern, PatternKind};
+    use gpui::{Bounds, Corners, Edges, PaintQuad, PathBuilder, Window, point, px, size};
+
+    /// Paint `pattern` inside `rings`, in window coordinates
+    pub fn paint_pattern(window: &mut Window, rings: &[&[(f32, f32)]], pattern: &FillPattern) {
+        let color = pattern.color.to_rgba();
+        if pattern.kind == PatternKind::Dots {
+            let radius = pattern.line_width / 2.0;
+            for (x, y) in pattern.dots_in(rings) {
+                window.paint_quad(PaintQuad {
+                    bounds: Bounds::new(
+                        point(px(x - radius), px(y - radius)),
+                        size(px(pattern.line_width), px(pattern.line_width)),
+                    ),
+                    corner_radii: Corners::all(px(radius)),
+                    background: color.into(),
+                    border_widths: Edges::default(),
+                    border_color: gpui::transparent_black(),
+                    border_style: Default::default(),
+                });
+            }
+            return;
+        }
+
+        let segments = pattern.segments(rings);
+        if segments.is_empty() {
+            return;
+        }
+        let mut builder = PathBuilder::stroke(px(pattern.line_width));
+        for [(x0, y0), (x1, y1)] in segments {
+            builder.move_to(point(px(x0), px(y0)));
+            builder.line_to(point(px(x1), px(y1)));
+        }
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, color);
+        }
+    }
+
+    /// Paint `pattern` inside a rectangle, in window coordinates
+    pub fn paint_pattern_rect(
+        window: &mut Window,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        pattern: &FillPattern,
+    ) {
+        let rect = [
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+        ];
+        paint_pattern(window, &[&rect], pattern);
+    }
+}
+
+#[cfg(feature = "gpui")]
+pub use paint::{paint_pattern, paint_pattern_rect};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQUARE: [(f32, f32); 4] = [(0.0, 0.0), (30.0, 0.0), (30.0, 30.0), (0.0, 30.0)];
+
+    fn black() -> D3Color {
+        D3Color::from_hex(0x000000)
+    }
+
+    #[test]
+    fn test_hatch_segments_stay_inside() {
+        let segments = hatch_segments(&[&SQUARE], 5.0, -FRAC_PI_4);
+        assert!(!segments.is_empty());
+        for [a, b] in &segments {
+            for (x, y) in [a, b] {
+                assert!((-1e-3..=30.001).contains(x), "{x}");
+                assert!((-1e-3..=30.001).contains(y), "{y}");
+            }
+            // Rising to the right: x + y is constant along the line
+            assert!(((a.0 + a.1) - (b.0 + b.1)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_hatch_is_anchored_to_screen() {
+        // The same lines continue across neighbouring squares
+        let left = hatch_segments(&[&SQUARE], 5.0, -FRAC_PI_4);
+        let moved: Vec<_> = SQUARE.iter().map(|&(x, y)| (x + 30.0, y)).collect();
+        let right = hatch_segments(&[&moved], 5.0, -FRAC_PI_4);
+        let offsets = |segments: &[[(f32, f32); 2]]| {
+            segments
+                .iter()
+                .map(|[a, _]| {
+                    ((a.0 + a.1) / 5.0 * std::f32::consts::FRAC_1_SQRT_2)
+                        .fract()
+                        .abs()
+                })
+                .collect::<Vec<_>>()
+        };
+        for fract in offsets(&left).into_iter().chain(offsets(&right)) {
+            assert!(!(1e-3..1.0 - 1e-3).contains(&fract), "{fract}");
+        }
+    }
+
+    #[test]
+    fn test_hatch_clips_concave_polygon() {
+        // U shape: lines crossing both arms are split in two segments
+        let u = [
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 20.0),
+            (20.0, 20.0),
+            (20.0, 0.0),
+            (30.0, 0.0),
+            (30.0, 30.0),
+            (0.0, 30.0),
+        ];
+        let horizontal = hatch_segments(&[&u], 5.0, 0.0);
+        let at_10: Vec<_> = horizontal
+            .iter()
+            .filter(|[a, _]| (a.1 - 10.0).abs() < 1e-3)
+            .collect();
+        assert_eq!(at_10.len(), 2);
+        let at_25: Vec<_> = horizontal
+            .iter()
+            .filter(|[a, _]| (a.1 - 25.0).abs() < 1e-3)
+            .collect();
+        assert_eq!(at_25.len(), 1);
+    }
+
+    #[test]
+    fn test_inner_ring_is_a_hole() {
+        let hole = [(10.0, 10.0), (20.0, 10.0), (20.0, 20.0), (10.0, 20.0)];
+        let horizontal = hatch_segments(&[&SQUARE, &hole], 5.0, 0.0);
+        let at_15: Vec<_> = horizontal
+            .iter()
+            .filter(|[a, _]| (a.1 - 15.0).abs() < 1e-3)
+            .collect();
+        assert_eq!(at_15.len(), 2);
+
+        let dots = FillPattern::dots(black())
+            .spacing(10.0)
+            .dots_in(&[&SQUARE, &hole]);
+        assert_eq!(dots.len(), 8);
+        assert!(!dots.contains(&(15.0, 15.0)));
+    }
+
+    #[test]
+    fn test_crosshatch_has_both_directions() {
+        let hatch = FillPattern::hatch(black())
+            .spacing(5.0)
+            .segments(&[&SQUARE]);
+        let cross = FillPattern::crosshatch(black())
+            .spacing(5.0)
+            .segments(&[&SQUARE]);
+        assert!(cross.len() > hatch.len());
+        assert!(FillPattern::dots(black()).segments(&[&SQUARE]).is_empty());
+    }
+
+    #[test]
+    fn test_density() {
+        let sparse = FillPattern::hatch(black())
+            .spacing(10.0)
+            .segments(&[&SQUARE]);
+        let dense = FillPattern::hatch(black())
+            .spacing(2.0)
+            .segments(&[&SQUARE]);
+        assert!(dense.len() > 3 * sparse.len());
+        assert_eq!(FillPattern::hatch(black()).spacing(0.0).spacing, 1.0);
+    }
+
+    #[test]
+    fn test_dots() {
+        let dots = FillPattern::dots(black()).spacing(10.0).dots_in(&[&SQUARE]);
+        assert_eq!(dots.len(), 9);
+        assert!(dots.contains(&(15.0, 15.0)));
+
+        let triangle = [(0.0, 0.0), (31.0, 0.0), (0.0, 31.0)];
+        let dots = FillPattern::dots(black())
+            .spacing(10.0)
+            .dots_in(&[&triangle]);
+        assert!(dots.iter().all(|&(x, y)| x + y < 31.0));
+        assert_eq!(dots.len(), 6);
+    }
+}
@@ -0,0 +1,314 @@
+//! Polyline simplification
+//!
+//! Reduces the number of vertices of dense lines (long measurement traces,
+//! detailed geographic boundaries) while keeping their visible shape, by
+//! dropping points that deviate less than a tolerance from the simplified
+//! line. Run it on screen coordinates so the tolerance is in pixels; a
+//! tolerance of about half a pixel is visually lossless.
+//!
+//! Two algorithms are provided:
+//! - **Douglas-Peucker**: keeps every point farther than the tolerance from
+//!   the simplified line. Preserves peaks exactly, good for measurement data.
+//! - **Visvalingam-Whyatt**: repeatedly drops the point forming the smallest
+//!   triangle with its neighbours. Smoother results, good for geographic
+//!   boundaries.
+//!
+//! The first and last points are always kept.
+//!
+//! # Example
+//!
+//! ```
+//! use d3rs::shape::path::Point;
+//! use d3rs::shape::simplify::Simplify;
+//!
+//! let points: Vec<Point> = (0..100)
+//!     .map(|i| Point::new(i as f64, if i == 50 { 10.0 } else { 0.0 }))
+//!     .collect();
+//!
+//! let simplified = Simplify::douglas_peucker(0.5).apply(&points);
+//! assert_eq!(simplified.len(), 5);
+//! ```
+
+use super::path::Point;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Simplification algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SimplifyMethod {
+    /// Ramer-Douglas-Peucker distance-based simplification (default)
+    #[default]
+    DouglasPeucker,
+    /// Visvalingam-Whyatt area-based simplification
+    Visvalingam,
+}
+
+/// Simplification settings: an algorithm and a tolerance.
+///
+/// For [`SimplifyMethod::DouglasPeucker`] the tolerance is the maximum
+/// distance between a dropped point and the simplified line. For
+/// [`SimplifyMethod::Visvalingam`] points whose triangle area is below
+/// `tolerance²` are dropped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Simplify {
+    /// Algorithm to use
+    pub method: SimplifyMethod,
+    /// Tolerance in the units of the points (pixels for screen coordinates)
+    pub tolerance: f64,
+}
+
+impl Simplify {
+    /// Douglas-Peucker simplification with the given tolerance.
+    pub fn douglas_peucker(tolerance: f64) -> Self {
+        Self {
+            method: SimplifyMethod::DouglasPeucker,
+            tolerance,
+        }
+    }
+
+    /// Visvalingam-Whyatt simplification with the given tolerance.
+    pub fn visvalingam(tolerance: f64) -> Self {
+        Self {
+            method: SimplifyMethod::Visvalingam,
+            tolerance,
+        }
+    }
+
+    /// Indices of the points to keep, in increasing order.
+    pub fn indices(&self, points: &[Point]) -> Vec<usize> {
+        if points.len() <= 2 || self.tolerance.is_nan() || self.tolerance <= 0.0 {
+            return (0..points.len()).collect();
+        }
+        match self.method {
+            SimplifyMethod::DouglasPeucker => douglas_peucker_indices(points, self.tolerance),
+            SimplifyMethod::Visvalingam => {
+                visvalingam_indices(points, self.tolerance * self.tolerance)
+            }
+        }
+    }
+
+    /// Simplify a polyline.
+    pub fn apply(&self, points: &[Point]) -> Vec<Point> {
+        self.indices(points)
+            .into_iter()
+            .map(|i| points[i])
+            .collect()
+    }
+}
+
+/// Simplify a polyline with Douglas-Peucker.
+///
+/// See [`Simplify::douglas_peucker`].
+pub fn simplify_douglas_peucker(points: &[Point], tolerance: f64) -> Vec<Point> {
+    Simplify::douglas_peucker(tolerance).apply(points)
+}
+
+/// Simplify a polyline with Visvalingam-Whyatt.
+///
+/// See [`Simplify::visvalingam`].
+pub fn simplify_visvalingam(points: &[Point], tolerance: f64) -> Vec<Point> {
+    Simplify::visvalingam(tolerance).apply(points)
+}
+
+/// Squared distance from `p` to the segment `a`-`b`.
+fn segment_distance_sq(p: &Point, a: &Point, b: &Point) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0.0 {
+        (((p.x - a.x) * dx + (p.y - a.y) * dy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (ex, ey) = (p.x - (a.x + t * dx), p.y - (a.y + t * dy));
+    ex * ex + ey * ey
+}
+
+fn douglas_peucker_indices(points: &[Point], tolerance: f64) -> Vec<usize> {
+    let tolerance_sq = tolerance * tolerance;
+    let last = points.len() - 1;
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    // Explicit stack instead of recursion: long traces would overflow it
+    let mut stack = vec![(0, last)];
+    while let Some((first, end)) = stack.pop() {
+        let mut farthest = 0;
+        let mut max_sq = tolerance_sq;
+        for i in first + 1..end {
+            let d = segment_distance_sq(&points[i], &points[first], &points[end]);
+            if d > max_sq {
+                farthest = i;
+                max_sq = d;
+            }
+        }
+        if farthest != 0 {
+            keep[farthest] = true;
+            stack.push((first, farthest));
+            stack.push((farthest, end));
+        }
+    }
+
+    (0..points.len()).filter(|&i| keep[i]).collect()
+}
+
+/// Area of the triangle `a`-`b`-`c`.
+fn triangle_area(a: &Point, b: &Point, c: &Point) -> f64 {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+}
+
+/// Heap entry; ordered so the smallest area pops first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AreaEntry {
+    area: f64,
+    index: usize,
+}
+
+impl Eq for AreaEntry {}
+
+impl Ord for AreaEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for AreaEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn visvalingam_indices(points: &[Point], min_area: f64) -> Vec<usize> {
+    let n = points.len();
+    let mut prev: Vec<usize> = (0..n).map(|i| i.wrapping_sub(1)).collect();
+    let mut next: Vec<usize> = (1..=n).collect();
+    let mut area = vec![f64::INFINITY; n];
+    let mut removed = vec![false; n];
+
+    let mut heap = BinaryHeap::with_capacity(n);
+    for i in 1..n - 1 {
+        area[i] = triangle_area(&points[i - 1], &points[i], &points[i + 1]);
+        heap.push(AreaEntry {
+            area: area[i],
+            index: i,
+        });
+    }
+
+    // Areas of removed points never decrease, so a point isn't dropped
+    // before a less significant neighbour
+    let mut floor = 0.0f64;
+    while let Some(AreaEntry { area: a, index: i }) = heap.pop() {
+        // Skip stale entries superseded by a recomputed area
+        if removed[i] || a != area[i] {
+            continue;
+        }
+        if a >= min_area {
+            break;
+        }
+        floor = floor.max(a);
+        removed[i] = true;
+
+        let (p, q) = (prev[i], next[i]);
+        next[p] = q;
+        prev[q] = p;
+        for neighbour in [p, q] {
+            if neighbour == 0 || neighbour == n - 1 {
+                continue;
+            }
+            let updated = triangle_area(
+                &points[prev[neighbour]],
+                &points[neighbour],
+                &points[next[neighbour]],
+            )
+            .max(floor);
+            area[neighbour] = updated;
+            heap.push(AreaEntry {
+                area: updated,
+                index: neighbour,
+            });
+        }
+    }
+
+    (0..n).filter(|&i| !removed[i]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noisy_line(n: usize, noise: f64) -> Vec<Point> {
+        (0..n)
+            .map(|i| {
+                let jitter = if i % 2 == 0 { noise } else { -noise };
+                Point::new(i as f64, jitter)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_douglas_peucker_drops_noise_keeps_peak() {
+        let mut points = noisy_line(1000, 0.1);
+        points[500].y = 20.0;
+
+        let simplified = simplify_douglas_peucker(&points, 0.5);
+        assert_eq!(simplified.first(), points.first());
+        assert_eq!(simplified.last(), points.last());
+        assert!(simplified.contains(&points[500]));
+        assert!(simplified.len() <= 5);
+
+        // Every dropped point stays within tolerance of the simplified line
+        let indices = Simplify::douglas_peucker(0.5).indices(&points);
+        for pair in indices.windows(2) {
+            for p in &points[pair[0]..pair[1]] {
+                let d = segment_distance_sq(p, &points[pair[0]], &points[pair[1]]);
+                assert!(d <= 0.25 + 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_visvalingam_drops_small_triangles() {
+        let mut points = noisy_line(200, 0.05);
+        points[100].y = 10.0;
+
+        let simplified = simplify_visvalingam(&points, 1.0);
+        assert!(simplified.len() < 10);
+        assert!(simplified.contains(&points[100]));
+        assert_eq!(simplified.first(), points.first());
+        assert_eq!(simplified.last(), points.last());
+    }
+
+    #[test]
+    fn test_zero_tolerance_and_short_lines_unchanged() {
+        let points = noisy_line(10, 0.1);
+        assert_eq!(simplify_douglas_peucker(&points, 0.0), points);
+        assert_eq!(simplify_visvalingam(&points, f64::NAN), points);
+        assert_eq!(simplify_douglas_peucker(&points[..2], 5.0), &points[..2]);
+    }
+
+    #[test]
+    fn test_closed_ring() {
+        // A square sampled densely along its edges
+        let mut ring = Vec::new();
+        for i in 0..10 {
+            ring.push(Point::new(i as f64, 0.0));
+        }
+        for i in 0..10 {
+            ring.push(Point::new(10.0, i as f64));
+        }
+        for i in 0..10 {
+            ring.push(Point::new(10.0 - i as f64, 10.0));
+        }
+        for i in 0..=10 {
+            ring.push(Point::new(0.0, 10.0 - i as f64));
+        }
+
+        for method in [Simplify::douglas_peucker(0.1), Simplify::visvalingam(0.1)] {
+            let simplified = method.apply(&ring);
+            assert_eq!(simplified.len(), 5, "{method:?}");
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Spinorama API client
+//!
+//! Typed access to the spinorama.org speaker database: the speaker list,
+//! measurement versions, CEA2034 curves, directivity sweeps and SPL contour
+//! grids. Responses are cached in memory for the life of the client, and the
+//! list endpoints go through [`HttpClient`] for retry and optional disk
+//! caching.
+//!
+//! The client is async and must run on a tokio runtime.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use d3rs::fetch::CancellationToken;
+//! use d3rs::spinorama::{Plane, SpinoramaClient};
+//!
+//! # async fn demo() -> Result<(), d3rs::spinorama::SpinoramaError> {
+//! let client = SpinoramaClient::new();
+//! let token = CancellationToken::new();
+//!
+//! let speakers = client.speakers().await?;
+//! let versions = client.versions(&speakers[0], &token).await?;
+//! let cea = client.cea2034(&speakers[0], &versions[0], "CEA2034").await?;
+//! if let Some(on_axis) = cea.on_axis() {
+//!     let (freq, spl) = on_axis.xy();
+//!     // gpui_px::line(freq, spl).x_scale(ScaleType::Log) ...
+//! #   let _ = (freq, spl);
+//! }
+//!
+//! let contour = client.contour(&speakers[0], &versions[0], Plane::Horizontal).await?;
+//! let (z, width, height) = contour.z();
+//! // gpui_px::contour(z, width, height).x(&contour.freq).y(&contour.angles) ...
+//! # let _ = (z, width, height);
+//! # Ok(())
+//! # }
+//! ```
+
+mod model;
+
+pub use model::{AngleCurve, CEA2034_CURVES, Cea2034, Directivity, FrequencyCurve, Plane, SplGrid};
+
+use crate::fetch::{CancellationToken, FetchError, HttpClient};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Base URL of the spinorama.org API.
+pub const API_BASE: &str = "https://api.spinorama.org/v1";
+
+/// Error returned by [`SpinoramaClient`].
+#[derive(Debug)]
+pub enum SpinoramaError {
+    /// An HTTP request failed
+    Fetch(FetchError),
+    /// The API answered with data that could not be interpreted
+    Api(String),
+}
+
+impl std::fmt::Display for SpinoramaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpinoramaError::Fetch(err) => write!(f, "{err}"),
+            SpinoramaError::Api(err) => write!(f, "spinorama API error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SpinoramaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SpinoramaError::Fetch(err) => Some(err),
+            SpinoramaError::Api(_) => None,
+        }
+    }
+}
+
+impl From<FetchError> for SpinoramaError {
+    fn from(err: FetchError) -> Self {
+        SpinoramaError::Fetch(err)
+    }
+}
+
+/// In-memory response cache, shared by clones of a client.
+#[derive(Default)]
+struct ResponseCache {
+    speakers: Option<Vec<String>>,
+    versions: HashMap<String, Vec<String>>,
+    cea2034: HashMap<(String, String, String), Cea2034>,
+    directivity: HashMap<(String, String), Directivity>,
+    contour: HashMap<(String, String, Plane), SplGrid>,
+}
+
+/// Client for the spinorama.org API.
+///
+/// Cheap to clone; clones share the HTTP client and the response cache.
+#[derive(Clone)]
+pub struct SpinoramaClient {
+    http: HttpClient,
+    cache: Arc<Mutex<ResponseCache>>,
+}
+
+impl std::fmt::Debug for SpinoramaClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpinoramaClient")
+            .field("http", &self.http)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for SpinoramaClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpinoramaClient {
+    /// Create a client with an in-memory cache only.
+    pub fn new() -> Self {
+        Self::with_http(HttpClient::new())
+    }
+
+    /// Create a client around a configured [`HttpClient`].
+    pub fn with_http(http: HttpClient) -> Self {
+        Self {
+            http,
+            cache: Arc::new(Mutex::new(ResponseCache::default())),
+        }
+    }
+
+    /// Also cache list responses on disk in `dir`, revalidated by ETag.
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.http = self.http.cache_dir(dir);
+        self
+    }
+
+    /// Forget all cached responses.
+    pub fn clear_cache(&self) {
+        *self.cache.lock().unwrap_or_else(|e| e.into_inner()) = ResponseCache::default();
+    }
+
+    fn cached<T: Clone>(&self, get: impl FnOnce(&ResponseCache) -> Option<&T>) -> Option<T> {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        get(&cache).cloned()
+    }
+
+    fn store(&self, put: impl FnOnce(&mut ResponseCache)) {
+        put(&mut self.cache.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+
+    /// All speakers in the database, sorted by name.
+    pub async fn speakers(&self) -> Result<Vec<String>, SpinoramaError> {
+        if let Some(speakers) = self.cached(|c| c.speakers.as_ref()) {
+            return Ok(speakers);
+        }
+        let mut speakers = autoeq::read::fetch_available_speakers()
+            .await
+            .map_err(|e| SpinoramaError::Api(e.to_string()))?;
+        speakers.sort();
+        self.store(|c| c.speakers = Some(speakers.clone()));
+        Ok(speakers)
+    }
+
+    /// Measurement versions available for a speaker.
+    pub async fn versions(
+        &self,
+        speaker: &str,
+        token: &CancellationToken,
+    ) -> Result<Vec<String>, SpinoramaError> {
+        if let Some(versions) = self.cached(|c| c.versions.get(speaker)) {
+            return Ok(versions);
+        }
+        let url = format!(
+            "{API_BASE}/speaker/{}/versions",
+            urlencoding::encode(speaker)
+        );
+        let versions: Vec<String> = self.http.fetch_json(&url, token).await?;
+        self.store(|c| {
+            c.versions.insert(speaker.to_string(), versions.clone());
+        });
+        Ok(versions)
+    }
+
+    /// CEA2034 curves of one measurement (e.g. `"CEA2034"`).
+    pub async fn cea2034(
+        &self,
+        speaker: &str,
+        version: &str,
+        measurement: &str,
+    ) -> Result<Cea2034, SpinoramaError> {
+        let key = (
+            speaker.to_string(),
+            version.to_string(),
+            measurement.to_string(),
+        );
+        if let Some(cea) = self.cached(|c| c.cea2034.get(&key)) {
+            return Ok(cea);
+        }
+        let plot = autoeq::read::fetch_measurement_plot_data(speaker, version, measurement)
+            .await
+            .map_err(|e| SpinoramaError::Api(e.to_string()))?;
+        let curves = autoeq::read::extract_cea2034_curves_original(&plot, measurement)
+            .map_err(|e| SpinoramaError::Api(e.to_string()))?;
+        let cea = Cea2034::from(curves);
+        self.store(|c| {
+            c.cea2034.insert(key, cea.clone());
+        });
+        Ok(cea)
+    }
+
+    /// Horizontal and vertical directivity sweeps.
+    pub async fn directivity(
+        &self,
+        speaker: &str,
+        version: &str,
+    ) -> Result<Directivity, SpinoramaError> {
+        let key = (speaker.to_string(), version.to_string());
+        if let Some(directivity) = self.cached(|c| c.directivity.get(&key)) {
+            return Ok(directivity);
+        }
+        let data = autoeq::read::fetch_directivity_data(speaker, version)
+            .await
+            .map_err(|e| SpinoramaError::Api(e.to_string()))?;
+        let directivity = Directivity::from(data);
+        self.store(|c| {
+            c.directivity.insert(key, directivity.clone());
+        });
+        Ok(directivity)
+    }
+
+    /// SPL contour grid of one plane, as published by spinorama.org.
+    pub async fn contour(
+        &self,
+        speaker: &str,
+        version: &str,
+        plane: Plane,
+    ) -> Result<SplGrid, SpinoramaError> {
+        let key = (speaker.to_string(), version.to_string(), plane);
+        if let Some(grid) = self.cached(|c| c.contour.get(&key)) {
+            return Ok(grid);
+        }
+        let data = autoeq::read::fetch_contour_data(speaker, version, plane.api_name())
+            .await
+            .map_err(|e| SpinoramaError::Api(e.to_string()))?;
+        let grid = SplGrid::from(data);
+        self.store(|c| {
+            c.contour.insert(key, grid.clone());
+        });
+        Ok(grid)
+    }
+}
@@ -0,0 +1,388 @@
+//! Spinorama data model
+//!
+//! Plain-vector versions of the measurements served by spinorama.org. They
+//! convert from the `autoeq` types returned by the API and expose their data
+//! in the shapes gpui-px charts take: `(x, y)` slices for `line`, and a
+//! row-major grid with its width and height for `contour`, `heatmap` and
+//! `surface3d`.
+
+use std::collections::HashMap;
+
+/// Names of the CEA2034 curves, in spinorama.org display order.
+pub const CEA2034_CURVES: &[&str] = &[
+    "On Axis",
+    "Listening Window",
+    "Early Reflections",
+    "Sound Power",
+    "Early Reflections DI",
+    "Sound Power DI",
+];
+
+/// A frequency response: SPL (dB) against frequency (Hz).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrequencyCurve {
+    /// Curve name, e.g. "On Axis"
+    pub name: String,
+    /// Frequencies in Hz, increasing
+    pub freq: Vec<f64>,
+    /// SPL in dB, one per frequency
+    pub spl: Vec<f64>,
+}
+
+impl FrequencyCurve {
+    /// Create a curve; extra values in the longer vector are dropped.
+    pub fn new(name: impl Into<String>, mut freq: Vec<f64>, mut spl: Vec<f64>) -> Self {
+        let len = freq.len().min(spl.len());
+        freq.truncate(len);
+        spl.truncate(len);
+        Self {
+            name: name.into(),
+            freq,
+            spl,
+        }
+    }
+
+    /// `(x, y)` inputs for `gpui_px::line`.
+    pub fn xy(&self) -> (&[f64], &[f64]) {
+        (&self.freq, &self.spl)
+    }
+
+    /// SPL at `freq`, interpolated linearly in log-frequency.
+    ///
+    /// Frequencies outside the measured range clamp to the end values;
+    /// returns `None` for an empty curve.
+    pub fn spl_at(&self, freq: f64) -> Option<f64> {
+        interpolate_log(&self.freq, &self.spl, freq)
+    }
+}
+
+/// The CEA2034 (spinorama) curve set of one measurement.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cea2034 {
+    curves: Vec<FrequencyCurve>,
+}
+
+impl Cea2034 {
+    /// Collect curves by name, ordered as in [`CEA2034_CURVES`].
+    ///
+    /// Curves with other names are kept after the standard ones, sorted by name.
+    pub fn new(curves: impl IntoIterator<Item = FrequencyCurve>) -> Self {
+        let mut curves: Vec<FrequencyCurve> = curves.into_iter().collect();
+        curves.sort_by(|a, b| {
+            let rank = |c: &FrequencyCurve| {
+                CEA2034_CURVES
+                    .iter()
+                    .position(|name| *name == c.name)
+                    .unwrap_or(CEA2034_CURVES.len())
+            };
+            rank(a).cmp(&rank(b)).then_with(|| a.name.cmp(&b.name))
+        });
+        Self { curves }
+    }
+
+    /// All curves in display order.
+    pub fn curves(&self) -> &[FrequencyCurve] {
+        &self.curves
+    }
+
+    /// Curve by name.
+    pub fn curve(&self, name: &str) -> Option<&FrequencyCurve> {
+        self.curves.iter().find(|c| c.name == name)
+    }
+
+    /// The on-axis response.
+    pub fn on_axis(&self) -> Option<&FrequencyCurve> {
+        self.curve("On Axis")
+    }
+
+    /// The listening window average.
+    pub fn listening_window(&self) -> Option<&FrequencyCurve> {
+        self.curve("Listening Window")
+    }
+
+    /// The early reflections average.
+    pub fn early_reflections(&self) -> Option<&FrequencyCurve> {
+        self.curve("Early Reflections")
+    }
+
+    /// The sound power average.
+    pub fn sound_power(&self) -> Option<&FrequencyCurve> {
+        self.curve("Sound Power")
+    }
+
+    /// Whether no curve was found.
+    pub fn is_empty(&self) -> bool {
+        self.curves.is_empty()
+    }
+}
+
+impl From<HashMap<String, autoeq::Curve>> for Cea2034 {
+    fn from(curves: HashMap<String, autoeq::Curve>) -> Self {
+        Self::new(
+            curves
+                .into_iter()
+                .map(|(name, c)| FrequencyCurve::new(name, c.freq.to_vec(), c.spl.to_vec())),
+        )
+    }
+}
+
+/// Measurement plane of a directivity sweep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Plane {
+    /// Horizontal orbit
+    #[default]
+    Horizontal,
+    /// Vertical orbit
+    Vertical,
+}
+
+impl Plane {
+    /// Name used by the spinorama API.
+    pub fn api_name(self) -> &'static str {
+        match self {
+            Plane::Horizontal => "horizontal",
+            Plane::Vertical => "vertical",
+        }
+    }
+}
+
+/// A frequency response measured at one angle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AngleCurve {
+    /// Angle in degrees
+    pub angle: f64,
+    /// Frequencies in Hz, increasing
+    pub freq: Vec<f64>,
+    /// SPL in dB, one per frequency
+    pub spl: Vec<f64>,
+}
+
+/// Horizontal and vertical directivity sweeps.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Directivity {
+    /// Horizontal curves, sorted by angle
+    pub horizontal: Vec<AngleCurve>,
+    /// Vertical curves, sorted by angle
+    pub vertical: Vec<AngleCurve>,
+}
+
+impl Directivity {
+    /// Create from two sweeps; curves are sorted by angle.
+    pub fn new(mut horizontal: Vec<AngleCurve>, mut vertical: Vec<AngleCurve>) -> Self {
+        horizontal.sort_by(|a, b| a.angle.total_cmp(&b.angle));
+        vertical.sort_by(|a, b| a.angle.total_cmp(&b.angle));
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+
+    /// Curves of one plane.
+    pub fn plane(&self, plane: Plane) -> &[AngleCurve] {
+        match plane {
+            Plane::Horizontal => &self.horizontal,
+            Plane::Vertical => &self.vertical,
+        }
+    }
+
+    /// `(angle, spl)` pairs at `freq`, interpolated in log-frequency.
+    pub fn spl_at(&self, plane: Plane, freq: f64) -> Vec<(f64, f64)> {
+        self.plane(plane)
+            .iter()
+            .filter_map(|c| interpolate_log(&c.freq, &c.spl, freq).map(|spl| (c.angle, spl)))
+            .collect()
+    }
+
+    /// Resample one plane onto `freqs`, giving an angle × frequency grid.
+    pub fn grid(&self, plane: Plane, freqs: &[f64]) -> SplGrid {
+        let curves = self.plane(plane);
+        let spl = curves
+            .iter()
+            .flat_map(|c| {
+                freqs
+                    .iter()
+                    .map(|&f| interpolate_log(&c.freq, &c.spl, f).unwrap_or(f64::NAN))
+            })
+            .collect();
+        SplGrid {
+            freq: freqs.to_vec(),
+            angles: curves.iter().map(|c| c.angle).collect(),
+            spl,
+        }
+    }
+}
+
+impl From<autoeq::DirectivityData> for Directivity {
+    fn from(data: autoeq::DirectivityData) -> Self {
+        let horizontal = data
+            .horizontal
+            .into_iter()
+            .map(|c| AngleCurve {
+                angle: c.angle,
+                freq: c.freq.to_vec(),
+                spl: c.spl.to_vec(),
+            })
+            .collect();
+        let vertical = data
+            .vertical
+            .into_iter()
+            .map(|c| AngleCurve {
+                angle: c.angle,
+                freq: c.freq.to_vec(),
+                spl: c.spl.to_vec(),
+            })
+            .collect();
+        Self::new(horizontal, vertical)
+    }
+}
+
+/// SPL over an angle × frequency grid, row-major by angle.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SplGrid {
+    /// Frequencies in Hz (grid columns)
+    pub freq: Vec<f64>,
+    /// Angles in degrees (grid rows)
+    pub angles: Vec<f64>,
+    /// SPL values, `angles.len()` rows of `freq.len()` values
+    pub spl: Vec<f64>,
+}
+
+impl SplGrid {
+    /// Number of columns (frequencies).
+    pub fn width(&self) -> usize {
+        self.freq.len()
+    }
+
+    /// Number of rows (angles).
+    pub fn height(&self) -> usize {
+        self.angles.len()
+    }
+
+    /// `(z, grid_width, grid_height)` inputs for `gpui_px::contour`,
+    /// `heatmap` and `surface3d`; use [`freq`](Self::freq) and
+    /// [`angles`](Self::angles) as the x and y values.
+    pub fn z(&self) -> (&[f64], usize, usize) {
+        (&self.spl, self.width(), self.height())
+    }
+
+    /// Value at a row and column.
+    pub fn get(&self, angle_index: usize, freq_index: usize) -> Option<f64> {
+        if freq_index >= self.width() {
+            return None;
+        }
+        self.spl
+            .get(angle_index * self.width() + freq_index)
+            .copied()
+    }
+
+    /// Subtract the on-axis (0°) row from every row, if present.
+    pub fn normalized(&self) -> SplGrid {
+        let Some(axis) = self.angles.iter().position(|a| *a == 0.0) else {
+            return self.clone();
+        };
+        let width = self.width();
+        let reference: Vec<f64> = self.spl[axis * width..(axis + 1) * width].to_vec();
+        let spl = self
+            .spl
+            .chunks(width.max(1))
+            .flat_map(|row| row.iter().zip(&reference).map(|(v, r)| v - r))
+            .collect();
+        SplGrid {
+            spl,
+            ..self.clone()
+        }
+    }
+}
+
+impl From<autoeq::read::ContourPlotData> for SplGrid {
+    fn from(data: autoeq::read::ContourPlotData) -> Self {
+        Self {
+            freq: data.freq.iter().copied().collect(),
+            angles: data.angles.iter().copied().collect(),
+            spl: data.spl.iter().copied().collect(),
+        }
+    }
+}
+
+/// Linear interpolation of `y(x)` in log-x, clamped at both ends.
+fn interpolate_log(xs: &[f64], ys: &[f64], x: f64) -> Option<f64> {
+    let n = xs.len().min(ys.len());
+    if n == 0 {
+        return None;
+    }
+    if x <= xs[0] {
+        return Some(ys[0]);
+    }
+    if x >= xs[n - 1] {
+        return Some(ys[n - 1]);
+    }
+    let hi = xs[..n].partition_point(|&v| v <= x);
+    let lo = hi - 1;
+    let span = xs[hi].ln() - xs[lo].ln();
+    if span <= 0.0 {
+        return Some(ys[lo]);
+    }
+    let t = (x.ln() - xs[lo].ln()) / span;
+    Some(ys[lo] + t * (ys[hi] - ys[lo]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(name: &str) -> FrequencyCurve {
+        FrequencyCurve::new(name, vec![100.0, 1000.0], vec![80.0, 90.0])
+    }
+
+    #[test]
+    fn test_cea2034_order() {
+        let cea = Cea2034::new([
+            curve("Sound Power"),
+            curve("Custom"),
+            curve("On Axis"),
+            curve("Early Reflections DI"),
+        ]);
+        let names: Vec<&str> = cea.curves().iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["On Axis", "Sound Power", "Early Reflections DI", "Custom"]
+        );
+        assert!(cea.on_axis().is_some());
+        assert!(cea.listening_window().is_none());
+    }
+
+    #[test]
+    fn test_log_interpolation() {
+        let c = curve("On Axis");
+        assert_eq!(c.spl_at(10.0), Some(80.0));
+        assert_eq!(c.spl_at(20000.0), Some(90.0));
+        let mid = c.spl_at(100.0 * 10f64.sqrt()).unwrap();
+        assert!((mid - 85.0).abs() < 1e-9);
+        assert_eq!(
+            FrequencyCurve::new("x", vec![], vec![1.0]).spl_at(1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_directivity_grid() {
+        let angle = |a: f64, offset: f64| AngleCurve {
+            angle: a,
+            freq: vec![100.0, 1000.0],
+            spl: vec![80.0 + offset, 90.0 + offset],
+        };
+        let d = Directivity::new(vec![angle(30.0, -3.0), angle(0.0, 0.0)], Vec::new());
+        assert_eq!(d.horizontal[0].angle, 0.0);
+
+        let grid = d.grid(Plane::Horizontal, &[100.0, 1000.0]);
+        assert_eq!(grid.z(), (&[80.0, 90.0, 77.0, 87.0][..], 2, 2));
+        assert_eq!(grid.get(1, 1), Some(87.0));
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid.normalized().spl, vec![0.0, 0.0, -3.0, -3.0]);
+        assert!(d.grid(Plane::Vertical, &[100.0]).spl.is_empty());
+        assert_eq!(
+            d.spl_at(Plane::Horizontal, 1000.0),
+            vec![(0.0, 90.0), (30.0, 87.0)]
+        );
+    }
+}
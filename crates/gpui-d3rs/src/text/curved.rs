@@ -0,0 +1,217 @@
+//! Vector text along circular arcs
+//!
+//! Labels of pie slices, chord groups and gauges read better when they
+//! follow their arc. [`layout_text_on_arc`] places each glyph on a circle,
+//! centered on an angle and turned along the tangent. Angles follow the
+//! convention of [`crate::shape::arc`]: radians, 0 at 12 o'clock, clockwise.
+//!
+//! Text centered on the lower half of the circle would be upside down, so it
+//! is flipped: it runs counter-clockwise with its glyphs turned half a turn,
+//! reading left to right like the labels on the upper half.
+
+use super::vector_font::{VectorFontConfig, glyph_strokes, measure_text_width, paint_strokes};
+use gpui::prelude::*;
+use gpui::{canvas, px};
+use std::f32::consts::{PI, TAU};
+
+/// Text laid out along a circle
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcTextLayout {
+    /// Angle covered by the text, in radians
+    pub angular_span: f32,
+    /// Whether the text was flipped to read upright on the lower half
+    pub flipped: bool,
+    /// Polylines in pixels relative to the circle center
+    pub strokes: Vec<Vec<(f32, f32)>>,
+}
+
+impl ArcTextLayout {
+    /// Whether the text fits between two angles, e.g. those of a pie slice
+    pub fn fits(&self, start_angle: f32, end_angle: f32) -> bool {
+        self.angular_span <= (end_angle - start_angle).abs()
+    }
+}
+
+/// Whether text centered at `angle` is flipped to stay readable
+///
+/// True strictly between 3 and 9 o'clock, through 6 o'clock.
+pub fn is_lower_half(angle: f32) -> bool {
+    let angle = angle.rem_euclid(TAU);
+    angle > PI / 2.0 && angle < 3.0 * PI / 2.0
+}
+
+/// Angle covered by `text` on a circle of `radius`, in radians
+pub fn text_angular_span(text: &str, font_size: f32, radius: f32) -> f32 {
+    if radius <= 0.0 {
+        return f32::INFINITY;
+    }
+    measure_text_width(text, font_size) / radius
+}
+
+/// Lay out `text` along a circle of `radius`, centered at `angle`
+///
+/// Glyphs are vertically centered on the circle, so a label on the middle
+/// radius of a donut stays within the ring on both halves.
+pub fn layout_text_on_arc(text: &str, font_size: f32, radius: f32, angle: f32) -> ArcTextLayout {
+    let flipped = is_lower_half(angle);
+    let angular_span = text_angular_span(text, font_size, radius);
+    if !angular_span.is_finite() {
+        return ArcTextLayout {
+            angular_span,
+            flipped,
+            strokes: Vec::new(),
+        };
+    }
+
+    let scale = font_size / 21.0;
+    // Reading direction along the circle: counter-clockwise when flipped
+    let direction = if flipped { -1.0 } else { 1.0 };
+    let mut cursor = -measure_text_width(text, font_size) / 2.0;
+    let mut strokes = Vec::new();
+
+    for c in text.chars() {
+        let (advance, glyph) = glyph_strokes(c, scale);
+        let theta = angle + direction * (cursor + advance / 2.0) / radius;
+        // Glyph center on the circle, turned along the tangent
+        let (center_x, center_y) = (radius * theta.sin(), -radius * theta.cos());
+        let rotation = if flipped { theta + PI } else { theta };
+        let (sin_r, cos_r) = rotation.sin_cos();
+
+        for stroke in glyph {
+            let placed = stroke
+                .into_iter()
+                .map(|(x, y)| {
+                    let x = x - advance / 2.0;
+                    (
+                        center_x + x * cos_r - y * sin_r,
+                        center_y + x * sin_r + y * cos_r,
+                    )
+                })
+                .collect();
+            strokes.push(placed);
+        }
+        cursor += advance;
+    }
+
+    ArcTextLayout {
+        angular_span,
+        flipped,
+        strokes,
+    }
+}
+
+/// Paint `text` along a circle centered at (`x`, `y`)
+///
+/// This is useful for labels in custom Element paint methods, e.g. of the
+/// slices of a pie drawn with [`crate::shape::arc`]. The rotation of
+/// `config` is ignored.
+pub fn paint_text_on_arc(
+    window: &mut gpui::Window,
+    text: &str,
+    x: f32,
+    y: f32,
+    radius: f32,
+    angle: f32,
+    config: &VectorFontConfig,
+) {
+    let layout = layout_text_on_arc(text, config.font_size, radius, angle);
+    paint_strokes(
+        window,
+        &layout.strokes,
+        x,
+        y,
+        config.stroke_width,
+        config.color,
+    );
+}
+
+/// Render `text` along a circle of `radius`, centered at `angle`
+///
+/// Returns a square canvas centered on the circle, to be positioned over a
+/// chart with the same center. The rotation of `config` is ignored.
+pub fn render_text_on_arc(
+    text: &str,
+    radius: f32,
+    angle: f32,
+    config: &VectorFontConfig,
+) -> impl IntoElement {
+    let layout = layout_text_on_arc(text, config.font_size, radius, angle);
+    let (stroke_width, color) = (config.stroke_width, config.color);
+    let side = 2.0 * (radius + config.font_size);
+
+    canvas(
+        move |_bounds, _, _cx| {},
+        move |bounds, _, window, _cx| {
+            let center_x: f32 = bounds.center().x.into();
+            let center_y: f32 = bounds.center().y.into();
+            paint_strokes(
+                window,
+                &layout.strokes,
+                center_x,
+                center_y,
+                stroke_width,
+                color,
+            );
+        },
+    )
+    .w(px(side))
+    .h(px(side))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(layout: &ArcTextLayout) -> impl Iterator<Item = (f32, f32)> + '_ {
+        layout.strokes.iter().flatten().copied()
+    }
+
+    #[test]
+    fn test_is_lower_half() {
+        assert!(!is_lower_half(0.0));
+        assert!(!is_lower_half(PI / 4.0));
+        assert!(is_lower_half(PI));
+        assert!(is_lower_half(-PI));
+        assert!(!is_lower_half(7.0 * PI / 4.0));
+        assert!(!is_lower_half(TAU + 0.1));
+    }
+
+    #[test]
+    fn test_angular_span() {
+        let span = text_angular_span("Label", 12.0, 100.0);
+        assert!((span - measure_text_width("Label", 12.0) / 100.0).abs() < 1e-6);
+        assert_eq!(text_angular_span("Label", 12.0, 0.0), f32::INFINITY);
+
+        let layout = layout_text_on_arc("Label", 12.0, 100.0, 0.0);
+        assert!(layout.fits(-0.5, 0.5));
+        assert!(!layout.fits(0.0, span / 2.0));
+    }
+
+    #[test]
+    fn test_text_follows_circle() {
+        let radius = 100.0;
+        let layout = layout_text_on_arc("Arc", 12.0, radius, PI / 3.0);
+        assert!(!layout.flipped);
+        // Glyphs are centered on the circle, within half the font size
+        for (x, y) in points(&layout) {
+            let distance = (x * x + y * y).sqrt();
+            assert!((distance - radius).abs() <= 6.5, "{distance}");
+        }
+    }
+
+    #[test]
+    fn test_lower_half_is_flipped_and_reads_left_to_right() {
+        let top = layout_text_on_arc("Ab", 12.0, 100.0, 0.0);
+        let bottom = layout_text_on_arc("Ab", 12.0, 100.0, PI);
+        assert!(!top.flipped);
+        assert!(bottom.flipped);
+
+        // At 6 o'clock, the text sits below the center and is upright, so
+        // it matches the top text moved down by the diameter
+        for ((tx, ty), (bx, by)) in points(&top).zip(points(&bottom)) {
+            assert!(by > 0.0);
+            assert!((bx - tx).abs() < 2.0, "{bx} vs {tx}");
+            assert!((by - (ty + 200.0)).abs() < 2.0, "{by} vs {ty}");
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Layout cache for vector text
+//!
+//! Axis tick labels and legends are redrawn every frame with the same
+//! strings. Laying them out (glyph lookup, scaling, rotation) is cached here,
+//! keyed by string, font size and rotation, with least-recently-used
+//! eviction. Painting a cached layout only translates its points.
+//!
+//! The drawing functions of this module use a process-wide cache; its hit
+//! statistics are available through [`text_layout_cache_stats`] and can be
+//! shown with [`super::render_text_cache_stats`].
+
+use super::vector_font::layout_text;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard};
+
+/// Default number of layouts kept by the global cache
+pub const DEFAULT_TEXT_LAYOUT_CACHE_CAPACITY: usize = 1024;
+
+/// Text laid out as stroke polylines, centered on the origin
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLayout {
+    /// Advance width of the text in pixels
+    pub width: f32,
+    /// Polylines in pixels relative to the text center, rotation applied
+    pub strokes: Vec<Vec<(f32, f32)>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct LayoutKey {
+    text: String,
+    font_size: u32,
+    rotation: u32,
+}
+
+impl LayoutKey {
+    fn new(text: &str, font_size: f32, rotation: f32) -> Self {
+        Self {
+            text: text.to_string(),
+            font_size: font_size.to_bits(),
+            rotation: rotation.to_bits(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    layout: Arc<TextLayout>,
+    last_used: u64,
+}
+
+/// Hit statistics of a [`TextLayoutCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextLayoutCacheStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that had to lay out the text
+    pub misses: u64,
+    /// Layouts dropped to stay within capacity
+    pub evictions: u64,
+    /// Layouts currently cached
+    pub entries: usize,
+    /// Maximum number of cached layouts
+    pub capacity: usize,
+}
+
+impl TextLayoutCacheStats {
+    /// Fraction of lookups answered from the cache (0 when nothing was looked up)
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl fmt::Display for TextLayoutCacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.1}% hits ({}/{}), {}/{} layouts, {} evicted",
+            self.hit_rate() * 100.0,
+            self.hits,
+            self.hits + self.misses,
+            self.entries,
+            self.capacity,
+            self.evictions
+        )
+    }
+}
+
+/// LRU cache of text layouts
+#[derive(Debug)]
+pub struct TextLayoutCache {
+    entries: HashMap<LayoutKey, CacheEntry>,
+    capacity: usize,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl Default for TextLayoutCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_TEXT_LAYOUT_CACHE_CAPACITY)
+    }
+}
+
+impl TextLayoutCache {
+    /// Create a cache holding at most `capacity` layouts (at least one)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    /// Get the layout of `text`, laying it out on a miss
+    pub fn get(&mut self, text: &str, font_size: f32, rotation: f32) -> Arc<TextLayout> {
+        self.get_or_insert_with(text, font_size, rotation, || {
+            layout_text(text, font_size, rotation)
+        })
+    }
+
+    fn get_or_insert_with(
+        &mut self,
+        text: &str,
+        font_size: f32,
+        rotation: f32,
+        layout: impl FnOnce() -> TextLayout,
+    ) -> Arc<TextLayout> {
+        self.clock += 1;
+        let key = LayoutKey::new(text, font_size, rotation);
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.last_used = self.clock;
+            self.hits += 1;
+            return Arc::clone(&entry.layout);
+        }
+
+        self.misses += 1;
+        if self.entries.len() >= self.capacity {
+            self.evict(self.entries.len() + 1 - self.capacity);
+        }
+        let layout = Arc::new(layout());
+        self.entries.insert(
+            key,
+            CacheEntry {
+                layout: Arc::clone(&layout),
+                last_used: self.clock,
+            },
+        );
+        layout
+    }
+
+    /// Drop the `count` least recently used layouts
+    fn evict(&mut self, count: usize) {
+        let mut by_age: Vec<(u64, LayoutKey)> = self
+            .entries
+            .iter()
+            .map(|(key, entry)| (entry.last_used, key.clone()))
+            .collect();
+        by_age.sort_unstable_by_key(|(last_used, _)| *last_used);
+        for (_, key) in by_age.into_iter().take(count) {
+            self.entries.remove(&key);
+            self.evictions += 1;
+        }
+    }
+
+    /// Change the capacity, evicting the oldest layouts if needed
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        if self.entries.len() > self.capacity {
+            self.evict(self.entries.len() - self.capacity);
+        }
+    }
+
+    /// Number of cached layouts
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop all cached layouts (statistics are kept)
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Current hit statistics
+    pub fn stats(&self) -> TextLayoutCacheStats {
+        TextLayoutCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            entries: self.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+
+    /// Reset the hit statistics
+    pub fn reset_stats(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+        self.evictions = 0;
+    }
+}
+
+static GLOBAL_CACHE: LazyLock<Mutex<TextLayoutCache>> =
+    LazyLock::new(|| Mutex::new(TextLayoutCache::default()));
+
+fn global_cache() -> MutexGuard<'static, TextLayoutCache> {
+    GLOBAL_CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Get the layout of `text` from the global cache
+pub fn cached_text_layout(text: &str, font_size: f32, rotation: f32) -> Arc<TextLayout> {
+    global_cache().get(text, font_size, rotation)
+}
+
+/// Hit statistics of the global cache
+pub fn text_layout_cache_stats() -> TextLayoutCacheStats {
+    global_cache().stats()
+}
+
+/// Reset the hit statistics of the global cache
+pub fn reset_text_layout_cache_stats() {
+    global_cache().reset_stats();
+}
+
+/// Change the capacity of the global cache
+pub fn set_text_layout_cache_capacity(capacity: usize) {
+    global_cache().set_capacity(capacity);
+}
+
+/// Drop all layouts from the global cache
+pub fn clear_text_layout_cache() {
+    global_cache().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy(width: f32) -> TextLayout {
+        TextLayout {
+            width,
+            strokes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_hits_and_misses() {
+        let mut cache = TextLayoutCache::new(8);
+        let first = cache.get_or_insert_with("100 Hz", 12.0, 0.0, || dummy(1.0));
+        let again = cache.get_or_insert_with("100 Hz", 12.0, 0.0, || dummy(2.0));
+        assert!(Arc::ptr_eq(&first, &again));
+
+        // Font size and rotation are part of the key
+        cache.get_or_insert_with("100 Hz", 14.0, 0.0, || dummy(3.0));
+        cache.get_or_insert_with("100 Hz", 12.0, -1.5, || dummy(4.0));
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 3, 3));
+        assert_eq!(stats.hit_rate(), 0.25);
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut cache = TextLayoutCache::new(2);
+        cache.get_or_insert_with("a", 12.0, 0.0, || dummy(1.0));
+        cache.get_or_insert_with("b", 12.0, 0.0, || dummy(2.0));
+        // Touch "a" so "b" is the least recently used
+        cache.get_or_insert_with("a", 12.0, 0.0, || dummy(0.0));
+        cache.get_or_insert_with("c", 12.0, 0.0, || dummy(3.0));
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.stats().evictions, 1);
+        let a = cache.get_or_insert_with("a", 12.0, 0.0, || dummy(0.0));
+        assert_eq!(a.width, 1.0);
+        let b = cache.get_or_insert_with("b", 12.0, 0.0, || dummy(5.0));
+        assert_eq!(b.width, 5.0);
+
+        cache.set_capacity(1);
+        assert_eq!(cache.len(), 1);
+    }
+}
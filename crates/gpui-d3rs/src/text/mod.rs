@@ -1,10 +1,25 @@
 //! Vector font text rendering
 //!
 //! This module provides a simple stroke-based vector font that can be used
-//! to render text as paths, allowing for rotation and other transformations.
+//! to render text as paths, allowing for rotation and other transformations,
+//! including text following an arc (see [`layout_text_on_arc`]).
+//! Text layouts are cached (see [`TextLayoutCache`]) so labels redrawn every
+//! frame are only laid out once.
 
+mod curved;
+mod layout_cache;
 mod vector_font;
 
+pub use curved::{
+    ArcTextLayout, is_lower_half, layout_text_on_arc, paint_text_on_arc, render_text_on_arc,
+    text_angular_span,
+};
+pub use layout_cache::{
+    DEFAULT_TEXT_LAYOUT_CACHE_CAPACITY, TextLayout, TextLayoutCache, TextLayoutCacheStats,
+    cached_text_layout, clear_text_layout_cache, reset_text_layout_cache_stats,
+    set_text_layout_cache_capacity, text_layout_cache_stats,
+};
 pub use vector_font::{
-    VectorFontConfig, measure_text_width, paint_vector_text_at, render_vector_text,
+    VectorFontConfig, measure_text_width, paint_vector_text_at, render_text_cache_stats,
+    render_vector_text,
 };
@@ -4,11 +4,12 @@
 //! Font data from https://paulbourke.net/dataformats/hershey/
 //! Public domain - no usage restrictions.
 
+use super::layout_cache::{TextLayout, cached_text_layout, text_layout_cache_stats};
 use gpui::prelude::*;
 use gpui::{Hsla, PathBuilder, canvas, hsla, point, px};
 use std::collections::HashMap;
 use std::f32::consts::PI;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 /// Configuration for vector font rendering
 #[derive(Clone)]
@@ -779,19 +780,113 @@ pub fn measure_text_width(text: &str, font_size: f32) -> f32 {
     calculate_text_width(text) * scale
 }
 
+/// Strokes of one character and its advance width, in pixels at `scale`
+///
+/// Points are relative to the left edge of the character, vertically
+/// centered. Unknown characters have no strokes and the default width.
+pub(super) fn glyph_strokes(c: char, scale: f32) -> (f32, Vec<Vec<(f32, f32)>>) {
+    let Some(ch) = get_hershey_char(c) else {
+        // Unknown character - skip with default width
+        return (16.0 * scale, Vec::new());
+    };
+    let mut strokes = Vec::new();
+    let mut stroke: Vec<(f32, f32)> = Vec::new();
+
+    // Process coordinate pairs
+    for pair in ch.data.chunks_exact(2) {
+        let (x, y) = (pair[0], pair[1]);
+        if x == -1 && y == -1 {
+            // Pen up - finish the current stroke
+            if stroke.len() > 1 {
+                strokes.push(std::mem::take(&mut stroke));
+            }
+            stroke.clear();
+        } else {
+            // Convert Hershey coordinates to our coordinate system
+            // Hershey: y=21 at top, y=0 at baseline
+            // We want: centered vertically
+            stroke.push((x as f32 * scale, (21.0 - y as f32 - 10.5) * scale));
+        }
+    }
+    if stroke.len() > 1 {
+        strokes.push(stroke);
+    }
+
+    (ch.width as f32 * scale, strokes)
+}
+
+/// Lay out text as stroke polylines, centered on the origin and rotated.
+///
+/// This is the expensive part of drawing vector text; callers go through
+/// the [`super::layout_cache`] so each label is laid out once.
+pub(super) fn layout_text(text: &str, font_size: f32, rotation: f32) -> TextLayout {
+    let hershey_height = 21.0;
+    let scale = font_size / hershey_height;
+
+    let cos_r = rotation.cos();
+    let sin_r = rotation.sin();
+
+    // Starting position (centered)
+    let text_width_units = calculate_text_width(text);
+    let mut cursor_x = -text_width_units * scale / 2.0;
+    let mut strokes = Vec::new();
+
+    for c in text.chars() {
+        let (advance, glyph) = glyph_strokes(c, scale);
+        for stroke in glyph {
+            // Apply rotation around center
+            let rotated = stroke
+                .into_iter()
+                .map(|(x, y)| {
+                    let px_local = cursor_x + x;
+                    (px_local * cos_r - y * sin_r, px_local * sin_r + y * cos_r)
+                })
+                .collect();
+            strokes.push(rotated);
+        }
+        cursor_x += advance;
+    }
+
+    TextLayout {
+        width: text_width_units * scale,
+        strokes,
+    }
+}
+
+/// Paint stroke polylines offset by (`x`, `y`)
+pub(super) fn paint_strokes(
+    window: &mut gpui::Window,
+    strokes: &[Vec<(f32, f32)>],
+    x: f32,
+    y: f32,
+    stroke_width: f32,
+    color: impl Into<gpui::Background> + Copy,
+) {
+    for stroke in strokes {
+        let mut builder = PathBuilder::stroke(px(stroke_width));
+        let (x0, y0) = stroke[0];
+        builder.move_to(point(px(x + x0), px(y + y0)));
+        for &(sx, sy) in &stroke[1..] {
+            builder.line_to(point(px(x + sx), px(y + sy)));
+        }
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, color);
+        }
+    }
+}
+
 /// Render vector text using Hershey Simplex font
 /// Returns a canvas element that draws the text
 pub fn render_vector_text(text: &str, config: &VectorFontConfig) -> impl IntoElement {
-    let text = text.to_string();
     let config = config.clone();
+    let layout = cached_text_layout(text, config.font_size, config.rotation);
+    render_layout(layout, config)
+}
 
-    // Hershey font has height ~21 units (from top at 21 to baseline at 0)
-    let hershey_height = 21.0;
-    let scale = config.font_size / hershey_height;
-
+/// Canvas element drawing an already laid-out text
+fn render_layout(layout: Arc<TextLayout>, config: VectorFontConfig) -> impl IntoElement {
     // Calculate text bounds in pixels
-    let text_width_units = calculate_text_width(&text);
-    let text_width = text_width_units * scale;
+    let text_width = layout.width;
     let text_height = config.font_size;
 
     // Account for rotation in bounds
@@ -808,87 +903,32 @@ pub fn render_vector_text(text: &str, config: &VectorFontConfig) -> impl IntoEle
         move |bounds, _, window, _cx| {
             let center_x: f32 = bounds.center().x.into();
             let center_y: f32 = bounds.center().y.into();
-
-            let cos_r = config.rotation.cos();
-            let sin_r = config.rotation.sin();
-
-            let hershey_height = 21.0;
-            let scale = config.font_size / hershey_height;
-
-            // Starting position (centered)
-            let text_width_units = calculate_text_width(&text);
-            let mut cursor_x = -text_width_units * scale / 2.0;
-
-            for c in text.chars() {
-                if let Some(ch) = get_hershey_char(c) {
-                    let char_width = ch.width as f32 * scale;
-
-                    // Process coordinate pairs
-                    let data = ch.data;
-                    let mut i = 0;
-                    let mut pen_down = false;
-                    let mut builder = PathBuilder::stroke(px(config.stroke_width));
-                    let mut has_path = false;
-
-                    while i + 1 < data.len() {
-                        let x = data[i];
-                        let y = data[i + 1];
-                        i += 2;
-
-                        if x == -1 && y == -1 {
-                            // Pen up - draw current path and start new one
-                            if has_path
-                                && let Ok(path) = builder.build()
-                            {
-                                window.paint_path(path, config.color);
-                            }
-                            builder = PathBuilder::stroke(px(config.stroke_width));
-                            has_path = false;
-                            pen_down = false;
-                        } else {
-                            // Convert Hershey coordinates to our coordinate system
-                            // Hershey: y=21 at top, y=0 at baseline
-                            // We want: centered vertically
-                            let px_val = cursor_x + x as f32 * scale;
-                            let py_val = (21.0 - y as f32 - 10.5) * scale; // Center vertically
-
-                            // Apply rotation around center
-                            let rx = px_val * cos_r - py_val * sin_r;
-                            let ry = px_val * sin_r + py_val * cos_r;
-
-                            // Translate to canvas position
-                            let final_x = center_x + rx;
-                            let final_y = center_y + ry;
-
-                            if !pen_down {
-                                builder.move_to(point(px(final_x), px(final_y)));
-                                pen_down = true;
-                                has_path = true;
-                            } else {
-                                builder.line_to(point(px(final_x), px(final_y)));
-                            }
-                        }
-                    }
-
-                    // Draw remaining path
-                    if has_path
-                        && let Ok(path) = builder.build()
-                    {
-                        window.paint_path(path, config.color);
-                    }
-
-                    cursor_x += char_width;
-                } else {
-                    // Unknown character - skip with default width
-                    cursor_x += 16.0 * scale;
-                }
-            }
+            paint_strokes(
+                window,
+                &layout.strokes,
+                center_x,
+                center_y,
+                config.stroke_width,
+                config.color,
+            );
         },
     )
     .w(px(canvas_width))
     .h(px(canvas_height))
 }
 
+/// Render the text layout cache statistics as vector text
+///
+/// A small perf overlay line such as `text cache: 98.5% hits (1970/2000),
+/// 120/1024 layouts, 0 evicted`. Drop it in a corner of a chart or demo
+/// window to check that labels are not re-laid out every frame. The overlay
+/// itself bypasses the cache so it doesn't skew the numbers it reports.
+pub fn render_text_cache_stats(config: &VectorFontConfig) -> impl IntoElement {
+    let text = format!("text cache: {}", text_layout_cache_stats());
+    let layout = Arc::new(layout_text(&text, config.font_size, config.rotation));
+    render_layout(layout, config.clone())
+}
+
 /// Paint vector text directly onto a window at a given position
 /// This is useful for rendering text in custom Element paint methods
 pub fn paint_vector_text_at(
@@ -902,78 +942,8 @@ pub fn paint_vector_text_at(
     rotation: f32,
 ) {
     let color: gpui::Rgba = color.into();
-    let hershey_height = 21.0;
-    let scale = font_size / hershey_height;
-
-    let cos_r = rotation.cos();
-    let sin_r = rotation.sin();
-
-    // Starting position (centered)
-    let text_width_units = calculate_text_width(text);
-    let mut cursor_x = -text_width_units * scale / 2.0;
-
-    for c in text.chars() {
-        if let Some(ch) = get_hershey_char(c) {
-            let char_width = ch.width as f32 * scale;
-
-            // Process coordinate pairs
-            let data = ch.data;
-            let mut i = 0;
-            let mut pen_down = false;
-            let mut builder = PathBuilder::stroke(px(stroke_width));
-            let mut has_path = false;
-
-            while i + 1 < data.len() {
-                let px_val_data = data[i];
-                let py_val_data = data[i + 1];
-                i += 2;
-
-                if px_val_data == -1 && py_val_data == -1 {
-                    // Pen up - draw current path and start new one
-                    if has_path
-                        && let Ok(path) = builder.build()
-                    {
-                        window.paint_path(path, color);
-                    }
-                    builder = PathBuilder::stroke(px(stroke_width));
-                    has_path = false;
-                    pen_down = false;
-                } else {
-                    // Convert Hershey coordinates to our coordinate system
-                    let px_local = cursor_x + px_val_data as f32 * scale;
-                    let py_local = (21.0 - py_val_data as f32 - 10.5) * scale;
-
-                    // Apply rotation around center
-                    let rx = px_local * cos_r - py_local * sin_r;
-                    let ry = px_local * sin_r + py_local * cos_r;
-
-                    // Translate to final position
-                    let final_x = x + rx;
-                    let final_y = y + ry;
-
-                    if !pen_down {
-                        builder.move_to(point(px(final_x), px(final_y)));
-                        pen_down = true;
-                        has_path = true;
-                    } else {
-                        builder.line_to(point(px(final_x), px(final_y)));
-                    }
-                }
-            }
-
-            // Draw remaining path
-            if has_path
-                && let Ok(path) = builder.build()
-            {
-                window.paint_path(path, color);
-            }
-
-            cursor_x += char_width;
-        } else {
-            // Unknown character - skip with default width
-            cursor_x += 16.0 * scale;
-        }
-    }
+    let layout = cached_text_layout(text, font_size, rotation);
+    paint_strokes(window, &layout.strokes, x, y, stroke_width, color);
 }
 
 // Note: Tests removed because they cause rustc to crash with SIGBUS
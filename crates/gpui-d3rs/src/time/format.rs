@@ -4,7 +4,24 @@
 //! Full strftime support would require a heavy dependency like `chrono`.
 //! Here we provide a lightweight formatter compatible with standard D3 expectations.
 
-// use super::interval::{Interval, TimeInterval};
+use super::duration;
+use super::interval::{Interval, TimeInterval, civil_from_days};
+
+/// English month names, for `%B` (and `%b`, abbreviated to 3 letters)
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
 
 /// Date format specifier
 pub struct TimeFormat {
@@ -18,34 +35,18 @@ impl TimeFormat {
         }
     }
 
-    /// Format a timestamp (Unix seconds)
+    /// Format a timestamp (Unix seconds, UTC)
+    ///
+    /// Supported tokens: %Y, %m, %d, %H, %M, %S, %b (e.g., "Mar") and
+    /// %B (e.g., "March").
     pub fn format(&self, timestamp: i64) -> String {
-        // We use the `time` crate or `chrono` if available, or a simple implementation
-        // Since we don't want to add heavy dependencies for this specific demo unless requested,
-        // we'll defer to a minimal implementation or assuming `chrono` is available if common.
-        // However, `gpui-d3rs` Cargo.toml doesn't show chrono.
-
-        // Let's implement basic ISO formatting and simple tokens.
-        // %Y, %m, %d, %H, %M, %S
-
         // Convert timestamp to components (UTC)
-        let days = timestamp / 86400;
-        let seconds_in_day = timestamp % 86400;
+        let (year, m, d) = civil_from_days(timestamp.div_euclid(duration::DAY));
+        let seconds_in_day = timestamp.rem_euclid(duration::DAY);
         let hour = seconds_in_day / 3600;
         let minute = (seconds_in_day % 3600) / 60;
         let second = seconds_in_day % 60;
-
-        // Simplified Gregorian calendar
-        let z = days + 719468;
-        let era = (if z >= 0 { z } else { z - 146096 }) / 146097;
-        let doe = z - era * 146097;
-        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
-        let y = yoe + era * 400;
-        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
-        let mp = (5 * doy + 2) / 153;
-        let d = doy - (153 * mp + 2) / 5 + 1;
-        let m = mp + (if mp < 10 { 3 } else { -9 });
-        let year = y + (if m <= 2 { 1 } else { 0 });
+        let month_name = MONTH_NAMES[(m - 1) as usize];
 
         let mut result = self.pattern.clone();
 
@@ -55,6 +56,8 @@ impl TimeFormat {
         result = result.replace("%H", &format!("{:02}", hour));
         result = result.replace("%M", &format!("{:02}", minute));
         result = result.replace("%S", &format!("{:02}", second));
+        result = result.replace("%B", month_name);
+        result = result.replace("%b", &month_name[..3]);
 
         result
     }
@@ -64,3 +67,51 @@ impl TimeFormat {
 pub fn format(pattern: &str, timestamp: i64) -> String {
     TimeFormat::new(pattern).format(timestamp)
 }
+
+/// Format a time axis tick with the coarsest unit that starts at it
+///
+/// Like d3's multi-scale time format: ticks within a day show the time,
+/// midnights the day, the first of a month the month name and January 1st
+/// the year, so labels follow the zoom level (e.g., "Feb 28", "March",
+/// "Mar 02" or "06:00", "12:00").
+pub fn format_tick(timestamp: i64) -> String {
+    let pattern = if timestamp.rem_euclid(duration::MINUTE) != 0 {
+        "%H:%M:%S"
+    } else if timestamp.rem_euclid(duration::DAY) != 0 {
+        "%H:%M"
+    } else if TimeInterval::Month.floor(timestamp) != timestamp {
+        "%b %d"
+    } else if TimeInterval::Year.floor(timestamp) != timestamp {
+        "%B"
+    } else {
+        "%Y"
+    };
+    format(pattern, timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let timestamp = 1709642096; // Mar 5, 2024 12:34:56 UTC
+        assert_eq!(
+            format("%Y-%m-%d %H:%M:%S", timestamp),
+            "2024-03-05 12:34:56"
+        );
+        assert_eq!(format("%b %d", timestamp), "Mar 05");
+        assert_eq!(format("%B %Y", timestamp), "March 2024");
+        // Before the epoch
+        assert_eq!(format("%Y-%m-%d %H:%M", -60), "1969-12-31 23:59");
+    }
+
+    #[test]
+    fn test_format_tick() {
+        assert_eq!(format_tick(1709642096), "12:34:56");
+        assert_eq!(format_tick(1709640000), "12:00");
+        assert_eq!(format_tick(1709596800), "Mar 05");
+        assert_eq!(format_tick(1709251200), "March");
+        assert_eq!(format_tick(1704067200), "2024");
+    }
+}
@@ -102,17 +102,12 @@ impl Interval for TimeInterval {
                 (days_since_epoch - days_to_monday) * duration::DAY
             }
             TimeInterval::Month => {
-                // Approximate: floor to first of month using UTC
-                let days = timestamp / duration::DAY;
-                // Very rough approximation - proper implementation needs full date library
-                let approx_months = days / 30;
-                approx_months * 30 * duration::DAY
+                let (year, month, _) = civil_from_days(timestamp.div_euclid(duration::DAY));
+                days_from_civil(year, month, 1) * duration::DAY
             }
             TimeInterval::Year => {
-                // Approximate: floor to first of year using UTC
-                let days = timestamp / duration::DAY;
-                let approx_years = days / 365;
-                approx_years * 365 * duration::DAY
+                let (year, _, _) = civil_from_days(timestamp.div_euclid(duration::DAY));
+                days_from_civil(year, 1, 1) * duration::DAY
             }
         }
     }
@@ -124,14 +119,8 @@ impl Interval for TimeInterval {
             TimeInterval::Hour => timestamp + step * duration::HOUR,
             TimeInterval::Day => timestamp + step * duration::DAY,
             TimeInterval::Week | TimeInterval::Monday => timestamp + step * duration::WEEK,
-            TimeInterval::Month => {
-                // Approximate: add 30 days per month
-                timestamp + step * 30 * duration::DAY
-            }
-            TimeInterval::Year => {
-                // Approximate: add 365 days per year
-                timestamp + step * 365 * duration::DAY
-            }
+            TimeInterval::Month => add_months(timestamp, step),
+            TimeInterval::Year => add_months(timestamp, 12 * step),
         }
     }
 }
@@ -183,6 +172,48 @@ impl TimeInterval {
     }
 }
 
+/// Year, month (1-12) and day (1-31) of a day number since the Unix epoch
+///
+/// Proleptic Gregorian calendar, after Howard Hinnant's `civil_from_days`.
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Day number since the Unix epoch of a date, inverse of [`civil_from_days`]
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Move a timestamp by whole calendar months, keeping the time of day
+///
+/// The day of the month is clamped to the length of the target month
+/// (e.g., Jan 31 + 1 month is Feb 28 or 29).
+fn add_months(timestamp: i64, months: i64) -> i64 {
+    let days = timestamp.div_euclid(duration::DAY);
+    let time_of_day = timestamp.rem_euclid(duration::DAY);
+    let (year, month, day) = civil_from_days(days);
+    let index = year * 12 + (month - 1) + months;
+    let (year, month) = (index.div_euclid(12), index.rem_euclid(12) + 1);
+    let month_length =
+        days_from_civil(year + (month / 12), month % 12 + 1, 1) - days_from_civil(year, month, 1);
+    days_from_civil(year, month, day.min(month_length)) * duration::DAY + time_of_day
+}
+
 /// Shorthand functions for common intervals
 pub fn time_second() -> TimeInterval {
     TimeInterval::Second
@@ -268,6 +299,37 @@ mod tests {
         assert_eq!(count, 7);
     }
 
+    #[test]
+    fn test_floor_month_and_year() {
+        let timestamp = 1709642096; // Mar 5, 2024 12:34:56 UTC
+        // Mar 1, 2024 00:00:00 UTC
+        assert_eq!(TimeInterval::Month.floor(timestamp), 1709251200);
+        // Jan 1, 2024 00:00:00 UTC
+        assert_eq!(TimeInterval::Year.floor(timestamp), 1704067200);
+    }
+
+    #[test]
+    fn test_offset_month_and_year() {
+        let jan_31 = 1706659200; // Jan 31, 2024 00:00:00 UTC
+        // Clamped to Feb 29 in a leap year
+        assert_eq!(TimeInterval::Month.offset(jan_31, 1), 1709164800);
+        // Dec 31, 2023
+        assert_eq!(TimeInterval::Month.offset(jan_31, -1), 1703980800);
+        // Jan 31, 2025
+        assert_eq!(TimeInterval::Year.offset(jan_31, 1), 1738281600);
+    }
+
+    #[test]
+    fn test_civil_round_trip() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19782), (2024, 2, 29));
+        for days in [-800_000, -1, 0, 59, 19782, 2_000_000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
     #[test]
     fn test_for_span() {
         assert_eq!(TimeInterval::for_span(30), TimeInterval::Second);
@@ -1,7 +1,7 @@
 //! Time scale implementation
 
 use super::duration;
-use super::interval::{Interval, TimeInterval};
+use super::interval::{Interval, TimeInterval, civil_from_days};
 use crate::scale::{Scale, nice_number};
 
 /// A time scale maps temporal domain to continuous range
@@ -103,33 +103,32 @@ impl TimeScale {
     }
 
     /// Get time-appropriate ticks
+    ///
+    /// Ticks fall on calendar boundaries (e.g., every 6 hours from midnight,
+    /// the first of each month), with the largest step giving at most
+    /// `count` ticks.
     pub fn time_ticks(&self, count: usize) -> Vec<i64> {
-        let span = self.domain_max - self.domain_min;
-        let interval = self.best_interval(span, count);
-        interval.range(self.domain_min, self.domain_max + 1, 1)
-    }
-
-    /// Get the best interval for tick generation
-    fn best_interval(&self, span: i64, target_count: usize) -> TimeInterval {
-        // Find interval that gives approximately target_count ticks
-        let intervals = [
-            (TimeInterval::Second, duration::SECOND),
-            (TimeInterval::Minute, duration::MINUTE),
-            (TimeInterval::Hour, duration::HOUR),
-            (TimeInterval::Day, duration::DAY),
-            (TimeInterval::Week, duration::WEEK),
-            (TimeInterval::Month, 30 * duration::DAY),
-            (TimeInterval::Year, 365 * duration::DAY),
-        ];
-
-        for (interval, dur) in intervals.iter().rev() {
-            let count = span / dur;
-            if count as usize >= target_count {
-                return *interval;
-            }
-        }
-
-        TimeInterval::Second
+        let (start, stop) = (
+            self.domain_min.min(self.domain_max),
+            self.domain_min.max(self.domain_max),
+        );
+        let target = (stop - start) as f64 / count.max(1) as f64;
+        let (interval, step) = TICK_STEPS
+            .iter()
+            .copied()
+            .find(|(interval, step)| (interval.duration() * step) as f64 >= target)
+            .unwrap_or_else(|| {
+                let years = target / TimeInterval::Year.duration() as f64;
+                (
+                    TimeInterval::Year,
+                    nice_number(years, false).max(1.0) as i64,
+                )
+            });
+        interval
+            .range(start, stop + 1, 1)
+            .into_iter()
+            .filter(|&tick| tick_field(interval, tick) % step == 0)
+            .collect()
     }
 
     /// Get the appropriate time interval for the current domain
@@ -138,6 +137,43 @@ impl TimeScale {
     }
 }
 
+/// Tick steps from finest to coarsest, as in d3's time scale
+const TICK_STEPS: [(TimeInterval, i64); 18] = [
+    (TimeInterval::Second, 1),
+    (TimeInterval::Second, 5),
+    (TimeInterval::Second, 15),
+    (TimeInterval::Second, 30),
+    (TimeInterval::Minute, 1),
+    (TimeInterval::Minute, 5),
+    (TimeInterval::Minute, 15),
+    (TimeInterval::Minute, 30),
+    (TimeInterval::Hour, 1),
+    (TimeInterval::Hour, 3),
+    (TimeInterval::Hour, 6),
+    (TimeInterval::Hour, 12),
+    (TimeInterval::Day, 1),
+    (TimeInterval::Day, 2),
+    (TimeInterval::Week, 1),
+    (TimeInterval::Month, 1),
+    (TimeInterval::Month, 3),
+    (TimeInterval::Year, 1),
+];
+
+/// Field of a tick that its step must divide (e.g., the hour for every
+/// 6 hours, the month for every quarter)
+fn tick_field(interval: TimeInterval, timestamp: i64) -> i64 {
+    let (year, month, day) = civil_from_days(timestamp.div_euclid(duration::DAY));
+    match interval {
+        TimeInterval::Second => timestamp.rem_euclid(duration::MINUTE),
+        TimeInterval::Minute => timestamp.rem_euclid(duration::HOUR) / duration::MINUTE,
+        TimeInterval::Hour => timestamp.rem_euclid(duration::DAY) / duration::HOUR,
+        TimeInterval::Day => day - 1,
+        TimeInterval::Week | TimeInterval::Monday => 0,
+        TimeInterval::Month => month - 1,
+        TimeInterval::Year => year,
+    }
+}
+
 impl Scale<i64, f64> for TimeScale {
     fn scale(&self, value: i64) -> f64 {
         let value = if self.clamped {
@@ -226,6 +262,31 @@ mod tests {
         assert!(ticks.len() <= 14); // Reasonable number of ticks
     }
 
+    #[test]
+    fn test_time_scale_ticks_on_calendar_boundaries() {
+        let start = 1709642096; // Mar 5, 2024 12:34:56 UTC
+
+        // Two days: every 6 hours from midnight
+        let scale = TimeScale::new().domain(start, start + 2 * 86400);
+        let ticks = scale.time_ticks(10);
+        assert_eq!(ticks.len(), 8);
+        assert_eq!(ticks[0], 1709661600); // Mar 5, 18:00
+        assert!(ticks.windows(2).all(|w| w[1] - w[0] == 6 * 3600));
+
+        // Ten months: the first of each month
+        let scale = TimeScale::new().domain(start, start + 300 * 86400);
+        let ticks = scale.time_ticks(12);
+        assert_eq!(ticks.len(), 9);
+        assert_eq!(ticks[0], 1711929600); // Apr 1
+        assert!(ticks.iter().all(|&t| TimeInterval::Month.floor(t) == t));
+
+        // Decades: nice multiples of years
+        let scale = TimeScale::new().domain(0, 1262304000); // 1970 to 2010
+        let ticks = scale.time_ticks(5);
+        assert_eq!(ticks.len(), 5);
+        assert_eq!(ticks[1], 315532800); // Jan 1, 1980
+    }
+
     #[test]
     fn test_time_scale_interval() {
         // 86400 seconds = 1 day, for_span returns Day for spans >= 1 day
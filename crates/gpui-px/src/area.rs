@@ -1,42 +1,132 @@
 //! Area chart - Plotly Express style API.
 
+use crate::axis::Axis;
 use crate::error::ChartError;
+use crate::interaction::PlotBoundsCapture;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::line::{LegendClickCallback, LegendPosition};
 use crate::{
-    DEFAULT_COLOR, DEFAULT_HEIGHT, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE,
-    DEFAULT_WIDTH, ScaleType, TITLE_AREA_HEIGHT, extent_padded, validate_data_array,
-    validate_data_length, validate_dimensions, validate_positive,
+    ChartDefaults, DEFAULT_COLOR, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE, GapMode,
+    ScaleType, TITLE_AREA_HEIGHT, extent_padded, interpolate_gaps, validate_data_array,
+    validate_data_length, validate_data_with_gaps, validate_dimensions, validate_plot_area,
+    validate_positive,
 };
 use d3rs::color::D3Color;
 use d3rs::scale::{LinearScale, LogScale, Scale};
-use d3rs::shape::{Area, Curve};
+use d3rs::shape::{Area, Curve, FillPattern, Stack, StackOffset, StackOrder, paint_pattern};
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
-use gpui::{AnyElement, IntoElement, PathBuilder, Rgba, canvas, div, hsla, px};
+use gpui::{
+    App, ElementId, IntoElement, MouseButton, PathBuilder, Rgba, Window, canvas, div, hsla, px, rgb,
+};
+use std::collections::HashSet;
+use std::rc::Rc;
 use std::sync::Arc;
 
+/// Stacking mode for multi-series area charts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum AreaStackMode {
+    /// Series are drawn independently and overlap each other (default).
+    #[default]
+    Overlay,
+    /// Series are stacked on top of each other from a zero baseline.
+    Stacked,
+    /// Series are stacked and normalized so each column sums to 1 (100% stacked).
+    Percent,
+    /// Streamgraph: series are stacked inside-out around a wiggle-minimizing baseline.
+    Stream,
+}
+
+/// Callback type for hover events (receives the hovered X index, if any)
+pub type AreaHoverCallback = Rc<dyn Fn(Option<usize>, &mut Window, &mut App)>;
+
+/// A single series in an area chart
+#[derive(Debug, Clone)]
+struct AreaSeries {
+    y: Vec<f64>,
+    label: Option<String>,
+    color: u32,
+}
+
+/// Computed lower and upper bounds of one visible series.
+#[derive(Debug, Clone)]
+struct AreaLayer {
+    /// Series index (0 = primary series, 1+ = additional series)
+    series_index: usize,
+    y0: Vec<f64>,
+    y1: Vec<f64>,
+}
+
 /// Area chart builder.
 #[derive(Clone)]
 pub struct AreaChart {
     x: Vec<f64>,
     y: Vec<f64>,
     y0: Option<Vec<f64>>,
+    label: Option<String>,
+    // Additional series
+    series: Vec<AreaSeries>,
     title: Option<String>,
     color: u32,
     opacity: f32,
+    /// Fill patterns, cycled over the series
+    patterns: Vec<FillPattern>,
     curve: Curve,
+    /// Whether curve was explicitly set by user
+    curve_explicit: bool,
+    stack_mode: AreaStackMode,
+    /// Treatment of missing (NaN) y-values
+    gap_mode: GapMode,
     width: f32,
     height: f32,
     x_scale_type: ScaleType,
     y_scale_type: ScaleType,
+    /// Shared x axis
+    x_axis: Option<Axis>,
+    show_legend: bool,
+    legend_position: LegendPosition,
+    /// Set of hidden series indices (0 = primary series, 1+ = additional series)
+    hidden_series: HashSet<usize>,
+    /// Callback when a legend item is clicked (receives series index)
+    on_legend_click: Option<LegendClickCallback>,
+    /// X index currently highlighted by the hover guide
+    hover_index: Option<usize>,
+    /// Callback when the hovered X index changes
+    on_hover: Option<AreaHoverCallback>,
+    layout: ChartLayout,
+}
+
+impl std::fmt::Debug for AreaChart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AreaChart")
+            .field("x_len", &self.x.len())
+            .field("y_len", &self.y.len())
+            .field("series_count", &self.series.len())
+            .field("title", &self.title)
+            .field("stack_mode", &self.stack_mode)
+            .field("hidden_series", &self.hidden_series)
+            .finish()
+    }
 }
 
 impl AreaChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
     /// Set chart title (rendered at top of chart).
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
         self
     }
 
+    /// Set label for the primary series legend entry.
+    ///
+    /// When a label is set, the legend will automatically be shown.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self.show_legend = true;
+        self
+    }
+
     /// Set fill color as 24-bit RGB hex value (format: 0xRRGGBB).
     pub fn color(mut self, hex: u32) -> Self {
         self.color = hex;
@@ -49,9 +139,48 @@ impl AreaChart {
         self
     }
 
+    /// Overlay fill patterns on the areas, cycled over the series.
+    ///
+    /// Patterns keep overlapping or stacked series distinguishable in
+    /// grayscale prints and for colorblind readers.
+    pub fn patterns(mut self, patterns: Vec<FillPattern>) -> Self {
+        self.patterns = patterns;
+        self
+    }
+
     /// Set curve interpolation type.
+    ///
+    /// When not set, [`AreaStackMode::Stream`] uses a smooth basis curve and
+    /// the other modes use linear interpolation.
     pub fn curve(mut self, curve: Curve) -> Self {
         self.curve = curve;
+        self.curve_explicit = true;
+        self
+    }
+
+    /// Set how multiple series are stacked.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::{area, AreaStackMode};
+    /// let x = vec![1.0, 2.0, 3.0];
+    /// let chart = area(&x, &[1.0, 2.0, 3.0])
+    ///     .label("Vinyl")
+    ///     .add_series(&[2.0, 1.0, 2.0], Some("Cassette"), 0xff7f0e)
+    ///     .stack_mode(AreaStackMode::Stream)
+    ///     .build();
+    /// ```
+    pub fn stack_mode(mut self, mode: AreaStackMode) -> Self {
+        self.stack_mode = mode;
+        self
+    }
+
+    /// Set how missing (NaN) y-values are drawn.
+    ///
+    /// By default a NaN breaks the fill of its series. In stacked modes a
+    /// missing value stacks as zero so the series above keep their place.
+    pub fn gaps(mut self, mode: GapMode) -> Self {
+        self.gap_mode = mode;
         self
     }
 
@@ -68,23 +197,183 @@ impl AreaChart {
         self
     }
 
+    /// Share an x axis with other charts: scale type and domain come from
+    /// `axis`, so the area lines up with the charts above or below it.
+    pub fn x_axis(mut self, axis: &Axis) -> Self {
+        self.x_scale_type = axis.scale_type();
+        self.x_axis = Some(axis.clone());
+        self
+    }
+
     /// Set Y-axis scale type (linear or log).
+    ///
+    /// Log scale is only supported with [`AreaStackMode::Overlay`] and
+    /// [`AreaStackMode::Stacked`].
     pub fn y_scale(mut self, scale: ScaleType) -> Self {
         self.y_scale_type = scale;
         self
     }
 
     /// Set baseline Y values (y0). Defaults to 0.0 if not specified.
+    ///
+    /// Only used in [`AreaStackMode::Overlay`]; stacked modes compute their
+    /// own baselines.
     pub fn y0(mut self, y0: &[f64]) -> Self {
         self.y0 = Some(y0.to_vec());
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
+    /// Add an additional data series to the chart.
+    ///
+    /// All series share the same X-axis data.
+    pub fn add_series(mut self, y: &[f64], label: Option<impl Into<String>>, color: u32) -> Self {
+        self.series.push(AreaSeries {
+            y: y.to_vec(),
+            label: label.map(|l| l.into()),
+            color,
+        });
+        // Auto-enable legend if any series has a label
+        if self.series.iter().any(|s| s.label.is_some()) {
+            self.show_legend = true;
+        }
+        self
+    }
+
+    /// Set the legend position (default: `Right`).
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
+
+    /// Set which series are hidden (not rendered).
+    ///
+    /// Series are indexed starting from 0 (primary series), then 1, 2, etc. for
+    /// additional series added via `add_series()`. Hidden series are removed
+    /// from the stack, so the remaining series close the gap.
+    pub fn hidden_series(mut self, indices: &[usize]) -> Self {
+        self.hidden_series = indices.iter().copied().collect();
+        self
+    }
+
+    /// Set callback for when a legend item is clicked.
+    ///
+    /// The callback receives the series index (0 = primary, 1+ = additional series).
+    /// Use this to implement toggle visibility by updating `hidden_series` and re-rendering.
+    pub fn on_legend_click<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, &mut Window, &mut App) + 'static,
+    {
+        self.on_legend_click = Some(Rc::new(callback));
+        self
+    }
+
+    /// Set the X index to highlight with a hover guide and value tooltip.
+    pub fn hover_index(mut self, index: Option<usize>) -> Self {
+        self.hover_index = index;
+        self
+    }
+
+    /// Set callback for when the mouse moves over the plot area.
+    ///
+    /// The callback receives the index of the nearest X value, or `None` when
+    /// the mouse leaves the plot. Feed it back through `hover_index` to show
+    /// the guide and tooltip.
+    pub fn on_hover<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Option<usize>, &mut Window, &mut App) + 'static,
+    {
+        self.on_hover = Some(Rc::new(callback));
+        self
+    }
+
+    /// Color, label and data of a series by index.
+    fn series_info(&self, index: usize) -> (u32, Option<&String>, &[f64]) {
+        if index == 0 {
+            (self.color, self.label.as_ref(), &self.y)
+        } else {
+            let s = &self.series[index - 1];
+            (s.color, s.label.as_ref(), &s.y)
+        }
+    }
+
+    /// Compute lower/upper bounds for every visible series.
+    fn compute_layers(&self) -> Vec<AreaLayer> {
+        let visible: Vec<usize> = (0..=self.series.len())
+            .filter(|i| !self.hidden_series.contains(i))
+            .collect();
+
+        if self.stack_mode == AreaStackMode::Overlay {
+            return visible
+                .into_iter()
+                .map(|index| {
+                    let (_, _, y) = self.series_info(index);
+                    let y0 = match (&self.y0, index) {
+                        (Some(y0), 0) => y0.clone(),
+                        _ => vec![0.0; y.len()],
+                    };
+                    AreaLayer {
+                        series_index: index,
+                        y0,
+                        y1: y.to_vec(),
+                    }
+                })
+                .collect();
+        }
+
+        // Rows are X points, columns are visible series
+        let data: Vec<Vec<f64>> = (0..self.x.len())
+            .map(|j| {
+                visible
+                    .iter()
+                    .map(|&index| {
+                        let y = self.series_info(index).2[j];
+                        if y.is_nan() { 0.0 } else { y }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let (order, offset) = match self.stack_mode {
+            AreaStackMode::Percent => (StackOrder::None, StackOffset::Expand),
+            AreaStackMode::Stream => (StackOrder::InsideOut, StackOffset::Wiggle),
+            _ => (StackOrder::None, StackOffset::None),
+        };
+
+        Stack::new()
+            .keys(visible.iter().map(|i| i.to_string()).collect())
+            .order(order)
+            .offset(offset)
+            .generate(&data)
+            .into_iter()
+            .map(|s| {
+                let series_index = s.key.parse().unwrap_or(0);
+                let y = self.series_info(series_index).2;
+                // Missing values stacked as zero stay undefined for their own series
+                let bound = |j: usize, v: f64| if y[j].is_nan() { f64::NAN } else { v };
+                AreaLayer {
+                    series_index,
+                    y0: s
+                        .values
+                        .iter()
+                        .enumerate()
+                        .map(|(j, v)| bound(j, v[0]))
+                        .collect(),
+                    y1: s
+                        .values
+                        .iter()
+                        .enumerate()
+                        .map(|(j, v)| bound(j, v[1]))
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<impl IntoElement, ChartError> {
         // Validate inputs
         validate_data_array(&self.x, "x")?;
-        validate_data_array(&self.y, "y")?;
+        validate_data_with_gaps(&self.y, "y")?;
         validate_data_length(self.x.len(), self.y.len(), "x", "y")?;
         validate_dimensions(self.width, self.height)?;
 
@@ -93,39 +382,129 @@ impl AreaChart {
             validate_data_length(self.x.len(), y0.len(), "x", "y0")?;
         }
 
+        for series in &self.series {
+            validate_data_with_gaps(&series.y, "series.y")?;
+            validate_data_length(self.x.len(), series.y.len(), "x", "series.y")?;
+        }
+
         // Validate positive values for log scales
         if self.x_scale_type == ScaleType::Log {
             validate_positive(&self.x, "x")?;
         }
         if self.y_scale_type == ScaleType::Log {
+            if matches!(
+                self.stack_mode,
+                AreaStackMode::Percent | AreaStackMode::Stream
+            ) {
+                return Err(ChartError::InvalidData {
+                    field: "y",
+                    reason: "log scale is not supported for percent or stream stacking",
+                });
+            }
             validate_positive(&self.y, "y")?;
             if let Some(ref y0) = self.y0 {
                 validate_positive(y0, "y0")?;
             }
+            for series in &self.series {
+                validate_positive(&series.y, "series.y")?;
+            }
+        }
+
+        if self.gap_mode == GapMode::Interpolate {
+            self.y = interpolate_gaps(&self.x, &self.y);
+            for series in &mut self.series {
+                series.y = interpolate_gaps(&self.x, &series.y);
+            }
         }
 
+        let layers = self.compute_layers();
+
         // Calculate plot area (reserve space for title if present)
         let title_height = if self.title.is_some() {
             TITLE_AREA_HEIGHT
         } else {
             0.0
         };
-        let plot_height = self.height - title_height;
+
+        // Collect legend items: (series_index, color, label)
+        let mut legend_items: Vec<(usize, u32, String)> = Vec::new();
+        if self.show_legend && self.legend_position != LegendPosition::Hidden {
+            for index in 0..=self.series.len() {
+                if let (color, Some(label), _) = self.series_info(index) {
+                    legend_items.push((index, color, label.clone()));
+                }
+            }
+        }
+
+        // Estimate ~7 pixels per character for text_xs font
+        let max_label_len = legend_items
+            .iter()
+            .map(|(_, _, label)| label.len())
+            .max()
+            .unwrap_or(0);
+        let legend_width = 16.0 + 8.0 + (max_label_len as f32) * 7.0 + 16.0;
+        let legend_height = 32.0;
+        let (plot_width, plot_height) = match (legend_items.is_empty(), self.legend_position) {
+            (false, LegendPosition::Left | LegendPosition::Right) => {
+                (self.width - legend_width, self.height - title_height)
+            }
+            (false, LegendPosition::Top | LegendPosition::Bottom) => {
+                (self.width, self.height - title_height - legend_height)
+            }
+            _ => (self.width, self.height - title_height),
+        };
+        validate_plot_area(plot_width.into(), plot_height.into())?;
 
         // Calculate domains with padding
         let (x_min, x_max) = extent_padded(&self.x, DEFAULT_PADDING_FRACTION);
+        let (x_min, x_max) = match &self.x_axis {
+            Some(axis) => axis.resolve((x_min, x_max)),
+            None => (x_min, x_max),
+        };
 
-        // Calculate Y domain considering y and y0
-        let y_iter = self.y.iter();
-        let (y_min, y_max) = if let Some(ref y0) = self.y0 {
-            let all_y: Vec<f64> = y_iter.chain(y0.iter()).copied().collect();
-            extent_padded(&all_y, DEFAULT_PADDING_FRACTION)
+        // Calculate Y domain over all stacked bounds
+        let (y_min, y_max) = if self.stack_mode == AreaStackMode::Percent {
+            (0.0, 1.0)
         } else {
-            let mut all_y: Vec<f64> = y_iter.copied().collect();
-            all_y.push(0.0); // Include baseline 0
+            let mut all_y: Vec<f64> = layers
+                .iter()
+                .flat_map(|l| l.y0.iter().chain(l.y1.iter()))
+                .copied()
+                .collect();
+            if all_y.is_empty() {
+                all_y.extend_from_slice(&self.y);
+            }
+            if self.y0.is_none() && self.stack_mode != AreaStackMode::Stream {
+                all_y.push(0.0); // Include baseline 0
+            }
             extent_padded(&all_y, DEFAULT_PADDING_FRACTION)
         };
 
+        let x_scale: Arc<dyn Scale<f64, f64>> = match self.x_scale_type {
+            ScaleType::Linear => Arc::new(
+                LinearScale::new()
+                    .domain(x_min, x_max)
+                    .range(0.0, plot_width as f64),
+            ),
+            ScaleType::Log => Arc::new(
+                LogScale::new()
+                    .domain(x_min.max(1e-10), x_max)
+                    .range(0.0, plot_width as f64),
+            ),
+        };
+        let y_scale: Arc<dyn Scale<f64, f64>> = match self.y_scale_type {
+            ScaleType::Linear => Arc::new(
+                LinearScale::new()
+                    .domain(y_min, y_max)
+                    .range(plot_height as f64, 0.0),
+            ),
+            ScaleType::Log => Arc::new(
+                LogScale::new()
+                    .domain(y_min.max(1e-10), y_max)
+                    .range(plot_height as f64, 0.0),
+            ),
+        };
+
         // Prepare data for rendering
         struct AreaDatum {
             x: f64,
@@ -133,39 +512,53 @@ impl AreaChart {
             y1: f64,
         }
 
-        let data: Vec<AreaDatum> = match &self.y0 {
-            Some(y0) => self
-                .x
-                .iter()
-                .zip(self.y.iter())
-                .zip(y0.iter())
-                .map(|((&x, &y1), &y0)| AreaDatum { x, y0, y1 })
-                .collect(),
-            None => self
-                .x
-                .iter()
-                .zip(self.y.iter())
-                .map(|(&x, &y1)| AreaDatum { x, y0: 0.0, y1 })
-                .collect(),
-        };
-
-        let color = D3Color::from_hex(self.color);
-        let fill_color = color.to_rgba();
         let opacity = self.opacity;
-        let curve = self.curve;
-
-        // Create render function
-        let render_element = move |x_scale: Arc<dyn Scale<f64, f64>>,
-                                   y_scale: Arc<dyn Scale<f64, f64>>| {
-            let x_scale_prepaint = x_scale.clone();
-            let y_scale_prepaint = y_scale.clone();
+        let curve = if self.stack_mode == AreaStackMode::Stream && !self.curve_explicit {
+            Curve::Basis
+        } else {
+            self.curve
+        };
 
-            canvas(
-                move |bounds, _, _| (x_scale_prepaint.clone(), y_scale_prepaint.clone(), bounds),
-                move |_, (x_scale, y_scale, bounds), window, _| {
-                    let x_scale_x = x_scale.clone();
-                    let y_scale_y0 = y_scale.clone();
-                    let y_scale_y1 = y_scale.clone();
+        // Each layer is split into runs of defined points, one polygon per run
+        let gap_mode = self.gap_mode;
+        let fills: Vec<(Vec<AreaDatum>, Rgba, Option<FillPattern>)> = layers
+            .iter()
+            .flat_map(|layer| {
+                let (color, _, _) = self.series_info(layer.series_index);
+                let color = D3Color::from_hex(color).to_rgba();
+                let pattern = (!self.patterns.is_empty())
+                    .then(|| self.patterns[layer.series_index % self.patterns.len()]);
+                let mut runs: Vec<Vec<AreaDatum>> = vec![Vec::new()];
+                for (&x, (&y0, &y1)) in self.x.iter().zip(layer.y0.iter().zip(layer.y1.iter())) {
+                    if y0.is_finite() && y1.is_finite() {
+                        runs.last_mut().unwrap().push(AreaDatum { x, y0, y1 });
+                    } else if gap_mode == GapMode::Break && !runs.last().unwrap().is_empty() {
+                        runs.push(Vec::new());
+                    }
+                }
+                runs.into_iter()
+                    .filter(|run| run.len() > 1)
+                    .map(move |run| (run, color, pattern))
+            })
+            .collect();
+
+        let plot_bounds = PlotBoundsCapture::new();
+        let plot_bounds_paint = plot_bounds.clone();
+
+        let x_scale_paint = x_scale.clone();
+        let y_scale_paint = y_scale.clone();
+        let area_element = canvas(
+            move |bounds, _, _| bounds,
+            move |_, bounds, window, _| {
+                plot_bounds_paint.record(bounds);
+
+                let origin_x: f32 = bounds.origin.x.into();
+                let origin_y: f32 = bounds.origin.y.into();
+
+                for (data, fill_color, pattern) in &fills {
+                    let x_scale_x = x_scale_paint.clone();
+                    let y_scale_y0 = y_scale_paint.clone();
+                    let y_scale_y1 = y_scale_paint.clone();
 
                     let area = Area::new()
                         .x(move |d: &AreaDatum| x_scale_x.scale(d.x))
@@ -173,29 +566,25 @@ impl AreaChart {
                         .y1(move |d: &AreaDatum| y_scale_y1.scale(d.y1))
                         .curve(curve);
 
-                    let path = area.generate(&data);
+                    let path = area.generate(data);
                     let points = path.flatten(0.5);
 
-                    let origin_x: f32 = bounds.origin.x.into();
-                    let origin_y: f32 = bounds.origin.y.into();
-
                     if points.is_empty() {
-                        return;
+                        continue;
                     }
 
+                    let polygon: Vec<(f32, f32)> = points
+                        .iter()
+                        .map(|p| (origin_x + p.x as f32, origin_y + p.y as f32))
+                        .collect();
+
                     let mut path_builder = PathBuilder::fill();
 
-                    let first = points[0];
-                    path_builder.move_to(gpui::point(
-                        px(origin_x + first.x as f32),
-                        px(origin_y + first.y as f32),
-                    ));
-
-                    for p in points.iter().skip(1) {
-                        path_builder.line_to(gpui::point(
-                            px(origin_x + p.x as f32),
-                            px(origin_y + p.y as f32),
-                        ));
+                    let (first_x, first_y) = polygon[0];
+                    path_builder.move_to(gpui::point(px(first_x), px(first_y)));
+
+                    for &(x, y) in polygon.iter().skip(1) {
+                        path_builder.line_to(gpui::point(px(x), px(y)));
                     }
 
                     path_builder.close();
@@ -204,56 +593,119 @@ impl AreaChart {
                         window.paint_path(
                             gpui_path,
                             Rgba {
-                                r: fill_color.r,
-                                g: fill_color.g,
-                                b: fill_color.b,
                                 a: fill_color.a * opacity,
+                                ..*fill_color
                             },
                         );
                     }
-                },
-            )
-        };
 
-        // Build the element based on scale types
-        let area_element: AnyElement = match (self.x_scale_type, self.y_scale_type) {
-            (ScaleType::Linear, ScaleType::Linear) => {
-                let x_scale = LinearScale::new()
-                    .domain(x_min, x_max)
-                    .range(0.0, self.width as f64);
-                let y_scale = LinearScale::new()
-                    .domain(y_min, y_max)
-                    .range(plot_height as f64, 0.0);
-                render_element(Arc::new(x_scale), Arc::new(y_scale)).into_any_element()
-            }
-            (ScaleType::Log, ScaleType::Linear) => {
-                let x_scale = LogScale::new()
-                    .domain(x_min.max(1e-10), x_max)
-                    .range(0.0, self.width as f64);
-                let y_scale = LinearScale::new()
-                    .domain(y_min, y_max)
-                    .range(plot_height as f64, 0.0);
-                render_element(Arc::new(x_scale), Arc::new(y_scale)).into_any_element()
-            }
-            (ScaleType::Linear, ScaleType::Log) => {
-                let x_scale = LinearScale::new()
-                    .domain(x_min, x_max)
-                    .range(0.0, self.width as f64);
-                let y_scale = LogScale::new()
-                    .domain(y_min.max(1e-10), y_max)
-                    .range(plot_height as f64, 0.0);
-                render_element(Arc::new(x_scale), Arc::new(y_scale)).into_any_element()
-            }
-            (ScaleType::Log, ScaleType::Log) => {
-                let x_scale = LogScale::new()
-                    .domain(x_min.max(1e-10), x_max)
-                    .range(0.0, self.width as f64);
-                let y_scale = LogScale::new()
-                    .domain(y_min.max(1e-10), y_max)
-                    .range(plot_height as f64, 0.0);
-                render_element(Arc::new(x_scale), Arc::new(y_scale)).into_any_element()
+                    if let Some(pattern) = pattern {
+                        paint_pattern(window, &[&polygon], pattern);
+                    }
+                }
+            },
+        )
+        .size_full()
+        .absolute()
+        .inset_0();
+
+        let mut plot_area = div()
+            .id("area-plot")
+            .w(px(plot_width))
+            .h(px(plot_height))
+            .relative()
+            .child(area_element);
+
+        // Hover guide and tooltip
+        if let Some(index) = self.hover_index.filter(|&i| i < self.x.len()) {
+            let guide_x = x_scale.scale(self.x[index]) as f32;
+            let mut tooltip = div()
+                .absolute()
+                .top(px(4.0))
+                .px_2()
+                .py_1()
+                .flex()
+                .flex_col()
+                .bg(hsla(0.0, 0.0, 0.2, 0.85))
+                .rounded_md()
+                .text_xs()
+                .text_color(hsla(0.0, 0.0, 1.0, 1.0))
+                .child(format!("x: {:.2}", self.x[index]));
+            // Tooltip flips to the left of the guide past the middle of the plot
+            tooltip = if guide_x > plot_width / 2.0 {
+                tooltip.right(px(plot_width - guide_x + 8.0))
+            } else {
+                tooltip.left(px(guide_x + 8.0))
+            };
+            for layer in layers.iter().rev() {
+                let (color, label, y) = self.series_info(layer.series_index);
+                if y[index].is_nan() {
+                    continue;
+                }
+                let name = label
+                    .cloned()
+                    .unwrap_or_else(|| format!("Series {}", layer.series_index));
+                let value = if self.stack_mode == AreaStackMode::Percent {
+                    format!(
+                        "{}: {:.2} ({:.1}%)",
+                        name,
+                        y[index],
+                        (layer.y1[index] - layer.y0[index]) * 100.0
+                    )
+                } else {
+                    format!("{}: {:.2}", name, y[index])
+                };
+                tooltip = tooltip.child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_1()
+                        .child(div().w(px(8.0)).h(px(8.0)).bg(rgb(color)))
+                        .child(value),
+                );
             }
-        };
+
+            plot_area = plot_area
+                .child(
+                    div()
+                        .absolute()
+                        .left(px(guide_x))
+                        .top_0()
+                        .w_px()
+                        .h(px(plot_height))
+                        .bg(hsla(0.0, 0.0, 0.3, 0.6)),
+                )
+                .child(tooltip);
+        }
+
+        if let Some(on_hover) = self.on_hover.clone() {
+            let x_values = self.x.clone();
+            let x_scale_hover = x_scale.clone();
+            let on_leave = on_hover.clone();
+            let current = self.hover_index;
+            plot_area = plot_area
+                .on_mouse_move(move |event, window, cx| {
+                    let Some((mouse_x, _)) = plot_bounds.to_local(event.position) else {
+                        return;
+                    };
+                    let nearest = x_values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &x)| (i, (x_scale_hover.scale(x) - mouse_x).abs()))
+                        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                        .map(|(i, _)| i);
+                    let inside = bounds.contains(&event.position);
+                    let index = if inside { nearest } else { None };
+                    if index != current {
+                        on_hover(index, window, cx);
+                    }
+                })
+                .on_hover(move |hovered, window, cx| {
+                    if !*hovered {
+                        on_leave(None, window, cx);
+                    }
+                });
+        }
 
         // Build container with optional title
         let mut container = div()
@@ -278,16 +730,81 @@ impl AreaChart {
             );
         }
 
-        // Add plot area
-        container = container.child(
-            div()
-                .w(px(self.width))
-                .h(px(plot_height))
-                .relative()
-                .child(area_element),
+        if legend_items.is_empty() {
+            // No legend, just add plot area
+            return Ok(container.child(plot_area));
+        }
+
+        let hidden_series = self.hidden_series.clone();
+        let on_click = self.on_legend_click.clone();
+        let build_legend_item = move |series_idx: usize, color: u32, label: String| {
+            let is_hidden = hidden_series.contains(&series_idx);
+
+            let mut item = div()
+                .id(ElementId::NamedInteger(
+                    "area-legend-item".into(),
+                    series_idx as u64,
+                ))
+                .flex()
+                .items_center()
+                .gap_2()
+                .rounded_sm()
+                .px_1()
+                .cursor_pointer()
+                .hover(|s| s.bg(gpui::rgba(0x00000010)));
+
+            // Color swatch - grayed out if hidden
+            let swatch_color = if is_hidden {
+                gpui::rgba(0xccccccff)
+            } else {
+                rgb(color)
+            };
+            item = item.child(div().w(px(12.0)).h(px(12.0)).bg(swatch_color));
+
+            let label_color = if is_hidden {
+                gpui::rgba(0x00000040)
+            } else {
+                gpui::rgba(0x00000099)
+            };
+            item = item.child(div().text_xs().text_color(label_color).child(label));
+
+            if let Some(cb) = on_click.clone() {
+                item = item.on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                    cb(series_idx, window, cx);
+                });
+            }
+
+            item
+        };
+
+        let vertical = matches!(
+            self.legend_position,
+            LegendPosition::Left | LegendPosition::Right
         );
+        let mut legend = if vertical {
+            div().w(px(legend_width)).flex().flex_col().gap_2().p_2()
+        } else {
+            div()
+                .h(px(legend_height))
+                .flex()
+                .flex_row()
+                .flex_wrap()
+                .gap_4()
+                .p_2()
+                .justify_center()
+        };
+        for (idx, color, label) in legend_items {
+            legend = legend.child(build_legend_item(idx, color, label));
+        }
+
+        let content = match self.legend_position {
+            LegendPosition::Left => div().flex().flex_row().child(legend).child(plot_area),
+            LegendPosition::Top => div().flex().flex_col().child(legend).child(plot_area),
+            LegendPosition::Bottom => div().flex().flex_col().child(plot_area).child(legend),
+            _ => div().flex().flex_row().child(plot_area).child(legend),
+        };
 
-        Ok(container)
+        Ok(container.child(content))
     }
 }
 
@@ -309,17 +826,155 @@ impl AreaChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn area(x: &[f64], y: &[f64]) -> AreaChart {
+    let defaults = ChartDefaults::current();
     AreaChart {
         x: x.to_vec(),
         y: y.to_vec(),
         y0: None,
+        label: None,
+        series: Vec::new(),
         title: None,
         color: DEFAULT_COLOR,
         opacity: 0.6,
+        patterns: Vec::new(),
         curve: Curve::Linear,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        curve_explicit: false,
+        stack_mode: AreaStackMode::Overlay,
+        gap_mode: GapMode::default(),
+        width: defaults.width,
+        height: defaults.height,
         x_scale_type: ScaleType::Linear,
         y_scale_type: ScaleType::Linear,
+        x_axis: None,
+        show_legend: false,
+        legend_position: defaults.legend_position.unwrap_or_default(),
+        hidden_series: HashSet::new(),
+        on_legend_click: None,
+        hover_index: None,
+        on_hover: None,
+        layout: ChartLayout::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revenue() -> AreaChart {
+        area(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0])
+            .label("Vinyl")
+            .add_series(&[3.0, 2.0, 1.0], Some("CD"), 0xff7f0e)
+            .add_series(&[0.0, 1.0, 4.0], Some("Streaming"), 0x2ca02c)
+    }
+
+    #[test]
+    fn test_area_overlay_layers_use_zero_baseline() {
+        let layers = revenue().compute_layers();
+        assert_eq!(layers.len(), 3);
+        assert!(layers.iter().all(|l| l.y0.iter().all(|&v| v == 0.0)));
+        assert_eq!(layers[1].y1, vec![3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_area_stacked_layers() {
+        let layers = revenue()
+            .stack_mode(AreaStackMode::Stacked)
+            .compute_layers();
+        assert_eq!(layers[0].y1, vec![1.0, 2.0, 3.0]);
+        assert_eq!(layers[1].y0, vec![1.0, 2.0, 3.0]);
+        assert_eq!(layers[2].y1, vec![4.0, 5.0, 8.0]);
+    }
+
+    #[test]
+    fn test_area_percent_layers_sum_to_one() {
+        let layers = revenue()
+            .stack_mode(AreaStackMode::Percent)
+            .compute_layers();
+        for j in 0..3 {
+            let top = layers.iter().map(|l| l.y1[j]).fold(f64::MIN, f64::max);
+            assert!((top - 1.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_area_stream_keeps_series_indices() {
+        let layers = revenue().stack_mode(AreaStackMode::Stream).compute_layers();
+        let mut indices: Vec<usize> = layers.iter().map(|l| l.series_index).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_area_hidden_series_removed_from_stack() {
+        let layers = revenue()
+            .stack_mode(AreaStackMode::Stacked)
+            .hidden_series(&[1])
+            .compute_layers();
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[1].series_index, 2);
+        assert_eq!(layers[1].y0, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_area_stacked_missing_value_stacks_as_zero() {
+        let layers = revenue()
+            .add_series(&[1.0, f64::NAN, 1.0], Some("Tape"), 0xd62728)
+            .stack_mode(AreaStackMode::Stacked)
+            .compute_layers();
+        assert_eq!(layers[2].y1, vec![4.0, 5.0, 8.0]);
+        assert_eq!(layers[3].y1[0], 5.0);
+        assert!(layers[3].y0[1].is_nan() && layers[3].y1[1].is_nan());
+    }
+
+    #[test]
+    fn test_area_nan_in_y_is_a_gap() {
+        for mode in [GapMode::Break, GapMode::Connect, GapMode::Interpolate] {
+            let result = area(&[1.0, 2.0, 3.0], &[1.0, f64::NAN, 3.0])
+                .gaps(mode)
+                .build();
+            assert!(result.is_ok());
+        }
+        let result = area(&[1.0, 2.0], &[f64::NAN, f64::NAN]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData { field: "y", .. })
+        ));
+    }
+
+    #[test]
+    fn test_area_series_length_mismatch() {
+        let result = area(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0])
+            .add_series(&[1.0, 2.0], None::<String>, 0xff7f0e)
+            .build();
+        assert!(matches!(
+            result,
+            Err(ChartError::DataLengthMismatch {
+                y_field: "series.y",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_area_stream_rejects_log_y() {
+        let result = revenue()
+            .stack_mode(AreaStackMode::Stream)
+            .y_scale(ScaleType::Log)
+            .build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData { field: "y", .. })
+        ));
+    }
+
+    #[test]
+    fn test_area_stacked_build_with_legend_and_hover() {
+        let result = revenue()
+            .stack_mode(AreaStackMode::Stacked)
+            .legend_position(LegendPosition::Bottom)
+            .hover_index(Some(1))
+            .on_hover(|_, _, _| {})
+            .build();
+        assert!(result.is_ok());
     }
 }
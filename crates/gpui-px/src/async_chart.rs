@@ -0,0 +1,114 @@
+//! Charts whose heavy preprocessing runs in the background.
+//!
+//! `build_async()` on the contour, isoline, box plot, violin, heatmap and
+//! line builders validates the data right away, then generates the bands,
+//! contour lines, box statistics or densities, resamples the heatmap grid
+//! or simplifies the lines on the background executor. It returns an
+//! [`AsyncChart`] view showing a placeholder with a spinner until the data
+//! is ready, then the chart itself, so the UI thread stays responsive for
+//! large datasets:
+//!
+//! ```rust,ignore
+//! // In a view constructor
+//! let chart = contour(&z, 2000, 2000)
+//!     .thresholds(levels)
+//!     .size(800.0, 600.0)
+//!     .build_async(cx)?;
+//!
+//! // In render
+//! div().child(self.chart.clone())
+//! ```
+//!
+//! Errors that depend on the final layout (e.g. a plot area too small for
+//! the margins) are shown in place with [`chart_error`] once the data is
+//! ready.
+
+use crate::error::ChartError;
+use crate::fallback::chart_error;
+use crate::layout::ChartLayout;
+use crate::theme::ChartTheme;
+use gpui::prelude::*;
+use gpui::{AnyElement, App, AsyncApp, Context, Entity, WeakEntity, Window, div, px};
+use gpui_ui_kit::{Spinner, SpinnerSize};
+
+/// Builds the chart element from the prepared data.
+type Finish = Box<dyn Fn() -> Result<AnyElement, ChartError>>;
+
+/// Chart view that shows a placeholder until its data is prepared.
+///
+/// Created by the `build_async()` methods of the chart builders; render it
+/// as a child like any other view.
+pub struct AsyncChart {
+    /// Outer size of the chart, `None` when it fills its parent
+    size: Option<(f32, f32)>,
+    finish: Option<Finish>,
+}
+
+impl AsyncChart {
+    /// Run `prepare` on the background executor, then render `finish` with
+    /// its result.
+    ///
+    /// `finish` runs on the UI thread at every render of the ready chart,
+    /// so it should only do the cheap part of the build.
+    pub(crate) fn spawn<P, Prepare, FinishFn>(
+        layout: &ChartLayout,
+        size: (f32, f32),
+        prepare: Prepare,
+        finish: FinishFn,
+        cx: &mut App,
+    ) -> Entity<Self>
+    where
+        P: Send + 'static,
+        Prepare: FnOnce() -> P + Send + 'static,
+        FinishFn: Fn(&P) -> Result<AnyElement, ChartError> + 'static,
+    {
+        let size = (!layout.responsive).then(|| layout.fixed_size(size.0, size.1));
+        cx.new(|cx: &mut Context<Self>| {
+            let task = cx.background_executor().spawn(async move { prepare() });
+            cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                let prepared = task.await;
+                this.update(cx, |chart, cx| {
+                    chart.finish = Some(Box::new(move || finish(&prepared)));
+                    cx.notify();
+                })
+            })
+            .detach();
+            Self { size, finish: None }
+        })
+    }
+
+    /// Whether the data is prepared and the chart itself is shown.
+    pub fn is_ready(&self) -> bool {
+        self.finish.is_some()
+    }
+}
+
+impl Render for AsyncChart {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let (width, height) = self.size.unwrap_or((0.0, 0.0));
+        match &self.finish {
+            Some(finish) => finish().unwrap_or_else(|error| chart_error(&error, width, height)),
+            None => chart_placeholder(self.size),
+        }
+    }
+}
+
+/// Skeleton of the chart's size with a spinner in the middle.
+fn chart_placeholder(size: Option<(f32, f32)>) -> AnyElement {
+    let theme = ChartTheme::current();
+    let placeholder = match size {
+        Some((width, height)) => div().w(px(width)).h(px(height)),
+        None => div().size_full(),
+    };
+    placeholder
+        .flex()
+        .items_center()
+        .justify_center()
+        .bg(theme.plot_background)
+        .child(
+            Spinner::new()
+                .size(SpinnerSize::Md)
+                .color(theme.axis_label_color),
+        )
+        .into_any_element()
+}
@@ -0,0 +1,237 @@
+//! X axes shared across charts.
+//!
+//! Charts compute their x domain from their own data, so two charts of
+//! related measurements rarely line up. An [`Axis`] is created once and
+//! passed to several charts with `.x_axis(&axis)`: they all use its scale
+//! type, tick format, title and domain.
+//!
+//! ```rust
+//! use gpui_px::{Axis, line};
+//!
+//! let freq = [20.0, 200.0, 2000.0, 20000.0];
+//! let axis = Axis::log().label("Frequency (Hz)").lock_domain();
+//!
+//! let spl = line(&freq, &[85.0, 86.0, 84.0, 80.0]).x_axis(&axis);
+//! let phase = line(&freq, &[0.0, -20.0, -90.0, -170.0]).x_axis(&axis);
+//! ```
+//!
+//! The domain comes from, in order:
+//! - a fixed domain set with [`Axis::domain`] or [`Axis::set_domain`]
+//! - on a locked axis, the domain of the first chart built with it, kept
+//!   when the data updates
+//! - otherwise the chart's own data (or `x_range`)
+
+use crate::ScaleType;
+use d3rs::axis::AxisConfig;
+use d3rs::time::TimeScale;
+use d3rs::time::format::format_tick;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Domain state shared by all clones of an [`Axis`].
+#[derive(Debug, Clone, Copy, Default)]
+struct AxisDomain {
+    fixed: Option<[f64; 2]>,
+    locked: bool,
+    current: Option<[f64; 2]>,
+}
+
+/// X axis shared by several charts.
+///
+/// Clones share the same domain: setting or locking it through one clone
+/// applies to every chart built with the others.
+#[derive(Debug, Clone, Default)]
+pub struct Axis {
+    scale: ScaleType,
+    format: Option<fn(f64) -> String>,
+    label: Option<String>,
+    domain: Rc<Cell<AxisDomain>>,
+}
+
+impl Axis {
+    /// Create an axis with the given scale type.
+    pub fn new(scale: ScaleType) -> Self {
+        Self {
+            scale,
+            ..Self::default()
+        }
+    }
+
+    /// Linear axis.
+    pub fn linear() -> Self {
+        Self::new(ScaleType::Linear)
+    }
+
+    /// Logarithmic axis (base 10).
+    pub fn log() -> Self {
+        Self::new(ScaleType::Log)
+    }
+
+    /// Fix the domain of every chart using the axis.
+    pub fn domain(self, min: f64, max: f64) -> Self {
+        self.set_domain(min, max);
+        self
+    }
+
+    /// Set the tick label formatter.
+    pub fn format(mut self, format: fn(f64) -> String) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Set the axis title.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Keep the domain once it has been computed, instead of recalculating
+    /// it from the data on every build (e.g. for streaming data).
+    pub fn lock_domain(self) -> Self {
+        self.set_locked(true);
+        self
+    }
+
+    /// Fix the domain of every chart using the axis, e.g. after a zoom.
+    pub fn set_domain(&self, min: f64, max: f64) {
+        self.update(|domain| {
+            domain.fixed = Some([min, max]);
+            domain.current = Some([min, max]);
+        });
+    }
+
+    /// Remove the fixed domain and forget the locked one, so the next build
+    /// computes the domain from the data again.
+    pub fn reset_domain(&self) {
+        self.update(|domain| {
+            domain.fixed = None;
+            domain.current = None;
+        });
+    }
+
+    /// Lock or unlock the domain.
+    pub fn set_locked(&self, locked: bool) {
+        self.update(|domain| domain.locked = locked);
+    }
+
+    /// Whether the domain is locked.
+    pub fn is_locked(&self) -> bool {
+        self.domain.get().locked
+    }
+
+    /// Domain used by the last chart built with the axis.
+    pub fn current_domain(&self) -> Option<[f64; 2]> {
+        self.domain.get().current
+    }
+
+    /// Scale type of the axis.
+    pub fn scale_type(&self) -> ScaleType {
+        self.scale
+    }
+
+    /// Domain that does not depend on the chart data: the fixed one, or the
+    /// locked one once computed.
+    pub(crate) fn pinned_domain(&self) -> Option<[f64; 2]> {
+        let domain = self.domain.get();
+        domain.fixed.or(domain.current.filter(|_| domain.locked))
+    }
+
+    /// Domain of a chart whose own data gives `auto`.
+    pub(crate) fn resolve(&self, auto: (f64, f64)) -> (f64, f64) {
+        if let Some([min, max]) = self.pinned_domain() {
+            return (min, max);
+        }
+        self.update(|domain| domain.current = Some([auto.0, auto.1]));
+        auto
+    }
+
+    /// Apply the tick format and title to an axis configuration.
+    pub(crate) fn configure(&self, mut config: AxisConfig) -> AxisConfig {
+        if let Some(format) = self.format {
+            config = config.with_formatter(format);
+        }
+        if let Some(label) = &self.label {
+            config = config.with_title(label.clone());
+        }
+        config
+    }
+
+    fn update(&self, f: impl FnOnce(&mut AxisDomain)) {
+        let mut domain = self.domain.get();
+        f(&mut domain);
+        self.domain.set(domain);
+    }
+}
+
+/// Width of the plot per tick on a time axis, in pixels.
+const TIME_TICK_SPACING: f64 = 80.0;
+
+/// Ticks of a time axis whose values are Unix timestamps in seconds.
+///
+/// The step depends on the visible span: every few minutes or hours when
+/// zoomed in, days, months or years when zoomed out.
+pub(crate) fn time_ticks(domain: (f64, f64), width: f64) -> Vec<f64> {
+    let count = (width / TIME_TICK_SPACING).floor().max(2.0) as usize;
+    TimeScale::new()
+        .domain(domain.0.floor() as i64, domain.1.ceil() as i64)
+        .time_ticks(count)
+        .into_iter()
+        .map(|tick| tick as f64)
+        .collect()
+}
+
+/// Tick label of a time axis (e.g., "06:00", "Mar 05", "March", "2024").
+pub(crate) fn format_time_tick(value: f64) -> String {
+    format_tick(value.round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_follows_data_when_unlocked() {
+        let axis = Axis::linear();
+        assert_eq!(axis.resolve((0.0, 10.0)), (0.0, 10.0));
+        assert_eq!(axis.resolve((0.0, 20.0)), (0.0, 20.0));
+        assert_eq!(axis.current_domain(), Some([0.0, 20.0]));
+    }
+
+    #[test]
+    fn test_axis_lock_domain_keeps_first_domain() {
+        let axis = Axis::linear().lock_domain();
+        let shared = axis.clone();
+        assert_eq!(axis.resolve((0.0, 10.0)), (0.0, 10.0));
+        assert_eq!(shared.resolve((5.0, 50.0)), (0.0, 10.0));
+
+        axis.reset_domain();
+        assert_eq!(shared.resolve((5.0, 50.0)), (5.0, 50.0));
+        shared.set_locked(false);
+        assert_eq!(axis.resolve((1.0, 2.0)), (1.0, 2.0));
+    }
+
+    #[test]
+    fn test_axis_fixed_domain_wins() {
+        let axis = Axis::log().domain(20.0, 20000.0);
+        assert_eq!(axis.resolve((1.0, 10.0)), (20.0, 20000.0));
+        axis.set_domain(100.0, 1000.0);
+        assert_eq!(axis.clone().resolve((1.0, 10.0)), (100.0, 1000.0));
+        assert_eq!(axis.scale_type(), ScaleType::Log);
+    }
+
+    #[test]
+    fn test_time_ticks_follow_zoom() {
+        let start = 1709596800.0; // Mar 5, 2024 00:00 UTC
+
+        // A day on 800 px: every 3 hours
+        let ticks = time_ticks((start, start + 86400.0), 800.0);
+        assert_eq!(ticks.len(), 9);
+        assert_eq!(format_time_tick(ticks[1]), "03:00");
+
+        // A year: months, with the year at its start
+        let ticks = time_ticks((start, start + 365.0 * 86400.0), 800.0);
+        let labels: Vec<String> = ticks.iter().map(|&t| format_time_tick(t)).collect();
+        assert_eq!(labels[0], "April");
+        assert!(labels.contains(&"2025".to_string()));
+    }
+}
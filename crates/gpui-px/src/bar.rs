@@ -1,23 +1,38 @@
 //! Bar chart - Plotly Express style API.
 
+use crate::category_order::{CategoryOrder, SortOrder, arrange};
 use crate::error::ChartError;
+use crate::geometry::{AxisGeometry, ChartGeometry, RectGeometry};
+use crate::layout::{ChartLayout, chart_layout_methods};
 use crate::line::LegendPosition;
+use crate::theme::ChartTheme;
 use crate::{
-    DEFAULT_COLOR, DEFAULT_HEIGHT, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE,
-    DEFAULT_WIDTH, ScaleType, TITLE_AREA_HEIGHT, extent_padded, validate_data_array,
-    validate_data_length, validate_dimensions, validate_positive,
+    ChartDefaults, DEFAULT_COLOR, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE, ScaleType,
+    TITLE_AREA_HEIGHT, extent_padded, validate_data_array, validate_data_length,
+    validate_dimensions, validate_plot_area, validate_positive,
 };
-use d3rs::axis::{AxisConfig, DefaultAxisTheme, render_axis};
+use d3rs::axis::{AxisConfig, render_axis};
 use d3rs::color::D3Color;
 use d3rs::grid::{GridConfig, render_grid};
-use d3rs::scale::{LinearScale, LogScale};
+use d3rs::scale::{LinearScale, LogScale, Scale};
 use d3rs::shape::{
-    BarConfig, BarDatum, GroupedBarConfig, GroupedBarDatum, GroupedBarMeta, analyze_grouped_data,
-    render_bars, render_grouped_bars,
+    BarConfig, BarDatum, BarRect, FillPattern, GroupedBarConfig, GroupedBarDatum, GroupedBarMeta,
+    analyze_grouped_data, bar_rects, grouped_bar_rects, render_bars, render_grouped_bars,
 };
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
 use gpui::{AnyElement, IntoElement, Rgba, div, hsla, px, rgb};
+use std::collections::HashMap;
+
+/// Widest cap of an error whisker, in pixels
+const WHISKER_CAP_WIDTH: f32 = 10.0;
+
+/// Distance between stacked significance brackets, as a share of the data
+/// span
+const BRACKET_STEP: f64 = 0.08;
+
+/// Length of the ticks at the ends of a significance bracket, in pixels
+const BRACKET_TICK: f32 = 6.0;
 
 /// A single series in a bar chart (for grouped/stacked bars)
 #[derive(Debug, Clone)]
@@ -29,6 +44,35 @@ struct BarSeries {
     opacity: f32,
 }
 
+/// Errors of the primary series, below and above each value
+#[derive(Debug, Clone)]
+struct BarErrors {
+    minus: Vec<f64>,
+    plus: Vec<f64>,
+}
+
+/// Significance bracket between two categories
+#[derive(Debug, Clone)]
+struct SignificanceBracket {
+    from: String,
+    to: String,
+    label: String,
+}
+
+/// Significance bracket placed above the bars it spans.
+#[derive(Debug, Clone, PartialEq)]
+struct PlacedBracket {
+    /// Index of the first category spanned
+    from: usize,
+    /// Index of the last category spanned
+    to: usize,
+    /// Height of the bracket line, in data units
+    y: f64,
+    /// Height of the top of its label, in data units
+    label_top: f64,
+    label: String,
+}
+
 /// Theme for bar chart styling
 #[derive(Debug, Clone)]
 pub struct BarTheme {
@@ -55,6 +99,30 @@ impl Default for BarTheme {
     }
 }
 
+impl From<&ChartTheme> for BarTheme {
+    fn from(theme: &ChartTheme) -> Self {
+        Self {
+            plot_background: theme.plot_background,
+            title_color: theme.title_color,
+            legend_text_color: theme.legend_text_color,
+        }
+    }
+}
+
+/// Plot area, legend and y domain of a bar chart.
+struct BarFrame {
+    title_height: f32,
+    legend_gap: f32,
+    has_legend_items: bool,
+    legend_position: LegendPosition,
+    legend_width: f32,
+    legend_height: f32,
+    plot_width: f64,
+    plot_height: f64,
+    y_domain: (f64, f64),
+    brackets: Vec<PlacedBracket>,
+}
+
 /// Bar chart builder.
 #[derive(Debug, Clone)]
 pub struct BarChart {
@@ -78,10 +146,21 @@ pub struct BarChart {
     legend_position: LegendPosition,
     legend_position_explicit: bool,
     graph_ratio: f32,
+    // Category ordering
+    sort: SortOrder,
+    top_n: Option<(usize, String)>,
+    category_order: Option<CategoryOrder>,
+    /// Fill patterns, cycled over the series
+    patterns: Vec<FillPattern>,
+    errors: Option<BarErrors>,
+    brackets: Vec<SignificanceBracket>,
     theme: BarTheme,
+    layout: ChartLayout,
 }
 
 impl BarChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
     /// Set chart title (rendered at top of chart).
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -194,7 +273,87 @@ impl BarChart {
         self
     }
 
+    /// Overlay fill patterns on the bars, cycled over the series.
+    ///
+    /// Patterns keep series distinguishable in grayscale prints and for
+    /// colorblind readers.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::{D3Color, FillPattern, bar};
+    /// let ink = D3Color::from_hex(0x000000);
+    /// let chart = bar(&["A", "B"], &[1.0, 2.0])
+    ///     .add_series(&[1.5, 2.5], Some("Other"), 0xff7f0e, 0.8)
+    ///     .patterns(vec![FillPattern::hatch(ink), FillPattern::dots(ink)])
+    ///     .build();
+    /// ```
+    pub fn patterns(mut self, patterns: Vec<FillPattern>) -> Self {
+        self.patterns = patterns;
+        self
+    }
+
+    /// Draw error whiskers of `errors[i]` below and above each bar of the
+    /// primary series.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::bar;
+    /// let chart = bar(&["Control", "Treated"], &[4.2, 6.8])
+    ///     .error(&[0.4, 0.6])
+    ///     .build();
+    /// ```
+    pub fn error(mut self, errors: &[f64]) -> Self {
+        self.errors = Some(BarErrors {
+            minus: errors.to_vec(),
+            plus: errors.to_vec(),
+        });
+        self
+    }
+
+    /// Draw error whiskers reaching `minus[i]` below and `plus[i]` above each
+    /// bar of the primary series.
+    pub fn error_asymmetric(mut self, minus: &[f64], plus: &[f64]) -> Self {
+        self.errors = Some(BarErrors {
+            minus: minus.to_vec(),
+            plus: plus.to_vec(),
+        });
+        self
+    }
+
+    /// Draw a significance bracket labeled `label` between the categories
+    /// `from` and `to`.
+    ///
+    /// Brackets sit above the bars and whiskers they span and stack when
+    /// they overlap. Brackets naming a category that is not drawn are
+    /// skipped.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::{bar, p_value_label};
+    /// let chart = bar(&["Control", "Low", "High"], &[4.2, 5.1, 6.8])
+    ///     .error(&[0.4, 0.5, 0.6])
+    ///     .significance("Control", "High", p_value_label(0.003))
+    ///     .significance("Control", "Low", "n.s.")
+    ///     .build();
+    /// ```
+    pub fn significance(
+        mut self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        self.brackets.push(SignificanceBracket {
+            from: from.into(),
+            to: to.into(),
+            label: label.into(),
+        });
+        self
+    }
+
     /// Set the chart theme.
+    ///
+    /// Defaults to the colors of [`ChartTheme::current`], which follows the
+    /// app theme once [`init`](crate::init) has been called.
     pub fn theme(mut self, theme: BarTheme) -> Self {
         self.theme = theme;
         self
@@ -225,8 +384,424 @@ impl BarChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
+    /// Set the order of the categories.
+    ///
+    /// Value orders use the total over all series. Default is input order.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::{SortOrder, bar};
+    /// let chart = bar(&["A", "B", "C"], &[10.0, 30.0, 20.0])
+    ///     .sort(SortOrder::ByValueDesc)
+    ///     .build();
+    /// ```
+    pub fn sort(mut self, order: SortOrder) -> Self {
+        self.sort = order;
+        self
+    }
+
+    /// Keep the `n` largest categories and merge the others into one bar.
+    ///
+    /// The merged bar is labeled `other_label` and drawn last; it holds the
+    /// sum of the merged values of each series.
+    pub fn top_n(mut self, n: usize, other_label: impl Into<String>) -> Self {
+        self.top_n = Some((n, other_label.into()));
+        self
+    }
+
+    /// Keep the category order stable across rebuilds.
+    ///
+    /// The first build fills `order`; later builds draw known categories at
+    /// the same place and add new ones after them, so bars don't move when
+    /// the values of an animated chart change.
+    pub fn category_order(mut self, order: &CategoryOrder) -> Self {
+        self.category_order = Some(order.clone());
+        self
+    }
+
+    /// Compute the scales, ticks and bar rectangles without rendering.
+    ///
+    /// Runs the same validation and layout as [`BarChart::build`] at the
+    /// chart's own size; the outer layout options are not applied.
+    pub fn compute_layout(&self) -> Result<ChartGeometry, ChartError> {
+        let mut chart = self.clone();
+        chart.arrange_categories();
+        chart.plot_geometry()
+    }
+
+    /// Geometry of the chart with its categories already arranged.
+    fn plot_geometry(&self) -> Result<ChartGeometry, ChartError> {
+        let frame = self.plot_frame()?;
+        let (plot_width, plot_height) = (frame.plot_width, frame.plot_height);
+        let x_scale = LinearScale::new()
+            .domain(0.0, self.categories.len() as f64)
+            .range(0.0, plot_width);
+        let x_axis = AxisGeometry::new(
+            ScaleType::Linear,
+            (0.0, self.categories.len() as f64),
+            (0.0, plot_width),
+            None,
+            10,
+        );
+        let y_axis = AxisGeometry::new(
+            self.y_scale_type,
+            frame.y_domain,
+            (plot_height, 0.0),
+            None,
+            10,
+        );
+        let (y_min, y_max) = y_axis.domain;
+        let rects = match self.y_scale_type {
+            ScaleType::Linear => {
+                let y_scale = LinearScale::new()
+                    .domain(y_min, y_max)
+                    .range(plot_height, 0.0);
+                self.bar_rects(&x_scale, &y_scale, plot_width, plot_height)
+            }
+            ScaleType::Log => {
+                let y_scale = LogScale::new().domain(y_min, y_max).range(plot_height, 0.0);
+                self.bar_rects(&x_scale, &y_scale, plot_width, plot_height)
+            }
+        };
+
+        let mut geometry = ChartGeometry::new(plot_width, plot_height, x_axis, y_axis);
+        geometry.rects = rects
+            .into_iter()
+            .map(|rect| RectGeometry {
+                series: rect.series,
+                index: rect.index,
+                x: rect.x as f64,
+                y: rect.y as f64,
+                width: rect.width as f64,
+                height: rect.height as f64,
+            })
+            .collect();
+        Ok(geometry)
+    }
+
+    /// Apply the sort, top-N grouping and remembered category order.
+    ///
+    /// Does nothing when a series length doesn't match the categories,
+    /// leaving the error to validation.
+    fn arrange_categories(&mut self) {
+        let n = self.categories.len();
+        if self.values.len() != n || self.series.iter().any(|s| s.values.len() != n) {
+            return;
+        }
+        let values: Vec<Vec<f64>> = std::iter::once(self.values.clone())
+            .chain(self.series.iter().map(|s| s.values.clone()))
+            .collect();
+        // Errors follow their category; merged categories have none
+        let errors: Option<HashMap<String, (f64, f64)>> = self.errors.as_ref().and_then(|errors| {
+            (errors.minus.len() == n && errors.plus.len() == n).then(|| {
+                let bounds = errors
+                    .minus
+                    .iter()
+                    .copied()
+                    .zip(errors.plus.iter().copied());
+                self.categories.iter().cloned().zip(bounds).collect()
+            })
+        });
+        let top_n = self.top_n.take();
+        let arranged = arrange(
+            &self.categories,
+            &values,
+            &self.sort,
+            top_n.as_ref().map(|(n, label)| (*n, label.as_str())),
+            self.category_order.take().as_ref(),
+        );
+        self.sort = SortOrder::Input;
+        self.categories = arranged.categories;
+        let mut values = arranged.values.into_iter();
+        self.values = values.next().unwrap_or_default();
+        for (series, values) in self.series.iter_mut().zip(values) {
+            series.values = values;
+        }
+        if let Some(errors) = errors {
+            let bounds = |category: &String| errors.get(category).copied().unwrap_or_default();
+            let (minus, plus) = self.categories.iter().map(bounds).unzip();
+            self.errors = Some(BarErrors { minus, plus });
+        }
+    }
+
+    /// Highest point of each category: its tallest bar or error whisker.
+    fn category_tops(&self) -> Vec<f64> {
+        (0..self.categories.len())
+            .map(|i| {
+                let plus = self.errors.as_ref().map_or(0.0, |errors| errors.plus[i]);
+                let top = self
+                    .series
+                    .iter()
+                    .map(|series| series.values[i])
+                    .fold(self.values[i] + plus, f64::max);
+                match self.y_scale_type {
+                    // Negative bars hang from the zero baseline
+                    ScaleType::Linear => top.max(0.0),
+                    ScaleType::Log => top,
+                }
+            })
+            .collect()
+    }
+
+    /// Stack the significance brackets above the bars they span, narrowest
+    /// first. `data` holds every value the y axis must show.
+    ///
+    /// On a log axis, brackets are stacked in log space.
+    fn place_brackets(&self, data: &[f64]) -> Vec<PlacedBracket> {
+        if self.brackets.is_empty() {
+            return Vec::new();
+        }
+        let log = self.y_scale_type == ScaleType::Log;
+        let to_space = |value: f64| if log { value.log10() } else { value };
+        let from_space = |value: f64| if log { 10f64.powf(value) } else { value };
+
+        let tops: Vec<f64> = self.category_tops().into_iter().map(to_space).collect();
+        let (low, high) = data.iter().map(|&value| to_space(value)).fold(
+            (if log { f64::INFINITY } else { 0.0 }, f64::NEG_INFINITY),
+            |(low, high), value| (low.min(value), high.max(value)),
+        );
+        let step = if high > low {
+            (high - low) * BRACKET_STEP
+        } else {
+            1.0
+        };
+
+        let index = |category: &str| self.categories.iter().position(|c| c == category);
+        let mut spans: Vec<(usize, usize, &str)> = self
+            .brackets
+            .iter()
+            .filter_map(|bracket| {
+                let (from, to) = (index(&bracket.from)?, index(&bracket.to)?);
+                (from != to).then_some((from.min(to), from.max(to), bracket.label.as_str()))
+            })
+            .collect();
+        spans.sort_by_key(|&(from, to, _)| to - from);
+
+        let mut placed: Vec<PlacedBracket> = Vec::with_capacity(spans.len());
+        for (from, to, label) in spans {
+            let base = tops[from..=to]
+                .iter()
+                .copied()
+                .fold(f64::NEG_INFINITY, f64::max)
+                + step;
+            // Above the brackets already placed that it would touch
+            let y = placed
+                .iter()
+                .filter(|other| other.from <= to && from <= other.to)
+                .map(|other| other.y + step)
+                .fold(base, f64::max);
+            placed.push(PlacedBracket {
+                from,
+                to,
+                y,
+                label_top: y + step,
+                label: label.to_string(),
+            });
+        }
+        for bracket in &mut placed {
+            bracket.y = from_space(bracket.y);
+            bracket.label_top = from_space(bracket.label_top);
+        }
+        placed
+    }
+
+    /// Error whiskers of the primary series, centered on its bars.
+    fn error_whiskers(
+        &self,
+        x_scale: &LinearScale,
+        y_scale: &impl Scale<f64, f64>,
+        width: f64,
+        height: f64,
+    ) -> Vec<AnyElement> {
+        let Some(errors) = &self.errors else {
+            return Vec::new();
+        };
+        let color = self.theme.title_color;
+        let to_px = |value: f64| {
+            let value = match self.y_scale_type {
+                ScaleType::Log => value.max(f64::MIN_POSITIVE),
+                ScaleType::Linear => value,
+            };
+            (y_scale.scale(value) as f32).clamp(0.0, height as f32)
+        };
+        self.bar_rects(x_scale, y_scale, width, height)
+            .into_iter()
+            .filter(|rect| rect.series == 0)
+            .filter_map(|rect| {
+                let i = rect.index;
+                let (minus, plus) = (errors.minus[i], errors.plus[i]);
+                if minus == 0.0 && plus == 0.0 {
+                    return None;
+                }
+                let value = self.values[i];
+                let (top, bottom) = (to_px(value + plus), to_px(value - minus));
+                let center = rect.x + rect.width / 2.0;
+                let cap = (rect.width / 2.0).min(WHISKER_CAP_WIDTH);
+                let cap_at = |y: f32| {
+                    div()
+                        .absolute()
+                        .left(px(center - cap / 2.0))
+                        .top(px(y))
+                        .w(px(cap))
+                        .h_px()
+                        .bg(color)
+                };
+                Some(
+                    div()
+                        .absolute()
+                        .inset_0()
+                        .child(
+                            div()
+                                .absolute()
+                                .left(px(center - 0.5))
+                                .top(px(top))
+                                .w_px()
+                                .h(px(bottom - top))
+                                .bg(color),
+                        )
+                        .child(cap_at(top))
+                        .child(cap_at(bottom))
+                        .into_any_element(),
+                )
+            })
+            .collect()
+    }
+
+    /// Lines and labels of the significance brackets.
+    fn bracket_marks(
+        &self,
+        brackets: &[PlacedBracket],
+        y_scale: &impl Scale<f64, f64>,
+        width: f64,
+        height: f64,
+    ) -> Vec<AnyElement> {
+        let color = self.theme.title_color;
+        let band = width as f32 / self.categories.len().max(1) as f32;
+        brackets
+            .iter()
+            .map(|bracket| {
+                let left = (bracket.from as f32 + 0.5) * band;
+                let span = (bracket.to - bracket.from) as f32 * band;
+                let y = y_scale.scale(bracket.y) as f32;
+                div()
+                    .absolute()
+                    .inset_0()
+                    .child(
+                        div()
+                            .absolute()
+                            .left(px(left))
+                            .top(px(y))
+                            .w(px(span))
+                            .h(px(BRACKET_TICK))
+                            .border_t_1()
+                            .border_l_1()
+                            .border_r_1()
+                            .border_color(color),
+                    )
+                    .child(
+                        div()
+                            .absolute()
+                            .left(px(left))
+                            .bottom(px(height as f32 - y + 1.0))
+                            .w(px(span))
+                            .flex()
+                            .justify_center()
+                            .text_xs()
+                            .text_color(color)
+                            .whitespace_nowrap()
+                            .child(bracket.label.clone()),
+                    )
+                    .into_any_element()
+            })
+            .collect()
+    }
+
+    /// Bars as drawn by `build`, simple or grouped.
+    fn bar_rects(
+        &self,
+        x_scale: &LinearScale,
+        y_scale: &impl Scale<f64, f64>,
+        width: f64,
+        height: f64,
+    ) -> Vec<BarRect> {
+        let (width, height) = (width as f32, height as f32);
+        if self.series.is_empty() {
+            let (data, config) = self.simple_bars();
+            bar_rects(x_scale, y_scale, &data, width, height, &config)
+        } else {
+            let (data, config) = self.grouped_bars();
+            let meta = analyze_grouped_data(&data);
+            grouped_bar_rects(y_scale, &data, &meta, width, height, &config)
+        }
+    }
+
+    /// Data and config of single-series bars.
+    fn simple_bars(&self) -> (Vec<BarDatum>, BarConfig) {
+        let data = self
+            .categories
+            .iter()
+            .zip(self.values.iter())
+            .map(|(cat, &val)| BarDatum::new(cat.clone(), val))
+            .collect();
+
+        let config = BarConfig::new()
+            .fill_color(D3Color::from_hex(self.color))
+            .opacity(self.opacity)
+            .bar_gap(self.bar_gap)
+            .border_radius(self.border_radius);
+        let config = match self.patterns.first() {
+            Some(&pattern) => config.pattern(pattern),
+            None => config,
+        };
+
+        (data, config)
+    }
+
+    /// Data and config of grouped bars: one group per category, one bar per
+    /// series.
+    fn grouped_bars(&self) -> (Vec<GroupedBarDatum>, GroupedBarConfig) {
+        let mut all_data = Vec::new();
+
+        // Primary series
+        let primary_label = self.label.clone().unwrap_or_else(|| "Series 1".to_string());
+        for (cat, &val) in self.categories.iter().zip(self.values.iter()) {
+            all_data.push(GroupedBarDatum::new(
+                cat.clone(),
+                primary_label.clone(),
+                val,
+            ));
+        }
+
+        // Additional series
+        for (i, s) in self.series.iter().enumerate() {
+            let series_label = s
+                .label
+                .clone()
+                .unwrap_or_else(|| format!("Series {}", i + 2));
+            for (cat, &val) in self.categories.iter().zip(s.values.iter()) {
+                all_data.push(GroupedBarDatum::new(cat.clone(), series_label.clone(), val));
+            }
+        }
+
+        // Collect colors for all series
+        let mut series_colors = vec![D3Color::from_hex(self.color)];
+        for s in &self.series {
+            series_colors.push(D3Color::from_hex(s.color));
+        }
+
+        let config = GroupedBarConfig::new()
+            .series_colors(series_colors)
+            .opacity(self.opacity)
+            .group_gap(self.bar_gap * 3.0) // Gap between category groups
+            .bar_gap(self.bar_gap * 0.5) // Gap between bars within group
+            .border_radius(self.border_radius)
+            .series_patterns(self.patterns.clone());
+
+        (all_data, config)
+    }
+
+    /// Validate the data and lay out the plot area, legend and y domain.
+    fn plot_frame(&self) -> Result<BarFrame, ChartError> {
         // Validate inputs
         if self.categories.is_empty() {
             return Err(ChartError::EmptyData {
@@ -261,6 +836,22 @@ impl BarChart {
             }
         }
 
+        if let Some(errors) = &self.errors {
+            for (errors, field) in [
+                (&errors.minus, "errors.minus"),
+                (&errors.plus, "errors.plus"),
+            ] {
+                validate_data_array(errors, field)?;
+                validate_data_length(self.categories.len(), errors.len(), "categories", field)?;
+                if errors.iter().any(|&e| e < 0.0) {
+                    return Err(ChartError::InvalidData {
+                        field,
+                        reason: "contains negative errors",
+                    });
+                }
+            }
+        }
+
         // Define margins
         let margin_left = 50.0;
         let margin_bottom = 30.0;
@@ -382,12 +973,25 @@ impl BarChart {
             - margin_bottom
             - height_for_legend as f64)
             .max(0.0);
+        validate_plot_area(plot_width, plot_height)?;
 
         // Calculate y domain with padding - include all series
         let mut all_values = self.values.clone();
         for series in &self.series {
             all_values.extend_from_slice(&series.values);
         }
+        // Error whiskers, except below zero on a log axis
+        if let Some(errors) = &self.errors {
+            for (i, &value) in self.values.iter().enumerate() {
+                all_values.push(value + errors.plus[i]);
+                let low = value - errors.minus[i];
+                if self.y_scale_type == ScaleType::Linear || low > 0.0 {
+                    all_values.push(low);
+                }
+            }
+        }
+        let brackets = self.place_brackets(&all_values);
+        all_values.extend(brackets.iter().map(|bracket| bracket.label_top));
         let (mut y_min, mut y_max) = extent_padded(&all_values, DEFAULT_PADDING_FRACTION);
 
         // For linear scale, always include zero baseline for bar charts
@@ -397,12 +1001,42 @@ impl BarChart {
             y_max = y_max.max(0.0);
         }
 
+        Ok(BarFrame {
+            title_height,
+            legend_gap,
+            has_legend_items,
+            legend_position,
+            legend_width,
+            legend_height,
+            plot_width,
+            plot_height,
+            y_domain: (y_min, y_max),
+            brackets,
+        })
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<impl IntoElement, ChartError> {
+        self.arrange_categories();
+        let BarFrame {
+            title_height,
+            legend_gap,
+            has_legend_items,
+            legend_position,
+            legend_width,
+            legend_height,
+            plot_width,
+            plot_height,
+            y_domain: (y_min, y_max),
+            brackets,
+        } = self.plot_frame()?;
+
         // Create X scale (always linear for categories)
         let x_scale = LinearScale::new()
             .domain(0.0, self.categories.len() as f64)
             .range(0.0, plot_width);
 
-        let axis_theme = DefaultAxisTheme;
+        let axis_theme = ChartTheme::current().axis_theme();
 
         // Determine if we're using grouped bars (multiple series) or simple bars
         let use_grouped_bars = !self.series.is_empty();
@@ -417,63 +1051,17 @@ impl BarChart {
         let primary_config: BarConfig;
 
         if use_grouped_bars {
-            // Build grouped bar data from all series
-            let mut all_data = Vec::new();
-
-            // Primary series
-            let primary_label = self.label.clone().unwrap_or_else(|| "Series 1".to_string());
-            for (cat, &val) in self.categories.iter().zip(self.values.iter()) {
-                all_data.push(GroupedBarDatum::new(
-                    cat.clone(),
-                    primary_label.clone(),
-                    val,
-                ));
-            }
-
-            // Additional series
-            for (i, s) in self.series.iter().enumerate() {
-                let series_label = s
-                    .label
-                    .clone()
-                    .unwrap_or_else(|| format!("Series {}", i + 2));
-                for (cat, &val) in self.categories.iter().zip(s.values.iter()) {
-                    all_data.push(GroupedBarDatum::new(cat.clone(), series_label.clone(), val));
-                }
-            }
-
-            grouped_data = all_data;
-            grouped_meta = analyze_grouped_data(&grouped_data);
-
-            // Collect colors for all series
-            let mut series_colors = vec![D3Color::from_hex(self.color)];
-            for s in &self.series {
-                series_colors.push(D3Color::from_hex(s.color));
-            }
-
-            grouped_config = GroupedBarConfig::new()
-                .series_colors(series_colors)
-                .opacity(self.opacity)
-                .group_gap(self.bar_gap * 3.0) // Gap between category groups
-                .bar_gap(self.bar_gap * 0.5) // Gap between bars within group
-                .border_radius(self.border_radius);
+            let (data, config) = self.grouped_bars();
+            grouped_meta = analyze_grouped_data(&data);
+            grouped_data = data;
+            grouped_config = config;
 
             // Dummy values for single-series (won't be used)
             primary_data = Vec::new();
             primary_config = BarConfig::new();
         } else {
             // Single series - use simple bars
-            primary_data = self
-                .categories
-                .iter()
-                .zip(self.values.iter())
-                .map(|(cat, &val)| BarDatum::new(cat.clone(), val))
-                .collect();
-
-            primary_config = BarConfig::new()
-                .fill_color(D3Color::from_hex(self.color))
-                .opacity(self.opacity)
-                .bar_gap(self.bar_gap)
-                .border_radius(self.border_radius);
+            (primary_data, primary_config) = self.simple_bars();
 
             // Dummy values for grouped bars (won't be used)
             grouped_data = Vec::new();
@@ -494,6 +1082,11 @@ impl BarChart {
                     .h(px(plot_height as f32))
                     .relative()
                     .bg(self.theme.plot_background)
+                    .children(self.layout.render_watermark(
+                        self.theme.title_color,
+                        plot_width as f32,
+                        plot_height as f32,
+                    ))
                     .child(render_grid(
                         &x_scale,
                         &$y_scale,
@@ -503,7 +1096,7 @@ impl BarChart {
                         &axis_theme,
                     ));
 
-                if use_grouped_bars {
+                let plot_area = if use_grouped_bars {
                     // Use grouped bar rendering
                     plot_area.child(render_grouped_bars(
                         &$y_scale,
@@ -523,7 +1116,11 @@ impl BarChart {
                         plot_height as f32,
                         &primary_config,
                     ))
-                }
+                };
+
+                plot_area
+                    .children(self.error_whiskers(&x_scale, &$y_scale, plot_width, plot_height))
+                    .children(self.bracket_marks(&brackets, &$y_scale, plot_width, plot_height))
             }};
         }
 
@@ -717,6 +1314,107 @@ impl BarChart {
     }
 }
 
+impl crate::clipboard::CopyToClipboard for BarChart {
+    /// All series as wide-format TSV, one row per category.
+    fn clipboard_text(&self) -> Option<String> {
+        let mut chart = self.clone();
+        chart.arrange_categories();
+        let label = chart
+            .label
+            .clone()
+            .unwrap_or_else(|| "Series 1".to_string());
+        let mut series: Vec<(String, &[f64])> = vec![(label, &chart.values)];
+        for (i, s) in chart.series.iter().enumerate() {
+            let label = s
+                .label
+                .clone()
+                .unwrap_or_else(|| format!("Series {}", i + 2));
+            series.push((label, &s.values));
+        }
+        Some(crate::clipboard::category_tsv(&chart.categories, &series))
+    }
+
+    #[cfg(feature = "gpu-2d")]
+    fn clipboard_png(&self) -> Option<Vec<u8>> {
+        crate::clipboard::chart_png(self, self.width, self.height)
+    }
+}
+
+#[cfg(feature = "gpu-2d")]
+impl crate::offscreen::TextureChart for BarChart {
+    fn validate(&self) -> Result<(), ChartError> {
+        self.plot_frame().map(|_| ())
+    }
+
+    fn content_key(&self) -> u64 {
+        use crate::offscreen::hash_values;
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.categories.hash(&mut hasher);
+        hash_values(&mut hasher, &self.values);
+        (self.color, self.opacity.to_bits()).hash(&mut hasher);
+        for series in &self.series {
+            hash_values(&mut hasher, &series.values);
+            series.color.hash(&mut hasher);
+        }
+        (self.bar_gap.to_bits(), self.border_radius.to_bits()).hash(&mut hasher);
+        (self.y_scale_type == ScaleType::Log).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn draw_marks(&self, renderer: &mut d3rs::gpu2d::Chart2DRenderer, width: f32, height: f32) {
+        use crate::offscreen::{TextureScale, texture_color};
+        use d3rs::gpu2d::primitives::Rect;
+
+        let n = self.categories.len();
+        if n == 0 {
+            return;
+        }
+        // Primary series followed by additional series, drawn as groups
+        let groups: Vec<(&[f64], u32)> = std::iter::once((self.values.as_slice(), self.color))
+            .chain(self.series.iter().map(|s| (s.values.as_slice(), s.color)))
+            .collect();
+
+        let all: Vec<f64> = groups.iter().flat_map(|g| g.0.iter().copied()).collect();
+        let (min, max) = extent_padded(&all, DEFAULT_PADDING_FRACTION);
+        let (min, max) = match self.y_scale_type {
+            ScaleType::Linear => (min.min(0.0), max.max(0.0)),
+            ScaleType::Log => (min, max),
+        };
+        let y_scale = TextureScale::vertical((min, max), self.y_scale_type, height);
+        let baseline = y_scale.map(match self.y_scale_type {
+            ScaleType::Linear => 0.0,
+            ScaleType::Log => min,
+        });
+
+        let band = width / n as f32;
+        let gap = (self.bar_gap * 3.0).clamp(0.0, (band - 1.0).max(0.0));
+        let bar_width = (band - gap) / groups.len() as f32;
+        for (g, (values, color)) in groups.iter().enumerate() {
+            let color = texture_color(*color, self.opacity);
+            for (i, &value) in values.iter().enumerate().take(n) {
+                let top = y_scale.map(value);
+                let (y, h) = (top.min(baseline), (top - baseline).abs());
+                let x = i as f32 * band + gap / 2.0 + g as f32 * bar_width;
+                renderer.draw_rect(Rect::new(x, y, bar_width, h), color, self.border_radius);
+            }
+        }
+    }
+}
+
+/// Conventional label of a significance bracket for the p-value `p`:
+/// `"p < 0.001"`, `"p < 0.01"`, `"p < 0.05"` or `"n.s."`.
+pub fn p_value_label(p: f64) -> String {
+    match p {
+        p if p < 0.001 => "p < 0.001",
+        p if p < 0.01 => "p < 0.01",
+        p if p < 0.05 => "p < 0.05",
+        _ => "n.s.",
+    }
+    .to_string()
+}
+
 /// Create a bar chart from categories and values.
 ///
 /// # Example
@@ -734,6 +1432,7 @@ impl BarChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn bar<S: AsRef<str>>(categories: &[S], values: &[f64]) -> BarChart {
+    let defaults = ChartDefaults::current();
     BarChart {
         categories: categories.iter().map(|s| s.as_ref().to_string()).collect(),
         values: values.to_vec(),
@@ -744,14 +1443,21 @@ pub fn bar<S: AsRef<str>>(categories: &[S], values: &[f64]) -> BarChart {
         title: None,
         bar_gap: 2.0,
         border_radius: 2.0,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        width: defaults.width,
+        height: defaults.height,
         y_scale_type: ScaleType::Linear,
         show_legend: false,
-        legend_position: LegendPosition::default(),
-        legend_position_explicit: false,
+        legend_position: defaults.legend_position.unwrap_or_default(),
+        legend_position_explicit: defaults.legend_position.is_some(),
         graph_ratio: 1.414,
-        theme: BarTheme::default(),
+        sort: SortOrder::Input,
+        top_n: None,
+        category_order: None,
+        patterns: Vec::new(),
+        errors: None,
+        brackets: Vec::new(),
+        theme: BarTheme::from(&ChartTheme::current()),
+        layout: ChartLayout::watermark_in_plot(),
     }
 }
 
@@ -885,4 +1591,114 @@ mod tests {
             .build();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_bar_compute_layout() {
+        let geometry = bar(&["A", "B", "C"], &[10.0, 20.0, 30.0])
+            .size(400.0, 300.0)
+            .compute_layout()
+            .unwrap();
+        assert_eq!(geometry.rects.len(), 3);
+        let (a, c) = (geometry.rects[0], geometry.rects[2]);
+        assert!(a.x < c.x);
+        assert!(a.height < c.height);
+        // Bars stand on the zero baseline
+        let baseline = geometry.y_axis.position(0.0);
+        assert!((c.y + c.height - baseline).abs() < 1e-3);
+
+        let grouped = bar(&["A", "B"], &[1.0, 2.0])
+            .add_series(&[3.0, 4.0], Some("Other"), 0xff0000, 1.0)
+            .size(400.0, 300.0)
+            .compute_layout()
+            .unwrap();
+        assert_eq!(grouped.rects.len(), 4);
+        assert!(grouped.rects.iter().any(|rect| rect.series == 1));
+    }
+
+    #[test]
+    fn test_bar_sort_and_top_n() {
+        let chart = bar(&["A", "B", "C", "D"], &[10.0, 40.0, 20.0, 30.0])
+            .sort(SortOrder::ByValueDesc)
+            .top_n(2, "Other");
+        let geometry = chart.compute_layout().unwrap();
+        // B, D, then Other = A + C
+        assert_eq!(geometry.rects.len(), 3);
+        let heights: Vec<f64> = geometry.rects.iter().map(|rect| rect.height).collect();
+        assert!(heights[0] > heights[1] && heights[1] == heights[2]);
+        assert!(chart.build().is_ok());
+    }
+
+    #[test]
+    fn test_bar_category_order_is_stable() {
+        let order = CategoryOrder::new();
+        let first = bar(&["A", "B"], &[1.0, 2.0])
+            .sort(SortOrder::ByValueDesc)
+            .category_order(&order);
+        assert!(first.build().is_ok());
+        assert_eq!(order.order(), vec!["B", "A"]);
+
+        let next = bar(&["A", "B"], &[5.0, 2.0])
+            .sort(SortOrder::ByValueDesc)
+            .category_order(&order)
+            .compute_layout()
+            .unwrap();
+        // B keeps the first slot although A is now larger
+        assert!(next.rects[0].height < next.rects[1].height);
+    }
+
+    #[test]
+    fn test_bar_errors() {
+        let chart = bar(&["A", "B", "C"], &[1.0, 3.0, 2.0])
+            .error_asymmetric(&[0.1, 0.2, 0.3], &[0.5, 0.0, 0.4])
+            .sort(SortOrder::ByValueDesc);
+        let frame = chart.plot_frame().unwrap();
+        assert!(frame.y_domain.1 >= 3.0 && frame.y_domain.1 < 3.5);
+        assert!(chart.clone().build().is_ok());
+
+        // Errors follow their category when sorted
+        let mut sorted = chart.clone();
+        sorted.arrange_categories();
+        let errors = sorted.errors.unwrap();
+        assert_eq!(errors.plus, vec![0.0, 0.4, 0.5]);
+        assert_eq!(errors.minus, vec![0.2, 0.3, 0.1]);
+
+        let result = bar(&["A", "B"], &[1.0, 2.0]).error(&[0.1]).build();
+        assert!(matches!(result, Err(ChartError::DataLengthMismatch { .. })));
+        let result = bar(&["A", "B"], &[1.0, 2.0]).error(&[0.1, -0.1]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData {
+                field: "errors.minus",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_bar_significance_brackets_stack() {
+        let chart = bar(&["A", "B", "C"], &[4.0, 5.0, 8.0])
+            .error(&[1.0, 1.0, 1.0])
+            .significance("A", "C", "p < 0.01")
+            .significance("A", "B", "n.s.")
+            .significance("A", "Missing", "p < 0.05");
+        let frame = chart.plot_frame().unwrap();
+        // The narrow bracket first, right above A and B, then the wide one
+        let [narrow, wide] = frame.brackets.as_slice() else {
+            panic!("expected two brackets");
+        };
+        assert_eq!((narrow.from, narrow.to), (0, 1));
+        assert_eq!((wide.from, wide.to), (0, 2));
+        assert!(narrow.y > 6.0 && narrow.y < wide.y);
+        assert!(wide.y > 9.0);
+        assert!(frame.y_domain.1 >= wide.label_top);
+        assert!(chart.build().is_ok());
+    }
+
+    #[test]
+    fn test_p_value_label() {
+        assert_eq!(p_value_label(0.0004), "p < 0.001");
+        assert_eq!(p_value_label(0.004), "p < 0.01");
+        assert_eq!(p_value_label(0.04), "p < 0.05");
+        assert_eq!(p_value_label(0.2), "n.s.");
+    }
 }
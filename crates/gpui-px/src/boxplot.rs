@@ -6,19 +6,22 @@
 //! - Whiskers extending to 1.5×IQR or data min/max
 //! - Outliers as individual points
 
+use crate::async_chart::AsyncChart;
 use crate::error::ChartError;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::theme::{ChartAxisTheme, ChartTheme};
 use crate::{
-    DEFAULT_COLOR, DEFAULT_HEIGHT, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE,
-    DEFAULT_WIDTH, ScaleType, TITLE_AREA_HEIGHT, extent_padded, validate_data_array,
-    validate_data_length, validate_dimensions, validate_positive,
+    ChartDefaults, DEFAULT_COLOR, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE, ScaleType,
+    TITLE_AREA_HEIGHT, extent_padded, validate_data_array, validate_data_length,
+    validate_dimensions, validate_plot_area, validate_positive,
 };
-use d3rs::axis::{AxisConfig, DefaultAxisTheme, render_axis};
+use d3rs::axis::{AxisConfig, render_axis};
 use d3rs::color::D3Color;
 use d3rs::grid::{GridConfig, render_grid};
 use d3rs::scale::{LinearScale, LogScale, Scale};
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
-use gpui::{AnyElement, IntoElement, div, hsla, px, rgb};
+use gpui::{AnyElement, App, Entity, IntoElement, Rgba, div, hsla, px};
 
 /// Statistics for a single box in a box plot
 #[derive(Debug, Clone)]
@@ -43,7 +46,7 @@ pub struct BoxStats {
 
 impl BoxStats {
     /// Calculate box statistics from a sorted slice of values
-    fn from_sorted(x: f64, sorted_values: &[f64]) -> Option<Self> {
+    pub(crate) fn from_sorted(x: f64, sorted_values: &[f64]) -> Option<Self> {
         if sorted_values.is_empty() {
             return None;
         }
@@ -99,6 +102,12 @@ impl BoxStats {
     }
 }
 
+/// Space left of the plot area, for the y axis
+const MARGIN_LEFT: f64 = 60.0;
+
+/// Space right of the plot area
+const MARGIN_RIGHT: f64 = 20.0;
+
 /// Calculate percentile using linear interpolation
 fn percentile(sorted: &[f64], p: f64) -> f64 {
     if sorted.is_empty() {
@@ -121,6 +130,168 @@ fn percentile(sorted: &[f64], p: f64) -> f64 {
     }
 }
 
+/// Look of a box-and-whisker glyph
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BoxStyle {
+    pub(crate) box_color: Rgba,
+    pub(crate) median_color: Rgba,
+    pub(crate) whisker_color: Rgba,
+    pub(crate) outlier_color: Rgba,
+    pub(crate) box_opacity: f32,
+    pub(crate) box_width: f32,
+    pub(crate) stroke_width: f32,
+    pub(crate) outlier_radius: f32,
+}
+
+/// Elements of the box-and-whisker glyph of `stats`, centered on `x_px`
+///
+/// Shared by box plots and the inner boxes of violin plots.
+pub(crate) fn box_glyph<YS: Scale<f64, f64>>(
+    stats: &BoxStats,
+    x_px: f32,
+    y_scale: &YS,
+    style: &BoxStyle,
+) -> Vec<AnyElement> {
+    let half_width = style.box_width / 2.0;
+
+    let q1_px = y_scale.scale(stats.q1) as f32;
+    let q2_px = y_scale.scale(stats.q2) as f32;
+    let q3_px = y_scale.scale(stats.q3) as f32;
+    let whisker_low_px = y_scale.scale(stats.whisker_low) as f32;
+    let whisker_high_px = y_scale.scale(stats.whisker_high) as f32;
+
+    let box_top = q3_px.min(q1_px);
+    let box_bottom = q3_px.max(q1_px);
+    let box_height = (box_bottom - box_top).max(1.0);
+
+    let mut elements: Vec<AnyElement> = Vec::new();
+
+    // Whisker line (vertical line from low to high)
+    elements.push(
+        div()
+            .absolute()
+            .left(px(x_px - 0.5))
+            .top(px(whisker_high_px.min(whisker_low_px)))
+            .w(px(style.stroke_width))
+            .h(px((whisker_low_px - whisker_high_px).abs().max(1.0)))
+            .bg(style.whisker_color)
+            .into_any_element(),
+    );
+
+    // Lower whisker cap (horizontal line)
+    elements.push(
+        div()
+            .absolute()
+            .left(px(x_px - half_width * 0.5))
+            .top(px(whisker_low_px - style.stroke_width / 2.0))
+            .w(px(half_width))
+            .h(px(style.stroke_width))
+            .bg(style.whisker_color)
+            .into_any_element(),
+    );
+
+    // Upper whisker cap (horizontal line)
+    elements.push(
+        div()
+            .absolute()
+            .left(px(x_px - half_width * 0.5))
+            .top(px(whisker_high_px - style.stroke_width / 2.0))
+            .w(px(half_width))
+            .h(px(style.stroke_width))
+            .bg(style.whisker_color)
+            .into_any_element(),
+    );
+
+    // Box (IQR)
+    elements.push(
+        div()
+            .absolute()
+            .left(px(x_px - half_width))
+            .top(px(box_top))
+            .w(px(style.box_width))
+            .h(px(box_height))
+            .bg(style.box_color)
+            .opacity(style.box_opacity)
+            .border_1()
+            .border_color(style.whisker_color)
+            .into_any_element(),
+    );
+
+    // Median line
+    elements.push(
+        div()
+            .absolute()
+            .left(px(x_px - half_width))
+            .top(px(q2_px - style.stroke_width))
+            .w(px(style.box_width))
+            .h(px(style.stroke_width * 2.0))
+            .bg(style.median_color)
+            .into_any_element(),
+    );
+
+    // Outliers
+    for &outlier in &stats.outliers_low {
+        let y_px = y_scale.scale(outlier) as f32;
+        elements.push(
+            div()
+                .absolute()
+                .left(px(x_px - style.outlier_radius))
+                .top(px(y_px - style.outlier_radius))
+                .w(px(style.outlier_radius * 2.0))
+                .h(px(style.outlier_radius * 2.0))
+                .rounded_full()
+                .bg(style.outlier_color)
+                .opacity(0.7)
+                .into_any_element(),
+        );
+    }
+
+    for &outlier in &stats.outliers_high {
+        let y_px = y_scale.scale(outlier) as f32;
+        elements.push(
+            div()
+                .absolute()
+                .left(px(x_px - style.outlier_radius))
+                .top(px(y_px - style.outlier_radius))
+                .w(px(style.outlier_radius * 2.0))
+                .h(px(style.outlier_radius * 2.0))
+                .rounded_full()
+                .bg(style.outlier_color)
+                .opacity(0.7)
+                .into_any_element(),
+        );
+    }
+
+    elements
+}
+
+/// Calculate box statistics for each bin of `x`
+fn calculate_boxes(x: &[f64], y: &[f64], x_min: f64, x_max: f64, num_bins: usize) -> Vec<BoxStats> {
+    let bin_width = (x_max - x_min) / num_bins as f64;
+
+    // Group data points by bin
+    let mut bins: Vec<Vec<f64>> = vec![Vec::new(); num_bins];
+
+    for (&x, &y) in x.iter().zip(y.iter()) {
+        let bin_idx = ((x - x_min) / bin_width).floor() as usize;
+        let bin_idx = bin_idx.min(num_bins - 1);
+        bins[bin_idx].push(y);
+    }
+
+    // Calculate statistics for each non-empty bin
+    bins.iter_mut()
+        .enumerate()
+        .filter_map(|(i, bin)| {
+            if bin.is_empty() {
+                return None;
+            }
+            bin.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let x_center = x_min + (i as f64 + 0.5) * bin_width;
+            BoxStats::from_sorted(x_center, bin)
+        })
+        .collect()
+}
+
 /// Box plot builder.
 #[derive(Debug, Clone)]
 pub struct BoxPlotChart {
@@ -140,9 +311,14 @@ pub struct BoxPlotChart {
     height: f32,
     x_scale_type: ScaleType,
     y_scale_type: ScaleType,
+    /// Box statistics computed by `build_async`, with their bin count
+    boxes: Option<(usize, Vec<BoxStats>)>,
+    layout: ChartLayout,
 }
 
 impl BoxPlotChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
     /// Set chart title (rendered at top of chart).
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -223,13 +399,40 @@ impl BoxPlotChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
-        // Validate inputs
+    /// Build the chart with its box statistics computed on the background
+    /// executor.
+    ///
+    /// The data is validated right away; the returned [`AsyncChart`] shows a
+    /// placeholder until the statistics are ready. A responsive chart resized
+    /// to another bin count recomputes them when it is rendered.
+    pub fn build_async(self, cx: &mut App) -> Result<Entity<AsyncChart>, ChartError> {
+        self.validate()?;
+        let (width, height) = self.layout.fixed_size(self.width, self.height);
+        let (inner_width, _) = self.layout.inner_size(width, height);
+        let num_bins = self.bin_count((inner_width as f64 - MARGIN_LEFT - MARGIN_RIGHT).max(0.0));
+        let (x, y) = (self.x.clone(), self.y.clone());
+        let (layout, size) = (self.layout.clone(), (self.width, self.height));
+        Ok(AsyncChart::spawn(
+            &layout,
+            size,
+            move || {
+                let (x_min, x_max) = extent_padded(&x, DEFAULT_PADDING_FRACTION);
+                (num_bins, calculate_boxes(&x, &y, x_min, x_max, num_bins))
+            },
+            move |boxes: &(usize, Vec<BoxStats>)| {
+                let mut chart = self.clone();
+                chart.boxes = Some(boxes.clone());
+                chart.build().map(IntoElement::into_any_element)
+            },
+            cx,
+        ))
+    }
+
+    /// Validate the data.
+    fn validate(&self) -> Result<(), ChartError> {
         validate_data_array(&self.x, "x")?;
         validate_data_array(&self.y, "y")?;
         validate_data_length(self.x.len(), self.y.len(), "x", "y")?;
-        validate_dimensions(self.width, self.height)?;
 
         // Validate positive values for log scale
         if self.x_scale_type == ScaleType::Log {
@@ -238,12 +441,24 @@ impl BoxPlotChart {
         if self.y_scale_type == ScaleType::Log {
             validate_positive(&self.y, "y")?;
         }
+        Ok(())
+    }
+
+    /// Number of bins: the one set with [`BoxPlotChart::bins`], or one per
+    /// 40 pixels of plot width.
+    fn bin_count(&self, plot_width: f64) -> usize {
+        self.num_bins
+            .unwrap_or_else(|| (plot_width / 40.0).max(3.0) as usize)
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<impl IntoElement, ChartError> {
+        self.validate()?;
+        validate_dimensions(self.width, self.height)?;
 
         // Define margins
-        let margin_left = 60.0;
         let margin_bottom = 30.0;
         let margin_top = 10.0;
-        let margin_right = 20.0;
 
         // Calculate plot area
         let title_height = if self.title.is_some() {
@@ -252,21 +467,21 @@ impl BoxPlotChart {
             0.0
         };
 
-        let plot_width = (self.width as f64 - margin_left - margin_right).max(0.0);
+        let plot_width = (self.width as f64 - MARGIN_LEFT - MARGIN_RIGHT).max(0.0);
         let plot_height =
             (self.height as f64 - title_height as f64 - margin_top - margin_bottom).max(0.0);
+        validate_plot_area(plot_width, plot_height)?;
 
         // Calculate domains
         let (x_min, x_max) = extent_padded(&self.x, DEFAULT_PADDING_FRACTION);
         let (y_min, y_max) = extent_padded(&self.y, DEFAULT_PADDING_FRACTION);
 
-        // Calculate number of bins
-        let num_bins = self
-            .num_bins
-            .unwrap_or_else(|| (plot_width / 40.0).max(3.0) as usize);
-
-        // Bin the data
-        let boxes = self.calculate_boxes(x_min, x_max, num_bins);
+        // Bin the data, unless `build_async` already did for this bin count
+        let num_bins = self.bin_count(plot_width);
+        let boxes = match self.boxes.take() {
+            Some((bins, boxes)) if bins == num_bins => boxes,
+            _ => calculate_boxes(&self.x, &self.y, x_min, x_max, num_bins),
+        };
 
         // Build based on scale types
         let chart_content =
@@ -301,33 +516,6 @@ impl BoxPlotChart {
         Ok(container)
     }
 
-    /// Calculate box statistics for each bin
-    fn calculate_boxes(&self, x_min: f64, x_max: f64, num_bins: usize) -> Vec<BoxStats> {
-        let bin_width = (x_max - x_min) / num_bins as f64;
-
-        // Group data points by bin
-        let mut bins: Vec<Vec<f64>> = vec![Vec::new(); num_bins];
-
-        for (&x, &y) in self.x.iter().zip(self.y.iter()) {
-            let bin_idx = ((x - x_min) / bin_width).floor() as usize;
-            let bin_idx = bin_idx.min(num_bins - 1);
-            bins[bin_idx].push(y);
-        }
-
-        // Calculate statistics for each non-empty bin
-        bins.iter_mut()
-            .enumerate()
-            .filter_map(|(i, bin)| {
-                if bin.is_empty() {
-                    return None;
-                }
-                bin.sort_by(|a, b| a.partial_cmp(b).unwrap());
-                let x_center = x_min + (i as f64 + 0.5) * bin_width;
-                BoxStats::from_sorted(x_center, bin)
-            })
-            .collect()
-    }
-
     /// Render the chart content
     fn render_chart(
         &self,
@@ -339,7 +527,7 @@ impl BoxPlotChart {
         plot_width: f64,
         plot_height: f64,
     ) -> AnyElement {
-        let theme = DefaultAxisTheme;
+        let theme = ChartTheme::current().axis_theme();
 
         match (self.x_scale_type, self.y_scale_type) {
             (ScaleType::Linear, ScaleType::Linear) => {
@@ -393,134 +581,27 @@ impl BoxPlotChart {
         boxes: &[BoxStats],
         plot_width: f64,
         plot_height: f64,
-        theme: &DefaultAxisTheme,
+        theme: &ChartAxisTheme,
     ) -> AnyElement
     where
         XS: Scale<f64, f64>,
         YS: Scale<f64, f64>,
     {
-        let box_color = D3Color::from_hex(self.box_color).to_rgba();
-        let median_color = D3Color::from_hex(self.median_color).to_rgba();
-        let whisker_color = D3Color::from_hex(self.whisker_color).to_rgba();
-        let outlier_color = D3Color::from_hex(self.outlier_color).to_rgba();
+        let style = BoxStyle {
+            box_color: D3Color::from_hex(self.box_color).to_rgba(),
+            median_color: D3Color::from_hex(self.median_color).to_rgba(),
+            whisker_color: D3Color::from_hex(self.whisker_color).to_rgba(),
+            outlier_color: D3Color::from_hex(self.outlier_color).to_rgba(),
+            box_opacity: self.box_opacity,
+            box_width: self.box_width,
+            stroke_width: self.stroke_width,
+            outlier_radius: self.outlier_radius,
+        };
 
         // Render all boxes
         let box_elements: Vec<AnyElement> = boxes
             .iter()
-            .flat_map(|stats| {
-                let x_px = x_scale.scale(stats.x) as f32;
-                let half_width = self.box_width / 2.0;
-
-                let q1_px = y_scale.scale(stats.q1) as f32;
-                let q2_px = y_scale.scale(stats.q2) as f32;
-                let q3_px = y_scale.scale(stats.q3) as f32;
-                let whisker_low_px = y_scale.scale(stats.whisker_low) as f32;
-                let whisker_high_px = y_scale.scale(stats.whisker_high) as f32;
-
-                let box_top = q3_px.min(q1_px);
-                let box_bottom = q3_px.max(q1_px);
-                let box_height = (box_bottom - box_top).max(1.0);
-
-                let mut elements: Vec<AnyElement> = Vec::new();
-
-                // Whisker line (vertical line from low to high)
-                elements.push(
-                    div()
-                        .absolute()
-                        .left(px(x_px - 0.5))
-                        .top(px(whisker_high_px.min(whisker_low_px)))
-                        .w(px(self.stroke_width))
-                        .h(px((whisker_low_px - whisker_high_px).abs().max(1.0)))
-                        .bg(whisker_color)
-                        .into_any_element(),
-                );
-
-                // Lower whisker cap (horizontal line)
-                elements.push(
-                    div()
-                        .absolute()
-                        .left(px(x_px - half_width * 0.5))
-                        .top(px(whisker_low_px - self.stroke_width / 2.0))
-                        .w(px(half_width))
-                        .h(px(self.stroke_width))
-                        .bg(whisker_color)
-                        .into_any_element(),
-                );
-
-                // Upper whisker cap (horizontal line)
-                elements.push(
-                    div()
-                        .absolute()
-                        .left(px(x_px - half_width * 0.5))
-                        .top(px(whisker_high_px - self.stroke_width / 2.0))
-                        .w(px(half_width))
-                        .h(px(self.stroke_width))
-                        .bg(whisker_color)
-                        .into_any_element(),
-                );
-
-                // Box (IQR)
-                elements.push(
-                    div()
-                        .absolute()
-                        .left(px(x_px - half_width))
-                        .top(px(box_top))
-                        .w(px(self.box_width))
-                        .h(px(box_height))
-                        .bg(box_color)
-                        .opacity(self.box_opacity)
-                        .border_1()
-                        .border_color(whisker_color)
-                        .into_any_element(),
-                );
-
-                // Median line
-                elements.push(
-                    div()
-                        .absolute()
-                        .left(px(x_px - half_width))
-                        .top(px(q2_px - self.stroke_width))
-                        .w(px(self.box_width))
-                        .h(px(self.stroke_width * 2.0))
-                        .bg(median_color)
-                        .into_any_element(),
-                );
-
-                // Outliers
-                for &outlier in &stats.outliers_low {
-                    let y_px = y_scale.scale(outlier) as f32;
-                    elements.push(
-                        div()
-                            .absolute()
-                            .left(px(x_px - self.outlier_radius))
-                            .top(px(y_px - self.outlier_radius))
-                            .w(px(self.outlier_radius * 2.0))
-                            .h(px(self.outlier_radius * 2.0))
-                            .rounded_full()
-                            .bg(outlier_color)
-                            .opacity(0.7)
-                            .into_any_element(),
-                    );
-                }
-
-                for &outlier in &stats.outliers_high {
-                    let y_px = y_scale.scale(outlier) as f32;
-                    elements.push(
-                        div()
-                            .absolute()
-                            .left(px(x_px - self.outlier_radius))
-                            .top(px(y_px - self.outlier_radius))
-                            .w(px(self.outlier_radius * 2.0))
-                            .h(px(self.outlier_radius * 2.0))
-                            .rounded_full()
-                            .bg(outlier_color)
-                            .opacity(0.7)
-                            .into_any_element(),
-                    );
-                }
-
-                elements
-            })
+            .flat_map(|stats| box_glyph(stats, x_scale.scale(stats.x) as f32, y_scale, &style))
             .collect();
 
         div()
@@ -540,7 +621,7 @@ impl BoxPlotChart {
                             .w(px(plot_width as f32))
                             .h(px(plot_height as f32))
                             .relative()
-                            .bg(rgb(0xf8f8f8))
+                            .bg(theme.background)
                             .child(render_grid(
                                 x_scale,
                                 y_scale,
@@ -584,6 +665,7 @@ impl BoxPlotChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn boxplot(x: &[f64], y: &[f64]) -> BoxPlotChart {
+    let defaults = ChartDefaults::current();
     BoxPlotChart {
         x: x.to_vec(),
         y: y.to_vec(),
@@ -597,10 +679,12 @@ pub fn boxplot(x: &[f64], y: &[f64]) -> BoxPlotChart {
         stroke_width: 2.0,
         outlier_radius: 3.0,
         num_bins: None,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        width: defaults.width,
+        height: defaults.height,
         x_scale_type: ScaleType::Linear,
         y_scale_type: ScaleType::Linear,
+        boxes: None,
+        layout: ChartLayout::default(),
     }
 }
 
@@ -675,6 +759,32 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_boxplot_bin_count() {
+        let chart = boxplot(&[1.0], &[1.0]);
+        assert_eq!(chart.bin_count(400.0), 10);
+        assert_eq!(chart.bin_count(50.0), 3);
+        assert_eq!(chart.bins(7).bin_count(400.0), 7);
+    }
+
+    #[test]
+    fn test_boxplot_precomputed_boxes() {
+        let x: Vec<f64> = (0..100).map(|i| (i / 10) as f64).collect();
+        let y: Vec<f64> = x.iter().map(|&xi| xi * 2.0).collect();
+        let (x_min, x_max) = extent_padded(&x, DEFAULT_PADDING_FRACTION);
+        let boxes = calculate_boxes(&x, &y, x_min, x_max, 10);
+        assert_eq!(boxes.len(), 10);
+
+        let mut chart = boxplot(&x, &y).bins(10);
+        chart.boxes = Some((10, boxes.clone()));
+        assert!(chart.build().is_ok());
+
+        // Boxes of another bin count are recomputed
+        let mut chart = boxplot(&x, &y).bins(5);
+        chart.boxes = Some((10, boxes));
+        assert!(chart.build().is_ok());
+    }
+
     #[test]
     fn test_boxplot_builder_chain() {
         let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
@@ -0,0 +1,252 @@
+//! Ordering of bar chart categories.
+//!
+//! Bars are drawn in input order unless sorted with `.sort(...)`. The
+//! smallest categories can be merged into one with `.top_n(...)`, and a
+//! [`CategoryOrder`] keeps the order of the first build while the data
+//! updates, so bars don't jump around during animations:
+//!
+//! ```rust,ignore
+//! // Created once, kept in the view
+//! let order = CategoryOrder::new();
+//!
+//! // On every frame
+//! let chart = bar(&browsers, &shares)
+//!     .sort(SortOrder::ByValueDesc)
+//!     .top_n(5, "Other")
+//!     .category_order(&order)
+//!     .build()?;
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Order of the categories of a bar chart.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum SortOrder {
+    /// Input order (default)
+    #[default]
+    Input,
+    /// Smallest total value first
+    ByValueAsc,
+    /// Largest total value first
+    ByValueDesc,
+    /// Alphabetical by category label
+    ByLabel,
+    /// Listed categories first, in this order, then the others in input order
+    Manual(Vec<String>),
+}
+
+/// Category order remembered across builds.
+///
+/// Clones share the same order. Categories seen for the first time are
+/// added after the known ones.
+#[derive(Debug, Clone, Default)]
+pub struct CategoryOrder {
+    order: Rc<RefCell<Vec<String>>>,
+}
+
+impl CategoryOrder {
+    /// Create an empty order, filled by the first chart built with it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the order, so the next build sets it again.
+    pub fn reset(&self) {
+        self.order.borrow_mut().clear();
+    }
+
+    /// Categories in their remembered order.
+    pub fn order(&self) -> Vec<String> {
+        self.order.borrow().clone()
+    }
+
+    /// Positions of `categories` in the remembered order, adding unknown
+    /// ones at the end.
+    fn positions(&self, categories: &[String]) -> Vec<usize> {
+        let mut order = self.order.borrow_mut();
+        categories
+            .iter()
+            .map(|category| match order.iter().position(|c| c == category) {
+                Some(position) => position,
+                None => {
+                    order.push(category.clone());
+                    order.len() - 1
+                }
+            })
+            .collect()
+    }
+}
+
+/// Categories and the values of every series after ordering.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ArrangedCategories {
+    pub(crate) categories: Vec<String>,
+    /// One vector per series, aligned with `categories`
+    pub(crate) values: Vec<Vec<f64>>,
+}
+
+/// Sort the categories, merge all but the `top_n` largest into one, then
+/// apply the remembered order.
+///
+/// `values` holds one vector per series, each as long as `categories`.
+pub(crate) fn arrange(
+    categories: &[String],
+    values: &[Vec<f64>],
+    sort: &SortOrder,
+    top_n: Option<(usize, &str)>,
+    remembered: Option<&CategoryOrder>,
+) -> ArrangedCategories {
+    let total = |i: usize| -> f64 { values.iter().map(|series| series[i]).sum() };
+    let mut indices: Vec<usize> = (0..categories.len()).collect();
+    match sort {
+        SortOrder::Input => {}
+        SortOrder::ByValueAsc => indices.sort_by(|&a, &b| total(a).total_cmp(&total(b))),
+        SortOrder::ByValueDesc => indices.sort_by(|&a, &b| total(b).total_cmp(&total(a))),
+        SortOrder::ByLabel => indices.sort_by(|&a, &b| categories[a].cmp(&categories[b])),
+        SortOrder::Manual(order) => indices.sort_by_key(|&i| {
+            order
+                .iter()
+                .position(|c| *c == categories[i])
+                .unwrap_or(order.len())
+        }),
+    }
+
+    let mut arranged = ArrangedCategories {
+        categories: indices.iter().map(|&i| categories[i].clone()).collect(),
+        values: values
+            .iter()
+            .map(|series| indices.iter().map(|&i| series[i]).collect())
+            .collect(),
+    };
+
+    if let Some((n, other_label)) = top_n
+        && n < indices.len()
+    {
+        // Rank by total value, keeping the sorted order of the kept ones
+        let mut ranked: Vec<usize> = (0..indices.len()).collect();
+        ranked.sort_by(|&a, &b| total(indices[b]).total_cmp(&total(indices[a])));
+        let mut kept = vec![false; indices.len()];
+        for &position in &ranked[..n] {
+            kept[position] = true;
+        }
+        let mut categories: Vec<String> = Vec::with_capacity(n + 1);
+        let mut values: Vec<Vec<f64>> = vec![Vec::with_capacity(n + 1); arranged.values.len()];
+        let mut other = vec![0.0; arranged.values.len()];
+        for (position, category) in arranged.categories.iter().enumerate() {
+            for (series, series_values) in arranged.values.iter().enumerate() {
+                if kept[position] {
+                    values[series].push(series_values[position]);
+                } else {
+                    other[series] += series_values[position];
+                }
+            }
+            if kept[position] {
+                categories.push(category.clone());
+            }
+        }
+        categories.push(other_label.to_string());
+        for (series_values, other) in values.iter_mut().zip(other) {
+            series_values.push(other);
+        }
+        arranged = ArrangedCategories { categories, values };
+    }
+
+    if let Some(remembered) = remembered {
+        let positions = remembered.positions(&arranged.categories);
+        let mut order: Vec<usize> = (0..positions.len()).collect();
+        order.sort_by_key(|&i| positions[i]);
+        arranged = ArrangedCategories {
+            categories: order
+                .iter()
+                .map(|&i| arranged.categories[i].clone())
+                .collect(),
+            values: arranged
+                .values
+                .iter()
+                .map(|series| order.iter().map(|&i| series[i]).collect())
+                .collect(),
+        };
+    }
+
+    arranged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_arrange_sort_orders() {
+        let categories = labels(&["b", "c", "a"]);
+        let values = vec![vec![2.0, 3.0, 1.0], vec![0.0, -3.0, 0.0]];
+
+        let desc = arrange(&categories, &values, &SortOrder::ByValueDesc, None, None);
+        // Totals: b = 2, c = 0, a = 1
+        assert_eq!(desc.categories, labels(&["b", "a", "c"]));
+        assert_eq!(desc.values[1], vec![0.0, 0.0, -3.0]);
+
+        let by_label = arrange(&categories, &values, &SortOrder::ByLabel, None, None);
+        assert_eq!(by_label.categories, labels(&["a", "b", "c"]));
+
+        let manual = SortOrder::Manual(labels(&["c"]));
+        let manual = arrange(&categories, &values, &manual, None, None);
+        assert_eq!(manual.categories, labels(&["c", "b", "a"]));
+    }
+
+    #[test]
+    fn test_arrange_top_n_merges_rest() {
+        let categories = labels(&["a", "b", "c", "d"]);
+        let values = vec![vec![1.0, 4.0, 2.0, 3.0]];
+        let top = arrange(
+            &categories,
+            &values,
+            &SortOrder::Input,
+            Some((2, "Other")),
+            None,
+        );
+        assert_eq!(top.categories, labels(&["b", "d", "Other"]));
+        assert_eq!(top.values[0], vec![4.0, 3.0, 3.0]);
+
+        let all = arrange(
+            &categories,
+            &values,
+            &SortOrder::Input,
+            Some((4, "Other")),
+            None,
+        );
+        assert_eq!(all.categories, categories);
+    }
+
+    #[test]
+    fn test_arrange_remembered_order_is_stable() {
+        let order = CategoryOrder::new();
+        let sort = SortOrder::ByValueDesc;
+        let first = arrange(
+            &labels(&["a", "b"]),
+            &[vec![1.0, 2.0]],
+            &sort,
+            None,
+            Some(&order),
+        );
+        assert_eq!(first.categories, labels(&["b", "a"]));
+
+        // "a" overtakes "b", and "c" appears: the known order is kept
+        let next = arrange(
+            &labels(&["a", "b", "c"]),
+            &[vec![5.0, 2.0, 9.0]],
+            &sort,
+            None,
+            Some(&order),
+        );
+        assert_eq!(next.categories, labels(&["b", "a", "c"]));
+        assert_eq!(next.values[0], vec![2.0, 5.0, 9.0]);
+
+        order.reset();
+        assert!(order.order().is_empty());
+    }
+}
@@ -0,0 +1,690 @@
+//! Circle packing chart - Plotly Express style API.
+//!
+//! Enclosure diagram for hierarchical data: leaves are circles sized by value,
+//! nested inside circles for their parent groups.
+
+use crate::color_scale::ColorScale;
+use crate::error::ChartError;
+use crate::interaction::PlotBoundsCapture;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::treemap::{
+    BREADCRUMB_HEIGHT, TreemapNode, TreemapZoomCallback, node_at_path, render_breadcrumb,
+    truncate_label,
+};
+use crate::{
+    ChartDefaults, DEFAULT_TITLE_FONT_SIZE, TITLE_AREA_HEIGHT, validate_dimensions,
+    validate_plot_area,
+};
+use d3rs::color::D3Color;
+use d3rs::hierarchy::{HierarchyNode, PackLayout};
+use d3rs::text::{VectorFontConfig, render_vector_text};
+use gpui::prelude::*;
+use gpui::{
+    Animation, AnimationExt, App, Div, ElementId, IntoElement, MouseButton, Pixels, Rgba, Window,
+    div, ease_in_out, hsla, px, rgb,
+};
+use std::cell::RefCell;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Callback type for hover events (receives the hovered node path, if any)
+pub type CirclePackHoverCallback = Rc<dyn Fn(Option<&[usize]>, &mut Window, &mut App)>;
+
+/// A circle in the computed pack layout, in layout coordinates.
+#[derive(Debug, Clone)]
+struct PackedCircle {
+    x: f64,
+    y: f64,
+    r: f64,
+    name: String,
+    value: f64,
+    depth: usize,
+    /// Child indices leading from the root to this node
+    path: Vec<usize>,
+    is_leaf: bool,
+}
+
+/// Viewport focused on a circle: (center x, center y, radius).
+type PackView = (f64, f64, f64);
+
+/// Circle packing chart builder.
+#[derive(Clone)]
+pub struct CirclePack {
+    root: TreemapNode,
+    title: Option<String>,
+    padding: f64,
+    width: f32,
+    height: f32,
+    color_scale: ColorScale,
+    leaf_color: u32,
+    min_label_radius: f32,
+    zoom_path: Vec<usize>,
+    on_zoom: Option<TreemapZoomCallback>,
+    zoom_duration: Duration,
+    hover_path: Option<Vec<usize>>,
+    on_hover: Option<CirclePackHoverCallback>,
+    layout: ChartLayout,
+}
+
+impl std::fmt::Debug for CirclePack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CirclePack")
+            .field("root", &self.root)
+            .field("title", &self.title)
+            .field("padding", &self.padding)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("zoom_path", &self.zoom_path)
+            .field("hover_path", &self.hover_path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CirclePack {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
+    /// Set the chart title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the padding between sibling circles in pixels (default: 3.0).
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the chart size in pixels.
+    ///
+    /// Default: 600 x 400
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set the color scale for group circles, sampled by depth.
+    pub fn color_scale(mut self, scale: ColorScale) -> Self {
+        self.color_scale = scale;
+        self
+    }
+
+    /// Set the fill color for leaf circles as 24-bit RGB hex (default: white).
+    pub fn leaf_color(mut self, hex: u32) -> Self {
+        self.leaf_color = hex;
+        self
+    }
+
+    /// Set the minimum on-screen radius for a circle to get a label (default: 20.0).
+    pub fn min_label_radius(mut self, radius: f32) -> Self {
+        self.min_label_radius = radius;
+        self
+    }
+
+    /// Set the node to zoom into, as child indices from the root.
+    ///
+    /// An empty path (default) shows the whole hierarchy.
+    pub fn zoom_path(mut self, path: &[usize]) -> Self {
+        self.zoom_path = path.to_vec();
+        self
+    }
+
+    /// Enable click-to-zoom with a callback receiving the new zoom path.
+    ///
+    /// Clicking a circle requests a zoom into the group containing it, and
+    /// clicking the background of the focused circle requests a zoom out.
+    /// A breadcrumb bar above the plot also zooms back out.
+    pub fn on_zoom<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&[usize], &mut Window, &mut App) + 'static,
+    {
+        self.on_zoom = Some(Rc::new(callback));
+        self
+    }
+
+    /// Set the duration of the zoom animation (default: 300ms).
+    ///
+    /// A zero duration disables the animation.
+    pub fn zoom_duration(mut self, duration: Duration) -> Self {
+        self.zoom_duration = duration;
+        self
+    }
+
+    /// Set the node to highlight with an outline and value tooltip.
+    pub fn hover_path(mut self, path: Option<&[usize]>) -> Self {
+        self.hover_path = path.map(<[usize]>::to_vec);
+        self
+    }
+
+    /// Set a callback for hover changes.
+    ///
+    /// Receives the path of the innermost circle under the mouse, or `None`
+    /// when the mouse leaves the plot. Feed it back through `hover_path` to
+    /// show the highlight and tooltip.
+    pub fn on_hover<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Option<&[usize]>, &mut Window, &mut App) + 'static,
+    {
+        self.on_hover = Some(Rc::new(callback));
+        self
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        validate_dimensions(self.width, self.height)?;
+
+        if node_at_path(&self.root, &self.zoom_path).is_none() {
+            return Err(ChartError::InvalidData {
+                field: "zoom_path",
+                reason: "does not reference a node in the hierarchy",
+            });
+        }
+        if self.root.total_value() <= 0.0 {
+            return Err(ChartError::InvalidData {
+                field: "root",
+                reason: "Total value must be positive",
+            });
+        }
+
+        let title_height = if self.title.is_some() {
+            TITLE_AREA_HEIGHT
+        } else {
+            0.0
+        };
+        let breadcrumb_height = if self.on_zoom.is_some() {
+            BREADCRUMB_HEIGHT
+        } else {
+            0.0
+        };
+        let margin = 10.0;
+        let plot_width = (self.width - 2.0 * margin).max(0.0);
+        let plot_height = (self.height - title_height - breadcrumb_height - 2.0 * margin).max(0.0);
+        validate_plot_area(plot_width.into(), plot_height.into())?;
+
+        let circles = Rc::new(pack_circles(
+            &self.root,
+            plot_width as f64,
+            plot_height as f64,
+            self.padding,
+        ));
+        let view_of = |path: &[usize]| {
+            circles
+                .iter()
+                .find(|c| c.path == path)
+                .map(|c| (c.x, c.y, c.r))
+        };
+        let Some(focus_view) = view_of(&self.zoom_path) else {
+            // Zero-valued subtrees are not laid out
+            return Err(ChartError::InvalidData {
+                field: "zoom_path",
+                reason: "references a node with no value",
+            });
+        };
+
+        // Depth-based fill colors
+        let max_depth = circles.iter().map(|c| c.depth).max().unwrap_or(0).max(1);
+        let leaf_rgba = D3Color::from_hex(self.leaf_color).to_rgba();
+        let fills: Rc<Vec<Rgba>> = Rc::new(
+            circles
+                .iter()
+                .map(|c| {
+                    if c.is_leaf && c.depth > 0 {
+                        leaf_rgba
+                    } else {
+                        self.color_scale
+                            .map(c.depth as f64 / max_depth as f64)
+                            .to_rgba()
+                    }
+                })
+                .collect(),
+        );
+
+        let size = (plot_width as f64, plot_height as f64);
+        let focus_depth = self.zoom_path.len();
+        let min_label_radius = self.min_label_radius;
+
+        let mut plot_area = div()
+            .id("circle-pack-plot")
+            .w(px(plot_width))
+            .h(px(plot_height))
+            .relative()
+            .overflow_hidden();
+
+        // When zooming in, animate from the parent's view to the focused circle
+        let parent_view = self
+            .zoom_path
+            .split_last()
+            .and_then(|(_, parent)| view_of(parent));
+        match parent_view {
+            Some(from) if !self.zoom_duration.is_zero() => {
+                let mut hasher = DefaultHasher::new();
+                self.zoom_path.hash(&mut hasher);
+                let circles = circles.clone();
+                let fills = fills.clone();
+                plot_area = plot_area.child(div().absolute().size_full().with_animation(
+                    ElementId::NamedInteger("circle-pack-zoom".into(), hasher.finish()),
+                    Animation::new(self.zoom_duration).with_easing(ease_in_out),
+                    move |el, t| {
+                        let t = t as f64;
+                        let view = (
+                            from.0 + (focus_view.0 - from.0) * t,
+                            from.1 + (focus_view.1 - from.1) * t,
+                            from.2 + (focus_view.2 - from.2) * t,
+                        );
+                        el.children(render_circles(
+                            &circles,
+                            &fills,
+                            view,
+                            size,
+                            focus_depth,
+                            min_label_radius,
+                        ))
+                    },
+                ));
+            }
+            _ => {
+                plot_area = plot_area.child(div().absolute().size_full().children(render_circles(
+                    &circles,
+                    &fills,
+                    focus_view,
+                    size,
+                    focus_depth,
+                    min_label_radius,
+                )));
+            }
+        }
+
+        // Hover outline and tooltip
+        if let Some(hovered) = self
+            .hover_path
+            .as_ref()
+            .and_then(|path| circles.iter().find(|c| &c.path == path))
+        {
+            let (x, y, r) = to_screen(hovered, focus_view, size);
+            let tooltip = div()
+                .absolute()
+                .left(px(x))
+                .top(px((y - r - 28.0).max(2.0)))
+                .px_2()
+                .py_1()
+                .bg(hsla(0.0, 0.0, 0.2, 0.85))
+                .rounded_md()
+                .text_xs()
+                .text_color(hsla(0.0, 0.0, 1.0, 1.0))
+                .child(format!("{}: {:.2}", hovered.name, hovered.value));
+            plot_area = plot_area
+                .child(
+                    div()
+                        .absolute()
+                        .left(px(x - r))
+                        .top(px(y - r))
+                        .w(px(2.0 * r))
+                        .h(px(2.0 * r))
+                        .rounded_full()
+                        .border_2()
+                        .border_color(hsla(0.0, 0.0, 0.1, 0.9)),
+                )
+                .child(tooltip);
+        }
+
+        let plot_bounds = PlotBoundsCapture::new();
+        plot_area = plot_area.child(plot_bounds.canvas());
+
+        let hit_test = {
+            let circles = circles.clone();
+            move |position: gpui::Point<Pixels>| {
+                if !plot_bounds.contains(position) {
+                    return None;
+                }
+                let (local_x, local_y) = plot_bounds.to_local(position)?;
+                let k = focus_view.2 / (size.0.min(size.1) / 2.0).max(1e-9);
+                let x = focus_view.0 + (local_x - size.0 / 2.0) * k;
+                let y = focus_view.1 + (local_y - size.1 / 2.0) * k;
+                innermost_at(&circles, x, y)
+            }
+        };
+        let hit_test = Rc::new(hit_test);
+
+        if let Some(on_hover) = self.on_hover.clone() {
+            let hit_test = hit_test.clone();
+            let on_leave = on_hover.clone();
+            let current = Rc::new(RefCell::new(self.hover_path.clone()));
+            plot_area = plot_area
+                .on_mouse_move(move |event, window, cx| {
+                    let path = hit_test(event.position);
+                    if *current.borrow() != path {
+                        *current.borrow_mut() = path.clone();
+                        on_hover(path.as_deref(), window, cx);
+                    }
+                })
+                .on_hover(move |hovered, window, cx| {
+                    if !*hovered {
+                        on_leave(None, window, cx);
+                    }
+                });
+        }
+
+        if let Some(on_zoom) = self.on_zoom.clone() {
+            let zoom_path = self.zoom_path.clone();
+            let root = self.root.clone();
+            plot_area = plot_area.on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                let Some(path) = hit_test(event.position) else {
+                    return;
+                };
+                if let Some(target) = zoom_target(&root, &zoom_path, &path) {
+                    on_zoom(&target, window, cx);
+                }
+            });
+        }
+
+        // Build container
+        let mut container = div()
+            .w(px(self.width))
+            .h(px(self.height))
+            .flex()
+            .flex_col()
+            .bg(rgb(0xffffff));
+
+        if let Some(title) = &self.title {
+            let font_config =
+                VectorFontConfig::horizontal(DEFAULT_TITLE_FONT_SIZE, hsla(0.0, 0.0, 0.2, 1.0));
+            container = container.child(
+                div()
+                    .w_full()
+                    .h(px(title_height))
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .child(render_vector_text(title, &font_config)),
+            );
+        }
+
+        if let Some(on_zoom) = self.on_zoom {
+            container = container.child(
+                render_breadcrumb(&self.root, &self.zoom_path, on_zoom)
+                    .h(px(breadcrumb_height))
+                    .px(px(margin)),
+            );
+        }
+
+        container = container.child(
+            div()
+                .flex()
+                .justify_center()
+                .items_center()
+                .flex_1()
+                .child(plot_area),
+        );
+
+        Ok(container)
+    }
+}
+
+/// Create a circle packing chart from hierarchical data.
+///
+/// # Arguments
+/// * `root` - Root node of the hierarchy
+///
+/// # Example
+/// ```ignore
+/// let root = TreemapNode::new("Sales", 0.0)
+///     .add_child(TreemapNode::new("East", 45.0))
+///     .add_child(TreemapNode::new("West", 55.0));
+///
+/// let chart = circle_pack(&root)
+///     .title("Regional Sales")
+///     .build()
+///     .unwrap();
+/// ```
+pub fn circle_pack(root: &TreemapNode) -> CirclePack {
+    let defaults = ChartDefaults::current();
+    CirclePack {
+        root: root.clone(),
+        title: None,
+        padding: 3.0,
+        width: defaults.width,
+        height: defaults.height,
+        color_scale: ColorScale::custom(|t| {
+            D3Color::from_hex(0xc7e9c0).interpolate(&D3Color::from_hex(0x3a4a7a), t as f32)
+        }),
+        leaf_color: 0xffffff,
+        min_label_radius: 20.0,
+        zoom_path: Vec::new(),
+        on_zoom: None,
+        zoom_duration: Duration::from_millis(300),
+        hover_path: None,
+        on_hover: None,
+        layout: ChartLayout::default(),
+    }
+}
+
+/// Lay out the hierarchy with `PackLayout`, returning circles in pre-order.
+fn pack_circles(root: &TreemapNode, width: f64, height: f64, padding: f64) -> Vec<PackedCircle> {
+    type PackData = (String, f64, Vec<usize>);
+
+    fn convert(node: &TreemapNode, path: &mut Vec<usize>) -> Rc<RefCell<HierarchyNode<PackData>>> {
+        let h = HierarchyNode::new((node.name.clone(), node.value, path.clone()));
+        if !node.is_leaf() {
+            let children = node
+                .children
+                .iter()
+                .enumerate()
+                .map(|(i, child)| {
+                    path.push(i);
+                    let c = convert(child, path);
+                    path.pop();
+                    c
+                })
+                .collect();
+            h.borrow_mut().set_children(&h, children);
+        }
+        h
+    }
+
+    let root = convert(root, &mut Vec::new());
+    HierarchyNode::sum(root.clone(), |(_, value, _)| value.max(0.0));
+    PackLayout::new()
+        .size((width, height))
+        .padding(padding)
+        .layout(root.clone());
+
+    let mut circles = Vec::new();
+    HierarchyNode::each(root, |node| {
+        let n = node.borrow();
+        let (name, _, path) = &n.data;
+        let value = n.value.unwrap_or(0.0);
+        if value > 0.0 {
+            circles.push(PackedCircle {
+                x: n.x,
+                y: n.y,
+                r: n.r,
+                name: name.clone(),
+                value,
+                depth: path.len(),
+                path: path.clone(),
+                is_leaf: n.children.as_ref().is_none_or(|c| c.is_empty()),
+            });
+        }
+    });
+    circles
+}
+
+/// Map a circle to screen coordinates for a view centered on `view`.
+fn to_screen(circle: &PackedCircle, view: PackView, size: (f64, f64)) -> (f32, f32, f32) {
+    let k = size.0.min(size.1) / 2.0 / view.2.max(1e-9);
+    (
+        ((circle.x - view.0) * k + size.0 / 2.0) as f32,
+        ((circle.y - view.1) * k + size.1 / 2.0) as f32,
+        (circle.r * k) as f32,
+    )
+}
+
+/// Path of the deepest circle containing the layout point (x, y).
+fn innermost_at(circles: &[PackedCircle], x: f64, y: f64) -> Option<Vec<usize>> {
+    circles
+        .iter()
+        .filter(|c| (c.x - x).powi(2) + (c.y - y).powi(2) <= c.r * c.r)
+        .max_by_key(|c| c.depth)
+        .map(|c| c.path.clone())
+}
+
+/// Zoom path requested by clicking the circle at `clicked`.
+///
+/// Clicking inside a group below the focus zooms into the focus child that
+/// contains it; clicking the focus itself zooms out one level.
+fn zoom_target(root: &TreemapNode, zoom_path: &[usize], clicked: &[usize]) -> Option<Vec<usize>> {
+    if clicked.len() <= zoom_path.len() {
+        return (!zoom_path.is_empty()).then(|| zoom_path[..zoom_path.len() - 1].to_vec());
+    }
+    if !clicked.starts_with(zoom_path) {
+        return None;
+    }
+    let target = clicked[..zoom_path.len() + 1].to_vec();
+    node_at_path(root, &target)
+        .filter(|node| !node.is_leaf())
+        .map(|_| target)
+}
+
+/// Render circles and labels for the given view.
+fn render_circles(
+    circles: &[PackedCircle],
+    fills: &[Rgba],
+    view: PackView,
+    size: (f64, f64),
+    focus_depth: usize,
+    min_label_radius: f32,
+) -> Vec<Div> {
+    let mut elements = Vec::with_capacity(circles.len());
+    let mut labels = Vec::new();
+
+    for (circle, &fill) in circles.iter().zip(fills) {
+        let (x, y, r) = to_screen(circle, view, size);
+        if r < 0.5 {
+            continue;
+        }
+        let border = Rgba {
+            r: fill.r * 0.7,
+            g: fill.g * 0.7,
+            b: fill.b * 0.7,
+            a: 1.0,
+        };
+        elements.push(
+            div()
+                .absolute()
+                .left(px(x - r))
+                .top(px(y - r))
+                .w(px(2.0 * r))
+                .h(px(2.0 * r))
+                .rounded_full()
+                .bg(fill)
+                .border_1()
+                .border_color(border),
+        );
+
+        // Label the children of the focused node when they are large enough
+        if circle.depth == focus_depth + 1 && r >= min_label_radius {
+            let font_size = (r * 0.25).clamp(8.0, 12.0);
+            if let Some(label) = truncate_label(&circle.name, 1.6 * r, font_size) {
+                let font_config = VectorFontConfig::horizontal(font_size, hsla(0.0, 0.0, 0.1, 1.0));
+                labels.push(
+                    div()
+                        .absolute()
+                        .left(px(x - r))
+                        .top(px(y - font_size))
+                        .w(px(2.0 * r))
+                        .h(px(2.0 * font_size))
+                        .flex()
+                        .justify_center()
+                        .items_center()
+                        .child(render_vector_text(&label, &font_config)),
+                );
+            }
+        }
+    }
+
+    // Labels are drawn above all circles
+    elements.extend(labels);
+    elements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TreemapNode {
+        TreemapNode::new("Root", 0.0)
+            .add_child(
+                TreemapNode::new("A", 0.0)
+                    .add_child(TreemapNode::new("A1", 10.0))
+                    .add_child(TreemapNode::new("A2", 20.0)),
+            )
+            .add_child(TreemapNode::new("B", 30.0))
+    }
+
+    #[test]
+    fn test_circle_pack_build() {
+        let result = circle_pack(&sample())
+            .title("Packed")
+            .padding(2.0)
+            .size(400.0, 400.0)
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_circle_pack_zoom_and_hover() {
+        let result = circle_pack(&sample())
+            .zoom_path(&[0])
+            .on_zoom(|_path, _window, _cx| {})
+            .hover_path(Some(&[0, 1]))
+            .on_hover(|_path, _window, _cx| {})
+            .build();
+        assert!(result.is_ok());
+
+        let result = circle_pack(&sample()).zoom_path(&[3]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData {
+                field: "zoom_path",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_circle_pack_invalid() {
+        let result = circle_pack(&TreemapNode::new("Empty", 0.0)).build();
+        assert!(matches!(result, Err(ChartError::InvalidData { .. })));
+
+        let result = circle_pack(&sample()).size(0.0, 100.0).build();
+        assert!(matches!(result, Err(ChartError::InvalidDimension { .. })));
+    }
+
+    #[test]
+    fn test_pack_circles_nesting() {
+        let circles = pack_circles(&sample(), 200.0, 200.0, 2.0);
+        assert_eq!(circles.len(), 5);
+        assert_eq!(circles[0].path, Vec::<usize>::new());
+        assert!((circles[0].r - 100.0).abs() < 1e-6);
+
+        let a = circles.iter().find(|c| c.name == "A").unwrap();
+        let a1 = circles.iter().find(|c| c.name == "A1").unwrap();
+        assert_eq!(a1.path, vec![0, 0]);
+        assert_eq!(a1.depth, 2);
+        let d = ((a1.x - a.x).powi(2) + (a1.y - a.y).powi(2)).sqrt();
+        assert!(d + a1.r <= a.r + 1e-6);
+
+        assert_eq!(innermost_at(&circles, a1.x, a1.y), Some(vec![0, 0]));
+    }
+
+    #[test]
+    fn test_zoom_target() {
+        let root = sample();
+        assert_eq!(zoom_target(&root, &[], &[0, 1]), Some(vec![0]));
+        assert_eq!(zoom_target(&root, &[], &[1]), None);
+        assert_eq!(zoom_target(&root, &[0], &[0]), Some(vec![]));
+        assert_eq!(zoom_target(&root, &[], &[]), None);
+    }
+}
@@ -0,0 +1,98 @@
+//! Clipboard export for charts.
+//!
+//! Charts implement [`CopyToClipboard`] from `gpui-ui-kit`: the text format
+//! is the underlying data as tab-separated values, and with the `gpu-2d`
+//! feature the image format is a PNG of the chart marks.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use gpui_px::{ClipboardFormat, CopyToClipboard, line};
+//!
+//! let chart = line(&x, &y).label("response");
+//! chart.copy_to_clipboard(ClipboardFormat::Text, cx);
+//! ```
+
+use gpui_ui_kit::clipboard::to_tsv;
+
+pub use gpui_ui_kit::clipboard::{ClipboardFormat, CopyToClipboard};
+
+/// Format x/y series in long format: one `series, x, y` row per point.
+pub(crate) fn xy_tsv(series: &[(String, &[f64], &[f64])]) -> String {
+    let header = vec!["series".to_string(), "x".to_string(), "y".to_string()];
+    let rows = series.iter().flat_map(|(label, xs, ys)| {
+        xs.iter()
+            .zip(ys.iter())
+            .map(move |(x, y)| vec![label.clone(), x.to_string(), y.to_string()])
+    });
+    to_tsv(std::iter::once(header).chain(rows))
+}
+
+/// Format categorical series in wide format: one row per category.
+pub(crate) fn category_tsv(categories: &[String], series: &[(String, &[f64])]) -> String {
+    let header = std::iter::once("category".to_string())
+        .chain(series.iter().map(|(label, _)| label.clone()))
+        .collect::<Vec<_>>();
+    let rows = categories.iter().enumerate().map(|(i, category)| {
+        std::iter::once(category.clone())
+            .chain(
+                series
+                    .iter()
+                    .map(|(_, values)| values.get(i).map(f64::to_string).unwrap_or_default()),
+            )
+            .collect::<Vec<_>>()
+    });
+    to_tsv(std::iter::once(header).chain(rows))
+}
+
+/// Render a chart's marks offscreen at `width` x `height` and encode as PNG.
+///
+/// Returns `None` when the chart data is invalid.
+#[cfg(feature = "gpu-2d")]
+pub(crate) fn chart_png<C: crate::offscreen::TextureChart>(
+    chart: &C,
+    width: f32,
+    height: f32,
+) -> Option<Vec<u8>> {
+    chart.validate().ok()?;
+    let (w, h) = (width.round(), height.round());
+    d3rs::gpu2d::ChartTexture::new()
+        .background_color([1.0, 1.0, 1.0, 1.0])
+        .render_png(w as u32, h as u32, |renderer| {
+            chart.draw_marks(renderer, w, h)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bar, line};
+
+    #[test]
+    fn test_xy_tsv() {
+        let tsv = xy_tsv(&[("a".to_string(), &[0.0, 1.0], &[2.0, 3.5])]);
+        assert_eq!(tsv, "series\tx\ty\na\t0\t2\na\t1\t3.5\n");
+    }
+
+    #[test]
+    fn test_category_tsv_pads_short_series() {
+        let categories = ["a".to_string(), "b".to_string()];
+        let tsv = category_tsv(
+            &categories,
+            &[("s1".to_string(), &[1.0, 2.0]), ("s2".to_string(), &[3.0])],
+        );
+        assert_eq!(tsv, "category\ts1\ts2\na\t1\t3\nb\t2\t\n");
+    }
+
+    #[test]
+    fn test_chart_clipboard_text() {
+        let text = line(&[0.0, 1.0], &[1.0, 2.0])
+            .label("resp")
+            .clipboard_text()
+            .unwrap();
+        assert_eq!(text, "series\tx\ty\nresp\t0\t1\nresp\t1\t2\n");
+
+        let text = bar(&["a", "b"], &[1.0, 2.0]).clipboard_text().unwrap();
+        assert_eq!(text, "category\tSeries 1\na\t1\nb\t2\n");
+    }
+}
@@ -23,6 +23,17 @@ pub enum ColorScale {
     Greys,
     /// Custom color scale function.
     Custom(Arc<dyn Fn(f64) -> D3Color + Send + Sync>),
+    /// A scale with a fixed midpoint and/or discrete classes.
+    ///
+    /// Built with [`ColorScale::center`] and [`ColorScale::classes`].
+    Adjusted {
+        /// Scale the colors come from.
+        base: Box<ColorScale>,
+        /// Data value mapped to the middle of the scale.
+        center: Option<f64>,
+        /// Number of discrete color bands.
+        classes: Option<usize>,
+    },
 }
 
 impl std::fmt::Debug for ColorScale {
@@ -36,6 +47,20 @@ impl std::fmt::Debug for ColorScale {
             ColorScale::Coolwarm => write!(f, "ColorScale::Coolwarm"),
             ColorScale::Greys => write!(f, "ColorScale::Greys"),
             ColorScale::Custom(_) => write!(f, "ColorScale::Custom(...)"),
+            ColorScale::Adjusted {
+                base,
+                center,
+                classes,
+            } => {
+                write!(f, "{:?}", base)?;
+                if let Some(center) = center {
+                    write!(f, ".center({:?})", center)?;
+                }
+                if let Some(classes) = classes {
+                    write!(f, ".classes({})", classes)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -51,6 +76,99 @@ impl ColorScale {
         ColorScale::Custom(Arc::new(f))
     }
 
+    /// Map `value` to the middle of the scale, whatever the data range.
+    ///
+    /// Meant for diverging scales: with `ColorScale::Coolwarm.center(0.0)`,
+    /// 0 dB is always the neutral color and the domain extends equally on
+    /// both sides, so equal distances from 0 get equally strong colors.
+    pub fn center(self, value: f64) -> Self {
+        match self {
+            ColorScale::Adjusted { base, classes, .. } => ColorScale::Adjusted {
+                base,
+                center: Some(value),
+                classes,
+            },
+            base => ColorScale::Adjusted {
+                base: Box::new(base),
+                center: Some(value),
+                classes: None,
+            },
+        }
+    }
+
+    /// Quantize the scale into `n` discrete color bands.
+    ///
+    /// Band colors are sampled evenly from the scale, first and last bands
+    /// taking its end colors. Charts draw their colorbar in matching steps.
+    pub fn classes(self, n: usize) -> Self {
+        let n = n.max(1);
+        match self {
+            ColorScale::Adjusted { base, center, .. } => ColorScale::Adjusted {
+                base,
+                center,
+                classes: Some(n),
+            },
+            base => ColorScale::Adjusted {
+                base: Box::new(base),
+                center: None,
+                classes: Some(n),
+            },
+        }
+    }
+
+    /// Midpoint set with [`ColorScale::center`].
+    pub fn center_value(&self) -> Option<f64> {
+        match self {
+            ColorScale::Adjusted { center, .. } => *center,
+            _ => None,
+        }
+    }
+
+    /// Number of bands set with [`ColorScale::classes`].
+    pub fn class_count(&self) -> Option<usize> {
+        match self {
+            ColorScale::Adjusted { classes, .. } => *classes,
+            _ => None,
+        }
+    }
+
+    /// Class of a position `t` in [0, 1], 0 being the lowest values.
+    ///
+    /// Returns `None` for a continuous scale.
+    pub fn class_of(&self, t: f64) -> Option<usize> {
+        let n = self.class_count()?;
+        Some(((t.clamp(0.0, 1.0) * n as f64) as usize).min(n - 1))
+    }
+
+    /// Data range mapped onto [0, 1] for data spanning `min..=max`.
+    ///
+    /// Without a center this is the data range itself. With one, the range
+    /// is widened to be symmetric around the center.
+    pub fn value_domain(&self, min: f64, max: f64) -> (f64, f64) {
+        match self.center_value() {
+            Some(center) => {
+                let radius = (max - center).abs().max((center - min).abs());
+                let radius = if radius > 0.0 { radius } else { 1.0 };
+                (center - radius, center + radius)
+            }
+            None => (min, max),
+        }
+    }
+
+    /// Values separating the classes over `value_domain(min, max)`.
+    ///
+    /// Returns `n + 1` values, both ends included, or `None` for a
+    /// continuous scale.
+    pub fn class_breaks(&self, min: f64, max: f64) -> Option<Vec<f64>> {
+        let n = self.class_count()?;
+        let (lo, hi) = self.value_domain(min, max);
+        Some(
+            (0..=n)
+                .map(|i| lo + (hi - lo) * i as f64 / n as f64)
+                .collect(),
+        )
+    }
+
     /// Convert to a function that maps [0, 1] → D3Color.
     pub fn to_fn(&self) -> impl Fn(f64) -> D3Color + Send + Sync + Clone + 'static {
         let scale = self.clone();
@@ -70,6 +188,20 @@ impl ColorScale {
             ColorScale::Coolwarm => coolwarm(t),
             ColorScale::Greys => greys(t),
             ColorScale::Custom(f) => f(t),
+            ColorScale::Adjusted {
+                base,
+                classes: Some(n),
+                ..
+            } => {
+                let class = ((t * *n as f64) as usize).min(n - 1);
+                let t = if *n > 1 {
+                    class as f64 / (n - 1) as f64
+                } else {
+                    0.5
+                };
+                base.map(t)
+            }
+            ColorScale::Adjusted { base, .. } => base.map(t),
         }
     }
 }
@@ -268,5 +400,67 @@ mod tests {
         assert_eq!(format!("{:?}", ColorScale::Heat), "ColorScale::Heat");
         let custom = ColorScale::custom(|_| D3Color::from_hex(0x000000));
         assert_eq!(format!("{:?}", custom), "ColorScale::Custom(...)");
+        assert_eq!(
+            format!("{:?}", ColorScale::Coolwarm.center(0.0).classes(5)),
+            "ColorScale::Coolwarm.center(0.0).classes(5)"
+        );
+    }
+
+    #[test]
+    fn test_center_makes_domain_symmetric() {
+        let scale = ColorScale::Coolwarm.center(0.0);
+        assert_eq!(scale.value_domain(-5.0, 20.0), (-20.0, 20.0));
+        assert_eq!(scale.value_domain(2.0, 10.0), (-10.0, 10.0));
+        // Without a center the data range is kept
+        assert_eq!(ColorScale::Coolwarm.value_domain(2.0, 10.0), (2.0, 10.0));
+
+        // The center lands in the middle of the scale
+        let (lo, hi) = scale.value_domain(-5.0, 20.0);
+        let t = (0.0 - lo) / (hi - lo);
+        assert_eq!(t, 0.5);
+        let mid = scale.map(t);
+        assert_eq!(mid, ColorScale::Coolwarm.map(0.5));
+    }
+
+    #[test]
+    fn test_classes_quantize() {
+        let scale = ColorScale::Viridis.classes(4);
+        assert_eq!(scale.class_count(), Some(4));
+        // Values in the same class share a color
+        assert_eq!(scale.map(0.01), scale.map(0.24));
+        assert_ne!(scale.map(0.24), scale.map(0.26));
+        // End classes take the end colors of the base scale
+        assert_eq!(scale.map(0.0), ColorScale::Viridis.map(0.0));
+        assert_eq!(scale.map(1.0), ColorScale::Viridis.map(1.0));
+
+        assert_eq!(
+            scale.class_breaks(0.0, 8.0),
+            Some(vec![0.0, 2.0, 4.0, 6.0, 8.0])
+        );
+        assert_eq!(ColorScale::Viridis.class_breaks(0.0, 8.0), None);
+    }
+
+    #[test]
+    fn test_class_of() {
+        let scale = ColorScale::Viridis.classes(4);
+        assert_eq!(scale.class_of(0.0), Some(0));
+        assert_eq!(scale.class_of(0.3), Some(1));
+        assert_eq!(scale.class_of(0.75), Some(3));
+        assert_eq!(scale.class_of(1.0), Some(3));
+        assert_eq!(ColorScale::Viridis.class_of(0.5), None);
+    }
+
+    #[test]
+    fn test_center_and_classes_combine() {
+        let scale = ColorScale::Heat.classes(3).center(0.0);
+        assert_eq!(scale.center_value(), Some(0.0));
+        assert_eq!(scale.class_count(), Some(3));
+        assert_eq!(
+            scale.class_breaks(-1.0, 3.0),
+            Some(vec![-3.0, -1.0, 1.0, 3.0])
+        );
+        // The middle class is the neutral color
+        let mid = scale.map(0.5);
+        assert!(mid.r > 0.9 && mid.g > 0.9 && mid.b > 0.9);
     }
 }
@@ -0,0 +1,140 @@
+//! Colorbar legend for heatmap and contour charts.
+//!
+//! Draws the color scale as a vertical bar with a value axis on its right.
+//! Scales quantized with [`ColorScale::classes`] get one step per class and
+//! ticks at the class breaks. Those scales can also be drawn as a clickable
+//! class legend instead, with one item per class.
+
+use crate::color_scale::ColorScale;
+use crate::theme::ChartAxisTheme;
+use d3rs::axis::{AxisConfig, render_axis};
+use d3rs::scale::LinearScale;
+use gpui::prelude::*;
+use gpui::{AnyElement, App, ElementId, MouseButton, Window, div, px};
+use std::rc::Rc;
+
+/// Width reserved right of the plot for the colorbar and its labels
+pub(crate) const COLORBAR_WIDTH: f64 = 90.0;
+
+/// Width reserved right of the plot for the class legend
+pub(crate) const CLASS_LEGEND_WIDTH: f64 = 130.0;
+
+/// Opacity of the legend items of inactive classes
+const INACTIVE_OPACITY: f32 = 0.35;
+
+/// Gap between the plot and the bar
+const BAR_GAP: f32 = 12.0;
+
+/// Width of the color bar itself
+const BAR_WIDTH: f32 = 14.0;
+
+/// Segments drawn for a continuous scale
+const CONTINUOUS_STEPS: usize = 64;
+
+/// Render the colorbar for data spanning `min..=max`.
+pub(crate) fn render_colorbar(
+    scale: &ColorScale,
+    min: f64,
+    max: f64,
+    height: f32,
+    theme: &ChartAxisTheme,
+) -> AnyElement {
+    let (lo, hi) = scale.value_domain(min, max);
+    let steps = scale.class_count().unwrap_or(CONTINUOUS_STEPS);
+    let step_height = height / steps as f32;
+
+    // Top to bottom: highest values first
+    let bar = div()
+        .w(px(BAR_WIDTH))
+        .h(px(height))
+        .flex()
+        .flex_col()
+        .children((0..steps).rev().map(|i| {
+            let t = (i as f64 + 0.5) / steps as f64;
+            div().w_full().h(px(step_height)).bg(scale.map(t).to_rgba())
+        }));
+
+    let axis_scale = LinearScale::new().domain(lo, hi).range(height as f64, 0.0);
+    let config = match scale.class_breaks(min, max) {
+        Some(breaks) => AxisConfig::right().with_tick_values(breaks),
+        None => AxisConfig::right().with_ticks(5),
+    };
+
+    div()
+        .flex()
+        .pl(px(BAR_GAP))
+        .child(bar)
+        .child(render_axis(&axis_scale, &config, height, theme))
+        .into_any_element()
+}
+
+/// Render one legend item per class of `scale`, highest values first.
+///
+/// Items of classes for which `is_active` is false are faded. `on_click`
+/// receives the class of the clicked item. Returns `None` for a continuous
+/// scale.
+pub(crate) fn render_class_legend(
+    scale: &ColorScale,
+    min: f64,
+    max: f64,
+    is_active: impl Fn(usize) -> bool,
+    on_click: Option<Rc<dyn Fn(usize, &mut Window, &mut App)>>,
+    theme: &ChartAxisTheme,
+) -> Option<AnyElement> {
+    let breaks = scale.class_breaks(min, max)?;
+    let count = breaks.len() - 1;
+    let step = (breaks[count] - breaks[0]) / count as f64;
+    let decimals = if step > 0.0 {
+        (-step.log10()).ceil().clamp(0.0, 6.0) as usize
+    } else {
+        0
+    };
+
+    let items = (0..count).rev().map(|class| {
+        let color = scale.map((class as f64 + 0.5) / count as f64).to_rgba();
+        let label = format!(
+            "{:.*} – {:.*}",
+            decimals,
+            breaks[class],
+            decimals,
+            breaks[class + 1]
+        );
+        let mut item = div()
+            .id(ElementId::NamedInteger(
+                "class-legend-item".into(),
+                class as u64,
+            ))
+            .flex()
+            .items_center()
+            .gap_2()
+            .rounded_sm()
+            .px_1()
+            .when(!is_active(class), |item| item.opacity(INACTIVE_OPACITY))
+            .child(div().w(px(BAR_WIDTH)).h(px(BAR_WIDTH)).bg(color))
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(theme.axis_label_color)
+                    .child(label),
+            );
+        if let Some(on_click) = on_click.clone() {
+            item = item
+                .cursor_pointer()
+                .hover(|style| style.bg(gpui::rgba(0x00000010)))
+                .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                    on_click(class, window, cx);
+                });
+        }
+        item
+    });
+
+    Some(
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .pl(px(BAR_GAP))
+            .children(items)
+            .into_any_element(),
+    )
+}
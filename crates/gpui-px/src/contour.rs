@@ -1,20 +1,29 @@
 //! Contour chart (filled bands) - Plotly Express style API.
 
+use crate::async_chart::AsyncChart;
 use crate::color_scale::ColorScale;
+use crate::colorbar::{COLORBAR_WIDTH, render_colorbar};
+use crate::contour_hover::{ContourHover, ContourHoverCallback, HoverGrid, hover_layer};
 use crate::error::ChartError;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::theme::ChartTheme;
+use crate::zoom::{ChartZoom, ZoomHome, build_zoomable, chart_zoom_methods};
 use crate::{
-    DEFAULT_HEIGHT, DEFAULT_TITLE_FONT_SIZE, DEFAULT_WIDTH, ScaleType, TITLE_AREA_HEIGHT,
-    extent_padded, validate_data_array, validate_dimensions, validate_grid_dimensions,
-    validate_monotonic, validate_positive,
+    ChartDefaults, DEFAULT_TITLE_FONT_SIZE, ScaleType, TITLE_AREA_HEIGHT, extent_padded,
+    validate_data_array, validate_data_with_gaps, validate_dimensions, validate_grid_dimensions,
+    validate_monotonic, validate_plot_area, validate_positive,
 };
-use d3rs::axis::{AxisConfig, DefaultAxisTheme, render_axis};
-use d3rs::contour::ContourGenerator;
+use d3rs::axis::{AxisConfig, render_axis};
+use d3rs::contour::{ComputeHandle, ContourBand, ContourGenerator, spawn_contour_bands};
 use d3rs::grid::{GridConfig, render_grid};
 use d3rs::scale::{LinearScale, LogScale};
-use d3rs::shape::{ContourConfig, render_contour_bands};
+use d3rs::shape::{
+    ContourConfig, FillPattern, HeatmapData, NoDataStyle, render_contour_bands, render_heatmap,
+};
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
-use gpui::{AnyElement, IntoElement, div, hsla, px, rgb};
+use gpui::{AnyElement, App, Entity, IntoElement, Window, div, hsla, px};
+use std::rc::Rc;
 
 /// Contour chart builder (filled bands between thresholds).
 #[derive(Clone)]
@@ -28,13 +37,24 @@ pub struct ContourChart {
     y_scale_type: ScaleType,
     thresholds: Option<Vec<f64>>,
     color_scale: ColorScale,
+    show_colorbar: bool,
+    no_data: NoDataStyle,
+    mask: Option<Vec<bool>>,
     title: Option<String>,
     opacity: f32,
+    /// Fill patterns, cycled over the bands
+    band_patterns: Vec<FillPattern>,
     width: f32,
     height: f32,
     // Axis range overrides (for zoom support)
     x_range: Option<[f64; 2]>,
     y_range: Option<[f64; 2]>,
+    /// Bands computed ahead of time (skips generation in `build`)
+    bands: Option<Vec<ContourBand>>,
+    hover: Option<ContourHover>,
+    on_hover: Option<ContourHoverCallback>,
+    layout: ChartLayout,
+    zoom: ChartZoom,
 }
 
 impl std::fmt::Debug for ContourChart {
@@ -46,15 +66,23 @@ impl std::fmt::Debug for ContourChart {
             .field("y_scale_type", &self.y_scale_type)
             .field("thresholds", &self.thresholds)
             .field("color_scale", &self.color_scale)
+            .field("show_colorbar", &self.show_colorbar)
+            .field("no_data", &self.no_data)
+            .field("masked", &self.mask.is_some())
             .field("title", &self.title)
             .field("opacity", &self.opacity)
             .field("width", &self.width)
             .field("height", &self.height)
-            .finish()
+            .field("precomputed_bands", &self.bands.as_ref().map(Vec::len))
+            .field("hover", &self.hover)
+            .finish_non_exhaustive()
     }
 }
 
 impl ContourChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+    chart_zoom_methods!(zoom);
+
     /// Set custom x axis values.
     ///
     /// Values must be strictly monotonically increasing.
@@ -106,12 +134,46 @@ impl ContourChart {
         self
     }
 
+    /// Show a colorbar right of the plot (default: false).
+    ///
+    /// Steps follow the classes of the color scale, if any.
+    pub fn colorbar(mut self, show: bool) -> Self {
+        self.show_colorbar = show;
+        self
+    }
+
+    /// Set how grid cells touching missing (NaN) z values are drawn.
+    ///
+    /// Defaults to [`NoDataStyle::Transparent`].
+    pub fn no_data(mut self, style: NoDataStyle) -> Self {
+        self.no_data = style;
+        self
+    }
+
+    /// Blank the grid points whose mask entry is `true`.
+    ///
+    /// The mask is row-major like `z` and must have the same length. Bands
+    /// stop at masked points and no-data styling is not drawn over them.
+    pub fn mask(mut self, mask: &[bool]) -> Self {
+        self.mask = Some(mask.to_vec());
+        self
+    }
+
     /// Set fill opacity (0.0 - 1.0).
     pub fn opacity(mut self, opacity: f32) -> Self {
         self.opacity = opacity.clamp(0.0, 1.0);
         self
     }
 
+    /// Overlay fill patterns on the bands, cycled from the lowest band.
+    ///
+    /// Patterns keep neighbouring levels distinguishable in grayscale
+    /// prints and for colorblind readers.
+    pub fn band_patterns(mut self, patterns: Vec<FillPattern>) -> Self {
+        self.band_patterns = patterns;
+        self
+    }
+
     /// Set chart dimensions.
     pub fn size(mut self, width: f32, height: f32) -> Self {
         self.width = width;
@@ -131,12 +193,109 @@ impl ContourChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
-        // Validate inputs
-        validate_data_array(&self.z, "z")?;
+    /// Use contour bands computed ahead of time instead of generating them
+    /// during [`ContourChart::build`].
+    ///
+    /// Pair with [`ContourChart::spawn_bands`] to keep large grids off the UI
+    /// thread.
+    pub fn bands(mut self, bands: Vec<ContourBand>) -> Self {
+        self.bands = Some(bands);
+        self
+    }
+
+    /// Show a marker and tooltip for a hovered value (from [`ContourChart::on_hover`]).
+    pub fn hover(mut self, hover: Option<ContourHover>) -> Self {
+        self.hover = hover;
+        self
+    }
+
+    /// Report the z value and band under the cursor (`None` when it leaves
+    /// the grid).
+    pub fn on_hover<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Option<ContourHover>, &mut Window, &mut App) + 'static,
+    {
+        self.on_hover = Some(Rc::new(callback));
+        self
+    }
+
+    /// Generate this chart's contour bands in the background.
+    ///
+    /// The returned handle is a future: await it (e.g. in `cx.spawn`), pass
+    /// the bands to [`ContourChart::bands`] and re-render. Its
+    /// [`progress`](ComputeHandle::progress) can drive a progress bar
+    /// meanwhile.
+    ///
+    /// ```rust,ignore
+    /// let handle = contour(&z, 400, 400).thresholds(levels).spawn_bands()?;
+    /// cx.spawn(async move |this, cx| {
+    ///     let bands = handle.await;
+    ///     this.update(cx, |view, cx| {
+    ///         view.bands = Some(bands);
+    ///         cx.notify();
+    ///     })
+    /// })
+    /// .detach();
+    /// ```
+    pub fn spawn_bands(&self) -> Result<ComputeHandle<Vec<ContourBand>>, ChartError> {
+        let (x_values, y_values) = self.grid_axes()?;
+        let generator = ContourGenerator::new(self.grid_width, self.grid_height)
+            .x_values(x_values)
+            .y_values(y_values);
+        Ok(spawn_contour_bands(
+            generator,
+            self.masked_z(),
+            self.resolved_thresholds(),
+        ))
+    }
+
+    /// Build the chart with its bands generated on the background executor.
+    ///
+    /// The grid is validated right away; the returned [`AsyncChart`] shows a
+    /// placeholder until the bands are ready.
+    pub fn build_async(mut self, cx: &mut App) -> Result<Entity<AsyncChart>, ChartError> {
+        let (x_values, y_values) = self.grid_axes()?;
+        let generator = ContourGenerator::new(self.grid_width, self.grid_height)
+            .x_values(x_values)
+            .y_values(y_values);
+        let z = self.masked_z();
+        let thresholds = self.resolved_thresholds();
+        let precomputed = self.bands.take();
+        let (layout, size) = (self.layout.clone(), (self.width, self.height));
+        Ok(AsyncChart::spawn(
+            &layout,
+            size,
+            move || precomputed.unwrap_or_else(|| generator.contour_bands(&z, &thresholds)),
+            move |bands: &Vec<ContourBand>| {
+                self.clone()
+                    .bands(bands.clone())
+                    .build()
+                    .map(IntoElement::into_any_element)
+            },
+            cx,
+        ))
+    }
+
+    /// Validate the grid and return its x and y values.
+    fn grid_axes(&self) -> Result<(Vec<f64>, Vec<f64>), ChartError> {
+        validate_data_with_gaps(&self.z, "z")?;
         validate_grid_dimensions(&self.z, self.grid_width, self.grid_height)?;
-        validate_dimensions(self.width, self.height)?;
+        if let Some(mask) = &self.mask {
+            if mask.len() != self.z.len() {
+                return Err(ChartError::DataLengthMismatch {
+                    x_field: "mask",
+                    y_field: "z",
+                    x_len: mask.len(),
+                    y_len: self.z.len(),
+                });
+            }
+            if !self.masked_z().iter().any(|z| z.is_finite()) {
+                return Err(ChartError::InvalidData {
+                    field: "z",
+                    reason: "contains only masked or missing values",
+                });
+            }
+        }
 
         // Generate or validate x values
         let x_values = match self.x_values {
@@ -180,6 +339,105 @@ impl ContourChart {
             None => (0..self.grid_height).map(|i| i as f64).collect(),
         };
 
+        Ok((x_values, y_values))
+    }
+
+    /// The z values with masked points replaced by NaN.
+    fn masked_z(&self) -> Vec<f64> {
+        match &self.mask {
+            Some(mask) => self
+                .z
+                .iter()
+                .zip(mask)
+                .map(|(&z, &masked)| if masked { f64::NAN } else { z })
+                .collect(),
+            None => self.z.clone(),
+        }
+    }
+
+    /// Grid cells to draw with the no-data style, as a heatmap whose cell
+    /// `(i, j)` spans the same area as contour cell `(i, j)`.
+    ///
+    /// A cell has no data when one of its corners is missing and none is
+    /// masked; every other cell is masked out. `None` when there is nothing
+    /// to draw.
+    fn no_data_cells(&self, x_values: &[f64], y_values: &[f64]) -> Option<HeatmapData> {
+        if self.no_data == NoDataStyle::Transparent || !self.z.iter().any(|z| z.is_nan()) {
+            return None;
+        }
+        let (w, h) = (self.grid_width, self.grid_height);
+        let masked = |i: usize| self.mask.as_ref().is_some_and(|mask| mask[i]);
+        let mut values = vec![0.0; w * h];
+        let mut mask = vec![true; w * h];
+        for j in 0..h.saturating_sub(1) {
+            for i in 0..w.saturating_sub(1) {
+                let corners = [
+                    j * w + i,
+                    j * w + i + 1,
+                    (j + 1) * w + i,
+                    (j + 1) * w + i + 1,
+                ];
+                if corners.iter().any(|&c| self.z[c].is_nan())
+                    && !corners.iter().any(|&c| masked(c))
+                {
+                    values[j * w + i] = f64::NAN;
+                    mask[j * w + i] = false;
+                }
+            }
+        }
+        Some(HeatmapData::new(x_values.to_vec(), y_values.to_vec(), values).with_mask(mask))
+    }
+
+    /// Thresholds set by the user, or 10 evenly spaced ones over the z range.
+    fn resolved_thresholds(&self) -> Vec<f64> {
+        if let Some(thresholds) = &self.thresholds {
+            return thresholds.clone();
+        }
+        let (z_min, z_max) = extent_padded(&self.masked_z(), 0.0);
+        let n = 10;
+        (0..=n)
+            .map(|i| z_min + (z_max - z_min) * (i as f64) / (n as f64))
+            .collect()
+    }
+
+    /// X and y domains, from the explicit ranges or the grid axes.
+    fn domains(&self, x_values: &[f64], y_values: &[f64]) -> ((f64, f64), (f64, f64)) {
+        let x_domain = if let Some([min, max]) = self.x_range {
+            (min, max)
+        } else {
+            extent_padded(x_values, 0.0)
+        };
+        let y_domain = if let Some([min, max]) = self.y_range {
+            (min, max)
+        } else {
+            extent_padded(y_values, 0.0)
+        };
+        (x_domain, y_domain)
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<AnyElement, ChartError> {
+        // Validate inputs
+        let (x_values, y_values) = self.grid_axes()?;
+        validate_dimensions(self.width, self.height)?;
+
+        if let Some(id) = self.zoom.id.take() {
+            let (x_domain, y_domain) = self.domains(&x_values, &y_values);
+            let home = ZoomHome {
+                x_domain,
+                y_domain,
+                x_scale: self.x_scale_type,
+                y_scale: self.y_scale_type,
+            };
+            let size = (self.width, self.height);
+            return Ok(build_zoomable(id, home, self, size, |chart, state| {
+                let ((x_min, x_max), (y_min, y_max)) = (state.x_domain(), state.y_domain());
+                let mut chart = chart.x_range(x_min, x_max).y_range(y_min, y_max);
+                chart.zoom.state = Some(state.clone());
+                chart.build_chart()
+            }));
+        }
+
         // Calculate plot area (reserve space for title and axes)
         let title_height = if self.title.is_some() {
             TITLE_AREA_HEIGHT
@@ -190,43 +448,66 @@ impl ContourChart {
         // Reserve space for axes
         let left_margin = 60.0_f64;
         let bottom_margin = 40.0_f64;
-        let plot_width = (self.width as f64) - left_margin;
+        let right_margin = if self.show_colorbar {
+            COLORBAR_WIDTH
+        } else {
+            0.0
+        };
+        let plot_width = (self.width as f64) - left_margin - right_margin;
         let plot_height = (self.height as f64) - title_height as f64 - bottom_margin;
+        validate_plot_area(plot_width, plot_height)?;
 
-        let theme = DefaultAxisTheme;
+        let theme = ChartTheme::current().axis_theme();
 
-        // Calculate domains with padding, or use explicit ranges if set
-        let (x_min, x_max) = if let Some([min, max]) = self.x_range {
-            (min, max)
-        } else {
-            extent_padded(&x_values, 0.0)
-        };
-        let (y_min, y_max) = if let Some([min, max]) = self.y_range {
-            (min, max)
-        } else {
-            extent_padded(&y_values, 0.0)
+        let ((x_min, x_max), (y_min, y_max)) = self.domains(&x_values, &y_values);
+
+        let thresholds = self.resolved_thresholds();
+        let z = self.masked_z();
+        let mut levels = thresholds.clone();
+        levels.sort_by(f64::total_cmp);
+        let hover_grid = HoverGrid {
+            z: z.clone(),
+            x_values: x_values.clone(),
+            y_values: y_values.clone(),
+            x_domain: (x_min, x_max),
+            y_domain: (y_min, y_max),
+            x_scale: self.x_scale_type,
+            y_scale: self.y_scale_type,
+            levels,
         };
+        let hover_layer = hover_layer(
+            "contour-hover",
+            hover_grid,
+            self.hover,
+            self.on_hover.clone(),
+            plot_width,
+            plot_height,
+        );
 
-        // Calculate z extent for auto-thresholds
-        let (z_min, z_max) = extent_padded(&self.z, 0.0);
-
-        // Generate thresholds if not provided
-        let thresholds = match self.thresholds {
-            Some(t) => t,
-            None => {
-                // Auto-generate 10 evenly spaced thresholds
-                let n = 10;
-                (0..=n)
-                    .map(|i| z_min + (z_max - z_min) * (i as f64) / (n as f64))
-                    .collect()
-            }
+        // Cells without data, drawn under the bands
+        let no_data_cells = self.no_data_cells(&x_values, &y_values);
+        let no_data_config = ContourConfig::new().no_data(self.no_data);
+
+        // Use precomputed bands, or generate them now
+        let bands = match self.bands.take() {
+            Some(bands) => bands,
+            None => ContourGenerator::new(self.grid_width, self.grid_height)
+                .x_values(x_values)
+                .y_values(y_values)
+                .contour_bands(&z, &thresholds),
         };
 
-        // Generate contour bands
-        let generator = ContourGenerator::new(self.grid_width, self.grid_height)
-            .x_values(x_values)
-            .y_values(y_values);
-        let bands = generator.contour_bands(&self.z, &thresholds);
+        // Map the color scale over the bands, around its center if set
+        let (band_min, band_max) = if bands.is_empty() {
+            (0.0, 1.0)
+        } else {
+            bands
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), band| {
+                    (lo.min(band.lower), hi.max(band.upper))
+                })
+        };
+        let (color_min, color_max) = self.color_scale.value_domain(band_min, band_max);
 
         // Build config with color scale
         let color_fn = self.color_scale.to_fn();
@@ -235,7 +516,8 @@ impl ContourChart {
             .fill_opacity(self.opacity)
             .stroke_width(0.5)
             .stroke_opacity(0.3)
-            .color_scale(color_fn);
+            .color_scale(color_fn)
+            .band_patterns(self.band_patterns.clone());
 
         // Build the element based on scale types
         let contour_element: AnyElement = match (self.x_scale_type, self.y_scale_type) {
@@ -265,7 +547,7 @@ impl ContourChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -274,9 +556,27 @@ impl ContourChart {
                                         plot_height as f32,
                                         &theme,
                                     ))
-                                    .child(div().absolute().inset_0().child(render_contour_bands(
-                                        bands, &x_scale, &y_scale, &config,
-                                    ))),
+                                    .children(no_data_cells.clone().map(|cells| {
+                                        div().absolute().inset_0().size_full().child(
+                                            render_heatmap(
+                                                cells,
+                                                &x_scale,
+                                                &y_scale,
+                                                &no_data_config,
+                                            )
+                                            .height(px(plot_height as f32)),
+                                        )
+                                    }))
+                                    .child(
+                                        div().absolute().inset_0().child(
+                                            render_contour_bands(
+                                                bands, &x_scale, &y_scale, &config,
+                                            )
+                                            .value_range(color_min, color_max),
+                                        ),
+                                    )
+                                    .children(hover_layer)
+                                    .children(self.zoom.probe()),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -313,7 +613,7 @@ impl ContourChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -322,9 +622,27 @@ impl ContourChart {
                                         plot_height as f32,
                                         &theme,
                                     ))
-                                    .child(div().absolute().inset_0().child(render_contour_bands(
-                                        bands, &x_scale, &y_scale, &config,
-                                    ))),
+                                    .children(no_data_cells.clone().map(|cells| {
+                                        div().absolute().inset_0().size_full().child(
+                                            render_heatmap(
+                                                cells,
+                                                &x_scale,
+                                                &y_scale,
+                                                &no_data_config,
+                                            )
+                                            .height(px(plot_height as f32)),
+                                        )
+                                    }))
+                                    .child(
+                                        div().absolute().inset_0().child(
+                                            render_contour_bands(
+                                                bands, &x_scale, &y_scale, &config,
+                                            )
+                                            .value_range(color_min, color_max),
+                                        ),
+                                    )
+                                    .children(hover_layer)
+                                    .children(self.zoom.probe()),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -361,7 +679,7 @@ impl ContourChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -370,9 +688,27 @@ impl ContourChart {
                                         plot_height as f32,
                                         &theme,
                                     ))
-                                    .child(div().absolute().inset_0().child(render_contour_bands(
-                                        bands, &x_scale, &y_scale, &config,
-                                    ))),
+                                    .children(no_data_cells.clone().map(|cells| {
+                                        div().absolute().inset_0().size_full().child(
+                                            render_heatmap(
+                                                cells,
+                                                &x_scale,
+                                                &y_scale,
+                                                &no_data_config,
+                                            )
+                                            .height(px(plot_height as f32)),
+                                        )
+                                    }))
+                                    .child(
+                                        div().absolute().inset_0().child(
+                                            render_contour_bands(
+                                                bands, &x_scale, &y_scale, &config,
+                                            )
+                                            .value_range(color_min, color_max),
+                                        ),
+                                    )
+                                    .children(hover_layer)
+                                    .children(self.zoom.probe()),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -409,7 +745,7 @@ impl ContourChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -418,9 +754,27 @@ impl ContourChart {
                                         plot_height as f32,
                                         &theme,
                                     ))
-                                    .child(div().absolute().inset_0().child(render_contour_bands(
-                                        bands, &x_scale, &y_scale, &config,
-                                    ))),
+                                    .children(no_data_cells.clone().map(|cells| {
+                                        div().absolute().inset_0().size_full().child(
+                                            render_heatmap(
+                                                cells,
+                                                &x_scale,
+                                                &y_scale,
+                                                &no_data_config,
+                                            )
+                                            .height(px(plot_height as f32)),
+                                        )
+                                    }))
+                                    .child(
+                                        div().absolute().inset_0().child(
+                                            render_contour_bands(
+                                                bands, &x_scale, &y_scale, &config,
+                                            )
+                                            .value_range(color_min, color_max),
+                                        ),
+                                    )
+                                    .children(hover_layer)
+                                    .children(self.zoom.probe()),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -456,16 +810,29 @@ impl ContourChart {
             );
         }
 
-        // Add plot area with axes
-        container = container.child(contour_element);
+        // Add plot area with axes, with the colorbar beside the plot
+        if self.show_colorbar {
+            container =
+                container.child(div().flex().child(contour_element).child(render_colorbar(
+                    &self.color_scale,
+                    band_min,
+                    band_max,
+                    plot_height as f32,
+                    &theme,
+                )));
+        } else {
+            container = container.child(contour_element);
+        }
 
-        Ok(container)
+        Ok(container.into_any_element())
     }
 }
 
 /// Create a contour chart (filled bands) from z data with grid dimensions.
 ///
 /// Data is in row-major order: `z[row * width + col]` where row 0 is at the bottom.
+/// NaN values mark missing points; bands leave out the cells around them,
+/// which are drawn with [`ContourChart::no_data`].
 ///
 /// # Example
 ///
@@ -487,6 +854,7 @@ impl ContourChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn contour(z: &[f64], grid_width: usize, grid_height: usize) -> ContourChart {
+    let defaults = ChartDefaults::current();
     ContourChart {
         z: z.to_vec(),
         grid_width,
@@ -497,18 +865,28 @@ pub fn contour(z: &[f64], grid_width: usize, grid_height: usize) -> ContourChart
         y_scale_type: ScaleType::Linear,
         thresholds: None,
         color_scale: ColorScale::default(),
+        show_colorbar: false,
+        no_data: NoDataStyle::default(),
+        mask: None,
         title: None,
         opacity: 0.8,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        band_patterns: Vec::new(),
+        width: defaults.width,
+        height: defaults.height,
         x_range: None,
         y_range: None,
+        bands: None,
+        hover: None,
+        on_hover: None,
+        layout: ChartLayout::default(),
+        zoom: ChartZoom::new(defaults.zoomable),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use d3rs::color::D3Color;
 
     #[test]
     fn test_contour_empty_z() {
@@ -516,6 +894,27 @@ mod tests {
         assert!(matches!(result, Err(ChartError::EmptyData { field: "z" })));
     }
 
+    #[test]
+    fn test_contour_spawn_bands() {
+        let z: Vec<f64> = (0..100).map(|i| (i % 10 + i / 10) as f64).collect();
+        let bands = contour(&z, 10, 10).spawn_bands().unwrap().wait();
+        // 11 auto thresholds give 10 bands
+        assert_eq!(bands.len(), 10);
+        assert!(contour(&z, 10, 10).bands(bands).build().is_ok());
+
+        assert!(contour(&z, 5, 5).spawn_bands().is_err());
+    }
+
+    #[test]
+    fn test_contour_centered_classes_with_colorbar() {
+        let z: Vec<f64> = (0..100).map(|i| (i % 10) as f64 - 3.0).collect();
+        let result = contour(&z, 10, 10)
+            .color_scale(ColorScale::Coolwarm.center(0.0).classes(8))
+            .colorbar(true)
+            .build();
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_contour_grid_mismatch() {
         let z = vec![1.0, 2.0, 3.0, 4.0, 5.0]; // 5 values
@@ -595,4 +994,60 @@ mod tests {
             .build();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_contour_missing_points_and_mask() {
+        let mut z: Vec<f64> = (0..16).map(|i| i as f64).collect(); // 4x4 grid
+        z[5] = f64::NAN;
+        let chart = contour(&z, 4, 4)
+            .no_data(NoDataStyle::Color(D3Color::from_hex(0xcccccc)))
+            .mask(&[
+                true, true, false, false, true, false, false, false, false, false, false, false,
+                false, false, false, false,
+            ]);
+
+        // Only the cells touching the missing point but no masked point
+        let cells = chart
+            .no_data_cells(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 2.0, 3.0])
+            .unwrap();
+        let shown: Vec<usize> = (0..16)
+            .filter(|&i| !cells.mask.as_ref().unwrap()[i])
+            .collect();
+        assert_eq!(shown, vec![5]);
+
+        assert!(chart.clone().build().is_ok());
+        assert!(chart.spawn_bands().is_ok());
+    }
+
+    #[test]
+    fn test_contour_mask_length_mismatch() {
+        let z = vec![1.0; 9];
+        let result = contour(&z, 3, 3).mask(&[false; 4]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::DataLengthMismatch {
+                x_field: "mask",
+                y_field: "z",
+                x_len: 4,
+                y_len: 9,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_contour_with_hover() {
+        let z: Vec<f64> = (0..9).map(|i| i as f64).collect();
+        let hover = ContourHover {
+            x: 1.0,
+            y: 1.0,
+            z: 4.0,
+            band: Some((3.0, 6.0)),
+        };
+        let result = contour(&z, 3, 3)
+            .thresholds(vec![0.0, 3.0, 6.0, 9.0])
+            .hover(Some(hover))
+            .on_hover(|_, _, _| {})
+            .build();
+        assert!(result.is_ok());
+    }
 }
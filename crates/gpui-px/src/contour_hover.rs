@@ -0,0 +1,278 @@
+//! Hover readout shared by contour and isoline charts.
+//!
+//! Filled contours are hard to read back without a colorbar. With
+//! `.on_hover(...)` the chart reports the z value under the cursor,
+//! interpolated from the grid, and the band of thresholds containing it.
+//! Feeding the value back through `.hover(...)` shows a marker and tooltip:
+//!
+//! ```rust,ignore
+//! contour(&z, 100, 100)
+//!     .hover(self.hover)
+//!     .on_hover(cx.listener(|view, hover, _, cx| {
+//!         view.hover = *hover;
+//!         cx.notify();
+//!     }))
+//! ```
+
+use crate::ScaleType;
+use crate::interaction::PlotBoundsCapture;
+use gpui::prelude::*;
+use gpui::{AnyElement, App, ElementId, Window, div, hsla, px};
+use std::rc::Rc;
+
+/// Value under the cursor of a contour or isoline chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContourHover {
+    /// Cursor x in data coordinates
+    pub x: f64,
+    /// Cursor y in data coordinates
+    pub y: f64,
+    /// z interpolated bilinearly from the four surrounding grid points
+    pub z: f64,
+    /// Thresholds (or levels) enclosing `z`, as `(lower, upper)`; `None`
+    /// outside the first and last one
+    pub band: Option<(f64, f64)>,
+}
+
+/// Callback type for contour hover (receives `None` when the mouse leaves)
+pub type ContourHoverCallback = Rc<dyn Fn(Option<ContourHover>, &mut Window, &mut App)>;
+
+/// Grid and axes needed to turn plot pixels into [`ContourHover`] values.
+#[derive(Debug, Clone)]
+pub(crate) struct HoverGrid {
+    pub(crate) z: Vec<f64>,
+    pub(crate) x_values: Vec<f64>,
+    pub(crate) y_values: Vec<f64>,
+    pub(crate) x_domain: (f64, f64),
+    pub(crate) y_domain: (f64, f64),
+    pub(crate) x_scale: ScaleType,
+    pub(crate) y_scale: ScaleType,
+    /// Thresholds or levels, ascending
+    pub(crate) levels: Vec<f64>,
+}
+
+impl HoverGrid {
+    /// Value under a point of the plot area, `None` outside the grid.
+    pub(crate) fn at(&self, px: f64, py: f64, width: f64, height: f64) -> Option<ContourHover> {
+        let x = invert(px / width, self.x_domain, self.x_scale);
+        let y = invert(1.0 - py / height, self.y_domain, self.y_scale);
+        // Cells touching missing or masked points have no value
+        let z =
+            sample_grid(&self.z, &self.x_values, &self.y_values, x, y).filter(|z| !z.is_nan())?;
+        Some(ContourHover {
+            x,
+            y,
+            z,
+            band: band_of(&self.levels, z),
+        })
+    }
+
+    /// Position of a hovered point in the plot area.
+    fn position(&self, hover: &ContourHover, width: f64, height: f64) -> (f32, f32) {
+        let x = project(hover.x, self.x_domain, self.x_scale) * width;
+        let y = (1.0 - project(hover.y, self.y_domain, self.y_scale)) * height;
+        (x as f32, y as f32)
+    }
+}
+
+/// Marker, tooltip and mouse tracking over the plot area.
+///
+/// Returns `None` when there is neither a hovered value nor a callback.
+pub(crate) fn hover_layer(
+    id: impl Into<ElementId>,
+    grid: HoverGrid,
+    hover: Option<ContourHover>,
+    on_hover: Option<ContourHoverCallback>,
+    plot_width: f64,
+    plot_height: f64,
+) -> Option<AnyElement> {
+    if hover.is_none() && on_hover.is_none() {
+        return None;
+    }
+
+    let mut layer = div()
+        .id(id)
+        .absolute()
+        .top_0()
+        .left_0()
+        .w(px(plot_width as f32))
+        .h(px(plot_height as f32));
+
+    if let Some(hover) = hover {
+        let (x, y) = grid.position(&hover, plot_width, plot_height);
+        let mut tooltip = div()
+            .absolute()
+            .px_2()
+            .py_1()
+            .flex()
+            .flex_col()
+            .bg(hsla(0.0, 0.0, 0.2, 0.85))
+            .rounded_md()
+            .text_xs()
+            .text_color(hsla(0.0, 0.0, 1.0, 1.0))
+            .child(format!("x: {:.3}, y: {:.3}", hover.x, hover.y))
+            .child(format!("z: {:.3}", hover.z));
+        if let Some((lower, upper)) = hover.band {
+            tooltip = tooltip.child(format!("band: {lower:.3} – {upper:.3}"));
+        }
+        // Tooltip flips to the other side past the middle of the plot
+        tooltip = if x > plot_width as f32 / 2.0 {
+            tooltip.right(px(plot_width as f32 - x + 8.0))
+        } else {
+            tooltip.left(px(x + 8.0))
+        };
+        tooltip = if y > plot_height as f32 / 2.0 {
+            tooltip.bottom(px(plot_height as f32 - y + 8.0))
+        } else {
+            tooltip.top(px(y + 8.0))
+        };
+        layer = layer
+            .child(
+                div()
+                    .absolute()
+                    .left(px(x - 4.0))
+                    .top(px(y - 4.0))
+                    .size(px(8.0))
+                    .rounded_full()
+                    .border_1()
+                    .border_color(hsla(0.0, 0.0, 1.0, 1.0))
+                    .bg(hsla(0.0, 0.0, 0.2, 0.85)),
+            )
+            .child(tooltip);
+    }
+
+    if let Some(on_hover) = on_hover {
+        let plot_bounds = PlotBoundsCapture::new();
+        let on_leave = on_hover.clone();
+        layer = layer
+            .child(plot_bounds.canvas())
+            .on_mouse_move(move |event, window, cx| {
+                let Some((x, y)) = plot_bounds.to_local(event.position) else {
+                    return;
+                };
+                let value = plot_bounds
+                    .contains(event.position)
+                    .then(|| grid.at(x, y, plot_width, plot_height));
+                if value.flatten() != hover {
+                    on_hover(value.flatten(), window, cx);
+                }
+            })
+            .on_hover(move |hovered, window, cx| {
+                if !*hovered {
+                    on_leave(None, window, cx);
+                }
+            });
+    }
+
+    Some(layer.into_any_element())
+}
+
+/// Data value at a fraction `t` of an axis.
+fn invert(t: f64, (min, max): (f64, f64), scale: ScaleType) -> f64 {
+    match scale {
+        ScaleType::Linear => min + t * (max - min),
+        ScaleType::Log => {
+            let (min, max) = (min.max(1e-10).log10(), max.log10());
+            10f64.powf(min + t * (max - min))
+        }
+    }
+}
+
+/// Fraction of an axis at a data value.
+fn project(value: f64, (min, max): (f64, f64), scale: ScaleType) -> f64 {
+    match scale {
+        ScaleType::Linear => (value - min) / (max - min),
+        ScaleType::Log => {
+            let min = min.max(1e-10).log10();
+            (value.log10() - min) / (max.log10() - min)
+        }
+    }
+}
+
+/// Cell containing `value` and the position in it, `None` outside `values`.
+fn locate(values: &[f64], value: f64) -> Option<(usize, f64)> {
+    let (first, last) = (*values.first()?, *values.last()?);
+    if !(first..=last).contains(&value) {
+        return None;
+    }
+    if values.len() == 1 {
+        return Some((0, 0.0));
+    }
+    let i = values
+        .partition_point(|&v| v <= value)
+        .saturating_sub(1)
+        .min(values.len() - 2);
+    let span = values[i + 1] - values[i];
+    Some((i, (value - values[i]) / span))
+}
+
+/// Bilinear interpolation of a row-major grid (`z[row * width + col]`).
+pub(crate) fn sample_grid(
+    z: &[f64],
+    x_values: &[f64],
+    y_values: &[f64],
+    x: f64,
+    y: f64,
+) -> Option<f64> {
+    let (col, tx) = locate(x_values, x)?;
+    let (row, ty) = locate(y_values, y)?;
+    let width = x_values.len();
+    let col1 = (col + 1).min(width - 1);
+    let row1 = (row + 1).min(y_values.len() - 1);
+    let at = |c: usize, r: usize| z[r * width + c];
+    let bottom = at(col, row) + (at(col1, row) - at(col, row)) * tx;
+    let top = at(col, row1) + (at(col1, row1) - at(col, row1)) * tx;
+    Some(bottom + (top - bottom) * ty)
+}
+
+/// Adjacent levels enclosing `z`; the last level closes the last band.
+pub(crate) fn band_of(levels: &[f64], z: f64) -> Option<(f64, f64)> {
+    levels
+        .windows(2)
+        .enumerate()
+        .find(|(i, pair)| pair[0] <= z && (z < pair[1] || (z == pair[1] && i + 2 == levels.len())))
+        .map(|(_, pair)| (pair[0], pair[1]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_grid_bilinear() {
+        // 2x2 grid: z = x + 10 y
+        let z = [0.0, 1.0, 10.0, 11.0];
+        let axis = [0.0, 1.0];
+        assert_eq!(sample_grid(&z, &axis, &axis, 0.5, 0.5), Some(5.5));
+        assert_eq!(sample_grid(&z, &axis, &axis, 1.0, 1.0), Some(11.0));
+        assert_eq!(sample_grid(&z, &axis, &axis, 1.5, 0.5), None);
+    }
+
+    #[test]
+    fn test_band_of() {
+        let levels = [0.0, 1.0, 2.0];
+        assert_eq!(band_of(&levels, 0.5), Some((0.0, 1.0)));
+        assert_eq!(band_of(&levels, 1.0), Some((1.0, 2.0)));
+        assert_eq!(band_of(&levels, 2.0), Some((1.0, 2.0)));
+        assert_eq!(band_of(&levels, 3.0), None);
+    }
+
+    #[test]
+    fn test_hover_grid_log_axis_round_trip() {
+        let grid = HoverGrid {
+            z: vec![0.0, 1.0, 10.0, 11.0],
+            x_values: vec![10.0, 1000.0],
+            y_values: vec![0.0, 1.0],
+            x_domain: (10.0, 1000.0),
+            y_domain: (0.0, 1.0),
+            x_scale: ScaleType::Log,
+            y_scale: ScaleType::Linear,
+            levels: vec![0.0, 5.0, 11.0],
+        };
+        let hover = grid.at(50.0, 50.0, 100.0, 100.0).unwrap();
+        assert!((hover.x - 100.0).abs() < 1e-9);
+        assert_eq!(hover.band, Some((5.0, 11.0)));
+        let (x, y) = grid.position(&hover, 100.0, 100.0);
+        assert!((x - 50.0).abs() < 1e-3 && (y - 50.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,265 @@
+//! Crosshair cursor with axis value readout.
+//!
+//! `.crosshair(true)` on a line or scatter chart draws vertical and
+//! horizontal guide lines through the pointer while it is over the plot,
+//! with the x value in a box on the bottom axis and the y value in a box on
+//! the left axis. Values follow log axes and the current zoom:
+//!
+//! ```rust,ignore
+//! let chart = line(&freq, &spl)
+//!     .x_scale(ScaleType::Log)
+//!     .crosshair(true)
+//!     .build()?;
+//! ```
+//!
+//! The pointer position is kept under the ids of the enclosing elements,
+//! like the zoom state.
+
+use crate::interaction::format_tooltip_value;
+use crate::theme::ChartTheme;
+use gpui::prelude::*;
+use gpui::{
+    AnyElement, App, AvailableSpace, Bounds, DispatchPhase, Element, ElementId, GlobalElementId,
+    InspectorElementId, LayoutId, MouseMoveEvent, Pixels, Point, Rgba, Size, Style, Window, div,
+    px, relative,
+};
+use std::cell::Cell;
+use std::panic;
+use std::rc::Rc;
+
+/// Gap between a guide line and its readout box, in pixels
+const READOUT_GAP: f32 = 2.0;
+
+/// Pointer position over the plot, kept in the element state
+type Pointer = Rc<Cell<Option<Point<Pixels>>>>;
+
+/// Crosshair layer of a plot area.
+///
+/// Place it in a `relative()` plot area, after the data.
+pub(crate) struct Crosshair {
+    x_domain: (f64, f64),
+    y_domain: (f64, f64),
+    x_log: bool,
+    y_log: bool,
+    line_color: Rgba,
+    readout_background: Rgba,
+    readout_color: Rgba,
+    font_size: f32,
+}
+
+impl Crosshair {
+    /// Crosshair of a plot showing `x_domain` and `y_domain`, in the colors
+    /// of `theme`.
+    pub(crate) fn new(x_domain: (f64, f64), y_domain: (f64, f64), theme: &ChartTheme) -> Self {
+        Self {
+            x_domain,
+            y_domain,
+            x_log: false,
+            y_log: false,
+            line_color: theme.axis_label_color,
+            readout_background: theme.title_color,
+            readout_color: theme.plot_background,
+            font_size: theme.axis_font_size + 2.0,
+        }
+    }
+
+    /// Set which axes are logarithmic.
+    pub(crate) fn log(mut self, x_log: bool, y_log: bool) -> Self {
+        self.x_log = x_log;
+        self.y_log = y_log;
+        self
+    }
+
+    /// Guide lines and readouts through `(x, y)`, in pixels from the top
+    /// left of a plot of `width` x `height`.
+    fn render(&self, x: f32, y: f32, width: f32, height: f32) -> AnyElement {
+        let x_value = axis_value(x, width, self.x_domain, self.x_log);
+        let y_value = axis_value(height - y, height, self.y_domain, self.y_log);
+
+        let readout = |value: f64| {
+            div()
+                .absolute()
+                .px_1()
+                .rounded_sm()
+                .bg(self.readout_background)
+                .text_color(self.readout_color)
+                .text_size(px(self.font_size))
+                .whitespace_nowrap()
+                .child(format_tooltip_value(value))
+        };
+        // On the bottom axis, on the side of the line with more room
+        let x_readout = readout(x_value).bottom_0();
+        let x_readout = if x < width / 2.0 {
+            x_readout.left(px(x + READOUT_GAP))
+        } else {
+            x_readout.right(px(width - x + READOUT_GAP))
+        };
+        // On the left axis, above the line unless it is near the top
+        let y_readout = readout(y_value).left_0();
+        let y_readout = if y > height / 2.0 {
+            y_readout.bottom(px(height - y + READOUT_GAP))
+        } else {
+            y_readout.top(px(y + READOUT_GAP))
+        };
+
+        div()
+            .w(px(width))
+            .h(px(height))
+            .relative()
+            .child(
+                div()
+                    .absolute()
+                    .left(px(x))
+                    .top_0()
+                    .w_px()
+                    .h_full()
+                    .bg(self.line_color),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .left_0()
+                    .top(px(y))
+                    .w_full()
+                    .h_px()
+                    .bg(self.line_color),
+            )
+            .child(x_readout)
+            .child(y_readout)
+            .into_any_element()
+    }
+}
+
+impl IntoElement for Crosshair {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Crosshair {
+    type RequestLayoutState = ();
+    type PrepaintState = (Pointer, Option<AnyElement>);
+
+    fn id(&self) -> Option<ElementId> {
+        Some("chart-crosshair".into())
+    }
+
+    fn source_location(&self) -> Option<&'static panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let style = Style {
+            position: gpui::Position::Absolute,
+            size: Size {
+                width: relative(1.0).into(),
+                height: relative(1.0).into(),
+            },
+            ..Default::default()
+        };
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let pointer = window.with_element_state(id.unwrap(), |pointer: Option<Pointer>, _| {
+            let pointer = pointer.unwrap_or_default();
+            (pointer.clone(), pointer)
+        });
+        let mut guides = pointer
+            .get()
+            .filter(|position| bounds.contains(position))
+            .map(|position| {
+                let offset = position - bounds.origin;
+                self.render(
+                    offset.x.into(),
+                    offset.y.into(),
+                    bounds.size.width.into(),
+                    bounds.size.height.into(),
+                )
+            });
+        if let Some(guides) = guides.as_mut() {
+            guides.prepaint_as_root(
+                bounds.origin,
+                bounds.size.map(AvailableSpace::Definite),
+                window,
+                cx,
+            );
+        }
+        (pointer, guides)
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        (pointer, guides): &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(guides) = guides.as_mut() {
+            guides.paint(window, cx);
+        }
+        let pointer = pointer.clone();
+        window.on_mouse_event(move |event: &MouseMoveEvent, phase, window, _cx| {
+            if phase != DispatchPhase::Bubble {
+                return;
+            }
+            let position = bounds.contains(&event.position).then_some(event.position);
+            if pointer.get() != position {
+                pointer.set(position);
+                window.refresh();
+            }
+        });
+    }
+}
+
+/// Value at `pixel` along an axis of `length` pixels showing `domain`,
+/// counted from the start of the domain.
+fn axis_value(pixel: f32, length: f32, (min, max): (f64, f64), log: bool) -> f64 {
+    let t = if length > 0.0 {
+        f64::from((pixel / length).clamp(0.0, 1.0))
+    } else {
+        0.0
+    };
+    if log && min > 0.0 && max > 0.0 {
+        (min.ln() + t * (max.ln() - min.ln())).exp()
+    } else {
+        min + t * (max - min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_value_linear_and_log() {
+        assert_eq!(axis_value(0.0, 400.0, (-40.0, 10.0), false), -40.0);
+        assert_eq!(axis_value(200.0, 400.0, (-40.0, 10.0), false), -15.0);
+        assert_eq!(axis_value(400.0, 400.0, (-40.0, 10.0), false), 10.0);
+        // Halfway on a log axis is the geometric mean
+        let mid = axis_value(150.0, 300.0, (20.0, 20000.0), true);
+        assert!((mid - (20.0f64 * 20000.0).sqrt()).abs() < 1e-9);
+        // Outside the plot is clamped to the domain
+        assert_eq!(axis_value(-10.0, 300.0, (1.0, 2.0), false), 1.0);
+        assert_eq!(axis_value(10.0, 0.0, (1.0, 2.0), false), 1.0);
+    }
+}
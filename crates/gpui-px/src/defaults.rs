@@ -0,0 +1,218 @@
+//! App-wide chart defaults.
+//!
+//! Options set once in the [`ChartDefaults`] global apply to every chart
+//! built afterwards, unless its builder overrides them: size, theme, fonts,
+//! palette, legend position, zoom and crosshair, and watermark. Like the
+//! chart theme, the global is mirrored into the current thread by [`init`]:
+//!
+//! ```rust,ignore
+//! cx.set_global(
+//!     ChartDefaults::new()
+//!         .size(480.0, 320.0)
+//!         .axis_font_size(10.0)
+//!         .palette(CategoricalPalette::tableau10())
+//!         .legend_position(LegendPosition::Bottom)
+//!         .zoomable(true)
+//!         .watermark(Watermark::new("ACME Audio", WatermarkPosition::BottomRight, 0.4)),
+//! );
+//! gpui_px::init(cx);
+//! ```
+//!
+//! MiniApp installs it with `MiniAppConfig::with_global(defaults)`; call
+//! [`init`] from the view builder. Builders read the defaults when they are
+//! created, so `line(&x, &y).size(800.0, 600.0)` still wins.
+//!
+//! [`init`]: crate::init
+
+use crate::line::LegendPosition;
+use crate::palette::CategoricalPalette;
+use crate::theme::ChartTheme;
+use crate::watermark::Watermark;
+use crate::{DEFAULT_HEIGHT, DEFAULT_WIDTH};
+use gpui::{App, Global};
+use std::cell::RefCell;
+
+thread_local! {
+    /// Defaults mirrored from the app global by [`init`]
+    static CURRENT: RefCell<Option<ChartDefaults>> = const { RefCell::new(None) };
+}
+
+/// Options applied to every chart of the app.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartDefaults {
+    /// Chart width in pixels
+    pub width: f32,
+    /// Chart height in pixels
+    pub height: f32,
+    /// Colors and fonts used instead of the ones following the app theme
+    pub theme: Option<ChartTheme>,
+    /// Font size of axis tick labels, over the theme's
+    pub axis_font_size: Option<f32>,
+    /// Font size of chart titles, over the theme's
+    pub title_font_size: Option<f32>,
+    /// Series colors of line, polar and radar charts
+    pub palette: Option<CategoricalPalette>,
+    /// Legend position; unset, each chart picks its own
+    pub legend_position: Option<LegendPosition>,
+    /// Zoom and pan charts with continuous axes
+    pub zoomable: bool,
+    /// Show a crosshair on line and scatter charts
+    pub crosshair: bool,
+    /// Watermark of charts without their own `.watermark(...)`
+    pub watermark: Option<Watermark>,
+}
+
+impl Default for ChartDefaults {
+    fn default() -> Self {
+        Self {
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            theme: None,
+            axis_font_size: None,
+            title_font_size: None,
+            palette: None,
+            legend_position: None,
+            zoomable: false,
+            crosshair: false,
+            watermark: None,
+        }
+    }
+}
+
+impl Global for ChartDefaults {}
+
+impl ChartDefaults {
+    /// Defaults that change nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the size of new charts.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Use `theme` instead of following the app theme.
+    pub fn theme(mut self, theme: ChartTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Set the font size of axis tick labels.
+    pub fn axis_font_size(mut self, size: f32) -> Self {
+        self.axis_font_size = Some(size);
+        self
+    }
+
+    /// Set the font size of chart titles.
+    pub fn title_font_size(mut self, size: f32) -> Self {
+        self.title_font_size = Some(size);
+        self
+    }
+
+    /// Color the series of line, polar and radar charts from `palette`.
+    pub fn palette(mut self, palette: CategoricalPalette) -> Self {
+        self.palette = Some(palette);
+        self
+    }
+
+    /// Place the legend of every chart at `position`.
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = Some(position);
+        self
+    }
+
+    /// Make charts with continuous axes zoomable.
+    pub fn zoomable(mut self, zoomable: bool) -> Self {
+        self.zoomable = zoomable;
+        self
+    }
+
+    /// Show a crosshair on line and scatter charts.
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
+    /// Brand every chart with `watermark`.
+    pub fn watermark(mut self, watermark: Watermark) -> Self {
+        self.watermark = Some(watermark);
+        self
+    }
+
+    /// Defaults used by charts built on this thread: the ones mirrored from
+    /// the app global, or none.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.borrow().clone().unwrap_or_default())
+    }
+
+    /// `app_theme` with the theme and fonts of these defaults applied.
+    pub(crate) fn chart_theme(&self, app_theme: ChartTheme) -> ChartTheme {
+        let mut theme = self.theme.clone().unwrap_or(app_theme);
+        if let Some(size) = self.axis_font_size {
+            theme.axis_font_size = size;
+        }
+        if let Some(size) = self.title_font_size {
+            theme.title_font_size = size;
+        }
+        theme
+    }
+}
+
+/// Mirror the global [`ChartDefaults`] now and whenever it changes.
+pub(crate) fn init(cx: &mut App) {
+    sync_from_app(cx);
+    cx.observe_global::<ChartDefaults>(sync_from_app).detach();
+}
+
+fn sync_from_app(cx: &mut App) {
+    set_current(cx.try_global::<ChartDefaults>().cloned());
+}
+
+/// Replace the defaults of this thread.
+pub(crate) fn set_current(defaults: Option<ChartDefaults>) {
+    CURRENT.with(|current| *current.borrow_mut() = defaults);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_apply_theme_and_fonts() {
+        let app_theme = ChartTheme::default();
+        assert_eq!(
+            ChartDefaults::new().chart_theme(app_theme.clone()),
+            app_theme
+        );
+
+        let custom = ChartTheme {
+            axis_font_size: 9.0,
+            ..ChartTheme::default()
+        };
+        let defaults = ChartDefaults::new().theme(custom).title_font_size(20.0);
+        let theme = defaults.chart_theme(app_theme.clone());
+        assert_eq!(theme.axis_font_size, 9.0);
+        assert_eq!(theme.title_font_size, 20.0);
+
+        // Fonts apply over the app theme too
+        let theme = ChartDefaults::new()
+            .axis_font_size(11.0)
+            .chart_theme(app_theme);
+        assert_eq!(theme.axis_font_size, 11.0);
+    }
+
+    #[test]
+    fn test_current_theme_follows_thread_defaults() {
+        set_current(Some(
+            ChartDefaults::new().size(300.0, 200.0).axis_font_size(10.0),
+        ));
+        assert_eq!(ChartDefaults::current().width, 300.0);
+        assert_eq!(ChartTheme::current().axis_font_size, 10.0);
+        set_current(None);
+        assert_eq!(ChartDefaults::current(), ChartDefaults::default());
+        assert_eq!(ChartTheme::current(), ChartTheme::default());
+    }
+}
@@ -39,4 +39,12 @@ pub enum ChartError {
         height: usize,
         expected: usize,
     },
+
+    /// Margins, title and legend leave no room for the plot area.
+    #[error("chart too small: plot area would be {width:.0} x {height:.0} px")]
+    PlotAreaTooSmall { width: f64, height: f64 },
+
+    /// A retained chart was asked to update a series it doesn't hold.
+    #[error("unknown series: {name}")]
+    UnknownSeries { name: String },
 }
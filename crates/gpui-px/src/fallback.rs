@@ -0,0 +1,117 @@
+//! Fallback element for charts that fail to build.
+//!
+//! `build()` reports bad data and layouts as a [`ChartError`]. Dashboards
+//! usually want to keep rendering the other charts and show the problem in
+//! place instead of propagating the error:
+//!
+//! ```rust,ignore
+//! use gpui_px::{ChartResultExt, line};
+//!
+//! let chart = line(&freq, &spl)
+//!     .size(600.0, 400.0)
+//!     .build()
+//!     .on_error(|error| eprintln!("SPL chart: {error}"))
+//!     .or_fallback(600.0, 400.0);
+//! ```
+
+use crate::error::ChartError;
+use crate::{DEFAULT_HEIGHT, DEFAULT_WIDTH};
+use gpui::prelude::*;
+use gpui::{AnyElement, IntoElement, Rgba, div, px, rgb};
+
+/// Accent color of the fallback element (Plotly red)
+const ERROR_COLOR: u32 = 0xd62728;
+
+/// Build the standard element shown in place of a chart that failed.
+///
+/// The element takes the chart's size so the surrounding layout does not
+/// shift; non-positive sizes fall back to the default chart size.
+pub fn chart_error(error: &ChartError, width: f32, height: f32) -> AnyElement {
+    let width = if width > 0.0 { width } else { DEFAULT_WIDTH };
+    let height = if height > 0.0 { height } else { DEFAULT_HEIGHT };
+
+    div()
+        .w(px(width))
+        .h(px(height))
+        .flex()
+        .flex_col()
+        .items_center()
+        .justify_center()
+        .gap_1()
+        .p_2()
+        .overflow_hidden()
+        .border_1()
+        .border_color(with_alpha(ERROR_COLOR, 0.5))
+        .bg(with_alpha(ERROR_COLOR, 0.05))
+        .child(
+            div()
+                .text_sm()
+                .text_color(rgb(ERROR_COLOR))
+                .child("Chart unavailable"),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(with_alpha(0x000000, 0.6))
+                .child(error.to_string()),
+        )
+        .into_any_element()
+}
+
+/// Graceful degradation for the `Result` returned by chart `build()`.
+pub trait ChartResultExt {
+    /// Call `callback` with the error, if any (logging, telemetry).
+    fn on_error(self, callback: impl FnOnce(&ChartError)) -> Self;
+
+    /// Return the chart element, or the [`chart_error`] fallback of the
+    /// given size.
+    fn or_fallback(self, width: f32, height: f32) -> AnyElement;
+}
+
+impl<E: IntoElement> ChartResultExt for Result<E, ChartError> {
+    fn on_error(self, callback: impl FnOnce(&ChartError)) -> Self {
+        if let Err(error) = &self {
+            callback(error);
+        }
+        self
+    }
+
+    fn or_fallback(self, width: f32, height: f32) -> AnyElement {
+        match self {
+            Ok(chart) => chart.into_any_element(),
+            Err(error) => chart_error(&error, width, height),
+        }
+    }
+}
+
+fn with_alpha(hex: u32, alpha: f32) -> Rgba {
+    Rgba {
+        a: alpha,
+        ..rgb(hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line;
+
+    #[test]
+    fn test_on_error_reports_build_errors_only() {
+        let mut reported = Vec::new();
+        let _ = line(&[1.0, 2.0], &[1.0])
+            .build()
+            .on_error(|error| reported.push(error.to_string()));
+        let _ = line(&[1.0, 2.0], &[1.0, 2.0])
+            .build()
+            .on_error(|error| reported.push(error.to_string()));
+
+        assert_eq!(reported, ["x has 2 elements but y has 1 elements"]);
+    }
+
+    #[test]
+    fn test_tiny_chart_reports_empty_plot_area() {
+        let result = line(&[1.0, 2.0], &[1.0, 2.0]).size(40.0, 400.0).build();
+        assert!(matches!(result, Err(ChartError::PlotAreaTooSmall { .. })));
+    }
+}
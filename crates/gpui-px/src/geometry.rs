@@ -0,0 +1,296 @@
+//! Computed geometry of a chart, without rendering.
+//!
+//! `compute_layout()` on line, scatter, bar, pie and heatmap charts runs the
+//! same layout as `build()` and returns the scales, ticks and marks as plain
+//! data, for unit tests of chart math or hit testing with
+//! [`ChartHitTester`](crate::ChartHitTester):
+//!
+//! ```rust
+//! use gpui_px::line;
+//!
+//! let geometry = line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+//!     .size(400.0, 300.0)
+//!     .compute_layout()?;
+//! let path = &geometry.paths[0];
+//! assert_eq!(path.vertices.len(), 3);
+//! assert!(geometry.x_axis.position(1.0) > geometry.x_axis.position(0.0));
+//! # Ok::<(), gpui_px::ChartError>(())
+//! ```
+//!
+//! Positions are in pixels from the top-left corner of the plot area.
+
+use crate::ScaleType;
+use d3rs::scale::{LinearScale, LogScale, Scale};
+
+/// Tick of an axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tick {
+    /// Data value
+    pub value: f64,
+    /// Position along the axis, in pixels
+    pub position: f64,
+}
+
+/// Scale and ticks of one axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AxisGeometry {
+    /// Scale type
+    pub scale_type: ScaleType,
+    /// Data domain, as `(min, max)`
+    pub domain: (f64, f64),
+    /// Pixel range the domain maps to (y ranges run bottom to top)
+    pub range: (f64, f64),
+    /// Ticks drawn on the axis
+    pub ticks: Vec<Tick>,
+}
+
+impl AxisGeometry {
+    /// Axis with the given ticks, or `tick_count` ticks chosen by the scale.
+    ///
+    /// Log domains are clamped above zero, as the charts do when rendering.
+    pub(crate) fn new(
+        scale_type: ScaleType,
+        domain: (f64, f64),
+        range: (f64, f64),
+        ticks: Option<Vec<f64>>,
+        tick_count: usize,
+    ) -> Self {
+        let domain = match scale_type {
+            ScaleType::Linear => domain,
+            ScaleType::Log => (domain.0.max(1e-10), domain.1),
+        };
+        let mut axis = Self {
+            scale_type,
+            domain,
+            range,
+            ticks: Vec::new(),
+        };
+        let values = ticks.unwrap_or_else(|| axis.with_scale(|scale| scale.ticks(tick_count)));
+        axis.ticks = values
+            .into_iter()
+            .map(|value| Tick {
+                value,
+                position: axis.position(value),
+            })
+            .collect();
+        axis
+    }
+
+    /// Pixel position of a data value.
+    pub fn position(&self, value: f64) -> f64 {
+        self.with_scale(|scale| scale.scale(value))
+    }
+
+    /// Data value at a pixel position.
+    pub fn value_at(&self, position: f64) -> Option<f64> {
+        self.with_scale(|scale| scale.invert(position))
+    }
+
+    fn with_scale<R>(&self, f: impl FnOnce(&dyn Scale<f64, f64>) -> R) -> R {
+        let (min, max) = self.domain;
+        let (start, end) = self.range;
+        match self.scale_type {
+            ScaleType::Linear => f(&LinearScale::new().domain(min, max).range(start, end)),
+            ScaleType::Log => f(&LogScale::new().domain(min, max).range(start, end)),
+        }
+    }
+}
+
+/// Data point drawn as a marker or line vertex.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointGeometry {
+    /// Series index (0 is the primary series)
+    pub series: usize,
+    /// Index of the point in its series data
+    pub index: usize,
+    /// Horizontal position
+    pub x: f64,
+    /// Vertical position
+    pub y: f64,
+}
+
+/// Connected run of line vertices; a line with gaps has several.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathGeometry {
+    /// Series index (0 is the primary series)
+    pub series: usize,
+    /// Vertices as `(x, y)`
+    pub vertices: Vec<(f64, f64)>,
+}
+
+/// Bar rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RectGeometry {
+    /// Series index (0 is the primary series)
+    pub series: usize,
+    /// Category index
+    pub index: usize,
+    /// Left edge
+    pub x: f64,
+    /// Top edge
+    pub y: f64,
+    /// Width
+    pub width: f64,
+    /// Height
+    pub height: f64,
+}
+
+/// Pie or donut slice.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArcGeometry {
+    /// Index of the slice value
+    pub index: usize,
+    /// Horizontal position of the center
+    pub center_x: f64,
+    /// Vertical position of the center
+    pub center_y: f64,
+    /// Inner radius (0 for a pie)
+    pub inner_radius: f64,
+    /// Outer radius
+    pub outer_radius: f64,
+    /// Start angle in radians (0 = 12 o'clock, clockwise)
+    pub start_angle: f64,
+    /// End angle in radians, never below the start angle
+    pub end_angle: f64,
+}
+
+/// Heatmap cell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellGeometry {
+    /// Column in the grid
+    pub column: usize,
+    /// Row in the grid
+    pub row: usize,
+    /// Left edge
+    pub x: f64,
+    /// Top edge
+    pub y: f64,
+    /// Width
+    pub width: f64,
+    /// Height
+    pub height: f64,
+}
+
+/// Scales, ticks and marks of a chart, as computed by `compute_layout()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartGeometry {
+    /// Width of the plot area
+    pub plot_width: f64,
+    /// Height of the plot area
+    pub plot_height: f64,
+    /// Horizontal axis
+    pub x_axis: AxisGeometry,
+    /// Vertical axis
+    pub y_axis: AxisGeometry,
+    /// Secondary vertical axis, if any series uses it
+    pub y2_axis: Option<AxisGeometry>,
+    /// Markers and line vertices
+    pub points: Vec<PointGeometry>,
+    /// Line paths
+    pub paths: Vec<PathGeometry>,
+    /// Bars
+    pub rects: Vec<RectGeometry>,
+    /// Pie slices
+    pub arcs: Vec<ArcGeometry>,
+    /// Heatmap cells
+    pub cells: Vec<CellGeometry>,
+}
+
+impl ChartGeometry {
+    pub(crate) fn new(
+        plot_width: f64,
+        plot_height: f64,
+        x_axis: AxisGeometry,
+        y_axis: AxisGeometry,
+    ) -> Self {
+        Self {
+            plot_width,
+            plot_height,
+            x_axis,
+            y_axis,
+            y2_axis: None,
+            points: Vec::new(),
+            paths: Vec::new(),
+            rects: Vec::new(),
+            arcs: Vec::new(),
+            cells: Vec::new(),
+        }
+    }
+
+    /// Add the points of a series, and its line paths when `line` is set.
+    ///
+    /// Points with a NaN coordinate are skipped and break the path.
+    pub(crate) fn push_series(&mut self, series: usize, data: &[(f64, f64)], y2: bool, line: bool) {
+        let y_axis = match &self.y2_axis {
+            Some(axis) if y2 => axis,
+            _ => &self.y_axis,
+        };
+        let mut points = Vec::new();
+        let mut paths = Vec::new();
+        let mut vertices = Vec::new();
+        for (index, &(x, y)) in data.iter().enumerate() {
+            if x.is_nan() || y.is_nan() {
+                paths.push(std::mem::take(&mut vertices));
+                continue;
+            }
+            let (x, y) = (self.x_axis.position(x), y_axis.position(y));
+            points.push(PointGeometry {
+                series,
+                index,
+                x,
+                y,
+            });
+            vertices.push((x, y));
+        }
+        paths.push(vertices);
+
+        self.points.extend(points);
+        if line {
+            self.paths.extend(
+                paths
+                    .into_iter()
+                    .filter(|vertices| !vertices.is_empty())
+                    .map(|vertices| PathGeometry { series, vertices }),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_geometry_positions_ticks() {
+        let axis = AxisGeometry::new(ScaleType::Linear, (0.0, 10.0), (0.0, 100.0), None, 5);
+        assert_eq!(axis.position(5.0), 50.0);
+        assert_eq!(axis.value_at(25.0), Some(2.5));
+        assert!(
+            axis.ticks
+                .iter()
+                .all(|tick| (tick.position - tick.value * 10.0).abs() < 1e-9)
+        );
+
+        let log = AxisGeometry::new(
+            ScaleType::Log,
+            (1.0, 100.0),
+            (200.0, 0.0),
+            Some(vec![1.0, 10.0, 100.0]),
+            10,
+        );
+        let positions: Vec<f64> = log.ticks.iter().map(|tick| tick.position).collect();
+        assert_eq!(positions, vec![200.0, 100.0, 0.0]);
+    }
+
+    #[test]
+    fn test_push_series_breaks_paths_at_gaps() {
+        let axis = AxisGeometry::new(ScaleType::Linear, (0.0, 4.0), (0.0, 4.0), None, 5);
+        let mut geometry = ChartGeometry::new(4.0, 4.0, axis.clone(), axis);
+        let data = [(0.0, 0.0), (1.0, 1.0), (2.0, f64::NAN), (3.0, 3.0)];
+        geometry.push_series(0, &data, false, true);
+        assert_eq!(geometry.points.len(), 3);
+        assert_eq!(geometry.points[2].index, 3);
+        assert_eq!(geometry.paths.len(), 2);
+        assert_eq!(geometry.paths[1].vertices, vec![(3.0, 3.0)]);
+    }
+}
@@ -1,19 +1,137 @@
 //! Heatmap chart - Plotly Express style API.
 
+use crate::async_chart::AsyncChart;
 use crate::color_scale::ColorScale;
+use crate::colorbar::{CLASS_LEGEND_WIDTH, COLORBAR_WIDTH, render_class_legend, render_colorbar};
 use crate::error::ChartError;
+use crate::geometry::{AxisGeometry, CellGeometry, ChartGeometry};
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::theme::{ChartAxisTheme, ChartTheme};
+use crate::zoom::{ChartZoom, ZoomHome, build_zoomable, chart_zoom_methods};
 use crate::{
-    DEFAULT_HEIGHT, DEFAULT_TITLE_FONT_SIZE, DEFAULT_WIDTH, ScaleType, TITLE_AREA_HEIGHT,
-    extent_padded, validate_data_array, validate_dimensions, validate_grid_dimensions,
-    validate_monotonic, validate_positive,
+    ChartDefaults, DEFAULT_TITLE_FONT_SIZE, ScaleType, TITLE_AREA_HEIGHT, extent_padded,
+    validate_data_array, validate_data_with_gaps, validate_dimensions, validate_grid_dimensions,
+    validate_monotonic, validate_plot_area, validate_positive,
 };
-use d3rs::axis::{AxisConfig, DefaultAxisTheme, render_axis};
+use d3rs::axis::{AxisConfig, render_axis};
+use d3rs::color::D3Color;
 use d3rs::grid::{GridConfig, render_grid};
-use d3rs::scale::{LinearScale, LogScale};
-use d3rs::shape::{ContourConfig, HeatmapData, render_heatmap};
+use d3rs::interpolate::{GridInterpolation, resample_grid, resampled_coordinates};
+use d3rs::scale::{LinearScale, LogScale, Scale};
+use d3rs::shape::{ContourConfig, HeatmapData, NoDataStyle, render_heatmap};
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
-use gpui::{AnyElement, IntoElement, div, hsla, px, rgb};
+use gpui::{AnyElement, App, Entity, IntoElement, Window, div, hsla, px};
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Callback receiving the active classes after a class legend click
+pub type ClassToggleCallback = Rc<dyn Fn(&HashSet<usize>, &mut Window, &mut App)>;
+
+/// How cells of inactive classes are drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum HiddenClassStyle {
+    /// Leave the cells out.
+    Hide,
+    /// Draw the cells faded, keeping the overall shape visible.
+    #[default]
+    Dim,
+}
+
+impl HiddenClassStyle {
+    /// Opacity factor of the cells.
+    fn opacity(self) -> f32 {
+        match self {
+            HiddenClassStyle::Hide => 0.0,
+            HiddenClassStyle::Dim => 0.15,
+        }
+    }
+}
+
+/// Plot area and domains of a heatmap.
+struct HeatmapFrame {
+    title_height: f32,
+    plot_width: f64,
+    plot_height: f64,
+    x_domain: (f64, f64),
+    y_domain: (f64, f64),
+    z_domain: (f64, f64),
+    x_values: Vec<f64>,
+    y_values: Vec<f64>,
+}
+
+/// Pixels per cell of the grid resampled by `build_async`, the step of the
+/// GPU smoothing mesh
+const RESAMPLED_CELL_PX: f64 = 2.0;
+
+/// Heatmap grid resampled ahead of rendering
+#[derive(Clone)]
+struct ResampledGrid {
+    x_values: Vec<f64>,
+    y_values: Vec<f64>,
+    z: Vec<f64>,
+    mask: Option<Vec<bool>>,
+}
+
+impl ResampledGrid {
+    /// Resample a `width * height` grid with `mode` to about one cell per
+    /// [`RESAMPLED_CELL_PX`] of a `plot_width * plot_height` plot
+    ///
+    /// Axis values are interpolated in scale space, so the new cells are
+    /// spread evenly within each old one as drawn.
+    fn new(
+        z: &[f64],
+        mask: Option<&[bool]>,
+        (width, height): (usize, usize),
+        (x_values, y_values): (&[f64], &[f64]),
+        (x_scale, y_scale): (ScaleType, ScaleType),
+        (plot_width, plot_height): (f64, f64),
+        mode: GridInterpolation,
+    ) -> Self {
+        let new_width = ((plot_width / RESAMPLED_CELL_PX).ceil() as usize).max(1);
+        let new_height = ((plot_height / RESAMPLED_CELL_PX).ceil() as usize).max(1);
+        let us = resampled_coordinates(width, new_width);
+        let vs = resampled_coordinates(height, new_height);
+        let mask = mask.map(|mask| {
+            vs.iter()
+                .flat_map(|&v| {
+                    let row = (v.round() as usize).min(height - 1) * width;
+                    us.iter()
+                        .map(move |&u| mask[row + (u.round() as usize).min(width - 1)])
+                })
+                .collect()
+        });
+        Self {
+            x_values: resample_axis(x_values, &us, x_scale),
+            y_values: resample_axis(y_values, &vs, y_scale),
+            z: resample_grid(z, width, height, new_width, new_height, mode),
+            mask,
+        }
+    }
+
+    fn into_data(self) -> HeatmapData {
+        let data = HeatmapData::new(self.x_values, self.y_values, self.z);
+        match self.mask {
+            Some(mask) => data.with_mask(mask),
+            None => data,
+        }
+    }
+}
+
+/// Axis `values` at the cell coordinates `at`, interpolated in scale space
+fn resample_axis(values: &[f64], at: &[f64], scale: ScaleType) -> Vec<f64> {
+    let log = scale == ScaleType::Log;
+    let forward = |v: f64| if log { v.ln() } else { v };
+    let inverse = |v: f64| if log { v.exp() } else { v };
+    let last = values.len() - 1;
+    at.iter()
+        .map(|&u| {
+            let i = (u.floor() as usize).min(last);
+            let (lo, hi) = (forward(values[i]), forward(values[(i + 1).min(last)]));
+            inverse(lo + (hi - lo) * (u - i as f64))
+        })
+        .collect()
+}
 
 /// Heatmap chart builder.
 #[derive(Clone)]
@@ -26,6 +144,15 @@ pub struct HeatmapChart {
     x_scale_type: ScaleType,
     y_scale_type: ScaleType,
     color_scale: ColorScale,
+    show_colorbar: bool,
+    show_class_legend: bool,
+    /// Classes drawn normally; all of them when `None`
+    active_classes: Option<HashSet<usize>>,
+    hidden_class_style: HiddenClassStyle,
+    on_class_toggle: Option<ClassToggleCallback>,
+    no_data: NoDataStyle,
+    interpolation: GridInterpolation,
+    mask: Option<Vec<bool>>,
     title: Option<String>,
     opacity: f32,
     width: f32,
@@ -33,6 +160,10 @@ pub struct HeatmapChart {
     // Axis range overrides (for zoom support)
     x_range: Option<[f64; 2]>,
     y_range: Option<[f64; 2]>,
+    layout: ChartLayout,
+    zoom: ChartZoom,
+    /// Grid resampled to the plot resolution by `build_async`
+    resampled: Option<ResampledGrid>,
 }
 
 impl std::fmt::Debug for HeatmapChart {
@@ -43,6 +174,14 @@ impl std::fmt::Debug for HeatmapChart {
             .field("x_scale_type", &self.x_scale_type)
             .field("y_scale_type", &self.y_scale_type)
             .field("color_scale", &self.color_scale)
+            .field("show_colorbar", &self.show_colorbar)
+            .field("show_class_legend", &self.show_class_legend)
+            .field("active_classes", &self.active_classes)
+            .field("hidden_class_style", &self.hidden_class_style)
+            .field("no_data", &self.no_data)
+            .field("interpolation", &self.interpolation)
+            .field("masked", &self.mask.is_some())
+            .field("resampled", &self.resampled.is_some())
             .field("title", &self.title)
             .field("opacity", &self.opacity)
             .field("width", &self.width)
@@ -52,6 +191,9 @@ impl std::fmt::Debug for HeatmapChart {
 }
 
 impl HeatmapChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+    chart_zoom_methods!(zoom);
+
     /// Set custom x axis values.
     ///
     /// Values must be strictly monotonically increasing.
@@ -88,6 +230,96 @@ impl HeatmapChart {
         self
     }
 
+    /// Show a colorbar right of the plot (default: false).
+    ///
+    /// Steps follow the classes of the color scale, if any.
+    pub fn colorbar(mut self, show: bool) -> Self {
+        self.show_colorbar = show;
+        self
+    }
+
+    /// Show a clickable legend of the color classes right of the plot
+    /// (default: false).
+    ///
+    /// Needs a scale quantized with [`ColorScale::classes`]; replaces the
+    /// colorbar. Clicking an item toggles its class, see
+    /// [`HeatmapChart::on_class_toggle`].
+    pub fn class_legend(mut self, show: bool) -> Self {
+        self.show_class_legend = show;
+        self
+    }
+
+    /// Set the classes drawn normally, 0 being the lowest values.
+    ///
+    /// Cells of the other classes are drawn with the
+    /// [`HeatmapChart::hidden_class_style`]. All classes are active by
+    /// default.
+    pub fn active_classes(mut self, classes: impl IntoIterator<Item = usize>) -> Self {
+        self.active_classes = Some(classes.into_iter().collect());
+        self
+    }
+
+    /// Set how cells of inactive classes are drawn (default: dimmed).
+    pub fn hidden_class_style(mut self, style: HiddenClassStyle) -> Self {
+        self.hidden_class_style = style;
+        self
+    }
+
+    /// Set callback for when a class legend item is clicked.
+    ///
+    /// The callback receives the active classes with the clicked one
+    /// toggled. Pass them back with [`HeatmapChart::active_classes`] on the
+    /// next render to apply the filter.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let chart = heatmap(&z, 64, 32)
+    ///     .color_scale(ColorScale::Viridis.classes(5))
+    ///     .class_legend(true)
+    ///     .active_classes(self.active.iter().copied())
+    ///     .on_class_toggle(cx.listener(|this, active, _, cx| {
+    ///         this.active = active.clone();
+    ///         cx.notify();
+    ///     }))
+    ///     .build()?;
+    /// ```
+    pub fn on_class_toggle<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&HashSet<usize>, &mut Window, &mut App) + 'static,
+    {
+        self.on_class_toggle = Some(Rc::new(callback));
+        self
+    }
+
+    /// Set how cells with missing (NaN) z values are drawn.
+    ///
+    /// Defaults to [`NoDataStyle::Transparent`].
+    pub fn no_data(mut self, style: NoDataStyle) -> Self {
+        self.no_data = style;
+        self
+    }
+
+    /// Set how the grid is upsampled to screen resolution.
+    ///
+    /// [`GridInterpolation::Nearest`] (default) keeps cells crisp, e.g. for
+    /// confusion matrices; bilinear and bicubic blend neighbouring cells,
+    /// e.g. for spectrograms. Smoothing is rendered on the GPU and needs the
+    /// `gpu-2d` feature; hatched no-data cells are not drawn when smoothing.
+    pub fn interpolation(mut self, interpolation: GridInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Blank the cells whose mask entry is `true`.
+    ///
+    /// The mask is row-major like `z` and must have the same length.
+    /// Masked cells are not drawn at all and do not count towards the
+    /// color range.
+    pub fn mask(mut self, mask: &[bool]) -> Self {
+        self.mask = Some(mask.to_vec());
+        self
+    }
+
     /// Set chart title (rendered at top of chart).
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -124,16 +356,132 @@ impl HeatmapChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
-        // Validate inputs
-        validate_data_array(&self.z, "z")?;
+    /// Build the chart with its grid resampled to the plot resolution on the
+    /// background executor.
+    ///
+    /// When [smoothing](Self::interpolation) with the `gpu-2d` feature, the
+    /// grid is upsampled ahead of time and only blended bilinearly when
+    /// painted, which keeps bicubic and large grids cheap to redraw; zooming
+    /// in shows the resampled grid. The data is validated right away; the
+    /// returned [`AsyncChart`] shows a placeholder until the grid is ready.
+    pub fn build_async(self, cx: &mut App) -> Result<Entity<AsyncChart>, ChartError> {
+        let frame = self.plot_frame()?;
+        let resample = cfg!(feature = "gpu-2d") && self.interpolation != GridInterpolation::Nearest;
+        // Masked cells become gaps, so their values do not bleed into the
+        // visible ones
+        let z: Vec<f64> = match &self.mask {
+            Some(mask) => self
+                .z
+                .iter()
+                .zip(mask)
+                .map(|(&z, &masked)| if masked { f64::NAN } else { z })
+                .collect(),
+            None => self.z.clone(),
+        };
+        let mask = self.mask.clone();
+        let grid_size = (self.grid_width, self.grid_height);
+        let scales = (self.x_scale_type, self.y_scale_type);
+        let mode = self.interpolation;
+        let (layout, size) = (self.layout.clone(), (self.width, self.height));
+        Ok(AsyncChart::spawn(
+            &layout,
+            size,
+            move || {
+                resample.then(|| {
+                    ResampledGrid::new(
+                        &z,
+                        mask.as_deref(),
+                        grid_size,
+                        (&frame.x_values, &frame.y_values),
+                        scales,
+                        (frame.plot_width, frame.plot_height),
+                        mode,
+                    )
+                })
+            },
+            move |resampled: &Option<ResampledGrid>| {
+                let mut chart = self.clone();
+                chart.resampled = resampled.clone();
+                chart.build().map(IntoElement::into_any_element)
+            },
+            cx,
+        ))
+    }
+
+    /// Compute the scales, ticks and cell rectangles without rendering.
+    ///
+    /// Runs the same validation and layout as [`HeatmapChart::build`] at the
+    /// chart's own size. Masked cells are left out; missing cells are kept,
+    /// as they are drawn with the no-data style.
+    pub fn compute_layout(&self) -> Result<ChartGeometry, ChartError> {
+        let frame = self.plot_frame()?;
+        let (plot_width, plot_height) = (frame.plot_width, frame.plot_height);
+        let x_axis = AxisGeometry::new(
+            self.x_scale_type,
+            frame.x_domain,
+            (0.0, plot_width),
+            None,
+            10,
+        );
+        let y_axis = AxisGeometry::new(
+            self.y_scale_type,
+            frame.y_domain,
+            (plot_height, 0.0),
+            None,
+            10,
+        );
+
+        let mut data = HeatmapData::new(frame.x_values, frame.y_values, self.z.clone());
+        if let Some(mask) = self.mask.clone() {
+            data = data.with_mask(mask);
+        }
+        let mut cells = Vec::new();
+        for row in 0..self.grid_height {
+            for column in 0..self.grid_width {
+                if data.is_masked(column, row) {
+                    continue;
+                }
+                let Some(((x0, x1), (y0, y1))) = data.cell_extent(column, row) else {
+                    continue;
+                };
+                let (x0, x1) = (x_axis.position(x0), x_axis.position(x1));
+                let (y0, y1) = (y_axis.position(y0), y_axis.position(y1));
+                cells.push(CellGeometry {
+                    column,
+                    row,
+                    x: x0.min(x1),
+                    y: y0.min(y1),
+                    width: (x1 - x0).abs(),
+                    height: (y1 - y0).abs(),
+                });
+            }
+        }
+
+        let mut geometry = ChartGeometry::new(plot_width, plot_height, x_axis, y_axis);
+        geometry.cells = cells;
+        Ok(geometry)
+    }
+
+    /// Validate the data and lay out the plot area and domains.
+    fn plot_frame(&self) -> Result<HeatmapFrame, ChartError> {
+        // Validate inputs; NaN marks a missing cell
+        validate_data_with_gaps(&self.z, "z")?;
         validate_grid_dimensions(&self.z, self.grid_width, self.grid_height)?;
         validate_dimensions(self.width, self.height)?;
+        if let Some(mask) = &self.mask
+            && mask.len() != self.z.len()
+        {
+            return Err(ChartError::DataLengthMismatch {
+                x_field: "mask",
+                y_field: "z",
+                x_len: mask.len(),
+                y_len: self.z.len(),
+            });
+        }
 
         // Generate or validate x values
-        let x_values = match self.x_values {
-            Some(ref v) => {
+        let x_values = match &self.x_values {
+            Some(v) => {
                 if v.len() != self.grid_width {
                     return Err(ChartError::DataLengthMismatch {
                         x_field: "x",
@@ -153,8 +501,8 @@ impl HeatmapChart {
         };
 
         // Generate or validate y values
-        let y_values = match self.y_values {
-            Some(ref v) => {
+        let y_values = match &self.y_values {
+            Some(v) => {
                 if v.len() != self.grid_height {
                     return Err(ChartError::DataLengthMismatch {
                         x_field: "y",
@@ -177,7 +525,19 @@ impl HeatmapChart {
         let margin_left = 50.0;
         let margin_bottom = 30.0;
         let margin_top = 10.0;
-        let margin_right = 20.0;
+        if self.show_class_legend && self.color_scale.class_count().is_none() {
+            return Err(ChartError::InvalidData {
+                field: "color_scale",
+                reason: "class legend needs a scale with classes",
+            });
+        }
+        let margin_right = if self.show_class_legend {
+            20.0 + CLASS_LEGEND_WIDTH
+        } else if self.show_colorbar {
+            20.0 + COLORBAR_WIDTH
+        } else {
+            20.0
+        };
 
         // Calculate plot area (reserve space for title if present)
         let title_height = if self.title.is_some() {
@@ -189,30 +549,107 @@ impl HeatmapChart {
         let plot_width = (self.width as f64 - margin_left - margin_right).max(0.0);
         let plot_height =
             (self.height as f64 - title_height as f64 - margin_top - margin_bottom).max(0.0);
+        validate_plot_area(plot_width, plot_height)?;
 
         // Calculate domains with padding, or use explicit ranges if set
-        let (x_min, x_max) = if let Some([min, max]) = self.x_range {
+        let x_domain = if let Some([min, max]) = self.x_range {
             (min, max)
         } else {
             extent_padded(&x_values, 0.0)
         };
-        let (y_min, y_max) = if let Some([min, max]) = self.y_range {
+        let y_domain = if let Some([min, max]) = self.y_range {
             (min, max)
         } else {
             extent_padded(&y_values, 0.0)
         };
 
-        // Create HeatmapData
-        let heatmap_data = HeatmapData::new(x_values, y_values, self.z.clone());
+        // The color scale spans the visible data only
+        let visible_z: Vec<f64> = match &self.mask {
+            Some(mask) => self
+                .z
+                .iter()
+                .zip(mask)
+                .filter(|&(_, &masked)| !masked)
+                .map(|(&z, _)| z)
+                .collect(),
+            None => self.z.clone(),
+        };
+        if !visible_z.iter().any(|z| z.is_finite()) {
+            return Err(ChartError::InvalidData {
+                field: "z",
+                reason: "contains only masked or missing values",
+            });
+        }
+
+        Ok(HeatmapFrame {
+            title_height,
+            plot_width,
+            plot_height,
+            x_domain,
+            y_domain,
+            z_domain: extent_padded(&visible_z, 0.0),
+            x_values,
+            y_values,
+        })
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<AnyElement, ChartError> {
+        if let Some(id) = self.zoom.id.take() {
+            let frame = self.plot_frame()?;
+            let home = ZoomHome {
+                x_domain: frame.x_domain,
+                y_domain: frame.y_domain,
+                x_scale: self.x_scale_type,
+                y_scale: self.y_scale_type,
+            };
+            let size = (self.width, self.height);
+            return Ok(build_zoomable(id, home, self, size, |chart, state| {
+                let ((x_min, x_max), (y_min, y_max)) = (state.x_domain(), state.y_domain());
+                let mut chart = chart.x_range(x_min, x_max).y_range(y_min, y_max);
+                chart.zoom.state = Some(state.clone());
+                chart.build_chart()
+            }));
+        }
+        let HeatmapFrame {
+            title_height,
+            plot_width,
+            plot_height,
+            x_domain: (x_min, x_max),
+            y_domain: (y_min, y_max),
+            z_domain: (z_min, z_max),
+            x_values,
+            y_values,
+        } = self.plot_frame()?;
+        let (color_min, color_max) = self.color_scale.value_domain(z_min, z_max);
+
+        // Draw the grid resampled by `build_async`, which only needs a
+        // bilinear blend, or let the renderer upsample the data grid
+        let (heatmap_data, interpolation) = match self.resampled.take() {
+            Some(grid) => (grid.into_data(), GridInterpolation::Bilinear),
+            None => {
+                let data = HeatmapData::new(x_values, y_values, self.z.clone());
+                match self.mask.clone() {
+                    Some(mask) => (data.with_mask(mask), self.interpolation),
+                    None => (data, self.interpolation),
+                }
+            }
+        };
 
-        // Build config with color scale
-        let color_fn = self.color_scale.to_fn();
+        // Build config with color scale, fading the inactive classes
+        let color_fn = class_filtered_colors(
+            &self.color_scale,
+            self.active_classes.clone(),
+            self.hidden_class_style,
+        );
         let config = ContourConfig::new()
             .fill(true)
             .fill_opacity(self.opacity)
-            .color_scale(color_fn);
+            .color_scale(color_fn)
+            .no_data(self.no_data)
+            .interpolation(interpolation);
 
-        let theme = DefaultAxisTheme;
+        let theme = ChartTheme::current().axis_theme();
 
         // Build the element based on scale types
         let chart_content: AnyElement = match (self.x_scale_type, self.y_scale_type) {
@@ -242,7 +679,7 @@ impl HeatmapChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -251,17 +688,17 @@ impl HeatmapChart {
                                         plot_height as f32,
                                         &theme,
                                     ))
-                                    .child(
-                                        div().absolute().inset_0().size_full().child(
-                                            render_heatmap(
-                                                heatmap_data,
-                                                &x_scale,
-                                                &y_scale,
-                                                &config,
-                                            )
-                                            .height(px(plot_height as f32)),
+                                    .child(div().absolute().inset_0().size_full().child(
+                                        heatmap_element(
+                                            heatmap_data,
+                                            &x_scale,
+                                            &y_scale,
+                                            &config,
+                                            (color_min, color_max),
+                                            plot_height as f32,
                                         ),
-                                    ),
+                                    ))
+                                    .children(self.zoom.probe()),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -298,7 +735,7 @@ impl HeatmapChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -307,17 +744,17 @@ impl HeatmapChart {
                                         plot_height as f32,
                                         &theme,
                                     ))
-                                    .child(
-                                        div().absolute().inset_0().size_full().child(
-                                            render_heatmap(
-                                                heatmap_data,
-                                                &x_scale,
-                                                &y_scale,
-                                                &config,
-                                            )
-                                            .height(px(plot_height as f32)),
+                                    .child(div().absolute().inset_0().size_full().child(
+                                        heatmap_element(
+                                            heatmap_data,
+                                            &x_scale,
+                                            &y_scale,
+                                            &config,
+                                            (color_min, color_max),
+                                            plot_height as f32,
                                         ),
-                                    ),
+                                    ))
+                                    .children(self.zoom.probe()),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -354,7 +791,7 @@ impl HeatmapChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -363,17 +800,17 @@ impl HeatmapChart {
                                         plot_height as f32,
                                         &theme,
                                     ))
-                                    .child(
-                                        div().absolute().inset_0().size_full().child(
-                                            render_heatmap(
-                                                heatmap_data,
-                                                &x_scale,
-                                                &y_scale,
-                                                &config,
-                                            )
-                                            .height(px(plot_height as f32)),
+                                    .child(div().absolute().inset_0().size_full().child(
+                                        heatmap_element(
+                                            heatmap_data,
+                                            &x_scale,
+                                            &y_scale,
+                                            &config,
+                                            (color_min, color_max),
+                                            plot_height as f32,
                                         ),
-                                    ),
+                                    ))
+                                    .children(self.zoom.probe()),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -410,7 +847,7 @@ impl HeatmapChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -419,17 +856,17 @@ impl HeatmapChart {
                                         plot_height as f32,
                                         &theme,
                                     ))
-                                    .child(
-                                        div().absolute().inset_0().size_full().child(
-                                            render_heatmap(
-                                                heatmap_data,
-                                                &x_scale,
-                                                &y_scale,
-                                                &config,
-                                            )
-                                            .height(px(plot_height as f32)),
+                                    .child(div().absolute().inset_0().size_full().child(
+                                        heatmap_element(
+                                            heatmap_data,
+                                            &x_scale,
+                                            &y_scale,
+                                            &config,
+                                            (color_min, color_max),
+                                            plot_height as f32,
                                         ),
-                                    ),
+                                    ))
+                                    .children(self.zoom.probe()),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -465,16 +902,135 @@ impl HeatmapChart {
             );
         }
 
-        // Add chart content
+        // Add chart content, with the class legend or colorbar beside the plot
+        let class_legend = self
+            .show_class_legend
+            .then(|| self.class_legend_element(z_min, z_max, plot_height, &theme))
+            .flatten();
+        let chart_content = if let Some(class_legend) = class_legend {
+            div()
+                .flex()
+                .child(chart_content)
+                .child(class_legend)
+                .into_any_element()
+        } else if self.show_colorbar {
+            div()
+                .flex()
+                .child(chart_content)
+                .child(render_colorbar(
+                    &self.color_scale,
+                    z_min,
+                    z_max,
+                    plot_height as f32,
+                    &theme,
+                ))
+                .into_any_element()
+        } else {
+            chart_content
+        };
         container = container.child(div().relative().child(chart_content));
 
-        Ok(container)
+        Ok(container.into_any_element())
+    }
+
+    /// Class legend for data spanning `z_min..=z_max`, reporting clicks to
+    /// the class toggle callback.
+    fn class_legend_element(
+        &self,
+        z_min: f64,
+        z_max: f64,
+        plot_height: f64,
+        theme: &ChartAxisTheme,
+    ) -> Option<AnyElement> {
+        let count = self.color_scale.class_count()?;
+        let on_click = self.on_class_toggle.clone().map(|callback| {
+            let active = self.active_classes.clone();
+            Rc::new(move |class: usize, window: &mut Window, cx: &mut App| {
+                let active = toggle_class(active.as_ref(), count, class);
+                callback(&active, window, cx);
+            }) as Rc<dyn Fn(usize, &mut Window, &mut App)>
+        });
+        let active = self.active_classes.as_ref();
+        let legend = render_class_legend(
+            &self.color_scale,
+            z_min,
+            z_max,
+            |class| active.is_none_or(|active| active.contains(&class)),
+            on_click,
+            theme,
+        )?;
+        Some(
+            div()
+                .h(px(plot_height as f32))
+                .child(legend)
+                .into_any_element(),
+        )
+    }
+}
+
+/// Active classes after toggling `class`, out of `count` classes
+///
+/// `None` means all classes are active.
+fn toggle_class(active: Option<&HashSet<usize>>, count: usize, class: usize) -> HashSet<usize> {
+    let mut active = active.cloned().unwrap_or_else(|| (0..count).collect());
+    if !active.remove(&class) {
+        active.insert(class);
+    }
+    active
+}
+
+/// Color function of `scale` fading the cells of classes not in `active`
+fn class_filtered_colors(
+    scale: &ColorScale,
+    active: Option<HashSet<usize>>,
+    style: HiddenClassStyle,
+) -> impl Fn(f64) -> D3Color + Send + Sync + Clone + 'static {
+    let scale = scale.clone();
+    move |t: f64| {
+        let mut color = scale.map(t);
+        let hidden = match (&active, scale.class_of(t)) {
+            (Some(active), Some(class)) => !active.contains(&class),
+            _ => false,
+        };
+        if hidden {
+            color.a *= style.opacity();
+        }
+        color
+    }
+}
+
+/// Element of the heatmap cells, colored over `value_range`
+///
+/// Smooth interpolation is drawn by the GPU renderer, which upsamples the
+/// grid to screen resolution; without the `gpu-2d` feature, cells are drawn
+/// as with nearest interpolation.
+fn heatmap_element<XS, YS>(
+    data: HeatmapData,
+    x_scale: &XS,
+    y_scale: &YS,
+    config: &ContourConfig,
+    value_range: (f64, f64),
+    height: f32,
+) -> AnyElement
+where
+    XS: Scale<f64, f64> + Clone + 'static,
+    YS: Scale<f64, f64> + Clone + 'static,
+{
+    #[cfg(feature = "gpu-2d")]
+    if config.interpolation != GridInterpolation::Nearest {
+        return d3rs::gpu2d::render_heatmap_in_range(data, x_scale, y_scale, config, value_range)
+            .into_any_element();
     }
+    render_heatmap(data, x_scale, y_scale, config)
+        .value_range(value_range.0, value_range.1)
+        .height(px(height))
+        .into_any_element()
 }
 
 /// Create a heatmap chart from z data with grid dimensions.
 ///
 /// Data is in row-major order: `z[row * width + col]` where row 0 is at the bottom.
+/// NaN values mark missing cells, drawn with [`HeatmapChart::no_data`].
 ///
 /// # Example
 ///
@@ -513,6 +1069,7 @@ impl HeatmapChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn heatmap(z: &[f64], grid_width: usize, grid_height: usize) -> HeatmapChart {
+    let defaults = ChartDefaults::current();
     HeatmapChart {
         z: z.to_vec(),
         grid_width,
@@ -522,12 +1079,23 @@ pub fn heatmap(z: &[f64], grid_width: usize, grid_height: usize) -> HeatmapChart
         x_scale_type: ScaleType::Linear,
         y_scale_type: ScaleType::Linear,
         color_scale: ColorScale::default(),
+        show_colorbar: false,
+        show_class_legend: false,
+        active_classes: None,
+        hidden_class_style: HiddenClassStyle::default(),
+        on_class_toggle: None,
+        no_data: NoDataStyle::default(),
+        interpolation: GridInterpolation::default(),
+        mask: None,
         title: None,
         opacity: 1.0,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        width: defaults.width,
+        height: defaults.height,
         x_range: None,
         y_range: None,
+        layout: ChartLayout::default(),
+        zoom: ChartZoom::new(defaults.zoomable),
+        resampled: None,
     }
 }
 
@@ -661,6 +1229,142 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_heatmap_smooth_interpolation() {
+        let z: Vec<f64> = (0..16).map(|i| (i as f64).sin()).collect();
+        for mode in [GridInterpolation::Bilinear, GridInterpolation::Bicubic] {
+            let result = heatmap(&z, 4, 4).interpolation(mode).colorbar(true).build();
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_resampled_grid() {
+        // 100x40 px plot: 50x20 cells over a 2x2 grid
+        let grid = ResampledGrid::new(
+            &[0.0, 1.0, 2.0, 3.0],
+            Some(&[false, false, true, false]),
+            (2, 2),
+            (&[10.0, 1000.0], &[0.0, 1.0]),
+            (ScaleType::Log, ScaleType::Linear),
+            (100.0, 40.0),
+            GridInterpolation::Bilinear,
+        );
+        assert_eq!((grid.x_values.len(), grid.y_values.len()), (50, 20));
+        assert_eq!(grid.z.len(), 1000);
+        // Ends stay on the cell centers, log axes are split evenly
+        assert!((grid.x_values[0] - 10.0).abs() < 1e-9);
+        assert!((grid.x_values[49] - 1000.0).abs() < 1e-9);
+        assert!((resample_axis(&[10.0, 1000.0], &[0.5], ScaleType::Log)[0] - 100.0).abs() < 1e-9);
+        assert_eq!(grid.y_values[19], 1.0);
+        // The mask follows the nearest cell
+        let mask = grid.mask.unwrap();
+        assert!(!mask[0] && mask[19 * 50] && !mask[999]);
+    }
+
+    #[test]
+    fn test_heatmap_centered_classes_with_colorbar() {
+        let z = vec![-3.0, -1.0, 0.0, 2.0, 6.0, 9.0]; // 2x3 grid
+        let result = heatmap(&z, 2, 3)
+            .color_scale(ColorScale::Coolwarm.center(0.0).classes(6))
+            .colorbar(true)
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_heatmap_class_legend() {
+        let z = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]; // 2x3 grid
+        let result = heatmap(&z, 2, 3)
+            .color_scale(ColorScale::Viridis.classes(3))
+            .class_legend(true)
+            .active_classes([0, 2])
+            .hidden_class_style(HiddenClassStyle::Hide)
+            .on_class_toggle(|_, _, _| {})
+            .build();
+        assert!(result.is_ok());
+
+        // A continuous scale has no classes to list
+        let result = heatmap(&z, 2, 3).class_legend(true).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData {
+                field: "color_scale",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_toggle_class() {
+        // All classes start active
+        assert_eq!(toggle_class(None, 3, 1), HashSet::from([0, 2]));
+        let active = HashSet::from([0, 2]);
+        assert_eq!(toggle_class(Some(&active), 3, 1), HashSet::from([0, 1, 2]));
+        assert_eq!(toggle_class(Some(&active), 3, 0), HashSet::from([2]));
+    }
+
+    #[test]
+    fn test_class_filtered_colors() {
+        let scale = ColorScale::Viridis.classes(2);
+        let active = Some(HashSet::from([1]));
+        let dimmed = class_filtered_colors(&scale, active.clone(), HiddenClassStyle::Dim);
+        let hidden = class_filtered_colors(&scale, active, HiddenClassStyle::Hide);
+        // Active classes keep their color
+        assert_eq!(dimmed(0.9), scale.map(0.9));
+        assert!(dimmed(0.1).a > 0.0 && dimmed(0.1).a < scale.map(0.1).a);
+        assert_eq!(hidden(0.1).a, 0.0);
+        // Without a filter every cell is drawn
+        let all = class_filtered_colors(&scale, None, HiddenClassStyle::Hide);
+        assert_eq!(all(0.1), scale.map(0.1));
+    }
+
+    #[test]
+    fn test_heatmap_missing_cells() {
+        let z = vec![1.0, f64::NAN, 3.0, 4.0]; // 2x2 grid
+        let result = heatmap(&z, 2, 2)
+            .no_data(NoDataStyle::Hatch {
+                color: D3Color::from_hex(0x999999),
+                background: D3Color::from_hex(0xeeeeee),
+            })
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_heatmap_infinite_z() {
+        let z = vec![1.0, f64::INFINITY, 3.0, 4.0];
+        let result = heatmap(&z, 2, 2).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData { field: "z", .. })
+        ));
+    }
+
+    #[test]
+    fn test_heatmap_mask() {
+        let z = vec![1.0, 2.0, 3.0, 4.0];
+        let result = heatmap(&z, 2, 2).mask(&[false, true, true, false]).build();
+        assert!(result.is_ok());
+
+        let result = heatmap(&z, 2, 2).mask(&[false, true]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::DataLengthMismatch {
+                x_field: "mask",
+                y_field: "z",
+                x_len: 2,
+                y_len: 4,
+            })
+        ));
+
+        let result = heatmap(&[1.0, f64::NAN], 2, 1).mask(&[true, false]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData { field: "z", .. })
+        ));
+    }
+
     #[test]
     fn test_heatmap_with_explicit_ranges() {
         let z = vec![1.0; 9]; // 3x3 grid
@@ -0,0 +1,310 @@
+//! Hit testing of computed chart geometry.
+//!
+//! [`ChartHitTester`] finds the mark under a pointer position in the
+//! geometry returned by `compute_layout()`: the nearest point through a
+//! quadtree, and the bar, pie slice or heatmap cell containing the pointer
+//! through exact geometric tests.
+//!
+//! ```rust
+//! use gpui_px::{ChartHit, ChartHitTester, scatter};
+//!
+//! let geometry = scatter(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+//!     .size(400.0, 300.0)
+//!     .compute_layout()?;
+//! let point = geometry.points[1];
+//! let tester = ChartHitTester::new(&geometry);
+//! assert!(matches!(
+//!     tester.hit(point.x + 2.0, point.y),
+//!     Some(ChartHit::Point { index: 1, .. })
+//! ));
+//! # Ok::<(), gpui_px::ChartError>(())
+//! ```
+//!
+//! Positions are in pixels from the top-left corner of the plot area, like
+//! the geometry itself.
+
+use crate::geometry::{ArcGeometry, CellGeometry, ChartGeometry, PointGeometry, RectGeometry};
+use d3rs::quadtree::QuadTree;
+use std::f64::consts::TAU;
+
+/// Default distance within which a point is hit, in pixels
+const DEFAULT_RADIUS: f64 = 8.0;
+
+/// Mark found at a pointer position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartHit {
+    /// Data point nearest the pointer
+    Point {
+        /// Series index (0 is the primary series)
+        series: usize,
+        /// Index of the point in its series data
+        index: usize,
+        /// Distance from the pointer, in pixels
+        distance: f64,
+    },
+    /// Bar under the pointer
+    Bar {
+        /// Series index (0 is the primary series)
+        series: usize,
+        /// Category index
+        index: usize,
+    },
+    /// Pie or donut slice under the pointer
+    Slice {
+        /// Index of the slice value
+        index: usize,
+    },
+    /// Heatmap cell under the pointer
+    Cell {
+        /// Column in the grid
+        column: usize,
+        /// Row in the grid
+        row: usize,
+    },
+}
+
+/// Finds the marks of a chart at pointer positions.
+///
+/// Built once per layout; lookups don't allocate.
+#[derive(Debug, Clone)]
+pub struct ChartHitTester {
+    points: QuadTree<PointGeometry>,
+    rects: Vec<RectGeometry>,
+    arcs: Vec<ArcGeometry>,
+    cells: Vec<CellGeometry>,
+    radius: f64,
+}
+
+impl ChartHitTester {
+    /// Index the marks of a computed chart layout.
+    pub fn new(geometry: &ChartGeometry) -> Self {
+        Self {
+            points: QuadTree::from_data(&geometry.points, |p| p.x, |p| p.y),
+            rects: geometry.rects.clone(),
+            arcs: geometry.arcs.clone(),
+            cells: geometry.cells.clone(),
+            radius: DEFAULT_RADIUS,
+        }
+    }
+
+    /// Set the distance within which [`hit`](Self::hit) finds points
+    /// (default 8 pixels).
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = radius.max(0.0);
+        self
+    }
+
+    /// Mark at a pointer position.
+    ///
+    /// Points within the hit radius come first, as they are drawn over
+    /// the other marks; then the bar, slice or cell containing the pointer.
+    pub fn hit(&self, x: f64, y: f64) -> Option<ChartHit> {
+        self.nearest_point(x, y, Some(self.radius))
+            .or_else(|| self.bar_at(x, y))
+            .or_else(|| self.slice_at(x, y))
+            .or_else(|| self.cell_at(x, y))
+    }
+
+    /// Point nearest a pointer position, within `max_distance` if set.
+    pub fn nearest_point(&self, x: f64, y: f64, max_distance: Option<f64>) -> Option<ChartHit> {
+        let point = self.points.find(x, y, max_distance)?;
+        Some(ChartHit::Point {
+            series: point.series,
+            index: point.index,
+            distance: (point.x - x).hypot(point.y - y),
+        })
+    }
+
+    /// Bar containing a pointer position; the last drawn wins on overlap.
+    pub fn bar_at(&self, x: f64, y: f64) -> Option<ChartHit> {
+        self.rects
+            .iter()
+            .rev()
+            .find(|r| contains(r.x, r.y, r.width, r.height, x, y))
+            .map(|r| ChartHit::Bar {
+                series: r.series,
+                index: r.index,
+            })
+    }
+
+    /// Pie slice containing a pointer position.
+    pub fn slice_at(&self, x: f64, y: f64) -> Option<ChartHit> {
+        self.arcs
+            .iter()
+            .find(|arc| arc_contains(arc, x, y))
+            .map(|arc| ChartHit::Slice { index: arc.index })
+    }
+
+    /// Heatmap cell containing a pointer position.
+    pub fn cell_at(&self, x: f64, y: f64) -> Option<ChartHit> {
+        self.cells
+            .iter()
+            .find(|c| contains(c.x, c.y, c.width, c.height, x, y))
+            .map(|c| ChartHit::Cell {
+                column: c.column,
+                row: c.row,
+            })
+    }
+}
+
+/// Whether a rectangle contains a position, right and bottom edges excluded
+/// so that neighbouring marks never both match.
+fn contains(left: f64, top: f64, width: f64, height: f64, x: f64, y: f64) -> bool {
+    x >= left && x < left + width && y >= top && y < top + height
+}
+
+/// Whether a slice contains a position.
+///
+/// Angles start at 12 o'clock and run clockwise, as for the arc generator.
+fn arc_contains(arc: &ArcGeometry, x: f64, y: f64) -> bool {
+    let (dx, dy) = (x - arc.center_x, y - arc.center_y);
+    let distance = dx.hypot(dy);
+    if distance < arc.inner_radius || distance > arc.outer_radius {
+        return false;
+    }
+    // Angle of the position measured from the slice start
+    let angle = (dx.atan2(-dy) - arc.start_angle).rem_euclid(TAU);
+    angle < arc.end_angle - arc.start_angle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bar, heatmap, pie, scatter};
+
+    #[test]
+    fn test_nearest_point_and_radius() {
+        let geometry = scatter(&[0.0, 5.0, 10.0], &[0.0, 5.0, 10.0])
+            .size(400.0, 300.0)
+            .compute_layout()
+            .unwrap();
+        let middle = geometry.points[1];
+        let tester = ChartHitTester::new(&geometry);
+
+        match tester.hit(middle.x + 3.0, middle.y - 4.0) {
+            Some(ChartHit::Point {
+                series,
+                index,
+                distance,
+            }) => {
+                assert_eq!((series, index), (0, 1));
+                assert!((distance - 5.0).abs() < 1e-9);
+            }
+            other => panic!("expected a point, got {other:?}"),
+        }
+        assert_eq!(tester.hit(middle.x + 20.0, middle.y), None);
+        assert!(
+            tester
+                .clone()
+                .radius(30.0)
+                .hit(middle.x + 20.0, middle.y)
+                .is_some()
+        );
+        assert!(matches!(
+            tester.nearest_point(middle.x + 20.0, middle.y, None),
+            Some(ChartHit::Point { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_bar_under_pointer() {
+        let geometry = bar(&["A", "B", "C"], &[1.0, 2.0, 3.0])
+            .size(400.0, 300.0)
+            .compute_layout()
+            .unwrap();
+        let tester = ChartHitTester::new(&geometry);
+        let rect = geometry.rects[1];
+        assert_eq!(
+            tester.hit(rect.x + rect.width / 2.0, rect.y + 1.0),
+            Some(ChartHit::Bar {
+                series: 0,
+                index: 1
+            })
+        );
+        // Above the bar
+        assert_eq!(tester.hit(rect.x + rect.width / 2.0, rect.y - 1.0), None);
+    }
+
+    #[test]
+    fn test_slice_under_pointer() {
+        let geometry = pie(&[1.0, 1.0, 2.0])
+            .sort(false)
+            .hole(0.5)
+            .size(200.0, 200.0)
+            .compute_layout()
+            .unwrap();
+        let tester = ChartHitTester::new(&geometry);
+        let arc = geometry.arcs[0];
+        let r = (arc.inner_radius + arc.outer_radius) / 2.0;
+
+        // Slices run clockwise from 12 o'clock: first quarter, second
+        // quarter, then the left half
+        let at = |angle: f64| {
+            tester.hit(
+                arc.center_x + r * angle.sin(),
+                arc.center_y - r * angle.cos(),
+            )
+        };
+        assert_eq!(at(0.1), Some(ChartHit::Slice { index: 0 }));
+        assert_eq!(at(2.0), Some(ChartHit::Slice { index: 1 }));
+        assert_eq!(at(-0.1), Some(ChartHit::Slice { index: 2 }));
+
+        // Inside the hole and outside the pie
+        assert_eq!(tester.hit(arc.center_x, arc.center_y), None);
+        assert_eq!(
+            tester.hit(arc.center_x + arc.outer_radius + 1.0, arc.center_y),
+            None
+        );
+    }
+
+    #[test]
+    fn test_counter_clockwise_slices() {
+        let geometry = pie(&[1.0, 3.0])
+            .sort(false)
+            .clockwise(false)
+            .size(200.0, 200.0)
+            .compute_layout()
+            .unwrap();
+        let tester = ChartHitTester::new(&geometry);
+        let arc = geometry.arcs[0];
+        let r = arc.outer_radius / 2.0;
+        // The first slice now fills the quarter left of 12 o'clock
+        assert_eq!(
+            tester.hit(arc.center_x - r * 0.5, arc.center_y - r * 0.5),
+            Some(ChartHit::Slice { index: 0 })
+        );
+        assert_eq!(
+            tester.hit(arc.center_x + r * 0.5, arc.center_y - r * 0.5),
+            Some(ChartHit::Slice { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_cell_under_pointer() {
+        let mut mask = vec![false; 6];
+        mask[5] = true;
+        let geometry = heatmap(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2)
+            .mask(&mask)
+            .x_range(0.0, 3.0)
+            .y_range(0.0, 2.0)
+            .size(400.0, 300.0)
+            .compute_layout()
+            .unwrap();
+        assert_eq!(geometry.cells.len(), 5);
+        let tester = ChartHitTester::new(&geometry);
+
+        // Row 0 is at the bottom of the plot
+        let cell = geometry.cells[1];
+        assert_eq!((cell.column, cell.row), (1, 0));
+        assert!(cell.y > geometry.plot_height / 3.0);
+        assert_eq!(
+            tester.hit(cell.x + cell.width / 2.0, cell.y + cell.height / 2.0),
+            Some(ChartHit::Cell { column: 1, row: 0 })
+        );
+
+        // The masked cell is never hit
+        let top = geometry.cells[4];
+        assert_eq!((top.column, top.row), (1, 1));
+        assert_eq!(tester.hit(top.x + top.width * 1.5, top.y + 1.0), None);
+    }
+}
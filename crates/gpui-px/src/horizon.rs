@@ -0,0 +1,418 @@
+//! Horizon chart - Plotly Express style API.
+//!
+//! A horizon chart folds a time series into a compact strip: the value range
+//! is split into bands that are layered on top of each other with increasing
+//! color intensity, and negative values are either mirrored upwards or hung
+//! from the top edge.
+
+use crate::color_scale::ColorScale;
+use crate::error::ChartError;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::{
+    ChartDefaults, DEFAULT_TITLE_FONT_SIZE, TITLE_AREA_HEIGHT, validate_data_array,
+    validate_dimensions,
+};
+use d3rs::color::D3Color;
+use d3rs::text::{VectorFontConfig, render_vector_text};
+use gpui::prelude::*;
+use gpui::{IntoElement, PathBuilder, canvas, div, hsla, point, px};
+use std::collections::VecDeque;
+
+/// Default horizon chart height in pixels (a single folded strip)
+const DEFAULT_HORIZON_HEIGHT: f32 = 60.0;
+
+/// How negative values are drawn in a horizon chart.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum HorizonMode {
+    /// Negative values are flipped and drawn upwards from the baseline (default).
+    #[default]
+    Mirror,
+    /// Negative values hang down from the top edge of the strip.
+    Offset,
+}
+
+/// Horizon chart builder.
+///
+/// Values are kept in a ring buffer so the chart can be held in view state and
+/// fed incrementally with [`HorizonChart::push`]; only the evicted sample can
+/// invalidate the cached extent.
+#[derive(Clone)]
+pub struct HorizonChart {
+    values: VecDeque<f64>,
+    /// Maximum number of samples kept (oldest samples are dropped first)
+    capacity: Option<usize>,
+    /// Cached maximum absolute value of `values`
+    max_abs: f64,
+    /// Fixed extent overriding `max_abs`
+    extent: Option<f64>,
+    bands: usize,
+    mode: HorizonMode,
+    positive_scale: ColorScale,
+    negative_scale: ColorScale,
+    title: Option<String>,
+    width: f32,
+    height: f32,
+    layout: ChartLayout,
+}
+
+impl std::fmt::Debug for HorizonChart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HorizonChart")
+            .field("len", &self.values.len())
+            .field("capacity", &self.capacity)
+            .field("bands", &self.bands)
+            .field("mode", &self.mode)
+            .field("title", &self.title)
+            .finish()
+    }
+}
+
+impl HorizonChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
+    /// Set chart title (rendered at top of chart).
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the number of bands the value range is folded into (default: 4).
+    pub fn bands(mut self, bands: usize) -> Self {
+        self.bands = bands;
+        self
+    }
+
+    /// Set how negative values are drawn.
+    pub fn mode(mut self, mode: HorizonMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the color scale for positive bands.
+    ///
+    /// Band `i` (0-based) of `n` uses the color at `(i + 1) / n`.
+    pub fn positive_color_scale(mut self, scale: ColorScale) -> Self {
+        self.positive_scale = scale;
+        self
+    }
+
+    /// Set the color scale for negative bands.
+    pub fn negative_color_scale(mut self, scale: ColorScale) -> Self {
+        self.negative_scale = scale;
+        self
+    }
+
+    /// Set a fixed extent: `|value| == extent` fills all bands.
+    ///
+    /// Defaults to the maximum absolute value of the data. A fixed extent keeps
+    /// band colors stable while streaming.
+    pub fn extent(mut self, extent: f64) -> Self {
+        self.extent = Some(extent);
+        self
+    }
+
+    /// Keep at most `capacity` samples; older samples are dropped on `push`.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self.trim();
+        self
+    }
+
+    /// Set chart dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Append a sample, dropping the oldest one when at capacity.
+    pub fn push(&mut self, value: f64) {
+        self.values.push_back(value);
+        if value.is_finite() {
+            self.max_abs = self.max_abs.max(value.abs());
+        }
+        self.trim();
+    }
+
+    /// Append several samples.
+    pub fn extend(&mut self, values: impl IntoIterator<Item = f64>) {
+        for value in values {
+            self.push(value);
+        }
+    }
+
+    /// Number of samples currently held.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the chart holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Drop samples beyond capacity, refreshing the cached extent only when
+    /// an evicted sample was the current maximum.
+    fn trim(&mut self) {
+        let Some(capacity) = self.capacity else {
+            return;
+        };
+        let mut evicted_max = false;
+        while self.values.len() > capacity {
+            if let Some(old) = self.values.pop_front() {
+                evicted_max |= old.abs() >= self.max_abs;
+            }
+        }
+        if evicted_max {
+            self.max_abs = self
+                .values
+                .iter()
+                .filter(|v| v.is_finite())
+                .fold(0.0, |acc: f64, v| acc.max(v.abs()));
+        }
+    }
+
+    /// Band height in data units.
+    fn band_step(&self) -> f64 {
+        let extent = self.extent.unwrap_or(self.max_abs);
+        if extent > 0.0 {
+            extent / self.bands as f64
+        } else {
+            1.0
+        }
+    }
+
+    /// Fraction [0, 1] of band `band` filled by each sample of one sign.
+    fn band_fill(&self, band: usize, negative: bool) -> Vec<f64> {
+        let step = self.band_step();
+        let base = band as f64 * step;
+        self.values
+            .iter()
+            .map(|&v| {
+                let v = if negative { -v } else { v };
+                ((v - base) / step).clamp(0.0, 1.0)
+            })
+            .collect()
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        let values: Vec<f64> = self.values.iter().copied().collect();
+        validate_data_array(&values, "values")?;
+        validate_dimensions(self.width, self.height)?;
+        if self.bands == 0 {
+            return Err(ChartError::InvalidData {
+                field: "bands",
+                reason: "must be at least 1",
+            });
+        }
+
+        let title_height = if self.title.is_some() {
+            TITLE_AREA_HEIGHT
+        } else {
+            0.0
+        };
+        let strip_height = self.height - title_height;
+
+        // (fill fractions, color, hanging from top)
+        let mut layers: Vec<(Vec<f64>, D3Color, bool)> = Vec::with_capacity(self.bands * 2);
+        for band in 0..self.bands {
+            let t = (band + 1) as f64 / self.bands as f64;
+            layers.push((
+                self.band_fill(band, false),
+                self.positive_scale.map(t),
+                false,
+            ));
+            layers.push((
+                self.band_fill(band, true),
+                self.negative_scale.map(t),
+                self.mode == HorizonMode::Offset,
+            ));
+        }
+
+        let count = values.len();
+        let strip = canvas(
+            move |bounds, _, _| bounds,
+            move |_, bounds, window, _| {
+                let origin_x: f32 = bounds.origin.x.into();
+                let origin_y: f32 = bounds.origin.y.into();
+                let width: f32 = bounds.size.width.into();
+                let height: f32 = bounds.size.height.into();
+                let dx = if count > 1 {
+                    width / (count - 1) as f32
+                } else {
+                    width
+                };
+
+                for (fill, color, from_top) in &layers {
+                    if fill.iter().all(|&f| f <= 0.0) {
+                        continue;
+                    }
+                    let (base_y, dir) = if *from_top {
+                        (origin_y, 1.0)
+                    } else {
+                        (origin_y + height, -1.0)
+                    };
+
+                    let mut builder = PathBuilder::fill();
+                    builder.move_to(point(px(origin_x), px(base_y)));
+                    for (i, &f) in fill.iter().enumerate() {
+                        builder.line_to(point(
+                            px(origin_x + i as f32 * dx),
+                            px(base_y + dir * f as f32 * height),
+                        ));
+                    }
+                    builder.line_to(point(
+                        px(origin_x + (count.max(1) - 1) as f32 * dx),
+                        px(base_y),
+                    ));
+                    builder.close();
+
+                    if let Ok(path) = builder.build() {
+                        window.paint_path(path, color.to_rgba());
+                    }
+                }
+            },
+        )
+        .size_full()
+        .absolute()
+        .inset_0();
+
+        let mut container = div()
+            .w(px(self.width))
+            .h(px(self.height))
+            .relative()
+            .flex()
+            .flex_col();
+
+        if let Some(title) = &self.title {
+            let font_config =
+                VectorFontConfig::horizontal(DEFAULT_TITLE_FONT_SIZE, hsla(0.0, 0.0, 0.2, 1.0));
+            container = container.child(
+                div()
+                    .w_full()
+                    .h(px(title_height))
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .child(render_vector_text(title, &font_config)),
+            );
+        }
+
+        container = container.child(
+            div()
+                .w(px(self.width))
+                .h(px(strip_height))
+                .relative()
+                .overflow_hidden()
+                .child(strip),
+        );
+
+        Ok(container)
+    }
+}
+
+/// Create a horizon chart from a series of values.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use gpui_px::{horizon, HorizonMode};
+///
+/// let values: Vec<f64> = (0..200).map(|i| (i as f64 * 0.1).sin() * 30.0).collect();
+///
+/// let mut chart = horizon(&values)
+///     .bands(4)
+///     .mode(HorizonMode::Mirror)
+///     .capacity(200);
+///
+/// // Streaming: push new samples, the oldest ones are dropped
+/// chart.push(12.5);
+/// let element = chart.clone().build();
+/// ```
+pub fn horizon(values: &[f64]) -> HorizonChart {
+    let defaults = ChartDefaults::current();
+    let mut chart = HorizonChart {
+        values: VecDeque::with_capacity(values.len()),
+        capacity: None,
+        max_abs: 0.0,
+        extent: None,
+        bands: 4,
+        mode: HorizonMode::Mirror,
+        positive_scale: ColorScale::custom(|t| {
+            D3Color::from_hex(0xc6dbef).interpolate(&D3Color::from_hex(0x08519c), t as f32)
+        }),
+        negative_scale: ColorScale::custom(|t| {
+            D3Color::from_hex(0xfcbba1).interpolate(&D3Color::from_hex(0xa50f15), t as f32)
+        }),
+        title: None,
+        width: defaults.width,
+        height: DEFAULT_HORIZON_HEIGHT,
+        layout: ChartLayout::default(),
+    };
+    chart.extend(values.iter().copied());
+    chart
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_horizon_empty_data() {
+        let result = horizon(&[]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::EmptyData { field: "values" })
+        ));
+    }
+
+    #[test]
+    fn test_horizon_zero_bands() {
+        let result = horizon(&[1.0, 2.0]).bands(0).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData { field: "bands", .. })
+        ));
+    }
+
+    #[test]
+    fn test_horizon_band_fill() {
+        let chart = horizon(&[0.0, 10.0, 25.0, 40.0, -30.0]).bands(4);
+        // extent 40 => step 10
+        assert_eq!(chart.band_fill(0, false), vec![0.0, 1.0, 1.0, 1.0, 0.0]);
+        assert_eq!(chart.band_fill(2, false), vec![0.0, 0.0, 0.5, 1.0, 0.0]);
+        assert_eq!(chart.band_fill(2, true), vec![0.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(chart.band_fill(3, true), vec![0.0; 5]);
+    }
+
+    #[test]
+    fn test_horizon_push_evicts_oldest() {
+        let mut chart = horizon(&[50.0, 1.0, 2.0]).capacity(3);
+        assert_eq!(chart.max_abs, 50.0);
+
+        chart.push(3.0);
+        assert_eq!(chart.len(), 3);
+        assert_eq!(chart.values.front(), Some(&1.0));
+        // Evicting the maximum refreshes the cached extent
+        assert_eq!(chart.max_abs, 3.0);
+    }
+
+    #[test]
+    fn test_horizon_fixed_extent() {
+        let chart = horizon(&[5.0]).bands(2).extent(20.0);
+        assert_eq!(chart.band_fill(0, false), vec![0.5]);
+    }
+
+    #[test]
+    fn test_horizon_successful_build() {
+        let values: Vec<f64> = (0..100).map(|i| (i as f64 * 0.2).sin()).collect();
+        let result = horizon(&values)
+            .title("Horizon")
+            .bands(3)
+            .mode(HorizonMode::Offset)
+            .size(400.0, 80.0)
+            .build();
+        assert!(result.is_ok());
+    }
+}
@@ -6,10 +6,13 @@
 //! # Features
 //!
 //! - **Brush Selection**: Click and drag to select rectangular regions
-//! - **Zoom to Selection**: Zoom into brushed regions
+//! - **Zoom to Selection**: Zoom into brushed regions (Shift+drag on an
+//!   [`InteractiveChart`])
 //! - **Zoom History**: Navigate back through zoom levels
 //! - **Double-click Reset**: Reset to original view
 //! - **Hover Events**: Track mouse position for tooltips
+//! - **Tooltips**: Show the values of the data point nearest the pointer
+//! - **Gestures**: Tell clicks from drags, double-clicks and long presses
 //!
 //! # Example
 //!
@@ -36,9 +39,11 @@
 //! ```
 
 use d3rs::brush::{BrushConfig, BrushSelection, BrushState, DomainSelection};
+use d3rs::quadtree::QuadTree;
 use d3rs::scale::{LinearScale, LogScale, Scale};
 use d3rs::zoom::{ZoomConfig, ZoomState};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // Re-export d3rs types for convenience
 pub use d3rs::brush::{
@@ -320,6 +325,60 @@ impl ChartInteraction {
 
         (domain_x, domain_y)
     }
+
+    /// Convert domain coordinates to a pixel point (inverse of [`Self::point_to_domain`]).
+    pub fn domain_to_point(&self, x: f64, y: f64) -> (f32, f32) {
+        let (width, height) = self.plot_size;
+        let (x_min, x_max) = self.zoom.x_domain();
+        let (y_min, y_max) = self.zoom.y_domain();
+
+        let pixel_x = if self.x_is_log {
+            LogScale::new()
+                .domain(x_min.max(1e-10), x_max)
+                .range(0.0, width as f64)
+                .scale(x)
+        } else {
+            LinearScale::new()
+                .domain(x_min, x_max)
+                .range(0.0, width as f64)
+                .scale(x)
+        };
+
+        let pixel_y = if self.y_is_log {
+            LogScale::new()
+                .domain(y_min.max(1e-10), y_max)
+                .range(height as f64, 0.0)
+                .scale(y)
+        } else {
+            LinearScale::new()
+                .domain(y_min, y_max)
+                .range(height as f64, 0.0)
+                .scale(y)
+        };
+
+        (pixel_x as f32, pixel_y as f32)
+    }
+
+    /// Index of the domain point nearest to the pixel position `(x, y)`,
+    /// if it lies within `radius` pixels.
+    pub fn nearest_point(
+        &self,
+        points: &[(f64, f64)],
+        x: f32,
+        y: f32,
+        radius: f32,
+    ) -> Option<usize> {
+        points
+            .iter()
+            .enumerate()
+            .map(|(i, &(px, py))| {
+                let (px, py) = self.domain_to_point(px, py);
+                (i, (px - x).powi(2) + (py - y).powi(2))
+            })
+            .filter(|&(_, d2)| d2 <= radius * radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
 }
 
 /// Mouse event state for tracking interactions.
@@ -360,6 +419,166 @@ impl MouseState {
     }
 }
 
+/// Thresholds used by [`GestureRecognizer`] to tell gestures apart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureConfig {
+    /// Movement in pixels before a press turns into a drag (default: 4)
+    pub drag_threshold: f32,
+    /// Longest time between the clicks of a double-click (default: 300ms)
+    pub double_click_interval: Duration,
+    /// Farthest the clicks of a double-click may be apart, in pixels (default: 5)
+    pub double_click_distance: f32,
+    /// How long a still press lasts before it is a long press (default: 500ms)
+    pub long_press: Duration,
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self {
+            drag_threshold: 4.0,
+            double_click_interval: Duration::from_millis(300),
+            double_click_distance: 5.0,
+            long_press: Duration::from_millis(500),
+        }
+    }
+}
+
+/// A gesture recognized from raw pointer events (positions in pixels).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// Press and release without moving past the drag threshold
+    Click { x: f32, y: f32 },
+    /// Second click close in time and space to the previous one
+    DoubleClick { x: f32, y: f32 },
+    /// Press held still for the long-press duration
+    LongPress { x: f32, y: f32 },
+    /// Pointer moved while pressed; the first event carries the movement
+    /// since the press, later ones the movement since the previous event
+    Drag { x: f32, y: f32, dx: f32, dy: f32 },
+    /// Release after a drag
+    DragEnd { x: f32, y: f32 },
+}
+
+/// Where the recognizer is within a press.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PressState {
+    Idle,
+    Pressed { x: f32, y: f32, at: Instant },
+    Dragging { x: f32, y: f32 },
+    LongPressed,
+}
+
+/// Turns press, move and release events into [`Gesture`]s.
+///
+/// Timestamps are passed in so callers control the clock; use
+/// [`Instant::now`] for live input. Long presses have no event of their
+/// own: call [`GestureRecognizer::poll`] once the long-press duration has
+/// passed (e.g. from a timer started on press).
+#[derive(Debug, Clone)]
+pub struct GestureRecognizer {
+    config: GestureConfig,
+    state: PressState,
+    last_click: Option<(Instant, f32, f32)>,
+}
+
+impl Default for GestureRecognizer {
+    fn default() -> Self {
+        Self::new(GestureConfig::default())
+    }
+}
+
+impl GestureRecognizer {
+    /// Create a recognizer with the given thresholds.
+    pub fn new(config: GestureConfig) -> Self {
+        Self {
+            config,
+            state: PressState::Idle,
+            last_click: None,
+        }
+    }
+
+    /// The thresholds in use.
+    pub fn config(&self) -> &GestureConfig {
+        &self.config
+    }
+
+    /// Whether the current press has turned into a drag.
+    pub fn is_dragging(&self) -> bool {
+        matches!(self.state, PressState::Dragging { .. })
+    }
+
+    /// Pointer pressed at `(x, y)`.
+    pub fn press(&mut self, x: f32, y: f32, now: Instant) {
+        self.state = PressState::Pressed { x, y, at: now };
+    }
+
+    /// Pointer moved to `(x, y)`; returns a drag once past the threshold.
+    pub fn move_to(&mut self, x: f32, y: f32) -> Option<Gesture> {
+        let (last_x, last_y) = match self.state {
+            PressState::Pressed { x: sx, y: sy, .. } => {
+                let distance = ((x - sx).powi(2) + (y - sy).powi(2)).sqrt();
+                if distance <= self.config.drag_threshold {
+                    return None;
+                }
+                self.last_click = None;
+                (sx, sy)
+            }
+            PressState::Dragging { x: lx, y: ly } => (lx, ly),
+            PressState::Idle | PressState::LongPressed => return None,
+        };
+        self.state = PressState::Dragging { x, y };
+        Some(Gesture::Drag {
+            x,
+            y,
+            dx: x - last_x,
+            dy: y - last_y,
+        })
+    }
+
+    /// Pointer released at `(x, y)`.
+    pub fn release(&mut self, x: f32, y: f32, now: Instant) -> Option<Gesture> {
+        match std::mem::replace(&mut self.state, PressState::Idle) {
+            PressState::Pressed { .. } => {
+                let double = self.last_click.is_some_and(|(at, lx, ly)| {
+                    now.duration_since(at) <= self.config.double_click_interval
+                        && ((x - lx).powi(2) + (y - ly).powi(2)).sqrt()
+                            <= self.config.double_click_distance
+                });
+                if double {
+                    self.last_click = None;
+                    Some(Gesture::DoubleClick { x, y })
+                } else {
+                    self.last_click = Some((now, x, y));
+                    Some(Gesture::Click { x, y })
+                }
+            }
+            PressState::Dragging { .. } => Some(Gesture::DragEnd { x, y }),
+            PressState::Idle | PressState::LongPressed => None,
+        }
+    }
+
+    /// Report a long press once a still press has lasted long enough.
+    ///
+    /// The release that ends a long press yields no click.
+    pub fn poll(&mut self, now: Instant) -> Option<Gesture> {
+        match self.state {
+            PressState::Pressed { x, y, at }
+                if now.duration_since(at) >= self.config.long_press =>
+            {
+                self.state = PressState::LongPressed;
+                self.last_click = None;
+                Some(Gesture::LongPress { x, y })
+            }
+            _ => None,
+        }
+    }
+
+    /// Abandon the current press (e.g. the pointer left the chart).
+    pub fn cancel(&mut self) {
+        self.state = PressState::Idle;
+    }
+}
+
 /// Configuration for chart mouse wheel behavior.
 #[derive(Debug, Clone, Copy)]
 pub struct WheelConfig {
@@ -419,6 +638,82 @@ pub fn apply_wheel_zoom(
     interaction.zoom_to(new_x_min, new_x_max, new_y_min, new_y_max);
 }
 
+/// Nearest data point lookup in pixel space.
+///
+/// Points are indexed in a quadtree of their pixel positions, so hovering
+/// over a dense scatter stays cheap. Pixel positions depend on the zoom,
+/// so the tree is rebuilt when the domains or the plot size change.
+#[derive(Debug, Clone, Default)]
+pub struct PointLocator {
+    tree: QuadTree<usize>,
+    /// Domains, plot size and point count the tree was built for
+    view: Option<((f64, f64), (f64, f64), (f32, f32), usize)>,
+}
+
+impl PointLocator {
+    /// Create an empty locator, filled on the first lookup.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of the point of `points` (domain coordinates) nearest the
+    /// pixel position `(x, y)`, if it lies within `radius` pixels.
+    ///
+    /// The locator assumes `points` is the same data on every call; create
+    /// a new one when the data changes.
+    pub fn nearest(
+        &mut self,
+        interaction: &ChartInteraction,
+        points: &[(f64, f64)],
+        x: f32,
+        y: f32,
+        radius: f32,
+    ) -> Option<usize> {
+        let view = (
+            interaction.x_domain(),
+            interaction.y_domain(),
+            interaction.plot_size,
+            points.len(),
+        );
+        if self.view != Some(view) {
+            // Non-finite positions (e.g. NaN gaps) are skipped by the tree
+            self.tree = QuadTree::new();
+            for (i, &(px, py)) in points.iter().enumerate() {
+                let (px, py) = interaction.domain_to_point(px, py);
+                self.tree.add(px as f64, py as f64, i);
+            }
+            self.view = Some(view);
+        }
+        self.tree
+            .find(x as f64, y as f64, Some(radius as f64))
+            .copied()
+    }
+}
+
+/// Default tooltip text of a data point: its x and y values.
+pub fn format_tooltip(x: f64, y: f64) -> String {
+    let (x, y) = (format_tooltip_value(x), format_tooltip_value(y));
+    format!("x: {x}, y: {y}")
+}
+
+/// Value with at most 4 significant digits, without trailing zeros.
+pub(crate) fn format_tooltip_value(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return value.to_string();
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    if !(-4..6).contains(&magnitude) {
+        return format!("{value:.3e}");
+    }
+    let decimals = (3 - magnitude).max(0) as usize;
+    let text = format!("{value:.decimals$}");
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
 // ============================================================================
 // GPUI-specific rendering functions (only available with gpui feature)
 // ============================================================================
@@ -534,6 +829,66 @@ pub use gpui_render::{
     render_brush_overlay, render_crosshairs, render_reset_button, render_zoom_indicator,
 };
 
+// ============================================================================
+// Plot Bounds Capture
+// ============================================================================
+
+#[cfg(feature = "gpui")]
+mod plot_bounds {
+    use gpui::prelude::*;
+    use gpui::{Bounds, IntoElement, Pixels, Point, canvas};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Window bounds of a plot area, recorded each time it is laid out
+    ///
+    /// Mouse events carry window positions; builders add [`Self::canvas`]
+    /// to the plot area (or call [`Self::record`] from a canvas they already
+    /// paint) and map event positions with [`Self::to_local`]. Clones share
+    /// the recorded bounds, so one can be moved into each handler.
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct PlotBoundsCapture(Rc<Cell<Option<Bounds<Pixels>>>>);
+
+    impl PlotBoundsCapture {
+        pub(crate) fn new() -> Self {
+            Self::default()
+        }
+
+        /// Invisible child covering a `relative()` plot area, recording its bounds
+        pub(crate) fn canvas(&self) -> impl IntoElement + use<> {
+            let capture = self.clone();
+            canvas(move |bounds, _, _| capture.record(bounds), |_, _, _, _| {})
+                .size_full()
+                .absolute()
+                .inset_0()
+        }
+
+        pub(crate) fn record(&self, bounds: Bounds<Pixels>) {
+            self.0.set(Some(bounds));
+        }
+
+        /// Last recorded bounds, `None` until the plot area is laid out
+        pub(crate) fn bounds(&self) -> Option<Bounds<Pixels>> {
+            self.0.get()
+        }
+
+        /// Whether `position` falls inside the plot area
+        pub(crate) fn contains(&self, position: Point<Pixels>) -> bool {
+            self.bounds()
+                .is_some_and(|bounds| bounds.contains(&position))
+        }
+
+        /// `position` relative to the plot origin, in pixels
+        pub(crate) fn to_local(&self, position: Point<Pixels>) -> Option<(f64, f64)> {
+            let local = position - self.bounds()?.origin;
+            Some((f32::from(local.x) as f64, f32::from(local.y) as f64))
+        }
+    }
+}
+
+#[cfg(feature = "gpui")]
+pub(crate) use plot_bounds::PlotBoundsCapture;
+
 // ============================================================================
 // InteractiveChart Component
 // ============================================================================
@@ -543,15 +898,25 @@ mod interactive_chart {
     use super::*;
     use gpui::prelude::*;
     use gpui::{
-        AnyElement, ClickEvent, ElementId, IntoElement, MouseButton, Pixels, Point, ScrollDelta,
-        ScrollWheelEvent, div, hsla, px,
+        AnyElement, App, AsyncApp, ElementId, Entity, IntoElement, Modifiers, MouseButton, Pixels,
+        Point, ScrollDelta, ScrollWheelEvent, canvas, div, hsla, px,
     };
-    use std::cell::RefCell;
+    use gpui_ui_kit::{SelectionModel, Tooltip};
+    use std::cell::{Cell, RefCell};
     use std::rc::Rc;
 
     /// Callback type for when zoom state changes
     pub type OnZoomChange = Rc<dyn Fn((f64, f64), (f64, f64))>;
 
+    /// Callback type for gestures on a data point (point index, domain position)
+    pub type OnPointGesture = Rc<dyn Fn(usize, (f64, f64))>;
+
+    /// Callback type for gestures away from data points (domain position)
+    pub type OnBackgroundGesture = Rc<dyn Fn((f64, f64))>;
+
+    /// Tooltip text of a data point (point index, domain position)
+    pub type TooltipFormatter = Rc<dyn Fn(usize, (f64, f64)) -> String>;
+
     /// Configuration for interactive chart behavior
     #[derive(Clone)]
     pub struct InteractiveChartConfig {
@@ -561,6 +926,8 @@ mod interactive_chart {
         pub enable_wheel_zoom: bool,
         /// Enable double-click to reset zoom
         pub enable_double_click_reset: bool,
+        /// Enable Shift+drag to brush a region and zoom into it
+        pub enable_brush_zoom: bool,
         /// Show zoom indicator when zoomed
         pub show_zoom_indicator: bool,
         /// Wheel zoom configuration
@@ -569,6 +936,12 @@ mod interactive_chart {
         pub left_margin: f32,
         /// Top margin (for title) - mouse coordinates are adjusted by this
         pub top_margin: f32,
+        /// Click, drag and long-press thresholds
+        pub gesture_config: GestureConfig,
+        /// Distance in pixels within which a gesture hits a data point
+        pub hit_radius: f32,
+        /// Show a tooltip for the data point under the pointer
+        pub show_tooltip: bool,
     }
 
     impl Default for InteractiveChartConfig {
@@ -577,10 +950,14 @@ mod interactive_chart {
                 enable_pan: true,
                 enable_wheel_zoom: true,
                 enable_double_click_reset: true,
+                enable_brush_zoom: true,
                 show_zoom_indicator: true,
                 wheel_config: WheelConfig::default(),
                 left_margin: 50.0,
                 top_margin: 30.0,
+                gesture_config: GestureConfig::default(),
+                hit_radius: 8.0,
+                show_tooltip: true,
             }
         }
     }
@@ -620,6 +997,30 @@ mod interactive_chart {
             self.enable_double_click_reset = enable;
             self
         }
+
+        /// Enable or disable Shift+drag brush-to-zoom
+        pub fn with_brush_zoom(mut self, enable: bool) -> Self {
+            self.enable_brush_zoom = enable;
+            self
+        }
+
+        /// Set click, drag and long-press thresholds
+        pub fn with_gestures(mut self, config: GestureConfig) -> Self {
+            self.gesture_config = config;
+            self
+        }
+
+        /// Set the distance in pixels within which a gesture hits a data point
+        pub fn with_hit_radius(mut self, radius: f32) -> Self {
+            self.hit_radius = radius;
+            self
+        }
+
+        /// Enable or disable hover tooltips
+        pub fn with_tooltip(mut self, enable: bool) -> Self {
+            self.show_tooltip = enable;
+            self
+        }
     }
 
     /// Shared state for interactive chart that can be passed to chart builders
@@ -631,6 +1032,26 @@ mod interactive_chart {
         pub config: InteractiveChartConfig,
         /// Callback when zoom changes
         pub on_zoom_change: Option<OnZoomChange>,
+        /// Gesture recognizer, kept across renders
+        pub gestures: Rc<RefCell<GestureRecognizer>>,
+        /// Data points (domain coordinates) that gestures can hit
+        pub points: Rc<Vec<(f64, f64)>>,
+        /// Pixel-space index of `points`
+        locator: Rc<RefCell<PointLocator>>,
+        /// Point under the pointer
+        hovered: Rc<Cell<Option<usize>>>,
+        /// Window bounds of the plot area, recorded by [`Self::plot_probe`]
+        plot_bounds: PlotBoundsCapture,
+        /// Tooltip text of the hovered point
+        pub tooltip_formatter: Option<TooltipFormatter>,
+        /// Callback when a data point is clicked
+        pub on_point_click: Option<OnPointGesture>,
+        /// Callback when a data point is double-clicked
+        pub on_point_double_click: Option<OnPointGesture>,
+        /// Callback when the background is long-pressed
+        pub on_background_long_press: Option<OnBackgroundGesture>,
+        /// Point selection shared with other views
+        pub selection: Option<Entity<SelectionModel<usize>>>,
     }
 
     impl InteractiveChartState {
@@ -642,6 +1063,16 @@ mod interactive_chart {
                 ))),
                 config: InteractiveChartConfig::default(),
                 on_zoom_change: None,
+                gestures: Rc::new(RefCell::new(GestureRecognizer::default())),
+                points: Rc::new(Vec::new()),
+                locator: Rc::new(RefCell::new(PointLocator::new())),
+                hovered: Rc::new(Cell::new(None)),
+                plot_bounds: PlotBoundsCapture::new(),
+                tooltip_formatter: None,
+                on_point_click: None,
+                on_point_double_click: None,
+                on_background_long_press: None,
+                selection: None,
             }
         }
 
@@ -673,10 +1104,183 @@ mod interactive_chart {
 
         /// Set the configuration
         pub fn with_config(mut self, config: InteractiveChartConfig) -> Self {
+            self.gestures = Rc::new(RefCell::new(GestureRecognizer::new(config.gesture_config)));
             self.config = config;
             self
         }
 
+        /// Set the data points (domain coordinates) that gestures can hit
+        ///
+        /// The same points get hover tooltips. For bar charts, pass the
+        /// top of each bar with its category index as x.
+        pub fn with_points(mut self, points: Vec<(f64, f64)>) -> Self {
+            self.points = Rc::new(points);
+            self.locator = Rc::new(RefCell::new(PointLocator::new()));
+            self.hovered = Rc::new(Cell::new(None));
+            self
+        }
+
+        /// Set the tooltip text of a data point
+        ///
+        /// Defaults to [`format_tooltip`](super::format_tooltip) of its
+        /// domain position.
+        ///
+        /// # Example
+        ///
+        /// ```rust,ignore
+        /// let state = InteractiveChartState::new(20.0, 20000.0, -40.0, 10.0)
+        ///     .with_points(points)
+        ///     .with_tooltip_formatter(|_, (hz, db)| format!("{hz:.0} Hz: {db:.1} dB"));
+        /// ```
+        pub fn with_tooltip_formatter<F>(mut self, formatter: F) -> Self
+        where
+            F: Fn(usize, (f64, f64)) -> String + 'static,
+        {
+            self.tooltip_formatter = Some(Rc::new(formatter));
+            self
+        }
+
+        /// Set callback for clicks on a data point
+        pub fn on_point_click<F>(mut self, callback: F) -> Self
+        where
+            F: Fn(usize, (f64, f64)) + 'static,
+        {
+            self.on_point_click = Some(Rc::new(callback));
+            self
+        }
+
+        /// Set callback for double-clicks on a data point
+        ///
+        /// Double-clicks away from data points still reset the zoom.
+        pub fn on_point_double_click<F>(mut self, callback: F) -> Self
+        where
+            F: Fn(usize, (f64, f64)) + 'static,
+        {
+            self.on_point_double_click = Some(Rc::new(callback));
+            self
+        }
+
+        /// Set callback for long presses away from data points
+        pub fn on_background_long_press<F>(mut self, callback: F) -> Self
+        where
+            F: Fn((f64, f64)) + 'static,
+        {
+            self.on_background_long_press = Some(Rc::new(callback));
+            self
+        }
+
+        /// Select points by clicking them, sharing the selection with other
+        /// views such as a table of the same data
+        ///
+        /// Points are identified by their index in [`with_points`](Self::with_points);
+        /// give the model those indices as items for Shift ranges.
+        pub fn with_selection(mut self, selection: Entity<SelectionModel<usize>>) -> Self {
+            self.selection = Some(selection);
+            self
+        }
+
+        /// Update the shared selection after a click at a chart position
+        ///
+        /// Clicking a point selects it, following the Ctrl/Cmd and Shift
+        /// modifiers; a plain click away from points clears the selection.
+        pub fn select_at(&self, x: f32, y: f32, modifiers: &Modifiers, cx: &mut App) {
+            let Some(selection) = &self.selection else {
+                return;
+            };
+            match self.hit_point(x, y) {
+                Some(index) => {
+                    selection.update(cx, |selection, cx| selection.click(index, modifiers, cx))
+                }
+                None if !modifiers.modified() => {
+                    selection.update(cx, |selection, cx| selection.clear(cx))
+                }
+                None => {}
+            }
+        }
+
+        /// Index of the data point under the chart-relative pixel position
+        pub fn hit_point(&self, x: f32, y: f32) -> Option<usize> {
+            self.locator.borrow_mut().nearest(
+                &self.interaction.borrow(),
+                &self.points,
+                x,
+                y,
+                self.config.hit_radius,
+            )
+        }
+
+        /// Point under the pointer, if any
+        pub fn hovered(&self) -> Option<usize> {
+            self.hovered.get()
+        }
+
+        /// Track the pointer at a chart-relative position; returns true if
+        /// the hovered point changed
+        pub fn hover_at(&self, x: f32, y: f32) -> bool {
+            let hovered = self.hit_point(x, y);
+            self.hovered.replace(hovered) != hovered
+        }
+
+        /// Forget the hovered point; returns true if there was one
+        pub fn clear_hover(&self) -> bool {
+            self.hovered.take().is_some()
+        }
+
+        /// Tooltip text of the data point at `index`
+        pub fn tooltip_text(&self, index: usize) -> String {
+            let point = self.points[index];
+            match &self.tooltip_formatter {
+                Some(formatter) => formatter(index, point),
+                None => format_tooltip(point.0, point.1),
+            }
+        }
+
+        /// Dispatch a recognized gesture; returns true if the view changed
+        pub fn handle_gesture(&self, gesture: Gesture) -> bool {
+            match gesture {
+                Gesture::Drag { dx, dy, .. } if self.config.enable_pan => {
+                    self.apply_pan(dx, dy);
+                    true
+                }
+                Gesture::DragEnd { .. } if self.config.enable_pan => {
+                    if let Some(ref callback) = self.on_zoom_change {
+                        callback(self.x_domain(), self.y_domain());
+                    }
+                    false
+                }
+                Gesture::Click { x, y } => {
+                    if let (Some(callback), Some(index)) =
+                        (&self.on_point_click, self.hit_point(x, y))
+                    {
+                        callback(index, self.points[index]);
+                    }
+                    false
+                }
+                Gesture::DoubleClick { x, y } => match self.hit_point(x, y) {
+                    Some(index) => {
+                        if let Some(ref callback) = self.on_point_double_click {
+                            callback(index, self.points[index]);
+                        }
+                        false
+                    }
+                    None if self.config.enable_double_click_reset => {
+                        self.reset_zoom();
+                        true
+                    }
+                    None => false,
+                },
+                Gesture::LongPress { x, y } => {
+                    if let Some(ref callback) = self.on_background_long_press
+                        && self.hit_point(x, y).is_none()
+                    {
+                        callback(self.interaction.borrow().point_to_domain(x, y));
+                    }
+                    false
+                }
+                _ => false,
+            }
+        }
+
         /// Set callback for zoom changes
         pub fn on_zoom_change<F>(mut self, callback: F) -> Self
         where
@@ -715,20 +1319,79 @@ mod interactive_chart {
             }
         }
 
+        /// Start a brush selection at a chart-relative position
+        pub fn start_brush(&self, x: f32, y: f32) {
+            self.interaction.borrow_mut().start_brush(x, y);
+        }
+
+        /// Extend the brush selection to a chart-relative position
+        pub fn update_brush(&self, x: f32, y: f32) {
+            self.interaction.borrow_mut().update_brush(x, y);
+        }
+
+        /// End the brush selection and zoom into it; returns true if the
+        /// view changed
+        ///
+        /// Selections smaller than the brush's `min_size` are dropped, so a
+        /// Shift+click does not zoom.
+        pub fn end_brush(&self) -> bool {
+            let zoomed = self.interaction.borrow_mut().end_brush(true).is_some();
+            if zoomed && let Some(ref callback) = self.on_zoom_change {
+                callback(self.x_domain(), self.y_domain());
+            }
+            zoomed
+        }
+
+        /// Invisible child of the plot area that measures pointer positions
+        /// from the plot itself
+        ///
+        /// Without it, positions are offset by the configured margins. The
+        /// plot area must be `relative()` so the probe covers it; the probe
+        /// also tracks the plot size and draws the brush selection.
+        pub fn plot_probe(&self) -> impl IntoElement + use<> {
+            let plot_bounds = self.plot_bounds.clone();
+            let interaction = self.interaction.clone();
+            let brush = self.brush_overlay();
+            div()
+                .absolute()
+                .inset_0()
+                .child(
+                    canvas(
+                        move |bounds, _window, _cx| {
+                            plot_bounds.record(bounds);
+                            interaction.borrow_mut().plot_size =
+                                (bounds.size.width.into(), bounds.size.height.into());
+                        },
+                        |_, _, _, _| {},
+                    )
+                    .size_full(),
+                )
+                .children(brush)
+        }
+
+        /// Current brush selection, in chart-relative coordinates
+        fn brush_overlay(&self) -> Option<AnyElement> {
+            let interaction = self.interaction.borrow();
+            let selection = interaction.current_brush_selection()?;
+            Some(render_brush_overlay(&selection, &interaction.brush_config).into_any_element())
+        }
+
         /// Convert pixel coordinates to chart-relative coordinates
-        /// Uses the configured margins to offset from the element position
+        ///
+        /// Measured from the plot area once a [`Self::plot_probe`] has been
+        /// painted, otherwise offset by the configured margins.
         fn to_chart_coords(&self, pos: Point<Pixels>) -> (f32, f32) {
             let config = &self.config;
             let interaction = self.interaction.borrow();
             let (plot_width, plot_height) = interaction.plot_size;
+            let (left, top) = match self.plot_bounds.bounds() {
+                Some(bounds) => (bounds.origin.x.into(), bounds.origin.y.into()),
+                None => (config.left_margin, config.top_margin),
+            };
 
-            // Subtract margins to get chart-relative coordinates
-            let chart_x = (f32::from(pos.x) - config.left_margin)
-                .max(0.0)
-                .min(plot_width);
-            let chart_y = (f32::from(pos.y) - config.top_margin)
-                .max(0.0)
-                .min(plot_height);
+            // Subtract the plot origin to get chart-relative coordinates
+            let chart_x = (f32::from(pos.x) - left).max(0.0).min(plot_width);
+            let chart_y = (f32::from(pos.y) - top).max(0.0).min(plot_height);
             (chart_x, chart_y)
         }
 
@@ -801,23 +1464,50 @@ mod interactive_chart {
             let state = self.state.clone();
             let state_for_down = self.state.clone();
             let state_for_move = self.state.clone();
-            let state_for_click = self.state.clone();
+            let state_for_up = self.state.clone();
             let state_for_wheel = self.state.clone();
 
+            let state_for_hover = self.state.clone();
+
             let is_zoomed = state.is_zoomed();
             let config = state.config.clone();
 
-            // Track drag state using RefCell for interior mutability
-            let drag_start: Rc<RefCell<Option<(f32, f32)>>> = Rc::new(RefCell::new(None));
-            let drag_start_down = drag_start.clone();
-            let drag_start_move = drag_start.clone();
-            let drag_start_up = drag_start.clone();
+            // Tooltip anchored on the hovered point, hidden while panning
+            let tooltip = state
+                .hovered()
+                .filter(|_| config.show_tooltip && !state.gestures.borrow().is_dragging())
+                .map(|index| {
+                    let (x, y) = state.points[index];
+                    let (x, y) = state.interaction.borrow().domain_to_point(x, y);
+                    div()
+                        .absolute()
+                        .left(px(config.left_margin + x))
+                        .top(px(config.top_margin + y))
+                        .child(Tooltip::new(state.tooltip_text(index)))
+                });
+
+            // Brush selection, unless a plot probe draws it inside the plot
+            let brush = state
+                .plot_bounds
+                .bounds()
+                .is_none()
+                .then(|| state.brush_overlay())
+                .flatten()
+                .map(|overlay| {
+                    div()
+                        .absolute()
+                        .left(px(config.left_margin))
+                        .top(px(config.top_margin))
+                        .child(overlay)
+                });
 
             div()
                 .id(self.id)
                 .relative()
                 .cursor_grab()
                 .child(self.child)
+                .children(tooltip)
+                .children(brush)
                 // Zoom indicator
                 .when(is_zoomed && config.show_zoom_indicator, |el| {
                     el.child(
@@ -834,39 +1524,83 @@ mod interactive_chart {
                             .child("Zoomed (double-click to reset)"),
                     )
                 })
-                // Mouse down - start pan
-                .on_mouse_down(MouseButton::Left, move |event, _window, _cx| {
-                    if state_for_down.config.enable_pan {
-                        let (x, y) = state_for_down.to_chart_coords(event.position);
-                        *drag_start_down.borrow_mut() = Some((x, y));
+                // Mouse down - start a gesture, and time a possible long press
+                .on_mouse_down(MouseButton::Left, move |event, _window, cx| {
+                    let (x, y) = state_for_down.to_chart_coords(event.position);
+                    if state_for_down.config.enable_brush_zoom && event.modifiers.shift {
+                        state_for_down.start_brush(x, y);
+                    }
+                    state_for_down
+                        .gestures
+                        .borrow_mut()
+                        .press(x, y, Instant::now());
+
+                    if state_for_down.on_background_long_press.is_some() {
+                        let state = state_for_down.clone();
+                        let delay = state.gestures.borrow().config().long_press;
+                        cx.spawn(async move |cx: &mut AsyncApp| {
+                            cx.background_executor().timer(delay).await;
+                            let gesture = state.gestures.borrow_mut().poll(Instant::now());
+                            if let Some(gesture) = gesture {
+                                let _ = cx.update(|cx| {
+                                    if state.handle_gesture(gesture) {
+                                        cx.refresh_windows();
+                                    }
+                                });
+                            }
+                        })
+                        .detach();
                     }
                 })
-                // Mouse move - pan if dragging
+                // Mouse move - extend the brush, pan once the press has
+                // turned into a drag, otherwise track the hovered point
                 .on_mouse_move(move |event, window, _cx| {
-                    if state_for_move.config.enable_pan
-                        && let Some((start_x, start_y)) = *drag_start_move.borrow()
+                    let (x, y) = state_for_move.to_chart_coords(event.position);
+                    if state_for_move.current_brush_selection().is_some() {
+                        state_for_move.update_brush(x, y);
+                        window.refresh();
+                        return;
+                    }
+                    let gesture = state_for_move.gestures.borrow_mut().move_to(x, y);
+                    if let Some(gesture) = gesture
+                        && state_for_move.handle_gesture(gesture)
                     {
-                        let (x, y) = state_for_move.to_chart_coords(event.position);
-                        let dx = x - start_x;
-                        let dy = y - start_y;
-                        if dx.abs() > 1.0 || dy.abs() > 1.0 {
-                            state_for_move.apply_pan(dx, dy);
-                            // Update drag start to current position for continuous panning
-                            *drag_start_move.borrow_mut() = Some((x, y));
-                            // Trigger re-render
-                            window.refresh();
-                        }
+                        window.refresh();
+                    } else if state_for_move.config.show_tooltip
+                        && !state_for_move.gestures.borrow().is_dragging()
+                        && state_for_move.hover_at(x, y)
+                    {
+                        window.refresh();
                     }
                 })
-                // Mouse up - end pan
-                .on_mouse_up(MouseButton::Left, move |_event, _window, _cx| {
-                    *drag_start_up.borrow_mut() = None;
+                // Mouse leave - hide the tooltip
+                .on_hover(move |hovered, window, _cx| {
+                    if !*hovered && state_for_hover.clear_hover() {
+                        window.refresh();
+                    }
                 })
-                // Click - handle double-click reset
-                .on_click(move |event: &ClickEvent, window, _cx| {
-                    if state_for_click.config.enable_double_click_reset && event.click_count() >= 2
+                // Mouse up - zoom into the brush, or click, double-click or
+                // end of drag
+                .on_mouse_up(MouseButton::Left, move |event, window, cx| {
+                    let (x, y) = state_for_up.to_chart_coords(event.position);
+                    if state_for_up.current_brush_selection().is_some() {
+                        state_for_up.update_brush(x, y);
+                        if state_for_up.end_brush() {
+                            state_for_up.gestures.borrow_mut().cancel();
+                            window.refresh();
+                            return;
+                        }
+                    }
+                    let gesture = state_for_up
+                        .gestures
+                        .borrow_mut()
+                        .release(x, y, Instant::now());
+                    if let Some(Gesture::Click { x, y }) = gesture {
+                        state_for_up.select_at(x, y, &event.modifiers, cx);
+                    }
+                    if let Some(gesture) = gesture
+                        && state_for_up.handle_gesture(gesture)
                     {
-                        state_for_click.reset_zoom();
                         window.refresh();
                     }
                 })
@@ -935,7 +1669,8 @@ mod interactive_chart {
 
 #[cfg(feature = "gpui")]
 pub use interactive_chart::{
-    InteractiveChart, InteractiveChartConfig, InteractiveChartState, OnZoomChange, interactive,
+    InteractiveChart, InteractiveChartConfig, InteractiveChartState, OnBackgroundGesture,
+    OnPointGesture, OnZoomChange, TooltipFormatter, interactive,
 };
 
 #[cfg(test)]
@@ -1107,6 +1842,149 @@ mod tests {
         assert!(interaction.current_brush_selection().is_none());
     }
 
+    #[test]
+    fn test_domain_to_point_round_trip() {
+        let interaction = ChartInteraction::new(20.0, 20000.0, -40.0, 10.0)
+            .with_log_x(true)
+            .with_size(500.0, 300.0);
+        let (x, y) = interaction.domain_to_point(1000.0, 0.0);
+        let (dx, dy) = interaction.point_to_domain(x, y);
+        assert!((dx - 1000.0).abs() < 0.1);
+        assert!(dy.abs() < 1e-3);
+
+        let points = [(100.0, -20.0), (1000.0, 0.0), (1100.0, 0.0)];
+        assert_eq!(interaction.nearest_point(&points, x + 2.0, y, 8.0), Some(1));
+        assert_eq!(interaction.nearest_point(&points, x, y + 50.0, 8.0), None);
+    }
+
+    #[test]
+    fn test_gesture_click_vs_drag() {
+        let t0 = Instant::now();
+        let mut gestures = GestureRecognizer::default();
+
+        // Small jitter stays a click
+        gestures.press(100.0, 100.0, t0);
+        assert_eq!(gestures.move_to(102.0, 101.0), None);
+        assert_eq!(
+            gestures.release(102.0, 101.0, t0 + Duration::from_millis(80)),
+            Some(Gesture::Click { x: 102.0, y: 101.0 })
+        );
+
+        // Past the threshold it is a drag, with no click on release
+        let t1 = t0 + Duration::from_secs(1);
+        gestures.press(100.0, 100.0, t1);
+        assert_eq!(
+            gestures.move_to(110.0, 100.0),
+            Some(Gesture::Drag {
+                x: 110.0,
+                y: 100.0,
+                dx: 10.0,
+                dy: 0.0
+            })
+        );
+        assert!(gestures.is_dragging());
+        assert_eq!(
+            gestures.move_to(112.0, 103.0),
+            Some(Gesture::Drag {
+                x: 112.0,
+                y: 103.0,
+                dx: 2.0,
+                dy: 3.0
+            })
+        );
+        assert_eq!(
+            gestures.release(112.0, 103.0, t1),
+            Some(Gesture::DragEnd { x: 112.0, y: 103.0 })
+        );
+        assert_eq!(gestures.move_to(150.0, 150.0), None);
+    }
+
+    #[test]
+    fn test_gesture_double_click_and_long_press() {
+        let t0 = Instant::now();
+        let ms = Duration::from_millis;
+        let mut gestures = GestureRecognizer::default();
+
+        gestures.press(50.0, 50.0, t0);
+        gestures.release(50.0, 50.0, t0 + ms(50));
+        gestures.press(51.0, 50.0, t0 + ms(150));
+        assert_eq!(
+            gestures.release(51.0, 50.0, t0 + ms(200)),
+            Some(Gesture::DoubleClick { x: 51.0, y: 50.0 })
+        );
+
+        // Too slow for a double-click
+        gestures.press(50.0, 50.0, t0 + ms(1000));
+        gestures.release(50.0, 50.0, t0 + ms(1050));
+        gestures.press(50.0, 50.0, t0 + ms(1500));
+        assert_eq!(
+            gestures.release(50.0, 50.0, t0 + ms(1550)),
+            Some(Gesture::Click { x: 50.0, y: 50.0 })
+        );
+
+        // Held still: a long press, and no click on release
+        gestures.press(20.0, 30.0, t0 + ms(3000));
+        assert_eq!(gestures.poll(t0 + ms(3200)), None);
+        assert_eq!(
+            gestures.poll(t0 + ms(3500)),
+            Some(Gesture::LongPress { x: 20.0, y: 30.0 })
+        );
+        assert_eq!(gestures.poll(t0 + ms(3600)), None);
+        assert_eq!(gestures.release(20.0, 30.0, t0 + ms(3700)), None);
+    }
+
+    #[test]
+    fn test_point_locator_follows_zoom() {
+        let mut interaction = ChartInteraction::new(0.0, 100.0, 0.0, 100.0).with_size(100.0, 100.0);
+        let points = [(10.0, 90.0), (50.0, 50.0), (f64::NAN, 50.0)];
+        let mut locator = PointLocator::new();
+        let mut nearest =
+            |interaction: &ChartInteraction, x, y| locator.nearest(interaction, &points, x, y, 8.0);
+
+        assert_eq!(nearest(&interaction, 52.0, 49.0), Some(1));
+        assert_eq!(nearest(&interaction, 11.0, 11.0), Some(0));
+        assert_eq!(nearest(&interaction, 80.0, 80.0), None);
+
+        // Zoomed in, (50, 50) moves to the top-left corner of the plot
+        interaction.zoom_to(50.0, 60.0, 40.0, 50.0);
+        assert_eq!(nearest(&interaction, 1.0, 1.0), Some(1));
+        assert_eq!(nearest(&interaction, 52.0, 49.0), None);
+    }
+
+    #[test]
+    fn test_format_tooltip() {
+        assert_eq!(format_tooltip(1000.0, -3.25), "x: 1000, y: -3.25");
+        assert_eq!(format_tooltip(0.0, 2.0 / 3.0), "x: 0, y: 0.6667");
+        assert_eq!(format_tooltip(12345.678, 0.5), "x: 12346, y: 0.5");
+        assert_eq!(format_tooltip(2.5e7, 1e-6), "x: 2.500e7, y: 1.000e-6");
+    }
+
+    #[cfg(feature = "gpui")]
+    #[test]
+    fn test_plot_bounds_capture() {
+        use gpui::{Bounds, point, px, size};
+
+        let capture = PlotBoundsCapture::new();
+        assert_eq!(capture.to_local(point(px(15.0), px(25.0))), None);
+        assert!(!capture.contains(point(px(15.0), px(25.0))));
+
+        // Clones share what the canvas records
+        capture.clone().record(Bounds::new(
+            point(px(10.0), px(20.0)),
+            size(px(100.0), px(50.0)),
+        ));
+        assert_eq!(
+            capture.to_local(point(px(15.0), px(25.0))),
+            Some((5.0, 5.0))
+        );
+        assert!(capture.contains(point(px(15.0), px(25.0))));
+        assert!(!capture.contains(point(px(5.0), px(25.0))));
+        assert_eq!(
+            capture.to_local(point(px(5.0), px(25.0))),
+            Some((-5.0, 5.0))
+        );
+    }
+
     #[cfg(feature = "gpui")]
     mod interactive_chart_state_tests {
         use super::super::interactive_chart::*;
@@ -1146,6 +2024,84 @@ mod tests {
             assert_eq!(state.x_domain(), (0.0, 100.0));
         }
 
+        #[test]
+        fn test_interactive_chart_state_point_gestures() {
+            use super::super::Gesture;
+            use std::cell::Cell;
+            use std::rc::Rc;
+
+            let clicked = Rc::new(Cell::new(None));
+            let pressed = Rc::new(Cell::new(false));
+            let (clicked_cb, pressed_cb) = (clicked.clone(), pressed.clone());
+            let state = InteractiveChartState::new(0.0, 100.0, 0.0, 100.0)
+                .with_size(100.0, 100.0)
+                .with_points(vec![(10.0, 90.0), (50.0, 50.0)])
+                .on_point_click(move |index, _| clicked_cb.set(Some(index)))
+                .on_background_long_press(move |_| pressed_cb.set(true));
+
+            // (50, 50) in the domain is the middle of the plot
+            state.handle_gesture(Gesture::Click { x: 52.0, y: 49.0 });
+            assert_eq!(clicked.get(), Some(1));
+
+            state.handle_gesture(Gesture::LongPress { x: 50.0, y: 50.0 });
+            assert!(!pressed.get());
+            state.handle_gesture(Gesture::LongPress { x: 80.0, y: 80.0 });
+            assert!(pressed.get());
+
+            // Double-click on the background resets the zoom
+            state
+                .interaction
+                .borrow_mut()
+                .zoom_to(25.0, 75.0, 25.0, 75.0);
+            assert!(state.handle_gesture(Gesture::DoubleClick { x: 90.0, y: 5.0 }));
+            assert!(!state.is_zoomed());
+        }
+
+        #[test]
+        fn test_interactive_chart_state_hover() {
+            let state = InteractiveChartState::new(0.0, 100.0, 0.0, 100.0)
+                .with_size(100.0, 100.0)
+                .with_points(vec![(10.0, 90.0), (50.0, 50.0)]);
+
+            assert!(state.hover_at(52.0, 49.0));
+            assert_eq!(state.hovered(), Some(1));
+            // Moving within the same point changes nothing
+            assert!(!state.hover_at(50.0, 50.0));
+            assert_eq!(state.tooltip_text(1), "x: 50, y: 50");
+
+            assert!(state.hover_at(80.0, 80.0));
+            assert_eq!(state.hovered(), None);
+            state.hover_at(10.0, 10.0);
+            assert!(state.clear_hover());
+            assert!(!state.clear_hover());
+
+            let state = state.with_tooltip_formatter(|index, (x, _)| format!("#{index} at {x}"));
+            assert_eq!(state.tooltip_text(0), "#0 at 10");
+        }
+
+        #[test]
+        fn test_interactive_chart_state_brush_zoom() {
+            let state = InteractiveChartState::new(10.0, 10000.0, 0.0, 100.0)
+                .with_log_x(true)
+                .with_size(300.0, 100.0);
+
+            // A Shift+click is too small to zoom
+            state.start_brush(50.0, 50.0);
+            state.update_brush(51.0, 50.0);
+            assert!(!state.end_brush());
+            assert!(!state.is_zoomed());
+
+            // The middle third of a 3-decade log axis is one decade
+            state.start_brush(100.0, 25.0);
+            state.update_brush(200.0, 75.0);
+            assert!(state.current_brush_selection().is_some());
+            assert!(state.end_brush());
+            let (x_min, x_max) = state.x_domain();
+            assert!((x_min - 100.0).abs() < 1e-6 && (x_max - 1000.0).abs() < 1e-6);
+            assert_eq!(state.y_domain(), (25.0, 75.0));
+            assert!(state.current_brush_selection().is_none());
+        }
+
         #[test]
         fn test_interactive_chart_config() {
             let config = InteractiveChartConfig::new()
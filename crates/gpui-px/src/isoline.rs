@@ -1,23 +1,35 @@
 //! Isoline chart (unfilled contour lines) - Plotly Express style API.
 
+use crate::async_chart::AsyncChart;
+use crate::contour_hover::{ContourHover, ContourHoverCallback, HoverGrid, hover_layer};
 use crate::error::ChartError;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::theme::ChartTheme;
 use crate::{
-    DEFAULT_COLOR, DEFAULT_HEIGHT, DEFAULT_TITLE_FONT_SIZE, DEFAULT_WIDTH, ScaleType,
-    TITLE_AREA_HEIGHT, extent_padded, validate_data_array, validate_dimensions,
-    validate_grid_dimensions, validate_monotonic, validate_positive,
+    ChartDefaults, DEFAULT_COLOR, DEFAULT_TITLE_FONT_SIZE, ScaleType, TITLE_AREA_HEIGHT,
+    extent_padded, validate_data_array, validate_dimensions, validate_grid_dimensions,
+    validate_monotonic, validate_plot_area, validate_positive,
 };
-use d3rs::axis::{AxisConfig, DefaultAxisTheme, render_axis};
+use d3rs::axis::{AxisConfig, render_axis};
 use d3rs::color::D3Color;
-use d3rs::contour::ContourGenerator;
+use d3rs::contour::{Contour, ContourGenerator};
 use d3rs::grid::{GridConfig, render_grid};
-use d3rs::scale::{LinearScale, LogScale};
+use d3rs::scale::{LinearScale, LogScale, Scale};
 use d3rs::shape::{ContourConfig, render_contour};
-use d3rs::text::{VectorFontConfig, render_vector_text};
+use d3rs::text::{VectorFontConfig, measure_text_width, paint_vector_text_at, render_vector_text};
 use gpui::prelude::*;
-use gpui::{AnyElement, IntoElement, div, hsla, px, rgb};
+use gpui::{AnyElement, App, Entity, IntoElement, Rgba, Window, canvas, div, hsla, px, rgb};
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::rc::Rc;
+
+/// Default distance between value labels along a line, in pixels
+const DEFAULT_LABEL_SPACING: f32 = 200.0;
+
+/// Font size of the value labels
+const LABEL_FONT_SIZE: f32 = 9.0;
 
 /// Isoline chart builder (unfilled contour lines).
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct IsolineChart {
     z: Vec<f64>,
     grid_width: usize,
@@ -29,6 +41,8 @@ pub struct IsolineChart {
     levels: Option<Vec<f64>>,
     color: u32,
     stroke_width: f32,
+    /// Dash pattern as alternating dash and gap lengths in pixels
+    stroke_dash: Option<Vec<f32>>,
     opacity: f32,
     title: Option<String>,
     width: f32,
@@ -36,9 +50,33 @@ pub struct IsolineChart {
     // Axis range overrides (for zoom support)
     x_range: Option<[f64; 2]>,
     y_range: Option<[f64; 2]>,
+    show_labels: bool,
+    label_format: Option<Rc<dyn Fn(f64) -> String>>,
+    label_spacing: f32,
+    hover: Option<ContourHover>,
+    on_hover: Option<ContourHoverCallback>,
+    /// Lines computed by `build_async` (skips generation in `build`)
+    lines: Option<Vec<Contour>>,
+    layout: ChartLayout,
+}
+
+impl std::fmt::Debug for IsolineChart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IsolineChart")
+            .field("grid_width", &self.grid_width)
+            .field("grid_height", &self.grid_height)
+            .field("levels", &self.levels)
+            .field("title", &self.title)
+            .field("show_labels", &self.show_labels)
+            .field("label_spacing", &self.label_spacing)
+            .field("hover", &self.hover)
+            .finish_non_exhaustive()
+    }
 }
 
 impl IsolineChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
     /// Set custom x axis values.
     ///
     /// Values must be strictly monotonically increasing.
@@ -90,6 +128,15 @@ impl IsolineChart {
         self
     }
 
+    /// Dash the lines, e.g. `&[4.0, 2.0]` for 4 px dashes and 2 px gaps.
+    ///
+    /// Dashes are measured on screen, so they keep their length when the
+    /// chart is zoomed.
+    pub fn stroke_dash(mut self, pattern: &[f32]) -> Self {
+        self.stroke_dash = Some(pattern.to_vec());
+        self
+    }
+
     /// Set line opacity (0.0 - 1.0).
     pub fn opacity(mut self, opacity: f32) -> Self {
         self.opacity = opacity.clamp(0.0, 1.0);
@@ -121,12 +168,155 @@ impl IsolineChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
-        // Validate inputs
+    /// Show the level value along each line, as on topographic maps.
+    ///
+    /// Labels follow the direction of the line, sit on a halo of the plot
+    /// background and are skipped where they would overlap another label.
+    pub fn labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Set the label text of a level value; also turns labels on.
+    pub fn label_format<F>(mut self, format: F) -> Self
+    where
+        F: Fn(f64) -> String + 'static,
+    {
+        self.label_format = Some(Rc::new(format));
+        self.show_labels = true;
+        self
+    }
+
+    /// Set the distance between labels along a line in pixels (default: 200).
+    ///
+    /// Smaller values give denser labels.
+    pub fn label_spacing(mut self, pixels: f32) -> Self {
+        self.label_spacing = pixels.max(1.0);
+        self
+    }
+
+    /// Show a marker and tooltip for a hovered value (from [`IsolineChart::on_hover`]).
+    pub fn hover(mut self, hover: Option<ContourHover>) -> Self {
+        self.hover = hover;
+        self
+    }
+
+    /// Report the z value and the levels around it under the cursor (`None`
+    /// when it leaves the grid).
+    pub fn on_hover<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Option<ContourHover>, &mut Window, &mut App) + 'static,
+    {
+        self.on_hover = Some(Rc::new(callback));
+        self
+    }
+
+    /// Value labels of the lines, drawn over the plot area.
+    fn label_overlay(
+        &self,
+        contours: &[Contour],
+        x_scale: &impl Scale<f64, f64>,
+        y_scale: &impl Scale<f64, f64>,
+        plot_width: f64,
+        plot_height: f64,
+        halo: Rgba,
+    ) -> Option<AnyElement> {
+        if !self.show_labels {
+            return None;
+        }
+        let lines: Vec<(String, Vec<(f32, f32)>)> = contours
+            .iter()
+            .flat_map(|contour| {
+                let text = match &self.label_format {
+                    Some(format) => format(contour.value),
+                    None => format_level(contour.value),
+                };
+                contour.coordinates.iter().map(move |ring| {
+                    let points = ring
+                        .points
+                        .iter()
+                        .map(|p| (x_scale.scale(p.x) as f32, y_scale.scale(p.y) as f32))
+                        .collect();
+                    (text.clone(), points)
+                })
+            })
+            .collect();
+        let labels = place_labels(
+            &lines,
+            LABEL_FONT_SIZE,
+            self.label_spacing,
+            plot_width as f32,
+            plot_height as f32,
+        );
+        let color = rgb(self.color);
+
+        Some(
+            canvas(
+                |_, _, _| {},
+                move |bounds, _, window, _| {
+                    let origin_x: f32 = bounds.origin.x.into();
+                    let origin_y: f32 = bounds.origin.y.into();
+                    for label in &labels {
+                        let (x, y) = (origin_x + label.x, origin_y + label.y);
+                        // Wide strokes in the background color knock the line out
+                        paint_vector_text_at(
+                            window,
+                            &label.text,
+                            x,
+                            y,
+                            LABEL_FONT_SIZE,
+                            LABEL_FONT_SIZE * 0.5,
+                            halo,
+                            label.angle,
+                        );
+                        paint_vector_text_at(
+                            window,
+                            &label.text,
+                            x,
+                            y,
+                            LABEL_FONT_SIZE,
+                            1.0,
+                            color,
+                            label.angle,
+                        );
+                    }
+                },
+            )
+            .absolute()
+            .size_full()
+            .into_any_element(),
+        )
+    }
+
+    /// Build the chart with its contour lines generated on the background
+    /// executor.
+    ///
+    /// The grid is validated right away; the returned [`AsyncChart`] shows a
+    /// placeholder until the lines are ready.
+    pub fn build_async(self, cx: &mut App) -> Result<Entity<AsyncChart>, ChartError> {
+        let (x_values, y_values) = self.grid_axes()?;
+        let generator = ContourGenerator::new(self.grid_width, self.grid_height)
+            .x_values(x_values)
+            .y_values(y_values);
+        let (z, levels) = (self.z.clone(), self.resolved_levels());
+        let (layout, size) = (self.layout.clone(), (self.width, self.height));
+        Ok(AsyncChart::spawn(
+            &layout,
+            size,
+            move || generator.contours(&z, &levels),
+            move |lines: &Vec<Contour>| {
+                let mut chart = self.clone();
+                chart.lines = Some(lines.clone());
+                chart.build().map(IntoElement::into_any_element)
+            },
+            cx,
+        ))
+    }
+
+    /// Validate the grid and return its x and y values.
+    fn grid_axes(&self) -> Result<(Vec<f64>, Vec<f64>), ChartError> {
         validate_data_array(&self.z, "z")?;
         validate_grid_dimensions(&self.z, self.grid_width, self.grid_height)?;
-        validate_dimensions(self.width, self.height)?;
 
         // Generate or validate x values
         let x_values = match self.x_values {
@@ -170,6 +360,27 @@ impl IsolineChart {
             None => (0..self.grid_height).map(|i| i as f64).collect(),
         };
 
+        Ok((x_values, y_values))
+    }
+
+    /// The levels set with [`IsolineChart::levels`], or 10 evenly spaced
+    /// levels over the z extent.
+    fn resolved_levels(&self) -> Vec<f64> {
+        if let Some(levels) = &self.levels {
+            return levels.clone();
+        }
+        let (z_min, z_max) = extent_padded(&self.z, 0.0);
+        let n = 10;
+        (0..=n)
+            .map(|i| z_min + (z_max - z_min) * (i as f64) / (n as f64))
+            .collect()
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<impl IntoElement, ChartError> {
+        validate_dimensions(self.width, self.height)?;
+        let (x_values, y_values) = self.grid_axes()?;
+
         // Calculate plot area (reserve space for title and axes)
         let title_height = if self.title.is_some() {
             TITLE_AREA_HEIGHT
@@ -182,8 +393,9 @@ impl IsolineChart {
         let bottom_margin = 40.0_f64;
         let plot_width = (self.width as f64) - left_margin;
         let plot_height = (self.height as f64) - title_height as f64 - bottom_margin;
+        validate_plot_area(plot_width, plot_height)?;
 
-        let theme = DefaultAxisTheme;
+        let theme = ChartTheme::current().axis_theme();
 
         // Calculate domains with padding, or use explicit ranges if set
         let (x_min, x_max) = if let Some([min, max]) = self.x_range {
@@ -197,26 +409,37 @@ impl IsolineChart {
             extent_padded(&y_values, 0.0)
         };
 
-        // Calculate z extent for auto-levels
-        let (z_min, z_max) = extent_padded(&self.z, 0.0);
+        let levels = self.resolved_levels();
 
-        // Generate levels if not provided
-        let levels = match self.levels {
-            Some(l) => l,
-            None => {
-                // Auto-generate 10 evenly spaced levels
-                let n = 10;
-                (0..=n)
-                    .map(|i| z_min + (z_max - z_min) * (i as f64) / (n as f64))
-                    .collect()
-            }
+        let mut sorted_levels = levels.clone();
+        sorted_levels.sort_by(f64::total_cmp);
+        let hover_grid = HoverGrid {
+            z: self.z.clone(),
+            x_values: x_values.clone(),
+            y_values: y_values.clone(),
+            x_domain: (x_min, x_max),
+            y_domain: (y_min, y_max),
+            x_scale: self.x_scale_type,
+            y_scale: self.y_scale_type,
+            levels: sorted_levels,
         };
+        let hover_layer = hover_layer(
+            "isoline-hover",
+            hover_grid,
+            self.hover,
+            self.on_hover.clone(),
+            plot_width,
+            plot_height,
+        );
 
-        // Generate contours
-        let generator = ContourGenerator::new(self.grid_width, self.grid_height)
-            .x_values(x_values)
-            .y_values(y_values);
-        let contours = generator.contours(&self.z, &levels);
+        // Use the lines computed by `build_async`, or generate them now
+        let contours = match self.lines.take() {
+            Some(lines) => lines,
+            None => ContourGenerator::new(self.grid_width, self.grid_height)
+                .x_values(x_values)
+                .y_values(y_values)
+                .contours(&self.z, &levels),
+        };
 
         // Build config with fixed color (no fill for isolines)
         let config = ContourConfig::new()
@@ -224,6 +447,10 @@ impl IsolineChart {
             .stroke_color(D3Color::from_hex(self.color))
             .stroke_width(self.stroke_width)
             .stroke_opacity(self.opacity);
+        let config = match &self.stroke_dash {
+            Some(pattern) => config.stroke_dash(pattern),
+            None => config,
+        };
 
         // Build the element based on scale types
         let isoline_element: AnyElement = match (self.x_scale_type, self.y_scale_type) {
@@ -234,6 +461,14 @@ impl IsolineChart {
                 let y_scale = LinearScale::new()
                     .domain(y_min, y_max)
                     .range(plot_height, 0.0);
+                let labels = self.label_overlay(
+                    &contours,
+                    &x_scale,
+                    &y_scale,
+                    plot_width,
+                    plot_height,
+                    theme.background,
+                );
 
                 div()
                     .flex()
@@ -253,7 +488,7 @@ impl IsolineChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -264,7 +499,9 @@ impl IsolineChart {
                                     ))
                                     .child(div().absolute().inset_0().child(render_contour(
                                         contours, &x_scale, &y_scale, &config,
-                                    ))),
+                                    )))
+                                    .children(labels)
+                                    .children(hover_layer),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -282,6 +519,14 @@ impl IsolineChart {
                 let y_scale = LinearScale::new()
                     .domain(y_min, y_max)
                     .range(plot_height, 0.0);
+                let labels = self.label_overlay(
+                    &contours,
+                    &x_scale,
+                    &y_scale,
+                    plot_width,
+                    plot_height,
+                    theme.background,
+                );
 
                 div()
                     .flex()
@@ -301,7 +546,7 @@ impl IsolineChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -312,7 +557,9 @@ impl IsolineChart {
                                     ))
                                     .child(div().absolute().inset_0().child(render_contour(
                                         contours, &x_scale, &y_scale, &config,
-                                    ))),
+                                    )))
+                                    .children(labels)
+                                    .children(hover_layer),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -330,6 +577,14 @@ impl IsolineChart {
                 let y_scale = LogScale::new()
                     .domain(y_min.max(1e-10), y_max)
                     .range(plot_height, 0.0);
+                let labels = self.label_overlay(
+                    &contours,
+                    &x_scale,
+                    &y_scale,
+                    plot_width,
+                    plot_height,
+                    theme.background,
+                );
 
                 div()
                     .flex()
@@ -349,7 +604,7 @@ impl IsolineChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -360,7 +615,9 @@ impl IsolineChart {
                                     ))
                                     .child(div().absolute().inset_0().child(render_contour(
                                         contours, &x_scale, &y_scale, &config,
-                                    ))),
+                                    )))
+                                    .children(labels)
+                                    .children(hover_layer),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -378,6 +635,14 @@ impl IsolineChart {
                 let y_scale = LogScale::new()
                     .domain(y_min.max(1e-10), y_max)
                     .range(plot_height, 0.0);
+                let labels = self.label_overlay(
+                    &contours,
+                    &x_scale,
+                    &y_scale,
+                    plot_width,
+                    plot_height,
+                    theme.background,
+                );
 
                 div()
                     .flex()
@@ -397,7 +662,7 @@ impl IsolineChart {
                                     .h(px(plot_height as f32))
                                     .relative()
                                     .overflow_hidden()
-                                    .bg(rgb(0xf8f8f8))
+                                    .bg(theme.background)
                                     .child(render_grid(
                                         &x_scale,
                                         &y_scale,
@@ -408,7 +673,9 @@ impl IsolineChart {
                                     ))
                                     .child(div().absolute().inset_0().child(render_contour(
                                         contours, &x_scale, &y_scale, &config,
-                                    ))),
+                                    )))
+                                    .children(labels)
+                                    .children(hover_layer),
                             )
                             .child(render_axis(
                                 &x_scale,
@@ -476,6 +743,7 @@ impl IsolineChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn isoline(z: &[f64], grid_width: usize, grid_height: usize) -> IsolineChart {
+    let defaults = ChartDefaults::current();
     IsolineChart {
         z: z.to_vec(),
         grid_width,
@@ -487,19 +755,198 @@ pub fn isoline(z: &[f64], grid_width: usize, grid_height: usize) -> IsolineChart
         levels: None,
         color: DEFAULT_COLOR,
         stroke_width: 1.5,
+        stroke_dash: None,
         opacity: 1.0,
         title: None,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        width: defaults.width,
+        height: defaults.height,
         x_range: None,
         y_range: None,
+        show_labels: false,
+        label_format: None,
+        label_spacing: DEFAULT_LABEL_SPACING,
+        hover: None,
+        on_hover: None,
+        lines: None,
+        layout: ChartLayout::default(),
+    }
+}
+
+/// Default label of a level: integers without decimals, others with up to
+/// two.
+pub(crate) fn format_level(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        let text = format!("{value:.2}");
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// Value label placed along a line, in plot pixels.
+#[derive(Debug, Clone, PartialEq)]
+struct IsolineLabel {
+    text: String,
+    /// Center of the label
+    x: f32,
+    y: f32,
+    /// Direction of the text in radians, kept upright
+    angle: f32,
+}
+
+/// Place labels every `spacing` pixels along each line.
+///
+/// A label is dropped where the line curves too much under it, where it
+/// would leave the plot, or where it would overlap a label already placed.
+fn place_labels(
+    lines: &[(String, Vec<(f32, f32)>)],
+    font_size: f32,
+    spacing: f32,
+    width: f32,
+    height: f32,
+) -> Vec<IsolineLabel> {
+    let mut labels = Vec::new();
+    // Axis-aligned boxes (min x, min y, max x, max y) of the placed labels
+    let mut boxes: Vec<[f32; 4]> = Vec::new();
+    let text_height = font_size * 1.4;
+
+    for (text, points) in lines {
+        if points.len() < 2 {
+            continue;
+        }
+        let text_width = measure_text_width(text, font_size) + font_size * 0.5;
+        let mut lengths = Vec::with_capacity(points.len());
+        let mut total = 0.0_f32;
+        lengths.push(0.0);
+        for pair in points.windows(2) {
+            total += (pair[1].0 - pair[0].0).hypot(pair[1].1 - pair[0].1);
+            lengths.push(total);
+        }
+        if total < text_width * 2.0 {
+            continue;
+        }
+        let point_at = |distance: f32| {
+            let distance = distance.clamp(0.0, total);
+            let i = lengths
+                .partition_point(|&l| l < distance)
+                .clamp(1, points.len() - 1);
+            let segment = lengths[i] - lengths[i - 1];
+            let t = if segment > 0.0 {
+                (distance - lengths[i - 1]) / segment
+            } else {
+                0.0
+            };
+            let (a, b) = (points[i - 1], points[i]);
+            (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+        };
+
+        let count = ((total / spacing).floor() as usize).max(1);
+        let step = total / count as f32;
+        for k in 0..count {
+            let distance = step * (k as f32 + 0.5);
+            let (x, y) = point_at(distance);
+            let start = point_at(distance - text_width / 2.0);
+            let end = point_at(distance + text_width / 2.0);
+            let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+            if dx.hypot(dy) < text_width * 0.8 {
+                continue;
+            }
+            let mut angle = dy.atan2(dx);
+            if angle > FRAC_PI_2 {
+                angle -= PI;
+            } else if angle < -FRAC_PI_2 {
+                angle += PI;
+            }
+
+            let (sin, cos) = (angle.sin().abs(), angle.cos().abs());
+            let half_w = (text_width * cos + text_height * sin) / 2.0;
+            let half_h = (text_width * sin + text_height * cos) / 2.0;
+            let bbox = [x - half_w, y - half_h, x + half_w, y + half_h];
+            let inside = bbox[0] >= 0.0 && bbox[1] >= 0.0 && bbox[2] <= width && bbox[3] <= height;
+            let overlaps = boxes
+                .iter()
+                .any(|b| bbox[0] < b[2] && b[0] < bbox[2] && bbox[1] < b[3] && b[1] < bbox[3]);
+            if !inside || overlaps {
+                continue;
+            }
+            boxes.push(bbox);
+            labels.push(IsolineLabel {
+                text: text.clone(),
+                x,
+                y,
+                angle,
+            });
+        }
     }
+    labels
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_isoline_labels_build() {
+        let z: Vec<f64> = (0..100).map(|i| ((i % 10) + (i / 10)) as f64).collect();
+        let result = isoline(&z, 10, 10)
+            .levels(vec![4.0, 8.0, 12.0])
+            .label_format(|v| format!("{v:.0} m"))
+            .label_spacing(120.0)
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_isoline_resolved_levels_and_precomputed_lines() {
+        let z: Vec<f64> = (0..100).map(|i| ((i % 10) + (i / 10)) as f64).collect();
+        let chart = isoline(&z, 10, 10);
+        let levels = chart.resolved_levels();
+        assert_eq!(levels.len(), 11);
+        assert_eq!((levels[0], levels[10]), (0.0, 18.0));
+
+        let (x_values, y_values) = chart.grid_axes().unwrap();
+        let mut chart = chart.levels(vec![4.0, 8.0]);
+        chart.lines = Some(
+            ContourGenerator::new(10, 10)
+                .x_values(x_values)
+                .y_values(y_values)
+                .contours(&z, &[4.0, 8.0]),
+        );
+        assert!(chart.build().is_ok());
+
+        assert!(isoline(&z, 5, 5).grid_axes().is_err());
+    }
+
+    #[test]
+    fn test_isoline_format_level() {
+        assert_eq!(format_level(20.0), "20");
+        assert_eq!(format_level(0.5), "0.5");
+        assert_eq!(format_level(-1.25), "-1.25");
+        assert_eq!(format_level(0.333), "0.33");
+    }
+
+    #[test]
+    fn test_place_labels_along_line_upright() {
+        // Right-to-left line: the text still reads left to right
+        let line = ("10".to_string(), vec![(590.0, 100.0), (10.0, 100.0)]);
+        let labels = place_labels(&[line], 9.0, 200.0, 600.0, 200.0);
+        assert_eq!(labels.len(), 2);
+        assert!(labels.iter().all(|l| l.angle.abs() < 1e-4 && l.y == 100.0));
+    }
+
+    #[test]
+    fn test_place_labels_avoid_collisions_and_short_lines() {
+        let line = vec![(10.0, 50.0), (390.0, 50.0)];
+        let lines = vec![
+            ("1".to_string(), line.clone()),
+            ("2".to_string(), line),
+            ("3".to_string(), vec![(10.0, 150.0), (14.0, 150.0)]),
+        ];
+        let labels = place_labels(&lines, 9.0, 200.0, 400.0, 200.0);
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].text, "1");
+    }
+
     #[test]
     fn test_isoline_empty_z() {
         let result = isoline(&[], 0, 0).build();
@@ -584,4 +1031,21 @@ mod tests {
             .build();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_isoline_with_hover() {
+        let z: Vec<f64> = (0..9).map(|i| i as f64).collect();
+        let hover = ContourHover {
+            x: 0.5,
+            y: 0.5,
+            z: 2.0,
+            band: Some((0.0, 4.0)),
+        };
+        let result = isoline(&z, 3, 3)
+            .levels(vec![0.0, 4.0, 8.0])
+            .hover(Some(hover))
+            .on_hover(|_, _, _| {})
+            .build();
+        assert!(result.is_ok());
+    }
 }
@@ -0,0 +1,490 @@
+//! Outer layout shared by all chart builders.
+//!
+//! Every builder accepts `.margins(...)`, `.background(...)`,
+//! `.aspect_ratio(...)` and `.responsive(true)`. A responsive chart fills its
+//! parent and is rebuilt at the parent's size on every layout, so it follows
+//! resizable panes and split views:
+//!
+//! ```rust,ignore
+//! let chart = line(&freq, &spl)
+//!     .margins(8.0, 16.0, 8.0, 16.0)
+//!     .background(0xffffff)
+//!     .aspect_ratio(16.0 / 9.0)
+//!     .responsive(true)
+//!     .build()?;
+//! ```
+
+use crate::defaults::ChartDefaults;
+use crate::error::ChartError;
+use crate::fallback::chart_error;
+use crate::theme::ChartTheme;
+use crate::titles::ChartTitles;
+use crate::watermark::Watermark;
+use gpui::prelude::*;
+use gpui::{
+    AnyElement, App, AvailableSpace, Bounds, Element, ElementId, GlobalElementId,
+    InspectorElementId, IntoElement, LayoutId, Pixels, Rgba, Size, Style, Window, div, px,
+    relative, rgb,
+};
+use std::panic;
+
+/// Space around a chart, in pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Margins {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Margins {
+    /// Create margins in CSS order (top, right, bottom, left).
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Same margin on all four sides.
+    pub fn all(margin: f32) -> Self {
+        Self::new(margin, margin, margin, margin)
+    }
+}
+
+/// Outer layout options of a chart builder.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ChartLayout {
+    pub(crate) margins: Margins,
+    pub(crate) background: Option<u32>,
+    pub(crate) aspect_ratio: Option<f32>,
+    pub(crate) responsive: bool,
+    pub(crate) titles: ChartTitles,
+    pub(crate) watermark: Option<Watermark>,
+    /// Set by `.no_watermark()`: ignore the default watermark
+    pub(crate) hide_watermark: bool,
+    /// Set by charts with an opaque plot background, which draw the
+    /// watermark on it themselves with [`ChartLayout::render_watermark`]
+    pub(crate) watermark_in_plot: bool,
+}
+
+impl ChartLayout {
+    /// Layout of a chart that draws the watermark in its plot area.
+    pub(crate) fn watermark_in_plot() -> Self {
+        Self {
+            watermark_in_plot: true,
+            ..Self::default()
+        }
+    }
+
+    /// Take the options left unset from [`ChartDefaults::current`].
+    pub(crate) fn apply_defaults(&mut self) {
+        if self.hide_watermark {
+            self.watermark = None;
+        } else if self.watermark.is_none() {
+            self.watermark = ChartDefaults::current().watermark;
+        }
+    }
+
+    /// Whether no option is set, so the chart is built without a frame.
+    fn is_plain(&self) -> bool {
+        *self
+            == Self {
+                hide_watermark: self.hide_watermark,
+                watermark_in_plot: self.watermark_in_plot,
+                ..Self::default()
+            }
+    }
+
+    /// Watermark layer of a plot area of `width` x `height`, with text in
+    /// `color`.
+    pub(crate) fn render_watermark(
+        &self,
+        color: Rgba,
+        width: f32,
+        height: f32,
+    ) -> Option<AnyElement> {
+        self.watermark
+            .as_ref()
+            .map(|watermark| watermark.render(color, width, height))
+    }
+
+    /// Outer size of a fixed chart: the height follows the width when an
+    /// aspect ratio is set.
+    pub(crate) fn fixed_size(&self, width: f32, height: f32) -> (f32, f32) {
+        match self.aspect_ratio {
+            Some(ratio) => (width, width / ratio),
+            None => (width, height),
+        }
+    }
+
+    /// Largest outer size that fits in the available space and keeps the
+    /// aspect ratio, if any.
+    pub(crate) fn fit_size(&self, width: f32, height: f32) -> (f32, f32) {
+        match self.aspect_ratio {
+            Some(ratio) => {
+                let width = width.min(height * ratio);
+                (width, width / ratio)
+            }
+            None => (width, height),
+        }
+    }
+
+    /// Size left to the chart inside the margins and title blocks.
+    pub(crate) fn inner_size(&self, width: f32, height: f32) -> (f32, f32) {
+        let margins = self.margins;
+        let inner_width = width - margins.left - margins.right;
+        (
+            inner_width,
+            height - margins.top - margins.bottom - self.titles.height(inner_width),
+        )
+    }
+
+    /// Wrap a chart element built at the inner size in its margins, title
+    /// blocks, background and watermark.
+    fn frame(&self, width: f32, height: f32, chart: AnyElement) -> AnyElement {
+        let margins = self.margins;
+        let (inner_width, inner_height) = self.inner_size(width, height);
+        let chart = match self.watermark.as_ref().filter(|_| !self.watermark_in_plot) {
+            // Behind the chart, which has no plot background of its own
+            Some(watermark) => div()
+                .relative()
+                .child(watermark.render(
+                    ChartTheme::current().title_color,
+                    inner_width,
+                    inner_height,
+                ))
+                .child(chart)
+                .into_any_element(),
+            None => chart,
+        };
+        div()
+            .w(px(width))
+            .h(px(height))
+            .pt(px(margins.top))
+            .pr(px(margins.right))
+            .pb(px(margins.bottom))
+            .pl(px(margins.left))
+            .flex()
+            .flex_col()
+            .when_some(self.background, |this, hex| this.bg(rgb(hex)))
+            .children(self.titles.render_header(inner_width))
+            .child(chart)
+            .children(self.titles.render_footer(inner_width))
+            .into_any_element()
+    }
+}
+
+/// Builds a chart at the given inner size.
+type BuildAt<C> = fn(C, f32, f32) -> Result<AnyElement, ChartError>;
+
+/// Apply `layout` to a chart whose nominal size is `size`.
+///
+/// Charts without layout options are built as before. A responsive chart is
+/// first built once at its nominal size so data errors are still returned
+/// from `build()`.
+pub(crate) fn build_with_layout<C: Clone + 'static>(
+    layout: ChartLayout,
+    chart: C,
+    size: (f32, f32),
+    build_at: BuildAt<C>,
+) -> Result<AnyElement, ChartError> {
+    if layout.is_plain() {
+        return build_at(chart, size.0, size.1);
+    }
+
+    let (width, height) = layout.fixed_size(size.0, size.1);
+    let (inner_width, inner_height) = layout.inner_size(width, height);
+    if !layout.responsive {
+        let element = build_at(chart, inner_width, inner_height)?;
+        return Ok(layout.frame(width, height, element));
+    }
+
+    build_at(chart.clone(), inner_width, inner_height)?;
+    let render = move |width: f32, height: f32| {
+        let (width, height) = layout.fit_size(width, height);
+        let (inner_width, inner_height) = layout.inner_size(width, height);
+        // Collapsed panes have nothing to show, not an error
+        if inner_width < 1.0 || inner_height < 1.0 {
+            return div().into_any_element();
+        }
+        let element = build_at(chart.clone(), inner_width, inner_height)
+            .unwrap_or_else(|error| chart_error(&error, inner_width, inner_height));
+        layout.frame(width, height, element)
+    };
+    Ok(ResponsiveChart {
+        render: Box::new(render),
+        child: None,
+    }
+    .into_any_element())
+}
+
+/// Element filling its parent that rebuilds the chart at its laid out size.
+struct ResponsiveChart {
+    render: Box<dyn Fn(f32, f32) -> AnyElement>,
+    child: Option<AnyElement>,
+}
+
+impl IntoElement for ResponsiveChart {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for ResponsiveChart {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let style = Style {
+            size: Size {
+                width: relative(1.0).into(),
+                height: relative(1.0).into(),
+            },
+            ..Default::default()
+        };
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let width: f32 = bounds.size.width.into();
+        let height: f32 = bounds.size.height.into();
+        let mut child = (self.render)(width, height);
+        child.prepaint_as_root(
+            bounds.origin,
+            bounds.size.map(AvailableSpace::Definite),
+            window,
+            cx,
+        );
+        self.child = Some(child);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(child) = self.child.as_mut() {
+            child.paint(window, cx);
+        }
+    }
+}
+
+/// Layout builder methods and the public `build()` of a chart builder.
+///
+/// The builder needs a `ChartLayout` field, a `size(width, height)` method and
+/// a private `build_chart()`. `$size` reads the nominal size of the chart.
+/// Builders with a title pass its field as `$title` to also get the
+/// subtitle, caption and title alignment methods.
+macro_rules! chart_layout_methods {
+    ($($layout:ident).+, $size:expr, $title:ident) => {
+        /// Set a subtitle, drawn below the title in a smaller, secondary style.
+        pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+            self.$($layout).+.titles.subtitle = Some(subtitle.into());
+            self
+        }
+
+        /// Set a caption (e.g. a source note), drawn at the bottom right.
+        pub fn caption(mut self, caption: impl Into<String>) -> Self {
+            self.$($layout).+.titles.caption = Some(caption.into());
+            self
+        }
+
+        /// Set the horizontal alignment of the title and subtitle (default: center).
+        pub fn title_align(mut self, align: $crate::TitleAlign) -> Self {
+            self.$($layout).+.titles.align = align;
+            self
+        }
+
+        /// Wrap the title, subtitle and caption onto several lines when they
+        /// are wider than the chart.
+        pub fn title_wrap(mut self, wrap: bool) -> Self {
+            self.$($layout).+.titles.wrap = wrap;
+            self
+        }
+
+        $crate::layout::chart_layout_methods!(@methods $($layout).+, $size, $title);
+    };
+    ($($layout:ident).+, $size:expr) => {
+        $crate::layout::chart_layout_methods!(@methods $($layout).+, $size);
+    };
+    (@methods $($layout:ident).+, $size:expr $(, $title:ident)?) => {
+        /// Set the space around the chart, in pixels, inside its size.
+        pub fn margins(mut self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+            self.$($layout).+.margins = $crate::Margins::new(top, right, bottom, left);
+            self
+        }
+
+        /// Set the background color behind the chart and its margins (24-bit RGB hex).
+        pub fn background(mut self, hex: u32) -> Self {
+            self.$($layout).+.background = Some(hex);
+            self
+        }
+
+        /// Keep width / height at `ratio`: the height follows the width, and a
+        /// responsive chart is fitted inside its parent. Non-positive ratios
+        /// are ignored.
+        pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+            self.$($layout).+.aspect_ratio = (ratio.is_finite() && ratio > 0.0).then_some(ratio);
+            self
+        }
+
+        /// Fill the parent instead of using the fixed size, rebuilding the
+        /// chart whenever the parent is resized.
+        pub fn responsive(mut self, responsive: bool) -> Self {
+            self.$($layout).+.responsive = responsive;
+            self
+        }
+
+        /// Draw a text or image watermark over the plot background, under
+        /// the data. The opacity is clamped to 0..=1.
+        pub fn watermark(
+            mut self,
+            content: impl Into<$crate::WatermarkContent>,
+            position: $crate::WatermarkPosition,
+            opacity: f32,
+        ) -> Self {
+            self.$($layout).+.watermark =
+                Some($crate::Watermark::new(content, position, opacity));
+            self.$($layout).+.hide_watermark = false;
+            self
+        }
+
+        /// Draw no watermark, not even the one of the chart defaults.
+        pub fn no_watermark(mut self) -> Self {
+            self.$($layout).+.watermark = None;
+            self.$($layout).+.hide_watermark = true;
+            self
+        }
+
+        /// Build and validate the chart, returning renderable element.
+        pub fn build(mut self) -> Result<impl IntoElement, ChartError> {
+            self.$($layout).+.apply_defaults();
+            let layout = self.$($layout).+.clone();
+            let size = ($size)(&self);
+            let chart = self;
+            $(
+                // Styled titles are drawn by the layout instead of the chart
+                let (mut layout, mut chart) = (layout, chart);
+                if layout.titles.is_styled() {
+                    layout.titles.title = chart.$title.take();
+                }
+            )?
+            $crate::layout::build_with_layout(layout, chart, size, |chart, width, height| {
+                chart
+                    .size(width, height)
+                    .build_chart()
+                    .map(IntoElement::into_any_element)
+            })
+        }
+    };
+}
+
+pub(crate) use chart_layout_methods;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_fixed_size_follows_aspect_ratio() {
+        let layout = ChartLayout {
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        };
+        assert_eq!(layout.fixed_size(600.0, 400.0), (600.0, 300.0));
+        assert_eq!(
+            ChartLayout::default().fixed_size(600.0, 400.0),
+            (600.0, 400.0)
+        );
+    }
+
+    #[test]
+    fn test_layout_fit_size_keeps_ratio_inside_bounds() {
+        let layout = ChartLayout {
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        };
+        assert_eq!(layout.fit_size(800.0, 200.0), (400.0, 200.0));
+        assert_eq!(layout.fit_size(300.0, 500.0), (300.0, 150.0));
+    }
+
+    #[test]
+    fn test_layout_inner_size_removes_margins() {
+        let layout = ChartLayout {
+            margins: Margins::new(10.0, 20.0, 30.0, 40.0),
+            ..Default::default()
+        };
+        assert_eq!(layout.inner_size(600.0, 400.0), (540.0, 360.0));
+        assert_eq!(Margins::all(5.0), Margins::new(5.0, 5.0, 5.0, 5.0));
+    }
+
+    #[test]
+    fn test_layout_inner_size_removes_title_blocks() {
+        let layout = ChartLayout {
+            titles: ChartTitles {
+                title: Some("Title".into()),
+                caption: Some("Source".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let (width, height) = layout.inner_size(600.0, 400.0);
+        assert_eq!(width, 600.0);
+        assert_eq!(height, 400.0 - layout.titles.height(600.0));
+        assert!(height < 400.0);
+    }
+
+    #[test]
+    fn test_layout_watermark_options() {
+        use crate::watermark::WatermarkPosition;
+
+        let mut layout = ChartLayout::watermark_in_plot();
+        assert!(layout.is_plain());
+        layout.apply_defaults();
+        assert_eq!(layout.watermark, None);
+
+        let watermark = Watermark::new("Draft", WatermarkPosition::Center, 0.2);
+        layout.watermark = Some(watermark.clone());
+        assert!(!layout.is_plain());
+        layout.apply_defaults();
+        assert_eq!(layout.watermark, Some(watermark));
+
+        // Hidden watermarks leave the chart without a frame
+        layout.hide_watermark = true;
+        layout.apply_defaults();
+        assert_eq!(layout.watermark, None);
+        assert!(layout.is_plain());
+    }
+}
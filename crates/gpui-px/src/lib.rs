@@ -20,6 +20,9 @@
 //! - Connecting related data points with smooth or linear interpolation
 //! - Comparing multiple series over the same range
 //!
+//! With `.x_time(&timestamps)` (Unix seconds), the x axis shows times, days,
+//! months or years depending on the visible span instead of raw seconds.
+//!
 //! ### Bar Charts
 //! Use [`bar()`] for:
 //! - Categorical data with discrete categories
@@ -27,17 +30,73 @@
 //! - Displaying counts or aggregated metrics
 //! - Visualizing rankings or distributions by category
 //!
+//! Bars follow the input order unless sorted with `.sort(SortOrder::...)`;
+//! `.top_n(n, "Other")` merges the smallest categories, and a shared
+//! [`CategoryOrder`] keeps bars in place while animated data changes.
+//!
+//! For scientific comparisons, `.error(&errors)` draws error whiskers on the
+//! bars and `.significance("A", "B", p_value_label(p))` a bracket between two
+//! categories; overlapping brackets stack above each other.
+//!
+//! ### Scatter Plot Matrices
+//! Use [`splom()`] for:
+//! - Pairwise relationships between many numeric columns at once
+//! - Linked brushing: selecting points in one panel highlights them in all
+//!
 //! ### Heatmaps
 //! Use [`heatmap()`] for:
 //! - Visualizing 2D scalar fields with color
 //! - Spectrograms, correlation matrices, geographic data
 //! - Supports log scale axes and multiple color scales
+//! - Scales with classes can show a clickable class legend that hides or
+//!   dims the cells of the classes toggled off
+//!
+//! ### Horizon Charts
+//! Use [`horizon()`] for:
+//! - Dense time series in a compact strip
+//! - Streaming data with incremental updates via `push`
+//! - Comparing many series stacked vertically
+//!
+//! ### Trace Overlays
+//! Use [`OverlayManager`] for:
+//! - Holding many named measurements with automatic palette colors
+//! - Toggling trace visibility from the chart legend
+//! - Derived traces: A − B difference, average, 1/N-octave smoothing
+//!
+//! ### Phase and Group Delay
+//! Use [`phase()`] and [`group_delay()`] for:
+//! - Phase versus frequency, wrapped to ±180° or unwrapped
+//! - Group delay in ms/µs/s computed from the phase
+//! - Removing time of flight or a minimum-phase response first ([`ExcessPhase`])
+//!
+//! ### Polar and Radar Charts
+//! Use [`polar_line()`] and [`radar()`] for:
+//! - Directivity plots: level versus angle at several frequencies
+//! - Sectors such as -90° to 90° with `.angular_range(...)`
+//! - Comparing scores across categories, one spoke per category
+//!
+//! ### Violin Plots
+//! Use [`violin()`] for:
+//! - Comparing the distribution of samples across categories
+//! - Spotting multimodal data that a box plot hides
+//! - Choosing the density kernel ([`KernelType`]) and bandwidth, with an
+//!   optional box plot inside each violin
+//!
+//! ### Waterfall Charts
+//! Use [`waterfall()`] for:
+//! - Cumulative spectral decay (CSD) of loudspeaker measurements
+//! - Any sequence of spectra over time as a pseudo-3D ridgeline
+//! - Limiting the view with a decay window and a floor below the peak
 //!
 //! ### Contour Charts (Filled)
 //! Use [`contour()`] for:
 //! - Filled bands between threshold values
 //! - Topographic-style visualizations
 //! - Density estimation results
+//! - Large grids: compute the bands in the background with
+//!   [`ContourChart::spawn_bands`]
+//! - Reading values back: [`ContourChart::on_hover`] reports the z value and
+//!   band under the cursor as a [`ContourHover`]
 //!
 //! ### Isoline Charts (Unfilled)
 //! Use [`isoline()`] for:
@@ -45,6 +104,101 @@
 //! - Elevation or pressure maps
 //! - Level curves of scalar fields
 //!
+//! ### Circle Packing
+//! Use [`circle_pack()`] for:
+//! - Hierarchical data as nested circles sized by value
+//! - Drilling into groups with click-to-zoom
+//! - Complementing [`treemap()`] when containment matters more than area precision
+//!
+//! ### Sparklines
+//! Use [`sparkline()`], [`spark_area()`] and [`spark_bar()`] for:
+//! - Word-sized trends in table cells, cards and status bars
+//! - Optional min/max/last markers and a hover tooltip, no axes or legend
+//!
+//! ### Retained Line Charts
+//! Use [`LineChartView`] for:
+//! - Dashboards with many line charts where only a few change at a time
+//! - Live data: [`LineChartView::update_data`] and
+//!   [`LineChartView::append_points`] only recompute the extent of the series
+//!   that changed
+//! - Skipping re-renders: the chart is a cached view, rebuilt only when its
+//!   own data changes
+//!
+//! ### Shared Axes
+//! Pass one [`Axis`] to several line, scatter or area charts with
+//! `.x_axis(&axis)` to give them identical x axes; `lock_domain()` keeps the
+//! domain stable while the data updates.
+//!
+//! ### Layout
+//! Every builder accepts:
+//! - `.margins(top, right, bottom, left)` ([`Margins`]) and `.background(hex)`
+//! - `.aspect_ratio(ratio)`: the height follows the width
+//! - `.responsive(true)`: fill the parent and re-layout when it is resized
+//!
+//! ### Titles
+//! Builders with a title also accept `.subtitle(...)`, `.caption(...)` (a
+//! source note at the bottom right), `.title_align(`[`TitleAlign`]`)` and
+//! `.title_wrap(true)`, drawn in the colors of the [`ChartTheme`].
+//!
+//! ### Computed Geometry
+//! Line, scatter, bar, pie and heatmap charts have `compute_layout()`,
+//! returning the scales, ticks, line vertices, markers, bar rectangles, pie
+//! slices and heatmap cells as a [`ChartGeometry`] without rendering, for
+//! unit tests and custom interactions.
+//!
+//! [`ChartHitTester`] finds the mark at a pointer position in that geometry:
+//! the nearest point with its distance, or the bar, slice or cell under the
+//! pointer, as a [`ChartHit`].
+//!
+//! ### Background Preprocessing
+//! Contour, isoline, box plot, violin, heatmap and line builders have
+//! `build_async(cx)`, which runs their heavy preprocessing (contour bands and
+//! lines, box statistics, kernel densities, grid interpolation, line
+//! simplification) on the background executor and returns an [`AsyncChart`]
+//! view showing a spinner until it is done. Use it for large datasets to
+//! keep the UI thread responsive.
+//!
+//! ### Error Fallback
+//! Use [`ChartResultExt`] on the result of `build()` to:
+//! - Show the error inside the chart area ([`chart_error()`]) instead of failing
+//! - Log or report errors with `.on_error(callback)`
+//!
+//! ### Clipboard
+//! Line, scatter and bar charts implement [`CopyToClipboard`]: the data as
+//! tab-separated values, or a PNG of the marks with the `gpu-2d` feature.
+//!
+//! ### Shared Selection
+//! A [`SelectionModel`] entity can be shared by a chart and a table of the
+//! same rows: `InteractiveChartState::with_selection` selects points on click
+//! (Ctrl/Cmd to toggle, Shift for ranges), `Table::selection` does the same
+//! for rows, and `.selected(&ids)` on a scatter chart highlights the points.
+//!
+//! ### Offscreen Rendering
+//! Use [`chart_image()`] (feature `gpu-2d`) to:
+//! - Render a chart's marks into a cached GPU texture
+//! - Embed thumbnails inside other components such as table cells or nodes
+//! - Supported by line, bar and scatter charts
+//!
+//! ### Reports
+//! Use [`Report`] (feature `gpu-2d`) to:
+//! - Lay out headings, text, tables and charts onto paginated pages
+//! - Export measurement reports as a multi-page PDF or one PNG per page
+//!
+//! ### App Theme
+//! Call [`init()`] once at startup to make charts follow the gpui-ui-kit
+//! theme: plot background, axis, grid and text colors come from
+//! [`ChartTheme::from`] the global theme and switch with light/dark mode.
+//!
+//! ### App Defaults
+//! Set a [`ChartDefaults`] global (with MiniApp, `.with_global(defaults)`)
+//! to give every chart the same size, theme, fonts, palette, legend position
+//! and zoom/crosshair flags; builder calls still override them.
+//!
+//! ### Branding
+//! Use `.watermark(content, position, opacity)` on any chart to draw a text
+//! or logo over the plot background, under the data. A watermark set in the
+//! [`ChartDefaults`] global brands every chart of the app.
+//!
 //! ## Coordinate System
 //!
 //! All charts use standard mathematical coordinates:
@@ -60,6 +214,10 @@
 //! - `0x2ca02c` - Plotly green
 //! - `0xd62728` - Plotly red
 //!
+//! To keep a series the same color across charts, take its color from a
+//! [`CategoricalPalette`] keyed by series name (Category10 or Tableau10,
+//! with manual overrides).
+//!
 //! For 2D charts (heatmap, contour), use [`ColorScale`]:
 //! - `ColorScale::Viridis` - perceptually uniform (default)
 //! - `ColorScale::Plasma` - perceptually uniform
@@ -70,6 +228,10 @@
 //! - `ColorScale::Greys` - sequential grayscale
 //! - `ColorScale::custom(|t| ...)` - custom function
 //!
+//! `.center(value)` pins a value to the middle of a diverging scale, and
+//! `.classes(n)` quantizes any scale into `n` bands. Heatmap and contour
+//! charts draw a matching colorbar with `.colorbar(true)`.
+//!
 //! ## Logarithmic Scales
 //!
 //! All chart types support logarithmic axis scaling via the `ScaleType` enum:
@@ -158,41 +320,111 @@
 //! ```
 
 mod area;
+mod async_chart;
+mod axis;
 mod bar;
 mod boxplot;
+mod category_order;
+mod circle_pack;
+mod clipboard;
 mod color_scale;
+mod colorbar;
 mod contour;
+mod contour_hover;
+mod crosshair;
+mod defaults;
 mod error;
+mod fallback;
+mod geometry;
 mod heatmap;
+mod hit_test;
+mod horizon;
 pub mod interaction;
 mod isoline;
+mod layout;
 mod line;
+mod line_view;
+#[cfg(feature = "gpu-2d")]
+mod offscreen;
+mod overlay;
+mod palette;
+mod phase;
 mod pie;
+mod polar;
+#[cfg(feature = "gpu-2d")]
+mod report;
 mod scatter;
+mod sparkline;
+mod splom;
 #[cfg(feature = "gpu-3d")]
 mod surface3d;
+mod theme;
+mod titles;
 mod treemap;
-
-pub use area::{AreaChart, area};
-pub use bar::{BarChart, BarTheme, bar};
+mod violin;
+mod waterfall;
+mod watermark;
+mod zoom;
+
+pub use area::{AreaChart, AreaHoverCallback, AreaStackMode, area};
+pub use async_chart::AsyncChart;
+pub use axis::Axis;
+pub use bar::{BarChart, BarTheme, bar, p_value_label};
 pub use boxplot::{BoxPlotChart, boxplot};
+pub use category_order::{CategoryOrder, SortOrder};
+pub use circle_pack::{CirclePack, CirclePackHoverCallback, circle_pack};
+pub use clipboard::{ClipboardFormat, CopyToClipboard};
 pub use color_scale::ColorScale;
 pub use contour::{ContourChart, contour};
+pub use contour_hover::{ContourHover, ContourHoverCallback};
+pub use defaults::ChartDefaults;
 pub use error::ChartError;
-pub use heatmap::{HeatmapChart, heatmap};
+pub use fallback::{ChartResultExt, chart_error};
+pub use geometry::{
+    ArcGeometry, AxisGeometry, CellGeometry, ChartGeometry, PathGeometry, PointGeometry,
+    RectGeometry, Tick,
+};
+pub use gpui_ui_kit::selection::{SelectionEvent, SelectionMode, SelectionModel};
+pub use heatmap::{ClassToggleCallback, HeatmapChart, HiddenClassStyle, heatmap};
+pub use hit_test::{ChartHit, ChartHitTester};
+pub use horizon::{HorizonChart, HorizonMode, horizon};
 pub use isoline::{IsolineChart, isoline};
-pub use line::{ChartTheme, LegendClickCallback, LegendPosition, LineChart, line};
+pub use layout::Margins;
+pub use line::{LegendClickCallback, LegendPosition, LineChart, LineStyle, SeriesVisibility, line};
+pub use line_view::{DataExtent, LineChartState, LineChartStyle, LineChartView, RetainedSeries};
+#[cfg(feature = "gpu-2d")]
+pub use offscreen::{Chart2DRenderer, ChartTexture, TextureChart, chart_image};
+pub use overlay::{OverlayManager, Trace, TraceId, TraceSource};
+pub use palette::CategoricalPalette;
+pub use phase::{
+    DelayUnit, ExcessPhase, GroupDelayChart, PhaseChart, best_fit_delay, group_delay,
+    group_delay_seconds, phase, unwrap_phase, wrap_phase,
+};
 pub use pie::{PieChart, donut, pie};
-pub use scatter::{ScatterChart, ScatterTheme, scatter};
+pub use polar::{PolarChart, RadarChart, polar_line, radar};
+#[cfg(feature = "gpu-2d")]
+pub use report::{PageSize, Report};
+pub use scatter::{JitterMode, ScatterChart, ScatterTheme, scatter};
+pub use sparkline::{
+    SparkArea, SparkBar, SparkHoverCallback, Sparkline, spark_area, spark_bar, sparkline,
+};
+pub use splom::{SplomBrush, SplomBrushCallback, SplomChart, splom};
 #[cfg(feature = "gpu-3d")]
-pub use surface3d::{Surface3DChart, surface3d};
-pub use treemap::{TilingMethod, Treemap, TreemapNode, treemap};
+pub use surface3d::{Surface3DChart, SurfacePickCallback, surface3d};
+pub use theme::{ChartTheme, init};
+pub use titles::TitleAlign;
+pub use treemap::{TilingMethod, Treemap, TreemapNode, TreemapZoomCallback, treemap};
+pub use violin::{ViolinChart, violin};
+pub use waterfall::{WaterfallChart, waterfall};
+pub use watermark::{Watermark, WatermarkContent, WatermarkPosition};
 
 // Re-export d3rs types users might need
 pub use d3rs::color::D3Color;
+pub use d3rs::contour::KernelType;
 #[cfg(feature = "gpu-3d")]
-pub use d3rs::gpu3d::{Colormap, Surface3DState};
-pub use d3rs::shape::CurveType;
+pub use d3rs::gpu3d::{Colormap, Surface3DState, SurfacePick};
+pub use d3rs::interpolate::GridInterpolation;
+pub use d3rs::shape::{CurveType, FillPattern, NoDataStyle, PatternKind, SymbolType};
 
 // ============================================================================
 // Scale Types
@@ -208,6 +440,19 @@ pub enum ScaleType {
     Log,
 }
 
+/// How line and area charts treat missing (NaN) y-values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GapMode {
+    /// Break the line at missing values (default).
+    #[default]
+    Break,
+    /// Skip missing values, connecting their neighbors with a straight line.
+    Connect,
+    /// Fill missing values by linear interpolation along x; missing values
+    /// before the first or after the last measurement are dropped.
+    Interpolate,
+}
+
 // ============================================================================
 // Shared Constants
 // ============================================================================
@@ -263,6 +508,40 @@ pub(crate) fn extent_padded(values: &[f64], padding_fraction: f64) -> (f64, f64)
     (min - padding, max + padding)
 }
 
+/// Pad a `[min, max]` data range for an axis of `scale`.
+///
+/// Linear axes grow by `DEFAULT_PADDING_FRACTION` of the range on each side
+/// (1.0 for a constant range); log axes are scaled by it, so they stay
+/// positive.
+pub(crate) fn padded_range(min: f64, max: f64, scale: ScaleType) -> (f64, f64) {
+    match scale {
+        ScaleType::Log => {
+            let factor = 1.0 + DEFAULT_PADDING_FRACTION;
+            (min / factor, max * factor)
+        }
+        ScaleType::Linear => {
+            let range = max - min;
+            let padding = if range.abs() < f64::EPSILON {
+                1.0
+            } else {
+                range * DEFAULT_PADDING_FRACTION
+            };
+            (min - padding, max + padding)
+        }
+    }
+}
+
+/// Padded range of `values` for an axis of `scale`, see [`padded_range`].
+pub(crate) fn padded_extent(values: &[f64], scale: ScaleType) -> (f64, f64) {
+    let (min, max) = values
+        .iter()
+        .copied()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), val| {
+            (min.min(val), max.max(val))
+        });
+    padded_range(min, max, scale)
+}
+
 /// Validate that a data array is not empty and contains only finite values.
 pub(crate) fn validate_data_array(values: &[f64], field: &'static str) -> Result<(), ChartError> {
     if values.is_empty() {
@@ -277,6 +556,80 @@ pub(crate) fn validate_data_array(values: &[f64], field: &'static str) -> Result
     Ok(())
 }
 
+/// Validate y-values that may have gaps: NaN marks a missing value, but
+/// infinities are rejected and at least one value must be present.
+pub(crate) fn validate_data_with_gaps(
+    values: &[f64],
+    field: &'static str,
+) -> Result<(), ChartError> {
+    if values.is_empty() {
+        return Err(ChartError::EmptyData { field });
+    }
+    if values.iter().any(|y| y.is_infinite()) {
+        return Err(ChartError::InvalidData {
+            field,
+            reason: "contains Infinity",
+        });
+    }
+    if values.iter().all(|y| y.is_nan()) {
+        return Err(ChartError::InvalidData {
+            field,
+            reason: "contains only missing (NaN) values",
+        });
+    }
+    Ok(())
+}
+
+/// Apply a [`GapMode`] to `(x, y)` pairs with NaN y-values.
+///
+/// [`GapMode::Break`] keeps the NaN values for the renderer to break the
+/// line; the other modes return pairs without any NaN.
+pub(crate) fn fill_gaps(x: &[f64], y: &[f64], mode: GapMode) -> Vec<(f64, f64)> {
+    match mode {
+        GapMode::Break => x.iter().copied().zip(y.iter().copied()).collect(),
+        GapMode::Connect => x
+            .iter()
+            .copied()
+            .zip(y.iter().copied())
+            .filter(|(_, y)| !y.is_nan())
+            .collect(),
+        GapMode::Interpolate => x
+            .iter()
+            .copied()
+            .zip(interpolate_gaps(x, y))
+            .filter(|(_, y)| !y.is_nan())
+            .collect(),
+    }
+}
+
+/// Replace NaN y-values between two known values by linear interpolation
+/// along x. NaN values before the first or after the last known value are
+/// kept.
+pub(crate) fn interpolate_gaps(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let mut filled = y.to_vec();
+    let mut previous: Option<usize> = None;
+    for i in 0..y.len().min(x.len()) {
+        if y[i].is_nan() {
+            continue;
+        }
+        if let Some(i0) = previous
+            && i > i0 + 1
+        {
+            let span = x[i] - x[i0];
+            for (j, value) in filled.iter_mut().enumerate().take(i).skip(i0 + 1) {
+                let t = if span != 0.0 {
+                    (x[j] - x[i0]) / span
+                } else {
+                    0.0
+                };
+                *value = y[i0] + t * (y[i] - y[i0]);
+            }
+        }
+        previous = Some(i);
+    }
+    filled
+}
+
 /// Validate that two arrays have the same length.
 pub(crate) fn validate_data_length(
     x_len: usize,
@@ -312,6 +665,14 @@ pub(crate) fn validate_dimensions(width: f32, height: f32) -> Result<(), ChartEr
     Ok(())
 }
 
+/// Validate that the plot area left after margins, title and legend is not empty.
+pub(crate) fn validate_plot_area(width: f64, height: f64) -> Result<(), ChartError> {
+    if width < 1.0 || height < 1.0 {
+        return Err(ChartError::PlotAreaTooSmall { width, height });
+    }
+    Ok(())
+}
+
 /// Validate that grid dimensions match the z array length.
 pub(crate) fn validate_grid_dimensions(
     z: &[f64],
@@ -358,6 +719,46 @@ pub(crate) fn validate_positive(values: &[f64], field: &'static str) -> Result<(
 mod tests {
     use super::*;
 
+    // gap tests
+    #[test]
+    fn test_validate_data_with_gaps() {
+        assert!(validate_data_with_gaps(&[1.0, f64::NAN, 3.0], "y").is_ok());
+        assert!(matches!(
+            validate_data_with_gaps(&[f64::NAN, f64::NAN], "y"),
+            Err(ChartError::InvalidData { field: "y", .. })
+        ));
+        assert!(validate_data_with_gaps(&[1.0, f64::INFINITY], "y").is_err());
+    }
+
+    #[test]
+    fn test_fill_gaps_modes() {
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let y = [f64::NAN, 1.0, f64::NAN, 3.0, f64::NAN];
+
+        let broken = fill_gaps(&x, &y, GapMode::Break);
+        assert_eq!(broken.len(), 5);
+        assert!(broken[2].1.is_nan());
+
+        assert_eq!(
+            fill_gaps(&x, &y, GapMode::Connect),
+            vec![(1.0, 1.0), (3.0, 3.0)]
+        );
+        assert_eq!(
+            fill_gaps(&x, &y, GapMode::Interpolate),
+            vec![(1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_gaps_keeps_edges() {
+        let x = [0.0, 1.0, 2.0, 5.0, 6.0];
+        let y = [f64::NAN, 0.0, f64::NAN, 4.0, f64::NAN];
+        let filled = interpolate_gaps(&x, &y);
+        assert!(filled[0].is_nan());
+        assert_eq!(&filled[1..4], &[0.0, 1.0, 4.0]);
+        assert!(filled[4].is_nan());
+    }
+
     // extent_padded tests
     #[test]
     fn test_extent_padded_normal_values() {
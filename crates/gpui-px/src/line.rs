@@ -1,20 +1,29 @@
 //! Line chart - Plotly Express style API.
 
+use crate::async_chart::AsyncChart;
+use crate::axis::{Axis, format_time_tick, time_ticks};
+use crate::crosshair::Crosshair;
 use crate::error::ChartError;
+use crate::geometry::{AxisGeometry, ChartGeometry};
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::palette::CategoricalPalette;
+use crate::theme::ChartTheme;
+use crate::zoom::{ChartZoom, ZoomHome, build_zoomable, chart_zoom_methods};
 use crate::{
-    DEFAULT_COLOR, DEFAULT_HEIGHT, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE,
-    DEFAULT_WIDTH, ScaleType, TITLE_AREA_HEIGHT, extent_padded, validate_data_array,
-    validate_data_length, validate_dimensions, validate_positive,
+    ChartDefaults, DEFAULT_COLOR, DEFAULT_PADDING_FRACTION, GapMode, ScaleType, TITLE_AREA_HEIGHT,
+    extent_padded, fill_gaps, padded_extent, validate_data_array, validate_data_length,
+    validate_data_with_gaps, validate_dimensions, validate_plot_area, validate_positive,
 };
-use d3rs::axis::{AxisConfig, AxisTheme, render_axis};
+use d3rs::axis::{AxisConfig, render_axis};
 use d3rs::color::D3Color;
 use d3rs::grid::{GridConfig, render_grid};
 use d3rs::scale::{LinearScale, LogScale};
-use d3rs::shape::{CurveType, LineConfig, LinePoint, render_line};
+use d3rs::shape::{CurveType, LineConfig, LinePoint, Point, Simplify, SymbolType, render_line};
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
-use gpui::{AnyElement, App, ElementId, IntoElement, Rgba, Window, div, px, rgb};
-use std::collections::HashSet;
+use gpui::{AnyElement, App, ElementId, Entity, IntoElement, Window, div, px, rgb};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 /// Position of the legend relative to the chart
@@ -33,62 +42,6 @@ pub enum LegendPosition {
     Hidden,
 }
 
-/// Theme for chart styling
-#[derive(Debug, Clone)]
-pub struct ChartTheme {
-    /// Background color for plot area
-    pub plot_background: Rgba,
-    /// Grid line color
-    pub grid_color: Rgba,
-    /// Axis line color
-    pub axis_line_color: Rgba,
-    /// Axis label color
-    pub axis_label_color: Rgba,
-    /// Title text color
-    pub title_color: Rgba,
-    /// Legend text color
-    pub legend_text_color: Rgba,
-}
-
-impl Default for ChartTheme {
-    fn default() -> Self {
-        Self {
-            plot_background: rgb(0xf8f8f8),
-            grid_color: rgba(0x000000, 0.1),
-            axis_line_color: rgba(0x000000, 0.2),
-            axis_label_color: rgba(0x000000, 0.6),
-            title_color: rgba(0x000000, 0.8),
-            legend_text_color: rgba(0x000000, 0.6),
-        }
-    }
-}
-
-/// Helper to create Rgba with alpha
-fn rgba(hex: u32, alpha: f32) -> Rgba {
-    Rgba {
-        r: ((hex >> 16) & 0xFF) as f32 / 255.0,
-        g: ((hex >> 8) & 0xFF) as f32 / 255.0,
-        b: (hex & 0xFF) as f32 / 255.0,
-        a: alpha,
-    }
-}
-
-/// Axis theme adapter for d3rs
-struct ChartAxisTheme {
-    axis_line_color: Rgba,
-    axis_label_color: Rgba,
-}
-
-impl AxisTheme for ChartAxisTheme {
-    fn axis_line_color(&self) -> Rgba {
-        self.axis_line_color
-    }
-
-    fn axis_label_color(&self) -> Rgba {
-        self.axis_label_color
-    }
-}
-
 /// Format tick labels for log scales with k/M suffixes
 fn format_log_tick(value: f64) -> String {
     let abs_value = value.abs();
@@ -173,11 +126,164 @@ struct LineSeries {
     opacity: f32,
     /// Whether this series uses the secondary (right) Y-axis
     use_secondary_axis: bool,
+    /// Whether the color comes from the chart palette
+    auto_color: bool,
+}
+
+/// Stroke and marker style of one line series.
+///
+/// Unset fields fall back to the chart settings.
+///
+/// # Example
+/// ```rust,no_run
+/// use gpui_px::{CurveType, LineStyle, SymbolType, line};
+/// let chart = line(&[1.0, 2.0, 3.0], &[1.0, 3.0, 2.0])
+///     .add_series(&[2.0, 2.5, 1.0], Some("Model"), 0xff7f0e, 2.0, 1.0)
+///     .series_style(
+///         1,
+///         LineStyle::new()
+///             .stroke_dash(&[6.0, 3.0])
+///             .marker(SymbolType::Diamond)
+///             .curve(CurveType::MonotoneX),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LineStyle {
+    /// Stroke width in pixels
+    pub stroke_width: Option<f32>,
+    /// Dash pattern as alternating dash and gap lengths in pixels
+    pub stroke_dash: Option<Vec<f32>>,
+    /// Curve interpolation
+    pub curve: Option<CurveType>,
+    /// Marker drawn at data points
+    pub marker: Option<SymbolType>,
+    /// Draw a marker every `marker_spacing` points (0 and 1 draw all)
+    pub marker_spacing: usize,
+}
+
+impl LineStyle {
+    /// Create a style that keeps every chart setting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the stroke width in pixels.
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    /// Dash the line, e.g. `&[6.0, 3.0]` for 6 px dashes and 3 px gaps.
+    pub fn stroke_dash(mut self, pattern: &[f32]) -> Self {
+        self.stroke_dash = Some(pattern.to_vec());
+        self
+    }
+
+    /// Dash the line, see [`stroke_dash`](Self::stroke_dash).
+    #[deprecated(note = "use `stroke_dash`")]
+    pub fn dash(self, pattern: &[f32]) -> Self {
+        self.stroke_dash(pattern)
+    }
+
+    /// Set the curve interpolation.
+    pub fn curve(mut self, curve: CurveType) -> Self {
+        self.curve = Some(curve);
+        self
+    }
+
+    /// Draw markers of this shape at the data points.
+    pub fn marker(mut self, symbol: SymbolType) -> Self {
+        self.marker = Some(symbol);
+        self
+    }
+
+    /// Draw a marker every `spacing` points, for dense series.
+    pub fn marker_spacing(mut self, spacing: usize) -> Self {
+        self.marker_spacing = spacing;
+        self
+    }
 }
 
 /// Callback type for legend click events
 pub type LegendClickCallback = Rc<dyn Fn(usize, &mut Window, &mut App)>;
 
+/// Hidden series remembered across builds.
+///
+/// Clones share the same state. Keep one in the view and pass it to
+/// [`LineChart::series_visibility`]: clicking a legend item toggles the
+/// series and redraws the window.
+///
+/// ```rust,ignore
+/// // Created once, kept in the view
+/// let visibility = SeriesVisibility::new();
+///
+/// // On every render
+/// let chart = line(&x, &left)
+///     .label("Left")
+///     .add_named_series("Right", &x, &right)
+///     .series_visibility(&visibility)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SeriesVisibility {
+    hidden: Rc<RefCell<HashSet<usize>>>,
+}
+
+impl SeriesVisibility {
+    /// Create a state with every series shown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the series at `index` is hidden (0 = primary series).
+    pub fn is_hidden(&self, index: usize) -> bool {
+        self.hidden.borrow().contains(&index)
+    }
+
+    /// Show or hide the series at `index`.
+    pub fn set_hidden(&self, index: usize, hidden: bool) {
+        let mut set = self.hidden.borrow_mut();
+        if hidden {
+            set.insert(index);
+        } else {
+            set.remove(&index);
+        }
+    }
+
+    /// Flip the visibility of the series at `index`.
+    pub fn toggle(&self, index: usize) {
+        let hidden = self.is_hidden(index);
+        self.set_hidden(index, !hidden);
+    }
+
+    /// Show every series.
+    pub fn show_all(&self) {
+        self.hidden.borrow_mut().clear();
+    }
+
+    /// Indices of the hidden series.
+    pub fn hidden(&self) -> HashSet<usize> {
+        self.hidden.borrow().clone()
+    }
+}
+
+/// Plot area, legend and domains of a line chart.
+struct LineFrame {
+    has_secondary_axis: bool,
+    title_height: f32,
+    legend_gap: f32,
+    has_legend_items: bool,
+    legend_position: LegendPosition,
+    legend_width: f32,
+    legend_height: f32,
+    plot_width: f64,
+    plot_height: f64,
+    x_domain: (f64, f64),
+    y_domain: (f64, f64),
+    y2_domain: (f64, f64),
+}
+
 /// Line chart builder.
 #[derive(Clone)]
 pub struct LineChart {
@@ -190,18 +296,30 @@ pub struct LineChart {
     opacity: f32,
     // Additional series
     series: Vec<LineSeries>,
+    /// Colors of series added with `add_named_series`
+    palette: CategoricalPalette,
     // Common settings
     title: Option<String>,
     x_label: Option<String>,
     y_label: Option<String>,
     curve: CurveType,
     show_points: bool,
+    /// Pixel-space simplification of dense lines
+    simplify: Option<Simplify>,
+    /// Treatment of missing (NaN) y-values
+    gap_mode: GapMode,
+    /// Radius of dots marking points isolated between gaps
+    isolated_point_radius: Option<f32>,
     width: f32,
     height: f32,
     x_scale_type: ScaleType,
     y_scale_type: ScaleType,
     x_range: Option<[f64; 2]>,
     y_range: Option<[f64; 2]>,
+    /// Shared x axis
+    x_axis: Option<Axis>,
+    /// Whether the x values are Unix timestamps, labeled as dates and times
+    x_time: bool,
     show_legend: bool,
     legend_position: LegendPosition,
     /// Whether legend_position was explicitly set by user
@@ -216,6 +334,13 @@ pub struct LineChart {
     hidden_series: HashSet<usize>,
     /// Callback when a legend item is clicked (receives series index)
     on_legend_click: Option<LegendClickCallback>,
+    /// Style overrides by series index (0 = primary series)
+    series_styles: HashMap<usize, LineStyle>,
+    layout: ChartLayout,
+    zoom: ChartZoom,
+    crosshair: bool,
+    /// Points of every series, simplified by `build_async`
+    simplified: Option<Vec<Vec<(f64, f64)>>>,
 }
 
 impl std::fmt::Debug for LineChart {
@@ -231,6 +356,16 @@ impl std::fmt::Debug for LineChart {
 }
 
 impl LineChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+    chart_zoom_methods!(zoom);
+
+    /// Draw guide lines through the pointer over the plot, with its x and y
+    /// values in boxes on the axes.
+    pub fn crosshair(mut self, enabled: bool) -> Self {
+        self.crosshair = enabled;
+        self
+    }
+
     /// Set chart title (rendered at top of chart).
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -304,6 +439,72 @@ impl LineChart {
         self
     }
 
+    /// Dash the primary line, e.g. `&[6.0, 3.0]` for 6 px dashes and 3 px gaps.
+    pub fn stroke_dash(mut self, pattern: &[f32]) -> Self {
+        self.series_styles.entry(0).or_default().stroke_dash = Some(pattern.to_vec());
+        self
+    }
+
+    /// Dash the primary line, see [`stroke_dash`](Self::stroke_dash).
+    #[deprecated(note = "use `stroke_dash`")]
+    pub fn dash(self, pattern: &[f32]) -> Self {
+        self.stroke_dash(pattern)
+    }
+
+    /// Draw markers of this shape at the points of the primary line.
+    pub fn marker(mut self, symbol: SymbolType) -> Self {
+        self.series_styles.entry(0).or_default().marker = Some(symbol);
+        self
+    }
+
+    /// Draw a marker every `spacing` points of the primary line.
+    pub fn marker_spacing(mut self, spacing: usize) -> Self {
+        self.series_styles.entry(0).or_default().marker_spacing = spacing;
+        self
+    }
+
+    /// Set the style of a series (0 = primary series, 1+ = additional series
+    /// in the order they were added).
+    ///
+    /// See [`LineStyle`] for an example.
+    pub fn series_style(mut self, index: usize, style: LineStyle) -> Self {
+        self.series_styles.insert(index, style);
+        self
+    }
+
+    /// Simplify dense lines before drawing, with a tolerance in pixels.
+    ///
+    /// Uses Douglas-Peucker on screen coordinates, so long measurement traces
+    /// render with far fewer vertices. A tolerance of 0.5 is visually lossless.
+    pub fn simplify(mut self, tolerance_px: f64) -> Self {
+        self.simplify = Some(Simplify::douglas_peucker(tolerance_px));
+        self
+    }
+
+    /// Set how missing (NaN) y-values are drawn.
+    ///
+    /// By default a NaN breaks the line, so missing bands of a measurement
+    /// show as gaps instead of failing validation.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::{GapMode, line};
+    /// let chart = line(&[1.0, 2.0, 3.0], &[1.0, f64::NAN, 3.0])
+    ///     .gaps(GapMode::Interpolate)
+    ///     .build();
+    /// ```
+    pub fn gaps(mut self, mode: GapMode) -> Self {
+        self.gap_mode = mode;
+        self
+    }
+
+    /// Set the radius of the dot marking a point between two gaps, which has
+    /// no line segment to show it (defaults to the stroke width, 0 hides it).
+    pub fn isolated_points(mut self, radius: f32) -> Self {
+        self.isolated_point_radius = Some(radius);
+        self
+    }
+
     /// Set chart dimensions.
     pub fn size(mut self, width: f32, height: f32) -> Self {
         self.width = width;
@@ -325,6 +526,36 @@ impl LineChart {
         self
     }
 
+    /// Share an x axis with other charts: scale type, tick format, title and
+    /// domain come from `axis`.
+    pub fn x_axis(mut self, axis: &Axis) -> Self {
+        self.x_scale_type = axis.scale_type();
+        self.x_axis = Some(axis.clone());
+        self
+    }
+
+    /// Use `timestamps` (Unix seconds, UTC) as the x values, replacing the
+    /// ones given to [`line`], and label the x axis with dates and times.
+    ///
+    /// Ticks fall on calendar boundaries and their labels follow the zoom
+    /// level: times of day over a few hours, days over a few weeks, months
+    /// over a year.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::line;
+    /// let timestamps = [1709596800, 1709600400, 1709604000, 1709607600];
+    /// let chart = line(&[], &[12.0, 18.0, 15.0, 21.0])
+    ///     .x_time(&timestamps)
+    ///     .build();
+    /// ```
+    pub fn x_time(mut self, timestamps: &[i64]) -> Self {
+        self.x = timestamps.iter().map(|&t| t as f64).collect();
+        self.x_scale_type = ScaleType::Linear;
+        self.x_time = true;
+        self
+    }
+
     /// Set Y-axis scale type (linear or log).
     pub fn y_scale(mut self, scale: ScaleType) -> Self {
         self.y_scale_type = scale;
@@ -402,6 +633,7 @@ impl LineChart {
             stroke_width,
             opacity,
             use_secondary_axis: false,
+            auto_color: false,
         });
         // Auto-enable legend if any series has a label
         if self.series.iter().any(|s| s.label.is_some()) {
@@ -431,6 +663,7 @@ impl LineChart {
             stroke_width,
             opacity,
             use_secondary_axis: false,
+            auto_color: false,
         });
         // Auto-enable legend if any series has a label
         if self.series.iter().any(|s| s.label.is_some()) {
@@ -439,6 +672,52 @@ impl LineChart {
         self
     }
 
+    /// Add a named series colored from the chart palette.
+    ///
+    /// Each call takes the next palette color, after the primary series, and
+    /// adds a legend entry. Use `palette` to pick the colors and
+    /// `series_visibility` to let users hide series from the legend.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::line;
+    /// let freq = vec![20.0, 200.0, 2000.0, 20000.0];
+    /// let chart = line(&freq, &[84.0, 86.0, 85.0, 80.0])
+    ///     .label("On axis")
+    ///     .add_named_series("15°", &freq, &[84.0, 85.5, 84.0, 77.0])
+    ///     .add_named_series("30°", &freq, &[84.0, 85.0, 82.0, 72.0])
+    ///     .build();
+    /// ```
+    pub fn add_named_series(mut self, name: impl Into<String>, x: &[f64], y: &[f64]) -> Self {
+        let index = self.series.len() + 1;
+        self.series.push(LineSeries {
+            x: Some(x.to_vec()),
+            y: y.to_vec(),
+            label: Some(name.into()),
+            color: self.palette.color_at(index),
+            stroke_width: self.stroke_width,
+            opacity: self.opacity,
+            use_secondary_axis: false,
+            auto_color: true,
+        });
+        self.show_legend = true;
+        self
+    }
+
+    /// Set the palette of series added with `add_named_series`.
+    ///
+    /// Series take the palette colors by position, cycling when there are
+    /// more series than colors. The primary series keeps `color`.
+    pub fn palette(mut self, palette: CategoricalPalette) -> Self {
+        for (i, series) in self.series.iter_mut().enumerate() {
+            if series.auto_color {
+                series.color = palette.color_at(i + 1);
+            }
+        }
+        self.palette = palette;
+        self
+    }
+
     /// Set label for secondary Y-axis (right side).
     ///
     /// When a secondary axis label is set, series added with `add_series_y2`
@@ -490,6 +769,7 @@ impl LineChart {
             stroke_width,
             opacity,
             use_secondary_axis: true,
+            auto_color: false,
         });
         // Auto-enable legend if any series has a label
         if self.series.iter().any(|s| s.label.is_some()) {
@@ -516,6 +796,7 @@ impl LineChart {
             stroke_width,
             opacity,
             use_secondary_axis: true,
+            auto_color: false,
         });
         // Auto-enable legend if any series has a label
         if self.series.iter().any(|s| s.label.is_some()) {
@@ -526,6 +807,9 @@ impl LineChart {
 
     /// Set the chart theme.
     ///
+    /// Defaults to [`ChartTheme::current`], which follows the app theme once
+    /// [`init`](crate::init) has been called.
+    ///
     /// # Example
     /// ```rust,no_run
     /// use gpui_px::{line, ChartTheme};
@@ -615,6 +899,20 @@ impl LineChart {
         self
     }
 
+    /// Keep the hidden series in a shared state, toggled by legend clicks.
+    ///
+    /// Replaces `hidden_series` and `on_legend_click`: the chart hides the
+    /// series of `visibility`, and clicking a legend item toggles it and
+    /// redraws the window.
+    pub fn series_visibility(mut self, visibility: &SeriesVisibility) -> Self {
+        self.hidden_series = visibility.hidden();
+        let visibility = visibility.clone();
+        self.on_legend_click(move |index, window, _cx| {
+            visibility.toggle(index);
+            window.refresh();
+        })
+    }
+
     /// Set the target aspect ratio for the graph area.
     ///
     /// The ratio is defined as `height / width`. Default is `1.414` (≈ √2, similar to A4 paper).
@@ -636,17 +934,83 @@ impl LineChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
+    /// Compute the scales, ticks and line vertices without rendering.
+    ///
+    /// Runs the same validation and layout as [`LineChart::build`] at the
+    /// chart's own size; the outer layout options are not applied.
+    pub fn compute_layout(&self) -> Result<ChartGeometry, ChartError> {
+        let frame = self.plot_frame()?;
+        let (x_min, x_max) = frame.x_domain;
+        let (y_min, y_max) = frame.y_domain;
+
+        let x_ticks = if self.x_time {
+            Some(time_ticks(frame.x_domain, frame.plot_width))
+        } else {
+            (self.x_scale_type == ScaleType::Log).then(|| generate_log_ticks(x_min, x_max))
+        };
+        let y_ticks =
+            (self.y_scale_type == ScaleType::Log).then(|| generate_log_ticks(y_min, y_max));
+        let x_axis = AxisGeometry::new(
+            self.x_scale_type,
+            frame.x_domain,
+            (0.0, frame.plot_width),
+            x_ticks,
+            20,
+        );
+        let y_axis = AxisGeometry::new(
+            self.y_scale_type,
+            frame.y_domain,
+            (frame.plot_height, 0.0),
+            y_ticks,
+            10,
+        );
+        let mut geometry = ChartGeometry::new(frame.plot_width, frame.plot_height, x_axis, y_axis);
+        if frame.has_secondary_axis {
+            geometry.y2_axis = Some(AxisGeometry::new(
+                ScaleType::Linear,
+                frame.y2_domain,
+                (frame.plot_height, 0.0),
+                None,
+                10,
+            ));
+        }
+
+        if !self.hidden_series.contains(&0) {
+            let data = fill_gaps(&self.x, &self.y, self.gap_mode);
+            geometry.push_series(0, &data, false, true);
+        }
+        for (i, series) in self.series.iter().enumerate() {
+            if self.hidden_series.contains(&(i + 1)) {
+                continue;
+            }
+            let x_values = series.x.as_ref().unwrap_or(&self.x);
+            let data = fill_gaps(x_values, &series.y, self.gap_mode);
+            geometry.push_series(i + 1, &data, series.use_secondary_axis, true);
+        }
+        Ok(geometry)
+    }
+
+    /// Validate the data and lay out the plot area, legend and domains.
+    /// Crosshair of a plot showing the given domains, when enabled.
+    fn crosshair_layer(&self, x_domain: (f64, f64), y_domain: (f64, f64)) -> Option<Crosshair> {
+        self.crosshair.then(|| {
+            Crosshair::new(x_domain, y_domain, &self.theme).log(
+                self.x_scale_type == ScaleType::Log,
+                self.y_scale_type == ScaleType::Log,
+            )
+        })
+    }
+
+    fn plot_frame(&self) -> Result<LineFrame, ChartError> {
         // Validate inputs
         validate_data_array(&self.x, "x")?;
-        validate_data_array(&self.y, "y")?;
+        validate_data_with_gaps(&self.y, "y")?;
         validate_data_length(self.x.len(), self.y.len(), "x", "y")?;
         validate_dimensions(self.width, self.height)?;
 
         // Validate all additional series
         for series in &self.series {
-            validate_data_array(&series.y, "series.y")?;
+            validate_data_with_gaps(&series.y, "series.y")?;
             if let Some(ref x) = series.x {
                 // Series has custom X values
                 validate_data_array(x, "series.x")?;
@@ -805,20 +1169,28 @@ impl LineChart {
             - margin_bottom
             - height_for_legend as f64)
             .max(0.0);
+        validate_plot_area(plot_width, plot_height)?;
 
         // Calculate domains with padding - include all series in Y-axis range
-        // Use user-provided ranges if set, otherwise auto-calculate from data
+        // Use user-provided ranges if set, otherwise auto-calculate from data.
+        // Named series span the X-axis too; other custom X values (e.g.
+        // reference lines) don't stretch it
+        let mut x_values = self.x.clone();
+        for series in &self.series {
+            if let (true, Some(x)) = (series.auto_color, &series.x) {
+                x_values.extend_from_slice(x);
+            }
+        }
         let (x_min, x_max) = if let Some([min, max]) = self.x_range {
             // User-specified range - use exactly as provided (no padding)
             (min, max)
-        } else if self.x_scale_type == ScaleType::Log {
-            // For log scale, use multiplicative padding to avoid going negative
-            let min = self.x.iter().copied().fold(f64::INFINITY, f64::min);
-            let max = self.x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-            let padding_factor = 1.0 + DEFAULT_PADDING_FRACTION;
-            (min / padding_factor, max * padding_factor)
         } else {
-            extent_padded(&self.x, DEFAULT_PADDING_FRACTION)
+            // Log scales use multiplicative padding to avoid going negative
+            padded_extent(&x_values, self.x_scale_type)
+        };
+        let (x_min, x_max) = match &self.x_axis {
+            Some(axis) => axis.resolve((x_min, x_max)),
+            None => (x_min, x_max),
         };
 
         // Collect Y values from primary series and non-secondary additional series
@@ -831,20 +1203,8 @@ impl LineChart {
         let (y_min, y_max) = if let Some([min, max]) = self.y_range {
             // User-specified range - use exactly as provided (no padding)
             (min, max)
-        } else if self.y_scale_type == ScaleType::Log {
-            // For log scale, use multiplicative padding
-            let min = primary_y_values
-                .iter()
-                .copied()
-                .fold(f64::INFINITY, f64::min);
-            let max = primary_y_values
-                .iter()
-                .copied()
-                .fold(f64::NEG_INFINITY, f64::max);
-            let padding_factor = 1.0 + DEFAULT_PADDING_FRACTION;
-            (min / padding_factor, max * padding_factor)
         } else {
-            extent_padded(&primary_y_values, DEFAULT_PADDING_FRACTION)
+            padded_extent(&primary_y_values, self.y_scale_type)
         };
 
         // Calculate secondary Y axis domain if needed
@@ -866,24 +1226,168 @@ impl LineChart {
             (0.0, 1.0) // Placeholder, won't be used
         };
 
+        Ok(LineFrame {
+            has_secondary_axis,
+            title_height,
+            legend_gap,
+            has_legend_items,
+            legend_position,
+            legend_width,
+            legend_height,
+            plot_width,
+            plot_height,
+            x_domain: (x_min, x_max),
+            y_domain: (y_min, y_max),
+            y2_domain: (y2_min, y2_max),
+        })
+    }
+
+    /// Build the chart with its lines simplified on the background executor.
+    ///
+    /// Straight series without markers are reduced in pixel space with the
+    /// [`simplify`](Self::simplify) tolerance, or a visually lossless 0.5 px,
+    /// so long traces are cheap to redraw; zooming in shows the simplified
+    /// lines. The data is validated right away; the returned [`AsyncChart`]
+    /// shows a placeholder until the lines are ready.
+    pub fn build_async(self, cx: &mut App) -> Result<Entity<AsyncChart>, ChartError> {
+        let frame = self.plot_frame()?;
+        let simplify = self
+            .simplify
+            .unwrap_or_else(|| Simplify::douglas_peucker(0.5));
+        let x_pixels = axis_pixels(
+            frame.x_domain,
+            frame.plot_width,
+            self.x_scale_type == ScaleType::Log,
+        );
+        let y_pixels = axis_pixels(
+            frame.y_domain,
+            frame.plot_height,
+            self.y_scale_type == ScaleType::Log,
+        );
+        let y2_pixels = axis_pixels(frame.y2_domain, frame.plot_height, false);
+        // (x, y, on the secondary axis, simplified) of every series
+        let lines: Vec<(Vec<f64>, Vec<f64>, bool, bool)> =
+            std::iter::once((self.x.clone(), self.y.clone(), false))
+                .chain(self.series.iter().map(|series| {
+                    let x = series.x.clone().unwrap_or_else(|| self.x.clone());
+                    (x, series.y.clone(), series.use_secondary_axis)
+                }))
+                .enumerate()
+                .map(|(index, (x, y, secondary))| (x, y, secondary, self.simplifies(index)))
+                .collect();
+        let gap_mode = self.gap_mode;
+        let (layout, size) = (self.layout.clone(), (self.width, self.height));
+        Ok(AsyncChart::spawn(
+            &layout,
+            size,
+            move || {
+                lines
+                    .iter()
+                    .map(|(x, y, secondary, simplifies)| {
+                        let points = fill_gaps(x, y, gap_mode);
+                        if !simplifies {
+                            return points;
+                        }
+                        let y_pixels = if *secondary { &y2_pixels } else { &y_pixels };
+                        simplified_points(&points, simplify, &x_pixels, y_pixels)
+                    })
+                    .collect::<Vec<_>>()
+            },
+            move |simplified: &Vec<Vec<(f64, f64)>>| {
+                let mut chart = self.clone();
+                chart.simplified = Some(simplified.clone());
+                chart.build().map(IntoElement::into_any_element)
+            },
+            cx,
+        ))
+    }
+
+    /// Whether the series at `index` is drawn as a plain polyline, which
+    /// `build_async` can simplify without moving markers
+    fn simplifies(&self, index: usize) -> bool {
+        let style = self.series_styles.get(&index);
+        let curve = style.and_then(|style| style.curve).unwrap_or(self.curve);
+        curve == CurveType::Linear
+            && !self.show_points
+            && style.is_none_or(|style| style.marker.is_none())
+    }
+
+    /// Line config of the series at `index`, with its style applied.
+    fn series_config(
+        &self,
+        index: usize,
+        color: u32,
+        stroke_width: f32,
+        opacity: f32,
+    ) -> LineConfig {
+        let style = self.series_styles.get(&index).cloned().unwrap_or_default();
+        let mut config = LineConfig::new()
+            .stroke_color(D3Color::from_hex(color))
+            .stroke_width(style.stroke_width.unwrap_or(stroke_width))
+            .opacity(opacity)
+            .curve(style.curve.unwrap_or(self.curve))
+            .show_points(self.show_points || style.marker.is_some())
+            .point_symbol(style.marker.unwrap_or_default())
+            .point_spacing(style.marker_spacing);
+        config.stroke_dash = style.stroke_dash;
+        config.simplify = self.simplify;
+        config.isolated_point_radius = self.isolated_point_radius;
+        config
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<AnyElement, ChartError> {
+        if let Some(id) = self.zoom.id.take() {
+            let frame = self.plot_frame()?;
+            let home = ZoomHome {
+                x_domain: frame.x_domain,
+                y_domain: frame.y_domain,
+                x_scale: self.x_scale_type,
+                y_scale: self.y_scale_type,
+            };
+            let size = (self.width, self.height);
+            return Ok(build_zoomable(id, home, self, size, |chart, state| {
+                let ((x_min, x_max), (y_min, y_max)) = (state.x_domain(), state.y_domain());
+                let mut chart = chart.x_range(x_min, x_max).y_range(y_min, y_max);
+                chart.zoom.state = Some(state.clone());
+                chart.build_chart()
+            }));
+        }
+        let LineFrame {
+            has_secondary_axis,
+            title_height,
+            legend_gap,
+            has_legend_items,
+            legend_position,
+            legend_width,
+            legend_height,
+            plot_width,
+            plot_height,
+            x_domain: (x_min, x_max),
+            y_domain: (y_min, y_max),
+            y2_domain: (y2_min, y2_max),
+        } = self.plot_frame()?;
+
         // Create data points for primary series
         // Check if primary series is hidden
         let primary_hidden = self.hidden_series.contains(&0);
 
-        let primary_data: Vec<LinePoint> = self
-            .x
-            .iter()
-            .zip(self.y.iter())
-            .map(|(&x, &y)| LinePoint::new(x, y))
-            .collect();
+        // Points of the series at `index`, as simplified by `build_async`
+        let simplified = self.simplified.take();
+        let line_points = |index: usize, x: &[f64], y: &[f64]| -> Vec<LinePoint> {
+            let points = match &simplified {
+                Some(lines) => lines[index].clone(),
+                None => fill_gaps(x, y, self.gap_mode),
+            };
+            points
+                .into_iter()
+                .map(|(x, y)| LinePoint::new(x, y))
+                .collect()
+        };
+        let primary_data = line_points(0, &self.x, &self.y);
 
         // Create configs for primary series
-        let primary_config = LineConfig::new()
-            .stroke_color(D3Color::from_hex(self.color))
-            .stroke_width(self.stroke_width)
-            .opacity(self.opacity)
-            .curve(self.curve)
-            .show_points(self.show_points);
+        let primary_config = self.series_config(0, self.color, self.stroke_width, self.opacity);
 
         // Prepare additional series data and configs, separating primary and secondary axis series
         // Skip hidden series
@@ -897,18 +1401,10 @@ impl LineChart {
 
             // Use custom X values if provided, otherwise use primary X values
             let x_values = series.x.as_ref().unwrap_or(&self.x);
-            let series_points: Vec<LinePoint> = x_values
-                .iter()
-                .zip(series.y.iter())
-                .map(|(&x, &y)| LinePoint::new(x, y))
-                .collect();
+            let series_points = line_points(i + 1, x_values, &series.y);
 
-            let series_config = LineConfig::new()
-                .stroke_color(D3Color::from_hex(series.color))
-                .stroke_width(series.stroke_width)
-                .opacity(series.opacity)
-                .curve(self.curve)
-                .show_points(self.show_points);
+            let series_config =
+                self.series_config(i + 1, series.color, series.stroke_width, series.opacity);
 
             if series.use_secondary_axis {
                 secondary_series_data_configs.push((series_points, series_config));
@@ -917,14 +1413,16 @@ impl LineChart {
             }
         }
 
-        let axis_theme = ChartAxisTheme {
-            axis_line_color: self.theme.axis_line_color,
-            axis_label_color: self.theme.axis_label_color,
-        };
+        let axis_theme = self.theme.axis_theme();
+        let axis_font_size = self.theme.axis_font_size;
 
-        let grid_config = GridConfig::with_lines()
+        let x_time_ticks = self.x_time.then(|| time_ticks((x_min, x_max), plot_width));
+        let mut grid_config = GridConfig::with_lines()
             .with_line_width(0.5)
             .with_line_opacity(0.3);
+        if let Some(ticks) = &x_time_ticks {
+            grid_config = grid_config.with_vertical_values(ticks.clone());
+        }
 
         // Build the element based on scale types
         let chart_content: AnyElement = match (self.x_scale_type, self.y_scale_type) {
@@ -948,6 +1446,11 @@ impl LineChart {
                     .relative()
                     .overflow_hidden()
                     .bg(self.theme.plot_background)
+                    .children(self.layout.render_watermark(
+                        self.theme.title_color,
+                        plot_width as f32,
+                        plot_height as f32,
+                    ))
                     .child(render_grid(
                         &x_scale,
                         &y_scale,
@@ -987,22 +1490,35 @@ impl LineChart {
                     ));
                 }
 
+                plot_area = plot_area.children(self.zoom.probe());
+                plot_area =
+                    plot_area.children(self.crosshair_layer((x_min, x_max), (y_min, y_max)));
+
                 // Create axis configs with labels
-                let mut y_axis_config = AxisConfig::left().with_label_font_size(8.0);
+                let mut y_axis_config = AxisConfig::left().with_label_font_size(axis_font_size);
                 if let Some(ref label) = self.y_label {
                     y_axis_config = y_axis_config.with_title(label.clone());
                 }
 
                 let mut x_axis_config = AxisConfig::bottom()
                     .with_ticks(20)
-                    .with_label_font_size(8.0);
+                    .with_label_font_size(axis_font_size);
                 if let Some(ref label) = self.x_label {
                     x_axis_config = x_axis_config.with_title(label.clone());
                 }
+                if let Some(axis) = &self.x_axis {
+                    x_axis_config = axis.configure(x_axis_config);
+                }
+                if let Some(ticks) = &x_time_ticks {
+                    x_axis_config = x_axis_config
+                        .with_tick_values(ticks.clone())
+                        .with_formatter(format_time_tick);
+                }
 
                 // Build chart with optional secondary Y axis
                 if has_secondary_axis {
-                    let mut y2_axis_config = AxisConfig::right().with_label_font_size(8.0);
+                    let mut y2_axis_config =
+                        AxisConfig::right().with_label_font_size(axis_font_size);
                     if let Some(ref label) = self.y2_label {
                         y2_axis_config = y2_axis_config.with_title(label.clone());
                     }
@@ -1064,6 +1580,11 @@ impl LineChart {
                     .relative()
                     .overflow_hidden()
                     .bg(self.theme.plot_background)
+                    .children(self.layout.render_watermark(
+                        self.theme.title_color,
+                        plot_width as f32,
+                        plot_height as f32,
+                    ))
                     .child(render_grid(
                         &x_scale,
                         &y_scale,
@@ -1103,8 +1624,12 @@ impl LineChart {
                     ));
                 }
 
+                plot_area = plot_area.children(self.zoom.probe());
+                plot_area =
+                    plot_area.children(self.crosshair_layer((x_min, x_max), (y_min, y_max)));
+
                 // Create axis configs with labels and angled X labels for log scale
-                let mut y_axis_config = AxisConfig::left().with_label_font_size(8.0);
+                let mut y_axis_config = AxisConfig::left().with_label_font_size(axis_font_size);
                 if let Some(ref label) = self.y_label {
                     y_axis_config = y_axis_config.with_title(label.clone());
                 }
@@ -1114,15 +1639,19 @@ impl LineChart {
                 let mut x_axis_config = AxisConfig::bottom()
                     .with_tick_values(x_ticks)
                     .with_label_angle(-45.0)
-                    .with_label_font_size(8.0)
+                    .with_label_font_size(axis_font_size)
                     .with_formatter(format_log_tick); // Use k/M formatting for log scale
                 if let Some(ref label) = self.x_label {
                     x_axis_config = x_axis_config.with_title(label.clone());
                 }
+                if let Some(axis) = &self.x_axis {
+                    x_axis_config = axis.configure(x_axis_config);
+                }
 
                 // Build chart with optional secondary Y axis
                 if has_secondary_axis {
-                    let mut y2_axis_config = AxisConfig::right().with_label_font_size(8.0);
+                    let mut y2_axis_config =
+                        AxisConfig::right().with_label_font_size(axis_font_size);
                     if let Some(ref label) = self.y2_label {
                         y2_axis_config = y2_axis_config.with_title(label.clone());
                     }
@@ -1184,6 +1713,11 @@ impl LineChart {
                     .relative()
                     .overflow_hidden()
                     .bg(self.theme.plot_background)
+                    .children(self.layout.render_watermark(
+                        self.theme.title_color,
+                        plot_width as f32,
+                        plot_height as f32,
+                    ))
                     .child(render_grid(
                         &x_scale,
                         &y_scale,
@@ -1223,12 +1757,16 @@ impl LineChart {
                     ));
                 }
 
+                plot_area = plot_area.children(self.zoom.probe());
+                plot_area =
+                    plot_area.children(self.crosshair_layer((x_min, x_max), (y_min, y_max)));
+
                 // Create axis configs with labels
                 // Generate smart tick values for log Y axis to prevent collision
                 let y_ticks = generate_log_ticks(y_min, y_max);
                 let mut y_axis_config = AxisConfig::left()
                     .with_tick_values(y_ticks)
-                    .with_label_font_size(8.0)
+                    .with_label_font_size(axis_font_size)
                     .with_formatter(format_log_tick); // Use k/M formatting for log scale
                 if let Some(ref label) = self.y_label {
                     y_axis_config = y_axis_config.with_title(label.clone());
@@ -1236,14 +1774,23 @@ impl LineChart {
 
                 let mut x_axis_config = AxisConfig::bottom()
                     .with_ticks(20)
-                    .with_label_font_size(8.0);
+                    .with_label_font_size(axis_font_size);
                 if let Some(ref label) = self.x_label {
                     x_axis_config = x_axis_config.with_title(label.clone());
                 }
+                if let Some(axis) = &self.x_axis {
+                    x_axis_config = axis.configure(x_axis_config);
+                }
+                if let Some(ticks) = &x_time_ticks {
+                    x_axis_config = x_axis_config
+                        .with_tick_values(ticks.clone())
+                        .with_formatter(format_time_tick);
+                }
 
                 // Build chart with optional secondary Y axis
                 if has_secondary_axis {
-                    let mut y2_axis_config = AxisConfig::right().with_label_font_size(8.0);
+                    let mut y2_axis_config =
+                        AxisConfig::right().with_label_font_size(axis_font_size);
                     if let Some(ref label) = self.y2_label {
                         y2_axis_config = y2_axis_config.with_title(label.clone());
                     }
@@ -1303,6 +1850,11 @@ impl LineChart {
                     .relative()
                     .overflow_hidden()
                     .bg(self.theme.plot_background)
+                    .children(self.layout.render_watermark(
+                        self.theme.title_color,
+                        plot_width as f32,
+                        plot_height as f32,
+                    ))
                     .child(render_grid(
                         &x_scale,
                         &y_scale,
@@ -1342,12 +1894,16 @@ impl LineChart {
                     ));
                 }
 
+                plot_area = plot_area.children(self.zoom.probe());
+                plot_area =
+                    plot_area.children(self.crosshair_layer((x_min, x_max), (y_min, y_max)));
+
                 // Create axis configs with labels and angled X labels for log scale
                 // Generate smart tick values for both log axes to prevent collision
                 let y_ticks = generate_log_ticks(y_min, y_max);
                 let mut y_axis_config = AxisConfig::left()
                     .with_tick_values(y_ticks)
-                    .with_label_font_size(8.0)
+                    .with_label_font_size(axis_font_size)
                     .with_formatter(format_log_tick); // Use k/M formatting for log scale
                 if let Some(ref label) = self.y_label {
                     y_axis_config = y_axis_config.with_title(label.clone());
@@ -1357,15 +1913,19 @@ impl LineChart {
                 let mut x_axis_config = AxisConfig::bottom()
                     .with_tick_values(x_ticks)
                     .with_label_angle(-45.0)
-                    .with_label_font_size(8.0)
+                    .with_label_font_size(axis_font_size)
                     .with_formatter(format_log_tick); // Use k/M formatting for log scale
                 if let Some(ref label) = self.x_label {
                     x_axis_config = x_axis_config.with_title(label.clone());
                 }
+                if let Some(axis) = &self.x_axis {
+                    x_axis_config = axis.configure(x_axis_config);
+                }
 
                 // Build chart with optional secondary Y axis
                 if has_secondary_axis {
-                    let mut y2_axis_config = AxisConfig::right().with_label_font_size(8.0);
+                    let mut y2_axis_config =
+                        AxisConfig::right().with_label_font_size(axis_font_size);
                     if let Some(ref label) = self.y2_label {
                         y2_axis_config = y2_axis_config.with_title(label.clone());
                     }
@@ -1439,7 +1999,7 @@ impl LineChart {
         // Add title if present
         if let Some(title) = &self.title {
             let font_config = VectorFontConfig::horizontal(
-                DEFAULT_TITLE_FONT_SIZE,
+                self.theme.title_font_size,
                 self.theme.title_color.into(),
             );
             container = container.child(
@@ -1596,8 +2156,179 @@ impl LineChart {
             container = container.child(div().relative().child(chart_content));
         }
 
-        Ok(container)
+        Ok(container.into_any_element())
+    }
+}
+
+impl crate::clipboard::CopyToClipboard for LineChart {
+    /// Visible series as long-format TSV (`series`, `x`, `y`).
+    fn clipboard_text(&self) -> Option<String> {
+        let mut series: Vec<(String, &[f64], &[f64])> = Vec::new();
+        if !self.hidden_series.contains(&0) {
+            let label = self.label.clone().unwrap_or_else(|| "Series 1".to_string());
+            series.push((label, &self.x, &self.y));
+        }
+        for (i, s) in self.series.iter().enumerate() {
+            if !self.hidden_series.contains(&(i + 1)) {
+                let label = s
+                    .label
+                    .clone()
+                    .unwrap_or_else(|| format!("Series {}", i + 2));
+                series.push((label, s.x.as_deref().unwrap_or(&self.x), &s.y));
+            }
+        }
+        Some(crate::clipboard::xy_tsv(&series))
+    }
+
+    #[cfg(feature = "gpu-2d")]
+    fn clipboard_png(&self) -> Option<Vec<u8>> {
+        crate::clipboard::chart_png(self, self.width, self.height)
+    }
+}
+
+#[cfg(feature = "gpu-2d")]
+impl crate::offscreen::TextureChart for LineChart {
+    fn validate(&self) -> Result<(), ChartError> {
+        self.plot_frame().map(|_| ())
+    }
+
+    fn content_key(&self) -> u64 {
+        use crate::offscreen::hash_values;
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        hash_values(&mut hasher, &self.x);
+        hash_values(&mut hasher, &self.y);
+        (
+            self.color,
+            self.stroke_width.to_bits(),
+            self.opacity.to_bits(),
+        )
+            .hash(&mut hasher);
+        for series in &self.series {
+            hash_values(&mut hasher, series.x.as_deref().unwrap_or(&[]));
+            hash_values(&mut hasher, &series.y);
+            (
+                series.color,
+                series.stroke_width.to_bits(),
+                series.opacity.to_bits(),
+            )
+                .hash(&mut hasher);
+        }
+        let mut hidden: Vec<_> = self.hidden_series.iter().collect();
+        hidden.sort();
+        hidden.hash(&mut hasher);
+        self.x_axis
+            .as_ref()
+            .and_then(Axis::pinned_domain)
+            .or(self.x_range)
+            .map(|r| r.map(f64::to_bits))
+            .hash(&mut hasher);
+        self.y_range.map(|r| r.map(f64::to_bits)).hash(&mut hasher);
+        (self.x_scale_type == ScaleType::Log).hash(&mut hasher);
+        (self.y_scale_type == ScaleType::Log).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn draw_marks(&self, renderer: &mut d3rs::gpu2d::Chart2DRenderer, width: f32, height: f32) {
+        use crate::offscreen::{TextureScale, texture_color};
+
+        // (index, x, y, color, stroke width, opacity) of each visible series
+        let mut visible: Vec<(usize, &[f64], &[f64], u32, f32, f32)> = Vec::new();
+        if !self.hidden_series.contains(&0) {
+            visible.push((
+                0,
+                &self.x,
+                &self.y,
+                self.color,
+                self.stroke_width,
+                self.opacity,
+            ));
+        }
+        for (i, series) in self.series.iter().enumerate() {
+            if !self.hidden_series.contains(&(i + 1)) {
+                let x = series.x.as_deref().unwrap_or(&self.x);
+                visible.push((
+                    i + 1,
+                    x,
+                    &series.y,
+                    series.color,
+                    series.stroke_width,
+                    series.opacity,
+                ));
+            }
+        }
+
+        let all_x: Vec<f64> = visible.iter().flat_map(|s| s.1.iter().copied()).collect();
+        let all_y: Vec<f64> = visible.iter().flat_map(|s| s.2.iter().copied()).collect();
+        if all_x.is_empty() {
+            return;
+        }
+        let x_domain = self
+            .x_axis
+            .as_ref()
+            .and_then(Axis::pinned_domain)
+            .or(self.x_range)
+            .map_or_else(|| extent_padded(&all_x, 0.0), |[a, b]| (a, b));
+        let y_domain = self.y_range.map_or_else(
+            || extent_padded(&all_y, DEFAULT_PADDING_FRACTION),
+            |[a, b]| (a, b),
+        );
+        let x_scale = TextureScale::horizontal(x_domain, self.x_scale_type, width);
+        let y_scale = TextureScale::vertical(y_domain, self.y_scale_type, height);
+
+        for (index, xs, ys, color, stroke_width, opacity) in visible {
+            let color = texture_color(color, opacity);
+            let points: Vec<[f32; 2]> = xs
+                .iter()
+                .zip(ys)
+                .map(|(&x, &y)| [x_scale.map(x), y_scale.map(y)])
+                .collect();
+            match self
+                .series_styles
+                .get(&index)
+                .and_then(|s| s.stroke_dash.as_ref())
+            {
+                Some(pattern) => {
+                    renderer.draw_dashed_polyline(&points, stroke_width, pattern, color)
+                }
+                None => renderer.draw_polyline(&points, stroke_width, color),
+            }
+        }
+    }
+}
+
+/// Pixel position along one axis of `extent` pixels spanning `domain`
+fn axis_pixels(domain: (f64, f64), extent: f64, log: bool) -> impl Fn(f64) -> f64 {
+    let transform = move |v: f64| if log { v.log10() } else { v };
+    let (start, end) = (transform(domain.0), transform(domain.1));
+    move |v| (transform(v) - start) / (end - start) * extent
+}
+
+/// `points` with each run of defined points simplified in pixel space
+///
+/// Undefined points are kept, so gaps still break the line.
+fn simplified_points(
+    points: &[(f64, f64)],
+    simplify: Simplify,
+    x_pixels: impl Fn(f64) -> f64,
+    y_pixels: impl Fn(f64) -> f64,
+) -> Vec<(f64, f64)> {
+    let defined = |&(x, y): &(f64, f64)| x.is_finite() && y.is_finite();
+    let mut kept = Vec::new();
+    for chunk in points.split_inclusive(|point| !defined(point)) {
+        let (run, gap) = match chunk.split_last() {
+            Some((last, run)) if !defined(last) => (run, Some(*last)),
+            _ => (chunk, None),
+        };
+        let pixels: Vec<Point> = run
+            .iter()
+            .map(|&(x, y)| Point::new(x_pixels(x), y_pixels(y)))
+            .collect();
+        kept.extend(simplify.indices(&pixels).into_iter().map(|i| run[i]));
+        kept.extend(gap);
     }
+    kept
 }
 
 /// Create a line chart from x and y data.
@@ -1619,6 +2350,7 @@ impl LineChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn line(x: &[f64], y: &[f64]) -> LineChart {
+    let defaults = ChartDefaults::current();
     LineChart {
         x: x.to_vec(),
         y: y.to_vec(),
@@ -1630,23 +2362,34 @@ pub fn line(x: &[f64], y: &[f64]) -> LineChart {
         stroke_width: 2.0,
         opacity: 1.0,
         series: Vec::new(),
+        palette: defaults.palette.unwrap_or_default(),
         curve: CurveType::Linear,
         show_points: false,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        simplify: None,
+        gap_mode: GapMode::default(),
+        isolated_point_radius: None,
+        width: defaults.width,
+        height: defaults.height,
         x_scale_type: ScaleType::Linear,
         y_scale_type: ScaleType::Linear,
         x_range: None,
         y_range: None,
+        x_axis: None,
+        x_time: false,
         show_legend: false,
-        legend_position: LegendPosition::default(),
-        legend_position_explicit: false,
+        legend_position: defaults.legend_position.unwrap_or_default(),
+        legend_position_explicit: defaults.legend_position.is_some(),
         graph_ratio: 1.414, // √2 ≈ A4 paper aspect ratio
-        theme: ChartTheme::default(),
+        theme: ChartTheme::current(),
         y2_label: None,
         y2_range: None,
         hidden_series: HashSet::new(),
         on_legend_click: None,
+        series_styles: HashMap::new(),
+        layout: ChartLayout::watermark_in_plot(),
+        zoom: ChartZoom::new(defaults.zoomable),
+        crosshair: defaults.crosshair,
+        simplified: None,
     }
 }
 
@@ -1693,14 +2436,24 @@ mod tests {
     }
 
     #[test]
-    fn test_line_nan_in_y() {
+    fn test_line_nan_in_y_is_a_gap() {
         let result = line(&[1.0, 2.0, 3.0], &[1.0, f64::NAN, 3.0]).build();
+        assert!(result.is_ok());
+
+        for mode in [GapMode::Connect, GapMode::Interpolate] {
+            let result = line(&[1.0, 2.0, 3.0], &[f64::NAN, 2.0, f64::NAN])
+                .gaps(mode)
+                .build();
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_line_all_nan_in_y() {
+        let result = line(&[1.0, 2.0], &[f64::NAN, f64::NAN]).build();
         assert!(matches!(
             result,
-            Err(ChartError::InvalidData {
-                field: "y",
-                reason: "contains NaN or Infinity"
-            })
+            Err(ChartError::InvalidData { field: "y", .. })
         ));
     }
 
@@ -1806,6 +2559,19 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_line_shared_axis_locks_domain() {
+        let axis = Axis::log().lock_domain();
+        let spl = line(&[20.0, 2000.0], &[85.0, 80.0]).x_axis(&axis).build();
+        assert!(spl.is_ok());
+        let domain = axis.current_domain();
+        assert!(domain.is_some());
+
+        let phase = line(&[10.0, 40000.0], &[0.0, -90.0]).x_axis(&axis).build();
+        assert!(phase.is_ok());
+        assert_eq!(axis.current_domain(), domain);
+    }
+
     #[test]
     fn test_line_y_range() {
         let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
@@ -1915,4 +2681,201 @@ mod tests {
             .build();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_line_subtitle_and_caption() {
+        let x = [1.0, 2.0, 3.0];
+        let y = [1.0, 4.0, 9.0];
+        let result = line(&x, &y)
+            .title("A long title that wraps onto a second line")
+            .subtitle("Subtitle")
+            .caption("Source: test")
+            .title_align(crate::TitleAlign::Left)
+            .title_wrap(true)
+            .size(200.0, 200.0)
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_line_series_styles() {
+        let x = [1.0, 2.0, 3.0, 4.0];
+        let chart = line(&x, &[1.0, 4.0, 9.0, 16.0])
+            .stroke_dash(&[4.0, 2.0])
+            .marker(SymbolType::Square)
+            .marker_spacing(2)
+            .add_series(&[2.0, 3.0, 2.0, 1.0], Some("B"), 0xff7f0e, 2.0, 1.0)
+            .series_style(
+                1,
+                LineStyle::new()
+                    .stroke_width(3.0)
+                    .curve(CurveType::MonotoneX),
+            );
+
+        let primary = chart.series_config(0, 0x1f77b4, 2.0, 1.0);
+        assert_eq!(primary.stroke_dash, Some(vec![4.0, 2.0]));
+        assert!(primary.show_points);
+        assert_eq!(primary.point_spacing, 2);
+        let series = chart.series_config(1, 0xff7f0e, 2.0, 1.0);
+        assert_eq!(series.stroke_width, 3.0);
+        assert_eq!(series.curve, CurveType::MonotoneX);
+        assert!(!series.show_points);
+        assert!(chart.build().is_ok());
+    }
+
+    #[test]
+    fn test_line_named_series() {
+        let chart = line(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0])
+            .label("A")
+            .add_named_series("B", &[0.0, 5.0], &[3.0, 1.0])
+            .add_named_series("C", &[1.0, 2.0], &[2.0, 2.0]);
+        let colors: Vec<u32> = chart.series.iter().map(|s| s.color).collect();
+        assert_eq!(colors, vec![0xff7f0e, 0x2ca02c]);
+        // Named series stretch the X-axis
+        let frame = chart.plot_frame().unwrap();
+        assert!(frame.x_domain.0 < 0.0 && frame.x_domain.1 > 5.0);
+        assert!(frame.has_legend_items);
+
+        let palette = CategoricalPalette::new(vec![0x111111, 0x222222]);
+        let chart = chart.palette(palette);
+        let colors: Vec<u32> = chart.series.iter().map(|s| s.color).collect();
+        assert_eq!(colors, vec![0x222222, 0x111111]);
+        assert_eq!(chart.color, DEFAULT_COLOR);
+        assert!(chart.build().is_ok());
+    }
+
+    #[test]
+    fn test_line_series_visibility() {
+        let visibility = SeriesVisibility::new();
+        visibility.toggle(1);
+        assert!(visibility.clone().is_hidden(1));
+
+        let chart = line(&[1.0, 2.0], &[1.0, 2.0])
+            .add_named_series("B", &[1.0, 2.0], &[2.0, 1.0])
+            .series_visibility(&visibility);
+        assert_eq!(chart.hidden_series, HashSet::from([1]));
+        assert!(chart.on_legend_click.is_some());
+        assert_eq!(chart.compute_layout().unwrap().paths.len(), 1);
+
+        visibility.toggle(1);
+        visibility.set_hidden(0, true);
+        assert_eq!(visibility.hidden(), HashSet::from([0]));
+        visibility.show_all();
+        assert!(visibility.hidden().is_empty());
+    }
+
+    #[test]
+    fn test_line_zoomable() {
+        let x = [20.0, 200.0, 2000.0, 20000.0];
+        let y = [0.0, 1.0, 2.0, 3.0];
+        let chart = line(&x, &y).x_scale(ScaleType::Log).zoomable(true);
+        assert!(chart.zoom.id.is_some());
+        assert!(chart.clone().build().is_ok());
+        assert!(chart.zoomable(false).zoom.id.is_none());
+
+        // Data errors are still returned from build()
+        let result = line(&x, &[0.0]).zoom_id("spl").build();
+        assert!(matches!(result, Err(ChartError::DataLengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_line_crosshair() {
+        let x = [20.0, 200.0, 2000.0, 20000.0];
+        let y = [0.0, 1.0, 2.0, 3.0];
+        let chart = line(&x, &y).x_scale(ScaleType::Log).crosshair(true);
+        assert!(chart.crosshair_layer((20.0, 20000.0), (0.0, 3.0)).is_some());
+        assert!(chart.clone().zoomable(true).build().is_ok());
+        let chart = chart.crosshair(false);
+        assert!(chart.crosshair_layer((20.0, 20000.0), (0.0, 3.0)).is_none());
+    }
+
+    #[test]
+    fn test_line_follows_chart_defaults() {
+        let x = [0.0, 1.0, 2.0];
+        let y = [0.0, 1.0, 2.0];
+        crate::defaults::set_current(Some(
+            ChartDefaults::new()
+                .size(320.0, 240.0)
+                .legend_position(LegendPosition::Bottom)
+                .crosshair(true),
+        ));
+        let chart = line(&x, &y);
+        let resized = line(&x, &y).size(800.0, 600.0);
+        crate::defaults::set_current(None);
+
+        assert_eq!((chart.width, chart.height), (320.0, 240.0));
+        assert_eq!(chart.legend_position, LegendPosition::Bottom);
+        assert!(chart.legend_position_explicit && chart.crosshair);
+        assert_eq!((resized.width, resized.height), (800.0, 600.0));
+        assert_eq!(line(&x, &y).width, crate::DEFAULT_WIDTH);
+    }
+
+    #[test]
+    fn test_line_compute_layout() {
+        let x = [0.0, 1.0, 2.0, 3.0];
+        let y = [0.0, 1.0, f64::NAN, 3.0];
+        let geometry = line(&x, &y).size(400.0, 300.0).compute_layout().unwrap();
+        assert_eq!(geometry.points.len(), 3);
+        assert_eq!(geometry.paths.len(), 2);
+        assert!(geometry.y2_axis.is_none());
+        // y grows upwards: larger values sit higher in the plot area
+        assert!(geometry.points[1].y < geometry.points[0].y);
+        assert!(geometry.points[1].x > geometry.points[0].x);
+        assert!(!geometry.x_axis.ticks.is_empty());
+        assert!(
+            geometry.x_axis.ticks.iter().all(|tick| {
+                tick.position >= -1e-9 && tick.position <= geometry.plot_width + 1e-9
+            })
+        );
+    }
+
+    #[test]
+    fn test_line_x_time() {
+        let day = 86400;
+        let timestamps: Vec<i64> = (0..5).map(|i| 1709596800 + i * day).collect();
+        let chart = line(&[], &[1.0, 3.0, 2.0, 5.0, 4.0]).x_time(&timestamps);
+        assert_eq!(chart.x[1], 1709683200.0);
+        assert!(chart.clone().build().is_ok());
+
+        // Ticks fall on midnights instead of arbitrary epoch seconds
+        let geometry = chart.size(400.0, 300.0).compute_layout().unwrap();
+        assert!(!geometry.x_axis.ticks.is_empty());
+        assert!(
+            geometry
+                .x_axis
+                .ticks
+                .iter()
+                .all(|tick| tick.value as i64 % day == 0)
+        );
+    }
+
+    #[test]
+    fn test_simplified_points() {
+        // A flat run with one peak, a gap, then a flat run
+        let mut points: Vec<(f64, f64)> = (0..50)
+            .map(|i| (i as f64, if i == 25 { 10.0 } else { 0.0 }))
+            .collect();
+        points.push((50.0, f64::NAN));
+        points.extend((51..100).map(|i| (i as f64, 0.0)));
+        let pixels = axis_pixels((0.0, 100.0), 100.0, false);
+        let kept = simplified_points(&points, Simplify::douglas_peucker(0.5), &pixels, &pixels);
+        // Ends and peak of the first run, the gap, ends of the second run
+        let xs: Vec<f64> = kept.iter().map(|&(x, _)| x).collect();
+        assert_eq!(xs, vec![0.0, 24.0, 25.0, 26.0, 49.0, 50.0, 51.0, 99.0]);
+        assert!(kept[5].1.is_nan());
+
+        let log = axis_pixels((1.0, 100.0), 200.0, true);
+        assert!((log(10.0) - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_line_simplifies_plain_series_only() {
+        let chart = line(&[1.0, 2.0], &[1.0, 2.0])
+            .add_series(&[2.0, 1.0], Some("b"), 0xff7f0e, 2.0, 1.0)
+            .series_style(1, LineStyle::new().marker(SymbolType::Circle));
+        assert!(chart.simplifies(0));
+        assert!(!chart.simplifies(1));
+        assert!(!chart.clone().curve(CurveType::MonotoneX).simplifies(0));
+        assert!(!chart.show_points(true).simplifies(0));
+    }
 }
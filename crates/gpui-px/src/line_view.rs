@@ -0,0 +1,605 @@
+//! Retained line charts - entity-based wrapper around [`LineChart`].
+//!
+//! The chart builders are immediate-mode: every render validates all data,
+//! scans it for extents and rebuilds the element tree. In a dashboard with
+//! many charts that work is repeated for every chart whenever any of them
+//! changes.
+//!
+//! [`LineChartView`] keeps the data of one line chart in an
+//! `Entity<LineChartState>` instead. What it caches:
+//! - per-series extents, only recomputed for the series passed to
+//!   [`LineChartView::update_data`] (appends just extend them)
+//! - the padded axis domain, derived from those extents
+//! - the rendered frame: the view is a cached `AnyView`, so a parent
+//!   re-render reuses it unless this chart's state was updated
+//!
+//! Scales and path geometry are not retained: once the chart is notified, it
+//! is rebuilt through [`LineChart`] with the data of every visible series,
+//! which validates and projects all of them again.
+
+use crate::error::ChartError;
+use crate::line::{LineChart, line};
+use crate::{
+    ChartDefaults, ScaleType, padded_range, validate_data_array, validate_data_length,
+    validate_positive,
+};
+use gpui::prelude::*;
+use gpui::{
+    AnyElement, AnyView, App, Context, Entity, IntoElement, StyleRefinement, Window, div, px,
+};
+use std::rc::Rc;
+
+/// Axis-aligned data bounds of a series.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DataExtent {
+    /// Smallest x value
+    pub x_min: f64,
+    /// Largest x value
+    pub x_max: f64,
+    /// Smallest y value
+    pub y_min: f64,
+    /// Largest y value
+    pub y_max: f64,
+}
+
+impl DataExtent {
+    fn of(x: &[f64], y: &[f64]) -> Option<Self> {
+        let (x_min, x_max) = min_max(x)?;
+        let (y_min, y_max) = min_max(y)?;
+        Some(Self {
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        })
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            x_min: self.x_min.min(other.x_min),
+            x_max: self.x_max.max(other.x_max),
+            y_min: self.y_min.min(other.y_min),
+            y_max: self.y_max.max(other.y_max),
+        }
+    }
+}
+
+fn min_max(values: &[f64]) -> Option<(f64, f64)> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(
+        values
+            .iter()
+            .copied()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(v), max.max(v))
+            }),
+    )
+}
+
+/// A named series held by a [`LineChartState`].
+#[derive(Debug, Clone)]
+pub struct RetainedSeries {
+    name: String,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    color: u32,
+    stroke_width: f32,
+    visible: bool,
+    extent: Option<DataExtent>,
+}
+
+impl RetainedSeries {
+    /// Series name (also its legend label).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// X values.
+    pub fn x(&self) -> &[f64] {
+        &self.x
+    }
+
+    /// Y values.
+    pub fn y(&self) -> &[f64] {
+        &self.y
+    }
+
+    /// Stroke color as `0xRRGGBB`.
+    pub fn color(&self) -> u32 {
+        self.color
+    }
+
+    /// Whether the series is drawn.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Cached data bounds, `None` for an empty series.
+    pub fn extent(&self) -> Option<DataExtent> {
+        self.extent
+    }
+}
+
+/// Chart styling applied on top of the retained data at render time.
+pub type LineChartStyle = Rc<dyn Fn(LineChart) -> LineChart>;
+
+/// Retained data, extents and axis domain of a [`LineChartView`].
+pub struct LineChartState {
+    series: Vec<RetainedSeries>,
+    x_scale: ScaleType,
+    y_scale: ScaleType,
+    width: f32,
+    height: f32,
+    style: Option<LineChartStyle>,
+    /// Cached padded `[x, y]` domain, `None` when it needs recomputing
+    domain: Option<Option<[[f64; 2]; 2]>>,
+    revision: u64,
+}
+
+impl std::fmt::Debug for LineChartState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LineChartState")
+            .field("series_count", &self.series.len())
+            .field("x_scale", &self.x_scale)
+            .field("y_scale", &self.y_scale)
+            .field("revision", &self.revision)
+            .finish()
+    }
+}
+
+impl Default for LineChartState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LineChartState {
+    /// Create an empty chart state, sized by the current [`ChartDefaults`].
+    pub fn new() -> Self {
+        let defaults = ChartDefaults::current();
+        Self {
+            series: Vec::new(),
+            x_scale: ScaleType::Linear,
+            y_scale: ScaleType::Linear,
+            width: defaults.width,
+            height: defaults.height,
+            style: None,
+            domain: None,
+            revision: 0,
+        }
+    }
+
+    /// Set the x axis scale type.
+    pub fn x_scale(mut self, scale: ScaleType) -> Self {
+        self.x_scale = scale;
+        self.domain = None;
+        self
+    }
+
+    /// Set the y axis scale type.
+    pub fn y_scale(mut self, scale: ScaleType) -> Self {
+        self.y_scale = scale;
+        self.domain = None;
+        self
+    }
+
+    /// Set chart dimensions in pixels.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Style the chart (title, labels, ranges, theme...) at render time.
+    ///
+    /// Ranges set here override the cached domain.
+    pub fn style(mut self, style: impl Fn(LineChart) -> LineChart + 'static) -> Self {
+        self.style = Some(Rc::new(style));
+        self
+    }
+
+    /// All series in insertion order.
+    pub fn series(&self) -> &[RetainedSeries] {
+        &self.series
+    }
+
+    /// Look up a series by name.
+    pub fn get(&self, name: &str) -> Option<&RetainedSeries> {
+        self.series.iter().find(|s| s.name == name)
+    }
+
+    /// Counter bumped by every data change.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Add a series, or replace the data and color of an existing one.
+    pub fn add_series(
+        &mut self,
+        name: impl Into<String>,
+        x: &[f64],
+        y: &[f64],
+        color: u32,
+    ) -> Result<(), ChartError> {
+        let name = name.into();
+        self.validate(x, y)?;
+        let extent = DataExtent::of(x, y);
+        let series = RetainedSeries {
+            name,
+            x: x.to_vec(),
+            y: y.to_vec(),
+            color,
+            stroke_width: 2.0,
+            visible: true,
+            extent,
+        };
+        match self.index_of(&series.name) {
+            Some(index) => self.series[index] = series,
+            None => self.series.push(series),
+        }
+        self.changed();
+        Ok(())
+    }
+
+    /// Replace the points of one series, recomputing only its extent.
+    pub fn update_data(&mut self, name: &str, x: &[f64], y: &[f64]) -> Result<(), ChartError> {
+        let index = self.require(name)?;
+        self.validate(x, y)?;
+        let series = &mut self.series[index];
+        series.x.clear();
+        series.x.extend_from_slice(x);
+        series.y.clear();
+        series.y.extend_from_slice(y);
+        series.extent = DataExtent::of(x, y);
+        self.changed();
+        Ok(())
+    }
+
+    /// Append points to one series, extending its cached extent.
+    pub fn append_points(&mut self, name: &str, x: &[f64], y: &[f64]) -> Result<(), ChartError> {
+        let index = self.require(name)?;
+        self.validate(x, y)?;
+        let series = &mut self.series[index];
+        series.x.extend_from_slice(x);
+        series.y.extend_from_slice(y);
+        series.extent = match (series.extent, DataExtent::of(x, y)) {
+            (Some(a), Some(b)) => Some(a.union(b)),
+            (a, b) => a.or(b),
+        };
+        self.changed();
+        Ok(())
+    }
+
+    /// Show or hide a series.
+    pub fn set_visible(&mut self, name: &str, visible: bool) -> Result<(), ChartError> {
+        let index = self.require(name)?;
+        if self.series[index].visible != visible {
+            self.series[index].visible = visible;
+            self.changed();
+        }
+        Ok(())
+    }
+
+    /// Remove a series; returns `false` if it doesn't exist.
+    pub fn remove_series(&mut self, name: &str) -> bool {
+        let Some(index) = self.index_of(name) else {
+            return false;
+        };
+        self.series.remove(index);
+        self.changed();
+        true
+    }
+
+    /// Padded `[x, y]` axis domain of the visible series.
+    ///
+    /// Derived from the cached per-series extents and memoized until the
+    /// next data change. `None` while no visible series has data.
+    pub fn domain(&mut self) -> Option<[[f64; 2]; 2]> {
+        if let Some(domain) = self.domain {
+            return domain;
+        }
+        let domain = self
+            .series
+            .iter()
+            .filter(|s| s.visible)
+            .filter_map(|s| s.extent)
+            .reduce(DataExtent::union)
+            .map(|e| {
+                let (x_min, x_max) = padded_range(e.x_min, e.x_max, self.x_scale);
+                let (y_min, y_max) = padded_range(e.y_min, e.y_max, self.y_scale);
+                [[x_min, x_max], [y_min, y_max]]
+            });
+        self.domain = Some(domain);
+        domain
+    }
+
+    /// Build the immediate-mode chart for the current data.
+    ///
+    /// Returns `None` while no visible series has data.
+    pub fn line_chart(&mut self) -> Option<LineChart> {
+        let [x_range, y_range] = self.domain()?;
+        let mut visible = self.series.iter().filter(|s| s.visible && !s.x.is_empty());
+        let first = visible.next()?;
+        let mut chart = line(&first.x, &first.y)
+            .label(first.name.clone())
+            .color(first.color)
+            .stroke_width(first.stroke_width);
+        for series in visible {
+            chart = chart.add_series_with_x(
+                &series.x,
+                &series.y,
+                Some(series.name.clone()),
+                series.color,
+                series.stroke_width,
+                1.0,
+            );
+        }
+        chart = chart
+            .x_scale(self.x_scale)
+            .y_scale(self.y_scale)
+            .x_range(x_range[0], x_range[1])
+            .y_range(y_range[0], y_range[1])
+            .size(self.width, self.height);
+        Some(match &self.style {
+            Some(style) => style(chart),
+            None => chart,
+        })
+    }
+
+    fn validate(&self, x: &[f64], y: &[f64]) -> Result<(), ChartError> {
+        validate_data_length(x.len(), y.len(), "x", "y")?;
+        if !x.is_empty() {
+            validate_data_array(x, "x")?;
+            validate_data_array(y, "y")?;
+        }
+        if self.x_scale == ScaleType::Log {
+            validate_positive(x, "x")?;
+        }
+        if self.y_scale == ScaleType::Log {
+            validate_positive(y, "y")?;
+        }
+        Ok(())
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.series.iter().position(|s| s.name == name)
+    }
+
+    fn require(&self, name: &str) -> Result<usize, ChartError> {
+        self.index_of(name)
+            .ok_or_else(|| ChartError::UnknownSeries {
+                name: name.to_string(),
+            })
+    }
+
+    fn changed(&mut self) {
+        self.domain = None;
+        self.revision += 1;
+    }
+}
+
+impl Render for LineChartState {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let (width, height) = (self.width, self.height);
+        let placeholder = |message: String| {
+            div()
+                .w(px(width))
+                .h(px(height))
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .text_color(gpui::rgb(0x888888))
+                .child(message)
+                .into_any_element()
+        };
+
+        let Some(chart) = self.line_chart() else {
+            return placeholder("No data".to_string());
+        };
+        match chart.build() {
+            Ok(element) => element.into_any_element(),
+            Err(err) => placeholder(format!("Chart error: {err}")),
+        }
+    }
+}
+
+/// Handle to a retained line chart.
+///
+/// Cheap to clone; updates notify only this chart's view, which is then
+/// rebuilt as a whole (see the [module docs](self)).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let view = LineChartView::new(
+///     LineChartState::new().x_scale(ScaleType::Log).style(|c| c.title("SPL")),
+///     cx,
+/// );
+/// view.add_series("left", &freq, &spl_left, 0x1f77b4, cx)?;
+/// view.add_series("right", &freq, &spl_right, 0xff7f0e, cx)?;
+///
+/// // Later: only the right channel changed
+/// view.update_data("right", &freq, &new_spl, cx)?;
+///
+/// // In render
+/// div().child(view.clone())
+/// ```
+#[derive(Clone)]
+pub struct LineChartView {
+    state: Entity<LineChartState>,
+}
+
+impl std::fmt::Debug for LineChartView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LineChartView")
+            .field("entity_id", &self.state.entity_id())
+            .finish()
+    }
+}
+
+impl LineChartView {
+    /// Create a retained chart from its initial state.
+    pub fn new(state: LineChartState, cx: &mut App) -> Self {
+        Self {
+            state: cx.new(|_| state),
+        }
+    }
+
+    /// The underlying entity.
+    pub fn state(&self) -> &Entity<LineChartState> {
+        &self.state
+    }
+
+    /// Add or replace a series.
+    pub fn add_series(
+        &self,
+        name: impl Into<String>,
+        x: &[f64],
+        y: &[f64],
+        color: u32,
+        cx: &mut App,
+    ) -> Result<(), ChartError> {
+        self.update(cx, |state| state.add_series(name, x, y, color))
+    }
+
+    /// Replace the points of one series.
+    pub fn update_data(
+        &self,
+        name: &str,
+        x: &[f64],
+        y: &[f64],
+        cx: &mut App,
+    ) -> Result<(), ChartError> {
+        self.update(cx, |state| state.update_data(name, x, y))
+    }
+
+    /// Append points to one series.
+    pub fn append_points(
+        &self,
+        name: &str,
+        x: &[f64],
+        y: &[f64],
+        cx: &mut App,
+    ) -> Result<(), ChartError> {
+        self.update(cx, |state| state.append_points(name, x, y))
+    }
+
+    /// Show or hide a series.
+    pub fn set_visible(&self, name: &str, visible: bool, cx: &mut App) -> Result<(), ChartError> {
+        self.update(cx, |state| state.set_visible(name, visible))
+    }
+
+    /// Remove a series; returns `false` if it doesn't exist.
+    pub fn remove_series(&self, name: &str, cx: &mut App) -> bool {
+        self.update(cx, |state| state.remove_series(name))
+    }
+
+    /// Notify the view only if the data actually changed.
+    fn update<R>(&self, cx: &mut App, f: impl FnOnce(&mut LineChartState) -> R) -> R {
+        self.state.update(cx, |state, cx| {
+            let revision = state.revision;
+            let result = f(state);
+            if state.revision != revision {
+                cx.notify();
+            }
+            result
+        })
+    }
+}
+
+impl IntoElement for LineChartView {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        AnyView::from(self.state)
+            .cached(StyleRefinement::default())
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_recomputes_only_changed_extent() {
+        let mut state = LineChartState::new();
+        state
+            .add_series("a", &[0.0, 1.0, 2.0], &[1.0, 2.0, 3.0], 0x1f77b4)
+            .unwrap();
+        state
+            .add_series("b", &[0.0, 1.0], &[-1.0, 0.0], 0xff7f0e)
+            .unwrap();
+        let a_extent = state.get("a").unwrap().extent();
+
+        state.update_data("b", &[0.0, 4.0], &[5.0, 6.0]).unwrap();
+        assert_eq!(state.get("a").unwrap().extent(), a_extent);
+        let b = state.get("b").unwrap().extent().unwrap();
+        assert_eq!((b.x_max, b.y_min, b.y_max), (4.0, 5.0, 6.0));
+
+        let [x, y] = state.domain().unwrap();
+        // x: [0, 4] padded by 5%, y: [1, 6] padded by 5%
+        assert!((x[0] + 0.2).abs() < 1e-10 && (x[1] - 4.2).abs() < 1e-10);
+        assert!((y[0] - 0.75).abs() < 1e-10 && (y[1] - 6.25).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_append_extends_extent() {
+        let mut state = LineChartState::new();
+        state.add_series("s", &[], &[], 0x1f77b4).unwrap();
+        assert_eq!(state.domain(), None);
+
+        state.append_points("s", &[1.0], &[2.0]).unwrap();
+        state.append_points("s", &[3.0, 2.0], &[-1.0, 4.0]).unwrap();
+        let extent = state.get("s").unwrap().extent().unwrap();
+        assert_eq!(
+            extent,
+            DataExtent {
+                x_min: 1.0,
+                x_max: 3.0,
+                y_min: -1.0,
+                y_max: 4.0
+            }
+        );
+        assert_eq!(state.get("s").unwrap().x().len(), 3);
+    }
+
+    #[test]
+    fn test_hidden_series_excluded_from_domain() {
+        let mut state = LineChartState::new().y_scale(ScaleType::Log);
+        state.add_series("a", &[1.0, 2.0], &[1.0, 10.0], 0).unwrap();
+        state
+            .add_series("b", &[1.0, 2.0], &[100.0, 1000.0], 0)
+            .unwrap();
+        state.set_visible("b", false).unwrap();
+        let [_, y] = state.domain().unwrap();
+        assert!((y[1] - 10.5).abs() < 1e-10);
+
+        let revision = state.revision();
+        state.set_visible("b", false).unwrap();
+        assert_eq!(state.revision(), revision);
+    }
+
+    #[test]
+    fn test_state_takes_default_size() {
+        crate::defaults::set_current(Some(ChartDefaults::new().size(300.0, 200.0)));
+        let state = LineChartState::new();
+        crate::defaults::set_current(None);
+        assert_eq!((state.width, state.height), (300.0, 200.0));
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut state = LineChartState::new().x_scale(ScaleType::Log);
+        assert!(matches!(
+            state.update_data("missing", &[1.0], &[1.0]),
+            Err(ChartError::UnknownSeries { .. })
+        ));
+        assert!(state.add_series("a", &[0.0], &[1.0], 0).is_err());
+        assert!(state.add_series("a", &[1.0, 2.0], &[1.0], 0).is_err());
+        assert!(state.series().is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+//! Offscreen chart rendering to GPU textures.
+//!
+//! Charts implementing [`TextureChart`] (line, bar and scatter charts) can draw their marks (series data,
+//! without axes, legends or titles) into a [`ChartTexture`], producing an
+//! image element that embeds anywhere: a thumbnail in a node, a trend inside
+//! a table cell. The texture is only redrawn when the chart's content key or
+//! the requested size changes.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use gpui_px::{ChartTexture, chart_image, line};
+//!
+//! // Keep the texture in the owning view so it survives re-renders
+//! let chart = line(&x, &y).color(0x1f77b4);
+//! let thumbnail = chart_image(&chart, &mut self.texture, 160.0, 48.0)?;
+//! ```
+
+use crate::ScaleType;
+use crate::error::ChartError;
+use d3rs::color::D3Color;
+use d3rs::gpu2d::primitives::Color4;
+use gpui::prelude::*;
+use gpui::{IntoElement, div, img, px};
+use std::hash::Hasher;
+
+pub use d3rs::gpu2d::{Chart2DRenderer, ChartTexture};
+
+/// A chart that can draw its marks into an offscreen texture.
+///
+/// Implemented by [`LineChart`](crate::LineChart),
+/// [`BarChart`](crate::BarChart) and [`ScatterChart`](crate::ScatterChart).
+/// Other charts can only be rendered as elements through their `build()`.
+pub trait TextureChart {
+    /// Check the chart data the same way the chart's `build()` does.
+    fn validate(&self) -> Result<(), ChartError>;
+
+    /// Hash of everything that affects the drawn marks.
+    ///
+    /// Cached textures are reused while this key is unchanged.
+    fn content_key(&self) -> u64;
+
+    /// Draw the chart's marks into a `width` x `height` pixel target.
+    fn draw_marks(&self, renderer: &mut Chart2DRenderer, width: f32, height: f32);
+}
+
+/// Render `chart` into `texture` and wrap the image in a fixed-size element.
+///
+/// Renders nothing (an empty box of the same size) when no GPU is available.
+/// Returns an error when the chart data or the size is invalid.
+pub fn chart_image<C: TextureChart>(
+    chart: &C,
+    texture: &mut ChartTexture,
+    width: f32,
+    height: f32,
+) -> Result<impl IntoElement, ChartError> {
+    crate::validate_dimensions(width, height)?;
+    chart.validate()?;
+
+    let image = texture.get_or_render(
+        chart.content_key(),
+        width.round() as u32,
+        height.round() as u32,
+        |renderer| chart.draw_marks(renderer, width.round(), height.round()),
+    );
+
+    let container = div().w(px(width)).h(px(height)).flex_none();
+    Ok(match image {
+        Some(image) => container.child(img(image).size_full()),
+        None => container,
+    })
+}
+
+/// Maps data values to texture pixels along one axis.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextureScale {
+    min: f64,
+    max: f64,
+    log: bool,
+    range: f32,
+    flip: bool,
+}
+
+impl TextureScale {
+    /// Horizontal scale from `domain` onto `[0, width]`.
+    pub(crate) fn horizontal(domain: (f64, f64), scale_type: ScaleType, width: f32) -> Self {
+        Self::new(domain, scale_type, width, false)
+    }
+
+    /// Vertical scale from `domain` onto `[height, 0]` (larger values on top).
+    pub(crate) fn vertical(domain: (f64, f64), scale_type: ScaleType, height: f32) -> Self {
+        Self::new(domain, scale_type, height, true)
+    }
+
+    fn new(domain: (f64, f64), scale_type: ScaleType, range: f32, flip: bool) -> Self {
+        let log = scale_type == ScaleType::Log;
+        let transform = |v: f64| if log { v.max(1e-10).log10() } else { v };
+        Self {
+            min: transform(domain.0),
+            max: transform(domain.1),
+            log,
+            range,
+            flip,
+        }
+    }
+
+    pub(crate) fn map(&self, value: f64) -> f32 {
+        let v = if self.log {
+            value.max(1e-10).log10()
+        } else {
+            value
+        };
+        let span = self.max - self.min;
+        let t = if span.abs() < f64::EPSILON {
+            0.5
+        } else {
+            (v - self.min) / span
+        };
+        let t = if self.flip { 1.0 - t } else { t };
+        (t as f32) * self.range
+    }
+}
+
+/// Convert a 24-bit hex color and opacity to a renderer color.
+pub(crate) fn texture_color(hex: u32, opacity: f32) -> Color4 {
+    let c = D3Color::from_hex(hex);
+    [c.r, c.g, c.b, c.a * opacity]
+}
+
+/// Feed the bit patterns of `values` into `hasher`.
+pub(crate) fn hash_values(hasher: &mut impl Hasher, values: &[f64]) {
+    hasher.write_usize(values.len());
+    for v in values {
+        hasher.write_u64(v.to_bits());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::DefaultHasher;
+
+    #[test]
+    fn test_texture_scale_linear() {
+        let x = TextureScale::horizontal((0.0, 10.0), ScaleType::Linear, 100.0);
+        assert_eq!(x.map(0.0), 0.0);
+        assert_eq!(x.map(5.0), 50.0);
+
+        let y = TextureScale::vertical((0.0, 10.0), ScaleType::Linear, 20.0);
+        assert_eq!(y.map(0.0), 20.0);
+        assert_eq!(y.map(10.0), 0.0);
+    }
+
+    #[test]
+    fn test_texture_scale_log_and_flat() {
+        let x = TextureScale::horizontal((1.0, 100.0), ScaleType::Log, 100.0);
+        assert!((x.map(10.0) - 50.0).abs() < 1e-4);
+
+        let flat = TextureScale::vertical((3.0, 3.0), ScaleType::Linear, 10.0);
+        assert_eq!(flat.map(3.0), 5.0);
+    }
+
+    #[test]
+    fn test_hash_values_distinguishes_data() {
+        let key = |values: &[f64]| {
+            let mut h = DefaultHasher::new();
+            hash_values(&mut h, values);
+            h.finish()
+        };
+        assert_eq!(key(&[1.0, 2.0]), key(&[1.0, 2.0]));
+        assert_ne!(key(&[1.0, 2.0]), key(&[1.0, 2.5]));
+        assert_ne!(key(&[1.0]), key(&[1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_content_key_tracks_chart_data() {
+        use crate::{bar, line, scatter};
+
+        let x = [0.0, 1.0, 2.0];
+        assert_eq!(
+            line(&x, &[1.0, 2.0, 3.0]).content_key(),
+            line(&x, &[1.0, 2.0, 3.0]).content_key()
+        );
+        assert_ne!(
+            line(&x, &[1.0, 2.0, 3.0]).content_key(),
+            line(&x, &[1.0, 2.0, 4.0]).content_key()
+        );
+        assert_ne!(
+            scatter(&x, &[1.0, 2.0, 3.0]).content_key(),
+            scatter(&x, &[1.0, 2.0, 3.0]).color(0xff0000).content_key()
+        );
+        assert_ne!(
+            bar(&["a", "b"], &[1.0, 2.0]).content_key(),
+            bar(&["a", "c"], &[1.0, 2.0]).content_key()
+        );
+    }
+
+    #[test]
+    fn test_chart_image_validates_data() {
+        use crate::{bar, line, scatter};
+
+        let mut texture = ChartTexture::new();
+        assert!(matches!(
+            chart_image(&line(&[0.0, 1.0], &[1.0]), &mut texture, 100.0, 40.0),
+            Err(ChartError::DataLengthMismatch { .. })
+        ));
+        assert!(matches!(
+            chart_image(&scatter(&[0.0], &[f64::NAN]), &mut texture, 100.0, 40.0),
+            Err(ChartError::InvalidData { .. })
+        ));
+        let empty_categories: Vec<&str> = vec![];
+        assert!(matches!(
+            chart_image(&bar(&empty_categories, &[]), &mut texture, 100.0, 40.0),
+            Err(ChartError::EmptyData { .. })
+        ));
+        assert!(line(&[0.0, 1.0], &[1.0, 2.0]).validate().is_ok());
+    }
+}
@@ -0,0 +1,459 @@
+//! Multi-trace overlay manager for measurement apps.
+//!
+//! [`OverlayManager`] holds many named frequency-response curves, assigns
+//! them palette colors, tracks their visibility and computes derived traces
+//! (difference, average, fractional-octave smoothing). Derived traces are
+//! recomputed whenever one of their sources is updated and are removed
+//! together with their sources.
+//!
+//! The manager turns into a [`LineChart`] with one series per trace, so the
+//! legend lists every trace and hidden ones can be toggled back from it.
+
+use crate::error::ChartError;
+use crate::line::{LineChart, line};
+use d3rs::acoustics::smooth_octave;
+use d3rs::color::{ColorScheme, D3Color};
+
+/// Identifier of a trace in an [`OverlayManager`].
+pub type TraceId = usize;
+
+/// Where a trace's values come from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceSource {
+    /// Values supplied by the app (a measurement).
+    Measured,
+    /// `a - b`, on the frequency grid of `a`.
+    Difference {
+        /// Minuend trace
+        a: TraceId,
+        /// Subtrahend trace
+        b: TraceId,
+    },
+    /// Mean of the traces, on the frequency grid of the first one.
+    Average(Vec<TraceId>),
+    /// 1/N-octave smoothing of `source`.
+    Smoothed {
+        /// Source trace
+        source: TraceId,
+        /// Fraction N of an octave (3 → 1/3 octave)
+        fraction: u32,
+    },
+}
+
+impl TraceSource {
+    /// Traces this one is computed from.
+    pub fn dependencies(&self) -> Vec<TraceId> {
+        match self {
+            TraceSource::Measured => Vec::new(),
+            TraceSource::Difference { a, b } => vec![*a, *b],
+            TraceSource::Average(ids) => ids.clone(),
+            TraceSource::Smoothed { source, .. } => vec![*source],
+        }
+    }
+}
+
+/// A named curve held by an [`OverlayManager`].
+#[derive(Debug, Clone)]
+pub struct Trace {
+    /// Stable identifier
+    pub id: TraceId,
+    /// Legend label
+    pub name: String,
+    /// Frequencies in Hz, ascending
+    pub freq: Vec<f64>,
+    /// Values (typically dB SPL)
+    pub values: Vec<f64>,
+    /// Line color as 24-bit RGB hex value
+    pub color: u32,
+    /// Whether the trace is drawn
+    pub visible: bool,
+    /// Measured or derived
+    pub source: TraceSource,
+}
+
+impl Trace {
+    /// Whether the trace is computed from other traces.
+    pub fn is_derived(&self) -> bool {
+        self.source != TraceSource::Measured
+    }
+}
+
+/// Collection of overlaid traces with visibility, colors and derived math.
+#[derive(Debug, Clone)]
+pub struct OverlayManager {
+    traces: Vec<Trace>,
+    palette: ColorScheme,
+    next_id: TraceId,
+    next_color: usize,
+}
+
+impl Default for OverlayManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OverlayManager {
+    /// Create an empty manager using the Category10 palette.
+    pub fn new() -> Self {
+        Self {
+            traces: Vec::new(),
+            palette: ColorScheme::category10(),
+            next_id: 0,
+            next_color: 0,
+        }
+    }
+
+    /// Set the palette used for traces added from now on.
+    pub fn palette(mut self, palette: ColorScheme) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// All traces in insertion order.
+    pub fn traces(&self) -> &[Trace] {
+        &self.traces
+    }
+
+    /// Visible traces in insertion order.
+    pub fn visible_traces(&self) -> impl Iterator<Item = &Trace> {
+        self.traces.iter().filter(|t| t.visible)
+    }
+
+    /// Number of traces.
+    pub fn len(&self) -> usize {
+        self.traces.len()
+    }
+
+    /// Whether the manager holds no traces.
+    pub fn is_empty(&self) -> bool {
+        self.traces.is_empty()
+    }
+
+    /// Look up a trace.
+    pub fn get(&self, id: TraceId) -> Option<&Trace> {
+        self.traces.iter().find(|t| t.id == id)
+    }
+
+    fn get_mut(&mut self, id: TraceId) -> Option<&mut Trace> {
+        self.traces.iter_mut().find(|t| t.id == id)
+    }
+
+    /// Trace drawn as chart series `index` (as passed to legend callbacks).
+    pub fn trace_at_series(&self, index: usize) -> Option<TraceId> {
+        self.traces.get(index).map(|t| t.id)
+    }
+
+    /// Add a measured trace; returns its id.
+    pub fn add(&mut self, name: impl Into<String>, freq: &[f64], values: &[f64]) -> TraceId {
+        self.push(
+            name.into(),
+            freq.to_vec(),
+            values.to_vec(),
+            TraceSource::Measured,
+        )
+    }
+
+    /// Add `a - b`; returns `None` if either trace is missing.
+    pub fn add_difference(&mut self, a: TraceId, b: TraceId) -> Option<TraceId> {
+        let name = format!("{} − {}", self.get(a)?.name, self.get(b)?.name);
+        self.add_derived(name, TraceSource::Difference { a, b })
+    }
+
+    /// Add the mean of `ids`; returns `None` if `ids` is empty or a trace is
+    /// missing.
+    pub fn add_average(&mut self, ids: &[TraceId]) -> Option<TraceId> {
+        if ids.is_empty() || ids.iter().any(|&id| self.get(id).is_none()) {
+            return None;
+        }
+        let name = format!("Average ({})", ids.len());
+        self.add_derived(name, TraceSource::Average(ids.to_vec()))
+    }
+
+    /// Add a 1/N-octave smoothed copy of `source`; returns `None` if the
+    /// trace is missing or `fraction` is 0.
+    pub fn add_smoothed(&mut self, source: TraceId, fraction: u32) -> Option<TraceId> {
+        if fraction == 0 {
+            return None;
+        }
+        let name = format!("{} (1/{} oct)", self.get(source)?.name, fraction);
+        self.add_derived(name, TraceSource::Smoothed { source, fraction })
+    }
+
+    fn add_derived(&mut self, name: String, source: TraceSource) -> Option<TraceId> {
+        let (freq, values) = self.compute(&source)?;
+        Some(self.push(name, freq, values, source))
+    }
+
+    fn push(
+        &mut self,
+        name: String,
+        freq: Vec<f64>,
+        values: Vec<f64>,
+        source: TraceSource,
+    ) -> TraceId {
+        let id = self.next_id;
+        self.next_id += 1;
+        let color = hex(&self.palette.color(self.next_color));
+        self.next_color += 1;
+        self.traces.push(Trace {
+            id,
+            name,
+            freq,
+            values,
+            color,
+            visible: true,
+            source,
+        });
+        id
+    }
+
+    /// Replace the data of a measured trace and recompute derived traces.
+    ///
+    /// Returns `false` if the trace is missing or derived.
+    pub fn update(&mut self, id: TraceId, freq: &[f64], values: &[f64]) -> bool {
+        match self.get_mut(id) {
+            Some(trace) if !trace.is_derived() => {
+                trace.freq = freq.to_vec();
+                trace.values = values.to_vec();
+            }
+            _ => return false,
+        }
+        self.recompute();
+        true
+    }
+
+    /// Recompute every derived trace from its sources.
+    ///
+    /// Sources always precede the traces derived from them, so a single pass
+    /// in insertion order is enough.
+    pub fn recompute(&mut self) {
+        for i in 0..self.traces.len() {
+            if !self.traces[i].is_derived() {
+                continue;
+            }
+            if let Some((freq, values)) = self.compute(&self.traces[i].source) {
+                self.traces[i].freq = freq;
+                self.traces[i].values = values;
+            }
+        }
+    }
+
+    /// Remove a trace and every trace derived from it, directly or not.
+    ///
+    /// Returns the removed ids.
+    pub fn remove(&mut self, id: TraceId) -> Vec<TraceId> {
+        if self.get(id).is_none() {
+            return Vec::new();
+        }
+        let mut removed = vec![id];
+        for trace in &self.traces {
+            if trace
+                .source
+                .dependencies()
+                .iter()
+                .any(|d| removed.contains(d))
+            {
+                removed.push(trace.id);
+            }
+        }
+        self.traces.retain(|t| !removed.contains(&t.id));
+        removed
+    }
+
+    /// Show or hide a trace.
+    pub fn set_visible(&mut self, id: TraceId, visible: bool) {
+        if let Some(trace) = self.get_mut(id) {
+            trace.visible = visible;
+        }
+    }
+
+    /// Flip the visibility of a trace.
+    pub fn toggle(&mut self, id: TraceId) {
+        if let Some(trace) = self.get_mut(id) {
+            trace.visible = !trace.visible;
+        }
+    }
+
+    /// Override the palette color of a trace.
+    pub fn set_color(&mut self, id: TraceId, hex: u32) {
+        if let Some(trace) = self.get_mut(id) {
+            trace.color = hex;
+        }
+    }
+
+    /// Rename a trace.
+    pub fn rename(&mut self, id: TraceId, name: impl Into<String>) {
+        if let Some(trace) = self.get_mut(id) {
+            trace.name = name.into();
+        }
+    }
+
+    /// Build a [`LineChart`] with one labelled series per trace.
+    ///
+    /// Hidden traces stay in the legend so they can be toggled back; map the
+    /// legend callback index with [`OverlayManager::trace_at_series`].
+    pub fn line_chart(&self) -> Result<LineChart, ChartError> {
+        let Some(first) = self.traces.first() else {
+            return Err(ChartError::EmptyData { field: "traces" });
+        };
+        let mut chart = line(&first.freq, &first.values)
+            .label(first.name.clone())
+            .color(first.color);
+        for trace in &self.traces[1..] {
+            chart = chart.add_series_with_x(
+                &trace.freq,
+                &trace.values,
+                Some(trace.name.clone()),
+                trace.color,
+                2.0,
+                1.0,
+            );
+        }
+        let hidden: Vec<usize> = self
+            .traces
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| !t.visible)
+            .map(|(i, _)| i)
+            .collect();
+        Ok(chart.hidden_series(&hidden))
+    }
+
+    /// Compute the data of a derived trace.
+    fn compute(&self, source: &TraceSource) -> Option<(Vec<f64>, Vec<f64>)> {
+        match source {
+            TraceSource::Measured => None,
+            TraceSource::Difference { a, b } => {
+                let (a, b) = (self.get(*a)?, self.get(*b)?);
+                let values = a
+                    .freq
+                    .iter()
+                    .zip(&a.values)
+                    .map(|(&f, &v)| v - interpolate(&b.freq, &b.values, f))
+                    .collect();
+                Some((a.freq.clone(), values))
+            }
+            TraceSource::Average(ids) => {
+                let traces: Vec<&Trace> =
+                    ids.iter().map(|&id| self.get(id)).collect::<Option<_>>()?;
+                let grid = &traces.first()?.freq;
+                let values = grid
+                    .iter()
+                    .map(|&f| {
+                        traces
+                            .iter()
+                            .map(|t| interpolate(&t.freq, &t.values, f))
+                            .sum::<f64>()
+                            / traces.len() as f64
+                    })
+                    .collect();
+                Some((grid.clone(), values))
+            }
+            TraceSource::Smoothed { source, fraction } => {
+                let trace = self.get(*source)?;
+                let values = smooth_octave(&trace.freq, &trace.values, *fraction);
+                Some((trace.freq.clone(), values))
+            }
+        }
+    }
+}
+
+/// 24-bit RGB hex value of a color.
+fn hex(color: &D3Color) -> u32 {
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u32;
+    (channel(color.r) << 16) | (channel(color.g) << 8) | channel(color.b)
+}
+
+/// Linear interpolation of `(freq, values)` at `f`, clamped at the ends.
+fn interpolate(freq: &[f64], values: &[f64], f: f64) -> f64 {
+    let n = freq.len().min(values.len());
+    if n == 0 {
+        return f64::NAN;
+    }
+    let i = freq[..n].partition_point(|&x| x < f);
+    if i == 0 {
+        values[0]
+    } else if i >= n {
+        values[n - 1]
+    } else {
+        let (f0, f1) = (freq[i - 1], freq[i]);
+        let t = if f1 > f0 { (f - f0) / (f1 - f0) } else { 0.0 };
+        values[i - 1] + t * (values[i] - values[i - 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Vec<f64> {
+        vec![100.0, 200.0, 400.0, 800.0]
+    }
+
+    #[test]
+    fn test_colors_cycle_through_palette() {
+        let mut overlay = OverlayManager::new();
+        let ids: Vec<TraceId> = (0..11)
+            .map(|i| overlay.add(format!("m{i}"), &grid(), &[0.0; 4]))
+            .collect();
+        assert_eq!(overlay.get(ids[0]).unwrap().color, 0x1f77b4);
+        assert_eq!(overlay.get(ids[1]).unwrap().color, 0xff7f0e);
+        assert_eq!(overlay.get(ids[10]).unwrap().color, 0x1f77b4);
+    }
+
+    #[test]
+    fn test_difference_and_average() {
+        let mut overlay = OverlayManager::new();
+        let a = overlay.add("A", &grid(), &[10.0, 10.0, 10.0, 10.0]);
+        let b = overlay.add("B", &[100.0, 800.0], &[0.0, 7.0]);
+
+        let diff = overlay.add_difference(a, b).unwrap();
+        let diff = overlay.get(diff).unwrap();
+        assert_eq!(diff.name, "A − B");
+        assert_eq!(diff.values, vec![10.0, 9.0, 7.0, 3.0]);
+
+        let avg = overlay.add_average(&[a, b]).unwrap();
+        assert_eq!(overlay.get(avg).unwrap().values, vec![5.0, 5.5, 6.5, 8.5]);
+        assert!(overlay.add_average(&[]).is_none());
+        assert!(overlay.add_difference(a, 99).is_none());
+    }
+
+    #[test]
+    fn test_update_recomputes_and_remove_cascades() {
+        let mut overlay = OverlayManager::new();
+        let a = overlay.add("A", &grid(), &[1.0; 4]);
+        let b = overlay.add("B", &grid(), &[0.0; 4]);
+        let diff = overlay.add_difference(a, b).unwrap();
+        let smooth = overlay.add_smoothed(diff, 3).unwrap();
+
+        assert!(overlay.update(b, &grid(), &[1.0; 4]));
+        assert_eq!(overlay.get(diff).unwrap().values, vec![0.0; 4]);
+        assert_eq!(overlay.get(smooth).unwrap().values, vec![0.0; 4]);
+        assert!(!overlay.update(diff, &grid(), &[0.0; 4]));
+
+        let removed = overlay.remove(b);
+        assert_eq!(removed, vec![b, diff, smooth]);
+        assert_eq!(overlay.len(), 1);
+        assert!(overlay.remove(b).is_empty());
+    }
+
+    #[test]
+    fn test_visibility_and_chart() {
+        let mut overlay = OverlayManager::new();
+        assert!(matches!(
+            overlay.line_chart(),
+            Err(ChartError::EmptyData { field: "traces" })
+        ));
+
+        let a = overlay.add("A", &grid(), &[1.0; 4]);
+        let b = overlay.add("B", &grid(), &[2.0; 4]);
+        overlay.toggle(a);
+        assert_eq!(
+            overlay.visible_traces().map(|t| t.id).collect::<Vec<_>>(),
+            vec![b]
+        );
+        assert_eq!(overlay.trace_at_series(1), Some(b));
+        assert!(overlay.line_chart().is_ok());
+    }
+}
@@ -0,0 +1,177 @@
+//! Categorical palette keyed by series name.
+//!
+//! Charts take plain `0xRRGGBB` colors, so an app comparing the same
+//! speakers in several charts has to pick the colors itself. A
+//! [`CategoricalPalette`] maps a series name to a color with a stable hash,
+//! so the same name gets the same color in every chart and on every run,
+//! whatever order the series are added in:
+//!
+//! ```rust
+//! use gpui_px::CategoricalPalette;
+//!
+//! let palette = CategoricalPalette::tableau10().with_override("Reference", 0x000000);
+//! assert_eq!(palette.color("KEF R3"), palette.color("KEF R3"));
+//! assert_eq!(palette.color("Reference"), 0x000000);
+//! ```
+
+use d3rs::color::{ColorScheme, D3Color};
+use std::collections::HashMap;
+
+/// Assigns consistent colors to series names.
+///
+/// The palette is cheap to clone; keep one per app and pass it to the views
+/// that build charts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoricalPalette {
+    colors: Vec<u32>,
+    overrides: HashMap<String, u32>,
+}
+
+impl Default for CategoricalPalette {
+    fn default() -> Self {
+        Self::category10()
+    }
+}
+
+impl CategoricalPalette {
+    /// Create a palette from `0xRRGGBB` colors.
+    ///
+    /// An empty list falls back to the Category10 colors.
+    pub fn new(colors: Vec<u32>) -> Self {
+        if colors.is_empty() {
+            return Self::category10();
+        }
+        Self {
+            colors,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Create a palette from a d3 color scheme.
+    pub fn from_scheme(scheme: &ColorScheme) -> Self {
+        Self::new(scheme.colors().iter().map(to_hex).collect())
+    }
+
+    /// D3 Category10 palette (the default colors of the charts).
+    pub fn category10() -> Self {
+        Self::from_scheme(&ColorScheme::category10())
+    }
+
+    /// Tableau10 palette.
+    pub fn tableau10() -> Self {
+        Self::from_scheme(&ColorScheme::tableau10())
+    }
+
+    /// Pin the color of a series name.
+    pub fn with_override(mut self, name: impl Into<String>, hex: u32) -> Self {
+        self.set_override(name, hex);
+        self
+    }
+
+    /// Pin the color of a series name, e.g. from a user preference.
+    pub fn set_override(&mut self, name: impl Into<String>, hex: u32) {
+        self.overrides.insert(name.into(), hex);
+    }
+
+    /// Remove a pinned color, returning the name to its hashed color.
+    pub fn clear_override(&mut self, name: &str) {
+        self.overrides.remove(name);
+    }
+
+    /// Color of a series name: its override, or the hashed palette color.
+    pub fn color(&self, name: &str) -> u32 {
+        self.overrides
+            .get(name)
+            .copied()
+            .unwrap_or_else(|| self.colors[self.index(name)])
+    }
+
+    /// Palette slot of a series name.
+    ///
+    /// The slot is stable across runs and platforms, so it can also drive a
+    /// second encoding such as marker shape or dash pattern for viewers who
+    /// cannot tell the colors apart.
+    pub fn index(&self, name: &str) -> usize {
+        (fnv1a(name) % self.colors.len() as u64) as usize
+    }
+
+    /// Color for a position, cycling through the palette.
+    ///
+    /// Use this for anonymous series, where there is no name to hash.
+    pub fn color_at(&self, index: usize) -> u32 {
+        self.colors[index % self.colors.len()]
+    }
+
+    /// Colors of the palette, without overrides.
+    pub fn colors(&self) -> &[u32] {
+        &self.colors
+    }
+
+    /// Number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Always false: an empty palette falls back to Category10.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+}
+
+fn to_hex(color: &D3Color) -> u32 {
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u32;
+    (channel(color.r) << 16) | (channel(color.g) << 8) | channel(color.b)
+}
+
+/// 64-bit FNV-1a. `std`'s hasher is randomly seeded and may change between
+/// Rust releases, which would reshuffle the colors.
+fn fnv1a(name: &str) -> u64 {
+    name.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_schemes_match_d3() {
+        let palette = CategoricalPalette::category10();
+        assert_eq!(palette.len(), 10);
+        assert_eq!(palette.color_at(0), 0x1f77b4);
+        assert_eq!(palette.color_at(11), 0xff7f0e);
+        assert_eq!(CategoricalPalette::tableau10().color_at(0), 0x4e79a7);
+    }
+
+    #[test]
+    fn test_palette_color_is_stable() {
+        let palette = CategoricalPalette::category10();
+        // Pinned so a change of hash is noticed: it would recolor saved charts
+        assert_eq!(fnv1a("KEF R3"), 0x7d92db8e4a7a52e0);
+        assert_eq!(
+            palette.color("KEF R3"),
+            palette.color_at(palette.index("KEF R3"))
+        );
+        assert_eq!(palette.clone().color("KEF R3"), palette.color("KEF R3"));
+    }
+
+    #[test]
+    fn test_palette_overrides() {
+        let mut palette = CategoricalPalette::tableau10().with_override("Reference", 0x000000);
+        assert_eq!(palette.color("Reference"), 0x000000);
+        palette.clear_override("Reference");
+        assert_eq!(
+            palette.color("Reference"),
+            palette.color_at(palette.index("Reference"))
+        );
+    }
+
+    #[test]
+    fn test_palette_empty_falls_back_to_category10() {
+        assert_eq!(
+            CategoricalPalette::new(Vec::new()),
+            CategoricalPalette::category10()
+        );
+    }
+}
@@ -0,0 +1,466 @@
+//! Phase and group delay charts - presets over [`LineChart`].
+//!
+//! Standard audio views of a measured phase response versus frequency:
+//!
+//! - [`phase()`]: phase in degrees, wrapped to ±180° or unwrapped
+//! - [`group_delay()`]: `-dφ/dω` in ms, µs or s
+//!
+//! Both can remove the part of the phase that does not describe the device
+//! itself (see [`ExcessPhase`]), typically the time of flight between the
+//! speaker and the microphone.
+
+use crate::error::ChartError;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::line::{LineChart, line};
+use crate::{ChartDefaults, DEFAULT_COLOR, ScaleType, validate_data_array, validate_data_length};
+use gpui::IntoElement;
+
+/// Phase component removed before plotting.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ExcessPhase {
+    /// Plot the phase as measured (default).
+    #[default]
+    Keep,
+    /// Remove a pure delay in seconds.
+    RemoveDelay(f64),
+    /// Remove the delay that best fits the unwrapped phase (least squares).
+    RemoveBestFitDelay,
+    /// Subtract a minimum-phase response in degrees, sampled at the same
+    /// frequencies, leaving the excess phase.
+    RemoveMinimumPhase(Vec<f64>),
+}
+
+/// Time unit for group delay.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DelayUnit {
+    /// Seconds
+    Seconds,
+    /// Milliseconds (default)
+    #[default]
+    Milliseconds,
+    /// Microseconds
+    Microseconds,
+}
+
+impl DelayUnit {
+    /// Multiplier from seconds.
+    pub fn factor(self) -> f64 {
+        match self {
+            DelayUnit::Seconds => 1.0,
+            DelayUnit::Milliseconds => 1e3,
+            DelayUnit::Microseconds => 1e6,
+        }
+    }
+
+    /// Unit symbol.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            DelayUnit::Seconds => "s",
+            DelayUnit::Milliseconds => "ms",
+            DelayUnit::Microseconds => "µs",
+        }
+    }
+}
+
+/// Wrap a phase in degrees to `(-180, 180]`.
+pub fn wrap_phase(degrees: f64) -> f64 {
+    let wrapped = (degrees + 180.0).rem_euclid(360.0) - 180.0;
+    if wrapped == -180.0 { 180.0 } else { wrapped }
+}
+
+/// Unwrap a phase in degrees by removing jumps larger than 180°.
+pub fn unwrap_phase(degrees: &[f64]) -> Vec<f64> {
+    let mut out = Vec::with_capacity(degrees.len());
+    let mut offset = 0.0;
+    for (i, &p) in degrees.iter().enumerate() {
+        if i > 0 {
+            let jump = p - degrees[i - 1];
+            offset -= 360.0 * (jump / 360.0).round();
+        }
+        out.push(p + offset);
+    }
+    out
+}
+
+/// Group delay in seconds, `-dφ/dω`, from a phase in degrees.
+///
+/// Uses central differences on the unwrapped phase (one-sided at the ends),
+/// so the frequency grid does not need to be uniform.
+pub fn group_delay_seconds(freq: &[f64], phase_deg: &[f64]) -> Vec<f64> {
+    let n = freq.len().min(phase_deg.len());
+    if n < 2 {
+        return vec![0.0; n];
+    }
+    let phase = unwrap_phase(&phase_deg[..n]);
+    (0..n)
+        .map(|i| {
+            let (a, b) = (i.saturating_sub(1), (i + 1).min(n - 1));
+            let df = freq[b] - freq[a];
+            if df == 0.0 {
+                0.0
+            } else {
+                -(phase[b] - phase[a]) / df / 360.0
+            }
+        })
+        .collect()
+}
+
+/// Delay in seconds whose linear phase best fits the unwrapped phase.
+pub fn best_fit_delay(freq: &[f64], phase_deg: &[f64]) -> f64 {
+    let n = freq.len().min(phase_deg.len());
+    if n < 2 {
+        return 0.0;
+    }
+    let phase = unwrap_phase(&phase_deg[..n]);
+    let mean_f = freq[..n].iter().sum::<f64>() / n as f64;
+    let mean_p = phase.iter().sum::<f64>() / n as f64;
+    let (mut cov, mut var) = (0.0, 0.0);
+    for (f, p) in freq[..n].iter().zip(&phase) {
+        cov += (f - mean_f) * (p - mean_p);
+        var += (f - mean_f) * (f - mean_f);
+    }
+    if var == 0.0 {
+        0.0
+    } else {
+        -(cov / var) / 360.0
+    }
+}
+
+/// Unwrapped phase with the excess component removed.
+fn processed_phase(
+    freq: &[f64],
+    phase_deg: &[f64],
+    excess: &ExcessPhase,
+) -> Result<Vec<f64>, ChartError> {
+    validate_data_array(freq, "freq")?;
+    validate_data_array(phase_deg, "phase")?;
+    validate_data_length(freq.len(), phase_deg.len(), "freq", "phase")?;
+
+    let mut phase = unwrap_phase(phase_deg);
+    let delay = match excess {
+        ExcessPhase::Keep => None,
+        ExcessPhase::RemoveDelay(delay) => Some(*delay),
+        ExcessPhase::RemoveBestFitDelay => Some(best_fit_delay(freq, &phase)),
+        ExcessPhase::RemoveMinimumPhase(min_phase) => {
+            validate_data_length(freq.len(), min_phase.len(), "freq", "minimum_phase")?;
+            let min_phase = unwrap_phase(min_phase);
+            for (p, m) in phase.iter_mut().zip(&min_phase) {
+                *p -= m;
+            }
+            None
+        }
+    };
+    if let Some(delay) = delay {
+        for (p, f) in phase.iter_mut().zip(freq) {
+            *p += 360.0 * f * delay;
+        }
+    }
+    Ok(phase)
+}
+
+/// Phase chart builder.
+#[derive(Debug, Clone)]
+pub struct PhaseChart {
+    freq: Vec<f64>,
+    phase: Vec<f64>,
+    unwrapped: bool,
+    excess: ExcessPhase,
+    title: Option<String>,
+    color: u32,
+    size: Option<(f32, f32)>,
+    layout: ChartLayout,
+}
+
+impl PhaseChart {
+    chart_layout_methods!(layout, |chart: &Self| size_or_default(chart.size), title);
+
+    /// Set chart title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Show the unwrapped phase instead of wrapping to ±180° (default: false).
+    pub fn unwrapped(mut self, unwrapped: bool) -> Self {
+        self.unwrapped = unwrapped;
+        self
+    }
+
+    /// Set the phase component removed before plotting.
+    pub fn excess_phase(mut self, excess: ExcessPhase) -> Self {
+        self.excess = excess;
+        self
+    }
+
+    /// Set line color as 24-bit RGB hex value (format: 0xRRGGBB).
+    pub fn color(mut self, hex: u32) -> Self {
+        self.color = hex;
+        self
+    }
+
+    /// Set chart dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Configure the underlying [`LineChart`] for further customization.
+    pub fn line_chart(self) -> Result<LineChart, ChartError> {
+        let unwrapped = processed_phase(&self.freq, &self.phase, &self.excess)?;
+        let values: Vec<f64> = if self.unwrapped {
+            unwrapped
+        } else {
+            unwrapped.into_iter().map(wrap_phase).collect()
+        };
+
+        let mut chart = line(&self.freq, &values)
+            .x_scale(ScaleType::Log)
+            .x_label("Frequency (Hz)")
+            .y_label("Phase (°)")
+            .color(self.color);
+        if !self.unwrapped {
+            chart = chart.y_range(-180.0, 180.0);
+        }
+        if let Some(title) = self.title {
+            chart = chart.title(title);
+        }
+        if let Some((width, height)) = self.size {
+            chart = chart.size(width, height);
+        }
+        Ok(chart)
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        self.line_chart()?.build()
+    }
+}
+
+/// Create a phase versus frequency chart (phase in degrees).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use gpui_px::{phase, ExcessPhase};
+///
+/// let freq = vec![20.0, 100.0, 1000.0, 10000.0];
+/// let phase_deg = vec![170.0, 90.0, -45.0, -170.0];
+///
+/// let chart = phase(&freq, &phase_deg)
+///     .unwrapped(true)
+///     .excess_phase(ExcessPhase::RemoveBestFitDelay)
+///     .build();
+/// ```
+pub fn phase(freq: &[f64], phase_deg: &[f64]) -> PhaseChart {
+    PhaseChart {
+        freq: freq.to_vec(),
+        phase: phase_deg.to_vec(),
+        unwrapped: false,
+        excess: ExcessPhase::Keep,
+        title: None,
+        color: DEFAULT_COLOR,
+        size: None,
+        layout: ChartLayout::default(),
+    }
+}
+
+/// Group delay chart builder.
+#[derive(Debug, Clone)]
+pub struct GroupDelayChart {
+    freq: Vec<f64>,
+    phase: Vec<f64>,
+    unit: DelayUnit,
+    excess: ExcessPhase,
+    range: Option<[f64; 2]>,
+    title: Option<String>,
+    color: u32,
+    size: Option<(f32, f32)>,
+    layout: ChartLayout,
+}
+
+impl GroupDelayChart {
+    chart_layout_methods!(layout, |chart: &Self| size_or_default(chart.size), title);
+
+    /// Set chart title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the time unit of the y axis (default: ms).
+    pub fn unit(mut self, unit: DelayUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Set the phase component removed before differentiating.
+    pub fn excess_phase(mut self, excess: ExcessPhase) -> Self {
+        self.excess = excess;
+        self
+    }
+
+    /// Set the y range, in the chart's unit.
+    pub fn y_range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some([min, max]);
+        self
+    }
+
+    /// Set line color as 24-bit RGB hex value (format: 0xRRGGBB).
+    pub fn color(mut self, hex: u32) -> Self {
+        self.color = hex;
+        self
+    }
+
+    /// Set chart dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Configure the underlying [`LineChart`] for further customization.
+    pub fn line_chart(self) -> Result<LineChart, ChartError> {
+        let phase = processed_phase(&self.freq, &self.phase, &self.excess)?;
+        let factor = self.unit.factor();
+        let values: Vec<f64> = group_delay_seconds(&self.freq, &phase)
+            .into_iter()
+            .map(|d| d * factor)
+            .collect();
+
+        let mut chart = line(&self.freq, &values)
+            .x_scale(ScaleType::Log)
+            .x_label("Frequency (Hz)")
+            .y_label(format!("Group delay ({})", self.unit.symbol()))
+            .color(self.color);
+        if let Some([min, max]) = self.range {
+            chart = chart.y_range(min, max);
+        }
+        if let Some(title) = self.title {
+            chart = chart.title(title);
+        }
+        if let Some((width, height)) = self.size {
+            chart = chart.size(width, height);
+        }
+        Ok(chart)
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        self.line_chart()?.build()
+    }
+}
+
+/// Create a group delay versus frequency chart from a phase in degrees.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use gpui_px::{group_delay, DelayUnit};
+///
+/// let freq = vec![20.0, 100.0, 1000.0, 10000.0];
+/// let phase_deg = vec![170.0, 90.0, -45.0, -170.0];
+///
+/// let chart = group_delay(&freq, &phase_deg)
+///     .unit(DelayUnit::Milliseconds)
+///     .y_range(-1.0, 10.0)
+///     .build();
+/// ```
+pub fn group_delay(freq: &[f64], phase_deg: &[f64]) -> GroupDelayChart {
+    GroupDelayChart {
+        freq: freq.to_vec(),
+        phase: phase_deg.to_vec(),
+        unit: DelayUnit::default(),
+        excess: ExcessPhase::Keep,
+        range: None,
+        title: None,
+        color: DEFAULT_COLOR,
+        size: None,
+        layout: ChartLayout::default(),
+    }
+}
+
+/// Nominal size of a chart whose size is left to the [`LineChart`] default.
+fn size_or_default(size: Option<(f32, f32)>) -> (f32, f32) {
+    size.unwrap_or_else(|| {
+        let defaults = ChartDefaults::current();
+        (defaults.width, defaults.height)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Phase of a pure delay, wrapped to ±180°
+    fn delayed(freq: &[f64], delay: f64) -> Vec<f64> {
+        freq.iter()
+            .map(|f| wrap_phase(-360.0 * f * delay))
+            .collect()
+    }
+
+    #[test]
+    fn test_wrap_and_unwrap() {
+        assert_eq!(wrap_phase(190.0), -170.0);
+        assert_eq!(wrap_phase(-180.0), 180.0);
+        assert_eq!(wrap_phase(540.0), 180.0);
+        assert_eq!(
+            unwrap_phase(&[170.0, -170.0, -10.0, 170.0]),
+            vec![170.0, 190.0, 350.0, 170.0]
+        );
+    }
+
+    #[test]
+    fn test_group_delay_of_pure_delay() {
+        let freq: Vec<f64> = (1..=50).map(|i| i as f64 * 100.0).collect();
+        let phase = delayed(&freq, 0.001);
+        for d in group_delay_seconds(&freq, &phase) {
+            assert!((d - 0.001).abs() < 1e-9);
+        }
+        assert!((best_fit_delay(&freq, &phase) - 0.001).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_excess_phase_removal() {
+        let freq: Vec<f64> = (1..=20).map(|i| i as f64 * 50.0).collect();
+        let phase = delayed(&freq, 0.002);
+
+        let flat = processed_phase(&freq, &phase, &ExcessPhase::RemoveBestFitDelay).unwrap();
+        assert!(flat.iter().all(|p| p.abs() < 1e-6));
+
+        let fixed = processed_phase(&freq, &phase, &ExcessPhase::RemoveDelay(0.002)).unwrap();
+        assert!(fixed.iter().all(|p| p.abs() < 1e-6));
+
+        let min_phase = ExcessPhase::RemoveMinimumPhase(phase.clone());
+        let excess = processed_phase(&freq, &phase, &min_phase).unwrap();
+        assert!(excess.iter().all(|p| p.abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let result = phase(&[100.0, 200.0], &[0.0]).build();
+        assert!(matches!(result, Err(ChartError::DataLengthMismatch { .. })));
+
+        let result = group_delay(&[100.0], &[0.0])
+            .excess_phase(ExcessPhase::RemoveMinimumPhase(vec![]))
+            .build();
+        assert!(matches!(
+            result,
+            Err(ChartError::DataLengthMismatch {
+                y_field: "minimum_phase",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_successful_build() {
+        let freq: Vec<f64> = (1..=100).map(|i| i as f64 * 100.0).collect();
+        let phase_deg = delayed(&freq, 0.0005);
+        assert!(phase(&freq, &phase_deg).title("Phase").build().is_ok());
+        assert!(
+            group_delay(&freq, &phase_deg)
+                .unit(DelayUnit::Microseconds)
+                .build()
+                .is_ok()
+        );
+    }
+}
@@ -1,15 +1,19 @@
 //! Pie chart - Plotly Express style API.
 
 use crate::error::ChartError;
+use crate::geometry::{ArcGeometry, AxisGeometry, ChartGeometry};
+use crate::isoline::format_level;
+use crate::layout::{ChartLayout, chart_layout_methods};
 use crate::{
-    DEFAULT_HEIGHT, DEFAULT_TITLE_FONT_SIZE, DEFAULT_WIDTH, TITLE_AREA_HEIGHT, validate_data_array,
+    ChartDefaults, DEFAULT_TITLE_FONT_SIZE, ScaleType, TITLE_AREA_HEIGHT, validate_data_array,
     validate_data_length, validate_dimensions,
 };
 use d3rs::color::D3Color;
-use d3rs::shape::{Arc, Pie};
+use d3rs::shape::{Arc, Pie, PieSlice};
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
-use gpui::{IntoElement, PathBuilder, canvas, div, hsla, point, px};
+use gpui::{AnyElement, IntoElement, PathBuilder, canvas, div, hsla, point, px};
+use std::rc::Rc;
 
 /// Default color palette (Plotly)
 const DEFAULT_PALETTE: [u32; 10] = [
@@ -17,6 +21,17 @@ const DEFAULT_PALETTE: [u32; 10] = [
     0x17becf,
 ];
 
+/// Font size of the center label relative to the title
+const CENTER_LABEL_SCALE: f32 = 0.875;
+
+/// Content drawn in the hole of a donut.
+#[derive(Clone)]
+enum PieCenter {
+    Total,
+    Label(String),
+    Element(Rc<dyn Fn() -> AnyElement>),
+}
+
 /// Pie chart builder.
 #[derive(Clone)]
 pub struct PieChart {
@@ -30,9 +45,16 @@ pub struct PieChart {
     width: f32,
     height: f32,
     sort: bool,
+    start_angle: f64,
+    clockwise: bool,
+    other_threshold: Option<f64>,
+    center: Option<PieCenter>,
+    layout: ChartLayout,
 }
 
 impl PieChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
     /// Set chart title (rendered at top of chart).
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -70,6 +92,60 @@ impl PieChart {
         self
     }
 
+    /// Set the angle of the first slice edge (in radians, 0 = 12 o'clock).
+    pub fn start_angle(mut self, angle: f64) -> Self {
+        self.start_angle = angle;
+        self
+    }
+
+    /// Lay out slices clockwise (default) or counter-clockwise.
+    pub fn clockwise(mut self, clockwise: bool) -> Self {
+        self.clockwise = clockwise;
+        self
+    }
+
+    /// Merge slices under `percent` of the total into one "Other" slice,
+    /// placed after the others before sorting.
+    ///
+    /// Nothing is merged unless at least two slices are under the threshold.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::pie;
+    /// let chart = pie(&[50.0, 40.0, 4.0, 3.0, 3.0])
+    ///     .labels(&["A", "B", "C", "D", "E"])
+    ///     .other_threshold(5.0)
+    ///     .build();
+    /// ```
+    pub fn other_threshold(mut self, percent: f64) -> Self {
+        self.other_threshold = Some(percent);
+        self
+    }
+
+    /// Show the sum of the values in the center of the chart.
+    pub fn center_total(mut self) -> Self {
+        self.center = Some(PieCenter::Total);
+        self
+    }
+
+    /// Show a text label in the center of the chart.
+    pub fn center_label(mut self, label: impl Into<String>) -> Self {
+        self.center = Some(PieCenter::Label(label.into()));
+        self
+    }
+
+    /// Show an element in the center of the chart.
+    ///
+    /// The closure is called on every build, and its element is centered in
+    /// the hole; keep it smaller than the hole of the donut.
+    pub fn center_content<F>(mut self, content: F) -> Self
+    where
+        F: Fn() -> AnyElement + 'static,
+    {
+        self.center = Some(PieCenter::Element(Rc::new(content)));
+        self
+    }
+
     /// Set chart dimensions.
     pub fn size(mut self, width: f32, height: f32) -> Self {
         self.width = width;
@@ -77,9 +153,42 @@ impl PieChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
-        // Validate inputs
+    /// Compute the slice arcs without rendering.
+    ///
+    /// Runs the same validation and layout as [`PieChart::build`] at the
+    /// chart's own size. The axes map pixels to themselves and arc centers
+    /// are relative to the plot area, below the title.
+    pub fn compute_layout(&self) -> Result<ChartGeometry, ChartError> {
+        let (plot_width, plot_height) = self.plot_size()?;
+        let (width, height) = (plot_width as f64, plot_height as f64);
+        let axis = |extent: f64| {
+            AxisGeometry::new(
+                ScaleType::Linear,
+                (0.0, extent),
+                (0.0, extent),
+                Some(Vec::new()),
+                0,
+            )
+        };
+        let mut geometry = ChartGeometry::new(width, height, axis(width), axis(height));
+        geometry.arcs = self
+            .slices(plot_width, plot_height)
+            .iter()
+            .map(|slice| ArcGeometry {
+                index: slice.index,
+                center_x: width / 2.0,
+                center_y: height / 2.0,
+                inner_radius: slice.arc.inner_radius,
+                outer_radius: slice.arc.outer_radius,
+                start_angle: slice.arc.start_angle,
+                end_angle: slice.arc.end_angle,
+            })
+            .collect();
+        Ok(geometry)
+    }
+
+    /// Validate the inputs and return the plot area size.
+    fn plot_size(&self) -> Result<(f32, f32), ChartError> {
         validate_data_array(&self.values, "values")?;
         validate_dimensions(self.width, self.height)?;
 
@@ -93,15 +202,24 @@ impl PieChart {
         } else {
             0.0
         };
-        let plot_height = self.height - title_height;
-        let plot_width = self.width;
+        Ok((self.width, self.height - title_height))
+    }
 
+    /// Slices laid out in a plot area of the given size.
+    fn slices(&self, plot_width: f32, plot_height: f32) -> Vec<PieSlice<f64>> {
         // Calculate radius
         let radius = (plot_width.min(plot_height) / 2.0) as f64 * 0.9; // 90% fit
         let inner_radius = radius * self.inner_radius_fraction;
 
+        let values = match self.other_threshold {
+            Some(percent) => group_small(&self.values, percent),
+            None => self.values.clone(),
+        };
+
         // Prepare pie generator
         let pie = Pie::new()
+            .start_angle(self.start_angle)
+            .end_angle(self.start_angle + std::f64::consts::TAU)
             .pad_angle(self.pad_angle)
             .corner_radius(self.corner_radius)
             .inner_radius(inner_radius)
@@ -109,7 +227,20 @@ impl PieChart {
             .sort(self.sort);
 
         // Generate slices
-        let slices = pie.generate(&self.values, |v| *v);
+        let mut slices = pie.generate(&values, |v| *v);
+        if !self.clockwise {
+            for slice in &mut slices {
+                mirror_slice(slice, self.start_angle);
+            }
+        }
+        slices
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        let (plot_width, plot_height) = self.plot_size()?;
+        let title_height = self.height - plot_height;
+        let slices = self.slices(plot_width, plot_height);
 
         // Determine colors
         let colors: Vec<u32> = match self.colors {
@@ -186,19 +317,74 @@ impl PieChart {
             );
         }
 
+        let center = self.center.as_ref().map(|center| {
+            let content = match center {
+                PieCenter::Total => {
+                    let total: f64 = self.values.iter().map(|v| v.max(0.0)).sum();
+                    center_text(&format_level(total), DEFAULT_TITLE_FONT_SIZE)
+                }
+                PieCenter::Label(label) => {
+                    center_text(label, DEFAULT_TITLE_FONT_SIZE * CENTER_LABEL_SCALE)
+                }
+                PieCenter::Element(content) => content(),
+            };
+            div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .flex()
+                .justify_center()
+                .items_center()
+                .child(content)
+        });
+
         // Add plot area
         container = container.child(
             div()
                 .w(px(self.width))
                 .h(px(plot_height))
                 .relative()
-                .child(render_element),
+                .child(render_element)
+                .children(center),
         );
 
         Ok(container)
     }
 }
 
+/// Vector text in the title color, for the center of a donut.
+fn center_text(text: &str, font_size: f32) -> AnyElement {
+    let font_config = VectorFontConfig::horizontal(font_size, hsla(0.0, 0.0, 0.2, 1.0));
+    render_vector_text(text, &font_config).into_any_element()
+}
+
+/// Merge the values under `percent` of the total into one value, added
+/// last. Values are returned unchanged unless two or more are merged.
+fn group_small(values: &[f64], percent: f64) -> Vec<f64> {
+    let total: f64 = values.iter().map(|v| v.max(0.0)).sum();
+    let threshold = total * percent / 100.0;
+    let (kept, small): (Vec<f64>, Vec<f64>) = values.iter().partition(|&&v| v >= threshold);
+    if small.len() < 2 {
+        return values.to_vec();
+    }
+    kept.into_iter()
+        .chain(std::iter::once(small.iter().sum()))
+        .collect()
+}
+
+/// Reflect a slice around `start_angle`, turning a clockwise layout into a
+/// counter-clockwise one.
+fn mirror_slice<T>(slice: &mut PieSlice<T>, start_angle: f64) {
+    let arc = &mut slice.arc;
+    let (start, end) = (
+        2.0 * start_angle - arc.end_angle,
+        2.0 * start_angle - arc.start_angle,
+    );
+    arc.start_angle = start;
+    arc.end_angle = end;
+}
+
 /// Create a pie chart from values.
 ///
 /// # Example
@@ -216,6 +402,7 @@ impl PieChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn pie(values: &[f64]) -> PieChart {
+    let defaults = ChartDefaults::current();
     PieChart {
         labels: None,
         values: values.to_vec(),
@@ -224,9 +411,14 @@ pub fn pie(values: &[f64]) -> PieChart {
         pad_angle: 0.0,
         corner_radius: 0.0,
         colors: None,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        width: defaults.width,
+        height: defaults.height,
         sort: true,
+        start_angle: 0.0,
+        clockwise: true,
+        other_threshold: None,
+        center: None,
+        layout: ChartLayout::default(),
     }
 }
 
@@ -252,3 +444,43 @@ impl PieChart {
 pub fn donut(values: &[f64]) -> PieChart {
     pie(values).hole(0.5)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_small_merges_under_threshold() {
+        let values = [50.0, 40.0, 4.0, 3.0, 3.0];
+        assert_eq!(group_small(&values, 5.0), vec![50.0, 40.0, 10.0]);
+        assert_eq!(group_small(&values, 3.5), vec![50.0, 40.0, 4.0, 6.0]);
+        // A single small slice is left alone
+        assert_eq!(group_small(&[90.0, 8.0, 2.0], 5.0), vec![90.0, 8.0, 2.0]);
+    }
+
+    #[test]
+    fn test_mirror_slice_runs_counter_clockwise() {
+        let slices = Pie::new().sort(false).generate(&[1.0, 3.0], |v| *v);
+        let mut first = slices[0].clone();
+        mirror_slice(&mut first, 0.0);
+        let quarter = std::f64::consts::FRAC_PI_2;
+        assert!((first.arc.start_angle + quarter).abs() < 1e-9);
+        assert!(first.arc.end_angle.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pie_center_and_direction() {
+        let chart = donut(&[50.0, 40.0, 4.0, 3.0, 3.0])
+            .other_threshold(5.0)
+            .start_angle(std::f64::consts::FRAC_PI_2)
+            .clockwise(false)
+            .center_total();
+        assert!(chart.clone().build().is_ok());
+        assert!(
+            chart
+                .center_content(|| div().into_any_element())
+                .build()
+                .is_ok()
+        );
+    }
+}
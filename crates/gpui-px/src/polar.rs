@@ -0,0 +1,865 @@
+//! Polar line and radar charts - Plotly Express style API.
+//!
+//! [`polar_line`] draws a radius against an angle in degrees, e.g. the
+//! directivity of a loudspeaker; [`radar`] draws one value per category on
+//! evenly spaced spokes. Both take several series:
+//!
+//! ```rust,ignore
+//! let chart = polar_line(&angles, &spl_1k)
+//!     .label("1 kHz")
+//!     .add_series(&angles, &spl_4k, "4 kHz")
+//!     .r_range(-30.0, 0.0)
+//!     .build()?;
+//!
+//! let chart = radar(&["Bass", "Mids", "Treble", "Imaging", "Dynamics"], &scores_a)
+//!     .label("Speaker A")
+//!     .add_series(&scores_b, "Speaker B")
+//!     .build()?;
+//! ```
+//!
+//! Angles run clockwise from 12 o'clock unless changed with `.rotation(...)`
+//! and `.clockwise(false)`.
+
+use crate::error::ChartError;
+use crate::isoline::format_level;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::palette::CategoricalPalette;
+use crate::theme::ChartTheme;
+use crate::{
+    ChartDefaults, DEFAULT_TITLE_FONT_SIZE, TITLE_AREA_HEIGHT, validate_data_array,
+    validate_data_length, validate_dimensions, validate_plot_area,
+};
+use d3rs::color::D3Color;
+use d3rs::scale::{LinearScale, Scale};
+use d3rs::text::{VectorFontConfig, render_vector_text};
+use gpui::prelude::*;
+use gpui::{AnyElement, IntoElement, PathBuilder, Rgba, canvas, div, point, px};
+
+/// Room kept between the outer ring and the plot edge for labels, in pixels
+const LABEL_MARGIN: f32 = 32.0;
+
+/// Distance of the angle labels from the outer ring, in pixels
+const LABEL_OFFSET: f32 = 14.0;
+
+/// Size of the box a label is centered in, in pixels
+const LABEL_BOX: (f32, f32) = (96.0, 16.0);
+
+/// Height of the legend row below the plot, in pixels
+const LEGEND_HEIGHT: f32 = 28.0;
+
+/// Angle between the points of a circular ring, in degrees
+const RING_STEP: f64 = 2.0;
+
+/// Polyline in plot pixels
+type Polyline = Vec<(f32, f32)>;
+
+/// One series of a polar or radar chart
+#[derive(Debug, Clone)]
+struct PolarSeries {
+    /// Angles in degrees; unused by radar charts
+    angles: Vec<f64>,
+    r: Vec<f64>,
+    label: Option<String>,
+    color: Option<u32>,
+}
+
+/// Angular and radial axes of a polar or radar chart
+#[derive(Debug, Clone)]
+struct PolarAxes {
+    /// Angles shown, in degrees
+    angular_range: (f64, f64),
+    /// Degrees between angle ticks
+    angular_step: f64,
+    /// Screen direction of angle 0, in degrees clockwise from 12 o'clock
+    rotation: f64,
+    clockwise: bool,
+    /// Radial domain; from the data when unset
+    r_range: Option<(f64, f64)>,
+    /// Approximate number of radial rings
+    radial_ticks: usize,
+}
+
+impl Default for PolarAxes {
+    fn default() -> Self {
+        Self {
+            angular_range: (0.0, 360.0),
+            angular_step: 30.0,
+            rotation: 0.0,
+            clockwise: true,
+            r_range: None,
+            radial_ticks: 4,
+        }
+    }
+}
+
+impl PolarAxes {
+    fn validate(&self) -> Result<(), ChartError> {
+        let (start, end) = self.angular_range;
+        if !(start.is_finite() && end.is_finite() && end > start && end - start <= 360.0) {
+            return Err(ChartError::InvalidData {
+                field: "angular_range",
+                reason: "must span more than 0 and at most 360 degrees",
+            });
+        }
+        if !(self.angular_step.is_finite() && self.angular_step > 0.0) {
+            return Err(ChartError::InvalidData {
+                field: "angular_step",
+                reason: "must be positive",
+            });
+        }
+        if let Some((min, max)) = self.r_range
+            && !(min.is_finite() && max.is_finite() && min < max)
+        {
+            return Err(ChartError::InvalidData {
+                field: "r_range",
+                reason: "min must be less than max",
+            });
+        }
+        Ok(())
+    }
+
+    /// Whether the angular range is a full turn.
+    fn is_full_turn(&self) -> bool {
+        self.angular_range.1 - self.angular_range.0 >= 360.0
+    }
+
+    /// Whether `angle`, or the same direction a whole turn away, is shown.
+    fn shows(&self, angle: f64) -> bool {
+        let (start, end) = self.angular_range;
+        self.is_full_turn() || start + (angle - start).rem_euclid(360.0) <= end
+    }
+
+    /// Angles of the ticks, from the start of the range; a full turn does
+    /// not repeat its start at the end.
+    fn angular_ticks(&self) -> Vec<f64> {
+        let (start, end) = self.angular_range;
+        let count = ((end - start) / self.angular_step + 1e-9).floor() as usize;
+        (0..=count)
+            .map(|i| start + i as f64 * self.angular_step)
+            .filter(|&angle| !self.is_full_turn() || angle < start + 360.0 - 1e-9)
+            .collect()
+    }
+
+    /// Radial domain: the set range, or the data extent on round values.
+    ///
+    /// With `from_zero`, the domain of positive data starts at zero.
+    fn r_domain(&self, values: impl Iterator<Item = f64>, from_zero: bool) -> (f64, f64) {
+        if let Some(range) = self.r_range {
+            return range;
+        }
+        let (min, max) = values
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        let min = if from_zero { min.min(0.0) } else { min };
+        if !min.is_finite() || max <= min {
+            let value = if min.is_finite() { min } else { 0.0 };
+            return (value, value + 1.0);
+        }
+        let scale = LinearScale::new()
+            .domain(min, max)
+            .nice(Some(self.radial_ticks));
+        (scale.domain_min(), scale.domain_max())
+    }
+
+    /// Point at `angle` degrees, `radius` pixels from `center`.
+    fn point(&self, center: (f32, f32), angle: f64, radius: f32) -> (f32, f32) {
+        let angle = if self.clockwise { angle } else { -angle };
+        let direction = (self.rotation + angle).to_radians();
+        (
+            center.0 + radius * direction.sin() as f32,
+            center.1 - radius * direction.cos() as f32,
+        )
+    }
+}
+
+/// Share of the radius at which `r` is drawn, clamped to the plot.
+fn radial_fraction((min, max): (f64, f64), r: f64) -> f32 {
+    ((r - min) / (max - min)).clamp(0.0, 1.0) as f32
+}
+
+/// Shape of the radial grid rings.
+enum RingShape {
+    /// Arcs over the angular range
+    Arc,
+    /// Polygons through the spokes
+    Polygon,
+}
+
+/// Series, axes and look shared by polar and radar charts
+#[derive(Debug, Clone)]
+struct PolarPlot {
+    series: Vec<PolarSeries>,
+    axes: PolarAxes,
+    stroke_width: f32,
+    /// Opacity of the area of closed series; 0 draws outlines only
+    fill_opacity: f32,
+    width: f32,
+    height: f32,
+    palette: CategoricalPalette,
+    theme: ChartTheme,
+    layout: ChartLayout,
+}
+
+impl PolarPlot {
+    fn new(series: PolarSeries, fill_opacity: f32) -> Self {
+        let defaults = ChartDefaults::current();
+        Self {
+            series: vec![series],
+            axes: PolarAxes::default(),
+            stroke_width: 2.0,
+            fill_opacity,
+            width: defaults.width,
+            height: defaults.height,
+            palette: defaults.palette.unwrap_or_default(),
+            theme: ChartTheme::current(),
+            layout: ChartLayout::default(),
+        }
+    }
+
+    /// Render the grid, the series as (angle, r) points and the labels.
+    ///
+    /// `spokes` holds the angle and label of each spoke.
+    fn render(
+        &self,
+        title: Option<&str>,
+        r_domain: (f64, f64),
+        spokes: &[(f64, String)],
+        rings: RingShape,
+        traces: Vec<(Vec<(f64, f64)>, bool)>,
+    ) -> Result<AnyElement, ChartError> {
+        validate_dimensions(self.width, self.height)?;
+        let title_height = if title.is_some() {
+            TITLE_AREA_HEIGHT
+        } else {
+            0.0
+        };
+        let has_legend = self.series.iter().any(|series| series.label.is_some());
+        let legend_height = if has_legend { LEGEND_HEIGHT } else { 0.0 };
+        let plot_width = self.width;
+        let plot_height = (self.height - title_height - legend_height).max(0.0);
+        let radius = plot_width.min(plot_height) / 2.0 - LABEL_MARGIN;
+        validate_plot_area(2.0 * radius as f64, 2.0 * radius as f64)?;
+
+        let axes = &self.axes;
+        let center = (plot_width / 2.0, plot_height / 2.0);
+        let at =
+            |angle: f64, r: f64| axes.point(center, angle, radius * radial_fraction(r_domain, r));
+
+        // Rings at the radial ticks and at the rim
+        let scale = LinearScale::new().domain(r_domain.0, r_domain.1);
+        let mut ring_values: Vec<f64> = scale
+            .ticks(axes.radial_ticks)
+            .into_iter()
+            .filter(|&value| value > r_domain.0 && value < r_domain.1)
+            .collect();
+        ring_values.push(r_domain.1);
+        let ring_angles: Vec<f64> = match rings {
+            RingShape::Arc => {
+                let (start, end) = axes.angular_range;
+                let count = ((end - start) / RING_STEP).ceil() as usize;
+                (0..=count)
+                    .map(|i| (start + i as f64 * RING_STEP).min(end))
+                    .collect()
+            }
+            RingShape::Polygon => spokes
+                .iter()
+                .map(|&(angle, _)| angle)
+                .chain(spokes.first().map(|&(angle, _)| angle))
+                .collect(),
+        };
+        let mut grid: Vec<Polyline> = ring_values
+            .iter()
+            .map(|&value| ring_angles.iter().map(|&angle| at(angle, value)).collect())
+            .collect();
+        grid.extend(
+            spokes
+                .iter()
+                .map(|&(angle, _)| vec![center, at(angle, r_domain.1)]),
+        );
+
+        let colors: Vec<Rgba> = self
+            .series
+            .iter()
+            .enumerate()
+            .map(|(i, series)| {
+                let hex = series.color.unwrap_or_else(|| self.palette.color_at(i));
+                D3Color::from_hex(hex).to_rgba()
+            })
+            .collect();
+        let traces: Vec<(Polyline, bool, Rgba)> = traces
+            .into_iter()
+            .zip(&colors)
+            .map(|((points, closed), &color)| {
+                let points = points.iter().map(|&(angle, r)| at(angle, r)).collect();
+                (points, closed, color)
+            })
+            .collect();
+
+        let grid_color = self.theme.grid_color;
+        let (stroke_width, fill_opacity) = (self.stroke_width, self.fill_opacity);
+        let plot = canvas(
+            move |bounds, _, _| bounds,
+            move |_, bounds, window, _| {
+                let origin: (f32, f32) = (bounds.origin.x.into(), bounds.origin.y.into());
+                let to_screen = |&(x, y): &(f32, f32)| point(px(origin.0 + x), px(origin.1 + y));
+                let trace = |builder: &mut PathBuilder, points: &Polyline, closed: bool| {
+                    let mut points = points.iter().map(to_screen);
+                    if let Some(first) = points.next() {
+                        builder.move_to(first);
+                        points.for_each(|next| builder.line_to(next));
+                        if closed {
+                            builder.close();
+                        }
+                    }
+                };
+
+                for line in &grid {
+                    let mut builder = PathBuilder::stroke(px(1.0));
+                    trace(&mut builder, line, false);
+                    if let Ok(path) = builder.build() {
+                        window.paint_path(path, grid_color);
+                    }
+                }
+                for (points, closed, color) in &traces {
+                    if points.len() < 2 {
+                        continue;
+                    }
+                    if *closed && fill_opacity > 0.0 {
+                        let mut builder = PathBuilder::fill();
+                        trace(&mut builder, points, true);
+                        if let Ok(path) = builder.build() {
+                            let fill = Rgba {
+                                a: color.a * fill_opacity,
+                                ..*color
+                            };
+                            window.paint_path(path, fill);
+                        }
+                    }
+                    let mut builder = PathBuilder::stroke(px(stroke_width));
+                    trace(&mut builder, points, *closed);
+                    if let Ok(path) = builder.build() {
+                        window.paint_path(path, *color);
+                    }
+                }
+            },
+        )
+        .size_full();
+
+        // Angle or category labels around the rim, ring values along the
+        // middle of the first two spokes
+        let font_config = VectorFontConfig::horizontal(
+            self.theme.axis_font_size,
+            self.theme.axis_label_color.into(),
+        );
+        let label = |(x, y): (f32, f32), text: &str| {
+            div()
+                .absolute()
+                .left(px(x - LABEL_BOX.0 / 2.0))
+                .top(px(y - LABEL_BOX.1 / 2.0))
+                .w(px(LABEL_BOX.0))
+                .h(px(LABEL_BOX.1))
+                .flex()
+                .justify_center()
+                .items_center()
+                .child(render_vector_text(text, &font_config))
+        };
+        let rim_labels = spokes
+            .iter()
+            .map(|(angle, text)| label(axes.point(center, *angle, radius + LABEL_OFFSET), text));
+        let ring_label_angle = match spokes {
+            [first, second, ..] => (first.0 + second.0) / 2.0,
+            [first] => first.0,
+            [] => axes.angular_range.0,
+        };
+        let ring_labels = ring_values
+            .iter()
+            .map(|&value| label(at(ring_label_angle, value), &format_level(value)));
+
+        let mut container = div()
+            .w(px(self.width))
+            .h(px(self.height))
+            .relative()
+            .flex()
+            .flex_col();
+        if let Some(title) = title {
+            let font_config = VectorFontConfig::horizontal(
+                DEFAULT_TITLE_FONT_SIZE,
+                self.theme.title_color.into(),
+            );
+            container = container.child(
+                div()
+                    .w_full()
+                    .h(px(title_height))
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .child(render_vector_text(title, &font_config)),
+            );
+        }
+        container = container.child(
+            div()
+                .w(px(plot_width))
+                .h(px(plot_height))
+                .relative()
+                .child(plot)
+                .children(rim_labels)
+                .children(ring_labels),
+        );
+        if has_legend {
+            let items = self
+                .series
+                .iter()
+                .zip(&colors)
+                .filter_map(|(series, &color)| Some((series.label.clone()?, color)))
+                .map(|(label, color)| {
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(div().w(px(16.0)).h(px(3.0)).bg(color))
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(self.theme.legend_text_color)
+                                .child(label),
+                        )
+                });
+            container = container.child(
+                div()
+                    .h(px(legend_height))
+                    .flex()
+                    .flex_row()
+                    .flex_wrap()
+                    .gap_4()
+                    .justify_center()
+                    .items_center()
+                    .children(items),
+            );
+        }
+        Ok(container.into_any_element())
+    }
+}
+
+/// Builder methods shared by polar and radar charts, over their `plot`
+/// field.
+macro_rules! polar_chart_methods {
+    () => {
+        /// Set chart title (rendered at top of chart).
+        pub fn title(mut self, title: impl Into<String>) -> Self {
+            self.title = Some(title.into());
+            self
+        }
+
+        /// Set the legend label of the first series.
+        pub fn label(mut self, label: impl Into<String>) -> Self {
+            self.plot.series[0].label = Some(label.into());
+            self
+        }
+
+        /// Set the color of the first series as 24-bit RGB hex value.
+        pub fn color(mut self, hex: u32) -> Self {
+            self.plot.series[0].color = Some(hex);
+            self
+        }
+
+        /// Set the colors of series without their own color.
+        pub fn palette(mut self, palette: CategoricalPalette) -> Self {
+            self.plot.palette = palette;
+            self
+        }
+
+        /// Set the line width of the series in pixels.
+        pub fn stroke_width(mut self, width: f32) -> Self {
+            self.plot.stroke_width = width;
+            self
+        }
+
+        /// Fill the area of closed series with their color at `opacity`;
+        /// 0 draws outlines only.
+        pub fn fill_opacity(mut self, opacity: f32) -> Self {
+            self.plot.fill_opacity = opacity.clamp(0.0, 1.0);
+            self
+        }
+
+        /// Set the radial domain, from the center to the outer ring.
+        ///
+        /// Values outside are drawn at the center or on the outer ring.
+        /// Defaults to the data extent on round values.
+        pub fn r_range(mut self, min: f64, max: f64) -> Self {
+            self.plot.axes.r_range = Some((min, max));
+            self
+        }
+
+        /// Set the approximate number of radial rings (default: 4).
+        pub fn radial_ticks(mut self, count: usize) -> Self {
+            self.plot.axes.radial_ticks = count.max(1);
+            self
+        }
+
+        /// Set the screen direction of angle 0, in degrees clockwise from
+        /// 12 o'clock (default: 0).
+        pub fn rotation(mut self, degrees: f64) -> Self {
+            self.plot.axes.rotation = degrees;
+            self
+        }
+
+        /// Lay out angles clockwise (default) or counter-clockwise.
+        pub fn clockwise(mut self, clockwise: bool) -> Self {
+            self.plot.axes.clockwise = clockwise;
+            self
+        }
+
+        /// Set the chart theme.
+        ///
+        /// Defaults to [`ChartTheme::current`], which follows the app theme
+        /// once [`init`](crate::init) has been called.
+        pub fn theme(mut self, theme: ChartTheme) -> Self {
+            self.plot.theme = theme;
+            self
+        }
+
+        /// Set chart dimensions.
+        pub fn size(mut self, width: f32, height: f32) -> Self {
+            self.plot.width = width;
+            self.plot.height = height;
+            self
+        }
+    };
+}
+
+/// Polar line chart builder.
+#[derive(Debug, Clone)]
+pub struct PolarChart {
+    title: Option<String>,
+    /// Join the last point to the first; by default when the angular range
+    /// is a full turn
+    closed: Option<bool>,
+    plot: PolarPlot,
+}
+
+impl PolarChart {
+    chart_layout_methods!(
+        plot.layout,
+        |chart: &Self| (chart.plot.width, chart.plot.height),
+        title
+    );
+    polar_chart_methods!();
+
+    /// Add a series of `r` at `angles` in degrees, colored from the palette.
+    pub fn add_series(mut self, angles: &[f64], r: &[f64], label: impl Into<String>) -> Self {
+        self.plot.series.push(PolarSeries {
+            angles: angles.to_vec(),
+            r: r.to_vec(),
+            label: Some(label.into()),
+            color: None,
+        });
+        self
+    }
+
+    /// Set the angles shown, in degrees (default: 0 to 360).
+    ///
+    /// A range under a full turn draws a sector, e.g. `(-90.0, 90.0)` for
+    /// the front half of a directivity plot; points outside are left out.
+    pub fn angular_range(mut self, start: f64, end: f64) -> Self {
+        self.plot.axes.angular_range = (start, end);
+        self
+    }
+
+    /// Set the degrees between angle ticks and spokes (default: 30).
+    pub fn angular_step(mut self, degrees: f64) -> Self {
+        self.plot.axes.angular_step = degrees;
+        self
+    }
+
+    /// Join the last point of each series to its first.
+    ///
+    /// By default series are closed when the angular range is a full turn.
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = Some(closed);
+        self
+    }
+
+    fn validate(&self) -> Result<(), ChartError> {
+        for series in &self.plot.series {
+            validate_data_array(&series.angles, "angles")?;
+            validate_data_array(&series.r, "r")?;
+            validate_data_length(series.angles.len(), series.r.len(), "angles", "r")?;
+        }
+        self.plot.axes.validate()
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        self.validate()?;
+        let axes = &self.plot.axes;
+        let r_domain = axes.r_domain(
+            self.plot.series.iter().flat_map(|s| s.r.iter().copied()),
+            false,
+        );
+        let spokes: Vec<(f64, String)> = axes
+            .angular_ticks()
+            .into_iter()
+            .map(|angle| (angle, format!("{}°", format_level(angle))))
+            .collect();
+        let closed = self.closed.unwrap_or_else(|| axes.is_full_turn());
+        let traces = self
+            .plot
+            .series
+            .iter()
+            .map(|series| {
+                let points = series
+                    .angles
+                    .iter()
+                    .zip(&series.r)
+                    .map(|(&angle, &r)| (angle, r))
+                    .filter(|&(angle, _)| axes.shows(angle))
+                    .collect();
+                (points, closed)
+            })
+            .collect();
+        self.plot.render(
+            self.title.as_deref(),
+            r_domain,
+            &spokes,
+            RingShape::Arc,
+            traces,
+        )
+    }
+}
+
+/// Radar chart builder.
+#[derive(Debug, Clone)]
+pub struct RadarChart {
+    title: Option<String>,
+    categories: Vec<String>,
+    plot: PolarPlot,
+}
+
+impl RadarChart {
+    chart_layout_methods!(
+        plot.layout,
+        |chart: &Self| (chart.plot.width, chart.plot.height),
+        title
+    );
+    polar_chart_methods!();
+
+    /// Add a series with one value per category, colored from the palette.
+    pub fn add_series(mut self, values: &[f64], label: impl Into<String>) -> Self {
+        self.plot.series.push(PolarSeries {
+            angles: Vec::new(),
+            r: values.to_vec(),
+            label: Some(label.into()),
+            color: None,
+        });
+        self
+    }
+
+    fn validate(&self) -> Result<(), ChartError> {
+        if self.categories.is_empty() {
+            return Err(ChartError::EmptyData {
+                field: "categories",
+            });
+        }
+        for series in &self.plot.series {
+            validate_data_array(&series.r, "values")?;
+            validate_data_length(
+                self.categories.len(),
+                series.r.len(),
+                "categories",
+                "values",
+            )?;
+        }
+        self.plot.axes.validate()
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        self.validate()?;
+        let step = 360.0 / self.categories.len() as f64;
+        let spokes: Vec<(f64, String)> = self
+            .categories
+            .iter()
+            .enumerate()
+            .map(|(i, category)| (i as f64 * step, category.clone()))
+            .collect();
+        let r_domain = self.plot.axes.r_domain(
+            self.plot.series.iter().flat_map(|s| s.r.iter().copied()),
+            true,
+        );
+        let traces = self
+            .plot
+            .series
+            .iter()
+            .map(|series| {
+                let points = spokes
+                    .iter()
+                    .zip(&series.r)
+                    .map(|(&(angle, _), &r)| (angle, r))
+                    .collect();
+                (points, true)
+            })
+            .collect();
+        self.plot.render(
+            self.title.as_deref(),
+            r_domain,
+            &spokes,
+            RingShape::Polygon,
+            traces,
+        )
+    }
+}
+
+/// Create a polar line chart of `r` at `angles` in degrees.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use gpui_px::polar_line;
+///
+/// let angles: Vec<f64> = (0..36).map(|i| i as f64 * 10.0).collect();
+/// let spl: Vec<f64> = angles.iter().map(|a| -6.0 * (1.0 - a.to_radians().cos())).collect();
+///
+/// let chart = polar_line(&angles, &spl)
+///     .label("1 kHz")
+///     .r_range(-30.0, 0.0)
+///     .angular_range(-180.0, 180.0)
+///     .build()?;
+/// # Ok::<(), gpui_px::ChartError>(())
+/// ```
+pub fn polar_line(angles: &[f64], r: &[f64]) -> PolarChart {
+    let series = PolarSeries {
+        angles: angles.to_vec(),
+        r: r.to_vec(),
+        label: None,
+        color: None,
+    };
+    PolarChart {
+        title: None,
+        closed: None,
+        plot: PolarPlot::new(series, 0.0),
+    }
+}
+
+/// Create a radar chart with one spoke per category.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use gpui_px::radar;
+///
+/// let traits = ["Bass", "Mids", "Treble", "Imaging", "Dynamics"];
+/// let chart = radar(&traits, &[4.0, 3.5, 4.5, 3.0, 4.0])
+///     .label("Speaker A")
+///     .add_series(&[3.0, 4.5, 3.5, 4.0, 3.0], "Speaker B")
+///     .r_range(0.0, 5.0)
+///     .build()?;
+/// # Ok::<(), gpui_px::ChartError>(())
+/// ```
+pub fn radar<S: AsRef<str>>(categories: &[S], values: &[f64]) -> RadarChart {
+    let series = PolarSeries {
+        angles: Vec::new(),
+        r: values.to_vec(),
+        label: None,
+        color: None,
+    };
+    RadarChart {
+        title: None,
+        categories: categories.iter().map(|c| c.as_ref().to_string()).collect(),
+        plot: PolarPlot::new(series, 0.2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near((x, y): (f32, f32), (expected_x, expected_y): (f32, f32)) {
+        assert!((x - expected_x).abs() < 1e-4 && (y - expected_y).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_polar_point_orientation() {
+        let mut axes = PolarAxes::default();
+        let center = (100.0, 100.0);
+        assert_near(axes.point(center, 0.0, 50.0), (100.0, 50.0));
+        assert_near(axes.point(center, 90.0, 50.0), (150.0, 100.0));
+        axes.clockwise = false;
+        assert_near(axes.point(center, 90.0, 50.0), (50.0, 100.0));
+        axes.rotation = 90.0;
+        assert_near(axes.point(center, 0.0, 50.0), (150.0, 100.0));
+    }
+
+    #[test]
+    fn test_angular_ticks_and_range() {
+        let mut axes = PolarAxes {
+            angular_range: (-180.0, 180.0),
+            angular_step: 90.0,
+            ..PolarAxes::default()
+        };
+        assert_eq!(axes.angular_ticks(), vec![-180.0, -90.0, 0.0, 90.0]);
+        assert!(axes.shows(270.0));
+
+        axes.angular_range = (-90.0, 90.0);
+        assert_eq!(axes.angular_ticks(), vec![-90.0, 0.0, 90.0]);
+        assert!(axes.shows(270.0) && axes.shows(45.0));
+        assert!(!axes.shows(135.0));
+    }
+
+    #[test]
+    fn test_r_domain() {
+        let mut axes = PolarAxes::default();
+        let values = [-27.0, -3.0, -12.0];
+        let (min, max) = axes.r_domain(values.iter().copied(), false);
+        assert!(min <= -27.0 && max >= -3.0 && max <= 0.0);
+        assert_eq!(axes.r_domain([2.0, 4.5].into_iter(), true).0, 0.0);
+        assert_eq!(axes.r_domain([3.0].into_iter(), false), (3.0, 4.0));
+        axes.r_range = Some((-30.0, 0.0));
+        assert_eq!(axes.r_domain(values.iter().copied(), false), (-30.0, 0.0));
+        assert_eq!(radial_fraction((-30.0, 0.0), -15.0), 0.5);
+        assert_eq!(radial_fraction((-30.0, 0.0), -40.0), 0.0);
+    }
+
+    #[test]
+    fn test_polar_line_build() {
+        let angles: Vec<f64> = (0..36).map(|i| i as f64 * 10.0 - 180.0).collect();
+        let r: Vec<f64> = angles.iter().map(|a| -a.abs() / 10.0).collect();
+        let chart = polar_line(&angles, &r)
+            .label("1 kHz")
+            .add_series(&angles, &r, "2 kHz")
+            .angular_range(-180.0, 180.0)
+            .r_range(-20.0, 0.0);
+        assert!(chart.clone().build().is_ok());
+        assert!(chart.clone().angular_range(-90.0, 90.0).build().is_ok());
+
+        let result = chart.clone().angular_range(0.0, 720.0).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData {
+                field: "angular_range",
+                ..
+            })
+        ));
+        let result = polar_line(&[0.0, 90.0], &[1.0]).build();
+        assert!(matches!(result, Err(ChartError::DataLengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_radar_build() {
+        let chart = radar(&["A", "B", "C", "D", "E"], &[4.0, 3.5, 4.5, 3.0, 4.0])
+            .add_series(&[3.0, 4.5, 3.5, 4.0, 3.0], "Other")
+            .title("Scores");
+        assert!(chart.clone().build().is_ok());
+
+        let result = radar(&["A", "B"], &[1.0]).build();
+        assert!(matches!(result, Err(ChartError::DataLengthMismatch { .. })));
+        let result = radar::<&str>(&[], &[]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::EmptyData {
+                field: "categories"
+            })
+        ));
+    }
+}
@@ -0,0 +1,701 @@
+//! Paginated reports.
+//!
+//! A [`Report`] is a sequence of headings, paragraphs, tables and charts that
+//! is laid out onto fixed-size pages with optional headers and footers, then
+//! exported as a multi-page PDF or as one PNG per page.
+//!
+//! Layout works in PDF points (1/72 inch) with the origin at the top-left
+//! corner of each page. Charts are rasterized offscreen through
+//! [`TextureChart`] and embedded as images.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use gpui_px::{PageSize, Report, line};
+//!
+//! let pdf = Report::new()
+//!     .page_size(PageSize::A4)
+//!     .header("KEF LS50 - measurements")
+//!     .footer("Page {page} of {pages}")
+//!     .heading("On-axis response", 1)
+//!     .text("Measured at 2 m in an anechoic chamber.")
+//!     .chart(line(&freq, &spl).log_x(), 240.0)
+//!     .table(&["Band", "Deviation"], vec![vec!["Bass".into(), "1.2 dB".into()]])
+//!     .to_pdf();
+//! std::fs::write("report.pdf", pdf)?;
+//! ```
+
+mod pdf;
+
+use crate::offscreen::TextureChart;
+use d3rs::gpu2d::{ChartTexture, encode_png};
+
+/// Average Helvetica glyph width as a fraction of the font size
+const AVG_CHAR_WIDTH: f32 = 0.52;
+
+/// Line height as a multiple of the font size
+const LINE_HEIGHT: f32 = 1.4;
+
+/// Body text font size in points
+const TEXT_SIZE: f32 = 11.0;
+
+/// Table text font size in points
+const TABLE_TEXT_SIZE: f32 = 10.0;
+
+/// Table row height in points
+const TABLE_ROW_HEIGHT: f32 = 18.0;
+
+/// Header and footer font size in points
+const MARGIN_TEXT_SIZE: f32 = 9.0;
+
+/// Vertical space after each block in points
+const BLOCK_SPACING: f32 = 10.0;
+
+/// Pixels per point when rasterizing charts for the PDF
+const CHART_RASTER_SCALE: f32 = 2.0;
+
+/// Page dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PageSize {
+    /// ISO A4 (595 x 842 pt)
+    #[default]
+    A4,
+    /// US Letter (612 x 792 pt)
+    Letter,
+    /// Custom size in points
+    Custom { width: f32, height: f32 },
+}
+
+impl PageSize {
+    /// Page width and height in points
+    pub fn dimensions(&self) -> (f32, f32) {
+        match *self {
+            PageSize::A4 => (595.0, 842.0),
+            PageSize::Letter => (612.0, 792.0),
+            PageSize::Custom { width, height } => (width, height),
+        }
+    }
+}
+
+/// A block of report content
+enum ReportBlock {
+    Heading {
+        text: String,
+        level: u8,
+    },
+    Text(String),
+    Table {
+        headers: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+    Chart {
+        chart: Box<dyn TextureChart>,
+        height: f32,
+    },
+    PageBreak,
+}
+
+/// A positioned drawing operation on a laid-out page
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PageItem {
+    /// Text with its baseline at `y`
+    Text {
+        text: String,
+        x: f32,
+        y: f32,
+        size: f32,
+        bold: bool,
+    },
+    /// Thin gray rule
+    Line { x0: f32, y0: f32, x1: f32, y1: f32 },
+    /// Chart `index` (into the report's charts) drawn into a box
+    Chart {
+        index: usize,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    },
+}
+
+/// A laid-out page
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ReportPage {
+    pub(crate) items: Vec<PageItem>,
+}
+
+/// Report builder.
+pub struct Report {
+    blocks: Vec<ReportBlock>,
+    page_size: PageSize,
+    margin: f32,
+    header: Option<String>,
+    footer: Option<String>,
+    font_data: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Report")
+            .field("blocks", &self.blocks.len())
+            .field("page_size", &self.page_size)
+            .field("margin", &self.margin)
+            .field("header", &self.header)
+            .field("footer", &self.footer)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Report {
+    /// Create an empty A4 report with 50 pt margins.
+    pub fn new() -> Self {
+        Self {
+            blocks: Vec::new(),
+            page_size: PageSize::default(),
+            margin: 50.0,
+            header: None,
+            footer: None,
+            font_data: None,
+        }
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: PageSize) -> Self {
+        self.page_size = size;
+        self
+    }
+
+    /// Set the page margin in points (all four sides).
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin.max(0.0);
+        self
+    }
+
+    /// Set the header text shown at the top of every page.
+    ///
+    /// `{page}` and `{pages}` are replaced by the page number and page count.
+    pub fn header(mut self, text: impl Into<String>) -> Self {
+        self.header = Some(text.into());
+        self
+    }
+
+    /// Set the footer text shown at the bottom of every page.
+    ///
+    /// `{page}` and `{pages}` are replaced by the page number and page count.
+    pub fn footer(mut self, text: impl Into<String>) -> Self {
+        self.footer = Some(text.into());
+        self
+    }
+
+    /// Set the TrueType font used for text in PNG pages.
+    ///
+    /// PDFs use the built-in Helvetica and do not need a font.
+    pub fn font(mut self, font_data: Vec<u8>) -> Self {
+        self.font_data = Some(font_data);
+        self
+    }
+
+    /// Add a heading (level 1 is the largest).
+    pub fn heading(mut self, text: impl Into<String>, level: u8) -> Self {
+        self.blocks.push(ReportBlock::Heading {
+            text: text.into(),
+            level: level.clamp(1, 3),
+        });
+        self
+    }
+
+    /// Add a paragraph, wrapped to the page width.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.blocks.push(ReportBlock::Text(text.into()));
+        self
+    }
+
+    /// Add a table; long tables continue on the next page with the header repeated.
+    pub fn table(mut self, headers: &[&str], rows: Vec<Vec<String>>) -> Self {
+        self.blocks.push(ReportBlock::Table {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows,
+        });
+        self
+    }
+
+    /// Add a chart spanning the content width at the given height in points.
+    pub fn chart(mut self, chart: impl TextureChart + 'static, height: f32) -> Self {
+        self.blocks.push(ReportBlock::Chart {
+            chart: Box::new(chart),
+            height,
+        });
+        self
+    }
+
+    /// Start a new page.
+    pub fn page_break(mut self) -> Self {
+        self.blocks.push(ReportBlock::PageBreak);
+        self
+    }
+
+    /// Charts in the order they appear, indexed by [`PageItem::Chart`].
+    fn charts(&self) -> Vec<&dyn TextureChart> {
+        self.blocks
+            .iter()
+            .filter_map(|block| match block {
+                ReportBlock::Chart { chart, .. } => Some(chart.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Lay out all blocks onto pages, including headers and footers.
+    pub(crate) fn paginate(&self) -> Vec<ReportPage> {
+        let (page_width, page_height) = self.page_size.dimensions();
+        let mut layout = Layout {
+            left: self.margin,
+            top: self.margin,
+            content_width: (page_width - 2.0 * self.margin).max(1.0),
+            bottom: page_height - self.margin,
+            y: self.margin,
+            pages: vec![ReportPage::default()],
+        };
+
+        let mut chart_index = 0;
+        for block in &self.blocks {
+            match block {
+                ReportBlock::Heading { text, level } => {
+                    let size = match level {
+                        1 => 20.0,
+                        2 => 16.0,
+                        _ => 13.0,
+                    };
+                    for line in wrap_text(text, layout.content_width, size) {
+                        layout.line(line, size, true);
+                    }
+                }
+                ReportBlock::Text(text) => {
+                    for line in wrap_text(text, layout.content_width, TEXT_SIZE) {
+                        layout.line(line, TEXT_SIZE, false);
+                    }
+                }
+                ReportBlock::Table { headers, rows } => layout.table(headers, rows),
+                ReportBlock::Chart { height, .. } => {
+                    layout.chart(chart_index, *height);
+                    chart_index += 1;
+                }
+                ReportBlock::PageBreak => {
+                    layout.new_page();
+                    continue;
+                }
+            }
+            layout.y += BLOCK_SPACING;
+        }
+
+        let mut pages = layout.pages;
+        let count = pages.len();
+        for (i, page) in pages.iter_mut().enumerate() {
+            let fill = |text: &str| {
+                text.replace("{page}", &(i + 1).to_string())
+                    .replace("{pages}", &count.to_string())
+            };
+            if let Some(header) = &self.header {
+                page.items.push(PageItem::Text {
+                    text: fill(header),
+                    x: self.margin,
+                    y: self.margin / 2.0 + MARGIN_TEXT_SIZE / 2.0,
+                    size: MARGIN_TEXT_SIZE,
+                    bold: false,
+                });
+            }
+            if let Some(footer) = &self.footer {
+                page.items.push(PageItem::Text {
+                    text: fill(footer),
+                    x: self.margin,
+                    y: page_height - self.margin / 2.0,
+                    size: MARGIN_TEXT_SIZE,
+                    bold: false,
+                });
+            }
+        }
+        pages
+    }
+
+    /// Export the report as a multi-page PDF.
+    ///
+    /// Charts that cannot be rasterized (no GPU available) or whose data is
+    /// invalid are left blank.
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let pages = self.paginate();
+        let charts = self.charts();
+        let mut texture = ChartTexture::new().background_color([1.0, 1.0, 1.0, 1.0]);
+
+        let mut writer = pdf::PdfWriter::new(self.page_size.dimensions());
+        for page in &pages {
+            let mut images = Vec::new();
+            for item in &page.items {
+                if let PageItem::Chart {
+                    index,
+                    width,
+                    height,
+                    ..
+                } = *item
+                {
+                    let (w, h) = raster_size(width, height, CHART_RASTER_SCALE);
+                    let pixels = match charts[index].validate() {
+                        Ok(()) => texture.render_rgba(w, h, |renderer| {
+                            charts[index].draw_marks(renderer, w as f32, h as f32)
+                        }),
+                        Err(_) => None,
+                    };
+                    images.push(pixels.map(|p| (w, h, p)));
+                }
+            }
+            writer.add_page(&page.items, images);
+        }
+        writer.finish()
+    }
+
+    /// Export each page as a PNG at `scale` pixels per point.
+    ///
+    /// Text is only drawn when a font was set with [`Report::font`]. Returns
+    /// an empty list when no GPU is available.
+    pub fn to_png_pages(&self, scale: f32) -> Vec<Vec<u8>> {
+        let (page_width, page_height) = self.page_size.dimensions();
+        let (w, h) = raster_size(page_width, page_height, scale);
+        let charts = self.charts();
+        let mut texture = ChartTexture::new().background_color([1.0, 1.0, 1.0, 1.0]);
+
+        let mut pngs = Vec::new();
+        for page in self.paginate() {
+            let Some(mut pixels) = texture.render_rgba(w, h, |renderer| {
+                if let Some(font) = &self.font_data
+                    && !renderer.has_font()
+                {
+                    renderer.load_font(font);
+                }
+                draw_page_items(renderer, &page.items, scale);
+            }) else {
+                return Vec::new();
+            };
+
+            for item in &page.items {
+                if let PageItem::Chart {
+                    index,
+                    x,
+                    y,
+                    width,
+                    height,
+                } = *item
+                {
+                    let (cw, ch) = raster_size(width, height, scale);
+                    if charts[index].validate().is_ok()
+                        && let Some(chart_pixels) = texture.render_rgba(cw, ch, |renderer| {
+                            charts[index].draw_marks(renderer, cw as f32, ch as f32)
+                        })
+                    {
+                        blit(
+                            &mut pixels,
+                            w,
+                            &chart_pixels,
+                            cw,
+                            ((x * scale) as u32, (y * scale) as u32),
+                        );
+                    }
+                }
+            }
+
+            if let Some(png) = encode_png(w, h, pixels) {
+                pngs.push(png);
+            }
+        }
+        pngs
+    }
+}
+
+/// Cursor state while laying out blocks
+struct Layout {
+    left: f32,
+    top: f32,
+    content_width: f32,
+    bottom: f32,
+    y: f32,
+    pages: Vec<ReportPage>,
+}
+
+impl Layout {
+    fn new_page(&mut self) {
+        self.pages.push(ReportPage::default());
+        self.y = self.top;
+    }
+
+    /// Move to a new page unless `height` fits below the cursor.
+    ///
+    /// Content taller than a whole page is placed at the top of a fresh page
+    /// and allowed to overflow.
+    fn ensure_space(&mut self, height: f32) {
+        if self.y + height > self.bottom && self.y > self.top {
+            self.new_page();
+        }
+    }
+
+    fn push(&mut self, item: PageItem) {
+        if let Some(page) = self.pages.last_mut() {
+            page.items.push(item);
+        }
+    }
+
+    fn line(&mut self, text: String, size: f32, bold: bool) {
+        let height = size * LINE_HEIGHT;
+        self.ensure_space(height);
+        self.push(PageItem::Text {
+            text,
+            x: self.left,
+            y: self.y + size,
+            size,
+            bold,
+        });
+        self.y += height;
+    }
+
+    fn table(&mut self, headers: &[String], rows: &[Vec<String>]) {
+        let columns = headers
+            .len()
+            .max(rows.iter().map(Vec::len).max().unwrap_or(0))
+            .max(1);
+        let column_width = self.content_width / columns as f32;
+
+        let header_row = |layout: &mut Layout| {
+            layout.table_row(headers, column_width, true);
+        };
+
+        self.ensure_space(2.0 * TABLE_ROW_HEIGHT);
+        header_row(self);
+        for row in rows {
+            if self.y + TABLE_ROW_HEIGHT > self.bottom {
+                self.new_page();
+                header_row(self);
+            }
+            self.table_row(row, column_width, false);
+        }
+    }
+
+    fn table_row(&mut self, cells: &[String], column_width: f32, bold: bool) {
+        let max_chars = (column_width / (TABLE_TEXT_SIZE * AVG_CHAR_WIDTH)) as usize;
+        for (i, cell) in cells.iter().enumerate() {
+            self.push(PageItem::Text {
+                text: truncate_chars(cell, max_chars.saturating_sub(1)),
+                x: self.left + i as f32 * column_width + 4.0,
+                y: self.y + (TABLE_ROW_HEIGHT + TABLE_TEXT_SIZE) / 2.0 - 1.0,
+                size: TABLE_TEXT_SIZE,
+                bold,
+            });
+        }
+        self.y += TABLE_ROW_HEIGHT;
+        self.push(PageItem::Line {
+            x0: self.left,
+            y0: self.y,
+            x1: self.left + self.content_width,
+            y1: self.y,
+        });
+    }
+
+    fn chart(&mut self, index: usize, height: f32) {
+        self.ensure_space(height);
+        self.push(PageItem::Chart {
+            index,
+            x: self.left,
+            y: self.y,
+            width: self.content_width,
+            height,
+        });
+        self.y += height;
+    }
+}
+
+/// Greedy word wrap using an average glyph width estimate.
+///
+/// Explicit newlines start new lines; words longer than a line are split.
+pub(crate) fn wrap_text(text: &str, max_width: f32, size: f32) -> Vec<String> {
+    let max_chars = ((max_width / (size * AVG_CHAR_WIDTH)) as usize).max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word: Vec<char> = word.chars().collect();
+            while word.len() > max_chars {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                lines.push(word.drain(..max_chars).collect());
+            }
+            let word: String = word.into_iter().collect();
+            let len = line.chars().count();
+            if len > 0 && len + 1 + word.chars().count() > max_chars {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Shorten `text` to at most `max_chars` characters, ending with "…"
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Pixel size of a box in points at `scale` pixels per point
+fn raster_size(width: f32, height: f32, scale: f32) -> (u32, u32) {
+    (
+        (width * scale).round().max(1.0) as u32,
+        (height * scale).round().max(1.0) as u32,
+    )
+}
+
+/// Draw text and rules of a page (charts are composited separately)
+fn draw_page_items(renderer: &mut d3rs::gpu2d::Chart2DRenderer, items: &[PageItem], scale: f32) {
+    let text_color = [0.1, 0.1, 0.1, 1.0];
+    let rule_color = [0.75, 0.75, 0.75, 1.0];
+    for item in items {
+        match item {
+            PageItem::Text {
+                text, x, y, size, ..
+            } => renderer.draw_text(text, x * scale, y * scale, size * scale, text_color),
+            PageItem::Line { x0, y0, x1, y1 } => renderer.draw_line(
+                x0 * scale,
+                y0 * scale,
+                x1 * scale,
+                y1 * scale,
+                scale * 0.5,
+                rule_color,
+            ),
+            PageItem::Chart { .. } => {}
+        }
+    }
+}
+
+/// Alpha-blend an RGBA image onto a larger RGBA buffer at `origin`
+fn blit(dst: &mut [u8], dst_width: u32, src: &[u8], src_width: u32, origin: (u32, u32)) {
+    let dst_height = dst.len() / (4 * dst_width as usize);
+    for (row, src_row) in src.chunks_exact(4 * src_width as usize).enumerate() {
+        let y = origin.1 as usize + row;
+        if y >= dst_height {
+            break;
+        }
+        for (col, px) in src_row.chunks_exact(4).enumerate() {
+            let x = origin.0 as usize + col;
+            if x >= dst_width as usize {
+                break;
+            }
+            let offset = 4 * (y * dst_width as usize + x);
+            let alpha = px[3] as u32;
+            for c in 0..3 {
+                let d = dst[offset + c] as u32;
+                dst[offset + c] = ((px[c] as u32 * alpha + d * (255 - alpha)) / 255) as u8;
+            }
+            dst[offset + 3] = 255;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line;
+
+    #[test]
+    fn test_wrap_text() {
+        // 11 pt text at 0.52 average width: 10 characters in 60 pt
+        let lines = wrap_text("one two three four", 60.0, 11.0);
+        assert_eq!(lines, vec!["one two", "three four"]);
+
+        let lines = wrap_text("abcdefghijklmnop", 60.0, 11.0);
+        assert_eq!(lines, vec!["abcdefghij", "klmnop"]);
+
+        assert_eq!(wrap_text("a\nb", 100.0, 11.0), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        assert_eq!(truncate_chars("short", 10), "short");
+        assert_eq!(truncate_chars("longer text", 5), "long…");
+    }
+
+    #[test]
+    fn test_paginate_headers_and_footers() {
+        let report = Report::new()
+            .footer("Page {page} of {pages}")
+            .text("first")
+            .page_break()
+            .text("second");
+        let pages = report.paginate();
+        assert_eq!(pages.len(), 2);
+
+        let footer = |page: &ReportPage| {
+            page.items.iter().find_map(|item| match item {
+                PageItem::Text { text, .. } if text.starts_with("Page") => Some(text.clone()),
+                _ => None,
+            })
+        };
+        assert_eq!(footer(&pages[0]).as_deref(), Some("Page 1 of 2"));
+        assert_eq!(footer(&pages[1]).as_deref(), Some("Page 2 of 2"));
+    }
+
+    #[test]
+    fn test_paginate_long_table_repeats_header() {
+        let rows: Vec<Vec<String>> = (0..100)
+            .map(|i| vec![i.to_string(), (i * 2).to_string()])
+            .collect();
+        let pages = Report::new().table(&["a", "b"], rows).paginate();
+        assert!(pages.len() > 1);
+
+        for page in &pages {
+            let first_text = page.items.iter().find_map(|item| match item {
+                PageItem::Text { text, bold, .. } => Some((text.clone(), *bold)),
+                _ => None,
+            });
+            assert_eq!(first_text, Some(("a".to_string(), true)));
+        }
+    }
+
+    #[test]
+    fn test_paginate_moves_chart_that_does_not_fit() {
+        let report = Report::new()
+            .page_size(PageSize::Custom {
+                width: 300.0,
+                height: 300.0,
+            })
+            .margin(20.0)
+            .chart(line(&[0.0, 1.0], &[0.0, 1.0]), 150.0)
+            .chart(line(&[0.0, 1.0], &[1.0, 0.0]), 150.0);
+        let pages = report.paginate();
+        assert_eq!(pages.len(), 2);
+        let PageItem::Chart { index, y, .. } = pages[1].items[0] else {
+            panic!("expected chart at the top of the second page");
+        };
+        assert_eq!((index, y), (1, 20.0));
+    }
+
+    #[test]
+    fn test_blit_alpha() {
+        let mut dst = vec![255u8; 4 * 2 * 2];
+        let src = [0, 0, 0, 255, 0, 0, 0, 0];
+        blit(&mut dst, 2, &src, 2, (0, 1));
+        assert_eq!(&dst[8..12], &[0, 0, 0, 255]);
+        assert_eq!(&dst[12..16], &[255, 255, 255, 255]);
+        assert_eq!(&dst[0..4], &[255, 255, 255, 255]);
+    }
+}
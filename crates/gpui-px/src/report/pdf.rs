@@ -0,0 +1,271 @@
+//! Minimal PDF 1.4 writer for reports.
+//!
+//! Supports exactly what report pages need: Helvetica text, thin rules and
+//! uncompressed RGB images. Coordinates are given top-down in points and
+//! flipped to PDF's bottom-up space here.
+
+use super::PageItem;
+use std::fmt::Write as _;
+
+/// Object ids reserved for the catalog, page tree and fonts
+const CATALOG_ID: usize = 1;
+const PAGES_ID: usize = 2;
+const FONT_REGULAR_ID: usize = 3;
+const FONT_BOLD_ID: usize = 4;
+
+/// Raster image to embed: `(width, height, rgba_pixels)`
+pub(super) type PdfImage = (u32, u32, Vec<u8>);
+
+/// Builds a PDF document page by page.
+pub(super) struct PdfWriter {
+    page_width: f32,
+    page_height: f32,
+    /// Serialized objects, indexed by id - 1 (`None` until written)
+    objects: Vec<Option<Vec<u8>>>,
+    page_ids: Vec<usize>,
+}
+
+impl PdfWriter {
+    pub(super) fn new((page_width, page_height): (f32, f32)) -> Self {
+        let mut writer = Self {
+            page_width,
+            page_height,
+            objects: vec![None; FONT_BOLD_ID],
+            page_ids: Vec::new(),
+        };
+        writer.set(
+            FONT_REGULAR_ID,
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        );
+        writer.set(
+            FONT_BOLD_ID,
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        );
+        writer
+    }
+
+    fn reserve(&mut self) -> usize {
+        self.objects.push(None);
+        self.objects.len()
+    }
+
+    fn set(&mut self, id: usize, body: Vec<u8>) {
+        self.objects[id - 1] = Some(body);
+    }
+
+    /// Add a page; `images` holds one entry per chart item, in item order.
+    ///
+    /// Charts whose image is `None` are left blank.
+    pub(super) fn add_page(&mut self, items: &[PageItem], images: Vec<Option<PdfImage>>) {
+        let page_id = self.reserve();
+        let content_id = self.reserve();
+        self.page_ids.push(page_id);
+
+        let mut content = String::new();
+        let mut xobjects = String::new();
+        let mut images = images.into_iter();
+
+        for item in items {
+            match item {
+                PageItem::Text {
+                    text,
+                    x,
+                    y,
+                    size,
+                    bold,
+                } => {
+                    let font = if *bold { "F2" } else { "F1" };
+                    let _ = writeln!(
+                        content,
+                        "BT /{font} {size:.1} Tf {x:.2} {:.2} Td ({}) Tj ET",
+                        self.page_height - y,
+                        escape_text(text)
+                    );
+                }
+                PageItem::Line { x0, y0, x1, y1 } => {
+                    let _ = writeln!(
+                        content,
+                        "0.75 G 0.5 w {x0:.2} {:.2} m {x1:.2} {:.2} l S",
+                        self.page_height - y0,
+                        self.page_height - y1
+                    );
+                }
+                PageItem::Chart {
+                    x,
+                    y,
+                    width,
+                    height,
+                    ..
+                } => {
+                    let Some(Some((w, h, rgba))) = images.next() else {
+                        continue;
+                    };
+                    let image_id = self.reserve();
+                    let rgb = flatten_rgba(&rgba);
+                    let mut body = format!(
+                        "<< /Type /XObject /Subtype /Image /Width {w} /Height {h} \
+                         /ColorSpace /DeviceRGB /BitsPerComponent 8 /Length {} >>\nstream\n",
+                        rgb.len()
+                    )
+                    .into_bytes();
+                    body.extend_from_slice(&rgb);
+                    body.extend_from_slice(b"\nendstream");
+                    self.set(image_id, body);
+
+                    let name = format!("Im{image_id}");
+                    let _ = write!(xobjects, "/{name} {image_id} 0 R ");
+                    let _ = writeln!(
+                        content,
+                        "q {width:.2} 0 0 {height:.2} {x:.2} {:.2} cm /{name} Do Q",
+                        self.page_height - y - height
+                    );
+                }
+            }
+        }
+
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(content.as_bytes());
+        stream.extend_from_slice(b"\nendstream");
+        self.set(content_id, stream);
+
+        let page = format!(
+            "<< /Type /Page /Parent {PAGES_ID} 0 R /MediaBox [0 0 {:.2} {:.2}] \
+             /Resources << /Font << /F1 {FONT_REGULAR_ID} 0 R /F2 {FONT_BOLD_ID} 0 R >> \
+             /XObject << {xobjects}>> >> /Contents {content_id} 0 R >>",
+            self.page_width, self.page_height
+        );
+        self.set(page_id, page.into_bytes());
+    }
+
+    /// Write the catalog, page tree and cross-reference table.
+    pub(super) fn finish(mut self) -> Vec<u8> {
+        let kids: Vec<String> = self.page_ids.iter().map(|id| format!("{id} 0 R")).collect();
+        self.set(
+            PAGES_ID,
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                kids.len()
+            )
+            .into_bytes(),
+        );
+        self.set(
+            CATALOG_ID,
+            format!("<< /Type /Catalog /Pages {PAGES_ID} 0 R >>").into_bytes(),
+        );
+
+        let mut out = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+        let mut offsets = Vec::with_capacity(self.objects.len());
+        for (i, object) in self.objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+            out.extend_from_slice(object.as_deref().unwrap_or(b"null"));
+            out.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = out.len();
+        let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", self.objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(xref, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            xref,
+            "trailer\n<< /Size {} /Root {CATALOG_ID} 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            self.objects.len() + 1
+        );
+        out.extend_from_slice(xref.as_bytes());
+        out
+    }
+}
+
+/// Escape a string for a PDF literal; characters outside Latin-1 become `?`.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '(' | ')' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '…' => escaped.push_str("\\205"),
+            c if (c as u32) < 0x20 => escaped.push(' '),
+            c if (c as u32) < 0x80 => escaped.push(c),
+            c if (c as u32) < 0x100 => {
+                let _ = write!(escaped, "\\{:03o}", c as u32);
+            }
+            _ => escaped.push('?'),
+        }
+    }
+    escaped
+}
+
+/// Composite RGBA pixels over white and drop the alpha channel.
+fn flatten_rgba(rgba: &[u8]) -> Vec<u8> {
+    let mut rgb = Vec::with_capacity(rgba.len() / 4 * 3);
+    for px in rgba.chunks_exact(4) {
+        let alpha = px[3] as u32;
+        for &c in &px[..3] {
+            rgb.push(((c as u32 * alpha + 255 * (255 - alpha)) / 255) as u8);
+        }
+    }
+    rgb
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("a(b)\\c"), "a\\(b\\)\\\\c");
+        assert_eq!(escape_text("é…✓"), "\\351\\205?");
+    }
+
+    #[test]
+    fn test_document_structure() {
+        let mut writer = PdfWriter::new((100.0, 200.0));
+        writer.add_page(
+            &[PageItem::Text {
+                text: "Hi".to_string(),
+                x: 10.0,
+                y: 20.0,
+                size: 12.0,
+                bold: false,
+            }],
+            Vec::new(),
+        );
+        writer.add_page(
+            &[PageItem::Chart {
+                index: 0,
+                x: 0.0,
+                y: 0.0,
+                width: 10.0,
+                height: 10.0,
+            }],
+            vec![Some((1, 1, vec![255, 0, 0, 255]))],
+        );
+        let pdf = writer.finish();
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.contains("/Count 2"));
+        assert!(text.contains("BT /F1 12.0 Tf 10.00 180.00 Td (Hi) Tj ET"));
+        assert!(text.contains("/Subtype /Image /Width 1 /Height 1"));
+        assert!(text.trim_end().ends_with("%%EOF"));
+
+        // startxref points at the xref table
+        let start = text.rfind("startxref\n").unwrap() + "startxref\n".len();
+        let offset: usize = text[start..].lines().next().unwrap().parse().unwrap();
+        assert!(pdf[offset..].starts_with(b"xref"));
+    }
+
+    #[test]
+    fn test_flatten_rgba() {
+        assert_eq!(
+            flatten_rgba(&[0, 0, 0, 0, 10, 20, 30, 255]),
+            vec![255, 255, 255, 10, 20, 30]
+        );
+    }
+}
@@ -1,21 +1,47 @@
 //! Scatter chart - Plotly Express style API.
 
+use crate::axis::Axis;
+use crate::crosshair::Crosshair;
 use crate::error::ChartError;
+use crate::geometry::{AxisGeometry, ChartGeometry};
+use crate::layout::{ChartLayout, chart_layout_methods};
 use crate::line::LegendPosition;
+use crate::theme::ChartTheme;
+use crate::zoom::{ChartZoom, ZoomHome, build_zoomable, chart_zoom_methods};
 use crate::{
-    DEFAULT_COLOR, DEFAULT_HEIGHT, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE,
-    DEFAULT_WIDTH, ScaleType, TITLE_AREA_HEIGHT, extent_padded, validate_data_array,
-    validate_data_length, validate_dimensions, validate_positive,
+    ChartDefaults, DEFAULT_COLOR, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE, ScaleType,
+    TITLE_AREA_HEIGHT, extent_padded, validate_data_array, validate_data_length,
+    validate_dimensions, validate_plot_area, validate_positive,
 };
-use d3rs::axis::{AxisConfig, DefaultAxisTheme, render_axis};
+use d3rs::axis::{AxisConfig, render_axis};
 use d3rs::color::D3Color;
 use d3rs::grid::{GridConfig, render_grid};
+use d3rs::random::LcgRng;
 use d3rs::scale::{LinearScale, LogScale};
 use d3rs::shape::{ScatterConfig, ScatterPoint, render_scatter};
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
 use gpui::{AnyElement, IntoElement, Rgba, div, hsla, px, rgb};
 
+/// Seed of deterministic jitter
+const JITTER_SEED: u64 = 0x5eed;
+
+/// Lowest opacity chosen by `auto_opacity`
+const MIN_AUTO_OPACITY: f32 = 0.05;
+
+/// Opacity factor of the points left out of a selection
+const UNSELECTED_OPACITY: f32 = 0.25;
+
+/// How jitter offsets are drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JitterMode {
+    /// Same offsets on every build, so points don't move on redraw (default)
+    #[default]
+    Deterministic,
+    /// New offsets on every build
+    Random,
+}
+
 /// A single series in a scatter chart
 #[derive(Debug, Clone)]
 struct ScatterSeries {
@@ -53,6 +79,30 @@ impl Default for ScatterTheme {
     }
 }
 
+impl From<&ChartTheme> for ScatterTheme {
+    fn from(theme: &ChartTheme) -> Self {
+        Self {
+            plot_background: theme.plot_background,
+            title_color: theme.title_color,
+            legend_text_color: theme.legend_text_color,
+        }
+    }
+}
+
+/// Plot area, legend and domains of a scatter chart.
+struct ScatterFrame {
+    title_height: f32,
+    legend_gap: f32,
+    has_legend_items: bool,
+    legend_position: LegendPosition,
+    legend_width: f32,
+    legend_height: f32,
+    plot_width: f64,
+    plot_height: f64,
+    x_domain: (f64, f64),
+    y_domain: (f64, f64),
+}
+
 /// Scatter chart builder.
 #[derive(Debug, Clone)]
 pub struct ScatterChart {
@@ -74,15 +124,35 @@ pub struct ScatterChart {
     // Axis range overrides (for zoom support)
     x_range: Option<[f64; 2]>,
     y_range: Option<[f64; 2]>,
+    // Shared x axis
+    x_axis: Option<Axis>,
     // Legend settings
     show_legend: bool,
     legend_position: LegendPosition,
     legend_position_explicit: bool,
     graph_ratio: f32,
+    // Overplotting
+    jitter: f64,
+    jitter_mode: JitterMode,
+    auto_opacity: bool,
+    selected: Option<Vec<usize>>,
     theme: ScatterTheme,
+    layout: ChartLayout,
+    zoom: ChartZoom,
+    crosshair: bool,
 }
 
 impl ScatterChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+    chart_zoom_methods!(zoom);
+
+    /// Draw guide lines through the pointer over the plot, with its x and y
+    /// values in boxes on the axes.
+    pub fn crosshair(mut self, enabled: bool) -> Self {
+        self.crosshair = enabled;
+        self
+    }
+
     /// Set chart title (rendered at top of chart).
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -115,6 +185,48 @@ impl ScatterChart {
         self
     }
 
+    /// Spread points horizontally by up to `amount / 2` on each side.
+    ///
+    /// The amount is in x data units, or in decades on a log x axis. Use it
+    /// to separate overlapping points of categorical data, where x is the
+    /// category index.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gpui_px::scatter;
+    /// let chart = scatter(&[0.0, 0.0, 0.0, 1.0, 1.0], &[1.0, 1.2, 1.1, 2.0, 2.1])
+    ///     .jitter(0.3)
+    ///     .build();
+    /// ```
+    pub fn jitter(mut self, amount: f64) -> Self {
+        self.jitter = amount.abs();
+        self
+    }
+
+    /// Set how jitter offsets are drawn. Default is [`JitterMode::Deterministic`].
+    pub fn jitter_mode(mut self, mode: JitterMode) -> Self {
+        self.jitter_mode = mode;
+        self
+    }
+
+    /// Lower the opacity of every series as its points cover more of the
+    /// plot area, so dense regions stay readable.
+    ///
+    /// The density factor multiplies the opacity set on each series.
+    pub fn auto_opacity(mut self, enabled: bool) -> Self {
+        self.auto_opacity = enabled;
+        self
+    }
+
+    /// Highlight points of the primary series by index, dimming the others.
+    ///
+    /// Pass the ids of a shared [`SelectionModel`](crate::SelectionModel)
+    /// to keep the chart in step with a table showing the same rows.
+    pub fn selected(mut self, indices: &[usize]) -> Self {
+        self.selected = Some(indices.to_vec());
+        self
+    }
+
     /// Set chart dimensions.
     pub fn size(mut self, width: f32, height: f32) -> Self {
         self.width = width;
@@ -136,6 +248,14 @@ impl ScatterChart {
         self
     }
 
+    /// Share an x axis with other charts: scale type, tick format, title and
+    /// domain come from `axis`.
+    pub fn x_axis(mut self, axis: &Axis) -> Self {
+        self.x_scale_type = axis.scale_type();
+        self.x_axis = Some(axis.clone());
+        self
+    }
+
     /// Set Y-axis scale type (linear or log).
     pub fn y_scale(mut self, scale: ScaleType) -> Self {
         self.y_scale_type = scale;
@@ -211,6 +331,9 @@ impl ScatterChart {
     }
 
     /// Set the chart theme.
+    ///
+    /// Defaults to the colors of [`ChartTheme::current`], which follows the
+    /// app theme once [`init`](crate::init) has been called.
     pub fn theme(mut self, theme: ScatterTheme) -> Self {
         self.theme = theme;
         self
@@ -241,8 +364,88 @@ impl ScatterChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
+    /// Compute the scales, ticks and marker positions without rendering.
+    ///
+    /// Runs the same validation and layout as [`ScatterChart::build`] at the
+    /// chart's own size; the outer layout options are not applied.
+    pub fn compute_layout(&self) -> Result<ChartGeometry, ChartError> {
+        let mut chart = self.clone();
+        chart.apply_jitter();
+        chart.plot_geometry()
+    }
+
+    /// Geometry of the chart with jitter already applied.
+    fn plot_geometry(&self) -> Result<ChartGeometry, ChartError> {
+        let frame = self.plot_frame()?;
+        let x_axis = AxisGeometry::new(
+            self.x_scale_type,
+            frame.x_domain,
+            (0.0, frame.plot_width),
+            None,
+            10,
+        );
+        let y_axis = AxisGeometry::new(
+            self.y_scale_type,
+            frame.y_domain,
+            (frame.plot_height, 0.0),
+            None,
+            10,
+        );
+        let mut geometry = ChartGeometry::new(frame.plot_width, frame.plot_height, x_axis, y_axis);
+
+        let pairs = |x: &[f64], y: &[f64]| -> Vec<(f64, f64)> {
+            x.iter().copied().zip(y.iter().copied()).collect()
+        };
+        geometry.push_series(0, &pairs(&self.x, &self.y), false, false);
+        for (i, series) in self.series.iter().enumerate() {
+            geometry.push_series(i + 1, &pairs(&series.x, &series.y), false, false);
+        }
+        Ok(geometry)
+    }
+
+    /// Offset the x values of all series by the jitter, then clear it.
+    fn apply_jitter(&mut self) {
+        if self.jitter == 0.0 {
+            return;
+        }
+        let rng = match self.jitter_mode {
+            JitterMode::Deterministic => LcgRng::new(JITTER_SEED),
+            JitterMode::Random => LcgRng::default_seed(),
+        };
+        let (amount, log) = (self.jitter, self.x_scale_type == ScaleType::Log);
+        let xs = std::iter::once(&mut self.x).chain(self.series.iter_mut().map(|s| &mut s.x));
+        for x in xs.flat_map(|x| x.iter_mut()) {
+            let offset = (rng.next_f64() - 0.5) * amount;
+            if log {
+                *x *= 10f64.powf(offset);
+            } else {
+                *x += offset;
+            }
+        }
+        self.jitter = 0.0;
+    }
+
+    /// Opacity of a series of `count` points, lowered by `auto_opacity`.
+    fn series_opacity(&self, opacity: f32, count: usize, radius: f32, plot: (f64, f64)) -> f32 {
+        if self.auto_opacity {
+            opacity * density_opacity(count, radius, plot.0 as f32, plot.1 as f32)
+        } else {
+            opacity
+        }
+    }
+
+    /// Validate the data and lay out the plot area, legend and domains.
+    /// Crosshair of a plot showing the given domains, when enabled.
+    fn crosshair_layer(&self, x_domain: (f64, f64), y_domain: (f64, f64)) -> Option<Crosshair> {
+        self.crosshair.then(|| {
+            Crosshair::new(x_domain, y_domain, &ChartTheme::current()).log(
+                self.x_scale_type == ScaleType::Log,
+                self.y_scale_type == ScaleType::Log,
+            )
+        })
+    }
+
+    fn plot_frame(&self) -> Result<ScatterFrame, ChartError> {
         // Validate inputs
         validate_data_array(&self.x, "x")?;
         validate_data_array(&self.y, "y")?;
@@ -391,6 +594,7 @@ impl ScatterChart {
             - margin_bottom
             - height_for_legend as f64)
             .max(0.0);
+        validate_plot_area(plot_width, plot_height)?;
 
         // Calculate domains with padding - include all series, or use explicit ranges if set
         let (x_min, x_max) = if let Some([min, max]) = self.x_range {
@@ -402,6 +606,10 @@ impl ScatterChart {
             }
             extent_padded(&all_x, DEFAULT_PADDING_FRACTION)
         };
+        let (x_min, x_max) = match &self.x_axis {
+            Some(axis) => axis.resolve((x_min, x_max)),
+            None => (x_min, x_max),
+        };
         let (y_min, y_max) = if let Some([min, max]) = self.y_range {
             (min, max)
         } else {
@@ -412,6 +620,52 @@ impl ScatterChart {
             extent_padded(&all_y, DEFAULT_PADDING_FRACTION)
         };
 
+        Ok(ScatterFrame {
+            title_height,
+            legend_gap,
+            has_legend_items,
+            legend_position,
+            legend_width,
+            legend_height,
+            plot_width,
+            plot_height,
+            x_domain: (x_min, x_max),
+            y_domain: (y_min, y_max),
+        })
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<AnyElement, ChartError> {
+        self.apply_jitter();
+        if let Some(id) = self.zoom.id.take() {
+            let frame = self.plot_frame()?;
+            let home = ZoomHome {
+                x_domain: frame.x_domain,
+                y_domain: frame.y_domain,
+                x_scale: self.x_scale_type,
+                y_scale: self.y_scale_type,
+            };
+            let size = (self.width, self.height);
+            return Ok(build_zoomable(id, home, self, size, |chart, state| {
+                let ((x_min, x_max), (y_min, y_max)) = (state.x_domain(), state.y_domain());
+                let mut chart = chart.x_range(x_min, x_max).y_range(y_min, y_max);
+                chart.zoom.state = Some(state.clone());
+                chart.build_chart()
+            }));
+        }
+        let ScatterFrame {
+            title_height,
+            legend_gap,
+            has_legend_items,
+            legend_position,
+            legend_width,
+            legend_height,
+            plot_width,
+            plot_height,
+            x_domain: (x_min, x_max),
+            y_domain: (y_min, y_max),
+        } = self.plot_frame()?;
+
         // Create data points for primary series
         let primary_data: Vec<ScatterPoint> = self
             .x
@@ -420,10 +674,33 @@ impl ScatterChart {
             .map(|(&x, &y)| ScatterPoint::new(x, y))
             .collect();
 
+        // Selected points are drawn again on top of the dimmed series
+        let selected_data: Vec<ScatterPoint> = self
+            .selected
+            .iter()
+            .flatten()
+            .filter_map(|&i| primary_data.get(i).copied())
+            .collect();
+        let primary_opacity = self.series_opacity(
+            self.opacity,
+            self.x.len(),
+            self.point_radius,
+            (plot_width, plot_height),
+        );
+        let primary_color = D3Color::from_hex(self.color);
         let primary_config = ScatterConfig::new()
-            .fill_color(D3Color::from_hex(self.color))
+            .fill_color(primary_color)
             .point_radius(self.point_radius)
-            .opacity(self.opacity);
+            .opacity(if selected_data.is_empty() {
+                primary_opacity
+            } else {
+                primary_opacity * UNSELECTED_OPACITY
+            });
+        let selected_config = ScatterConfig::new()
+            .fill_color(primary_color)
+            .point_radius(self.point_radius + 1.0)
+            .stroke_color(primary_color.adjust_lightness(-0.25))
+            .stroke_width(2.0);
 
         // Prepare additional series data and configs
         let series_data_configs: Vec<(Vec<ScatterPoint>, ScatterConfig)> = self
@@ -438,12 +715,17 @@ impl ScatterChart {
                 let config = ScatterConfig::new()
                     .fill_color(D3Color::from_hex(s.color))
                     .point_radius(s.point_radius)
-                    .opacity(s.opacity);
+                    .opacity(self.series_opacity(
+                        s.opacity,
+                        s.x.len(),
+                        s.point_radius,
+                        (plot_width, plot_height),
+                    ));
                 (points, config)
             })
             .collect();
 
-        let axis_theme = DefaultAxisTheme;
+        let axis_theme = ChartTheme::current().axis_theme();
 
         // Helper macro to build plot area with all series
         macro_rules! build_plot_area {
@@ -454,6 +736,11 @@ impl ScatterChart {
                     .relative()
                     .overflow_hidden()
                     .bg(self.theme.plot_background)
+                    .children(self.layout.render_watermark(
+                        self.theme.title_color,
+                        plot_width as f32,
+                        plot_height as f32,
+                    ))
                     .child(render_grid(
                         &$x_scale,
                         &$y_scale,
@@ -480,12 +767,26 @@ impl ScatterChart {
                     &primary_data,
                     &primary_config,
                 ));
+                if !selected_data.is_empty() {
+                    plot_area = plot_area.child(render_scatter(
+                        &$x_scale,
+                        &$y_scale,
+                        &selected_data,
+                        &selected_config,
+                    ));
+                }
 
                 plot_area
+                    .children(self.zoom.probe())
+                    .children(self.crosshair_layer((x_min, x_max), (y_min, y_max)))
             }};
         }
 
         // Build the element based on scale types
+        let x_axis_config = match &self.x_axis {
+            Some(axis) => axis.configure(AxisConfig::bottom()),
+            None => AxisConfig::bottom(),
+        };
         let chart_content: AnyElement = match (self.x_scale_type, self.y_scale_type) {
             (ScaleType::Linear, ScaleType::Linear) => {
                 let x_scale = LinearScale::new()
@@ -507,7 +808,7 @@ impl ScatterChart {
                     ))
                     .child(div().flex().flex_col().child(plot_area).child(render_axis(
                         &x_scale,
-                        &AxisConfig::bottom(),
+                        &x_axis_config,
                         plot_width as f32,
                         &axis_theme,
                     )))
@@ -533,7 +834,7 @@ impl ScatterChart {
                     ))
                     .child(div().flex().flex_col().child(plot_area).child(render_axis(
                         &x_scale,
-                        &AxisConfig::bottom(),
+                        &x_axis_config,
                         plot_width as f32,
                         &axis_theme,
                     )))
@@ -559,7 +860,7 @@ impl ScatterChart {
                     ))
                     .child(div().flex().flex_col().child(plot_area).child(render_axis(
                         &x_scale,
-                        &AxisConfig::bottom(),
+                        &x_axis_config,
                         plot_width as f32,
                         &axis_theme,
                     )))
@@ -585,7 +886,7 @@ impl ScatterChart {
                     ))
                     .child(div().flex().flex_col().child(plot_area).child(render_axis(
                         &x_scale,
-                        &AxisConfig::bottom(),
+                        &x_axis_config,
                         plot_width as f32,
                         &axis_theme,
                     )))
@@ -736,10 +1037,132 @@ impl ScatterChart {
             container = container.child(div().relative().child(chart_content));
         }
 
-        Ok(container)
+        Ok(container.into_any_element())
+    }
+}
+
+impl crate::clipboard::CopyToClipboard for ScatterChart {
+    /// All series as long-format TSV (`series`, `x`, `y`).
+    fn clipboard_text(&self) -> Option<String> {
+        let label = self.label.clone().unwrap_or_else(|| "Series 1".to_string());
+        let mut series: Vec<(String, &[f64], &[f64])> = vec![(label, &self.x, &self.y)];
+        for (i, s) in self.series.iter().enumerate() {
+            let label = s
+                .label
+                .clone()
+                .unwrap_or_else(|| format!("Series {}", i + 2));
+            series.push((label, &s.x, &s.y));
+        }
+        Some(crate::clipboard::xy_tsv(&series))
+    }
+
+    #[cfg(feature = "gpu-2d")]
+    fn clipboard_png(&self) -> Option<Vec<u8>> {
+        crate::clipboard::chart_png(self, self.width, self.height)
+    }
+}
+
+#[cfg(feature = "gpu-2d")]
+impl crate::offscreen::TextureChart for ScatterChart {
+    fn validate(&self) -> Result<(), ChartError> {
+        self.plot_frame().map(|_| ())
+    }
+
+    fn content_key(&self) -> u64 {
+        use crate::offscreen::hash_values;
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        hash_values(&mut hasher, &self.x);
+        hash_values(&mut hasher, &self.y);
+        (
+            self.color,
+            self.point_radius.to_bits(),
+            self.opacity.to_bits(),
+            &self.selected,
+        )
+            .hash(&mut hasher);
+        for series in &self.series {
+            hash_values(&mut hasher, &series.x);
+            hash_values(&mut hasher, &series.y);
+            (
+                series.color,
+                series.point_radius.to_bits(),
+                series.opacity.to_bits(),
+            )
+                .hash(&mut hasher);
+        }
+        self.x_axis
+            .as_ref()
+            .and_then(Axis::pinned_domain)
+            .or(self.x_range)
+            .map(|r| r.map(f64::to_bits))
+            .hash(&mut hasher);
+        self.y_range.map(|r| r.map(f64::to_bits)).hash(&mut hasher);
+        (self.x_scale_type == ScaleType::Log).hash(&mut hasher);
+        (self.y_scale_type == ScaleType::Log).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn draw_marks(&self, renderer: &mut d3rs::gpu2d::Chart2DRenderer, width: f32, height: f32) {
+        use crate::offscreen::{TextureScale, texture_color};
+
+        let mut all_x = self.x.clone();
+        let mut all_y = self.y.clone();
+        for series in &self.series {
+            all_x.extend_from_slice(&series.x);
+            all_y.extend_from_slice(&series.y);
+        }
+        if all_x.is_empty() {
+            return;
+        }
+        let x_domain = self
+            .x_axis
+            .as_ref()
+            .and_then(Axis::pinned_domain)
+            .or(self.x_range)
+            .map_or_else(
+                || extent_padded(&all_x, DEFAULT_PADDING_FRACTION),
+                |[a, b]| (a, b),
+            );
+        let y_domain = self.y_range.map_or_else(
+            || extent_padded(&all_y, DEFAULT_PADDING_FRACTION),
+            |[a, b]| (a, b),
+        );
+        let x_scale = TextureScale::horizontal(x_domain, self.x_scale_type, width);
+        let y_scale = TextureScale::vertical(y_domain, self.y_scale_type, height);
+
+        // Additional series first so the primary series is drawn on top
+        let series = self
+            .series
+            .iter()
+            .map(|s| (&s.x, &s.y, s.color, s.point_radius, s.opacity))
+            .chain(std::iter::once((
+                &self.x,
+                &self.y,
+                self.color,
+                self.point_radius,
+                self.opacity,
+            )));
+        for (xs, ys, color, radius, opacity) in series {
+            let color = texture_color(color, opacity);
+            for (&x, &y) in xs.iter().zip(ys) {
+                renderer.draw_circle(x_scale.map(x), y_scale.map(y), radius, color);
+            }
+        }
     }
 }
 
+/// Opacity factor for `count` markers of `radius` in a plot area.
+///
+/// 1.0 while the markers could cover the area at most once, then falls with
+/// the square root of the coverage.
+fn density_opacity(count: usize, radius: f32, plot_width: f32, plot_height: f32) -> f32 {
+    let area = (plot_width * plot_height).max(1.0);
+    let coverage = count as f32 * std::f32::consts::PI * radius * radius / area;
+    (1.0 / coverage.max(1.0).sqrt()).max(MIN_AUTO_OPACITY)
+}
+
 /// Create a scatter chart from x and y data.
 ///
 /// # Example
@@ -757,6 +1180,7 @@ impl ScatterChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn scatter(x: &[f64], y: &[f64]) -> ScatterChart {
+    let defaults = ChartDefaults::current();
     ScatterChart {
         x: x.to_vec(),
         y: y.to_vec(),
@@ -766,17 +1190,25 @@ pub fn scatter(x: &[f64], y: &[f64]) -> ScatterChart {
         opacity: 0.7,
         series: Vec::new(),
         title: None,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        width: defaults.width,
+        height: defaults.height,
         x_scale_type: ScaleType::Linear,
         y_scale_type: ScaleType::Linear,
         x_range: None,
         y_range: None,
+        x_axis: None,
         show_legend: false,
-        legend_position: LegendPosition::default(),
-        legend_position_explicit: false,
+        legend_position: defaults.legend_position.unwrap_or_default(),
+        legend_position_explicit: defaults.legend_position.is_some(),
         graph_ratio: 1.414,
-        theme: ScatterTheme::default(),
+        jitter: 0.0,
+        jitter_mode: JitterMode::Deterministic,
+        auto_opacity: false,
+        selected: None,
+        theme: ScatterTheme::from(&ChartTheme::current()),
+        layout: ChartLayout::watermark_in_plot(),
+        zoom: ChartZoom::new(defaults.zoomable),
+        crosshair: defaults.crosshair,
     }
 }
 
@@ -948,4 +1380,50 @@ mod tests {
             .build();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_scatter_compute_layout() {
+        let geometry = scatter(&[1.0, 2.0, 3.0], &[3.0, 1.0, 2.0])
+            .size(400.0, 300.0)
+            .compute_layout()
+            .unwrap();
+        assert_eq!(geometry.points.len(), 3);
+        assert!(geometry.paths.is_empty());
+        let x = geometry.x_axis.position(2.0);
+        assert!((geometry.points[1].x - x).abs() < 1e-9);
+        assert_eq!(geometry.x_axis.value_at(x).map(|v| v.round()), Some(2.0));
+    }
+
+    #[test]
+    fn test_scatter_jitter_is_deterministic() {
+        let chart = scatter(&[1.0; 20], &[2.0; 20]).jitter(0.4);
+        let first = chart.compute_layout().unwrap();
+        let second = chart.compute_layout().unwrap();
+        assert_eq!(first.points, second.points);
+
+        let (low, high) = (first.x_axis.position(0.8), first.x_axis.position(1.2));
+        assert!(first.points.iter().all(|p| p.x >= low && p.x <= high));
+        assert!(first.points.iter().any(|p| p.x != first.points[0].x));
+        assert!(chart.jitter_mode(JitterMode::Random).build().is_ok());
+    }
+
+    #[test]
+    fn test_scatter_selected_points() {
+        let chart = scatter(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]);
+        assert!(chart.clone().selected(&[0, 2]).build().is_ok());
+        // Out-of-range indices are ignored
+        assert!(chart.selected(&[7]).build().is_ok());
+    }
+
+    #[test]
+    fn test_density_opacity() {
+        assert_eq!(density_opacity(10, 5.0, 400.0, 300.0), 1.0);
+        // Markers covering the plot four times over
+        let count = (4.0 * 400.0 * 300.0 / (std::f32::consts::PI * 25.0)) as usize;
+        assert!((density_opacity(count, 5.0, 400.0, 300.0) - 0.5).abs() < 0.01);
+        assert_eq!(
+            density_opacity(usize::MAX / 2, 5.0, 1.0, 1.0),
+            MIN_AUTO_OPACITY
+        );
+    }
 }
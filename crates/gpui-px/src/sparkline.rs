@@ -0,0 +1,631 @@
+//! Sparklines - word-sized charts for inline use.
+//!
+//! [`Sparkline`], [`SparkArea`] and [`SparkBar`] draw a single series with no
+//! axes, legend or title, sized to fit table cells, cards or status bars.
+
+use crate::error::ChartError;
+use crate::interaction::PlotBoundsCapture;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::{DEFAULT_COLOR, validate_data_array, validate_dimensions};
+use d3rs::color::D3Color;
+use gpui::prelude::*;
+use gpui::{
+    App, Bounds, ElementId, IntoElement, PathBuilder, Rgba, Window, canvas, div, fill, hsla, point,
+    px, size,
+};
+use std::rc::Rc;
+
+/// Default sparkline width in pixels
+const DEFAULT_SPARK_WIDTH: f32 = 100.0;
+
+/// Default sparkline height in pixels
+const DEFAULT_SPARK_HEIGHT: f32 = 24.0;
+
+/// Callback type for sparkline hover events (receives the hovered index, if any)
+pub type SparkHoverCallback = Rc<dyn Fn(Option<usize>, &mut Window, &mut App)>;
+
+/// Settings shared by all sparkline kinds.
+#[derive(Clone)]
+struct SparkBase {
+    id: ElementId,
+    values: Vec<f64>,
+    color: u32,
+    width: f32,
+    height: f32,
+    show_min: bool,
+    show_max: bool,
+    show_last: bool,
+    marker_radius: f32,
+    hover_index: Option<usize>,
+    on_hover: Option<SparkHoverCallback>,
+    layout: ChartLayout,
+}
+
+impl SparkBase {
+    fn new(values: &[f64]) -> Self {
+        Self {
+            id: ElementId::from("sparkline"),
+            values: values.to_vec(),
+            color: DEFAULT_COLOR,
+            width: DEFAULT_SPARK_WIDTH,
+            height: DEFAULT_SPARK_HEIGHT,
+            show_min: false,
+            show_max: false,
+            show_last: false,
+            marker_radius: 2.0,
+            hover_index: None,
+            on_hover: None,
+            layout: ChartLayout::default(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ChartError> {
+        validate_data_array(&self.values, "values")?;
+        validate_dimensions(self.width, self.height)
+    }
+
+    /// Horizontal pixel position of sample `i`, centered in its slot for bars.
+    fn x_at(&self, i: usize, centered: bool) -> f32 {
+        let n = self.values.len();
+        if centered {
+            (i as f32 + 0.5) * self.width / n as f32
+        } else if n < 2 {
+            self.width / 2.0
+        } else {
+            let inset = self.marker_radius;
+            inset + i as f32 * (self.width - 2.0 * inset) / (n - 1) as f32
+        }
+    }
+}
+
+impl std::fmt::Debug for SparkBase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SparkBase")
+            .field("id", &self.id)
+            .field("values", &self.values.len())
+            .field("color", &self.color)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("hover_index", &self.hover_index)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Maps values to vertical pixels, keeping room for markers.
+#[derive(Debug, Clone, Copy)]
+struct SparkYScale {
+    min: f64,
+    max: f64,
+    top: f32,
+    bottom: f32,
+}
+
+impl SparkYScale {
+    fn new(values: &[f64], include_zero: bool, height: f32, inset: f32) -> Self {
+        let mut min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let mut max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if include_zero {
+            min = min.min(0.0);
+            max = max.max(0.0);
+        }
+        Self {
+            min,
+            max,
+            top: inset,
+            bottom: (height - inset).max(inset),
+        }
+    }
+
+    fn map(&self, value: f64) -> f32 {
+        let span = self.max - self.min;
+        if span.abs() < f64::EPSILON {
+            return (self.top + self.bottom) / 2.0;
+        }
+        let t = ((value - self.min) / span) as f32;
+        self.bottom - t * (self.bottom - self.top)
+    }
+}
+
+/// Index of the smallest and largest value (first occurrence).
+fn min_max_index(values: &[f64]) -> Option<(usize, usize)> {
+    let first = *values.first()?;
+    let (mut min_i, mut max_i) = (0, 0);
+    let (mut min, mut max) = (first, first);
+    for (i, &v) in values.iter().enumerate().skip(1) {
+        if v < min {
+            min = v;
+            min_i = i;
+        }
+        if v > max {
+            max = v;
+            max_i = i;
+        }
+    }
+    Some((min_i, max_i))
+}
+
+/// Shared setters for the sparkline builders.
+macro_rules! spark_common_methods {
+    () => {
+        chart_layout_methods!(base.layout, |chart: &Self| (
+            chart.base.width,
+            chart.base.height
+        ));
+
+        /// Set the element ID (default: `"sparkline"`).
+        ///
+        /// Sparklines with hover handling that share a parent, e.g. one per
+        /// table row, need distinct IDs.
+        pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+            self.base.id = id.into();
+            self
+        }
+
+        /// Set the series color as 24-bit RGB hex.
+        pub fn color(mut self, hex: u32) -> Self {
+            self.base.color = hex;
+            self
+        }
+
+        /// Set the size in pixels (default: 100 x 24).
+        pub fn size(mut self, width: f32, height: f32) -> Self {
+            self.base.width = width;
+            self.base.height = height;
+            self
+        }
+
+        /// Mark the minimum value.
+        pub fn show_min(mut self, show: bool) -> Self {
+            self.base.show_min = show;
+            self
+        }
+
+        /// Mark the maximum value.
+        pub fn show_max(mut self, show: bool) -> Self {
+            self.base.show_max = show;
+            self
+        }
+
+        /// Mark the last value.
+        pub fn show_last(mut self, show: bool) -> Self {
+            self.base.show_last = show;
+            self
+        }
+
+        /// Set the marker radius in pixels (default: 2.0).
+        pub fn marker_radius(mut self, radius: f32) -> Self {
+            self.base.marker_radius = radius.max(0.0);
+            self
+        }
+
+        /// Set the index to highlight with a value tooltip.
+        pub fn hover_index(mut self, index: Option<usize>) -> Self {
+            self.base.hover_index = index;
+            self
+        }
+
+        /// Set a callback for hover changes.
+        ///
+        /// Receives the index nearest to the mouse, or `None` when the mouse
+        /// leaves. Feed it back through `hover_index` to show the tooltip.
+        pub fn on_hover<F>(mut self, callback: F) -> Self
+        where
+            F: Fn(Option<usize>, &mut Window, &mut App) + 'static,
+        {
+            self.base.on_hover = Some(Rc::new(callback));
+            self
+        }
+    };
+}
+
+/// Line sparkline builder.
+#[derive(Debug, Clone)]
+pub struct Sparkline {
+    base: SparkBase,
+    stroke_width: f32,
+}
+
+/// Area sparkline builder.
+#[derive(Debug, Clone)]
+pub struct SparkArea {
+    base: SparkBase,
+    stroke_width: f32,
+    fill_opacity: f32,
+}
+
+/// Bar sparkline builder.
+#[derive(Debug, Clone)]
+pub struct SparkBar {
+    base: SparkBase,
+    negative_color: Option<u32>,
+    bar_gap: f32,
+}
+
+impl Sparkline {
+    spark_common_methods!();
+
+    /// Set the line width in pixels (default: 1.5).
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        self.base.validate()?;
+        let base = self.base;
+        let y_scale = SparkYScale::new(&base.values, false, base.height, base.marker_radius + 1.0);
+        let points: Vec<(f32, f32)> = (0..base.values.len())
+            .map(|i| (base.x_at(i, false), y_scale.map(base.values[i])))
+            .collect();
+        let color = D3Color::from_hex(base.color).to_rgba();
+        let stroke_width = self.stroke_width;
+
+        let line = move |origin: (f32, f32), window: &mut Window| {
+            paint_polyline(&points, origin, stroke_width, color, window);
+        };
+        Ok(render_spark(base, y_scale, false, line))
+    }
+}
+
+impl SparkArea {
+    spark_common_methods!();
+
+    /// Set the outline width in pixels (default: 1.0).
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Set the fill opacity (0.0 - 1.0, default: 0.3).
+    pub fn fill_opacity(mut self, opacity: f32) -> Self {
+        self.fill_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        self.base.validate()?;
+        let base = self.base;
+        let y_scale = SparkYScale::new(&base.values, false, base.height, base.marker_radius + 1.0);
+        let points: Vec<(f32, f32)> = (0..base.values.len())
+            .map(|i| (base.x_at(i, false), y_scale.map(base.values[i])))
+            .collect();
+        let color = D3Color::from_hex(base.color).to_rgba();
+        let fill_color = Rgba {
+            a: color.a * self.fill_opacity,
+            ..color
+        };
+        let stroke_width = self.stroke_width;
+        let baseline = base.height;
+
+        let area = move |(ox, oy): (f32, f32), window: &mut Window| {
+            if let (Some(first), Some(last)) = (points.first(), points.last()) {
+                let mut builder = PathBuilder::fill();
+                builder.move_to(point(px(ox + first.0), px(oy + baseline)));
+                for &(x, y) in &points {
+                    builder.line_to(point(px(ox + x), px(oy + y)));
+                }
+                builder.line_to(point(px(ox + last.0), px(oy + baseline)));
+                builder.close();
+                if let Ok(path) = builder.build() {
+                    window.paint_path(path, fill_color);
+                }
+            }
+            paint_polyline(&points, (ox, oy), stroke_width, color, window);
+        };
+        Ok(render_spark(base, y_scale, false, area))
+    }
+}
+
+impl SparkBar {
+    spark_common_methods!();
+
+    /// Set a distinct color for negative bars as 24-bit RGB hex.
+    pub fn negative_color(mut self, hex: u32) -> Self {
+        self.negative_color = Some(hex);
+        self
+    }
+
+    /// Set the gap between bars in pixels (default: 1.0).
+    pub fn bar_gap(mut self, gap: f32) -> Self {
+        self.bar_gap = gap.max(0.0);
+        self
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        self.base.validate()?;
+        let base = self.base;
+        let y_scale = SparkYScale::new(&base.values, true, base.height, 1.0);
+        let zero = y_scale.map(0.0);
+        let n = base.values.len().max(1);
+        let slot = base.width / n as f32;
+        let bar_width = (slot - self.bar_gap).max(1.0);
+        let positive = D3Color::from_hex(base.color).to_rgba();
+        let negative = D3Color::from_hex(self.negative_color.unwrap_or(base.color)).to_rgba();
+        let bars: Vec<(f32, f32, f32, Rgba)> = base
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                let y = y_scale.map(v);
+                let color = if v < 0.0 { negative } else { positive };
+                (
+                    i as f32 * slot + (slot - bar_width) / 2.0,
+                    y.min(zero),
+                    (y - zero).abs().max(1.0),
+                    color,
+                )
+            })
+            .collect();
+
+        let paint_bars = move |(ox, oy): (f32, f32), window: &mut Window| {
+            for &(x, y, h, color) in &bars {
+                window.paint_quad(fill(
+                    Bounds::new(point(px(ox + x), px(oy + y)), size(px(bar_width), px(h))),
+                    color,
+                ));
+            }
+        };
+        Ok(render_spark(base, y_scale, true, paint_bars))
+    }
+}
+
+/// Stroke a polyline through `points`, offset by `origin`.
+fn paint_polyline(
+    points: &[(f32, f32)],
+    (ox, oy): (f32, f32),
+    stroke_width: f32,
+    color: Rgba,
+    window: &mut Window,
+) {
+    let Some(first) = points.first() else {
+        return;
+    };
+    if points.len() < 2 {
+        return;
+    }
+    let mut builder = PathBuilder::stroke(px(stroke_width));
+    builder.move_to(point(px(ox + first.0), px(oy + first.1)));
+    for &(x, y) in &points[1..] {
+        builder.line_to(point(px(ox + x), px(oy + y)));
+    }
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// Wrap painted marks with markers, tooltip and hover handling.
+fn render_spark(
+    base: SparkBase,
+    y_scale: SparkYScale,
+    centered: bool,
+    paint_marks: impl Fn((f32, f32), &mut Window) + 'static,
+) -> impl IntoElement {
+    let spark_bounds = PlotBoundsCapture::new();
+    let spark_bounds_paint = spark_bounds.clone();
+
+    let mut container = div()
+        .id(base.id.clone())
+        .w(px(base.width))
+        .h(px(base.height))
+        .relative()
+        .flex_none()
+        .child(
+            canvas(
+                move |bounds, _, _| bounds,
+                move |_, bounds, window, _| {
+                    spark_bounds_paint.record(bounds);
+                    paint_marks((bounds.origin.x.into(), bounds.origin.y.into()), window);
+                },
+            )
+            .size_full()
+            .absolute()
+            .inset_0(),
+        );
+
+    // Min / max / last markers
+    let mut markers: Vec<(usize, u32)> = Vec::new();
+    if let Some((min_i, max_i)) = min_max_index(&base.values) {
+        if base.show_min {
+            markers.push((min_i, 0xd62728));
+        }
+        if base.show_max {
+            markers.push((max_i, 0x2ca02c));
+        }
+    }
+    if base.show_last && !base.values.is_empty() {
+        markers.push((base.values.len() - 1, base.color));
+    }
+    let r = base.marker_radius;
+    for (i, color) in markers {
+        let (x, y) = (base.x_at(i, centered), y_scale.map(base.values[i]));
+        container = container.child(
+            div()
+                .absolute()
+                .left(px(x - r))
+                .top(px(y - r))
+                .w(px(2.0 * r))
+                .h(px(2.0 * r))
+                .rounded_full()
+                .bg(D3Color::from_hex(color).to_rgba()),
+        );
+    }
+
+    // Hover tooltip
+    if let Some(index) = base.hover_index.filter(|&i| i < base.values.len()) {
+        let x = base.x_at(index, centered);
+        let mut tooltip = div()
+            .absolute()
+            .bottom(px(base.height + 2.0))
+            .px_1()
+            .bg(hsla(0.0, 0.0, 0.2, 0.85))
+            .rounded_sm()
+            .text_xs()
+            .text_color(hsla(0.0, 0.0, 1.0, 1.0))
+            .whitespace_nowrap()
+            .child(format!("{:.2}", base.values[index]));
+        tooltip = if x > base.width / 2.0 {
+            tooltip.right(px(base.width - x))
+        } else {
+            tooltip.left(px(x))
+        };
+        container = container
+            .child(
+                div()
+                    .absolute()
+                    .left(px(x))
+                    .top_0()
+                    .w_px()
+                    .h(px(base.height))
+                    .bg(hsla(0.0, 0.0, 0.3, 0.6)),
+            )
+            .child(tooltip);
+    }
+
+    if let Some(on_hover) = base.on_hover.clone() {
+        let on_leave = on_hover.clone();
+        let current = base.hover_index;
+        let n = base.values.len();
+        let width = base.width;
+        let inset = if centered { 0.0 } else { base.marker_radius };
+        container = container
+            .on_mouse_move(move |event, window, cx| {
+                if !spark_bounds.contains(event.position) || n == 0 {
+                    return;
+                }
+                let Some((x, _)) = spark_bounds.to_local(event.position) else {
+                    return;
+                };
+                let x = x as f32;
+                let index = if centered {
+                    (x / width * n as f32).floor() as usize
+                } else if n < 2 {
+                    0
+                } else {
+                    ((x - inset) / (width - 2.0 * inset) * (n - 1) as f32).round() as usize
+                };
+                let index = Some(index.min(n - 1));
+                if index != current {
+                    on_hover(index, window, cx);
+                }
+            })
+            .on_hover(move |hovered, window, cx| {
+                if !*hovered {
+                    on_leave(None, window, cx);
+                }
+            });
+    }
+
+    container
+}
+
+/// Create a line sparkline.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use gpui_px::sparkline;
+///
+/// let spark = sparkline(&[3.0, 5.0, 2.0, 8.0, 6.0])
+///     .show_last(true)
+///     .build()?;
+/// # Ok::<(), gpui_px::ChartError>(())
+/// ```
+pub fn sparkline(values: &[f64]) -> Sparkline {
+    Sparkline {
+        base: SparkBase::new(values),
+        stroke_width: 1.5,
+    }
+}
+
+/// Create an area sparkline.
+pub fn spark_area(values: &[f64]) -> SparkArea {
+    SparkArea {
+        base: SparkBase::new(values),
+        stroke_width: 1.0,
+        fill_opacity: 0.3,
+    }
+}
+
+/// Create a bar sparkline; bars grow up or down from zero.
+pub fn spark_bar(values: &[f64]) -> SparkBar {
+    SparkBar {
+        base: SparkBase::new(values),
+        negative_color: None,
+        bar_gap: 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline_build() {
+        let values = [1.0, 3.0, 2.0, 5.0, 4.0];
+        assert!(
+            sparkline(&values)
+                .show_min(true)
+                .show_max(true)
+                .show_last(true)
+                .build()
+                .is_ok()
+        );
+        assert!(spark_area(&values).fill_opacity(0.5).build().is_ok());
+        assert!(
+            spark_bar(&[1.0, -2.0, 3.0])
+                .negative_color(0xd62728)
+                .hover_index(Some(1))
+                .on_hover(|_index, _window, _cx| {})
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_sparkline_validation() {
+        assert!(matches!(
+            sparkline(&[]).build(),
+            Err(ChartError::EmptyData { .. })
+        ));
+        assert!(matches!(
+            spark_bar(&[1.0, f64::NAN]).build(),
+            Err(ChartError::InvalidData { .. })
+        ));
+        assert!(matches!(
+            spark_area(&[1.0]).size(0.0, 10.0).build(),
+            Err(ChartError::InvalidDimension { .. })
+        ));
+    }
+
+    #[test]
+    fn test_sparkline_id() {
+        assert_eq!(sparkline(&[1.0]).base.id, ElementId::from("sparkline"));
+        let id = ElementId::NamedInteger("row-spark".into(), 3);
+        let spark = spark_bar(&[1.0, 2.0]).id(id.clone());
+        assert_eq!(spark.base.id, id);
+        assert!(spark.build().is_ok());
+        assert!(spark_area(&[1.0, 2.0]).id("area").build().is_ok());
+    }
+
+    #[test]
+    fn test_min_max_index() {
+        assert_eq!(min_max_index(&[]), None);
+        assert_eq!(min_max_index(&[2.0, 1.0, 5.0, 1.0]), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_spark_y_scale() {
+        let scale = SparkYScale::new(&[2.0, 4.0], false, 24.0, 2.0);
+        assert_eq!(scale.map(4.0), 2.0);
+        assert_eq!(scale.map(2.0), 22.0);
+
+        let bars = SparkYScale::new(&[2.0, 4.0], true, 20.0, 0.0);
+        assert_eq!(bars.map(0.0), 20.0);
+
+        let flat = SparkYScale::new(&[3.0, 3.0], false, 20.0, 0.0);
+        assert_eq!(flat.map(3.0), 10.0);
+    }
+}
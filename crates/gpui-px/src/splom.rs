@@ -0,0 +1,673 @@
+//! Scatter plot matrix (SPLOM) - Plotly Express style API.
+//!
+//! An n×n grid of scatter plots comparing every pair of columns, with a
+//! histogram of each column on the diagonal.
+
+use crate::error::ChartError;
+use crate::interaction::PlotBoundsCapture;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::theme::ChartTheme;
+use crate::{
+    ChartDefaults, DEFAULT_COLOR, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE,
+    TITLE_AREA_HEIGHT, extent_padded, validate_data_array, validate_data_length,
+    validate_dimensions,
+};
+use d3rs::array::bin::BinGenerator;
+use d3rs::axis::{AxisConfig, render_axis};
+use d3rs::brush::DomainSelection;
+use d3rs::color::D3Color;
+use d3rs::scale::{LinearScale, Scale};
+use d3rs::shape::{ScatterConfig, ScatterPoint, render_scatter};
+use d3rs::text::{VectorFontConfig, render_vector_text};
+use gpui::prelude::*;
+use gpui::{App, ElementId, IntoElement, MouseButton, Pixels, Window, div, hsla, px, rgb};
+use std::rc::Rc;
+
+/// Minimum brush size in pixels; smaller drags clear the selection
+const MIN_BRUSH_SIZE: f32 = 3.0;
+
+/// Gap between panels in pixels
+const PANEL_GAP: f32 = 4.0;
+
+/// A rectangular brush drawn in one SPLOM panel, in data coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplomBrush {
+    /// Column index plotted on the panel's X axis
+    pub x_column: usize,
+    /// Column index plotted on the panel's Y axis
+    pub y_column: usize,
+    /// Corner where the drag started
+    pub anchor: (f64, f64),
+    /// Corner under the cursor
+    pub cursor: (f64, f64),
+}
+
+impl SplomBrush {
+    /// The brushed region as a normalized domain selection.
+    pub fn selection(&self) -> DomainSelection {
+        DomainSelection::new(self.anchor.0, self.anchor.1, self.cursor.0, self.cursor.1)
+    }
+
+    /// Row indices of `data` whose values fall inside the brush.
+    pub fn selected_rows(&self, data: &[Vec<f64>]) -> Vec<usize> {
+        let sel = self.selection();
+        let (Some(xs), Some(ys)) = (data.get(self.x_column), data.get(self.y_column)) else {
+            return Vec::new();
+        };
+        xs.iter()
+            .zip(ys)
+            .enumerate()
+            .filter(|(_, (x, y))| (sel.x0..=sel.x1).contains(*x) && (sel.y0..=sel.y1).contains(*y))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Callback type for brush changes (receives the new brush, if any)
+pub type SplomBrushCallback = Rc<dyn Fn(Option<SplomBrush>, &mut Window, &mut App)>;
+
+/// Scatter plot matrix builder.
+#[derive(Clone)]
+pub struct SplomChart {
+    data: Vec<Vec<f64>>,
+    columns: Vec<String>,
+    title: Option<String>,
+    color: u32,
+    selected_color: u32,
+    point_radius: f32,
+    opacity: f32,
+    bins: usize,
+    width: f32,
+    height: f32,
+    brush: Option<SplomBrush>,
+    on_brush: Option<SplomBrushCallback>,
+    layout: ChartLayout,
+}
+
+impl std::fmt::Debug for SplomChart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SplomChart")
+            .field("columns", &self.columns)
+            .field("rows", &self.data.first().map_or(0, Vec::len))
+            .field("title", &self.title)
+            .field("bins", &self.bins)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("brush", &self.brush)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SplomChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
+    /// Set the chart title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the point color as 24-bit RGB hex.
+    pub fn color(mut self, hex: u32) -> Self {
+        self.color = hex;
+        self
+    }
+
+    /// Set the color of brushed points as 24-bit RGB hex (default: orange).
+    pub fn selected_color(mut self, hex: u32) -> Self {
+        self.selected_color = hex;
+        self
+    }
+
+    /// Set the point radius in pixels (default: 2.0).
+    pub fn point_radius(mut self, radius: f32) -> Self {
+        self.point_radius = radius;
+        self
+    }
+
+    /// Set point opacity (0.0 - 1.0).
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the number of histogram bins on the diagonal (default: 10).
+    pub fn bins(mut self, bins: usize) -> Self {
+        self.bins = bins;
+        self
+    }
+
+    /// Set chart dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set the active brush.
+    ///
+    /// Rows inside the brush are highlighted in every panel.
+    pub fn brush(mut self, brush: Option<SplomBrush>) -> Self {
+        self.brush = brush;
+        self
+    }
+
+    /// Enable brushing with a callback receiving brush updates.
+    ///
+    /// Dragging in any off-diagonal panel reports a new brush on every mouse
+    /// move; a click without dragging reports `None`. Feed the brush back
+    /// through `brush` and re-render to link the selection across panels.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let chart = splom(&data, &["a", "b", "c"])
+    ///     .brush(self.brush)
+    ///     .on_brush(cx.listener(|this, brush: Option<SplomBrush>, _window, cx| {
+    ///         this.brush = brush;
+    ///         cx.notify();
+    ///     }))
+    ///     .build()?;
+    /// ```
+    pub fn on_brush<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Option<SplomBrush>, &mut Window, &mut App) + 'static,
+    {
+        self.on_brush = Some(Rc::new(callback));
+        self
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        validate_dimensions(self.width, self.height)?;
+        if self.data.is_empty() {
+            return Err(ChartError::EmptyData { field: "data" });
+        }
+        if self.columns.len() != self.data.len() {
+            return Err(ChartError::DataLengthMismatch {
+                x_field: "columns",
+                y_field: "data",
+                x_len: self.columns.len(),
+                y_len: self.data.len(),
+            });
+        }
+        for column in &self.data {
+            validate_data_array(column, "data")?;
+            validate_data_length(column.len(), self.data[0].len(), "data", "data")?;
+        }
+        if self.bins == 0 {
+            return Err(ChartError::InvalidData {
+                field: "bins",
+                reason: "must be at least 1",
+            });
+        }
+
+        let n = self.data.len();
+        let axis_theme = ChartTheme::current().axis_theme();
+        let left_axis = compact_axis(AxisConfig::left());
+        let bottom_axis = compact_axis(AxisConfig::bottom());
+
+        let title_height = if self.title.is_some() {
+            TITLE_AREA_HEIGHT
+        } else {
+            0.0
+        };
+        let grid_width = (self.width - left_axis.total_size() - 10.0).max(0.0);
+        let grid_height = (self.height - title_height - bottom_axis.total_size() - 10.0).max(0.0);
+        let panel_width = ((grid_width - PANEL_GAP * (n - 1) as f32) / n as f32).max(1.0);
+        let panel_height = ((grid_height - PANEL_GAP * (n - 1) as f32) / n as f32).max(1.0);
+
+        let extents: Vec<(f64, f64)> = self
+            .data
+            .iter()
+            .map(|column| extent_padded(column, DEFAULT_PADDING_FRACTION))
+            .collect();
+        let x_scales: Vec<LinearScale> = extents
+            .iter()
+            .map(|&(min, max)| {
+                LinearScale::new()
+                    .domain(min, max)
+                    .range(0.0, panel_width as f64)
+            })
+            .collect();
+        let y_scales: Vec<LinearScale> = extents
+            .iter()
+            .map(|&(min, max)| {
+                LinearScale::new()
+                    .domain(min, max)
+                    .range(panel_height as f64, 0.0)
+            })
+            .collect();
+
+        let selected: Option<Vec<bool>> = self.brush.map(|brush| {
+            let mut mask = vec![false; self.data[0].len()];
+            for row in brush.selected_rows(&self.data) {
+                mask[row] = true;
+            }
+            mask
+        });
+
+        let base_config = ScatterConfig::new()
+            .fill_color(D3Color::from_hex(if selected.is_some() {
+                0xbbbbbb
+            } else {
+                self.color
+            }))
+            .point_radius(self.point_radius)
+            .opacity(self.opacity);
+        let selected_config = ScatterConfig::new()
+            .fill_color(D3Color::from_hex(self.selected_color))
+            .point_radius(self.point_radius)
+            .opacity(self.opacity);
+
+        let mut grid = div().flex().flex_col().gap(px(PANEL_GAP));
+        for row in 0..n {
+            let mut grid_row = div().flex().gap(px(PANEL_GAP));
+            for col in 0..n {
+                let mut panel = div()
+                    .id(ElementId::NamedInteger(
+                        "splom-panel".into(),
+                        (row * n + col) as u64,
+                    ))
+                    .w(px(panel_width))
+                    .h(px(panel_height))
+                    .relative()
+                    .overflow_hidden()
+                    .bg(rgb(0xffffff))
+                    .border_1()
+                    .border_color(rgb(0xdddddd));
+
+                if row == col {
+                    panel = panel.child(self.render_histogram(
+                        col,
+                        &x_scales[col],
+                        panel_height,
+                        selected.as_deref(),
+                    ));
+                } else {
+                    // Column `col` on X, column `row` on Y
+                    let xs = &self.data[col];
+                    let ys = &self.data[row];
+                    let (base, highlighted): (Vec<_>, Vec<_>) = xs
+                        .iter()
+                        .zip(ys)
+                        .enumerate()
+                        .map(|(i, (&x, &y))| (i, ScatterPoint::new(x, y)))
+                        .partition(|(i, _)| selected.as_ref().is_none_or(|mask| !mask[*i]));
+                    let base: Vec<ScatterPoint> = base.into_iter().map(|(_, p)| p).collect();
+                    let highlighted: Vec<ScatterPoint> =
+                        highlighted.into_iter().map(|(_, p)| p).collect();
+
+                    panel = panel
+                        .child(render_scatter(
+                            &x_scales[col],
+                            &y_scales[row],
+                            &base,
+                            &base_config,
+                        ))
+                        .child(render_scatter(
+                            &x_scales[col],
+                            &y_scales[row],
+                            &highlighted,
+                            &selected_config,
+                        ));
+
+                    if let Some(brush) = self
+                        .brush
+                        .filter(|b| b.x_column == col && b.y_column == row)
+                    {
+                        let sel = brush.selection();
+                        let x0 = x_scales[col].scale(sel.x0) as f32;
+                        let x1 = x_scales[col].scale(sel.x1) as f32;
+                        let y0 = y_scales[row].scale(sel.y1) as f32;
+                        let y1 = y_scales[row].scale(sel.y0) as f32;
+                        panel = panel.child(
+                            div()
+                                .absolute()
+                                .left(px(x0))
+                                .top(px(y0))
+                                .w(px(x1 - x0))
+                                .h(px(y1 - y0))
+                                .bg(gpui::rgba(0x6496c850))
+                                .border_1()
+                                .border_color(rgb(0x4682b4)),
+                        );
+                    }
+
+                    if let Some(on_brush) = self.on_brush.clone() {
+                        panel = attach_brush_handlers(
+                            panel,
+                            on_brush,
+                            self.brush,
+                            (col, row),
+                            x_scales[col],
+                            y_scales[row],
+                        );
+                    }
+                }
+
+                // Left column and bottom row carry compact axes
+                let mut cell = div().flex().flex_col().child(panel);
+                if row == n - 1 {
+                    cell = cell.child(render_axis(
+                        &x_scales[col],
+                        &bottom_axis.clone().with_title(&self.columns[col]),
+                        panel_width,
+                        &axis_theme,
+                    ));
+                }
+                let mut cell_row = div().flex();
+                if col == 0 {
+                    cell_row = cell_row.child(render_axis(
+                        &y_scales[row],
+                        &left_axis.clone().with_title(&self.columns[row]),
+                        panel_height,
+                        &axis_theme,
+                    ));
+                }
+                grid_row = grid_row.child(cell_row.child(cell));
+            }
+            grid = grid.child(grid_row);
+        }
+
+        let mut container = div()
+            .w(px(self.width))
+            .h(px(self.height))
+            .flex()
+            .flex_col()
+            .bg(rgb(0xffffff));
+
+        if let Some(title) = &self.title {
+            let font_config =
+                VectorFontConfig::horizontal(DEFAULT_TITLE_FONT_SIZE, hsla(0.0, 0.0, 0.2, 1.0));
+            container = container.child(
+                div()
+                    .w_full()
+                    .h(px(title_height))
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .child(render_vector_text(title, &font_config)),
+            );
+        }
+
+        Ok(container.child(div().flex().justify_center().child(grid)))
+    }
+
+    /// Render the histogram of one column for a diagonal panel.
+    fn render_histogram(
+        &self,
+        column: usize,
+        x_scale: &LinearScale,
+        panel_height: f32,
+        selected: Option<&[bool]>,
+    ) -> impl IntoElement {
+        let (min, max) = (x_scale.domain_min(), x_scale.domain_max());
+        let rows: Vec<usize> = (0..self.data[column].len()).collect();
+        let values = &self.data[column];
+        let bins = BinGenerator::new()
+            .value(move |&(value, _): &(f64, usize)| value)
+            .domain(min, max)
+            .thresholds_count(self.bins)
+            .generate(
+                &rows
+                    .iter()
+                    .map(|&i| (values[i], i))
+                    .collect::<Vec<(f64, usize)>>(),
+            );
+        let max_count = bins.iter().map(|b| b.len()).max().unwrap_or(0).max(1) as f32;
+
+        let label_config = VectorFontConfig::horizontal(10.0, hsla(0.0, 0.0, 0.3, 1.0));
+        let mut histogram = div().absolute().inset_0().child(
+            div()
+                .absolute()
+                .left(px(4.0))
+                .top(px(2.0))
+                .child(render_vector_text(&self.columns[column], &label_config)),
+        );
+
+        for bin in &bins {
+            let left = x_scale.scale(bin.x0) as f32;
+            let width = (x_scale.scale(bin.x1) as f32 - left - 1.0).max(0.0);
+            let total_height = bin.len() as f32 / max_count * (panel_height - 16.0);
+            let selected_count = selected.map_or(0, |mask| {
+                bin.values.iter().filter(|(_, i)| mask[*i]).count()
+            });
+            let selected_height = selected_count as f32 / max_count * (panel_height - 16.0);
+            let base_color = if selected.is_some() {
+                rgb(0xbbbbbb)
+            } else {
+                rgb(self.color)
+            };
+
+            histogram = histogram
+                .child(
+                    div()
+                        .absolute()
+                        .left(px(left))
+                        .bottom_0()
+                        .w(px(width))
+                        .h(px(total_height))
+                        .bg(base_color),
+                )
+                .child(
+                    div()
+                        .absolute()
+                        .left(px(left))
+                        .bottom_0()
+                        .w(px(width))
+                        .h(px(selected_height))
+                        .bg(rgb(self.selected_color)),
+                );
+        }
+
+        histogram
+    }
+}
+
+/// Axis config with few ticks and small labels for the matrix edges.
+fn compact_axis(config: AxisConfig) -> AxisConfig {
+    config
+        .with_ticks(3)
+        .with_label_font_size(9.0)
+        .with_tick_size(3.0)
+        .with_title_font_size(10.0)
+}
+
+/// Wire mouse handlers that draw a brush in one panel.
+fn attach_brush_handlers(
+    panel: gpui::Stateful<gpui::Div>,
+    on_brush: SplomBrushCallback,
+    current: Option<SplomBrush>,
+    (x_column, y_column): (usize, usize),
+    x_scale: LinearScale,
+    y_scale: LinearScale,
+) -> gpui::Stateful<gpui::Div> {
+    let panel_bounds = PlotBoundsCapture::new();
+    let capture = panel_bounds.canvas();
+    let (x_scale_data, y_scale_data) = (x_scale, y_scale);
+    let to_data = move |position: gpui::Point<Pixels>| {
+        let (x, y) = panel_bounds.to_local(position)?;
+        Some((x_scale_data.invert(x)?, y_scale_data.invert(y)?))
+    };
+    let to_data = Rc::new(to_data);
+    let active = current.filter(|b| b.x_column == x_column && b.y_column == y_column);
+
+    let on_down = on_brush.clone();
+    let to_data_down = to_data.clone();
+    let on_move = on_brush.clone();
+    let to_data_move = to_data.clone();
+
+    panel
+        .child(capture)
+        .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+            if let Some(point) = to_data_down(event.position) {
+                on_down(
+                    Some(SplomBrush {
+                        x_column,
+                        y_column,
+                        anchor: point,
+                        cursor: point,
+                    }),
+                    window,
+                    cx,
+                );
+            }
+        })
+        .on_mouse_move(move |event, window, cx| {
+            let Some(brush) = active else {
+                return;
+            };
+            if event.pressed_button != Some(MouseButton::Left) {
+                return;
+            }
+            if let Some(point) = to_data_move(event.position)
+                && point != brush.cursor
+            {
+                on_move(
+                    Some(SplomBrush {
+                        cursor: point,
+                        ..brush
+                    }),
+                    window,
+                    cx,
+                );
+            }
+        })
+        .on_mouse_up(MouseButton::Left, move |_event, window, cx| {
+            if let Some(brush) = active
+                && is_trivial_brush(&brush, &x_scale, &y_scale)
+            {
+                on_brush(None, window, cx);
+            }
+        })
+}
+
+/// Whether a brush spans less than `MIN_BRUSH_SIZE` pixels in both directions.
+fn is_trivial_brush(brush: &SplomBrush, x_scale: &LinearScale, y_scale: &LinearScale) -> bool {
+    let dx = (x_scale.scale(brush.anchor.0) - x_scale.scale(brush.cursor.0)).abs();
+    let dy = (y_scale.scale(brush.anchor.1) - y_scale.scale(brush.cursor.1)).abs();
+    dx < MIN_BRUSH_SIZE as f64 && dy < MIN_BRUSH_SIZE as f64
+}
+
+/// Create a scatter plot matrix from column-major data.
+///
+/// `data[i]` holds the values of column `i`, named `columns[i]`; all columns
+/// must have the same length.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use gpui_px::splom;
+///
+/// let data = vec![
+///     vec![5.1, 4.9, 6.2, 5.9],
+///     vec![3.5, 3.0, 2.9, 3.0],
+///     vec![1.4, 1.4, 4.3, 5.1],
+/// ];
+/// let chart = splom(&data, &["sepal length", "sepal width", "petal length"])
+///     .title("Iris")
+///     .build()?;
+/// # Ok::<(), gpui_px::ChartError>(())
+/// ```
+pub fn splom(data: &[Vec<f64>], columns: &[&str]) -> SplomChart {
+    let defaults = ChartDefaults::current();
+    SplomChart {
+        data: data.to_vec(),
+        columns: columns.iter().map(|c| c.to_string()).collect(),
+        title: None,
+        color: DEFAULT_COLOR,
+        selected_color: 0xff7f0e,
+        point_radius: 2.0,
+        opacity: 0.7,
+        bins: 10,
+        width: defaults.width,
+        height: defaults.height,
+        brush: None,
+        on_brush: None,
+        layout: ChartLayout::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Vec<f64>> {
+        vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![2.0, 4.0, 1.0, 3.0, 5.0],
+            vec![0.5, 0.1, 0.9, 0.3, 0.7],
+        ]
+    }
+
+    #[test]
+    fn test_splom_build() {
+        let result = splom(&sample(), &["a", "b", "c"])
+            .title("Matrix")
+            .bins(5)
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_splom_column_mismatch() {
+        let result = splom(&sample(), &["a", "b"]).build();
+        assert!(matches!(result, Err(ChartError::DataLengthMismatch { .. })));
+
+        let mut data = sample();
+        data[1].pop();
+        let result = splom(&data, &["a", "b", "c"]).build();
+        assert!(matches!(result, Err(ChartError::DataLengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_splom_invalid() {
+        let result = splom(&[], &[]).build();
+        assert!(matches!(result, Err(ChartError::EmptyData { .. })));
+
+        let result = splom(&sample(), &["a", "b", "c"]).bins(0).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData { field: "bins", .. })
+        ));
+
+        let mut data = sample();
+        data[2][0] = f64::NAN;
+        let result = splom(&data, &["a", "b", "c"]).build();
+        assert!(matches!(result, Err(ChartError::InvalidData { .. })));
+    }
+
+    #[test]
+    fn test_splom_brush_selection() {
+        let brush = SplomBrush {
+            x_column: 0,
+            y_column: 1,
+            anchor: (4.5, 5.5),
+            cursor: (1.5, 2.5),
+        };
+        let sel = brush.selection();
+        assert_eq!((sel.x0, sel.x1), (1.5, 4.5));
+        assert_eq!(brush.selected_rows(&sample()), vec![1, 3]);
+
+        let result = splom(&sample(), &["a", "b", "c"])
+            .brush(Some(brush))
+            .on_brush(|_brush, _window, _cx| {})
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_is_trivial_brush() {
+        let x_scale = LinearScale::new().domain(0.0, 10.0).range(0.0, 100.0);
+        let y_scale = LinearScale::new().domain(0.0, 10.0).range(100.0, 0.0);
+        let mut brush = SplomBrush {
+            x_column: 0,
+            y_column: 1,
+            anchor: (1.0, 1.0),
+            cursor: (1.1, 1.1),
+        };
+        assert!(is_trivial_brush(&brush, &x_scale, &y_scale));
+        brush.cursor = (3.0, 1.1);
+        assert!(!is_trivial_brush(&brush, &x_scale, &y_scale));
+    }
+}
@@ -1,17 +1,28 @@
 //! 3D Surface chart.
 
 use crate::error::ChartError;
+use crate::interaction::PlotBoundsCapture;
+use crate::layout::{ChartLayout, chart_layout_methods};
 use crate::{
-    DEFAULT_HEIGHT, DEFAULT_TITLE_FONT_SIZE, DEFAULT_WIDTH, TITLE_AREA_HEIGHT, validate_data_array,
+    ChartDefaults, DEFAULT_TITLE_FONT_SIZE, TITLE_AREA_HEIGHT, validate_data_array,
     validate_dimensions, validate_grid_dimensions, validate_monotonic, validate_positive,
 };
-use d3rs::gpu3d::{Colormap, Surface3DConfig, Surface3DElement, Surface3DState, SurfaceData};
+use d3rs::gpu3d::{
+    Colormap, Surface3DConfig, Surface3DElement, Surface3DState, SurfaceData, SurfacePick,
+};
 use d3rs::text::{VectorFontConfig, render_vector_text};
 use gpui::prelude::*;
-use gpui::{IntoElement, div, hsla, px};
+use gpui::{App, IntoElement, Window, div, hsla, px};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Callback for picking: receives the grid point under the cursor, or `None`
+/// when the cursor leaves the surface.
+pub type SurfacePickCallback = Rc<dyn Fn(Option<SurfacePick>, &mut Window, &mut App)>;
+
+/// Screen distance in pixels within which a grid point can be picked
+const PICK_RADIUS: f32 = 12.0;
+
 /// Surface 3D chart builder.
 #[derive(Clone)]
 pub struct Surface3DChart {
@@ -32,8 +43,17 @@ pub struct Surface3DChart {
     x_label: Option<String>,
     y_label: Option<String>,
     z_label: Option<String>,
+    x_unit: Option<String>,
+    y_unit: Option<String>,
+    z_unit: Option<String>,
+    show_colorbar: bool,
+    /// Picked point to mark on the surface
+    picked: Option<SurfacePick>,
+    /// Callback when the point under the cursor changes
+    on_pick: Option<SurfacePickCallback>,
     /// External state for camera/interaction control
     external_state: Option<Rc<RefCell<Surface3DState>>>,
+    layout: ChartLayout,
 }
 
 impl std::fmt::Debug for Surface3DChart {
@@ -46,11 +66,15 @@ impl std::fmt::Debug for Surface3DChart {
             .field("wireframe", &self.wireframe)
             .field("width", &self.width)
             .field("height", &self.height)
+            .field("show_colorbar", &self.show_colorbar)
+            .field("picked", &self.picked)
             .finish()
     }
 }
 
 impl Surface3DChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
     /// Set custom x axis values.
     ///
     /// Values must be strictly monotonically increasing.
@@ -131,6 +155,70 @@ impl Surface3DChart {
         self
     }
 
+    /// Set X-axis unit, appended to tick labels and to the axis title.
+    pub fn x_unit(mut self, unit: impl Into<String>) -> Self {
+        self.x_unit = Some(unit.into());
+        self
+    }
+
+    /// Set Y-axis unit (defaults to degrees), appended to tick labels and to
+    /// the axis title.
+    pub fn y_unit(mut self, unit: impl Into<String>) -> Self {
+        self.y_unit = Some(unit.into());
+        self
+    }
+
+    /// Set Z-axis unit (defaults to dB), appended to tick labels, the axis
+    /// title and the colorbar.
+    pub fn z_unit(mut self, unit: impl Into<String>) -> Self {
+        self.z_unit = Some(unit.into());
+        self
+    }
+
+    /// Show the colorbar legend mapping colors to Z values (default: true).
+    ///
+    /// The Z label, if any, is the colorbar title.
+    pub fn colorbar(mut self, show: bool) -> Self {
+        self.show_colorbar = show;
+        self
+    }
+
+    /// Mark a picked point with a dot and its values.
+    ///
+    /// Usually the point last reported by [`on_pick`](Self::on_pick), kept
+    /// in the parent view.
+    pub fn picked(mut self, pick: Option<SurfacePick>) -> Self {
+        self.picked = pick;
+        self
+    }
+
+    /// Set callback invoked with the grid point under the cursor.
+    ///
+    /// The grid point closest to the camera within a few pixels of the
+    /// cursor is reported; `None` when there is none or the cursor leaves
+    /// the chart. The callback is only called when the point changes.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let view = cx.entity().downgrade();
+    /// surface3d(&z, 3, 3)
+    ///     .picked(self.picked)
+    ///     .on_pick(move |pick, _window, cx| {
+    ///         view.update(cx, |this, cx| {
+    ///             this.picked = pick;
+    ///             cx.notify();
+    ///         })
+    ///         .ok();
+    ///     })
+    /// ```
+    pub fn on_pick<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Option<SurfacePick>, &mut Window, &mut App) + 'static,
+    {
+        self.on_pick = Some(Rc::new(callback));
+        self
+    }
+
     /// Set external state for camera/interaction control.
     ///
     /// When external state is provided, mouse interaction handlers on the parent
@@ -140,8 +228,8 @@ impl Surface3DChart {
         self
     }
 
-    /// Build and validate the chart, returning renderable element.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
         // Validate inputs
         validate_data_array(&self.z, "z")?;
         validate_grid_dimensions(&self.z, self.grid_width, self.grid_height)?;
@@ -219,6 +307,15 @@ impl Surface3DChart {
         if let Some(label) = self.z_label {
             data = data.with_z_label(label);
         }
+        if let Some(unit) = self.x_unit {
+            data = data.with_x_unit(unit);
+        }
+        if let Some(unit) = self.y_unit {
+            data = data.with_y_unit(unit);
+        }
+        if let Some(unit) = self.z_unit {
+            data = data.with_z_unit(unit);
+        }
         data = data.with_log_x(self.x_log).with_log_y(self.y_log);
         if let (Some(min), Some(max)) = (self.z_min, self.z_max) {
             data = data.with_z_range(min, max);
@@ -227,7 +324,8 @@ impl Surface3DChart {
         // Create Surface3DConfig
         let config = Surface3DConfig::new()
             .colormap(self.colormap)
-            .wireframe(self.wireframe);
+            .wireframe(self.wireframe)
+            .show_colorbar(self.show_colorbar);
 
         // Build container with optional title
         let mut container = div()
@@ -252,21 +350,64 @@ impl Surface3DChart {
             );
         }
 
-        // Add surface element with optional external state
-        let element = Surface3DElement::new(data, config);
-        let element = if let Some(state) = self.external_state {
-            element.with_state(state)
-        } else {
-            element
-        };
+        // Picking reads the camera, so the element state is shared with the
+        // mouse handlers
+        let state = self.external_state.unwrap_or_else(|| {
+            Rc::new(RefCell::new(Surface3DState::new(
+                config.camera_distance,
+                config.camera_azimuth,
+                config.camera_elevation,
+            )))
+        });
+        let pick_data = self.on_pick.as_ref().map(|_| data.clone());
+        let plot_type = config.plot_type;
+        let element = Surface3DElement::new(data, config)
+            .with_state(state.clone())
+            .with_picked(self.picked);
+
+        let mut plot_area = div()
+            .id("surface3d-plot")
+            .w(px(self.width))
+            .h(px(plot_height))
+            .relative()
+            .child(element);
+
+        if let (Some(on_pick), Some(pick_data)) = (self.on_pick, pick_data) {
+            let plot_bounds = PlotBoundsCapture::new();
+            let on_leave = on_pick.clone();
+            let current = self.picked;
+            plot_area = plot_area
+                .child(plot_bounds.canvas())
+                .on_mouse_move(move |event, window, cx| {
+                    let Some(bounds) = plot_bounds.bounds() else {
+                        return;
+                    };
+                    let pick = if plot_bounds.contains(event.position)
+                        && let Some((x, y)) = plot_bounds.to_local(event.position)
+                    {
+                        pick_data.pick(
+                            &state.borrow().camera,
+                            plot_type,
+                            (x as f32, y as f32),
+                            bounds.size.width.into(),
+                            bounds.size.height.into(),
+                            PICK_RADIUS,
+                        )
+                    } else {
+                        None
+                    };
+                    if pick != current {
+                        on_pick(pick, window, cx);
+                    }
+                })
+                .on_hover(move |hovered, window, cx| {
+                    if !*hovered && current.is_some() {
+                        on_leave(None, window, cx);
+                    }
+                });
+        }
 
-        container = container.child(
-            div()
-                .w(px(self.width))
-                .h(px(plot_height))
-                .relative()
-                .child(element),
-        );
+        container = container.child(plot_area);
 
         Ok(container)
     }
@@ -296,6 +437,7 @@ impl Surface3DChart {
 /// # Ok::<(), gpui_px::ChartError>(())
 /// ```
 pub fn surface3d(z: &[f64], grid_width: usize, grid_height: usize) -> Surface3DChart {
+    let defaults = ChartDefaults::current();
     Surface3DChart {
         z: z.to_vec(),
         grid_width,
@@ -305,8 +447,8 @@ pub fn surface3d(z: &[f64], grid_width: usize, grid_height: usize) -> Surface3DC
         title: None,
         colormap: Colormap::Viridis,
         wireframe: false,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        width: defaults.width,
+        height: defaults.height,
         x_log: false,
         y_log: false,
         z_min: None,
@@ -314,6 +456,13 @@ pub fn surface3d(z: &[f64], grid_width: usize, grid_height: usize) -> Surface3DC
         x_label: None,
         y_label: None,
         z_label: None,
+        x_unit: None,
+        y_unit: None,
+        z_unit: None,
+        show_colorbar: true,
+        picked: None,
+        on_pick: None,
         external_state: None,
+        layout: ChartLayout::default(),
     }
 }
@@ -0,0 +1,176 @@
+//! Chart colors and the bridge from the gpui-ui-kit theme.
+//!
+//! Charts are built without access to the app, so they read their default
+//! colors from the chart theme of the current thread. [`init`] keeps that
+//! theme in sync with the global [`ThemeState`], so charts follow the app's
+//! light/dark mode (and fade with it during theme transitions):
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     Application::new().run(|cx: &mut App| {
+//!         cx.set_global(ThemeState::with_variant(ThemeVariant::Light));
+//!         gpui_px::init(cx);
+//!         // ...
+//!     });
+//! }
+//! ```
+//!
+//! A theme passed to a chart with `.theme(...)` always takes precedence.
+
+use crate::DEFAULT_TITLE_FONT_SIZE;
+use crate::defaults::ChartDefaults;
+use d3rs::axis::AxisTheme;
+use gpui::{App, Rgba, rgb};
+use gpui_ui_kit::{Theme, ThemeExt, ThemeState};
+use std::cell::RefCell;
+
+thread_local! {
+    /// Chart theme mirrored from the app theme by [`init`]
+    static CURRENT: RefCell<Option<ChartTheme>> = const { RefCell::new(None) };
+}
+
+/// Theme for chart styling
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartTheme {
+    /// Background color for plot area
+    pub plot_background: Rgba,
+    /// Grid line color
+    pub grid_color: Rgba,
+    /// Axis line color
+    pub axis_line_color: Rgba,
+    /// Axis label color
+    pub axis_label_color: Rgba,
+    /// Title text color
+    pub title_color: Rgba,
+    /// Legend text color
+    pub legend_text_color: Rgba,
+    /// Font size of axis tick labels
+    pub axis_font_size: f32,
+    /// Font size of the chart title
+    pub title_font_size: f32,
+}
+
+impl Default for ChartTheme {
+    fn default() -> Self {
+        Self {
+            plot_background: rgb(0xf8f8f8),
+            grid_color: rgba(0x000000, 0.1),
+            axis_line_color: rgba(0x000000, 0.2),
+            axis_label_color: rgba(0x000000, 0.6),
+            title_color: rgba(0x000000, 0.8),
+            legend_text_color: rgba(0x000000, 0.6),
+            axis_font_size: 8.0,
+            title_font_size: DEFAULT_TITLE_FONT_SIZE,
+        }
+    }
+}
+
+impl From<&Theme> for ChartTheme {
+    fn from(theme: &Theme) -> Self {
+        Self {
+            plot_background: theme.surface,
+            grid_color: theme.border,
+            axis_line_color: theme.border_hover,
+            axis_label_color: theme.text_secondary,
+            title_color: theme.text_primary,
+            legend_text_color: theme.text_secondary,
+            ..Self::default()
+        }
+    }
+}
+
+impl ChartTheme {
+    /// Theme used by charts without an explicit `.theme(...)`: the one
+    /// mirrored from the app theme by [`init`], or the default light theme,
+    /// with the theme and fonts of [`ChartDefaults`] applied.
+    pub fn current() -> Self {
+        let app_theme = CURRENT.with(|current| current.borrow().clone().unwrap_or_default());
+        ChartDefaults::current().chart_theme(app_theme)
+    }
+
+    /// Axis and grid colors for d3rs renderers.
+    pub(crate) fn axis_theme(&self) -> ChartAxisTheme {
+        ChartAxisTheme {
+            axis_line_color: self.axis_line_color,
+            axis_label_color: self.axis_label_color,
+            grid_color: self.grid_color,
+            background: self.plot_background,
+        }
+    }
+}
+
+/// Follow the app theme in charts.
+///
+/// Mirrors the global [`ThemeState`] into [`ChartTheme::current`] now and
+/// whenever it changes, and the [`ChartDefaults`] global likewise. Call
+/// once at startup, after setting the globals.
+pub fn init(cx: &mut App) {
+    sync_from_app(cx);
+    cx.observe_global::<ThemeState>(sync_from_app).detach();
+    crate::defaults::init(cx);
+}
+
+fn sync_from_app(cx: &mut App) {
+    let theme = cx
+        .has_global::<ThemeState>()
+        .then(|| ChartTheme::from(&cx.theme()));
+    CURRENT.with(|current| *current.borrow_mut() = theme);
+}
+
+/// Axis theme adapter for d3rs
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ChartAxisTheme {
+    pub(crate) axis_line_color: Rgba,
+    pub(crate) axis_label_color: Rgba,
+    pub(crate) grid_color: Rgba,
+    pub(crate) background: Rgba,
+}
+
+impl AxisTheme for ChartAxisTheme {
+    fn axis_line_color(&self) -> Rgba {
+        self.axis_line_color
+    }
+
+    fn axis_label_color(&self) -> Rgba {
+        self.axis_label_color
+    }
+
+    fn grid_color(&self) -> Rgba {
+        self.grid_color
+    }
+
+    fn background_color(&self) -> Option<Rgba> {
+        Some(self.background)
+    }
+}
+
+/// Helper to create Rgba with alpha
+fn rgba(hex: u32, alpha: f32) -> Rgba {
+    Rgba {
+        r: ((hex >> 16) & 0xFF) as f32 / 255.0,
+        g: ((hex >> 8) & 0xFF) as f32 / 255.0,
+        b: (hex & 0xFF) as f32 / 255.0,
+        a: alpha,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_theme_from_app_theme() {
+        let dark = Theme::dark();
+        let theme = ChartTheme::from(&dark);
+        assert_eq!(theme.plot_background, dark.surface);
+        assert_eq!(theme.title_color, dark.text_primary);
+        assert_eq!(theme.axis_font_size, ChartTheme::default().axis_font_size);
+    }
+
+    #[test]
+    fn test_current_defaults_without_app_theme() {
+        assert_eq!(ChartTheme::current(), ChartTheme::default());
+        let axis = ChartTheme::current().axis_theme();
+        assert_eq!(axis.grid_color(), ChartTheme::default().grid_color);
+    }
+}
@@ -0,0 +1,259 @@
+//! Title, subtitle and caption blocks shared by chart builders.
+//!
+//! Every builder with a `.title(...)` also accepts `.subtitle(...)`,
+//! `.caption(...)`, `.title_align(...)` and `.title_wrap(true)`. The blocks
+//! are drawn around the chart in the colors of the chart theme, and the plot
+//! shrinks to make room for them:
+//!
+//! ```rust,ignore
+//! let chart = line(&freq, &spl)
+//!     .title("Frequency response")
+//!     .subtitle("On axis, 2.83 V at 1 m")
+//!     .caption("Source: anechoic chamber")
+//!     .title_align(TitleAlign::Left)
+//!     .build()?;
+//! ```
+
+use crate::theme::ChartTheme;
+use d3rs::text::{VectorFontConfig, measure_text_width, render_vector_text};
+use gpui::prelude::*;
+use gpui::{AnyElement, Rgba, div, px};
+
+/// Font size of the subtitle relative to the title
+const SUBTITLE_SCALE: f32 = 0.75;
+
+/// Font size of the caption relative to the title
+const CAPTION_SCALE: f32 = 0.625;
+
+/// Line height relative to the font size
+const LINE_HEIGHT: f32 = 1.5;
+
+/// Horizontal alignment of the chart title and subtitle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TitleAlign {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+/// Title, subtitle and caption of a chart.
+///
+/// `title` is moved here from the builder at build time, so the chart itself
+/// is built without one.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ChartTitles {
+    pub(crate) title: Option<String>,
+    pub(crate) subtitle: Option<String>,
+    pub(crate) caption: Option<String>,
+    pub(crate) align: TitleAlign,
+    pub(crate) wrap: bool,
+}
+
+impl ChartTitles {
+    /// Whether anything beyond the plain centered title was set.
+    pub(crate) fn is_styled(&self) -> bool {
+        self.subtitle.is_some()
+            || self.caption.is_some()
+            || self.align != TitleAlign::Center
+            || self.wrap
+    }
+
+    /// Height of all blocks at the given width.
+    pub(crate) fn height(&self, width: f32) -> f32 {
+        let theme = ChartTheme::current();
+        self.header(&theme, width)
+            .iter()
+            .chain(&self.footer(&theme, width))
+            .map(TextBlock::height)
+            .sum()
+    }
+
+    /// Title and subtitle above the chart, `None` when both are unset.
+    pub(crate) fn render_header(&self, width: f32) -> Option<AnyElement> {
+        let blocks = self.header(&ChartTheme::current(), width);
+        (!blocks.is_empty()).then(|| {
+            div()
+                .w(px(width))
+                .flex()
+                .flex_col()
+                .children(blocks.into_iter().map(|block| block.render(width)))
+                .into_any_element()
+        })
+    }
+
+    /// Caption below the chart.
+    pub(crate) fn render_footer(&self, width: f32) -> Option<AnyElement> {
+        self.footer(&ChartTheme::current(), width)
+            .map(|block| block.render(width))
+    }
+
+    fn header(&self, theme: &ChartTheme, width: f32) -> Vec<TextBlock> {
+        let font_size = theme.title_font_size;
+        let title = self.title.as_deref().map(|text| {
+            TextBlock::new(
+                text,
+                font_size,
+                theme.title_color,
+                self.align,
+                self.wrap,
+                width,
+            )
+        });
+        let subtitle = self.subtitle.as_deref().map(|text| {
+            TextBlock::new(
+                text,
+                font_size * SUBTITLE_SCALE,
+                theme.axis_label_color,
+                self.align,
+                self.wrap,
+                width,
+            )
+        });
+        title.into_iter().chain(subtitle).collect()
+    }
+
+    fn footer(&self, theme: &ChartTheme, width: f32) -> Option<TextBlock> {
+        self.caption.as_deref().map(|text| {
+            TextBlock::new(
+                text,
+                theme.title_font_size * CAPTION_SCALE,
+                theme.legend_text_color,
+                TitleAlign::Right,
+                self.wrap,
+                width,
+            )
+        })
+    }
+}
+
+/// Lines of text laid out at one font size.
+struct TextBlock {
+    lines: Vec<String>,
+    font_size: f32,
+    color: Rgba,
+    align: TitleAlign,
+}
+
+impl TextBlock {
+    fn new(
+        text: &str,
+        font_size: f32,
+        color: Rgba,
+        align: TitleAlign,
+        wrap: bool,
+        width: f32,
+    ) -> Self {
+        let lines = if wrap {
+            wrap_text(text, font_size, width)
+        } else {
+            vec![text.to_string()]
+        };
+        Self {
+            lines,
+            font_size,
+            color,
+            align,
+        }
+    }
+
+    fn line_height(&self) -> f32 {
+        (self.font_size * LINE_HEIGHT).ceil()
+    }
+
+    fn height(&self) -> f32 {
+        self.lines.len() as f32 * self.line_height()
+    }
+
+    fn render(self, width: f32) -> AnyElement {
+        let config = VectorFontConfig::horizontal(self.font_size, self.color.into());
+        let line_height = self.line_height();
+        let align = self.align;
+        div()
+            .w(px(width))
+            .flex()
+            .flex_col()
+            .children(self.lines.iter().map(|line| {
+                let row = div()
+                    .w_full()
+                    .h(px(line_height))
+                    .px_1()
+                    .flex()
+                    .items_center()
+                    .overflow_hidden()
+                    .child(render_vector_text(line, &config));
+                match align {
+                    TitleAlign::Left => row.justify_start(),
+                    TitleAlign::Center => row.justify_center(),
+                    TitleAlign::Right => row.justify_end(),
+                }
+            }))
+            .into_any_element()
+    }
+}
+
+/// Break `text` at spaces into lines no wider than `width`.
+///
+/// A word wider than `width` gets a line of its own.
+fn wrap_text(text: &str, font_size: f32, width: f32) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{current} {word}")
+        };
+        if current.is_empty() || measure_text_width(&candidate, font_size) <= width {
+            current = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_text_breaks_at_width() {
+        let font_size = 16.0;
+        let word = measure_text_width("word", font_size);
+        let lines = wrap_text("word word word", font_size, word * 2.5);
+        assert_eq!(lines, vec!["word word", "word"]);
+        assert_eq!(wrap_text("word", font_size, 1.0), vec!["word"]);
+    }
+
+    #[test]
+    fn test_titles_height() {
+        let plain = ChartTitles::default();
+        assert!(!plain.is_styled());
+        assert_eq!(plain.height(400.0), 0.0);
+
+        let titles = ChartTitles {
+            title: Some("Title".into()),
+            subtitle: Some("Subtitle".into()),
+            caption: Some("Source".into()),
+            ..Default::default()
+        };
+        assert!(titles.is_styled());
+        // 16 px title, 12 px subtitle, 10 px caption
+        assert_eq!(titles.height(400.0), 24.0 + 18.0 + 15.0);
+    }
+
+    #[test]
+    fn test_titles_wrap_adds_lines() {
+        let titles = ChartTitles {
+            title: Some("A long chart title that needs several lines".into()),
+            wrap: true,
+            ..Default::default()
+        };
+        assert!(titles.height(80.0) > titles.height(2000.0));
+        assert_eq!(titles.height(2000.0), 24.0);
+    }
+}
@@ -21,12 +21,18 @@
 //! ```
 
 use crate::error::ChartError;
-use crate::{DEFAULT_HEIGHT, DEFAULT_WIDTH, TITLE_AREA_HEIGHT, validate_dimensions};
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::{ChartDefaults, TITLE_AREA_HEIGHT, validate_dimensions, validate_plot_area};
 use d3rs::color::ColorScheme;
-use d3rs::text::{VectorFontConfig, render_vector_text};
+use d3rs::text::{VectorFontConfig, measure_text_width, render_vector_text};
 use gpui::prelude::*;
-use gpui::{IntoElement, MouseButton, Rgba, div, hsla, px, rgb};
+use gpui::{
+    Animation, AnimationExt, App, ElementId, FontWeight, IntoElement, MouseButton, Rgba, Window,
+    div, ease_in_out, hsla, px, rgb,
+};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::rc::Rc;
+use std::time::Duration;
 
 /// Tiling algorithm for treemap layout.
 ///
@@ -114,8 +120,10 @@ struct TreemapRect {
     y1: f64,
     name: String,
     value: f64,
-    _depth: usize,
+    depth: usize,
     category_index: usize,
+    /// Child indices leading from the laid-out node to this leaf
+    path: Vec<usize>,
 }
 
 impl TreemapRect {
@@ -128,7 +136,14 @@ impl TreemapRect {
     }
 }
 
+/// Callback type for zoom events (receives the new zoom path)
+pub type TreemapZoomCallback = Rc<dyn Fn(&[usize], &mut Window, &mut App)>;
+
+/// Height of the breadcrumb bar shown when zooming is enabled
+pub(crate) const BREADCRUMB_HEIGHT: f32 = 20.0;
+
 /// Treemap chart builder.
+#[derive(Clone)]
 pub struct Treemap {
     root: TreemapNode,
     title: Option<String>,
@@ -139,9 +154,18 @@ pub struct Treemap {
     color_scheme: Option<ColorScheme>,
     on_click: Option<Rc<dyn Fn(&str, f64) + 'static>>,
     hover_enabled: bool,
+    /// Child indices from the root to the node currently zoomed into
+    zoom_path: Vec<usize>,
+    on_zoom: Option<TreemapZoomCallback>,
+    zoom_duration: Duration,
+    value_format: Option<Rc<dyn Fn(f64) -> String>>,
+    depth_shading: f32,
+    layout: ChartLayout,
 }
 
 impl Treemap {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
     /// Set the chart title.
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -198,12 +222,74 @@ impl Treemap {
         self
     }
 
-    /// Build the treemap chart.
-    pub fn build(self) -> Result<impl IntoElement, ChartError> {
+    /// Set the node to zoom into, as child indices from the root.
+    ///
+    /// An empty path (default) shows the whole hierarchy.
+    pub fn zoom_path(mut self, path: &[usize]) -> Self {
+        self.zoom_path = path.to_vec();
+        self
+    }
+
+    /// Enable click-to-zoom with a callback receiving the new zoom path.
+    ///
+    /// Clicking a cell requests a zoom into the top-level group containing it;
+    /// a breadcrumb bar above the plot requests a zoom back out. Feed the path
+    /// back through `zoom_path` and re-render to apply it.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let chart = treemap(&root)
+    ///     .zoom_path(&self.zoom)
+    ///     .on_zoom(cx.listener(|this, path: &[usize], _window, cx| {
+    ///         this.zoom = path.to_vec();
+    ///         cx.notify();
+    ///     }))
+    ///     .build()?;
+    /// ```
+    pub fn on_zoom<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&[usize], &mut Window, &mut App) + 'static,
+    {
+        self.on_zoom = Some(Rc::new(callback));
+        self
+    }
+
+    /// Set the duration of the zoom re-tiling animation (default: 300ms).
+    ///
+    /// A zero duration disables the animation.
+    pub fn zoom_duration(mut self, duration: Duration) -> Self {
+        self.zoom_duration = duration;
+        self
+    }
+
+    /// Show cell values below the labels, formatted with `format`.
+    pub fn value_format<F>(mut self, format: F) -> Self
+    where
+        F: Fn(f64) -> String + 'static,
+    {
+        self.value_format = Some(Rc::new(format));
+        self
+    }
+
+    /// Set how much each nesting level is lightened (0.0 - 1.0, default: 0.15).
+    pub fn depth_shading(mut self, amount: f32) -> Self {
+        self.depth_shading = amount.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
         // Validate
         validate_dimensions(self.width, self.height)?;
 
-        let total_value = self.root.total_value();
+        let Some(focus) = node_at_path(&self.root, &self.zoom_path) else {
+            return Err(ChartError::InvalidData {
+                field: "zoom_path",
+                reason: "does not reference a node in the hierarchy",
+            });
+        };
+
+        let total_value = focus.total_value();
         if total_value <= 0.0 {
             return Err(ChartError::InvalidData {
                 field: "root",
@@ -217,15 +303,20 @@ impl Treemap {
         } else {
             0.0
         };
+        let zoomable = self.on_zoom.is_some();
+        let breadcrumb_height = if zoomable { BREADCRUMB_HEIGHT } else { 0.0 };
 
         let margin = 10.0;
         let plot_width = (self.width as f64 - 2.0 * margin).max(0.0);
-        let plot_height = (self.height as f64 - title_height as f64 - 2.0 * margin).max(0.0);
+        let plot_height =
+            (self.height as f64 - title_height as f64 - breadcrumb_height as f64 - 2.0 * margin)
+                .max(0.0);
+        validate_plot_area(plot_width, plot_height)?;
 
-        // Compute treemap layout
+        // Compute treemap layout of the focused subtree
         let mut rects = Vec::new();
         compute_treemap(
-            &self.root,
+            focus,
             0.0,
             0.0,
             plot_width,
@@ -234,13 +325,33 @@ impl Treemap {
             self.padding,
             0,
             0,
+            &mut Vec::new(),
             &mut rects,
         );
 
+        // When zoomed, cells grow out of the rectangle the focused node
+        // occupied in its parent's layout
+        let zoom_origin = self.zoom_path.split_last().and_then(|(last, parent_path)| {
+            let parent = node_at_path(&self.root, parent_path)?;
+            child_rects(
+                parent,
+                0.0,
+                0.0,
+                plot_width,
+                plot_height,
+                self.tiling_method,
+                self.padding,
+                0,
+            )
+            .get(*last)
+            .copied()
+        });
+        let mut hasher = DefaultHasher::new();
+        self.zoom_path.hash(&mut hasher);
+        let zoom_key = hasher.finish();
+
         // Render rectangles
-        let color_scheme = self
-            .color_scheme
-            .unwrap_or_else(ColorScheme::tableau10);
+        let color_scheme = self.color_scheme.unwrap_or_else(ColorScheme::tableau10);
         let mut plot_content = div()
             .w(px(plot_width as f32))
             .h(px(plot_height as f32))
@@ -248,14 +359,17 @@ impl Treemap {
             .bg(rgb(0xffffff));
 
         let on_click = self.on_click;
+        let on_zoom = self.on_zoom;
         let hover_enabled = self.hover_enabled;
 
-        for rect in &rects {
-            let color = color_scheme.color(rect.category_index);
+        for (index, rect) in rects.iter().enumerate() {
+            let color = color_scheme
+                .color(rect.category_index)
+                .lighten(self.depth_shading * rect.depth.saturating_sub(1) as f32);
             let rgba = Rgba {
-                r: color.r / 255.0,
-                g: color.g / 255.0,
-                b: color.b / 255.0,
+                r: color.r,
+                g: color.g,
+                b: color.b,
                 a: 0.8,
             };
 
@@ -276,6 +390,7 @@ impl Treemap {
                 .top(px(rect.y0 as f32))
                 .w(px(rect.width() as f32))
                 .h(px(rect.height() as f32))
+                .overflow_hidden()
                 .bg(rgba)
                 .border_1()
                 .border_color(border_color);
@@ -302,12 +417,26 @@ impl Treemap {
                     });
             }
 
-            let rect_div = rect_div;
-
-            // Add label if rectangle is large enough
-            let rect_div = if rect.width() > 30.0 && rect.height() > 15.0 {
-                let font_size = (rect.height() * 0.2).clamp(8.0, 12.0);
+            // Zoom into the top-level group containing this cell
+            if let Some(callback) = on_zoom.as_ref()
+                && rect.path.len() > 1
+            {
+                let callback = Rc::clone(callback);
+                let mut target = self.zoom_path.clone();
+                target.push(rect.path[0]);
+                rect_div = rect_div.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                    callback(&target, window, cx);
+                });
+            }
 
+            // Add label, truncated with an ellipsis to fit the cell
+            let font_size = (rect.height() * 0.2).clamp(8.0, 12.0) as f32;
+            let max_label_width = rect.width() as f32 - 8.0;
+            let show_label = rect.height() > 15.0;
+            let label = show_label
+                .then(|| truncate_label(&rect.name, max_label_width, font_size))
+                .flatten();
+            if let Some(label) = label {
                 // Calculate text color based on background luminance
                 // Using relative luminance formula: 0.2126*R + 0.7152*G + 0.0722*B
                 let luminance = 0.2126 * rgba.r + 0.7152 * rgba.g + 0.0722 * rgba.b;
@@ -317,25 +446,60 @@ impl Treemap {
                     hsla(0.0, 0.0, 0.95, 1.0) // White text for dark backgrounds
                 };
 
-                let font_config = VectorFontConfig::horizontal(font_size as f32, text_color);
+                let font_config = VectorFontConfig::horizontal(font_size, text_color);
 
-                rect_div
+                rect_div = rect_div
                     .flex()
                     .flex_col()
                     .justify_center()
                     .items_center()
-                    .child(
-                        div()
-                            .overflow_hidden()
-                            .text_ellipsis()
-                            .px_1()
-                            .child(render_vector_text(&rect.name, &font_config)),
-                    )
-            } else {
-                rect_div
-            };
+                    .gap_1()
+                    .child(render_vector_text(&label, &font_config));
+
+                let value_label = self
+                    .value_format
+                    .as_ref()
+                    .filter(|_| rect.height() > 2.0 * font_size as f64 + 8.0)
+                    .and_then(|format| {
+                        truncate_label(&format(rect.value), max_label_width, font_size * 0.85)
+                    });
+                if let Some(value_label) = value_label {
+                    let value_config = VectorFontConfig::horizontal(font_size * 0.85, text_color);
+                    rect_div = rect_div.child(render_vector_text(&value_label, &value_config));
+                }
+            }
 
-            plot_content = plot_content.child(rect_div);
+            // Animate re-tiling from the focused node's previous rectangle
+            match zoom_origin {
+                Some((fx0, fy0, fx1, fy1)) if !self.zoom_duration.is_zero() => {
+                    let sx = (fx1 - fx0) / plot_width.max(1.0);
+                    let sy = (fy1 - fy0) / plot_height.max(1.0);
+                    let from = [
+                        (fx0 + rect.x0 * sx) as f32,
+                        (fy0 + rect.y0 * sy) as f32,
+                        (rect.width() * sx) as f32,
+                        (rect.height() * sy) as f32,
+                    ];
+                    let to = [
+                        rect.x0 as f32,
+                        rect.y0 as f32,
+                        rect.width() as f32,
+                        rect.height() as f32,
+                    ];
+                    let lerp = move |i: usize, t: f32| from[i] + (to[i] - from[i]) * t;
+                    plot_content = plot_content.child(rect_div.with_animation(
+                        ElementId::NamedInteger(format!("treemap-cell-{index}").into(), zoom_key),
+                        Animation::new(self.zoom_duration).with_easing(ease_in_out),
+                        move |el, t| {
+                            el.left(px(lerp(0, t)))
+                                .top(px(lerp(1, t)))
+                                .w(px(lerp(2, t)))
+                                .h(px(lerp(3, t)))
+                        },
+                    ));
+                }
+                _ => plot_content = plot_content.child(rect_div),
+            }
         }
 
         // Build container
@@ -360,6 +524,15 @@ impl Treemap {
             );
         }
 
+        // Add breadcrumb to zoom back out
+        if let Some(callback) = on_zoom {
+            container = container.child(
+                render_breadcrumb(&self.root, &self.zoom_path, callback)
+                    .h(px(breadcrumb_height))
+                    .px(px(margin as f32)),
+            );
+        }
+
         // Add plot
         container = container.child(
             div()
@@ -391,24 +564,98 @@ impl Treemap {
 ///     .unwrap();
 /// ```
 pub fn treemap(root: &TreemapNode) -> Treemap {
+    let defaults = ChartDefaults::current();
     Treemap {
         root: root.clone(),
         title: None,
         tiling_method: TilingMethod::default(),
         padding: 1.0,
-        width: DEFAULT_WIDTH,
-        height: DEFAULT_HEIGHT,
+        width: defaults.width,
+        height: defaults.height,
         color_scheme: None,
         on_click: None,
         hover_enabled: true,
+        zoom_path: Vec::new(),
+        on_zoom: None,
+        zoom_duration: Duration::from_millis(300),
+        value_format: None,
+        depth_shading: 0.15,
+        layout: ChartLayout::default(),
     }
 }
 
+/// Render a "Root / Child / ..." breadcrumb for `zoom_path`.
+///
+/// Every segment but the last requests a zoom out to that node when clicked.
+pub(crate) fn render_breadcrumb(
+    root: &TreemapNode,
+    zoom_path: &[usize],
+    on_zoom: TreemapZoomCallback,
+) -> gpui::Div {
+    let mut breadcrumb = div()
+        .w_full()
+        .flex()
+        .items_center()
+        .gap_1()
+        .text_xs()
+        .text_color(hsla(0.0, 0.0, 0.3, 1.0));
+
+    let mut node = root;
+    for depth in 0..=zoom_path.len() {
+        if depth > 0 {
+            node = &node.children[zoom_path[depth - 1]];
+            breadcrumb = breadcrumb.child("/");
+        }
+        let mut crumb = div()
+            .id(ElementId::NamedInteger("breadcrumb".into(), depth as u64))
+            .px_1()
+            .rounded_sm()
+            .child(node.name.clone());
+        if depth < zoom_path.len() {
+            let on_zoom = Rc::clone(&on_zoom);
+            let target = zoom_path[..depth].to_vec();
+            crumb = crumb
+                .cursor_pointer()
+                .hover(|s| s.bg(gpui::rgba(0x00000010)))
+                .on_click(move |_, window, cx| on_zoom(&target, window, cx));
+        } else {
+            crumb = crumb.font_weight(FontWeight::SEMIBOLD);
+        }
+        breadcrumb = breadcrumb.child(crumb);
+    }
+    breadcrumb
+}
+
+/// Find the node reached by following child indices from `root`.
+pub(crate) fn node_at_path<'a>(root: &'a TreemapNode, path: &[usize]) -> Option<&'a TreemapNode> {
+    path.iter()
+        .try_fold(root, |node, &index| node.children.get(index))
+}
+
+/// Truncate `text` with a trailing "..." so it fits in `max_width` pixels.
+///
+/// Returns `None` when not even the ellipsis fits.
+pub(crate) fn truncate_label(text: &str, max_width: f32, font_size: f32) -> Option<String> {
+    if measure_text_width(text, font_size) <= max_width {
+        return Some(text.to_string());
+    }
+    let mut chars: Vec<char> = text.chars().collect();
+    while !chars.is_empty() {
+        chars.pop();
+        let candidate: String = chars.iter().collect::<String>().trim_end().to_string() + "...";
+        if measure_text_width(&candidate, font_size) <= max_width {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
 // ============================================================================
 // Layout Algorithms
 // ============================================================================
 
 /// Compute treemap layout recursively.
+#[allow(clippy::too_many_arguments)]
 fn compute_treemap(
     node: &TreemapNode,
     x0: f64,
@@ -419,6 +666,7 @@ fn compute_treemap(
     padding: f64,
     depth: usize,
     category_index: usize,
+    path: &mut Vec<usize>,
     results: &mut Vec<TreemapRect>,
 ) {
     let total_value = node.total_value();
@@ -445,39 +693,64 @@ fn compute_treemap(
             y1: py1,
             name: node.name.clone(),
             value: node.value,
-            _depth: depth,
+            depth,
             category_index,
+            path: path.clone(),
         });
     } else {
-        // Layout children based on tiling method
-        let children: Vec<_> = node.children.iter().map(|c| (c, c.total_value())).collect();
-
-        let rects = match method {
-            TilingMethod::Squarify => tile_squarify(&children, px0, py0, px1, py1, total_value),
-            TilingMethod::Binary => tile_binary(&children, px0, py0, px1, py1, total_value),
-            TilingMethod::Slice => tile_slice(&children, px0, py0, px1, py1, total_value),
-            TilingMethod::Dice => tile_dice(&children, px0, py0, px1, py1, total_value),
-            TilingMethod::SliceDice => {
-                tile_slice_dice(&children, px0, py0, px1, py1, total_value, depth)
-            }
-        };
+        let rects = child_rects(node, x0, y0, x1, y1, method, padding, depth);
 
         // Recursively process children
-        for (i, ((child, _), (cx0, cy0, cx1, cy1))) in children.iter().zip(rects.iter()).enumerate()
-        {
+        for (i, (child, (cx0, cy0, cx1, cy1))) in node.children.iter().zip(rects).enumerate() {
             let child_category = if depth == 0 { i } else { category_index };
+            path.push(i);
             compute_treemap(
                 child,
-                *cx0,
-                *cy0,
-                *cx1,
-                *cy1,
+                cx0,
+                cy0,
+                cx1,
+                cy1,
                 method,
                 padding,
                 depth + 1,
                 child_category,
+                path,
                 results,
             );
+            path.pop();
+        }
+    }
+}
+
+/// Tile the children of `node` inside its padded rectangle.
+///
+/// Rectangles are returned in the same order as `node.children`.
+#[allow(clippy::too_many_arguments)]
+fn child_rects(
+    node: &TreemapNode,
+    x0: f64,
+    y0: f64,
+    x1: f64,
+    y1: f64,
+    method: TilingMethod,
+    padding: f64,
+    depth: usize,
+) -> Vec<(f64, f64, f64, f64)> {
+    let total_value = node.total_value();
+    let (px0, py0, px1, py1) = (x0 + padding, y0 + padding, x1 - padding, y1 - padding);
+    if total_value == 0.0 || px1 <= px0 || py1 <= py0 {
+        return Vec::new();
+    }
+
+    let children: Vec<_> = node.children.iter().map(|c| (c, c.total_value())).collect();
+
+    match method {
+        TilingMethod::Squarify => tile_squarify(&children, px0, py0, px1, py1, total_value),
+        TilingMethod::Binary => tile_binary(&children, px0, py0, px1, py1, total_value),
+        TilingMethod::Slice => tile_slice(&children, px0, py0, px1, py1, total_value),
+        TilingMethod::Dice => tile_dice(&children, px0, py0, px1, py1, total_value),
+        TilingMethod::SliceDice => {
+            tile_slice_dice(&children, px0, py0, px1, py1, total_value, depth)
         }
     }
 }
@@ -619,16 +892,21 @@ fn tile_squarify(
     let width = x1 - x0;
     let height = y1 - y0;
 
-    // Sort by value descending for better packing
-    let mut sorted: Vec<_> = children.iter().map(|(n, v)| (*n, *v)).collect();
+    // Sort by value descending for better packing, remembering original positions
+    let mut sorted: Vec<_> = children
+        .iter()
+        .enumerate()
+        .map(|(i, (_, v))| (i, *v))
+        .collect();
     sorted.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
-    let mut rects = Vec::new();
+    let mut rects = vec![(x0, y0, x0, y0); children.len()];
     let mut remaining: Vec<_> = sorted.iter().collect();
     let mut x = x0;
     let mut y = y0;
     let mut w = width;
     let mut h = height;
+    let mut remaining_total = total;
 
     while !remaining.is_empty() {
         // Try to find best row
@@ -668,28 +946,29 @@ fn tile_squarify(
         let area = w * h;
         if use_width {
             // Layout horizontally
-            let row_height = (row_sum / total) * area / w;
+            let row_height = (row_sum / remaining_total) * area / w;
             let mut rx = x;
-            for (_n, value) in row {
+            for (i, value) in row {
                 let rw = (*value / row_sum) * w;
-                rects.push((rx, y, rx + rw, y + row_height));
+                rects[*i] = (rx, y, rx + rw, y + row_height);
                 rx += rw;
             }
             y += row_height;
             h -= row_height;
         } else {
             // Layout vertically
-            let row_width = (row_sum / total) * area / h;
+            let row_width = (row_sum / remaining_total) * area / h;
             let mut ry = y;
-            for (_n, value) in row {
+            for (i, value) in row {
                 let rh = (*value / row_sum) * h;
-                rects.push((x, ry, x + row_width, ry + rh));
+                rects[*i] = (x, ry, x + row_width, ry + rh);
                 ry += rh;
             }
             x += row_width;
             w -= row_width;
         }
 
+        remaining_total -= row_sum;
         remaining = remaining[best_row_len..].to_vec();
     }
 
@@ -834,4 +1113,108 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_tile_squarify_preserves_child_order() {
+        let node_a = TreemapNode::new("A", 10.0);
+        let node_b = TreemapNode::new("B", 60.0);
+        let node_c = TreemapNode::new("C", 30.0);
+        let nodes = vec![(&node_a, 10.0), (&node_b, 60.0), (&node_c, 30.0)];
+
+        let rects = tile_squarify(&nodes, 0.0, 0.0, 100.0, 100.0, 100.0);
+        let areas: Vec<f64> = rects
+            .iter()
+            .map(|(x0, y0, x1, y1)| (x1 - x0) * (y1 - y0))
+            .collect();
+        assert!((areas[0] - 1000.0).abs() < 1e-6);
+        assert!((areas[1] - 6000.0).abs() < 1e-6);
+        assert!((areas[2] - 3000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_truncate_label() {
+        assert_eq!(
+            truncate_label("Short", 500.0, 12.0).as_deref(),
+            Some("Short")
+        );
+
+        let long = "A rather long category name";
+        let truncated = truncate_label(long, 60.0, 12.0).unwrap();
+        assert!(truncated.ends_with("..."));
+        assert!(measure_text_width(&truncated, 12.0) <= 60.0);
+
+        assert_eq!(truncate_label(long, 1.0, 12.0), None);
+    }
+
+    #[test]
+    fn test_node_at_path() {
+        let root = TreemapNode::new("Root", 0.0)
+            .add_child(TreemapNode::new("A", 0.0).add_child(TreemapNode::new("A1", 5.0)))
+            .add_child(TreemapNode::new("B", 10.0));
+
+        assert_eq!(node_at_path(&root, &[]).unwrap().name, "Root");
+        assert_eq!(node_at_path(&root, &[0, 0]).unwrap().name, "A1");
+        assert!(node_at_path(&root, &[2]).is_none());
+    }
+
+    #[test]
+    fn test_compute_treemap_paths() {
+        let root = TreemapNode::new("Root", 0.0)
+            .add_child(
+                TreemapNode::new("A", 0.0)
+                    .add_child(TreemapNode::new("A1", 10.0))
+                    .add_child(TreemapNode::new("A2", 20.0)),
+            )
+            .add_child(TreemapNode::new("B", 70.0));
+
+        let mut rects = Vec::new();
+        compute_treemap(
+            &root,
+            0.0,
+            0.0,
+            200.0,
+            100.0,
+            TilingMethod::Squarify,
+            0.0,
+            0,
+            0,
+            &mut Vec::new(),
+            &mut rects,
+        );
+
+        let a2 = rects.iter().find(|r| r.name == "A2").unwrap();
+        assert_eq!(a2.path, vec![0, 1]);
+        assert_eq!(a2.depth, 2);
+        let b = rects.iter().find(|r| r.name == "B").unwrap();
+        assert_eq!(b.path, vec![1]);
+        assert!((b.width() * b.height() - 14000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_treemap_zoom() {
+        let root = TreemapNode::new("Root", 0.0)
+            .add_child(
+                TreemapNode::new("A", 0.0)
+                    .add_child(TreemapNode::new("A1", 10.0))
+                    .add_child(TreemapNode::new("A2", 20.0)),
+            )
+            .add_child(TreemapNode::new("B", 70.0));
+
+        let result = treemap(&root)
+            .zoom_path(&[0])
+            .on_zoom(|_path, _window, _cx| {})
+            .value_format(|v| format!("{v:.0}"))
+            .depth_shading(0.3)
+            .build();
+        assert!(result.is_ok());
+
+        let result = treemap(&root).zoom_path(&[0, 5]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData {
+                field: "zoom_path",
+                ..
+            })
+        ));
+    }
 }
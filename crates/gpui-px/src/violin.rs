@@ -0,0 +1,560 @@
+//! Violin plot - Plotly Express style API.
+//!
+//! Violin plots show the distribution of each category as a mirrored
+//! kernel density estimate:
+//! - The width of a violin at a value follows the density of the samples
+//! - Each violin spans its samples plus two bandwidths, so the tails close
+//! - An optional box plot inside each violin marks the quartiles
+//!
+//! The density comes from [`d3rs::contour::kernel_density_1d`]; the bandwidth
+//! defaults to Silverman's rule of thumb for each category.
+
+use crate::async_chart::AsyncChart;
+use crate::boxplot::{BoxStats, BoxStyle, box_glyph};
+use crate::error::ChartError;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::theme::ChartTheme;
+use crate::{
+    ChartDefaults, DEFAULT_COLOR, DEFAULT_PADDING_FRACTION, DEFAULT_TITLE_FONT_SIZE,
+    TITLE_AREA_HEIGHT, extent_padded, validate_data_array, validate_dimensions, validate_plot_area,
+};
+use d3rs::axis::{AxisConfig, render_axis};
+use d3rs::color::D3Color;
+use d3rs::contour::{KernelType, kernel_density_1d, silverman_bandwidth};
+use d3rs::grid::{GridConfig, render_grid};
+use d3rs::scale::{LinearScale, Scale};
+use d3rs::text::{VectorFontConfig, render_vector_text};
+use gpui::prelude::*;
+use gpui::{AnyElement, App, Entity, IntoElement, PathBuilder, Rgba, canvas, div, point, px};
+
+/// Space left of the plot area, for the y axis
+const MARGIN_LEFT: f64 = 60.0;
+
+/// Space right of the plot area
+const MARGIN_RIGHT: f64 = 20.0;
+
+/// Space below the plot area, for the category labels
+const MARGIN_BOTTOM: f64 = 24.0;
+
+/// Space above the plot area
+const MARGIN_TOP: f64 = 10.0;
+
+/// Density outline of one category
+#[derive(Debug, Clone)]
+struct ViolinShape {
+    /// Sample value and its density, from the lowest value up
+    density: Vec<(f64, f64)>,
+    /// Largest density, the half width of the violin
+    max_density: f64,
+    /// Quartiles of the samples, for the inner box
+    stats: BoxStats,
+}
+
+/// Density outline of `values` at `resolution` points
+///
+/// `index` is the position of the category on the x axis.
+fn violin_shape(
+    index: usize,
+    values: &[f64],
+    kernel: KernelType,
+    bandwidth: Option<f64>,
+    resolution: usize,
+) -> Option<ViolinShape> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let stats = BoxStats::from_sorted(index as f64, &sorted)?;
+
+    let bandwidth = bandwidth.unwrap_or_else(|| silverman_bandwidth(&sorted));
+    let low = sorted[0] - 2.0 * bandwidth;
+    let high = sorted[sorted.len() - 1] + 2.0 * bandwidth;
+    let steps = resolution.max(2) - 1;
+    let thresholds: Vec<f64> = (0..=steps)
+        .map(|i| low + (high - low) * i as f64 / steps as f64)
+        .collect();
+
+    let density = kernel_density_1d(&sorted, &thresholds, kernel, bandwidth);
+    let max_density = density.iter().map(|&(_, d)| d).fold(0.0, f64::max);
+    Some(ViolinShape {
+        density,
+        max_density,
+        stats,
+    })
+}
+
+/// Density outlines of all `groups`, in input order
+fn violin_shapes(
+    groups: &[(String, Vec<f64>)],
+    kernel: KernelType,
+    bandwidth: Option<f64>,
+    resolution: usize,
+) -> Vec<ViolinShape> {
+    groups
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (_, values))| violin_shape(i, values, kernel, bandwidth, resolution))
+        .collect()
+}
+
+/// Violin plot builder.
+#[derive(Debug, Clone)]
+pub struct ViolinChart {
+    groups: Vec<(String, Vec<f64>)>,
+    title: Option<String>,
+    color: u32,
+    opacity: f32,
+    kernel: KernelType,
+    bandwidth: Option<f64>,
+    resolution: usize,
+    violin_width: f32,
+    show_box: bool,
+    box_width: f32,
+    width: f32,
+    height: f32,
+    /// Density outlines computed by `build_async`
+    shapes: Option<Vec<ViolinShape>>,
+    layout: ChartLayout,
+}
+
+impl ViolinChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
+    /// Set chart title (rendered at top of chart).
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set violin fill color as 24-bit RGB hex value (format: 0xRRGGBB).
+    pub fn color(mut self, hex: u32) -> Self {
+        self.color = hex;
+        self
+    }
+
+    /// Set violin fill opacity (0.0 - 1.0).
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the kernel of the density estimate (default: Gaussian).
+    pub fn kernel(mut self, kernel: KernelType) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Set the kernel bandwidth in data units, the same for all categories.
+    ///
+    /// If not set, each category uses Silverman's rule of thumb.
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    /// Set the number of points the density is evaluated at per violin.
+    pub fn resolution(mut self, points: usize) -> Self {
+        self.resolution = points;
+        self
+    }
+
+    /// Set the widest violin width as a fraction of its category slot
+    /// (0.0 - 1.0).
+    pub fn violin_width(mut self, fraction: f32) -> Self {
+        self.violin_width = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Show a box plot of the quartiles inside each violin (default: true).
+    pub fn show_box(mut self, show: bool) -> Self {
+        self.show_box = show;
+        self
+    }
+
+    /// Set the inner box width in pixels.
+    pub fn box_width(mut self, width: f32) -> Self {
+        self.box_width = width;
+        self
+    }
+
+    /// Set chart dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Validate the data.
+    fn validate(&self) -> Result<(), ChartError> {
+        if self.groups.is_empty() {
+            return Err(ChartError::EmptyData { field: "groups" });
+        }
+        for (_, values) in &self.groups {
+            validate_data_array(values, "groups")?;
+        }
+        if let Some(bandwidth) = self.bandwidth
+            && !(bandwidth.is_finite() && bandwidth > 0.0)
+        {
+            return Err(ChartError::InvalidData {
+                field: "bandwidth",
+                reason: "must be positive",
+            });
+        }
+        Ok(())
+    }
+
+    /// Build the chart with its kernel density estimates computed on the
+    /// background executor.
+    ///
+    /// The data is validated right away; the returned [`AsyncChart`] shows a
+    /// placeholder until the densities are ready.
+    pub fn build_async(self, cx: &mut App) -> Result<Entity<AsyncChart>, ChartError> {
+        self.validate()?;
+        let groups = self.groups.clone();
+        let (kernel, bandwidth, resolution) = (self.kernel, self.bandwidth, self.resolution);
+        let (layout, size) = (self.layout.clone(), (self.width, self.height));
+        Ok(AsyncChart::spawn(
+            &layout,
+            size,
+            move || violin_shapes(&groups, kernel, bandwidth, resolution),
+            move |shapes: &Vec<ViolinShape>| {
+                let mut chart = self.clone();
+                chart.shapes = Some(shapes.clone());
+                chart.build().map(IntoElement::into_any_element)
+            },
+            cx,
+        ))
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(mut self) -> Result<impl IntoElement, ChartError> {
+        self.validate()?;
+        validate_dimensions(self.width, self.height)?;
+
+        let title_height = if self.title.is_some() {
+            TITLE_AREA_HEIGHT
+        } else {
+            0.0
+        };
+        let plot_width = (self.width as f64 - MARGIN_LEFT - MARGIN_RIGHT).max(0.0);
+        let plot_height =
+            (self.height as f64 - title_height as f64 - MARGIN_TOP - MARGIN_BOTTOM).max(0.0);
+        validate_plot_area(plot_width, plot_height)?;
+
+        // Use the densities computed by `build_async`, or estimate them now
+        let shapes = self.shapes.take().unwrap_or_else(|| {
+            violin_shapes(&self.groups, self.kernel, self.bandwidth, self.resolution)
+        });
+        let span: Vec<f64> = shapes
+            .iter()
+            .flat_map(|shape| [shape.density[0].0, shape.density[shape.density.len() - 1].0])
+            .collect();
+        let (y_min, y_max) = extent_padded(&span, DEFAULT_PADDING_FRACTION);
+
+        let chart_theme = ChartTheme::current();
+        let chart_content = self.render_chart(&shapes, y_min, y_max, plot_width, plot_height);
+
+        let mut container = div()
+            .w(px(self.width))
+            .h(px(self.height))
+            .relative()
+            .flex()
+            .flex_col();
+
+        if let Some(title) = &self.title {
+            let font_config = VectorFontConfig::horizontal(
+                DEFAULT_TITLE_FONT_SIZE,
+                chart_theme.title_color.into(),
+            );
+            container = container.child(
+                div()
+                    .w_full()
+                    .h(px(title_height))
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .child(render_vector_text(title, &font_config)),
+            );
+        }
+
+        container = container.child(div().relative().pt(px(MARGIN_TOP)).child(chart_content));
+
+        Ok(container)
+    }
+
+    /// Render the axis, violins and category labels
+    fn render_chart(
+        &self,
+        shapes: &[ViolinShape],
+        y_min: f64,
+        y_max: f64,
+        plot_width: f64,
+        plot_height: f64,
+    ) -> AnyElement {
+        let theme = ChartTheme::current().axis_theme();
+        let count = self.groups.len();
+        let slot_width = plot_width / count as f64;
+
+        // Category i is centered on i + 0.5
+        let x_scale = LinearScale::new()
+            .domain(0.0, count as f64)
+            .range(0.0, plot_width);
+        let y_scale = LinearScale::new()
+            .domain(y_min, y_max)
+            .range(plot_height, 0.0);
+
+        // Violin polygons in plot coordinates, right side up then left side down
+        let half_width = slot_width * self.violin_width as f64 / 2.0;
+        let polygons: Vec<Vec<(f32, f32)>> = shapes
+            .iter()
+            .map(|shape| {
+                let center = x_scale.scale(shape.stats.x + 0.5);
+                let scale = if shape.max_density > 0.0 {
+                    half_width / shape.max_density
+                } else {
+                    0.0
+                };
+                let right = shape
+                    .density
+                    .iter()
+                    .map(|&(y, d)| (center + d * scale, y_scale.scale(y)));
+                let left = shape
+                    .density
+                    .iter()
+                    .rev()
+                    .map(|&(y, d)| (center - d * scale, y_scale.scale(y)));
+                right
+                    .chain(left)
+                    .map(|(x, y)| (x as f32, y as f32))
+                    .collect()
+            })
+            .collect();
+
+        let fill = D3Color::from_hex(self.color).to_rgba();
+        let opacity = self.opacity;
+        let violins = canvas(
+            move |bounds, _, _| bounds,
+            move |_, bounds, window, _| {
+                let origin_x: f32 = bounds.origin.x.into();
+                let origin_y: f32 = bounds.origin.y.into();
+
+                for polygon in &polygons {
+                    let Some(&(first_x, first_y)) = polygon.first() else {
+                        continue;
+                    };
+                    let mut fill_path = PathBuilder::fill();
+                    let mut outline = PathBuilder::stroke(px(1.0));
+                    for builder in [&mut fill_path, &mut outline] {
+                        builder.move_to(point(px(origin_x + first_x), px(origin_y + first_y)));
+                        for &(x, y) in polygon.iter().skip(1) {
+                            builder.line_to(point(px(origin_x + x), px(origin_y + y)));
+                        }
+                        builder.close();
+                    }
+                    if let Ok(path) = fill_path.build() {
+                        window.paint_path(
+                            path,
+                            Rgba {
+                                a: fill.a * opacity,
+                                ..fill
+                            },
+                        );
+                    }
+                    if let Ok(path) = outline.build() {
+                        window.paint_path(path, fill);
+                    }
+                }
+            },
+        )
+        .size_full()
+        .absolute()
+        .inset_0();
+
+        // Inner box plots, without outliers, which the violin tails already show
+        let box_elements: Vec<AnyElement> = if self.show_box {
+            let style = BoxStyle {
+                box_color: D3Color::from_hex(0x333333).to_rgba(),
+                median_color: D3Color::from_hex(0xffffff).to_rgba(),
+                whisker_color: D3Color::from_hex(0x333333).to_rgba(),
+                outlier_color: fill,
+                box_opacity: 1.0,
+                box_width: self.box_width,
+                stroke_width: 1.5,
+                outlier_radius: 0.0,
+            };
+            shapes
+                .iter()
+                .flat_map(|shape| {
+                    let stats = BoxStats {
+                        outliers_low: Vec::new(),
+                        outliers_high: Vec::new(),
+                        ..shape.stats.clone()
+                    };
+                    let x_px = x_scale.scale(stats.x + 0.5) as f32;
+                    box_glyph(&stats, x_px, &y_scale, &style)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let labels = self.groups.iter().map(|(label, _)| {
+            div()
+                .w(px(slot_width as f32))
+                .h(px(MARGIN_BOTTOM as f32))
+                .flex()
+                .justify_center()
+                .items_center()
+                .text_xs()
+                .text_color(theme.axis_label_color)
+                .child(label.clone())
+        });
+
+        div()
+            .flex()
+            .child(render_axis(
+                &y_scale,
+                &AxisConfig::left(),
+                plot_height as f32,
+                &theme,
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .w(px(plot_width as f32))
+                            .h(px(plot_height as f32))
+                            .relative()
+                            .bg(theme.background)
+                            .child(render_grid(
+                                &x_scale,
+                                &y_scale,
+                                &GridConfig::new()
+                                    .with_horizontal_lines(true)
+                                    .with_dots(false),
+                                plot_width as f32,
+                                plot_height as f32,
+                                &theme,
+                            ))
+                            .child(violins)
+                            .children(box_elements),
+                    )
+                    .child(div().flex().children(labels)),
+            )
+            .into_any_element()
+    }
+}
+
+/// Create a violin plot with one violin per named group of samples.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_px::{KernelType, violin};
+///
+/// let groups = [
+///     ("Woofer", vec![82.1, 83.4, 84.0, 84.2, 85.9]),
+///     ("Tweeter", vec![88.5, 89.0, 90.2, 90.3, 91.7]),
+/// ];
+///
+/// let chart = violin(&groups)
+///     .title("Sensitivity by Driver")
+///     .kernel(KernelType::Epanechnikov)
+///     .bandwidth(0.8)
+///     .build()?;
+/// # Ok::<(), gpui_px::ChartError>(())
+/// ```
+pub fn violin<S: AsRef<str>, V: AsRef<[f64]>>(groups: &[(S, V)]) -> ViolinChart {
+    let defaults = ChartDefaults::current();
+    ViolinChart {
+        groups: groups
+            .iter()
+            .map(|(label, values)| (label.as_ref().to_string(), values.as_ref().to_vec()))
+            .collect(),
+        title: None,
+        color: DEFAULT_COLOR,
+        opacity: 0.6,
+        kernel: KernelType::default(),
+        bandwidth: None,
+        resolution: 64,
+        violin_width: 0.8,
+        show_box: true,
+        box_width: 8.0,
+        width: defaults.width,
+        height: defaults.height,
+        shapes: None,
+        layout: ChartLayout::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_violin_shape_spans_samples() {
+        let values = [1.0, 2.0, 2.0, 3.0];
+        let shape = violin_shape(0, &values, KernelType::Gaussian, Some(0.5), 41).unwrap();
+        assert_eq!(shape.density.len(), 41);
+        // Two bandwidths past the samples on both ends
+        assert!((shape.density[0].0 - 0.0).abs() < 1e-10);
+        assert!((shape.density[40].0 - 4.0).abs() < 1e-10);
+        // Widest at the repeated sample
+        let (peak, _) = shape
+            .density
+            .iter()
+            .copied()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        assert!((peak - 2.0).abs() < 1e-10);
+        assert!((shape.stats.q2 - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_violin_empty_data() {
+        let groups: [(&str, Vec<f64>); 0] = [];
+        let result = violin(&groups).build();
+        assert!(matches!(result, Err(ChartError::EmptyData { .. })));
+
+        let result = violin(&[("a", Vec::new())]).build();
+        assert!(matches!(result, Err(ChartError::EmptyData { .. })));
+    }
+
+    #[test]
+    fn test_violin_invalid_bandwidth() {
+        let result = violin(&[("a", vec![1.0, 2.0])]).bandwidth(0.0).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData {
+                field: "bandwidth",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_violin_successful_build() {
+        let groups = [("a", vec![1.0, 2.0, 3.0]), ("b", vec![2.0, 4.0, 4.5, 8.0])];
+        assert!(violin(&groups).build().is_ok());
+        // A single sample has no spread but still draws
+        assert!(violin(&[("one", [5.0])]).build().is_ok());
+    }
+
+    #[test]
+    fn test_violin_builder_chain() {
+        let groups = [("a", vec![1.0, 2.0, 3.0]), ("b", vec![2.0, 4.0, 4.5, 8.0])];
+        let result = violin(&groups)
+            .title("My Violin Plot")
+            .color(0xff7f0e)
+            .opacity(0.5)
+            .kernel(KernelType::Epanechnikov)
+            .bandwidth(0.7)
+            .resolution(100)
+            .violin_width(0.9)
+            .show_box(false)
+            .box_width(10.0)
+            .size(800.0, 600.0)
+            .build();
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,446 @@
+//! Waterfall chart - Plotly Express style API.
+//!
+//! A cumulative spectral decay (CSD) plot: magnitude spectra taken at
+//! successive time offsets are stacked front to back as a pseudo-3D
+//! ridgeline. The earliest slice sits in front, later slices recede up and
+//! to the right, and each slice hides the part of the slices behind it.
+
+use crate::color_scale::ColorScale;
+use crate::error::ChartError;
+use crate::layout::{ChartLayout, chart_layout_methods};
+use crate::theme::ChartTheme;
+use crate::{
+    ChartDefaults, DEFAULT_TITLE_FONT_SIZE, ScaleType, TITLE_AREA_HEIGHT, validate_data_array,
+    validate_dimensions, validate_grid_dimensions, validate_monotonic, validate_plot_area,
+    validate_positive,
+};
+use d3rs::axis::{AxisConfig, render_axis};
+use d3rs::scale::{LinearScale, LogScale};
+use d3rs::text::{VectorFontConfig, render_vector_text};
+use gpui::prelude::*;
+use gpui::{AnyElement, IntoElement, PathBuilder, canvas, div, hsla, point, px};
+
+/// Default floor, in dB below the spectrum peak
+const DEFAULT_FLOOR_DB: f64 = -30.0;
+
+/// Default depth of the stack as a fraction of the plot size
+const DEFAULT_DEPTH: f32 = 0.3;
+
+/// Waterfall (CSD) chart builder.
+#[derive(Clone)]
+pub struct WaterfallChart {
+    freq: Vec<f64>,
+    times: Vec<f64>,
+    /// Row-major magnitudes: `z[t * freq.len() + f]`, in dB
+    z: Vec<f64>,
+    x_scale_type: ScaleType,
+    /// Only slices with `time <= decay_window` are drawn
+    decay_window: Option<f64>,
+    floor_db: f64,
+    depth: f32,
+    color_scale: ColorScale,
+    outline: bool,
+    title: Option<String>,
+    width: f32,
+    height: f32,
+    layout: ChartLayout,
+}
+
+impl std::fmt::Debug for WaterfallChart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WaterfallChart")
+            .field("freq_len", &self.freq.len())
+            .field("slices", &self.times.len())
+            .field("decay_window", &self.decay_window)
+            .field("floor_db", &self.floor_db)
+            .field("title", &self.title)
+            .finish()
+    }
+}
+
+impl WaterfallChart {
+    chart_layout_methods!(layout, |chart: &Self| (chart.width, chart.height), title);
+
+    /// Set chart title (rendered at top of chart).
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the frequency axis scale (default: log).
+    pub fn x_scale(mut self, scale: ScaleType) -> Self {
+        self.x_scale_type = scale;
+        self
+    }
+
+    /// Only draw slices up to `time` (same unit as the slice times).
+    pub fn decay_window(mut self, time: f64) -> Self {
+        self.decay_window = Some(time);
+        self
+    }
+
+    /// Set the floor in dB relative to the peak (default: -30 dB).
+    ///
+    /// Levels below the floor are clipped to the baseline of their slice.
+    pub fn floor(mut self, floor_db: f64) -> Self {
+        self.floor_db = floor_db;
+        self
+    }
+
+    /// Set how far the last slice recedes, as a fraction of the plot width
+    /// and height (default: 0.3).
+    pub fn depth(mut self, depth: f32) -> Self {
+        self.depth = depth.clamp(0.0, 0.9);
+        self
+    }
+
+    /// Set the color scale; slice `i` of `n` uses the color at `i / (n - 1)`.
+    pub fn color_scale(mut self, scale: ColorScale) -> Self {
+        self.color_scale = scale;
+        self
+    }
+
+    /// Draw a darker outline along the top of each slice (default: true).
+    pub fn outline(mut self, outline: bool) -> Self {
+        self.outline = outline;
+        self
+    }
+
+    /// Set chart dimensions.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Indices of the slices inside the decay window.
+    fn visible_slices(&self) -> Vec<usize> {
+        (0..self.times.len())
+            .filter(|&i| self.decay_window.is_none_or(|w| self.times[i] <= w))
+            .collect()
+    }
+
+    /// Peak level of the visible slices, and the absolute floor below it.
+    fn level_range(&self, slices: &[usize]) -> (f64, f64) {
+        let n = self.freq.len();
+        let peak = slices
+            .iter()
+            .flat_map(|&s| &self.z[s * n..(s + 1) * n])
+            .fold(f64::NEG_INFINITY, |acc, &v| acc.max(v));
+        (peak + self.floor_db.min(-f64::EPSILON), peak)
+    }
+
+    /// Map frequencies to [0, 1] along the x axis.
+    fn freq_positions(&self) -> Vec<f32> {
+        let (lo, hi) = (self.freq[0], self.freq[self.freq.len() - 1]);
+        let map = |f: f64| match self.x_scale_type {
+            ScaleType::Log => (f.log10() - lo.log10()) / (hi.log10() - lo.log10()),
+            ScaleType::Linear => (f - lo) / (hi - lo),
+        };
+        if hi > lo {
+            self.freq.iter().map(|&f| map(f) as f32).collect()
+        } else {
+            vec![0.0; self.freq.len()]
+        }
+    }
+
+    /// Build the chart at its own size, without the outer layout.
+    fn build_chart(self) -> Result<impl IntoElement, ChartError> {
+        validate_data_array(&self.freq, "freq")?;
+        validate_data_array(&self.times, "times")?;
+        validate_data_array(&self.z, "z")?;
+        validate_grid_dimensions(&self.z, self.freq.len(), self.times.len())?;
+        validate_monotonic(&self.freq, "freq")?;
+        validate_monotonic(&self.times, "times")?;
+        if self.x_scale_type == ScaleType::Log {
+            validate_positive(&self.freq, "freq")?;
+        }
+        validate_dimensions(self.width, self.height)?;
+
+        let slices = self.visible_slices();
+        if slices.is_empty() {
+            return Err(ChartError::InvalidData {
+                field: "decay_window",
+                reason: "excludes every slice",
+            });
+        }
+        let (floor, peak) = self.level_range(&slices);
+
+        // Margins around the plot area
+        let margin_left = 50.0;
+        let margin_bottom = 30.0;
+        let margin_top = 10.0;
+        let margin_right = 20.0;
+
+        let title_height = if self.title.is_some() {
+            TITLE_AREA_HEIGHT
+        } else {
+            0.0
+        };
+        let plot_width = (self.width - margin_left - margin_right).max(0.0);
+        let plot_height = (self.height - title_height - margin_top - margin_bottom).max(0.0);
+        validate_plot_area(plot_width.into(), plot_height.into())?;
+
+        // The front slice occupies the lower-left part of the plot area
+        let depth_x = plot_width * self.depth;
+        let depth_y = plot_height * self.depth;
+        let front_width = plot_width - depth_x;
+        let front_height = plot_height - depth_y;
+
+        let n = self.freq.len();
+        let xs = self.freq_positions();
+        let count = slices.len();
+        // (slice position 0..1 front to back, normalized levels, color)
+        let layers: Vec<(f32, Vec<f32>, d3rs::color::D3Color)> = slices
+            .iter()
+            .enumerate()
+            .map(|(k, &s)| {
+                let t = if count > 1 {
+                    k as f32 / (count - 1) as f32
+                } else {
+                    0.0
+                };
+                let levels = self.z[s * n..(s + 1) * n]
+                    .iter()
+                    .map(|&v| ((v - floor) / (peak - floor)).clamp(0.0, 1.0) as f32)
+                    .collect();
+                (t, levels, self.color_scale.map(t as f64))
+            })
+            .collect();
+        let outline = self.outline;
+
+        let ridges = canvas(
+            move |bounds, _, _| bounds,
+            move |_, bounds, window, _| {
+                let origin_x: f32 = bounds.origin.x.into();
+                let origin_y: f32 = bounds.origin.y.into();
+
+                // Back to front so nearer slices cover farther ones
+                for (t, levels, color) in layers.iter().rev() {
+                    let left = origin_x + t * depth_x;
+                    let base = origin_y + plot_height - t * depth_y;
+                    let at = |i: usize| {
+                        point(
+                            px(left + xs[i] * front_width),
+                            px(base - levels[i] * front_height),
+                        )
+                    };
+
+                    let mut fill = PathBuilder::fill();
+                    fill.move_to(point(px(left + xs[0] * front_width), px(base)));
+                    for i in 0..levels.len() {
+                        fill.line_to(at(i));
+                    }
+                    fill.line_to(point(
+                        px(left + xs[levels.len() - 1] * front_width),
+                        px(base),
+                    ));
+                    fill.close();
+                    if let Ok(path) = fill.build() {
+                        window.paint_path(path, color.to_rgba());
+                    }
+
+                    if outline && levels.len() > 1 {
+                        let mut stroke = PathBuilder::stroke(px(1.0));
+                        stroke.move_to(at(0));
+                        for i in 1..levels.len() {
+                            stroke.line_to(at(i));
+                        }
+                        if let Ok(path) = stroke.build() {
+                            window.paint_path(path, color.darker(1.0).to_rgba());
+                        }
+                    }
+                }
+            },
+        )
+        .size_full()
+        .absolute()
+        .inset_0();
+
+        // Axes are drawn against the front slice
+        let theme = ChartTheme::current().axis_theme();
+        let level_scale = LinearScale::new()
+            .domain(floor, peak)
+            .range(front_height as f64, 0.0);
+        let (f_lo, f_hi) = (self.freq[0], self.freq[n - 1]);
+        let freq_axis: AnyElement = match self.x_scale_type {
+            ScaleType::Log => render_axis(
+                &LogScale::new()
+                    .domain(f_lo, f_hi)
+                    .range(0.0, front_width as f64),
+                &AxisConfig::bottom(),
+                front_width,
+                &theme,
+            )
+            .into_any_element(),
+            ScaleType::Linear => render_axis(
+                &LinearScale::new()
+                    .domain(f_lo, f_hi)
+                    .range(0.0, front_width as f64),
+                &AxisConfig::bottom(),
+                front_width,
+                &theme,
+            )
+            .into_any_element(),
+        };
+
+        let mut container = div()
+            .w(px(self.width))
+            .h(px(self.height))
+            .relative()
+            .flex()
+            .flex_col();
+
+        if let Some(title) = &self.title {
+            let font_config =
+                VectorFontConfig::horizontal(DEFAULT_TITLE_FONT_SIZE, hsla(0.0, 0.0, 0.2, 1.0));
+            container = container.child(
+                div()
+                    .w_full()
+                    .h(px(title_height))
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .child(render_vector_text(title, &font_config)),
+            );
+        }
+
+        container = container.child(
+            div()
+                .pt(px(margin_top))
+                .flex()
+                .child(div().w(px(margin_left)).pt(px(depth_y)).child(render_axis(
+                    &level_scale,
+                    &AxisConfig::left(),
+                    front_height,
+                    &theme,
+                )))
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .child(
+                            div()
+                                .w(px(plot_width))
+                                .h(px(plot_height))
+                                .relative()
+                                .overflow_hidden()
+                                .child(ridges),
+                        )
+                        .child(freq_axis),
+                ),
+        );
+
+        Ok(container)
+    }
+}
+
+/// Create a waterfall (CSD) chart.
+///
+/// `z` holds one magnitude spectrum in dB per time slice, row-major:
+/// `z[t * freq.len() + f]`, with `times.len()` rows.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use gpui_px::{waterfall, ColorScale};
+///
+/// let freq: Vec<f64> = (0..200).map(|i| 20.0 * 1000f64.powf(i as f64 / 199.0)).collect();
+/// let times: Vec<f64> = (0..30).map(|i| i as f64 * 0.2).collect(); // ms
+/// let z: Vec<f64> = times
+///     .iter()
+///     .flat_map(|t| freq.iter().map(move |f| -t * 4.0 * (1.0 + (f / 1000.0).ln().abs())))
+///     .collect();
+///
+/// let chart = waterfall(&freq, &times, &z)
+///     .title("Cumulative Spectral Decay")
+///     .decay_window(5.0)
+///     .floor(-35.0)
+///     .color_scale(ColorScale::Inferno)
+///     .build();
+/// ```
+pub fn waterfall(freq: &[f64], times: &[f64], z: &[f64]) -> WaterfallChart {
+    let defaults = ChartDefaults::current();
+    WaterfallChart {
+        freq: freq.to_vec(),
+        times: times.to_vec(),
+        z: z.to_vec(),
+        x_scale_type: ScaleType::Log,
+        decay_window: None,
+        floor_db: DEFAULT_FLOOR_DB,
+        depth: DEFAULT_DEPTH,
+        color_scale: ColorScale::Viridis,
+        outline: true,
+        title: None,
+        width: defaults.width,
+        height: defaults.height,
+        layout: ChartLayout::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> WaterfallChart {
+        let freq = [100.0, 1000.0, 10000.0];
+        let times = [0.0, 1.0, 2.0];
+        let z = [
+            0.0, -3.0, -6.0, // t = 0
+            -10.0, -13.0, -16.0, // t = 1
+            -20.0, -23.0, -40.0, // t = 2
+        ];
+        waterfall(&freq, &times, &z)
+    }
+
+    #[test]
+    fn test_waterfall_empty_data() {
+        let result = waterfall(&[], &[], &[]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::EmptyData { field: "freq" })
+        ));
+    }
+
+    #[test]
+    fn test_waterfall_grid_mismatch() {
+        let result = waterfall(&[100.0, 200.0], &[0.0, 1.0], &[0.0; 3]).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::GridDimensionMismatch { expected: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn test_waterfall_decay_window() {
+        let chart = sample().decay_window(1.5);
+        assert_eq!(chart.visible_slices(), vec![0, 1]);
+
+        let result = sample().decay_window(-1.0).build();
+        assert!(matches!(
+            result,
+            Err(ChartError::InvalidData {
+                field: "decay_window",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_waterfall_level_range_and_positions() {
+        let chart = sample().floor(-20.0);
+        assert_eq!(chart.level_range(&[0, 1, 2]), (-20.0, 0.0));
+        assert_eq!(chart.freq_positions(), vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_waterfall_successful_build() {
+        let result = sample()
+            .title("CSD")
+            .x_scale(ScaleType::Linear)
+            .depth(0.4)
+            .size(500.0, 300.0)
+            .build();
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,211 @@
+//! Watermark layer for branded charts.
+//!
+//! Every builder accepts `.watermark(content, position, opacity)`, with a
+//! text or an image file as content. The watermark is drawn over the plot
+//! background and under the data:
+//!
+//! ```rust,ignore
+//! let chart = line(&freq, &spl)
+//!     .watermark("ACME Audio", WatermarkPosition::BottomRight, 0.4)
+//!     .build()?;
+//!
+//! let chart = bar(&categories, &values)
+//!     .watermark(PathBuf::from("assets/logo.png"), WatermarkPosition::Center, 0.1)
+//!     .build()?;
+//! ```
+//!
+//! To brand every chart of an app, set the watermark in the
+//! [`ChartDefaults`](crate::ChartDefaults) global instead; `.no_watermark()`
+//! opts a chart out.
+
+use d3rs::text::{VectorFontConfig, measure_text_width, render_vector_text};
+use gpui::prelude::*;
+use gpui::{AnyElement, ObjectFit, Rgba, SharedString, div, img, px};
+use std::path::{Path, PathBuf};
+
+/// Font size of a centered text watermark
+const CENTER_FONT_SIZE: f32 = 48.0;
+
+/// Font size of a text watermark in a corner
+const CORNER_FONT_SIZE: f32 = 12.0;
+
+/// Largest share of the chart width taken by a centered watermark
+const CENTER_SHARE: f32 = 0.6;
+
+/// Largest share of the chart width taken by a watermark in a corner
+const CORNER_SHARE: f32 = 0.3;
+
+/// Side of a watermark image in a corner, relative to the smaller side of
+/// the chart
+const CORNER_IMAGE_SHARE: f32 = 0.15;
+
+/// Distance of the watermark from the edges, in pixels
+const WATERMARK_INSET: f32 = 8.0;
+
+/// Text or image shown by a [`Watermark`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatermarkContent {
+    /// Text drawn in the chart title color
+    Text(SharedString),
+    /// Image file (PNG, JPEG, SVG, ...) fitted in a square
+    Image(PathBuf),
+}
+
+impl From<&str> for WatermarkContent {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string().into())
+    }
+}
+
+impl From<String> for WatermarkContent {
+    fn from(text: String) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<SharedString> for WatermarkContent {
+    fn from(text: SharedString) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<PathBuf> for WatermarkContent {
+    fn from(path: PathBuf) -> Self {
+        Self::Image(path)
+    }
+}
+
+impl From<&Path> for WatermarkContent {
+    fn from(path: &Path) -> Self {
+        Self::Image(path.to_path_buf())
+    }
+}
+
+/// Placement of a [`Watermark`] in the plot area.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WatermarkPosition {
+    /// Large, across the middle of the chart
+    #[default]
+    Center,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Text or logo drawn behind the data of a chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watermark {
+    pub content: WatermarkContent,
+    pub position: WatermarkPosition,
+    /// Opacity from 0 (invisible) to 1
+    pub opacity: f32,
+}
+
+impl Watermark {
+    /// Create a watermark; the opacity is clamped to 0..=1.
+    pub fn new(
+        content: impl Into<WatermarkContent>,
+        position: WatermarkPosition,
+        opacity: f32,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            position,
+            opacity: if opacity.is_nan() {
+                1.0
+            } else {
+                opacity.clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    /// Layer covering its parent, the plot area of `width` x `height`, with
+    /// the watermark in place. Text is drawn in `color`.
+    pub(crate) fn render(&self, color: Rgba, width: f32, height: f32) -> AnyElement {
+        let layer = div()
+            .absolute()
+            .inset_0()
+            .p(px(WATERMARK_INSET))
+            .flex()
+            .overflow_hidden()
+            .opacity(self.opacity);
+        let layer = match self.position {
+            WatermarkPosition::Center => layer.items_center().justify_center(),
+            WatermarkPosition::TopLeft => layer.items_start().justify_start(),
+            WatermarkPosition::TopRight => layer.items_start().justify_end(),
+            WatermarkPosition::BottomLeft => layer.items_end().justify_start(),
+            WatermarkPosition::BottomRight => layer.items_end().justify_end(),
+        };
+
+        let content = match &self.content {
+            WatermarkContent::Text(text) => {
+                let config =
+                    VectorFontConfig::horizontal(self.font_size(text, width), color.into());
+                render_vector_text(text, &config).into_any_element()
+            }
+            WatermarkContent::Image(path) => {
+                let side = self.image_side(width, height);
+                img(path.clone())
+                    .size(px(side))
+                    .object_fit(ObjectFit::Contain)
+                    .into_any_element()
+            }
+        };
+        layer.child(content).into_any_element()
+    }
+
+    /// Font size of a text watermark, reduced to fit its share of `width`.
+    fn font_size(&self, text: &str, width: f32) -> f32 {
+        let (font_size, share) = match self.position {
+            WatermarkPosition::Center => (CENTER_FONT_SIZE, CENTER_SHARE),
+            _ => (CORNER_FONT_SIZE, CORNER_SHARE),
+        };
+        let text_width = measure_text_width(text, font_size);
+        let max_width = width * share;
+        if text_width > max_width && text_width > 0.0 {
+            font_size * max_width / text_width
+        } else {
+            font_size
+        }
+    }
+
+    /// Side of the square a watermark image is fitted in.
+    fn image_side(&self, width: f32, height: f32) -> f32 {
+        let share = match self.position {
+            WatermarkPosition::Center => CENTER_SHARE,
+            _ => CORNER_IMAGE_SHARE,
+        };
+        width.min(height).max(0.0) * share
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watermark_opacity_is_clamped() {
+        let center = WatermarkPosition::Center;
+        assert_eq!(Watermark::new("Draft", center, 1.5).opacity, 1.0);
+        assert_eq!(Watermark::new("Draft", center, -0.5).opacity, 0.0);
+        assert_eq!(Watermark::new("Draft", center, f32::NAN).opacity, 1.0);
+        assert_eq!(
+            Watermark::new(PathBuf::from("logo.png"), center, 0.2).content,
+            WatermarkContent::Image(PathBuf::from("logo.png"))
+        );
+    }
+
+    #[test]
+    fn test_watermark_text_fits_width() {
+        let center = Watermark::new("Confidential", WatermarkPosition::Center, 0.2);
+        assert_eq!(center.font_size("Confidential", 4000.0), CENTER_FONT_SIZE);
+        let small = center.font_size("Confidential", 200.0);
+        assert!(small < CENTER_FONT_SIZE);
+        assert!(measure_text_width("Confidential", small) <= 200.0 * CENTER_SHARE + 0.01);
+
+        let corner = Watermark::new("ACME", WatermarkPosition::BottomRight, 0.4);
+        assert_eq!(corner.font_size("ACME", 600.0), CORNER_FONT_SIZE);
+        assert_eq!(corner.image_side(600.0, 400.0), 400.0 * CORNER_IMAGE_SHARE);
+    }
+}
@@ -0,0 +1,252 @@
+//! Built-in zoom and pan for charts with continuous axes.
+//!
+//! `.zoomable(true)` on a scatter, line, heatmap or contour chart wraps it in
+//! an [`InteractiveChart`](crate::interaction::InteractiveChart) whose state
+//! is kept between renders, so the view needs no zoom wiring of its own:
+//! - drag to pan, scroll to zoom around the pointer
+//! - Shift+drag to brush a region and zoom into it
+//! - double-click to reset
+//!
+//! Log axes pan and zoom in log space. The zoom is reset when the chart's
+//! own domain changes, e.g. after new data.
+//!
+//! ```rust,ignore
+//! let chart = line(&freq, &spl)
+//!     .x_scale(ScaleType::Log)
+//!     .zoomable(true)
+//!     .build()?;
+//! ```
+//!
+//! The state is kept under the ids of the enclosing elements; give zoomable
+//! charts that share a parent distinct ids with `.zoom_id(...)`.
+
+use crate::ScaleType;
+use crate::error::ChartError;
+use crate::fallback::chart_error;
+use crate::interaction::{InteractiveChartState, interactive};
+use gpui::prelude::*;
+use gpui::{
+    AnyElement, App, Bounds, Element, ElementId, GlobalElementId, InspectorElementId, LayoutId,
+    Pixels, Window,
+};
+use std::{fmt, panic};
+
+/// Zoom options of a chart builder.
+#[derive(Clone, Default)]
+pub(crate) struct ChartZoom {
+    /// Id the zoom state is kept under, when the chart is zoomable
+    pub(crate) id: Option<ElementId>,
+    /// State of the zoomable element rebuilding the chart
+    pub(crate) state: Option<InteractiveChartState>,
+}
+
+impl ChartZoom {
+    /// Zoom options of a new builder, zoomable if `enabled`.
+    pub(crate) fn new(enabled: bool) -> Self {
+        let mut zoom = Self::default();
+        zoom.set_enabled(enabled);
+        zoom
+    }
+
+    pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        self.id = match (enabled, self.id.take()) {
+            (true, id) => Some(id.unwrap_or_else(|| "zoomable-chart".into())),
+            (false, _) => None,
+        };
+    }
+
+    /// Probe to place in the plot area of a chart built by a zoomable element.
+    pub(crate) fn probe(&self) -> Option<impl IntoElement + use<>> {
+        self.state.as_ref().map(InteractiveChartState::plot_probe)
+    }
+}
+
+impl fmt::Debug for ChartZoom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChartZoom")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Unzoomed view of a chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ZoomHome {
+    pub(crate) x_domain: (f64, f64),
+    pub(crate) y_domain: (f64, f64),
+    pub(crate) x_scale: ScaleType,
+    pub(crate) y_scale: ScaleType,
+}
+
+impl ZoomHome {
+    /// Fresh interaction state showing this view.
+    fn state(&self) -> InteractiveChartState {
+        let ((x_min, x_max), (y_min, y_max)) = (self.x_domain, self.y_domain);
+        InteractiveChartState::new(x_min, x_max, y_min, y_max)
+            .with_log_x(self.x_scale == ScaleType::Log)
+            .with_log_y(self.y_scale == ScaleType::Log)
+    }
+}
+
+/// Builds a chart showing the domains of an interaction state.
+type BuildZoomed<C> = fn(C, &InteractiveChartState) -> Result<AnyElement, ChartError>;
+
+/// Element rebuilding `chart` at its current zoom on every render.
+///
+/// `size` is the size of the chart, for the error fallback.
+pub(crate) fn build_zoomable<C: Clone + 'static>(
+    id: ElementId,
+    home: ZoomHome,
+    chart: C,
+    size: (f32, f32),
+    build: BuildZoomed<C>,
+) -> AnyElement {
+    let render = move |state: &InteractiveChartState| {
+        build(chart.clone(), state).unwrap_or_else(|error| chart_error(&error, size.0, size.1))
+    };
+    ZoomableChart {
+        id,
+        home,
+        render: Box::new(render),
+    }
+    .into_any_element()
+}
+
+/// Zoom state of a zoomable chart, kept in its element state.
+struct ZoomSlot {
+    home: ZoomHome,
+    state: InteractiveChartState,
+}
+
+/// Element keeping the zoom state of a chart between renders.
+struct ZoomableChart {
+    id: ElementId,
+    home: ZoomHome,
+    render: Box<dyn Fn(&InteractiveChartState) -> AnyElement>,
+}
+
+impl IntoElement for ZoomableChart {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for ZoomableChart {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let home = self.home;
+        let state = window.with_element_state(id.unwrap(), |slot: Option<ZoomSlot>, _window| {
+            let slot = slot
+                .filter(|slot| slot.home == home)
+                .unwrap_or_else(|| ZoomSlot {
+                    home,
+                    state: home.state(),
+                });
+            (slot.state.clone(), slot)
+        });
+        let chart = (self.render)(&state);
+        let mut child = interactive("zoom", chart, state).build().into_any_element();
+        (child.request_layout(window, cx), child)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        child: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        child.prepaint(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        child: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        child.paint(window, cx);
+    }
+}
+
+/// Zoom builder methods of a chart builder.
+///
+/// The builder needs a `ChartZoom` field, passed as `$zoom`, and its
+/// `build_chart()` must hand the chart to [`build_zoomable`] when the
+/// field has an id.
+macro_rules! chart_zoom_methods {
+    ($zoom:ident) => {
+        /// Zoom and pan with the mouse: drag to pan, scroll to zoom around the
+        /// pointer, Shift+drag to zoom into a region and double-click to
+        /// reset. The zoom is kept between renders.
+        pub fn zoomable(mut self, zoomable: bool) -> Self {
+            self.$zoom.set_enabled(zoomable);
+            self
+        }
+
+        /// Make the chart zoomable, keeping its zoom under `id` to tell it
+        /// apart from other zoomable charts in the same parent element.
+        pub fn zoom_id(mut self, id: impl Into<gpui::ElementId>) -> Self {
+            self.$zoom.id = Some(id.into());
+            self
+        }
+    };
+}
+
+pub(crate) use chart_zoom_methods;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_home_state_follows_log_axes() {
+        let home = ZoomHome {
+            x_domain: (20.0, 20000.0),
+            y_domain: (-40.0, 10.0),
+            x_scale: ScaleType::Log,
+            y_scale: ScaleType::Linear,
+        };
+        let state = home.state();
+        assert_eq!(state.x_domain(), (20.0, 20000.0));
+        assert_eq!(state.y_domain(), (-40.0, 10.0));
+        let interaction = state.interaction.borrow();
+        assert!(interaction.x_is_log && !interaction.y_is_log);
+    }
+
+    #[test]
+    fn test_chart_zoom_enabled_keeps_id() {
+        let mut zoom = ChartZoom::default();
+        zoom.set_enabled(true);
+        assert_eq!(zoom.id, Some("zoomable-chart".into()));
+        zoom.id = Some("spl".into());
+        zoom.set_enabled(true);
+        assert_eq!(zoom.id, Some("spl".into()));
+        zoom.set_enabled(false);
+        assert!(zoom.id.is_none());
+    }
+}
@@ -0,0 +1,435 @@
+//! Theme diff and merge view
+//!
+//! Compares two themes token by token:
+//! - Changed tokens highlighted, with before/after swatches
+//! - Selective merge of tokens from the other theme into a new theme
+
+use crate::editor::all_color_fields;
+use crate::theme::{Color, ColorGroup, EditorTheme};
+use gpui::prelude::*;
+use gpui::*;
+use gpui_ui_kit::{
+    Button, ButtonSize, ButtonVariant, Checkbox, HStack, StackSpacing, Text, TextSize, TextWeight,
+    VStack,
+};
+use std::collections::HashSet;
+
+/// Transparent color constant
+const TRANSPARENT: Rgba = Rgba {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.0,
+};
+
+/// One color token in both themes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenDiff {
+    pub group: ColorGroup,
+    pub name: &'static str,
+    /// Color in the base theme
+    pub base: Color,
+    /// Color in the other theme
+    pub other: Color,
+}
+
+impl TokenDiff {
+    /// Whether the token differs between the themes
+    pub fn is_changed(&self) -> bool {
+        self.base != self.other
+    }
+}
+
+/// Compare every color token of two themes, in editor order
+pub fn diff_themes(base: &EditorTheme, other: &EditorTheme) -> Vec<TokenDiff> {
+    all_color_fields()
+        .into_iter()
+        .map(|field| TokenDiff {
+            group: field.group,
+            name: field.name,
+            base: (field.getter)(base),
+            other: (field.getter)(other),
+        })
+        .collect()
+}
+
+/// Copy the named tokens of `other` into a copy of `base`
+pub fn merge_tokens(base: &EditorTheme, other: &EditorTheme, tokens: &[&str]) -> EditorTheme {
+    let mut merged = base.clone();
+    for field in all_color_fields() {
+        if tokens.contains(&field.name) {
+            (field.setter)(&mut merged, (field.getter)(other));
+        }
+    }
+    merged
+}
+
+/// Callback receiving the merged theme
+type SaveCallback = Box<dyn Fn(EditorTheme, &mut Window, &mut App)>;
+
+/// Side-by-side diff of two themes with selective merge
+pub struct ThemeDiffView {
+    /// Theme merged into
+    base: EditorTheme,
+    /// Theme tokens are taken from
+    other: EditorTheme,
+    /// Tokens of both themes
+    diffs: Vec<TokenDiff>,
+    /// Tokens taken from the other theme
+    selected: HashSet<&'static str>,
+    /// Hide tokens equal in both themes
+    changed_only: bool,
+    /// Called with the merged theme on save
+    on_save: Option<SaveCallback>,
+}
+
+impl ThemeDiffView {
+    pub fn new(base: EditorTheme, other: EditorTheme) -> Self {
+        let diffs = diff_themes(&base, &other);
+        Self {
+            base,
+            other,
+            diffs,
+            selected: HashSet::new(),
+            changed_only: true,
+            on_save: None,
+        }
+    }
+
+    /// Called with the merged theme when the user saves it
+    pub fn on_save(
+        mut self,
+        handler: impl Fn(EditorTheme, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_save = Some(Box::new(handler));
+        self
+    }
+
+    /// Compare new themes, keeping the selection of tokens that still differ
+    pub fn set_themes(&mut self, base: EditorTheme, other: EditorTheme) {
+        self.diffs = diff_themes(&base, &other);
+        let changed: HashSet<&'static str> = self
+            .diffs
+            .iter()
+            .filter(|diff| diff.is_changed())
+            .map(|diff| diff.name)
+            .collect();
+        self.selected.retain(|name| changed.contains(name));
+        self.base = base;
+        self.other = other;
+    }
+
+    /// Tokens of both themes
+    pub fn diffs(&self) -> &[TokenDiff] {
+        &self.diffs
+    }
+
+    /// Number of tokens that differ
+    pub fn changed_count(&self) -> usize {
+        self.diffs.iter().filter(|diff| diff.is_changed()).count()
+    }
+
+    /// Take a token from the other theme, or stop taking it
+    pub fn toggle(&mut self, name: &'static str) {
+        if !self.selected.remove(name) {
+            self.selected.insert(name);
+        }
+    }
+
+    /// Take every changed token from the other theme
+    pub fn select_all(&mut self) {
+        self.selected = self
+            .diffs
+            .iter()
+            .filter(|diff| diff.is_changed())
+            .map(|diff| diff.name)
+            .collect();
+    }
+
+    /// Keep every token of the base theme
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Base theme with the selected tokens of the other theme
+    pub fn merged(&self) -> EditorTheme {
+        let tokens: Vec<&str> = self.selected.iter().copied().collect();
+        let mut merged = merge_tokens(&self.base, &self.other, &tokens);
+        merged.name = format!("{} + {}", self.base.name, self.other.name);
+        merged
+    }
+
+    fn save(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(on_save) = &self.on_save {
+            on_save(self.merged(), window, cx);
+        }
+        self.selected.clear();
+        cx.notify();
+    }
+
+    /// Render a swatch with its hex value
+    fn render_swatch(&self, color: Color, align_end: bool) -> impl IntoElement {
+        let theme = &self.base;
+        let swatch = div()
+            .w(px(20.0))
+            .h(px(20.0))
+            .rounded(px(4.0))
+            .bg(color.to_rgba())
+            .border_1()
+            .border_color(theme.border.to_rgba());
+        let hex = Text::new(SharedString::from(color.to_hex_string()))
+            .size(TextSize::Xs)
+            .color(theme.text_muted.to_rgba());
+
+        let row = HStack::new().spacing(StackSpacing::Sm);
+        if align_end {
+            row.child(hex).child(swatch).build()
+        } else {
+            row.child(swatch).child(hex).build()
+        }
+    }
+
+    /// Render one token row
+    fn render_row(&self, diff: &TokenDiff, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = &self.base;
+        let changed = diff.is_changed();
+        let selected = self.selected.contains(diff.name);
+        let name = diff.name;
+        let bg = if changed {
+            theme.accent_muted.to_rgba()
+        } else {
+            TRANSPARENT
+        };
+
+        let row = div()
+            .id(SharedString::from(format!("diff-{}", diff.name)))
+            .px_3()
+            .py_1()
+            .bg(bg)
+            .border_b_1()
+            .border_color(theme.border.to_rgba());
+        let row = if changed {
+            row.cursor_pointer()
+                .hover(|s| s.bg(theme.surface_hover.to_rgba()))
+                .on_mouse_up(
+                    MouseButton::Left,
+                    cx.listener(move |this, _: &MouseUpEvent, _window, cx| {
+                        this.toggle(name);
+                        cx.notify();
+                    }),
+                )
+        } else {
+            row
+        };
+
+        row.child(
+            HStack::new()
+                .spacing(StackSpacing::Md)
+                .child(div().w(px(20.0)).children(changed.then(|| {
+                    Checkbox::new(SharedString::from(format!("take-{}", name))).checked(selected)
+                })))
+                .child(
+                    div().w(px(160.0)).child(
+                        Text::new(diff.group.label())
+                            .size(TextSize::Xs)
+                            .color(theme.text_muted.to_rgba()),
+                    ),
+                )
+                .child(
+                    div().flex_1().child(
+                        Text::new(name)
+                            .size(TextSize::Sm)
+                            .weight(if changed {
+                                TextWeight::Bold
+                            } else {
+                                TextWeight::Normal
+                            })
+                            .color(theme.text_primary.to_rgba()),
+                    ),
+                )
+                .child(self.render_swatch(diff.base, true))
+                .child(
+                    Text::new(if changed { "→" } else { "=" })
+                        .size(TextSize::Sm)
+                        .color(theme.text_secondary.to_rgba()),
+                )
+                .child(self.render_swatch(diff.other, false))
+                .build(),
+        )
+    }
+
+    /// Render the header with theme names and actions
+    fn render_header(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = &self.base;
+        let changed_only = self.changed_only;
+
+        div()
+            .px_4()
+            .py_2()
+            .bg(theme.background_secondary.to_rgba())
+            .border_b_1()
+            .border_color(theme.border.to_rgba())
+            .child(
+                HStack::new()
+                    .spacing(StackSpacing::Md)
+                    .child(
+                        Text::new(SharedString::from(format!(
+                            "{} ↔ {}",
+                            self.base.name, self.other.name
+                        )))
+                        .size(TextSize::Md)
+                        .weight(TextWeight::Bold)
+                        .color(theme.text_primary.to_rgba()),
+                    )
+                    .child(
+                        Text::new(SharedString::from(format!(
+                            "{} changed, {} selected",
+                            self.changed_count(),
+                            self.selected.len()
+                        )))
+                        .size(TextSize::Sm)
+                        .color(theme.text_secondary.to_rgba()),
+                    )
+                    .child(div().flex_1())
+                    .child(
+                        Button::new(
+                            "diff-changed-only",
+                            if changed_only {
+                                "Show All"
+                            } else {
+                                "Changed Only"
+                            },
+                        )
+                        .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Sm)
+                        .build()
+                        .on_click(cx.listener(
+                            |this, _: &ClickEvent, _window, cx| {
+                                this.changed_only = !this.changed_only;
+                                cx.notify();
+                            },
+                        )),
+                    )
+                    .child(
+                        Button::new("diff-select-all", "Select All")
+                            .variant(ButtonVariant::Ghost)
+                            .size(ButtonSize::Sm)
+                            .build()
+                            .on_click(cx.listener(|this, _: &ClickEvent, _window, cx| {
+                                this.select_all();
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("diff-clear", "Clear")
+                            .variant(ButtonVariant::Ghost)
+                            .size(ButtonSize::Sm)
+                            .build()
+                            .on_click(cx.listener(|this, _: &ClickEvent, _window, cx| {
+                                this.clear_selection();
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("diff-save", "Save Merged Theme")
+                            .variant(ButtonVariant::Primary)
+                            .size(ButtonSize::Sm)
+                            .disabled(self.selected.is_empty())
+                            .build()
+                            .on_click(cx.listener(|this, _: &ClickEvent, window, cx| {
+                                this.save(window, cx);
+                            })),
+                    )
+                    .build(),
+            )
+    }
+}
+
+impl Render for ThemeDiffView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = &self.base;
+        let diffs: Vec<TokenDiff> = self
+            .diffs
+            .iter()
+            .filter(|diff| !self.changed_only || diff.is_changed())
+            .copied()
+            .collect();
+
+        let content = if diffs.is_empty() {
+            div()
+                .p_4()
+                .child(
+                    Text::new("The themes have the same colors")
+                        .size(TextSize::Md)
+                        .color(theme.text_muted.to_rgba()),
+                )
+                .into_any_element()
+        } else {
+            VStack::new()
+                .spacing(StackSpacing::None)
+                .children(diffs.iter().map(|diff| self.render_row(diff, cx)))
+                .build()
+                .into_any_element()
+        };
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(theme.background.to_rgba())
+            .child(self.render_header(cx))
+            .child(
+                div()
+                    .id("theme-diff-rows")
+                    .flex_1()
+                    .min_h_0()
+                    .overflow_y_scroll()
+                    .child(content),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_themes_marks_changed_tokens() {
+        let dark = EditorTheme::dark();
+        assert!(diff_themes(&dark, &dark).iter().all(|d| !d.is_changed()));
+
+        let light = EditorTheme::light();
+        let diffs = diff_themes(&dark, &light);
+        assert_eq!(diffs.len(), all_color_fields().len());
+        let background = diffs.iter().find(|d| d.name == "Background").unwrap();
+        assert!(background.is_changed());
+        assert_eq!(background.base, dark.background);
+        assert_eq!(background.other, light.background);
+    }
+
+    #[test]
+    fn test_merge_tokens_takes_only_selected() {
+        let dark = EditorTheme::dark();
+        let light = EditorTheme::light();
+        let merged = merge_tokens(&dark, &light, &["Background"]);
+        assert_eq!(merged.background, light.background);
+        assert_eq!(merged.accent, dark.accent);
+        assert_eq!(merged.name, dark.name);
+    }
+
+    #[test]
+    fn test_diff_view_selection() {
+        let mut view = ThemeDiffView::new(EditorTheme::dark(), EditorTheme::light());
+        view.toggle("Background");
+        assert_eq!(view.merged().background, EditorTheme::light().background);
+        view.toggle("Background");
+        assert_eq!(view.merged().background, EditorTheme::dark().background);
+
+        view.select_all();
+        let changed = view.changed_count();
+        assert_eq!(view.selected.len(), changed);
+        // Comparing a theme with itself drops the selection
+        view.set_themes(EditorTheme::dark(), EditorTheme::dark());
+        assert!(view.selected.is_empty());
+    }
+}
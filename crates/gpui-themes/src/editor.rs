@@ -634,6 +634,16 @@ impl ThemeEditor {
         cx.notify();
     }
 
+    /// Regenerate the semantic tokens from the current accent color
+    fn generate_from_accent(&mut self, cx: &mut Context<Self>) {
+        let (_, _, background_l) = self.theme.background.to_hsl();
+        self.theme = EditorTheme::from_accent(self.theme.accent, background_l < 0.5);
+        self.showcase.update(cx, |showcase, _| {
+            showcase.set_theme(self.theme.clone());
+        });
+        cx.notify();
+    }
+
     /// Open color picker modal for current field
     fn open_color_modal(&mut self, cx: &mut Context<Self>) {
         // Clone field info before mutating self
@@ -1109,6 +1119,15 @@ impl ThemeEditor {
                                         this.load_preset("light", cx);
                                     })),
                             )
+                            .child(
+                                Button::new("generate-from-accent", "Generate from Accent")
+                                    .variant(ButtonVariant::Secondary)
+                                    .size(ButtonSize::Sm)
+                                    .build()
+                                    .on_click(cx.listener(|this, _: &ClickEvent, _window, cx| {
+                                        this.generate_from_accent(cx);
+                                    })),
+                            )
                             .build(),
                     ),
             )
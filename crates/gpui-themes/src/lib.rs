@@ -5,7 +5,9 @@
 //! - A color picker component for editing colors (re-exported from gpui-ui-kit)
 //! - A component showcase for previewing theme changes
 //! - A theme editor application
+//! - A theme diff view for merging tokens between themes
 
+mod diff;
 mod editor;
 mod showcase;
 mod theme;
@@ -13,6 +15,7 @@ mod theme;
 // Re-export ColorPickerView from gpui-ui-kit
 pub use gpui_ui_kit::{ColorPickerMode, ColorPickerView};
 
+pub use diff::{ThemeDiffView, TokenDiff, diff_themes, merge_tokens};
 pub use editor::ThemeEditor;
 pub use showcase::ComponentShowcase;
 pub use theme::{
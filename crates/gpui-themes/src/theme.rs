@@ -2,6 +2,7 @@
 //!
 //! Provides a serializable theme structure that can be exported to JSON or Rust code.
 
+use gpui_ui_kit::{ColorPalette, ColorToken, darken_oklch, from_oklch, lighten_oklch, to_oklch};
 use serde::{Deserialize, Serialize};
 
 // Re-export Color from gpui-ui-kit
@@ -802,6 +803,83 @@ impl EditorTheme {
         }
     }
 
+    /// Generate a theme whose semantic tokens all derive from one accent color
+    ///
+    /// Backgrounds, text, borders, accent variants and status colors come
+    /// from [`ColorPalette::from_accent`]; plugin, graph and
+    /// meter colors are kept from the dark or light preset.
+    pub fn from_accent(accent: Color, dark: bool) -> Self {
+        let base = if dark { Self::dark() } else { Self::light() };
+        let palette = ColorPalette::from_accent(accent.to_rgba(), dark);
+        let page = palette.backgrounds.page.base;
+        let surface = palette.backgrounds.surface.base;
+        // Step away from the page: lighter on dark themes, darker on light ones
+        let step = |color, amount| {
+            if dark {
+                lighten_oklch(color, amount)
+            } else {
+                darken_oklch(color, amount)
+            }
+        };
+        let (accent_l, accent_c, accent_h) = to_oklch(accent.to_rgba());
+        let accent_muted = from_oklch(
+            if dark { 0.4 } else { 0.88 },
+            (accent_c * 0.5).min(0.08),
+            accent_h,
+            1.0,
+        );
+        let text_on_accent = if accent_l > 0.7 {
+            Color::from_hex(0x111111)
+        } else {
+            Color::from_hex(0xffffff)
+        };
+        let semantic = &palette.semantic;
+        let toast_bg = |token: ColorToken| {
+            let (_, c, h) = to_oklch(token.base);
+            Color::from_rgba(from_oklch(
+                if dark { 0.28 } else { 0.94 },
+                (c * 0.3).min(0.04),
+                h,
+                1.0,
+            ))
+        };
+
+        Self {
+            name: format!("{} Accent", accent.to_hex_string()),
+            background: Color::from_rgba(page),
+            background_secondary: Color::from_rgba(step(page, 0.025)),
+            background_tertiary: Color::from_rgba(step(page, 0.05)),
+            surface: Color::from_rgba(surface),
+            surface_hover: Color::from_rgba(step(surface, 0.06)),
+            surface_selected: Color::from_rgba(accent_muted),
+            text_primary: Color::from_rgba(palette.text.primary.base),
+            text_secondary: Color::from_rgba(palette.text.secondary.base),
+            text_muted: Color::from_rgba(palette.text.muted.base),
+            text_disabled: Color::from_rgba(step(page, 0.2)),
+            border: Color::from_rgba(palette.borders.default.base),
+            border_focused: Color::from_rgba(palette.borders.focus.base),
+            accent,
+            accent_hover: Color::from_rgba(semantic.primary.hover),
+            accent_muted: Color::from_rgba(accent_muted),
+            text_on_accent,
+            text_on_accent_muted: text_on_accent.with_alpha(0.8),
+            success: Color::from_rgba(semantic.success.base),
+            warning: Color::from_rgba(semantic.warning.base),
+            error: Color::from_rgba(semantic.error.base),
+            info: Color::from_rgba(semantic.info.base),
+            progress_bar_bg: Color::from_rgba(palette.borders.default.base),
+            progress_bar_fill: accent,
+            toast_success_bg: toast_bg(semantic.success),
+            toast_error_bg: toast_bg(semantic.error),
+            toast_info_bg: toast_bg(semantic.info),
+            toast_warning_bg: toast_bg(semantic.warning),
+            drag_over_highlight: Color::from_rgba(semantic.primary.muted),
+            drag_over_border: accent,
+            grid_color: Color::from_rgba(step(page, 0.08)),
+            ..base
+        }
+    }
+
     /// Save theme to JSON string
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
@@ -1093,4 +1171,26 @@ mod tests {
         assert_eq!(loaded.name, theme.name);
         assert_eq!(loaded.background.r, theme.background.r);
     }
+
+    #[test]
+    fn test_theme_from_accent() {
+        let accent = Color::from_hex(0x7c3aed);
+        let dark = EditorTheme::from_accent(accent, true);
+        assert_eq!(dark.accent, accent);
+        assert_eq!(dark.progress_bar_fill, accent);
+        assert_ne!(dark.accent_hover, accent);
+        let (bg_l, _, _) = dark.background.to_hsl();
+        let (text_l, _, _) = dark.text_primary.to_hsl();
+        assert!(text_l > bg_l);
+
+        let light = EditorTheme::from_accent(accent, false);
+        let (bg_l, _, _) = light.background.to_hsl();
+        let (text_l, _, _) = light.text_primary.to_hsl();
+        assert!(text_l < bg_l);
+        // Non-semantic colors come from the preset
+        assert_eq!(
+            light.plugin_colors.eq,
+            EditorTheme::light().plugin_colors.eq
+        );
+    }
 }
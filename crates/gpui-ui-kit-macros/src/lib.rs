@@ -84,6 +84,11 @@ use syn::{Data, DeriveInput, Expr, Fields, Lit, Meta, Token, parse_macro_input};
 ///
 /// **Border:** `border`, `border_hover`
 ///
+/// **Focus:** `focus_ring`
+///
+/// Shadows come from `theme.elevation`, e.g.
+/// `from_expr = "theme.elevation.shadow(Elevation::Overlay)"`.
+///
 /// # Examples
 ///
 /// ## Basic Color Theme
@@ -0,0 +1,365 @@
+//! Automation server for scripting a MiniApp
+//!
+//! A small line-based command server (TCP, or a Unix socket on Unix) that
+//! lets scripts drive a running app: navigate to a section, set a control,
+//! switch theme, export a snapshot, quit. It is meant for generating docs
+//! screenshots and demos without OS-level window automation.
+//!
+//! Enabled with the `automation` feature and [`MiniAppConfig::automation`].
+//! Each request is one line; each reply is one line, `ok` or `error: <reason>`.
+//!
+//! ```text
+//! $ printf 'navigate scales\nsnapshot docs/images/scales.png\n' | nc localhost 7878
+//! ok
+//! ok
+//! ```
+//!
+//! Supported commands:
+//!
+//! | Command                   | Effect                                        |
+//! |---------------------------|-----------------------------------------------|
+//! | `navigate <section>`      | Show a section (handled by the app)           |
+//! | `set <control> <value>`   | Set a control value (handled by the app)      |
+//! | `snapshot <path>`         | Export a snapshot (handled by the app)        |
+//! | `theme <variant>`         | Switch theme: dark, light, midnight, ...      |
+//! | `ping`                    | Reply `ok`                                    |
+//! | `quit`                    | Quit the application                          |
+//!
+//! [`MiniAppConfig::automation`]: super::MiniAppConfig::automation
+
+use crate::theme::{ThemeState, ThemeVariant};
+use gpui::{App, AsyncApp};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+/// How often the app polls for pending commands
+const POLL_INTERVAL: Duration = Duration::from_millis(30);
+
+/// Where the automation server listens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutomationAddress {
+    /// TCP socket address, e.g. `127.0.0.1:7878`
+    Tcp(String),
+    /// Unix domain socket path
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+/// A parsed automation command
+#[derive(Debug, Clone, PartialEq)]
+pub enum AutomationCommand {
+    /// Show the given section
+    Navigate(String),
+    /// Set a control to a value
+    Set {
+        /// Control identifier
+        control: String,
+        /// Value as sent by the client
+        value: String,
+    },
+    /// Export a snapshot to a path
+    Snapshot(PathBuf),
+    /// Switch the theme variant
+    Theme(ThemeVariant),
+    /// Check that the server is alive
+    Ping,
+    /// Quit the application
+    Quit,
+}
+
+impl AutomationCommand {
+    /// Parse one command line
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let line = line.trim();
+        let (verb, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let rest = rest.trim();
+        let require = |what: &str| {
+            if rest.is_empty() {
+                Err(format!("{verb} requires {what}"))
+            } else {
+                Ok(rest.to_string())
+            }
+        };
+
+        match verb {
+            "navigate" => Ok(Self::Navigate(require("a section")?)),
+            "set" => {
+                let args = require("a control and a value")?;
+                let (control, value) = args
+                    .split_once(char::is_whitespace)
+                    .ok_or_else(|| "set requires a control and a value".to_string())?;
+                Ok(Self::Set {
+                    control: control.to_string(),
+                    value: value.trim().to_string(),
+                })
+            }
+            "snapshot" => Ok(Self::Snapshot(PathBuf::from(require("a path")?))),
+            "theme" => {
+                let name = require("a variant")?;
+                parse_theme(&name)
+                    .map(Self::Theme)
+                    .ok_or_else(|| format!("unknown theme: {name}"))
+            }
+            "ping" => Ok(Self::Ping),
+            "quit" => Ok(Self::Quit),
+            "" => Err("empty command".to_string()),
+            _ => Err(format!("unknown command: {verb}")),
+        }
+    }
+}
+
+fn parse_theme(name: &str) -> Option<ThemeVariant> {
+    match name.to_ascii_lowercase().as_str() {
+        "dark" => Some(ThemeVariant::Dark),
+        "light" => Some(ThemeVariant::Light),
+        "midnight" => Some(ThemeVariant::Midnight),
+        "forest" => Some(ThemeVariant::Forest),
+        "bw" | "black-and-white" | "blackandwhite" => Some(ThemeVariant::BlackAndWhite),
+        _ => None,
+    }
+}
+
+/// App-provided handler for commands MiniApp cannot execute itself
+///
+/// Receives `Navigate`, `Set` and `Snapshot` commands and returns `Err` with
+/// a message to send back to the client.
+pub type AutomationHandler = Rc<dyn Fn(&AutomationCommand, &mut App) -> Result<(), String>>;
+
+/// A command waiting to be executed, with the channel for its reply
+struct PendingCommand {
+    command: Result<AutomationCommand, String>,
+    reply: Sender<Result<(), String>>,
+}
+
+/// Start listening on `address` and execute commands on the app's main thread
+pub(crate) fn start(
+    address: &AutomationAddress,
+    handler: Option<AutomationHandler>,
+    cx: &mut App,
+) -> std::io::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    spawn_listener(address, sender)?;
+
+    cx.spawn(async move |cx: &mut AsyncApp| {
+        loop {
+            cx.background_executor().timer(POLL_INTERVAL).await;
+            if drain(&receiver, handler.as_ref(), cx).is_err() {
+                break;
+            }
+        }
+    })
+    .detach();
+    Ok(())
+}
+
+/// Execute all pending commands; fails once the app is gone
+fn drain(
+    receiver: &Receiver<PendingCommand>,
+    handler: Option<&AutomationHandler>,
+    cx: &mut AsyncApp,
+) -> Result<(), ()> {
+    while let Ok(pending) = receiver.try_recv() {
+        let result = match pending.command {
+            Ok(command) => cx
+                .update(|cx| execute(&command, handler, cx))
+                .map_err(|_| ())?,
+            Err(err) => Err(err),
+        };
+        let _ = pending.reply.send(result);
+    }
+    Ok(())
+}
+
+fn execute(
+    command: &AutomationCommand,
+    handler: Option<&AutomationHandler>,
+    cx: &mut App,
+) -> Result<(), String> {
+    match command {
+        AutomationCommand::Ping => Ok(()),
+        AutomationCommand::Quit => {
+            cx.quit();
+            Ok(())
+        }
+        AutomationCommand::Theme(variant) => {
+            if !cx.has_global::<ThemeState>() {
+                return Err("theme support is not enabled".to_string());
+            }
+            // No fade: snapshots taken right after must see the final colors
+            cx.update_global::<ThemeState, _>(|state, _cx| state.set_variant_immediate(*variant));
+            cx.refresh_windows();
+            Ok(())
+        }
+        AutomationCommand::Navigate(_)
+        | AutomationCommand::Set { .. }
+        | AutomationCommand::Snapshot(_) => match handler {
+            Some(handler) => {
+                let result = handler(command, cx);
+                cx.refresh_windows();
+                result
+            }
+            None => Err("no automation handler registered".to_string()),
+        },
+    }
+}
+
+/// Accept connections on a background thread and forward their commands
+fn spawn_listener(
+    address: &AutomationAddress,
+    sender: Sender<PendingCommand>,
+) -> std::io::Result<()> {
+    match address {
+        AutomationAddress::Tcp(addr) => {
+            let listener = std::net::TcpListener::bind(addr)?;
+            std::thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    let sender = sender.clone();
+                    std::thread::spawn(move || {
+                        if let Ok(reader) = stream.try_clone() {
+                            serve(BufReader::new(reader), stream, &sender);
+                        }
+                    });
+                }
+            });
+        }
+        #[cfg(unix)]
+        AutomationAddress::Unix(path) => {
+            use std::os::unix::fs::FileTypeExt;
+
+            // Remove a stale socket left by a previous run, but never
+            // another kind of file: binding then fails with its error
+            if std::fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_socket()) {
+                std::fs::remove_file(path)?;
+            }
+            let listener = std::os::unix::net::UnixListener::bind(path)?;
+            std::thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    let sender = sender.clone();
+                    std::thread::spawn(move || {
+                        if let Ok(reader) = stream.try_clone() {
+                            serve(BufReader::new(reader), stream, &sender);
+                        }
+                    });
+                }
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Serve one connection: one command per line, one reply per line
+fn serve(reader: impl BufRead, mut writer: impl Write, sender: &Sender<PendingCommand>) {
+    for line in reader.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (reply, response) = mpsc::channel();
+        let pending = PendingCommand {
+            command: AutomationCommand::parse(&line),
+            reply,
+        };
+        if sender.send(pending).is_err() {
+            break;
+        }
+        let message = match response.recv() {
+            Ok(Ok(())) => "ok".to_string(),
+            Ok(Err(err)) => format!("error: {err}"),
+            Err(_) => break,
+        };
+        if writeln!(writer, "{message}").is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            AutomationCommand::parse("navigate scales"),
+            Ok(AutomationCommand::Navigate("scales".to_string()))
+        );
+        assert_eq!(
+            AutomationCommand::parse("set gain  -3.5 dB"),
+            Ok(AutomationCommand::Set {
+                control: "gain".to_string(),
+                value: "-3.5 dB".to_string(),
+            })
+        );
+        assert_eq!(
+            AutomationCommand::parse("snapshot docs/a b.png"),
+            Ok(AutomationCommand::Snapshot(PathBuf::from("docs/a b.png")))
+        );
+        assert_eq!(
+            AutomationCommand::parse("theme Light"),
+            Ok(AutomationCommand::Theme(ThemeVariant::Light))
+        );
+        assert_eq!(
+            AutomationCommand::parse(" ping "),
+            Ok(AutomationCommand::Ping)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(AutomationCommand::parse("").is_err());
+        assert!(AutomationCommand::parse("navigate").is_err());
+        assert!(AutomationCommand::parse("set gain").is_err());
+        assert!(AutomationCommand::parse("theme sepia").is_err());
+        assert!(AutomationCommand::parse("jump").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_listener_keeps_other_files() {
+        let dir = std::env::temp_dir().join(format!("ui-kit-automation-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("automation.sock");
+
+        // A stale socket is replaced
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        let (sender, _receiver) = mpsc::channel();
+        spawn_listener(&AutomationAddress::Unix(path.clone()), sender).unwrap();
+
+        // A regular file is left alone and reported
+        let file = dir.join("notes.txt");
+        std::fs::write(&file, "keep").unwrap();
+        let (sender, _receiver) = mpsc::channel();
+        assert!(spawn_listener(&AutomationAddress::Unix(file.clone()), sender).is_err());
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "keep");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_serve_replies_per_line() {
+        let (sender, receiver) = mpsc::channel::<PendingCommand>();
+        let executor = std::thread::spawn(move || {
+            for pending in receiver {
+                let result = pending.command.map(|_| ());
+                let _ = pending.reply.send(result);
+            }
+        });
+
+        let input = b"ping\n\nbogus\n".as_slice();
+        let mut output = Vec::new();
+        serve(input, &mut output, &sender);
+        drop(sender);
+        executor.join().unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "ok\nerror: unknown command: bogus\n"
+        );
+    }
+}
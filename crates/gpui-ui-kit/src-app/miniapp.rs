@@ -5,6 +5,11 @@
 //! - Theme switching (light/dark) with Cmd+T
 //! - Language switching menu
 //! - Configurable window title and size
+//! - Optional custom title bar for frameless windows
+//! - Named background tasks tracked by the task manager
+//! - Optional session persistence of UI state across launches
+//! - Keyboard shortcut help overlay toggled with `?`
+//! - App-wide globals, such as chart defaults, installed before the view
 //! - Extensible for additional default features
 //!
 //! # Example
@@ -26,9 +31,24 @@
 //! }
 //! ```
 
+#[cfg(feature = "automation")]
+use super::automation::{AutomationAddress, AutomationCommand, AutomationHandler};
+use super::recorder::{self, RecordedEvent, SessionRecorder};
+use super::session::{self, SessionStore};
 use crate::i18n::{I18nState, Language};
-use crate::theme::{ThemeState, ThemeVariant};
+use crate::shortcuts::{ShortcutHelp, ShortcutRegistry, ToggleShortcutHelp};
+use crate::tasks::{TaskHandle, TaskId, TaskManager, spawn_task};
+use crate::theme::{ThemeState, ThemeVariant, animate_theme_transition};
+use crate::title_bar::{TitleBar, title_bar_window_options};
 use gpui::*;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// Session key of the persisted theme variant
+const SESSION_THEME_KEY: &str = "miniapp.theme";
+
+/// Session key of the persisted language code
+const SESSION_LANGUAGE_KEY: &str = "miniapp.language";
 
 /// Configuration for a MiniApp instance
 #[derive(Clone)]
@@ -43,6 +63,8 @@ pub struct MiniAppConfig {
     pub app_name: SharedString,
     /// Enable vertical scrollbar for content
     pub scrollable: bool,
+    /// Draw a [`TitleBar`] instead of the OS title bar
+    pub custom_titlebar: bool,
     /// Enable theme support
     pub with_theme: bool,
     /// Enable i18n support
@@ -51,6 +73,15 @@ pub struct MiniAppConfig {
     pub initial_theme: ThemeVariant,
     /// Initial language
     pub initial_language: Language,
+    /// Record a session trace, written to this path on quit
+    pub record_session: Option<PathBuf>,
+    /// Persist UI state in this session file, restored on launch
+    pub persist_session: Option<PathBuf>,
+    /// Installers of the globals set with [`with_global`](Self::with_global)
+    pub globals: Vec<Rc<dyn Fn(&mut App)>>,
+    /// Automation server address and command handler
+    #[cfg(feature = "automation")]
+    pub automation: Option<(AutomationAddress, Option<AutomationHandler>)>,
 }
 
 impl MiniAppConfig {
@@ -65,10 +96,16 @@ impl MiniAppConfig {
             height: 700.0,
             app_name: title,
             scrollable: true,
+            custom_titlebar: false,
             with_theme: false,
             with_i18n: false,
             initial_theme: ThemeVariant::default(),
             initial_language: Language::default(),
+            record_session: None,
+            persist_session: None,
+            globals: Vec::new(),
+            #[cfg(feature = "automation")]
+            automation: None,
         }
     }
 
@@ -95,6 +132,15 @@ impl MiniAppConfig {
         self
     }
 
+    /// Replace the OS title bar with a themed [`TitleBar`] showing the
+    /// window title
+    ///
+    /// By default, the OS title bar is used.
+    pub fn custom_titlebar(mut self, enabled: bool) -> Self {
+        self.custom_titlebar = enabled;
+        self
+    }
+
     /// Enable theme support with light/dark switching
     pub fn with_theme(mut self, enabled: bool) -> Self {
         self.with_theme = enabled;
@@ -118,6 +164,48 @@ impl MiniAppConfig {
         self.initial_language = language;
         self
     }
+
+    /// Record UI interactions into a session trace saved to `path` on quit
+    ///
+    /// See [`recorder`](super::recorder) for replaying traces.
+    pub fn record_session(mut self, path: Option<PathBuf>) -> Self {
+        self.record_session = path;
+        self
+    }
+
+    /// Restore UI state from the session file at `path` on launch and save
+    /// it there on quit
+    ///
+    /// The theme and language are kept automatically; components keep their
+    /// own state through [`session`](super::session).
+    pub fn persist_session(mut self, path: Option<PathBuf>) -> Self {
+        self.persist_session = path;
+        self
+    }
+
+    /// Install `global` when the app starts, before the view is built
+    ///
+    /// Use it for app-wide configuration read by components, e.g. the
+    /// `ChartDefaults` of gpui-px.
+    pub fn with_global<G: Global + Clone>(mut self, global: G) -> Self {
+        self.globals
+            .push(Rc::new(move |cx: &mut App| cx.set_global(global.clone())));
+        self
+    }
+
+    /// Accept automation commands on `address`
+    ///
+    /// `handler` executes the app-specific commands (navigate, set, snapshot);
+    /// see [`automation`](super::automation) for the protocol.
+    #[cfg(feature = "automation")]
+    pub fn automation(
+        mut self,
+        address: AutomationAddress,
+        handler: impl Fn(&AutomationCommand, &mut App) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.automation = Some((address, Some(Rc::new(handler))));
+        self
+    }
 }
 
 impl Default for MiniAppConfig {
@@ -160,6 +248,30 @@ impl Render for ScrollableWrapper {
     }
 }
 
+/// Window root view, stacking the custom title bar (if any) above the content,
+/// with the shortcut help overlay on top
+struct WindowRoot {
+    title_bar: Option<SharedString>,
+    inner: AnyView,
+}
+
+impl Render for WindowRoot {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .relative()
+            .size_full()
+            .flex()
+            .flex_col()
+            .children(
+                self.title_bar
+                    .clone()
+                    .map(|title| TitleBar::new("miniapp-titlebar").title(title)),
+            )
+            .child(div().flex_1().min_h_0().child(self.inner.clone()))
+            .child(ShortcutHelp::new("miniapp-shortcut-help"))
+    }
+}
+
 /// MiniApp provides a minimal application shell for GPUI examples and showcases
 ///
 /// It handles:
@@ -200,20 +312,88 @@ impl MiniApp {
         let config_clone = config.clone();
 
         Application::new().run(move |cx: &mut App| {
+            // Open the session store if enabled, before restoring any state
+            if let Some(path) = &config_clone.persist_session {
+                cx.set_global(SessionStore::open(
+                    path.clone(),
+                    config_clone.app_name.to_string(),
+                ));
+            }
+
             // Initialize theme state if enabled
             if config_clone.with_theme {
-                cx.set_global(ThemeState::with_variant(config_clone.initial_theme));
+                let variant = session::restore::<String>(cx, SESSION_THEME_KEY)
+                    .and_then(|name| {
+                        ThemeVariant::all()
+                            .iter()
+                            .copied()
+                            .find(|variant| format!("{:?}", variant) == name)
+                    })
+                    .unwrap_or(config_clone.initial_theme);
+                cx.set_global(ThemeState::with_variant(variant));
+                session::register(cx, SESSION_THEME_KEY, |cx| {
+                    cx.try_global::<ThemeState>()
+                        .map(|state| format!("{:?}", state.theme.variant))
+                });
             }
 
             // Initialize i18n state if enabled
             if config_clone.with_i18n {
+                let language = session::restore::<String>(cx, SESSION_LANGUAGE_KEY)
+                    .and_then(|code| {
+                        Language::all()
+                            .iter()
+                            .copied()
+                            .find(|language| language.code() == code)
+                    })
+                    .unwrap_or(config_clone.initial_language);
                 let mut i18n = I18nState::new();
-                i18n.set_language(config_clone.initial_language);
+                i18n.set_language(language);
                 cx.set_global(i18n);
+                session::register(cx, SESSION_LANGUAGE_KEY, |cx| {
+                    cx.try_global::<I18nState>()
+                        .map(|state| state.language.code().to_string())
+                });
+            }
+
+            // Track background tasks spawned through MiniApp::spawn_task
+            cx.set_global(TaskManager::new());
+
+            // Install the app-wide globals of the configuration
+            for install in &config_clone.globals {
+                install(cx);
+            }
+
+            // Install the session recorder if enabled
+            if config_clone.record_session.is_some() {
+                cx.set_global(SessionRecorder::new(config_clone.app_name.to_string()));
+            }
+
+            // Start the automation server if enabled
+            #[cfg(feature = "automation")]
+            if let Some((address, handler)) = config_clone.automation.clone()
+                && let Err(err) = super::automation::start(&address, handler, cx)
+            {
+                eprintln!("Failed to start automation server on {address:?}: {err}");
             }
 
             // Register quit action
-            cx.on_action::<Quit>(|_action, cx| {
+            let trace_path = config_clone.record_session.clone();
+            cx.on_action::<Quit>(move |_action, cx| {
+                if let Some(path) = &trace_path
+                    && let Some(recorder) = cx.try_global::<SessionRecorder>()
+                    && let Err(err) = recorder.trace().save(path)
+                {
+                    eprintln!("Failed to save session trace to {}: {err}", path.display());
+                }
+                if let Some(store) = cx.try_global::<SessionStore>()
+                    && let Err(err) = store.save(cx)
+                {
+                    eprintln!(
+                        "Failed to save session to {}: {err}",
+                        store.path().display()
+                    );
+                }
                 cx.quit();
             });
 
@@ -223,42 +403,48 @@ impl MiniApp {
                     cx.update_global::<ThemeState, _>(|state, _cx| {
                         state.toggle();
                     });
-                    cx.refresh_windows();
+                    Self::record_theme(cx);
+                    animate_theme_transition(cx);
                 });
 
                 cx.on_action::<SetThemeDark>(|_action, cx| {
                     cx.update_global::<ThemeState, _>(|state, _cx| {
                         state.set_variant(ThemeVariant::Dark);
                     });
-                    cx.refresh_windows();
+                    Self::record_theme(cx);
+                    animate_theme_transition(cx);
                 });
 
                 cx.on_action::<SetThemeLight>(|_action, cx| {
                     cx.update_global::<ThemeState, _>(|state, _cx| {
                         state.set_variant(ThemeVariant::Light);
                     });
-                    cx.refresh_windows();
+                    Self::record_theme(cx);
+                    animate_theme_transition(cx);
                 });
 
                 cx.on_action::<SetThemeMidnight>(|_action, cx| {
                     cx.update_global::<ThemeState, _>(|state, _cx| {
                         state.set_variant(ThemeVariant::Midnight);
                     });
-                    cx.refresh_windows();
+                    Self::record_theme(cx);
+                    animate_theme_transition(cx);
                 });
 
                 cx.on_action::<SetThemeForest>(|_action, cx| {
                     cx.update_global::<ThemeState, _>(|state, _cx| {
                         state.set_variant(ThemeVariant::Forest);
                     });
-                    cx.refresh_windows();
+                    Self::record_theme(cx);
+                    animate_theme_transition(cx);
                 });
 
                 cx.on_action::<SetThemeBlackAndWhite>(|_action, cx| {
                     cx.update_global::<ThemeState, _>(|state, _cx| {
                         state.set_variant(ThemeVariant::BlackAndWhite);
                     });
-                    cx.refresh_windows();
+                    Self::record_theme(cx);
+                    animate_theme_transition(cx);
                 });
             }
 
@@ -273,6 +459,12 @@ impl MiniApp {
                         .try_global::<I18nState>()
                         .map(|state| state.language)
                         .unwrap_or(Language::English);
+                    recorder::record(
+                        cx,
+                        RecordedEvent::LanguageChanged {
+                            language: current_language.code().to_string(),
+                        },
+                    );
                     let menus = Self::build_menus_with_language(&config_for_lang, current_language);
                     cx.set_menus(menus);
                     cx.refresh_windows();
@@ -287,6 +479,12 @@ impl MiniApp {
                         .try_global::<I18nState>()
                         .map(|state| state.language)
                         .unwrap_or(Language::English);
+                    recorder::record(
+                        cx,
+                        RecordedEvent::LanguageChanged {
+                            language: current_language.code().to_string(),
+                        },
+                    );
                     let menus = Self::build_menus_with_language(&config_for_lang, current_language);
                     cx.set_menus(menus);
                     cx.refresh_windows();
@@ -301,6 +499,12 @@ impl MiniApp {
                         .try_global::<I18nState>()
                         .map(|state| state.language)
                         .unwrap_or(Language::English);
+                    recorder::record(
+                        cx,
+                        RecordedEvent::LanguageChanged {
+                            language: current_language.code().to_string(),
+                        },
+                    );
                     let menus = Self::build_menus_with_language(&config_for_lang, current_language);
                     cx.set_menus(menus);
                     cx.refresh_windows();
@@ -315,6 +519,12 @@ impl MiniApp {
                         .try_global::<I18nState>()
                         .map(|state| state.language)
                         .unwrap_or(Language::English);
+                    recorder::record(
+                        cx,
+                        RecordedEvent::LanguageChanged {
+                            language: current_language.code().to_string(),
+                        },
+                    );
                     let menus = Self::build_menus_with_language(&config_for_lang, current_language);
                     cx.set_menus(menus);
                     cx.refresh_windows();
@@ -329,6 +539,12 @@ impl MiniApp {
                         .try_global::<I18nState>()
                         .map(|state| state.language)
                         .unwrap_or(Language::English);
+                    recorder::record(
+                        cx,
+                        RecordedEvent::LanguageChanged {
+                            language: current_language.code().to_string(),
+                        },
+                    );
                     let menus = Self::build_menus_with_language(&config_for_lang, current_language);
                     cx.set_menus(menus);
                     cx.refresh_windows();
@@ -343,11 +559,19 @@ impl MiniApp {
             let menus = Self::build_menus_with_language(&config_clone, current_language);
             cx.set_menus(menus);
 
-            // Bind keyboard shortcuts
-            cx.bind_keys([KeyBinding::new("cmd-q", Quit, None)]);
+            // Bind keyboard shortcuts, listed in the shortcut help
+            ShortcutRegistry::bind("cmd-q", Quit, "Application", "Quit", cx);
+            ShortcutRegistry::bind(
+                "?",
+                ToggleShortcutHelp,
+                "Application",
+                "Show keyboard shortcuts",
+                cx,
+            );
+            cx.on_action::<ToggleShortcutHelp>(|_action, cx| ShortcutRegistry::toggle_help(cx));
 
             if config_clone.with_theme {
-                cx.bind_keys([KeyBinding::new("cmd-t", ToggleTheme, None)]);
+                ShortcutRegistry::bind("cmd-t", ToggleTheme, "View", "Toggle theme", cx);
             }
 
             // Create window
@@ -357,43 +581,52 @@ impl MiniApp {
                 cx,
             );
 
-            if config_clone.scrollable {
-                cx.open_window(
-                    WindowOptions {
-                        window_bounds: Some(WindowBounds::Windowed(bounds)),
-                        titlebar: Some(TitlebarOptions {
-                            title: Some(config_clone.title.clone()),
-                            ..Default::default()
-                        }),
-                        ..Default::default()
-                    },
-                    move |_, cx| {
-                        let inner_view = build_view(cx);
-                        cx.new(|_| ScrollableWrapper {
-                            inner: inner_view.into(),
-                        })
-                    },
-                )
-                .unwrap();
+            let options = if config_clone.custom_titlebar {
+                title_bar_window_options(config_clone.title.clone())
             } else {
-                cx.open_window(
-                    WindowOptions {
-                        window_bounds: Some(WindowBounds::Windowed(bounds)),
-                        titlebar: Some(TitlebarOptions {
-                            title: Some(config_clone.title.clone()),
-                            ..Default::default()
-                        }),
+                WindowOptions {
+                    titlebar: Some(TitlebarOptions {
+                        title: Some(config_clone.title.clone()),
                         ..Default::default()
-                    },
-                    |_, cx| build_view(cx),
-                )
-                .unwrap();
-            }
+                    }),
+                    ..Default::default()
+                }
+            };
+            let scrollable = config_clone.scrollable;
+            let title_bar = config_clone
+                .custom_titlebar
+                .then(|| config_clone.title.clone());
+
+            cx.open_window(
+                WindowOptions {
+                    window_bounds: Some(WindowBounds::Windowed(bounds)),
+                    ..options
+                },
+                move |_, cx| {
+                    let mut view: AnyView = build_view(cx).into();
+                    if scrollable {
+                        view = cx.new(|_| ScrollableWrapper { inner: view }).into();
+                    }
+                    cx.new(|_| WindowRoot {
+                        title_bar,
+                        inner: view,
+                    })
+                },
+            )
+            .unwrap();
 
             cx.activate(true);
         });
     }
 
+    /// Record the current theme variant in the session trace
+    fn record_theme(cx: &mut App) {
+        if let Some(state) = cx.try_global::<ThemeState>() {
+            let variant = format!("{:?}", state.theme.variant);
+            recorder::record(cx, RecordedEvent::ThemeChanged { variant });
+        }
+    }
+
     /// Build the menu bar based on configuration and current language
     fn build_menus_with_language(config: &MiniAppConfig, current_language: Language) -> Vec<Menu> {
         let mut menus = Vec::new();
@@ -447,6 +680,15 @@ impl MiniApp {
             });
         }
 
+        // Help menu listing the keyboard shortcuts
+        menus.push(Menu {
+            name: "Help".into(),
+            items: vec![MenuItem::action(
+                "Keyboard Shortcuts  ?",
+                ToggleShortcutHelp,
+            )],
+        });
+
         menus
     }
 
@@ -460,6 +702,18 @@ impl MiniApp {
     {
         Self::run(MiniAppConfig::default(), build_view);
     }
+
+    /// Spawn a named async task tracked by the app's [`TaskManager`]
+    ///
+    /// See [`spawn_task`] for details; show progress with
+    /// [`TaskList`](crate::tasks::TaskList) or
+    /// [`TaskStatusIndicator`](crate::tasks::TaskStatusIndicator).
+    pub fn spawn_task<F>(name: impl Into<SharedString>, cx: &mut App, work: F) -> TaskId
+    where
+        F: AsyncFnOnce(TaskHandle, &mut AsyncApp) -> Result<(), SharedString> + 'static,
+    {
+        spawn_task(name, cx, work)
+    }
 }
 
 #[cfg(test)]
@@ -467,6 +721,7 @@ mod tests {
     use super::MiniAppConfig;
     use crate::i18n::Language;
     use crate::theme::ThemeVariant;
+    use std::path::PathBuf;
 
     // ========================================================================
     // Basic Configuration Tests
@@ -541,6 +796,13 @@ mod tests {
         assert!(!config.scrollable);
     }
 
+    #[test]
+    fn test_config_custom_titlebar() {
+        assert!(!MiniAppConfig::new("Test").custom_titlebar);
+        let config = MiniAppConfig::new("Test").custom_titlebar(true);
+        assert!(config.custom_titlebar);
+    }
+
     #[test]
     fn test_config_scrollable_enabled() {
         let config = MiniAppConfig::new("Test").scrollable(true);
@@ -720,6 +982,14 @@ mod tests {
     // Default Value Verification Tests
     // ========================================================================
 
+    #[test]
+    fn test_config_persist_session() {
+        let config = MiniAppConfig::new("Test");
+        assert!(config.persist_session.is_none());
+        let config = config.persist_session(Some(PathBuf::from("session.json")));
+        assert_eq!(config.persist_session, Some(PathBuf::from("session.json")));
+    }
+
     #[test]
     fn test_config_all_defaults() {
         let config = MiniAppConfig::new("Test");
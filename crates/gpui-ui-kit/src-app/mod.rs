@@ -3,6 +3,14 @@
 //! This module provides reusable application shells that handle common
 //! boilerplate like menus, window creation, and keyboard shortcuts.
 
+#[cfg(feature = "automation")]
+pub mod automation;
 pub mod miniapp;
+pub mod recorder;
+pub mod session;
 
+#[cfg(feature = "automation")]
+pub use automation::{AutomationAddress, AutomationCommand, AutomationHandler};
 pub use miniapp::{MiniApp, MiniAppConfig};
+pub use recorder::{RecordedEvent, SessionRecorder, SessionReplay, SessionTrace, TraceEntry};
+pub use session::{SessionState, SessionStore};
@@ -0,0 +1,368 @@
+//! Session recording and replay
+//!
+//! An opt-in recorder that logs high-level UI events (clicks on components,
+//! value changes, section navigation, theme and language switches) into a
+//! JSON trace. Traces can be attached to bug reports and replayed later to
+//! reproduce an issue, or scripted by hand for demos.
+//!
+//! Recording is enabled with [`MiniAppConfig::record_session`]; components
+//! report their events through [`record`], which is a no-op when no recorder
+//! is installed.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use gpui_ui_kit::app::recorder::{SessionReplay, SessionTrace};
+//!
+//! // Record: the trace is written when the app quits
+//! MiniApp::run(
+//!     MiniAppConfig::new("Demo").record_session(Some("session.json".into())),
+//!     |cx| cx.new(Demo::new),
+//! );
+//!
+//! // Replay inside a view
+//! let trace = SessionTrace::load("session.json")?;
+//! SessionReplay::new(trace).speed(2.0).run(window, cx, |event, window, cx| {
+//!     demo.apply_event(event, window, cx);
+//! });
+//! ```
+//!
+//! [`MiniAppConfig::record_session`]: super::MiniAppConfig::record_session
+
+use gpui::{App, AsyncWindowContext, Global, Window};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Current trace format version
+pub const TRACE_VERSION: u32 = 1;
+
+/// A high-level UI event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordedEvent {
+    /// A component was clicked
+    Click {
+        /// Element id of the component
+        id: String,
+    },
+    /// A control changed value
+    ValueChanged {
+        /// Element id of the control
+        id: String,
+        /// New value
+        value: serde_json::Value,
+    },
+    /// The user navigated to a section (tab, page, wizard step)
+    Navigate {
+        /// Section identifier
+        section: String,
+    },
+    /// The theme variant changed
+    ThemeChanged {
+        /// Variant name
+        variant: String,
+    },
+    /// The language changed
+    LanguageChanged {
+        /// Language code
+        language: String,
+    },
+    /// Application-defined event
+    Custom {
+        /// Event name
+        name: String,
+        /// Event payload
+        #[serde(default)]
+        data: serde_json::Value,
+    },
+}
+
+/// A recorded event with its time offset from the start of the session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// Milliseconds since recording started
+    pub t_ms: u64,
+    /// The event
+    #[serde(flatten)]
+    pub event: RecordedEvent,
+}
+
+/// A replayable session trace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionTrace {
+    /// Trace format version
+    pub version: u32,
+    /// Name of the recording application
+    pub app: String,
+    /// Recorded events in time order
+    pub entries: Vec<TraceEntry>,
+}
+
+impl SessionTrace {
+    /// Create an empty trace for the given application
+    pub fn new(app: impl Into<String>) -> Self {
+        Self {
+            version: TRACE_VERSION,
+            app: app.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Append an event at the given time offset
+    pub fn push(&mut self, t_ms: u64, event: RecordedEvent) {
+        self.entries.push(TraceEntry { t_ms, event });
+    }
+
+    /// Serialize to pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Parse a trace from JSON
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Write the trace to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+
+    /// Read a trace from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_json(&json).map_err(std::io::Error::other)
+    }
+}
+
+/// Global recorder state, installed by MiniApp when recording is enabled
+pub struct SessionRecorder {
+    started: Instant,
+    paused: bool,
+    trace: SessionTrace,
+}
+
+impl Global for SessionRecorder {}
+
+impl SessionRecorder {
+    /// Create a recorder for the given application name
+    pub fn new(app: impl Into<String>) -> Self {
+        Self {
+            started: Instant::now(),
+            paused: false,
+            trace: SessionTrace::new(app),
+        }
+    }
+
+    /// Pause or resume recording
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Whether recording is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Record an event now (ignored while paused)
+    pub fn push(&mut self, event: RecordedEvent) {
+        if !self.paused {
+            let t_ms = self.started.elapsed().as_millis() as u64;
+            self.trace.push(t_ms, event);
+        }
+    }
+
+    /// The trace recorded so far
+    pub fn trace(&self) -> &SessionTrace {
+        &self.trace
+    }
+
+    /// Drop all recorded events and restart the clock
+    pub fn clear(&mut self) {
+        self.trace.entries.clear();
+        self.started = Instant::now();
+    }
+}
+
+/// Record an event if a [`SessionRecorder`] is installed
+pub fn record(cx: &mut App, event: RecordedEvent) {
+    if cx.has_global::<SessionRecorder>() {
+        cx.update_global::<SessionRecorder, _>(|recorder, _cx| recorder.push(event));
+    }
+}
+
+/// Record a click on the component with the given id
+pub fn record_click(cx: &mut App, id: impl ToString) {
+    record(cx, RecordedEvent::Click { id: id.to_string() });
+}
+
+/// Record a value change on the control with the given id
+pub fn record_value(cx: &mut App, id: impl ToString, value: impl Into<serde_json::Value>) {
+    record(
+        cx,
+        RecordedEvent::ValueChanged {
+            id: id.to_string(),
+            value: value.into(),
+        },
+    );
+}
+
+/// Record navigation to a section
+pub fn record_navigation(cx: &mut App, section: impl ToString) {
+    record(
+        cx,
+        RecordedEvent::Navigate {
+            section: section.to_string(),
+        },
+    );
+}
+
+/// Drives a trace back into the application
+///
+/// Events are delivered to a handler at their recorded time offsets (scaled
+/// by the replay speed); the handler maps them onto the application state.
+pub struct SessionReplay {
+    trace: SessionTrace,
+    speed: f32,
+}
+
+impl SessionReplay {
+    /// Create a replay for the given trace at normal speed
+    pub fn new(trace: SessionTrace) -> Self {
+        Self { trace, speed: 1.0 }
+    }
+
+    /// Set the playback speed (2.0 plays twice as fast, 0 delivers all at once)
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
+    /// Delay before each entry, relative to the previous one
+    pub fn delays(&self) -> Vec<Duration> {
+        let mut previous = 0;
+        self.trace
+            .entries
+            .iter()
+            .map(|entry| {
+                let gap = entry.t_ms.saturating_sub(previous);
+                previous = entry.t_ms;
+                if self.speed == 0.0 {
+                    Duration::ZERO
+                } else {
+                    Duration::from_secs_f64(gap as f64 / 1000.0 / self.speed as f64)
+                }
+            })
+            .collect()
+    }
+
+    /// Replay the trace in `window`, calling `handler` for each event
+    pub fn run<F>(self, window: &mut Window, cx: &mut App, handler: F)
+    where
+        F: Fn(&RecordedEvent, &mut Window, &mut App) + 'static,
+    {
+        let delays = self.delays();
+        let entries = self.trace.entries;
+        window
+            .spawn(cx, async move |cx: &mut AsyncWindowContext| {
+                for (entry, delay) in entries.iter().zip(delays) {
+                    if !delay.is_zero() {
+                        cx.background_executor().timer(delay).await;
+                    }
+                    if cx
+                        .update(|window, cx| {
+                            handler(&entry.event, window, cx);
+                            window.refresh();
+                        })
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            })
+            .detach();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_json_round_trip() {
+        let mut trace = SessionTrace::new("Demo");
+        trace.push(
+            0,
+            RecordedEvent::Click {
+                id: "save".to_string(),
+            },
+        );
+        trace.push(
+            120,
+            RecordedEvent::ValueChanged {
+                id: "gain".to_string(),
+                value: serde_json::json!(-3.5),
+            },
+        );
+        trace.push(
+            300,
+            RecordedEvent::Navigate {
+                section: "eq".to_string(),
+            },
+        );
+
+        let json = trace.to_json();
+        assert!(json.contains("\"type\": \"click\""));
+        assert_eq!(SessionTrace::from_json(&json).unwrap(), trace);
+    }
+
+    #[test]
+    fn test_custom_event_without_data() {
+        let json =
+            r#"{"version":1,"app":"x","entries":[{"t_ms":5,"type":"custom","name":"ping"}]}"#;
+        let trace = SessionTrace::from_json(json).unwrap();
+        assert_eq!(
+            trace.entries[0].event,
+            RecordedEvent::Custom {
+                name: "ping".to_string(),
+                data: serde_json::Value::Null,
+            }
+        );
+    }
+
+    #[test]
+    fn test_recorder_pause() {
+        let mut recorder = SessionRecorder::new("Demo");
+        recorder.push(RecordedEvent::Click { id: "a".into() });
+        recorder.set_paused(true);
+        recorder.push(RecordedEvent::Click { id: "b".into() });
+        assert_eq!(recorder.trace().entries.len(), 1);
+        recorder.clear();
+        assert!(recorder.trace().entries.is_empty());
+    }
+
+    #[test]
+    fn test_replay_delays() {
+        let mut trace = SessionTrace::new("Demo");
+        for t in [100, 300, 300] {
+            trace.push(t, RecordedEvent::Click { id: "a".into() });
+        }
+        let delays = SessionReplay::new(trace.clone()).speed(2.0).delays();
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(50),
+                Duration::from_millis(100),
+                Duration::ZERO
+            ]
+        );
+        assert!(
+            SessionReplay::new(trace)
+                .speed(0.0)
+                .delays()
+                .iter()
+                .all(|d| d.is_zero())
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! Session persistence
+//!
+//! An opt-in store that keeps UI state across launches: the selected tab,
+//! pane sizes, the last opened section, chart view specs. Each piece of
+//! state is saved as JSON under its own key; the session file is read when
+//! the app starts and written when it quits.
+//!
+//! Persistence is enabled with [`MiniAppConfig::persist_session`], which also
+//! keeps the theme and language. Components read their saved state with
+//! [`restore`] when they are created, and either store changes right away
+//! with [`persist`] or [`register`] a snapshot taken on quit. All of them
+//! are no-ops when no store is installed.
+//!
+//! A session file that cannot be read or parsed, or that was written by a
+//! newer version, is set aside as `<file>.corrupt` and the app starts with
+//! a fresh session: saved state never prevents the app from launching.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use gpui_ui_kit::app::session;
+//!
+//! MiniApp::run(
+//!     MiniAppConfig::new("Demo").persist_session(Some("session.json".into())),
+//!     |cx| {
+//!         let tab = session::restore::<usize>(cx, "demo.tab").unwrap_or(0);
+//!         let demo = cx.new(|cx| Demo::new(tab, cx));
+//!         let weak = demo.downgrade();
+//!         session::register(cx, "demo.tab", move |cx| {
+//!             weak.upgrade().map(|demo| demo.read(cx).selected_tab)
+//!         });
+//!         demo
+//!     },
+//! );
+//! ```
+//!
+//! [`MiniAppConfig::persist_session`]: super::MiniAppConfig::persist_session
+
+use gpui::{App, Global};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Current session format version
+pub const SESSION_VERSION: u32 = 1;
+
+/// Saved UI state, as stored in the session file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionState {
+    /// Session format version
+    pub version: u32,
+    /// Name of the application
+    pub app: String,
+    /// Saved values by key
+    #[serde(default)]
+    pub entries: BTreeMap<String, serde_json::Value>,
+}
+
+impl SessionState {
+    /// Create an empty session for the given application
+    pub fn new(app: impl Into<String>) -> Self {
+        Self {
+            version: SESSION_VERSION,
+            app: app.into(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Value saved under `key`, if any and of the expected type
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.entries.get(key)?;
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    /// Save a value under `key`
+    pub fn set<T: Serialize>(&mut self, key: impl Into<String>, value: &T) {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.entries.insert(key.into(), value);
+        }
+    }
+
+    /// Forget the value saved under `key`
+    pub fn remove(&mut self, key: &str) {
+        self.entries.remove(key);
+    }
+
+    /// Serialize to pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Parse a session from JSON
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Write the session to a JSON file
+    ///
+    /// The file is replaced atomically, so a crash while saving leaves the
+    /// previous session intact.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let temp = with_suffix(path, ".tmp");
+        std::fs::write(&temp, self.to_json())?;
+        std::fs::rename(&temp, path)
+    }
+
+    /// Read a session from a JSON file
+    ///
+    /// Fails with [`ErrorKind::InvalidData`] for sessions written by a newer
+    /// version.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        let state = Self::from_json(&json).map_err(std::io::Error::other)?;
+        if state.version > SESSION_VERSION {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("session version {} is not supported", state.version),
+            ));
+        }
+        Ok(state)
+    }
+}
+
+/// Snapshot of one key, taken when the session is saved
+type Snapshot = Box<dyn Fn(&App) -> Option<serde_json::Value>>;
+
+/// Global session store, installed by MiniApp when persistence is enabled
+pub struct SessionStore {
+    path: PathBuf,
+    state: SessionState,
+    snapshots: Vec<(String, Snapshot)>,
+}
+
+impl Global for SessionStore {}
+
+impl SessionStore {
+    /// Open the session file at `path`, starting fresh if it is missing or
+    /// unusable
+    ///
+    /// An unusable file is renamed to `<file>.corrupt` so it is not lost.
+    pub fn open(path: impl Into<PathBuf>, app: impl Into<String>) -> Self {
+        let path = path.into();
+        let state = match SessionState::load(&path) {
+            Ok(state) => state,
+            Err(err) if err.kind() == ErrorKind::NotFound => SessionState::new(app),
+            Err(err) => {
+                eprintln!("Ignoring session file {}: {err}", path.display());
+                if let Err(err) = std::fs::rename(&path, with_suffix(&path, ".corrupt")) {
+                    eprintln!("Failed to set aside session file: {err}");
+                }
+                SessionState::new(app)
+            }
+        };
+        Self {
+            path,
+            state,
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// Path of the session file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Saved state, as loaded and updated so far
+    pub fn state(&self) -> &SessionState {
+        &self.state
+    }
+
+    /// Mutable saved state
+    pub fn state_mut(&mut self) -> &mut SessionState {
+        &mut self.state
+    }
+
+    /// Take a snapshot of `key` whenever the session is saved, replacing
+    /// any snapshot registered for it
+    ///
+    /// A snapshot returning `None` keeps the value saved before.
+    pub fn register(
+        &mut self,
+        key: impl Into<String>,
+        snapshot: impl Fn(&App) -> Option<serde_json::Value> + 'static,
+    ) {
+        let key = key.into();
+        self.snapshots.retain(|(existing, _)| *existing != key);
+        self.snapshots.push((key, Box::new(snapshot)));
+    }
+
+    /// Saved state with the registered snapshots taken now
+    pub fn snapshot(&self, cx: &App) -> SessionState {
+        let mut state = self.state.clone();
+        for (key, snapshot) in &self.snapshots {
+            if let Some(value) = snapshot(cx) {
+                state.entries.insert(key.clone(), value);
+            }
+        }
+        state
+    }
+
+    /// Write the session file now
+    pub fn save(&self, cx: &App) -> std::io::Result<()> {
+        self.snapshot(cx).save(&self.path)
+    }
+}
+
+/// Value saved under `key` in the session, if a [`SessionStore`] is installed
+pub fn restore<T: DeserializeOwned>(cx: &App, key: &str) -> Option<T> {
+    cx.try_global::<SessionStore>()?.state().get(key)
+}
+
+/// Save a value under `key` in the session, if a [`SessionStore`] is installed
+pub fn persist<T: Serialize>(cx: &mut App, key: impl Into<String>, value: &T) {
+    if cx.has_global::<SessionStore>() {
+        cx.update_global::<SessionStore, _>(|store, _cx| store.state_mut().set(key, value));
+    }
+}
+
+/// Save the value returned by `snapshot` under `key` when the session is
+/// saved, if a [`SessionStore`] is installed
+pub fn register<T: Serialize>(
+    cx: &mut App,
+    key: impl Into<String>,
+    snapshot: impl Fn(&App) -> Option<T> + 'static,
+) {
+    if cx.has_global::<SessionStore>() {
+        cx.update_global::<SessionStore, _>(|store, _cx| {
+            store.register(key, move |cx| {
+                snapshot(cx).and_then(|value| serde_json::to_value(value).ok())
+            });
+        });
+    }
+}
+
+/// `path` with `suffix` appended to its file name
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("session-{name}-{}.json", std::process::id()))
+    }
+
+    #[test]
+    fn test_session_values_round_trip() {
+        let mut state = SessionState::new("Demo");
+        state.set("demo.tab", &2usize);
+        state.set("demo.panes", &vec![240.0, 560.0]);
+        assert_eq!(state.get::<usize>("demo.tab"), Some(2));
+        // Wrong type or missing key
+        assert_eq!(state.get::<String>("demo.tab"), None);
+        assert_eq!(state.get::<usize>("demo.section"), None);
+
+        let path = temp_path("round-trip");
+        state.save(&path).unwrap();
+        let loaded = SessionState::load(&path).unwrap();
+        assert_eq!(loaded, state);
+        assert_eq!(
+            loaded.get::<Vec<f64>>("demo.panes"),
+            Some(vec![240.0, 560.0])
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_missing_file_starts_fresh() {
+        let path = temp_path("missing");
+        let store = SessionStore::open(&path, "Demo");
+        assert_eq!(store.state(), &SessionState::new("Demo"));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_corrupt_file_is_set_aside() {
+        let path = temp_path("corrupt");
+        std::fs::write(&path, "{ not json").unwrap();
+        let store = SessionStore::open(&path, "Demo");
+        assert!(store.state().entries.is_empty());
+
+        let backup = with_suffix(&path, ".corrupt");
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), "{ not json");
+        assert!(!path.exists());
+        std::fs::remove_file(&backup).unwrap();
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        let path = temp_path("newer");
+        let mut state = SessionState::new("Demo");
+        state.version = SESSION_VERSION + 1;
+        state.save(&path).unwrap();
+
+        let err = SessionState::load(&path).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            SessionStore::open(&path, "Demo").state().version,
+            SESSION_VERSION
+        );
+        std::fs::remove_file(with_suffix(&path, ".corrupt")).unwrap();
+    }
+
+    #[test]
+    fn test_entries_default_to_empty() {
+        let state = SessionState::from_json(r#"{"version":1,"app":"x"}"#).unwrap();
+        assert!(state.entries.is_empty());
+    }
+}
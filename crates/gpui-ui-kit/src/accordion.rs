@@ -3,6 +3,7 @@
 //! Collapsible content sections with support for both vertical and horizontal orientations.
 
 use crate::ComponentTheme;
+use crate::callback::Callback;
 use crate::theme::{ThemeExt, glow_shadow};
 use gpui::prelude::*;
 use gpui::*;
@@ -90,7 +91,7 @@ pub struct Accordion {
     mode: AccordionMode,
     orientation: AccordionOrientation,
     theme: Option<AccordionTheme>,
-    on_change: Option<Box<dyn Fn(&SharedString, bool, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<(SharedString, bool)>>,
 }
 
 impl Accordion {
@@ -147,7 +148,11 @@ impl Accordion {
         mut self,
         handler: impl Fn(&SharedString, bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(
+            move |(id, expanded): (SharedString, bool), window, cx| {
+                handler(&id, expanded, window, cx)
+            },
+        ));
         self
     }
 
@@ -165,11 +170,10 @@ impl Accordion {
                 on_change,
                 ..
             } = self;
-            let on_change = on_change.map(|h| std::rc::Rc::new(h));
             return Self::build_side_layout_static(items, expanded, theme, on_change);
         }
 
-        let on_change = self.on_change.map(|h| std::rc::Rc::new(h));
+        let on_change = self.on_change;
         let is_vertical = matches!(self.orientation, AccordionOrientation::Vertical);
 
         let mut container = div()
@@ -228,7 +232,7 @@ impl Accordion {
                     let id = item_id.clone();
                     let new_state = !is_expanded;
                     header = header.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                        (handler)(&id, new_state, window, cx);
+                        handler.call((id.clone(), new_state), window, cx);
                     });
                 }
             }
@@ -282,9 +286,7 @@ impl Accordion {
         items: Vec<AccordionItem>,
         expanded: Vec<SharedString>,
         theme: AccordionTheme,
-        on_change: Option<
-            std::rc::Rc<Box<dyn Fn(&SharedString, bool, &mut Window, &mut App) + 'static>>,
-        >,
+        on_change: Option<Callback<(SharedString, bool)>>,
     ) -> Div {
         let mut container = div()
             .flex()
@@ -338,7 +340,7 @@ impl Accordion {
                     let id = item_id.clone();
                     let new_state = !is_expanded;
                     header = header.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                        (handler)(&id, new_state, window, cx);
+                        handler.call((id.clone(), new_state), window, cx);
                     });
                 }
             }
@@ -428,3 +430,123 @@ impl IntoElement for Accordion {
         gpui::Component::new(self)
     }
 }
+
+/// Factory building the items of an [`AccordionView`] on every render
+pub type AccordionItemsFactory = Box<dyn Fn() -> Vec<AccordionItem> + 'static>;
+
+/// Events emitted by [`AccordionView`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccordionEvent {
+    /// The user expanded or collapsed an item
+    Toggled { id: SharedString, expanded: bool },
+}
+
+/// An [`Accordion`] that manages its own expanded items
+///
+/// Items hold their content elements, so the view builds them with a
+/// factory on every render. In [`AccordionMode::Single`] expanding an item
+/// collapses the others.
+///
+/// In controlled mode the view only emits [`AccordionEvent::Toggled`] and
+/// the parent applies the change with [`AccordionView::set_expanded`].
+pub struct AccordionView {
+    items: AccordionItemsFactory,
+    expanded: Vec<SharedString>,
+    mode: AccordionMode,
+    orientation: AccordionOrientation,
+    controlled: bool,
+}
+
+impl EventEmitter<AccordionEvent> for AccordionView {}
+
+impl AccordionView {
+    /// Create a new accordion view
+    pub fn new(items: impl Fn() -> Vec<AccordionItem> + 'static) -> Self {
+        Self {
+            items: Box::new(items),
+            expanded: Vec::new(),
+            mode: AccordionMode::default(),
+            orientation: AccordionOrientation::default(),
+            controlled: false,
+        }
+    }
+
+    /// Set the initially expanded item IDs
+    pub fn expanded(mut self, expanded: Vec<SharedString>) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Set mode
+    pub fn mode(mut self, mode: AccordionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set orientation
+    pub fn orientation(mut self, orientation: AccordionOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Only emit events, leaving state changes to the parent
+    pub fn controlled(mut self, controlled: bool) -> Self {
+        self.controlled = controlled;
+        self
+    }
+
+    /// Get the expanded item IDs
+    pub fn expanded_items(&self) -> &[SharedString] {
+        &self.expanded
+    }
+
+    /// Set the expanded item IDs without emitting an event
+    pub fn set_expanded(&mut self, expanded: Vec<SharedString>, cx: &mut Context<Self>) {
+        self.expanded = expanded;
+        cx.notify();
+    }
+
+    /// Replace the items factory
+    pub fn set_items(
+        &mut self,
+        items: impl Fn() -> Vec<AccordionItem> + 'static,
+        cx: &mut Context<Self>,
+    ) {
+        self.items = Box::new(items);
+        cx.notify();
+    }
+
+    fn toggle(&mut self, id: &SharedString, expanded: bool, cx: &mut Context<Self>) {
+        if !self.controlled {
+            if !expanded {
+                self.expanded.retain(|item| item != id);
+            } else if self.mode == AccordionMode::Single {
+                self.expanded = vec![id.clone()];
+            } else if !self.expanded.contains(id) {
+                self.expanded.push(id.clone());
+            }
+            cx.notify();
+        }
+        cx.emit(AccordionEvent::Toggled {
+            id: id.clone(),
+            expanded,
+        });
+    }
+}
+
+impl Render for AccordionView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let view = cx.entity().downgrade();
+        let theme = AccordionTheme::from(&cx.theme());
+        Accordion::new()
+            .items((self.items)())
+            .expanded(self.expanded.clone())
+            .mode(self.mode)
+            .orientation(self.orientation)
+            .on_change(move |id, expanded, _window, cx| {
+                view.update(cx, |this, cx| this.toggle(id, expanded, cx))
+                    .ok();
+            })
+            .build_with_theme(&theme)
+    }
+}
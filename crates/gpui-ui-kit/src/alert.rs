@@ -1,10 +1,54 @@
 //! Alert component
 //!
-//! Contextual feedback messages.
+//! Contextual feedback messages. Besides a title and a message, an
+//! [`Alert`] can carry:
+//! - a details disclosure for long error text or stack traces, opened and
+//!   closed by the app like [`ErrorState`](crate::error_state::ErrorState)'s
+//! - primary and secondary action buttons
+//! - an auto-dismiss delay, after which its close handler is called; the
+//!   countdown restarts when the mouse leaves the alert
+//!
+//! ```ignore
+//! Alert::new("export-failed", "The export could not be written.")
+//!     .variant(AlertVariant::Error)
+//!     .details(format!("{err:#}"))
+//!     .details_open(self.show_details)
+//!     .on_toggle_details(cx.listener(|this, open, _, cx| {
+//!         this.show_details = *open;
+//!         cx.notify();
+//!     }))
+//!     .primary_action("Retry", cx.listener(|this, _, _, cx| this.export(cx)))
+//!     .secondary_action("Choose folder", cx.listener(|this, _, _, cx| this.pick_folder(cx)))
+//! ```
+//!
+//! Compact alerts fit on one line, e.g. under a form field.
 
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::callback::Callback;
+use crate::state_registry::ElementStateRegistry;
 use crate::theme::{Theme, ThemeExt, ThemeVariant};
 use gpui::prelude::*;
 use gpui::{Component, *};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+// Auto-dismiss countdowns of the rendered alerts, kept alive by their hover
+// listeners
+thread_local! {
+    static DISMISS_TIMERS: RefCell<ElementStateRegistry<DismissTimer>> =
+        RefCell::new(ElementStateRegistry::default());
+}
+
+/// Auto-dismiss countdown of one alert
+#[derive(Default)]
+struct DismissTimer {
+    started: bool,
+    /// Bumped when the countdown stops or restarts, ending older ones
+    generation: u64,
+    /// Close handler of the latest render
+    on_dismiss: Option<Callback>,
+}
 
 /// Alert variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -53,6 +97,12 @@ impl AlertVariant {
     }
 }
 
+/// Labeled action button of an alert
+struct AlertAction {
+    label: SharedString,
+    handler: Callback,
+}
+
 /// An alert component
 pub struct Alert {
     id: ElementId,
@@ -61,7 +111,15 @@ pub struct Alert {
     variant: AlertVariant,
     closeable: bool,
     icon: Option<SharedString>,
-    on_close: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    details: Option<SharedString>,
+    details_open: bool,
+    compact: bool,
+    auto_dismiss: Option<Duration>,
+    primary_action: Option<AlertAction>,
+    secondary_action: Option<AlertAction>,
+    /// Shared by the close button and the auto-dismiss
+    on_close: Option<Callback>,
+    on_toggle_details: Option<Callback<bool>>,
 }
 
 impl Alert {
@@ -74,7 +132,14 @@ impl Alert {
             variant: AlertVariant::default(),
             closeable: false,
             icon: None,
+            details: None,
+            details_open: false,
+            compact: false,
+            auto_dismiss: None,
+            primary_action: None,
+            secondary_action: None,
             on_close: None,
+            on_toggle_details: None,
         }
     }
 
@@ -102,74 +167,234 @@ impl Alert {
         self
     }
 
+    /// Set long text (e.g. an error chain or stack trace) shown in a
+    /// disclosure below the message
+    pub fn details(mut self, details: impl Into<SharedString>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Show or hide the details
+    pub fn details_open(mut self, open: bool) -> Self {
+        self.details_open = open;
+        self
+    }
+
+    /// Fit the alert on one line, e.g. for a form field error
+    ///
+    /// The title runs into the message, which is truncated, and the details
+    /// are not shown.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Call the close handler once the alert has been shown for `delay`
+    ///
+    /// The countdown stops while the mouse is over the alert and starts
+    /// over when it leaves. Requires [`Alert::on_close`] to remove the
+    /// alert.
+    pub fn auto_dismiss(mut self, delay: Duration) -> Self {
+        self.auto_dismiss = Some(delay);
+        self
+    }
+
+    /// Show a primary action button
+    pub fn primary_action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.primary_action = Some(AlertAction {
+            label: label.into(),
+            handler: Callback::from_fn(handler),
+        });
+        self
+    }
+
+    /// Show a secondary action button, after the primary one
+    pub fn secondary_action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.secondary_action = Some(AlertAction {
+            label: label.into(),
+            handler: Callback::from_fn(handler),
+        });
+        self
+    }
+
     /// Set close handler
     pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_close = Some(Box::new(handler));
+        self.on_close = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Called with the requested open state when the details toggle is clicked
+    pub fn on_toggle_details(
+        mut self,
+        handler: impl Fn(&bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_toggle_details = Some(Callback::new(move |open: bool, window, cx| {
+            handler(&open, window, cx)
+        }));
         self
     }
 
     /// Build into element with theme
+    ///
+    /// The auto-dismiss countdown only runs when the alert is rendered as an
+    /// element.
     pub fn build_with_theme(self, theme: &Theme) -> Stateful<Div> {
         let (bg, border, icon_color) = self.variant.colors(theme);
         let default_icon = self.variant.icon();
-        // Clone ID for use in close button (self.id is moved to alert container)
-        let close_btn_id = self.id.clone();
+        let compact = self.compact;
+        let id = self.id.clone();
+        let child_id =
+            |suffix: &str| ElementId::Name(SharedString::from(format!("{}-{}", id, suffix)));
 
         let mut alert = div()
-            .id(self.id)
+            .id(self.id.clone())
             .flex()
-            .items_start()
-            .gap_3()
-            .p_4()
             .bg(bg)
             .border_1()
-            .border_color(border)
-            .rounded_lg();
+            .border_color(border);
+        alert = if compact {
+            alert.items_center().gap_2().px_2().py_1().rounded_md()
+        } else {
+            alert.items_start().gap_3().p_4().rounded_lg()
+        };
 
         // Icon
         let icon = self.icon.unwrap_or_else(|| default_icon.into());
-        alert = alert.child(div().text_lg().text_color(icon_color).child(icon));
+        let icon = div().text_color(icon_color).child(icon);
+        alert = alert.child(if compact {
+            icon.text_sm()
+        } else {
+            icon.text_lg()
+        });
+
+        let title = self.title.map(|title| {
+            div()
+                .text_sm()
+                .font_weight(FontWeight::SEMIBOLD)
+                .text_color(theme.text_primary)
+                .child(title)
+        });
+        let message = div()
+            .text_sm()
+            .text_color(theme.text_secondary)
+            .child(self.message);
+
+        let button_size = if compact {
+            ButtonSize::Xs
+        } else {
+            ButtonSize::Sm
+        };
+        let primary = self.primary_action.map(|action| {
+            Button::new(child_id("primary"), action.label)
+                .variant(ButtonVariant::Primary)
+                .size(button_size)
+                .on_click(move |window, cx| action.handler.invoke(window, cx))
+        });
+        let secondary = self.secondary_action.map(|action| {
+            Button::new(child_id("secondary"), action.label)
+                .variant(ButtonVariant::Ghost)
+                .size(button_size)
+                .on_click(move |window, cx| action.handler.invoke(window, cx))
+        });
+        let has_actions = primary.is_some() || secondary.is_some();
+        let actions = div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .children(primary)
+            .children(secondary);
 
         // Content
-        let mut content = div().flex_1().flex().flex_col().gap_1();
+        if compact {
+            alert = alert
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .flex()
+                        .items_center()
+                        .gap_1()
+                        .children(title.map(|title| title.flex_none()))
+                        .child(message.min_w_0().truncate()),
+                )
+                .children(has_actions.then_some(actions));
+        } else {
+            // Details disclosure: toggle link and, when open, a selectable panel
+            let details = self.details.map(|details| {
+                let open = self.details_open;
+                let text_primary = theme.text_primary;
+                let mut toggle = div()
+                    .id(child_id("details-toggle"))
+                    .text_xs()
+                    .text_color(theme.text_muted)
+                    .cursor_pointer()
+                    .hover(move |s| s.text_color(text_primary))
+                    .child(if open {
+                        "Hide details ▴"
+                    } else {
+                        "Show details ▾"
+                    });
+                if let Some(handler) = self.on_toggle_details {
+                    toggle = toggle.on_click(move |_, window, cx| handler.call(!open, window, cx));
+                }
 
-        if let Some(title) = self.title {
-            content = content.child(
+                let mut section = div().flex().flex_col().gap_1().child(toggle);
+                if open {
+                    section = section.child(
+                        div()
+                            .id(child_id("details"))
+                            .max_h(px(200.0))
+                            .overflow_y_scroll()
+                            .p_2()
+                            .rounded_md()
+                            .bg(theme.background)
+                            .border_1()
+                            .border_color(theme.border)
+                            .text_xs()
+                            .font_family("monospace")
+                            .text_color(theme.text_secondary)
+                            .child(details),
+                    );
+                }
+                section
+            });
+
+            alert = alert.child(
                 div()
-                    .text_sm()
-                    .font_weight(FontWeight::SEMIBOLD)
-                    .text_color(theme.text_primary)
-                    .child(title),
+                    .flex_1()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .children(title)
+                    .child(message)
+                    .children(details)
+                    .children(has_actions.then(|| actions.pt_2())),
             );
         }
 
-        content = content.child(
-            div()
-                .text_sm()
-                .text_color(theme.text_secondary)
-                .child(self.message),
-        );
-
-        alert = alert.child(content);
-
         // Close button
         if self.closeable {
             let text_muted = theme.text_muted;
             let text_primary = theme.text_primary;
             let mut close_btn = div()
-                .id((close_btn_id, "close"))
+                .id(child_id("close"))
                 .text_sm()
                 .text_color(text_muted)
                 .cursor_pointer()
                 .hover(move |s| s.text_color(text_primary));
 
             if let Some(handler) = self.on_close {
-                let handler_ptr: *const dyn Fn(&mut Window, &mut App) = handler.as_ref();
-                close_btn =
-                    close_btn.on_mouse_up(MouseButton::Left, move |_event, window, cx| unsafe {
-                        (*handler_ptr)(window, cx);
-                    });
-                std::mem::forget(handler);
+                close_btn = close_btn.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
+                    handler.invoke(window, cx);
+                });
             }
 
             alert = alert.child(close_btn.child("x"));
@@ -188,10 +413,83 @@ impl IntoElement for Alert {
 }
 
 impl RenderOnce for Alert {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let theme = cx.theme();
-        self.build_with_theme(&theme)
+        let countdown = match (self.auto_dismiss, self.on_close.clone()) {
+            (Some(delay), Some(on_close)) => {
+                Some((delay, dismiss_timer(&self.id, on_close, delay, window, cx)))
+            }
+            _ => None,
+        };
+
+        let alert = self.build_with_theme(&theme);
+        match countdown {
+            Some((delay, timer)) => alert.on_hover(move |hovered, window, cx| {
+                let generation = bump_generation(&timer);
+                if !*hovered {
+                    start_countdown(Rc::downgrade(&timer), generation, delay, window, cx);
+                }
+            }),
+            None => alert,
+        }
+    }
+}
+
+/// Get the countdown of the alert `id`, starting it on the first render
+fn dismiss_timer(
+    id: &ElementId,
+    on_dismiss: Callback,
+    delay: Duration,
+    window: &mut Window,
+    cx: &mut App,
+) -> Rc<RefCell<DismissTimer>> {
+    let timer =
+        DISMISS_TIMERS.with_borrow_mut(|registry| registry.state(id, DismissTimer::default));
+    let mut state = timer.borrow_mut();
+    state.on_dismiss = Some(on_dismiss);
+    if !state.started {
+        state.started = true;
+        let generation = state.generation;
+        drop(state);
+        start_countdown(Rc::downgrade(&timer), generation, delay, window, cx);
     }
+    timer
+}
+
+/// Stop the current countdown, returning the new generation
+fn bump_generation(timer: &RefCell<DismissTimer>) -> u64 {
+    let mut timer = timer.borrow_mut();
+    timer.generation += 1;
+    timer.generation
+}
+
+/// Dismiss the alert after `delay`, unless the countdown of `generation`
+/// was stopped or the alert is gone
+fn start_countdown(
+    timer: Weak<RefCell<DismissTimer>>,
+    generation: u64,
+    delay: Duration,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    window
+        .spawn(cx, async move |cx: &mut AsyncWindowContext| {
+            cx.background_executor().timer(delay).await;
+            let Some(timer) = timer.upgrade() else {
+                return;
+            };
+            let on_dismiss = {
+                let timer = timer.borrow();
+                (timer.generation == generation)
+                    .then(|| timer.on_dismiss.clone())
+                    .flatten()
+            };
+            drop(timer);
+            if let Some(on_dismiss) = on_dismiss {
+                cx.update(|window, cx| on_dismiss.invoke(window, cx)).ok();
+            }
+        })
+        .detach();
 }
 
 /// A simple inline alert (no close button)
@@ -199,6 +497,7 @@ impl RenderOnce for Alert {
 pub struct InlineAlert {
     message: SharedString,
     variant: AlertVariant,
+    compact: bool,
 }
 
 impl InlineAlert {
@@ -207,6 +506,7 @@ impl InlineAlert {
         Self {
             message: message.into(),
             variant: AlertVariant::default(),
+            compact: false,
         }
     }
 
@@ -216,19 +516,32 @@ impl InlineAlert {
         self
     }
 
+    /// Keep the message on one line in smaller text, truncated to the
+    /// available width, e.g. under a form field
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
     /// Build into element with theme
     pub fn build_with_theme(self, theme: &Theme) -> Div {
         let (_, _border, icon_color) = self.variant.colors(theme);
         let icon = self.variant.icon();
 
-        div()
+        let alert = div()
             .flex()
             .items_center()
-            .gap_2()
-            .text_sm()
             .text_color(icon_color)
-            .child(div().child(icon))
-            .child(self.message)
+            .child(div().child(icon));
+        if self.compact {
+            alert
+                .gap_1()
+                .text_xs()
+                .min_w_0()
+                .child(div().min_w_0().truncate().child(self.message))
+        } else {
+            alert.gap_2().text_sm().child(self.message)
+        }
     }
 }
 
@@ -0,0 +1,492 @@
+//! AudioDeviceSelect - Input/output audio device picker
+//!
+//! A dropdown of audio devices with:
+//! - Input or output device filtering
+//! - Sample rate and channel count of the selected device
+//! - Default device marker and fallback when the selection disappears
+//! - Selection, toggle and refresh callbacks
+//!
+//! The component is presentational: the app owns the device list and the
+//! open state. With the `cpal` feature, [`list_devices`] enumerates system
+//! devices and [`watch_devices`] polls for hot-plug changes.
+
+use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::select::{Select, SelectOption, SelectTheme};
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+use std::rc::Rc;
+
+/// Common sample rates probed against a device's supported ranges
+pub const STANDARD_SAMPLE_RATES: &[u32] = &[44_100, 48_000, 88_200, 96_000, 176_400, 192_000];
+
+/// Direction of an audio device
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AudioDeviceKind {
+    /// Capture device (microphone, line in)
+    #[default]
+    Input,
+    /// Playback device (speakers, headphones)
+    Output,
+}
+
+/// Description of an audio device
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioDeviceInfo {
+    /// Stable identifier (the device name on most hosts)
+    pub id: SharedString,
+    /// Display name
+    pub name: SharedString,
+    /// Input or output
+    pub kind: AudioDeviceKind,
+    /// Default sample rate in Hz
+    pub sample_rate: u32,
+    /// Supported sample rates in Hz, ascending
+    pub sample_rates: Vec<u32>,
+    /// Channel count of the default configuration
+    pub channels: u16,
+    /// Whether this is the system default device
+    pub is_default: bool,
+}
+
+impl AudioDeviceInfo {
+    /// Create a device description with a default configuration
+    pub fn new(
+        name: impl Into<SharedString>,
+        kind: AudioDeviceKind,
+        sample_rate: u32,
+        channels: u16,
+    ) -> Self {
+        let name = name.into();
+        Self {
+            id: name.clone(),
+            name,
+            kind,
+            sample_rate,
+            sample_rates: vec![sample_rate],
+            channels,
+            is_default: false,
+        }
+    }
+
+    /// Mark as the system default device
+    pub fn default_device(mut self, is_default: bool) -> Self {
+        self.is_default = is_default;
+        self
+    }
+
+    /// Set the supported sample rates
+    pub fn sample_rates(mut self, mut rates: Vec<u32>) -> Self {
+        rates.sort_unstable();
+        rates.dedup();
+        self.sample_rates = rates;
+        self
+    }
+
+    /// Summary line, e.g. "48 kHz · 2 ch"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} · {} ch",
+            format_sample_rate(self.sample_rate),
+            self.channels
+        )
+    }
+}
+
+/// Format a sample rate, e.g. 44100 → "44.1 kHz"
+pub fn format_sample_rate(rate: u32) -> String {
+    if rate.is_multiple_of(1000) {
+        format!("{} kHz", rate / 1000)
+    } else {
+        format!("{:.1} kHz", rate as f64 / 1000.0)
+    }
+}
+
+/// The device to show as selected: `selected` if still present, otherwise
+/// the default device of that kind, otherwise the first one
+pub fn resolve_selection<'a>(
+    devices: &'a [AudioDeviceInfo],
+    kind: AudioDeviceKind,
+    selected: Option<&str>,
+) -> Option<&'a AudioDeviceInfo> {
+    let mut of_kind = devices.iter().filter(|d| d.kind == kind);
+    if let Some(id) = selected
+        && let Some(device) = of_kind.clone().find(|d| d.id.as_ref() == id)
+    {
+        return Some(device);
+    }
+    of_kind
+        .clone()
+        .find(|d| d.is_default)
+        .or_else(|| of_kind.next())
+}
+
+/// Theme colors for the device selector
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct AudioDeviceSelectTheme {
+    /// Label text color
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub label: Rgba,
+    /// Device info text color
+    #[theme(default = 0x888888ff, from = text_muted)]
+    pub info: Rgba,
+    /// Empty-state text color
+    #[theme(default = 0xf59e0bff, from = warning)]
+    pub warning: Rgba,
+    /// Refresh button hover background
+    #[theme(default = 0x2a2a2aff, from = surface_hover)]
+    pub button_hover: Rgba,
+}
+
+/// Callback receiving the newly selected device
+pub type AudioDeviceCallback = Callback<AudioDeviceInfo>;
+
+/// An audio device dropdown with device details
+#[derive(IntoElement)]
+pub struct AudioDeviceSelect {
+    id: ElementId,
+    kind: AudioDeviceKind,
+    devices: Vec<AudioDeviceInfo>,
+    selected: Option<SharedString>,
+    label: Option<SharedString>,
+    is_open: bool,
+    show_info: bool,
+    disabled: bool,
+    theme: Option<AudioDeviceSelectTheme>,
+    select_theme: Option<SelectTheme>,
+    on_select: Option<AudioDeviceCallback>,
+    on_toggle: Option<Callback<bool>>,
+    on_refresh: Option<Callback>,
+}
+
+impl AudioDeviceSelect {
+    /// Create a selector for devices of the given kind
+    pub fn new(id: impl Into<ElementId>, kind: AudioDeviceKind) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+            devices: Vec::new(),
+            selected: None,
+            label: None,
+            is_open: false,
+            show_info: true,
+            disabled: false,
+            theme: None,
+            select_theme: None,
+            on_select: None,
+            on_toggle: None,
+            on_refresh: None,
+        }
+    }
+
+    /// Set the device list (devices of the other kind are ignored)
+    pub fn devices(mut self, devices: Vec<AudioDeviceInfo>) -> Self {
+        self.devices = devices;
+        self
+    }
+
+    /// Set the selected device id
+    pub fn selected(mut self, id: impl Into<SharedString>) -> Self {
+        self.selected = Some(id.into());
+        self
+    }
+
+    /// Set the label shown above the dropdown
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set open state (controlled)
+    pub fn is_open(mut self, is_open: bool) -> Self {
+        self.is_open = is_open;
+        self
+    }
+
+    /// Show sample rate and channel info under the dropdown (default: true)
+    pub fn show_info(mut self, show: bool) -> Self {
+        self.show_info = show;
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the theme
+    pub fn theme(mut self, theme: AudioDeviceSelectTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Set the theme of the inner dropdown
+    pub fn select_theme(mut self, theme: SelectTheme) -> Self {
+        self.select_theme = Some(theme);
+        self
+    }
+
+    /// Called with the device the user picked
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(&AudioDeviceInfo, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_select = Some(Callback::new(move |device: AudioDeviceInfo, window, cx| {
+            handler(&device, window, cx)
+        }));
+        self
+    }
+
+    /// Called when the dropdown is opened or closed
+    pub fn on_toggle(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_toggle = Some(Callback::new(handler));
+        self
+    }
+
+    /// Show a refresh button that calls `handler`
+    pub fn on_refresh(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_refresh = Some(Callback::from_fn(handler));
+        self
+    }
+
+    fn build_with_theme(self, theme: &AudioDeviceSelectTheme) -> Div {
+        let devices: Rc<Vec<AudioDeviceInfo>> = Rc::new(
+            self.devices
+                .into_iter()
+                .filter(|d| d.kind == self.kind)
+                .collect(),
+        );
+        let current = resolve_selection(&devices, self.kind, self.selected.as_deref()).cloned();
+
+        let options: Vec<SelectOption> = devices
+            .iter()
+            .map(|d| {
+                let label = if d.is_default {
+                    format!("{} (default)", d.name)
+                } else {
+                    d.name.to_string()
+                };
+                SelectOption::new(d.id.clone(), label)
+            })
+            .collect();
+
+        let placeholder = match self.kind {
+            AudioDeviceKind::Input => "No input devices",
+            AudioDeviceKind::Output => "No output devices",
+        };
+
+        let mut select = Select::new(self.id.clone())
+            .options(options)
+            .placeholder(if devices.is_empty() {
+                placeholder
+            } else {
+                "Select a device"
+            })
+            .is_open(self.is_open)
+            .disabled(self.disabled || devices.is_empty());
+        if let Some(device) = &current {
+            select = select.selected(device.id.clone());
+        }
+        if let Some(select_theme) = self.select_theme {
+            select = select.theme(select_theme);
+        }
+        if let Some(handler) = self.on_select {
+            let devices = devices.clone();
+            select = select.on_change(move |value, window, cx| {
+                if let Some(device) = devices.iter().find(|d| &d.id == value) {
+                    handler.call(device.clone(), window, cx);
+                }
+            });
+        }
+        if let Some(handler) = self.on_toggle {
+            select = select.on_toggle(move |open, window, cx| handler.call(open, window, cx));
+        }
+
+        let mut row = div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .child(div().flex_1().child(select));
+        if let Some(handler) = self.on_refresh {
+            let hover = theme.button_hover;
+            row = row.child(
+                div()
+                    .id(ElementId::Name(format!("{}-refresh", self.id).into()))
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .text_sm()
+                    .text_color(theme.info)
+                    .hover(move |s| s.bg(hover))
+                    .child("⟳")
+                    .on_click(move |_event, window, cx| handler.invoke(window, cx)),
+            );
+        }
+
+        let mut container = div().flex().flex_col().gap_1();
+        if let Some(label) = self.label {
+            container = container.child(div().text_sm().text_color(theme.label).child(label));
+        }
+        container = container.child(row);
+
+        if self.show_info {
+            let info = match &current {
+                Some(device) => div()
+                    .text_xs()
+                    .text_color(theme.info)
+                    .child(device.summary()),
+                None => div().text_xs().text_color(theme.warning).child(placeholder),
+            };
+            container = container.child(info);
+        }
+        container
+    }
+}
+
+impl RenderOnce for AudioDeviceSelect {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| AudioDeviceSelectTheme::from(&global_theme));
+        self.build_with_theme(&theme)
+    }
+}
+
+/// Enumerate system devices of the given kind with the default cpal host
+#[cfg(feature = "cpal")]
+pub fn list_devices(kind: AudioDeviceKind) -> Vec<AudioDeviceInfo> {
+    use cpal::traits::{DeviceTrait, HostTrait};
+
+    let host = cpal::default_host();
+    let (devices, default_name) = match kind {
+        AudioDeviceKind::Input => (
+            host.input_devices().ok(),
+            host.default_input_device().and_then(|d| d.name().ok()),
+        ),
+        AudioDeviceKind::Output => (
+            host.output_devices().ok(),
+            host.default_output_device().and_then(|d| d.name().ok()),
+        ),
+    };
+    let Some(devices) = devices else {
+        return Vec::new();
+    };
+
+    devices
+        .filter_map(|device| {
+            let name = device.name().ok()?;
+            let (config, ranges): (_, Vec<_>) = match kind {
+                AudioDeviceKind::Input => (
+                    device.default_input_config().ok()?,
+                    device.supported_input_configs().ok()?.collect(),
+                ),
+                AudioDeviceKind::Output => (
+                    device.default_output_config().ok()?,
+                    device.supported_output_configs().ok()?.collect(),
+                ),
+            };
+            let default_rate = config.sample_rate().0;
+            let mut rates: Vec<u32> = STANDARD_SAMPLE_RATES
+                .iter()
+                .copied()
+                .filter(|rate| {
+                    ranges
+                        .iter()
+                        .any(|r| (r.min_sample_rate().0..=r.max_sample_rate().0).contains(rate))
+                })
+                .collect();
+            rates.push(default_rate);
+
+            let is_default = default_name.as_deref() == Some(name.as_str());
+            Some(
+                AudioDeviceInfo::new(name, kind, default_rate, config.channels())
+                    .sample_rates(rates)
+                    .default_device(is_default),
+            )
+        })
+        .collect()
+}
+
+/// Poll the system device list every `interval` and call `on_change` with the
+/// full list (inputs then outputs) whenever it differs from the last one.
+///
+/// Enumeration runs on the background executor. Drop the returned task to
+/// stop watching.
+#[cfg(feature = "cpal")]
+pub fn watch_devices(
+    interval: std::time::Duration,
+    on_change: impl Fn(Vec<AudioDeviceInfo>, &mut App) + 'static,
+    cx: &mut App,
+) -> Task<()> {
+    cx.spawn(async move |cx: &mut AsyncApp| {
+        let mut last: Option<Vec<AudioDeviceInfo>> = None;
+        loop {
+            let devices = cx
+                .background_executor()
+                .spawn(async {
+                    let mut devices = list_devices(AudioDeviceKind::Input);
+                    devices.extend(list_devices(AudioDeviceKind::Output));
+                    devices
+                })
+                .await;
+            if last.as_ref() != Some(&devices) {
+                last = Some(devices.clone());
+                if cx.update(|cx| on_change(devices, cx)).is_err() {
+                    break;
+                }
+            }
+            cx.background_executor().timer(interval).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn devices() -> Vec<AudioDeviceInfo> {
+        vec![
+            AudioDeviceInfo::new("Built-in Mic", AudioDeviceKind::Input, 48_000, 1),
+            AudioDeviceInfo::new("UMIK-1", AudioDeviceKind::Input, 48_000, 2).default_device(true),
+            AudioDeviceInfo::new("Speakers", AudioDeviceKind::Output, 44_100, 2),
+        ]
+    }
+
+    #[test]
+    fn test_format_sample_rate() {
+        assert_eq!(format_sample_rate(48_000), "48 kHz");
+        assert_eq!(format_sample_rate(44_100), "44.1 kHz");
+        assert_eq!(
+            AudioDeviceInfo::new("x", AudioDeviceKind::Output, 88_200, 8).summary(),
+            "88.2 kHz · 8 ch"
+        );
+    }
+
+    #[test]
+    fn test_resolve_selection() {
+        let devices = devices();
+        let input = AudioDeviceKind::Input;
+        let pick = |selected| resolve_selection(&devices, input, selected).map(|d| d.name.as_ref());
+        assert_eq!(pick(Some("Built-in Mic")), Some("Built-in Mic"));
+        // Unplugged or wrong-kind selection falls back to the default device
+        assert_eq!(pick(Some("Speakers")), Some("UMIK-1"));
+        assert_eq!(pick(None), Some("UMIK-1"));
+        assert_eq!(
+            resolve_selection(&devices, AudioDeviceKind::Output, None).map(|d| d.channels),
+            Some(2)
+        );
+        assert!(resolve_selection(&[], input, None).is_none());
+    }
+
+    #[test]
+    fn test_sample_rates_sorted() {
+        let device = AudioDeviceInfo::new("x", AudioDeviceKind::Input, 48_000, 2)
+            .sample_rates(vec![96_000, 48_000, 44_100, 48_000]);
+        assert_eq!(device.sample_rates, vec![44_100, 48_000, 96_000]);
+    }
+}
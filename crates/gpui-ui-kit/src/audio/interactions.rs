@@ -3,7 +3,10 @@
 //! This module provides common interaction patterns for sliders, knobs, and potentiometers:
 //! - Keyboard navigation (arrows, page up/down, home/end, escape)
 //! - Mouse drag with delta-based value changes
+//! - Drag-to-scrub with fine (Shift) and coarse (Ctrl/Cmd) speeds, linear
+//!   per step or following the value scale (logarithmic for frequencies)
 //! - Scroll wheel adjustment (with shift for fine control)
+//! - Detents that catch dragged values near marked positions
 //! - Double-click to reset
 //!
 //! The drag state is stored in thread-local storage to survive component re-renders
@@ -22,10 +25,27 @@ pub struct DragState {
     pub start_value: f64, // Value when drag started
 }
 
+/// Scrub state that persists across re-renders
+#[derive(Clone, Copy, Debug)]
+pub struct ScrubState {
+    /// Pointer position at mouse down
+    pub start: (f32, f32),
+    /// Pointer position at the last update
+    pub last: (f32, f32),
+    /// Unrounded value, so slow drags still add up
+    pub value: f64,
+    /// Whether the pointer moved past the click threshold
+    pub active: bool,
+}
+
 thread_local! {
     static DRAG_STATES: RefCell<HashMap<String, DragState>> = RefCell::new(HashMap::new());
+    static SCRUB_STATES: RefCell<HashMap<String, ScrubState>> = RefCell::new(HashMap::new());
 }
 
+/// Distance in pixels a press must move before it scrubs instead of clicking
+const SCRUB_THRESHOLD: f32 = 3.0;
+
 /// Store drag state for an element (call on mouse_down)
 pub fn store_drag_state(element_key: &str, start_pos: f32, start_value: f64) {
     DRAG_STATES.with(|states| {
@@ -226,6 +246,157 @@ pub fn handle_scroll(
     )
 }
 
+/// How scrubbed pixels map to value changes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScrubMapping {
+    /// One `step` every `pixels_per_step` pixels, rounded to the step
+    ///
+    /// Works for unbounded ranges. Fine speed scrubs the next decimal digit.
+    Step { step: f64, pixels_per_step: f32 },
+    /// The whole range over `pixels` pixels, spaced by the scale
+    ///
+    /// With [`Scale::Logarithmic`], every octave of a 20 Hz–20 kHz field
+    /// takes the same distance, so mid-band values are as easy to hit as
+    /// low ones.
+    Range { scale: Scale, pixels: f32 },
+}
+
+/// Speed multiplier of scrubbing for the held modifiers
+///
+/// Shift = Fine (×0.1), Ctrl/Cmd = Coarse (×10), like keyboard steps.
+pub fn scrub_speed(modifiers: &Modifiers) -> f64 {
+    if modifiers.shift {
+        0.1
+    } else if modifiers.control || modifiers.platform {
+        10.0
+    } else {
+        1.0
+    }
+}
+
+/// Start scrubbing an element (call on mouse_down)
+pub fn start_scrub(element_key: &str, x: f32, y: f32, value: f64) {
+    SCRUB_STATES.with(|states| {
+        states.borrow_mut().insert(
+            element_key.to_string(),
+            ScrubState {
+                start: (x, y),
+                last: (x, y),
+                value,
+                active: false,
+            },
+        );
+    });
+}
+
+/// Stop scrubbing an element (call on mouse_up)
+///
+/// Returns the final state, whose `active` flag tells a drag from a click.
+pub fn end_scrub(element_key: &str) -> Option<ScrubState> {
+    SCRUB_STATES.with(|states| states.borrow_mut().remove(element_key))
+}
+
+/// Whether an element is being scrubbed past the click threshold
+pub fn is_scrubbing(element_key: &str) -> bool {
+    SCRUB_STATES.with(|states| {
+        states
+            .borrow()
+            .get(element_key)
+            .is_some_and(|state| state.active)
+    })
+}
+
+/// Handle pointer movement while scrubbing (call on mouse_move)
+///
+/// Right and up increase the value. The speed follows the modifiers held
+/// at each move, so switching to fine control mid-drag doesn't jump.
+/// Returns the new value once the pointer has moved past the click
+/// threshold, None otherwise.
+pub fn update_scrub(
+    element_key: &str,
+    x: f32,
+    y: f32,
+    modifiers: &Modifiers,
+    min: f64,
+    max: f64,
+    mapping: ScrubMapping,
+) -> Option<f64> {
+    SCRUB_STATES.with(|states| {
+        let mut states = states.borrow_mut();
+        let state = states.get_mut(element_key)?;
+        scrub_state(state, x, y, scrub_speed(modifiers), min, max, mapping)
+    })
+}
+
+/// Advance a scrub to the pointer position `(x, y)`
+fn scrub_state(
+    state: &mut ScrubState,
+    x: f32,
+    y: f32,
+    speed: f64,
+    min: f64,
+    max: f64,
+    mapping: ScrubMapping,
+) -> Option<f64> {
+    if !state.active {
+        let moved = (x - state.start.0).hypot(y - state.start.1);
+        if moved < SCRUB_THRESHOLD {
+            return None;
+        }
+        state.active = true;
+    }
+    let delta = ((x - state.last.0) + (state.last.1 - y)) as f64 * speed;
+    state.last = (x, y);
+
+    match mapping {
+        ScrubMapping::Step {
+            step,
+            pixels_per_step,
+        } => {
+            state.value = (state.value + delta / pixels_per_step as f64 * step).clamp(min, max);
+            // Round to the finest step in use, then keep within bounds
+            let increment = step * speed.min(1.0);
+            Some(((state.value / increment).round() * increment).clamp(min, max))
+        }
+        ScrubMapping::Range { scale, pixels } => {
+            state.value = scale.step_value(state.value, min, max, delta / pixels as f64, 1.0);
+            Some(state.value)
+        }
+    }
+}
+
+/// Share of the travel around a detent that snaps to it
+pub const DETENT_SNAP: f64 = 0.02;
+
+/// Snap a dragged value to the nearest detent within [`DETENT_SNAP`]
+///
+/// Distances are measured along the travel, so the detents of a
+/// logarithmic knob catch as much at 100 Hz as at 10 kHz. Snap the value
+/// reported to the user only: scrubbing keeps its unsnapped position, so
+/// dragging on leaves the detent instead of sticking to it.
+pub fn snap_to_detent(value: f64, detents: &[f64], min: f64, max: f64, scale: Scale) -> f64 {
+    let position = scale.value_to_normalized(value, min, max);
+    detents
+        .iter()
+        .copied()
+        .filter(|detent| (min..=max).contains(detent))
+        .map(|detent| {
+            let distance = (scale.value_to_normalized(detent, min, max) - position).abs();
+            (detent, distance)
+        })
+        .filter(|&(_, distance)| distance <= DETENT_SNAP)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(value, |(detent, _)| detent)
+}
+
+/// Whether `value` rests on one of the detents
+pub fn on_detent(value: f64, detents: &[f64], min: f64, max: f64) -> bool {
+    let tolerance = (max - min).abs() * 1e-6;
+    detents
+        .iter()
+        .any(|detent| (detent - value).abs() <= tolerance)
+}
+
 /// Handle drag movement for value adjustment
 ///
 /// Returns the new value based on drag delta from start position.
@@ -265,3 +436,80 @@ pub fn handle_drag(
         1.0,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scrub(mapping: ScrubMapping, moves: &[(f32, f32, f64)]) -> Vec<Option<f64>> {
+        let mut state = ScrubState {
+            start: (0.0, 0.0),
+            last: (0.0, 0.0),
+            value: 50.0,
+            active: false,
+        };
+        moves
+            .iter()
+            .map(|&(x, y, speed)| scrub_state(&mut state, x, y, speed, 0.0, 100.0, mapping))
+            .collect()
+    }
+
+    #[test]
+    fn test_scrub_steps() {
+        let mapping = ScrubMapping::Step {
+            step: 1.0,
+            pixels_per_step: 4.0,
+        };
+        // Jitter below the threshold is a click; right and up both increase
+        let values = scrub(
+            mapping,
+            &[(1.0, 1.0, 1.0), (8.0, 0.0, 1.0), (8.0, -8.0, 1.0)],
+        );
+        assert_eq!(values, vec![None, Some(52.0), Some(54.0)]);
+
+        // Fine speed rounds to a tenth of the step, and clamps at the bounds
+        let values = scrub(mapping, &[(4.0, 0.0, 0.1), (-1000.0, 0.0, 10.0)]);
+        assert_eq!(values, vec![Some(50.1), Some(0.0)]);
+    }
+
+    #[test]
+    fn test_scrub_logarithmic_range() {
+        let mapping = ScrubMapping::Range {
+            scale: Scale::Logarithmic,
+            pixels: 300.0,
+        };
+        let mut state = ScrubState {
+            start: (0.0, 0.0),
+            last: (0.0, 0.0),
+            value: 20.0,
+            active: false,
+        };
+        // A third of the distance covers one decade of 20 Hz–20 kHz
+        let value = scrub_state(&mut state, 100.0, 0.0, 1.0, 20.0, 20_000.0, mapping).unwrap();
+        assert!((value - 200.0).abs() < 1e-6, "{value}");
+        let value = scrub_state(&mut state, 200.0, 0.0, 1.0, 20.0, 20_000.0, mapping).unwrap();
+        assert!((value - 2000.0).abs() < 1e-6, "{value}");
+    }
+
+    #[test]
+    fn test_snap_to_detent() {
+        let detents = [0.0, 50.0, 150.0];
+        let snap = |value| snap_to_detent(value, &detents, -100.0, 100.0, Scale::Linear);
+        // Within 2% of the travel snaps to the nearest detent
+        assert_eq!(snap(1.5), 0.0);
+        assert_eq!(snap(47.0), 50.0);
+        assert_eq!(snap(10.0), 10.0);
+        // Detents out of range are ignored
+        assert_eq!(snap(99.0), 99.0);
+
+        // Logarithmic knobs measure along the scale: 1.05 kHz is close to
+        // 1 kHz, while 120 Hz is far from 100 Hz
+        let detents = [100.0, 1000.0];
+        let snap = |value| snap_to_detent(value, &detents, 20.0, 20_000.0, Scale::Logarithmic);
+        assert_eq!(snap(1050.0), 1000.0);
+        assert_eq!(snap(120.0), 120.0);
+
+        assert!(on_detent(50.0, &[0.0, 50.0], 0.0, 100.0));
+        assert!(!on_detent(50.5, &[0.0, 50.0], 0.0, 100.0));
+    }
+}
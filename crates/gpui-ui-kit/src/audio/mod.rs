@@ -1,12 +1,23 @@
+pub mod device_select;
 mod interactions;
 pub mod potentiometer;
+pub mod signal_generator;
+pub mod transport;
+mod value_entry;
 pub mod vertical_slider;
+pub mod vertical_slider_pair;
 pub mod volume_knob;
 
+pub use device_select::*;
 pub use interactions::{
-    DragState, InteractionConfig, ValueTracker, clear_drag_state, get_drag_state, handle_drag,
-    handle_keyboard, handle_scroll, store_drag_state, value_tracker,
+    DETENT_SNAP, DragState, InteractionConfig, ScrubMapping, ScrubState, ValueTracker,
+    clear_drag_state, end_scrub, get_drag_state, handle_drag, handle_keyboard, handle_scroll,
+    is_scrubbing, on_detent, scrub_speed, snap_to_detent, start_scrub, store_drag_state,
+    update_scrub, value_tracker,
 };
 pub use potentiometer::*;
+pub use signal_generator::*;
+pub use transport::*;
 pub use vertical_slider::*;
+pub use vertical_slider_pair::*;
 pub use volume_knob::*;
@@ -2,9 +2,14 @@
 //!
 //! A circular knob with:
 //! - Selection highlighting for plugin parameter editing
-//! - Drag support with vertical mouse movement (via on_drag_start handler)
+//! - Drag to scrub: up or right increases (Shift fine, Ctrl/Cmd coarse),
+//!   following the knob scale; or custom dragging via on_drag_start
 //! - Scroll wheel adjustment (Shift for fine control: 0.5% vs 5%)
-//! - Double-click to reset to default
+//! - Double-click to reset to default, or to type an exact value in a
+//!   popover with `value_entry(true)`
+//! - Detents that catch the value while dragging, marked by ticks that
+//!   light up when the value rests on them
+//! - Bipolar display filling from the center, for pan and gain
 //! - Keyboard navigation (when focused via click):
 //!   - Arrow Up/Right: increase value (5%)
 //!   - Arrow Down/Left: decrease value (5%)
@@ -16,10 +21,16 @@
 //! - Rotating indicator dot
 //! - Tick marks with major (labeled) and minor (unlabeled) ticks
 
-use super::interactions::{InteractionConfig, handle_keyboard, handle_scroll, value_tracker};
+use super::interactions::{
+    InteractionConfig, ScrubMapping, end_scrub, handle_keyboard, handle_scroll, on_detent,
+    snap_to_detent, start_scrub, update_scrub, value_tracker,
+};
+use super::value_entry::{EntryStyle, ValueEntry};
 use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::focus::{FocusAudit, FocusRing, FocusRingExt};
 use crate::scale::Scale;
-use crate::theme::ThemeExt;
+use crate::theme::{Elevation, ThemeExt};
 use gpui::prelude::*;
 use gpui::*;
 
@@ -130,11 +141,14 @@ pub struct Potentiometer {
     scale: PotentiometerScale,
     selected: bool,
     disabled: bool,
+    detents: Vec<f64>,
+    bipolar: bool,
+    value_entry: bool,
     theme: Option<PotentiometerTheme>,
-    on_change: Option<Box<dyn Fn(f64, &mut Window, &mut App) + 'static>>,
-    on_drag_start: Option<Box<dyn Fn(f32, f64, &mut Window, &mut App) + 'static>>,
-    on_select: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
-    on_reset: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<f64>>,
+    on_drag_start: Option<Callback<(f32, f64)>>,
+    on_select: Option<Callback>,
+    on_reset: Option<Callback>,
     focus_handle: Option<FocusHandle>,
 }
 
@@ -153,6 +167,9 @@ impl Potentiometer {
             scale: PotentiometerScale::default(),
             selected: false,
             disabled: false,
+            detents: Vec::new(),
+            bipolar: false,
+            value_entry: false,
             theme: None,
             on_change: None,
             on_drag_start: None,
@@ -167,6 +184,15 @@ impl Potentiometer {
         self.scale.value_to_normalized(value, self.min, self.max)
     }
 
+    /// Normalized position the bipolar fill starts from
+    fn bipolar_origin(&self) -> f64 {
+        if self.min < 0.0 && self.max > 0.0 {
+            self.value_to_normalized(0.0)
+        } else {
+            0.5
+        }
+    }
+
     /// Set the current value (clamped to min/max during render)
     pub fn value(mut self, value: f64) -> Self {
         self.value = value;
@@ -232,6 +258,35 @@ impl Potentiometer {
         self
     }
 
+    /// Set detent positions (e.g. 0 dB, or the center of a pan knob)
+    ///
+    /// Dragging near a detent snaps to it, unless Shift is held for fine
+    /// control. Each detent is marked by a tick that lights up while the
+    /// value rests on it.
+    pub fn detents(mut self, detents: impl IntoIterator<Item = f64>) -> Self {
+        self.detents = detents.into_iter().collect();
+        self
+    }
+
+    /// Fill from the center instead of the minimum, for pan and gain
+    ///
+    /// The fill starts at 0 when the range spans it, at the middle of the
+    /// travel otherwise.
+    pub fn bipolar(mut self, bipolar: bool) -> Self {
+        self.bipolar = bipolar;
+        self
+    }
+
+    /// Open a popover to type an exact value on double-click
+    ///
+    /// Replaces reset on double-click (Escape still resets), and single
+    /// clicks no longer step the value so that the first click of a
+    /// double-click leaves it alone. Requires `on_change`.
+    pub fn value_entry(mut self, value_entry: bool) -> Self {
+        self.value_entry = value_entry;
+        self
+    }
+
     /// Set theme colors
     pub fn theme(mut self, theme: PotentiometerTheme) -> Self {
         self.theme = Some(theme);
@@ -244,7 +299,7 @@ impl Potentiometer {
     /// clicking the potentiometer will increment the value by 10% and wrap around at max.
     /// Scrolling will adjust the value by 5% increments.
     pub fn on_change(mut self, handler: impl Fn(f64, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(handler));
         self
     }
 
@@ -253,19 +308,22 @@ impl Potentiometer {
         mut self,
         handler: impl Fn(f32, f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_drag_start = Some(Box::new(handler));
+        self.on_drag_start = Some(Callback::new(
+            move |(position, value): (f32, f64), window, cx| handler(position, value, window, cx),
+        ));
         self
     }
 
     /// Set select handler (called on click to select this parameter)
     pub fn on_select(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_select = Some(Box::new(handler));
+        self.on_select = Some(Callback::from_fn(handler));
         self
     }
 
-    /// Set reset handler (called on double-click)
+    /// Set reset handler (called on double-click, unless value entry is
+    /// enabled, and on Escape)
     pub fn on_reset(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_reset = Some(Box::new(handler));
+        self.on_reset = Some(Callback::from_fn(handler));
         self
     }
 
@@ -359,6 +417,7 @@ impl RenderOnce for Potentiometer {
 
         // Use scale-aware normalization for indicator position
         let normalized = self.value_to_normalized(self.value) as f32;
+        let bipolar_origin = self.bipolar.then(|| self.bipolar_origin() as f32);
 
         // Calculate angle for indicator with dead zone at 6 o'clock (bottom)
         // In screen coordinates (y-down): 0° = 3 o'clock, 90° = 6 o'clock, 180° = 9 o'clock, 270° = 12 o'clock
@@ -437,12 +496,17 @@ impl RenderOnce for Potentiometer {
         let min = self.min;
         let max = self.max;
         let scale = self.scale;
+        let detents: std::rc::Rc<[f64]> = self.detents.into();
 
         // Shared current value tracker and interaction config
         let current_value = value_tracker(value);
         // Potentiometer uses rotational config (drag distance = knob_size for full range)
         let interaction_config = InteractionConfig::rotational(min, max, scale, knob_size);
 
+        let audit_label = self.id.to_string();
+        let scrub_key = format!("{:?}", self.id);
+        let value_entry = (self.value_entry && self.on_change.is_some() && !disabled)
+            .then(|| ValueEntry::new(&self.id, cx));
         let mut container = div()
             .id(self.id)
             .flex()
@@ -462,6 +526,15 @@ impl RenderOnce for Potentiometer {
             container = container.track_focus(focus_handle).focusable();
         }
 
+        container = container
+            .relative()
+            .focus_ring(FocusRing::from_theme(&global_theme))
+            .children(FocusAudit::marker(
+                audit_label,
+                self.focus_handle.as_ref(),
+                cx,
+            ));
+
         // Add shadow when selected
         if selected {
             container = container.shadow_md();
@@ -481,15 +554,17 @@ impl RenderOnce for Potentiometer {
 
         // Event handlers
         if !disabled {
-            // Wrap on_change in Rc if it exists, so we can use it in multiple handlers
-            let on_change_rc = self.on_change.map(|handler| std::rc::Rc::new(handler));
-            let on_reset_rc = self.on_reset.map(|handler| std::rc::Rc::new(handler));
+            let on_change_rc = self.on_change;
+            let on_reset_rc = self.on_reset;
 
             // Mouse down - focus, select, and optionally start drag
             let on_select = self.on_select;
             let on_drag_start = self.on_drag_start;
+            let scrubs = on_drag_start.is_none() && on_change_rc.is_some();
+            let steps_on_click = value_entry.is_none();
             let on_change_click = on_change_rc.clone();
             let focus_handle_click = self.focus_handle.clone();
+            let scrub_key_down = scrub_key.clone();
 
             container = container.on_mouse_down(MouseButton::Left, move |event, window, cx| {
                 // Always focus for keyboard navigation
@@ -499,25 +574,71 @@ impl RenderOnce for Potentiometer {
 
                 // Handle Selection
                 if let Some(ref handler) = on_select {
-                    handler(window, cx);
+                    handler.invoke(window, cx);
                 }
 
                 // Handle Drag or Click-Step
                 if let Some(ref handler) = on_drag_start {
-                    handler(event.position.y.into(), value, window, cx);
+                    handler.call((event.position.y.into(), value), window, cx);
                 } else if let Some(ref handler) = on_change_click {
+                    // A drag scrubs from the value before the click step
+                    let (x, y) = (event.position.x.into(), event.position.y.into());
+                    start_scrub(&scrub_key_down, x, y, value);
                     // If no drag handler, use click to step value (scale-aware)
-                    let new_value = scale.step_value(value, min, max, 1.0, 0.1);
-                    handler(new_value, window, cx);
+                    if steps_on_click {
+                        let new_value = scale.step_value(value, min, max, 1.0, 0.1);
+                        handler.call(new_value, window, cx);
+                    }
                 }
             });
 
-            // Double-click - reset
-            if let Some(ref reset_rc) = on_reset_rc {
+            // Drag to scrub, with the knob size covering the whole range
+            if scrubs && let Some(ref handler_rc) = on_change_rc {
+                let scrub_key_up = scrub_key.clone();
+                container = container.on_mouse_up(MouseButton::Left, move |_, _, _| {
+                    end_scrub(&scrub_key_up);
+                });
+
+                let handler = handler_rc.clone();
+                let current_value_drag = current_value.clone();
+                let detents_drag = detents.clone();
+                let mapping = ScrubMapping::Range {
+                    scale,
+                    pixels: knob_size,
+                };
+                container = container.on_mouse_move(move |event, window, cx| {
+                    if event.pressed_button != Some(MouseButton::Left) {
+                        return;
+                    }
+                    let (x, y) = (event.position.x.into(), event.position.y.into());
+                    if let Some(new_value) =
+                        update_scrub(&scrub_key, x, y, &event.modifiers, min, max, mapping)
+                    {
+                        // Fine control passes over the detents
+                        let new_value = if event.modifiers.shift {
+                            new_value
+                        } else {
+                            snap_to_detent(new_value, &detents_drag, min, max, scale)
+                        };
+                        current_value_drag.set(new_value);
+                        handler.call(new_value, window, cx);
+                    }
+                });
+            }
+
+            // Double-click - type an exact value, or reset
+            if let Some(ref entry) = value_entry {
+                let entry_click = entry.clone();
+                container = container.on_click(move |event, window, cx| {
+                    if event.click_count() == 2 {
+                        entry_click.open(window, cx);
+                    }
+                });
+            } else if let Some(ref reset_rc) = on_reset_rc {
                 let reset_handler = reset_rc.clone();
                 container = container.on_click(move |event, window, cx| {
                     if event.click_count() == 2 {
-                        reset_handler(window, cx);
+                        reset_handler.invoke(window, cx);
                     }
                 });
             }
@@ -533,7 +654,7 @@ impl RenderOnce for Potentiometer {
                     let key = event.keystroke.key.as_str();
                     if key == "escape" {
                         if let Some(ref reset_handler) = reset_key {
-                            reset_handler(window, cx);
+                            reset_handler.invoke(window, cx);
                         }
                     } else if let Some(ref handler) = handler_key
                         && let Some(new_value) = handle_keyboard(
@@ -544,11 +665,31 @@ impl RenderOnce for Potentiometer {
                         )
                     {
                         current_value_key.set(new_value);
-                        handler(new_value, window, cx);
+                        handler.call(new_value, window, cx);
                     }
                 });
             }
 
+            // Value entry popover, while open
+            if let Some(ref entry) = value_entry
+                && let Some(ref handler_rc) = on_change_rc
+            {
+                let handler = handler_rc.clone();
+                let style = EntryStyle {
+                    background: theme.surface,
+                    border: theme.accent,
+                    shadow: global_theme.elevation.shadow(Elevation::Overlay),
+                };
+                container = container.children(entry.popover(
+                    value_str_only.clone(),
+                    &unit_str,
+                    min,
+                    max,
+                    style,
+                    move |new_value, window, cx| handler.call(new_value, window, cx),
+                ));
+            }
+
             // Scroll wheel - adjust value
             if let Some(handler_rc) = on_change_rc {
                 let current_value_scroll = current_value.clone();
@@ -560,17 +701,20 @@ impl RenderOnce for Potentiometer {
                         handle_scroll(&event.delta, &event.modifiers, val, &config_scroll)
                     {
                         current_value_scroll.set(new_value);
-                        handler_rc(new_value, window, cx);
+                        handler_rc.call(new_value, window, cx);
                     }
                 });
             }
 
             // Focus on mouse enter - keyboard follows hover like scroll wheel
             let focus_handle_hover = self.focus_handle.clone();
+            let entry_hover = value_entry.clone();
             container = container.on_mouse_move(move |event, window, cx| {
                 if let Some(ref fh) = focus_handle_hover
                     && !fh.is_focused(window)
                     && event.pressed_button.is_none()
+                    // Leave the focus in the open value entry
+                    && !entry_hover.as_ref().is_some_and(ValueEntry::is_open)
                 {
                     fh.focus(window, cx);
                 }
@@ -753,6 +897,36 @@ impl RenderOnce for Potentiometer {
             }
         }
 
+        // Detent ticks, longer than the major ticks and lit while the value
+        // rests on them
+        let detent_outer_radius = major_tick_outer_radius + 3.0;
+        for &detent in detents.iter().filter(|d| (min..=max).contains(*d)) {
+            let detent_normalized = scale.value_to_normalized(detent, min, max) as f32;
+            let detent_angle = start_rad + (end_rad - start_rad) * detent_normalized;
+            let (tick_width, tick_color) = if on_detent(value, &[detent], min, max) {
+                (major_tick_width + 1.0, theme.accent)
+            } else {
+                (major_tick_width, theme.text_secondary)
+            };
+            let tick_length = detent_outer_radius - tick_inner_radius;
+            let num_dots = (tick_length / 1.5) as usize;
+            for j in 0..=num_dots {
+                let r = tick_inner_radius + tick_length * j as f32 / num_dots as f32;
+                let dot_x = knob_offset + center + r * detent_angle.cos() - tick_width / 2.0;
+                let dot_y = knob_offset + center + r * detent_angle.sin() - tick_width / 2.0;
+                knob_container = knob_container.child(
+                    div()
+                        .absolute()
+                        .left(px(dot_x))
+                        .top(px(dot_y))
+                        .w(px(tick_width))
+                        .h(px(tick_width))
+                        .rounded_full()
+                        .bg(tick_color),
+                );
+            }
+        }
+
         // Knob circle (offset to center in larger container)
         // Use major_tick_color for border to match ticks and labels
         let mut knob = div()
@@ -779,6 +953,33 @@ impl RenderOnce for Potentiometer {
             );
         }
 
+        // Bipolar fill: an arc from the origin to the value, along the
+        // indicator's path
+        if let Some(origin) = bipolar_origin {
+            let origin_rad = start_rad + (end_rad - start_rad) * origin;
+            let arc_length = (angle_rad - origin_rad).abs() * radius;
+            let num_dots = (arc_length / 2.0) as usize;
+            let dot_size = 3.0;
+            for j in 0..=num_dots {
+                let t = if num_dots == 0 {
+                    0.0
+                } else {
+                    j as f32 / num_dots as f32
+                };
+                let dot_angle = origin_rad + (angle_rad - origin_rad) * t;
+                knob = knob.child(
+                    div()
+                        .absolute()
+                        .left(px(center + radius * dot_angle.cos() - dot_size / 2.0))
+                        .top(px(center + radius * dot_angle.sin() - dot_size / 2.0))
+                        .w(px(dot_size))
+                        .h(px(dot_size))
+                        .rounded_full()
+                        .bg(major_tick_color),
+                );
+            }
+        }
+
         // Indicator dot
         let mut indicator = div()
             .absolute()
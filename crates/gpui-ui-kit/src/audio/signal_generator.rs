@@ -0,0 +1,570 @@
+//! SignalGeneratorPanel - Test signal generator controls
+//!
+//! A control panel for a measurement signal generator with:
+//! - Waveform selection: sine, log sweep, pink/white noise, MLS
+//! - Frequency knob (sine) and output level knob in dBFS
+//! - Sweep start/end frequency and duration
+//! - MLS order (sequence length 2^n - 1)
+//! - Start/stop button emitting a typed [`GeneratorConfig`]
+//!
+//! The panel is presentational: the app owns the config and the running
+//! state and feeds them back on every render.
+//!
+//! # Example
+//!
+//! ```ignore
+//! SignalGeneratorPanel::new("generator")
+//!     .config(self.generator)
+//!     .running(self.is_playing)
+//!     .on_change(cx.listener(|this, config, _window, cx| {
+//!         this.generator = *config;
+//!         cx.notify();
+//!     }))
+//!     .on_start(|config, _window, _cx| println!("start {:?}", config))
+//!     .on_stop(|_window, _cx| println!("stop"))
+//! ```
+
+use super::potentiometer::{Potentiometer, PotentiometerScale, PotentiometerSize};
+use crate::ComponentTheme;
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::button_set::{ButtonSet, ButtonSetOption, ButtonSetSize};
+use crate::callback::Callback;
+use crate::number_input::{NumberInput, NumberInputSize};
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Lowest generator frequency in Hz
+pub const GENERATOR_MIN_FREQ: f64 = 10.0;
+/// Highest generator frequency in Hz
+pub const GENERATOR_MAX_FREQ: f64 = 24_000.0;
+/// Lowest output level in dBFS
+pub const GENERATOR_MIN_LEVEL: f64 = -90.0;
+
+/// Generator waveform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Waveform {
+    /// Pure tone at a fixed frequency
+    #[default]
+    Sine,
+    /// Logarithmic sine sweep
+    Sweep,
+    /// Pink noise (-3 dB/octave)
+    PinkNoise,
+    /// White noise (flat)
+    WhiteNoise,
+    /// Maximum length sequence
+    Mls,
+}
+
+impl Waveform {
+    /// All waveforms in display order
+    pub const ALL: [Waveform; 5] = [
+        Self::Sine,
+        Self::Sweep,
+        Self::PinkNoise,
+        Self::WhiteNoise,
+        Self::Mls,
+    ];
+
+    /// Stable identifier, used as the option value
+    pub fn value(self) -> &'static str {
+        match self {
+            Self::Sine => "sine",
+            Self::Sweep => "sweep",
+            Self::PinkNoise => "pink",
+            Self::WhiteNoise => "white",
+            Self::Mls => "mls",
+        }
+    }
+
+    /// Parse a value returned by [`Waveform::value`]
+    pub fn from_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|w| w.value() == value)
+    }
+
+    /// Display label
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Sine => "Sine",
+            Self::Sweep => "Sweep",
+            Self::PinkNoise => "Pink",
+            Self::WhiteNoise => "White",
+            Self::Mls => "MLS",
+        }
+    }
+}
+
+/// Sweep settings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SweepSettings {
+    /// Start frequency in Hz
+    pub start_hz: f64,
+    /// End frequency in Hz
+    pub end_hz: f64,
+    /// Sweep duration in seconds
+    pub duration_s: f64,
+}
+
+impl Default for SweepSettings {
+    fn default() -> Self {
+        Self {
+            start_hz: 20.0,
+            end_hz: 20_000.0,
+            duration_s: 5.0,
+        }
+    }
+}
+
+/// Complete generator configuration emitted by the panel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeneratorConfig {
+    /// Selected waveform
+    pub waveform: Waveform,
+    /// Sine frequency in Hz
+    pub frequency_hz: f64,
+    /// Output level in dBFS (≤ 0)
+    pub level_dbfs: f64,
+    /// Sweep settings (used by [`Waveform::Sweep`])
+    pub sweep: SweepSettings,
+    /// MLS order n, sequence length 2^n - 1 (used by [`Waveform::Mls`])
+    pub mls_order: u8,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        Self {
+            waveform: Waveform::Sine,
+            frequency_hz: 1000.0,
+            level_dbfs: -20.0,
+            sweep: SweepSettings::default(),
+            mls_order: 16,
+        }
+    }
+}
+
+impl GeneratorConfig {
+    /// Clamp every field into its valid range; sweep start stays below end
+    pub fn clamped(mut self) -> Self {
+        let freq = |f: f64| {
+            if f.is_nan() {
+                GENERATOR_MIN_FREQ
+            } else {
+                f.clamp(GENERATOR_MIN_FREQ, GENERATOR_MAX_FREQ)
+            }
+        };
+        self.frequency_hz = freq(self.frequency_hz);
+        self.level_dbfs = if self.level_dbfs.is_nan() {
+            GENERATOR_MIN_LEVEL
+        } else {
+            self.level_dbfs.clamp(GENERATOR_MIN_LEVEL, 0.0)
+        };
+        self.sweep.start_hz = freq(self.sweep.start_hz);
+        self.sweep.end_hz = freq(self.sweep.end_hz);
+        if self.sweep.start_hz > self.sweep.end_hz {
+            std::mem::swap(&mut self.sweep.start_hz, &mut self.sweep.end_hz);
+        }
+        self.sweep.duration_s = if self.sweep.duration_s.is_nan() {
+            1.0
+        } else {
+            self.sweep.duration_s.clamp(0.1, 60.0)
+        };
+        self.mls_order = self.mls_order.clamp(8, 20);
+        self
+    }
+
+    /// MLS sequence length in samples
+    pub fn mls_length(&self) -> u32 {
+        (1u32 << self.mls_order) - 1
+    }
+
+    /// Linear output amplitude (1.0 = full scale)
+    pub fn amplitude(&self) -> f64 {
+        // `d3rs::acoustics::db_to_linear`, which the kit cannot call: d3rs
+        // depends on the kit for its widgets
+        10f64.powf(self.level_dbfs / 20.0)
+    }
+}
+
+/// Theme colors for the signal generator panel
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct SignalGeneratorPanelTheme {
+    /// Panel background
+    #[theme(default = 0x1e1e1eff, from = surface)]
+    pub background: Rgba,
+    /// Panel border
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub border: Rgba,
+    /// Title text color
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub title: Rgba,
+    /// Section label color
+    #[theme(default = 0x888888ff, from = text_muted)]
+    pub label: Rgba,
+    /// Running indicator color
+    #[theme(default = 0x22c55eff, from = success)]
+    pub running: Rgba,
+    /// Stopped indicator color
+    #[theme(default = 0x555555ff, from = muted)]
+    pub idle: Rgba,
+}
+
+/// Control panel for a test signal generator
+#[derive(IntoElement)]
+pub struct SignalGeneratorPanel {
+    id: ElementId,
+    config: GeneratorConfig,
+    title: Option<SharedString>,
+    running: bool,
+    disabled: bool,
+    theme: Option<SignalGeneratorPanelTheme>,
+    on_change: Option<Callback<GeneratorConfig>>,
+    on_start: Option<Callback<GeneratorConfig>>,
+    on_stop: Option<Callback>,
+}
+
+impl SignalGeneratorPanel {
+    /// Create a new panel with the default config
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            config: GeneratorConfig::default(),
+            title: Some("Signal Generator".into()),
+            running: false,
+            disabled: false,
+            theme: None,
+            on_change: None,
+            on_start: None,
+            on_stop: None,
+        }
+    }
+
+    /// Set the current config (clamped to valid ranges)
+    pub fn config(mut self, config: GeneratorConfig) -> Self {
+        self.config = config.clamped();
+        self
+    }
+
+    /// Set the panel title
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Hide the panel title
+    pub fn hide_title(mut self) -> Self {
+        self.title = None;
+        self
+    }
+
+    /// Set whether the generator is currently playing
+    pub fn running(mut self, running: bool) -> Self {
+        self.running = running;
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the theme
+    pub fn theme(mut self, theme: SignalGeneratorPanelTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called with the updated config whenever a control changes
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&GeneratorConfig, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Callback::new(move |config: GeneratorConfig, window, cx| {
+            handler(&config, window, cx)
+        }));
+        self
+    }
+
+    /// Called with the current config when Start is pressed
+    pub fn on_start(
+        mut self,
+        handler: impl Fn(&GeneratorConfig, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_start = Some(Callback::new(move |config: GeneratorConfig, window, cx| {
+            handler(&config, window, cx)
+        }));
+        self
+    }
+
+    /// Called when Stop is pressed
+    pub fn on_stop(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_stop = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Child element id scoped to this panel
+    fn child_id(&self, suffix: &str) -> ElementId {
+        ElementId::Name(SharedString::from(format!("{}-{}", self.id, suffix)))
+    }
+
+    /// Wrap an edit of the config into a change callback
+    fn emit(
+        &self,
+        edit: impl Fn(&mut GeneratorConfig, f64) + 'static,
+    ) -> impl Fn(f64, &mut Window, &mut App) + 'static {
+        let config = self.config;
+        let on_change = self.on_change.clone();
+        move |value, window, cx| {
+            let mut next = config;
+            edit(&mut next, value);
+            let next = next.clamped();
+            if let Some(handler) = &on_change {
+                handler.call(next, window, cx);
+            }
+        }
+    }
+
+    fn section(label: &'static str, theme: &SignalGeneratorPanelTheme) -> Div {
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(div().text_xs().text_color(theme.label).child(label))
+    }
+
+    fn build(self, theme: &SignalGeneratorPanelTheme) -> Stateful<Div> {
+        let config = self.config;
+        let locked = self.disabled || self.running;
+
+        // Waveform selector
+        let on_waveform = self.on_change.clone().map(|handler| {
+            move |value: &SharedString, window: &mut Window, cx: &mut App| {
+                if let Some(waveform) = Waveform::from_value(value) {
+                    handler.call(GeneratorConfig { waveform, ..config }, window, cx);
+                }
+            }
+        });
+        let mut waveforms = ButtonSet::new(self.child_id("waveform"))
+            .options(
+                Waveform::ALL
+                    .into_iter()
+                    .map(|w| ButtonSetOption::new(w.value(), w.label()))
+                    .collect(),
+            )
+            .selected(config.waveform.value())
+            .size(ButtonSetSize::Sm)
+            .disabled(locked);
+        if let Some(handler) = on_waveform {
+            waveforms = waveforms.on_change(handler);
+        }
+
+        // Knobs: frequency (sine only) and level
+        let mut knobs = div().flex().items_start().gap_4();
+        if config.waveform == Waveform::Sine {
+            knobs = knobs.child(
+                Potentiometer::new(self.child_id("frequency"))
+                    .label("Frequency")
+                    .value(config.frequency_hz)
+                    .min(GENERATOR_MIN_FREQ)
+                    .max(GENERATOR_MAX_FREQ)
+                    .unit("Hz")
+                    .scale(PotentiometerScale::Logarithmic)
+                    .size(PotentiometerSize::Md)
+                    .disabled(self.disabled)
+                    .on_change(self.emit(|c, v| c.frequency_hz = v)),
+            );
+        }
+        knobs = knobs.child(
+            Potentiometer::new(self.child_id("level"))
+                .label("Level")
+                .value(config.level_dbfs)
+                .min(GENERATOR_MIN_LEVEL)
+                .max(0.0)
+                .unit("dBFS")
+                .size(PotentiometerSize::Md)
+                .disabled(self.disabled)
+                .on_change(self.emit(|c, v| c.level_dbfs = v)),
+        );
+
+        // Waveform-specific parameters
+        let params = match config.waveform {
+            Waveform::Sweep => Some(
+                Self::section("Sweep", theme).child(
+                    div()
+                        .flex()
+                        .gap_2()
+                        .child(
+                            NumberInput::new(self.child_id("sweep-start"))
+                                .label("Start")
+                                .value(config.sweep.start_hz)
+                                .range(GENERATOR_MIN_FREQ, GENERATOR_MAX_FREQ)
+                                .unit("Hz")
+                                .size(NumberInputSize::Sm)
+                                .disabled(locked)
+                                .on_change(self.emit(|c, v| c.sweep.start_hz = v)),
+                        )
+                        .child(
+                            NumberInput::new(self.child_id("sweep-end"))
+                                .label("End")
+                                .value(config.sweep.end_hz)
+                                .range(GENERATOR_MIN_FREQ, GENERATOR_MAX_FREQ)
+                                .unit("Hz")
+                                .size(NumberInputSize::Sm)
+                                .disabled(locked)
+                                .on_change(self.emit(|c, v| c.sweep.end_hz = v)),
+                        )
+                        .child(
+                            NumberInput::new(self.child_id("sweep-duration"))
+                                .label("Duration")
+                                .value(config.sweep.duration_s)
+                                .range(0.1, 60.0)
+                                .step(0.5)
+                                .decimals(1)
+                                .unit("s")
+                                .size(NumberInputSize::Sm)
+                                .disabled(locked)
+                                .on_change(self.emit(|c, v| c.sweep.duration_s = v)),
+                        ),
+                ),
+            ),
+            Waveform::Mls => Some(
+                Self::section("MLS", theme).child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            NumberInput::new(self.child_id("mls-order"))
+                                .label("Order")
+                                .value(config.mls_order as f64)
+                                .range(8.0, 20.0)
+                                .size(NumberInputSize::Sm)
+                                .disabled(locked)
+                                .on_change(self.emit(|c, v| c.mls_order = v.round() as u8)),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(theme.label)
+                                .child(format!("{} samples", config.mls_length())),
+                        ),
+                ),
+            ),
+            _ => None,
+        };
+
+        // Start / stop
+        let transport = if self.running {
+            let mut button = Button::new(self.child_id("stop"), "Stop")
+                .variant(ButtonVariant::Destructive)
+                .size(ButtonSize::Sm)
+                .disabled(self.disabled);
+            if let Some(handler) = self.on_stop {
+                button = button.on_click(move |window, cx| handler.invoke(window, cx));
+            }
+            button
+        } else {
+            let mut button = Button::new(self.child_id("start"), "Start")
+                .variant(ButtonVariant::Primary)
+                .size(ButtonSize::Sm)
+                .disabled(self.disabled);
+            if let Some(handler) = self.on_start {
+                button = button.on_click(move |window, cx| handler.call(config, window, cx));
+            }
+            button
+        };
+
+        let indicator = div().size(px(8.0)).rounded_full().bg(if self.running {
+            theme.running
+        } else {
+            theme.idle
+        });
+
+        let mut header = div().flex().items_center().gap_2().child(indicator);
+        if let Some(title) = self.title {
+            header = header.child(
+                div()
+                    .flex_1()
+                    .text_sm()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.title)
+                    .child(title),
+            );
+        } else {
+            header = header.child(div().flex_1());
+        }
+        header = header.child(transport);
+
+        let mut panel = div()
+            .id(self.id)
+            .flex()
+            .flex_col()
+            .gap_3()
+            .p_3()
+            .rounded_md()
+            .border_1()
+            .border_color(theme.border)
+            .bg(theme.background)
+            .child(header)
+            .child(Self::section("Waveform", theme).child(waveforms))
+            .child(knobs);
+        if let Some(params) = params {
+            panel = panel.child(params);
+        }
+        panel
+    }
+}
+
+impl RenderOnce for SignalGeneratorPanel {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| SignalGeneratorPanelTheme::from(&global_theme));
+        self.build(&theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waveform_values_round_trip() {
+        for waveform in Waveform::ALL {
+            assert_eq!(Waveform::from_value(waveform.value()), Some(waveform));
+        }
+        assert_eq!(Waveform::from_value("square"), None);
+    }
+
+    #[test]
+    fn test_config_clamped() {
+        let config = GeneratorConfig {
+            frequency_hz: 1.0,
+            level_dbfs: 6.0,
+            sweep: SweepSettings {
+                start_hz: 30_000.0,
+                end_hz: 100.0,
+                duration_s: f64::NAN,
+            },
+            mls_order: 40,
+            ..Default::default()
+        }
+        .clamped();
+        assert_eq!(config.frequency_hz, GENERATOR_MIN_FREQ);
+        assert_eq!(config.level_dbfs, 0.0);
+        assert_eq!(config.sweep.start_hz, 100.0);
+        assert_eq!(config.sweep.end_hz, GENERATOR_MAX_FREQ);
+        assert_eq!(config.sweep.duration_s, 1.0);
+        assert_eq!(config.mls_order, 20);
+    }
+
+    #[test]
+    fn test_derived_values() {
+        let config = GeneratorConfig::default();
+        assert_eq!(config.mls_length(), 65_535);
+        assert!((config.amplitude() - 0.1).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,549 @@
+//! TransportBar - Media transport controls
+//!
+//! A horizontal bar of playback controls with:
+//! - Play/pause, stop and record buttons
+//! - Seek track with buffered region, click or drag to seek
+//! - Elapsed and total time labels (`m:ss` or `h:mm:ss`)
+//! - Loop toggle and playback-rate selector
+//!
+//! The bar is presentational: the app owns playback state and position and
+//! reacts to the callbacks.
+//!
+//! # Example
+//!
+//! ```ignore
+//! TransportBar::new("transport")
+//!     .state(self.playback)
+//!     .position(self.position_s)
+//!     .duration(self.duration_s)
+//!     .buffered(self.buffered_s)
+//!     .on_play(|_window, _cx| println!("play"))
+//!     .on_pause(|_window, _cx| println!("pause"))
+//!     .on_seek(|seconds, _window, _cx| println!("seek to {seconds}"))
+//! ```
+
+use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::icon_button::{IconButton, IconButtonSize, IconButtonVariant};
+use crate::select::{Select, SelectOption, SelectSize};
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Playback rates offered by the rate selector
+pub const PLAYBACK_RATES: &[f32] = &[0.5, 0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Playback state shown by the transport
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PlaybackState {
+    /// Stopped at the start
+    #[default]
+    Stopped,
+    /// Playing
+    Playing,
+    /// Paused at the current position
+    Paused,
+}
+
+/// Format a time in seconds as `m:ss`, or `h:mm:ss` from one hour.
+///
+/// Negative, NaN and infinite values render as `--:--`.
+pub fn format_time(seconds: f64) -> String {
+    if !seconds.is_finite() || seconds < 0.0 {
+        return "--:--".to_string();
+    }
+    let total = seconds.floor() as u64;
+    let (h, m, s) = (total / 3600, (total / 60) % 60, total % 60);
+    if h > 0 {
+        format!("{h}:{m:02}:{s:02}")
+    } else {
+        format!("{m}:{s:02}")
+    }
+}
+
+/// Format a playback rate, e.g. 1.0 → "1×", 0.75 → "0.75×"
+pub fn format_rate(rate: f32) -> String {
+    let text = format!("{rate:.2}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    format!("{text}×")
+}
+
+/// Fraction of `total` covered by `value`, clamped to [0, 1]
+fn fraction(value: f64, total: f64) -> f32 {
+    if total > 0.0 && value.is_finite() {
+        (value / total).clamp(0.0, 1.0) as f32
+    } else {
+        0.0
+    }
+}
+
+/// Theme colors for the transport bar
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct TransportBarTheme {
+    /// Bar background
+    #[theme(default = 0x1e1e1eff, from = surface)]
+    pub background: Rgba,
+    /// Seek track background
+    #[theme(default = 0x3a3a3aff, from = muted)]
+    pub track: Rgba,
+    /// Buffered region
+    #[theme(default = 0x666666ff, from = border_hover)]
+    pub buffered: Rgba,
+    /// Played region
+    #[theme(default = 0x007accff, from = accent)]
+    pub progress: Rgba,
+    /// Seek thumb
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub thumb: Rgba,
+    /// Time label color
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub time: Rgba,
+    /// Record button color when armed
+    #[theme(default = 0xef4444ff, from = error)]
+    pub record: Rgba,
+}
+
+/// Media transport bar with seek track
+#[derive(IntoElement)]
+pub struct TransportBar {
+    id: ElementId,
+    state: PlaybackState,
+    recording: bool,
+    position: f64,
+    duration: f64,
+    buffered: f64,
+    loop_enabled: bool,
+    rate: f32,
+    rate_menu_open: bool,
+    show_record: bool,
+    disabled: bool,
+    theme: Option<TransportBarTheme>,
+    on_play: Option<Callback>,
+    on_pause: Option<Callback>,
+    on_stop: Option<Callback>,
+    on_record: Option<Callback<bool>>,
+    on_seek: Option<Callback<f64>>,
+    on_loop: Option<Callback<bool>>,
+    on_rate: Option<Callback<f32>>,
+    on_rate_menu_toggle: Option<Callback<bool>>,
+}
+
+impl TransportBar {
+    /// Create a new transport bar
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            state: PlaybackState::default(),
+            recording: false,
+            position: 0.0,
+            duration: 0.0,
+            buffered: 0.0,
+            loop_enabled: false,
+            rate: 1.0,
+            rate_menu_open: false,
+            show_record: true,
+            disabled: false,
+            theme: None,
+            on_play: None,
+            on_pause: None,
+            on_stop: None,
+            on_record: None,
+            on_seek: None,
+            on_loop: None,
+            on_rate: None,
+            on_rate_menu_toggle: None,
+        }
+    }
+
+    /// Set the playback state
+    pub fn state(mut self, state: PlaybackState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Set whether recording is armed
+    pub fn recording(mut self, recording: bool) -> Self {
+        self.recording = recording;
+        self
+    }
+
+    /// Set the playback position in seconds
+    pub fn position(mut self, seconds: f64) -> Self {
+        self.position = seconds;
+        self
+    }
+
+    /// Set the total duration in seconds
+    pub fn duration(mut self, seconds: f64) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Set how far the media is buffered, in seconds
+    pub fn buffered(mut self, seconds: f64) -> Self {
+        self.buffered = seconds;
+        self
+    }
+
+    /// Set the loop toggle state
+    pub fn loop_enabled(mut self, enabled: bool) -> Self {
+        self.loop_enabled = enabled;
+        self
+    }
+
+    /// Set the playback rate (1.0 = normal speed)
+    pub fn rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Set the rate selector open state (controlled)
+    pub fn rate_menu_open(mut self, open: bool) -> Self {
+        self.rate_menu_open = open;
+        self
+    }
+
+    /// Show the record button (default: true)
+    pub fn show_record(mut self, show: bool) -> Self {
+        self.show_record = show;
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the theme
+    pub fn theme(mut self, theme: TransportBarTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called when play is pressed
+    pub fn on_play(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_play = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Called when pause is pressed
+    pub fn on_pause(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_pause = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Called when stop is pressed
+    pub fn on_stop(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_stop = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Called with the new record state when record is pressed
+    pub fn on_record(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_record = Some(Callback::new(handler));
+        self
+    }
+
+    /// Called with the target position in seconds on click or drag
+    pub fn on_seek(mut self, handler: impl Fn(f64, &mut Window, &mut App) + 'static) -> Self {
+        self.on_seek = Some(Callback::new(handler));
+        self
+    }
+
+    /// Called with the new loop state when loop is toggled
+    pub fn on_loop(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_loop = Some(Callback::new(handler));
+        self
+    }
+
+    /// Called with the selected playback rate
+    pub fn on_rate(mut self, handler: impl Fn(f32, &mut Window, &mut App) + 'static) -> Self {
+        self.on_rate = Some(Callback::new(handler));
+        self
+    }
+
+    /// Called when the rate selector is opened or closed
+    pub fn on_rate_menu_toggle(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_rate_menu_toggle = Some(Callback::new(handler));
+        self
+    }
+
+    /// Child element id scoped to this bar
+    fn child_id(&self, suffix: &str) -> ElementId {
+        ElementId::Name(SharedString::from(format!("{}-{}", self.id, suffix)))
+    }
+
+    fn seek_track(&mut self, theme: &TransportBarTheme) -> Stateful<Div> {
+        let played = fraction(self.position, self.duration);
+        let buffered = fraction(self.buffered, self.duration).max(played);
+        let duration = self.duration;
+        let enabled = !self.disabled && duration > 0.0;
+
+        // Track bounds are captured at prepaint so pointer positions can be
+        // mapped to a time regardless of where the bar sits in the window
+        let bounds: Rc<Cell<Option<Bounds<Pixels>>>> = Rc::new(Cell::new(None));
+        let bounds_prepaint = bounds.clone();
+
+        let mut track = div()
+            .id(self.child_id("seek"))
+            .flex_1()
+            .h(px(16.0))
+            .flex()
+            .items_center()
+            .relative()
+            .child(
+                canvas(
+                    move |b, _window, _cx| bounds_prepaint.set(Some(b)),
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .left_0()
+                    .w_full()
+                    .h(px(4.0))
+                    .rounded(px(2.0))
+                    .bg(theme.track),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .left_0()
+                    .w(relative(buffered))
+                    .h(px(4.0))
+                    .rounded(px(2.0))
+                    .bg(theme.buffered),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .left_0()
+                    .w(relative(played))
+                    .h(px(4.0))
+                    .rounded(px(2.0))
+                    .bg(theme.progress),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .left(relative(played))
+                    .ml(px(-5.0))
+                    .size(px(10.0))
+                    .rounded_full()
+                    .bg(theme.thumb),
+            );
+
+        if !enabled {
+            return track.cursor_not_allowed();
+        }
+        track = track.cursor_pointer();
+
+        if let Some(handler) = self.on_seek.take() {
+            let time_at = move |x: Pixels| {
+                bounds.get().map(|b| {
+                    let offset: f32 = (x - b.origin.x).into();
+                    let width: f32 = b.size.width.into();
+                    fraction(offset as f64, width as f64) as f64 * duration
+                })
+            };
+            let time_at = Rc::new(time_at);
+
+            let (handler_down, time_down) = (handler.clone(), time_at.clone());
+            track = track.on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                if let Some(t) = time_down(event.position.x) {
+                    handler_down.call(t, window, cx);
+                }
+            });
+            track = track.on_mouse_move(move |event, window, cx| {
+                if event.pressed_button == Some(MouseButton::Left)
+                    && let Some(t) = time_at(event.position.x)
+                {
+                    handler.call(t, window, cx);
+                }
+            });
+        }
+        track
+    }
+
+    fn build(mut self, theme: &TransportBarTheme) -> Stateful<Div> {
+        let disabled = self.disabled;
+        let playing = self.state == PlaybackState::Playing;
+
+        // Play / pause
+        let play_pause = if playing {
+            let mut button = IconButton::new(self.child_id("pause"), "⏸");
+            if let Some(handler) = self.on_pause.take() {
+                button = button.on_click(move |window, cx| handler.invoke(window, cx));
+            }
+            button
+        } else {
+            let mut button = IconButton::new(self.child_id("play"), "▶");
+            if let Some(handler) = self.on_play.take() {
+                button = button.on_click(move |window, cx| handler.invoke(window, cx));
+            }
+            button
+        };
+        let play_pause = play_pause
+            .size(IconButtonSize::Lg)
+            .variant(IconButtonVariant::Filled)
+            .rounded_full()
+            .disabled(disabled);
+
+        let mut stop = IconButton::new(self.child_id("stop"), "■")
+            .size(IconButtonSize::Md)
+            .disabled(disabled || self.state == PlaybackState::Stopped);
+        if let Some(handler) = self.on_stop.take() {
+            stop = stop.on_click(move |window, cx| handler.invoke(window, cx));
+        }
+
+        let mut controls = div()
+            .flex()
+            .items_center()
+            .gap_1()
+            .child(play_pause)
+            .child(stop);
+
+        if self.show_record {
+            let recording = self.recording;
+            let mut record = IconButton::with_child(
+                self.child_id("record"),
+                div()
+                    .size(px(10.0))
+                    .rounded_full()
+                    .bg(theme.record)
+                    .when(!recording, |d| d.opacity(0.6)),
+            )
+            .size(IconButtonSize::Md)
+            .selected(recording)
+            .disabled(disabled);
+            if let Some(handler) = self.on_record.take() {
+                record = record.on_click(move |window, cx| handler.call(!recording, window, cx));
+            }
+            controls = controls.child(record);
+        }
+
+        // Seek row: elapsed, track, total
+        let time_label = |text: String| {
+            div()
+                .min_w(px(44.0))
+                .text_xs()
+                .text_color(theme.time)
+                .child(text)
+        };
+        let elapsed = time_label(format_time(self.position)).text_right();
+        let total = time_label(format_time(self.duration));
+        let track = self.seek_track(theme);
+
+        // Loop toggle
+        let loop_enabled = self.loop_enabled;
+        let mut loop_button = IconButton::new(self.child_id("loop"), "⟲")
+            .size(IconButtonSize::Md)
+            .selected(loop_enabled)
+            .disabled(disabled);
+        if let Some(handler) = self.on_loop.take() {
+            loop_button =
+                loop_button.on_click(move |window, cx| handler.call(!loop_enabled, window, cx));
+        }
+
+        // Playback rate
+        let mut rates: Vec<SelectOption> = PLAYBACK_RATES
+            .iter()
+            .map(|&r| SelectOption::new(format!("{r}"), format_rate(r)))
+            .collect();
+        if !PLAYBACK_RATES.contains(&self.rate) {
+            rates.push(SelectOption::new(
+                format!("{}", self.rate),
+                format_rate(self.rate),
+            ));
+        }
+        let mut rate_select = Select::new(self.child_id("rate"))
+            .options(rates)
+            .selected(format!("{}", self.rate))
+            .size(SelectSize::Sm)
+            .is_open(self.rate_menu_open)
+            .disabled(disabled);
+        if let Some(handler) = self.on_rate.take() {
+            rate_select = rate_select.on_change(move |value, window, cx| {
+                if let Ok(rate) = value.parse::<f32>() {
+                    handler.call(rate, window, cx);
+                }
+            });
+        }
+        if let Some(handler) = self.on_rate_menu_toggle.take() {
+            rate_select =
+                rate_select.on_toggle(move |open, window, cx| handler.call(open, window, cx));
+        }
+
+        div()
+            .id(self.id)
+            .flex()
+            .items_center()
+            .gap_3()
+            .px_3()
+            .py_2()
+            .rounded_md()
+            .bg(theme.background)
+            .child(controls)
+            .child(
+                div()
+                    .flex_1()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(elapsed)
+                    .child(track)
+                    .child(total),
+            )
+            .child(loop_button)
+            .child(div().w(px(72.0)).child(rate_select))
+    }
+}
+
+impl RenderOnce for TransportBar {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| TransportBarTheme::from(&global_theme));
+        self.build(&theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(0.0), "0:00");
+        assert_eq!(format_time(65.9), "1:05");
+        assert_eq!(format_time(3725.0), "1:02:05");
+        assert_eq!(format_time(-1.0), "--:--");
+        assert_eq!(format_time(f64::NAN), "--:--");
+    }
+
+    #[test]
+    fn test_format_rate() {
+        assert_eq!(format_rate(1.0), "1×");
+        assert_eq!(format_rate(0.75), "0.75×");
+        assert_eq!(format_rate(1.5), "1.5×");
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(fraction(30.0, 60.0), 0.5);
+        assert_eq!(fraction(90.0, 60.0), 1.0);
+        assert_eq!(fraction(10.0, 0.0), 0.0);
+        assert_eq!(fraction(f64::NAN, 60.0), 0.0);
+    }
+}
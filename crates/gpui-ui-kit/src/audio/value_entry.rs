@@ -0,0 +1,220 @@
+//! Popover for typing the exact value of a knob
+//!
+//! Double-clicking a knob with value entry enabled opens a small [`Input`]
+//! under it, prefilled with the current value. Enter commits the typed
+//! value (clamped to the knob range, the unit suffix is optional), Escape
+//! or moving the focus away closes it unchanged. Text that is not a number
+//! keeps the popover open with an error.
+//!
+//! The open state lives in a per-thread registry keyed by the knob's
+//! element ID, like [`EditableLabel`](crate::editable_label::EditableLabel)'s,
+//! and is freed once the knob stops being rendered.
+
+use crate::input::{Input, InputSize};
+use crate::state_registry::ElementStateRegistry;
+use gpui::prelude::*;
+use gpui::{deferred, *};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// Popover state of the rendered knobs, kept alive by their listeners
+thread_local! {
+    static ENTRY_STATES: RefCell<ElementStateRegistry<EntryState>> =
+        RefCell::new(ElementStateRegistry::default());
+}
+
+/// Width of the popover input
+const ENTRY_WIDTH: f32 = 96.0;
+
+/// Popover state of one knob
+#[derive(Default)]
+struct EntryState {
+    open: bool,
+    /// Error of the last commit attempt
+    error: Option<SharedString>,
+    /// Closes the popover when the input loses focus
+    focus_out: Option<Subscription>,
+}
+
+/// Look of the popover frame
+#[derive(Debug, Clone)]
+pub(crate) struct EntryStyle {
+    pub(crate) background: Rgba,
+    pub(crate) border: Rgba,
+    pub(crate) shadow: Vec<BoxShadow>,
+}
+
+/// Value entry popover of one knob
+///
+/// Must be captured by a listener of the knob (e.g. the double-click that
+/// opens it) to keep its state between renders.
+#[derive(Clone)]
+pub(crate) struct ValueEntry {
+    id: ElementId,
+    state: Rc<RefCell<EntryState>>,
+    focus_handle: FocusHandle,
+}
+
+impl ValueEntry {
+    /// Get the popover of the knob `id`
+    pub(crate) fn new(id: &ElementId, cx: &mut App) -> Self {
+        let (focus_handle, state) = ENTRY_STATES.with_borrow_mut(|registry| {
+            (
+                registry.focus_handle(id, cx),
+                registry.state(id, EntryState::default),
+            )
+        });
+        Self {
+            id: id.clone(),
+            state,
+            focus_handle,
+        }
+    }
+
+    /// Whether the popover is shown
+    pub(crate) fn is_open(&self) -> bool {
+        self.state.borrow().open
+    }
+
+    /// Show the popover with the input focused, closing it when the focus
+    /// moves away
+    pub(crate) fn open(&self, window: &mut Window, cx: &mut App) {
+        // Weak, as the subscription is stored in the state itself
+        let weak: Weak<RefCell<EntryState>> = Rc::downgrade(&self.state);
+        let handle = self.focus_handle.clone();
+        let focus_out = window.on_focus_out(&self.focus_handle, cx, move |_event, window, _cx| {
+            // Refocused after rejected text
+            if handle.is_focused(window) {
+                return;
+            }
+            if let Some(state) = weak.upgrade() {
+                close(&state, window);
+            }
+        });
+
+        let mut current = self.state.borrow_mut();
+        current.open = true;
+        current.error = None;
+        current.focus_out = Some(focus_out);
+        drop(current);
+        self.focus_handle.focus(window, cx);
+        window.refresh();
+    }
+
+    /// The popover, while open, showing `text` and committing values parsed
+    /// with [`parse_entry`]
+    pub(crate) fn popover(
+        &self,
+        text: String,
+        unit: &str,
+        min: f64,
+        max: f64,
+        style: EntryStyle,
+        on_commit: impl Fn(f64, &mut Window, &mut App) + 'static,
+    ) -> Option<AnyElement> {
+        let (open, error) = {
+            let current = self.state.borrow();
+            (current.open, current.error.clone())
+        };
+        if !open {
+            return None;
+        }
+
+        let state = self.state.clone();
+        let focus_handle = self.focus_handle.clone();
+        let unit = unit.to_string();
+        let mut input = Input::new(ElementId::Name(format!("{}-entry", self.id).into()))
+            .value(text)
+            .size(InputSize::Sm)
+            .focus_handle(self.focus_handle.clone())
+            .on_edit_end(move |text, window, cx| {
+                let Some(text) = text else {
+                    close(&state, window);
+                    return;
+                };
+                match parse_entry(&text, &unit, min, max) {
+                    Some(value) => {
+                        close(&state, window);
+                        on_commit(value, window, cx);
+                    }
+                    None => {
+                        state.borrow_mut().error = Some("Enter a number".into());
+                        focus_handle.focus(window, cx);
+                        window.refresh();
+                    }
+                }
+            });
+        if let Some(error) = error {
+            input = input.error(error);
+        }
+
+        let frame = div()
+            .id(ElementId::Name(format!("{}-entry-popover", self.id).into()))
+            .absolute()
+            .top_full()
+            .left_0()
+            .mt_1()
+            .w(px(ENTRY_WIDTH))
+            .p_1()
+            .bg(style.background)
+            .border_1()
+            .border_color(style.border)
+            .rounded_md()
+            .shadow(style.shadow)
+            // Keep presses in the popover from reaching the knob
+            .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+            .on_scroll_wheel(|_, _, cx| cx.stop_propagation())
+            .child(input);
+        Some(deferred(frame).with_priority(1).into_any_element())
+    }
+}
+
+fn close(state: &RefCell<EntryState>, window: &mut Window) {
+    let mut current = state.borrow_mut();
+    current.open = false;
+    current.error = None;
+    current.focus_out = None;
+    drop(current);
+    window.refresh();
+}
+
+/// Parse typed text into a value within `min..=max`
+///
+/// The `unit` suffix is optional and `%` is read relative to the range,
+/// like the knobs display it.
+pub(crate) fn parse_entry(text: &str, unit: &str, min: f64, max: f64) -> Option<f64> {
+    let text = text.trim();
+    let number = if unit.is_empty() {
+        text
+    } else {
+        text.strip_suffix(unit).unwrap_or(text).trim_end()
+    };
+    let value = number
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())?;
+    let value = if unit == "%" {
+        min + value / 100.0 * (max - min)
+    } else {
+        value
+    };
+    Some(value.clamp(min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entry() {
+        assert_eq!(parse_entry(" 440 ", "Hz", 20.0, 20000.0), Some(440.0));
+        assert_eq!(parse_entry("1000 Hz", "Hz", 20.0, 20000.0), Some(1000.0));
+        assert_eq!(parse_entry("-3.5dB", "dB", -24.0, 12.0), Some(-3.5));
+        // Clamped to the range
+        assert_eq!(parse_entry("50", "dB", -24.0, 12.0), Some(12.0));
+        // Percent of the range
+        assert_eq!(parse_entry("25%", "%", 0.0, 2.0), Some(0.5));
+        assert_eq!(parse_entry("loud", "dB", -24.0, 12.0), None);
+        assert_eq!(parse_entry("NaN", "", 0.0, 1.0), None);
+    }
+}
@@ -16,16 +16,33 @@
 //! - Value display with units
 //! - Keyboard shortcut hints
 //! - Linear or logarithmic scale
+//! - Multiple thumbs (e.g. a low/high threshold pair): pressing the track
+//!   grabs the nearest thumb, which the scroll wheel and keyboard then move;
+//!   thumbs stop at their neighbors instead of crossing them
+//!
+//! Two sliders moving together, such as a stereo gain, are provided by
+//! [`VerticalSliderPair`](super::vertical_slider_pair::VerticalSliderPair).
 
 use super::interactions::{
     InteractionConfig, clear_drag_state, get_drag_state, handle_drag, handle_keyboard,
     handle_scroll, store_drag_state, value_tracker,
 };
 use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::focus::{FocusAudit, FocusRing, FocusRingExt};
 use crate::scale::Scale;
+use crate::state_registry::ElementStateRegistry;
 use crate::theme::ThemeExt;
 use gpui::prelude::*;
 use gpui::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+// Thumb moved by the scroll wheel and keyboard, per multi-thumb slider
+thread_local! {
+    static ACTIVE_THUMBS: RefCell<ElementStateRegistry<usize>> =
+        RefCell::new(ElementStateRegistry::default());
+}
 
 /// Scale type for vertical slider value mapping
 /// Re-exported from scale module for API consistency
@@ -396,6 +413,43 @@ fn calculate_ticks(min: f64, max: f64, scale: Scale, track_height: f32) -> Vec<T
     }
 }
 
+/// Keep thumb values within the range, each at or above the previous one
+fn ordered_thumbs(values: &[f64], min: f64, max: f64) -> Vec<f64> {
+    let mut floor = min;
+    values
+        .iter()
+        .map(|&value| {
+            floor = value.clamp(floor, max);
+            floor
+        })
+        .collect()
+}
+
+/// Clamp a new value of thumb `index` between its neighbors
+fn clamp_thumb(values: &[f64], index: usize, value: f64) -> f64 {
+    let low = index
+        .checked_sub(1)
+        .map_or(f64::NEG_INFINITY, |below| values[below]);
+    let high = values.get(index + 1).copied().unwrap_or(f64::INFINITY);
+    value.clamp(low, high)
+}
+
+/// Index of the thumb nearest to a normalized track position
+///
+/// Of stacked thumbs, a press above grabs the top one and a press below
+/// the bottom one, so that they can be pulled apart.
+fn nearest_thumb(positions: &[f64], position: f64) -> usize {
+    let mut nearest = 0;
+    for (index, &thumb) in positions.iter().enumerate().skip(1) {
+        let distance = (thumb - position).abs();
+        let best = (positions[nearest] - position).abs();
+        if distance < best || (distance == best && position > thumb) {
+            nearest = index;
+        }
+    }
+    nearest
+}
+
 /// A vertical slider component for audio plugin parameters
 #[derive(IntoElement)]
 pub struct VerticalSlider {
@@ -414,11 +468,14 @@ pub struct VerticalSlider {
     disabled: bool,
     /// Optional peak marker value (for audio peak indicators)
     peak: Option<f64>,
+    /// Values of the thumbs, when there are several
+    thumbs: Vec<f64>,
     theme: Option<VerticalSliderTheme>,
-    on_change: Option<Box<dyn Fn(f64, &mut Window, &mut App) + 'static>>,
-    on_drag_start: Option<Box<dyn Fn(f32, f64, &mut Window, &mut App) + 'static>>,
-    on_select: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
-    on_reset: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<f64>>,
+    on_thumb_change: Option<Callback<(usize, f64)>>,
+    on_drag_start: Option<Callback<(f32, f64)>>,
+    on_select: Option<Callback>,
+    on_reset: Option<Callback>,
     focus_handle: Option<FocusHandle>,
 }
 
@@ -440,8 +497,10 @@ impl VerticalSlider {
             selected: false,
             disabled: false,
             peak: None,
+            thumbs: Vec::new(),
             theme: None,
             on_change: None,
+            on_thumb_change: None,
             on_drag_start: None,
             on_select: None,
             on_reset: None,
@@ -543,6 +602,16 @@ impl VerticalSlider {
         self
     }
 
+    /// Show several thumbs instead of one, e.g. a low/high threshold pair
+    ///
+    /// Values are in ascending order. With fewer than two values the slider
+    /// keeps its single thumb set by [`value`](Self::value). Moves are
+    /// reported by [`on_thumb_change`](Self::on_thumb_change).
+    pub fn thumbs(mut self, values: impl IntoIterator<Item = f64>) -> Self {
+        self.thumbs = values.into_iter().collect();
+        self
+    }
+
     /// Set theme colors
     pub fn theme(mut self, theme: VerticalSliderTheme) -> Self {
         self.theme = Some(theme);
@@ -551,7 +620,19 @@ impl VerticalSlider {
 
     /// Set value change handler (called on scroll wheel)
     pub fn on_change(mut self, handler: impl Fn(f64, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(handler));
+        self
+    }
+
+    /// Set thumb change handler for multi-thumb sliders, called with the
+    /// index of the moved thumb and its new value
+    pub fn on_thumb_change(
+        mut self,
+        handler: impl Fn(usize, f64, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_thumb_change = Some(Callback::new(
+            move |(index, value): (usize, f64), window, cx| handler(index, value, window, cx),
+        ));
         self
     }
 
@@ -560,19 +641,21 @@ impl VerticalSlider {
         mut self,
         handler: impl Fn(f32, f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_drag_start = Some(Box::new(handler));
+        self.on_drag_start = Some(Callback::new(
+            move |(position, value): (f32, f64), window, cx| handler(position, value, window, cx),
+        ));
         self
     }
 
     /// Set select handler (called on click to select this parameter)
     pub fn on_select(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_select = Some(Box::new(handler));
+        self.on_select = Some(Callback::from_fn(handler));
         self
     }
 
     /// Set reset handler (called on double-click)
     pub fn on_reset(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_reset = Some(Box::new(handler));
+        self.on_reset = Some(Callback::from_fn(handler));
         self
     }
 
@@ -608,17 +691,17 @@ impl VerticalSlider {
         }
     }
 
-    /// Format the value display
-    fn format_value(&self) -> String {
+    /// Format a value for display
+    fn format_value(&self, value: f64) -> String {
         let unit = self.unit.to_string();
         if unit == ":1" {
-            format!("{:.1}{}", self.value, unit)
+            format!("{:.1}{}", value, unit)
         } else if unit == "%" {
-            format!("{:.0}{}", self.value * 100.0, unit)
+            format!("{:.0}{}", value * 100.0, unit)
         } else if unit.is_empty() {
-            format!("{:.1}", self.value)
+            format!("{:.1}", value)
         } else {
-            format!("{:.1} {}", self.value, unit)
+            format!("{:.1} {}", value, unit)
         }
     }
 }
@@ -645,8 +728,36 @@ impl RenderOnce for VerticalSlider {
             self.value_to_normalized(clamped_peak) as f32
         });
 
+        // Thumb values and the thumb the scroll wheel and keyboard move,
+        // when there are several
+        let thumbs = (self.thumbs.len() >= 2).then(|| {
+            let values = ordered_thumbs(&self.thumbs, self.min, self.max);
+            let last = values.len() - 1;
+            let active =
+                ACTIVE_THUMBS.with_borrow_mut(|registry| registry.state(&self.id, || last));
+            let index = (*active.borrow()).min(last);
+            *active.borrow_mut() = index;
+            (Rc::new(RefCell::new(values)), active)
+        });
+        let thumb_normalized: Vec<f32> = match &thumbs {
+            Some((values, _)) => values
+                .borrow()
+                .iter()
+                .map(|&thumb| self.value_to_normalized(thumb) as f32)
+                .collect(),
+            None => vec![normalized],
+        };
+
         let formatted_label = self.format_label();
-        let value_str = self.format_value();
+        let value_str = match &thumbs {
+            Some((values, _)) => values
+                .borrow()
+                .iter()
+                .map(|&thumb| self.format_value(thumb))
+                .collect::<Vec<_>>()
+                .join(" – "),
+            None => self.format_value(self.value),
+        };
 
         let track_width = self.size.track_width();
         let track_height = self
@@ -698,13 +809,17 @@ impl RenderOnce for VerticalSlider {
             theme.text_muted
         };
 
-        // Capture values for closures
-        let value = self.value;
+        // Capture values for closures, the active thumb's for several
+        let value = match &thumbs {
+            Some((values, active)) => values.borrow()[*active.borrow()],
+            None => self.value,
+        };
         let min = self.min;
         let max = self.max;
         let scale = self.scale;
         let element_id = self.id.clone(); // Clone for use in track ID
 
+        let audit_label = self.id.to_string();
         let mut container = div()
             .id(self.id)
             .flex()
@@ -724,6 +839,15 @@ impl RenderOnce for VerticalSlider {
             container = container.track_focus(focus_handle).focusable();
         }
 
+        container = container
+            .relative()
+            .focus_ring(FocusRing::from_theme(&global_theme))
+            .children(FocusAudit::marker(
+                audit_label,
+                self.focus_handle.as_ref(),
+                cx,
+            ));
+
         // Add shadow when selected
         if selected {
             container = container.shadow_md();
@@ -741,31 +865,37 @@ impl RenderOnce for VerticalSlider {
             container = container.cursor_ns_resize();
         }
 
-        // Wrap handlers in Rc for sharing between container and track handlers
-        // These need to be created before the if block so they can be used for track handlers later
-        let on_change_rc: Option<std::rc::Rc<Box<dyn Fn(f64, &mut Window, &mut App) + 'static>>> =
-            if !disabled {
-                self.on_change.map(|h| std::rc::Rc::new(h))
-            } else {
-                None
-            };
-        let on_reset_rc: Option<std::rc::Rc<Box<dyn Fn(&mut Window, &mut App) + 'static>>> =
-            if !disabled {
-                self.on_reset.map(|h| std::rc::Rc::new(h))
-            } else {
-                None
-            };
-        let on_select_rc: Option<std::rc::Rc<Box<dyn Fn(&mut Window, &mut App) + 'static>>> =
-            if !disabled {
-                self.on_select.map(|h| std::rc::Rc::new(h))
-            } else {
-                None
-            };
-
         // Shared current value tracker and interaction config
         let current_value = value_tracker(value);
         let interaction_config = InteractionConfig::vertical(min, max, scale, track_height);
 
+        // Shared between container and track handlers, so they are created
+        // before the if block
+        let on_change_rc: Option<Callback<f64>> = match (&thumbs, self.on_thumb_change) {
+            _ if disabled => None,
+            // Several thumbs: move the active one, up to its neighbors
+            (Some((values, active)), Some(handler)) => {
+                let values = values.clone();
+                let active = active.clone();
+                let current_value_thumb = current_value.clone();
+                let move_thumb = Callback::new(move |new_value: f64, window, cx| {
+                    let index = *active.borrow();
+                    let new_value = {
+                        let mut values = values.borrow_mut();
+                        values[index] = clamp_thumb(&values, index, new_value);
+                        values[index]
+                    };
+                    current_value_thumb.set(new_value);
+                    handler.call((index, new_value), window, cx);
+                });
+                Some(move_thumb)
+            }
+            (Some(_), None) => None,
+            (None, _) => self.on_change,
+        };
+        let on_reset_rc = if !disabled { self.on_reset } else { None };
+        let on_select_rc = if !disabled { self.on_select } else { None };
+
         // Event handlers for container
         if !disabled {
             // Mouse down on container - focus, select, and external drag start
@@ -781,11 +911,11 @@ impl RenderOnce for VerticalSlider {
                 }
 
                 if let Some(ref handler) = on_select_container {
-                    handler(window, cx);
+                    handler.invoke(window, cx);
                 }
                 if let Some(ref handler) = on_drag_start {
                     let val = current_value_container.get();
-                    handler(event.position.y.into(), val, window, cx);
+                    handler.call((event.position.y.into(), val), window, cx);
                 }
             });
 
@@ -794,7 +924,7 @@ impl RenderOnce for VerticalSlider {
                 let reset_handler = reset_rc.clone();
                 container = container.on_click(move |event, window, cx| {
                     if event.click_count() == 2 {
-                        reset_handler(window, cx);
+                        reset_handler.invoke(window, cx);
                     }
                 });
             }
@@ -811,7 +941,7 @@ impl RenderOnce for VerticalSlider {
                         handle_scroll(&event.delta, &event.modifiers, val, &config_scroll)
                     {
                         current_value_scroll.set(new_value);
-                        handler_scroll(new_value, window, cx);
+                        handler_scroll.call(new_value, window, cx);
                     }
                 });
             }
@@ -841,7 +971,7 @@ impl RenderOnce for VerticalSlider {
                     // Escape resets to default
                     if key == "escape" {
                         if let Some(ref reset_handler) = reset_key {
-                            reset_handler(window, cx);
+                            reset_handler.invoke(window, cx);
                         }
                         return;
                     }
@@ -853,7 +983,7 @@ impl RenderOnce for VerticalSlider {
                             handle_keyboard(key, &event.keystroke.modifiers, val, &config_key)
                         {
                             current_value_key.set(new_value);
-                            handler(new_value, window, cx);
+                            handler.call(new_value, window, cx);
                         }
                     }
                 });
@@ -888,7 +1018,8 @@ impl RenderOnce for VerticalSlider {
         );
 
         // Track ID for click-to-position handling
-        let track_id: ElementId = ElementId::Name(SharedString::from(format!("{}-track", element_id)));
+        let track_id: ElementId =
+            ElementId::Name(SharedString::from(format!("{}-track", element_id)));
 
         // Track with fill and thumb
         let mut track = div()
@@ -907,30 +1038,52 @@ impl RenderOnce for VerticalSlider {
             track = track.shadow_sm();
         }
 
-        // Filled portion (from bottom)
+        // Filled portion, from bottom or between the outer thumbs
+        let fill_bottom = if thumbs.is_some() {
+            thumb_normalized[0]
+        } else {
+            0.0
+        };
+        let fill_top = thumb_normalized.last().copied().unwrap_or(normalized);
         track = track.child(
             div()
                 .absolute()
-                .bottom_0()
+                .bottom(relative(fill_bottom))
                 .left_0()
                 .right_0()
-                .h(relative(normalized))
+                .h(relative(fill_top - fill_bottom))
                 .bg(theme.accent)
-                .rounded_b_md(),
+                .when(thumbs.is_none(), |d| d.rounded_b_md()),
         );
 
-        // Thumb indicator
-        track = track.child(
-            div()
+        // Thumb indicators
+        for &position in &thumb_normalized {
+            track = track.child(
+                div()
+                    .absolute()
+                    .left_0()
+                    .right_0()
+                    .bottom(relative(position))
+                    .h(px(thumb_height))
+                    .bg(thumb_color)
+                    .rounded_sm()
+                    .when(selected, |d| d.shadow_sm()),
+            );
+        }
+
+        // Track bounds, to find the thumb nearest to a press
+        let track_bounds = Rc::new(Cell::new(None::<Bounds<Pixels>>));
+        if thumbs.is_some() {
+            let track_bounds = track_bounds.clone();
+            track = track.child(
+                canvas(
+                    move |bounds, _, _| track_bounds.set(Some(bounds)),
+                    |_, _, _, _| {},
+                )
                 .absolute()
-                .left_0()
-                .right_0()
-                .bottom(relative(normalized))
-                .h(px(thumb_height))
-                .bg(thumb_color)
-                .rounded_sm()
-                .when(selected, |d| d.shadow_sm()),
-        );
+                .inset_0(),
+            );
+        }
 
         // Peak marker (optional) - thick horizontal line at peak position
         if let Some(peak_pos) = peak_normalized {
@@ -959,9 +1112,27 @@ impl RenderOnce for VerticalSlider {
             let current_value_at_click = current_value.clone();
             let has_change_handler = on_change_rc.is_some();
             let focus_handle_track = self.focus_handle.clone();
+            let thumbs_track = thumbs.clone();
             track = track.on_mouse_down(MouseButton::Left, move |event, window, cx| {
                 cx.stop_propagation();
 
+                // Grab the thumb nearest to the press
+                if let Some((values, active)) = &thumbs_track
+                    && let Some(bounds) = track_bounds.get()
+                {
+                    let offset: f32 = (bounds.bottom() - event.position.y).into();
+                    let height: f32 = bounds.size.height.into();
+                    let position = (offset / height) as f64;
+                    let values = values.borrow();
+                    let positions: Vec<f64> = values
+                        .iter()
+                        .map(|&thumb| scale.value_to_normalized(thumb, min, max))
+                        .collect();
+                    let index = nearest_thumb(&positions, position);
+                    *active.borrow_mut() = index;
+                    current_value_at_click.set(values[index]);
+                }
+
                 // Focus for keyboard navigation (focus follows click)
                 if let Some(ref fh) = focus_handle_track {
                     fh.focus(window, cx);
@@ -969,7 +1140,7 @@ impl RenderOnce for VerticalSlider {
 
                 // Select the slider (if handler provided)
                 if let Some(ref handler) = on_select_track {
-                    handler(window, cx);
+                    handler.invoke(window, cx);
                 }
 
                 // Store drag state only if we have a change handler
@@ -984,7 +1155,7 @@ impl RenderOnce for VerticalSlider {
                 let reset_handler = reset_rc.clone();
                 track = track.on_click(move |event, window, cx| {
                     if event.click_count() == 2 {
-                        reset_handler(window, cx);
+                        reset_handler.invoke(window, cx);
                     }
                 });
             }
@@ -1002,7 +1173,7 @@ impl RenderOnce for VerticalSlider {
                         let current_pos: f32 = event.position.y.into();
                         if let Some(new_value) = handle_drag(current_pos, &state, &config_drag) {
                             current_value_drag.set(new_value);
-                            handler_drag(new_value, window, cx);
+                            handler_drag.call(new_value, window, cx);
                         }
                     }
                 });
@@ -1023,7 +1194,7 @@ impl RenderOnce for VerticalSlider {
                         handle_scroll(&event.delta, &event.modifiers, val, &config_track_scroll)
                     {
                         current_value_track_scroll.set(new_value);
-                        handler_scroll_track(new_value, window, cx);
+                        handler_scroll_track.call(new_value, window, cx);
                     }
                 });
             }
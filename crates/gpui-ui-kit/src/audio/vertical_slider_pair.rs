@@ -0,0 +1,314 @@
+//! Linked pair of vertical sliders, e.g. a stereo gain
+//!
+//! Two [`VerticalSlider`]s side by side with a link toggle between them:
+//! - Linked: moving either slider moves the other by the same amount,
+//!   keeping their offset. Near a bound both stop together, so the offset
+//!   is never lost. On a logarithmic scale the ratio is kept instead.
+//! - Unlinked: each slider moves on its own
+//!
+//! Every interaction of a single slider (drag, scroll wheel, keyboard)
+//! works on either side. The app owns both values and the link state.
+//!
+//! ```ignore
+//! VerticalSliderPair::new("master")
+//!     .values(self.gain_l, self.gain_r)
+//!     .min(-60.0)
+//!     .max(12.0)
+//!     .unit("dB")
+//!     .linked(self.linked)
+//!     .on_change(cx.listener(|this, &(left, right), _, cx| {
+//!         (this.gain_l, this.gain_r) = (left, right);
+//!         cx.notify();
+//!     }))
+//!     .on_link_change(cx.listener(|this, linked: &bool, _, cx| {
+//!         this.linked = *linked;
+//!         cx.notify();
+//!     }))
+//! ```
+
+use super::vertical_slider::{VerticalSlider, VerticalSliderSize, VerticalSliderTheme};
+use crate::callback::Callback;
+use crate::icon_button::{IconButton, IconButtonSize};
+use crate::scale::Scale;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Values of a linked pair after one side moved to `new_value`
+///
+/// `side` is 0 for the left slider, 1 for the right one. The other side
+/// moves by the same distance along the scale, limited so that both stay
+/// within `min..=max`.
+pub fn linked_values(
+    values: (f64, f64),
+    side: usize,
+    new_value: f64,
+    min: f64,
+    max: f64,
+    scale: Scale,
+) -> (f64, f64) {
+    let to_position = |value: f64| scale.value_to_normalized(value.clamp(min, max), min, max);
+    let (left, right) = (to_position(values.0), to_position(values.1));
+    let moved = if side == 0 { left } else { right };
+    let (low, high) = (left.min(right), left.max(right));
+    let delta = (to_position(new_value) - moved).clamp(-low, 1.0 - high);
+    (
+        scale.normalized_to_value(left + delta, min, max),
+        scale.normalized_to_value(right + delta, min, max),
+    )
+}
+
+/// Two vertical sliders that move together unless unlinked
+#[derive(IntoElement)]
+pub struct VerticalSliderPair {
+    id: ElementId,
+    values: (f64, f64),
+    min: f64,
+    max: f64,
+    unit: SharedString,
+    labels: (SharedString, SharedString),
+    size: VerticalSliderSize,
+    scale: Scale,
+    custom_height: Option<f32>,
+    show_ticks: bool,
+    linked: bool,
+    disabled: bool,
+    theme: Option<VerticalSliderTheme>,
+    on_change: Option<Callback<(f64, f64)>>,
+    on_link_change: Option<Callback<bool>>,
+    on_reset: Option<Callback>,
+}
+
+impl VerticalSliderPair {
+    /// Create a new linked pair with the given ID
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            values: (0.0, 0.0),
+            min: 0.0,
+            max: 100.0,
+            unit: "".into(),
+            labels: ("L".into(), "R".into()),
+            size: VerticalSliderSize::default(),
+            scale: Scale::default(),
+            custom_height: None,
+            show_ticks: false,
+            linked: true,
+            disabled: false,
+            theme: None,
+            on_change: None,
+            on_link_change: None,
+            on_reset: None,
+        }
+    }
+
+    /// Set the left and right values
+    pub fn values(mut self, left: f64, right: f64) -> Self {
+        self.values = (left, right);
+        self
+    }
+
+    /// Set the minimum value of both sliders
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum value of both sliders
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the unit label (e.g., "dB", "%")
+    pub fn unit(mut self, unit: impl Into<SharedString>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Set the labels of the left and right sliders (default: "L" and "R")
+    pub fn labels(mut self, left: impl Into<SharedString>, right: impl Into<SharedString>) -> Self {
+        self.labels = (left.into(), right.into());
+        self
+    }
+
+    /// Set the size of both sliders
+    pub fn size(mut self, size: VerticalSliderSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the value scale type (linear or logarithmic)
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set a custom track height in pixels (overrides size preset)
+    pub fn height(mut self, height: f32) -> Self {
+        self.custom_height = Some(height);
+        self
+    }
+
+    /// Enable tick marks along both tracks
+    pub fn with_ticks(mut self) -> Self {
+        self.show_ticks = true;
+        self
+    }
+
+    /// Set whether the sliders move together (default: true)
+    pub fn linked(mut self, linked: bool) -> Self {
+        self.linked = linked;
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set theme colors of both sliders
+    pub fn theme(mut self, theme: VerticalSliderTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called with the new `(left, right)` values when either slider moves
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&(f64, f64), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Callback::new(move |values: (f64, f64), window, cx| {
+            handler(&values, window, cx)
+        }));
+        self
+    }
+
+    /// Called with the new link state when the link toggle is clicked
+    ///
+    /// The toggle is only shown when this handler is set.
+    pub fn on_link_change(
+        mut self,
+        handler: impl Fn(&bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_link_change = Some(Callback::new(move |linked: bool, window, cx| {
+            handler(&linked, window, cx)
+        }));
+        self
+    }
+
+    /// Set reset handler (called on double-click or Escape on either slider)
+    pub fn on_reset(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_reset = Some(Callback::from_fn(handler));
+        self
+    }
+
+    fn child_id(&self, suffix: &str) -> ElementId {
+        ElementId::Name(format!("{}-{}", self.id, suffix).into())
+    }
+}
+
+impl RenderOnce for VerticalSliderPair {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let (min, max, scale) = (self.min, self.max, self.scale);
+        let (left, right) = (self.values.0.clamp(min, max), self.values.1.clamp(min, max));
+        let linked = self.linked;
+        let on_change = self.on_change;
+        let on_reset = self.on_reset;
+
+        let slider = |side: usize, id: ElementId, label: SharedString, value: f64| {
+            let mut slider = VerticalSlider::new(id)
+                .value(value)
+                .min(min)
+                .max(max)
+                .unit(self.unit.clone())
+                .label(label)
+                .size(self.size)
+                .scale(scale)
+                .disabled(self.disabled);
+            if let Some(height) = self.custom_height {
+                slider = slider.height(height);
+            }
+            if self.show_ticks {
+                slider = slider.with_ticks();
+            }
+            if let Some(theme) = self.theme.clone() {
+                slider = slider.theme(theme);
+            }
+            if let Some(handler) = on_change.clone() {
+                slider = slider.on_change(move |new_value, window, cx| {
+                    let values = if linked {
+                        linked_values((left, right), side, new_value, min, max, scale)
+                    } else if side == 0 {
+                        (new_value, right)
+                    } else {
+                        (left, new_value)
+                    };
+                    handler.call(values, window, cx);
+                });
+            }
+            if let Some(handler) = on_reset.clone() {
+                slider = slider.on_reset(move |window, cx| handler.invoke(window, cx));
+            }
+            slider
+        };
+        let left_slider = slider(0, self.child_id("left"), self.labels.0.clone(), left);
+        let right_slider = slider(1, self.child_id("right"), self.labels.1.clone(), right);
+
+        let link_id = self.child_id("link");
+        let link_toggle = self.on_link_change.map(|handler| {
+            IconButton::new(link_id, "🔗")
+                .size(IconButtonSize::Sm)
+                .selected(linked)
+                .disabled(self.disabled)
+                .on_click(move |window, cx| handler.call(!linked, window, cx))
+        });
+
+        div()
+            .id(self.id)
+            .flex()
+            .items_center()
+            .gap_1()
+            .child(left_slider)
+            .children(link_toggle)
+            .child(right_slider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linked_values_keep_offset() {
+        // Left at -6 dB, right at -3 dB: raising the left raises the right
+        let values = linked_values((-6.0, -3.0), 0, -2.0, -60.0, 12.0, Scale::Linear);
+        assert_eq!(values, (-2.0, 1.0));
+        // Moving the right side works the same
+        let values = linked_values((-6.0, -3.0), 1, -13.0, -60.0, 12.0, Scale::Linear);
+        assert_eq!(values, (-16.0, -13.0));
+    }
+
+    #[test]
+    fn test_linked_values_stop_together_at_bounds() {
+        // The right side reaches the maximum first and holds the left back
+        let values = linked_values((0.0, 10.0), 0, 8.0, -60.0, 12.0, Scale::Linear);
+        assert_eq!(values, (2.0, 12.0));
+        let values = linked_values((-50.0, -40.0), 1, -100.0, -60.0, 12.0, Scale::Linear);
+        assert_eq!(values, (-60.0, -50.0));
+    }
+
+    #[test]
+    fn test_linked_values_keep_ratio_on_log_scale() {
+        let (left, right) = linked_values(
+            (100.0, 200.0),
+            0,
+            1000.0,
+            20.0,
+            20_000.0,
+            Scale::Logarithmic,
+        );
+        assert!((left - 1000.0).abs() < 1e-6, "{left}");
+        assert!((right - 2000.0).abs() < 1e-6, "{right}");
+    }
+}
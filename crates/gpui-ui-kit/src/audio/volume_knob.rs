@@ -1,10 +1,14 @@
 //! VolumeKnob - A circular volume knob with path-painted fill indicator
 //!
 //! A visual volume control with:
-//! - Path-painted circular fill that rises from bottom
-//! - Drag support with vertical mouse movement
+//! - Path-painted circular fill that rises from bottom, or spreads from
+//!   the middle with `bipolar(true)`
+//! - Drag to scrub: up or right increases (Shift fine, Ctrl/Cmd coarse)
 //! - Scroll wheel adjustment (Shift for fine control: 0.5% vs 5%)
-//! - Double-click to toggle mute
+//! - Double-click to toggle mute, or to type an exact value in a popover
+//!   with `value_entry(true)`
+//! - Detents that catch the value while dragging, marked by notches that
+//!   light up when the value rests on them
 //! - Keyboard support (requires focus - click to focus):
 //!   - Arrow Up/Right: increase volume (5%)
 //!   - Arrow Down/Left: decrease volume (5%)
@@ -15,10 +19,16 @@
 //! - Mute state support
 //! - Customizable colors and theme support
 
-use super::interactions::{InteractionConfig, handle_keyboard, handle_scroll, value_tracker};
+use super::interactions::{
+    InteractionConfig, ScrubMapping, end_scrub, handle_keyboard, handle_scroll, on_detent,
+    snap_to_detent, start_scrub, update_scrub, value_tracker,
+};
+use super::value_entry::{EntryStyle, ValueEntry};
 use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::focus::{FocusAudit, FocusRing, FocusRingExt};
 use crate::scale::Scale;
-use crate::theme::ThemeExt;
+use crate::theme::{Elevation, ThemeExt};
 use gpui::*;
 use std::f32::consts::PI;
 
@@ -46,6 +56,10 @@ struct VolumeKnobFillElement {
     bg_color: Rgba,
     fill_color: Rgba,
     ring_color: Rgba,
+    /// Level the fill spreads from, when bipolar
+    origin: Option<f32>,
+    /// Detent levels, and whether the value rests on them
+    detents: Vec<(f32, bool)>,
 }
 
 impl VolumeKnobFillElement {
@@ -56,6 +70,41 @@ impl VolumeKnobFillElement {
             bg_color,
             fill_color,
             ring_color,
+            origin: None,
+            detents: Vec::new(),
+        }
+    }
+
+    /// Fill the part of the circle between two levels (0 = bottom, 1 = top)
+    fn paint_band(&self, bounds: Bounds<Pixels>, from: f32, to: f32, window: &mut Window) {
+        let radius = self.size.to_f64() as f32 / 2.0;
+        let (low, high) = (from.min(to), from.max(to));
+        // Edge point of the circle at a level, on the given side
+        let edge = |level: f32, side: f32| {
+            let y = radius + radius - level * 2.0 * radius;
+            let dx = (radius * radius - (y - radius).powi(2)).max(0.0).sqrt();
+            point(
+                bounds.origin.x + px(radius + side * dx),
+                bounds.origin.y + px(y),
+            )
+        };
+
+        // Up the right side, then down the left side
+        let segments = 32;
+        let mut builder = PathBuilder::fill();
+        builder.move_to(edge(low, 1.0));
+        for i in 1..=segments {
+            let t = i as f32 / segments as f32;
+            builder.line_to(edge(low + (high - low) * t, 1.0));
+        }
+        for i in 0..=segments {
+            let t = i as f32 / segments as f32;
+            builder.line_to(edge(high - (high - low) * t, -1.0));
+        }
+        builder.line_to(edge(low, 1.0));
+
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, self.fill_color);
         }
     }
 }
@@ -146,8 +195,13 @@ impl Element for VolumeKnobFillElement {
             border_style: BorderStyle::default(),
         });
 
-        // Draw fill - a circular segment from bottom
-        if self.value > 0.001 {
+        // Draw fill - a band from the origin when bipolar, otherwise a
+        // circular segment from bottom
+        if let Some(origin) = self.origin {
+            if (self.value - origin).abs() > 0.001 {
+                self.paint_band(bounds, origin, self.value, window);
+            }
+        } else if self.value > 0.001 {
             // Calculate geometry in f32
             let center_x = radius;
             let center_y = radius;
@@ -244,6 +298,31 @@ impl Element for VolumeKnobFillElement {
             border_color: ring_with_opacity.into(),
             border_style: BorderStyle::default(),
         });
+
+        // Detent notches on both sides of the ring, lit while the value
+        // rests on them
+        for &(level, lit) in &self.detents {
+            let (height, alpha) = if lit { (3.0, 1.0) } else { (2.0, 0.5) };
+            let y = radius + radius - level * 2.0 * radius;
+            let dx = (radius * radius - (y - radius).powi(2)).max(0.0).sqrt();
+            let notch_color = Rgba {
+                a: self.ring_color.a * alpha,
+                ..self.ring_color
+            };
+            for x in [radius - dx, radius + dx - 5.0] {
+                window.paint_quad(PaintQuad {
+                    bounds: Bounds {
+                        origin: point(origin_x + px(x), origin_y + px(y - height / 2.0)),
+                        size: size(px(5.0), px(height)),
+                    },
+                    corner_radii: Corners::all(px(1.0)),
+                    background: notch_color.into(),
+                    border_widths: Edges::default(),
+                    border_color: transparent.into(),
+                    border_style: BorderStyle::default(),
+                });
+            }
+        }
     }
 }
 
@@ -255,6 +334,9 @@ pub struct VolumeKnob {
     label: SharedString,
     size: Pixels,
     muted: bool,
+    detents: Vec<f32>,
+    bipolar: bool,
+    value_entry: bool,
     /// Optional theme (uses global theme if not set)
     theme: Option<VolumeKnobTheme>,
     /// Override: accent color
@@ -265,8 +347,8 @@ pub struct VolumeKnob {
     bg_color: Option<Rgba>,
     /// Override: text color
     text_color: Option<Rgba>,
-    on_change: Option<Box<dyn Fn(f32, &mut Window, &mut App) + 'static>>,
-    on_mute_toggle: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<f32>>,
+    on_mute_toggle: Option<Callback<bool>>,
     focus_handle: Option<FocusHandle>,
 }
 
@@ -281,6 +363,9 @@ impl VolumeKnob {
             label: "".into(),
             size: px(40.0),
             muted: false,
+            detents: Vec::new(),
+            bipolar: false,
+            value_entry: false,
             theme: None,
             accent_color: None,
             muted_color: None,
@@ -323,6 +408,32 @@ impl VolumeKnob {
         self
     }
 
+    /// Set detent levels (0.0 to 1.0, e.g. unity gain)
+    ///
+    /// Dragging near a detent snaps to it, unless Shift is held for fine
+    /// control. Each detent is marked by notches on the ring that light up
+    /// while the value rests on it.
+    pub fn detents(mut self, detents: impl IntoIterator<Item = f32>) -> Self {
+        self.detents = detents.into_iter().collect();
+        self
+    }
+
+    /// Fill from the middle of the knob instead of the bottom, for pan
+    /// and gain
+    pub fn bipolar(mut self, bipolar: bool) -> Self {
+        self.bipolar = bipolar;
+        self
+    }
+
+    /// Open a popover to type an exact value, in percent, on double-click
+    ///
+    /// Replaces mute toggling on double-click (the M key still toggles
+    /// it). Requires `on_change`.
+    pub fn value_entry(mut self, value_entry: bool) -> Self {
+        self.value_entry = value_entry;
+        self
+    }
+
     /// Override accent color (ring and fill when active)
     pub fn accent_color(mut self, color: impl Into<Rgba>) -> Self {
         self.accent_color = Some(color.into());
@@ -349,16 +460,17 @@ impl VolumeKnob {
 
     /// Set value change handler (called on scroll wheel)
     pub fn on_change(mut self, handler: impl Fn(f32, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(handler));
         self
     }
 
-    /// Set mute toggle handler (called on double-click)
+    /// Set mute toggle handler (called on double-click, unless value entry
+    /// is enabled, and on the M key)
     pub fn on_mute_toggle(
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_mute_toggle = Some(Box::new(handler));
+        self.on_mute_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -416,12 +528,28 @@ impl RenderOnce for VolumeKnob {
         // Capture values for closures
         let current_muted = self.muted;
         let knob_size_f32 = self.size.to_f64() as f32;
+        let detents: std::rc::Rc<[f64]> = self.detents.iter().map(|&d| d as f64).collect();
+        let detent_marks = detents
+            .iter()
+            .filter(|d| (0.0..=1.0).contains(*d))
+            .map(|&d| {
+                (
+                    d as f32,
+                    !self.muted && on_detent(self.value as f64, &[d], 0.0, 1.0),
+                )
+            })
+            .collect();
+        let entry_text = format!("{:.0}", self.value.clamp(0.0, 1.0) * 100.0);
 
         // Shared current value tracker and interaction config (with media keys enabled)
         let current_value = value_tracker(self.value as f64);
         let interaction_config =
             InteractionConfig::rotational(0.0, 1.0, Scale::Linear, knob_size_f32).with_media_keys();
 
+        let audit_label = self.id.to_string();
+        let scrub_key = format!("{:?}", self.id);
+        let value_entry =
+            (self.value_entry && self.on_change.is_some()).then(|| ValueEntry::new(&self.id, cx));
         let mut container = div()
             .id(self.id)
             .relative()
@@ -433,9 +561,16 @@ impl RenderOnce for VolumeKnob {
             container = container.track_focus(focus_handle).focusable();
         }
 
-        // Convert handlers to Rc for sharing between closures
-        let on_change_rc = self.on_change.map(std::rc::Rc::new);
-        let on_mute_rc = self.on_mute_toggle.map(std::rc::Rc::new);
+        container = container
+            .focus_ring(FocusRing::from_theme(&global_theme))
+            .children(FocusAudit::marker(
+                audit_label,
+                self.focus_handle.as_ref(),
+                cx,
+            ));
+
+        let on_change_rc = self.on_change;
+        let on_mute_rc = self.on_mute_toggle;
 
         // Focus handling
         if let Some(ref focus_handle) = self.focus_handle {
@@ -458,7 +593,7 @@ impl RenderOnce for VolumeKnob {
                     handle_scroll(&event.delta, &event.modifiers, val, &config_scroll)
                 {
                     current_value_scroll.set(new_value);
-                    scroll_handler(new_value as f32, window, cx);
+                    scroll_handler.call(new_value as f32, window, cx);
                 }
             });
         }
@@ -466,32 +601,70 @@ impl RenderOnce for VolumeKnob {
         // Drag support and hover focus
         {
             let drag_handler = on_change_rc.clone();
-            let knob_size_f32 = self.size.to_f64() as f32;
             let focus_handle_hover = self.focus_handle.clone();
+            let entry_hover = value_entry.clone();
+            let detents_drag = detents.clone();
+            // Dragging the knob size covers the whole range
+            let mapping = ScrubMapping::Range {
+                scale: Scale::Linear,
+                pixels: knob_size_f32,
+            };
+
+            let scrub_key_down = scrub_key.clone();
+            let current_value_down = current_value.clone();
+            container = container.on_mouse_down(MouseButton::Left, move |event, _window, _cx| {
+                let (x, y) = (event.position.x.into(), event.position.y.into());
+                start_scrub(&scrub_key_down, x, y, current_value_down.get());
+            });
+            let scrub_key_up = scrub_key.clone();
+            container = container.on_mouse_up(MouseButton::Left, move |_event, _window, _cx| {
+                end_scrub(&scrub_key_up);
+            });
 
+            let current_value_drag = current_value.clone();
             container = container.on_mouse_move(move |event, window, cx| {
                 if event.pressed_button == Some(MouseButton::Left) {
-                    // Drag: Convert vertical drag to value change
+                    // Drag: scrub relative to the press, at the modifier speed
                     if let Some(ref handler) = drag_handler {
-                        let drag_y: f32 = event.position.y.into();
-                        let progress = 1.0 - (drag_y / knob_size_f32).clamp(0.0, 1.0);
-                        handler(progress, window, cx);
+                        let (x, y) = (event.position.x.into(), event.position.y.into());
+                        if let Some(new_value) =
+                            update_scrub(&scrub_key, x, y, &event.modifiers, 0.0, 1.0, mapping)
+                        {
+                            // Fine control passes over the detents
+                            let new_value = if event.modifiers.shift {
+                                new_value
+                            } else {
+                                snap_to_detent(new_value, &detents_drag, 0.0, 1.0, Scale::Linear)
+                            };
+                            current_value_drag.set(new_value);
+                            handler.call(new_value as f32, window, cx);
+                        }
                     }
                 } else if let Some(ref fh) = focus_handle_hover {
-                    // Hover: Focus for keyboard navigation
-                    if !fh.is_focused(window) {
+                    // Hover: Focus for keyboard navigation, leaving it in the
+                    // open value entry
+                    if !fh.is_focused(window)
+                        && !entry_hover.as_ref().is_some_and(ValueEntry::is_open)
+                    {
                         fh.focus(window, cx);
                     }
                 }
             });
         }
 
-        // Double-click - toggle mute
-        if let Some(ref mute_handler) = on_mute_rc {
+        // Double-click - type an exact value, or toggle mute
+        if let Some(ref entry) = value_entry {
+            let entry_click = entry.clone();
+            container = container.on_click(move |event, window, cx| {
+                if event.click_count() == 2 {
+                    entry_click.open(window, cx);
+                }
+            });
+        } else if let Some(ref mute_handler) = on_mute_rc {
             let click_mute = mute_handler.clone();
             container = container.on_click(move |event, window, cx| {
                 if event.click_count() == 2 {
-                    click_mute(!current_muted, window, cx);
+                    click_mute.call(!current_muted, window, cx);
                 }
             });
         }
@@ -509,7 +682,7 @@ impl RenderOnce for VolumeKnob {
                 // Handle mute keys specially
                 if key == "m" || key == "audiovolumemute" || key == "f10" {
                     if let Some(ref handler) = key_mute {
-                        handler(!current_muted, window, cx);
+                        handler.call(!current_muted, window, cx);
                     }
                 } else if let Some(ref handler) = key_change {
                     // Use shared keyboard handler for value changes
@@ -520,21 +693,40 @@ impl RenderOnce for VolumeKnob {
                         &config_key,
                     ) {
                         current_value_key.set(new_value);
-                        handler(new_value as f32, window, cx);
+                        handler.call(new_value as f32, window, cx);
                     }
                 }
             });
         }
 
+        // Value entry popover, while open
+        if let Some(ref entry) = value_entry
+            && let Some(ref change_handler) = on_change_rc
+        {
+            let entry_handler = change_handler.clone();
+            let style = EntryStyle {
+                background: bg_color,
+                border: accent_color,
+                shadow: global_theme.elevation.shadow(Elevation::Overlay),
+            };
+            container = container.children(entry.popover(
+                entry_text,
+                "%",
+                0.0,
+                1.0,
+                style,
+                move |new_value, window, cx| entry_handler.call(new_value as f32, window, cx),
+            ));
+        }
+
+        let fill = VolumeKnobFillElement {
+            origin: (self.bipolar && !self.muted).then_some(0.5),
+            detents: detent_marks,
+            ..VolumeKnobFillElement::new(self.size, display_value, bg_color, fill_color, ring_color)
+        };
         container
             // Custom painted fill element
-            .child(div().absolute().inset_0().child(VolumeKnobFillElement::new(
-                self.size,
-                display_value,
-                bg_color,
-                fill_color,
-                ring_color,
-            )))
+            .child(div().absolute().inset_0().child(fill))
             // Label text in center
             .child(
                 div()
@@ -24,6 +24,7 @@ use gpui::prelude::*;
 use gpui::*;
 
 use crate::ComponentTheme;
+use crate::callback::Callback;
 use crate::card::Card;
 use crate::number_input::{NumberInput, NumberInputSize, NumberInputTheme};
 use crate::select::{Select, SelectOption, SelectTheme};
@@ -413,15 +414,15 @@ pub struct AutoEqFormTheme {
 // ============================================================================
 
 /// Callback type for string parameter changes
-type StringCallback = Box<dyn Fn(&str, &mut Window, &mut App) + 'static>;
+type StringCallback = Callback<String>;
 /// Callback type for f64 parameter changes
-type F64Callback = Box<dyn Fn(f64, &mut Window, &mut App) + 'static>;
+type F64Callback = Callback<f64>;
 /// Callback type for usize parameter changes
-type UsizeCallback = Box<dyn Fn(usize, &mut Window, &mut App) + 'static>;
+type UsizeCallback = Callback<usize>;
 /// Callback type for bool parameter changes
-type BoolCallback = Box<dyn Fn(bool, &mut Window, &mut App) + 'static>;
+type BoolCallback = Callback<bool>;
 /// Callback type for dropdown toggle
-type ToggleCallback = Box<dyn Fn(bool, &mut Window, &mut App) + 'static>;
+type ToggleCallback = Callback<bool>;
 
 /// A reusable form for AutoEQ optimization parameters.
 ///
@@ -624,7 +625,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_opt_mode_change = Some(Box::new(handler));
+        self.on_opt_mode_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -633,7 +636,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_opt_mode_toggle = Some(Box::new(handler));
+        self.on_opt_mode_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -642,7 +645,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_fir_taps_change = Some(Box::new(handler));
+        self.on_fir_taps_change = Some(Callback::new(handler));
         self
     }
 
@@ -651,7 +654,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_fir_phase_change = Some(Box::new(handler));
+        self.on_fir_phase_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -660,7 +665,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_fir_phase_toggle = Some(Box::new(handler));
+        self.on_fir_phase_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -669,7 +674,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_num_filters_change = Some(Box::new(handler));
+        self.on_num_filters_change = Some(Callback::new(handler));
         self
     }
 
@@ -678,7 +683,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_sample_rate_change = Some(Box::new(handler));
+        self.on_sample_rate_change = Some(Callback::new(handler));
         self
     }
 
@@ -687,7 +692,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_min_db_change = Some(Box::new(handler));
+        self.on_min_db_change = Some(Callback::new(handler));
         self
     }
 
@@ -696,7 +701,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_max_db_change = Some(Box::new(handler));
+        self.on_max_db_change = Some(Callback::new(handler));
         self
     }
 
@@ -705,7 +710,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_min_q_change = Some(Box::new(handler));
+        self.on_min_q_change = Some(Callback::new(handler));
         self
     }
 
@@ -714,7 +719,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_max_q_change = Some(Box::new(handler));
+        self.on_max_q_change = Some(Callback::new(handler));
         self
     }
 
@@ -723,7 +728,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_min_freq_change = Some(Box::new(handler));
+        self.on_min_freq_change = Some(Callback::new(handler));
         self
     }
 
@@ -732,7 +737,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_max_freq_change = Some(Box::new(handler));
+        self.on_max_freq_change = Some(Callback::new(handler));
         self
     }
 
@@ -741,7 +746,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_peq_model_change = Some(Box::new(handler));
+        self.on_peq_model_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -750,7 +757,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_peq_model_toggle = Some(Box::new(handler));
+        self.on_peq_model_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -759,7 +766,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_spacing_weight_change = Some(Box::new(handler));
+        self.on_spacing_weight_change = Some(Callback::new(handler));
         self
     }
 
@@ -768,7 +775,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_min_spacing_oct_change = Some(Box::new(handler));
+        self.on_min_spacing_oct_change = Some(Callback::new(handler));
         self
     }
 
@@ -779,7 +786,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_algo_change = Some(Box::new(handler));
+        self.on_algo_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -788,7 +797,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_algo_toggle = Some(Box::new(handler));
+        self.on_algo_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -797,7 +806,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_population_change = Some(Box::new(handler));
+        self.on_population_change = Some(Callback::new(handler));
         self
     }
 
@@ -806,7 +815,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_maxeval_change = Some(Box::new(handler));
+        self.on_maxeval_change = Some(Callback::new(handler));
         self
     }
 
@@ -815,7 +824,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_tolerance_change = Some(Box::new(handler));
+        self.on_tolerance_change = Some(Callback::new(handler));
         self
     }
 
@@ -824,7 +833,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_atolerance_change = Some(Box::new(handler));
+        self.on_atolerance_change = Some(Callback::new(handler));
         self
     }
 
@@ -833,7 +842,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_de_f_change = Some(Box::new(handler));
+        self.on_de_f_change = Some(Callback::new(handler));
         self
     }
 
@@ -842,7 +851,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(f64, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_de_cr_change = Some(Box::new(handler));
+        self.on_de_cr_change = Some(Callback::new(handler));
         self
     }
 
@@ -851,7 +860,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_strategy_change = Some(Box::new(handler));
+        self.on_strategy_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -860,7 +871,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_strategy_toggle = Some(Box::new(handler));
+        self.on_strategy_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -869,7 +880,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_refine_change = Some(Box::new(handler));
+        self.on_refine_change = Some(Callback::new(handler));
         self
     }
 
@@ -878,7 +889,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_local_algo_change = Some(Box::new(handler));
+        self.on_local_algo_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -887,7 +900,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_local_algo_toggle = Some(Box::new(handler));
+        self.on_local_algo_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -896,7 +909,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_smooth_change = Some(Box::new(handler));
+        self.on_smooth_change = Some(Callback::new(handler));
         self
     }
 
@@ -905,7 +918,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_smooth_n_change = Some(Box::new(handler));
+        self.on_smooth_n_change = Some(Callback::new(handler));
         self
     }
 
@@ -916,7 +929,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_loss_type_change = Some(Box::new(handler));
+        self.on_loss_type_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -925,7 +940,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_loss_type_toggle = Some(Box::new(handler));
+        self.on_loss_type_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -934,7 +949,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_target_curve_change = Some(Box::new(handler));
+        self.on_target_curve_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -943,7 +960,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_target_curve_toggle = Some(Box::new(handler));
+        self.on_target_curve_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -952,7 +969,9 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_system_type_change = Some(Box::new(handler));
+        self.on_system_type_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
@@ -961,7 +980,7 @@ impl AutoEqForm {
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_system_type_toggle = Some(Box::new(handler));
+        self.on_system_type_toggle = Some(Callback::new(handler));
         self
     }
 }
@@ -984,45 +1003,44 @@ impl RenderOnce for AutoEqForm {
         let optimization_type = self.optimization_type;
         let available_spinorama_curves = self.available_spinorama_curves;
 
-        // Wrap callbacks in Rc for sharing
-        let on_opt_mode_change_rc = self.on_opt_mode_change.map(std::rc::Rc::new);
-        let on_opt_mode_toggle_rc = self.on_opt_mode_toggle.map(std::rc::Rc::new);
-        let on_fir_taps_change_rc = self.on_fir_taps_change.map(std::rc::Rc::new);
-        let on_fir_phase_change_rc = self.on_fir_phase_change.map(std::rc::Rc::new);
-        let on_fir_phase_toggle_rc = self.on_fir_phase_toggle.map(std::rc::Rc::new);
-        let on_num_filters_change_rc = self.on_num_filters_change.map(std::rc::Rc::new);
-        let on_sample_rate_change_rc = self.on_sample_rate_change.map(std::rc::Rc::new);
-        let on_min_db_change_rc = self.on_min_db_change.map(std::rc::Rc::new);
-        let on_max_db_change_rc = self.on_max_db_change.map(std::rc::Rc::new);
-        let on_min_q_change_rc = self.on_min_q_change.map(std::rc::Rc::new);
-        let on_max_q_change_rc = self.on_max_q_change.map(std::rc::Rc::new);
-        let on_min_freq_change_rc = self.on_min_freq_change.map(std::rc::Rc::new);
-        let on_max_freq_change_rc = self.on_max_freq_change.map(std::rc::Rc::new);
-        let on_peq_model_change_rc = self.on_peq_model_change.map(std::rc::Rc::new);
-        let on_peq_model_toggle_rc = self.on_peq_model_toggle.map(std::rc::Rc::new);
-        let on_spacing_weight_change_rc = self.on_spacing_weight_change.map(std::rc::Rc::new);
-        let on_min_spacing_oct_change_rc = self.on_min_spacing_oct_change.map(std::rc::Rc::new);
-        let on_algo_change_rc = self.on_algo_change.map(std::rc::Rc::new);
-        let on_algo_toggle_rc = self.on_algo_toggle.map(std::rc::Rc::new);
-        let on_population_change_rc = self.on_population_change.map(std::rc::Rc::new);
-        let on_maxeval_change_rc = self.on_maxeval_change.map(std::rc::Rc::new);
-        let on_tolerance_change_rc = self.on_tolerance_change.map(std::rc::Rc::new);
-        let on_atolerance_change_rc = self.on_atolerance_change.map(std::rc::Rc::new);
-        let on_de_f_change_rc = self.on_de_f_change.map(std::rc::Rc::new);
-        let on_de_cr_change_rc = self.on_de_cr_change.map(std::rc::Rc::new);
-        let on_strategy_change_rc = self.on_strategy_change.map(std::rc::Rc::new);
-        let on_strategy_toggle_rc = self.on_strategy_toggle.map(std::rc::Rc::new);
-        let on_refine_change_rc = self.on_refine_change.map(std::rc::Rc::new);
-        let on_local_algo_change_rc = self.on_local_algo_change.map(std::rc::Rc::new);
-        let on_local_algo_toggle_rc = self.on_local_algo_toggle.map(std::rc::Rc::new);
-        let on_smooth_change_rc = self.on_smooth_change.map(std::rc::Rc::new);
-        let on_smooth_n_change_rc = self.on_smooth_n_change.map(std::rc::Rc::new);
-        let on_loss_type_change_rc = self.on_loss_type_change.map(std::rc::Rc::new);
-        let on_loss_type_toggle_rc = self.on_loss_type_toggle.map(std::rc::Rc::new);
-        let on_target_curve_change_rc = self.on_target_curve_change.map(std::rc::Rc::new);
-        let on_target_curve_toggle_rc = self.on_target_curve_toggle.map(std::rc::Rc::new);
-        let on_system_type_change_rc = self.on_system_type_change.map(std::rc::Rc::new);
-        let on_system_type_toggle_rc = self.on_system_type_toggle.map(std::rc::Rc::new);
+        let on_opt_mode_change_rc = self.on_opt_mode_change;
+        let on_opt_mode_toggle_rc = self.on_opt_mode_toggle;
+        let on_fir_taps_change_rc = self.on_fir_taps_change;
+        let on_fir_phase_change_rc = self.on_fir_phase_change;
+        let on_fir_phase_toggle_rc = self.on_fir_phase_toggle;
+        let on_num_filters_change_rc = self.on_num_filters_change;
+        let on_sample_rate_change_rc = self.on_sample_rate_change;
+        let on_min_db_change_rc = self.on_min_db_change;
+        let on_max_db_change_rc = self.on_max_db_change;
+        let on_min_q_change_rc = self.on_min_q_change;
+        let on_max_q_change_rc = self.on_max_q_change;
+        let on_min_freq_change_rc = self.on_min_freq_change;
+        let on_max_freq_change_rc = self.on_max_freq_change;
+        let on_peq_model_change_rc = self.on_peq_model_change;
+        let on_peq_model_toggle_rc = self.on_peq_model_toggle;
+        let on_spacing_weight_change_rc = self.on_spacing_weight_change;
+        let on_min_spacing_oct_change_rc = self.on_min_spacing_oct_change;
+        let on_algo_change_rc = self.on_algo_change;
+        let on_algo_toggle_rc = self.on_algo_toggle;
+        let on_population_change_rc = self.on_population_change;
+        let on_maxeval_change_rc = self.on_maxeval_change;
+        let on_tolerance_change_rc = self.on_tolerance_change;
+        let on_atolerance_change_rc = self.on_atolerance_change;
+        let on_de_f_change_rc = self.on_de_f_change;
+        let on_de_cr_change_rc = self.on_de_cr_change;
+        let on_strategy_change_rc = self.on_strategy_change;
+        let on_strategy_toggle_rc = self.on_strategy_toggle;
+        let on_refine_change_rc = self.on_refine_change;
+        let on_local_algo_change_rc = self.on_local_algo_change;
+        let on_local_algo_toggle_rc = self.on_local_algo_toggle;
+        let on_smooth_change_rc = self.on_smooth_change;
+        let on_smooth_n_change_rc = self.on_smooth_n_change;
+        let on_loss_type_change_rc = self.on_loss_type_change;
+        let on_loss_type_toggle_rc = self.on_loss_type_toggle;
+        let on_target_curve_change_rc = self.on_target_curve_change;
+        let on_target_curve_toggle_rc = self.on_target_curve_toggle;
+        let on_system_type_change_rc = self.on_system_type_change;
+        let on_system_type_toggle_rc = self.on_system_type_toggle;
 
         let mut form = VStack::new().spacing(StackSpacing::Lg);
 
@@ -1067,13 +1085,13 @@ impl RenderOnce for AutoEqForm {
                 if let Some(ref handler) = on_system_type_toggle_rc {
                     let h = handler.clone();
                     system_type_select =
-                        system_type_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                        system_type_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
                 }
 
                 if let Some(ref handler) = on_system_type_change_rc {
                     let h = handler.clone();
-                    system_type_select =
-                        system_type_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                    system_type_select = system_type_select
+                        .on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
                 }
 
                 goals_content = goals_content.child(system_type_select);
@@ -1095,13 +1113,14 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_loss_type_toggle_rc {
                 let h = handler.clone();
-                loss_type_select = loss_type_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                loss_type_select =
+                    loss_type_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
             }
 
             if let Some(ref handler) = on_loss_type_change_rc {
                 let h = handler.clone();
-                loss_type_select =
-                    loss_type_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                loss_type_select = loss_type_select
+                    .on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
             }
 
             goals_content = goals_content.child(loss_type_select);
@@ -1143,14 +1162,14 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_target_curve_change_rc {
                 let h = handler.clone();
-                target_curve_select =
-                    target_curve_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                target_curve_select = target_curve_select
+                    .on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
             }
 
             if let Some(ref handler) = on_target_curve_toggle_rc {
                 let h = handler.clone();
                 target_curve_select =
-                    target_curve_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                    target_curve_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
             }
 
             goals_content = goals_content.child(target_curve_select);
@@ -1204,13 +1223,13 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_opt_mode_toggle_rc {
                 let h = handler.clone();
-                opt_mode_select = opt_mode_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                opt_mode_select = opt_mode_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
             }
 
             if let Some(ref handler) = on_opt_mode_change_rc {
                 let h = handler.clone();
                 opt_mode_select =
-                    opt_mode_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                    opt_mode_select.on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
             }
 
             eq_design_content = eq_design_content.child(opt_mode_select);
@@ -1236,7 +1255,7 @@ impl RenderOnce for AutoEqForm {
                 if let Some(ref handler) = on_fir_taps_change_rc {
                     let h = handler.clone();
                     fir_taps_input =
-                        fir_taps_input.on_change(move |v, w, cx| h(v.round() as usize, w, cx));
+                        fir_taps_input.on_change(move |v, w, cx| h.call(v.round() as usize, w, cx));
                 }
 
                 // FIR Phase dropdown
@@ -1256,13 +1275,13 @@ impl RenderOnce for AutoEqForm {
                 if let Some(ref handler) = on_fir_phase_toggle_rc {
                     let h = handler.clone();
                     fir_phase_select =
-                        fir_phase_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                        fir_phase_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
                 }
 
                 if let Some(ref handler) = on_fir_phase_change_rc {
                     let h = handler.clone();
-                    fir_phase_select =
-                        fir_phase_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                    fir_phase_select = fir_phase_select
+                        .on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
                 }
 
                 eq_design_content = eq_design_content.child(
@@ -1289,7 +1308,7 @@ impl RenderOnce for AutoEqForm {
             if let Some(ref handler) = on_sample_rate_change_rc {
                 let h = handler.clone();
                 sample_rate_input =
-                    sample_rate_input.on_change(move |v, w, cx| h(v.round() as usize, w, cx));
+                    sample_rate_input.on_change(move |v, w, cx| h.call(v.round() as usize, w, cx));
             }
 
             if is_iir {
@@ -1307,8 +1326,8 @@ impl RenderOnce for AutoEqForm {
 
                 if let Some(ref handler) = on_num_filters_change_rc {
                     let h = handler.clone();
-                    num_filters_input =
-                        num_filters_input.on_change(move |v, w, cx| h(v.round() as usize, w, cx));
+                    num_filters_input = num_filters_input
+                        .on_change(move |v, w, cx| h.call(v.round() as usize, w, cx));
                 }
 
                 eq_design_content = eq_design_content.child(
@@ -1341,7 +1360,7 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_min_db_change_rc {
                 let h = handler.clone();
-                min_db_input = min_db_input.on_change(move |v, w, cx| h(v, w, cx));
+                min_db_input = min_db_input.on_change(move |v, w, cx| h.call(v, w, cx));
             }
 
             let mut max_db_input = NumberInput::new("autoeq-max-db")
@@ -1358,7 +1377,7 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_max_db_change_rc {
                 let h = handler.clone();
-                max_db_input = max_db_input.on_change(move |v, w, cx| h(v, w, cx));
+                max_db_input = max_db_input.on_change(move |v, w, cx| h.call(v, w, cx));
             }
 
             eq_design_content = eq_design_content.child(
@@ -1384,7 +1403,7 @@ impl RenderOnce for AutoEqForm {
 
                 if let Some(ref handler) = on_min_q_change_rc {
                     let h = handler.clone();
-                    min_q_input = min_q_input.on_change(move |v, w, cx| h(v, w, cx));
+                    min_q_input = min_q_input.on_change(move |v, w, cx| h.call(v, w, cx));
                 }
 
                 let mut max_q_input = NumberInput::new("autoeq-max-q")
@@ -1401,7 +1420,7 @@ impl RenderOnce for AutoEqForm {
 
                 if let Some(ref handler) = on_max_q_change_rc {
                     let h = handler.clone();
-                    max_q_input = max_q_input.on_change(move |v, w, cx| h(v, w, cx));
+                    max_q_input = max_q_input.on_change(move |v, w, cx| h.call(v, w, cx));
                 }
 
                 eq_design_content = eq_design_content.child(
@@ -1427,7 +1446,7 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_min_freq_change_rc {
                 let h = handler.clone();
-                min_freq_input = min_freq_input.on_change(move |v, w, cx| h(v, w, cx));
+                min_freq_input = min_freq_input.on_change(move |v, w, cx| h.call(v, w, cx));
             }
 
             let mut max_freq_input = NumberInput::new("autoeq-max-freq")
@@ -1444,7 +1463,7 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_max_freq_change_rc {
                 let h = handler.clone();
-                max_freq_input = max_freq_input.on_change(move |v, w, cx| h(v, w, cx));
+                max_freq_input = max_freq_input.on_change(move |v, w, cx| h.call(v, w, cx));
             }
 
             eq_design_content = eq_design_content.child(
@@ -1472,13 +1491,13 @@ impl RenderOnce for AutoEqForm {
                 if let Some(ref handler) = on_peq_model_toggle_rc {
                     let h = handler.clone();
                     peq_model_select =
-                        peq_model_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                        peq_model_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
                 }
 
                 if let Some(ref handler) = on_peq_model_change_rc {
                     let h = handler.clone();
-                    peq_model_select =
-                        peq_model_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                    peq_model_select = peq_model_select
+                        .on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
                 }
 
                 eq_design_content = eq_design_content.child(peq_model_select);
@@ -1499,7 +1518,7 @@ impl RenderOnce for AutoEqForm {
                 if let Some(ref handler) = on_spacing_weight_change_rc {
                     let h = handler.clone();
                     spacing_weight_input =
-                        spacing_weight_input.on_change(move |v, w, cx| h(v, w, cx));
+                        spacing_weight_input.on_change(move |v, w, cx| h.call(v, w, cx));
                 }
 
                 let mut min_spacing_oct_input = NumberInput::new("autoeq-min-spacing-oct")
@@ -1517,7 +1536,7 @@ impl RenderOnce for AutoEqForm {
                 if let Some(ref handler) = on_min_spacing_oct_change_rc {
                     let h = handler.clone();
                     min_spacing_oct_input =
-                        min_spacing_oct_input.on_change(move |v, w, cx| h(v, w, cx));
+                        min_spacing_oct_input.on_change(move |v, w, cx| h.call(v, w, cx));
                 }
 
                 eq_design_content = eq_design_content.child(
@@ -1570,12 +1589,13 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_algo_toggle_rc {
                 let h = handler.clone();
-                algo_select = algo_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                algo_select = algo_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
             }
 
             if let Some(ref handler) = on_algo_change_rc {
                 let h = handler.clone();
-                algo_select = algo_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                algo_select =
+                    algo_select.on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
             }
 
             opt_tuning_content = opt_tuning_content.child(algo_select);
@@ -1596,7 +1616,7 @@ impl RenderOnce for AutoEqForm {
             if let Some(ref handler) = on_population_change_rc {
                 let h = handler.clone();
                 population_input =
-                    population_input.on_change(move |v, w, cx| h(v.round() as usize, w, cx));
+                    population_input.on_change(move |v, w, cx| h.call(v.round() as usize, w, cx));
             }
 
             let mut maxeval_input = NumberInput::new("autoeq-maxeval")
@@ -1614,7 +1634,7 @@ impl RenderOnce for AutoEqForm {
             if let Some(ref handler) = on_maxeval_change_rc {
                 let h = handler.clone();
                 maxeval_input =
-                    maxeval_input.on_change(move |v, w, cx| h(v.round() as usize, w, cx));
+                    maxeval_input.on_change(move |v, w, cx| h.call(v.round() as usize, w, cx));
             }
 
             opt_tuning_content = opt_tuning_content.child(
@@ -1639,7 +1659,7 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_tolerance_change_rc {
                 let h = handler.clone();
-                tolerance_input = tolerance_input.on_change(move |v, w, cx| h(v, w, cx));
+                tolerance_input = tolerance_input.on_change(move |v, w, cx| h.call(v, w, cx));
             }
 
             let mut atolerance_input = NumberInput::new("autoeq-atolerance")
@@ -1656,7 +1676,7 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_atolerance_change_rc {
                 let h = handler.clone();
-                atolerance_input = atolerance_input.on_change(move |v, w, cx| h(v, w, cx));
+                atolerance_input = atolerance_input.on_change(move |v, w, cx| h.call(v, w, cx));
             }
 
             opt_tuning_content = opt_tuning_content.child(
@@ -1690,13 +1710,13 @@ impl RenderOnce for AutoEqForm {
                     if let Some(ref handler) = on_strategy_toggle_rc {
                         let h = handler.clone();
                         strategy_select =
-                            strategy_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                            strategy_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
                     }
 
                     if let Some(ref handler) = on_strategy_change_rc {
                         let h = handler.clone();
-                        strategy_select =
-                            strategy_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                        strategy_select = strategy_select
+                            .on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
                     }
 
                     opt_tuning_content = opt_tuning_content.child(strategy_select);
@@ -1716,7 +1736,7 @@ impl RenderOnce for AutoEqForm {
 
                     if let Some(ref handler) = on_de_f_change_rc {
                         let h = handler.clone();
-                        de_f_input = de_f_input.on_change(move |v, w, cx| h(v, w, cx));
+                        de_f_input = de_f_input.on_change(move |v, w, cx| h.call(v, w, cx));
                     }
 
                     let mut de_cr_input = NumberInput::new("autoeq-de-cr")
@@ -1733,7 +1753,7 @@ impl RenderOnce for AutoEqForm {
 
                     if let Some(ref handler) = on_de_cr_change_rc {
                         let h = handler.clone();
-                        de_cr_input = de_cr_input.on_change(move |v, w, cx| h(v, w, cx));
+                        de_cr_input = de_cr_input.on_change(move |v, w, cx| h.call(v, w, cx));
                     }
 
                     opt_tuning_content = opt_tuning_content.child(
@@ -1771,7 +1791,7 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_refine_change_rc {
                 let h = handler.clone();
-                refine_toggle = refine_toggle.on_change(move |v, w, cx| h(v, w, cx));
+                refine_toggle = refine_toggle.on_change(move |v, w, cx| h.call(v, w, cx));
             }
 
             opt_tuning_content = opt_tuning_content.child(
@@ -1804,13 +1824,13 @@ impl RenderOnce for AutoEqForm {
                 if let Some(ref handler) = on_local_algo_toggle_rc {
                     let h = handler.clone();
                     local_algo_select =
-                        local_algo_select.on_toggle(move |open, w, cx| h(open, w, cx));
+                        local_algo_select.on_toggle(move |open, w, cx| h.call(open, w, cx));
                 }
 
                 if let Some(ref handler) = on_local_algo_change_rc {
                     let h = handler.clone();
-                    local_algo_select =
-                        local_algo_select.on_change(move |value, w, cx| h(value.as_ref(), w, cx));
+                    local_algo_select = local_algo_select
+                        .on_change(move |value, w, cx| h.call(value.to_string(), w, cx));
                 }
 
                 opt_tuning_content = opt_tuning_content.child(local_algo_select);
@@ -1824,7 +1844,7 @@ impl RenderOnce for AutoEqForm {
 
             if let Some(ref handler) = on_smooth_change_rc {
                 let h = handler.clone();
-                smooth_toggle = smooth_toggle.on_change(move |v, w, cx| h(v, w, cx));
+                smooth_toggle = smooth_toggle.on_change(move |v, w, cx| h.call(v, w, cx));
             }
 
             opt_tuning_content = opt_tuning_content.child(
@@ -1856,7 +1876,7 @@ impl RenderOnce for AutoEqForm {
                 if let Some(ref handler) = on_smooth_n_change_rc {
                     let h = handler.clone();
                     smooth_n_input =
-                        smooth_n_input.on_change(move |v, w, cx| h(v.round() as usize, w, cx));
+                        smooth_n_input.on_change(move |v, w, cx| h.call(v.round() as usize, w, cx));
                 }
 
                 opt_tuning_content = opt_tuning_content.child(smooth_n_input);
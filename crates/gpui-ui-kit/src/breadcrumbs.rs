@@ -2,6 +2,7 @@
 //!
 //! Navigation breadcrumb trail.
 
+use crate::callback::Callback;
 use crate::theme::{Theme, ThemeExt};
 use gpui::prelude::*;
 use gpui::*;
@@ -70,7 +71,7 @@ impl BreadcrumbSeparator {
 pub struct Breadcrumbs {
     items: Vec<BreadcrumbItem>,
     separator: BreadcrumbSeparator,
-    on_click: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    on_click: Option<Callback<SharedString>>,
 }
 
 impl Breadcrumbs {
@@ -100,7 +101,9 @@ impl Breadcrumbs {
         mut self,
         handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Callback::new(move |id: SharedString, window, cx| {
+            handler(&id, window, cx)
+        }));
         self
     }
 
@@ -144,14 +147,11 @@ impl Breadcrumbs {
                     .hover(move |s| s.text_color(hover_color));
 
                 // Click handler
-                if let Some(ref handler) = self.on_click {
-                    let handler_ptr: *const dyn Fn(&SharedString, &mut Window, &mut App) =
-                        handler.as_ref();
+                if let Some(handler) = self.on_click.clone() {
                     let id = item_id.clone();
-                    crumb =
-                        crumb.on_mouse_up(MouseButton::Left, move |_event, window, cx| unsafe {
-                            (*handler_ptr)(&id, window, cx);
-                        });
+                    crumb = crumb.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
+                        handler.call(id.clone(), window, cx);
+                    });
                 }
             }
 
@@ -3,6 +3,8 @@
 //! Provides a flexible button component with different visual styles.
 
 use crate::ComponentTheme;
+use crate::app::recorder::record_click;
+use crate::callback::Callback;
 use crate::theme::{ThemeExt, glow_shadow};
 use gpui::prelude::*;
 use gpui::*;
@@ -35,18 +37,11 @@ pub enum ButtonSize {
     Md,
     /// Large button
     Lg,
+    /// Extra large button
+    Xl,
 }
 
-impl From<crate::ComponentSize> for ButtonSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs => Self::Xs,
-            crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg | crate::ComponentSize::Xl => Self::Lg,
-        }
-    }
-}
+crate::size::component_size_conversions!(ButtonSize);
 
 /// Theme colors for button styling
 #[derive(Debug, Clone, ComponentTheme)]
@@ -91,7 +86,7 @@ pub struct Button {
     icon_left: Option<SharedString>,
     icon_right: Option<SharedString>,
     theme: Option<ButtonTheme>,
-    on_click: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_click: Option<Callback>,
 }
 
 impl Button {
@@ -162,7 +157,7 @@ impl Button {
 
     /// Set the click handler (for standalone use without cx.listener)
     pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Callback::from_fn(handler));
         self
     }
 
@@ -223,21 +218,16 @@ impl Button {
         let (bg, bg_hover, text_color, border_color) =
             Self::compute_colors(self.variant, self.selected, &theme);
 
-        let (px_val, py_val) = match self.size {
-            ButtonSize::Xs => (px(6.0), px(2.0)),
-            ButtonSize::Sm => (px(8.0), px(4.0)),
-            ButtonSize::Md => (px(12.0), px(6.0)),
-            ButtonSize::Lg => (px(24.0), px(12.0)),
-        };
+        let size = crate::ComponentSize::from(self.size);
 
         let mut el = div()
-            .id(self.id)
+            .id(self.id.clone())
             .flex()
             .items_center()
             .justify_center()
-            .gap_2()
-            .px(px_val)
-            .py(py_val)
+            .gap(size.gap())
+            .h(size.control_height())
+            .px(size.padding_x())
             .rounded_md()
             .bg(bg)
             .text_color(text_color)
@@ -245,12 +235,7 @@ impl Button {
             .border_color(border_color);
 
         // Apply text size based on button size
-        el = match self.size {
-            ButtonSize::Xs => el.text_xs(),
-            ButtonSize::Sm => el.text_xs(),
-            ButtonSize::Md => el.text_sm(),
-            ButtonSize::Lg => el.text_lg(),
-        };
+        el = size.apply_text_size(el);
 
         // Apply full width
         if self.full_width {
@@ -286,21 +271,16 @@ impl RenderOnce for Button {
         let (bg, bg_hover, text_color, border_color) =
             Self::compute_colors(self.variant, self.selected, &theme);
 
-        let (px_val, py_val) = match self.size {
-            ButtonSize::Xs => (px(6.0), px(2.0)),
-            ButtonSize::Sm => (px(8.0), px(4.0)),
-            ButtonSize::Md => (px(12.0), px(6.0)),
-            ButtonSize::Lg => (px(24.0), px(12.0)),
-        };
+        let size = crate::ComponentSize::from(self.size);
 
         let mut el = div()
             .id(self.id)
             .flex()
             .items_center()
             .justify_center()
-            .gap_2()
-            .px(px_val)
-            .py(py_val)
+            .gap(size.gap())
+            .h(size.control_height())
+            .px(size.padding_x())
             .rounded_md()
             .bg(bg)
             .text_color(text_color)
@@ -309,12 +289,7 @@ impl RenderOnce for Button {
             .cursor_pointer();
 
         // Apply text size based on button size
-        el = match self.size {
-            ButtonSize::Xs => el.text_xs(),
-            ButtonSize::Sm => el.text_xs(),
-            ButtonSize::Md => el.text_sm(),
-            ButtonSize::Lg => el.text_lg(),
-        };
+        el = size.apply_text_size(el);
 
         // Apply full width
         if self.full_width {
@@ -326,8 +301,10 @@ impl RenderOnce for Button {
         } else {
             el = el.hover(move |style| style.bg(bg_hover).shadow(glow_shadow(bg_hover)));
             if let Some(handler) = self.on_click {
+                let id = self.id.clone();
                 el = el.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                    handler(window, cx);
+                    record_click(cx, &id);
+                    handler.invoke(window, cx);
                 });
             }
         }
@@ -19,7 +19,9 @@
 //! ```
 
 use crate::ComponentTheme;
+use crate::callback::Callback;
 use crate::theme::{ThemeExt, glow_shadow};
+use crate::validation::BuilderWarnings;
 use gpui::prelude::*;
 use gpui::*;
 
@@ -61,18 +63,11 @@ pub enum ButtonSetSize {
     Md,
     /// Large
     Lg,
+    /// Extra large
+    Xl,
 }
 
-impl From<crate::ComponentSize> for ButtonSetSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs => Self::Xs,
-            crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg | crate::ComponentSize::Xl => Self::Lg,
-        }
-    }
-}
+crate::size::component_size_conversions!(ButtonSetSize);
 
 /// An option in the button set
 #[derive(Clone)]
@@ -119,7 +114,7 @@ pub struct ButtonSet {
     size: ButtonSetSize,
     disabled: bool,
     theme: Option<ButtonSetTheme>,
-    on_change: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<SharedString>>,
 }
 
 impl ButtonSet {
@@ -171,33 +166,40 @@ impl ButtonSet {
         mut self,
         handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(move |value: SharedString, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
     /// Build into element
     fn build(self, theme: &ButtonSetTheme) -> Stateful<Div> {
-        let (px_val, py_val, text_size) = match self.size {
-            ButtonSetSize::Xs => (px(6.0), px(2.0), "xs"),
-            ButtonSetSize::Sm => (px(8.0), px(4.0), "sm"),
-            ButtonSetSize::Md => (px(12.0), px(6.0), "md"),
-            ButtonSetSize::Lg => (px(16.0), px(8.0), "lg"),
-        };
-
+        let size = crate::ComponentSize::from(self.size);
+
+        let mut warnings = BuilderWarnings::new("ButtonSet", &self.id);
+        if let Some(selected) = &self.selected {
+            warnings.check(
+                self.options.iter().any(|option| &option.value == selected),
+                || format!("selected value \"{selected}\" is not one of the options"),
+            );
+        }
         let border_radius = match self.size {
             ButtonSetSize::Xs => px(4.0),
             ButtonSetSize::Sm => px(4.0),
             ButtonSetSize::Md => px(6.0),
             ButtonSetSize::Lg => px(8.0),
+            ButtonSetSize::Xl => px(10.0),
         };
 
-        let on_change_rc = self.on_change.map(std::rc::Rc::new);
+        let on_change = self.on_change;
         let num_options = self.options.len();
 
         let mut container = div()
             .id(self.id)
             .flex()
             .flex_row()
+            .relative()
+            .h(size.control_height())
             .border_1()
             .border_color(theme.border)
             .rounded(border_radius);
@@ -223,19 +225,14 @@ impl ButtonSet {
                 .items_center()
                 .justify_center()
                 .gap_1()
-                .px(px_val)
-                .py(py_val)
+                .h_full()
+                .px(size.padding_x())
                 .bg(bg)
                 .text_color(text_color)
                 .cursor_pointer();
 
             // Apply text size
-            button = match text_size {
-                "xs" => button.text_xs(),
-                "sm" => button.text_sm(),
-                "lg" => button.text_lg(),
-                _ => button.text_sm(),
-            };
+            button = size.apply_text_size(button);
 
             // Apply border radius only to first and last buttons
             if is_first && is_last {
@@ -269,10 +266,9 @@ impl ButtonSet {
                 }
 
                 // Click handler
-                if let Some(ref handler) = on_change_rc {
-                    let handler = handler.clone();
+                if let Some(on_change) = on_change.clone() {
                     button = button.on_mouse_down(MouseButton::Left, move |_, window, cx| {
-                        handler(&option_value, window, cx);
+                        on_change.call(option_value.clone(), window, cx);
                     });
                 }
             }
@@ -288,7 +284,7 @@ impl ButtonSet {
             container = container.child(button);
         }
 
-        container
+        container.children(warnings.marker())
     }
 }
 
@@ -0,0 +1,64 @@
+//! Shared event callbacks
+//!
+//! Components receive their handlers as `impl Fn` at build time, but one
+//! handler often has to be wired to several elements (a wizard's Next button
+//! and its keyboard shortcut, the + and - buttons of a number input). The
+//! listeners outlive the component struct, so the handler must be
+//! reference-counted. [`Callback`] is that shared, clonable handler.
+//!
+//! Every `on_*` event handler of the kit is stored as a `Callback`; handlers
+//! receiving a reference take the value by clone (`&SharedString` becomes
+//! `SharedString`) and several arguments are passed as a tuple. Closures that
+//! return a value rather than react to an event — cell renderers, formatters,
+//! validators and element factories — stay plain boxed closures.
+//!
+//! ```ignore
+//! let on_change = Callback::new(|value: f64, _window, _cx| println!("{value}"));
+//! let on_increment = on_change.clone();
+//! button.on_click(move |window, cx| on_increment.call(value + step, window, cx));
+//! ```
+
+use gpui::{App, Window};
+use std::fmt;
+use std::rc::Rc;
+
+/// A clonable event handler receiving a value of type `T`
+///
+/// Handlers without a value use `Callback<()>`, see [`Callback::from_fn`].
+pub struct Callback<T = ()>(Rc<dyn Fn(T, &mut Window, &mut App)>);
+
+impl<T> Callback<T> {
+    /// Wrap a handler
+    pub fn new(handler: impl Fn(T, &mut Window, &mut App) + 'static) -> Self {
+        Self(Rc::new(handler))
+    }
+
+    /// Invoke the handler
+    pub fn call(&self, value: T, window: &mut Window, cx: &mut App) {
+        (self.0)(value, window, cx);
+    }
+}
+
+impl Callback<()> {
+    /// Wrap a handler that takes no value
+    pub fn from_fn(handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        Self::new(move |(), window, cx| handler(window, cx))
+    }
+
+    /// Invoke a handler that takes no value
+    pub fn invoke(&self, window: &mut Window, cx: &mut App) {
+        self.call((), window, cx);
+    }
+}
+
+impl<T> Clone for Callback<T> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<T> fmt::Debug for Callback<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Callback").finish_non_exhaustive()
+    }
+}
@@ -24,7 +24,7 @@
 //!     })
 //! ```
 
-use crate::theme::{Theme, ThemeExt};
+use crate::theme::{Elevation, Theme, ThemeExt};
 use gpui::prelude::*;
 use gpui::*;
 
@@ -176,7 +176,7 @@ impl Card {
             .border_1()
             .border_color(border_color)
             .rounded_lg()
-            .shadow_md()
+            .shadow(theme.elevation.shadow(Elevation::Raised))
             .overflow_hidden();
 
         // Apply extra classes
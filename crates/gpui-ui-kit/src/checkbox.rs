@@ -8,6 +8,7 @@
 //! - Indeterminate state support
 
 use crate::ComponentTheme;
+use crate::callback::Callback;
 use crate::theme::ThemeExt;
 use gpui::prelude::*;
 use gpui::*;
@@ -38,6 +39,8 @@ pub struct CheckboxTheme {
 /// Checkbox size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CheckboxSize {
+    /// Extra small (12px)
+    Xs,
     /// Small (14px)
     Sm,
     /// Medium (18px, default)
@@ -45,27 +48,17 @@ pub enum CheckboxSize {
     Md,
     /// Large (22px)
     Lg,
+    /// Extra large (26px)
+    Xl,
 }
 
 impl CheckboxSize {
     fn size(&self) -> Pixels {
-        match self {
-            CheckboxSize::Sm => px(14.0),
-            CheckboxSize::Md => px(18.0),
-            CheckboxSize::Lg => px(22.0),
-        }
+        crate::ComponentSize::from(*self).icon_size()
     }
 }
 
-impl From<crate::ComponentSize> for CheckboxSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs | crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg | crate::ComponentSize::Xl => Self::Lg,
-        }
-    }
-}
+crate::size::component_size_conversions!(CheckboxSize);
 
 /// A checkbox component
 pub struct Checkbox {
@@ -75,7 +68,7 @@ pub struct Checkbox {
     label: Option<SharedString>,
     size: CheckboxSize,
     disabled: bool,
-    on_change: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<bool>>,
 }
 
 impl Checkbox {
@@ -124,7 +117,7 @@ impl Checkbox {
 
     /// Set change handler
     pub fn on_change(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(handler));
         self
     }
 
@@ -140,11 +133,14 @@ impl Checkbox {
             (theme.unchecked_bg, theme.unchecked_border)
         };
 
+        // Same row height as other controls of this size, so checkboxes line
+        // up with inputs and buttons in a form row
         let mut container = div()
             .id(self.id)
             .flex()
             .items_center()
             .gap_2()
+            .min_h(crate::ComponentSize::from(self.size).control_height())
             .cursor_pointer();
 
         if self.disabled {
@@ -191,11 +187,7 @@ impl Checkbox {
 
         // Label
         if let Some(label) = self.label {
-            let label_el = match self.size {
-                CheckboxSize::Sm => div().text_xs(),
-                CheckboxSize::Md => div().text_sm(),
-                CheckboxSize::Lg => div(),
-            };
+            let label_el = crate::ComponentSize::from(self.size).apply_text_size(div());
             container = container.child(label_el.text_color(theme.label).child(label));
         }
 
@@ -203,21 +195,20 @@ impl Checkbox {
         if !self.disabled
             && let Some(handler) = self.on_change
         {
-            let handler_rc = std::rc::Rc::new(handler);
             let new_checked = !checked;
 
             // Mouse click handler
-            let click_handler = handler_rc.clone();
+            let click_handler = handler.clone();
             container = container.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                click_handler(new_checked, window, cx);
+                click_handler.call(new_checked, window, cx);
             });
 
             // Keyboard handler (Space or Enter)
-            let key_handler = handler_rc.clone();
+            let key_handler = handler.clone();
             container = container.on_key_down(move |event, window, cx| {
                 match event.keystroke.key.as_str() {
                     "space" | " " | "enter" => {
-                        key_handler(new_checked, window, cx);
+                        key_handler.call(new_checked, window, cx);
                     }
                     _ => {}
                 }
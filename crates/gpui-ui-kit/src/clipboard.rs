@@ -0,0 +1,102 @@
+//! Clipboard utilities
+//!
+//! A single place for components and charts to copy text, tab-separated
+//! data and PNG images to the system clipboard.
+//!
+//! ```rust,ignore
+//! use gpui_ui_kit::clipboard::{ClipboardFormat, CopyToClipboard};
+//!
+//! // Copy the chart as an image, falling back to its data as TSV
+//! chart.copy_to_clipboard(ClipboardFormat::Image, cx);
+//! ```
+
+use gpui::{App, ClipboardItem, Image, ImageFormat};
+
+/// What to put on the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClipboardFormat {
+    /// Text (plain text, or tab-separated data for charts and tables)
+    #[default]
+    Text,
+    /// PNG image, falling back to text when no image is available
+    Image,
+}
+
+/// Something that can be copied to the clipboard
+pub trait CopyToClipboard {
+    /// Text representation (tab-separated values for tabular data)
+    fn clipboard_text(&self) -> Option<String>;
+
+    /// PNG-encoded image representation, if supported
+    fn clipboard_png(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Copy to the clipboard in the requested format
+    ///
+    /// Returns `false` when there was nothing to copy.
+    fn copy_to_clipboard(&self, format: ClipboardFormat, cx: &mut App) -> bool {
+        if format == ClipboardFormat::Image
+            && let Some(png) = self.clipboard_png()
+        {
+            copy_png(png, cx);
+            return true;
+        }
+        match self.clipboard_text() {
+            Some(text) => {
+                copy_text(text, cx);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Copy plain text to the clipboard
+pub fn copy_text(text: impl Into<String>, cx: &mut App) {
+    cx.write_to_clipboard(ClipboardItem::new_string(text.into()));
+}
+
+/// Copy a PNG-encoded image to the clipboard
+pub fn copy_png(png: Vec<u8>, cx: &mut App) {
+    let image = Image::from_bytes(ImageFormat::Png, png);
+    cx.write_to_clipboard(ClipboardItem::new_image(&image));
+}
+
+/// Join rows of fields into tab-separated text
+///
+/// Tabs and line breaks inside fields are replaced by spaces so every row
+/// stays on one line, which is what spreadsheets expect when pasting.
+pub fn to_tsv<R, F>(rows: R) -> String
+where
+    R: IntoIterator,
+    R::Item: IntoIterator<Item = F>,
+    F: AsRef<str>,
+{
+    let mut tsv = String::new();
+    for row in rows {
+        let fields: Vec<String> = row
+            .into_iter()
+            .map(|field| field.as_ref().replace(['\t', '\r', '\n'], " "))
+            .collect();
+        tsv.push_str(&fields.join("\t"));
+        tsv.push('\n');
+    }
+    tsv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_tsv() {
+        let tsv = to_tsv([vec!["x", "y"], vec!["1", "a\tb"]]);
+        assert_eq!(tsv, "x\ty\n1\ta b\n");
+    }
+
+    #[test]
+    fn test_to_tsv_empty() {
+        assert_eq!(to_tsv(Vec::<Vec<String>>::new()), "");
+    }
+}
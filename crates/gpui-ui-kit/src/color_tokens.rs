@@ -31,6 +31,12 @@
 //! - `active`: Slightly darker for pressed/active states
 //! - `muted`: Low opacity version for backgrounds
 //! - `subtle`: Very low opacity for subtle highlights
+//!
+//! # Generating a Palette
+//!
+//! [`ColorPalette::from_accent`] derives every token from a single brand
+//! color. Variants are computed in OKLCH, a perceptual color space, so
+//! lightness steps look even across hues.
 
 use gpui::{Hsla, Rgba, rgb, rgba};
 
@@ -100,6 +106,20 @@ impl ColorToken {
         }
     }
 
+    /// Create a color token whose variants are derived in OKLCH.
+    ///
+    /// Hover and active states move perceptual lightness by a fixed step,
+    /// so they look equally far from the base whatever its hue.
+    pub fn from_base_oklch(base: Rgba) -> Self {
+        Self {
+            base,
+            hover: lighten_oklch(base, 0.06),
+            active: darken_oklch(base, 0.08),
+            muted: with_alpha(base, 0.2),
+            subtle: with_alpha(base, 0.1),
+        }
+    }
+
     /// Create a color token from a hex RGB value (e.g., 0x007acc)
     pub fn from_hex(hex: u32) -> Self {
         Self::from_base(rgb(hex))
@@ -394,6 +414,65 @@ impl ColorPalette {
             borders: BorderColors::light(),
         }
     }
+
+    /// Derive a full palette from a single brand color.
+    ///
+    /// The accent becomes the primary color. Neutrals carry a faint tint of
+    /// its hue, and the status colors share its lightness and chroma so the
+    /// palette reads as one family.
+    pub fn from_accent(accent: Rgba, dark: bool) -> Self {
+        let (_, chroma, hue) = to_oklch(accent);
+        let tint = chroma.min(0.02);
+        let neutral = |l: f32| ColorToken::from_base_oklch(from_oklch(l, tint, hue, 1.0));
+        let status_l = if dark { 0.72 } else { 0.58 };
+        let status_c = chroma.clamp(0.1, 0.18);
+        let status = |h: f32| ColorToken::from_base_oklch(from_oklch(status_l, status_c, h, 1.0));
+
+        let primary = ColorToken::from_base_oklch(accent);
+        let error = status(27.0);
+        let (page, surface, border) = if dark {
+            (0.2, 0.25, 0.35)
+        } else {
+            (0.97, 0.995, 0.87)
+        };
+        let (text_primary, text_secondary, text_muted, text_inverted) = if dark {
+            (0.93, 0.78, 0.6, 0.2)
+        } else {
+            (0.22, 0.4, 0.6, 0.98)
+        };
+
+        Self {
+            semantic: SemanticColors {
+                primary,
+                secondary: ColorToken::from_base_oklch(from_oklch(
+                    status_l,
+                    chroma.min(0.04),
+                    hue,
+                    1.0,
+                )),
+                success: status(145.0),
+                warning: status(75.0),
+                error,
+                info: status(250.0),
+            },
+            backgrounds: BackgroundColors {
+                page: neutral(page),
+                surface: neutral(surface),
+                overlay: ColorToken::from_base_with_alpha(rgb(0x000000), 0.5),
+            },
+            text: TextColors {
+                primary: neutral(text_primary),
+                secondary: neutral(text_secondary),
+                muted: neutral(text_muted),
+                inverted: neutral(text_inverted),
+            },
+            borders: BorderColors {
+                default: neutral(border),
+                focus: primary,
+                error,
+            },
+        }
+    }
 }
 
 /// Helper function to create a muted version of a color
@@ -447,6 +526,108 @@ pub fn desaturate(color: Rgba, amount: f32) -> Rgba {
     saturate(color, -amount)
 }
 
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// OKLab to linear sRGB, components may fall outside 0.0 - 1.0
+fn oklab_to_linear_srgb(l: f32, a: f32, b: f32) -> [f32; 3] {
+    let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
+/// Convert a color to OKLab `(lightness, a, b)`
+pub fn to_oklab(color: Rgba) -> (f32, f32, f32) {
+    let r = srgb_to_linear(color.r);
+    let g = srgb_to_linear(color.g);
+    let b = srgb_to_linear(color.b);
+
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+/// Convert a color to OKLCH `(lightness 0-1, chroma, hue in degrees)`
+pub fn to_oklch(color: Rgba) -> (f32, f32, f32) {
+    let (l, a, b) = to_oklab(color);
+    let c = (a * a + b * b).sqrt();
+    let h = if c < 1e-6 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    };
+    (l, c, h)
+}
+
+/// Create a color from OKLCH values.
+///
+/// Colors outside sRGB keep their lightness and hue and lose chroma until
+/// they fit, instead of being clipped to a different hue.
+pub fn from_oklch(l: f32, c: f32, h: f32, alpha: f32) -> Rgba {
+    const EPS: f32 = 1e-4;
+    let l = l.clamp(0.0, 1.0);
+    let (sin, cos) = h.to_radians().sin_cos();
+    let linear = |c: f32| oklab_to_linear_srgb(l, c * cos, c * sin);
+    let in_gamut = |rgb: [f32; 3]| rgb.iter().all(|v| (-EPS..=1.0 + EPS).contains(v));
+
+    let mut rgb = linear(c.max(0.0));
+    if !in_gamut(rgb) {
+        let (mut lo, mut hi) = (0.0_f32, c.max(0.0));
+        for _ in 0..20 {
+            let mid = (lo + hi) / 2.0;
+            if in_gamut(linear(mid)) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        rgb = linear(lo);
+    }
+
+    let [r, g, b] = rgb.map(|v| linear_to_srgb(v.clamp(0.0, 1.0)));
+    Rgba { r, g, b, a: alpha }
+}
+
+/// Helper function to lighten a color by a perceptual amount (OKLCH)
+pub fn lighten_oklch(color: Rgba, amount: f32) -> Rgba {
+    let (l, c, h) = to_oklch(color);
+    from_oklch(l + amount, c, h, color.a)
+}
+
+/// Helper function to darken a color by a perceptual amount (OKLCH)
+pub fn darken_oklch(color: Rgba, amount: f32) -> Rgba {
+    lighten_oklch(color, -amount)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -526,4 +707,51 @@ mod tests {
         let hsla = Hsla::from(darker_color);
         assert!(hsla.l < 0.5);
     }
+
+    #[test]
+    fn test_oklch_round_trip() {
+        let color = rgb(0x007acc);
+        let (l, c, h) = to_oklch(color);
+        let back = from_oklch(l, c, h, 1.0);
+        assert!((back.r - color.r).abs() < 1e-3);
+        assert!((back.g - color.g).abs() < 1e-3);
+        assert!((back.b - color.b).abs() < 1e-3);
+
+        // Pure red from the OKLab reference values
+        let (l, c, h) = to_oklch(rgb(0xff0000));
+        assert!((l - 0.628).abs() < 1e-3);
+        assert!((c - 0.258).abs() < 1e-3);
+        assert!((h - 29.2).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_lighten_oklch_keeps_hue() {
+        let color = rgb(0x007acc);
+        let (l, _, h) = to_oklch(color);
+        let (lighter_l, _, lighter_h) = to_oklch(lighten_oklch(color, 0.1));
+        assert!((lighter_l - (l + 0.1)).abs() < 0.01);
+        assert!((lighter_h - h).abs() < 2.0);
+        assert!(to_oklch(darken_oklch(color, 0.1)).0 < l);
+    }
+
+    #[test]
+    fn test_palette_from_accent() {
+        let accent = rgb(0x7c3aed);
+        let dark = ColorPalette::from_accent(accent, true);
+        assert_eq!(dark.semantic.primary.base, accent);
+        assert_eq!(dark.borders.focus, dark.semantic.primary);
+
+        let lightness = |color: Rgba| to_oklch(color).0;
+        assert!(lightness(dark.text.primary.base) > lightness(dark.backgrounds.page.base) + 0.5);
+        assert!(lightness(dark.semantic.primary.hover) > lightness(accent));
+
+        let light = ColorPalette::from_accent(accent, false);
+        assert!(lightness(light.text.primary.base) < lightness(light.backgrounds.page.base) - 0.5);
+
+        // Status colors keep their conventional hues
+        let (_, _, success_h) = to_oklch(dark.semantic.success.base);
+        let (_, _, error_h) = to_oklch(dark.semantic.error.base);
+        assert!((success_h - 145.0).abs() < 3.0);
+        assert!((error_h - 27.0).abs() < 3.0);
+    }
 }
@@ -14,6 +14,13 @@
 //!     .footer(div().child("Footer buttons"))
 //! ```
 //!
+//! ## Focus and scrolling
+//!
+//! While a dialog is shown, focus moves into it and scrolling the view
+//! behind it is blocked. When it is dismissed (close button, backdrop click
+//! or Escape), focus returns to the element focused before it opened.
+//! Opt out with [`Dialog::return_focus`] and [`Dialog::lock_scroll`].
+//!
 //! ## Dynamic content with theme access
 //! ```ignore
 //! Dialog::new("my-dialog")
@@ -27,9 +34,12 @@
 //! ```
 
 use crate::ComponentTheme;
-use crate::theme::ThemeExt;
+use crate::callback::Callback;
+use crate::state_registry::ElementStateRegistry;
+use crate::theme::{Elevation, ElevationTokens, ThemeExt};
 use gpui::prelude::*;
 use gpui::*;
+use std::cell::RefCell;
 use std::rc::Rc;
 
 /// Factory function type for creating elements with dialog theme access
@@ -62,6 +72,12 @@ pub struct DialogTheme {
     /// Close button hover background
     #[theme(default = 0x3a3a3a, from = surface_hover)]
     pub close_hover_bg: Rgba,
+    /// Dialog shadow
+    #[theme(
+        default_expr = "ElevationTokens::default().shadow(Elevation::Modal)",
+        from_expr = "theme.elevation.shadow(Elevation::Modal)"
+    )]
+    pub shadow: Vec<BoxShadow>,
 }
 
 /// Dialog size variants
@@ -92,6 +108,18 @@ impl DialogSize {
     }
 }
 
+/// Focus to restore when a dialog is dismissed
+struct FocusReturn {
+    previous: Option<FocusHandle>,
+    focused: bool,
+}
+
+// Focus state of the shown dialogs, kept alive by their listeners
+thread_local! {
+    static DIALOG_FOCUS: RefCell<ElementStateRegistry<FocusReturn>> =
+        RefCell::new(ElementStateRegistry::default());
+}
+
 /// A modal dialog component
 pub struct Dialog {
     id: ElementId,
@@ -103,7 +131,12 @@ pub struct Dialog {
     footer_factory: Option<DialogSlotFactory>,
     show_close_button: bool,
     close_on_backdrop: bool,
-    on_close: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    return_focus: bool,
+    lock_scroll: bool,
+    focus_handle: Option<FocusHandle>,
+    focus_return: Option<Rc<RefCell<FocusReturn>>>,
+    on_close: Option<Callback>,
+    on_submit: Option<Callback>,
 }
 
 impl Dialog {
@@ -119,7 +152,12 @@ impl Dialog {
             footer_factory: None,
             show_close_button: true,
             close_on_backdrop: true,
+            return_focus: true,
+            lock_scroll: true,
+            focus_handle: None,
+            focus_return: None,
             on_close: None,
+            on_submit: None,
         }
     }
 
@@ -207,13 +245,42 @@ impl Dialog {
         self
     }
 
+    /// Move focus into the dialog when shown and back to the previously
+    /// focused element when dismissed (default: true)
+    pub fn return_focus(mut self, return_focus: bool) -> Self {
+        self.return_focus = return_focus;
+        self
+    }
+
+    /// Block scrolling and clicks on the view behind the dialog (default: true)
+    pub fn lock_scroll(mut self, lock: bool) -> Self {
+        self.lock_scroll = lock;
+        self
+    }
+
+    /// Set the focus handle tracked by the dialog
+    pub fn focus_handle(mut self, handle: FocusHandle) -> Self {
+        self.focus_handle = Some(handle);
+        self
+    }
+
     /// Set the close handler
     pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_close = Some(Box::new(handler));
+        self.on_close = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Set the handler of the Enter key, pressed while focus is in the
+    /// dialog and not taken by one of its controls
+    pub fn on_submit(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_submit = Some(Callback::from_fn(handler));
         self
     }
 
     /// Build the dialog into elements with theme
+    ///
+    /// Focus only moves into the dialog and back when it is rendered as an
+    /// element, which has access to the window.
     pub fn build_with_theme(self, theme: &DialogTheme) -> Div {
         let width = self.size.width();
         let close_on_backdrop = self.close_on_backdrop;
@@ -221,9 +288,21 @@ impl Dialog {
         let close_btn_id = self.id.clone();
         let content_id = self.id.clone();
 
-        // Convert Box to Rc for shared ownership between backdrop and close button
-        let on_close: Option<Rc<dyn Fn(&mut Window, &mut App)>> =
-            self.on_close.map(|f| Rc::from(f));
+        // Shared between backdrop, close button and Escape key, restoring
+        // focus before closing
+        let focus_return = self.focus_return;
+        let on_close = self.on_close.map(|handler| {
+            let focus_return = focus_return.clone();
+            Callback::from_fn(move |window, cx| {
+                if let Some(previous) = focus_return
+                    .as_ref()
+                    .and_then(|state| state.borrow().previous.clone())
+                {
+                    previous.focus(window, cx);
+                }
+                handler.invoke(window, cx);
+            })
+        });
 
         // Backdrop
         let mut backdrop = div()
@@ -232,14 +311,19 @@ impl Dialog {
             .flex()
             .items_center()
             .justify_center()
-            .bg(theme.backdrop)
-            // Capture scroll events to prevent propagation to underlying view
-            .on_scroll_wheel(|_event, _window, _cx| {});
+            .bg(theme.backdrop);
+
+        if self.lock_scroll {
+            // Keep the view behind from being hovered, scrolled or clicked
+            backdrop = backdrop
+                .occlude()
+                .on_scroll_wheel(|_event, _window, cx| cx.stop_propagation());
+        }
 
         // Handle backdrop click
         if close_on_backdrop && let Some(handler) = on_close.clone() {
             backdrop = backdrop.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
-                handler(window, cx);
+                handler.invoke(window, cx);
             });
         }
 
@@ -252,7 +336,7 @@ impl Dialog {
             .border_1()
             .border_color(theme.border)
             .rounded_lg()
-            .shadow_lg()
+            .shadow(theme.shadow.clone())
             .overflow_hidden()
             .flex()
             .flex_col()
@@ -261,6 +345,27 @@ impl Dialog {
                 // Consume the event
             });
 
+        if let Some(handle) = &self.focus_handle {
+            dialog = dialog.track_focus(handle);
+        }
+        if on_close.is_some() || focus_return.is_some() || self.on_submit.is_some() {
+            let handler = on_close.clone();
+            let on_submit = self.on_submit;
+            dialog = dialog.on_key_down(move |event, window, cx| {
+                // Capturing the focus state keeps it alive while the dialog is shown
+                let _ = &focus_return;
+                let action = match event.keystroke.key.as_str() {
+                    "escape" => handler.as_ref(),
+                    "enter" => on_submit.as_ref(),
+                    _ => None,
+                };
+                if let Some(action) = action {
+                    action.invoke(window, cx);
+                    cx.stop_propagation();
+                }
+            });
+        }
+
         // Header with title and close button
         if self.title.is_some() || self.show_close_button {
             let mut header = div()
@@ -300,7 +405,7 @@ impl Dialog {
                         .text_color(close_color)
                         .hover(move |s| s.bg(close_hover_bg).text_color(close_hover))
                         .on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                            handler(window, cx);
+                            handler.invoke(window, cx);
                         })
                         .child("×"),
                 );
@@ -341,7 +446,33 @@ impl Dialog {
 }
 
 impl RenderOnce for Dialog {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if self.return_focus {
+            let (handle, focus_return) = DIALOG_FOCUS.with_borrow_mut(|registry| {
+                let handle = match &self.focus_handle {
+                    Some(handle) => handle.clone(),
+                    None => registry.focus_handle(&self.id, cx),
+                };
+                let focus_return = registry.state(&self.id, || FocusReturn {
+                    previous: window.focused(cx),
+                    focused: false,
+                });
+                (handle, focus_return)
+            });
+
+            // Focus the dialog on its first render only, so focus can move
+            // between its controls afterwards
+            let mut state = focus_return.borrow_mut();
+            if !state.focused {
+                state.focused = true;
+                handle.focus(window, cx);
+            }
+            drop(state);
+
+            self.focus_handle = Some(handle);
+            self.focus_return = Some(focus_return);
+        }
+
         let global_theme = cx.theme();
         let dialog_theme = DialogTheme::from(&global_theme);
         self.build_with_theme(&dialog_theme)
@@ -355,3 +486,193 @@ impl IntoElement for Dialog {
         gpui::Component::new(self)
     }
 }
+
+/// Events emitted by [`DialogView`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogEvent {
+    /// The user closed the dialog (close button or backdrop click)
+    Dismissed,
+}
+
+/// A [`Dialog`] that manages its own open state
+///
+/// The body and footer are views, so they keep their own state while the
+/// dialog is re-rendered. Nothing is rendered while the dialog is closed.
+/// In controlled mode dismissing only emits [`DialogEvent::Dismissed`] and
+/// the parent decides whether to [`close`](DialogView::close) it, e.g.
+/// after confirming unsaved changes.
+///
+/// ```ignore
+/// let body = cx.new(|_| SettingsForm::default());
+/// let dialog = cx.new(|_| DialogView::new("settings").title("Settings").content(body));
+/// cx.subscribe(&dialog, |this, _, DialogEvent::Dismissed, cx| this.save(cx)).detach();
+/// dialog.update(cx, |dialog, cx| dialog.open(cx));
+/// ```
+pub struct DialogView {
+    id: ElementId,
+    title: Option<SharedString>,
+    size: DialogSize,
+    content: Option<AnyView>,
+    footer: Option<AnyView>,
+    show_close_button: bool,
+    close_on_backdrop: bool,
+    return_focus: bool,
+    lock_scroll: bool,
+    is_open: bool,
+    controlled: bool,
+    focus_handle: Option<FocusHandle>,
+    previous_focus: Option<FocusHandle>,
+    // Open state of the last render, to detect opening and closing
+    was_open: bool,
+}
+
+impl EventEmitter<DialogEvent> for DialogView {}
+
+impl DialogView {
+    /// Create a new, closed dialog view
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            title: None,
+            size: DialogSize::default(),
+            content: None,
+            footer: None,
+            show_close_button: true,
+            close_on_backdrop: true,
+            return_focus: true,
+            lock_scroll: true,
+            is_open: false,
+            controlled: false,
+            focus_handle: None,
+            previous_focus: None,
+            was_open: false,
+        }
+    }
+
+    /// Set the dialog title
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the dialog size
+    pub fn size(mut self, size: DialogSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the body view
+    pub fn content(mut self, view: impl Into<AnyView>) -> Self {
+        self.content = Some(view.into());
+        self
+    }
+
+    /// Set the footer view
+    pub fn footer(mut self, view: impl Into<AnyView>) -> Self {
+        self.footer = Some(view.into());
+        self
+    }
+
+    /// Show or hide the close button
+    pub fn show_close_button(mut self, show: bool) -> Self {
+        self.show_close_button = show;
+        self
+    }
+
+    /// Close dialog when clicking backdrop
+    pub fn close_on_backdrop(mut self, close: bool) -> Self {
+        self.close_on_backdrop = close;
+        self
+    }
+
+    /// Move focus into the dialog when opened and back to the previously
+    /// focused element when closed (default: true)
+    pub fn return_focus(mut self, return_focus: bool) -> Self {
+        self.return_focus = return_focus;
+        self
+    }
+
+    /// Block scrolling and clicks on the view behind the dialog (default: true)
+    pub fn lock_scroll(mut self, lock: bool) -> Self {
+        self.lock_scroll = lock;
+        self
+    }
+
+    /// Only emit events, leaving state changes to the parent
+    pub fn controlled(mut self, controlled: bool) -> Self {
+        self.controlled = controlled;
+        self
+    }
+
+    /// Whether the dialog is shown
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Show the dialog
+    pub fn open(&mut self, cx: &mut Context<Self>) {
+        self.is_open = true;
+        cx.notify();
+    }
+
+    /// Hide the dialog without emitting [`DialogEvent::Dismissed`]
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.is_open = false;
+        cx.notify();
+    }
+
+    fn dismiss(&mut self, cx: &mut Context<Self>) {
+        if self.is_open {
+            if !self.controlled {
+                self.is_open = false;
+                cx.notify();
+            }
+            cx.emit(DialogEvent::Dismissed);
+        }
+    }
+}
+
+impl Render for DialogView {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Focus moves here rather than in open/close, which have no window,
+        // so programmatic closing returns focus too
+        let was_open = std::mem::replace(&mut self.was_open, self.is_open);
+        if !self.is_open {
+            if was_open && let Some(previous) = self.previous_focus.take() {
+                previous.focus(window, cx);
+            }
+            return div().into_any_element();
+        }
+
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        if !was_open && self.return_focus {
+            self.previous_focus = window.focused(cx);
+            focus_handle.focus(window, cx);
+        }
+
+        let on_close = cx.listener(|this, _: &(), _window, cx| this.dismiss(cx));
+        let mut dialog = Dialog::new(self.id.clone())
+            .size(self.size)
+            .show_close_button(self.show_close_button)
+            .close_on_backdrop(self.close_on_backdrop)
+            .return_focus(false)
+            .lock_scroll(self.lock_scroll)
+            .focus_handle(focus_handle)
+            .on_close(move |window, cx| on_close(&(), window, cx));
+        if let Some(title) = &self.title {
+            dialog = dialog.title(title.clone());
+        }
+        if let Some(content) = &self.content {
+            dialog = dialog.content(content.clone());
+        }
+        if let Some(footer) = &self.footer {
+            dialog = dialog.footer(footer.clone());
+        }
+
+        let theme = DialogTheme::from(&cx.theme());
+        dialog.build_with_theme(&theme).into_any_element()
+    }
+}
@@ -0,0 +1,422 @@
+//! Confirmation and prompt dialogs
+//!
+//! Ready-made [`Dialog`]s for the two most common questions: [`confirm`]
+//! asks for a yes/no answer, [`prompt`] asks for a line of text. They lay
+//! out the message and buttons, order the buttons as the platform does
+//! (see [`ButtonOrder`]), take the focus while shown and handle Enter and
+//! Escape. The answer is delivered to `on_result`; the app renders the
+//! dialog while the question is pending and stops once answered:
+//!
+//! ```ignore
+//! if self.confirm_delete {
+//!     root = root.child(
+//!         dialogs::confirm("Delete preset?", "This cannot be undone.")
+//!             .confirm_label("Delete")
+//!             .destructive(true)
+//!             .on_result(cx.listener(|this, confirmed: &bool, _, cx| {
+//!                 this.confirm_delete = false;
+//!                 if *confirmed {
+//!                     this.delete_preset(cx);
+//!                 }
+//!                 cx.notify();
+//!             })),
+//!     );
+//! }
+//!
+//! dialogs::prompt("Rename preset", "Name", &self.preset_name)
+//!     .on_result(cx.listener(|this, name: &Option<String>, _, cx| { ... }))
+//! ```
+//!
+//! Enter confirms and Escape, the close button or a click outside cancel.
+
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::callback::Callback;
+use crate::dialog::{Dialog, DialogSize};
+use crate::input::Input;
+use crate::state_registry::ElementStateRegistry;
+use crate::theme::ThemeExt;
+use crate::title_bar::TitleBarPlatform;
+use gpui::prelude::*;
+use gpui::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Focus and text of the shown dialogs, kept alive by their listeners
+thread_local! {
+    static DIALOG_STATES: RefCell<ElementStateRegistry<DialogState>> =
+        RefCell::new(ElementStateRegistry::default());
+}
+
+/// State of a shown dialog
+struct DialogState {
+    /// Element to focus again once answered
+    previous_focus: Option<FocusHandle>,
+    /// Whether the dialog took the focus on its first render
+    focused: bool,
+    /// Text typed in a prompt
+    text: String,
+}
+
+/// Order of the confirm and cancel buttons, right-aligned in the footer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonOrder {
+    /// Cancel, then confirm (macOS and Linux desktops)
+    ConfirmLast,
+    /// Confirm, then cancel (Windows)
+    ConfirmFirst,
+}
+
+impl ButtonOrder {
+    /// Order used on the platform the app is compiled for
+    pub fn current() -> Self {
+        match TitleBarPlatform::current() {
+            TitleBarPlatform::Windows => ButtonOrder::ConfirmFirst,
+            TitleBarPlatform::MacOS | TitleBarPlatform::Linux => ButtonOrder::ConfirmLast,
+        }
+    }
+
+    /// Arrange the two buttons, left to right
+    pub fn arrange<T>(self, confirm: T, cancel: T) -> [T; 2] {
+        match self {
+            ButtonOrder::ConfirmLast => [cancel, confirm],
+            ButtonOrder::ConfirmFirst => [confirm, cancel],
+        }
+    }
+}
+
+impl Default for ButtonOrder {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+/// Ask a yes/no question
+pub fn confirm(title: impl Into<SharedString>, message: impl Into<SharedString>) -> ConfirmDialog {
+    let title = title.into();
+    ConfirmDialog {
+        id: ElementId::Name(format!("confirm-{title}").into()),
+        title,
+        message: message.into(),
+        confirm_label: "OK".into(),
+        cancel_label: "Cancel".into(),
+        destructive: false,
+        button_order: ButtonOrder::current(),
+        on_result: None,
+    }
+}
+
+/// Ask for a line of text, starting from `default`
+pub fn prompt(
+    title: impl Into<SharedString>,
+    label: impl Into<SharedString>,
+    default: impl Into<SharedString>,
+) -> PromptDialog {
+    let title = title.into();
+    PromptDialog {
+        id: ElementId::Name(format!("prompt-{title}").into()),
+        title,
+        label: label.into(),
+        default: default.into(),
+        placeholder: None,
+        confirm_label: "OK".into(),
+        cancel_label: "Cancel".into(),
+        button_order: ButtonOrder::current(),
+        on_result: None,
+    }
+}
+
+/// Confirmation dialog, created with [`confirm`]
+#[derive(IntoElement)]
+pub struct ConfirmDialog {
+    id: ElementId,
+    title: SharedString,
+    message: SharedString,
+    confirm_label: SharedString,
+    cancel_label: SharedString,
+    destructive: bool,
+    button_order: ButtonOrder,
+    on_result: Option<Callback<bool>>,
+}
+
+impl ConfirmDialog {
+    /// Set the element ID (derived from the title by default)
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the confirm button label (default: "OK")
+    pub fn confirm_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    /// Set the cancel button label (default: "Cancel")
+    pub fn cancel_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Style the confirm button as a destructive action
+    pub fn destructive(mut self, destructive: bool) -> Self {
+        self.destructive = destructive;
+        self
+    }
+
+    /// Override the platform button order
+    pub fn button_order(mut self, order: ButtonOrder) -> Self {
+        self.button_order = order;
+        self
+    }
+
+    /// Called with `true` when confirmed and `false` when cancelled
+    pub fn on_result(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_result = Some(Callback::new(move |value: bool, window, cx| {
+            handler(&value, window, cx)
+        }));
+        self
+    }
+}
+
+impl RenderOnce for ConfirmDialog {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (focus_handle, state) = take_focus(&self.id, String::new(), window, cx);
+        let finish = finisher(state, self.on_result);
+
+        let confirm_variant = if self.destructive {
+            ButtonVariant::Destructive
+        } else {
+            ButtonVariant::Primary
+        };
+        let footer = footer(
+            &self.id,
+            self.button_order,
+            (self.confirm_label, confirm_variant, {
+                let finish = finish.clone();
+                Callback::from_fn(move |window, cx| finish.call(true, window, cx))
+            }),
+            (self.cancel_label, {
+                let finish = finish.clone();
+                Callback::from_fn(move |window, cx| finish.call(false, window, cx))
+            }),
+        );
+
+        let text_color = cx.theme().text_secondary;
+        let on_submit = finish.clone();
+        Dialog::new(self.id)
+            .title(self.title)
+            .size(DialogSize::Sm)
+            .return_focus(false)
+            .focus_handle(focus_handle)
+            .on_close(move |window, cx| finish.call(false, window, cx))
+            .on_submit(move |window, cx| on_submit.call(true, window, cx))
+            .content(div().text_sm().text_color(text_color).child(self.message))
+            .footer(footer)
+    }
+}
+
+/// Text prompt dialog, created with [`prompt`]
+#[derive(IntoElement)]
+pub struct PromptDialog {
+    id: ElementId,
+    title: SharedString,
+    label: SharedString,
+    default: SharedString,
+    placeholder: Option<SharedString>,
+    confirm_label: SharedString,
+    cancel_label: SharedString,
+    button_order: ButtonOrder,
+    on_result: Option<Callback<Option<String>>>,
+}
+
+impl PromptDialog {
+    /// Set the element ID (derived from the title by default)
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the input placeholder
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the confirm button label (default: "OK")
+    pub fn confirm_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    /// Set the cancel button label (default: "Cancel")
+    pub fn cancel_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Override the platform button order
+    pub fn button_order(mut self, order: ButtonOrder) -> Self {
+        self.button_order = order;
+        self
+    }
+
+    /// Called with the text when confirmed and `None` when cancelled
+    pub fn on_result(
+        mut self,
+        handler: impl Fn(&Option<String>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_result = Some(Callback::new(move |value: Option<String>, window, cx| {
+            handler(&value, window, cx)
+        }));
+        self
+    }
+}
+
+impl RenderOnce for PromptDialog {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        // The focus goes to the input, which handles Enter and Escape itself
+        let (input_focus, state) = take_focus(&self.id, self.default.to_string(), window, cx);
+        let finish = finisher(state.clone(), self.on_result);
+
+        let text = state.borrow().text.clone();
+        let typed = state.clone();
+        let mut input = Input::new(child_id(&self.id, "input"))
+            .label(self.label)
+            .value(text)
+            .focus_handle(input_focus)
+            .on_text_change(move |text, _window, _cx| typed.borrow_mut().text = text)
+            .on_edit_end({
+                let finish = finish.clone();
+                move |text, window, cx| finish.call(text, window, cx)
+            });
+        if let Some(placeholder) = self.placeholder {
+            input = input.placeholder(placeholder);
+        }
+
+        let footer = footer(
+            &self.id,
+            self.button_order,
+            (self.confirm_label, ButtonVariant::Primary, {
+                let finish = finish.clone();
+                Callback::from_fn(move |window, cx| {
+                    let text = state.borrow().text.clone();
+                    finish.call(Some(text), window, cx);
+                })
+            }),
+            (self.cancel_label, {
+                let finish = finish.clone();
+                Callback::from_fn(move |window, cx| finish.call(None, window, cx))
+            }),
+        );
+
+        Dialog::new(self.id)
+            .title(self.title)
+            .size(DialogSize::Sm)
+            .return_focus(false)
+            .on_close(move |window, cx| finish.call(None, window, cx))
+            .content(input)
+            .footer(footer)
+    }
+}
+
+/// Focus handle and state of a dialog, focusing it on its first render
+fn take_focus(
+    id: &ElementId,
+    text: String,
+    window: &mut Window,
+    cx: &mut App,
+) -> (FocusHandle, Rc<RefCell<DialogState>>) {
+    let (handle, state) = DIALOG_STATES.with_borrow_mut(|registry| {
+        let handle = registry.focus_handle(id, cx);
+        let state = registry.state(id, || DialogState {
+            previous_focus: window.focused(cx),
+            focused: false,
+            text,
+        });
+        (handle, state)
+    });
+    let mut current = state.borrow_mut();
+    if !current.focused {
+        current.focused = true;
+        handle.focus(window, cx);
+    }
+    drop(current);
+    (handle, state)
+}
+
+/// Result handler that gives the focus back before reporting the answer
+fn finisher<T: 'static>(
+    state: Rc<RefCell<DialogState>>,
+    on_result: Option<Callback<T>>,
+) -> Callback<T> {
+    Callback::new(move |value, window, cx| {
+        let previous = state.borrow_mut().previous_focus.take();
+        if let Some(previous) = previous {
+            previous.focus(window, cx);
+        }
+        if let Some(on_result) = &on_result {
+            on_result.call(value, window, cx);
+        }
+    })
+}
+
+/// Footer with the confirm and cancel buttons in platform order
+fn footer(
+    id: &ElementId,
+    order: ButtonOrder,
+    (confirm_label, confirm_variant, on_confirm): (SharedString, ButtonVariant, Callback),
+    (cancel_label, on_cancel): (SharedString, Callback),
+) -> Div {
+    let confirm = Button::new(child_id(id, "confirm"), confirm_label)
+        .variant(confirm_variant)
+        .size(ButtonSize::Sm)
+        .on_click(move |window, cx| on_confirm.invoke(window, cx));
+    let cancel = Button::new(child_id(id, "cancel"), cancel_label)
+        .variant(ButtonVariant::Secondary)
+        .size(ButtonSize::Sm)
+        .on_click(move |window, cx| on_cancel.invoke(window, cx));
+    div()
+        .flex()
+        .justify_end()
+        .gap_2()
+        .children(order.arrange(confirm, cancel))
+}
+
+fn child_id(id: &ElementId, suffix: &str) -> ElementId {
+    ElementId::Name(SharedString::from(format!("{id}-{suffix}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_button_order() {
+        assert_eq!(
+            ButtonOrder::ConfirmLast.arrange("ok", "cancel"),
+            ["cancel", "ok"]
+        );
+        assert_eq!(
+            ButtonOrder::ConfirmFirst.arrange("ok", "cancel"),
+            ["ok", "cancel"]
+        );
+        let expected = if cfg!(target_os = "windows") {
+            ButtonOrder::ConfirmFirst
+        } else {
+            ButtonOrder::ConfirmLast
+        };
+        assert_eq!(ButtonOrder::default(), expected);
+    }
+
+    #[test]
+    fn test_default_labels_and_ids() {
+        let dialog = confirm("Delete?", "Gone for good").confirm_label("Delete");
+        assert_eq!(dialog.confirm_label.as_ref(), "Delete");
+        assert_eq!(dialog.cancel_label.as_ref(), "Cancel");
+        assert_eq!(dialog.id, ElementId::Name("confirm-Delete?".into()));
+
+        let dialog = prompt("Rename", "Name", "Preset 1").id("rename");
+        assert_eq!(dialog.default.as_ref(), "Preset 1");
+        assert_eq!(dialog.id, ElementId::Name("rename".into()));
+    }
+}
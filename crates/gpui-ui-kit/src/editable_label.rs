@@ -0,0 +1,432 @@
+//! Click-to-edit text
+//!
+//! [`EditableLabel`] shows its value as plain text until it is clicked (or
+//! double-clicked, see [`EditTrigger`]), then swaps in an [`Input`] of the
+//! same size. Enter or moving the focus away commits the edit, Escape
+//! cancels it. A validator can reject the text: the error is shown under
+//! the input and editing continues, or the edit is dropped if the focus
+//! moved away.
+//!
+//! ```ignore
+//! EditableLabel::new(("preset-name", index), preset.name.clone())
+//!     .validate(|name| {
+//!         if name.trim().is_empty() {
+//!             Err("Name cannot be empty".into())
+//!         } else {
+//!             Ok(())
+//!         }
+//!     })
+//!     .on_commit(cx.listener(move |this, name: &str, _, cx| {
+//!         this.rename_preset(index, name, cx);
+//!     }))
+//! ```
+//!
+//! The editing state lives in a per-thread registry keyed by element ID,
+//! like [`Input`]'s, and is freed once the label stops being rendered.
+
+use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::input::{Input, InputSize};
+use crate::state_registry::ElementStateRegistry;
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// Editing state of the rendered labels, kept alive by their listeners
+thread_local! {
+    static LABEL_STATES: RefCell<ElementStateRegistry<LabelState>> =
+        RefCell::new(ElementStateRegistry::default());
+}
+
+/// Text validator, returning the message to show for rejected text
+type Validator = Rc<dyn Fn(&str) -> Result<(), SharedString>>;
+
+/// Gesture that switches an [`EditableLabel`] to editing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EditTrigger {
+    /// A single click
+    Click,
+    /// A double click, leaving single clicks to the parent (e.g. selection)
+    #[default]
+    DoubleClick,
+}
+
+impl EditTrigger {
+    fn click_count(self) -> usize {
+        match self {
+            EditTrigger::Click => 1,
+            EditTrigger::DoubleClick => 2,
+        }
+    }
+}
+
+/// Theme colors for editable labels
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct EditableLabelTheme {
+    /// Text color
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub text: Rgba,
+    /// Placeholder shown for an empty value
+    #[theme(default = 0x666666ff, from = text_muted)]
+    pub placeholder: Rgba,
+    /// Background while hovered, hinting that the text is editable
+    #[theme(default = 0x3a3a3aff, from = surface_hover)]
+    pub hover_bg: Rgba,
+}
+
+/// Editing state of one label
+#[derive(Default)]
+struct LabelState {
+    editing: bool,
+    /// Text typed so far
+    draft: String,
+    /// Validation error of the last commit attempt
+    error: Option<SharedString>,
+    /// Commits the edit when the input loses focus
+    focus_out: Option<Subscription>,
+}
+
+/// Outcome of committing a draft
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Commit {
+    /// Same text as the value: nothing to report
+    Unchanged,
+    /// New text to report
+    Changed(String),
+    /// Text rejected by the validator
+    Invalid(SharedString),
+}
+
+impl Commit {
+    fn resolve(value: &str, draft: &str, validate: Option<&Validator>) -> Self {
+        if draft == value {
+            return Commit::Unchanged;
+        }
+        match validate.map(|validate| validate(draft)) {
+            Some(Err(message)) => Commit::Invalid(message),
+            _ => Commit::Changed(draft.to_string()),
+        }
+    }
+}
+
+/// Text that turns into an input when clicked
+#[derive(IntoElement)]
+pub struct EditableLabel {
+    id: ElementId,
+    value: SharedString,
+    placeholder: Option<SharedString>,
+    trigger: EditTrigger,
+    size: InputSize,
+    disabled: bool,
+    theme: Option<EditableLabelTheme>,
+    validate: Option<Validator>,
+    on_commit: Option<Callback<String>>,
+    on_cancel: Option<Callback>,
+}
+
+impl EditableLabel {
+    /// Create a label showing `value`
+    pub fn new(id: impl Into<ElementId>, value: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            value: value.into(),
+            placeholder: None,
+            trigger: EditTrigger::default(),
+            size: InputSize::default(),
+            disabled: false,
+            theme: None,
+            validate: None,
+            on_commit: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Set the text shown while the value is empty
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the gesture that starts editing (default: double click)
+    pub fn trigger(mut self, trigger: EditTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Set the size, shared by the text and the input
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Show the value without allowing edits
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the label theme
+    pub fn theme(mut self, theme: EditableLabelTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Check the text before committing it
+    pub fn validate(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), SharedString> + 'static,
+    ) -> Self {
+        self.validate = Some(Rc::new(validator));
+        self
+    }
+
+    /// Called with the new text when an edit is committed
+    ///
+    /// Not called when the text is unchanged.
+    pub fn on_commit(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.on_commit = Some(Callback::new(move |text: String, window, cx| {
+            handler(&text, window, cx)
+        }));
+        self
+    }
+
+    /// Called when an edit is cancelled or dropped
+    pub fn on_cancel(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_cancel = Some(Callback::from_fn(handler));
+        self
+    }
+}
+
+/// Handlers shared by the listeners of one label
+#[derive(Clone)]
+struct Handlers {
+    value: SharedString,
+    validate: Option<Validator>,
+    on_commit: Option<Callback<String>>,
+    on_cancel: Option<Callback>,
+}
+
+impl Handlers {
+    /// Commit the draft, or keep editing if it is invalid and `keep_invalid`
+    fn commit(
+        &self,
+        state: &RefCell<LabelState>,
+        focus_handle: &FocusHandle,
+        keep_invalid: bool,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let mut current = state.borrow_mut();
+        if !current.editing {
+            return;
+        }
+        match Commit::resolve(&self.value, &current.draft, self.validate.as_ref()) {
+            Commit::Invalid(message) if keep_invalid => {
+                current.error = Some(message);
+                drop(current);
+                focus_handle.focus(window, cx);
+                window.refresh();
+            }
+            Commit::Invalid(_) => {
+                drop(current);
+                self.cancel(state, window, cx);
+            }
+            Commit::Unchanged => {
+                stop_editing(&mut current);
+                drop(current);
+                window.refresh();
+            }
+            Commit::Changed(text) => {
+                stop_editing(&mut current);
+                drop(current);
+                window.refresh();
+                if let Some(on_commit) = &self.on_commit {
+                    on_commit.call(text, window, cx);
+                }
+            }
+        }
+    }
+
+    /// Drop the draft and show the value again
+    fn cancel(&self, state: &RefCell<LabelState>, window: &mut Window, cx: &mut App) {
+        let mut current = state.borrow_mut();
+        if !current.editing {
+            return;
+        }
+        stop_editing(&mut current);
+        drop(current);
+        window.refresh();
+        if let Some(on_cancel) = &self.on_cancel {
+            on_cancel.invoke(window, cx);
+        }
+    }
+}
+
+fn stop_editing(state: &mut LabelState) {
+    state.editing = false;
+    state.error = None;
+    state.focus_out = None;
+}
+
+impl RenderOnce for EditableLabel {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .unwrap_or_else(|| EditableLabelTheme::from(&global_theme));
+        let (focus_handle, state) = LABEL_STATES.with_borrow_mut(|registry| {
+            (
+                registry.focus_handle(&self.id, cx),
+                registry.state(&self.id, LabelState::default),
+            )
+        });
+        let handlers = Handlers {
+            value: self.value.clone(),
+            validate: self.validate,
+            on_commit: self.on_commit,
+            on_cancel: self.on_cancel,
+        };
+
+        let (editing, draft, error) = {
+            let current = state.borrow();
+            (
+                current.editing,
+                current.draft.clone(),
+                current.error.clone(),
+            )
+        };
+        if editing && !self.disabled {
+            let typed = state.clone();
+            let ended = state.clone();
+            let focus_for_end = focus_handle.clone();
+            let mut input = Input::new(ElementId::Name(format!("{}-input", self.id).into()))
+                .value(draft)
+                .size(self.size)
+                .focus_handle(focus_handle)
+                .on_text_change(move |text, _window, _cx| typed.borrow_mut().draft = text)
+                .on_edit_end(move |text, window, cx| match text {
+                    Some(text) => {
+                        ended.borrow_mut().draft = text;
+                        handlers.commit(&ended, &focus_for_end, true, window, cx);
+                    }
+                    None => handlers.cancel(&ended, window, cx),
+                });
+            if let Some(error) = error {
+                input = input.error(error);
+            }
+            if let Some(placeholder) = self.placeholder {
+                input = input.placeholder(placeholder);
+            }
+            return input.into_any_element();
+        }
+
+        let size = crate::ComponentSize::from(self.size);
+        let (text, color) = match self.placeholder {
+            Some(placeholder) if self.value.is_empty() => (placeholder, theme.placeholder),
+            _ => (self.value.clone(), theme.text),
+        };
+        let mut label = div()
+            .id(self.id)
+            .flex()
+            .items_center()
+            .h(size.control_height())
+            .px(size.padding_x())
+            .rounded_md()
+            .text_color(color)
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .text_ellipsis()
+            .child(text);
+        label = size.apply_text_size(label);
+
+        if !self.disabled {
+            let required_clicks = self.trigger.click_count();
+            label = label
+                .cursor_text()
+                .hover(|style| style.bg(theme.hover_bg))
+                .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                    if event.click_count != required_clicks {
+                        return;
+                    }
+                    cx.stop_propagation();
+                    start_editing(&state, &focus_handle, handlers.clone(), window, cx);
+                });
+        }
+        label.into_any_element()
+    }
+}
+
+/// Switch to the input, focused, committing when the focus moves away
+fn start_editing(
+    state: &Rc<RefCell<LabelState>>,
+    focus_handle: &FocusHandle,
+    handlers: Handlers,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let value = handlers.value.to_string();
+    // Weak, as the subscription is stored in the state itself
+    let weak: Weak<RefCell<LabelState>> = Rc::downgrade(state);
+    let handle = focus_handle.clone();
+    let focus_out = window.on_focus_out(focus_handle, cx, move |_event, window, cx| {
+        // Refocused after a rejected commit
+        if handle.is_focused(window) {
+            return;
+        }
+        if let Some(state) = weak.upgrade() {
+            handlers.commit(&state, &handle, false, window, cx);
+        }
+    });
+
+    let mut current = state.borrow_mut();
+    current.editing = true;
+    current.draft = value;
+    current.error = None;
+    current.focus_out = Some(focus_out);
+    drop(current);
+    focus_handle.focus(window, cx);
+    window.refresh();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_resolution() {
+        let not_empty: Validator = Rc::new(|text: &str| {
+            if text.trim().is_empty() {
+                Err(SharedString::from("Name cannot be empty"))
+            } else {
+                Ok(())
+            }
+        });
+
+        assert_eq!(
+            Commit::resolve("Preset", "Preset", Some(&not_empty)),
+            Commit::Unchanged
+        );
+        assert_eq!(
+            Commit::resolve("Preset", "Bass boost", Some(&not_empty)),
+            Commit::Changed("Bass boost".to_string())
+        );
+        assert_eq!(
+            Commit::resolve("Preset", "  ", Some(&not_empty)),
+            Commit::Invalid("Name cannot be empty".into())
+        );
+        // Anything goes without a validator
+        assert_eq!(
+            Commit::resolve("Preset", "", None),
+            Commit::Changed(String::new())
+        );
+    }
+
+    #[test]
+    fn test_trigger_click_count() {
+        assert_eq!(EditTrigger::default(), EditTrigger::DoubleClick);
+        assert_eq!(EditTrigger::Click.click_count(), 1);
+        assert_eq!(EditTrigger::DoubleClick.click_count(), 2);
+    }
+}
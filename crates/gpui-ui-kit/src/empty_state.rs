@@ -0,0 +1,178 @@
+//! Empty state component
+//!
+//! Placeholder shown where content would be: an icon or illustration, a
+//! title, a description and an optional primary action.
+//!
+//! # Example
+//!
+//! ```ignore
+//! EmptyState::new("no-measurements", "No measurements yet")
+//!     .icon("📈")
+//!     .description("Import a file or start a sweep to see a frequency response.")
+//!     .action("Import…", |_window, cx| open_import_dialog(cx))
+//! ```
+
+use crate::ComponentTheme;
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::callback::Callback;
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Theme colors for the empty state
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct EmptyStateTheme {
+    /// Icon color
+    #[theme(default = 0x666666ff, from = text_muted)]
+    pub icon: Rgba,
+    /// Title color
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub title: Rgba,
+    /// Description color
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub description: Rgba,
+}
+
+/// Placeholder for an empty list, chart or panel
+#[derive(IntoElement)]
+pub struct EmptyState {
+    id: ElementId,
+    title: SharedString,
+    icon: Option<SharedString>,
+    illustration: Option<AnyElement>,
+    description: Option<SharedString>,
+    action: Option<(SharedString, Callback)>,
+    compact: bool,
+    theme: Option<EmptyStateTheme>,
+}
+
+impl EmptyState {
+    /// Create an empty state with a title
+    pub fn new(id: impl Into<ElementId>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            icon: None,
+            illustration: None,
+            description: None,
+            action: None,
+            compact: false,
+            theme: None,
+        }
+    }
+
+    /// Set an icon glyph shown above the title
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set an illustration shown above the title (replaces the icon)
+    pub fn illustration(mut self, illustration: impl IntoElement) -> Self {
+        self.illustration = Some(illustration.into_any_element());
+        self
+    }
+
+    /// Set the description below the title
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a primary action button
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Callback::from_fn(handler)));
+        self
+    }
+
+    /// Use smaller spacing and text, for panels and table bodies
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Set the theme
+    pub fn theme(mut self, theme: EmptyStateTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .unwrap_or_else(|| EmptyStateTheme::from(&global_theme));
+        let compact = self.compact;
+
+        let graphic = match (self.illustration, self.icon) {
+            (Some(illustration), _) => Some(illustration),
+            (None, Some(icon)) => Some(
+                div()
+                    .text_size(px(if compact { 24.0 } else { 40.0 }))
+                    .text_color(theme.icon)
+                    .child(icon)
+                    .into_any_element(),
+            ),
+            (None, None) => None,
+        };
+
+        let mut title = div()
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(theme.title)
+            .child(self.title);
+        title = if compact {
+            title.text_sm()
+        } else {
+            title.text_lg()
+        };
+
+        let description = self.description.map(|description| {
+            div()
+                .max_w(px(360.0))
+                .text_sm()
+                .text_color(theme.description)
+                .text_center()
+                .child(description)
+        });
+
+        let action = self.action.map(|(label, handler)| {
+            Button::new(
+                ElementId::Name(SharedString::from(format!("{}-action", self.id))),
+                label,
+            )
+            .variant(ButtonVariant::Primary)
+            .size(if compact {
+                ButtonSize::Sm
+            } else {
+                ButtonSize::Md
+            })
+            .on_click(move |window, cx| handler.invoke(window, cx))
+        });
+
+        let mut container = div()
+            .id(self.id)
+            .size_full()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .text_center();
+        container = if compact {
+            container.gap_1().p_4()
+        } else {
+            container.gap_3().p_8()
+        };
+
+        container
+            .children(graphic)
+            .child(title)
+            .children(description)
+            .children(action.map(|button| div().pt_2().child(button)))
+    }
+}
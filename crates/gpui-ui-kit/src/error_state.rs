@@ -0,0 +1,258 @@
+//! Error state component
+//!
+//! Shown in place of content that failed to load: a summary, an optional
+//! details disclosure (e.g. the underlying error chain) and a retry action.
+//!
+//! The disclosure state is owned by the app, like other presentational
+//! components:
+//!
+//! ```ignore
+//! ErrorState::new("load-error", "Couldn't load measurements")
+//!     .message("The server did not respond.")
+//!     .details(format!("{err:#}"))
+//!     .details_open(self.show_details)
+//!     .on_toggle_details(cx.listener(|this, open, _, cx| {
+//!         this.show_details = *open;
+//!         cx.notify();
+//!     }))
+//!     .on_retry(cx.listener(|this, _, _, cx| this.reload(cx)))
+//! ```
+
+use crate::ComponentTheme;
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::callback::Callback;
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Theme colors for the error state
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct ErrorStateTheme {
+    /// Icon color
+    #[theme(default = 0xf44336ff, from = error)]
+    pub icon: Rgba,
+    /// Summary color
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub title: Rgba,
+    /// Message color
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub message: Rgba,
+    /// Details toggle color
+    #[theme(default = 0x007accff, from = accent)]
+    pub link: Rgba,
+    /// Details panel background
+    #[theme(default = 0x3a3a3aff, from = muted)]
+    pub details_bg: Rgba,
+    /// Details text color
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub details_text: Rgba,
+    /// Details panel border
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub details_border: Rgba,
+}
+
+/// Placeholder for content that failed to load
+#[derive(IntoElement)]
+pub struct ErrorState {
+    id: ElementId,
+    title: SharedString,
+    message: Option<SharedString>,
+    details: Option<SharedString>,
+    details_open: bool,
+    retry_label: SharedString,
+    compact: bool,
+    theme: Option<ErrorStateTheme>,
+    on_retry: Option<Callback>,
+    on_toggle_details: Option<Callback<bool>>,
+}
+
+impl ErrorState {
+    /// Create an error state with a summary
+    pub fn new(id: impl Into<ElementId>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            message: None,
+            details: None,
+            details_open: false,
+            retry_label: "Retry".into(),
+            compact: false,
+            theme: None,
+            on_retry: None,
+            on_toggle_details: None,
+        }
+    }
+
+    /// Set a user-facing explanation below the summary
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Set technical details shown in a disclosure
+    pub fn details(mut self, details: impl Into<SharedString>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Show or hide the details
+    pub fn details_open(mut self, open: bool) -> Self {
+        self.details_open = open;
+        self
+    }
+
+    /// Set the retry button label (default: "Retry")
+    pub fn retry_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.retry_label = label.into();
+        self
+    }
+
+    /// Use smaller spacing and text, for panels and table bodies
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Set the theme
+    pub fn theme(mut self, theme: ErrorStateTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Show a retry button calling `handler`
+    pub fn on_retry(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_retry = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Called with the requested open state when the details toggle is clicked
+    pub fn on_toggle_details(
+        mut self,
+        handler: impl Fn(&bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_toggle_details = Some(Callback::new(move |open: bool, window, cx| {
+            handler(&open, window, cx)
+        }));
+        self
+    }
+
+    fn child_id(&self, suffix: &str) -> ElementId {
+        ElementId::Name(SharedString::from(format!("{}-{}", self.id, suffix)))
+    }
+}
+
+impl RenderOnce for ErrorState {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| ErrorStateTheme::from(&global_theme));
+        let compact = self.compact;
+
+        let icon = div()
+            .size(px(if compact { 28.0 } else { 40.0 }))
+            .rounded_full()
+            .border_2()
+            .border_color(theme.icon)
+            .flex()
+            .items_center()
+            .justify_center()
+            .text_color(theme.icon)
+            .font_weight(FontWeight::BOLD)
+            .child("!");
+
+        let mut title = div()
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(theme.title)
+            .child(self.title.clone());
+        title = if compact {
+            title.text_sm()
+        } else {
+            title.text_lg()
+        };
+
+        let message = self.message.clone().map(|message| {
+            div()
+                .max_w(px(420.0))
+                .text_sm()
+                .text_color(theme.message)
+                .child(message)
+        });
+
+        // Details disclosure: toggle link and, when open, a selectable panel
+        let details = self.details.clone().map(|details| {
+            let open = self.details_open;
+            let mut toggle = div()
+                .id(self.child_id("details-toggle"))
+                .text_xs()
+                .text_color(theme.link)
+                .cursor_pointer()
+                .hover(|s| s.underline())
+                .child(if open {
+                    "Hide details ▴"
+                } else {
+                    "Show details ▾"
+                });
+            if let Some(handler) = self.on_toggle_details {
+                toggle = toggle.on_click(move |_, window, cx| handler.call(!open, window, cx));
+            }
+
+            let mut section = div().flex().flex_col().items_center().gap_2().child(toggle);
+            if open {
+                section = section.child(
+                    div()
+                        .id(self.child_id("details"))
+                        .w_full()
+                        .max_w(px(520.0))
+                        .max_h(px(200.0))
+                        .overflow_y_scroll()
+                        .p_2()
+                        .rounded_md()
+                        .bg(theme.details_bg)
+                        .border_1()
+                        .border_color(theme.details_border)
+                        .text_xs()
+                        .font_family("monospace")
+                        .text_left()
+                        .text_color(theme.details_text)
+                        .child(details),
+                );
+            }
+            section
+        });
+
+        let retry = self.on_retry.map(|handler| {
+            Button::new(self.child_id("retry"), self.retry_label.clone())
+                .variant(ButtonVariant::Secondary)
+                .size(if compact {
+                    ButtonSize::Sm
+                } else {
+                    ButtonSize::Md
+                })
+                .icon_left("⟳")
+                .on_click(move |window, cx| handler.invoke(window, cx))
+        });
+
+        let mut container = div()
+            .id(self.id)
+            .size_full()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .text_center();
+        container = if compact {
+            container.gap_1().p_4()
+        } else {
+            container.gap_3().p_8()
+        };
+
+        container
+            .child(icon)
+            .child(title)
+            .children(message)
+            .children(retry.map(|button| div().pt_2().child(button)))
+            .children(details)
+    }
+}
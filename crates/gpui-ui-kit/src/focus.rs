@@ -20,16 +20,49 @@
 //!
 //! - **Vertical**: Up/Down arrows move focus, Home/End go to first/last
 //! - **Horizontal**: Left/Right arrows move focus, Home/End go to first/last
-//! - **Grid**: All arrow keys work, Home/End go to first/last in row
-//! - **Tab**: Always moves to next/previous focusable (with Shift)
+//! - **Grid**: All arrow keys work, Home/End go to first/last in row,
+//!   Ctrl+Home/Ctrl+End go to first/last item
+//! - **Tab**: Enters and leaves the group; only the last active child is a
+//!   tab stop (roving tabindex), so focus returns where the user left it
+//!
+//! At the edges, arrow keys follow the [`FocusWrap`] policy: clamp, wrap
+//! within the row/column, or flow into the next row/column.
 //!
 //! # Focus Ring
 //!
 //! By default, FocusGroup adds a visual focus ring to the currently focused
 //! child. Disable with `.focus_ring(false)`.
+//!
+//! Focusable components draw the same ring when they receive keyboard focus,
+//! through [`FocusRingExt::focus_ring`] and the [`FocusRing`] style derived
+//! from the theme's `focus_ring` color and width. The ring only shows for
+//! keyboard focus (`focus_visible`), not after a mouse click.
+//!
+//! # Tab Order Audit
+//!
+//! While [`FocusAudit`] is enabled, focusable components report their bounds
+//! every frame and [`TabOrderOverlay`] numbers them on screen in tab order,
+//! highlighting components rendered without a focus handle and elements that
+//! break reading order (top-to-bottom, left-to-right):
+//!
+//! ```ignore
+//! FocusAudit::set_enabled(true, cx);
+//!
+//! div()
+//!     .relative()
+//!     .size_full()
+//!     .child(app_content)
+//!     .child(TabOrderOverlay::new()) // last child, covers the window
+//! ```
 
+use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::theme::{Theme, ThemeExt};
 use gpui::prelude::*;
 use gpui::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 
 /// Direction of focus navigation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -46,18 +79,196 @@ pub enum FocusDirection {
     },
 }
 
+/// What arrow keys do at the edge of a row, column or list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FocusWrap {
+    /// Stay on the edge item
+    #[default]
+    Clamp,
+    /// Wrap to the other end of the same row or column
+    Wrap,
+    /// Continue on the next row or column (reading order), wrapping from
+    /// the last item to the first
+    Flow,
+}
+
+/// Navigation key handled by a [`FocusGroup`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusKey {
+    /// Up arrow
+    Up,
+    /// Down arrow
+    Down,
+    /// Left arrow
+    Left,
+    /// Right arrow
+    Right,
+    /// Home: start of the row (start of the list outside grids)
+    Home,
+    /// End: end of the row (end of the list outside grids)
+    End,
+    /// Ctrl+Home: first item
+    First,
+    /// Ctrl+End: last item
+    Last,
+}
+
+impl FocusKey {
+    /// Map a keystroke to a navigation key
+    pub fn from_keystroke(keystroke: &Keystroke) -> Option<Self> {
+        let control = keystroke.modifiers.control || keystroke.modifiers.platform;
+        match keystroke.key.as_str() {
+            "up" => Some(FocusKey::Up),
+            "down" => Some(FocusKey::Down),
+            "left" => Some(FocusKey::Left),
+            "right" => Some(FocusKey::Right),
+            "home" if control => Some(FocusKey::First),
+            "end" if control => Some(FocusKey::Last),
+            "home" => Some(FocusKey::Home),
+            "end" => Some(FocusKey::End),
+            _ => None,
+        }
+    }
+}
+
+/// Index reached from `current` by `key` among `count` items
+///
+/// Returns `None` when the key does not move focus (wrong axis for the
+/// direction, or clamped at an edge). Vertical and horizontal groups ignore
+/// the other axis; grids lay items out row by row.
+pub fn navigate_focus(
+    current: usize,
+    count: usize,
+    direction: FocusDirection,
+    wrap: FocusWrap,
+    key: FocusKey,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let current = current.min(count - 1);
+    let last = count - 1;
+
+    let target = match (direction, key) {
+        (_, FocusKey::First) => Some(0),
+        (_, FocusKey::Last) => Some(last),
+        (FocusDirection::Vertical, FocusKey::Up) | (FocusDirection::Horizontal, FocusKey::Left) => {
+            match (current, wrap) {
+                (0, FocusWrap::Clamp) => None,
+                (0, _) => Some(last),
+                _ => Some(current - 1),
+            }
+        }
+        (FocusDirection::Vertical, FocusKey::Down)
+        | (FocusDirection::Horizontal, FocusKey::Right) => match wrap {
+            _ if current < last => Some(current + 1),
+            FocusWrap::Clamp => None,
+            _ => Some(0),
+        },
+        (FocusDirection::Vertical | FocusDirection::Horizontal, FocusKey::Home) => Some(0),
+        (FocusDirection::Vertical | FocusDirection::Horizontal, FocusKey::End) => Some(last),
+        (FocusDirection::Vertical | FocusDirection::Horizontal, _) => None,
+        (FocusDirection::Grid { columns }, key) => {
+            navigate_grid(current, count, columns.max(1), wrap, key)
+        }
+    };
+    target.filter(|&t| t != current)
+}
+
+fn navigate_grid(
+    current: usize,
+    count: usize,
+    columns: usize,
+    wrap: FocusWrap,
+    key: FocusKey,
+) -> Option<usize> {
+    let last = count - 1;
+    let (row, col) = (current / columns, current % columns);
+    let row_start = row * columns;
+    let row_end = (row_start + columns - 1).min(last);
+    // Last item of column `c` (columns past the item count end on `last`)
+    let column_end = |c: usize| {
+        if c > last {
+            last
+        } else {
+            c + (last - c) / columns * columns
+        }
+    };
+
+    match key {
+        FocusKey::Left => match wrap {
+            _ if current > row_start => Some(current - 1),
+            FocusWrap::Clamp => None,
+            FocusWrap::Wrap => Some(row_end),
+            FocusWrap::Flow => Some(if current == 0 { last } else { current - 1 }),
+        },
+        FocusKey::Right => match wrap {
+            _ if current < row_end => Some(current + 1),
+            FocusWrap::Clamp => None,
+            FocusWrap::Wrap => Some(row_start),
+            FocusWrap::Flow => Some(if current == last { 0 } else { current + 1 }),
+        },
+        FocusKey::Up => match wrap {
+            _ if current >= columns => Some(current - columns),
+            FocusWrap::Clamp => None,
+            FocusWrap::Wrap => Some(column_end(col)),
+            FocusWrap::Flow if col > 0 => Some(column_end(col - 1)),
+            FocusWrap::Flow => Some(column_end(columns - 1)),
+        },
+        FocusKey::Down => match wrap {
+            _ if current + columns <= last => Some(current + columns),
+            FocusWrap::Clamp => None,
+            FocusWrap::Wrap => Some(col),
+            FocusWrap::Flow if col + 1 < columns && col < last => Some(col + 1),
+            FocusWrap::Flow => Some(0),
+        },
+        FocusKey::Home => Some(row_start),
+        FocusKey::End => Some(row_end),
+        FocusKey::First => Some(0),
+        FocusKey::Last => Some(last),
+    }
+}
+
+/// Persistent roving focus state of a group
+#[derive(Default)]
+struct FocusGroupState {
+    // One focus handle per child, reused across renders
+    handles: Vec<FocusHandle>,
+    // Child that is the group's tab stop
+    active: usize,
+}
+
+// Thread-local registry of focus group state, keyed by group ID.
+// FocusGroup is a RenderOnce component, so child focus handles and the
+// active index must live outside of it to survive re-renders.
+thread_local! {
+    static FOCUS_GROUPS: RefCell<HashMap<ElementId, Rc<RefCell<FocusGroupState>>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Clean up the state of a focus group with a dynamic element ID
+pub fn cleanup_focus_group_state(id: &ElementId) {
+    FOCUS_GROUPS.with(|groups| {
+        groups.borrow_mut().remove(id);
+    });
+}
+
 /// A container that manages keyboard focus navigation between children
 ///
-/// FocusGroup handles arrow key navigation, Tab key movement, and Home/End
-/// keys for quick navigation to first/last elements.
+/// FocusGroup implements a roving tab stop: only the active child is in the
+/// window tab order, so Tab enters and leaves the group as a single stop,
+/// while arrow keys, Home and End move focus between children. This makes
+/// toolbars, button sets and card grids behave like native composite
+/// controls.
 pub struct FocusGroup {
     id: ElementId,
     children: Vec<AnyElement>,
     direction: FocusDirection,
-    wraparound: bool,
+    wrap: FocusWrap,
     focus_ring: bool,
     gap: Pixels,
     focus_handle: Option<FocusHandle>,
+    on_active_change: Option<Callback<usize>>,
 }
 
 impl FocusGroup {
@@ -67,10 +278,11 @@ impl FocusGroup {
             id: id.into(),
             children: Vec::new(),
             direction: FocusDirection::default(),
-            wraparound: false,
+            wrap: FocusWrap::default(),
             focus_ring: true,
             gap: px(8.0),
             focus_handle: None,
+            on_active_change: None,
         }
     }
 
@@ -81,8 +293,20 @@ impl FocusGroup {
     }
 
     /// Enable wraparound navigation (first <-> last)
+    ///
+    /// Shorthand for `.wrap(FocusWrap::Wrap)` / `.wrap(FocusWrap::Clamp)`.
     pub fn wraparound(mut self, wrap: bool) -> Self {
-        self.wraparound = wrap;
+        self.wrap = if wrap {
+            FocusWrap::Wrap
+        } else {
+            FocusWrap::Clamp
+        };
+        self
+    }
+
+    /// Set the edge policy for arrow keys (default: clamp)
+    pub fn wrap(mut self, wrap: FocusWrap) -> Self {
+        self.wrap = wrap;
         self
     }
 
@@ -99,11 +323,24 @@ impl FocusGroup {
     }
 
     /// Set the focus handle for this group
+    ///
+    /// The group tracks it without being a tab stop, so the app can observe
+    /// whether focus is inside the group.
     pub fn focus_handle(mut self, handle: FocusHandle) -> Self {
         self.focus_handle = Some(handle);
         self
     }
 
+    /// Called with the child index when keyboard navigation or a click moves
+    /// the active child
+    pub fn on_active_change(
+        mut self,
+        handler: impl Fn(usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_active_change = Some(Callback::new(handler));
+        self
+    }
+
     /// Add a child element
     pub fn child(mut self, child: impl IntoElement) -> Self {
         self.children.push(child.into_any_element());
@@ -122,18 +359,32 @@ impl RenderOnce for FocusGroup {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
         let child_count = self.children.len();
         let direction = self.direction;
-        let wraparound = self.wraparound;
+        let wrap = self.wrap;
         let gap = self.gap;
 
-        // Create or use provided focus handle
-        let focus_handle = self.focus_handle.unwrap_or_else(|| cx.focus_handle());
+        // Get or create the persistent state, with one handle per child
+        let state = FOCUS_GROUPS.with(|groups| {
+            groups
+                .borrow_mut()
+                .entry(self.id.clone())
+                .or_default()
+                .clone()
+        });
+        let handles = {
+            let mut state = state.borrow_mut();
+            state.handles.truncate(child_count);
+            while state.handles.len() < child_count {
+                state.handles.push(cx.focus_handle());
+            }
+            state.active = state.active.min(child_count.saturating_sub(1));
+            state.handles.clone()
+        };
+        let active = state.borrow().active;
 
-        let mut container = div()
-            .id(self.id)
-            .track_focus(&focus_handle)
-            .flex()
-            .gap(gap)
-            .focusable();
+        let mut container = div().id(self.id.clone()).flex().gap(gap);
+        if let Some(handle) = &self.focus_handle {
+            container = container.track_focus(&handle.clone().tab_stop(false));
+        }
 
         // Set flex direction based on navigation direction
         container = match direction {
@@ -145,68 +396,71 @@ impl RenderOnce for FocusGroup {
             }
         };
 
-        // Add keyboard navigation handler
-        let focus_handle_for_key = focus_handle.clone();
+        let on_active_change = self.on_active_change;
+
+        // Arrow keys from any child bubble up here
+        let state_for_key = state.clone();
+        let handles_for_key = handles.clone();
+        let on_change_for_key = on_active_change.clone();
         container = container.on_key_down(move |event, window, cx| {
-            if !focus_handle_for_key.is_focused(window) {
+            let Some(key) = FocusKey::from_keystroke(&event.keystroke) else {
+                return;
+            };
+            let Some(current) = handles_for_key
+                .iter()
+                .position(|h| h.contains_focused(window, cx))
+            else {
+                return;
+            };
+            let Some(next) = navigate_focus(current, handles_for_key.len(), direction, wrap, key)
+            else {
+                // Keep clamped arrows from scrolling or moving outer focus
+                cx.stop_propagation();
                 return;
+            };
+            cx.stop_propagation();
+            state_for_key.borrow_mut().active = next;
+            window.focus(&handles_for_key[next], cx);
+            if let Some(handler) = &on_change_for_key {
+                handler.call(next, window, cx);
             }
+        });
 
-            let key = event.keystroke.key.as_str();
+        let ring = self.focus_ring.then(|| FocusRing::from_theme(&cx.theme()));
 
-            match direction {
-                FocusDirection::Vertical => match key {
-                    "up" => {
-                        // Move focus up
-                        cx.stop_propagation();
-                        // In GPUI, we'd need to track focused child index
-                        // For now, we just prevent default
-                    }
-                    "down" => {
-                        cx.stop_propagation();
-                    }
-                    "home" => {
-                        cx.stop_propagation();
-                        // Focus first child
-                    }
-                    "end" => {
-                        cx.stop_propagation();
-                        // Focus last child
-                    }
-                    _ => {}
-                },
-                FocusDirection::Horizontal => match key {
-                    "left" => {
-                        cx.stop_propagation();
+        for (index, (child, handle)) in self.children.into_iter().zip(&handles).enumerate() {
+            // Roving tab stop: only the active child is reachable with Tab
+            let handle = handle.clone().tab_stop(index == active);
+            let state_for_click = state.clone();
+            let on_change_for_click = on_active_change.clone();
+            let mut item = div()
+                .id(ElementId::Name(SharedString::from(format!(
+                    "{}-item-{}",
+                    self.id, index
+                ))))
+                .relative()
+                .track_focus(&handle)
+                .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                    let changed = {
+                        let mut state = state_for_click.borrow_mut();
+                        let changed = state.active != index;
+                        state.active = index;
+                        changed
+                    };
+                    if changed && let Some(handler) = &on_change_for_click {
+                        handler.call(index, window, cx);
                     }
-                    "right" => {
-                        cx.stop_propagation();
-                    }
-                    "home" => {
-                        cx.stop_propagation();
-                    }
-                    "end" => {
-                        cx.stop_propagation();
-                    }
-                    _ => {}
-                },
-                FocusDirection::Grid { columns } => {
-                    let _ = columns; // Used for calculating navigation
-                    let _ = wraparound;
-                    let _ = child_count;
-                    match key {
-                        "up" | "down" | "left" | "right" | "home" | "end" => {
-                            cx.stop_propagation();
-                        }
-                        _ => {}
-                    }
-                }
+                })
+                .children(FocusAudit::marker(
+                    format!("{}[{}]", self.id, index),
+                    Some(&handle),
+                    cx,
+                ))
+                .child(child);
+            if let Some(ring) = ring {
+                item = item.focus_ring(ring);
             }
-        });
-
-        // Add children
-        for child in self.children {
-            container = container.child(child);
+            container = container.child(item);
         }
 
         container
@@ -226,3 +480,472 @@ pub trait FocusGroupExt {
     /// Wrap this element in a focus group with vertical navigation
     fn with_focus_navigation(self, id: impl Into<ElementId>) -> FocusGroup;
 }
+
+// ============================================================================
+// Focus Ring
+// ============================================================================
+
+/// Ring drawn around a component with keyboard focus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FocusRing {
+    /// Ring color
+    pub color: Rgba,
+    /// Ring width
+    pub width: Pixels,
+}
+
+impl FocusRing {
+    /// Create a 2px ring of `color`
+    pub fn new(color: Rgba) -> Self {
+        Self {
+            color,
+            width: px(2.0),
+        }
+    }
+
+    /// Ring in the theme's focus ring color and width
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self::new(theme.focus_ring).width(px(theme.focus_ring_width))
+    }
+
+    /// Set the ring width
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// The ring as a box shadow, so it does not affect layout
+    pub fn shadow(&self) -> Vec<BoxShadow> {
+        vec![BoxShadow {
+            offset: point(px(0.0), px(0.0)),
+            blur_radius: px(0.0),
+            spread_radius: self.width,
+            color: Hsla::from(self.color),
+        }]
+    }
+}
+
+/// Adds the shared focus ring to focusable elements
+pub trait FocusRingExt: InteractiveElement + Sized {
+    /// Draw `ring` while the element has keyboard focus
+    ///
+    /// The element must track a focus handle for the ring to show.
+    fn focus_ring(self, ring: FocusRing) -> Self {
+        self.focus_visible(move |style| style.shadow(ring.shadow()))
+    }
+}
+
+impl<E: InteractiveElement> FocusRingExt for E {}
+
+// ============================================================================
+// Tab Order Audit
+// ============================================================================
+
+/// A focusable element recorded by the [`FocusAudit`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusAuditEntry {
+    /// Element label (usually its id)
+    pub label: SharedString,
+    /// Window bounds of the element
+    pub bounds: Bounds<Pixels>,
+    /// Whether the element tracks a focus handle
+    pub focusable: bool,
+    /// Whether the element currently has focus
+    pub focused: bool,
+}
+
+/// Global tab-order audit state
+///
+/// Disabled by default; when disabled, components add nothing to their
+/// element tree.
+#[derive(Debug, Default)]
+pub struct FocusAudit {
+    enabled: bool,
+    // Entries reported during the frame being laid out
+    pending: Vec<FocusAuditEntry>,
+    // Entries of the last completed frame, shown by the overlay
+    entries: Vec<FocusAuditEntry>,
+}
+
+impl Global for FocusAudit {}
+
+impl FocusAudit {
+    /// Whether the audit is enabled
+    pub fn is_enabled(cx: &App) -> bool {
+        cx.try_global::<FocusAudit>().is_some_and(|a| a.enabled)
+    }
+
+    /// Enable or disable the audit
+    pub fn set_enabled(enabled: bool, cx: &mut App) {
+        let audit = cx.default_global::<FocusAudit>();
+        audit.enabled = enabled;
+        audit.pending.clear();
+        audit.entries.clear();
+        cx.refresh_windows();
+    }
+
+    /// Toggle the audit
+    pub fn toggle(cx: &mut App) {
+        Self::set_enabled(!Self::is_enabled(cx), cx);
+    }
+
+    /// Entries of the last completed frame, in tab order
+    pub fn entries(cx: &App) -> Vec<FocusAuditEntry> {
+        cx.try_global::<FocusAudit>()
+            .map(|a| a.entries.clone())
+            .unwrap_or_default()
+    }
+
+    /// Invisible child that records its parent in the audit
+    ///
+    /// Returns `None` when the audit is disabled. The parent must be
+    /// `relative()` so the marker covers it. `handle` is `None` for
+    /// interactive components rendered without a focus handle, which the
+    /// overlay flags as unreachable by keyboard.
+    pub fn marker(
+        label: impl Into<SharedString>,
+        handle: Option<&FocusHandle>,
+        cx: &App,
+    ) -> Option<AnyElement> {
+        if !Self::is_enabled(cx) {
+            return None;
+        }
+        let label = label.into();
+        let handle = handle.cloned();
+        Some(
+            canvas(
+                move |bounds, window, cx| {
+                    let entry = FocusAuditEntry {
+                        label,
+                        bounds,
+                        focusable: handle.is_some(),
+                        focused: handle.as_ref().is_some_and(|h| h.is_focused(window)),
+                    };
+                    let audit = cx.default_global::<FocusAudit>();
+                    // A repeated entry means the last frame was never
+                    // published (no overlay mounted); start over
+                    if audit.pending.contains(&entry) {
+                        audit.pending.clear();
+                    }
+                    audit.pending.push(entry);
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .inset_0()
+            .into_any_element(),
+        )
+    }
+}
+
+/// Indices of entries placed before their predecessor in reading order
+///
+/// An entry is out of order when it starts on an earlier row than the
+/// previous focusable entry, or on the same row but further left. Rows are
+/// compared with a tolerance of half the previous entry's height.
+pub fn out_of_reading_order(entries: &[FocusAuditEntry]) -> Vec<usize> {
+    let focusable: Vec<(usize, &FocusAuditEntry)> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| e.focusable)
+        .collect();
+    focusable
+        .windows(2)
+        .filter_map(|pair| {
+            let (prev, (index, next)) = (pair[0].1, pair[1]);
+            let tolerance = prev.bounds.size.height / 2.0;
+            let dy = next.bounds.origin.y - prev.bounds.origin.y;
+            let earlier_row = dy < -tolerance;
+            let same_row_left =
+                dy.abs() <= tolerance && next.bounds.origin.x < prev.bounds.origin.x;
+            (earlier_row || same_row_left).then_some(index)
+        })
+        .collect()
+}
+
+/// Theme colors for the tab order overlay
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct TabOrderOverlayTheme {
+    /// Outline and badge of focusable elements
+    #[theme(default = 0x007accff, from = accent)]
+    pub focusable: Rgba,
+    /// Outline and badge of the focused element
+    #[theme(default = 0x4caf50ff, from = success)]
+    pub focused: Rgba,
+    /// Outline and badge of elements breaking reading order
+    #[theme(default = 0xff9800ff, from = warning)]
+    pub out_of_order: Rgba,
+    /// Outline and badge of interactive elements without a focus handle
+    #[theme(default = 0xf44336ff, from = error)]
+    pub missing: Rgba,
+    /// Badge text
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub badge_text: Rgba,
+    /// Summary panel background
+    #[theme(default = 0x2a2a2aff, from = surface)]
+    pub summary_bg: Rgba,
+}
+
+/// Debug overlay numbering focusable elements in tab order
+///
+/// Place it as the last child of a `relative()` root covering the window; it
+/// renders nothing while [`FocusAudit`] is disabled. Numbers follow the order
+/// in which focusable elements are laid out, which is the order Tab visits
+/// them unless tab indices say otherwise.
+#[derive(IntoElement)]
+pub struct TabOrderOverlay {
+    theme: Option<TabOrderOverlayTheme>,
+}
+
+impl TabOrderOverlay {
+    /// Create the overlay
+    pub fn new() -> Self {
+        Self { theme: None }
+    }
+
+    /// Set the overlay theme
+    pub fn theme(mut self, theme: TabOrderOverlayTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+}
+
+impl Default for TabOrderOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for TabOrderOverlay {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if !FocusAudit::is_enabled(cx) {
+            return div().into_any_element();
+        }
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .unwrap_or_else(|| TabOrderOverlayTheme::from(&global_theme));
+
+        let entries = FocusAudit::entries(cx);
+        let out_of_order = out_of_reading_order(&entries);
+
+        let mut number = 0;
+        let mut missing = 0;
+        let mut markers = Vec::with_capacity(entries.len());
+        for (index, entry) in entries.iter().enumerate() {
+            let (color, badge) = if !entry.focusable {
+                missing += 1;
+                (theme.missing, SharedString::from("!"))
+            } else {
+                number += 1;
+                let color = if entry.focused {
+                    theme.focused
+                } else if out_of_order.contains(&index) {
+                    theme.out_of_order
+                } else {
+                    theme.focusable
+                };
+                (color, SharedString::from(number.to_string()))
+            };
+            markers.push(
+                div()
+                    .absolute()
+                    .left(entry.bounds.origin.x)
+                    .top(entry.bounds.origin.y)
+                    .w(entry.bounds.size.width)
+                    .h(entry.bounds.size.height)
+                    .border_2()
+                    .border_color(color)
+                    .child(
+                        div()
+                            .absolute()
+                            .top(px(-8.0))
+                            .left(px(-8.0))
+                            .min_w(px(18.0))
+                            .h(px(18.0))
+                            .px_1()
+                            .rounded_full()
+                            .bg(color)
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .text_xs()
+                            .font_weight(FontWeight::BOLD)
+                            .text_color(theme.badge_text)
+                            .child(badge),
+                    ),
+            );
+        }
+
+        let summary = format!(
+            "{} focusable · {} without focus handle · {} out of order",
+            number,
+            missing,
+            out_of_order.len()
+        );
+
+        div()
+            .absolute()
+            .inset_0()
+            .children(markers)
+            .child(
+                div()
+                    .absolute()
+                    .bottom_2()
+                    .right_2()
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .bg(theme.summary_bg)
+                    .text_xs()
+                    .text_color(theme.badge_text)
+                    .child(summary),
+            )
+            // Publish this frame's entries once every marker has reported
+            .child(
+                canvas(
+                    |_, window, cx| {
+                        let audit = cx.default_global::<FocusAudit>();
+                        let pending = std::mem::take(&mut audit.pending);
+                        if pending != audit.entries {
+                            audit.entries = pending;
+                            window.refresh();
+                        }
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_0(),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(x: f32, y: f32, focusable: bool) -> FocusAuditEntry {
+        FocusAuditEntry {
+            label: "e".into(),
+            bounds: Bounds::new(point(px(x), px(y)), size(px(80.0), px(24.0))),
+            focusable,
+            focused: false,
+        }
+    }
+
+    #[test]
+    fn test_reading_order() {
+        // Row 1: two elements left to right; row 2: one element
+        let ordered = vec![
+            entry(0.0, 0.0, true),
+            entry(100.0, 4.0, true),
+            entry(0.0, 40.0, true),
+        ];
+        assert!(out_of_reading_order(&ordered).is_empty());
+
+        // Second element sits left of the first on the same row; the third
+        // jumps back up a row
+        let shuffled = vec![
+            entry(100.0, 40.0, true),
+            entry(0.0, 40.0, true),
+            entry(0.0, 0.0, true),
+        ];
+        assert_eq!(out_of_reading_order(&shuffled), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_reading_order_skips_unfocusable() {
+        let entries = vec![
+            entry(0.0, 0.0, true),
+            entry(0.0, 200.0, false),
+            entry(100.0, 0.0, true),
+        ];
+        assert!(out_of_reading_order(&entries).is_empty());
+    }
+
+    #[test]
+    fn test_navigate_linear() {
+        let v = FocusDirection::Vertical;
+        assert_eq!(
+            navigate_focus(0, 3, v, FocusWrap::Clamp, FocusKey::Down),
+            Some(1)
+        );
+        assert_eq!(
+            navigate_focus(2, 3, v, FocusWrap::Clamp, FocusKey::Down),
+            None
+        );
+        assert_eq!(
+            navigate_focus(2, 3, v, FocusWrap::Wrap, FocusKey::Down),
+            Some(0)
+        );
+        assert_eq!(
+            navigate_focus(0, 3, v, FocusWrap::Flow, FocusKey::Up),
+            Some(2)
+        );
+        assert_eq!(
+            navigate_focus(1, 3, v, FocusWrap::Wrap, FocusKey::Left),
+            None
+        );
+        assert_eq!(
+            navigate_focus(1, 3, v, FocusWrap::Clamp, FocusKey::End),
+            Some(2)
+        );
+
+        let h = FocusDirection::Horizontal;
+        assert_eq!(
+            navigate_focus(1, 3, h, FocusWrap::Clamp, FocusKey::Left),
+            Some(0)
+        );
+        assert_eq!(
+            navigate_focus(1, 3, h, FocusWrap::Clamp, FocusKey::Down),
+            None
+        );
+        assert_eq!(
+            navigate_focus(0, 0, h, FocusWrap::Wrap, FocusKey::Right),
+            None
+        );
+    }
+
+    #[test]
+    fn test_navigate_grid() {
+        // 3 columns, 7 items:
+        // 0 1 2
+        // 3 4 5
+        // 6
+        let g = FocusDirection::Grid { columns: 3 };
+        let nav = |i, wrap, key| navigate_focus(i, 7, g, wrap, key);
+
+        assert_eq!(nav(4, FocusWrap::Clamp, FocusKey::Up), Some(1));
+        assert_eq!(nav(4, FocusWrap::Clamp, FocusKey::Down), None);
+        assert_eq!(nav(2, FocusWrap::Clamp, FocusKey::Right), None);
+        assert_eq!(nav(6, FocusWrap::Clamp, FocusKey::End), None);
+        assert_eq!(nav(4, FocusWrap::Clamp, FocusKey::Home), Some(3));
+        assert_eq!(nav(4, FocusWrap::Clamp, FocusKey::Last), Some(6));
+
+        // Wrap stays within the row or column
+        assert_eq!(nav(2, FocusWrap::Wrap, FocusKey::Right), Some(0));
+        assert_eq!(nav(3, FocusWrap::Wrap, FocusKey::Left), Some(5));
+        assert_eq!(nav(0, FocusWrap::Wrap, FocusKey::Up), Some(6));
+        assert_eq!(nav(1, FocusWrap::Wrap, FocusKey::Up), Some(4));
+        assert_eq!(nav(4, FocusWrap::Wrap, FocusKey::Down), Some(1));
+
+        // Flow continues in reading order
+        assert_eq!(nav(2, FocusWrap::Flow, FocusKey::Right), Some(3));
+        assert_eq!(nav(6, FocusWrap::Flow, FocusKey::Right), Some(0));
+        assert_eq!(nav(3, FocusWrap::Flow, FocusKey::Left), Some(2));
+        assert_eq!(nav(6, FocusWrap::Flow, FocusKey::Down), Some(1));
+        assert_eq!(nav(5, FocusWrap::Flow, FocusKey::Down), Some(0));
+        assert_eq!(nav(1, FocusWrap::Flow, FocusKey::Up), Some(6));
+        assert_eq!(nav(0, FocusWrap::Flow, FocusKey::Up), Some(5));
+    }
+
+    #[test]
+    fn test_focus_ring_shadow() {
+        let ring = FocusRing::new(rgb(0x007acc)).width(px(3.0));
+        let shadow = ring.shadow();
+        assert_eq!(shadow.len(), 1);
+        assert_eq!(shadow[0].spread_radius, px(3.0));
+        assert_eq!(shadow[0].blur_radius, px(0.0));
+    }
+}
@@ -4,6 +4,7 @@
 //! Supports both text/emoji icons and custom child elements (like SVG icons).
 
 use crate::ComponentTheme;
+use crate::callback::Callback;
 use crate::theme::{ThemeExt, glow_shadow};
 use gpui::prelude::*;
 use gpui::*;
@@ -47,49 +48,37 @@ pub struct IconButtonTheme {
 }
 
 /// IconButton size variants
+///
+/// The button is square, as tall as other controls of the same size.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum IconButtonSize {
-    /// Extra small (16px)
+    /// Extra small (20px)
     Xs,
-    /// Small (20px)
+    /// Small (26px)
     Sm,
-    /// Medium (24px, default)
+    /// Medium (32px, default)
     #[default]
     Md,
-    /// Large (32px)
+    /// Large (40px)
     Lg,
     /// Extra large (48px)
     Xl,
-    /// Custom size in pixels
-    Custom(u32),
 }
 
 impl IconButtonSize {
     /// Get the size in pixels
     pub fn size(&self) -> Pixels {
-        match self {
-            IconButtonSize::Xs => px(16.0),
-            IconButtonSize::Sm => px(20.0),
-            IconButtonSize::Md => px(24.0),
-            IconButtonSize::Lg => px(32.0),
-            IconButtonSize::Xl => px(48.0),
-            IconButtonSize::Custom(size) => px(*size as f32),
-        }
+        crate::ComponentSize::from(*self).control_height()
     }
-}
 
-impl From<crate::ComponentSize> for IconButtonSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs => Self::Xs,
-            crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg => Self::Lg,
-            crate::ComponentSize::Xl => Self::Xl,
-        }
+    /// Get the size of text icons in pixels
+    pub fn icon_size(&self) -> Pixels {
+        crate::ComponentSize::from(*self).icon_size()
     }
 }
 
+crate::size::component_size_conversions!(IconButtonSize);
+
 /// IconButton variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum IconButtonVariant {
@@ -128,13 +117,14 @@ pub struct IconButton {
     id: ElementId,
     content: IconContent,
     size: IconButtonSize,
+    custom_size: Option<Pixels>,
     variant: IconButtonVariant,
     disabled: bool,
     selected: bool,
     rounded_full: bool,
     padding: Option<Pixels>,
     theme: Option<IconButtonTheme>,
-    on_click: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_click: Option<Callback>,
 }
 
 impl IconButton {
@@ -144,6 +134,7 @@ impl IconButton {
             id: id.into(),
             content: IconContent::Text(icon.into()),
             size: IconButtonSize::default(),
+            custom_size: None,
             variant: IconButtonVariant::default(),
             disabled: false,
             selected: false,
@@ -160,6 +151,7 @@ impl IconButton {
             id: id.into(),
             content: IconContent::Element(child.into_any_element()),
             size: IconButtonSize::default(),
+            custom_size: None,
             variant: IconButtonVariant::default(),
             disabled: false,
             selected: false,
@@ -176,6 +168,14 @@ impl IconButton {
         self
     }
 
+    /// Set an exact button size, overriding the size tier
+    ///
+    /// Text icons keep the icon size of the tier.
+    pub fn custom_size(mut self, size: Pixels) -> Self {
+        self.custom_size = Some(size);
+        self
+    }
+
     /// Set the button variant
     pub fn variant(mut self, variant: IconButtonVariant) -> Self {
         self.variant = variant;
@@ -196,7 +196,7 @@ impl IconButton {
 
     /// Set click handler
     pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Callback::from_fn(handler));
         self
     }
 
@@ -267,7 +267,7 @@ impl IconButton {
 
     /// Build into element with theme
     pub fn build_with_theme(self, icon_theme: &IconButtonTheme) -> Stateful<Div> {
-        let size = self.size.size();
+        let size = self.custom_size.unwrap_or_else(|| self.size.size());
         let (bg, bg_hover, text_color, border) = self.compute_colors(icon_theme);
 
         let mut el = div()
@@ -304,14 +304,14 @@ impl IconButton {
 
             if let Some(handler) = self.on_click {
                 el = el.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                    handler(window, cx);
+                    handler.invoke(window, cx);
                 });
             }
         }
 
         // Add content
         match self.content {
-            IconContent::Text(text) => el.child(text),
+            IconContent::Text(text) => el.text_size(self.size.icon_size()).child(text),
             IconContent::Element(element) => el.child(element),
         }
     }
@@ -31,166 +31,60 @@
 //!     })
 //! ```
 //!
-//! # Thread-Local State Pattern
+//! # State Ownership
 //!
-//! This component uses `thread_local!` storage to persist focus handles and
-//! edit state across renders. This is necessary because GPUI's `RenderOnce`
-//! components are recreated on each render, but we need state to persist:
-//!
-//! - **Focus handles**: Must be the same instance across renders or focus is lost
-//! - **Edit state**: Cursor position, text, and selection must persist during editing
-//!
-//! ## Memory Considerations
-//!
-//! The thread-local `HashMap` entries grow as new element IDs are used and are
-//! never automatically cleaned up. For most applications this is fine because:
-//! - Element IDs are typically static or part of a bounded set
-//! - The stored data is small (FocusHandle, EditState)
-//!
-//! If you have dynamic element IDs (e.g., from a virtualized list), consider:
-//! 1. Using a stable ID scheme that reuses IDs
-//! 2. Calling `cleanup_input_state(id)` when components are removed
-//!
-//! ## Cleanup Function
-//!
-//! To manually clean up state for a removed element:
-//! ```rust,ignore
-//! cleanup_input_state(&element_id);
-//! ```
+//! `Input` is a `RenderOnce` component, so its focus handle and edit state
+//! (cursor, text, selection) are kept in a per-thread registry keyed by
+//! element ID. The registry holds them weakly: the rendered element owns
+//! them, and they are dropped automatically once the input stops being
+//! rendered. Dynamic element IDs (e.g. in virtualized lists) need no cleanup.
 
 use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::focus::{FocusAudit, FocusRing, FocusRingExt};
+use crate::state_registry::ElementStateRegistry;
 use crate::theme::ThemeExt;
 use gpui::prelude::*;
 use gpui::*;
 use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
-
-// Maximum number of input states to retain in thread-local storage.
-// Excess states will be automatically evicted (oldest first).
-// This prevents memory leaks when cleanup functions are not called.
-const MAX_THREAD_LOCAL_INPUT_STATES: usize = 1000;
-
-// Thread-local registry for focus handles, keyed by element ID.
-// This ensures the same focus handle is reused across renders for Input components
-// that don't provide their own focus handle. Without this, focus would be lost
-// on every re-render since Input is a RenderOnce component.
-thread_local! {
-    static FOCUS_HANDLES: RefCell<HashMap<ElementId, FocusHandle>> = RefCell::new(HashMap::new());
-}
 
-// Thread-local registry for edit state, keyed by element ID.
-// This ensures edit state (cursor position, current text, selection) persists
-// across renders. Without this, every re-render would reset the editing state.
-//
-// Note: State is automatically evicted when exceeding MAX_THREAD_LOCAL_INPUT_STATES.
-// For applications with many dynamic inputs, call cleanup_input_state() or
-// cleanup_stale_input_states() periodically to manage memory explicitly.
+// Thread-local registry of focus handles and edit state, keyed by element ID.
+// Entries are weak, so state is owned by the rendered Input and freed with it.
 thread_local! {
-    static EDIT_STATES: RefCell<HashMap<ElementId, Rc<RefCell<EditState>>>> = RefCell::new(HashMap::new());
+    static INPUT_STATES: RefCell<ElementStateRegistry<EditState>> =
+        RefCell::new(ElementStateRegistry::default());
 }
 
-/// Evict oldest entries if thread-local storage exceeds maximum size.
-/// This prevents unbounded memory growth when cleanup functions are not called.
-/// Returns the number of entries evicted from each map.
-fn trim_thread_local_storage() -> (usize, usize) {
-    let mut focus_evicted = 0;
-    let mut edit_evicted = 0;
-
-    FOCUS_HANDLES.with(|handles| {
-        let mut handles = handles.borrow_mut();
-        while handles.len() > MAX_THREAD_LOCAL_INPUT_STATES {
-            if let Some(key) = handles.keys().next().cloned() {
-                handles.remove(&key);
-                focus_evicted += 1;
-            }
-        }
-    });
-
-    EDIT_STATES.with(|states| {
-        let mut states = states.borrow_mut();
-        while states.len() > MAX_THREAD_LOCAL_INPUT_STATES {
-            if let Some(key) = states.keys().next().cloned() {
-                states.remove(&key);
-                edit_evicted += 1;
-            }
-        }
-    });
-
-    (focus_evicted, edit_evicted)
-}
-
-/// Clean up thread-local state for an Input element.
+/// Formerly removed the state of an Input element.
 ///
-/// Call this when removing an Input with a dynamic element ID to prevent
-/// memory leaks. For static element IDs, cleanup is not necessary.
-///
-/// # Example
-/// ```rust,ignore
-/// // When removing a dynamically-created Input
-/// cleanup_input_state(&ElementId::Name(format!("input-{}", item_id).into()));
-/// ```
-pub fn cleanup_input_state(id: &ElementId) {
-    FOCUS_HANDLES.with(|handles| {
-        handles.borrow_mut().remove(id);
-    });
-    EDIT_STATES.with(|states| {
-        states.borrow_mut().remove(id);
-    });
-}
+/// State is now owned by the rendered element and freed when it is no
+/// longer rendered, so this does nothing.
+#[deprecated(note = "Input state is freed automatically when the element is no longer rendered")]
+pub fn cleanup_input_state(_id: &ElementId) {}
 
-/// Clean up thread-local state for Input elements whose IDs are not in the retained set.
+/// Formerly removed the state of Input elements not in the retained set.
 ///
-/// This is useful for virtualized lists where you want to keep state only for
-/// currently visible items. Call this periodically or when the visible set changes.
-///
-/// # Example
-/// ```rust,ignore
-/// // Keep only the inputs currently in view
-/// let visible_ids: HashSet<ElementId> = visible_items
-///     .iter()
-///     .map(|item| ElementId::Name(format!("input-{}", item.id).into()))
-///     .collect();
-/// cleanup_stale_input_states(&visible_ids);
-/// ```
-pub fn cleanup_stale_input_states(retained_ids: &std::collections::HashSet<ElementId>) {
-    FOCUS_HANDLES.with(|handles| {
-        handles
-            .borrow_mut()
-            .retain(|id, _| retained_ids.contains(id));
-    });
-    EDIT_STATES.with(|states| {
-        states
-            .borrow_mut()
-            .retain(|id, _| retained_ids.contains(id));
-    });
-}
+/// State is now owned by the rendered element and freed when it is no
+/// longer rendered, so this does nothing.
+#[deprecated(note = "Input state is freed automatically when the element is no longer rendered")]
+pub fn cleanup_stale_input_states(_retained_ids: &std::collections::HashSet<ElementId>) {}
 
-/// Get the current count of stored input states.
+/// Get the number of live input states.
 ///
-/// Useful for debugging memory leaks. If this number grows beyond
-/// MAX_THREAD_LOCAL_INPUT_STATES, older entries will be automatically evicted.
+/// Useful for debugging: this counts only inputs that are still rendered.
 ///
 /// # Returns
 /// A tuple of (focus_handle_count, edit_state_count)
 pub fn input_state_count() -> (usize, usize) {
-    let _ = trim_thread_local_storage();
-    let focus_count = FOCUS_HANDLES.with(|handles| handles.borrow().len());
-    let edit_count = EDIT_STATES.with(|states| states.borrow().len());
-    (focus_count, edit_count)
+    INPUT_STATES.with(|states| states.borrow().live_counts())
 }
 
 /// Clear all input states.
 ///
-/// This removes all thread-local state for all Input components.
-/// Use with caution - this will reset all input editing state.
+/// Rendered inputs get fresh focus handles and edit state on their next
+/// render. Use with caution - this will reset all input editing state.
 pub fn clear_all_input_states() {
-    FOCUS_HANDLES.with(|handles| {
-        handles.borrow_mut().clear();
-    });
-    EDIT_STATES.with(|states| {
-        states.borrow_mut().clear();
-    });
+    INPUT_STATES.with(|states| states.borrow_mut().clear());
 }
 
 /// Theme colors for input styling
@@ -240,6 +134,8 @@ pub struct InputTheme {
 /// Input size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum InputSize {
+    /// Extra small input
+    Xs,
     /// Small input
     Sm,
     /// Medium input (default)
@@ -247,17 +143,11 @@ pub enum InputSize {
     Md,
     /// Large input
     Lg,
+    /// Extra large input
+    Xl,
 }
 
-impl From<crate::ComponentSize> for InputSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs | crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg | crate::ComponentSize::Xl => Self::Lg,
-        }
-    }
-}
+crate::size::component_size_conversions!(InputSize);
 
 /// Input visual variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -578,13 +468,13 @@ pub struct Input {
     border_color: Option<Rgba>,
     placeholder_color: Option<Rgba>,
     /// Called when value is confirmed (Enter pressed)
-    on_change: Option<Box<dyn Fn(&str, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<String>>,
     /// Called when editing starts (click on input)
-    on_edit_start: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_edit_start: Option<Callback>,
     /// Called when editing ends (Enter = Some(value), Escape = None)
-    on_edit_end: Option<Box<dyn Fn(Option<String>, &mut Window, &mut App) + 'static>>,
+    on_edit_end: Option<Callback<Option<String>>>,
     /// Called on every text change during editing (for live updates)
-    on_text_change: Option<Box<dyn Fn(String, &mut Window, &mut App) + 'static>>,
+    on_text_change: Option<Callback<String>>,
     /// Focus handle for this input
     focus_handle: Option<FocusHandle>,
 }
@@ -708,13 +598,15 @@ impl Input {
 
     /// Set change handler (called when input value is confirmed with Enter)
     pub fn on_change(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(move |value: String, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
     /// Set edit start handler (called when user clicks on input to edit)
     pub fn on_edit_start(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_edit_start = Some(Box::new(handler));
+        self.on_edit_start = Some(Callback::from_fn(handler));
         self
     }
 
@@ -724,7 +616,7 @@ impl Input {
         mut self,
         handler: impl Fn(Option<String>, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_edit_end = Some(Box::new(handler));
+        self.on_edit_end = Some(Callback::new(handler));
         self
     }
 
@@ -733,7 +625,7 @@ impl Input {
         mut self,
         handler: impl Fn(String, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_text_change = Some(Box::new(handler));
+        self.on_text_change = Some(Callback::new(handler));
         self
     }
 }
@@ -743,11 +635,7 @@ impl RenderOnce for Input {
         let global_theme = cx.theme();
         let theme = InputTheme::from(&global_theme);
 
-        let (py, _text_size_class) = match self.size {
-            InputSize::Sm => (px(4.0), "text_xs"),
-            InputSize::Md => (px(8.0), "text_sm"),
-            InputSize::Lg => (px(12.0), "text_base"),
-        };
+        let size = crate::ComponentSize::from(self.size);
 
         let has_error = self.error.is_some();
         let disabled = self.disabled;
@@ -755,16 +643,10 @@ impl RenderOnce for Input {
         let current_value = self.value.clone();
 
         // Use provided focus handle, or get/create one from the registry.
-        // The registry ensures the same focus handle is reused across renders,
-        // which is critical since Input is a RenderOnce component.
+        // The registry reuses the same focus handle while the input stays
+        // rendered, which is critical since Input is a RenderOnce component.
         let focus_handle = self.focus_handle.unwrap_or_else(|| {
-            FOCUS_HANDLES.with(|handles| {
-                let mut handles = handles.borrow_mut();
-                handles
-                    .entry(self.id.clone())
-                    .or_insert_with(|| cx.focus_handle())
-                    .clone()
-            })
+            INPUT_STATES.with(|states| states.borrow_mut().focus_handle(&self.id, cx))
         });
 
         // Determine editing state from focus
@@ -774,14 +656,9 @@ impl RenderOnce for Input {
         // When focused, we're always in editing mode
         let editing = is_focused && !disabled && !readonly;
 
-        // Get or create edit state from registry (persists across renders)
-        let edit_state = EDIT_STATES.with(|states| {
-            let mut states = states.borrow_mut();
-            states
-                .entry(self.id.clone())
-                .or_insert_with(|| Rc::new(RefCell::new(EditState::default())))
-                .clone()
-        });
+        // Get or create edit state from registry (persists while rendered)
+        let edit_state =
+            INPUT_STATES.with(|states| states.borrow_mut().state(&self.id, EditState::default));
 
         // Get display state from edit_state
         let state = edit_state.borrow();
@@ -831,12 +708,19 @@ impl RenderOnce for Input {
             .flex()
             .items_center()
             .gap_2()
-            .px_3()
-            .py(py)
+            .h(size.control_height())
+            .px(size.padding_x())
             .rounded_md()
             .border_1()
             .border_color(border_color)
-            .focusable();
+            .relative()
+            .focusable()
+            .focus_ring(FocusRing::from_theme(&global_theme))
+            .children(FocusAudit::marker(
+                self.id.to_string(),
+                Some(&focus_handle),
+                cx,
+            ));
 
         // Apply variant styling
         match self.variant {
@@ -872,12 +756,6 @@ impl RenderOnce for Input {
         let selection_bg = theme.selection_bg;
         let cursor_color = theme.cursor;
 
-        // Wrap handlers in Rc for sharing
-        let on_change_rc = self.on_change.map(Rc::new);
-        let on_edit_start_rc = self.on_edit_start.map(Rc::new);
-        let on_edit_end_rc = self.on_edit_end.map(Rc::new);
-        let on_text_change_rc = self.on_text_change.map(Rc::new);
-
         // Add click handler - focus and start editing
         // Double-click selects word
         // Single click positions cursor, drag selects text
@@ -885,7 +763,7 @@ impl RenderOnce for Input {
             let focus_handle_for_click = focus_handle.clone();
             let edit_state_for_click = edit_state.clone();
             let value_for_click = current_value.to_string();
-            let on_edit_start_click = on_edit_start_rc.clone();
+            let on_edit_start_click = self.on_edit_start.clone();
             let edit_text_for_click = edit_text.clone();
 
             input_wrapper =
@@ -923,7 +801,7 @@ impl RenderOnce for Input {
 
                         // Call on_edit_start callback
                         if let Some(ref handler) = on_edit_start_click {
-                            handler(window, cx);
+                            handler.invoke(window, cx);
                         }
                     } else {
                         state.start_selection(char_pos);
@@ -968,9 +846,9 @@ impl RenderOnce for Input {
         // Since I need to output valid Rust code, I will include the keyboard logic.
         if !disabled && !readonly {
             let edit_state_for_key = edit_state.clone();
-            let on_edit_end_key = on_edit_end_rc.clone();
-            let on_text_change_key = on_text_change_rc.clone();
-            let on_change_key = on_change_rc.clone();
+            let on_edit_end_key = self.on_edit_end.clone();
+            let on_text_change_key = self.on_text_change.clone();
+            let on_change_key = self.on_change.clone();
             let focus_handle_for_key = focus_handle.clone();
             let current_value_for_key = current_value.to_string();
 
@@ -1008,7 +886,7 @@ impl RenderOnce for Input {
                                 let text = state.text.clone();
                                 drop(state);
                                 if let Some(ref handler) = on_text_change_key {
-                                    handler(text, window, cx);
+                                    handler.call(text, window, cx);
                                 }
                                 window.refresh();
                             }
@@ -1022,7 +900,7 @@ impl RenderOnce for Input {
                                 let text = state.text.clone();
                                 drop(state);
                                 if let Some(ref handler) = on_text_change_key {
-                                    handler(text, window, cx);
+                                    handler.call(text, window, cx);
                                 }
                                 window.refresh();
                             }
@@ -1054,7 +932,7 @@ impl RenderOnce for Input {
                     let text = state.text.clone();
                     drop(state);
                     if let Some(ref handler) = on_text_change_key {
-                        handler(text, window, cx);
+                        handler.call(text, window, cx);
                     }
                     window.refresh();
                     return;
@@ -1068,10 +946,10 @@ impl RenderOnce for Input {
                         drop(state);
                         window.blur();
                         if let Some(ref handler) = on_change_key {
-                            handler(&text, window, cx);
+                            handler.call(text.clone(), window, cx);
                         }
                         if let Some(ref handler) = on_edit_end_key {
-                            handler(Some(text), window, cx);
+                            handler.call(Some(text), window, cx);
                         }
                     }
                     "escape" => {
@@ -1080,7 +958,7 @@ impl RenderOnce for Input {
                         drop(state);
                         window.blur();
                         if let Some(ref handler) = on_edit_end_key {
-                            handler(None, window, cx);
+                            handler.call(None, window, cx);
                         }
                     }
                     "backspace" => {
@@ -1088,7 +966,7 @@ impl RenderOnce for Input {
                         let text = state.text.clone();
                         drop(state);
                         if let Some(ref handler) = on_text_change_key {
-                            handler(text, window, cx);
+                            handler.call(text, window, cx);
                         }
                         window.refresh();
                     }
@@ -1097,7 +975,7 @@ impl RenderOnce for Input {
                         let text = state.text.clone();
                         drop(state);
                         if let Some(ref handler) = on_text_change_key {
-                            handler(text, window, cx);
+                            handler.call(text, window, cx);
                         }
                         window.refresh();
                     }
@@ -1127,7 +1005,7 @@ impl RenderOnce for Input {
                             let text = state.text.clone();
                             drop(state);
                             if let Some(ref handler) = on_text_change_key {
-                                handler(text, window, cx);
+                                handler.call(text, window, cx);
                             }
                             window.refresh();
                         }
@@ -1158,11 +1036,7 @@ impl RenderOnce for Input {
         let mut text_el = div().id(field_id).flex_1().flex().items_center();
 
         // Apply text size
-        text_el = match self.size {
-            InputSize::Sm => text_el.text_xs(),
-            InputSize::Md => text_el.text_sm(),
-            InputSize::Lg => text_el,
-        };
+        text_el = size.apply_text_size(text_el);
 
         // Cursor element builder
         let cursor_el = || {
@@ -23,25 +23,39 @@ pub mod button;
 pub mod button_set;
 pub mod card;
 pub mod dialog;
+pub mod dialogs;
 pub mod focus;
 pub mod icon_button;
 pub mod menu;
 pub mod tabs;
 pub mod toast;
+pub mod tour;
 
 // Shared utilities
+pub mod callback;
+pub mod clipboard;
+pub mod router;
 pub mod scale;
+pub mod selection;
+pub mod shortcuts;
 pub mod size;
+mod state_registry;
+pub mod tasks;
+mod validation;
 
 // Form components
 pub mod autoeq;
 pub mod checkbox;
 pub mod color;
 pub mod color_picker;
+pub mod editable_label;
 pub mod input;
 pub mod number_input;
+pub mod rating;
 pub mod select;
 pub mod slider;
+pub mod stepper;
+pub mod tag_input;
 pub mod toggle;
 
 // audio
@@ -52,10 +66,13 @@ pub mod avatar;
 pub mod badge;
 pub mod progress;
 pub mod spinner;
+pub mod table;
 pub mod text;
 
 // Feedback
 pub mod alert;
+pub mod empty_state;
+pub mod error_state;
 pub mod tooltip;
 
 // Navigation
@@ -66,6 +83,7 @@ pub mod wizard;
 // Layout
 pub mod pane_divider;
 pub mod stack;
+pub mod title_bar;
 
 // Workflow canvas
 pub mod workflow;
@@ -79,28 +97,55 @@ pub use icon_button::{IconButton, IconButtonSize, IconButtonTheme, IconButtonVar
 
 // Containers
 pub use card::{Card, SlotFactory};
-pub use dialog::{Dialog, DialogSize, DialogSlotFactory, DialogTheme};
+pub use dialog::{Dialog, DialogEvent, DialogSize, DialogSlotFactory, DialogTheme, DialogView};
+pub use dialogs::{ButtonOrder, ConfirmDialog, PromptDialog};
 
 // Navigation
-pub use accordion::{Accordion, AccordionItem, AccordionMode, AccordionTheme};
+pub use accordion::{
+    Accordion, AccordionEvent, AccordionItem, AccordionItemsFactory, AccordionMode, AccordionTheme,
+    AccordionView,
+};
 pub use breadcrumbs::{BreadcrumbItem, BreadcrumbSeparator, Breadcrumbs};
 pub use menu::{Menu, MenuBar, MenuBarItem, MenuItem, MenuTheme, menu_bar_button};
-pub use tabs::{TabItem, TabVariant, Tabs, TabsTheme};
+pub use tabs::{TabItem, TabItemsFactory, TabVariant, Tabs, TabsEvent, TabsTheme, TabsView};
 pub use wizard::{
     StepStatus, Wizard, WizardHeader, WizardNavigation, WizardStep, WizardTheme, WizardVariant,
 };
 
 // Focus management
-pub use focus::{FocusDirection, FocusGroup};
+pub use focus::{
+    FocusAudit, FocusAuditEntry, FocusDirection, FocusGroup, FocusKey, FocusRing, FocusRingExt,
+    FocusWrap, TabOrderOverlay, TabOrderOverlayTheme, cleanup_focus_group_state, navigate_focus,
+    out_of_reading_order,
+};
 
 // Notifications
 pub use alert::{Alert, AlertVariant, InlineAlert};
-pub use toast::{Toast, ToastContainer, ToastPosition, ToastVariant};
+pub use empty_state::{EmptyState, EmptyStateTheme};
+pub use error_state::{ErrorState, ErrorStateTheme};
+pub use tasks::{
+    TaskEntry, TaskHandle, TaskId, TaskList, TaskListTheme, TaskManager, TaskStatus,
+    TaskStatusIndicator, cancel_task, dismiss_task, spawn_task,
+};
+pub use toast::{
+    Toast, ToastContainer, ToastContainerView, ToastEvent, ToastPosition, ToastVariant,
+};
+pub use tour::{
+    CardPosition, Tour, TourAnchors, TourOutcome, TourOverlay, TourProgress, TourStep, TourTheme,
+    card_position, tour_anchor,
+};
 
 // Form
+pub use audio::device_select::{
+    AudioDeviceInfo, AudioDeviceKind, AudioDeviceSelect, AudioDeviceSelectTheme,
+};
 pub use audio::potentiometer::{
     Potentiometer, PotentiometerScale, PotentiometerSize, PotentiometerTheme,
 };
+pub use audio::signal_generator::{
+    GeneratorConfig, SignalGeneratorPanel, SignalGeneratorPanelTheme, SweepSettings, Waveform,
+};
+pub use audio::transport::{PlaybackState, TransportBar, TransportBarTheme};
 pub use audio::vertical_slider::{
     VerticalSlider, VerticalSliderScale, VerticalSliderSize, VerticalSliderTheme,
 };
@@ -113,15 +158,21 @@ pub use autoeq::{
 pub use checkbox::{Checkbox, CheckboxSize};
 pub use color::Color;
 pub use color_picker::{ColorPickerMode, ColorPickerView};
+pub use editable_label::{EditTrigger, EditableLabel, EditableLabelTheme};
+#[allow(deprecated)]
 pub use input::{
     Input, InputSize, InputVariant, cleanup_input_state, cleanup_stale_input_states,
     clear_all_input_states, input_state_count,
 };
+#[allow(deprecated)]
 pub use number_input::{
     NumberInput, NumberInputSize, NumberInputTheme, cleanup_number_input_state,
 };
-pub use select::{Select, SelectOption, SelectSize, SelectTheme};
+pub use rating::{Rating, RatingTheme};
+pub use select::{Select, SelectEvent, SelectOption, SelectSize, SelectTheme, SelectView};
 pub use slider::{Slider, SliderSize, SliderTheme};
+pub use stepper::{StepButton, StepDirection, Stepper, StepperTheme};
+pub use tag_input::{TagInput, TagInputTheme};
 pub use toggle::{Toggle, ToggleSize, ToggleStyle, ToggleTheme};
 
 // Data display
@@ -129,6 +180,7 @@ pub use avatar::{Avatar, AvatarGroup, AvatarShape, AvatarSize, AvatarStatus};
 pub use badge::{Badge, BadgeDot, BadgeSize, BadgeVariant};
 pub use progress::{CircularProgress, Progress, ProgressSize, ProgressVariant};
 pub use spinner::{LoadingDots, Spinner, SpinnerSize};
+pub use table::{CellAlign, CellRenderer, CellValue, Table, TableColumn, TableTheme};
 pub use text::{Code, Heading, Link, Text, TextSize, TextWeight};
 
 // Feedback
@@ -140,9 +192,13 @@ pub use stack::{
     Divider, HStack, Spacer, StackAlign, StackJustify, StackOverflow, StackSize, StackSpacing,
     VStack,
 };
+pub use title_bar::{
+    TITLE_BAR_HEIGHT, TitleBar, TitleBarPlatform, TitleBarTheme, WindowControlsSide,
+    title_bar_window_options,
+};
 
 // Application templates
-pub use app::{MiniApp, MiniAppConfig};
+pub use app::{MiniApp, MiniAppConfig, SessionReplay, SessionStore, SessionTrace};
 
 // Animation
 pub use animation::{
@@ -153,16 +209,29 @@ pub use animation::{
 // Theme and i18n
 pub use color_tokens::{
     BackgroundColors, BorderColors, ColorPalette, ColorToken, SemanticColors, TextColors, darken,
-    desaturate, lighten, saturate, with_alpha,
+    darken_oklch, desaturate, from_oklch, lighten, lighten_oklch, saturate, to_oklch, with_alpha,
 };
 pub use i18n::{I18nExt, I18nState, Language, TranslationKey, Translations};
-pub use theme::{Theme, ThemeExt, ThemeState, ThemeVariant};
+pub use theme::{
+    Elevation, ElevationTokens, ShadowPreset, Theme, ThemeExt, ThemeState, ThemeVariant,
+    animate_theme_transition,
+};
 
 // Workflow canvas
 pub use workflow::{
     CanvasState, Command, Connection, ConnectionId, HistoryManager, HitTestResult, HitTester,
-    NodeContent, NodeId, Port, PortDirection, Position, SelectionState, ViewportState,
-    WorkflowCanvas, WorkflowGraph, WorkflowNode, WorkflowNodeData, WorkflowTheme,
+    NodeContent, NodeId, Port, PortDirection, PortInspector, PortRef, PortValue, Position,
+    ScrollBehavior, SelectionState, ViewportState, WorkflowCanvas, WorkflowGraph, WorkflowNode,
+    WorkflowNodeData, WorkflowTheme,
+};
+
+// Callbacks, clipboard, routing, selection and shortcuts
+pub use callback::Callback;
+pub use clipboard::{ClipboardFormat, CopyToClipboard};
+pub use router::{Router, RouterEvent};
+pub use selection::{SelectionEvent, SelectionMode, SelectionModel};
+pub use shortcuts::{
+    Shortcut, ShortcutHelp, ShortcutHelpTheme, ShortcutRegistry, ToggleShortcutHelp,
 };
 
 // Shared size definitions
@@ -3,7 +3,8 @@
 //! Provides a complete menu system for application navigation and context menus.
 
 use crate::ComponentTheme;
-use crate::theme::{ThemeExt, glow_shadow};
+use crate::callback::Callback;
+use crate::theme::{Elevation, ElevationTokens, ThemeExt, glow_shadow};
 use gpui::prelude::*;
 use gpui::*;
 
@@ -37,6 +38,12 @@ pub struct MenuTheme {
     /// Danger item hover background (for destructive actions like Quit)
     #[theme(default = 0xdc2626ff, from = error)]
     pub danger_hover_bg: Rgba,
+    /// Menu shadow
+    #[theme(
+        default_expr = "ElevationTokens::default().shadow(Elevation::Overlay)",
+        from_expr = "theme.elevation.shadow(Elevation::Overlay)"
+    )]
+    pub shadow: Vec<BoxShadow>,
 }
 
 /// A single menu item
@@ -171,11 +178,11 @@ pub struct Menu {
     focused_index: Option<usize>,
     /// Focus handle for keyboard events
     focus_handle: Option<FocusHandle>,
-    on_select: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    on_select: Option<Callback<SharedString>>,
     /// Called when the menu should close (e.g., Escape pressed)
-    on_close: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_close: Option<Callback>,
     /// Called when keyboard focus changes (arrow up/down, home/end)
-    on_focus_change: Option<Box<dyn Fn(Option<usize>, &mut Window, &mut App) + 'static>>,
+    on_focus_change: Option<Callback<Option<usize>>>,
 }
 
 impl Menu {
@@ -225,13 +232,15 @@ impl Menu {
         mut self,
         handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_select = Some(Box::new(handler));
+        self.on_select = Some(Callback::new(move |id: SharedString, window, cx| {
+            handler(&id, window, cx)
+        }));
         self
     }
 
     /// Set the close handler (triggered by Escape key)
     pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_close = Some(Box::new(handler));
+        self.on_close = Some(Callback::from_fn(handler));
         self
     }
 
@@ -243,7 +252,7 @@ impl Menu {
         mut self,
         handler: impl Fn(Option<usize>, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_focus_change = Some(Box::new(handler));
+        self.on_focus_change = Some(Callback::new(handler));
         self
     }
 
@@ -330,10 +339,9 @@ impl Menu {
             .map(|item| (item.id.clone(), item.is_separator, item.disabled))
             .collect();
 
-        // Use Rc pattern for handlers (takes ownership)
-        let on_select_rc = self.on_select.map(|f| std::rc::Rc::new(f));
-        let on_close_rc = self.on_close.map(|f| std::rc::Rc::new(f));
-        let on_focus_change_rc = self.on_focus_change.map(|f| std::rc::Rc::new(f));
+        let on_select_rc = self.on_select;
+        let on_close_rc = self.on_close;
+        let on_focus_change_rc = self.on_focus_change;
 
         let mut menu = div()
             .id(self.id)
@@ -343,7 +351,7 @@ impl Menu {
             .border_1()
             .border_color(theme.border)
             .rounded(px(4.0))
-            .shadow_lg()
+            .shadow(theme.shadow.clone())
             .py_1()
             .overflow_y_scroll();
 
@@ -364,7 +372,7 @@ impl Menu {
                 match key {
                     "escape" => {
                         if let Some(ref handler) = on_close_for_keyboard {
-                            handler(window, cx);
+                            handler.invoke(window, cx);
                         }
                     }
                     "enter" | " " => {
@@ -375,27 +383,27 @@ impl Menu {
                             && !*disabled
                             && let Some(ref handler) = on_select_for_keyboard
                         {
-                            handler(id, window, cx);
+                            handler.call(id.clone(), window, cx);
                         }
                     }
                     "down" | "arrowdown" => {
                         if let Some(ref handler) = on_focus_change_for_keyboard {
-                            handler(next_index, window, cx);
+                            handler.call(next_index, window, cx);
                         }
                     }
                     "up" | "arrowup" => {
                         if let Some(ref handler) = on_focus_change_for_keyboard {
-                            handler(prev_index, window, cx);
+                            handler.call(prev_index, window, cx);
                         }
                     }
                     "home" => {
                         if let Some(ref handler) = on_focus_change_for_keyboard {
-                            handler(first_index, window, cx);
+                            handler.call(first_index, window, cx);
                         }
                     }
                     "end" => {
                         if let Some(ref handler) = on_focus_change_for_keyboard {
-                            handler(last_index, window, cx);
+                            handler.call(last_index, window, cx);
                         }
                     }
                     _ => {}
@@ -460,7 +468,7 @@ impl Menu {
                         let handler = handler.clone();
                         let id = item_id.clone();
                         row = row.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                            handler(&id, window, cx);
+                            handler.call(id.clone(), window, cx);
                         });
                     }
                 }
@@ -555,8 +563,8 @@ impl MenuBarItem {
 pub struct MenuBar {
     items: Vec<MenuBarItem>,
     active_menu: Option<SharedString>,
-    on_select: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
-    on_menu_toggle: Option<Box<dyn Fn(Option<&SharedString>, &mut Window, &mut App) + 'static>>,
+    on_select: Option<Callback<SharedString>>,
+    on_menu_toggle: Option<Callback<Option<SharedString>>>,
 }
 
 impl MenuBar {
@@ -581,7 +589,9 @@ impl MenuBar {
         mut self,
         handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_select = Some(Box::new(handler));
+        self.on_select = Some(Callback::new(move |id: SharedString, window, cx| {
+            handler(&id, window, cx)
+        }));
         self
     }
 
@@ -590,7 +600,9 @@ impl MenuBar {
         mut self,
         handler: impl Fn(Option<&SharedString>, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_menu_toggle = Some(Box::new(handler));
+        self.on_menu_toggle = Some(Callback::new(
+            move |id: Option<SharedString>, window, cx| handler(id.as_ref(), window, cx),
+        ));
         self
     }
 
@@ -606,8 +618,7 @@ impl MenuBar {
 
     /// Build into element with theme
     pub fn build_with_theme(self, theme: &MenuTheme) -> Div {
-        // Use Rc pattern instead of unsafe pointer for on_menu_toggle handler
-        let on_toggle_rc = self.on_menu_toggle.map(|f| std::rc::Rc::new(f));
+        let on_toggle_rc = self.on_menu_toggle;
 
         let mut bar = div().flex().items_center().gap_1();
 
@@ -642,9 +653,9 @@ impl MenuBar {
                 let currently_open = is_open;
                 button = button.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
                     if currently_open {
-                        handler(None, window, cx);
+                        handler.call(None, window, cx);
                     } else {
-                        handler(Some(&id), window, cx);
+                        handler.call(Some(id.clone()), window, cx);
                     }
                 });
             }
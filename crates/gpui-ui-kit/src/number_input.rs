@@ -1,7 +1,7 @@
 //! NumberInput component for numeric value entry
 //!
 //! A numeric input field with:
-//! - Increment/decrement buttons (+ and -)
+//! - Increment/decrement buttons (+ and -), repeating while held
 //! - Direct text editing of the value (click on value to edit)
 //! - Keyboard navigation:
 //!   - Arrow Up/Right: increase value
@@ -9,75 +9,55 @@
 //!   - Enter: confirm edit
 //!   - Escape: cancel edit
 //! - Scroll wheel adjustment
+//! - Optional drag-to-scrub on the value (Shift fine, Ctrl/Cmd coarse), per
+//!   step or logarithmic for frequency fields
 //! - Configurable step size, min/max bounds
 //! - Value formatting (decimals, units)
 //!
 //! The component handles its own editing state internally - just provide
 //! an `on_change` callback to receive value updates.
 //!
-//! # Thread-Local State Pattern
+//! # State Ownership
 //!
-//! This component uses `thread_local!` storage to persist focus handles and
-//! edit state across renders. This is necessary because GPUI's `RenderOnce`
-//! components are recreated on each render, but we need state to persist:
-//!
-//! - **Focus handles**: Must be the same instance across renders or focus is lost
-//! - **Edit state**: Cursor position, text, and selection must persist during editing
-//!
-//! ## Memory Considerations
-//!
-//! The thread-local `HashMap` entries grow as new element IDs are used and are
-//! never automatically cleaned up. For most applications this is fine because:
-//! - Element IDs are typically static or part of a bounded set
-//! - The stored data is small (FocusHandle, EditState)
-//!
-//! If you have dynamic element IDs (e.g., from a virtualized list), consider:
-//! 1. Using a stable ID scheme that reuses IDs
-//! 2. Calling `cleanup_number_input_state(id)` when components are removed
-//!
-//! ## Cleanup Function
-//!
-//! To manually clean up state for a removed element:
-//! ```rust,ignore
-//! cleanup_number_input_state(&element_id);
-//! ```
+//! `NumberInput` is a `RenderOnce` component, so its focus handle and edit
+//! state are kept in a per-thread registry keyed by element ID. The registry
+//! holds them weakly: the rendered element owns them, and they are dropped
+//! automatically once the input stops being rendered.
 
 use crate::ComponentTheme;
+use crate::audio::{ScrubMapping, end_scrub, is_scrubbing, start_scrub, update_scrub};
+use crate::callback::Callback;
+use crate::focus::{FocusAudit, FocusRing, FocusRingExt};
+use crate::scale::Scale;
+use crate::state_registry::ElementStateRegistry;
+use crate::stepper::{StepButton, StepDirection, StepperTheme};
 use crate::theme::ThemeExt;
+use crate::validation::BuilderWarnings;
 use gpui::prelude::*;
 use gpui::*;
 use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
 
-// Thread-local registry for focus handles, keyed by element ID.
-thread_local! {
-    static NUMBER_INPUT_FOCUS_HANDLES: RefCell<HashMap<ElementId, FocusHandle>> = RefCell::new(HashMap::new());
-}
+/// Pixels of linear scrubbing per step
+const SCRUB_PIXELS_PER_STEP: f32 = 4.0;
+
+/// Pixels of logarithmic scrubbing across the whole range
+const SCRUB_RANGE_PIXELS: f32 = 300.0;
 
-// Thread-local registry for edit state, keyed by element ID.
+// Thread-local registry of focus handles and edit state, keyed by element ID.
+// Entries are weak, so state is owned by the rendered NumberInput and freed with it.
 thread_local! {
-    static NUMBER_INPUT_EDIT_STATES: RefCell<HashMap<ElementId, Rc<RefCell<NumberEditState>>>> = RefCell::new(HashMap::new());
+    static NUMBER_INPUT_STATES: RefCell<ElementStateRegistry<NumberEditState>> =
+        RefCell::new(ElementStateRegistry::default());
 }
 
-/// Clean up thread-local state for a NumberInput element.
+/// Formerly removed the state of a NumberInput element.
 ///
-/// Call this when removing a NumberInput with a dynamic element ID to prevent
-/// memory leaks. For static element IDs, cleanup is not necessary.
-///
-/// # Example
-/// ```rust,ignore
-/// // When removing a dynamically-created NumberInput
-/// cleanup_number_input_state(&ElementId::Name(format!("input-{}", item_id).into()));
-/// ```
-pub fn cleanup_number_input_state(id: &ElementId) {
-    NUMBER_INPUT_FOCUS_HANDLES.with(|handles| {
-        handles.borrow_mut().remove(id);
-    });
-    NUMBER_INPUT_EDIT_STATES.with(|states| {
-        states.borrow_mut().remove(id);
-    });
-}
+/// State is now owned by the rendered element and freed when it is no
+/// longer rendered, so this does nothing.
+#[deprecated(
+    note = "NumberInput state is freed automatically when the element is no longer rendered"
+)]
+pub fn cleanup_number_input_state(_id: &ElementId) {}
 
 /// Internal editing state for the number input
 #[derive(Clone, Default)]
@@ -246,6 +226,8 @@ pub struct NumberInputTheme {
 /// Number input size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum NumberInputSize {
+    /// Extra small size
+    Xs,
     /// Small size
     Sm,
     /// Medium size (default)
@@ -253,48 +235,51 @@ pub enum NumberInputSize {
     Md,
     /// Large size
     Lg,
+    /// Extra large size
+    Xl,
 }
 
-impl From<crate::ComponentSize> for NumberInputSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs | crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg | crate::ComponentSize::Xl => Self::Lg,
-        }
-    }
-}
+crate::size::component_size_conversions!(NumberInputSize);
 
 impl NumberInputSize {
+    /// Same as [`ComponentSize::control_height`](crate::ComponentSize::control_height)
     fn height(&self) -> f32 {
         match self {
-            Self::Sm => 24.0,
+            Self::Xs => 20.0,
+            Self::Sm => 26.0,
             Self::Md => 32.0,
             Self::Lg => 40.0,
+            Self::Xl => 48.0,
         }
     }
 
     fn button_width(&self) -> f32 {
         match self {
-            Self::Sm => 20.0,
+            Self::Xs => 16.0,
+            Self::Sm => 22.0,
             Self::Md => 28.0,
             Self::Lg => 36.0,
+            Self::Xl => 44.0,
         }
     }
 
     fn font_size(&self) -> f32 {
         match self {
+            Self::Xs => 10.0,
             Self::Sm => 11.0,
             Self::Md => 13.0,
             Self::Lg => 15.0,
+            Self::Xl => 17.0,
         }
     }
 
     fn padding(&self) -> f32 {
         match self {
+            Self::Xs => 4.0,
             Self::Sm => 4.0,
             Self::Md => 8.0,
             Self::Lg => 12.0,
+            Self::Xl => 16.0,
         }
     }
 }
@@ -316,8 +301,9 @@ pub struct NumberInput {
     size: NumberInputSize,
     width: Option<f32>,
     disabled: bool,
+    scrub: Option<Scale>,
     theme: Option<NumberInputTheme>,
-    on_change: Option<Box<dyn Fn(f64, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<f64>>,
 }
 
 impl NumberInput {
@@ -335,6 +321,7 @@ impl NumberInput {
             size: NumberInputSize::default(),
             width: None,
             disabled: false,
+            scrub: None,
             theme: None,
             on_change: None,
         }
@@ -448,6 +435,21 @@ impl NumberInput {
         self
     }
 
+    /// Enable drag-to-scrub on the value
+    ///
+    /// Dragging the value right or up increases it; hold Shift for fine and
+    /// Ctrl/Cmd for coarse changes. `Scale::Linear` moves one step every 4
+    /// pixels (a tenth of a step with Shift). `Scale::Logarithmic` spreads
+    /// the range over 300 pixels with equal distance per octave, for
+    /// frequency fields such as 20 Hz–20 kHz; it needs a finite, positive
+    /// range and scrubs linearly otherwise.
+    ///
+    /// A click without dragging still edits the text.
+    pub fn scrub(mut self, scale: Scale) -> Self {
+        self.scrub = Some(scale);
+        self
+    }
+
     /// Set the theme
     pub fn theme(mut self, theme: NumberInputTheme) -> Self {
         self.theme = Some(theme);
@@ -456,7 +458,7 @@ impl NumberInput {
 
     /// Set value change handler (called on button click, scroll, keyboard, or text edit confirm)
     pub fn on_change(mut self, handler: impl Fn(f64, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(handler));
         self
     }
 
@@ -499,22 +501,40 @@ impl RenderOnce for NumberInput {
         let decimals = self.decimals;
         let unit_clone = self.unit.clone();
 
-        // Get or create focus handle for this element
-        let focus_handle = NUMBER_INPUT_FOCUS_HANDLES.with(|handles| {
-            let mut handles = handles.borrow_mut();
-            handles
-                .entry(self.id.clone())
-                .or_insert_with(|| cx.focus_handle())
-                .clone()
+        let mut warnings = BuilderWarnings::new("NumberInput", &self.id);
+        warnings.check(min <= max, || {
+            format!("min {min} is greater than max {max}")
+        });
+        warnings.check(step.is_finite() && step > 0.0, || {
+            format!("step {step} is not a positive number")
+        });
+        warnings.check(min > max || (min..=max).contains(&current_value), || {
+            format!("value {current_value} is outside [{min}, {max}]")
         });
+        let log_range = min > 0.0 && max.is_finite() && min < max;
+        warnings.check(self.scrub != Some(Scale::Logarithmic) || log_range, || {
+            format!("logarithmic scrub needs a finite positive range, got [{min}, {max}]")
+        });
+        let scrub_mapping = self.scrub.map(|scale| match scale {
+            Scale::Logarithmic if log_range => ScrubMapping::Range {
+                scale,
+                pixels: SCRUB_RANGE_PIXELS,
+            },
+            _ => ScrubMapping::Step {
+                step,
+                pixels_per_step: SCRUB_PIXELS_PER_STEP,
+            },
+        });
+
+        // Get or create focus handle for this element
+        let focus_handle =
+            NUMBER_INPUT_STATES.with(|states| states.borrow_mut().focus_handle(&self.id, cx));
 
         // Get or create edit state for this element
-        let edit_state = NUMBER_INPUT_EDIT_STATES.with(|states| {
-            let mut states = states.borrow_mut();
+        let edit_state = NUMBER_INPUT_STATES.with(|states| {
             states
-                .entry(self.id.clone())
-                .or_insert_with(|| Rc::new(RefCell::new(NumberEditState::default())))
-                .clone()
+                .borrow_mut()
+                .state(&self.id, NumberEditState::default)
         });
 
         // Check if we're focused - editing is only active when focused
@@ -527,9 +547,9 @@ impl RenderOnce for NumberInput {
                 // Parse and confirm the value on focus loss
                 if let Some(value) =
                     Self::parse_value_str(&state.text, self.unit.as_ref(), min, max)
-                    && let Some(ref handler) = self.on_change
+                    && let Some(ref on_change) = self.on_change
                 {
-                    handler(value, _window, cx);
+                    on_change.call(value, _window, cx);
                 }
                 // Clear editing state
                 state.editing = false;
@@ -556,10 +576,10 @@ impl RenderOnce for NumberInput {
         let value_id = ElementId::Name(SharedString::from(format!("{}-value", parent_id)));
         let inc_id = ElementId::Name(SharedString::from(format!("{}-inc", parent_id)));
 
-        // Wrap handler in Rc for sharing
-        let on_change_rc = self.on_change.map(Rc::new);
+        // Shared by the buttons, keyboard and scroll handlers
+        let on_change = self.on_change;
 
-        let mut container = div().flex().flex_col().gap_1();
+        let mut container = div().relative().flex().flex_col().gap_1();
 
         // Label
         if let Some(label) = self.label {
@@ -596,41 +616,33 @@ impl RenderOnce for NumberInput {
             input_row = input_row.opacity(theme.disabled_opacity);
         }
 
-        // Decrement button (−)
-        let button_bg = theme.button_bg;
-        let button_hover = theme.button_hover;
-        let button_active = theme.button_active;
-        let button_text = theme.button_text;
+        // Decrement and increment buttons, stepping while held
+        let step_theme = StepperTheme {
+            button_bg: theme.button_bg,
+            button_hover: theme.button_hover,
+            button_active: theme.button_active,
+            button_text: theme.button_text,
+            border: theme.border,
+            // The whole row is already dimmed when disabled
+            disabled_opacity: 1.0,
+        };
         let text_color = theme.text;
-
-        let mut dec_button = div()
-            .id(dec_id)
-            .flex()
-            .items_center()
-            .justify_center()
-            .w(px(button_width))
-            .h_full()
-            .bg(button_bg)
-            .text_color(button_text)
-            .font_weight(FontWeight::BOLD)
-            .child("−");
-
-        if !disabled {
-            dec_button = dec_button
-                .cursor_pointer()
-                .hover(move |s| s.bg(button_hover))
-                .active(move |s| s.bg(button_active));
-
-            if let Some(ref handler_rc) = on_change_rc {
-                let handler = handler_rc.clone();
-                dec_button = dec_button.on_mouse_down(MouseButton::Left, move |_, window, cx| {
-                    let new_value = (current_value - step).clamp(min, max);
-                    handler(new_value, window, cx);
+        let step_button = |id: ElementId, direction: StepDirection| {
+            let mut button = StepButton::new(id, direction)
+                .size(self.size)
+                .width(px(button_width))
+                .disabled(disabled)
+                .theme(step_theme.clone());
+            if let Some(on_change) = on_change.clone() {
+                button = button.on_step(move |direction, window, cx| {
+                    let new_value = (current_value + direction.sign() * step).clamp(min, max);
+                    on_change.call(new_value, window, cx);
                 });
             }
-        } else {
-            dec_button = dec_button.cursor_not_allowed();
-        }
+            button
+        };
+        let dec_button = step_button(dec_id, StepDirection::Decrement);
+        let inc_button = step_button(inc_id, StepDirection::Increment);
 
         input_row = input_row.child(dec_button);
 
@@ -676,6 +688,13 @@ impl RenderOnce for NumberInput {
             .text_color(value_text_color)
             .track_focus(&focus_handle)
             .focusable()
+            .focus_ring(FocusRing::from_theme(&global_theme))
+            .relative()
+            .children(FocusAudit::marker(
+                self.id.to_string(),
+                Some(&focus_handle),
+                cx,
+            ))
             .child(display_element);
 
         // Apply selection background if selected
@@ -723,9 +742,53 @@ impl RenderOnce for NumberInput {
                 },
             );
 
+            // Drag to scrub: a press that moves past the click threshold
+            // leaves text editing and adjusts the value instead
+            if let (Some(mapping), Some(on_change)) = (scrub_mapping, on_change.clone()) {
+                if !editing {
+                    value_field = value_field.cursor_ew_resize();
+                }
+
+                let scrub_key = parent_id.clone();
+                value_field = value_field.on_mouse_down(MouseButton::Left, move |event, _, _| {
+                    if event.click_count == 1 {
+                        let (x, y) = (event.position.x.into(), event.position.y.into());
+                        start_scrub(&scrub_key, x, y, current_value);
+                    }
+                });
+
+                let scrub_key = parent_id.clone();
+                let edit_state_for_scrub = edit_state.clone();
+                value_field = value_field.on_mouse_move(move |event, window, cx| {
+                    if event.pressed_button != Some(MouseButton::Left) {
+                        return;
+                    }
+                    let (x, y) = (event.position.x.into(), event.position.y.into());
+                    if let Some(value) =
+                        update_scrub(&scrub_key, x, y, &event.modifiers, min, max, mapping)
+                    {
+                        {
+                            let mut state = edit_state_for_scrub.borrow_mut();
+                            state.editing = false;
+                            state.text.clear();
+                            state.text_selected = false;
+                        }
+                        on_change.call(value, window, cx);
+                    }
+                });
+
+                let scrub_key = parent_id.clone();
+                value_field = value_field.on_mouse_up(MouseButton::Left, move |_, window, _| {
+                    if is_scrubbing(&scrub_key) {
+                        window.refresh();
+                    }
+                    end_scrub(&scrub_key);
+                });
+            }
+
             // Keyboard handling
             let edit_state_for_key = edit_state.clone();
-            let on_change_key = on_change_rc.clone();
+            let on_change_key = on_change.clone();
             let unit_for_key = unit_clone.clone();
 
             value_field = value_field.on_key_down(move |event, window, cx| {
@@ -742,10 +805,10 @@ impl RenderOnce for NumberInput {
                             state.text_selected = false;
                             drop(state);
 
-                            if let Some(ref handler) = on_change_key
+                            if let Some(ref on_change) = on_change_key
                                 && let Some(value) = parsed
                             {
-                                handler(value, window, cx);
+                                on_change.call(value, window, cx);
                             }
                             window.refresh();
                         }
@@ -808,9 +871,9 @@ impl RenderOnce for NumberInput {
                     drop(state);
 
                     if let Some(v) = new_value
-                        && let Some(ref handler) = on_change_key
+                        && let Some(ref on_change) = on_change_key
                     {
-                        handler(v, window, cx);
+                        on_change.call(v, window, cx);
                     }
                 }
             });
@@ -818,41 +881,11 @@ impl RenderOnce for NumberInput {
 
         input_row = input_row.child(value_field);
 
-        // Increment button (+)
-        let mut inc_button = div()
-            .id(inc_id)
-            .flex()
-            .items_center()
-            .justify_center()
-            .w(px(button_width))
-            .h_full()
-            .bg(button_bg)
-            .text_color(button_text)
-            .font_weight(FontWeight::BOLD)
-            .child("+");
-
-        if !disabled {
-            inc_button = inc_button
-                .cursor_pointer()
-                .hover(move |s| s.bg(button_hover))
-                .active(move |s| s.bg(button_active));
-
-            if let Some(ref handler_rc) = on_change_rc {
-                let handler = handler_rc.clone();
-                inc_button = inc_button.on_mouse_down(MouseButton::Left, move |_, window, cx| {
-                    let new_value = (current_value + step).clamp(min, max);
-                    handler(new_value, window, cx);
-                });
-            }
-        } else {
-            inc_button = inc_button.cursor_not_allowed();
-        }
-
         input_row = input_row.child(inc_button);
 
         // Note: Scroll wheel handling removed to allow page scrolling.
         // Use +/- buttons or keyboard to adjust value.
 
-        container.child(input_row)
+        container.child(input_row).children(warnings.marker())
     }
 }
@@ -7,6 +7,7 @@
 //! - Drag to resize (via parent tracking mouse state)
 
 use crate::ComponentTheme;
+use crate::callback::Callback;
 use gpui::prelude::*;
 use gpui::*;
 
@@ -120,9 +121,9 @@ pub struct PaneDivider {
     /// Whether the panel is currently collapsed
     collapsed: bool,
     /// Callback when collapse state changes (receives new collapsed state)
-    on_toggle: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
+    on_toggle: Option<Callback<bool>>,
     /// Callback when drag starts (receives position in the drag axis: x for vertical, y for horizontal)
-    on_drag_start: Option<Box<dyn Fn(f32, &mut Window, &mut App) + 'static>>,
+    on_drag_start: Option<Callback<f32>>,
     /// Theme for styling
     theme: PaneDividerTheme,
     /// Thickness of the divider when not collapsed
@@ -184,7 +185,7 @@ impl PaneDivider {
 
     /// Set callback for when collapse state toggles (double-click or click when collapsed)
     pub fn on_toggle(mut self, callback: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
-        self.on_toggle = Some(Box::new(callback));
+        self.on_toggle = Some(Callback::new(callback));
         self
     }
 
@@ -196,7 +197,7 @@ impl PaneDivider {
         mut self,
         callback: impl Fn(f32, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_drag_start = Some(Box::new(callback));
+        self.on_drag_start = Some(Callback::new(callback));
         self
     }
 
@@ -298,7 +299,7 @@ impl PaneDivider {
                 if event.click_count == 2 {
                     // Double-click: toggle collapse
                     if let Some(ref toggle_cb) = on_toggle {
-                        toggle_cb(true, window, cx);
+                        toggle_cb.call(true, window, cx);
                     }
                 } else if event.click_count == 1 {
                     // Single click: start drag
@@ -308,7 +309,7 @@ impl PaneDivider {
                         } else {
                             event.position.y.into()
                         };
-                        drag_cb(pos, window, cx);
+                        drag_cb.call(pos, window, cx);
                     }
                 }
             });
@@ -425,7 +426,7 @@ impl PaneDivider {
         if let Some(toggle_cb) = on_toggle {
             base = base.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
                 // Any click on collapsed divider expands it
-                toggle_cb(false, window, cx);
+                toggle_cb.call(false, window, cx);
             });
         }
 
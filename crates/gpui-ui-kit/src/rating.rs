@@ -0,0 +1,278 @@
+//! Star rating
+//!
+//! [`Rating`] shows a value out of `max` as a row of icons, stars by
+//! default. With half steps each icon can be half filled, and the left half
+//! of an icon selects the half value. Hovering previews the value a click
+//! would set; read-only ratings only display the value.
+//!
+//! ```ignore
+//! Rating::new("review-score")
+//!     .value(3.5)
+//!     .half_steps(true)
+//!     .on_change(move |score, _window, cx| {
+//!         view.update(cx, |this, cx| {
+//!             this.score = score;
+//!             cx.notify();
+//!         });
+//!     })
+//! ```
+
+use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::size::ComponentSize;
+use crate::state_registry::ElementStateRegistry;
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+use std::cell::RefCell;
+
+// Hover preview of the rendered ratings, kept alive by their listeners
+thread_local! {
+    static RATING_STATES: RefCell<ElementStateRegistry<Option<f32>>> =
+        RefCell::new(ElementStateRegistry::default());
+}
+
+/// Theme colors for ratings
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct RatingTheme {
+    /// Color of the filled part of the icons
+    #[theme(default = 0xf59e0bff, from = warning)]
+    pub filled: Rgba,
+    /// Color of the empty part of the icons
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub empty: Rgba,
+}
+
+/// Round `value` to the nearest whole (or half) step within `0..=max`
+pub fn snap_rating(value: f32, max: u32, half_steps: bool) -> f32 {
+    if !value.is_finite() {
+        return 0.0;
+    }
+    let value = value.clamp(0.0, max as f32);
+    if half_steps {
+        (value * 2.0).round() / 2.0
+    } else {
+        value.round()
+    }
+}
+
+/// Filled fraction of the icon at `index` (0-based) for `value`
+fn fill_fraction(value: f32, index: u32) -> f32 {
+    (value - index as f32).clamp(0.0, 1.0)
+}
+
+/// Value set by clicking the icon at `index`, on its left half or not
+fn zone_value(index: u32, left_half: bool) -> f32 {
+    if left_half {
+        index as f32 + 0.5
+    } else {
+        index as f32 + 1.0
+    }
+}
+
+/// Row of icons rating a value out of `max`
+#[derive(IntoElement)]
+pub struct Rating {
+    id: ElementId,
+    value: f32,
+    max: u32,
+    half_steps: bool,
+    icon: SharedString,
+    size: ComponentSize,
+    read_only: bool,
+    theme: Option<RatingTheme>,
+    on_change: Option<Callback<f32>>,
+}
+
+impl Rating {
+    /// Create an empty five-star rating
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: 0.0,
+            max: 5,
+            half_steps: false,
+            icon: "★".into(),
+            size: ComponentSize::default(),
+            read_only: false,
+            theme: None,
+            on_change: None,
+        }
+    }
+
+    /// Set the value, rounded to the nearest step when rendered
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the number of icons (default: 5)
+    pub fn max(mut self, max: u32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Allow half values, shown as half-filled icons
+    pub fn half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// Replace the star with another glyph, e.g. "♥"
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Set the size
+    pub fn size(mut self, size: impl Into<ComponentSize>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Only display the value
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Set the rating theme
+    pub fn theme(mut self, theme: RatingTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called with the clicked value
+    pub fn on_change(mut self, handler: impl Fn(f32, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Callback::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Rating {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .unwrap_or_else(|| RatingTheme::from(&global_theme));
+        let interactive = !self.read_only && self.on_change.is_some();
+        let icon_size = self.size.icon_size() * 1.25;
+
+        let hover = interactive
+            .then(|| RATING_STATES.with_borrow_mut(|registry| registry.state(&self.id, || None)));
+        let shown = hover
+            .as_ref()
+            .and_then(|hover| *hover.borrow())
+            .unwrap_or(self.value);
+        let shown = snap_rating(shown, self.max, self.half_steps);
+
+        let mut row = div().id(self.id.clone()).flex().items_center();
+        if let Some(hover) = &hover {
+            let hover = hover.clone();
+            row = row.cursor_pointer().on_hover(move |hovered, window, _cx| {
+                if !hovered {
+                    *hover.borrow_mut() = None;
+                    window.refresh();
+                }
+            });
+        }
+
+        for index in 0..self.max {
+            let icon_text = || {
+                div()
+                    .flex()
+                    .flex_none()
+                    .items_center()
+                    .justify_center()
+                    .size(icon_size)
+                    .text_size(icon_size)
+                    .line_height(icon_size)
+                    .child(self.icon.clone())
+            };
+            let mut icon = div()
+                .relative()
+                .flex_none()
+                .size(icon_size)
+                .child(icon_text().text_color(theme.empty))
+                .child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .h_full()
+                        .w(icon_size * fill_fraction(shown, index))
+                        .overflow_hidden()
+                        .child(icon_text().text_color(theme.filled)),
+                );
+
+            if let (Some(hover), Some(on_change)) = (&hover, &self.on_change) {
+                let zones: &[bool] = if self.half_steps {
+                    &[true, false]
+                } else {
+                    &[false]
+                };
+                let zone_width = icon_size / zones.len() as f32;
+                for (position, &left_half) in zones.iter().enumerate() {
+                    let value = zone_value(index, left_half);
+                    let hover = hover.clone();
+                    let on_change = on_change.clone();
+                    icon = icon.child(
+                        div()
+                            .id(ElementId::Name(
+                                format!("{}-{}-{}", self.id, index, position).into(),
+                            ))
+                            .absolute()
+                            .top_0()
+                            .h_full()
+                            .left(zone_width * position as f32)
+                            .w(zone_width)
+                            .on_hover({
+                                let hover = hover.clone();
+                                move |hovered, window, _cx| {
+                                    if *hovered {
+                                        *hover.borrow_mut() = Some(value);
+                                        window.refresh();
+                                    }
+                                }
+                            })
+                            .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                                *hover.borrow_mut() = None;
+                                on_change.call(value, window, cx);
+                            }),
+                    );
+                }
+            }
+            row = row.child(icon);
+        }
+        row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snap_rating() {
+        assert_eq!(snap_rating(3.3, 5, false), 3.0);
+        assert_eq!(snap_rating(3.3, 5, true), 3.5);
+        assert_eq!(snap_rating(3.2, 5, true), 3.0);
+        assert_eq!(snap_rating(7.0, 5, true), 5.0);
+        assert_eq!(snap_rating(-1.0, 5, false), 0.0);
+        assert_eq!(snap_rating(f32::NAN, 5, true), 0.0);
+    }
+
+    #[test]
+    fn test_fill_fraction() {
+        assert_eq!(fill_fraction(3.5, 2), 1.0);
+        assert_eq!(fill_fraction(3.5, 3), 0.5);
+        assert_eq!(fill_fraction(3.5, 4), 0.0);
+    }
+
+    #[test]
+    fn test_zone_value() {
+        assert_eq!(zone_value(0, true), 0.5);
+        assert_eq!(zone_value(0, false), 1.0);
+        assert_eq!(zone_value(4, false), 5.0);
+    }
+}
@@ -0,0 +1,368 @@
+//! Deep-link routing between navigational components
+//!
+//! A [`Router`] entity holds the UI location as a string path, e.g.
+//! `"settings/audio/devices"`. Each navigational component (Tabs,
+//! Accordion, Wizard, a MiniApp sidebar) registers the keys it can show
+//! under its parent path, then reads its selection from the router and
+//! navigates through it instead of keeping its own state. This gives:
+//!
+//! - programmatic navigation: `router.navigate("settings/audio", cx)`
+//! - back/forward history
+//! - restoring the location from a saved string, e.g. with the session
+//!   store
+//!
+//! Paths are normalized against the registered keys: an unknown key ends
+//! the path, and a path ending at a registered parent continues with its
+//! first key, so `"settings"` opens the default tab of the settings page.
+//! Segments under unregistered parents are kept as they are.
+//!
+//! ```ignore
+//! let router = cx.new(|_| {
+//!     Router::new()
+//!         .route("", ["home", "settings"])
+//!         .route("settings", ["audio", "video"])
+//!         .route("settings/audio", ["devices", "latency"])
+//! });
+//!
+//! // In render: a tab bar for the settings page
+//! let settings = router.clone();
+//! Tabs::new("settings-tabs")
+//!     .tabs(vec![TabItem::new("audio", "Audio"), TabItem::new("video", "Video")])
+//!     .selected_index(router.read(cx).active_index("settings").unwrap_or(0))
+//!     .on_change(move |index, _window, cx| {
+//!         settings.update(cx, |router, cx| router.select_index("settings", index, cx));
+//!     });
+//!
+//! // Re-render whenever the location changes, whoever changed it
+//! cx.observe(&router, |_, _, cx| cx.notify()).detach();
+//! ```
+
+use gpui::{Context, EventEmitter, SharedString};
+use std::collections::HashMap;
+
+/// Maximum number of locations kept in the back history
+const MAX_HISTORY: usize = 100;
+
+/// Events emitted by [`Router`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouterEvent {
+    /// The location changed, to the given path
+    Navigated(SharedString),
+}
+
+/// UI location shared by navigational components, with history
+#[derive(Debug, Clone, Default)]
+pub struct Router {
+    /// Keys of each registered parent path, the first being the default
+    routes: HashMap<String, Vec<SharedString>>,
+    /// Current location
+    segments: Vec<SharedString>,
+    back: Vec<Vec<SharedString>>,
+    forward: Vec<Vec<SharedString>>,
+}
+
+impl EventEmitter<RouterEvent> for Router {}
+
+impl Router {
+    /// Create a router at the root location, with no routes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the keys a component shows under `parent` (`""` for the
+    /// top level); the first key is the default one
+    ///
+    /// The current location is normalized again, so a router created with
+    /// its routes starts at the default location.
+    pub fn route(
+        mut self,
+        parent: &str,
+        keys: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.register(parent, keys);
+        self
+    }
+
+    /// Register or replace the keys of `parent`, e.g. when tabs are added
+    ///
+    /// Observers are not notified if this moves the current location; call
+    /// [`Router::navigate`] with [`Router::path`] for that.
+    pub fn register(
+        &mut self,
+        parent: &str,
+        keys: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) {
+        let keys = keys.into_iter().map(Into::into).collect();
+        self.routes.insert(normalize(parent), keys);
+        self.segments = self.resolve(&self.path());
+    }
+
+    /// Current location, e.g. `"settings/audio/devices"`
+    pub fn path(&self) -> String {
+        to_path(&self.segments)
+    }
+
+    /// Segments of the current location
+    pub fn segments(&self) -> &[SharedString] {
+        &self.segments
+    }
+
+    /// Key shown under `parent`, if the location goes through it
+    pub fn active(&self, parent: &str) -> Option<&SharedString> {
+        let depth = split(parent).count();
+        let is_under = split(parent)
+            .zip(&self.segments)
+            .all(|(segment, current)| segment == current.as_ref());
+        self.segments.get(depth).filter(|_| is_under)
+    }
+
+    /// Position of the key shown under `parent` among its registered keys
+    ///
+    /// Meant for index-based components such as Tabs or Wizard.
+    pub fn active_index(&self, parent: &str) -> Option<usize> {
+        let active = self.active(parent)?;
+        self.routes
+            .get(&normalize(parent))?
+            .iter()
+            .position(|key| key == active)
+    }
+
+    /// Whether the location is `path` or below it
+    pub fn is_active(&self, path: &str) -> bool {
+        let mut segments = self.segments.iter();
+        split(path).all(|segment| {
+            segments
+                .next()
+                .is_some_and(|current| current.as_ref() == segment)
+        })
+    }
+
+    /// Resolve `path` against the registered routes, see the module
+    /// documentation
+    pub fn resolve(&self, path: &str) -> Vec<SharedString> {
+        let mut segments: Vec<SharedString> = Vec::new();
+        for segment in split(path) {
+            if let Some(keys) = self.routes.get(&to_path(&segments))
+                && !keys.iter().any(|key| key.as_ref() == segment)
+            {
+                break;
+            }
+            segments.push(SharedString::from(segment.to_string()));
+        }
+        // Continue with the default key of each registered parent
+        while let Some(first) = self
+            .routes
+            .get(&to_path(&segments))
+            .and_then(|keys| keys.first())
+        {
+            segments.push(first.clone());
+        }
+        segments
+    }
+
+    /// Go to `path`, keeping the current location in the back history
+    pub fn navigate(&mut self, path: &str, cx: &mut Context<Self>) {
+        if self.go_to(self.resolve(path)) {
+            self.changed(cx);
+        }
+    }
+
+    /// Show `key` under `parent`, e.g. from a tab bar's change handler
+    pub fn select(&mut self, parent: &str, key: &str, cx: &mut Context<Self>) {
+        self.navigate(&join(parent, key), cx);
+    }
+
+    /// Show the key at `index` among the registered keys of `parent`
+    pub fn select_index(&mut self, parent: &str, index: usize, cx: &mut Context<Self>) {
+        let key = self
+            .routes
+            .get(&normalize(parent))
+            .and_then(|keys| keys.get(index))
+            .cloned();
+        if let Some(key) = key {
+            self.select(parent, &key, cx);
+        }
+    }
+
+    /// Whether there is a previous location
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    /// Whether there is a location to go forward to
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    /// Go to the previous location, returning whether there was one
+    pub fn back(&mut self, cx: &mut Context<Self>) -> bool {
+        let moved = self.go_back();
+        if moved {
+            self.changed(cx);
+        }
+        moved
+    }
+
+    /// Go to the location left with [`Router::back`], returning whether
+    /// there was one
+    pub fn forward(&mut self, cx: &mut Context<Self>) -> bool {
+        let moved = self.go_forward();
+        if moved {
+            self.changed(cx);
+        }
+        moved
+    }
+
+    /// Go to a saved location (see [`Router::path`]), clearing the history
+    pub fn restore(&mut self, path: &str, cx: &mut Context<Self>) {
+        let segments = self.resolve(path);
+        let before = std::mem::replace(&mut self.segments, segments);
+        self.back.clear();
+        self.forward.clear();
+        if self.segments != before {
+            self.changed(cx);
+        }
+    }
+
+    /// Move to `segments`, returning whether the location changed
+    fn go_to(&mut self, segments: Vec<SharedString>) -> bool {
+        if segments == self.segments {
+            return false;
+        }
+        let previous = std::mem::replace(&mut self.segments, segments);
+        self.back.push(previous);
+        if self.back.len() > MAX_HISTORY {
+            self.back.remove(0);
+        }
+        self.forward.clear();
+        true
+    }
+
+    fn go_back(&mut self) -> bool {
+        let Some(previous) = self.back.pop() else {
+            return false;
+        };
+        let current = std::mem::replace(&mut self.segments, previous);
+        self.forward.push(current);
+        true
+    }
+
+    fn go_forward(&mut self) -> bool {
+        let Some(next) = self.forward.pop() else {
+            return false;
+        };
+        let current = std::mem::replace(&mut self.segments, next);
+        self.back.push(current);
+        true
+    }
+
+    /// Notify observers and emit [`RouterEvent::Navigated`]
+    fn changed(&self, cx: &mut Context<Self>) {
+        cx.emit(RouterEvent::Navigated(self.path().into()));
+        cx.notify();
+    }
+}
+
+/// Non-empty segments of a path
+fn split(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// Path without empty segments, as routes are keyed
+fn normalize(path: &str) -> String {
+    split(path).collect::<Vec<_>>().join("/")
+}
+
+/// Path of a list of segments
+fn to_path(segments: &[SharedString]) -> String {
+    let segments: Vec<&str> = segments.iter().map(|segment| segment.as_ref()).collect();
+    segments.join("/")
+}
+
+/// Path of `key` under `parent`
+fn join(parent: &str, key: &str) -> String {
+    let parent = normalize(parent);
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}/{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn router() -> Router {
+        Router::new()
+            .route("", ["home", "settings"])
+            .route("settings", ["audio", "video"])
+            .route("settings/audio", ["devices", "latency"])
+    }
+
+    #[test]
+    fn test_resolve_fills_defaults_and_drops_unknown_keys() {
+        let router = router();
+        assert_eq!(router.path(), "home");
+        assert_eq!(
+            to_path(&router.resolve("settings")),
+            "settings/audio/devices"
+        );
+        assert_eq!(
+            to_path(&router.resolve("/settings/audio/latency/")),
+            "settings/audio/latency"
+        );
+        // An unknown key ends the path, which then continues with defaults
+        assert_eq!(
+            to_path(&router.resolve("settings/midi/ports")),
+            "settings/audio/devices"
+        );
+        assert_eq!(to_path(&router.resolve("nowhere")), "home");
+        // Segments under unregistered parents are kept
+        assert_eq!(to_path(&router.resolve("home/recent/42")), "home/recent/42");
+    }
+
+    #[test]
+    fn test_active_keys() {
+        let mut router = router();
+        router.go_to(router.resolve("settings/audio/latency"));
+        assert_eq!(router.active("").map(|k| k.as_ref()), Some("settings"));
+        assert_eq!(router.active("settings").map(|k| k.as_ref()), Some("audio"));
+        assert_eq!(router.active_index("settings/audio"), Some(1));
+        // The location does not go through home
+        assert_eq!(router.active("home"), None);
+        assert!(router.is_active("settings/audio"));
+        assert!(!router.is_active("settings/video"));
+        assert!(router.is_active(""));
+    }
+
+    #[test]
+    fn test_back_and_forward_history() {
+        let mut router = router();
+        assert!(router.go_to(router.resolve("settings")));
+        assert!(router.go_to(router.resolve("settings/video")));
+        // Same location: no history entry
+        assert!(!router.go_to(router.resolve("settings/video")));
+
+        assert!(router.go_back());
+        assert_eq!(router.path(), "settings/audio/devices");
+        assert!(router.go_back());
+        assert_eq!(router.path(), "home");
+        assert!(!router.go_back());
+
+        assert!(router.go_forward());
+        assert_eq!(router.path(), "settings/audio/devices");
+        // Navigating drops the forward history
+        router.go_to(router.resolve("home"));
+        assert!(!router.can_go_forward());
+        assert!(router.can_go_back());
+    }
+
+    #[test]
+    fn test_register_keeps_location_valid() {
+        let mut router = router();
+        router.go_to(router.resolve("settings/video"));
+        router.register("settings", ["audio", "display"]);
+        assert_eq!(router.path(), "settings/audio/devices");
+    }
+}
@@ -16,7 +16,10 @@ use gpui::prelude::*;
 use gpui::{deferred, *};
 
 use crate::ComponentTheme;
-use crate::theme::ThemeExt;
+use crate::app::recorder::record_value;
+use crate::callback::Callback;
+use crate::theme::{Elevation, ElevationTokens, ThemeExt};
+use crate::validation::BuilderWarnings;
 
 /// Theme colors for select styling
 #[derive(Debug, Clone, ComponentTheme)]
@@ -66,11 +69,19 @@ pub struct SelectTheme {
     /// Arrow/chevron color
     #[theme(default = 0x666666ff, from = text_muted)]
     pub arrow_color: Rgba,
+    /// Dropdown shadow
+    #[theme(
+        default_expr = "ElevationTokens::default().shadow(Elevation::Overlay)",
+        from_expr = "theme.elevation.shadow(Elevation::Overlay)"
+    )]
+    pub dropdown_shadow: Vec<BoxShadow>,
 }
 
 /// Select size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SelectSize {
+    /// Extra small
+    Xs,
     /// Small
     Sm,
     /// Medium (default)
@@ -78,17 +89,11 @@ pub enum SelectSize {
     Md,
     /// Large
     Lg,
+    /// Extra large
+    Xl,
 }
 
-impl From<crate::ComponentSize> for SelectSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs | crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg | crate::ComponentSize::Xl => Self::Lg,
-        }
-    }
-}
+crate::size::component_size_conversions!(SelectSize);
 
 /// A select option
 #[derive(Clone)]
@@ -130,9 +135,9 @@ pub struct Select {
     is_open: bool,
     highlighted_index: Option<usize>,
     theme: Option<SelectTheme>,
-    on_change: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
-    on_toggle: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
-    on_highlight: Option<Box<dyn Fn(Option<usize>, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<SharedString>>,
+    on_toggle: Option<Callback<bool>>,
+    on_highlight: Option<Callback<Option<usize>>>,
 }
 
 impl Select {
@@ -214,13 +219,15 @@ impl Select {
         mut self,
         handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(move |value: SharedString, window, cx| {
+            handler(&value, window, cx)
+        }));
         self
     }
 
     /// Set toggle handler (called when trigger is clicked)
     pub fn on_toggle(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
-        self.on_toggle = Some(Box::new(handler));
+        self.on_toggle = Some(Callback::new(handler));
         self
     }
 
@@ -229,17 +236,29 @@ impl Select {
         mut self,
         handler: impl Fn(Option<usize>, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_highlight = Some(Box::new(handler));
+        self.on_highlight = Some(Callback::new(handler));
         self
     }
 
     /// Build into element
     fn build(self, theme: &SelectTheme) -> Div {
-        let (py, _text_size_class) = match self.size {
-            SelectSize::Sm => (px(4.0), "sm"),
-            SelectSize::Md => (px(8.0), "md"),
-            SelectSize::Lg => (px(12.0), "lg"),
-        };
+        let size = crate::ComponentSize::from(self.size);
+
+        let mut warnings = BuilderWarnings::new("Select", &self.id);
+        if let Some(selected) = &self.selected {
+            warnings.check(
+                self.options.iter().any(|option| &option.value == selected),
+                || format!("selected value \"{selected}\" is not one of the options"),
+            );
+        }
+        if let Some(index) = self.highlighted_index {
+            warnings.check(index < self.options.len(), || {
+                format!(
+                    "highlighted index {index} is out of range ({} options)",
+                    self.options.len()
+                )
+            });
+        }
 
         let mut container = div().relative().flex().flex_col().gap_1();
 
@@ -273,12 +292,13 @@ impl Select {
         let dropdown_id = self.id.clone();
 
         let mut trigger = div()
+            .debug_selector(|| dropdown_id.to_string())
             .id(self.id)
             .flex()
             .items_center()
             .justify_between()
-            .px_3()
-            .py(py)
+            .h(size.control_height())
+            .px(size.padding_x())
             .min_w(px(120.0))
             .bg(theme.trigger_bg)
             .border_1()
@@ -288,16 +308,17 @@ impl Select {
             .focusable();
 
         // Apply text size
-        trigger = match self.size {
-            SelectSize::Sm => trigger.text_xs(),
-            SelectSize::Md => trigger.text_sm(),
-            SelectSize::Lg => trigger,
-        };
-
-        // Convert handlers to Rc upfront so we can use them in closures
-        let on_toggle_rc = self.on_toggle.map(std::rc::Rc::new);
-        let on_change_rc = self.on_change.map(std::rc::Rc::new);
-        let on_highlight_rc = self.on_highlight.map(std::rc::Rc::new);
+        trigger = size.apply_text_size(trigger);
+
+        let on_toggle_rc = self.on_toggle;
+        let record_id = dropdown_id.clone();
+        let on_change_rc = self.on_change.map(|handler| {
+            Callback::new(move |value: SharedString, window, cx| {
+                record_value(cx, &record_id, value.to_string());
+                handler.call(value, window, cx);
+            })
+        });
+        let on_highlight_rc = self.on_highlight;
 
         let currently_open = self.is_open;
         let num_options = self.options.len();
@@ -313,7 +334,7 @@ impl Select {
             if let Some(ref handler) = on_toggle_rc {
                 let handler = handler.clone();
                 trigger = trigger.on_mouse_down(MouseButton::Left, move |_, window, cx| {
-                    (handler)(!currently_open, window, cx);
+                    handler.call(!currently_open, window, cx);
                 });
             }
 
@@ -328,11 +349,11 @@ impl Select {
                     match event.keystroke.key.as_str() {
                         "space" | " " => {
                             // Toggle open/closed
-                            toggle_rc(!currently_open, window, cx);
+                            toggle_rc.call(!currently_open, window, cx);
                         }
                         "escape" if currently_open => {
                             // Close dropdown
-                            toggle_rc(false, window, cx);
+                            toggle_rc.call(false, window, cx);
                         }
                         "enter" if currently_open => {
                             // Select highlighted option
@@ -341,9 +362,13 @@ impl Select {
                                 && !options_clone[idx].disabled
                             {
                                 if let Some(ref change_handler) = change_rc {
-                                    change_handler(&options_clone[idx].value, window, cx);
+                                    change_handler.call(
+                                        options_clone[idx].value.clone(),
+                                        window,
+                                        cx,
+                                    );
                                 }
-                                toggle_rc(false, window, cx);
+                                toggle_rc.call(false, window, cx);
                             }
                         }
                         "down" | "up" if currently_open => {
@@ -372,7 +397,7 @@ impl Select {
                             };
 
                             if let Some(ref highlight_handler) = highlight_rc {
-                                highlight_handler(new_idx, window, cx);
+                                highlight_handler.call(new_idx, window, cx);
                             }
                         }
                         _ => {}
@@ -401,7 +426,8 @@ impl Select {
         // Use deferred() to ensure the dropdown renders on top of other content
         if self.is_open {
             let mut dropdown = div()
-                .id((dropdown_id, "dropdown"))
+                .debug_selector(|| format!("{dropdown_id}-dropdown"))
+                .id((dropdown_id.clone(), "dropdown"))
                 .absolute()
                 .top_full()
                 .left_0()
@@ -411,7 +437,7 @@ impl Select {
                 .border_1()
                 .border_color(theme.dropdown_border)
                 .rounded_md()
-                .shadow_lg()
+                .shadow(theme.dropdown_shadow.clone())
                 .max_h(px(200.0))
                 .overflow_y_scroll()
                 .py_1()
@@ -423,17 +449,14 @@ impl Select {
                 let option_value = option.value.clone();
 
                 let mut option_el = div()
+                    .debug_selector(|| format!("{dropdown_id}-option-{idx}"))
                     .id(("select-option", idx))
-                    .px_3()
+                    .px(size.padding_x())
                     .py(px(6.0))
                     .cursor_pointer();
 
                 // Apply text size
-                option_el = match self.size {
-                    SelectSize::Sm => option_el.text_xs(),
-                    SelectSize::Md => option_el.text_sm(),
-                    SelectSize::Lg => option_el,
-                };
+                option_el = size.apply_text_size(option_el);
 
                 if option.disabled {
                     option_el = option_el
@@ -466,11 +489,11 @@ impl Select {
                         option_el.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
                             // Call change handler if provided
                             if let Some(ref handler) = change_handler {
-                                handler(&option_value, window, cx);
+                                handler.call(option_value.clone(), window, cx);
                             }
                             // Close the dropdown
                             if let Some(ref handler) = toggle_handler {
-                                handler(false, window, cx);
+                                handler.call(false, window, cx);
                             }
                         });
                 }
@@ -483,7 +506,7 @@ impl Select {
             container = container.child(deferred(dropdown).with_priority(1));
         }
 
-        container
+        container.children(warnings.marker())
     }
 }
 
@@ -508,3 +531,186 @@ impl IntoElement for Select {
         self.build(&theme)
     }
 }
+
+/// Events emitted by [`SelectView`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectEvent {
+    /// The user picked a different option
+    SelectionChanged { value: SharedString },
+}
+
+/// A [`Select`] that manages its own open, highlight and selection state
+///
+/// In controlled mode the selection only changes through
+/// [`SelectView::set_selected`]; the dropdown still opens and closes itself.
+///
+/// Parent entities subscribe to [`SelectEvent`] instead of wiring closures:
+///
+/// ```ignore
+/// let select = cx.new(|_| SelectView::new("device", options));
+/// cx.subscribe(&select, |this, _, event, cx| match event {
+///     SelectEvent::SelectionChanged { value } => this.device = value.clone(),
+/// })
+/// .detach();
+/// ```
+pub struct SelectView {
+    id: ElementId,
+    options: Vec<SelectOption>,
+    selected: Option<SharedString>,
+    placeholder: Option<SharedString>,
+    label: Option<SharedString>,
+    size: SelectSize,
+    disabled: bool,
+    is_open: bool,
+    highlighted_index: Option<usize>,
+    controlled: bool,
+}
+
+impl EventEmitter<SelectEvent> for SelectView {}
+
+impl SelectView {
+    /// Create a new select view
+    pub fn new(id: impl Into<ElementId>, options: Vec<SelectOption>) -> Self {
+        Self {
+            id: id.into(),
+            options,
+            selected: None,
+            placeholder: None,
+            label: None,
+            size: SelectSize::default(),
+            disabled: false,
+            is_open: false,
+            highlighted_index: None,
+            controlled: false,
+        }
+    }
+
+    /// Set the initially selected value
+    pub fn selected(mut self, value: impl Into<SharedString>) -> Self {
+        self.selected = Some(value.into());
+        self
+    }
+
+    /// Set placeholder text
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set label
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set size
+    pub fn size(mut self, size: SelectSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Only emit events, leaving state changes to the parent
+    pub fn controlled(mut self, controlled: bool) -> Self {
+        self.controlled = controlled;
+        self
+    }
+
+    /// Get the selected value
+    pub fn selected_value(&self) -> Option<&SharedString> {
+        self.selected.as_ref()
+    }
+
+    /// Whether the option list is open
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Select a value without emitting an event
+    pub fn set_selected(&mut self, value: Option<SharedString>, cx: &mut Context<Self>) {
+        self.selected = value;
+        cx.notify();
+    }
+
+    /// Replace the options, dropping a selection that is no longer available
+    pub fn set_options(&mut self, options: Vec<SelectOption>, cx: &mut Context<Self>) {
+        if let Some(selected) = &self.selected
+            && !options.iter().any(|option| &option.value == selected)
+        {
+            self.selected = None;
+        }
+        self.options = options;
+        self.highlighted_index = None;
+        cx.notify();
+    }
+
+    /// Enable or disable the select
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut Context<Self>) {
+        self.disabled = disabled;
+        if disabled {
+            self.is_open = false;
+        }
+        cx.notify();
+    }
+
+    fn select(&mut self, value: &SharedString, cx: &mut Context<Self>) {
+        if self.selected.as_ref() != Some(value) {
+            if !self.controlled {
+                self.selected = Some(value.clone());
+            }
+            cx.emit(SelectEvent::SelectionChanged {
+                value: value.clone(),
+            });
+        }
+        cx.notify();
+    }
+
+    fn toggle(&mut self, open: bool, cx: &mut Context<Self>) {
+        self.is_open = open;
+        self.highlighted_index = if open {
+            self.selected
+                .as_ref()
+                .and_then(|selected| self.options.iter().position(|o| &o.value == selected))
+        } else {
+            None
+        };
+        cx.notify();
+    }
+
+    fn highlight(&mut self, index: Option<usize>, cx: &mut Context<Self>) {
+        self.highlighted_index = index;
+        cx.notify();
+    }
+}
+
+impl Render for SelectView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = SelectTheme::from(&cx.theme());
+        // `on_toggle` and `on_highlight` pass their value by value, listeners by reference
+        let on_toggle = cx.listener(|this, open: &bool, _window, cx| this.toggle(*open, cx));
+        let on_highlight = cx.listener(|this, index: &Option<usize>, _window, cx| {
+            this.highlight(*index, cx);
+        });
+        let mut select = Select::new(self.id.clone())
+            .options(self.options.clone())
+            .size(self.size)
+            .disabled(self.disabled)
+            .is_open(self.is_open)
+            .highlighted_index(self.highlighted_index)
+            .theme(theme)
+            .on_change(cx.listener(|this, value: &SharedString, _window, cx| {
+                this.select(value, cx);
+            }))
+            .on_toggle(move |open, window, cx| on_toggle(&open, window, cx))
+            .on_highlight(move |index, window, cx| on_highlight(&index, window, cx));
+        if let Some(selected) = &self.selected {
+            select = select.selected(selected.clone());
+        }
+        if let Some(placeholder) = &self.placeholder {
+            select = select.placeholder(placeholder.clone());
+        }
+        if let Some(label) = &self.label {
+            select = select.label(label.clone());
+        }
+        select
+    }
+}
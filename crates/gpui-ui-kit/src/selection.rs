@@ -0,0 +1,279 @@
+//! Selection shared between views
+//!
+//! A [`SelectionModel`] entity holds which items are selected, so a table
+//! and a chart showing the same data can share one selection: selecting
+//! rows highlights the matching points and clicking points selects the
+//! rows. Clicks follow the usual desktop conventions:
+//!
+//! - click: select only the clicked item
+//! - Ctrl/Cmd + click: toggle the item, keeping the rest of the selection
+//! - Shift + click: select the range from the last clicked item (the anchor)
+//! - Ctrl/Cmd + Shift + click: add that range to the selection
+//!
+//! ```ignore
+//! let selection = cx.new(|_| SelectionModel::new(SelectionMode::Multiple).items(0..rows));
+//!
+//! // In a row or point click handler
+//! selection.update(cx, |selection, cx| selection.click(index, &event.modifiers, cx));
+//!
+//! // Re-render whenever the selection changes, whoever changed it
+//! cx.observe(&selection, |_, _, cx| cx.notify()).detach();
+//! ```
+
+use gpui::{Context, EventEmitter, Modifiers};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// How many items can be selected at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// At most one item; modifiers are ignored
+    Single,
+    /// Any number of items, with Ctrl/Cmd and Shift clicks
+    #[default]
+    Multiple,
+}
+
+/// Events emitted by [`SelectionModel`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionEvent {
+    /// The set of selected items changed
+    Changed,
+}
+
+/// Selected items of a list of `Id`s, shared as a GPUI entity
+///
+/// Range selection follows the order of the items set with
+/// [`SelectionModel::items`]; ids outside that list can still be selected
+/// one at a time.
+#[derive(Debug, Clone)]
+pub struct SelectionModel<Id> {
+    mode: SelectionMode,
+    items: Vec<Id>,
+    selected: HashSet<Id>,
+    anchor: Option<Id>,
+}
+
+impl<Id> EventEmitter<SelectionEvent> for SelectionModel<Id> {}
+
+impl<Id: Clone + Eq + Hash> SelectionModel<Id> {
+    /// Create an empty selection
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            items: Vec::new(),
+            selected: HashSet::new(),
+            anchor: None,
+        }
+    }
+
+    /// Set the items in display order, used for range selection
+    pub fn items(mut self, items: impl IntoIterator<Item = Id>) -> Self {
+        self.items = items.into_iter().collect();
+        self
+    }
+
+    /// Selection mode
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Whether an item is selected
+    pub fn is_selected(&self, id: &Id) -> bool {
+        self.selected.contains(id)
+    }
+
+    /// Selected items, in item order
+    ///
+    /// Selected ids missing from the items come last, in no given order.
+    pub fn selected(&self) -> Vec<Id> {
+        let listed = self.items.iter().filter(|id| self.selected.contains(*id));
+        let unlisted = self.selected.iter().filter(|id| !self.items.contains(*id));
+        listed.chain(unlisted).cloned().collect()
+    }
+
+    /// Number of selected items
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    /// Whether nothing is selected
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Item that Shift-click ranges start from
+    pub fn anchor(&self) -> Option<&Id> {
+        self.anchor.as_ref()
+    }
+
+    /// Handle a click on an item, with the modifiers held at the time
+    pub fn click(&mut self, id: Id, modifiers: &Modifiers, cx: &mut Context<Self>) {
+        let toggle = modifiers.control || modifiers.platform;
+        self.update(cx, |this| this.apply_click(id, toggle, modifiers.shift));
+    }
+
+    /// Select only `id`
+    pub fn select(&mut self, id: Id, cx: &mut Context<Self>) {
+        self.update(cx, |this| this.apply_click(id, false, false));
+    }
+
+    /// Add `id` to the selection, or remove it if already selected
+    pub fn toggle(&mut self, id: Id, cx: &mut Context<Self>) {
+        self.update(cx, |this| this.apply_click(id, true, false));
+    }
+
+    /// Replace the selection, e.g. from a brush or a filter
+    ///
+    /// In single mode only the first id is kept.
+    pub fn set_selection(&mut self, ids: impl IntoIterator<Item = Id>, cx: &mut Context<Self>) {
+        self.update(cx, |this| {
+            let limit = match this.mode {
+                SelectionMode::Single => 1,
+                SelectionMode::Multiple => usize::MAX,
+            };
+            this.selected = ids.into_iter().take(limit).collect();
+            this.anchor = None;
+        });
+    }
+
+    /// Select every item (multiple mode only)
+    pub fn select_all(&mut self, cx: &mut Context<Self>) {
+        if self.mode == SelectionMode::Multiple {
+            self.update(cx, |this| this.selected.extend(this.items.iter().cloned()));
+        }
+    }
+
+    /// Deselect everything
+    pub fn clear(&mut self, cx: &mut Context<Self>) {
+        self.update(cx, |this| {
+            this.selected.clear();
+            this.anchor = None;
+        });
+    }
+
+    /// Replace the items, dropping selected ids that are no longer present
+    pub fn set_items(&mut self, items: impl IntoIterator<Item = Id>, cx: &mut Context<Self>) {
+        self.update(cx, |this| {
+            this.items = items.into_iter().collect();
+            let items = &this.items;
+            this.selected.retain(|id| items.contains(id));
+            if this.anchor.as_ref().is_some_and(|id| !items.contains(id)) {
+                this.anchor = None;
+            }
+        });
+    }
+
+    /// Apply a change, notifying observers and emitting
+    /// [`SelectionEvent::Changed`] if the selected set differs afterwards
+    fn update(&mut self, cx: &mut Context<Self>, change: impl FnOnce(&mut Self)) {
+        let before = self.selected.clone();
+        change(self);
+        if self.selected != before {
+            cx.emit(SelectionEvent::Changed);
+            cx.notify();
+        }
+    }
+
+    /// Selection after a click, with Ctrl/Cmd (`toggle`) and Shift (`range`)
+    fn apply_click(&mut self, id: Id, toggle: bool, range: bool) {
+        if self.mode == SelectionMode::Single {
+            let deselect = toggle && self.selected.contains(&id);
+            self.selected.clear();
+            if !deselect {
+                self.selected.insert(id.clone());
+            }
+            self.anchor = Some(id);
+            return;
+        }
+
+        if range && let Some(ids) = self.range_to(&id) {
+            if !toggle {
+                self.selected.clear();
+            }
+            self.selected.extend(ids);
+            // The anchor stays, so the range can be adjusted
+            return;
+        }
+
+        if toggle {
+            if !self.selected.remove(&id) {
+                self.selected.insert(id.clone());
+            }
+        } else {
+            self.selected.clear();
+            self.selected.insert(id.clone());
+        }
+        self.anchor = Some(id);
+    }
+
+    /// Items from the anchor to `id` inclusive, if both are listed
+    fn range_to(&self, id: &Id) -> Option<Vec<Id>> {
+        let anchor = self.anchor.as_ref()?;
+        let from = self.items.iter().position(|item| item == anchor)?;
+        let to = self.items.iter().position(|item| item == id)?;
+        let (start, end) = (from.min(to), from.max(to));
+        Some(self.items[start..=end].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(mode: SelectionMode) -> SelectionModel<usize> {
+        SelectionModel::new(mode).items(0..10)
+    }
+
+    #[test]
+    fn test_click_toggle_and_range() {
+        let mut selection = model(SelectionMode::Multiple);
+        selection.apply_click(2, false, false);
+        assert_eq!(selection.selected(), vec![2]);
+
+        // Ctrl-click adds, then removes
+        selection.apply_click(5, true, false);
+        assert_eq!(selection.selected(), vec![2, 5]);
+        selection.apply_click(5, true, false);
+        assert_eq!(selection.selected(), vec![2]);
+
+        // Shift-click selects from the anchor (5), in either direction
+        selection.apply_click(8, false, true);
+        assert_eq!(selection.selected(), vec![5, 6, 7, 8]);
+        selection.apply_click(3, false, true);
+        assert_eq!(selection.selected(), vec![3, 4, 5]);
+        assert_eq!(selection.anchor(), Some(&5));
+
+        // Ctrl+Shift-click adds the range to the selection
+        selection.apply_click(0, false, false);
+        selection.apply_click(7, true, false);
+        selection.apply_click(9, true, true);
+        assert_eq!(selection.selected(), vec![0, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_single_mode_ignores_modifiers() {
+        let mut selection = model(SelectionMode::Single);
+        selection.apply_click(1, false, false);
+        selection.apply_click(4, true, false);
+        assert_eq!(selection.selected(), vec![4]);
+        selection.apply_click(7, false, true);
+        assert_eq!(selection.selected(), vec![7]);
+
+        // Ctrl-click on the selected item deselects it
+        selection.apply_click(7, true, false);
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn test_range_without_anchor_selects_one() {
+        let mut selection = model(SelectionMode::Multiple);
+        selection.apply_click(6, false, true);
+        assert_eq!(selection.selected(), vec![6]);
+        assert_eq!(selection.anchor(), Some(&6));
+
+        // Ids outside the items can be clicked but not ranged over
+        selection.apply_click(42, false, true);
+        assert_eq!(selection.selected(), vec![42]);
+    }
+}
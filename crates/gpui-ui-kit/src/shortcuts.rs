@@ -0,0 +1,411 @@
+//! Keyboard shortcut registry and help overlay
+//!
+//! Shortcuts bound through [`ShortcutRegistry::bind`] are recorded with a
+//! category and a description, and [`ShortcutHelp`] lists them all, grouped
+//! by category, over the window. MiniApp binds its own shortcuts this way
+//! and toggles the overlay with `?`, so apps only need to register theirs:
+//!
+//! ```ignore
+//! ShortcutRegistry::bind("cmd-s", Save, "File", "Save project", cx);
+//! ShortcutRegistry::bind("space", TogglePlayback, "Playback", "Play / pause", cx);
+//!
+//! // Shortcuts handled by a component's own key listener
+//! ShortcutRegistry::register("↑ ↓", "Lists", "Move the selection", cx);
+//!
+//! // Window root, when not using MiniApp
+//! div()
+//!     .relative()
+//!     .size_full()
+//!     .child(app_content)
+//!     .child(ShortcutHelp::new("shortcut-help")) // last child, covers the window
+//! ```
+
+use crate::ComponentTheme;
+use crate::theme::{Elevation, ElevationTokens, ThemeExt};
+use gpui::prelude::*;
+use gpui::*;
+
+/// Width of the help card
+const CARD_WIDTH: f32 = 560.0;
+
+actions!(shortcuts, [ToggleShortcutHelp]);
+
+/// A keyboard shortcut shown in the help overlay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    /// Keystrokes in GPUI binding syntax, e.g. `"cmd-shift-z"`
+    pub keystrokes: SharedString,
+    /// Group the shortcut is listed under
+    pub category: SharedString,
+    /// What the shortcut does
+    pub description: SharedString,
+}
+
+/// Global list of the app's keyboard shortcuts
+#[derive(Debug, Default)]
+pub struct ShortcutRegistry {
+    shortcuts: Vec<Shortcut>,
+    help_visible: bool,
+}
+
+impl Global for ShortcutRegistry {}
+
+impl ShortcutRegistry {
+    /// Bind `keystrokes` to `action` and list the shortcut in the help
+    pub fn bind<A: Action>(
+        keystrokes: &str,
+        action: A,
+        category: impl Into<SharedString>,
+        description: impl Into<SharedString>,
+        cx: &mut App,
+    ) {
+        cx.bind_keys([KeyBinding::new(keystrokes, action, None)]);
+        Self::register(keystrokes.to_string(), category, description, cx);
+    }
+
+    /// List a shortcut in the help without binding it
+    ///
+    /// For keys handled by component key listeners rather than actions.
+    pub fn register(
+        keystrokes: impl Into<SharedString>,
+        category: impl Into<SharedString>,
+        description: impl Into<SharedString>,
+        cx: &mut App,
+    ) {
+        cx.default_global::<ShortcutRegistry>().insert(Shortcut {
+            keystrokes: keystrokes.into(),
+            category: category.into(),
+            description: description.into(),
+        });
+    }
+
+    /// Registered shortcuts, in registration order
+    pub fn shortcuts(cx: &App) -> Vec<Shortcut> {
+        cx.try_global::<ShortcutRegistry>()
+            .map(|registry| registry.shortcuts.clone())
+            .unwrap_or_default()
+    }
+
+    /// Whether the help overlay is shown
+    pub fn is_help_visible(cx: &App) -> bool {
+        cx.try_global::<ShortcutRegistry>()
+            .is_some_and(|registry| registry.help_visible)
+    }
+
+    /// Show or hide the help overlay
+    pub fn set_help_visible(visible: bool, cx: &mut App) {
+        cx.default_global::<ShortcutRegistry>().help_visible = visible;
+        cx.refresh_windows();
+    }
+
+    /// Toggle the help overlay
+    pub fn toggle_help(cx: &mut App) {
+        Self::set_help_visible(!Self::is_help_visible(cx), cx);
+    }
+
+    /// Add a shortcut, replacing the one with the same keystrokes
+    fn insert(&mut self, shortcut: Shortcut) {
+        match self
+            .shortcuts
+            .iter_mut()
+            .find(|s| s.keystrokes == shortcut.keystrokes)
+        {
+            Some(existing) => *existing = shortcut,
+            None => self.shortcuts.push(shortcut),
+        }
+    }
+}
+
+/// Group shortcuts by category, categories in order of first appearance
+pub fn group_by_category(shortcuts: &[Shortcut]) -> Vec<(SharedString, Vec<Shortcut>)> {
+    let mut groups: Vec<(SharedString, Vec<Shortcut>)> = Vec::new();
+    for shortcut in shortcuts {
+        match groups
+            .iter_mut()
+            .find(|(category, _)| *category == shortcut.category)
+        {
+            Some((_, group)) => group.push(shortcut.clone()),
+            None => groups.push((shortcut.category.clone(), vec![shortcut.clone()])),
+        }
+    }
+    groups
+}
+
+/// Display form of keystrokes in binding syntax for the current platform
+///
+/// `"cmd-shift-z"` reads `⌘⇧Z` on macOS and `Super+Shift+Z` elsewhere;
+/// keystroke sequences are separated by spaces.
+pub fn format_keystrokes(keystrokes: &str) -> String {
+    format_keystrokes_for(keystrokes, cfg!(target_os = "macos"))
+}
+
+fn format_keystrokes_for(keystrokes: &str, macos: bool) -> String {
+    keystrokes
+        .split_whitespace()
+        .map(|keystroke| format_keystroke(keystroke, macos))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn format_keystroke(keystroke: &str, macos: bool) -> String {
+    let (modifiers, key) = match keystroke.rsplit_once('-') {
+        // A trailing "-" is the minus key itself, as in "cmd--"
+        Some((modifiers, "")) => (modifiers.strip_suffix('-').unwrap_or(modifiers), "-"),
+        Some(split) => split,
+        None => ("", keystroke),
+    };
+    let mut parts: Vec<String> = modifiers
+        .split('-')
+        .filter(|m| !m.is_empty())
+        .map(|m| modifier_label(m, macos).to_string())
+        .collect();
+    parts.push(key_label(key, macos));
+    parts.join(if macos { "" } else { "+" })
+}
+
+fn modifier_label(modifier: &str, macos: bool) -> &str {
+    match (modifier, macos) {
+        ("cmd" | "super" | "win" | "platform", true) => "⌘",
+        ("cmd" | "super" | "win" | "platform", false) => "Super",
+        ("secondary", true) => "⌘",
+        ("secondary", false) | ("ctrl", false) => "Ctrl",
+        ("ctrl", true) => "⌃",
+        ("alt", true) => "⌥",
+        ("alt", false) => "Alt",
+        ("shift", true) => "⇧",
+        ("shift", false) => "Shift",
+        ("fn", _) => "Fn",
+        (other, _) => other,
+    }
+}
+
+fn key_label(key: &str, macos: bool) -> String {
+    let label = match (key, macos) {
+        ("up", _) => "↑",
+        ("down", _) => "↓",
+        ("left", _) => "←",
+        ("right", _) => "→",
+        ("enter", true) => "↩",
+        ("backspace", true) => "⌫",
+        ("escape", true) => "⎋",
+        ("tab", true) => "⇥",
+        ("enter", false) => "Enter",
+        ("backspace", false) => "Backspace",
+        ("escape", false) => "Esc",
+        ("tab", false) => "Tab",
+        ("space", _) => "Space",
+        ("delete", _) => "Delete",
+        ("home", _) => "Home",
+        ("end", _) => "End",
+        ("pageup", _) => "Page Up",
+        ("pagedown", _) => "Page Down",
+        (key, _) => return key.to_uppercase(),
+    };
+    label.to_string()
+}
+
+/// Theme colors for the shortcut help overlay
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct ShortcutHelpTheme {
+    /// Backdrop behind the card
+    #[theme(default = 0x00000088, from = overlay_bg)]
+    pub backdrop: Rgba,
+    /// Card background
+    #[theme(default = 0x2a2a2aff, from = surface)]
+    pub card_bg: Rgba,
+    /// Card border
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub card_border: Rgba,
+    /// Card title
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub title: Rgba,
+    /// Category headings
+    #[theme(default = 0x888888ff, from = text_muted)]
+    pub category: Rgba,
+    /// Shortcut descriptions
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub description: Rgba,
+    /// Key caps background
+    #[theme(default = 0x3a3a3aff, from = surface_hover)]
+    pub key_bg: Rgba,
+    /// Key caps text
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub key_text: Rgba,
+    /// Card shadow
+    #[theme(
+        default_expr = "ElevationTokens::default().shadow(Elevation::Modal)",
+        from_expr = "theme.elevation.shadow(Elevation::Modal)"
+    )]
+    pub card_shadow: Vec<BoxShadow>,
+}
+
+/// Overlay listing the registered shortcuts by category
+///
+/// Place it as the last child of a `relative()` root covering the window; it
+/// renders nothing unless [`ShortcutRegistry::is_help_visible`]. Clicking
+/// outside the card hides it.
+#[derive(IntoElement)]
+pub struct ShortcutHelp {
+    id: ElementId,
+    theme: Option<ShortcutHelpTheme>,
+}
+
+impl ShortcutHelp {
+    /// Create the overlay
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            theme: None,
+        }
+    }
+
+    /// Set the overlay theme
+    pub fn theme(mut self, theme: ShortcutHelpTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+}
+
+impl RenderOnce for ShortcutHelp {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if !ShortcutRegistry::is_help_visible(cx) {
+            return div().into_any_element();
+        }
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .unwrap_or_else(|| ShortcutHelpTheme::from(&global_theme));
+
+        let sections = group_by_category(&ShortcutRegistry::shortcuts(cx))
+            .into_iter()
+            .map(|(category, shortcuts)| {
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_xs()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(theme.category)
+                            .child(category.to_uppercase()),
+                    )
+                    .children(shortcuts.into_iter().map(|shortcut| {
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_4()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_sm()
+                                    .text_color(theme.description)
+                                    .child(shortcut.description),
+                            )
+                            .child(
+                                div()
+                                    .px_2()
+                                    .py_0p5()
+                                    .rounded_sm()
+                                    .bg(theme.key_bg)
+                                    .text_xs()
+                                    .font_family("monospace")
+                                    .text_color(theme.key_text)
+                                    .child(format_keystrokes(&shortcut.keystrokes)),
+                            )
+                    }))
+            });
+
+        let card = div()
+            .id(ElementId::Name(format!("{}-card", self.id).into()))
+            .w(px(CARD_WIDTH))
+            .max_h(relative(0.8))
+            .overflow_y_scroll()
+            .flex()
+            .flex_col()
+            .gap_4()
+            .p_4()
+            .rounded_lg()
+            .bg(theme.card_bg)
+            .border_1()
+            .border_color(theme.card_border)
+            .shadow(theme.card_shadow.clone())
+            // Clicks inside the card keep it open
+            .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+            .child(
+                div()
+                    .text_base()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.title)
+                    .child("Keyboard Shortcuts"),
+            )
+            .children(sections);
+
+        div()
+            .id(self.id)
+            .absolute()
+            .inset_0()
+            .bg(theme.backdrop)
+            .flex()
+            .items_center()
+            .justify_center()
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+                ShortcutRegistry::set_help_visible(false, cx);
+            })
+            .child(card)
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shortcut(keystrokes: &str, category: &str, description: &str) -> Shortcut {
+        Shortcut {
+            keystrokes: keystrokes.to_string().into(),
+            category: category.to_string().into(),
+            description: description.to_string().into(),
+        }
+    }
+
+    #[test]
+    fn test_registry_replaces_same_keystrokes() {
+        let mut registry = ShortcutRegistry::default();
+        registry.insert(shortcut("cmd-s", "File", "Save"));
+        registry.insert(shortcut("cmd-q", "App", "Quit"));
+        registry.insert(shortcut("cmd-s", "File", "Save all"));
+        assert_eq!(registry.shortcuts.len(), 2);
+        assert_eq!(registry.shortcuts[0].description.as_ref(), "Save all");
+    }
+
+    #[test]
+    fn test_group_by_category_keeps_order() {
+        let groups = group_by_category(&[
+            shortcut("cmd-q", "App", "Quit"),
+            shortcut("cmd-t", "View", "Toggle theme"),
+            shortcut("?", "App", "Shortcuts"),
+        ]);
+        let categories: Vec<&str> = groups.iter().map(|(c, _)| c.as_ref()).collect();
+        assert_eq!(categories, ["App", "View"]);
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[0].1[1].keystrokes.as_ref(), "?");
+    }
+
+    #[test]
+    fn test_format_keystrokes() {
+        assert_eq!(format_keystrokes_for("cmd-shift-z", true), "⌘⇧Z");
+        assert_eq!(format_keystrokes_for("cmd-shift-z", false), "Super+Shift+Z");
+        assert_eq!(
+            format_keystrokes_for("ctrl-k ctrl-s", false),
+            "Ctrl+K Ctrl+S"
+        );
+        assert_eq!(format_keystrokes_for("cmd--", true), "⌘-");
+        assert_eq!(format_keystrokes_for("-", false), "-");
+        assert_eq!(format_keystrokes_for("alt-up", false), "Alt+↑");
+        assert_eq!(format_keystrokes_for("escape", false), "Esc");
+        assert_eq!(format_keystrokes_for("?", true), "?");
+        assert_eq!(format_keystrokes_for("f5", false), "F5");
+    }
+}
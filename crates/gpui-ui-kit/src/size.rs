@@ -8,6 +8,12 @@
 //! Components can either use `ComponentSize` directly or define their own
 //! size enum and implement `From<ComponentSize>` for gradual migration.
 //!
+//! Form controls (buttons, inputs, selects, checkboxes...) take their
+//! dimensions from the size tokens below ([`ComponentSize::control_height`],
+//! [`ComponentSize::padding_x`], ...), so controls of the same size line up
+//! in a row without per-component padding tweaks. Their size enums convert
+//! to and from `ComponentSize` one to one.
+//!
 //! ```rust,ignore
 //! use gpui_ui_kit::ComponentSize;
 //!
@@ -18,6 +24,8 @@
 //! let slider = Slider::new().size(SliderSize::from(ComponentSize::Lg));
 //! ```
 
+use gpui::{Pixels, Styled, px};
+
 /// Standard component sizes used across the UI kit.
 ///
 /// The naming convention is:
@@ -70,7 +78,96 @@ impl ComponentSize {
     pub fn to_px(&self, base: f32) -> f32 {
         base * self.multiplier()
     }
+
+    /// Outer height of single-line controls (buttons, inputs, selects)
+    pub fn control_height(&self) -> Pixels {
+        match self {
+            ComponentSize::Xs => px(20.0),
+            ComponentSize::Sm => px(26.0),
+            ComponentSize::Md => px(32.0),
+            ComponentSize::Lg => px(40.0),
+            ComponentSize::Xl => px(48.0),
+        }
+    }
+
+    /// Horizontal padding inside controls
+    pub fn padding_x(&self) -> Pixels {
+        match self {
+            ComponentSize::Xs => px(6.0),
+            ComponentSize::Sm => px(8.0),
+            ComponentSize::Md => px(12.0),
+            ComponentSize::Lg => px(16.0),
+            ComponentSize::Xl => px(20.0),
+        }
+    }
+
+    /// Gap between the parts of a control (icon and label, buttons in a set)
+    pub fn gap(&self) -> Pixels {
+        match self {
+            ComponentSize::Xs | ComponentSize::Sm => px(4.0),
+            ComponentSize::Md => px(6.0),
+            ComponentSize::Lg => px(8.0),
+            ComponentSize::Xl => px(10.0),
+        }
+    }
+
+    /// Size of indicators and icons inside controls (checkbox box, chevrons)
+    pub fn icon_size(&self) -> Pixels {
+        match self {
+            ComponentSize::Xs => px(12.0),
+            ComponentSize::Sm => px(14.0),
+            ComponentSize::Md => px(18.0),
+            ComponentSize::Lg => px(22.0),
+            ComponentSize::Xl => px(26.0),
+        }
+    }
+
+    /// Apply the text size matching this control size
+    ///
+    /// - Xs, Sm: `text_xs`
+    /// - Md: `text_sm`
+    /// - Lg: `text_base`
+    /// - Xl: `text_lg`
+    pub fn apply_text_size<E: Styled>(&self, element: E) -> E {
+        match self {
+            ComponentSize::Xs | ComponentSize::Sm => element.text_xs(),
+            ComponentSize::Md => element.text_sm(),
+            ComponentSize::Lg => element.text_base(),
+            ComponentSize::Xl => element.text_lg(),
+        }
+    }
+}
+
+/// Implement the one-to-one conversions between a component size enum with
+/// `Xs`, `Sm`, `Md`, `Lg` and `Xl` variants and [`ComponentSize`].
+macro_rules! component_size_conversions {
+    ($size:ty) => {
+        impl From<$crate::ComponentSize> for $size {
+            fn from(size: $crate::ComponentSize) -> Self {
+                match size {
+                    $crate::ComponentSize::Xs => Self::Xs,
+                    $crate::ComponentSize::Sm => Self::Sm,
+                    $crate::ComponentSize::Md => Self::Md,
+                    $crate::ComponentSize::Lg => Self::Lg,
+                    $crate::ComponentSize::Xl => Self::Xl,
+                }
+            }
+        }
+
+        impl From<$size> for $crate::ComponentSize {
+            fn from(size: $size) -> Self {
+                match size {
+                    <$size>::Xs => Self::Xs,
+                    <$size>::Sm => Self::Sm,
+                    <$size>::Md => Self::Md,
+                    <$size>::Lg => Self::Lg,
+                    <$size>::Xl => Self::Xl,
+                }
+            }
+        }
+    };
 }
+pub(crate) use component_size_conversions;
 
 /// Trait for components that support sizing.
 ///
@@ -14,6 +14,7 @@
 //! - Value snapping with step parameter
 
 use crate::ComponentTheme;
+use crate::callback::Callback;
 use crate::theme::ThemeExt;
 use gpui::*;
 
@@ -52,6 +53,8 @@ pub struct SliderTheme {
 /// Slider size variants
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum SliderSize {
+    /// Extra small size
+    Xs,
     /// Small size
     Sm,
     /// Medium size (default)
@@ -59,35 +62,33 @@ pub enum SliderSize {
     Md,
     /// Large size
     Lg,
+    /// Extra large size
+    Xl,
 }
 
 impl SliderSize {
     fn track_height(&self) -> f32 {
         match self {
+            Self::Xs => 3.0,
             Self::Sm => 4.0,
             Self::Md => 6.0,
             Self::Lg => 8.0,
+            Self::Xl => 10.0,
         }
     }
 
     fn thumb_size(&self) -> f32 {
         match self {
+            Self::Xs => 12.0,
             Self::Sm => 14.0,
             Self::Md => 18.0,
             Self::Lg => 22.0,
+            Self::Xl => 26.0,
         }
     }
 }
 
-impl From<crate::ComponentSize> for SliderSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs | crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg | crate::ComponentSize::Xl => Self::Lg,
-        }
-    }
-}
+crate::size::component_size_conversions!(SliderSize);
 
 /// A slider component for selecting numeric values
 ///
@@ -108,9 +109,9 @@ pub struct Slider {
     show_value: bool,
     label: Option<SharedString>,
     width: f32,
-    on_change: Option<Box<dyn Fn(f32, &mut Window, &mut App) + 'static>>,
-    on_drag_start: Option<Box<dyn Fn(f32, f32, &mut Window, &mut App) + 'static>>,
-    on_reset: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<f32>>,
+    on_drag_start: Option<Callback<(f32, f32)>>,
+    on_reset: Option<Callback>,
     track_color: Option<Rgba>,
     fill_color: Option<Rgba>,
     thumb_color: Option<Rgba>,
@@ -221,7 +222,7 @@ impl Slider {
     ///
     /// The handler receives the new value by value.
     pub fn on_change(mut self, handler: impl Fn(f32, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(handler));
         self
     }
 
@@ -232,7 +233,7 @@ impl Slider {
         mut self,
         handler: impl Fn(&f32, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_change = Some(Box::new(move |value, window, app| {
+        self.on_change = Some(Callback::new(move |value: f32, window, app| {
             handler(&value, window, app);
         }));
         self
@@ -246,13 +247,15 @@ impl Slider {
         mut self,
         handler: impl Fn(f32, f32, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_drag_start = Some(Box::new(handler));
+        self.on_drag_start = Some(Callback::new(move |(x, value): (f32, f32), window, cx| {
+            handler(x, value, window, cx)
+        }));
         self
     }
 
     /// Set reset handler (called on double-click)
     pub fn on_reset(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_reset = Some(Box::new(handler));
+        self.on_reset = Some(Callback::from_fn(handler));
         self
     }
 
@@ -357,8 +360,7 @@ impl RenderOnce for Slider {
             container = container.child(label_row);
         }
 
-        // Wrap on_change in Rc for sharing between handlers
-        let on_change_rc = self.on_change.map(|h| std::rc::Rc::new(h));
+        let on_change = self.on_change;
 
         // Slider track
         let mut track = div()
@@ -419,11 +421,11 @@ impl RenderOnce for Slider {
             if let Some(on_drag_start) = self.on_drag_start {
                 let handler_down = on_drag_start;
                 track = track.on_mouse_down(MouseButton::Left, move |event, window, cx| {
-                    handler_down(event.position.x.into(), current_value, window, cx);
+                    handler_down.call((event.position.x.into(), current_value), window, cx);
                 });
-            } else if let Some(ref handler_rc) = on_change_rc {
+            } else if let Some(ref handler) = on_change {
                 // Click to set value based on position (immediate feedback)
-                let handler_click = handler_rc.clone();
+                let handler_click = handler.clone();
                 track = track.on_mouse_down(MouseButton::Left, move |event, window, cx| {
                     // Calculate value from click position relative to track
                     let x: f32 = event.position.x.into();
@@ -435,11 +437,11 @@ impl RenderOnce for Slider {
                     } else {
                         new_value.clamp(min, max)
                     };
-                    handler_click(snapped, window, cx);
+                    handler_click.call(snapped, window, cx);
                 });
 
                 // Mouse move while pressed - continue drag
-                let handler_drag = handler_rc.clone();
+                let handler_drag = handler.clone();
                 track = track.on_mouse_move(move |event, window, cx| {
                     if event.pressed_button == Some(MouseButton::Left) {
                         let x: f32 = event.position.x.into();
@@ -451,25 +453,23 @@ impl RenderOnce for Slider {
                         } else {
                             new_value.clamp(min, max)
                         };
-                        handler_drag(snapped, window, cx);
+                        handler_drag.call(snapped, window, cx);
                     }
                 });
             }
 
             // Double-click to reset
             if let Some(on_reset) = self.on_reset {
-                let reset_handler = std::rc::Rc::new(on_reset);
-                let reset_clone = reset_handler.clone();
                 track = track.on_click(move |event, window, cx| {
                     if event.click_count() == 2 {
-                        reset_clone(window, cx);
+                        on_reset.invoke(window, cx);
                     }
                 });
             }
 
             // Scroll wheel - adjust value (shift for fine-grained control)
-            if let Some(ref handler_rc) = on_change_rc {
-                let handler_scroll = handler_rc.clone();
+            if let Some(ref handler) = on_change {
+                let handler_scroll = handler.clone();
                 track = track.on_scroll_wheel(move |event, window, cx| {
                     // CRITICAL: Stop propagation immediately to prevent parent scroll container
                     // from capturing the event before we can handle it
@@ -508,13 +508,13 @@ impl RenderOnce for Slider {
                         new_value.clamp(min, max)
                     };
 
-                    handler_scroll(snapped, window, cx);
+                    handler_scroll.call(snapped, window, cx);
                 });
             }
 
             // Keyboard navigation
-            if let Some(handler_rc) = on_change_rc {
-                let handler_key = handler_rc.clone();
+            if let Some(handler) = on_change {
+                let handler_key = handler.clone();
                 track = track.on_key_down(move |event, window, cx| {
                     let step_amount = step.unwrap_or((max - min) * 0.05);
                     let large_step = (max - min) * 0.10; // 10% for page up/down
@@ -536,7 +536,7 @@ impl RenderOnce for Slider {
                         } else {
                             value.clamp(min, max)
                         };
-                        handler_key(snapped, window, cx);
+                        handler_key.call(snapped, window, cx);
                     }
                 });
             }
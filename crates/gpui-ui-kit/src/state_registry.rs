@@ -0,0 +1,183 @@
+//! Per-element state for `RenderOnce` components
+//!
+//! `RenderOnce` components are rebuilt on every render, so focus handles and
+//! editing state must live somewhere else to survive. This registry only
+//! keeps *weak* handles, keyed by element ID. The strong references are held
+//! by the rendered element tree (focus tracking and the event listeners
+//! capture them), so the state lives exactly as long as the element keeps
+//! being rendered: once a frame is drawn without it, the state is dropped
+//! and its registry entry is pruned. No manual cleanup is needed, even with
+//! dynamic element IDs.
+
+use gpui::{App, ElementId, FocusHandle, WeakFocusHandle};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+/// Entry count below which dead entries are not pruned
+const MIN_PRUNE_THRESHOLD: usize = 64;
+
+/// Weakly held focus handles and states of one component type
+pub(crate) struct ElementStateRegistry<S> {
+    focus_handles: HashMap<ElementId, WeakFocusHandle>,
+    states: HashMap<ElementId, Weak<RefCell<S>>>,
+    // Entry count that triggers the next pruning pass
+    prune_at: usize,
+}
+
+impl<S> Default for ElementStateRegistry<S> {
+    fn default() -> Self {
+        Self {
+            focus_handles: HashMap::new(),
+            states: HashMap::new(),
+            prune_at: MIN_PRUNE_THRESHOLD,
+        }
+    }
+}
+
+impl<S> ElementStateRegistry<S> {
+    /// Get the focus handle of an element, creating it if the element has
+    /// none alive.
+    ///
+    /// The caller must keep the returned handle in the element tree (e.g.
+    /// with `track_focus`), otherwise it is dropped after the frame.
+    pub(crate) fn focus_handle(&mut self, id: &ElementId, cx: &mut App) -> FocusHandle {
+        if let Some(handle) = self
+            .focus_handles
+            .get(id)
+            .and_then(WeakFocusHandle::upgrade)
+        {
+            return handle;
+        }
+        let handle = cx.focus_handle();
+        self.focus_handles.insert(id.clone(), handle.downgrade());
+        self.prune_if_needed();
+        handle
+    }
+
+    /// Get the state of an element, creating it with `init` if the element
+    /// has none alive.
+    ///
+    /// The caller must capture the returned state in the element's listeners
+    /// for it to survive until the next render.
+    pub(crate) fn state(&mut self, id: &ElementId, init: impl FnOnce() -> S) -> Rc<RefCell<S>> {
+        if let Some(state) = self.states.get(id).and_then(Weak::upgrade) {
+            return state;
+        }
+        let state = Rc::new(RefCell::new(init()));
+        self.states.insert(id.clone(), Rc::downgrade(&state));
+        self.prune_if_needed();
+        state
+    }
+
+    /// Number of live (focus handle, state) entries
+    pub(crate) fn live_counts(&self) -> (usize, usize) {
+        let focus = self
+            .focus_handles
+            .values()
+            .filter(|handle| handle.upgrade().is_some())
+            .count();
+        let states = self
+            .states
+            .values()
+            .filter(|state| state.strong_count() > 0)
+            .count();
+        (focus, states)
+    }
+
+    /// Forget all entries; live elements get fresh state on their next render
+    pub(crate) fn clear(&mut self) {
+        self.focus_handles.clear();
+        self.states.clear();
+        self.prune_at = MIN_PRUNE_THRESHOLD;
+    }
+
+    /// Drop dead entries once the maps doubled since the last pass, keeping
+    /// the cost amortized constant per insertion
+    fn prune_if_needed(&mut self) {
+        if self.focus_handles.len().max(self.states.len()) < self.prune_at {
+            return;
+        }
+        self.focus_handles
+            .retain(|_, handle| handle.upgrade().is_some());
+        self.states.retain(|_, state| state.strong_count() > 0);
+        let live = self.focus_handles.len().max(self.states.len());
+        self.prune_at = (live * 2).max(MIN_PRUNE_THRESHOLD);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::SharedString;
+
+    fn id(name: &str) -> ElementId {
+        ElementId::Name(SharedString::from(name.to_string()))
+    }
+
+    #[test]
+    fn test_state_is_shared_while_alive() {
+        let mut registry = ElementStateRegistry::<u32>::default();
+        let first = registry.state(&id("a"), || 1);
+        let second = registry.state(&id("a"), || 2);
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_eq!(*second.borrow(), 1);
+
+        // Other elements get their own state
+        let other = registry.state(&id("b"), || 3);
+        assert!(!Rc::ptr_eq(&first, &other));
+    }
+
+    #[test]
+    fn test_state_is_fresh_after_last_drop() {
+        let mut registry = ElementStateRegistry::<u32>::default();
+        let first = registry.state(&id("a"), || 1);
+        *first.borrow_mut() = 5;
+        drop(first);
+        let second = registry.state(&id("a"), || 2);
+        assert_eq!(*second.borrow(), 2);
+    }
+
+    #[test]
+    fn test_prune_drops_dead_entries() {
+        let mut registry = ElementStateRegistry::<usize>::default();
+        // Odd states stay alive; the pass runs on the insertion reaching the
+        // threshold, while that (odd) state is held
+        let mut alive = Vec::new();
+        for i in 0..MIN_PRUNE_THRESHOLD {
+            let state = registry.state(&id(&format!("s{i}")), || i);
+            if i % 2 == 1 {
+                alive.push(state);
+            }
+        }
+        assert_eq!(registry.states.len(), MIN_PRUNE_THRESHOLD / 2);
+        assert_eq!(registry.prune_at, MIN_PRUNE_THRESHOLD);
+
+        // With many live entries the next pass waits until they double
+        for i in 0..MIN_PRUNE_THRESHOLD {
+            alive.push(registry.state(&id(&format!("t{i}")), || i));
+        }
+        let live = MIN_PRUNE_THRESHOLD * 3 / 2;
+        assert_eq!(registry.states.len(), live);
+        assert_eq!(registry.prune_at, MIN_PRUNE_THRESHOLD * 2);
+
+        registry.clear();
+        assert!(registry.states.is_empty());
+        assert_eq!(registry.prune_at, MIN_PRUNE_THRESHOLD);
+    }
+
+    #[test]
+    fn test_live_counts() {
+        let mut registry = ElementStateRegistry::<u32>::default();
+        let first = registry.state(&id("a"), || 1);
+        let second = registry.state(&id("b"), || 2);
+        assert_eq!(registry.live_counts(), (0, 2));
+
+        // Dead entries stop counting before they are pruned
+        drop(first);
+        assert_eq!(registry.live_counts(), (0, 1));
+        assert_eq!(registry.states.len(), 2);
+        drop(second);
+        assert_eq!(registry.live_counts(), (0, 0));
+    }
+}
@@ -0,0 +1,418 @@
+//! Increment and decrement buttons
+//!
+//! [`StepButton`] is a single − or + button: it steps once when pressed and
+//! keeps stepping while held, faster the longer it is held. [`Stepper`]
+//! pairs two of them into a compact control for values shown elsewhere, and
+//! [`NumberInput`](crate::NumberInput) places the same buttons around its
+//! value field.
+//!
+//! ```ignore
+//! Stepper::new("zoom")
+//!     .can_decrement(zoom > MIN_ZOOM)
+//!     .on_step(move |direction, _window, cx| {
+//!         view.update(cx, |this, cx| {
+//!             this.zoom += direction.sign() * 0.1;
+//!             cx.notify();
+//!         });
+//!     })
+//! ```
+//!
+//! Repeats call the handler of the latest render, so a handler computing
+//! the next value from the current one keeps seeing fresh values.
+
+use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::size::ComponentSize;
+use crate::state_registry::ElementStateRegistry;
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+// Press-and-hold state of the rendered buttons, kept alive by their listeners
+thread_local! {
+    static STEP_STATES: RefCell<ElementStateRegistry<HoldState>> =
+        RefCell::new(ElementStateRegistry::default());
+}
+
+/// Delay between the press and the first repeat
+pub const HOLD_DELAY: Duration = Duration::from_millis(400);
+/// Interval between the first repeats
+const REPEAT_START: Duration = Duration::from_millis(150);
+/// Shortest interval between repeats
+const REPEAT_MIN: Duration = Duration::from_millis(30);
+/// Factor applied to the interval after each repeat
+const REPEAT_ACCELERATION: f64 = 0.85;
+
+/// Interval before the next repeat, after `repeats` repeats
+///
+/// Starts at 150ms and shrinks by 15% per repeat down to 30ms.
+pub fn repeat_interval(repeats: u32) -> Duration {
+    let interval = REPEAT_START.as_secs_f64() * REPEAT_ACCELERATION.powi(repeats.min(64) as i32);
+    Duration::from_secs_f64(interval).max(REPEAT_MIN)
+}
+
+/// Direction of a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepDirection {
+    /// Towards smaller values (−)
+    Decrement,
+    /// Towards larger values (+)
+    Increment,
+}
+
+impl StepDirection {
+    /// `-1.0` or `1.0`, to multiply a step size with
+    pub fn sign(self) -> f64 {
+        match self {
+            StepDirection::Decrement => -1.0,
+            StepDirection::Increment => 1.0,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            StepDirection::Decrement => "−",
+            StepDirection::Increment => "+",
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            StepDirection::Decrement => "dec",
+            StepDirection::Increment => "inc",
+        }
+    }
+}
+
+/// Theme colors for step buttons
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct StepperTheme {
+    /// Button background
+    #[theme(default = 0x2a2a2aff, from = surface)]
+    pub button_bg: Rgba,
+    /// Button hover background
+    #[theme(default = 0x3a3a3aff, from = surface_hover)]
+    pub button_hover: Rgba,
+    /// Button background while pressed
+    #[theme(default = 0x007accff, from = accent)]
+    pub button_active: Rgba,
+    /// Symbol color
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub button_text: Rgba,
+    /// Border around a [`Stepper`]
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub border: Rgba,
+    /// Opacity of disabled buttons
+    #[theme(default_f32 = 0.5, from_expr = "0.5")]
+    pub disabled_opacity: f32,
+}
+
+/// Press-and-hold state of one button
+#[derive(Default)]
+struct HoldState {
+    /// Bumped on every press and release, ending older repeat loops
+    generation: u64,
+    /// Handler of the latest render
+    on_step: Option<Callback<StepDirection>>,
+}
+
+/// A − or + button repeating while held
+#[derive(IntoElement)]
+pub struct StepButton {
+    id: ElementId,
+    direction: StepDirection,
+    size: ComponentSize,
+    width: Option<Pixels>,
+    disabled: bool,
+    theme: Option<StepperTheme>,
+    on_step: Option<Callback<StepDirection>>,
+}
+
+impl StepButton {
+    /// Create a button stepping in `direction`
+    pub fn new(id: impl Into<ElementId>, direction: StepDirection) -> Self {
+        Self {
+            id: id.into(),
+            direction,
+            size: ComponentSize::default(),
+            width: None,
+            disabled: false,
+            theme: None,
+            on_step: None,
+        }
+    }
+
+    /// Set the size (default: square of the control height)
+    pub fn size(mut self, size: impl Into<ComponentSize>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Override the width; the button then fills the parent's height
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Disable the button
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the button theme
+    pub fn theme(mut self, theme: StepperTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called on press, then repeatedly while the button is held
+    pub fn on_step(
+        mut self,
+        handler: impl Fn(StepDirection, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_step = Some(Callback::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for StepButton {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .unwrap_or_else(|| StepperTheme::from(&global_theme));
+        let direction = self.direction;
+
+        let mut button = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .bg(theme.button_bg)
+            .text_color(theme.button_text)
+            .font_weight(FontWeight::BOLD)
+            .child(direction.symbol());
+        button = match self.width {
+            Some(width) => button.w(width).h_full(),
+            None => button
+                .w(self.size.control_height())
+                .h(self.size.control_height()),
+        };
+        button = self.size.apply_text_size(button);
+
+        let on_step = match self.on_step {
+            Some(on_step) if !self.disabled => on_step,
+            _ => {
+                return button.opacity(theme.disabled_opacity).cursor_not_allowed();
+            }
+        };
+
+        let state =
+            STEP_STATES.with_borrow_mut(|registry| registry.state(&self.id, HoldState::default));
+        state.borrow_mut().on_step = Some(on_step.clone());
+
+        let pressed = state.clone();
+        let released = state.clone();
+        let released_out = state.clone();
+        let left = state;
+        let (hover_bg, active_bg) = (theme.button_hover, theme.button_active);
+        button
+            .cursor_pointer()
+            .hover(move |style| style.bg(hover_bg))
+            .active(move |style| style.bg(active_bg))
+            .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                let generation = bump_generation(&pressed);
+                on_step.call(direction, window, cx);
+                repeat_while_held(Rc::downgrade(&pressed), generation, direction, window, cx);
+            })
+            .on_mouse_up(MouseButton::Left, move |_event, _window, _cx| {
+                bump_generation(&released);
+            })
+            .on_mouse_up_out(MouseButton::Left, move |_event, _window, _cx| {
+                bump_generation(&released_out);
+            })
+            .on_hover(move |hovered, _window, _cx| {
+                if !hovered {
+                    bump_generation(&left);
+                }
+            })
+    }
+}
+
+/// End the current repeat loop, returning the new generation
+fn bump_generation(state: &RefCell<HoldState>) -> u64 {
+    let mut state = state.borrow_mut();
+    state.generation += 1;
+    state.generation
+}
+
+/// Step repeatedly until the press of `generation` ends or the button is gone
+fn repeat_while_held(
+    state: Weak<RefCell<HoldState>>,
+    generation: u64,
+    direction: StepDirection,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    window
+        .spawn(cx, async move |cx: &mut AsyncWindowContext| {
+            cx.background_executor().timer(HOLD_DELAY).await;
+            let mut repeats = 0u32;
+            loop {
+                let Some(held) = state.upgrade() else {
+                    break;
+                };
+                let on_step = {
+                    let held = held.borrow();
+                    if held.generation != generation {
+                        break;
+                    }
+                    held.on_step.clone()
+                };
+                drop(held);
+                let Some(on_step) = on_step else {
+                    break;
+                };
+                if cx
+                    .update(|window, cx| on_step.call(direction, window, cx))
+                    .is_err()
+                {
+                    break;
+                }
+                cx.background_executor()
+                    .timer(repeat_interval(repeats))
+                    .await;
+                repeats = repeats.saturating_add(1);
+            }
+        })
+        .detach();
+}
+
+/// Compact − / + pair
+#[derive(IntoElement)]
+pub struct Stepper {
+    id: ElementId,
+    size: ComponentSize,
+    disabled: bool,
+    can_decrement: bool,
+    can_increment: bool,
+    theme: Option<StepperTheme>,
+    on_step: Option<Callback<StepDirection>>,
+}
+
+impl Stepper {
+    /// Create a stepper
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            size: ComponentSize::default(),
+            disabled: false,
+            can_decrement: true,
+            can_increment: true,
+            theme: None,
+            on_step: None,
+        }
+    }
+
+    /// Set the size
+    pub fn size(mut self, size: impl Into<ComponentSize>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Disable both buttons
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Enable the − button, e.g. `value > min`
+    pub fn can_decrement(mut self, enabled: bool) -> Self {
+        self.can_decrement = enabled;
+        self
+    }
+
+    /// Enable the + button, e.g. `value < max`
+    pub fn can_increment(mut self, enabled: bool) -> Self {
+        self.can_increment = enabled;
+        self
+    }
+
+    /// Set the stepper theme
+    pub fn theme(mut self, theme: StepperTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called on press, then repeatedly while a button is held
+    pub fn on_step(
+        mut self,
+        handler: impl Fn(StepDirection, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_step = Some(Callback::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Stepper {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .unwrap_or_else(|| StepperTheme::from(&global_theme));
+
+        let button = |direction: StepDirection, enabled: bool| {
+            let mut button = StepButton::new(
+                ElementId::Name(format!("{}-{}", self.id, direction.suffix()).into()),
+                direction,
+            )
+            .size(self.size)
+            .disabled(self.disabled || !enabled)
+            .theme(theme.clone());
+            button.on_step = self.on_step.clone();
+            button
+        };
+
+        div()
+            .id(self.id.clone())
+            .flex()
+            .items_center()
+            .rounded_md()
+            .border_1()
+            .border_color(theme.border)
+            .overflow_hidden()
+            .child(button(StepDirection::Decrement, self.can_decrement))
+            .child(
+                div()
+                    .w(px(1.0))
+                    .h(self.size.control_height())
+                    .bg(theme.border),
+            )
+            .child(button(StepDirection::Increment, self.can_increment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeat_interval_accelerates_to_minimum() {
+        assert_eq!(repeat_interval(0), REPEAT_START);
+        assert!(repeat_interval(1) < repeat_interval(0));
+        assert!(repeat_interval(5) < repeat_interval(1));
+        assert_eq!(repeat_interval(50), REPEAT_MIN);
+        assert_eq!(repeat_interval(u32::MAX), REPEAT_MIN);
+    }
+
+    #[test]
+    fn test_step_direction_sign() {
+        assert_eq!(StepDirection::Decrement.sign(), -1.0);
+        assert_eq!(StepDirection::Increment.sign(), 1.0);
+    }
+}
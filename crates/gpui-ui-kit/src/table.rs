@@ -0,0 +1,706 @@
+//! Table component
+//!
+//! Tabular data display with per-column cell renderers, column pinning and
+//! CSV export of the visible data. The row selection can be shared with
+//! charts through a [`SelectionModel`].
+//!
+//! Columns render plain text by default. A column can instead render any
+//! element from the row data, e.g. a badge, a progress bar, a button or a
+//! sparkline from `gpui-px`:
+//!
+//! ```rust,ignore
+//! use gpui_ui_kit::{BadgeVariant, CellValue, Table, TableColumn};
+//!
+//! let table = Table::new("speakers")
+//!     .column(TableColumn::new("Model").pinned(true))
+//!     .column(TableColumn::new("Status").badge(|value| match value.as_text() {
+//!         Some("measured") => BadgeVariant::Success,
+//!         _ => BadgeVariant::Default,
+//!     }))
+//!     .column(TableColumn::new("Score").progress(10.0))
+//!     .column(TableColumn::new("Trend").render(|row, _index| {
+//!         let values = row[3].as_series().unwrap_or(&[]);
+//!         gpui_px::sparkline(values)
+//!             .build()
+//!             .map(|chart| chart.into_any_element())
+//!             .unwrap_or_else(|_| div().into_any_element())
+//!     }))
+//!     .row(vec![
+//!         CellValue::from("KEF LS50"),
+//!         CellValue::from("measured"),
+//!         CellValue::from(6.2),
+//!         CellValue::from(vec![5.8, 6.0, 6.2]),
+//!     ]);
+//!
+//! let csv = table.to_csv();
+//! ```
+
+use crate::ComponentTheme;
+use crate::badge::{Badge, BadgeSize, BadgeVariant};
+use crate::callback::Callback;
+use crate::clipboard::{CopyToClipboard, to_tsv};
+use crate::progress::{Progress, ProgressSize};
+use crate::selection::SelectionModel;
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Theme colors for table styling
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct TableTheme {
+    #[theme(default = 0x252525, from = muted)]
+    pub header_bg: Rgba,
+    #[theme(default = 0xcccccc, from = text_secondary)]
+    pub header_text: Rgba,
+    #[theme(default = 0x1e1e1e, from = background)]
+    pub row_bg: Rgba,
+    #[theme(default = 0x252525, from = surface)]
+    pub row_alt_bg: Rgba,
+    #[theme(default = 0x2a2a2a, from = surface_hover)]
+    pub row_hover_bg: Rgba,
+    #[theme(default = 0x007acc, from = accent_muted)]
+    pub row_selected_bg: Rgba,
+    #[theme(default = 0xffffff, from = text_primary)]
+    pub text: Rgba,
+    #[theme(default = 0x3a3a3a, from = border)]
+    pub border: Rgba,
+}
+
+/// A single cell value in a table row
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum CellValue {
+    /// No value
+    #[default]
+    Empty,
+    /// Text value
+    Text(SharedString),
+    /// Numeric value
+    Number(f64),
+    /// Boolean value
+    Bool(bool),
+    /// Numeric series (e.g. for sparklines)
+    Series(Vec<f64>),
+}
+
+impl CellValue {
+    /// Get the text, if this is a text value
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            CellValue::Text(text) => Some(text.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Get the number, if this is a numeric value
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            CellValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get the boolean, if this is a boolean value
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            CellValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get the series, if this is a series value
+    pub fn as_series(&self) -> Option<&[f64]> {
+        match self {
+            CellValue::Series(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Plain-text representation used for default cells and CSV export
+    pub fn display_text(&self) -> String {
+        match self {
+            CellValue::Empty => String::new(),
+            CellValue::Text(text) => text.to_string(),
+            CellValue::Number(value) => value.to_string(),
+            CellValue::Bool(value) => value.to_string(),
+            CellValue::Series(values) => values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(value: &str) -> Self {
+        CellValue::Text(SharedString::from(value.to_string()))
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        CellValue::Text(value.into())
+    }
+}
+
+impl From<SharedString> for CellValue {
+    fn from(value: SharedString) -> Self {
+        CellValue::Text(value)
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        CellValue::Number(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+
+impl From<Vec<f64>> for CellValue {
+    fn from(values: Vec<f64>) -> Self {
+        CellValue::Series(values)
+    }
+}
+
+/// Cell renderer: receives the whole row and its index
+pub type CellRenderer = Rc<dyn Fn(&[CellValue], usize) -> AnyElement>;
+
+/// Horizontal alignment of cell content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CellAlign {
+    /// Left aligned (default)
+    #[default]
+    Start,
+    /// Centered
+    Center,
+    /// Right aligned
+    End,
+}
+
+/// How a column renders its cells
+#[derive(Clone)]
+enum CellKind {
+    /// Plain (formatted) text
+    Text,
+    /// Badge with a value-driven variant
+    Badge(Rc<dyn Fn(&CellValue) -> BadgeVariant>),
+    /// Progress bar from 0 to max
+    Progress(f32),
+    /// Custom renderer
+    Custom(CellRenderer),
+}
+
+/// A table column definition
+///
+/// Columns read their value from the row field with the same index as the
+/// column, in the order columns were added.
+pub struct TableColumn {
+    header: SharedString,
+    width: Pixels,
+    align: CellAlign,
+    pinned: bool,
+    hidden: bool,
+    format: Option<Rc<dyn Fn(&CellValue) -> String>>,
+    kind: CellKind,
+}
+
+impl TableColumn {
+    /// Create a new text column
+    pub fn new(header: impl Into<SharedString>) -> Self {
+        Self {
+            header: header.into(),
+            width: px(120.0),
+            align: CellAlign::default(),
+            pinned: false,
+            hidden: false,
+            format: None,
+            kind: CellKind::Text,
+        }
+    }
+
+    /// Set column width
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set content alignment
+    pub fn align(mut self, align: CellAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Pin the column to the left edge so it stays visible while scrolling
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Hide the column (hidden columns are neither rendered nor exported)
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Set a text formatter, used for text and badge cells and CSV export
+    pub fn format(mut self, format: impl Fn(&CellValue) -> String + 'static) -> Self {
+        self.format = Some(Rc::new(format));
+        self
+    }
+
+    /// Render cells with a custom element built from the row data
+    ///
+    /// The renderer receives the whole row and the row index.
+    pub fn render(
+        mut self,
+        renderer: impl Fn(&[CellValue], usize) -> AnyElement + 'static,
+    ) -> Self {
+        self.kind = CellKind::Custom(Rc::new(renderer));
+        self
+    }
+
+    /// Render cells as badges, choosing the variant from the cell value
+    pub fn badge(mut self, variant: impl Fn(&CellValue) -> BadgeVariant + 'static) -> Self {
+        self.kind = CellKind::Badge(Rc::new(variant));
+        self
+    }
+
+    /// Render numeric cells as progress bars from 0 to `max`
+    pub fn progress(mut self, max: f32) -> Self {
+        self.kind = CellKind::Progress(max);
+        self
+    }
+
+    /// Text of a cell as shown in text cells and CSV export
+    fn cell_text(&self, value: &CellValue) -> String {
+        match &self.format {
+            Some(format) => format(value),
+            None => value.display_text(),
+        }
+    }
+
+    /// Build the content of one cell
+    fn render_cell(&self, row: &[CellValue], field: usize, row_index: usize) -> AnyElement {
+        let value = row.get(field).cloned().unwrap_or_default();
+        match &self.kind {
+            CellKind::Text => div()
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .child(self.cell_text(&value))
+                .into_any_element(),
+            CellKind::Badge(variant) => Badge::new(self.cell_text(&value))
+                .variant(variant(&value))
+                .size(BadgeSize::Sm)
+                .into_any_element(),
+            CellKind::Progress(max) => div()
+                .w_full()
+                .child(
+                    Progress::new(value.as_number().unwrap_or(0.0) as f32)
+                        .max(*max)
+                        .size(ProgressSize::Sm),
+                )
+                .into_any_element(),
+            CellKind::Custom(renderer) => renderer(row, row_index),
+        }
+    }
+}
+
+/// A data table component
+pub struct Table {
+    id: ElementId,
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<CellValue>>,
+    row_height: Pixels,
+    striped: bool,
+    selected_row: Option<usize>,
+    selection: Option<Entity<SelectionModel<usize>>>,
+    selected_rows: HashSet<usize>,
+    theme: Option<TableTheme>,
+    on_row_click: Option<Callback<usize>>,
+}
+
+impl Table {
+    /// Create a new table
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            row_height: px(32.0),
+            striped: true,
+            selected_row: None,
+            selection: None,
+            selected_rows: HashSet::new(),
+            theme: None,
+            on_row_click: None,
+        }
+    }
+
+    /// Add a column
+    pub fn column(mut self, column: TableColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Set all columns
+    pub fn columns(mut self, columns: Vec<TableColumn>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a row (one value per column, in column order)
+    pub fn row(mut self, row: Vec<CellValue>) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Set all rows
+    pub fn rows(mut self, rows: Vec<Vec<CellValue>>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Set row height (rows have a fixed height so pinned columns line up)
+    pub fn row_height(mut self, height: Pixels) -> Self {
+        self.row_height = height;
+        self
+    }
+
+    /// Alternate row backgrounds
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Set the selected row index
+    pub fn selected_row(mut self, row: Option<usize>) -> Self {
+        self.selected_row = row;
+        self
+    }
+
+    /// Share the row selection with other views, e.g. a chart of the rows
+    ///
+    /// Rows are identified by index. Clicks select rows with the Ctrl/Cmd
+    /// and Shift conventions of [`SelectionModel`]; give the model the row
+    /// indices as items for Shift ranges.
+    pub fn selection(mut self, selection: Entity<SelectionModel<usize>>) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Set theme
+    pub fn theme(mut self, theme: TableTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Set row click handler (receives the row index)
+    pub fn on_row_click(
+        mut self,
+        handler: impl Fn(usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_row_click = Some(Callback::new(handler));
+        self
+    }
+
+    /// Visible column indices in display order: pinned columns first
+    fn visible_columns(&self) -> Vec<usize> {
+        let visible = |c: &&TableColumn| !c.hidden;
+        let pinned = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| visible(c) && c.pinned);
+        let scrolling = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| visible(c) && !c.pinned);
+        pinned.chain(scrolling).map(|(i, _)| i).collect()
+    }
+
+    /// Export the visible columns and all rows as CSV (RFC 4180 quoting)
+    ///
+    /// Columns appear in display order. Custom-rendered cells are exported
+    /// as their text value.
+    pub fn to_csv(&self) -> String {
+        let columns = self.visible_columns();
+        let mut csv = String::new();
+
+        let header: Vec<String> = columns
+            .iter()
+            .map(|&c| csv_field(&self.columns[c].header))
+            .collect();
+        csv.push_str(&header.join(","));
+        csv.push_str("\r\n");
+
+        for row in &self.rows {
+            let fields: Vec<String> = columns
+                .iter()
+                .map(|&c| {
+                    let value = row.get(c).cloned().unwrap_or_default();
+                    csv_field(&self.columns[c].cell_text(&value))
+                })
+                .collect();
+            csv.push_str(&fields.join(","));
+            csv.push_str("\r\n");
+        }
+
+        csv
+    }
+
+    /// Whether a row is drawn as selected
+    fn is_row_selected(&self, row: usize) -> bool {
+        self.selected_row == Some(row) || self.selected_rows.contains(&row)
+    }
+
+    /// Visible columns of the selected rows (or of all rows when nothing is
+    /// selected) as tab-separated values with a header line
+    pub fn selection_tsv(&self) -> String {
+        let columns = self.visible_columns();
+        let has_selection = self.selected_row.is_some() || !self.selected_rows.is_empty();
+        let header = columns
+            .iter()
+            .map(|&c| self.columns[c].header.to_string())
+            .collect::<Vec<_>>();
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !has_selection || self.is_row_selected(*i))
+            .map(|(_, row)| {
+                columns
+                    .iter()
+                    .map(|&c| {
+                        let value = row.get(c).cloned().unwrap_or_default();
+                        self.columns[c].cell_text(&value)
+                    })
+                    .collect::<Vec<_>>()
+            });
+        to_tsv(std::iter::once(header).chain(rows))
+    }
+
+    /// Build into element with theme
+    pub fn build_with_theme(self, theme: &TableTheme) -> Stateful<Div> {
+        let theme = self.theme.clone().unwrap_or_else(|| theme.clone());
+        let display = self.visible_columns();
+        let (pinned, scrolling): (Vec<usize>, Vec<usize>) =
+            display.into_iter().partition(|&c| self.columns[c].pinned);
+
+        let mut container = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .border_1()
+            .border_color(theme.border)
+            .rounded_md()
+            .overflow_hidden()
+            .text_sm();
+
+        if !pinned.is_empty() {
+            let section = self.build_section(&pinned, &theme).flex_none();
+            container = container.child(if scrolling.is_empty() {
+                section
+            } else {
+                section.border_r_1().border_color(theme.border)
+            });
+        }
+
+        if !scrolling.is_empty() {
+            container = container.child(
+                div()
+                    .id(SharedString::from(format!("{}-scroll", self.id)))
+                    .flex_1()
+                    .overflow_x_scroll()
+                    .child(self.build_section(&scrolling, &theme)),
+            );
+        }
+
+        container
+    }
+
+    /// Build the header and rows for a group of columns
+    fn build_section(&self, columns: &[usize], theme: &TableTheme) -> Div {
+        let mut section = div().flex().flex_col();
+
+        let mut header = div()
+            .flex()
+            .flex_row()
+            .h(self.row_height)
+            .bg(theme.header_bg)
+            .border_b_1()
+            .border_color(theme.border)
+            .text_color(theme.header_text)
+            .font_weight(FontWeight::SEMIBOLD);
+        for &c in columns {
+            let column = &self.columns[c];
+            header = header.child(cell_box(column).child(column.header.clone()));
+        }
+        section = section.child(header);
+
+        for (row_index, row) in self.rows.iter().enumerate() {
+            let bg = if self.is_row_selected(row_index) {
+                theme.row_selected_bg
+            } else if self.striped && row_index % 2 == 1 {
+                theme.row_alt_bg
+            } else {
+                theme.row_bg
+            };
+            let hover_bg = theme.row_hover_bg;
+
+            let mut row_div = div()
+                .id(SharedString::from(format!(
+                    "{}-row-{}-{}",
+                    self.id, columns[0], row_index
+                )))
+                .flex()
+                .flex_row()
+                .h(self.row_height)
+                .bg(bg)
+                .text_color(theme.text)
+                .hover(move |style| style.bg(hover_bg));
+
+            if let Some(handler) = self.on_row_click.clone() {
+                row_div = row_div.cursor_pointer().on_mouse_up(
+                    MouseButton::Left,
+                    move |_event, window, cx| {
+                        handler.call(row_index, window, cx);
+                    },
+                );
+            }
+            if let Some(selection) = self.selection.clone() {
+                row_div = row_div.cursor_pointer().on_mouse_up(
+                    MouseButton::Left,
+                    move |event, _window, cx| {
+                        selection.update(cx, |selection, cx| {
+                            selection.click(row_index, &event.modifiers, cx)
+                        });
+                    },
+                );
+            }
+
+            for &c in columns {
+                let column = &self.columns[c];
+                let content = column.render_cell(row, c, row_index);
+                row_div = row_div.child(cell_box(column).child(content));
+            }
+
+            section = section.child(row_div);
+        }
+
+        section
+    }
+}
+
+/// Fixed-width cell wrapper with column alignment
+fn cell_box(column: &TableColumn) -> Div {
+    let cell = div()
+        .flex()
+        .items_center()
+        .flex_none()
+        .w(column.width)
+        .h_full()
+        .px_2()
+        .overflow_hidden();
+    match column.align {
+        CellAlign::Start => cell.justify_start(),
+        CellAlign::Center => cell.justify_center(),
+        CellAlign::End => cell.justify_end(),
+    }
+}
+
+/// Quote a CSV field when it contains separators, quotes or line breaks
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+impl CopyToClipboard for Table {
+    fn clipboard_text(&self) -> Option<String> {
+        (!self.rows.is_empty()).then(|| self.selection_tsv())
+    }
+}
+
+impl RenderOnce for Table {
+    fn render(mut self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if let Some(selection) = &self.selection {
+            self.selected_rows = selection.read(cx).selected().into_iter().collect();
+        }
+        let global_theme = cx.theme();
+        let table_theme = TableTheme::from(&global_theme);
+        self.build_with_theme(&table_theme)
+    }
+}
+
+impl IntoElement for Table {
+    type Element = gpui::Component<Self>;
+
+    fn into_element(self) -> Self::Element {
+        gpui::Component::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_to_csv_uses_visible_columns_in_display_order() {
+        let table = Table::new("t")
+            .column(
+                TableColumn::new("Score")
+                    .format(|v| format!("{:.1}", v.as_number().unwrap_or(0.0))),
+            )
+            .column(TableColumn::new("Name").pinned(true))
+            .column(TableColumn::new("Secret").hidden(true))
+            .row(vec![
+                CellValue::from(6.24),
+                CellValue::from("KEF, LS50"),
+                CellValue::from("x"),
+            ])
+            .row(vec![CellValue::from(4.0)]);
+
+        assert_eq!(
+            table.to_csv(),
+            "Name,Score\r\n\"KEF, LS50\",6.2\r\n,4.0\r\n"
+        );
+    }
+
+    #[test]
+    fn test_selection_tsv() {
+        let table = Table::new("t")
+            .column(TableColumn::new("Name"))
+            .column(TableColumn::new("Score"))
+            .row(vec![CellValue::from("a"), CellValue::from(1.0)])
+            .row(vec![CellValue::from("b"), CellValue::from(2.0)]);
+        assert_eq!(table.selection_tsv(), "Name\tScore\na\t1\nb\t2\n");
+
+        let table = table.selected_row(Some(1));
+        assert_eq!(table.selection_tsv(), "Name\tScore\nb\t2\n");
+    }
+
+    #[test]
+    fn test_cell_value_display_text() {
+        assert_eq!(CellValue::Empty.display_text(), "");
+        assert_eq!(CellValue::from(true).display_text(), "true");
+        assert_eq!(CellValue::from(vec![1.0, 2.5]).display_text(), "1 2.5");
+    }
+}
@@ -3,7 +3,11 @@
 //! Provides a horizontal tab bar with content panels and theming support.
 
 use crate::ComponentTheme;
+use crate::app::recorder::record_navigation;
+use crate::callback::Callback;
+use crate::focus::{FocusAudit, FocusRing, FocusRingExt};
 use crate::theme::{ThemeExt, glow_shadow};
+use crate::validation::BuilderWarnings;
 use gpui::prelude::*;
 use gpui::*;
 
@@ -155,8 +159,8 @@ pub struct Tabs {
     selected_index: usize,
     variant: TabVariant,
     theme: Option<TabsTheme>,
-    on_change: Option<Box<dyn Fn(usize, &mut Window, &mut App) + 'static>>,
-    on_close: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<usize>>,
+    on_close: Option<Callback<SharedString>>,
     focus_handle: Option<FocusHandle>,
 }
 
@@ -207,7 +211,7 @@ impl Tabs {
 
     /// Set the tab change handler
     pub fn on_change(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(handler));
         self
     }
 
@@ -216,7 +220,9 @@ impl Tabs {
         mut self,
         handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_close = Some(Box::new(handler));
+        self.on_close = Some(Callback::new(move |id: SharedString, window, cx| {
+            handler(&id, window, cx)
+        }));
         self
     }
 
@@ -229,15 +235,34 @@ impl Tabs {
     pub fn build_with_theme(self, global_theme: &TabsTheme, cx: &mut App) -> Stateful<Div> {
         let theme = self.theme.as_ref().unwrap_or(global_theme);
 
+        let mut warnings = BuilderWarnings::new("Tabs", &self.id);
+        warnings.check(
+            self.tabs.is_empty() || self.selected_index < self.tabs.len(),
+            || {
+                format!(
+                    "selected index {} is out of range ({} tabs)",
+                    self.selected_index,
+                    self.tabs.len()
+                )
+            },
+        );
+
         // Get or create focus handle
         let focus_handle = self.focus_handle.unwrap_or_else(|| cx.focus_handle());
 
         let mut container = div()
             .id(self.id.clone())
             .track_focus(&focus_handle)
+            .relative()
             .flex()
             .items_center()
-            .focusable();
+            .focusable()
+            .focus_ring(FocusRing::from_theme(&cx.theme()))
+            .children(FocusAudit::marker(
+                self.id.to_string(),
+                Some(&focus_handle),
+                cx,
+            ));
 
         // Apply variant-specific container styling
         match self.variant {
@@ -255,9 +280,16 @@ impl Tabs {
             }
         }
 
-        // Wrap callbacks in Rc for safe sharing across closures
-        let on_change_rc = self.on_change.map(|f| std::rc::Rc::new(f));
-        let on_close_rc = self.on_close.map(|f| std::rc::Rc::new(f));
+        let tab_ids: Vec<SharedString> = self.tabs.iter().map(|tab| tab.id.clone()).collect();
+        let on_change_rc = self.on_change.map(|f| {
+            Callback::new(move |index: usize, window, cx| {
+                if let Some(tab_id) = tab_ids.get(index) {
+                    record_navigation(cx, tab_id);
+                }
+                f.call(index, window, cx);
+            })
+        });
+        let on_close_rc = self.on_close;
 
         // Capture tab count before consuming tabs
         let tab_count = self.tabs.len();
@@ -308,7 +340,7 @@ impl Tabs {
                         tab_content = tab_content.on_mouse_down(
                             MouseButton::Left,
                             move |_event, window, cx| {
-                                handler(idx, window, cx);
+                                handler.call(idx, window, cx);
                             },
                         );
                     }
@@ -353,7 +385,7 @@ impl Tabs {
                         close_btn = close_btn.on_mouse_down(
                             MouseButton::Left,
                             move |_event, window, cx| {
-                                handler(&id, window, cx);
+                                handler.call(id.clone(), window, cx);
                             },
                         );
                     }
@@ -420,7 +452,7 @@ impl Tabs {
                         let handler = handler.clone();
                         tab_el =
                             tab_el.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
-                                handler(idx, window, cx);
+                                handler.call(idx, window, cx);
                             });
                     }
                 }
@@ -542,7 +574,7 @@ impl Tabs {
                         let handler = handler.clone();
                         tab_el =
                             tab_el.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
-                                handler(idx, window, cx);
+                                handler.call(idx, window, cx);
                             });
                     }
                 }
@@ -586,7 +618,7 @@ impl Tabs {
                         close_btn = close_btn.on_mouse_down(
                             MouseButton::Left,
                             move |_event, window, cx| {
-                                handler(&id, window, cx);
+                                handler.call(id.clone(), window, cx);
                             },
                         );
                     }
@@ -640,12 +672,12 @@ impl Tabs {
             if let Some(new_idx) = new_index {
                 cx.stop_propagation();
                 if let Some(ref handler) = on_change_key {
-                    handler(new_idx, window, cx);
+                    handler.call(new_idx, window, cx);
                 }
             }
         });
 
-        container
+        container.children(warnings.marker())
     }
 }
 
@@ -670,3 +702,141 @@ impl IntoElement for Tabs {
         gpui::Component::new(self)
     }
 }
+
+/// Factory building the tab items of a [`TabsView`] on every render
+pub type TabItemsFactory = Box<dyn Fn() -> Vec<TabItem> + 'static>;
+
+/// Events emitted by [`TabsView`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TabsEvent {
+    /// The user selected a different tab
+    SelectionChanged { index: usize },
+    /// The user clicked the close button of a closeable tab
+    CloseRequested { id: SharedString },
+}
+
+/// A [`Tabs`] bar that manages its own selection and focus
+///
+/// Tab items are not clonable (they may hold custom icon elements), so the
+/// view builds them with a factory on every render. Closing a tab only
+/// emits [`TabsEvent::CloseRequested`]; the parent removes it with
+/// [`TabsView::set_tabs`]. In controlled mode selecting a tab only emits
+/// [`TabsEvent::SelectionChanged`]; the parent applies it with
+/// [`TabsView::set_selected`].
+///
+/// ```ignore
+/// let tabs = cx.new(|cx| {
+///     TabsView::new("settings-tabs", cx)
+///         .tabs(|| vec![TabItem::new("general", "General"), TabItem::new("audio", "Audio")])
+/// });
+/// cx.subscribe(&tabs, |this, _, event, cx| {
+///     if let TabsEvent::SelectionChanged { index } = event {
+///         this.page = *index;
+///         cx.notify();
+///     }
+/// })
+/// .detach();
+/// ```
+pub struct TabsView {
+    id: ElementId,
+    tabs: TabItemsFactory,
+    selected_index: usize,
+    variant: TabVariant,
+    focus_handle: FocusHandle,
+    controlled: bool,
+}
+
+impl EventEmitter<TabsEvent> for TabsView {}
+
+impl Focusable for TabsView {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl TabsView {
+    /// Create a new tabs view without tabs
+    pub fn new(id: impl Into<ElementId>, cx: &mut Context<Self>) -> Self {
+        Self {
+            id: id.into(),
+            tabs: Box::new(Vec::new),
+            selected_index: 0,
+            variant: TabVariant::default(),
+            focus_handle: cx.focus_handle(),
+            controlled: false,
+        }
+    }
+
+    /// Set the tab items factory
+    pub fn tabs(mut self, tabs: impl Fn() -> Vec<TabItem> + 'static) -> Self {
+        self.tabs = Box::new(tabs);
+        self
+    }
+
+    /// Set the initially selected tab index
+    pub fn selected_index(mut self, index: usize) -> Self {
+        self.selected_index = index;
+        self
+    }
+
+    /// Set the visual variant
+    pub fn variant(mut self, variant: TabVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Only emit events, leaving state changes to the parent
+    pub fn controlled(mut self, controlled: bool) -> Self {
+        self.controlled = controlled;
+        self
+    }
+
+    /// Get the selected tab index
+    pub fn selected(&self) -> usize {
+        self.selected_index
+    }
+
+    /// Select a tab without emitting an event
+    pub fn set_selected(&mut self, index: usize, cx: &mut Context<Self>) {
+        self.selected_index = index;
+        cx.notify();
+    }
+
+    /// Replace the tab items factory
+    pub fn set_tabs(&mut self, tabs: impl Fn() -> Vec<TabItem> + 'static, cx: &mut Context<Self>) {
+        self.tabs = Box::new(tabs);
+        cx.notify();
+    }
+
+    fn select(&mut self, index: usize, cx: &mut Context<Self>) {
+        if index != self.selected_index {
+            if !self.controlled {
+                self.selected_index = index;
+            }
+            cx.emit(TabsEvent::SelectionChanged { index });
+        }
+        cx.notify();
+    }
+}
+
+impl Render for TabsView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tabs = (self.tabs)();
+        // Keep the selection valid when tabs were removed
+        self.selected_index = self.selected_index.min(tabs.len().saturating_sub(1));
+
+        let on_change = cx.listener(|this, index: &usize, _window, cx| this.select(*index, cx));
+        let tabs = Tabs::new(self.id.clone())
+            .tabs(tabs)
+            .selected_index(self.selected_index)
+            .variant(self.variant)
+            .focus_handle(self.focus_handle.clone())
+            .on_change(move |index, window, cx| on_change(&index, window, cx))
+            .on_close(cx.listener(|_this, id: &SharedString, _window, cx| {
+                cx.emit(TabsEvent::CloseRequested { id: id.clone() });
+            }));
+
+        let theme = TabsTheme::from(&cx.theme());
+        tabs.build_with_theme(&theme, cx)
+    }
+}
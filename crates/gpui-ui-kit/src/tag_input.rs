@@ -0,0 +1,481 @@
+//! Tag (chip) input
+//!
+//! [`TagInput`] edits a list of short labels: text typed in its input
+//! becomes a removable chip on Enter or when a comma is typed, so pasting
+//! `"bass, tweeter, anechoic"` adds three tags at once. Tags are trimmed,
+//! and duplicates (ignoring case) and tags beyond the limit are dropped.
+//! Suggestions matching the typed text are offered in a dropdown.
+//!
+//! The tag list is owned by the parent, which stores the list passed to
+//! `on_change`:
+//!
+//! ```ignore
+//! TagInput::new("labels")
+//!     .tags(self.labels.clone())
+//!     .suggestions(self.known_labels.clone())
+//!     .max_tags(8)
+//!     .placeholder("Filter by label")
+//!     .on_change(cx.listener(|this, tags: &[SharedString], _, cx| {
+//!         this.labels = tags.to_vec();
+//!         cx.notify();
+//!     }))
+//! ```
+
+use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::input::{Input, InputSize};
+use crate::state_registry::ElementStateRegistry;
+use crate::theme::{Elevation, ElevationTokens, ThemeExt};
+use gpui::prelude::*;
+use gpui::{deferred, *};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Suggestions shown at most in the dropdown
+const MAX_SUGGESTIONS: usize = 8;
+
+// Typing state of the rendered tag inputs, kept alive by their listeners
+thread_local! {
+    static TAG_INPUT_STATES: RefCell<ElementStateRegistry<TagInputState>> =
+        RefCell::new(ElementStateRegistry::default());
+}
+
+/// Typing state of one tag input
+#[derive(Debug, Default)]
+struct TagInputState {
+    /// Text typed since the last tag was added
+    draft: String,
+    /// Bumped whenever the draft is consumed, giving the inner input a new
+    /// ID and so a fresh edit state
+    generation: usize,
+}
+
+/// Split typed or pasted text on commas into trimmed, non-empty tags
+pub fn split_tags(text: &str) -> Vec<&str> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// Append `candidates` to `tags`, skipping duplicates (ignoring case) and
+/// stopping at `max_tags`
+pub fn add_tags<'a>(
+    tags: &[SharedString],
+    candidates: impl IntoIterator<Item = &'a str>,
+    max_tags: Option<usize>,
+) -> Vec<SharedString> {
+    let mut result = tags.to_vec();
+    for candidate in candidates {
+        let candidate = candidate.trim();
+        if max_tags.is_some_and(|max| result.len() >= max) {
+            break;
+        }
+        if candidate.is_empty() || contains_tag(&result, candidate) {
+            continue;
+        }
+        result.push(candidate.to_string().into());
+    }
+    result
+}
+
+/// Suggestions containing `draft` (ignoring case) that are not tags yet
+pub fn matching_suggestions<'a>(
+    suggestions: &'a [SharedString],
+    tags: &[SharedString],
+    draft: &str,
+) -> Vec<&'a SharedString> {
+    let needle = draft.trim().to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    suggestions
+        .iter()
+        .filter(|s| s.to_lowercase().contains(&needle) && !contains_tag(tags, s))
+        .take(MAX_SUGGESTIONS)
+        .collect()
+}
+
+fn contains_tag(tags: &[SharedString], tag: &str) -> bool {
+    tags.iter().any(|t| t.to_lowercase() == tag.to_lowercase())
+}
+
+/// Theme colors for the tag input
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct TagInputTheme {
+    /// Chip background
+    #[theme(default = 0x2a2a2aff, from = surface)]
+    pub chip_bg: Rgba,
+    /// Chip border
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub chip_border: Rgba,
+    /// Chip text
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub chip_text: Rgba,
+    /// Chip remove button
+    #[theme(default = 0x888888ff, from = text_muted)]
+    pub remove: Rgba,
+    /// Chip remove button when hovered
+    #[theme(default = 0xcc3333ff, from = error)]
+    pub remove_hover: Rgba,
+    /// Label and tag count
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub label: Rgba,
+    /// Suggestions dropdown background
+    #[theme(default = 0x1e1e1eff, from = surface)]
+    pub dropdown_bg: Rgba,
+    /// Suggestions dropdown border
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub dropdown_border: Rgba,
+    /// Suggestion text
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub suggestion_text: Rgba,
+    /// Suggestion background when hovered
+    #[theme(default = 0x3a3a3aff, from = surface_hover)]
+    pub suggestion_hover_bg: Rgba,
+    /// Suggestion dropdown shadow
+    #[theme(
+        default_expr = "ElevationTokens::default().shadow(Elevation::Overlay)",
+        from_expr = "theme.elevation.shadow(Elevation::Overlay)"
+    )]
+    pub dropdown_shadow: Vec<BoxShadow>,
+}
+
+/// Input editing a list of tags shown as removable chips
+#[derive(IntoElement)]
+pub struct TagInput {
+    id: ElementId,
+    tags: Vec<SharedString>,
+    suggestions: Vec<SharedString>,
+    max_tags: Option<usize>,
+    label: Option<SharedString>,
+    placeholder: Option<SharedString>,
+    size: InputSize,
+    disabled: bool,
+    theme: Option<TagInputTheme>,
+    on_change: Option<Callback<Vec<SharedString>>>,
+}
+
+impl TagInput {
+    /// Create an empty tag input
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            tags: Vec::new(),
+            suggestions: Vec::new(),
+            max_tags: None,
+            label: None,
+            placeholder: None,
+            size: InputSize::Sm,
+            disabled: false,
+            theme: None,
+            on_change: None,
+        }
+    }
+
+    /// Set the current tags
+    pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the tags offered in the dropdown while typing
+    pub fn suggestions(
+        mut self,
+        suggestions: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.suggestions = suggestions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Limit the number of tags; the input is hidden once reached
+    pub fn max_tags(mut self, max: usize) -> Self {
+        self.max_tags = Some(max);
+        self
+    }
+
+    /// Set the label shown above the chips
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the input placeholder
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the input size
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Show the tags without allowing changes
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the tag input theme
+    pub fn theme(mut self, theme: TagInputTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called with the new tag list when tags are added or removed
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Callback::new(move |tags: Vec<SharedString>, window, cx| {
+            handler(&tags, window, cx)
+        }));
+        self
+    }
+
+    fn child_id(&self, suffix: impl std::fmt::Display) -> ElementId {
+        ElementId::Name(SharedString::from(format!("{}-{}", self.id, suffix)))
+    }
+}
+
+/// Adds tags from the draft and reports the new list
+#[derive(Clone)]
+struct Committer {
+    tags: Rc<Vec<SharedString>>,
+    max_tags: Option<usize>,
+    state: Rc<RefCell<TagInputState>>,
+    on_change: Option<Callback<Vec<SharedString>>>,
+}
+
+impl Committer {
+    /// Add `candidates`, clear the draft and start a fresh input
+    fn commit<'a>(
+        &self,
+        candidates: impl IntoIterator<Item = &'a str>,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let tags = add_tags(&self.tags, candidates, self.max_tags);
+        let mut state = self.state.borrow_mut();
+        state.draft.clear();
+        state.generation += 1;
+        drop(state);
+        window.refresh();
+        if tags != *self.tags
+            && let Some(on_change) = &self.on_change
+        {
+            on_change.call(tags, window, cx);
+        }
+    }
+}
+
+impl RenderOnce for TagInput {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| TagInputTheme::from(&global_theme));
+        let (focus_handle, state) = TAG_INPUT_STATES.with_borrow_mut(|registry| {
+            (
+                registry.focus_handle(&self.id, cx),
+                registry.state(&self.id, TagInputState::default),
+            )
+        });
+        let tags = Rc::new(self.tags.clone());
+        let committer = Committer {
+            tags: tags.clone(),
+            max_tags: self.max_tags,
+            state: state.clone(),
+            on_change: self.on_change.clone(),
+        };
+
+        let mut container = div().flex().flex_col().gap_1().relative();
+        if let Some(label) = &self.label {
+            let count = match self.max_tags {
+                Some(max) => format!("{label} ({}/{max})", tags.len()),
+                None => label.to_string(),
+            };
+            container = container.child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(theme.label)
+                    .child(count),
+            );
+        }
+
+        // Chips
+        if !tags.is_empty() {
+            let chips = tags.iter().enumerate().map(|(index, tag)| {
+                let mut chip = div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .px_2()
+                    .py_0p5()
+                    .rounded_full()
+                    .border_1()
+                    .border_color(theme.chip_border)
+                    .bg(theme.chip_bg)
+                    .text_xs()
+                    .text_color(theme.chip_text)
+                    .child(tag.clone());
+                if !self.disabled {
+                    let remaining: Vec<SharedString> = tags
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| *i != index)
+                        .map(|(_, t)| t.clone())
+                        .collect();
+                    let on_change = self.on_change.clone();
+                    let remove_hover = theme.remove_hover;
+                    chip = chip.child(
+                        div()
+                            .id(self.child_id(format!("remove-{index}")))
+                            .cursor_pointer()
+                            .text_color(theme.remove)
+                            .hover(move |style| style.text_color(remove_hover))
+                            .on_click(move |_event, window, cx| {
+                                if let Some(on_change) = &on_change {
+                                    on_change.call(remaining.clone(), window, cx);
+                                }
+                            })
+                            .child("×"),
+                    );
+                }
+                chip
+            });
+            container = container.child(div().flex().flex_wrap().gap_1().children(chips));
+        }
+
+        let full = self.max_tags.is_some_and(|max| tags.len() >= max);
+        if self.disabled || full {
+            return container;
+        }
+
+        // Input, recreated with a new ID whenever its text became tags
+        let (draft, generation) = {
+            let current = state.borrow();
+            (current.draft.clone(), current.generation)
+        };
+        let typed = committer.clone();
+        let ended = committer.clone();
+        let focus_for_end = focus_handle.clone();
+        let mut input = Input::new(self.child_id(format!("input-{generation}")))
+            .value(draft.clone())
+            .size(self.size)
+            .focus_handle(focus_handle.clone())
+            .on_text_change(move |text, window, cx| {
+                if text.contains(',') {
+                    typed.commit(split_tags(&text), window, cx);
+                } else {
+                    typed.state.borrow_mut().draft = text;
+                    window.refresh();
+                }
+            })
+            .on_edit_end(move |text, window, cx| match text {
+                Some(text) => {
+                    ended.commit(split_tags(&text), window, cx);
+                    // Keep typing the next tag
+                    focus_for_end.focus(window, cx);
+                }
+                None => ended.commit([], window, cx),
+            });
+        if let Some(placeholder) = &self.placeholder {
+            input = input.placeholder(placeholder.clone());
+        }
+        container = container.child(input);
+
+        // Suggestions for the typed text
+        let matches = matching_suggestions(&self.suggestions, &tags, &draft);
+        if focus_handle.is_focused(window) && !matches.is_empty() {
+            let options = matches.into_iter().enumerate().map(|(index, suggestion)| {
+                let pick = committer.clone();
+                let suggestion = suggestion.clone();
+                let refocus = focus_handle.clone();
+                div()
+                    .id(self.child_id(format!("suggestion-{index}")))
+                    .px_2()
+                    .py_1()
+                    .text_sm()
+                    .cursor_pointer()
+                    .text_color(theme.suggestion_text)
+                    .hover(|style| style.bg(theme.suggestion_hover_bg))
+                    .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                        cx.stop_propagation();
+                        pick.commit([suggestion.as_ref()], window, cx);
+                        refocus.focus(window, cx);
+                    })
+                    .child(suggestion.clone())
+            });
+            let dropdown = div()
+                .id(self.child_id("suggestions"))
+                .absolute()
+                .top_full()
+                .left_0()
+                .min_w_full()
+                .mt_1()
+                .py_1()
+                .bg(theme.dropdown_bg)
+                .border_1()
+                .border_color(theme.dropdown_border)
+                .rounded_md()
+                .shadow(theme.dropdown_shadow.clone())
+                .occlude()
+                .children(options);
+            container = container.child(deferred(dropdown).with_priority(1));
+        }
+
+        container
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(names: &[&str]) -> Vec<SharedString> {
+        names
+            .iter()
+            .map(|n| SharedString::from(n.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_split_pasted_text() {
+        assert_eq!(
+            split_tags(" bass, tweeter ,,anechoic,"),
+            ["bass", "tweeter", "anechoic"]
+        );
+        assert!(split_tags(" , ").is_empty());
+    }
+
+    #[test]
+    fn test_add_tags_skips_duplicates_and_respects_limit() {
+        let current = tags(&["Bass"]);
+        assert_eq!(
+            add_tags(&current, ["bass", "Mid", " mid ", "Treble"], None),
+            tags(&["Bass", "Mid", "Treble"])
+        );
+        assert_eq!(
+            add_tags(&current, ["Mid", "Treble"], Some(2)),
+            tags(&["Bass", "Mid"])
+        );
+        assert_eq!(add_tags(&current, [""], None), current);
+    }
+
+    #[test]
+    fn test_matching_suggestions() {
+        let suggestions = tags(&["Anechoic", "Bass", "Bass reflex", "Sealed"]);
+        let current = tags(&["bass"]);
+        let found: Vec<&str> = matching_suggestions(&suggestions, &current, "BAS")
+            .into_iter()
+            .map(|s| s.as_ref())
+            .collect();
+        assert_eq!(found, ["Bass reflex"]);
+        assert!(matching_suggestions(&suggestions, &current, " ").is_empty());
+    }
+}
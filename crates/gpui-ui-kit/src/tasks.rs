@@ -0,0 +1,829 @@
+//! Async task manager
+//!
+//! Tracks long-running operations (data loads, optimizations, exports) in one
+//! place so they surface consistently:
+//! - [`spawn_task`] runs a named async task and registers it in the
+//!   [`TaskManager`] global
+//! - the task reports progress and checks for cancellation through its
+//!   [`TaskHandle`], which is `Send` and can be moved to background threads
+//! - [`TaskList`] shows every task with progress and cancel buttons
+//! - [`TaskStatusIndicator`] is a compact summary for status bars
+//! - [`TaskManager::toasts`] turns finished tasks into notifications
+//!
+//! # Example
+//!
+//! ```ignore
+//! let id = spawn_task("Load measurements", cx, async move |task, cx| {
+//!     let files = list_files()?;
+//!     for (i, file) in files.iter().enumerate() {
+//!         if task.is_cancelled() {
+//!             return Ok(());
+//!         }
+//!         task.set_message(file.display().to_string());
+//!         cx.background_executor().spawn(load(file.clone())).await?;
+//!         task.set_progress((i + 1) as f32 / files.len() as f32);
+//!     }
+//!     Ok(())
+//! });
+//! ```
+
+use crate::ComponentTheme;
+use crate::callback::Callback;
+use crate::icon_button::{IconButton, IconButtonSize, IconButtonVariant};
+use crate::progress::{Progress, ProgressSize, ProgressVariant};
+use crate::theme::ThemeExt;
+use crate::toast::{Toast, ToastVariant};
+use gpui::prelude::*;
+use gpui::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Interval between repaints while tasks are running
+const TASK_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Identifier of a task in the [`TaskManager`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaskId(u64);
+
+/// Lifecycle of a task
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// Still running
+    Running,
+    /// Finished successfully
+    Completed,
+    /// Finished with an error
+    Failed(SharedString),
+    /// Cancelled before finishing
+    Cancelled,
+}
+
+impl TaskStatus {
+    /// Whether the task has stopped
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, TaskStatus::Running)
+    }
+}
+
+#[derive(Default)]
+struct TaskReport {
+    progress: Option<f32>,
+    message: Option<SharedString>,
+}
+
+#[derive(Default)]
+struct TaskShared {
+    cancelled: AtomicBool,
+    report: Mutex<TaskReport>,
+}
+
+/// Progress reporting and cancellation token handed to a running task
+///
+/// Cheap to clone and safe to move to background threads.
+#[derive(Clone, Default)]
+pub struct TaskHandle {
+    shared: Arc<TaskShared>,
+}
+
+impl TaskHandle {
+    /// Create a handle that is not registered with a manager
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report progress as a fraction in 0..=1 (clamped)
+    pub fn set_progress(&self, progress: f32) {
+        let progress = if progress.is_finite() {
+            progress.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.report().progress = Some(progress);
+    }
+
+    /// Go back to indeterminate progress
+    pub fn clear_progress(&self) {
+        self.report().progress = None;
+    }
+
+    /// Describe the current step (e.g. the file being loaded)
+    pub fn set_message(&self, message: impl Into<SharedString>) {
+        self.report().message = Some(message.into());
+    }
+
+    /// Last reported progress, `None` while indeterminate
+    pub fn progress(&self) -> Option<f32> {
+        self.report().progress
+    }
+
+    /// Last reported step description
+    pub fn message(&self) -> Option<SharedString> {
+        self.report().message.clone()
+    }
+
+    /// Request cancellation
+    pub fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether cancellation was requested; long loops should check this
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn report(&self) -> std::sync::MutexGuard<'_, TaskReport> {
+        // A panic while holding the lock only leaves stale progress behind
+        self.shared
+            .report
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A task tracked by the [`TaskManager`]
+pub struct TaskEntry {
+    id: TaskId,
+    name: SharedString,
+    status: TaskStatus,
+    handle: TaskHandle,
+    started: Instant,
+    finished: Option<Instant>,
+    task: Option<Task<()>>,
+}
+
+impl TaskEntry {
+    /// Task identifier
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Display name
+    pub fn name(&self) -> &SharedString {
+        &self.name
+    }
+
+    /// Current status
+    pub fn status(&self) -> &TaskStatus {
+        &self.status
+    }
+
+    /// Whether the task is still running
+    pub fn is_running(&self) -> bool {
+        !self.status.is_finished()
+    }
+
+    /// Last reported progress, `None` while indeterminate
+    ///
+    /// Finished tasks report full progress.
+    pub fn progress(&self) -> Option<f32> {
+        match self.status {
+            TaskStatus::Running => self.handle.progress(),
+            TaskStatus::Completed => Some(1.0),
+            TaskStatus::Failed(_) | TaskStatus::Cancelled => self.handle.progress(),
+        }
+    }
+
+    /// Last reported step description
+    pub fn message(&self) -> Option<SharedString> {
+        self.handle.message()
+    }
+
+    /// Time spent running (up to now for running tasks)
+    pub fn elapsed(&self) -> Duration {
+        self.finished
+            .unwrap_or_else(Instant::now)
+            .duration_since(self.started)
+    }
+
+    /// Notification for a finished task, `None` while running
+    pub fn toast(&self) -> Option<Toast> {
+        let (variant, message) = match &self.status {
+            TaskStatus::Running => return None,
+            TaskStatus::Completed => (ToastVariant::Success, SharedString::from("Completed")),
+            TaskStatus::Failed(error) => (ToastVariant::Error, error.clone()),
+            TaskStatus::Cancelled => (ToastVariant::Warning, SharedString::from("Cancelled")),
+        };
+        Some(
+            Toast::new(
+                ElementId::Name(SharedString::from(format!("task-toast-{}", self.id.0))),
+                message,
+            )
+            .title(self.name.clone())
+            .variant(variant),
+        )
+    }
+}
+
+/// Global registry of running and finished tasks
+#[derive(Default)]
+pub struct TaskManager {
+    next_id: u64,
+    entries: Vec<TaskEntry>,
+    ticking: bool,
+}
+
+impl Global for TaskManager {}
+
+impl TaskManager {
+    /// Create an empty manager
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All tasks, oldest first
+    pub fn tasks(&self) -> &[TaskEntry] {
+        &self.entries
+    }
+
+    /// Look up a task
+    pub fn get(&self, id: TaskId) -> Option<&TaskEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    /// Number of running tasks
+    pub fn running_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.is_running())
+            .count()
+    }
+
+    /// Average progress of the running tasks that report one
+    ///
+    /// `None` when nothing runs or every running task is indeterminate.
+    pub fn overall_progress(&self) -> Option<f32> {
+        let reported: Vec<f32> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.is_running())
+            .filter_map(TaskEntry::progress)
+            .collect();
+        if reported.is_empty() {
+            None
+        } else {
+            Some(reported.iter().sum::<f32>() / reported.len() as f32)
+        }
+    }
+
+    /// Cancel a running task
+    ///
+    /// Sets its cancellation token and drops its future, so it stops at its
+    /// next await point. Returns `false` if the task isn't running.
+    pub fn cancel(&mut self, id: TaskId) -> bool {
+        let Some(entry) = self.entry_mut(id) else {
+            return false;
+        };
+        if !entry.is_running() {
+            return false;
+        }
+        entry.handle.cancel();
+        entry.task = None;
+        entry.status = TaskStatus::Cancelled;
+        entry.finished = Some(Instant::now());
+        true
+    }
+
+    /// Forget a finished task; running tasks are kept
+    pub fn dismiss(&mut self, id: TaskId) -> bool {
+        let before = self.entries.len();
+        self.entries
+            .retain(|entry| entry.id != id || entry.is_running());
+        self.entries.len() != before
+    }
+
+    /// Forget all finished tasks
+    pub fn clear_finished(&mut self) {
+        self.entries.retain(TaskEntry::is_running);
+    }
+
+    /// Notifications for the finished tasks, oldest first
+    ///
+    /// Render them in a [`ToastContainer`](crate::ToastContainer) and call
+    /// [`TaskManager::dismiss`] when one closes.
+    pub fn toasts(&self) -> Vec<Toast> {
+        self.entries.iter().filter_map(TaskEntry::toast).collect()
+    }
+
+    fn register(&mut self, name: SharedString, handle: TaskHandle) -> TaskId {
+        let id = TaskId(self.next_id);
+        self.next_id += 1;
+        self.entries.push(TaskEntry {
+            id,
+            name,
+            status: TaskStatus::Running,
+            handle,
+            started: Instant::now(),
+            finished: None,
+            task: None,
+        });
+        id
+    }
+
+    fn attach(&mut self, id: TaskId, task: Task<()>) {
+        if let Some(entry) = self.entry_mut(id)
+            && entry.is_running()
+        {
+            entry.task = Some(task);
+        }
+    }
+
+    fn finish(&mut self, id: TaskId, result: Result<(), SharedString>) {
+        let Some(entry) = self.entry_mut(id) else {
+            return;
+        };
+        if !entry.is_running() {
+            return;
+        }
+        entry.status = match result {
+            Ok(()) if entry.handle.is_cancelled() => TaskStatus::Cancelled,
+            Ok(()) => TaskStatus::Completed,
+            Err(error) => TaskStatus::Failed(error),
+        };
+        entry.finished = Some(Instant::now());
+        // The future is finishing right now; detach instead of dropping it
+        if let Some(task) = entry.task.take() {
+            task.detach();
+        }
+    }
+
+    fn entry_mut(&mut self, id: TaskId) -> Option<&mut TaskEntry> {
+        self.entries.iter_mut().find(|entry| entry.id == id)
+    }
+}
+
+/// Run a named async task and track it in the [`TaskManager`]
+///
+/// `work` receives the task's [`TaskHandle`] and the async app context.
+/// Returning `Err` marks the task as failed with that message. Windows are
+/// repainted periodically while tasks run so progress stays current.
+pub fn spawn_task<F>(name: impl Into<SharedString>, cx: &mut App, work: F) -> TaskId
+where
+    F: AsyncFnOnce(TaskHandle, &mut AsyncApp) -> Result<(), SharedString> + 'static,
+{
+    let handle = TaskHandle::new();
+    let id = cx
+        .default_global::<TaskManager>()
+        .register(name.into(), handle.clone());
+
+    let task = cx.spawn(async move |cx: &mut AsyncApp| {
+        let result = work(handle, cx).await;
+        cx.update(|cx| {
+            cx.global_mut::<TaskManager>().finish(id, result);
+            cx.refresh_windows();
+        })
+        .ok();
+    });
+    cx.global_mut::<TaskManager>().attach(id, task);
+
+    refresh_while_running(cx);
+    id
+}
+
+/// Cancel a running task, see [`TaskManager::cancel`]
+pub fn cancel_task(id: TaskId, cx: &mut App) -> bool {
+    let cancelled = cx
+        .try_global_mut::<TaskManager>()
+        .is_some_and(|manager| manager.cancel(id));
+    if cancelled {
+        cx.refresh_windows();
+    }
+    cancelled
+}
+
+/// Forget a finished task, see [`TaskManager::dismiss`]
+pub fn dismiss_task(id: TaskId, cx: &mut App) -> bool {
+    let dismissed = cx
+        .try_global_mut::<TaskManager>()
+        .is_some_and(|manager| manager.dismiss(id));
+    if dismissed {
+        cx.refresh_windows();
+    }
+    dismissed
+}
+
+/// Repaint all windows until no task is running
+fn refresh_while_running(cx: &mut App) {
+    let manager = cx.global_mut::<TaskManager>();
+    if manager.ticking {
+        return;
+    }
+    manager.ticking = true;
+
+    cx.spawn(async move |cx: &mut AsyncApp| {
+        loop {
+            cx.background_executor().timer(TASK_REFRESH_INTERVAL).await;
+            let running = cx.update(|cx| {
+                cx.refresh_windows();
+                let manager = cx.global_mut::<TaskManager>();
+                let running = manager.running_count() > 0;
+                if !running {
+                    manager.ticking = false;
+                }
+                running
+            });
+            if !matches!(running, Ok(true)) {
+                break;
+            }
+        }
+    })
+    .detach();
+}
+
+/// Format a duration as `12s` or `3m 05s`
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Theme colors for task components
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct TaskListTheme {
+    /// Row background
+    #[theme(default = 0x252526ff, from = surface)]
+    pub background: Rgba,
+    /// Row separator
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub border: Rgba,
+    /// Task name color
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub name: Rgba,
+    /// Message and elapsed time color
+    #[theme(default = 0x999999ff, from = text_muted)]
+    pub detail: Rgba,
+    /// Running indicator color
+    #[theme(default = 0x007accff, from = accent)]
+    pub running: Rgba,
+    /// Completed indicator color
+    #[theme(default = 0x4caf50ff, from = success)]
+    pub completed: Rgba,
+    /// Failed indicator color
+    #[theme(default = 0xf44336ff, from = error)]
+    pub failed: Rgba,
+    /// Cancelled indicator color
+    #[theme(default = 0xff9800ff, from = warning)]
+    pub cancelled: Rgba,
+}
+
+impl TaskListTheme {
+    fn status_color(&self, status: &TaskStatus) -> Rgba {
+        match status {
+            TaskStatus::Running => self.running,
+            TaskStatus::Completed => self.completed,
+            TaskStatus::Failed(_) => self.failed,
+            TaskStatus::Cancelled => self.cancelled,
+        }
+    }
+}
+
+fn status_icon(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Running => "⟳",
+        TaskStatus::Completed => "✓",
+        TaskStatus::Failed(_) => "✕",
+        TaskStatus::Cancelled => "⊘",
+    }
+}
+
+/// List of the tasks in the [`TaskManager`]
+///
+/// Running tasks show a progress bar and a cancel button, finished tasks a
+/// dismiss button.
+#[derive(IntoElement)]
+pub struct TaskList {
+    id: ElementId,
+    show_finished: bool,
+    empty_message: SharedString,
+    theme: Option<TaskListTheme>,
+}
+
+impl TaskList {
+    /// Create a task list
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            show_finished: true,
+            empty_message: "No background tasks".into(),
+            theme: None,
+        }
+    }
+
+    /// Include finished tasks (default: true)
+    pub fn show_finished(mut self, show: bool) -> Self {
+        self.show_finished = show;
+        self
+    }
+
+    /// Text shown when there is nothing to list
+    pub fn empty_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.empty_message = message.into();
+        self
+    }
+
+    /// Set the theme
+    pub fn theme(mut self, theme: TaskListTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    fn child_id(&self, suffix: &str, task: TaskId) -> ElementId {
+        ElementId::Name(SharedString::from(format!(
+            "{}-{}-{}",
+            self.id, suffix, task.0
+        )))
+    }
+
+    fn row(&self, entry: &TaskEntry, theme: &TaskListTheme) -> Div {
+        let id = entry.id();
+        let status = entry.status();
+        let color = theme.status_color(status);
+
+        let detail = match status {
+            TaskStatus::Failed(error) => Some(error.clone()),
+            _ => entry.message(),
+        };
+
+        let action = if entry.is_running() {
+            IconButton::new(self.child_id("cancel", id), "✕")
+                .size(IconButtonSize::Xs)
+                .variant(IconButtonVariant::Ghost)
+                .on_click(move |_window, cx| {
+                    cancel_task(id, cx);
+                })
+        } else {
+            IconButton::new(self.child_id("dismiss", id), "−")
+                .size(IconButtonSize::Xs)
+                .variant(IconButtonVariant::Ghost)
+                .on_click(move |_window, cx| {
+                    dismiss_task(id, cx);
+                })
+        };
+
+        let mut body = div().flex_1().min_w_0().flex().flex_col().gap_1().child(
+            div()
+                .flex()
+                .justify_between()
+                .gap_2()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(theme.name)
+                        .truncate()
+                        .child(entry.name().clone()),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.detail)
+                        .child(format_elapsed(entry.elapsed())),
+                ),
+        );
+        if entry.is_running() {
+            // Indeterminate tasks show an empty striped bar
+            let progress = entry.progress();
+            body = body.child(
+                Progress::new(progress.unwrap_or(0.0))
+                    .size(ProgressSize::Xs)
+                    .variant(ProgressVariant::Default)
+                    .striped(progress.is_none())
+                    .animated(progress.is_none()),
+            );
+        }
+        if let Some(detail) = detail {
+            body = body.child(
+                div()
+                    .text_xs()
+                    .text_color(theme.detail)
+                    .truncate()
+                    .child(detail),
+            );
+        }
+
+        div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .px_3()
+            .py_2()
+            .border_b_1()
+            .border_color(theme.border)
+            .child(
+                div()
+                    .w(px(16.0))
+                    .flex_shrink_0()
+                    .text_color(color)
+                    .child(status_icon(status)),
+            )
+            .child(body)
+            .child(action)
+    }
+}
+
+impl RenderOnce for TaskList {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| TaskListTheme::from(&global_theme));
+
+        let rows: Vec<Div> = cx
+            .try_global::<TaskManager>()
+            .map(|manager| {
+                manager
+                    .tasks()
+                    .iter()
+                    .rev()
+                    .filter(|entry| self.show_finished || entry.is_running())
+                    .map(|entry| self.row(entry, &theme))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut list = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .bg(theme.background);
+        if rows.is_empty() {
+            list = list.child(
+                div()
+                    .p_3()
+                    .text_sm()
+                    .text_color(theme.detail)
+                    .child(self.empty_message.clone()),
+            );
+        }
+        list.children(rows)
+    }
+}
+
+/// Compact summary of running tasks for a status bar
+///
+/// Renders nothing while no task runs.
+#[derive(IntoElement)]
+pub struct TaskStatusIndicator {
+    id: ElementId,
+    theme: Option<TaskListTheme>,
+    on_click: Option<Callback>,
+}
+
+impl TaskStatusIndicator {
+    /// Create a status indicator
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            theme: None,
+            on_click: None,
+        }
+    }
+
+    /// Set the theme
+    pub fn theme(mut self, theme: TaskListTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called when the indicator is clicked (e.g. to open a [`TaskList`])
+    pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Callback::from_fn(handler));
+        self
+    }
+}
+
+impl RenderOnce for TaskStatusIndicator {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .unwrap_or_else(|| TaskListTheme::from(&global_theme));
+
+        let (running, progress, label) = cx
+            .try_global::<TaskManager>()
+            .map(|manager| {
+                let running = manager.running_count();
+                let label = if running == 1 {
+                    manager
+                        .tasks()
+                        .iter()
+                        .find(|entry| entry.is_running())
+                        .map(|entry| entry.name().clone())
+                        .unwrap_or_default()
+                } else {
+                    SharedString::from(format!("{running} tasks"))
+                };
+                (running, manager.overall_progress(), label)
+            })
+            .unwrap_or((0, None, SharedString::default()));
+
+        let mut indicator = div().id(self.id);
+        if running == 0 {
+            return indicator;
+        }
+
+        indicator = indicator
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_2()
+            .text_xs()
+            .text_color(theme.detail)
+            .child(
+                div()
+                    .text_color(theme.running)
+                    .child(status_icon(&TaskStatus::Running)),
+            )
+            .child(div().max_w(px(160.0)).truncate().child(label))
+            .children(progress.map(|progress| format!("{:.0}%", progress * 100.0)));
+
+        if let Some(handler) = self.on_click {
+            indicator = indicator
+                .cursor_pointer()
+                .on_click(move |_, window, cx| handler.invoke(window, cx));
+        }
+        indicator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_progress() {
+        let handle = TaskHandle::new();
+        assert_eq!(handle.progress(), None);
+        handle.set_progress(1.5);
+        assert_eq!(handle.progress(), Some(1.0));
+        handle.set_progress(f32::NAN);
+        assert_eq!(handle.progress(), Some(0.0));
+        handle.set_message("step");
+        assert_eq!(handle.message(), Some(SharedString::from("step")));
+
+        let clone = handle.clone();
+        clone.cancel();
+        assert!(handle.is_cancelled());
+    }
+
+    #[test]
+    fn test_manager_lifecycle() {
+        let mut manager = TaskManager::new();
+        let a = TaskHandle::new();
+        let b = TaskHandle::new();
+        let c = TaskHandle::new();
+        let id_a = manager.register("a".into(), a.clone());
+        let id_b = manager.register("b".into(), b.clone());
+        let id_c = manager.register("c".into(), c.clone());
+        assert_eq!(manager.running_count(), 3);
+        assert_eq!(manager.overall_progress(), None);
+
+        a.set_progress(0.2);
+        b.set_progress(0.6);
+        assert!((manager.overall_progress().unwrap() - 0.4).abs() < 1e-6);
+
+        manager.finish(id_a, Ok(()));
+        manager.finish(id_b, Err("disk full".into()));
+        assert_eq!(manager.get(id_a).unwrap().status(), &TaskStatus::Completed);
+        assert_eq!(
+            manager.get(id_b).unwrap().status(),
+            &TaskStatus::Failed("disk full".into())
+        );
+        assert_eq!(manager.get(id_a).unwrap().progress(), Some(1.0));
+
+        assert!(manager.cancel(id_c));
+        assert!(c.is_cancelled());
+        assert!(!manager.cancel(id_c));
+        // A late result doesn't overwrite the cancellation
+        manager.finish(id_c, Ok(()));
+        assert_eq!(manager.get(id_c).unwrap().status(), &TaskStatus::Cancelled);
+
+        assert_eq!(manager.running_count(), 0);
+        assert_eq!(manager.toasts().len(), 3);
+        assert!(manager.dismiss(id_a));
+        manager.clear_finished();
+        assert!(manager.tasks().is_empty());
+    }
+
+    #[test]
+    fn test_dismiss_keeps_running_tasks() {
+        let mut manager = TaskManager::new();
+        let handle = TaskHandle::new();
+        let id = manager.register("load".into(), handle.clone());
+        assert!(!manager.dismiss(id));
+
+        // Cooperative cancellation: the task saw the token and returned Ok
+        handle.cancel();
+        manager.finish(id, Ok(()));
+        assert_eq!(manager.get(id).unwrap().status(), &TaskStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_secs(12)), "12s");
+        assert_eq!(format_elapsed(Duration::from_secs(185)), "3m 05s");
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Typography and text styling utilities.
 
+use crate::callback::Callback;
+use crate::clipboard::{CopyToClipboard, copy_text};
 use crate::theme::{Theme, ThemeExt};
 use gpui::prelude::*;
 use gpui::{Component, *};
@@ -252,6 +254,7 @@ impl RenderOnce for Heading {
 pub struct Code {
     content: SharedString,
     inline: bool,
+    copyable: bool,
     theme: Option<Theme>,
 }
 
@@ -261,6 +264,7 @@ impl Code {
         Self {
             content: content.into(),
             inline: true,
+            copyable: false,
             theme: None,
         }
     }
@@ -270,6 +274,7 @@ impl Code {
         Self {
             content: content.into(),
             inline: false,
+            copyable: false,
             theme: None,
         }
     }
@@ -280,6 +285,12 @@ impl Code {
         self
     }
 
+    /// Show a copy button on code blocks
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+
     /// Build into element with explicit theme
     pub fn build_with_theme(self, theme: &Theme) -> Div {
         // Code uses a slightly different color from accent
@@ -302,14 +313,38 @@ impl Code {
                 .text_color(code_text)
                 .child(self.content)
         } else {
-            div()
+            let mut block = div()
+                .relative()
                 .p_3()
                 .bg(theme.muted)
                 .rounded_md()
                 .text_sm()
                 .text_color(theme.text_secondary)
                 .overflow_hidden()
-                .child(self.content)
+                .child(self.content.clone());
+
+            if self.copyable {
+                let content = self.content;
+                let hover_color = theme.text_primary;
+                block = block.child(
+                    div()
+                        .absolute()
+                        .top_1()
+                        .right_1()
+                        .px_1()
+                        .rounded(px(3.0))
+                        .text_xs()
+                        .text_color(theme.text_muted)
+                        .cursor_pointer()
+                        .hover(move |style| style.text_color(hover_color))
+                        .on_mouse_up(MouseButton::Left, move |_event, _window, cx| {
+                            copy_text(content.to_string(), cx);
+                        })
+                        .child("Copy"),
+                );
+            }
+
+            block
         }
     }
 
@@ -320,6 +355,12 @@ impl Code {
     }
 }
 
+impl CopyToClipboard for Code {
+    fn clipboard_text(&self) -> Option<String> {
+        Some(self.content.to_string())
+    }
+}
+
 impl RenderOnce for Code {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
         let theme = self.theme.clone().unwrap_or_else(|| cx.theme());
@@ -333,7 +374,7 @@ pub struct Link {
     content: SharedString,
     href: Option<SharedString>,
     external: bool,
-    on_click: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_click: Option<Callback>,
     theme: Option<Theme>,
 }
 
@@ -370,7 +411,7 @@ impl Link {
 
     /// Set click handler
     pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Callback::from_fn(handler));
         self
     }
 
@@ -387,7 +428,7 @@ impl Link {
 
         if let Some(handler) = self.on_click {
             link = link.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                handler(window, cx);
+                handler.invoke(window, cx);
             });
         }
 
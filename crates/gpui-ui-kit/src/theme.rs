@@ -19,10 +19,12 @@
 //!     .active(|s| s.bg(accent.active))
 //! ```
 
+use crate::animation::{Animation, Easing, interpolate_color};
 use crate::color_tokens::{
     BackgroundColors, BorderColors, ColorPalette, ColorToken, SemanticColors, TextColors,
 };
 use gpui::*;
+use std::time::{Duration, Instant};
 
 /// Available theme variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -148,6 +150,14 @@ pub struct Theme {
     pub badge_info_bg: Rgba,
     /// Badge info text
     pub badge_info_text: Rgba,
+
+    // Depth and focus
+    /// Shadow presets of raised surfaces (cards, menus, dialogs)
+    pub elevation: ElevationTokens,
+    /// Color of the ring around the focused control
+    pub focus_ring: Rgba,
+    /// Width of the focus ring in pixels
+    pub focus_ring_width: f32,
 }
 
 impl Theme {
@@ -189,6 +199,10 @@ impl Theme {
             badge_error_text: rgb(0xcc7c7c),
             badge_info_bg: rgb(0x1a3a3a),
             badge_info_text: rgb(0x7ccccc),
+            // Depth and focus
+            elevation: ElevationTokens::new(rgba(0x00000066)),
+            focus_ring: rgb(0x007acc),
+            focus_ring_width: 2.0,
         }
     }
 
@@ -230,6 +244,10 @@ impl Theme {
             badge_error_text: rgb(0xdc2626),
             badge_info_bg: rgb(0xe0f2fe),
             badge_info_text: rgb(0x0284c7),
+            // Depth and focus
+            elevation: ElevationTokens::new(rgba(0x0000002a)),
+            focus_ring: rgb(0x0066cc),
+            focus_ring_width: 2.0,
         }
     }
 
@@ -271,6 +289,10 @@ impl Theme {
             badge_error_text: rgb(0xcc7c7c),
             badge_info_bg: rgb(0x1a3a3a),
             badge_info_text: rgb(0x7ccccc),
+            // Depth and focus
+            elevation: ElevationTokens::new(rgba(0x01040999)),
+            focus_ring: rgb(0x58a6ff),
+            focus_ring_width: 2.0,
         }
     }
 
@@ -312,6 +334,10 @@ impl Theme {
             badge_error_text: rgb(0xcc7c7c),
             badge_info_bg: rgb(0x1a3a3a),
             badge_info_text: rgb(0x7ccccc),
+            // Depth and focus
+            elevation: ElevationTokens::new(rgba(0x0a120988)),
+            focus_ring: rgb(0x6abf69),
+            focus_ring_width: 2.0,
         }
     }
 
@@ -353,6 +379,10 @@ impl Theme {
             badge_error_text: rgb(0xcc7c7c),
             badge_info_bg: rgb(0x1a3a3a),
             badge_info_text: rgb(0x7ccccc),
+            // Depth and focus (light shadows and a wide white ring on black)
+            elevation: ElevationTokens::new(rgba(0xffffff26)),
+            focus_ring: rgb(0xffffff),
+            focus_ring_width: 3.0,
         }
     }
 
@@ -367,6 +397,48 @@ impl Theme {
         }
     }
 
+    /// Blend every color towards `other` by `t` (0.0 = self, 1.0 = other)
+    ///
+    /// The variant is taken from `other`, the theme being transitioned to.
+    pub fn interpolate(&self, other: &Theme, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |from: Rgba, to: Rgba| interpolate_color(from, to, Easing::Linear, t);
+        Self {
+            variant: other.variant,
+            background: mix(self.background, other.background),
+            surface: mix(self.surface, other.surface),
+            surface_hover: mix(self.surface_hover, other.surface_hover),
+            muted: mix(self.muted, other.muted),
+            transparent: mix(self.transparent, other.transparent),
+            overlay_bg: mix(self.overlay_bg, other.overlay_bg),
+            text_primary: mix(self.text_primary, other.text_primary),
+            text_secondary: mix(self.text_secondary, other.text_secondary),
+            text_muted: mix(self.text_muted, other.text_muted),
+            accent: mix(self.accent, other.accent),
+            accent_hover: mix(self.accent_hover, other.accent_hover),
+            accent_muted: mix(self.accent_muted, other.accent_muted),
+            success: mix(self.success, other.success),
+            warning: mix(self.warning, other.warning),
+            error: mix(self.error, other.error),
+            info: mix(self.info, other.info),
+            border: mix(self.border, other.border),
+            border_hover: mix(self.border_hover, other.border_hover),
+            badge_primary_bg: mix(self.badge_primary_bg, other.badge_primary_bg),
+            badge_primary_text: mix(self.badge_primary_text, other.badge_primary_text),
+            badge_success_bg: mix(self.badge_success_bg, other.badge_success_bg),
+            badge_success_text: mix(self.badge_success_text, other.badge_success_text),
+            badge_warning_bg: mix(self.badge_warning_bg, other.badge_warning_bg),
+            badge_warning_text: mix(self.badge_warning_text, other.badge_warning_text),
+            badge_error_bg: mix(self.badge_error_bg, other.badge_error_bg),
+            badge_error_text: mix(self.badge_error_text, other.badge_error_text),
+            badge_info_bg: mix(self.badge_info_bg, other.badge_info_bg),
+            badge_info_text: mix(self.badge_info_text, other.badge_info_text),
+            elevation: self.elevation.interpolate(&other.elevation, t),
+            focus_ring: mix(self.focus_ring, other.focus_ring),
+            focus_ring_width: lerp(self.focus_ring_width, other.focus_ring_width, t),
+        }
+    }
+
     // =========================================================================
     // Color Token Accessors
     // =========================================================================
@@ -443,22 +515,165 @@ impl Theme {
             },
             borders: BorderColors {
                 default: self.border_token(),
-                focus: self.accent_token(),
+                focus: ColorToken::from_base(self.focus_ring),
                 error: self.error_token(),
             },
         }
     }
 }
 
+/// Depth of a surface above the page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Elevation {
+    /// No shadow
+    #[default]
+    Flat,
+    /// Barely lifted, e.g. a selected control
+    Low,
+    /// Resting on the page: cards, nodes
+    Raised,
+    /// Floating over the content: menus, dropdowns, popovers, tooltips, toasts
+    Overlay,
+    /// Blocking the content: dialogs
+    Modal,
+}
+
+/// One shadow of an [`ElevationTokens`] set
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowPreset {
+    /// Vertical offset in pixels
+    pub offset_y: f32,
+    /// Blur radius in pixels
+    pub blur: f32,
+    /// Spread radius in pixels (negative tucks the shadow under the surface)
+    pub spread: f32,
+    /// Shadow color, usually translucent
+    pub color: Rgba,
+}
+
+impl ShadowPreset {
+    /// The preset as a box shadow
+    pub fn to_box_shadow(&self) -> BoxShadow {
+        BoxShadow {
+            offset: point(px(0.0), px(self.offset_y)),
+            blur_radius: px(self.blur),
+            spread_radius: px(self.spread),
+            color: Hsla::from(self.color),
+        }
+    }
+
+    fn interpolate(&self, other: &ShadowPreset, t: f32) -> Self {
+        Self {
+            offset_y: lerp(self.offset_y, other.offset_y, t),
+            blur: lerp(self.blur, other.blur, t),
+            spread: lerp(self.spread, other.spread, t),
+            color: interpolate_color(self.color, other.color, Easing::Linear, t),
+        }
+    }
+}
+
+/// Shadow presets of a theme, one per [`Elevation`] above flat
+///
+/// ```ignore
+/// let theme = cx.theme();
+/// div().shadow(theme.elevation.shadow(Elevation::Overlay))
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElevationTokens {
+    /// Shadow of [`Elevation::Low`]
+    pub low: ShadowPreset,
+    /// Shadow of [`Elevation::Raised`]
+    pub raised: ShadowPreset,
+    /// Shadow of [`Elevation::Overlay`]
+    pub overlay: ShadowPreset,
+    /// Shadow of [`Elevation::Modal`]
+    pub modal: ShadowPreset,
+}
+
+impl ElevationTokens {
+    /// Presets casting shadows of `color`; dark themes need a more opaque
+    /// color than light ones for the same depth
+    pub fn new(color: Rgba) -> Self {
+        let preset = |offset_y, blur, spread| ShadowPreset {
+            offset_y,
+            blur,
+            spread,
+            color,
+        };
+        Self {
+            low: preset(1.0, 2.0, 0.0),
+            raised: preset(4.0, 6.0, -1.0),
+            overlay: preset(10.0, 15.0, -3.0),
+            modal: preset(20.0, 25.0, -5.0),
+        }
+    }
+
+    /// Preset of `elevation`, `None` when flat
+    pub fn preset(&self, elevation: Elevation) -> Option<ShadowPreset> {
+        match elevation {
+            Elevation::Flat => None,
+            Elevation::Low => Some(self.low),
+            Elevation::Raised => Some(self.raised),
+            Elevation::Overlay => Some(self.overlay),
+            Elevation::Modal => Some(self.modal),
+        }
+    }
+
+    /// Box shadows of `elevation`, for [`Styled::shadow`]
+    pub fn shadow(&self, elevation: Elevation) -> Vec<BoxShadow> {
+        self.preset(elevation)
+            .map(|preset| preset.to_box_shadow())
+            .into_iter()
+            .collect()
+    }
+
+    fn interpolate(&self, other: &ElevationTokens, t: f32) -> Self {
+        Self {
+            low: self.low.interpolate(&other.low, t),
+            raised: self.raised.interpolate(&other.raised, t),
+            overlay: self.overlay.interpolate(&other.overlay, t),
+            modal: self.modal.interpolate(&other.modal, t),
+        }
+    }
+}
+
+impl Default for ElevationTokens {
+    fn default() -> Self {
+        Self::new(rgba(0x00000066))
+    }
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
 impl Default for Theme {
     fn default() -> Self {
         Self::dark()
     }
 }
 
+/// An in-flight theme change
+#[derive(Debug, Clone)]
+struct ThemeTransition {
+    from: Theme,
+    started: Instant,
+}
+
 /// Global state for theme management
+///
+/// Theme changes made through [`ThemeState::set_theme`] and
+/// [`ThemeState::set_variant`] fade from the previous colors to the new ones
+/// over [`ThemeState::animation`]. Components read the blended theme through
+/// [`ThemeExt::theme`], so every themed component transitions together; call
+/// [`animate_theme_transition`] after the change to repaint until it ends.
 pub struct ThemeState {
+    /// Target theme (the blended one is [`ThemeState::current`])
     pub theme: Theme,
+    /// Timing of theme transitions (zero duration switches instantly)
+    pub animation: Animation,
+    transition: Option<ThemeTransition>,
+    ticking: bool,
 }
 
 impl Global for ThemeState {}
@@ -466,27 +681,139 @@ impl Global for ThemeState {}
 impl ThemeState {
     /// Create new theme state with default (dark) theme
     pub fn new() -> Self {
-        Self {
-            theme: Theme::default(),
-        }
+        Self::with_theme(Theme::default())
     }
 
     /// Create theme state with specific variant
     pub fn with_variant(variant: ThemeVariant) -> Self {
+        Self::with_theme(Theme::for_variant(variant))
+    }
+
+    /// Create theme state with a custom theme
+    pub fn with_theme(theme: Theme) -> Self {
         Self {
-            theme: Theme::for_variant(variant),
+            theme,
+            animation: Animation::default(),
+            transition: None,
+            ticking: false,
         }
     }
 
-    /// Set theme variant
+    /// Set the transition timing
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Set theme variant, fading from the current colors
     pub fn set_variant(&mut self, variant: ThemeVariant) {
-        self.theme = Theme::for_variant(variant);
+        self.set_theme(Theme::for_variant(variant));
+    }
+
+    /// Set a custom theme (e.g. edited in a theme editor), fading from the
+    /// current colors
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.set_theme_at(theme, Instant::now());
+    }
+
+    fn set_theme_at(&mut self, theme: Theme, now: Instant) {
+        // Start from the blended colors so an interrupted transition
+        // continues smoothly instead of jumping
+        let from = self.current_at(now);
+        self.theme = theme;
+        self.transition = (!self.animation.total_duration().is_zero())
+            .then_some(ThemeTransition { from, started: now });
+    }
+
+    /// Set theme variant without a transition
+    pub fn set_variant_immediate(&mut self, variant: ThemeVariant) {
+        self.set_theme_immediate(Theme::for_variant(variant));
+    }
+
+    /// Set a custom theme without a transition
+    pub fn set_theme_immediate(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.transition = None;
     }
 
     /// Toggle between light and dark themes
     pub fn toggle(&mut self) {
         self.set_variant(self.theme.variant.toggle());
     }
+
+    /// Theme to render now: the target theme, or a blend while a transition
+    /// is running
+    pub fn current(&self) -> Theme {
+        self.current_at(Instant::now())
+    }
+
+    fn current_at(&self, now: Instant) -> Theme {
+        match &self.transition {
+            Some(transition) if !self.is_complete_at(now) => {
+                let elapsed = now.duration_since(transition.started);
+                transition
+                    .from
+                    .interpolate(&self.theme, self.animation.progress(elapsed))
+            }
+            _ => self.theme.clone(),
+        }
+    }
+
+    /// Whether a transition is still running
+    pub fn is_animating(&self) -> bool {
+        !self.is_complete_at(Instant::now())
+    }
+
+    fn is_complete_at(&self, now: Instant) -> bool {
+        self.transition.as_ref().is_none_or(|transition| {
+            self.animation
+                .is_complete(now.duration_since(transition.started))
+        })
+    }
+
+    /// Jump to the end of the running transition
+    pub fn finish_transition(&mut self) {
+        self.transition = None;
+    }
+}
+
+/// Frame interval used while a theme transition runs
+const THEME_FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Repaint all windows until the running theme transition completes
+///
+/// Call after changing the theme through [`ThemeState`]. Only one driver runs
+/// at a time; calling this while a transition is already being driven just
+/// refreshes the windows.
+pub fn animate_theme_transition(cx: &mut App) {
+    cx.refresh_windows();
+    let Some(state) = cx.try_global::<ThemeState>() else {
+        return;
+    };
+    if state.ticking || !state.is_animating() {
+        return;
+    }
+    cx.global_mut::<ThemeState>().ticking = true;
+
+    cx.spawn(async move |cx: &mut AsyncApp| {
+        loop {
+            cx.background_executor().timer(THEME_FRAME_INTERVAL).await;
+            let running = cx.update(|cx| {
+                cx.refresh_windows();
+                let state = cx.global_mut::<ThemeState>();
+                let running = state.is_animating();
+                if !running {
+                    state.finish_transition();
+                    state.ticking = false;
+                }
+                running
+            });
+            if !matches!(running, Ok(true)) {
+                break;
+            }
+        }
+    })
+    .detach();
 }
 
 impl Default for ThemeState {
@@ -497,14 +824,14 @@ impl Default for ThemeState {
 
 /// Extension trait for easy theme access
 pub trait ThemeExt {
-    /// Get the current theme
+    /// Get the current theme, blended while a theme transition runs
     fn theme(&self) -> Theme;
 }
 
 impl ThemeExt for App {
     fn theme(&self) -> Theme {
         self.try_global::<ThemeState>()
-            .map(|s| s.theme.clone())
+            .map(ThemeState::current)
             .unwrap_or_else(Theme::dark)
     }
 }
@@ -532,3 +859,64 @@ pub fn glow_shadow(color: Rgba) -> Vec<BoxShadow> {
         },
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_interpolate() {
+        let dark = Theme::dark();
+        let light = Theme::light();
+        assert_eq!(dark.interpolate(&light, 0.0).background, dark.background);
+        assert_eq!(dark.interpolate(&light, 1.0).background, light.background);
+
+        let mid = dark.interpolate(&light, 0.5);
+        assert_eq!(mid.variant, ThemeVariant::Light);
+        let expected = (dark.background.r + light.background.r) / 2.0;
+        assert!((mid.background.r - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_elevation_shadows() {
+        let tokens = ElevationTokens::new(rgba(0x00000066));
+        assert!(tokens.shadow(Elevation::Flat).is_empty());
+        let modal = tokens.shadow(Elevation::Modal);
+        assert_eq!(modal.len(), 1);
+        assert_eq!(modal[0].blur_radius, px(25.0));
+
+        // Focus ring and shadows blend with the rest of the theme
+        let dark = Theme::dark();
+        let bw = Theme::black_and_white();
+        let mid = dark.interpolate(&bw, 0.5);
+        assert!((mid.focus_ring_width - 2.5).abs() < 1e-6);
+        assert_ne!(mid.elevation.raised.color, dark.elevation.raised.color);
+    }
+
+    #[test]
+    fn test_theme_state_transition() {
+        let mut state = ThemeState::with_variant(ThemeVariant::Dark);
+        let start = Instant::now();
+        state.set_theme_at(Theme::light(), start);
+
+        let halfway = state.current_at(start + Duration::from_millis(100));
+        assert_ne!(halfway.background, Theme::dark().background);
+        assert_ne!(halfway.background, Theme::light().background);
+
+        let done = start + state.animation.total_duration();
+        assert!(state.is_complete_at(done));
+        assert_eq!(state.current_at(done).background, Theme::light().background);
+
+        state.set_variant_immediate(ThemeVariant::Dark);
+        assert!(!state.is_animating());
+        assert_eq!(state.current().background, Theme::dark().background);
+    }
+
+    #[test]
+    fn test_theme_state_instant_when_duration_zero() {
+        let mut state = ThemeState::new().animation(Animation::new().duration_ms(0));
+        state.set_variant(ThemeVariant::Light);
+        assert!(!state.is_animating());
+        assert_eq!(state.current().background, Theme::light().background);
+    }
+}
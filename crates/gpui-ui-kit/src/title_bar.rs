@@ -0,0 +1,389 @@
+//! Custom title bar for frameless windows
+//!
+//! Provides a title bar drawn by the app instead of the OS:
+//! - App icon and title
+//! - Draggable region (double-click to zoom/maximize)
+//! - Window controls on the platform's side: native traffic lights on
+//!   macOS (space is reserved for them), minimize/maximize/close buttons on
+//!   Windows and Linux
+//! - Slots for embedded controls (tabs, search field, toolbar buttons)
+//!
+//! The window must be opened without OS decorations; see
+//! [`title_bar_window_options`] or enable `MiniAppConfig::custom_titlebar`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! TitleBar::new("titlebar")
+//!     .title("Spinorama")
+//!     .icon(div().child("🔊"))
+//!     .center(search_field)
+//!     .trailing(settings_button)
+//! ```
+
+use crate::ComponentTheme;
+use crate::theme::ThemeExt;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Default title bar height
+pub const TITLE_BAR_HEIGHT: f32 = 34.0;
+
+/// Width reserved for the macOS traffic lights
+const TRAFFIC_LIGHTS_WIDTH: f32 = 72.0;
+
+/// Platform conventions for window controls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TitleBarPlatform {
+    /// Native traffic lights on the left
+    MacOS,
+    /// Minimize/maximize/close on the right
+    Windows,
+    /// Minimize/maximize/close on the right
+    Linux,
+}
+
+impl TitleBarPlatform {
+    /// Platform the app is compiled for
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            TitleBarPlatform::MacOS
+        } else if cfg!(target_os = "windows") {
+            TitleBarPlatform::Windows
+        } else {
+            TitleBarPlatform::Linux
+        }
+    }
+
+    /// Side of the title bar holding the window controls
+    pub fn controls_side(self) -> WindowControlsSide {
+        match self {
+            TitleBarPlatform::MacOS => WindowControlsSide::Left,
+            TitleBarPlatform::Windows | TitleBarPlatform::Linux => WindowControlsSide::Right,
+        }
+    }
+
+    /// Whether the OS draws the window controls (the title bar only reserves
+    /// space for them)
+    pub fn native_controls(self) -> bool {
+        self == TitleBarPlatform::MacOS
+    }
+}
+
+/// Side of the title bar holding the window controls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowControlsSide {
+    /// Controls on the left
+    Left,
+    /// Controls on the right
+    Right,
+}
+
+/// Window options for a window using a [`TitleBar`]
+///
+/// Hides the OS title bar while keeping the macOS traffic lights, and asks
+/// Linux compositors for client-side decorations.
+pub fn title_bar_window_options(title: impl Into<SharedString>) -> WindowOptions {
+    WindowOptions {
+        titlebar: Some(TitlebarOptions {
+            title: Some(title.into()),
+            appears_transparent: true,
+            traffic_light_position: Some(point(px(12.0), px(10.0))),
+        }),
+        window_decorations: Some(WindowDecorations::Client),
+        ..Default::default()
+    }
+}
+
+/// Theme colors for the title bar
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct TitleBarTheme {
+    /// Bar background
+    #[theme(default = 0x252526ff, from = surface)]
+    pub background: Rgba,
+    /// Bottom border
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub border: Rgba,
+    /// Title text
+    #[theme(default = 0xccccccff, from = text_primary)]
+    pub title: Rgba,
+    /// Window button glyphs
+    #[theme(default = 0x999999ff, from = text_secondary)]
+    pub control: Rgba,
+    /// Window button background on hover
+    #[theme(default = 0x3a3a3aff, from = surface_hover)]
+    pub control_hover: Rgba,
+    /// Close button background on hover
+    #[theme(default = 0xe81123ff, from = error)]
+    pub close_hover: Rgba,
+}
+
+/// Title bar for frameless windows
+#[derive(IntoElement)]
+pub struct TitleBar {
+    id: ElementId,
+    title: Option<SharedString>,
+    icon: Option<AnyElement>,
+    leading: Vec<AnyElement>,
+    center: Vec<AnyElement>,
+    trailing: Vec<AnyElement>,
+    height: Pixels,
+    platform: TitleBarPlatform,
+    show_window_controls: bool,
+    theme: Option<TitleBarTheme>,
+}
+
+impl TitleBar {
+    /// Create a title bar for the current platform
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            title: None,
+            icon: None,
+            leading: Vec::new(),
+            center: Vec::new(),
+            trailing: Vec::new(),
+            height: px(TITLE_BAR_HEIGHT),
+            platform: TitleBarPlatform::current(),
+            show_window_controls: true,
+            theme: None,
+        }
+    }
+
+    /// Set the window title
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the app icon shown before the title
+    pub fn icon(mut self, icon: impl IntoElement) -> Self {
+        self.icon = Some(icon.into_any_element());
+        self
+    }
+
+    /// Add a control after the title (e.g. tabs)
+    pub fn leading(mut self, child: impl IntoElement) -> Self {
+        self.leading.push(child.into_any_element());
+        self
+    }
+
+    /// Add a control centered in the bar (e.g. a search field)
+    pub fn center(mut self, child: impl IntoElement) -> Self {
+        self.center.push(child.into_any_element());
+        self
+    }
+
+    /// Add a control before the window buttons (e.g. settings)
+    pub fn trailing(mut self, child: impl IntoElement) -> Self {
+        self.trailing.push(child.into_any_element());
+        self
+    }
+
+    /// Set the bar height
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Override the platform conventions (defaults to the current platform)
+    pub fn platform(mut self, platform: TitleBarPlatform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Show or hide the window buttons (default: shown)
+    pub fn show_window_controls(mut self, show: bool) -> Self {
+        self.show_window_controls = show;
+        self
+    }
+
+    /// Set the title bar theme
+    pub fn theme(mut self, theme: TitleBarTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    fn child_id(&self, suffix: &str) -> ElementId {
+        ElementId::Name(SharedString::from(format!("{}-{}", self.id, suffix)))
+    }
+
+    fn window_button(
+        &self,
+        suffix: &str,
+        glyph: &'static str,
+        area: WindowControlArea,
+        hover: Rgba,
+        theme: &TitleBarTheme,
+        on_click: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Stateful<Div> {
+        div()
+            .id(self.child_id(suffix))
+            .h_full()
+            .w(px(46.0))
+            .flex()
+            .items_center()
+            .justify_center()
+            .text_sm()
+            .text_color(theme.control)
+            .window_control_area(area)
+            .hover(move |s| s.bg(hover))
+            .on_click(move |_, window, cx| on_click(window, cx))
+            .child(glyph)
+    }
+
+    fn window_controls(&self, window: &Window, theme: &TitleBarTheme) -> Div {
+        let maximize_glyph = if window.is_maximized() { "❐" } else { "☐" };
+        div()
+            .flex()
+            .h_full()
+            .child(self.window_button(
+                "minimize",
+                "—",
+                WindowControlArea::Min,
+                theme.control_hover,
+                theme,
+                |window, _| window.minimize_window(),
+            ))
+            .child(self.window_button(
+                "maximize",
+                maximize_glyph,
+                WindowControlArea::Max,
+                theme.control_hover,
+                theme,
+                |window, _| window.zoom_window(),
+            ))
+            .child(self.window_button(
+                "close",
+                "✕",
+                WindowControlArea::Close,
+                theme.close_hover,
+                theme,
+                |window, _| window.remove_window(),
+            ))
+    }
+}
+
+/// Move the window, or zoom it on double-click
+fn drag_window(event: &MouseDownEvent, window: &mut Window, _cx: &mut App) {
+    if event.click_count == 2 {
+        window.titlebar_double_click();
+    } else {
+        window.start_window_move();
+    }
+}
+
+impl RenderOnce for TitleBar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| TitleBarTheme::from(&global_theme));
+
+        let side = self.platform.controls_side();
+        let controls = if !self.show_window_controls {
+            None
+        } else if self.platform.native_controls() {
+            // The OS draws the traffic lights; keep their area free
+            Some(div().w(px(TRAFFIC_LIGHTS_WIDTH)).flex_shrink_0())
+        } else {
+            Some(self.window_controls(window, &theme))
+        };
+
+        let mut title_group = div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .px_3()
+            .min_w_0()
+            .on_mouse_down(MouseButton::Left, drag_window);
+        if let Some(icon) = self.icon {
+            title_group = title_group.child(div().flex().items_center().child(icon));
+        }
+        if let Some(title) = self.title {
+            title_group = title_group.child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(theme.title)
+                    .truncate()
+                    .child(title),
+            );
+        }
+
+        // Empty space in the bar drags the window; embedded controls don't
+        let drag_region = div()
+            .flex_1()
+            .h_full()
+            .flex()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .window_control_area(WindowControlArea::Drag)
+            .on_mouse_down(MouseButton::Left, drag_window)
+            .children(self.center);
+
+        let mut bar = div()
+            .id(self.id)
+            .flex()
+            .flex_shrink_0()
+            .items_center()
+            .w_full()
+            .h(self.height)
+            .bg(theme.background)
+            .border_b_1()
+            .border_color(theme.border);
+
+        if side == WindowControlsSide::Left
+            && let Some(controls) = controls
+        {
+            bar = bar
+                .child(controls)
+                .child(title_group)
+                .children(self.leading)
+                .child(drag_region)
+                .children(self.trailing)
+                .child(div().w_2());
+        } else {
+            bar = bar
+                .child(title_group)
+                .children(self.leading)
+                .child(drag_region)
+                .children(self.trailing)
+                .children(controls);
+        }
+
+        bar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_conventions() {
+        assert_eq!(
+            TitleBarPlatform::MacOS.controls_side(),
+            WindowControlsSide::Left
+        );
+        assert!(TitleBarPlatform::MacOS.native_controls());
+        for platform in [TitleBarPlatform::Windows, TitleBarPlatform::Linux] {
+            assert_eq!(platform.controls_side(), WindowControlsSide::Right);
+            assert!(!platform.native_controls());
+        }
+    }
+
+    #[test]
+    fn test_current_platform() {
+        let expected = if cfg!(target_os = "macos") {
+            TitleBarPlatform::MacOS
+        } else if cfg!(target_os = "windows") {
+            TitleBarPlatform::Windows
+        } else {
+            TitleBarPlatform::Linux
+        };
+        assert_eq!(TitleBarPlatform::current(), expected);
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! Provides non-blocking notifications that appear temporarily.
 
-use crate::theme::{Theme, ThemeExt, ThemeVariant};
+use crate::callback::Callback;
+use crate::theme::{Elevation, Theme, ThemeExt, ThemeVariant};
 use gpui::prelude::*;
 use gpui::{Component, *};
+use std::time::Duration;
 
 /// Toast visual variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -72,13 +74,14 @@ pub enum ToastPosition {
 }
 
 /// A single toast notification
+#[derive(Clone)]
 pub struct Toast {
     id: ElementId,
     title: Option<SharedString>,
     message: SharedString,
     variant: ToastVariant,
     closeable: bool,
-    on_close: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_close: Option<Callback>,
     /// Duration in seconds before auto-dismiss (None = no auto-dismiss, default = 5.0)
     duration_secs: Option<f32>,
 }
@@ -120,7 +123,7 @@ impl Toast {
 
     /// Set the close handler
     pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_close = Some(Box::new(handler));
+        self.on_close = Some(Callback::from_fn(handler));
         self
     }
 
@@ -136,6 +139,11 @@ impl Toast {
         self
     }
 
+    /// Get the toast ID
+    pub fn id(&self) -> &ElementId {
+        &self.id
+    }
+
     /// Get the duration in seconds (for timer management)
     pub fn get_duration_secs(&self) -> Option<f32> {
         self.duration_secs
@@ -165,7 +173,7 @@ impl Toast {
             .border_1()
             .border_color(border)
             .rounded_lg()
-            .shadow_lg();
+            .shadow(theme.elevation.shadow(Elevation::Overlay));
 
         // Icon
         toast = toast.child(
@@ -203,7 +211,6 @@ impl Toast {
             let text_muted = theme.text_muted;
             let text_primary = theme.text_primary;
             if let Some(handler) = self.on_close {
-                let handler_ptr: *const dyn Fn(&mut Window, &mut App) = handler.as_ref();
                 toast = toast.child(
                     div()
                         .id((close_btn_id, "close"))
@@ -211,12 +218,11 @@ impl Toast {
                         .text_color(text_muted)
                         .cursor_pointer()
                         .hover(move |s| s.text_color(text_primary))
-                        .on_mouse_up(MouseButton::Left, move |_event, window, cx| unsafe {
-                            (*handler_ptr)(window, cx);
+                        .on_mouse_up(MouseButton::Left, move |_event, window, cx| {
+                            handler.invoke(window, cx);
                         })
                         .child("x"),
                 );
-                std::mem::forget(handler);
             }
         }
 
@@ -306,3 +312,99 @@ impl RenderOnce for ToastContainer {
         self.build()
     }
 }
+
+/// Events emitted by [`ToastContainerView`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToastEvent {
+    /// A toast was closed by the user or timed out
+    Dismissed { id: ElementId },
+}
+
+/// A [`ToastContainer`] that owns its toasts and dismisses them itself
+///
+/// Pushed toasts are removed when their close button is clicked or their
+/// duration elapses, replacing the per-app timer bookkeeping.
+///
+/// ```ignore
+/// let toasts = cx.new(|_| ToastContainerView::new(ToastPosition::BottomRight));
+/// toasts.update(cx, |toasts, cx| {
+///     toasts.push(Toast::new("saved", "Settings saved").variant(ToastVariant::Success), cx);
+/// });
+/// ```
+pub struct ToastContainerView {
+    position: ToastPosition,
+    toasts: Vec<Toast>,
+}
+
+impl EventEmitter<ToastEvent> for ToastContainerView {}
+
+impl ToastContainerView {
+    /// Create an empty toast container view
+    pub fn new(position: ToastPosition) -> Self {
+        Self {
+            position,
+            toasts: Vec::new(),
+        }
+    }
+
+    /// Show a toast, replacing a shown toast with the same ID
+    pub fn push(&mut self, toast: Toast, cx: &mut Context<Self>) {
+        if let Some(ms) = toast.get_duration_ms() {
+            let id = toast.id.clone();
+            cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                cx.background_executor()
+                    .timer(Duration::from_millis(ms))
+                    .await;
+                this.update(cx, |this, cx| this.dismiss(&id, cx)).ok();
+            })
+            .detach();
+        }
+        self.toasts.retain(|shown| shown.id != toast.id);
+        self.toasts.push(toast);
+        cx.notify();
+    }
+
+    /// Remove a toast, emitting [`ToastEvent::Dismissed`] if it was shown
+    pub fn dismiss(&mut self, id: &ElementId, cx: &mut Context<Self>) {
+        let count = self.toasts.len();
+        self.toasts.retain(|toast| &toast.id != id);
+        if self.toasts.len() != count {
+            cx.emit(ToastEvent::Dismissed { id: id.clone() });
+            cx.notify();
+        }
+    }
+
+    /// Remove all toasts without emitting events
+    pub fn clear(&mut self, cx: &mut Context<Self>) {
+        self.toasts.clear();
+        cx.notify();
+    }
+
+    /// Number of toasts shown
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Whether no toast is shown
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+impl Render for ToastContainerView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let view = cx.entity().downgrade();
+        let toasts = self.toasts.iter().cloned().map(|mut toast| {
+            let id = toast.id.clone();
+            let on_close = toast.on_close.take();
+            let view = view.clone();
+            toast.on_close(move |window, cx| {
+                if let Some(handler) = &on_close {
+                    handler.invoke(window, cx);
+                }
+                view.update(cx, |this, cx| this.dismiss(&id, cx)).ok();
+            })
+        });
+        ToastContainer::new(self.position).toasts(toasts).build()
+    }
+}
@@ -10,6 +10,8 @@
 //! - Two visual styles: Sliding (iOS-style) and Segmented ([OFF|ON])
 
 use crate::ComponentTheme;
+use crate::app::recorder::record_value;
+use crate::callback::Callback;
 use crate::theme::ThemeExt;
 use gpui::prelude::*;
 use gpui::*;
@@ -17,6 +19,8 @@ use gpui::*;
 /// Toggle size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ToggleSize {
+    /// Extra small
+    Xs,
     /// Small
     Sm,
     /// Medium (default)
@@ -24,51 +28,47 @@ pub enum ToggleSize {
     Md,
     /// Large
     Lg,
+    /// Extra large
+    Xl,
 }
 
 impl ToggleSize {
     fn track_width(&self) -> Pixels {
         match self {
+            ToggleSize::Xs => px(26.0),
             ToggleSize::Sm => px(32.0),
             ToggleSize::Md => px(40.0),
             ToggleSize::Lg => px(52.0),
+            ToggleSize::Xl => px(64.0),
         }
     }
 
     fn track_height(&self) -> Pixels {
         match self {
+            ToggleSize::Xs => px(14.0),
             ToggleSize::Sm => px(18.0),
             ToggleSize::Md => px(22.0),
             ToggleSize::Lg => px(28.0),
+            ToggleSize::Xl => px(34.0),
         }
     }
 
     fn knob_size(&self) -> Pixels {
         match self {
+            ToggleSize::Xs => px(10.0),
             ToggleSize::Sm => px(14.0),
             ToggleSize::Md => px(18.0),
             ToggleSize::Lg => px(24.0),
+            ToggleSize::Xl => px(30.0),
         }
     }
 
     fn knob_offset(&self) -> Pixels {
-        match self {
-            ToggleSize::Sm => px(2.0),
-            ToggleSize::Md => px(2.0),
-            ToggleSize::Lg => px(2.0),
-        }
+        px(2.0)
     }
 }
 
-impl From<crate::ComponentSize> for ToggleSize {
-    fn from(size: crate::ComponentSize) -> Self {
-        match size {
-            crate::ComponentSize::Xs | crate::ComponentSize::Sm => Self::Sm,
-            crate::ComponentSize::Md => Self::Md,
-            crate::ComponentSize::Lg | crate::ComponentSize::Xl => Self::Lg,
-        }
-    }
-}
+crate::size::component_size_conversions!(ToggleSize);
 
 /// Toggle visual style
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -140,7 +140,7 @@ pub struct Toggle {
     disabled: bool,
     selected: bool,
     theme: Option<ToggleTheme>,
-    on_change: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Callback<bool>>,
 }
 
 impl Toggle {
@@ -203,7 +203,7 @@ impl Toggle {
 
     /// Set change handler
     pub fn on_change(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.on_change = Some(Callback::new(handler));
         self
     }
 
@@ -239,10 +239,11 @@ impl Toggle {
         };
 
         let mut container = div()
-            .id(self.id)
+            .id(self.id.clone())
             .flex()
             .items_center()
             .gap_2()
+            .min_h(crate::ComponentSize::from(self.size).control_height())
             .cursor_pointer();
 
         // Apply selection styling
@@ -289,11 +290,7 @@ impl Toggle {
         if let Some(label) = &self.label
             && selected
         {
-            let label_el = match self.size {
-                ToggleSize::Sm => div().text_xs(),
-                ToggleSize::Md => div().text_sm(),
-                ToggleSize::Lg => div(),
-            };
+            let label_el = crate::ComponentSize::from(self.size).apply_text_size(div());
             container = container.child(
                 label_el
                     .text_color(theme.label)
@@ -308,11 +305,7 @@ impl Toggle {
         if let Some(label) = &self.label
             && !selected
         {
-            let label_el = match self.size {
-                ToggleSize::Sm => div().text_xs(),
-                ToggleSize::Md => div().text_sm(),
-                ToggleSize::Lg => div(),
-            };
+            let label_el = crate::ComponentSize::from(self.size).apply_text_size(div());
             container = container.child(label_el.text_color(theme.label).child(label.clone()));
         }
 
@@ -320,13 +313,17 @@ impl Toggle {
         if !self.disabled
             && let Some(handler) = self.on_change
         {
-            let handler_rc = std::rc::Rc::new(handler);
+            let id = self.id.clone();
+            let handler_rc = Callback::new(move |checked: bool, window, cx| {
+                record_value(cx, &id, checked);
+                handler.call(checked, window, cx);
+            });
             let new_checked = !checked;
 
             // Click handler
             let handler_click = handler_rc.clone();
             container = container.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                handler_click(new_checked, window, cx);
+                handler_click.call(new_checked, window, cx);
             });
 
             // Keyboard handler (Space key when selected)
@@ -334,7 +331,7 @@ impl Toggle {
                 let handler_key = handler_rc.clone();
                 container = container.on_key_down(move |event, window, cx| {
                     if event.keystroke.key == "space" {
-                        handler_key(new_checked, window, cx);
+                        handler_key.call(new_checked, window, cx);
                     }
                 });
             }
@@ -348,7 +345,7 @@ impl Toggle {
         let selected = self.selected;
 
         let mut container = div()
-            .id(self.id)
+            .id(self.id.clone())
             .flex()
             .items_center()
             .justify_between()
@@ -446,13 +443,17 @@ impl Toggle {
         if !self.disabled
             && let Some(handler) = self.on_change
         {
-            let handler_rc = std::rc::Rc::new(handler);
+            let id = self.id.clone();
+            let handler_rc = Callback::new(move |checked: bool, window, cx| {
+                record_value(cx, &id, checked);
+                handler.call(checked, window, cx);
+            });
             let new_checked = !checked;
 
             // Click handler
             let handler_click = handler_rc.clone();
             container = container.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                handler_click(new_checked, window, cx);
+                handler_click.call(new_checked, window, cx);
             });
 
             // Keyboard handler (Space key when selected)
@@ -460,7 +461,7 @@ impl Toggle {
                 let handler_key = handler_rc.clone();
                 container = container.on_key_down(move |event, window, cx| {
                     if event.keystroke.key == "space" {
-                        handler_key(new_checked, window, cx);
+                        handler_key.call(new_checked, window, cx);
                     }
                 });
             }
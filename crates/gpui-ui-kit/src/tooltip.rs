@@ -2,7 +2,7 @@
 //!
 //! Contextual information displayed on hover.
 
-use crate::theme::{Theme, ThemeExt};
+use crate::theme::{Elevation, Theme, ThemeExt};
 use gpui::prelude::*;
 use gpui::*;
 
@@ -60,7 +60,7 @@ impl Tooltip {
             .border_1()
             .border_color(theme.border)
             .rounded(px(4.0))
-            .shadow_lg()
+            .shadow(theme.elevation.shadow(Elevation::Overlay))
             .text_xs()
             .text_color(theme.text_primary)
             .whitespace_nowrap();
@@ -0,0 +1,675 @@
+//! Onboarding tours (coach marks)
+//!
+//! Step-by-step product tours that highlight parts of the UI:
+//!
+//! 1. Mark the elements to highlight with [`tour_anchor`], a zero-size child
+//!    of a `relative()` element that records its bounds every frame
+//! 2. Describe the tour as a [`Tour`] of [`TourStep`]s referencing the
+//!    anchor ids
+//! 3. Render a [`TourOverlay`] as the last child of the window root; it dims
+//!    the window except for a spotlight around the current anchor and shows
+//!    the step text with Back/Next/Skip buttons
+//! 4. Persist finished tours with [`TourProgress`] so they only run once
+//!
+//! # Example
+//!
+//! ```ignore
+//! // In the toolbar
+//! div().relative().child(save_button).child(tour_anchor("save"))
+//!
+//! // Tour model, owned by the app
+//! let tour = Tour::new("welcome")
+//!     .step(TourStep::new("save", "Save your work").body("Saves the current project."))
+//!     .step(TourStep::new("eq", "Tune the EQ").placement(TooltipPlacement::Right));
+//!
+//! // Root view
+//! div()
+//!     .relative()
+//!     .size_full()
+//!     .child(content)
+//!     .child(
+//!         TourOverlay::new("welcome-tour", &self.tour)
+//!             .on_next(cx.listener(|this, _, _, cx| { this.tour.next(); cx.notify(); }))
+//!             .on_back(cx.listener(|this, _, _, cx| { this.tour.back(); cx.notify(); }))
+//!             .on_skip(cx.listener(|this, _, _, cx| { this.tour.skip(); cx.notify(); })),
+//!     )
+//! ```
+
+use crate::ComponentTheme;
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::callback::Callback;
+use crate::theme::{Elevation, ElevationTokens, ThemeExt};
+use crate::tooltip::TooltipPlacement;
+use gpui::prelude::*;
+use gpui::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/// Gap between the spotlight and the step card
+const CARD_GAP: f32 = 12.0;
+/// Width of the step card
+const CARD_WIDTH: f32 = 320.0;
+/// Padding of the spotlight around the anchor
+const SPOTLIGHT_PADDING: f32 = 6.0;
+
+// ============================================================================
+// Anchors
+// ============================================================================
+
+/// Global registry of anchor bounds, filled by [`tour_anchor`] markers
+#[derive(Debug, Default)]
+pub struct TourAnchors {
+    bounds: HashMap<SharedString, Bounds<Pixels>>,
+}
+
+impl Global for TourAnchors {}
+
+impl TourAnchors {
+    /// Last recorded window bounds of an anchor
+    pub fn get(id: &str, cx: &App) -> Option<Bounds<Pixels>> {
+        cx.try_global::<TourAnchors>()
+            .and_then(|anchors| anchors.bounds.get(id).copied())
+    }
+
+    /// Forget an anchor (e.g. when its element is removed)
+    pub fn remove(id: &str, cx: &mut App) {
+        if let Some(anchors) = cx.try_global_mut::<TourAnchors>() {
+            anchors.bounds.remove(id);
+        }
+    }
+}
+
+/// Invisible child recording its parent's bounds under `id`
+///
+/// The parent must be `relative()` so the marker covers it.
+pub fn tour_anchor(id: impl Into<SharedString>) -> impl IntoElement {
+    let id = id.into();
+    canvas(
+        move |bounds, window, cx| {
+            let anchors = cx.default_global::<TourAnchors>();
+            if anchors.bounds.get(&id) != Some(&bounds) {
+                anchors.bounds.insert(id, bounds);
+                // The overlay may have used stale bounds this frame
+                window.refresh();
+            }
+        },
+        |_, _, _, _| {},
+    )
+    .absolute()
+    .inset_0()
+}
+
+// ============================================================================
+// Tour model
+// ============================================================================
+
+/// One step of a tour
+#[derive(Debug, Clone)]
+pub struct TourStep {
+    /// Anchor id to highlight
+    pub anchor: SharedString,
+    /// Step title
+    pub title: SharedString,
+    /// Step description
+    pub body: Option<SharedString>,
+    /// Side of the anchor where the card is shown
+    pub placement: TooltipPlacement,
+}
+
+impl TourStep {
+    /// Create a step highlighting `anchor`
+    pub fn new(anchor: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            anchor: anchor.into(),
+            title: title.into(),
+            body: None,
+            placement: TooltipPlacement::Bottom,
+        }
+    }
+
+    /// Set the step description
+    pub fn body(mut self, body: impl Into<SharedString>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set where the card is shown relative to the anchor (default: below)
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+/// How a tour ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourOutcome {
+    /// The user went through every step
+    Completed,
+    /// The user skipped the tour
+    Skipped,
+}
+
+/// A product tour and its progress
+#[derive(Debug, Clone)]
+pub struct Tour {
+    id: SharedString,
+    steps: Vec<TourStep>,
+    current: usize,
+    running: bool,
+    outcome: Option<TourOutcome>,
+}
+
+impl Tour {
+    /// Create a running tour identified by `id` (used for persistence)
+    pub fn new(id: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            steps: Vec::new(),
+            current: 0,
+            running: true,
+            outcome: None,
+        }
+    }
+
+    /// Add a step
+    pub fn step(mut self, step: TourStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Tour identifier
+    pub fn id(&self) -> &SharedString {
+        &self.id
+    }
+
+    /// All steps
+    pub fn steps(&self) -> &[TourStep] {
+        &self.steps
+    }
+
+    /// Index of the current step
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Current step, if the tour is running
+    pub fn current_step(&self) -> Option<&TourStep> {
+        self.running.then(|| self.steps.get(self.current)).flatten()
+    }
+
+    /// Whether the tour is showing a step
+    pub fn is_running(&self) -> bool {
+        self.current_step().is_some()
+    }
+
+    /// Whether the current step is the last one
+    pub fn is_last_step(&self) -> bool {
+        self.current + 1 >= self.steps.len()
+    }
+
+    /// How the tour ended, if it did
+    pub fn outcome(&self) -> Option<TourOutcome> {
+        self.outcome
+    }
+
+    /// Restart from the first step
+    pub fn restart(&mut self) {
+        self.current = 0;
+        self.running = true;
+        self.outcome = None;
+    }
+
+    /// Go to the next step, completing the tour after the last one
+    pub fn next(&mut self) {
+        if !self.is_running() {
+            return;
+        }
+        if self.is_last_step() {
+            self.finish(TourOutcome::Completed);
+        } else {
+            self.current += 1;
+        }
+    }
+
+    /// Go back one step
+    pub fn back(&mut self) {
+        if self.is_running() {
+            self.current = self.current.saturating_sub(1);
+        }
+    }
+
+    /// Stop the tour early
+    pub fn skip(&mut self) {
+        if self.is_running() {
+            self.finish(TourOutcome::Skipped);
+        }
+    }
+
+    fn finish(&mut self, outcome: TourOutcome) {
+        self.running = false;
+        self.outcome = Some(outcome);
+    }
+}
+
+// ============================================================================
+// Persistence
+// ============================================================================
+
+/// Set of finished tours, saved as JSON
+///
+/// Skipped tours count as finished so they are not shown again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TourProgress {
+    completed: BTreeSet<String>,
+}
+
+impl TourProgress {
+    /// Create an empty progress record
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the tour `id` was finished
+    pub fn is_completed(&self, id: &str) -> bool {
+        self.completed.contains(id)
+    }
+
+    /// Record the tour `id` as finished
+    pub fn mark_completed(&mut self, id: impl Into<String>) {
+        self.completed.insert(id.into());
+    }
+
+    /// Record `tour` as finished if it ended; returns whether it did
+    pub fn record(&mut self, tour: &Tour) -> bool {
+        let ended = tour.outcome().is_some();
+        if ended {
+            self.mark_completed(tour.id().to_string());
+        }
+        ended
+    }
+
+    /// Forget the tour `id` so it runs again
+    pub fn reset(&mut self, id: &str) {
+        self.completed.remove(id);
+    }
+
+    /// Write the progress to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    /// Read the progress from a JSON file; a missing file is empty progress
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(std::io::Error::other),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+// ============================================================================
+// Overlay
+// ============================================================================
+
+/// Where the step card is drawn, in window coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CardPosition {
+    /// Left edge
+    pub left: Pixels,
+    /// Top edge, for cards below or beside the anchor
+    pub top: Option<Pixels>,
+    /// Distance from the bottom of the window, for cards above the anchor
+    pub bottom: Option<Pixels>,
+}
+
+/// Place a card of `card_width` next to `anchor` inside `viewport`
+///
+/// Left/right placements flip to the other side when the card would leave
+/// the window; the card is kept horizontally inside the window.
+pub fn card_position(
+    anchor: Bounds<Pixels>,
+    viewport: Size<Pixels>,
+    placement: TooltipPlacement,
+    card_width: Pixels,
+) -> CardPosition {
+    let gap = px(CARD_GAP);
+    let max_left = (viewport.width - card_width - gap).max(gap);
+    let centered = (anchor.center().x - card_width / 2.0).clamp(gap, max_left);
+    let right_of = anchor.right() + gap;
+    let left_of = anchor.left() - gap - card_width;
+
+    match placement {
+        TooltipPlacement::Bottom => CardPosition {
+            left: centered,
+            top: Some(anchor.bottom() + gap),
+            bottom: None,
+        },
+        TooltipPlacement::Top => CardPosition {
+            left: centered,
+            top: None,
+            bottom: Some(viewport.height - anchor.top() + gap),
+        },
+        TooltipPlacement::Right | TooltipPlacement::Left => {
+            let fits_right = right_of + card_width <= viewport.width;
+            let fits_left = left_of >= px(0.0);
+            let left = if placement == TooltipPlacement::Right {
+                if fits_right || !fits_left {
+                    right_of
+                } else {
+                    left_of
+                }
+            } else if fits_left || !fits_right {
+                left_of
+            } else {
+                right_of
+            };
+            CardPosition {
+                left: left.clamp(gap, max_left),
+                top: Some(anchor.top()),
+                bottom: None,
+            }
+        }
+    }
+}
+
+/// Theme colors for the tour overlay
+#[derive(Debug, Clone, ComponentTheme)]
+pub struct TourTheme {
+    /// Dimmed backdrop around the spotlight
+    #[theme(default = 0x00000099, from = overlay_bg)]
+    pub backdrop: Rgba,
+    /// Spotlight outline
+    #[theme(default = 0x007accff, from = accent)]
+    pub spotlight: Rgba,
+    /// Card background
+    #[theme(default = 0x2a2a2aff, from = surface)]
+    pub card_bg: Rgba,
+    /// Card border
+    #[theme(default = 0x3a3a3aff, from = border)]
+    pub card_border: Rgba,
+    /// Step title
+    #[theme(default = 0xffffffff, from = text_primary)]
+    pub title: Rgba,
+    /// Step body
+    #[theme(default = 0xccccccff, from = text_secondary)]
+    pub body: Rgba,
+    /// Step counter
+    #[theme(default = 0x888888ff, from = text_muted)]
+    pub counter: Rgba,
+    /// Step card shadow
+    #[theme(
+        default_expr = "ElevationTokens::default().shadow(Elevation::Modal)",
+        from_expr = "theme.elevation.shadow(Elevation::Modal)"
+    )]
+    pub card_shadow: Vec<BoxShadow>,
+}
+
+/// Overlay showing the current step of a [`Tour`]
+///
+/// Renders nothing when the tour is not running. The app owns the tour and
+/// advances it from the callbacks.
+#[derive(IntoElement)]
+pub struct TourOverlay {
+    id: ElementId,
+    step: Option<TourStep>,
+    index: usize,
+    count: usize,
+    is_last: bool,
+    theme: Option<TourTheme>,
+    on_next: Option<Callback>,
+    on_back: Option<Callback>,
+    on_skip: Option<Callback>,
+}
+
+impl TourOverlay {
+    /// Create an overlay for the current state of `tour`
+    pub fn new(id: impl Into<ElementId>, tour: &Tour) -> Self {
+        Self {
+            id: id.into(),
+            step: tour.current_step().cloned(),
+            index: tour.current_index(),
+            count: tour.steps().len(),
+            is_last: tour.is_last_step(),
+            theme: None,
+            on_next: None,
+            on_back: None,
+            on_skip: None,
+        }
+    }
+
+    /// Set the overlay theme
+    pub fn theme(mut self, theme: TourTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Called by the Next/Done button
+    pub fn on_next(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_next = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Called by the Back button (hidden on the first step)
+    pub fn on_back(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_back = Some(Callback::from_fn(handler));
+        self
+    }
+
+    /// Called by the Skip button
+    pub fn on_skip(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_skip = Some(Callback::from_fn(handler));
+        self
+    }
+
+    fn child_id(&self, suffix: &str) -> ElementId {
+        ElementId::Name(SharedString::from(format!("{}-{}", self.id, suffix)))
+    }
+
+    fn card(&mut self, step: &TourStep, theme: &TourTheme) -> Div {
+        let mut buttons = div().flex().items_center().gap_2();
+        if let Some(handler) = self.on_skip.take() {
+            buttons = buttons.child(
+                Button::new(self.child_id("skip"), "Skip")
+                    .variant(ButtonVariant::Ghost)
+                    .size(ButtonSize::Sm)
+                    .on_click(move |window, cx| handler.invoke(window, cx)),
+            );
+        }
+        buttons = buttons.child(div().flex_1());
+        if self.index > 0
+            && let Some(handler) = self.on_back.take()
+        {
+            buttons = buttons.child(
+                Button::new(self.child_id("back"), "Back")
+                    .variant(ButtonVariant::Secondary)
+                    .size(ButtonSize::Sm)
+                    .on_click(move |window, cx| handler.invoke(window, cx)),
+            );
+        }
+        if let Some(handler) = self.on_next.take() {
+            let label = if self.is_last { "Done" } else { "Next" };
+            buttons = buttons.child(
+                Button::new(self.child_id("next"), label)
+                    .size(ButtonSize::Sm)
+                    .on_click(move |window, cx| handler.invoke(window, cx)),
+            );
+        }
+
+        div()
+            .w(px(CARD_WIDTH))
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_4()
+            .rounded_lg()
+            .bg(theme.card_bg)
+            .border_1()
+            .border_color(theme.card_border)
+            .shadow(theme.card_shadow.clone())
+            .child(div().text_xs().text_color(theme.counter).child(format!(
+                "{} of {}",
+                self.index + 1,
+                self.count
+            )))
+            .child(
+                div()
+                    .text_base()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.title)
+                    .child(step.title.clone()),
+            )
+            .children(
+                step.body
+                    .clone()
+                    .map(|body| div().text_sm().text_color(theme.body).child(body)),
+            )
+            .child(buttons)
+    }
+}
+
+impl RenderOnce for TourOverlay {
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let Some(step) = self.step.take() else {
+            return div().into_any_element();
+        };
+        let global_theme = cx.theme();
+        let theme = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| TourTheme::from(&global_theme));
+        let viewport = window.viewport_size();
+        let anchor = TourAnchors::get(&step.anchor, cx).map(|b| b.dilate(px(SPOTLIGHT_PADDING)));
+
+        let card = self.card(&step, &theme);
+
+        let mut overlay = div()
+            .id(self.id.clone())
+            .absolute()
+            .inset_0()
+            // Swallow clicks on the backdrop
+            .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation());
+
+        match anchor {
+            Some(spot) => {
+                // Four dim panels around the spotlight leave it uncovered
+                let panel = || div().absolute().bg(theme.backdrop);
+                overlay = overlay
+                    .child(panel().left_0().right_0().top_0().h(spot.top()))
+                    .child(panel().left_0().right_0().bottom_0().top(spot.bottom()))
+                    .child(
+                        panel()
+                            .left_0()
+                            .w(spot.left())
+                            .top(spot.top())
+                            .h(spot.size.height),
+                    )
+                    .child(
+                        panel()
+                            .right_0()
+                            .left(spot.right())
+                            .top(spot.top())
+                            .h(spot.size.height),
+                    )
+                    .child(
+                        div()
+                            .absolute()
+                            .left(spot.left())
+                            .top(spot.top())
+                            .w(spot.size.width)
+                            .h(spot.size.height)
+                            .rounded_md()
+                            .border_2()
+                            .border_color(theme.spotlight),
+                    );
+
+                let position = card_position(spot, viewport, step.placement, px(CARD_WIDTH));
+                let mut holder = div().absolute().left(position.left);
+                if let Some(top) = position.top {
+                    holder = holder.top(top);
+                }
+                if let Some(bottom) = position.bottom {
+                    holder = holder.bottom(bottom);
+                }
+                overlay.child(holder.child(card)).into_any_element()
+            }
+            // Anchor not rendered (yet): center the card over a full backdrop
+            None => overlay
+                .bg(theme.backdrop)
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(card)
+                .into_any_element(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tour() -> Tour {
+        Tour::new("welcome")
+            .step(TourStep::new("a", "First"))
+            .step(TourStep::new("b", "Second").body("More"))
+    }
+
+    #[test]
+    fn test_tour_navigation() {
+        let mut tour = tour();
+        assert_eq!(tour.current_step().unwrap().anchor.as_ref(), "a");
+        tour.back();
+        assert_eq!(tour.current_index(), 0);
+        tour.next();
+        assert!(tour.is_last_step());
+        tour.back();
+        tour.next();
+        tour.next();
+        assert!(!tour.is_running());
+        assert_eq!(tour.outcome(), Some(TourOutcome::Completed));
+
+        tour.restart();
+        tour.skip();
+        assert_eq!(tour.outcome(), Some(TourOutcome::Skipped));
+        assert!(Tour::new("empty").current_step().is_none());
+    }
+
+    #[test]
+    fn test_progress_roundtrip() {
+        let mut progress = TourProgress::new();
+        let mut tour = tour();
+        assert!(!progress.record(&tour));
+        tour.skip();
+        assert!(progress.record(&tour));
+        assert!(progress.is_completed("welcome"));
+
+        let path = std::env::temp_dir().join(format!("tour-progress-{}.json", std::process::id()));
+        progress.save(&path).unwrap();
+        assert_eq!(TourProgress::load(&path).unwrap(), progress);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(TourProgress::load(&path).unwrap(), TourProgress::new());
+    }
+
+    #[test]
+    fn test_card_position() {
+        let viewport = size(px(800.0), px(600.0));
+        let anchor = Bounds::new(point(px(700.0), px(100.0)), size(px(80.0), px(30.0)));
+        let width = px(CARD_WIDTH);
+
+        let below = card_position(anchor, viewport, TooltipPlacement::Bottom, width);
+        assert_eq!(below.top, Some(px(142.0)));
+        // Kept inside the window on the right
+        assert_eq!(below.left, px(800.0 - CARD_WIDTH - CARD_GAP));
+
+        let above = card_position(anchor, viewport, TooltipPlacement::Top, width);
+        assert_eq!(above.bottom, Some(px(512.0)));
+
+        // No room on the right: flips to the left of the anchor
+        let right = card_position(anchor, viewport, TooltipPlacement::Right, width);
+        assert_eq!(right.left, px(700.0 - CARD_GAP - CARD_WIDTH));
+        assert_eq!(right.top, Some(px(100.0)));
+    }
+}
@@ -0,0 +1,126 @@
+//! Debug-build validation of component builder state
+//!
+//! Builders accept inconsistent state (a selected value missing from the
+//! options, step statuses not matching the steps, `min > max`, ...) and
+//! would otherwise render it silently wrong. In debug builds components
+//! check their state when rendered: each problem is logged to stderr once
+//! and the component is outlined with a warning marker. In release builds
+//! the checks compile to nothing.
+//!
+//! ```ignore
+//! let mut warnings = BuilderWarnings::new("Select", &self.id);
+//! warnings.check(selected_exists, || format!("selected value {selected:?} is not an option"));
+//! container = container.relative().children(warnings.marker());
+//! ```
+
+use gpui::prelude::*;
+use gpui::*;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// Marker and log color
+const WARNING_COLOR: u32 = 0xff3b30;
+
+// Messages already logged, so a misconfigured component re-rendered every
+// frame logs once
+thread_local! {
+    static LOGGED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+/// Problems found in the builder state of one component
+#[derive(Debug, Default)]
+pub(crate) struct BuilderWarnings {
+    component: String,
+    messages: Vec<String>,
+}
+
+impl BuilderWarnings {
+    /// Start validating `component` (type name and id, for messages)
+    pub(crate) fn new(component: &str, id: impl Display) -> Self {
+        Self {
+            component: format!("{component} '{id}'"),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Start validating a component without an id
+    pub(crate) fn unnamed(component: &str) -> Self {
+        Self {
+            component: component.to_string(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Record `message` when `valid` is false (debug builds only)
+    pub(crate) fn check(&mut self, valid: bool, message: impl FnOnce() -> String) {
+        if cfg!(debug_assertions) && !valid {
+            self.messages.push(message());
+        }
+    }
+
+    /// Log new problems and build the warning marker, if there are any.
+    ///
+    /// The marker is absolutely positioned over its parent, which must be
+    /// `relative()`.
+    pub(crate) fn marker(self) -> Option<AnyElement> {
+        if self.messages.is_empty() {
+            return None;
+        }
+
+        LOGGED.with(|logged| {
+            let mut logged = logged.borrow_mut();
+            for message in &self.messages {
+                let line = format!("{}: {}", self.component, message);
+                if logged.insert(line.clone()) {
+                    eprintln!("gpui-ui-kit warning: {line}");
+                }
+            }
+        });
+
+        let summary = format!("⚠ {}", self.messages.join("; "));
+        Some(
+            div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .border_1()
+                .border_color(rgb(WARNING_COLOR))
+                .rounded_md()
+                .child(
+                    div()
+                        .absolute()
+                        .bottom_full()
+                        .left_0()
+                        .px_1()
+                        .rounded_sm()
+                        .bg(rgb(WARNING_COLOR))
+                        .text_color(rgb(0xffffff))
+                        .text_xs()
+                        .whitespace_nowrap()
+                        .child(summary),
+                )
+                .into_any_element(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collects_failed_checks_in_debug() {
+        let mut warnings = BuilderWarnings::new("NumberInput", "gain");
+        warnings.check(true, || "never".to_string());
+        warnings.check(false, || "min 10 is greater than max 0".to_string());
+
+        if cfg!(debug_assertions) {
+            assert_eq!(warnings.messages, ["min 10 is greater than max 0"]);
+        } else {
+            assert!(warnings.messages.is_empty());
+        }
+        assert!(BuilderWarnings::new("Select", "empty").marker().is_none());
+    }
+}
@@ -10,8 +10,10 @@
 
 use crate::ComponentTheme;
 use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::callback::Callback;
 use crate::progress::{Progress, ProgressSize, ProgressVariant};
 use crate::theme::ThemeExt;
+use crate::validation::BuilderWarnings;
 use gpui::prelude::*;
 use gpui::*;
 
@@ -155,17 +157,17 @@ pub struct Wizard {
     /// Custom label for the cancel button
     cancel_label: Option<SharedString>,
     /// Callback when step changes
-    on_step_change: Option<Box<dyn Fn(usize, &mut Window, &mut App) + 'static>>,
+    on_step_change: Option<Callback<usize>>,
     /// Callback when validation is needed before advancing
     on_validate: Option<Box<dyn Fn(usize) -> bool + 'static>>,
     /// Callback when finish is clicked (last step)
-    on_finish: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_finish: Option<Callback>,
     /// Callback when cancel is clicked
-    on_cancel: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_cancel: Option<Callback>,
     /// Callback when back is clicked
-    on_back: Option<Box<dyn Fn(usize, &mut Window, &mut App) + 'static>>,
+    on_back: Option<Callback<usize>>,
     /// Callback when next is clicked
-    on_next: Option<Box<dyn Fn(usize, &mut Window, &mut App) + 'static>>,
+    on_next: Option<Callback<usize>>,
 }
 
 impl Wizard {
@@ -283,7 +285,7 @@ impl Wizard {
         mut self,
         handler: impl Fn(usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_step_change = Some(Box::new(handler));
+        self.on_step_change = Some(Callback::new(handler));
         self
     }
 
@@ -295,25 +297,25 @@ impl Wizard {
 
     /// Set finish handler
     pub fn on_finish(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_finish = Some(Box::new(handler));
+        self.on_finish = Some(Callback::from_fn(handler));
         self
     }
 
     /// Set cancel handler
     pub fn on_cancel(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_cancel = Some(Box::new(handler));
+        self.on_cancel = Some(Callback::from_fn(handler));
         self
     }
 
     /// Set back button handler
     pub fn on_back(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
-        self.on_back = Some(Box::new(handler));
+        self.on_back = Some(Callback::new(handler));
         self
     }
 
     /// Set next button handler
     pub fn on_next(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
-        self.on_next = Some(Box::new(handler));
+        self.on_next = Some(Callback::new(handler));
         self
     }
 
@@ -446,18 +448,13 @@ impl Wizard {
 
         // Cancel button (if shown and we have a handler)
         if self.show_cancel && self.on_cancel.is_some() {
-            let on_cancel: Option<*const dyn Fn(&mut Window, &mut App)> =
-                self.on_cancel.as_ref().map(|f| f.as_ref() as *const _);
-
             let mut cancel_btn = Button::new("wizard-cancel", cancel_label)
                 .variant(ButtonVariant::Ghost)
                 .size(ButtonSize::Md)
                 .disabled(self.is_busy);
 
-            if let Some(handler_ptr) = on_cancel {
-                cancel_btn = cancel_btn.on_click(move |window, cx| unsafe {
-                    (*handler_ptr)(window, cx);
-                });
+            if let Some(on_cancel) = self.on_cancel.clone() {
+                cancel_btn = cancel_btn.on_click(move |window, cx| on_cancel.invoke(window, cx));
             }
 
             buttons = buttons.child(cancel_btn);
@@ -467,8 +464,6 @@ impl Wizard {
         buttons = buttons.child(div().flex_1());
 
         // Back button
-        let on_back: Option<*const dyn Fn(usize, &mut Window, &mut App)> =
-            self.on_back.as_ref().map(|f| f.as_ref() as *const _);
         let current_step = self.current_step;
 
         let mut back_btn = Button::new("wizard-back", back_label)
@@ -476,35 +471,24 @@ impl Wizard {
             .size(ButtonSize::Md)
             .disabled(self.is_busy);
 
-        if let Some(handler_ptr) = on_back {
-            back_btn = back_btn.on_click(move |window, cx| unsafe {
-                (*handler_ptr)(current_step, window, cx);
-            });
+        if let Some(on_back) = self.on_back.clone() {
+            back_btn = back_btn.on_click(move |window, cx| on_back.call(current_step, window, cx));
         }
 
         buttons = buttons.child(back_btn);
 
         // Next/Finish button
-        let on_next: Option<*const dyn Fn(usize, &mut Window, &mut App)> =
-            self.on_next.as_ref().map(|f| f.as_ref() as *const _);
-        let on_finish: Option<*const dyn Fn(&mut Window, &mut App)> =
-            self.on_finish.as_ref().map(|f| f.as_ref() as *const _);
-
         let mut next_btn = Button::new("wizard-next", next_label)
             .variant(ButtonVariant::Primary)
             .size(ButtonSize::Md)
             .disabled(self.is_busy);
 
         if is_last_step {
-            if let Some(handler_ptr) = on_finish {
-                next_btn = next_btn.on_click(move |window, cx| unsafe {
-                    (*handler_ptr)(window, cx);
-                });
+            if let Some(on_finish) = self.on_finish.clone() {
+                next_btn = next_btn.on_click(move |window, cx| on_finish.invoke(window, cx));
             }
-        } else if let Some(handler_ptr) = on_next {
-            next_btn = next_btn.on_click(move |window, cx| unsafe {
-                (*handler_ptr)(current_step, window, cx);
-            });
+        } else if let Some(on_next) = self.on_next.clone() {
+            next_btn = next_btn.on_click(move |window, cx| on_next.call(current_step, window, cx));
         }
 
         buttons = buttons.child(next_btn);
@@ -516,7 +500,26 @@ impl Wizard {
     pub fn build_with_theme(self, global_theme: &WizardTheme) -> Div {
         let theme = self.theme.as_ref().unwrap_or(global_theme);
 
-        let mut container = div().flex().flex_col().gap_4().w_full();
+        let mut warnings = BuilderWarnings::unnamed("Wizard");
+        warnings.check(self.step_statuses.len() == self.steps.len(), || {
+            format!(
+                "{} step statuses for {} steps",
+                self.step_statuses.len(),
+                self.steps.len()
+            )
+        });
+        warnings.check(
+            self.steps.is_empty() || self.current_step < self.steps.len(),
+            || {
+                format!(
+                    "current step {} is out of range ({} steps)",
+                    self.current_step,
+                    self.steps.len()
+                )
+            },
+        );
+
+        let mut container = div().relative().flex().flex_col().gap_4().w_full();
 
         // Step indicators
         let indicators = self.build_step_indicators(theme);
@@ -545,7 +548,7 @@ impl Wizard {
         let navigation = self.build_navigation(theme);
         container = container.child(navigation);
 
-        container
+        container.children(warnings.marker())
     }
 }
 
@@ -727,7 +730,26 @@ impl WizardHeader {
     pub fn build_with_theme(self, global_theme: &WizardTheme) -> Div {
         let theme = self.theme.as_ref().unwrap_or(global_theme);
 
-        let mut container = div().flex().items_center().gap_4();
+        let mut warnings = BuilderWarnings::unnamed("WizardHeader");
+        warnings.check(self.step_statuses.len() == self.steps.len(), || {
+            format!(
+                "{} step statuses for {} steps",
+                self.step_statuses.len(),
+                self.steps.len()
+            )
+        });
+        warnings.check(
+            self.steps.is_empty() || self.current_step < self.steps.len(),
+            || {
+                format!(
+                    "current step {} is out of range ({} steps)",
+                    self.current_step,
+                    self.steps.len()
+                )
+            },
+        );
+
+        let mut container = div().relative().flex().items_center().gap_4();
 
         // Title (if set)
         if let Some(title) = &self.title {
@@ -746,7 +768,7 @@ impl WizardHeader {
         // Step indicators
         container = container.child(self.build_step_indicators(theme));
 
-        container
+        container.children(warnings.marker())
     }
 }
 
@@ -787,10 +809,10 @@ pub struct WizardNavigation {
     cancel_label: Option<SharedString>,
     back_disabled: bool,
     next_disabled: bool,
-    on_back: Option<Box<dyn Fn(usize, &mut Window, &mut App) + 'static>>,
-    on_next: Option<Box<dyn Fn(usize, &mut Window, &mut App) + 'static>>,
-    on_finish: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
-    on_cancel: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_back: Option<Callback<usize>>,
+    on_next: Option<Callback<usize>>,
+    on_finish: Option<Callback>,
+    on_cancel: Option<Callback>,
     theme: Option<WizardTheme>,
 }
 
@@ -880,25 +902,25 @@ impl WizardNavigation {
 
     /// Set back handler
     pub fn on_back(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
-        self.on_back = Some(Box::new(handler));
+        self.on_back = Some(Callback::new(handler));
         self
     }
 
     /// Set next handler
     pub fn on_next(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
-        self.on_next = Some(Box::new(handler));
+        self.on_next = Some(Callback::new(handler));
         self
     }
 
     /// Set finish handler
     pub fn on_finish(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_finish = Some(Box::new(handler));
+        self.on_finish = Some(Callback::from_fn(handler));
         self
     }
 
     /// Set cancel handler
     pub fn on_cancel(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
-        self.on_cancel = Some(Box::new(handler));
+        self.on_cancel = Some(Callback::from_fn(handler));
         self
     }
 
@@ -956,18 +978,13 @@ impl WizardNavigation {
 
         // Cancel button
         if self.show_cancel {
-            let on_cancel: Option<*const dyn Fn(&mut Window, &mut App)> =
-                self.on_cancel.as_ref().map(|f| f.as_ref() as *const _);
-
             let mut cancel_btn = Button::new("wizard-nav-cancel", cancel_label)
                 .variant(ButtonVariant::Ghost)
                 .size(ButtonSize::Md)
                 .disabled(self.is_busy);
 
-            if let Some(handler_ptr) = on_cancel {
-                cancel_btn = cancel_btn.on_click(move |window, cx| unsafe {
-                    (*handler_ptr)(window, cx);
-                });
+            if let Some(on_cancel) = self.on_cancel.clone() {
+                cancel_btn = cancel_btn.on_click(move |window, cx| on_cancel.invoke(window, cx));
             }
 
             buttons = buttons.child(cancel_btn);
@@ -977,8 +994,6 @@ impl WizardNavigation {
         buttons = buttons.child(div().flex_1());
 
         // Back button
-        let on_back: Option<*const dyn Fn(usize, &mut Window, &mut App)> =
-            self.on_back.as_ref().map(|f| f.as_ref() as *const _);
         let current_step = self.current_step;
 
         let mut back_btn = Button::new("wizard-nav-back", back_label)
@@ -986,35 +1001,24 @@ impl WizardNavigation {
             .size(ButtonSize::Md)
             .disabled(self.is_busy || self.back_disabled);
 
-        if let Some(handler_ptr) = on_back {
-            back_btn = back_btn.on_click(move |window, cx| unsafe {
-                (*handler_ptr)(current_step, window, cx);
-            });
+        if let Some(on_back) = self.on_back.clone() {
+            back_btn = back_btn.on_click(move |window, cx| on_back.call(current_step, window, cx));
         }
 
         buttons = buttons.child(back_btn);
 
         // Next/Finish button
-        let on_next: Option<*const dyn Fn(usize, &mut Window, &mut App)> =
-            self.on_next.as_ref().map(|f| f.as_ref() as *const _);
-        let on_finish: Option<*const dyn Fn(&mut Window, &mut App)> =
-            self.on_finish.as_ref().map(|f| f.as_ref() as *const _);
-
         let mut next_btn = Button::new("wizard-nav-next", next_label)
             .variant(ButtonVariant::Primary)
             .size(ButtonSize::Md)
             .disabled(self.is_busy || self.next_disabled);
 
         if is_last_step {
-            if let Some(handler_ptr) = on_finish {
-                next_btn = next_btn.on_click(move |window, cx| unsafe {
-                    (*handler_ptr)(window, cx);
-                });
+            if let Some(on_finish) = self.on_finish.clone() {
+                next_btn = next_btn.on_click(move |window, cx| on_finish.invoke(window, cx));
             }
-        } else if let Some(handler_ptr) = on_next {
-            next_btn = next_btn.on_click(move |window, cx| unsafe {
-                (*handler_ptr)(current_step, window, cx);
-            });
+        } else if let Some(on_next) = self.on_next.clone() {
+            next_btn = next_btn.on_click(move |window, cx| on_next.call(current_step, window, cx));
         }
 
         buttons = buttons.child(next_btn);
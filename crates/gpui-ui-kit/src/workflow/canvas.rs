@@ -6,20 +6,30 @@ use super::history::{
     RemoveConnectionCommand, RemoveNodeCommand,
 };
 use super::hit_test::{HitTestResult, HitTester};
+use super::inspect::{
+    PortInspector, PortRef, PortValue, execution_fraction, execution_tint, format_duration,
+    inspected_port,
+};
 use super::node::WorkflowNode;
+use super::port::PortDirection;
 use super::state::{
     BoxSelection, CanvasState, Connection, ConnectionDrag, ContextMenuState, InteractionMode,
-    LinkType, NodeDragState, NodeId, Position, SelectionState, ViewportState, WorkflowGraph,
-    WorkflowNodeData,
+    LinkType, NodeDragState, NodeId, Position, ScrollBehavior, SelectionState, ViewportState,
+    WorkflowGraph, WorkflowNodeData,
 };
 use super::theme::WorkflowTheme;
+use crate::callback::Callback;
 use crate::menu::{Menu, MenuItem};
-use crate::theme::ThemeExt;
+use crate::theme::{Elevation, ThemeExt};
 use gpui::*;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Scroll distance in pixels of one mouse wheel line
+const SCROLL_LINE_HEIGHT: f32 = 50.0;
 
 /// Callback type for node double-click events
-pub type NodeDoubleClickCallback = Box<dyn Fn(NodeId, &mut Window, &mut App) + 'static>;
+pub type NodeDoubleClickCallback = Callback<NodeId>;
 
 /// Workflow canvas component
 ///
@@ -39,6 +49,20 @@ pub struct WorkflowCanvas {
     custom_menu_items: Option<Vec<MenuItem>>,
     /// Callback for node double-click
     on_node_double_click: Option<NodeDoubleClickCallback>,
+    /// Show port values on hover
+    inspection_mode: bool,
+    /// Reports the port values shown in inspection mode
+    port_inspector: Option<PortInspector>,
+    /// Port under the mouse in inspection mode, with the mouse position
+    inspected: Option<(PortRef, Position)>,
+    /// Tint nodes by execution time
+    debug_overlay: bool,
+    /// Last execution time of each node
+    execution_times: HashMap<NodeId, Duration>,
+    /// Space is held: dragging pans the canvas
+    space_held: bool,
+    /// Time of the last zoom animation frame
+    last_animation_frame: Option<Instant>,
 }
 
 impl WorkflowCanvas {
@@ -53,6 +77,13 @@ impl WorkflowCanvas {
             clipboard: None,
             custom_menu_items: None,
             on_node_double_click: None,
+            inspection_mode: false,
+            port_inspector: None,
+            inspected: None,
+            debug_overlay: false,
+            execution_times: HashMap::new(),
+            space_held: false,
+            last_animation_frame: None,
         }
     }
 
@@ -68,6 +99,13 @@ impl WorkflowCanvas {
             clipboard: None,
             custom_menu_items: None,
             on_node_double_click: None,
+            inspection_mode: false,
+            port_inspector: None,
+            inspected: None,
+            debug_overlay: false,
+            execution_times: HashMap::new(),
+            space_held: false,
+            last_animation_frame: None,
         }
     }
 
@@ -87,7 +125,56 @@ impl WorkflowCanvas {
         &mut self,
         callback: impl Fn(NodeId, &mut Window, &mut App) + 'static,
     ) {
-        self.on_node_double_click = Some(Box::new(callback));
+        self.on_node_double_click = Some(Callback::new(callback));
+    }
+
+    /// Show the last value of the port or connection under the mouse
+    ///
+    /// Values come from the inspector set with
+    /// [`WorkflowCanvas::set_port_inspector`].
+    pub fn set_inspection_mode(&mut self, enabled: bool) {
+        self.inspection_mode = enabled;
+        if !enabled {
+            self.inspected = None;
+        }
+    }
+
+    /// Whether port values are shown on hover
+    pub fn inspection_mode(&self) -> bool {
+        self.inspection_mode
+    }
+
+    /// Set the callback reporting the port values shown in inspection mode
+    pub fn set_port_inspector(
+        &mut self,
+        inspector: impl Fn(PortRef, &App) -> Option<PortValue> + 'static,
+    ) {
+        self.port_inspector = Some(Box::new(inspector));
+    }
+
+    /// Port whose value is shown, in inspection mode
+    pub fn inspected_port(&self) -> Option<PortRef> {
+        self.inspected.map(|(port, _)| port)
+    }
+
+    /// Tint nodes by their last execution time
+    pub fn set_debug_overlay(&mut self, enabled: bool) {
+        self.debug_overlay = enabled;
+    }
+
+    /// Record the last execution time of a node, for the debug overlay
+    pub fn set_execution_time(&mut self, node: NodeId, time: Duration) {
+        self.execution_times.insert(node, time);
+    }
+
+    /// Forget all execution times
+    pub fn clear_execution_times(&mut self) {
+        self.execution_times.clear();
+    }
+
+    /// Set what scrolling over the canvas does (default: zoom)
+    pub fn set_scroll_behavior(&mut self, behavior: ScrollBehavior) {
+        self.state.viewport.scroll_behavior = behavior;
     }
 
     // === Public API ===
@@ -335,8 +422,12 @@ impl WorkflowCanvas {
 
     /// Reset viewport to origin with zoom 1.0
     pub fn reset_viewport(&mut self, cx: &mut Context<Self>) {
-        self.state.viewport.offset = Position::new(0.0, 0.0);
-        self.state.viewport.zoom = 1.0;
+        let viewport = self.state.viewport;
+        self.state.viewport = ViewportState {
+            size: viewport.size,
+            scroll_behavior: viewport.scroll_behavior,
+            ..ViewportState::default()
+        };
         cx.notify();
     }
 
@@ -359,6 +450,14 @@ impl WorkflowCanvas {
             cx.notify();
         }
 
+        // Space+drag pans, whatever is under the mouse
+        if self.space_held {
+            self.state.mode = InteractionMode::Panning;
+            self.state.pan_position = Some(position);
+            cx.notify();
+            return;
+        }
+
         // position is in screen coordinates (relative to canvas element)
         let canvas_pos = self.state.viewport.screen_to_canvas(position.x, position.y);
 
@@ -437,6 +536,24 @@ impl WorkflowCanvas {
     fn handle_mouse_move(&mut self, position: Position, cx: &mut Context<Self>) {
         let canvas_pos = self.state.viewport.screen_to_canvas(position.x, position.y);
 
+        if self.inspection_mode {
+            // Only inspect while no drag is in progress
+            let inspected = if self.state.mode == InteractionMode::None {
+                let hit = self.hit_tester.hit_test_with_viewport(
+                    position,
+                    &self.state.graph,
+                    &self.state.viewport,
+                );
+                inspected_port(hit, &self.state.graph).map(|port| (port, position))
+            } else {
+                None
+            };
+            if inspected != self.inspected {
+                self.inspected = inspected;
+                cx.notify();
+            }
+        }
+
         match self.state.mode {
             InteractionMode::DraggingNodes => {
                 if let Some(ref drag) = self.state.node_drag {
@@ -468,7 +585,12 @@ impl WorkflowCanvas {
                 }
             }
             InteractionMode::Panning => {
-                // Handled separately via middle mouse
+                if let Some(last) = self.state.pan_position.replace(position) {
+                    self.state
+                        .viewport
+                        .pan(position.x - last.x, position.y - last.y);
+                    cx.notify();
+                }
             }
             InteractionMode::None => {}
         }
@@ -557,6 +679,9 @@ impl WorkflowCanvas {
                     }
                 }
             }
+            InteractionMode::Panning => {
+                self.state.pan_position = None;
+            }
             _ => {}
         }
 
@@ -589,7 +714,7 @@ impl WorkflowCanvas {
         if let HitTestResult::Node(node_id) = hit_result
             && let Some(ref callback) = self.on_node_double_click
         {
-            callback(node_id, window, cx);
+            callback.call(node_id, window, cx);
         }
     }
 
@@ -618,15 +743,59 @@ impl WorkflowCanvas {
         }
     }
 
-    fn handle_scroll(&mut self, delta: f32, position: Position, cx: &mut Context<Self>) {
-        self.state.viewport.zoom_at(delta, position.x, position.y);
+    fn handle_scroll(
+        &mut self,
+        event: &ScrollWheelEvent,
+        position: Position,
+        cx: &mut Context<Self>,
+    ) {
+        let modifiers = event.modifiers;
+        let viewport = &mut self.state.viewport;
+        match event.delta {
+            // Trackpads report pinch gestures as Ctrl+scroll
+            ScrollDelta::Pixels(pixels) if modifiers.control => {
+                let dy: f32 = pixels.y.into();
+                viewport.pinch_zoom(1.0 + dy / 100.0, position.x, position.y);
+            }
+            delta => {
+                let (dx, dy) = match delta {
+                    ScrollDelta::Lines(lines) => {
+                        (lines.x * SCROLL_LINE_HEIGHT, lines.y * SCROLL_LINE_HEIGHT)
+                    }
+                    ScrollDelta::Pixels(pixels) => (pixels.x.into(), pixels.y.into()),
+                };
+                let alternate = match viewport.scroll_behavior {
+                    ScrollBehavior::Zoom => modifiers.shift,
+                    ScrollBehavior::Pan => modifiers.platform || modifiers.control,
+                };
+                viewport.scroll(dx, dy, position.x, position.y, alternate);
+            }
+        }
         cx.notify();
     }
 
+    fn handle_key_up(&mut self, event: &KeyUpEvent, cx: &mut Context<Self>) {
+        if event.keystroke.key == "space" {
+            self.space_held = false;
+            if self.state.mode == InteractionMode::Panning {
+                self.state.mode = InteractionMode::None;
+                self.state.pan_position = None;
+            }
+            cx.notify();
+        }
+    }
+
     fn handle_key_down(&mut self, event: &KeyDownEvent, cx: &mut Context<Self>) {
         let modifiers = event.keystroke.modifiers;
 
         match &event.keystroke.key {
+            // Hold Space: drag to pan
+            key if key == "space" => {
+                if !self.space_held {
+                    self.space_held = true;
+                    cx.notify();
+                }
+            }
             // Delete selected
             key if key == "backspace" || key == "delete" => {
                 if !self.state.selection.is_empty() {
@@ -688,6 +857,7 @@ impl WorkflowCanvas {
                     self.state.node_drag = None;
                     self.state.connection_drag = None;
                     self.state.box_selection = None;
+                    self.state.pan_position = None;
                 } else {
                     self.state.selection.clear();
                 }
@@ -767,7 +937,21 @@ fn port_screen_position(
 
 /// GPUI View implementation
 impl Render for WorkflowCanvas {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Advance the smooth zoom, asking for frames until it settles
+        if self.state.viewport.is_animating() {
+            let now = Instant::now();
+            let elapsed = self
+                .last_animation_frame
+                .map_or(Duration::from_millis(16), |last| now - last);
+            if self.state.viewport.step_zoom_animation(elapsed) {
+                self.last_animation_frame = Some(now);
+                window.request_animation_frame();
+            } else {
+                self.last_animation_frame = None;
+            }
+        }
+
         let theme = self
             .theme
             .clone()
@@ -937,6 +1121,100 @@ impl Render for WorkflowCanvas {
             })
             .collect();
 
+        // Tint nodes by execution time, from the fastest to the slowest one
+        let execution_overlays: Vec<_> = if self.debug_overlay {
+            let fastest = self.execution_times.values().min().copied();
+            let slowest = self.execution_times.values().max().copied();
+            self.state
+                .graph
+                .nodes
+                .values()
+                .filter_map(|node| {
+                    let time = *self.execution_times.get(&node.id)?;
+                    let fraction = execution_fraction(time, fastest?, slowest?);
+                    let screen_pos = viewport.canvas_to_screen(&node.position);
+                    Some(
+                        div()
+                            .absolute()
+                            .left(px(screen_pos.x))
+                            .top(px(screen_pos.y))
+                            .w(px(node.width * viewport.zoom))
+                            .h(px(node.height * viewport.zoom))
+                            .rounded(px(scaled_theme.node_border_radius))
+                            .bg(execution_tint(
+                                fraction,
+                                theme.execution_fast,
+                                theme.execution_slow,
+                            ))
+                            .child(
+                                div()
+                                    .absolute()
+                                    .top(px(-20.0))
+                                    .right_0()
+                                    .px_1()
+                                    .rounded_sm()
+                                    .bg(theme.inspect_background)
+                                    .text_xs()
+                                    .text_color(theme.inspect_text)
+                                    .child(format_duration(time)),
+                            ),
+                    )
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // Value of the inspected port, next to the mouse
+        let inspect_tooltip = self.inspected.map(|(port, position)| {
+            let value = self
+                .port_inspector
+                .as_ref()
+                .and_then(|inspector| inspector(port, cx));
+            let title = self
+                .state
+                .graph
+                .nodes
+                .get(&port.node)
+                .map(|node| node.title.as_str())
+                .unwrap_or_default();
+            let direction = match port.direction {
+                PortDirection::Input => "in",
+                PortDirection::Output => "out",
+            };
+            let (value, type_name) = match value {
+                Some(value) => (value.value, value.type_name),
+                None => ("No value yet".into(), None),
+            };
+
+            div()
+                .id("workflow-inspect-tooltip")
+                .absolute()
+                .left(px(position.x + 12.0))
+                .top(px(position.y + 12.0))
+                .px_2()
+                .py_1()
+                .flex()
+                .flex_col()
+                .rounded_md()
+                .bg(theme.inspect_background)
+                .border_1()
+                .border_color(theme.inspect_border)
+                .shadow(cx.theme().elevation.shadow(Elevation::Overlay))
+                .text_xs()
+                .child(
+                    div()
+                        .text_color(theme.inspect_text_secondary)
+                        .child(format!("{title} · {direction} {}", port.index)),
+                )
+                .child(div().text_color(theme.inspect_text).child(value))
+                .children(type_name.map(|type_name| {
+                    div()
+                        .text_color(theme.inspect_text_secondary)
+                        .child(type_name)
+                }))
+        });
+
         // Build context menu
         let context_menu = if let Some(menu_state) = &self.state.context_menu {
             let entity = cx.entity().clone();
@@ -976,10 +1254,17 @@ impl Render for WorkflowCanvas {
             None
         };
 
+        let cursor = match (self.state.mode, self.space_held) {
+            (InteractionMode::Panning, _) => CursorStyle::ClosedHand,
+            (_, true) => CursorStyle::OpenHand,
+            _ => CursorStyle::Arrow,
+        };
+
         let mut result = div()
             .id("workflow-canvas")
             .size_full()
             .relative()
+            .cursor(cursor)
             .bg(theme.canvas_background)
             .overflow_hidden()
             // Draw grid pattern (simplified)
@@ -987,7 +1272,10 @@ impl Render for WorkflowCanvas {
             // Connections layer
             .child(connections_element)
             // Nodes layer
-            .children(node_elements);
+            .children(node_elements)
+            // Debug overlay
+            .children(execution_overlays)
+            .children(inspect_tooltip);
 
         // Add selection box if present
         if let Some(sel) = selection_box_element {
@@ -1048,23 +1336,19 @@ impl Render for WorkflowCanvas {
                 }),
             )
             .on_scroll_wheel(cx.listener(|this, event: &ScrollWheelEvent, _window, cx| {
-                let delta = match event.delta {
-                    ScrollDelta::Lines(lines) => lines.y,
-                    ScrollDelta::Pixels(pixels) => {
-                        let py: f32 = pixels.y.into();
-                        py / 100.0
-                    }
-                };
                 let x: f32 = event.position.x.into();
                 let y: f32 = event.position.y.into();
                 // Convert from window coordinates to canvas-element-relative coordinates
                 let pos = Position::new(x - this.canvas_origin.x, y - this.canvas_origin.y);
-                this.handle_scroll(delta, pos, cx);
+                this.handle_scroll(event, pos, cx);
             }))
             // Keyboard shortcuts
             .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
                 this.handle_key_down(event, cx);
             }))
+            .on_key_up(cx.listener(|this, event: &KeyUpEvent, _window, cx| {
+                this.handle_key_up(event, cx);
+            }))
             // Make focusable to receive keyboard events
             .focusable()
             .track_focus(&self.focus_handle)
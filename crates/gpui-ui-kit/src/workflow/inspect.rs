@@ -0,0 +1,112 @@
+//! Value inspection and execution time overlay for the workflow canvas
+//!
+//! In inspection mode, hovering a port shows the last value that went
+//! through it, as reported by the app's [`PortInspector`]. Hovering a
+//! connection shows the value of its source output port.
+//!
+//! The debug overlay tints every node with a known execution time, from
+//! the fast color for the quickest node to the slow color for the slowest
+//! one, and labels it with the time.
+
+use super::hit_test::HitTestResult;
+use super::port::PortDirection;
+use super::state::{NodeId, WorkflowGraph};
+use gpui::{App, Rgba, SharedString};
+use std::time::Duration;
+
+/// A port of a workflow node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PortRef {
+    pub node: NodeId,
+    pub direction: PortDirection,
+    pub index: usize,
+}
+
+/// Last value seen at a port, as reported by a [`PortInspector`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortValue {
+    /// Value formatted for display
+    pub value: SharedString,
+    /// Type of the value (e.g., "f32", "Audio 2ch")
+    pub type_name: Option<SharedString>,
+}
+
+impl PortValue {
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            type_name: None,
+        }
+    }
+
+    /// Set the type shown under the value
+    pub fn with_type(mut self, type_name: impl Into<SharedString>) -> Self {
+        self.type_name = Some(type_name.into());
+        self
+    }
+}
+
+/// Callback reporting the last value of a port, or `None` before any value
+/// went through it
+pub type PortInspector = Box<dyn Fn(PortRef, &App) -> Option<PortValue> + 'static>;
+
+/// Port to inspect for a hit test result
+///
+/// Ports are inspected themselves; connections show the value of their
+/// source output port.
+pub fn inspected_port(hit: HitTestResult, graph: &WorkflowGraph) -> Option<PortRef> {
+    match hit {
+        HitTestResult::InputPort(node, index) => Some(PortRef {
+            node,
+            direction: PortDirection::Input,
+            index,
+        }),
+        HitTestResult::OutputPort(node, index) => Some(PortRef {
+            node,
+            direction: PortDirection::Output,
+            index,
+        }),
+        HitTestResult::Connection(id) => {
+            let connection = graph.connections.iter().find(|c| c.id == id)?;
+            Some(PortRef {
+                node: connection.from_node,
+                direction: PortDirection::Output,
+                index: connection.from_port,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Position of `time` between the fastest and slowest times, from 0 to 1
+pub fn execution_fraction(time: Duration, fastest: Duration, slowest: Duration) -> f32 {
+    let range = slowest.saturating_sub(fastest).as_secs_f32();
+    if range <= 0.0 {
+        return 0.0;
+    }
+    (time.saturating_sub(fastest).as_secs_f32() / range).clamp(0.0, 1.0)
+}
+
+/// Tint of a node at `fraction` between the fast and slow colors
+pub fn execution_tint(fraction: f32, fast: Rgba, slow: Rgba) -> Rgba {
+    let t = fraction.clamp(0.0, 1.0);
+    let mix = |a: f32, b: f32| a + (b - a) * t;
+    Rgba {
+        r: mix(fast.r, slow.r),
+        g: mix(fast.g, slow.g),
+        b: mix(fast.b, slow.b),
+        a: 0.35,
+    }
+}
+
+/// Short label of an execution time (e.g., "850 µs", "12.3 ms", "1.25 s")
+pub fn format_duration(time: Duration) -> String {
+    let micros = time.as_secs_f64() * 1e6;
+    if micros < 1000.0 {
+        format!("{micros:.0} µs")
+    } else if micros < 1e6 {
+        format!("{:.1} ms", micros / 1000.0)
+    } else {
+        format!("{:.2} s", micros / 1e6)
+    }
+}
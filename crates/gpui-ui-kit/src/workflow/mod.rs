@@ -4,15 +4,18 @@
 //! - Draggable nodes with custom content
 //! - Directional connections between input/output ports
 //! - Selection (single, multi, box selection)
-//! - Pan/zoom navigation
+//! - Pan/zoom navigation: smooth zoom about the cursor, pinch-zoom, Space+drag
+//!   panning, and scroll set to pan or zoom
 //! - Undo/redo history
 //! - Copy/paste support
 //! - State persistence with versioned JSON
+//! - Port value inspection and an execution time overlay for debugging
 
 mod bezier;
 mod canvas;
 mod history;
 mod hit_test;
+mod inspect;
 mod node;
 mod port;
 mod state;
@@ -25,11 +28,12 @@ mod tests;
 pub use canvas::WorkflowCanvas;
 pub use history::{Command, HistoryManager};
 pub use hit_test::{HitTestResult, HitTester};
+pub use inspect::{PortInspector, PortRef, PortValue};
 pub use node::{NodeContent, WorkflowNode};
 pub use port::{Port, PortDirection};
 pub use state::{
     BoxSelection, CanvasState, Connection, ConnectionDrag, ConnectionId, InteractionMode, LinkType,
-    NodeDragState, NodeId, Position, SelectionState, ViewportState, WorkflowGraph,
+    NodeDragState, NodeId, Position, ScrollBehavior, SelectionState, ViewportState, WorkflowGraph,
     WorkflowNodeData,
 };
 pub use theme::WorkflowTheme;
@@ -3,6 +3,7 @@
 use super::port::{Port, PortDirection};
 use super::state::{NodeId, Position, WorkflowNodeData};
 use super::theme::WorkflowTheme;
+use crate::callback::Callback;
 use crate::theme::ThemeExt;
 use gpui::prelude::*;
 use gpui::*;
@@ -45,12 +46,10 @@ pub struct WorkflowNode {
     content: Option<Box<dyn NodeContent>>,
 
     // Event handlers
-    on_select: Option<Box<dyn Fn(NodeId, bool, &mut Window, &mut App) + 'static>>,
-    on_drag_start: Option<Box<dyn Fn(NodeId, Position, &mut Window, &mut App) + 'static>>,
-    on_port_mouse_down:
-        Option<Box<dyn Fn(NodeId, PortDirection, usize, &mut Window, &mut App) + 'static>>,
-    on_port_mouse_up:
-        Option<Box<dyn Fn(NodeId, PortDirection, usize, &mut Window, &mut App) + 'static>>,
+    on_select: Option<Callback<(NodeId, bool)>>,
+    on_drag_start: Option<Callback<(NodeId, Position)>>,
+    on_port_mouse_down: Option<Callback<(NodeId, PortDirection, usize)>>,
+    on_port_mouse_up: Option<Callback<(NodeId, PortDirection, usize)>>,
 }
 
 impl WorkflowNode {
@@ -100,7 +99,9 @@ impl WorkflowNode {
         mut self,
         handler: impl Fn(NodeId, bool, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_select = Some(Box::new(handler));
+        self.on_select = Some(Callback::new(
+            move |(node_id, shift): (NodeId, bool), window, cx| handler(node_id, shift, window, cx),
+        ));
         self
     }
 
@@ -109,7 +110,11 @@ impl WorkflowNode {
         mut self,
         handler: impl Fn(NodeId, Position, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_drag_start = Some(Box::new(handler));
+        self.on_drag_start = Some(Callback::new(
+            move |(node_id, position): (NodeId, Position), window, cx| {
+                handler(node_id, position, window, cx)
+            },
+        ));
         self
     }
 
@@ -118,7 +123,11 @@ impl WorkflowNode {
         mut self,
         handler: impl Fn(NodeId, PortDirection, usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_port_mouse_down = Some(Box::new(handler));
+        self.on_port_mouse_down = Some(Callback::new(
+            move |(node_id, direction, index): (NodeId, PortDirection, usize), window, cx| {
+                handler(node_id, direction, index, window, cx)
+            },
+        ));
         self
     }
 
@@ -127,7 +136,11 @@ impl WorkflowNode {
         mut self,
         handler: impl Fn(NodeId, PortDirection, usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_port_mouse_up = Some(Box::new(handler));
+        self.on_port_mouse_up = Some(Callback::new(
+            move |(node_id, direction, index): (NodeId, PortDirection, usize), window, cx| {
+                handler(node_id, direction, index, window, cx)
+            },
+        ));
         self
     }
 }
@@ -209,7 +222,7 @@ impl RenderOnce for WorkflowNode {
             .when_some(on_select, |el, handler| {
                 el.on_click(move |event, window, cx| {
                     let shift = event.modifiers().shift;
-                    handler(node_id, shift, window, cx);
+                    handler.call((node_id, shift), window, cx);
                 })
             })
             .when_some(on_drag_start, |el, handler| {
@@ -217,7 +230,7 @@ impl RenderOnce for WorkflowNode {
                     let x: f32 = event.position.x.into();
                     let y: f32 = event.position.y.into();
                     let pos = Position::new(x, y);
-                    handler(node_id, pos, window, cx);
+                    handler.call((node_id, pos), window, cx);
                 })
             })
             // Node structure
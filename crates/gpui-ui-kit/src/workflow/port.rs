@@ -1,11 +1,12 @@
 //! Port component for workflow nodes
 
 use super::theme::WorkflowTheme;
+use crate::callback::Callback;
 use gpui::prelude::*;
 use gpui::*;
 
 /// Port direction
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PortDirection {
     Input,
     Output,
@@ -20,8 +21,8 @@ pub struct Port {
     connected: bool,
     valid_target: Option<bool>,
     theme: Option<WorkflowTheme>,
-    on_mouse_down: Option<Box<dyn Fn(PortDirection, usize, &mut Window, &mut App) + 'static>>,
-    on_mouse_up: Option<Box<dyn Fn(PortDirection, usize, &mut Window, &mut App) + 'static>>,
+    on_mouse_down: Option<Callback<(PortDirection, usize)>>,
+    on_mouse_up: Option<Callback<(PortDirection, usize)>>,
 }
 
 impl Port {
@@ -61,7 +62,11 @@ impl Port {
         mut self,
         handler: impl Fn(PortDirection, usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_mouse_down = Some(Box::new(handler));
+        self.on_mouse_down = Some(Callback::new(
+            move |(direction, index): (PortDirection, usize), window, cx| {
+                handler(direction, index, window, cx)
+            },
+        ));
         self
     }
 
@@ -70,7 +75,11 @@ impl Port {
         mut self,
         handler: impl Fn(PortDirection, usize, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_mouse_up = Some(Box::new(handler));
+        self.on_mouse_up = Some(Callback::new(
+            move |(direction, index): (PortDirection, usize), window, cx| {
+                handler(direction, index, window, cx)
+            },
+        ));
         self
     }
 
@@ -123,13 +132,13 @@ impl RenderOnce for Port {
 
         if let Some(handler) = on_mouse_down {
             result = result.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
-                handler(direction, index, window, cx);
+                handler.call((direction, index), window, cx);
             });
         }
 
         if let Some(handler) = on_mouse_up {
             result = result.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
-                handler(direction, index, window, cx);
+                handler.call((direction, index), window, cx);
             });
         }
 
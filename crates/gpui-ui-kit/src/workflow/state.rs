@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 /// Unique identifier for workflow nodes
 pub type NodeId = uuid::Uuid;
@@ -310,6 +311,33 @@ impl WorkflowGraph {
     }
 }
 
+/// What scrolling over the canvas does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ScrollBehavior {
+    /// Scroll zooms about the cursor, Shift+scroll pans
+    #[default]
+    Zoom,
+    /// Scroll pans (e.g. two-finger trackpad swipe), Ctrl/Cmd+scroll zooms
+    Pan,
+}
+
+/// Zoom animation towards a target level
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ZoomAnimation {
+    target: f32,
+    /// Screen point kept fixed while zooming
+    anchor: Position,
+}
+
+/// Minimum zoom level
+const MIN_ZOOM: f32 = 0.25;
+/// Maximum zoom level
+const MAX_ZOOM: f32 = 4.0;
+/// Scroll distance in pixels for one zoom step
+const ZOOM_SCROLL_PIXELS: f32 = 50.0;
+/// Time constant of the zoom animation, in seconds
+const ZOOM_ANIMATION_TIME: f32 = 0.06;
+
 /// Viewport state (pan/zoom)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct ViewportState {
@@ -319,6 +347,11 @@ pub struct ViewportState {
     pub zoom: f32,
     /// Canvas size in pixels
     pub size: (f32, f32),
+    /// What scrolling over the canvas does
+    #[serde(default)]
+    pub scroll_behavior: ScrollBehavior,
+    #[serde(skip)]
+    zoom_animation: Option<ZoomAnimation>,
 }
 
 impl Default for ViewportState {
@@ -327,6 +360,8 @@ impl Default for ViewportState {
             offset: Position::new(0.0, 0.0),
             zoom: 1.0,
             size: (800.0, 600.0),
+            scroll_behavior: ScrollBehavior::default(),
+            zoom_animation: None,
         }
     }
 }
@@ -350,13 +385,63 @@ impl ViewportState {
 
     /// Apply zoom centered on a point
     pub fn zoom_at(&mut self, delta: f32, screen_x: f32, screen_y: f32) {
-        let old_zoom = self.zoom;
-        self.zoom = (self.zoom * (1.0 + delta * 0.1)).clamp(0.25, 4.0);
+        self.zoom_animation = None;
+        self.set_zoom_about(self.zoom * (1.0 + delta * 0.1), screen_x, screen_y);
+    }
 
-        // Adjust offset to keep the point under the cursor fixed
-        let scale_change = self.zoom / old_zoom;
-        self.offset.x = screen_x - (screen_x - self.offset.x) * scale_change;
-        self.offset.y = screen_y - (screen_y - self.offset.y) * scale_change;
+    /// Start an animated zoom centered on a point
+    ///
+    /// Successive calls before the animation ends add up, so fast scrolling
+    /// keeps zooming smoothly. Advance the animation with
+    /// [`step_zoom_animation`](Self::step_zoom_animation).
+    pub fn animate_zoom_at(&mut self, delta: f32, screen_x: f32, screen_y: f32) {
+        let from = self.zoom_animation.map_or(self.zoom, |a| a.target);
+        self.zoom_animation = Some(ZoomAnimation {
+            target: (from * (1.0 + delta * 0.1)).clamp(MIN_ZOOM, MAX_ZOOM),
+            anchor: Position::new(screen_x, screen_y),
+        });
+    }
+
+    /// Advance the zoom animation by `elapsed`, returning whether it is
+    /// still running
+    pub fn step_zoom_animation(&mut self, elapsed: Duration) -> bool {
+        let Some(animation) = self.zoom_animation else {
+            return false;
+        };
+        let t = 1.0 - (-elapsed.as_secs_f32() / ZOOM_ANIMATION_TIME).exp();
+        let mut zoom = self.zoom + (animation.target - self.zoom) * t;
+        if (animation.target - zoom).abs() < 1e-3 {
+            zoom = animation.target;
+            self.zoom_animation = None;
+        }
+        self.set_zoom_about(zoom, animation.anchor.x, animation.anchor.y);
+        self.zoom_animation.is_some()
+    }
+
+    /// Whether an animated zoom is in progress
+    pub fn is_animating(&self) -> bool {
+        self.zoom_animation.is_some()
+    }
+
+    /// Zoom by a pinch gesture's `scale` (e.g. 1.1 for 10% closer),
+    /// centered on a point
+    pub fn pinch_zoom(&mut self, scale: f32, screen_x: f32, screen_y: f32) {
+        self.zoom_animation = None;
+        self.set_zoom_about(self.zoom * scale, screen_x, screen_y);
+    }
+
+    /// Handle a scroll of `(dx, dy)` pixels with the cursor at a point
+    ///
+    /// Pans or zooms depending on [`scroll_behavior`](Self::scroll_behavior);
+    /// `alternate` (the modifier named in [`ScrollBehavior`]) does the
+    /// other one. Zooming is animated.
+    pub fn scroll(&mut self, dx: f32, dy: f32, screen_x: f32, screen_y: f32, alternate: bool) {
+        let zoom = (self.scroll_behavior == ScrollBehavior::Zoom) != alternate;
+        if zoom {
+            self.animate_zoom_at(dy / ZOOM_SCROLL_PIXELS, screen_x, screen_y);
+        } else {
+            self.pan(dx, dy);
+        }
     }
 
     /// Pan by a delta in screen coordinates
@@ -364,6 +449,17 @@ impl ViewportState {
         self.offset.x += dx;
         self.offset.y += dy;
     }
+
+    /// Set the zoom level, keeping the given screen point fixed
+    fn set_zoom_about(&mut self, zoom: f32, screen_x: f32, screen_y: f32) {
+        let old_zoom = self.zoom;
+        self.zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+
+        // Adjust offset to keep the point under the cursor fixed
+        let scale_change = self.zoom / old_zoom;
+        self.offset.x = screen_x - (screen_x - self.offset.x) * scale_change;
+        self.offset.y = screen_y - (screen_y - self.offset.y) * scale_change;
+    }
 }
 
 /// Selection state
@@ -491,6 +587,8 @@ pub struct CanvasState {
     pub connection_drag: Option<ConnectionDrag>,
     pub box_selection: Option<BoxSelection>,
     pub context_menu: Option<ContextMenuState>,
+    /// Last mouse position while panning, in screen coordinates
+    pub pan_position: Option<Position>,
 }
 
 impl Default for CanvasState {
@@ -504,6 +602,7 @@ impl Default for CanvasState {
             connection_drag: None,
             box_selection: None,
             context_menu: None,
+            pan_position: None,
         }
     }
 }
@@ -6,9 +6,15 @@ use super::history::{
     RemoveNodeCommand,
 };
 use super::hit_test::{HitTestResult, HitTester};
+use super::inspect::{
+    PortRef, execution_fraction, execution_tint, format_duration, inspected_port,
+};
+use super::port::PortDirection;
 use super::state::{
-    Connection, NodeId, Position, SelectionState, ViewportState, WorkflowGraph, WorkflowNodeData,
+    Connection, NodeId, Position, ScrollBehavior, SelectionState, ViewportState, WorkflowGraph,
+    WorkflowNodeData,
 };
+use std::time::Duration;
 
 // ============================================================================
 // Position Tests
@@ -339,6 +345,67 @@ fn test_viewport_roundtrip() {
     assert!((original.y - back.y).abs() < 0.001);
 }
 
+#[test]
+fn test_viewport_animated_zoom_keeps_anchor_fixed() {
+    let mut viewport = ViewportState::default();
+    let anchor = viewport.screen_to_canvas(200.0, 100.0);
+
+    // Two quick scroll steps add up to one target
+    viewport.animate_zoom_at(1.0, 200.0, 100.0);
+    viewport.animate_zoom_at(1.0, 200.0, 100.0);
+    assert!(viewport.is_animating());
+    assert_eq!(viewport.zoom, 1.0);
+
+    assert!(viewport.step_zoom_animation(Duration::from_millis(16)));
+    assert!(viewport.zoom > 1.0 && viewport.zoom < 1.21);
+
+    let mut frames = 0;
+    while viewport.step_zoom_animation(Duration::from_millis(16)) {
+        frames += 1;
+        assert!(frames < 100, "animation never settles");
+    }
+    assert!((viewport.zoom - 1.21).abs() < 1e-4);
+    let after = viewport.canvas_to_screen(&anchor);
+    assert!((after.x - 200.0).abs() < 1e-3);
+    assert!((after.y - 100.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_viewport_pinch_zoom() {
+    let mut viewport = ViewportState::default();
+    viewport.pinch_zoom(2.0, 100.0, 100.0);
+    assert_eq!(viewport.zoom, 2.0);
+    assert_eq!(viewport.offset.x, -100.0);
+    assert!(!viewport.is_animating());
+
+    // Clamped to the zoom limits
+    viewport.pinch_zoom(10.0, 100.0, 100.0);
+    assert_eq!(viewport.zoom, 4.0);
+}
+
+#[test]
+fn test_viewport_scroll_behavior() {
+    // Zoom (default): scroll zooms, the alternate modifier pans
+    let mut viewport = ViewportState::default();
+    viewport.scroll(0.0, 50.0, 0.0, 0.0, false);
+    assert!(viewport.is_animating());
+    assert_eq!(viewport.offset.y, 0.0);
+    let mut viewport = ViewportState::default();
+    viewport.scroll(10.0, 50.0, 0.0, 0.0, true);
+    assert!(!viewport.is_animating());
+    assert_eq!((viewport.offset.x, viewport.offset.y), (10.0, 50.0));
+
+    // Pan: the other way around
+    let mut viewport = ViewportState {
+        scroll_behavior: ScrollBehavior::Pan,
+        ..ViewportState::default()
+    };
+    viewport.scroll(10.0, 50.0, 0.0, 0.0, false);
+    assert_eq!((viewport.offset.x, viewport.offset.y), (10.0, 50.0));
+    viewport.scroll(0.0, 50.0, 0.0, 0.0, true);
+    assert!(viewport.is_animating());
+}
+
 // ============================================================================
 // HitTester Tests
 // ============================================================================
@@ -746,3 +813,67 @@ fn test_hit_test_priority() {
         _ => panic!("Expected InputPort hit result"),
     }
 }
+
+// ============================================================================
+// Inspection Tests
+// ============================================================================
+
+#[test]
+fn test_inspected_port_of_hits() {
+    let mut graph = WorkflowGraph::new();
+    let source = WorkflowNodeData::new("Source", Position::new(0.0, 0.0)).with_ports(0, 2);
+    let sink = WorkflowNodeData::new("Sink", Position::new(300.0, 0.0)).with_ports(1, 0);
+    let (source_id, sink_id) = (source.id, sink.id);
+    graph.add_node(source);
+    graph.add_node(sink);
+    graph.add_connection(source_id, 1, sink_id, 0).unwrap();
+    let connection_id = graph.connections[0].id;
+
+    assert_eq!(
+        inspected_port(HitTestResult::InputPort(sink_id, 0), &graph),
+        Some(PortRef {
+            node: sink_id,
+            direction: PortDirection::Input,
+            index: 0,
+        })
+    );
+    // A connection shows the value of its source port
+    assert_eq!(
+        inspected_port(HitTestResult::Connection(connection_id), &graph),
+        Some(PortRef {
+            node: source_id,
+            direction: PortDirection::Output,
+            index: 1,
+        })
+    );
+    assert_eq!(inspected_port(HitTestResult::Node(sink_id), &graph), None);
+    assert_eq!(inspected_port(HitTestResult::Canvas, &graph), None);
+}
+
+#[test]
+fn test_execution_fraction() {
+    let ms = Duration::from_millis;
+    assert_eq!(execution_fraction(ms(10), ms(10), ms(30)), 0.0);
+    assert_eq!(execution_fraction(ms(20), ms(10), ms(30)), 0.5);
+    assert_eq!(execution_fraction(ms(30), ms(10), ms(30)), 1.0);
+    // A single timed node counts as fast
+    assert_eq!(execution_fraction(ms(5), ms(5), ms(5)), 0.0);
+}
+
+#[test]
+fn test_execution_tint() {
+    let fast = gpui::rgb(0x00ff00);
+    let slow = gpui::rgb(0xff0000);
+    let tint = execution_tint(1.0, fast, slow);
+    assert_eq!((tint.r, tint.g), (1.0, 0.0));
+    let tint = execution_tint(0.5, fast, slow);
+    assert_eq!((tint.r, tint.g), (0.5, 0.5));
+    assert!(tint.a < 1.0);
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(Duration::from_micros(850)), "850 µs");
+    assert_eq!(format_duration(Duration::from_micros(12_345)), "12.3 ms");
+    assert_eq!(format_duration(Duration::from_millis(1250)), "1.25 s");
+}
@@ -63,6 +63,20 @@ pub struct WorkflowTheme {
     pub selection_fill: Rgba,
     /// Selection box border color
     pub selection_border: Rgba,
+
+    // Inspection
+    /// Port value tooltip background
+    pub inspect_background: Rgba,
+    /// Port value tooltip border
+    pub inspect_border: Rgba,
+    /// Port value tooltip text color
+    pub inspect_text: Rgba,
+    /// Port value tooltip secondary text color (port name, value type)
+    pub inspect_text_secondary: Rgba,
+    /// Debug overlay tint of the fastest node
+    pub execution_fast: Rgba,
+    /// Debug overlay tint of the slowest node
+    pub execution_slow: Rgba,
 }
 
 impl WorkflowTheme {
@@ -124,6 +138,14 @@ impl WorkflowTheme {
                 a: 0.1,
             },
             selection_border: theme.accent,
+
+            // Inspection
+            inspect_background: theme.surface,
+            inspect_border: theme.border,
+            inspect_text: theme.text_primary,
+            inspect_text_secondary: theme.text_secondary,
+            execution_fast: theme.success,
+            execution_slow: theme.error,
         }
     }
 
@@ -9,9 +9,12 @@
 //! - on_change callback
 
 use gpui::{
-    Context, Modifiers, MouseButton, TestAppContext, VisualTestContext, Window, div, prelude::*,
+    Context, Modifiers, MouseButton, SharedString, TestAppContext, VisualTestContext, Window, div,
+    prelude::*,
+};
+use gpui_ui_kit::accordion::{
+    Accordion, AccordionItem, AccordionMode, AccordionOrientation, AccordionView,
 };
-use gpui_ui_kit::accordion::{Accordion, AccordionItem, AccordionMode, AccordionOrientation};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -487,3 +490,42 @@ async fn test_accordion_empty(cx: &mut TestAppContext) {
 
     let _window = cx.add_window(|_window, _cx| EmptyAccordionView);
 }
+
+// ============================================================================
+// Stateful View Tests
+// ============================================================================
+
+fn view_items() -> Vec<AccordionItem> {
+    vec![
+        AccordionItem::new("first", "First").content(div().child("One")),
+        AccordionItem::new("second", "Second").content(div().child("Two")),
+    ]
+}
+
+#[gpui::test]
+async fn test_accordion_view_renders(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, _cx| {
+        AccordionView::new(view_items)
+            .mode(AccordionMode::Multiple)
+            .orientation(AccordionOrientation::Side)
+            .expanded(vec!["first".into()])
+    });
+
+    window
+        .update(cx, |view, _window, _cx| {
+            assert_eq!(view.expanded_items(), [SharedString::from("first")]);
+        })
+        .unwrap();
+}
+
+#[gpui::test]
+async fn test_accordion_view_set_expanded(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, _cx| AccordionView::new(view_items).controlled(true));
+
+    window
+        .update(cx, |view, _window, cx| {
+            view.set_expanded(vec!["second".into()], cx);
+            assert_eq!(view.expanded_items(), [SharedString::from("second")]);
+        })
+        .unwrap();
+}
@@ -9,8 +9,8 @@
 //! - Backdrop click behavior
 //! - Theme customization
 
-use gpui::{Context, TestAppContext, Window, div, prelude::*};
-use gpui_ui_kit::dialog::{Dialog, DialogSize, DialogTheme};
+use gpui::{Context, TestAppContext, VisualTestContext, Window, div, prelude::*};
+use gpui_ui_kit::dialog::{Dialog, DialogSize, DialogTheme, DialogView};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
@@ -331,6 +331,7 @@ async fn test_dialog_with_custom_theme(cx: &mut TestAppContext) {
                 close: gpui::rgb(0xaaaaaa),
                 close_hover: gpui::rgb(0xffffff),
                 close_hover_bg: gpui::rgb(0x444444),
+                shadow: Vec::new(),
             };
 
             div()
@@ -392,3 +393,83 @@ async fn test_dialog_empty_content(cx: &mut TestAppContext) {
 
     let _window = cx.add_window(|_window, _cx| EmptyContentView);
 }
+
+// ============================================================================
+// Stateful View Tests
+// ============================================================================
+
+#[gpui::test]
+async fn test_dialog_view_open_close(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, _cx| {
+        DialogView::new("dialog-view")
+            .title("Settings")
+            .size(DialogSize::Sm)
+    });
+
+    window
+        .update(cx, |view, _window, cx| {
+            assert!(!view.is_open());
+            view.open(cx);
+            assert!(view.is_open());
+        })
+        .unwrap();
+
+    window
+        .update(cx, |view, _window, cx| {
+            view.close(cx);
+            assert!(!view.is_open());
+        })
+        .unwrap();
+}
+
+#[gpui::test]
+async fn test_dialog_view_with_content_view(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, cx| {
+        let content = cx.new(|_| DialogTestView);
+        DialogView::new("dialog-view-content").content(content)
+    });
+
+    window
+        .update(cx, |view, _window, cx| view.open(cx))
+        .unwrap();
+}
+
+#[gpui::test]
+async fn test_dialog_focus_and_scroll_opt_outs(cx: &mut TestAppContext) {
+    struct OptOutView;
+
+    impl Render for OptOutView {
+        fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+            div().size_full().child(
+                Dialog::new("opt-out-dialog")
+                    .title("Non-modal")
+                    .return_focus(false)
+                    .lock_scroll(false)
+                    .on_close(|_window, _cx| {}),
+            )
+        }
+    }
+
+    let _window = cx.add_window(|_window, _cx| OptOutView);
+}
+
+#[gpui::test]
+async fn test_dialog_view_reopens_after_close(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, _cx| DialogView::new("reopen-dialog").title("Reopen"));
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+
+    for _ in 0..2 {
+        window
+            .update(&mut cx, |view, _window, cx| view.open(cx))
+            .unwrap();
+        cx.run_until_parked();
+        window
+            .update(&mut cx, |view, _window, cx| view.close(cx))
+            .unwrap();
+        cx.run_until_parked();
+    }
+
+    window
+        .update(&mut cx, |view, _window, _cx| assert!(!view.is_open()))
+        .unwrap();
+}
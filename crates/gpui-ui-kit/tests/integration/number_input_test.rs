@@ -15,12 +15,14 @@
 //! - Double-click to select all
 //! - Keyboard input
 //! - Scroll wheel
+//! - Drag to scrub
 //! - Theme customization
 
 use gpui::{
     Context, Modifiers, MouseButton, TestAppContext, VisualTestContext, Window, div, prelude::*,
 };
 use gpui_ui_kit::number_input::{NumberInput, NumberInputSize, NumberInputTheme};
+use gpui_ui_kit::scale::Scale;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -1123,6 +1125,61 @@ async fn test_number_input_small_step(cx: &mut TestAppContext) {
     let _window = cx.add_window(|_window, _cx| SmallStepView);
 }
 
+// ============================================================================
+// Scrub Tests
+// ============================================================================
+
+/// View with a logarithmic frequency field
+struct NumberInputScrubTestView {
+    value: Rc<RefCell<f64>>,
+}
+
+impl Render for NumberInputScrubTestView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let value_rc = self.value.clone();
+
+        div().size_full().child(
+            NumberInput::new("scrub-test-input")
+                .value(*self.value.borrow())
+                .range(20.0, 20000.0)
+                .unit("Hz")
+                .width(150.0)
+                .scrub(Scale::Logarithmic)
+                .on_change(move |new_val, _window, _cx| {
+                    *value_rc.borrow_mut() = new_val;
+                }),
+        )
+    }
+}
+
+/// Test dragging the value right scrubs it up, a decade per 100 pixels
+#[gpui::test]
+async fn test_number_input_scrub_logarithmic(cx: &mut TestAppContext) {
+    let value: Rc<RefCell<f64>> = Rc::new(RefCell::new(200.0));
+
+    let value_clone = value.clone();
+    let window = cx.add_window(move |_window, _cx| NumberInputScrubTestView { value: value_clone });
+
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+    cx.run_until_parked();
+
+    if let Some(bounds) = cx.debug_bounds("scrub-test-input") {
+        let start = bounds.center();
+        let end = gpui::point(start.x + gpui::px(30.0), start.y);
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_move(end, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_up(end, MouseButton::Left, Modifiers::default());
+        cx.run_until_parked();
+
+        let new_value = *value.borrow();
+        let expected = 200.0 * 10f64.powf(0.3);
+        assert!(
+            (new_value - expected).abs() < 1e-6,
+            "Value should be {expected} after scrubbing, got {new_value}"
+        );
+    }
+}
+
 // Note: Scroll wheel tests are not included because VisualTestContext
 // does not currently support simulate_scroll(). Scroll wheel functionality
 // should be tested manually.
@@ -9,10 +9,11 @@
 //! - Linear vs Logarithmic scales
 //! - Disabled state
 //! - Callbacks: on_change, on_select, on_reset, on_drag_start
+//! - Detents, bipolar display and value entry
 
 use gpui::{
     Context, Modifiers, MouseButton, ScrollDelta, ScrollWheelEvent, TestAppContext, TouchPhase,
-    VisualTestContext, Window, div, point, prelude::*,
+    VisualTestContext, Window, div, point, prelude::*, px,
 };
 use gpui_ui_kit::audio::potentiometer::{Potentiometer, PotentiometerScale, PotentiometerSize};
 use std::cell::RefCell;
@@ -1417,3 +1418,94 @@ async fn test_potentiometer_log_scale_end_sets_max(cx: &mut TestAppContext) {
         );
     }
 }
+
+// ============================================================================
+// Detent, Bipolar and Value Entry Tests
+// ============================================================================
+
+/// View with a bipolar gain knob snapping to 0 dB
+struct PotDetentView {
+    value: Rc<RefCell<f64>>,
+}
+
+impl Render for PotDetentView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let current_value = *self.value.borrow();
+        let value_rc = self.value.clone();
+
+        div().size_full().child(
+            Potentiometer::new("detent-pot")
+                .value(current_value)
+                .min(-12.0)
+                .max(12.0)
+                .unit("dB")
+                .label("Gain")
+                .detents([0.0])
+                .bipolar(true)
+                .value_entry(true)
+                .on_change(move |new_value, _window, _cx| {
+                    *value_rc.borrow_mut() = new_value;
+                }),
+        )
+    }
+}
+
+/// Test that dragging next to a detent snaps onto it
+#[gpui::test]
+async fn test_potentiometer_drag_snaps_to_detent(cx: &mut TestAppContext) {
+    let value = Rc::new(RefCell::new(-1.5));
+
+    let value_clone = value.clone();
+    let window = cx.add_window(move |_window, _cx| PotDetentView { value: value_clone });
+
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+    cx.run_until_parked();
+
+    if let Some(bounds) = cx.debug_bounds("detent-pot") {
+        let center = bounds.center();
+        cx.simulate_mouse_down(center, MouseButton::Left, Modifiers::default());
+        // 4px of the 60px knob is +1.6 dB: -1.5 dB lands on 0.1 dB, next to 0 dB
+        let up = point(center.x, center.y - px(4.0));
+        cx.simulate_mouse_move(up, Some(MouseButton::Left), Modifiers::default());
+        cx.simulate_mouse_up(up, MouseButton::Left, Modifiers::default());
+        cx.run_until_parked();
+
+        let new_val = *value.borrow();
+        assert_eq!(new_val, 0.0, "Drag should snap to the 0 dB detent");
+    }
+}
+
+/// Test that double-clicking opens the value entry and Enter commits it
+#[gpui::test]
+async fn test_potentiometer_value_entry(cx: &mut TestAppContext) {
+    let value = Rc::new(RefCell::new(3.0));
+
+    let value_clone = value.clone();
+    let window = cx.add_window(move |_window, _cx| PotDetentView { value: value_clone });
+
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+    cx.run_until_parked();
+
+    if let Some(bounds) = cx.debug_bounds("detent-pot") {
+        let center = bounds.center();
+        cx.simulate_mouse_down(center, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_up(center, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_down(center, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_up(center, MouseButton::Left, Modifiers::default());
+        cx.run_until_parked();
+
+        // Clicks don't step the value when value entry is enabled
+        assert_eq!(*value.borrow(), 3.0);
+
+        cx.simulate_input("-4.5");
+        cx.simulate_keystrokes("enter");
+        cx.run_until_parked();
+
+        let new_val = *value.borrow();
+        assert!(
+            (new_val + 4.5).abs() < 0.001,
+            "Typed value should be committed, got {}",
+            new_val
+        );
+    }
+}
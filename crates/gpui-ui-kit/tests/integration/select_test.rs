@@ -11,7 +11,7 @@
 use gpui::{
     Context, Modifiers, MouseButton, TestAppContext, VisualTestContext, Window, div, prelude::*,
 };
-use gpui_ui_kit::select::{Select, SelectOption, SelectSize};
+use gpui_ui_kit::select::{Select, SelectOption, SelectSize, SelectView};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -454,3 +454,74 @@ async fn test_select_shows_selected_label(cx: &mut TestAppContext) {
 
     let _window = cx.add_window(|_window, _cx| SelectedLabelView);
 }
+
+// ============================================================================
+// Stateful View Tests
+// ============================================================================
+
+fn fruit_options() -> Vec<SelectOption> {
+    vec![
+        SelectOption::new("apple", "Apple"),
+        SelectOption::new("banana", "Banana"),
+    ]
+}
+
+#[gpui::test]
+async fn test_select_view_opens_itself(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, _cx| {
+        SelectView::new("select-view", fruit_options()).placeholder("Fruit")
+    });
+
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+    cx.run_until_parked();
+
+    let bounds = cx
+        .debug_bounds("select-view")
+        .expect("select trigger should be rendered");
+    assert!(cx.debug_bounds("select-view-dropdown").is_none());
+
+    cx.simulate_mouse_down(bounds.center(), MouseButton::Left, Modifiers::default());
+    cx.run_until_parked();
+
+    window
+        .update(&mut cx, |view, _window, _cx| assert!(view.is_open()))
+        .unwrap();
+    assert!(cx.debug_bounds("select-view-dropdown").is_some());
+    assert!(cx.debug_bounds("select-view-option-0").is_some());
+    assert!(cx.debug_bounds("select-view-option-1").is_some());
+}
+
+#[gpui::test]
+async fn test_select_view_set_options_drops_missing_selection(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, _cx| {
+        SelectView::new("select-view", fruit_options()).selected("banana")
+    });
+
+    window
+        .update(cx, |view, _window, cx| {
+            assert_eq!(
+                view.selected_value().map(ToString::to_string).as_deref(),
+                Some("banana")
+            );
+            view.set_options(vec![SelectOption::new("apple", "Apple")], cx);
+            assert!(view.selected_value().is_none());
+        })
+        .unwrap();
+}
+
+#[gpui::test]
+async fn test_select_view_controlled_selection(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, _cx| {
+        SelectView::new("controlled-select", fruit_options()).controlled(true)
+    });
+
+    window
+        .update(cx, |view, _window, cx| {
+            view.set_selected(Some("apple".into()), cx);
+            assert_eq!(
+                view.selected_value().map(ToString::to_string).as_deref(),
+                Some("apple")
+            );
+        })
+        .unwrap();
+}
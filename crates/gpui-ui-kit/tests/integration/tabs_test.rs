@@ -10,7 +10,7 @@
 use gpui::{
     Context, Modifiers, MouseButton, TestAppContext, VisualTestContext, Window, div, prelude::*,
 };
-use gpui_ui_kit::tabs::{TabItem, TabVariant, Tabs};
+use gpui_ui_kit::tabs::{TabItem, TabVariant, Tabs, TabsView};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -379,3 +379,46 @@ async fn test_tabs_complex(cx: &mut TestAppContext) {
 
     let _window = cx.add_window(|_window, _cx| ComplexTabsView);
 }
+
+// ============================================================================
+// Stateful View Tests
+// ============================================================================
+
+#[gpui::test]
+async fn test_tabs_view_renders(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, cx| {
+        TabsView::new("tabs-view", cx)
+            .tabs(|| vec![TabItem::new("one", "One"), TabItem::new("two", "Two")])
+            .variant(TabVariant::Pills)
+            .selected_index(1)
+    });
+
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+    cx.run_until_parked();
+
+    window
+        .update(&mut cx, |view, _window, _cx| assert_eq!(view.selected(), 1))
+        .unwrap();
+}
+
+#[gpui::test]
+async fn test_tabs_view_clamps_selection_to_tabs(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, cx| {
+        TabsView::new("tabs-view", cx)
+            .tabs(|| vec![TabItem::new("one", "One"), TabItem::new("two", "Two")])
+            .selected_index(1)
+    });
+
+    window
+        .update(cx, |view, _window, cx| {
+            view.set_tabs(|| vec![TabItem::new("one", "One")], cx);
+        })
+        .unwrap();
+
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+    cx.run_until_parked();
+
+    window
+        .update(&mut cx, |view, _window, _cx| assert_eq!(view.selected(), 0))
+        .unwrap();
+}
@@ -1,7 +1,9 @@
 //! Integration test for Toast component
 
-use gpui::{Context, TestAppContext, Window, div, prelude::*};
-use gpui_ui_kit::toast::Toast;
+use gpui::{Context, ElementId, TestAppContext, Window, div, prelude::*};
+use gpui_ui_kit::toast::{Toast, ToastContainerView, ToastEvent, ToastPosition};
+use std::cell::RefCell;
+use std::rc::Rc;
 
 struct ToastTestView;
 
@@ -15,3 +17,32 @@ impl Render for ToastTestView {
 async fn test_toast_renders(cx: &mut TestAppContext) {
     let _window = cx.add_window(|_window, _cx| ToastTestView);
 }
+
+#[gpui::test]
+async fn test_toast_container_view_dismiss(cx: &mut TestAppContext) {
+    let window = cx.add_window(|_window, _cx| ToastContainerView::new(ToastPosition::TopRight));
+    let view = window.root(cx).unwrap();
+
+    let dismissed: Rc<RefCell<Vec<ElementId>>> = Rc::new(RefCell::new(Vec::new()));
+    let dismissed_clone = dismissed.clone();
+    cx.update(|cx| {
+        cx.subscribe(&view, move |_, event: &ToastEvent, _| {
+            let ToastEvent::Dismissed { id } = event;
+            dismissed_clone.borrow_mut().push(id.clone());
+        })
+        .detach();
+    });
+
+    view.update(cx, |view, cx| {
+        view.push(Toast::new("saved", "Saved").persistent(), cx);
+        // Same ID replaces the shown toast
+        view.push(Toast::new("saved", "Saved again").persistent(), cx);
+        assert_eq!(view.len(), 1);
+
+        view.dismiss(&ElementId::from("saved"), cx);
+        view.dismiss(&ElementId::from("saved"), cx);
+        assert!(view.is_empty());
+    });
+
+    assert_eq!(dismissed.borrow().len(), 1);
+}
@@ -13,6 +13,7 @@
 //! - Theme customization
 //! - Scroll wheel interactions
 //! - Keyboard navigation
+//! - Multiple thumbs and linked pairs
 
 use gpui::{
     Context, Modifiers, ScrollDelta, ScrollWheelEvent, TestAppContext, TouchPhase,
@@ -21,6 +22,7 @@ use gpui::{
 use gpui_ui_kit::audio::vertical_slider::{
     VerticalSlider, VerticalSliderSize, VerticalSliderTheme,
 };
+use gpui_ui_kit::audio::vertical_slider_pair::VerticalSliderPair;
 use gpui_ui_kit::scale::Scale;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -2479,3 +2481,175 @@ async fn test_vertical_slider_track_scroll_wheel(cx: &mut TestAppContext) {
         );
     }
 }
+
+// ============================================================================
+// INTERACTION TESTS - Multiple Thumbs and Linked Pair
+// ============================================================================
+
+/// View with a low/high threshold slider
+struct SliderThumbsView {
+    values: Rc<RefCell<Vec<f64>>>,
+}
+
+impl Render for SliderThumbsView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let values = self.values.borrow().clone();
+        let values_rc = self.values.clone();
+
+        div().size_full().child(
+            VerticalSlider::new("thumbs-slider")
+                .thumbs(values)
+                .min(0.0)
+                .max(100.0)
+                .label("Threshold")
+                .on_thumb_change(move |index, new_val, _window, _cx| {
+                    values_rc.borrow_mut()[index] = new_val;
+                }),
+        )
+    }
+}
+
+/// Test dragging near a thumb moves that thumb only, up to its neighbor
+#[gpui::test]
+async fn test_vertical_slider_thumbs_drag_nearest(cx: &mut TestAppContext) {
+    let values = Rc::new(RefCell::new(vec![20.0, 80.0]));
+
+    let values_clone = values.clone();
+    let window = cx.add_window(move |_window, _cx| SliderThumbsView {
+        values: values_clone,
+    });
+
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+    cx.run_until_parked();
+
+    if let Some(track_bounds) = cx.debug_bounds("thumbs-slider-track") {
+        // Press on the upper thumb (80% up the track) and drag it down
+        let height: f32 = track_bounds.size.height.into();
+        let x = track_bounds.center().x;
+        let start = point(x, track_bounds.bottom() - px(height * 0.8));
+        cx.simulate_mouse_down(start, gpui::MouseButton::Left, gpui::Modifiers::default());
+        cx.simulate_mouse_move(
+            point(x, start.y + px(height * 0.2)),
+            gpui::MouseButton::Left,
+            gpui::Modifiers::default(),
+        );
+        cx.run_until_parked();
+
+        let after = values.borrow().clone();
+        assert_eq!(after[0], 20.0, "Lower thumb should not move");
+        assert!(
+            after[1] < 80.0,
+            "Upper thumb should follow the drag, got {}",
+            after[1]
+        );
+
+        // Dragging further down stops at the lower thumb
+        cx.simulate_mouse_move(
+            point(x, track_bounds.bottom()),
+            gpui::MouseButton::Left,
+            gpui::Modifiers::default(),
+        );
+        cx.simulate_mouse_up(
+            point(x, track_bounds.bottom()),
+            gpui::MouseButton::Left,
+            gpui::Modifiers::default(),
+        );
+        cx.run_until_parked();
+
+        let after = values.borrow().clone();
+        assert!(
+            after[1] >= after[0],
+            "Thumbs should not cross, got {:?}",
+            after
+        );
+    }
+}
+
+/// View with a linked stereo pair
+struct SliderPairView {
+    values: Rc<RefCell<(f64, f64)>>,
+    linked: Rc<RefCell<bool>>,
+}
+
+impl Render for SliderPairView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let (left, right) = *self.values.borrow();
+        let values_rc = self.values.clone();
+        let linked_rc = self.linked.clone();
+
+        div().size_full().child(
+            VerticalSliderPair::new("pair")
+                .values(left, right)
+                .min(-60.0)
+                .max(12.0)
+                .unit("dB")
+                .linked(*self.linked.borrow())
+                .on_change(move |values, _window, _cx| {
+                    *values_rc.borrow_mut() = *values;
+                })
+                .on_link_change(move |linked, _window, _cx| {
+                    *linked_rc.borrow_mut() = *linked;
+                }),
+        )
+    }
+}
+
+/// Test a linked pair moves both sides by the same amount until unlinked
+#[gpui::test]
+async fn test_vertical_slider_pair_linked(cx: &mut TestAppContext) {
+    let values = Rc::new(RefCell::new((-12.0, -6.0)));
+    let linked = Rc::new(RefCell::new(true));
+
+    let values_clone = values.clone();
+    let linked_clone = linked.clone();
+    let window = cx.add_window(move |_window, _cx| SliderPairView {
+        values: values_clone,
+        linked: linked_clone,
+    });
+
+    let mut cx = VisualTestContext::from_window(window.into(), cx);
+    cx.run_until_parked();
+
+    if let Some(bounds) = cx.debug_bounds("pair-left") {
+        // Scroll up on the left side
+        cx.simulate_event(ScrollWheelEvent {
+            position: bounds.center(),
+            delta: ScrollDelta::Lines(point(0.0, -1.0)),
+            modifiers: Modifiers::default(),
+            touch_phase: TouchPhase::Moved,
+        });
+        cx.run_until_parked();
+
+        let (left, right) = *values.borrow();
+        assert!(left > -12.0, "Left side should increase, got {}", left);
+        assert!(
+            (right - left - 6.0).abs() < 1e-9,
+            "Linked sides should keep their offset, got ({}, {})",
+            left,
+            right
+        );
+    }
+
+    if let Some(bounds) = cx.debug_bounds("pair-link") {
+        cx.simulate_click(bounds.center(), gpui::Modifiers::default());
+        cx.run_until_parked();
+        assert!(!*linked.borrow(), "Link toggle should unlink the pair");
+
+        let right_before = values.borrow().1;
+        if let Some(bounds) = cx.debug_bounds("pair-left") {
+            cx.simulate_event(ScrollWheelEvent {
+                position: bounds.center(),
+                delta: ScrollDelta::Lines(point(0.0, 1.0)),
+                modifiers: Modifiers::default(),
+                touch_phase: TouchPhase::Moved,
+            });
+            cx.run_until_parked();
+
+            assert_eq!(
+                values.borrow().1,
+                right_before,
+                "Unlinked right side should stay put"
+            );
+        }
+    }
+}
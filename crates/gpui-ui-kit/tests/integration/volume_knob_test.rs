@@ -11,6 +11,7 @@
 //! - Theme customization
 //! - Color overrides
 //! - Value clamping at bounds (0.0 to 1.0)
+//! - Detents and bipolar fill
 
 use gpui::{
     Context, FocusHandle, Modifiers, MouseButton, ScrollDelta, ScrollWheelEvent, TestAppContext,
@@ -1151,3 +1152,37 @@ async fn test_volume_knob_step_size(cx: &mut TestAppContext) {
         );
     }
 }
+
+// ============================================================================
+// Detent and Bipolar Tests
+// ============================================================================
+
+#[gpui::test]
+async fn test_volume_knob_bipolar_with_detents(cx: &mut TestAppContext) {
+    struct BipolarView;
+
+    impl Render for BipolarView {
+        fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .flex()
+                .gap_4()
+                .child(
+                    VolumeKnob::new()
+                        .id("pan-left")
+                        .value(0.25)
+                        .bipolar(true)
+                        .detents([0.5]),
+                )
+                .child(
+                    VolumeKnob::new()
+                        .id("pan-center")
+                        .value(0.5)
+                        .bipolar(true)
+                        .detents([0.5])
+                        .value_entry(true),
+                )
+        }
+    }
+
+    let _window = cx.add_window(|_window, _cx| BipolarView);
+}